@@ -13,9 +13,17 @@
 //!
 //! - Phase 4B.1: Active (block commands migration)
 
+use std::sync::Arc;
+
+use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use pulsearc_core::classification::ports::BlockSyncQueue;
+use pulsearc_core::sync::ports::OutboxQueue;
+use pulsearc_core::user::ports::UserProfileRepository;
 use pulsearc_domain::types::classification::{ActivityBreakdown, ProposedBlock};
 use pulsearc_domain::types::database::PrismaTimeEntryDto;
+use pulsearc_domain::types::{OutboxStatus, TimeEntryOutbox};
+use pulsearc_domain::{PulseArcError, Result as DomainResult};
 use serde::Serialize;
 use thiserror::Error;
 use {blake3, hex};
@@ -148,6 +156,83 @@ pub fn generate_idempotency_key(block_id: &str, user_id: &str, start_ts: i64) ->
     )
 }
 
+/// [`BlockSyncQueue`] that enqueues blocks as SAP-bound outbox entries
+///
+/// Entries are attributed to the current user profile (single-user system)
+/// and keyed with [`generate_idempotency_key`].
+pub struct OutboxBlockSyncQueue {
+    user_profile: Arc<dyn UserProfileRepository>,
+    outbox_queue: Arc<dyn OutboxQueue>,
+}
+
+impl OutboxBlockSyncQueue {
+    /// Create a queue writing to `outbox_queue`
+    pub fn new(
+        user_profile: Arc<dyn UserProfileRepository>,
+        outbox_queue: Arc<dyn OutboxQueue>,
+    ) -> Self {
+        Self { user_profile, outbox_queue }
+    }
+
+    /// Enqueue `block` and return the idempotency key of its outbox entry
+    pub async fn enqueue(&self, block: &ProposedBlock) -> DomainResult<String> {
+        let user_profile = self.user_profile.get_current_profile().await?.ok_or_else(|| {
+            PulseArcError::InvalidInput("No user profile found. Please log in.".into())
+        })?;
+
+        let user_id = user_profile.auth0_id;
+        let org_id = user_profile.org_id;
+
+        // Convert block to time entry DTO for SAP
+        let dto = block_to_time_entry_dto(block, &user_id, &org_id).map_err(|e| {
+            PulseArcError::Internal(format!("Failed to convert block to DTO: {}", e))
+        })?;
+
+        let idempotency_key = generate_idempotency_key(&block.id, &user_id, block.start_ts);
+        let now = Utc::now().timestamp();
+
+        let outbox_entry = TimeEntryOutbox {
+            id: uuid::Uuid::now_v7().to_string(),
+            idempotency_key: idempotency_key.clone(),
+            user_id: user_id.clone(),
+            payload_json: serde_json::to_string(&dto).map_err(|e| {
+                PulseArcError::Internal(format!("Failed to serialize payload: {}", e))
+            })?,
+            backend_cuid: None,
+            status: OutboxStatus::Pending,
+            attempts: 0,
+            last_error: None,
+            retry_after: None,
+            created_at: now,
+            sent_at: None,
+            correlation_id: None,
+            local_status: None,
+            remote_status: None,
+            sap_entry_id: None,
+            next_attempt_at: None,
+            error_code: None,
+            last_forwarded_at: None,
+            wbs_code: block.inferred_wbs_code.clone(),
+            target: "sap".to_string(),
+            description: None,
+            auto_applied: false,
+            version: 1,
+            last_modified_by: user_id,
+            last_modified_at: Some(now),
+        };
+
+        self.outbox_queue.enqueue(&outbox_entry).await?;
+        Ok(idempotency_key)
+    }
+}
+
+#[async_trait]
+impl BlockSyncQueue for OutboxBlockSyncQueue {
+    async fn enqueue_block(&self, block: &ProposedBlock) -> DomainResult<()> {
+        self.enqueue(block).await.map(|_| ())
+    }
+}
+
 /// Format Unix timestamp to ISO 8601 date string for Prisma schema
 ///
 /// Converts epoch seconds to "YYYY-MM-DD" format required by backend API.
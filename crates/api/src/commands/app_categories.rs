@@ -0,0 +1,162 @@
+//! App category rule commands
+//!
+//! Lets the user override how activity in an app is categorized (e.g. "Slack
+//! is client work for project X"). Rules take effect on the next capture.
+//! Teams can also import a shared rules file.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Instant;
+
+use chrono::Utc;
+use pulsearc_domain::types::classification::{
+    AppCategoryConflictPolicy, AppCategoryRule, AppCategoryRuleImport, AppCategoryRuleParams,
+};
+use tauri::State;
+use tracing::info;
+
+use crate::context::AppContext;
+use crate::utils::command_error::CommandError;
+use crate::utils::logging::{log_command_execution, record_command_metric, MetricRecord};
+
+/// List the user's app category rules, oldest first
+#[tauri::command]
+pub async fn list_app_category_rules(
+    ctx: State<'_, Arc<AppContext>>,
+) -> Result<Vec<AppCategoryRule>, CommandError> {
+    let command_name = "app_categories::list_app_category_rules";
+    let implementation = "new";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(command = command_name, "Listing app category rules");
+    let result = app_ctx.app_category_rules.list().await;
+    let elapsed = start.elapsed();
+    let success = result.is_ok();
+    let error_label = result.as_ref().err().map(|err| err.to_string());
+
+    log_command_execution(command_name, implementation, elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation,
+            elapsed,
+            success,
+            error_type: error_label.as_deref(),
+        },
+    )
+    .await;
+
+    result.map_err(CommandError::from)
+}
+
+/// Add an app category rule overriding the defaults for an app
+#[tauri::command]
+pub async fn add_app_category_rule(
+    ctx: State<'_, Arc<AppContext>>,
+    params: AppCategoryRuleParams,
+) -> Result<AppCategoryRule, CommandError> {
+    let command_name = "app_categories::add_app_category_rule";
+    let implementation = "new";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(command = command_name, bundle_id = %params.bundle_id, "Adding app category rule");
+    let result = app_ctx.app_category_rules.add(params, Utc::now().timestamp()).await;
+    let elapsed = start.elapsed();
+    let success = result.is_ok();
+    let error_label = result.as_ref().err().map(|err| err.to_string());
+
+    log_command_execution(command_name, implementation, elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation,
+            elapsed,
+            success,
+            error_type: error_label.as_deref(),
+        },
+    )
+    .await;
+
+    result.map_err(CommandError::from)
+}
+
+/// Remove an app category rule, returning whether it existed
+#[tauri::command]
+pub async fn remove_app_category_rule(
+    ctx: State<'_, Arc<AppContext>>,
+    id: String,
+) -> Result<bool, CommandError> {
+    let command_name = "app_categories::remove_app_category_rule";
+    let implementation = "new";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(command = command_name, rule_id = %id, "Removing app category rule");
+    let result = app_ctx.app_category_rules.remove(&id).await;
+    let elapsed = start.elapsed();
+    let success = result.is_ok();
+    let error_label = result.as_ref().err().map(|err| err.to_string());
+
+    log_command_execution(command_name, implementation, elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation,
+            elapsed,
+            success,
+            error_type: error_label.as_deref(),
+        },
+    )
+    .await;
+
+    result.map_err(CommandError::from)
+}
+
+/// Import app category rules from a team's shared JSON or TOML file
+///
+/// Rules matching the same windows as a local rule are resolved with
+/// `policy`; invalid rules are listed in the result instead of failing the
+/// import.
+#[tauri::command]
+pub async fn import_app_category_rules(
+    ctx: State<'_, Arc<AppContext>>,
+    source: String,
+    policy: AppCategoryConflictPolicy,
+) -> Result<AppCategoryRuleImport, CommandError> {
+    let command_name = "app_categories::import_app_category_rules";
+    let implementation = "new";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(command = command_name, source = %source, ?policy, "Importing app category rules");
+    let result = pulsearc_infra::config::import_app_category_rules(
+        &app_ctx.app_category_rules,
+        &PathBuf::from(source),
+        policy,
+        Utc::now().timestamp(),
+    )
+    .await;
+    let elapsed = start.elapsed();
+    let success = result.is_ok();
+    let error_label = result.as_ref().err().map(|err| err.to_string());
+
+    log_command_execution(command_name, implementation, elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation,
+            elapsed,
+            success,
+            error_type: error_label.as_deref(),
+        },
+    )
+    .await;
+
+    result.map_err(CommandError::from)
+}
@@ -9,6 +9,12 @@
 //! - `build_my_day` - Build blocks for a specific day from segments
 //! - `accept_proposed_block` - Accept a block and enqueue for SAP sync
 //! - `dismiss_proposed_block` - Reject a proposed block
+//! - `detect_entry_overlaps` - Find double-booked entries before submission
+//! - `detect_untracked_gaps` - Find untracked time in a workday
+//! - `regenerate_blocks_now` - Run block generation immediately
+//! - `explain_classification` - Explain why a block was classified as it was
+//! - `correct_classification` - Correct a block's classification and learn from
+//!   it
 //!
 //! # Note
 //!
@@ -17,16 +23,23 @@
 //! - Classification is handled separately by ClassificationScheduler
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use chrono::{DateTime, Local, Utc};
-use pulsearc_core::classification::BlockBuilder;
-use pulsearc_domain::types::classification::ProposedBlock;
-use pulsearc_domain::types::{OutboxStatus, TimeEntryOutbox};
-use pulsearc_domain::{PulseArcError, Result};
+use pulsearc_core::classification::{
+    detect_gaps, detect_overlaps, explain_classification as explain_block_classification,
+    label_gaps, BlockBuilder,
+};
+use pulsearc_domain::types::classification::{
+    ClassificationCorrection, ClassificationExplanation, ProposedBlock,
+};
+use pulsearc_domain::{Gap, OverlapConflict, PulseArcError, Result, TimeEntry, TimeRange};
 use tauri::{Emitter, State};
 use tracing::{info, warn};
 
-use crate::adapters::blocks::{block_to_time_entry_dto, generate_idempotency_key};
+use super::calendar::connected_calendar_events;
+use super::user_profile::current_workday_schedule;
+use crate::adapters::blocks::OutboxBlockSyncQueue;
 use crate::context::AppContext;
 
 // ============================================================================
@@ -52,6 +65,7 @@ use crate::context::AppContext;
 ///   separate)
 /// - Idempotent: Returns existing blocks if already built for the day
 /// - Uses BlockBuilder from core for business logic
+/// - Flags weekend/after-hours blocks using the user's workday settings
 #[tauri::command]
 pub async fn build_my_day(
     ctx: State<'_, Arc<AppContext>>,
@@ -110,7 +124,8 @@ pub async fn build_my_day(
 
     // Build blocks using BlockBuilder
     let config = app_ctx.block_repository.get_block_config().await?;
-    let builder = BlockBuilder::new(config)?;
+    let workday = current_workday_schedule(&app_ctx).await;
+    let builder = BlockBuilder::new(config)?.with_workday(workday);
     let mut blocks = builder.build_daily_blocks_from_segments(&segments, target_day)?;
 
     info!(count = blocks.len(), "Built {} blocks from segments", blocks.len());
@@ -168,59 +183,22 @@ pub async fn accept_proposed_block(
         .await?
         .ok_or_else(|| PulseArcError::InvalidInput(format!("Block {} not found", block_id)))?;
 
-    // Get current user profile (single-user system assumption)
-    let user_profile = app_ctx.user_profile.get_current_profile().await?.ok_or_else(|| {
-        PulseArcError::InvalidInput("No user profile found. Please log in.".into())
-    })?;
-
-    let user_id = user_profile.auth0_id;
-    let org_id = user_profile.org_id;
-
-    // Convert block to time entry DTO for SAP
-    let dto = block_to_time_entry_dto(&block, &user_id, &org_id)
-        .map_err(|e| PulseArcError::Internal(format!("Failed to convert block to DTO: {}", e)))?;
-
-    // Generate idempotency key
-    let idempotency_key = generate_idempotency_key(&block.id, &user_id, block.start_ts);
-
-    let now = Utc::now().timestamp();
-
-    // Create outbox entry
-    let outbox_entry = TimeEntryOutbox {
-        id: uuid::Uuid::now_v7().to_string(),
-        idempotency_key: idempotency_key.clone(),
-        user_id: user_id.clone(),
-        payload_json: serde_json::to_string(&dto)
-            .map_err(|e| PulseArcError::Internal(format!("Failed to serialize payload: {}", e)))?,
-        backend_cuid: None,
-        status: OutboxStatus::Pending,
-        attempts: 0,
-        last_error: None,
-        retry_after: None,
-        created_at: now,
-        sent_at: None,
-        correlation_id: None,
-        local_status: None,
-        remote_status: None,
-        sap_entry_id: None,
-        next_attempt_at: None,
-        error_code: None,
-        last_forwarded_at: None,
-        wbs_code: block.inferred_wbs_code.clone(),
-        target: "sap".to_string(),
-        description: None,
-        auto_applied: false,
-        version: 1,
-        last_modified_by: user_id.clone(),
-        last_modified_at: Some(now),
-    };
-
     // Enqueue for sync
-    app_ctx.outbox_queue.enqueue(&outbox_entry).await?;
+    let idempotency_key = OutboxBlockSyncQueue::new(
+        Arc::clone(&app_ctx.user_profile),
+        Arc::clone(&app_ctx.outbox_queue),
+    )
+    .enqueue(&block)
+    .await?;
 
     // Mark block as approved
     app_ctx.block_repository.approve_block(&block_id, Utc::now()).await?;
 
+    // Rank the accepted project higher in project code autocomplete
+    if let Some(project_id) = block.inferred_project_id.as_deref() {
+        app_ctx.project_autocomplete.write().await.record_usage(project_id);
+    }
+
     info!(
         block_id = %block_id,
         idempotency_key = %idempotency_key,
@@ -257,6 +235,8 @@ pub async fn accept_proposed_block(
 ///
 /// - Uses block_repository.reject_block() instead of raw SQL
 /// - Simple rejection, no outbox entry created
+/// - Records the dismissal so repeatedly dismissed app/context signatures stop
+///   being suggested
 #[tauri::command]
 pub async fn dismiss_proposed_block(
     ctx: State<'_, Arc<AppContext>>,
@@ -267,16 +247,229 @@ pub async fn dismiss_proposed_block(
     info!(block_id = %block_id, "Dismissing proposed block");
 
     // Verify block exists
-    let _block = app_ctx
+    let block = app_ctx
         .block_repository
         .get_proposed_block(&block_id)
         .await?
         .ok_or_else(|| PulseArcError::InvalidInput(format!("Block {} not found", block_id)))?;
 
     // Reject the block
-    app_ctx.block_repository.reject_block(&block_id, Utc::now()).await?;
+    let now = Utc::now();
+    app_ctx.block_repository.reject_block(&block_id, now).await?;
+
+    // Learn from the dismissal; failing to record must not fail the dismissal
+    if let Err(err) = app_ctx.suggestion_suppressor.record_dismissal(&block, now.timestamp()).await
+    {
+        warn!(block_id = %block_id, error = %err, "Failed to record suggestion dismissal");
+    }
 
     info!(block_id = %block_id, "Block dismissed successfully");
 
     Ok(format!("Block {} dismissed", block_id))
 }
+
+// ============================================================================
+// Command: detect_entry_overlaps
+// ============================================================================
+
+/// Find time entries that overlap each other (double-booking)
+///
+/// Used while reviewing entries before submission. Entries are compared as
+/// half-open `[start, end)` intervals, so back-to-back entries do not
+/// conflict.
+///
+/// # Arguments
+///
+/// * `entries` - Entries under review
+///
+/// # Returns
+///
+/// One conflict per overlapping pair, with the overlapping span
+#[tauri::command]
+pub async fn detect_entry_overlaps(entries: Vec<TimeEntry>) -> Result<Vec<OverlapConflict>> {
+    let conflicts = detect_overlaps(&entries);
+
+    info!(entries = entries.len(), conflicts = conflicts.len(), "Checked entries for overlaps");
+
+    Ok(conflicts)
+}
+
+// ============================================================================
+// Command: detect_untracked_gaps
+// ============================================================================
+
+/// Find untracked time in a workday
+///
+/// # Arguments
+///
+/// * `ctx` - Application context
+/// * `entries` - Entries recorded for the day
+/// * `workday` - Working hours to check (Unix epoch seconds); defaults to the
+///   user's configured workday on the local day containing `day_epoch`
+/// * `day_epoch` - Any moment in the day to check (used when `workday` is
+///   omitted; defaults to now)
+/// * `min_gap_secs` - Only report gaps longer than this
+///
+/// # Returns
+///
+/// Gaps in chronological order. When a calendar is connected, each gap is
+/// labelled with the meeting that overlaps it most; a calendar lookup failure
+/// only drops the labels.
+#[tauri::command]
+pub async fn detect_untracked_gaps(
+    ctx: State<'_, Arc<AppContext>>,
+    entries: Vec<TimeEntry>,
+    workday: Option<TimeRange>,
+    day_epoch: Option<i64>,
+    min_gap_secs: i64,
+) -> Result<Vec<Gap>> {
+    let app_ctx = Arc::clone(&ctx);
+    let workday = match workday {
+        Some(workday) => workday,
+        None => {
+            let day = match day_epoch {
+                Some(epoch) => DateTime::from_timestamp(epoch, 0).ok_or_else(|| {
+                    PulseArcError::InvalidInput(format!("Invalid day_epoch: {}", epoch))
+                })?,
+                None => Utc::now(),
+            };
+            let schedule = current_workday_schedule(&app_ctx).await;
+            schedule.workday_range(schedule.local_date(day))
+        }
+    };
+
+    if workday.end_ts < workday.start_ts {
+        return Err(PulseArcError::InvalidInput("workday ends before it starts".to_string()));
+    }
+    let min_gap = u64::try_from(min_gap_secs).map(Duration::from_secs).map_err(|_| {
+        PulseArcError::InvalidInput("min_gap_secs must not be negative".to_string())
+    })?;
+
+    let mut gaps = detect_gaps(&entries, workday, min_gap);
+
+    if !gaps.is_empty() {
+        match connected_calendar_events(&app_ctx, workday.start_ts, workday.end_ts).await {
+            Ok(events) => label_gaps(&mut gaps, &events),
+            Err(err) => warn!(error = %err, "Failed to load calendar events for gap labels"),
+        }
+    }
+
+    info!(entries = entries.len(), gaps = gaps.len(), "Checked workday for untracked gaps");
+
+    Ok(gaps)
+}
+
+// ============================================================================
+// Command: regenerate_blocks_now
+// ============================================================================
+
+/// Run block generation now instead of waiting for the next scheduled run
+///
+/// If a run is already in progress (scheduled or from an earlier click), this
+/// waits for that run rather than starting another one.
+///
+/// # Arguments
+///
+/// * `ctx` - Application context with the block scheduler
+///
+/// # Returns
+///
+/// Ok once the run completes; an error if it fails or exceeds the scheduler's
+/// job timeout
+#[tauri::command]
+pub async fn regenerate_blocks_now(ctx: State<'_, Arc<AppContext>>) -> Result<()> {
+    let app_ctx = Arc::clone(&ctx);
+
+    app_ctx.block_scheduler.trigger_now().await.map_err(|err| {
+        warn!(error = %err, "On-demand block generation failed");
+        PulseArcError::from(err)
+    })?;
+
+    info!("On-demand block generation finished");
+    Ok(())
+}
+
+// ============================================================================
+// Command: explain_classification
+// ============================================================================
+
+/// Explain why a block was classified the way it was
+///
+/// Lists the evidence behind the project/WBS assignment with what each piece
+/// added to the confidence score, the calendar overlap, and the model's
+/// rationale when a language model classified the block.
+///
+/// # Arguments
+///
+/// * `ctx` - Application context with the block repository
+/// * `block_id` - Block to explain
+///
+/// # Returns
+///
+/// The explanation, or `NotFound` if the block does not exist
+#[tauri::command]
+pub async fn explain_classification(
+    ctx: State<'_, Arc<AppContext>>,
+    block_id: String,
+) -> Result<ClassificationExplanation> {
+    let app_ctx = Arc::clone(&ctx);
+
+    let explanation =
+        explain_block_classification(app_ctx.block_repository.as_ref(), &block_id).await?;
+
+    info!(
+        block_id = %block_id,
+        evidence = explanation.evidence.len(),
+        "Explained block classification"
+    );
+    Ok(explanation)
+}
+
+// ============================================================================
+// Command: correct_classification
+// ============================================================================
+
+/// Correct the project and/or billable flag of a block
+///
+/// The corrected block is marked `edited` and queued for SAP sync. A project
+/// change is also learned: similar activity is matched to the corrected
+/// project, and the original suggestion counts as dismissed.
+///
+/// # Arguments
+///
+/// * `ctx` - Application context with the classification corrector
+/// * `block_id` - Block to correct
+/// * `correction` - Fields to change; omitted fields are kept
+///
+/// # Returns
+///
+/// The corrected block, `NotFound` if the block does not exist, or
+/// `InvalidInput` if the correction changes nothing
+#[tauri::command]
+pub async fn correct_classification(
+    ctx: State<'_, Arc<AppContext>>,
+    app: tauri::AppHandle,
+    block_id: String,
+    correction: ClassificationCorrection,
+) -> Result<ProposedBlock> {
+    let app_ctx = Arc::clone(&ctx);
+
+    info!(block_id = %block_id, "Correcting block classification");
+
+    let corrected =
+        app_ctx.classification_corrector.correct_classification(&block_id, &correction).await?;
+
+    if let Some(project_id) = corrected.inferred_project_id.as_deref() {
+        app_ctx.project_autocomplete.write().await.record_usage(project_id);
+    }
+
+    if let Err(err) = app.emit("outbox-updated", ()) {
+        warn!(
+            block_id = %block_id,
+            error = %err,
+            "failed to emit outbox-updated event after classification correction"
+        );
+    }
+
+    Ok(corrected)
+}
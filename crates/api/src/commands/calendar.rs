@@ -9,12 +9,17 @@ use std::time::Duration;
 use std::time::Instant;
 
 #[cfg(feature = "calendar")]
-use chrono::{DateTime, Local, TimeZone, Utc};
-use pulsearc_domain::Result;
+use chrono::{DateTime, Local, Utc};
+use pulsearc_core::tracking::get_timeline as build_timeline;
 #[cfg(feature = "calendar")]
-use pulsearc_domain::{CalendarEventParams, CalendarEventRow, PulseArcError};
+use pulsearc_core::tracking::timeline_calendar_event;
+#[cfg(feature = "calendar")]
+use pulsearc_core::user::set_setting;
 #[cfg(feature = "calendar")]
-use pulsearc_infra::integrations::calendar::TimelineCalendarEvent;
+use pulsearc_domain::{CalendarEventParams, CalendarEventRow, PulseArcError};
+use pulsearc_domain::{
+    CalendarSyncSettings, Result, TimeRange, TimelineCalendarEvent, TimelineItem,
+};
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "calendar")]
 use tauri::Emitter;
@@ -24,26 +29,10 @@ use tracing::info;
 #[cfg(feature = "calendar")]
 use tracing::{error, info, warn};
 
+use crate::utils::correlation::with_correlation_id;
 use crate::utils::logging::{log_command_execution, record_command_metric, MetricRecord};
 use crate::AppContext;
 
-// Stub type for when calendar feature is disabled
-#[cfg(not(feature = "calendar"))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct TimelineCalendarEvent {
-    pub id: String,
-    pub project: String,
-    pub task: String,
-    pub start_time: String,
-    pub start_epoch: i64,
-    pub duration: i64,
-    pub status: String,
-    pub is_calendar_event: bool,
-    pub is_all_day: bool,
-    pub original_summary: String,
-}
-
 /// Calendar connection status
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -55,21 +44,6 @@ pub struct CalendarConnectionStatus {
     pub sync_enabled: bool,
 }
 
-/// Calendar sync settings
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct CalendarSyncSettings {
-    pub enabled: bool,
-    pub sync_interval_minutes: u32,
-    pub include_all_day_events: bool,
-    pub min_event_duration_minutes: u32,
-    pub lookback_hours: u32,
-    pub lookahead_hours: u32,
-    pub excluded_calendar_ids: Vec<String>,
-    pub sync_token: Option<String>,
-    pub last_sync_epoch: Option<i64>,
-}
-
 /// Initiate Google Calendar OAuth flow
 ///
 /// Phase 4B.2: New implementation using CalendarOAuthManager
@@ -209,39 +183,42 @@ pub async fn sync_calendar_events(
     ctx: State<'_, Arc<AppContext>>,
     email: String,
 ) -> std::result::Result<usize, String> {
-    let command_name = "calendar::sync_calendar_events";
-    let start = Instant::now();
-    let app_ctx = Arc::clone(ctx.inner());
-
-    info!(command = command_name, email, "Syncing calendar events");
-
-    // Check feature flag
-    let use_new =
-        ctx.feature_flags.is_enabled("new_calendar_commands", true).await.unwrap_or(false);
-
-    let result = if use_new {
-        new_sync_calendar_events(Arc::clone(ctx.inner()), email).await
-    } else {
-        Err("Legacy calendar commands not available in new crate".to_string())
-    };
-
-    let elapsed = start.elapsed();
-    let success = result.is_ok();
-
-    log_command_execution(command_name, "new", elapsed, success);
-    record_command_metric(
-        &app_ctx,
-        MetricRecord {
-            command: command_name,
-            implementation: "new",
-            elapsed,
-            success,
-            error_type: if !success { Some("sync_failed") } else { None },
-        },
-    )
-    .await;
+    with_correlation_id("calendar::sync_calendar_events", async move {
+        let command_name = "calendar::sync_calendar_events";
+        let start = Instant::now();
+        let app_ctx = Arc::clone(ctx.inner());
+
+        info!(command = command_name, email, "Syncing calendar events");
+
+        // Check feature flag
+        let use_new =
+            ctx.feature_flags.is_enabled("new_calendar_commands", true).await.unwrap_or(false);
+
+        let result = if use_new {
+            new_sync_calendar_events(Arc::clone(ctx.inner()), email).await
+        } else {
+            Err("Legacy calendar commands not available in new crate".to_string())
+        };
+
+        let elapsed = start.elapsed();
+        let success = result.is_ok();
+
+        log_command_execution(command_name, "new", elapsed, success);
+        record_command_metric(
+            &app_ctx,
+            MetricRecord {
+                command: command_name,
+                implementation: "new",
+                elapsed,
+                success,
+                error_type: if !success { Some("sync_failed") } else { None },
+            },
+        )
+        .await;
 
-    result
+        result
+    })
+    .await
 }
 
 #[cfg(feature = "calendar")]
@@ -491,14 +468,88 @@ pub async fn get_calendar_events_for_timeline(
     result
 }
 
+/// Get the merged timeline of calendar events and tracked activity
+///
+/// Returns calendar events from every connected provider, proposed blocks
+/// and activity segments not yet in a block, ordered by start time and
+/// tagged by `kind`. Times are rendered in local time.
+#[tauri::command]
+pub async fn get_timeline(
+    ctx: State<'_, Arc<AppContext>>,
+    start_date: i64,
+    end_date: i64,
+) -> Result<Vec<TimelineItem>> {
+    let command_name = "calendar::get_timeline";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(command = command_name, start_date, end_date, "Building merged timeline");
+
+    let result = async {
+        let calendar_events = connected_calendar_events(&app_ctx, start_date, end_date).await?;
+        build_timeline(
+            app_ctx.block_repository.as_ref(),
+            app_ctx.segment_repository.as_ref(),
+            calendar_events,
+            TimeRange { start_ts: start_date, end_ts: end_date, is_all_day: false },
+            &chrono::Local,
+        )
+        .await
+    }
+    .await;
+
+    let elapsed = start.elapsed();
+    let success = result.is_ok();
+
+    log_command_execution(command_name, "new", elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation: "new",
+            elapsed,
+            success,
+            error_type: if !success { Some("query_failed") } else { None },
+        },
+    )
+    .await;
+
+    result
+}
+
 #[cfg(feature = "calendar")]
 async fn new_get_calendar_events_for_timeline(
     ctx: Arc<AppContext>,
     start_date: i64,
     end_date: i64,
 ) -> Result<Vec<TimelineCalendarEvent>> {
-    // 1. Get all connected user emails from calendar_tokens
-    let emails = get_connected_user_emails(&ctx).await?;
+    // 1. Query events for every connected provider, sorted by start_ts
+    let all_events = connected_calendar_events(&ctx, start_date, end_date).await?;
+
+    // 2. Map to timeline format (local time)
+    Ok(all_events.iter().map(|e| timeline_calendar_event(e, &Local)).collect())
+}
+
+#[cfg(not(feature = "calendar"))]
+async fn new_get_calendar_events_for_timeline(
+    _ctx: Arc<AppContext>,
+    _start_date: i64,
+    _end_date: i64,
+) -> Result<Vec<TimelineCalendarEvent>> {
+    Ok(vec![])
+}
+
+/// Calendar events from every connected provider within a time range
+///
+/// Events are merged across providers and sorted by start time. Returns an
+/// empty list when no calendar is connected.
+#[cfg(feature = "calendar")]
+pub(crate) async fn connected_calendar_events(
+    ctx: &Arc<AppContext>,
+    start_ts: i64,
+    end_ts: i64,
+) -> Result<Vec<CalendarEventRow>> {
+    let emails = get_connected_user_emails(ctx).await?;
 
     if emails.is_empty() {
         warn!("No calendar connected, returning empty array");
@@ -507,52 +558,23 @@ async fn new_get_calendar_events_for_timeline(
 
     info!("Querying calendar events for {} connected provider(s)", emails.len());
 
-    // 2. Query events for each email and merge
     let mut all_events: Vec<CalendarEventRow> = Vec::new();
     for email in emails {
-        let events = ctx
-            .calendar_events
-            .get_calendar_events_by_time_range(&email, start_date, end_date)
-            .await?;
+        let events =
+            ctx.calendar_events.get_calendar_events_by_time_range(&email, start_ts, end_ts).await?;
         all_events.extend(events);
     }
 
-    // 3. Sort by start_ts
     all_events.sort_by_key(|e| e.start_ts);
-
-    // 4. Map to timeline format
-    Ok(all_events
-        .into_iter()
-        .map(|e| {
-            // Format start_time as human-readable string (local time)
-            let start_time_formatted = Local
-                .timestamp_opt(e.start_ts, 0)
-                .single()
-                .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
-                .unwrap_or_else(|| "Unknown".to_string());
-
-            TimelineCalendarEvent {
-                id: e.id,
-                project: e.parsed_project.unwrap_or_default(),
-                task: e.parsed_task.unwrap_or_default(),
-                start_time: start_time_formatted,
-                start_epoch: e.start_ts,
-                duration: e.end_ts - e.start_ts,
-                status: "active".to_string(), // Calendar events are always active
-                is_calendar_event: true,      // This is a calendar event
-                is_all_day: e.is_all_day,
-                original_summary: e.summary,
-            }
-        })
-        .collect())
+    Ok(all_events)
 }
 
 #[cfg(not(feature = "calendar"))]
-async fn new_get_calendar_events_for_timeline(
-    _ctx: Arc<AppContext>,
-    _start_date: i64,
-    _end_date: i64,
-) -> Result<Vec<TimelineCalendarEvent>> {
+pub(crate) async fn connected_calendar_events(
+    _ctx: &Arc<AppContext>,
+    _start_ts: i64,
+    _end_ts: i64,
+) -> Result<Vec<pulsearc_domain::CalendarEventRow>> {
     Ok(vec![])
 }
 
@@ -949,6 +971,7 @@ async fn new_update_calendar_sync_settings(
 ) -> std::result::Result<(), String> {
     let db = ctx.db.clone();
     let email_clone = email.to_string();
+    let recorded = settings.clone();
 
     tokio::task::spawn_blocking(move || -> Result<()> {
         let conn = db.get_connection()?;
@@ -1000,6 +1023,10 @@ async fn new_update_calendar_sync_settings(
     .map_err(|e| format!("Task join error: {}", e))?
     .map_err(|e| format!("{}", e))?;
 
+    set_setting(ctx.user_settings.as_ref(), email, &recorded)
+        .await
+        .map_err(|e| format!("Failed to record settings change: {}", e))?;
+
     info!(email, "Calendar sync settings updated successfully");
     Ok(())
 }
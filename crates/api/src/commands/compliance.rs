@@ -0,0 +1,46 @@
+//! MDM compliance events
+//!
+//! Forwards status changes from the infra `ComplianceScheduler` to the
+//! frontend so a non-compliant device is flagged in the UI, and enforcement
+//! changes from `PolicyEnforcer` so mandatory notices are shown.
+
+use pulsearc_infra::mdm::{ComplianceStatus, EnforcementState};
+use tauri::{AppHandle, Emitter, Runtime};
+use tokio::sync::watch;
+use tracing::warn;
+
+/// Event emitted when MDM compliance status changes
+pub const MDM_COMPLIANCE_EVENT: &str = "mdm-compliance-changed";
+
+/// Event carrying the new [`EnforcementState`] when MDM enforcement changes
+pub const MDM_ENFORCEMENT_EVENT: &str = "mdm-enforcement-changed";
+
+/// Emit [`MDM_COMPLIANCE_EVENT`] for every status change on `changes`
+///
+/// Runs until the sending `ComplianceScheduler` is dropped.
+pub async fn emit_compliance_status_changes<R: Runtime>(
+    app: AppHandle<R>,
+    mut changes: watch::Receiver<Option<ComplianceStatus>>,
+) {
+    while changes.changed().await.is_ok() {
+        let Some(status) = changes.borrow_and_update().clone() else { continue };
+        if let Err(err) = app.emit(MDM_COMPLIANCE_EVENT, &status) {
+            warn!(compliant = status.compliant, error = %err, "failed to emit MDM compliance event");
+        }
+    }
+}
+
+/// Emit [`MDM_ENFORCEMENT_EVENT`] for every change on `changes`
+///
+/// Runs until the sending `PolicyEnforcer` is dropped.
+pub async fn emit_enforcement_changes<R: Runtime>(
+    app: AppHandle<R>,
+    mut changes: watch::Receiver<EnforcementState>,
+) {
+    while changes.changed().await.is_ok() {
+        let state = changes.borrow_and_update().clone();
+        if let Err(err) = app.emit(MDM_ENFORCEMENT_EVENT, &state) {
+            warn!(sync_blocked = state.sync_blocked, error = %err, "failed to emit MDM enforcement event");
+        }
+    }
+}
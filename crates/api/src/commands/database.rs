@@ -8,9 +8,9 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use chrono::{Duration, Utc};
-use pulsearc_domain::types::database::ActivitySnapshot;
+use pulsearc_domain::types::database::{ActivitySnapshot, Page, PageRequest, SnapshotFilter};
 use pulsearc_domain::types::stats::{BatchStats, DatabaseStats};
-use pulsearc_domain::types::HealthStatus;
+use pulsearc_domain::types::{FragmentationReport, HealthStatus, IntegrityReport};
 use pulsearc_domain::{PulseArcError, Result as DomainResult};
 use rusqlite::types::ToSql;
 use tauri::State;
@@ -18,6 +18,7 @@ use tracing::info;
 
 use crate::adapters::database_stats::build_database_stats;
 use crate::context::AppContext;
+use crate::utils::command_error::CommandError;
 use crate::utils::logging::{log_command_execution, record_command_metric, MetricRecord};
 
 // =============================================================================
@@ -192,7 +193,7 @@ async fn new_get_recent_snapshots(
             .prepare(
                 "SELECT id, timestamp, activity_context_json, detected_activity, work_type,
                     activity_category, primary_app, processed, batch_id, created_at,
-                    processed_at, is_idle, idle_duration_secs
+                    processed_at, is_idle, idle_duration_secs, content_hash
              FROM activity_snapshots
              WHERE processed = 0 AND timestamp >= ?1 AND timestamp < ?2
              ORDER BY timestamp DESC
@@ -221,6 +222,7 @@ async fn new_get_recent_snapshots(
                     processed_at: row.get(10)?,
                     is_idle: row.get(11)?,
                     idle_duration_secs: row.get(12)?,
+                    content_hash: row.get(13)?,
                 })
             })
             .map_err(|e| PulseArcError::Database(format!("Failed to query snapshots: {}", e)))?;
@@ -249,7 +251,7 @@ async fn legacy_get_recent_snapshots(
             .prepare(
                 "SELECT id, timestamp, activity_context_json, detected_activity, work_type,
                     activity_category, primary_app, processed, batch_id, created_at,
-                    processed_at, is_idle, idle_duration_secs
+                    processed_at, is_idle, idle_duration_secs, content_hash
              FROM activity_snapshots
              WHERE processed = 0
              ORDER BY timestamp DESC
@@ -274,6 +276,7 @@ async fn legacy_get_recent_snapshots(
                     processed_at: row.get(10)?,
                     is_idle: row.get(11)?,
                     idle_duration_secs: row.get(12)?,
+                    content_hash: row.get(13)?,
                 })
             })
             .map_err(|e| PulseArcError::Database(format!("Failed to query snapshots: {}", e)))?;
@@ -342,7 +345,7 @@ async fn legacy_vacuum_database(ctx: &AppContext) -> DomainResult<()> {
 
     tokio::task::spawn_blocking(move || {
         let conn = db
-            .get_connection()
+            .get_maintenance_connection()
             .map_err(|e| PulseArcError::Database(format!("Failed to get connection: {}", e)))?;
 
         conn.execute("VACUUM", [])
@@ -457,6 +460,142 @@ pub async fn clear_snapshots(
     result.map_err(|e| e.to_string())
 }
 
+// =============================================================================
+// Command 6: browse_snapshots
+// =============================================================================
+
+/// Browse activity snapshots page by page.
+///
+/// Filters by app name substring, bundle id and `[start_ts, end_ts)`; results
+/// are ordered by timestamp then id so pages stay stable while browsing. The
+/// page size is clamped to `PageRequest::MAX_PAGE_SIZE`.
+#[tauri::command]
+pub async fn browse_snapshots(
+    ctx: State<'_, Arc<AppContext>>,
+    filter: SnapshotFilter,
+    page: PageRequest,
+) -> Result<Page<ActivitySnapshot>, CommandError> {
+    let command_name = "database::browse_snapshots";
+    let implementation = "new";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(
+        command = command_name,
+        page = page.page,
+        page_size = page.page_size,
+        "Executing browse_snapshots"
+    );
+
+    let snapshots = Arc::clone(&app_ctx.snapshots);
+    let result = match tokio::task::spawn_blocking(move || {
+        snapshots.browse_snapshots(&filter, page)
+    })
+    .await
+    {
+        Ok(result) => result.map_err(CommandError::from),
+        Err(e) => Err(CommandError::from(PulseArcError::Internal(format!(
+            "spawn_blocking failed: {}",
+            e
+        )))),
+    };
+
+    let elapsed = start.elapsed();
+    let success = result.is_ok();
+    log_command_execution(command_name, implementation, elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation,
+            elapsed,
+            success,
+            error_type: result.as_ref().err().map(|e| e.code),
+        },
+    )
+    .await;
+
+    result
+}
+
+// =============================================================================
+// Command 7: get_fragmentation_report
+// =============================================================================
+
+/// Report free pages and fragmentation with a vacuum recommendation.
+///
+/// The vacuum scheduler acts on the same report, running an incremental
+/// vacuum once the fragmentation ratio reaches its threshold.
+#[tauri::command]
+pub async fn get_fragmentation_report(
+    ctx: State<'_, Arc<AppContext>>,
+) -> Result<FragmentationReport, CommandError> {
+    let command_name = "database::get_fragmentation_report";
+    let implementation = "new";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(command = command_name, "Executing get_fragmentation_report");
+
+    let result = app_ctx.database_stats.fragmentation_report().await.map_err(CommandError::from);
+
+    let elapsed = start.elapsed();
+    let success = result.is_ok();
+    log_command_execution(command_name, implementation, elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation,
+            elapsed,
+            success,
+            error_type: result.as_ref().err().map(|e| e.code),
+        },
+    )
+    .await;
+
+    result
+}
+
+// =============================================================================
+// Command 8: check_database_integrity
+// =============================================================================
+
+/// Run `PRAGMA integrity_check` and `PRAGMA foreign_key_check`.
+///
+/// Reads the whole database, so the frontend should only call it on demand
+/// (e.g. when corruption is suspected), not on a timer.
+#[tauri::command]
+pub async fn check_database_integrity(
+    ctx: State<'_, Arc<AppContext>>,
+) -> Result<IntegrityReport, CommandError> {
+    let command_name = "database::check_database_integrity";
+    let implementation = "new";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(command = command_name, "Executing check_database_integrity");
+
+    let result = app_ctx.database_stats.integrity_check().await.map_err(CommandError::from);
+
+    let elapsed = start.elapsed();
+    let success = result.is_ok();
+    log_command_execution(command_name, implementation, elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation,
+            elapsed,
+            success,
+            error_type: result.as_ref().err().map(|e| e.code),
+        },
+    )
+    .await;
+
+    result
+}
+
 #[allow(dead_code)] // Will be removed in Phase 5
 async fn legacy_get_database_health(ctx: &AppContext) -> DomainResult<HealthStatus> {
     let db = ctx.db.clone();
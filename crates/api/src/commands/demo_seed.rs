@@ -0,0 +1,171 @@
+//! Demo data seeding commands for QA builds (`demo-seed` feature)
+//!
+//! Unlike `seed_activity_snapshots`, these commands are available in release
+//! builds compiled with `--features demo-seed`, so QA can populate a build
+//! with a deterministic dataset and remove it again. Every seeded row is
+//! labeled as test data (see `pulsearc_core::demo_seed`).
+//!
+//! # Example Usage
+//!
+//! ```javascript
+//! await invoke('seed_demo_data', { startDate: '2025-03-03', endDate: '2025-03-07' });
+//! await invoke('clear_seeded_data');
+//! ```
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use chrono::NaiveDate;
+use pulsearc_core::demo_seed::{DemoSeedConfig, DemoSeedService, DemoSeedSummary};
+use pulsearc_domain::{PulseArcError, Result as DomainResult};
+use pulsearc_infra::database::SqlCipherDemoSeedRepository;
+use tauri::State;
+use tracing::info;
+
+use crate::context::AppContext;
+use crate::utils::logging::{log_command_execution, record_command_metric, MetricRecord};
+
+/// Seed demo snapshots, segments and blocks for `start_date..=end_date`
+///
+/// Dates use `YYYY-MM-DD`. Weekends are skipped unless `include_weekends` is
+/// set. Seeding the same range again overwrites the previous rows.
+#[tauri::command]
+pub async fn seed_demo_data(
+    ctx: State<'_, Arc<AppContext>>,
+    start_date: String,
+    end_date: String,
+    blocks_per_day: Option<usize>,
+    include_weekends: Option<bool>,
+) -> Result<DemoSeedSummary, String> {
+    let command_name = "demo_seed::seed_demo_data";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(command = command_name, %start_date, %end_date, "Seeding demo data");
+
+    let result = seed_demo_data_impl(
+        &app_ctx,
+        &start_date,
+        &end_date,
+        blocks_per_day,
+        include_weekends.unwrap_or(false),
+    )
+    .await;
+
+    let elapsed = start.elapsed();
+    let success = result.is_ok();
+
+    log_command_execution(command_name, "new", elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation: "new",
+            elapsed,
+            success,
+            error_type: if !success { Some("seed_failed") } else { None },
+        },
+    )
+    .await;
+
+    result.map_err(|e| e.to_string())
+}
+
+/// Remove every row created by `seed_demo_data`
+#[tauri::command]
+pub async fn clear_seeded_data(ctx: State<'_, Arc<AppContext>>) -> Result<DemoSeedSummary, String> {
+    let command_name = "demo_seed::clear_seeded_data";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(command = command_name, "Clearing seeded demo data");
+
+    let result = demo_seed_service(&app_ctx).clear().await;
+
+    let elapsed = start.elapsed();
+    let success = result.is_ok();
+
+    log_command_execution(command_name, "new", elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation: "new",
+            elapsed,
+            success,
+            error_type: if !success { Some("clear_failed") } else { None },
+        },
+    )
+    .await;
+
+    result.map_err(|e| e.to_string())
+}
+
+async fn seed_demo_data_impl(
+    ctx: &AppContext,
+    start_date: &str,
+    end_date: &str,
+    blocks_per_day: Option<usize>,
+    include_weekends: bool,
+) -> DomainResult<DemoSeedSummary> {
+    let mut config = DemoSeedConfig::new(parse_date(start_date)?, parse_date(end_date)?);
+    if let Some(blocks_per_day) = blocks_per_day {
+        config.blocks_per_day = blocks_per_day;
+    }
+    config.include_weekends = include_weekends;
+
+    demo_seed_service(ctx).seed(&config).await
+}
+
+fn demo_seed_service(ctx: &AppContext) -> DemoSeedService {
+    DemoSeedService::new(
+        ctx.snapshots.clone(),
+        ctx.segment_repository.clone(),
+        ctx.block_repository.clone(),
+        Arc::new(SqlCipherDemoSeedRepository::new(Arc::clone(&ctx.db))),
+    )
+}
+
+fn parse_date(date: &str) -> DomainResult<NaiveDate> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|e| {
+        PulseArcError::InvalidInput(format!(
+            "Invalid date format '{date}': {e}. Expected YYYY-MM-DD"
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pulsearc_core::classification::ports::BlockRepository;
+
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_seed_then_clear_leaves_blocks_unchanged() {
+        let (ctx, _temp_dir) =
+            AppContext::new_for_test().await.expect("failed to create AppContext");
+        let date = NaiveDate::from_ymd_opt(2025, 3, 3).expect("valid date");
+
+        let seeded = seed_demo_data_impl(&ctx, "2025-03-03", "2025-03-03", Some(2), false)
+            .await
+            .expect("seed demo data");
+        assert_eq!(seeded.blocks, 2);
+        assert_eq!(ctx.block_repository.get_proposed_blocks(date).await.expect("blocks").len(), 2);
+
+        let cleared = demo_seed_service(&ctx).clear().await.expect("clear demo data");
+
+        assert_eq!(cleared, seeded);
+        assert!(ctx.block_repository.get_proposed_blocks(date).await.expect("blocks").is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_seed_rejects_bad_dates() {
+        let (ctx, _temp_dir) =
+            AppContext::new_for_test().await.expect("failed to create AppContext");
+
+        let err = seed_demo_data_impl(&ctx, "03/03/2025", "2025-03-03", None, false)
+            .await
+            .expect_err("invalid date");
+        assert!(matches!(err, PulseArcError::InvalidInput(_)));
+    }
+}
@@ -0,0 +1,95 @@
+//! Diagnostics commands for support
+
+use std::sync::Arc;
+
+use pulsearc_common::observability::RecordedError;
+use pulsearc_domain::CostRateConfig;
+use tauri::State;
+
+use crate::context::AppContext;
+
+/// Get the most recent errors, oldest first
+///
+/// Returns up to `RecentErrors::DEFAULT_CAPACITY` entries without clearing
+/// them, so support can inspect failures without enabling verbose logging.
+///
+/// # Example Response
+/// ```json
+/// [
+///   {
+///     "recorded_at": "2025-01-01T09:00:00Z",
+///     "error_type": "database",
+///     "message": "Database error: database is locked",
+///     "fields": {
+///       "command": "projects::get_user_projects",
+///       "error_type": "database",
+///       "implementation": "new"
+///     }
+///   }
+/// ]
+/// ```
+#[tauri::command]
+pub async fn get_recent_errors(
+    ctx: State<'_, Arc<AppContext>>,
+) -> Result<Vec<RecordedError>, String> {
+    Ok(fetch_recent_errors(&ctx))
+}
+
+fn fetch_recent_errors(context: &AppContext) -> Vec<RecordedError> {
+    context.recent_errors.snapshot()
+}
+
+/// Get the API cost rates loaded from config (`[costs]`)
+///
+/// # Example Response
+/// ```json
+/// {
+///   "max_monthly_cost_usd": 5.0,
+///   "alert_threshold_usd": 4.0,
+///   "default_model": "gpt-4o-mini",
+///   "models": {
+///     "gpt-4o": { "input_per_1m": 2.5, "output_per_1m": 10.0 },
+///     "gpt-4o-mini": { "input_per_1m": 0.15, "output_per_1m": 0.6 }
+///   },
+///   "call_costs": {}
+/// }
+/// ```
+#[tauri::command]
+pub async fn get_cost_rates(ctx: State<'_, Arc<AppContext>>) -> Result<CostRateConfig, String> {
+    Ok(fetch_cost_rates(&ctx))
+}
+
+fn fetch_cost_rates(context: &AppContext) -> CostRateConfig {
+    context.config.costs.clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use pulsearc_common::error::CommonError;
+
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_get_recent_errors_returns_recorded_errors() {
+        let (ctx, _temp_dir) =
+            AppContext::new_for_test().await.expect("failed to create AppContext");
+        assert!(fetch_recent_errors(&ctx).is_empty());
+
+        ctx.recent_errors.record(&CommonError::internal("boom"));
+
+        let errors = fetch_recent_errors(&ctx);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].error_type, "internal");
+        assert_eq!(fetch_recent_errors(&ctx), errors, "reading must not clear the store");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_get_cost_rates_returns_configured_rates() {
+        let (ctx, _temp_dir) =
+            AppContext::new_for_test().await.expect("failed to create AppContext");
+
+        let rates = fetch_cost_rates(&ctx);
+        assert_eq!(rates, CostRateConfig::default());
+        assert!(rates.model_pricing(&rates.default_model).is_some());
+    }
+}
@@ -1,11 +1,14 @@
 //! Feature flags commands for Phase 4 rollback control
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use pulsearc_core::feature_flags_ports::FeatureFlagEvaluation;
 use tauri::State;
 use tracing::info;
 
+use crate::utils::command_error::CommandError;
 use crate::utils::logging::{
     log_command_execution, log_feature_flag_check, record_command_metric, MetricRecord,
 };
@@ -16,17 +19,14 @@ pub async fn is_feature_enabled(
     ctx: State<'_, Arc<AppContext>>,
     flag: String,
     default: bool,
-) -> Result<bool, String> {
+) -> Result<bool, CommandError> {
     let command_name = "feature_flags::is_feature_enabled";
     let implementation = "new";
     let start = Instant::now();
     let app_ctx = Arc::clone(ctx.inner());
 
-    let evaluation = app_ctx
-        .feature_flags
-        .evaluate(&flag, default)
-        .await
-        .map_err(|e| format!("Failed to check feature flag: {e}"));
+    let evaluation =
+        app_ctx.feature_flags.evaluate(&flag, default).await.map_err(CommandError::from);
 
     let elapsed = start.elapsed();
     let success = evaluation.is_ok();
@@ -34,7 +34,12 @@ pub async fn is_feature_enabled(
     log_command_execution(command_name, implementation, elapsed, success);
 
     if let Ok(eval) = &evaluation {
-        log_feature_flag_check(&flag, eval.enabled, eval.fallback_used);
+        log_feature_flag_check(
+            &flag,
+            eval.enabled,
+            eval.fallback_used,
+            eval.unmet_dependency.as_deref(),
+        );
     }
 
     let result = evaluation.map(|eval| eval.enabled);
@@ -49,12 +54,56 @@ pub async fn is_feature_enabled(
     result
 }
 
+/// Evaluate several flags in one call (one cache pass, dependencies applied).
+///
+/// Flags missing from storage come back disabled with `fallback_used` set, so
+/// the frontend can apply its own default.
+#[tauri::command]
+pub async fn evaluate_feature_flags(
+    ctx: State<'_, Arc<AppContext>>,
+    names: Vec<String>,
+) -> Result<HashMap<String, FeatureFlagEvaluation>, CommandError> {
+    let command_name = "feature_flags::evaluate_feature_flags";
+    let implementation = "new";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    let result =
+        app_ctx.feature_flags.evaluate_feature_flags(&names).await.map_err(CommandError::from);
+
+    let elapsed = start.elapsed();
+    let success = result.is_ok();
+
+    log_command_execution(command_name, implementation, elapsed, success);
+
+    if let Ok(evaluations) = &result {
+        for (flag, eval) in evaluations {
+            log_feature_flag_check(
+                flag,
+                eval.enabled,
+                eval.fallback_used,
+                eval.unmet_dependency.as_deref(),
+            );
+        }
+    }
+
+    let error_type = if success { None } else { Some("feature_flag_error") };
+
+    record_command_metric(
+        &app_ctx,
+        MetricRecord { command: command_name, implementation, elapsed, success, error_type },
+    )
+    .await;
+
+    result
+}
+
 #[tauri::command]
 pub async fn toggle_feature_flag(
     ctx: State<'_, Arc<AppContext>>,
     flag: String,
     enabled: bool,
-) -> Result<(), String> {
+) -> Result<(), CommandError> {
     let command_name = "feature_flags::toggle_feature_flag";
     let implementation = "new";
     let start = Instant::now();
@@ -67,11 +116,8 @@ pub async fn toggle_feature_flag(
         "Toggling feature flag"
     );
 
-    let result = app_ctx
-        .feature_flags
-        .set_enabled(&flag, enabled)
-        .await
-        .map_err(|e| format!("Failed to toggle feature flag: {e}"));
+    let result =
+        app_ctx.feature_flags.set_enabled(&flag, enabled).await.map_err(CommandError::from);
 
     let elapsed = start.elapsed();
     let success = result.is_ok();
@@ -90,7 +136,7 @@ pub async fn toggle_feature_flag(
 #[tauri::command]
 pub async fn list_feature_flags(
     ctx: State<'_, Arc<AppContext>>,
-) -> Result<Vec<serde_json::Value>, String> {
+) -> Result<Vec<serde_json::Value>, CommandError> {
     let command_name = "feature_flags::list_feature_flags";
     let implementation = "new";
     let start = Instant::now();
@@ -109,11 +155,12 @@ pub async fn list_feature_flags(
                         "enabled": flag.enabled,
                         "description": flag.description,
                         "updated_at": flag.updated_at,
+                        "requires": flag.requires,
                     })
                 })
                 .collect()
         })
-        .map_err(|e| format!("Failed to list feature flags: {e}"));
+        .map_err(CommandError::from);
 
     let elapsed = start.elapsed();
     let success = result.is_ok();
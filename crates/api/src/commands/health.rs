@@ -28,5 +28,31 @@ use crate::utils::health::HealthStatus;
 /// ```
 #[tauri::command]
 pub async fn get_app_health(context: State<'_, AppContext>) -> Result<HealthStatus, String> {
-    Ok(context.health_check().await)
+    Ok(fetch_app_health(&context).await)
+}
+
+async fn fetch_app_health(context: &AppContext) -> HealthStatus {
+    context.health_check().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_get_app_health_with_test_context() {
+        let (ctx, _temp_dir) =
+            AppContext::new_for_test().await.expect("failed to create AppContext");
+
+        let health = fetch_app_health(&ctx).await;
+
+        assert!(health.is_healthy, "unexpected health status: {health:?}");
+        assert!((health.score - 1.0).abs() < f64::EPSILON);
+        let database = health
+            .components
+            .iter()
+            .find(|component| component.name == "database")
+            .expect("database component");
+        assert!(database.is_healthy);
+    }
 }
@@ -11,8 +11,8 @@
 use std::sync::Arc;
 use std::time::Instant;
 
-use pulsearc_domain::{IdlePeriod, IdleSummary, PulseArcError};
-use serde::{Deserialize, Serialize};
+use pulsearc_core::user::{get_setting, set_setting, USER_SCOPE};
+use pulsearc_domain::{IdlePeriod, IdleSettings, IdleSummary, PulseArcError};
 use tauri::State;
 use tokio::task;
 use tracing::{debug, error, info, warn};
@@ -20,13 +20,6 @@ use tracing::{debug, error, info, warn};
 use crate::context::AppContext;
 use crate::utils::logging::{log_command_execution, record_command_metric, MetricRecord};
 
-/// Idle detection settings
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct IdleSettings {
-    pub pause_on_idle: bool,
-    pub idle_threshold_secs: i64,
-}
-
 /// Valid user actions for idle periods
 const VALID_ACTIONS: &[&str] = &["kept", "discarded", "auto_excluded", "pending"];
 
@@ -240,36 +233,7 @@ pub async fn get_idle_settings(
     info!(command = command_name, "Getting idle settings");
 
     let app_ctx = Arc::clone(context.inner());
-    let db = Arc::clone(&app_ctx.db);
-
-    let result = task::spawn_blocking(move || -> Result<IdleSettings, PulseArcError> {
-        let conn = db.get_connection()?;
-
-        // Query settings from database, or use defaults if not found
-        let settings = match conn.query_row(
-            "SELECT pause_on_idle, idle_threshold_secs FROM idle_settings WHERE id = 1",
-            rusqlite::params![],
-            |row| {
-                Ok(IdleSettings {
-                    pause_on_idle: row.get::<_, i64>(0)? != 0,
-                    idle_threshold_secs: row.get(1)?,
-                })
-            },
-        ) {
-            Ok(settings) => settings,
-            Err(_) => {
-                // If no settings exist, return defaults
-                IdleSettings {
-                    pause_on_idle: true,
-                    idle_threshold_secs: 600, // 10 minutes default
-                }
-            }
-        };
-
-        Ok(settings)
-    })
-    .await
-    .map_err(|e| format!("Task join error: {}", e))?;
+    let result = load_idle_settings(&app_ctx).await;
 
     let elapsed = start_time.elapsed();
     let success = result.is_ok();
@@ -304,34 +268,7 @@ pub async fn set_idle_enabled(
     info!(command = command_name, enabled, "Setting idle enabled status");
 
     let app_ctx = Arc::clone(context.inner());
-    let db = Arc::clone(&app_ctx.db);
-
-    let result = task::spawn_blocking(move || -> Result<(), PulseArcError> {
-        let conn = db.get_connection()?;
-
-        // Ensure table exists
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS idle_settings (
-                id INTEGER PRIMARY KEY,
-                pause_on_idle BOOLEAN NOT NULL DEFAULT 1,
-                idle_threshold_secs INTEGER NOT NULL DEFAULT 600
-            )",
-            rusqlite::params![],
-        )
-        .map_err(|e| PulseArcError::Database(format!("Failed to create settings table: {}", e)))?;
-
-        // Insert or update
-        conn.execute(
-            "INSERT INTO idle_settings (id, pause_on_idle) VALUES (1, ?1)
-             ON CONFLICT(id) DO UPDATE SET pause_on_idle = ?1",
-            rusqlite::params![enabled as i64],
-        )
-        .map_err(|e| PulseArcError::Database(format!("Failed to update idle enabled: {}", e)))?;
-
-        Ok(())
-    })
-    .await
-    .map_err(|e| format!("Task join error: {}", e))?;
+    let result = update_idle_settings(&app_ctx, |settings| settings.pause_on_idle = enabled).await;
 
     let elapsed = start_time.elapsed();
     let success = result.is_ok();
@@ -371,34 +308,9 @@ pub async fn set_idle_threshold(
     }
 
     let app_ctx = Arc::clone(context.inner());
-    let db = Arc::clone(&app_ctx.db);
-
-    let result = task::spawn_blocking(move || -> Result<(), PulseArcError> {
-        let conn = db.get_connection()?;
-
-        // Ensure table exists
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS idle_settings (
-                id INTEGER PRIMARY KEY,
-                pause_on_idle BOOLEAN NOT NULL DEFAULT 1,
-                idle_threshold_secs INTEGER NOT NULL DEFAULT 600
-            )",
-            rusqlite::params![],
-        )
-        .map_err(|e| PulseArcError::Database(format!("Failed to create settings table: {}", e)))?;
-
-        // Insert or update
-        conn.execute(
-            "INSERT INTO idle_settings (id, idle_threshold_secs) VALUES (1, ?1)
-             ON CONFLICT(id) DO UPDATE SET idle_threshold_secs = ?1",
-            rusqlite::params![threshold_secs],
-        )
-        .map_err(|e| PulseArcError::Database(format!("Failed to update idle threshold: {}", e)))?;
-
-        Ok(())
-    })
-    .await
-    .map_err(|e| format!("Task join error: {}", e))?;
+    let result =
+        update_idle_settings(&app_ctx, |settings| settings.idle_threshold_secs = threshold_secs)
+            .await;
 
     let elapsed = start_time.elapsed();
     let success = result.is_ok();
@@ -419,6 +331,53 @@ pub async fn set_idle_threshold(
     result.map_err(|e| e.to_string())
 }
 
+/// Current idle settings from the settings store
+///
+/// Databases that predate the store may still hold settings in the legacy
+/// `idle_settings` table; those are used until the first change is saved.
+/// Defaults apply when neither has a value.
+pub async fn load_idle_settings(ctx: &AppContext) -> Result<IdleSettings, PulseArcError> {
+    if let Some(settings) =
+        get_setting::<IdleSettings>(ctx.user_settings.as_ref(), USER_SCOPE).await?
+    {
+        return Ok(settings);
+    }
+
+    let db = Arc::clone(&ctx.db);
+    let legacy = task::spawn_blocking(move || -> Result<Option<IdleSettings>, PulseArcError> {
+        let conn = db.get_connection()?;
+        let settings = conn
+            .query_row(
+                "SELECT pause_on_idle, idle_threshold_secs FROM idle_settings WHERE id = 1",
+                rusqlite::params![],
+                |row| {
+                    Ok(IdleSettings {
+                        pause_on_idle: row.get::<_, i64>(0)? != 0,
+                        idle_threshold_secs: row.get(1)?,
+                    })
+                },
+            )
+            // Missing table or row: nothing to carry over
+            .ok();
+        Ok(settings)
+    })
+    .await
+    .map_err(|e| PulseArcError::Internal(format!("Task join error: {}", e)))??;
+
+    Ok(legacy.unwrap_or_default())
+}
+
+/// Apply `change` to the current idle settings and save them (with history)
+async fn update_idle_settings<F>(ctx: &AppContext, change: F) -> Result<(), PulseArcError>
+where
+    F: FnOnce(&mut IdleSettings),
+{
+    let mut settings = load_idle_settings(ctx).await?;
+    change(&mut settings);
+    set_setting(ctx.user_settings.as_ref(), USER_SCOPE, &settings).await?;
+    Ok(())
+}
+
 // =============================================================================
 // New Implementation (Phase 4B.3)
 // =============================================================================
@@ -1,13 +1,22 @@
 //! Tauri commands - frontend to backend bridge
 
+mod app_categories;
 mod blocks;
 mod calendar;
+#[cfg(feature = "audit-compliance")]
+mod compliance;
 mod database;
+#[cfg(feature = "demo-seed")]
+mod demo_seed;
+mod diagnostics;
 mod feature_flags;
 mod health;
 mod idle;
 mod idle_sync;
 mod projects;
+mod reports;
+#[cfg(feature = "sap")]
+mod sap;
 mod suggestions;
 mod tracking;
 pub mod user_profile; // Public for integration tests
@@ -16,14 +25,23 @@ mod window;
 #[cfg(debug_assertions)]
 mod seed_snapshots;
 
+pub use app_categories::*;
 pub use blocks::*;
 pub use calendar::*;
+#[cfg(feature = "audit-compliance")]
+pub use compliance::*;
 pub use database::*;
+#[cfg(feature = "demo-seed")]
+pub use demo_seed::*;
+pub use diagnostics::*;
 pub use feature_flags::*;
 pub use health::*;
 pub use idle::*;
 pub use idle_sync::*;
 pub use projects::*;
+pub use reports::*;
+#[cfg(feature = "sap")]
+pub use sap::*;
 #[cfg(debug_assertions)]
 pub use seed_snapshots::*;
 pub use suggestions::*;
@@ -3,16 +3,19 @@
 use std::sync::Arc;
 use std::time::Instant;
 
-use pulsearc_domain::types::database::Project;
+use pulsearc_domain::types::database::{Project, ProjectSuggestion};
+use pulsearc_domain::types::WbsSearchHit;
 use pulsearc_domain::{PulseArcError, Result};
 use tauri::State;
 use tokio::task;
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 
 use crate::context::AppContext;
 use crate::utils::logging::{log_command_execution, record_command_metric, MetricRecord};
 
 const MAX_PROJECT_RESULTS: i64 = 500;
+const DEFAULT_AUTOCOMPLETE_LIMIT: usize = 10;
+const DEFAULT_WBS_SEARCH_LIMIT: usize = 20;
 const PROJECT_QUERY: &str = r#"
     SELECT
         wc.project_def,
@@ -66,6 +69,61 @@ pub async fn get_user_projects(ctx: State<'_, Arc<AppContext>>) -> Result<Vec<Pr
     result
 }
 
+/// Suggest projects whose code, WBS code or name starts with `prefix`
+///
+/// Matching is case-insensitive; the most frequently accepted projects come
+/// first. `limit` defaults to 10.
+#[tauri::command]
+pub async fn autocomplete_project(
+    ctx: State<'_, Arc<AppContext>>,
+    prefix: String,
+    limit: Option<usize>,
+) -> Result<Vec<ProjectSuggestion>> {
+    let app_ctx = Arc::clone(ctx.inner());
+    autocomplete_projects(&app_ctx, &prefix, limit.unwrap_or(DEFAULT_AUTOCOMPLETE_LIMIT)).await
+}
+
+async fn autocomplete_projects(
+    ctx: &Arc<AppContext>,
+    prefix: &str,
+    limit: usize,
+) -> Result<Vec<ProjectSuggestion>> {
+    let projects = fetch_user_projects(ctx).await?;
+
+    let mut autocomplete = ctx.project_autocomplete.write().await;
+    if autocomplete.set_projects(projects) {
+        debug!(projects = autocomplete.len(), "Rebuilt project autocomplete index");
+    }
+
+    Ok(autocomplete.complete(prefix, limit))
+}
+
+/// Fuzzy search the cached WBS elements by code or description
+///
+/// An exact code match ranks first; partial codes and descriptions (including
+/// small typos) are ranked by similarity. `limit` defaults to 20.
+#[tauri::command]
+pub async fn search_wbs(
+    ctx: State<'_, Arc<AppContext>>,
+    query: String,
+    limit: Option<usize>,
+) -> Result<Vec<WbsSearchHit>> {
+    let app_ctx = Arc::clone(ctx.inner());
+    search_wbs_elements(&app_ctx, query, limit.unwrap_or(DEFAULT_WBS_SEARCH_LIMIT)).await
+}
+
+async fn search_wbs_elements(
+    ctx: &Arc<AppContext>,
+    query: String,
+    limit: usize,
+) -> Result<Vec<WbsSearchHit>> {
+    let wbs_search = Arc::clone(&ctx.wbs_search);
+
+    task::spawn_blocking(move || wbs_search.search(&query, limit))
+        .await
+        .map_err(|err| PulseArcError::Internal(format!("WBS search task failed: {}", err)))?
+}
+
 async fn fetch_user_projects(ctx: &Arc<AppContext>) -> Result<Vec<Project>> {
     let db = Arc::clone(&ctx.db);
 
@@ -129,6 +187,8 @@ mod tests {
                 path: db_path.to_string_lossy().to_string(),
                 pool_size: 5,
                 encryption_key: None,
+                busy_timeout_ms: None,
+                query_timeout_ms: None,
             },
             ..Config::default()
         };
@@ -192,4 +252,71 @@ mod tests {
 
         ctx.shutdown().await.expect("shutdown should succeed");
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_autocomplete_projects_ranks_used_projects_first() {
+        let (ctx, _temp_dir) =
+            AppContext::new_for_test().await.expect("failed to create AppContext");
+        let ctx = Arc::new(ctx);
+
+        let db = Arc::clone(&ctx.db);
+        task::spawn_blocking(move || {
+            let conn = db.get_connection().expect("connection");
+            let now = Utc::now().timestamp();
+            for (wbs_code, project_def, name) in
+                [("WBS-001.001", "PRJ-001", "Project Alpha"), ("WBS-002.001", "PRJ-002", "Beta")]
+            {
+                conn.execute(
+                    "INSERT INTO wbs_cache (wbs_code, project_def, project_name, status, cached_at, expires_at)
+                     VALUES (?1, ?2, ?3, 'REL', ?4, ?5)",
+                    rusqlite::params![wbs_code, project_def, name, now, now + 86_400],
+                )
+                .expect("insert project");
+            }
+        })
+        .await
+        .expect("insert task");
+
+        let suggestions = autocomplete_projects(&ctx, "prj", 10).await.expect("autocomplete");
+        assert_eq!(suggestions.len(), 2);
+        assert_eq!(suggestions[0].id, "PRJ-001");
+
+        ctx.project_autocomplete.write().await.record_usage("PRJ-002");
+        let suggestions = autocomplete_projects(&ctx, "PRJ", 1).await.expect("autocomplete");
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].id, "PRJ-002");
+        assert_eq!(suggestions[0].usage_count, 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_search_wbs_ranks_description_match_first() {
+        let (ctx, _temp_dir) =
+            AppContext::new_for_test().await.expect("failed to create AppContext");
+        let ctx = Arc::new(ctx);
+
+        let db = Arc::clone(&ctx.db);
+        task::spawn_blocking(move || {
+            let conn = db.get_connection().expect("connection");
+            let now = Utc::now().timestamp();
+            for (wbs_code, description) in
+                [("USC0063201.1.1", "Cloud migration"), ("USC0063201.1.2", "Due diligence")]
+            {
+                conn.execute(
+                    "INSERT INTO wbs_cache (wbs_code, project_def, description, status, cached_at, expires_at)
+                     VALUES (?1, 'USC0063201', ?2, 'REL', ?3, ?4)",
+                    rusqlite::params![wbs_code, description, now, now + 86_400],
+                )
+                .expect("insert wbs");
+            }
+        })
+        .await
+        .expect("insert task");
+
+        let hits = search_wbs_elements(&ctx, "diligen".into(), 10).await.expect("search");
+        assert_eq!(hits[0].element.wbs_code, "USC0063201.1.2");
+
+        let hits = search_wbs_elements(&ctx, "USC0063201.1.1".into(), 10).await.expect("search");
+        assert_eq!(hits[0].element.wbs_code, "USC0063201.1.1");
+        assert_eq!(hits[0].score, 1.0);
+    }
 }
@@ -0,0 +1,188 @@
+//! Report commands
+//!
+//! End-of-day summaries of accepted time, returned as data or exported as
+//! formatted text.
+//!
+//! # Commands
+//!
+//! - `get_daily_summary` - Billable/non-billable totals and per-project
+//!   breakdown for a day
+//! - `export_daily_summary` - The same summary rendered as text or Markdown
+//! - `export_training_data` - Accepted blocks as labeled classifier training
+//!   examples (JSONL)
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use chrono::{DateTime, Utc};
+use pulsearc_common::privacy::PatternMatcher;
+use pulsearc_core::classification::{
+    daily_summary, render_daily_summary, training_data, SummaryFormat,
+};
+use pulsearc_domain::types::classification::DailySummary;
+use pulsearc_domain::{PulseArcError, TimeRange};
+use tauri::State;
+use tracing::info;
+
+use super::user_profile::current_workday_schedule;
+use crate::context::AppContext;
+use crate::utils::command_error::CommandError;
+use crate::utils::logging::{log_command_execution, record_command_metric, MetricRecord};
+
+// Internal result type for domain operations
+type DomainResult<T> = std::result::Result<T, PulseArcError>;
+
+/// Get the end-of-day summary for a day
+///
+/// Only accepted (or edited) blocks are counted. After-hours and
+/// non-working-day time follows the user's workday settings.
+#[tauri::command]
+pub async fn get_daily_summary(
+    ctx: State<'_, Arc<AppContext>>,
+    day_epoch: i64,
+) -> Result<DailySummary, CommandError> {
+    let command_name = "reports::get_daily_summary";
+    let implementation = "new";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(command = command_name, day_epoch, "Building daily summary");
+    let result = fetch_daily_summary(&app_ctx, day_epoch).await;
+    let elapsed = start.elapsed();
+    let success = result.is_ok();
+    let error_label = result.as_ref().err().map(|err| err.to_string());
+
+    log_command_execution(command_name, implementation, elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation,
+            elapsed,
+            success,
+            error_type: error_label.as_deref(),
+        },
+    )
+    .await;
+
+    result.map_err(CommandError::from)
+}
+
+/// Export the end-of-day summary as formatted text
+///
+/// `format` is `"markdown"` (default) or `"text"`.
+#[tauri::command]
+pub async fn export_daily_summary(
+    ctx: State<'_, Arc<AppContext>>,
+    day_epoch: i64,
+    format: Option<String>,
+) -> Result<String, CommandError> {
+    let command_name = "reports::export_daily_summary";
+    let implementation = "new";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(command = command_name, day_epoch, format = ?format, "Exporting daily summary");
+    let result = export_summary(&app_ctx, day_epoch, format.as_deref()).await;
+    let elapsed = start.elapsed();
+    let success = result.is_ok();
+    let error_label = result.as_ref().err().map(|err| err.to_string());
+
+    log_command_execution(command_name, implementation, elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation,
+            elapsed,
+            success,
+            error_type: error_label.as_deref(),
+        },
+    )
+    .await;
+
+    result.map_err(CommandError::from)
+}
+
+/// Export accepted blocks starting in `[start_epoch, end_epoch)` as labeled
+/// training examples
+///
+/// Returns JSON Lines, one example per accepted block. Free-text features are
+/// PII-redacted with the default privacy patterns.
+#[tauri::command]
+pub async fn export_training_data(
+    ctx: State<'_, Arc<AppContext>>,
+    start_epoch: i64,
+    end_epoch: i64,
+) -> Result<String, CommandError> {
+    let command_name = "reports::export_training_data";
+    let implementation = "new";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(command = command_name, start_epoch, end_epoch, "Exporting training data");
+    let result = export_examples(&app_ctx, start_epoch, end_epoch).await;
+    let elapsed = start.elapsed();
+    let success = result.is_ok();
+    let error_label = result.as_ref().err().map(|err| err.to_string());
+
+    log_command_execution(command_name, implementation, elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation,
+            elapsed,
+            success,
+            error_type: error_label.as_deref(),
+        },
+    )
+    .await;
+
+    result.map_err(CommandError::from)
+}
+
+async fn fetch_daily_summary(ctx: &Arc<AppContext>, day_epoch: i64) -> DomainResult<DailySummary> {
+    let target_day = DateTime::<Utc>::from_timestamp(day_epoch, 0)
+        .ok_or_else(|| PulseArcError::InvalidInput(format!("Invalid day_epoch: {day_epoch}")))?;
+
+    let workday = current_workday_schedule(ctx).await;
+
+    daily_summary(ctx.block_repository.as_ref(), target_day.date_naive(), &workday).await
+}
+
+async fn export_summary(
+    ctx: &Arc<AppContext>,
+    day_epoch: i64,
+    format: Option<&str>,
+) -> DomainResult<String> {
+    let format = match format.map(str::trim).filter(|value| !value.is_empty()) {
+        Some(value) => value.parse::<SummaryFormat>()?,
+        None => SummaryFormat::default(),
+    };
+    let summary = fetch_daily_summary(ctx, day_epoch).await?;
+
+    Ok(render_daily_summary(&summary, format))
+}
+
+async fn export_examples(
+    ctx: &Arc<AppContext>,
+    start_epoch: i64,
+    end_epoch: i64,
+) -> DomainResult<String> {
+    let redactor = PatternMatcher::with_defaults()
+        .await
+        .map_err(|err| PulseArcError::Internal(format!("Failed to load PII patterns: {err}")))?;
+    let range = TimeRange { start_ts: start_epoch, end_ts: end_epoch, is_all_day: false };
+
+    let examples = training_data::export_training_data(
+        ctx.block_repository.as_ref(),
+        ctx.snapshots.as_ref(),
+        &redactor,
+        range,
+    )
+    .await?;
+    info!(examples = examples.len(), "Exported training examples");
+
+    training_data::training_data_jsonl(&examples)
+}
@@ -0,0 +1,202 @@
+//! SAP batch validation commands (`sap` feature)
+//!
+//! Lets admins dry-run the pending SAP outbox before a real submission push:
+//! every entry goes through conversion, WBS validation (format, existence,
+//! status) and the posting period check, but nothing is posted to SAP.
+//!
+//! # Example Usage
+//!
+//! ```javascript
+//! const report = await invoke('validate_sap_batch', { limit: 50 });
+//! report.entries.filter((e) => e.outcome === 'failed');
+//! ```
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use pulsearc_domain::{PulseArcError, Result as DomainResult};
+use pulsearc_infra::integrations::sap::{
+    BatchSubmissionResult, DeferralReason, EntrySubmissionResult, EntrySubmissionStatus,
+};
+use serde::{Deserialize, Serialize};
+use tauri::State;
+use tracing::info;
+
+use crate::context::AppContext;
+use crate::utils::correlation::with_correlation_id;
+use crate::utils::logging::{log_command_execution, record_command_metric, MetricRecord};
+
+/// Default number of pending entries validated per call (matches the SAP
+/// scheduler batch size)
+const DEFAULT_VALIDATION_LIMIT: usize = 50;
+
+/// Validation report for a batch of pending SAP entries
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SapValidationReport {
+    pub validated: usize,
+    pub failed: usize,
+    pub deferred: usize,
+    pub entries: Vec<SapEntryValidation>,
+}
+
+/// Validation outcome for a single outbox entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SapEntryValidation {
+    pub outbox_id: String,
+    /// `validated`, `failed` or `deferred`
+    pub outcome: String,
+    /// Error category for failed entries, deferral reason for deferred ones
+    pub code: Option<String>,
+    pub message: Option<String>,
+}
+
+/// Validate up to `limit` pending outbox entries against SAP without posting
+/// them
+#[tauri::command]
+pub async fn validate_sap_batch(
+    ctx: State<'_, Arc<AppContext>>,
+    limit: Option<usize>,
+) -> Result<SapValidationReport, String> {
+    with_correlation_id("sap::validate_sap_batch", async move {
+        let command_name = "sap::validate_sap_batch";
+        let start = Instant::now();
+        let app_ctx = Arc::clone(ctx.inner());
+
+        let limit = limit.unwrap_or(DEFAULT_VALIDATION_LIMIT);
+        info!(command = command_name, limit, "Validating pending SAP entries");
+
+        let result = validate_sap_batch_impl(&app_ctx, limit).await;
+
+        let elapsed = start.elapsed();
+        let success = result.is_ok();
+
+        log_command_execution(command_name, "new", elapsed, success);
+        record_command_metric(
+            &app_ctx,
+            MetricRecord {
+                command: command_name,
+                implementation: "new",
+                elapsed,
+                success,
+                error_type: if !success { Some("validation_failed") } else { None },
+            },
+        )
+        .await;
+
+        result.map_err(|e| e.to_string())
+    })
+    .await
+}
+
+async fn validate_sap_batch_impl(
+    ctx: &AppContext,
+    limit: usize,
+) -> DomainResult<SapValidationReport> {
+    let validator = ctx
+        .sap_validator
+        .as_ref()
+        .ok_or_else(|| PulseArcError::Config("SAP integration is not configured".to_string()))?;
+
+    // Reading pending entries does not claim them, so the scheduler still
+    // submits them later
+    let entries = ctx.outbox_queue.dequeue_batch(limit).await?;
+    let result = validator.submit_batch(&entries).await?;
+
+    Ok(build_report(result))
+}
+
+fn build_report(result: BatchSubmissionResult) -> SapValidationReport {
+    SapValidationReport {
+        validated: result.validated,
+        failed: result.failed,
+        deferred: result.deferred,
+        entries: result.entry_results.into_iter().map(entry_validation).collect(),
+    }
+}
+
+fn entry_validation(result: EntrySubmissionResult) -> SapEntryValidation {
+    let (outcome, code, message) = match result.status {
+        EntrySubmissionStatus::Validated => ("validated", None, None),
+        EntrySubmissionStatus::Deferred { date, reason } => {
+            let code = match reason {
+                DeferralReason::PeriodClosed => "PERIOD_CLOSED",
+                DeferralReason::PeriodNotYetOpen => "PERIOD_NOT_YET_OPEN",
+            };
+            (
+                "deferred",
+                Some(code.to_string()),
+                Some(format!("Posting period for {date} is not open")),
+            )
+        }
+        // A validate-only forwarder never submits; report anything else as a
+        // failure rather than dropping it
+        EntrySubmissionStatus::Failed { error } | EntrySubmissionStatus::DeadLettered { error } => {
+            ("failed", Some(error.category().to_string()), Some(error.to_string()))
+        }
+        EntrySubmissionStatus::Submitted { sap_entry_id } => {
+            ("failed", None, Some(format!("Entry was submitted unexpectedly as {sap_entry_id}")))
+        }
+    };
+
+    SapEntryValidation { outbox_id: result.outbox_id, outcome: outcome.to_string(), code, message }
+}
+
+#[cfg(test)]
+mod tests {
+    use pulsearc_infra::integrations::sap::{SapError, SapErrorCategory};
+
+    use super::*;
+
+    #[test]
+    fn test_report_maps_each_entry_outcome() {
+        let result = BatchSubmissionResult {
+            successful: 0,
+            failed: 1,
+            dead_lettered: 0,
+            deferred: 1,
+            validated: 1,
+            entry_results: vec![
+                EntrySubmissionResult {
+                    outbox_id: "valid".to_string(),
+                    status: EntrySubmissionStatus::Validated,
+                },
+                EntrySubmissionResult {
+                    outbox_id: "bad-wbs".to_string(),
+                    status: EntrySubmissionStatus::Failed {
+                        error: SapError::new(SapErrorCategory::Validation, "WBS not found"),
+                    },
+                },
+                EntrySubmissionResult {
+                    outbox_id: "closed".to_string(),
+                    status: EntrySubmissionStatus::Deferred {
+                        date: "2025-09-30".to_string(),
+                        reason: DeferralReason::PeriodClosed,
+                    },
+                },
+            ],
+        };
+
+        let report = build_report(result);
+
+        assert_eq!((report.validated, report.failed, report.deferred), (1, 1, 1));
+        assert_eq!(report.entries[0].outcome, "validated");
+        assert_eq!(report.entries[1].outcome, "failed");
+        assert_eq!(
+            report.entries[1].code.as_deref(),
+            Some(SapErrorCategory::Validation.to_string().as_str())
+        );
+        assert_eq!(report.entries[2].outcome, "deferred");
+        assert_eq!(report.entries[2].code.as_deref(), Some("PERIOD_CLOSED"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_validation_requires_configured_sap_client() {
+        let (ctx, _temp_dir) =
+            AppContext::new_for_test().await.expect("failed to create AppContext");
+
+        let err = validate_sap_batch_impl(&ctx, 10).await.expect_err("SAP is not configured");
+        assert!(matches!(err, PulseArcError::Config(_)));
+    }
+}
@@ -235,6 +235,7 @@ fn build_snapshots_from_blocks(blocks: &[TestBlock], base_start: i64) -> Vec<Act
                 processed_at: None,
                 is_idle: false,
                 idle_duration_secs: None,
+                content_hash: None,
             });
         }
 
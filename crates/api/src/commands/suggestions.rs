@@ -5,9 +5,11 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use chrono::{DateTime, Utc};
-use pulsearc_domain::types::classification::ProposedBlock;
+use pulsearc_core::classification::BlockRanker;
+use pulsearc_domain::types::classification::{RankedProposedBlock, SuggestionSuppression};
 use pulsearc_domain::types::database::TimeEntryOutbox;
-use pulsearc_domain::{OutboxStatus, PulseArcError};
+use pulsearc_domain::{OutboxFlushReport, OutboxStatus, OutboxStatusSummary, PulseArcError};
+use pulsearc_infra::database::{SqlCipherOutboxRepository, SqlCipherSuggestionBatchRepository};
 use tauri::State;
 use tokio::task;
 use tracing::{info, warn};
@@ -16,13 +18,15 @@ use tracing::{info, warn};
 type DomainResult<T> = std::result::Result<T, PulseArcError>;
 
 use crate::context::AppContext;
+use crate::utils::command_error::CommandError;
+use crate::utils::correlation::with_correlation_id;
 use crate::utils::logging::{log_command_execution, record_command_metric, MetricRecord};
 
 /// Get dismissed time entry suggestions
 #[tauri::command]
 pub async fn get_dismissed_suggestions(
     ctx: State<'_, Arc<AppContext>>,
-) -> DomainResult<Vec<TimeEntryOutbox>> {
+) -> Result<Vec<TimeEntryOutbox>, CommandError> {
     let command_name = "suggestions::get_dismissed_suggestions";
     let implementation = "new";
     let start = Instant::now();
@@ -47,16 +51,19 @@ pub async fn get_dismissed_suggestions(
     )
     .await;
 
-    result
+    result.map_err(CommandError::from)
 }
 
 /// Get proposed time blocks for a specific day
+///
+/// Blocks are returned pre-sorted by ranking score (highest first), with the
+/// score included on each item.
 #[tauri::command]
 pub async fn get_proposed_blocks(
     ctx: State<'_, Arc<AppContext>>,
     day_epoch: i64,
     status: Option<String>,
-) -> DomainResult<Vec<ProposedBlock>> {
+) -> Result<Vec<RankedProposedBlock>, CommandError> {
     let command_name = "suggestions::get_proposed_blocks";
     let implementation = "new";
     let start = Instant::now();
@@ -86,14 +93,14 @@ pub async fn get_proposed_blocks(
     )
     .await;
 
-    result
+    result.map_err(CommandError::from)
 }
 
 /// Get outbox status (legacy time entry suggestions)
 #[tauri::command]
 pub async fn get_outbox_status(
     ctx: State<'_, Arc<AppContext>>,
-) -> DomainResult<Vec<TimeEntryOutbox>> {
+) -> Result<Vec<TimeEntryOutbox>, CommandError> {
     let command_name = "suggestions::get_outbox_status";
     let implementation = "new";
     let start = Instant::now();
@@ -118,18 +125,155 @@ pub async fn get_outbox_status(
     )
     .await;
 
-    result
+    result.map_err(CommandError::from)
+}
+
+/// Get an aggregate outbox summary (status counts, oldest pending age and
+/// pending age buckets) for detecting a stuck sync
+#[tauri::command]
+pub async fn get_outbox_summary(
+    ctx: State<'_, Arc<AppContext>>,
+) -> Result<OutboxStatusSummary, CommandError> {
+    let command_name = "suggestions::get_outbox_summary";
+    let implementation = "new";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(command = command_name, "Fetching outbox summary");
+    let result = fetch_outbox_summary(&app_ctx).await;
+    let elapsed = start.elapsed();
+    let success = result.is_ok();
+    let error_label = result.as_ref().err().map(|err| err.to_string());
+
+    if let Ok(summary) = &result {
+        info!(
+            command = command_name,
+            pending = summary.pending_count,
+            oldest_pending_age_secs = ?summary.oldest_pending_age_secs,
+            "Outbox summary computed"
+        );
+    }
+
+    log_command_execution(command_name, implementation, elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation,
+            elapsed,
+            success,
+            error_type: error_label.as_deref(),
+        },
+    )
+    .await;
+
+    result.map_err(CommandError::from)
+}
+
+/// Forward pending outbox entries now instead of waiting for the next poll
+///
+/// The request takes the outbox worker's high-priority lane, so it is serviced
+/// ahead of background batching rather than behind the backlog.
+#[tauri::command]
+pub async fn sync_now(ctx: State<'_, Arc<AppContext>>) -> Result<OutboxFlushReport, CommandError> {
+    with_correlation_id("suggestions::sync_now", async move {
+        let command_name = "suggestions::sync_now";
+        let implementation = "new";
+        let start = Instant::now();
+        let app_ctx = Arc::clone(ctx.inner());
+
+        info!(command = command_name, "Flushing outbox on user request");
+        let result = match &app_ctx.outbox_worker {
+            Some(worker) => worker.sync_now().await.map_err(PulseArcError::Internal),
+            None => Err(PulseArcError::Config("outbox sync is disabled".to_string())),
+        };
+        let elapsed = start.elapsed();
+        let success = result.is_ok();
+        let error_label = result.as_ref().err().map(|err| err.to_string());
+
+        if let Ok(report) = &result {
+            info!(
+                command = command_name,
+                forwarded = report.forwarded,
+                failed = report.failed,
+                "User-initiated outbox flush completed"
+            );
+        }
+
+        log_command_execution(command_name, implementation, elapsed, success);
+        record_command_metric(
+            &app_ctx,
+            MetricRecord {
+                command: command_name,
+                implementation,
+                elapsed,
+                success,
+                error_type: error_label.as_deref(),
+            },
+        )
+        .await;
+
+        result.map_err(CommandError::from)
+    })
+    .await
+}
+
+/// Get suggestion suppressions learned from dismissed blocks
+///
+/// Includes signatures still below the threshold so the UI can explain why a
+/// suggestion was hidden (or is about to be).
+#[tauri::command]
+pub async fn get_suggestion_suppressions(
+    ctx: State<'_, Arc<AppContext>>,
+) -> Result<Vec<SuggestionSuppression>, CommandError> {
+    let command_name = "suggestions::get_suggestion_suppressions";
+    let implementation = "new";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(command = command_name, "Fetching suggestion suppressions");
+    let result = fetch_suggestion_suppressions(&app_ctx).await;
+    let elapsed = start.elapsed();
+    let success = result.is_ok();
+    let error_label = result.as_ref().err().map(|err| err.to_string());
+
+    log_command_execution(command_name, implementation, elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation,
+            elapsed,
+            success,
+            error_type: error_label.as_deref(),
+        },
+    )
+    .await;
+
+    result.map_err(CommandError::from)
+}
+
+async fn fetch_outbox_summary(ctx: &Arc<AppContext>) -> DomainResult<OutboxStatusSummary> {
+    SqlCipherOutboxRepository::new(Arc::clone(&ctx.db)).status_summary().await
+}
+
+async fn fetch_suggestion_suppressions(
+    ctx: &Arc<AppContext>,
+) -> DomainResult<Vec<SuggestionSuppression>> {
+    ctx.suggestion_suppressor.suppressions(Utc::now().timestamp()).await
 }
 
 async fn fetch_proposed_blocks(
     ctx: &Arc<AppContext>,
     day_epoch: i64,
     status: Option<String>,
-) -> DomainResult<Vec<ProposedBlock>> {
+) -> DomainResult<Vec<RankedProposedBlock>> {
     let target_day = DateTime::<Utc>::from_timestamp(day_epoch, 0)
         .ok_or_else(|| PulseArcError::InvalidInput(format!("Invalid day_epoch: {day_epoch}")))?;
 
-    let mut blocks = ctx.block_repository.get_proposed_blocks(target_day.date_naive()).await?;
+    let now = Utc::now().timestamp();
+    let blocks = ctx.block_repository.get_proposed_blocks(target_day.date_naive()).await?;
+    let mut blocks = ctx.suggestion_suppressor.filter_blocks(blocks, now).await?;
 
     if let Some(filter) = status.and_then(|s| {
         let trimmed = s.trim();
@@ -142,7 +286,7 @@ async fn fetch_proposed_blocks(
         blocks.retain(|block| block.status.eq_ignore_ascii_case(&filter));
     }
 
-    Ok(blocks)
+    Ok(BlockRanker::default().rank(blocks, now))
 }
 
 async fn fetch_outbox_entries(
@@ -251,9 +395,14 @@ const OUTBOX_SELECT_BY_STATUS: &str = r#"
 // Suggestion Management Commands (Phase 4 - Legacy Migration)
 // =============================================================================
 
-/// Delete all suggestions from the outbox
+/// How long a `clear_suggestions` can be undone with `restore_last_cleared`
+pub const CLEAR_UNDO_WINDOW_SECS: i64 = 15 * 60;
+
+/// Clear all pending and failed suggestions from the outbox
 ///
-/// Replaces legacy `clear_outbox` command
+/// Replaces legacy `clear_outbox` command. Cleared suggestions are dismissed
+/// rather than deleted so the clear can be undone with
+/// `restore_last_cleared` within [`CLEAR_UNDO_WINDOW_SECS`].
 #[tauri::command]
 pub async fn clear_suggestions(
     ctx: State<'_, Arc<AppContext>>,
@@ -264,16 +413,8 @@ pub async fn clear_suggestions(
 
     info!(command = command_name, "Clearing all suggestions");
 
-    let db = Arc::clone(&app_ctx.db);
-    let result = task::spawn_blocking(move || -> DomainResult<usize> {
-        let conn = db.get_connection()?;
-        let count = conn
-            .execute("DELETE FROM time_entry_outbox", rusqlite::params![])
-            .map_err(|err| map_rusqlite_error("clear suggestions", err))?;
-        Ok(count)
-    })
-    .await
-    .map_err(|err| format!("Task join error: {err}"))?;
+    let repo = SqlCipherSuggestionBatchRepository::new(Arc::clone(&app_ctx.db));
+    let result = repo.clear_all(Utc::now().timestamp()).await;
 
     let elapsed = start.elapsed();
     let success = result.is_ok();
@@ -294,6 +435,79 @@ pub async fn clear_suggestions(
     result.map_err(|e| e.to_string())
 }
 
+/// Restore several dismissed suggestions back to pending
+///
+/// Suggestions that were hard-deleted or are not dismissed are skipped.
+/// Returns the number of suggestions restored.
+#[tauri::command]
+pub async fn restore_suggestions(
+    ctx: State<'_, Arc<AppContext>>,
+    ids: Vec<String>,
+) -> std::result::Result<usize, String> {
+    let command_name = "suggestions::restore_suggestions";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(command = command_name, count = ids.len(), "Restoring suggestions");
+
+    let repo = SqlCipherSuggestionBatchRepository::new(Arc::clone(&app_ctx.db));
+    let result = repo.restore(&ids, Utc::now().timestamp()).await;
+
+    let elapsed = start.elapsed();
+    let success = result.is_ok();
+
+    log_command_execution(command_name, "new", elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation: "new",
+            elapsed,
+            success,
+            error_type: if !success { Some("restore_failed") } else { None },
+        },
+    )
+    .await;
+
+    result.map_err(|e| e.to_string())
+}
+
+/// Undo the most recent `clear_suggestions`
+///
+/// Only effective within [`CLEAR_UNDO_WINDOW_SECS`] of the clear. Returns the
+/// number of suggestions restored (0 when there is nothing to undo).
+#[tauri::command]
+pub async fn restore_last_cleared(
+    ctx: State<'_, Arc<AppContext>>,
+) -> std::result::Result<usize, String> {
+    let command_name = "suggestions::restore_last_cleared";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(command = command_name, "Undoing last suggestion clear");
+
+    let repo = SqlCipherSuggestionBatchRepository::new(Arc::clone(&app_ctx.db));
+    let result = repo.restore_last_cleared(Utc::now().timestamp(), CLEAR_UNDO_WINDOW_SECS).await;
+
+    let elapsed = start.elapsed();
+    let success = result.is_ok();
+
+    log_command_execution(command_name, "new", elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation: "new",
+            elapsed,
+            success,
+            error_type: if !success { Some("restore_failed") } else { None },
+        },
+    )
+    .await;
+
+    result.map_err(|e| e.to_string())
+}
+
 /// Delete a specific suggestion by ID
 ///
 /// Replaces legacy `delete_outbox_entry` command
@@ -513,14 +727,10 @@ mod tests {
     use pulsearc_common::testing::TempDir;
     use pulsearc_core::OutboxQueue;
     use pulsearc_domain::types::classification::{ActivityBreakdown, ProposedBlock};
-    use pulsearc_domain::{Config, DatabaseConfig};
-    use pulsearc_infra::database::SqlCipherOutboxRepository;
     use uuid::Uuid;
 
     use super::*;
 
-    const TEST_KEY: &str = "test_key_64_chars_long_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
-
     #[tokio::test(flavor = "multi_thread")]
     async fn test_fetch_proposed_blocks_filters_status() {
         let (ctx, temp_dir) = create_app_context().await;
@@ -537,7 +747,58 @@ mod tests {
         let blocks =
             fetch_proposed_blocks(&ctx, day_start, Some("suggested".into())).await.expect("fetch");
         assert_eq!(blocks.len(), 1);
-        assert_eq!(blocks[0].id, "block-suggested");
+        assert_eq!(blocks[0].block.id, "block-suggested");
+
+        ctx.shutdown().await.expect("shutdown succeeds");
+        drop(temp_dir);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_fetch_proposed_blocks_sorted_by_score() {
+        let (ctx, temp_dir) = create_app_context().await;
+        let day_start = Utc::now().date_naive().and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp();
+
+        let mut weak = sample_block("block-weak", day_start, "suggested");
+        weak.confidence = 0.2;
+        ctx.block_repository.save_proposed_block(&weak).await.expect("save weak");
+
+        let strong = sample_block("block-strong", day_start + 3600, "suggested");
+        ctx.block_repository.save_proposed_block(&strong).await.expect("save strong");
+
+        let blocks = fetch_proposed_blocks(&ctx, day_start, None).await.expect("fetch");
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].block.id, "block-strong");
+        assert!(blocks[0].score >= blocks[1].score);
+
+        ctx.shutdown().await.expect("shutdown succeeds");
+        drop(temp_dir);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_fetch_proposed_blocks_hides_suppressed_signatures() {
+        let (ctx, temp_dir) = create_app_context().await;
+        let day_start = Utc::now().date_naive().and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp();
+        let now = Utc::now().timestamp();
+
+        let dismissed = sample_block("block-dismissed", day_start, "rejected");
+        for _ in 0..ctx.suggestion_suppressor.config().min_dismissals {
+            ctx.suggestion_suppressor.record_dismissal(&dismissed, now).await.expect("record");
+        }
+
+        let mut unrelated = sample_block("block-unrelated", day_start + 3600, "suggested");
+        unrelated.inferred_project_id = Some("PRJ-002".into());
+        ctx.block_repository.save_proposed_block(&unrelated).await.expect("save unrelated");
+        let matching = sample_block("block-matching", day_start + 7200, "suggested");
+        ctx.block_repository.save_proposed_block(&matching).await.expect("save matching");
+
+        let blocks = fetch_proposed_blocks(&ctx, day_start, None).await.expect("fetch");
+        let ids: Vec<_> = blocks.iter().map(|b| b.block.id.as_str()).collect();
+        assert_eq!(ids, vec!["block-unrelated"]);
+
+        let suppressions = fetch_suggestion_suppressions(&ctx).await.expect("suppressions");
+        assert_eq!(suppressions.len(), 1);
+        assert_eq!(suppressions[0].signature, "vscode|prj-001");
+        assert!(suppressions[0].active);
 
         ctx.shutdown().await.expect("shutdown succeeds");
         drop(temp_dir);
@@ -589,6 +850,37 @@ mod tests {
         drop(temp_dir);
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_fetch_outbox_summary_reports_ages() {
+        let (ctx, temp_dir) = create_app_context().await;
+        let outbox_repo = SqlCipherOutboxRepository::new(Arc::clone(&ctx.db));
+        let now = Utc::now().timestamp();
+
+        outbox_repo
+            .enqueue(&sample_outbox("fresh", OutboxStatus::Pending, now - 5))
+            .await
+            .expect("enqueue fresh");
+        outbox_repo
+            .enqueue(&sample_outbox("stuck", OutboxStatus::Pending, now - 7_200))
+            .await
+            .expect("enqueue stuck");
+        outbox_repo
+            .enqueue(&sample_outbox("failed", OutboxStatus::Failed, now - 100))
+            .await
+            .expect("enqueue failed");
+
+        let summary = fetch_outbox_summary(&ctx).await.expect("summary");
+        assert_eq!(summary.pending_count, 2);
+        assert_eq!(summary.failed_count, 1);
+        assert_eq!(summary.pending_age_buckets.total(), 2);
+        assert_eq!(summary.pending_age_buckets.older, 1);
+        let oldest = summary.oldest_pending_age_secs.expect("oldest age present");
+        assert!(oldest >= 7_200, "oldest pending age should reflect the stuck entry");
+
+        ctx.shutdown().await.expect("shutdown succeeds");
+        drop(temp_dir);
+    }
+
     fn sample_block(id: &str, start_ts: i64, status: &str) -> ProposedBlock {
         ProposedBlock {
             id: id.into(),
@@ -657,24 +949,8 @@ mod tests {
     }
 
     async fn create_app_context() -> (Arc<AppContext>, TempDir) {
-        std::env::set_var("TEST_DATABASE_ENCRYPTION_KEY", TEST_KEY);
-
-        let temp_dir =
-            TempDir::new("suggestions-command-test").expect("failed to create temporary directory");
-        let db_path = temp_dir.path().join("pulsearc.db");
-        let lock_dir = temp_dir.create_dir("lock").expect("failed to create lock directory");
-        let config = Config {
-            database: DatabaseConfig {
-                path: db_path.to_string_lossy().to_string(),
-                pool_size: 5,
-                encryption_key: None,
-            },
-            ..Config::default()
-        };
-
-        let ctx = AppContext::new_with_config_in_lock_dir(config, lock_dir)
-            .await
-            .expect("failed to create AppContext");
+        let (ctx, temp_dir) =
+            AppContext::new_for_test().await.expect("failed to create AppContext");
 
         (Arc::new(ctx), temp_dir)
     }
@@ -3,38 +3,107 @@
 use std::sync::Arc;
 use std::time::Instant;
 
-use pulsearc_domain::{ActivityContext, Result};
-use tauri::State;
-use tracing::info;
+use pulsearc_domain::{ActivityContext, PermissionStatus, Result};
+use pulsearc_infra::platform::macos::ax_helpers;
+use tauri::{AppHandle, Emitter, Runtime, State};
+use tokio::sync::watch;
+use tracing::{info, warn};
 
+use crate::utils::correlation::with_correlation_id;
 use crate::utils::logging::{
     error_label, log_command_execution, record_command_metric, MetricRecord,
 };
 use crate::AppContext;
 
+/// Event carrying the new [`PermissionStatus`] when Accessibility
+/// permission is granted or revoked
+pub const ACCESSIBILITY_PERMISSION_EVENT: &str = "accessibility-permission-changed";
+
 /// Get the current activity context
 #[tauri::command]
 pub async fn get_activity(ctx: State<'_, Arc<AppContext>>) -> Result<ActivityContext> {
-    let command_name = "tracking::get_activity";
-    let implementation = "new";
-    let start = Instant::now();
-    let app_ctx = Arc::clone(ctx.inner());
+    with_correlation_id("tracking::get_activity", async move {
+        let command_name = "tracking::get_activity";
+        let implementation = "new";
+        let start = Instant::now();
+        let app_ctx = Arc::clone(ctx.inner());
+
+        info!(command = command_name, "Capturing current activity");
+
+        let result = app_ctx.tracking_service.capture_activity().await;
+        let elapsed = start.elapsed();
+        let success = result.is_ok();
+        let error_type = result.as_ref().err().map(error_label);
+
+        log_command_execution(command_name, implementation, elapsed, success);
+        record_command_metric(
+            &app_ctx,
+            MetricRecord { command: command_name, implementation, elapsed, success, error_type },
+        )
+        .await;
+
+        result
+    })
+    .await
+}
 
-    info!(command = command_name, "Capturing current activity");
+/// Get the Accessibility permission status
+///
+/// Re-checks the permission first, so the UI sees a grant made in System
+/// Settings without waiting for the periodic re-check. Without the
+/// permission captures are app-only (no window titles or enrichment).
+#[tauri::command]
+pub async fn get_accessibility_permission(
+    ctx: State<'_, Arc<AppContext>>,
+) -> Result<PermissionStatus> {
+    Ok(fetch_accessibility_permission(&ctx).await)
+}
 
-    let result = app_ctx.tracking_service.capture_activity().await;
-    let elapsed = start.elapsed();
-    let success = result.is_ok();
-    let error_type = result.as_ref().err().map(error_label);
+async fn fetch_accessibility_permission(context: &AppContext) -> PermissionStatus {
+    // Drop the cached AX state so a fresh grant is seen immediately
+    if let Err(err) = ax_helpers::refresh_ax_permission() {
+        warn!(error = %err, "failed to refresh accessibility permission");
+    }
+    context.accessibility_permission.check().await;
+    context.accessibility_permission.status()
+}
 
-    log_command_execution(command_name, implementation, elapsed, success);
-    record_command_metric(
-        &app_ctx,
-        MetricRecord { command: command_name, implementation, elapsed, success, error_type },
-    )
-    .await;
+/// Ask the user for Accessibility permission
+///
+/// Shows the macOS permission dialog if the app is not yet trusted, then
+/// publishes the re-checked status (emitting
+/// [`ACCESSIBILITY_PERMISSION_EVENT`] if it changed). macOS shows the dialog
+/// only once per launch; if the status stays denied, open
+/// [`get_accessibility_settings_url`] instead.
+#[tauri::command]
+pub async fn request_accessibility_permission(
+    ctx: State<'_, Arc<AppContext>>,
+) -> Result<PermissionStatus> {
+    ax_helpers::request_accessibility_permission()?;
+    ctx.accessibility_permission.check().await;
+    Ok(ctx.accessibility_permission.status())
+}
 
-    result
+/// Deep link to the Accessibility pane of System Settings
+#[tauri::command]
+pub fn get_accessibility_settings_url() -> String {
+    ax_helpers::accessibility_settings_url().to_string()
+}
+
+/// Emit [`ACCESSIBILITY_PERMISSION_EVENT`] for every status change on
+/// `changes`
+///
+/// Runs until the sending `PermissionMonitor` is dropped.
+pub async fn emit_accessibility_permission_changes<R: Runtime>(
+    app: AppHandle<R>,
+    mut changes: watch::Receiver<PermissionStatus>,
+) {
+    while changes.changed().await.is_ok() {
+        let status = *changes.borrow_and_update();
+        if let Err(err) = app.emit(ACCESSIBILITY_PERMISSION_EVENT, status) {
+            warn!(status = ?status, error = %err, "failed to emit accessibility permission event");
+        }
+    }
 }
 
 /// Pause activity tracking
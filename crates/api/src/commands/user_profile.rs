@@ -1,15 +1,23 @@
 //! User profile commands for Phase 4A.2 migration
 //!
-//! These commands provide user profile management (get and upsert operations).
+//! These commands provide user profile management (get and upsert operations,
+//! plus workday settings).
 //! All commands support feature flag toggling between new (hexagonal
 //! architecture) and legacy implementations.
 
 use std::sync::Arc;
 use std::time::Instant;
 
-use pulsearc_domain::{PulseArcError, Result as DomainResult, UserProfile};
+use pulsearc_core::user::{
+    import_configuration as import_bundle, reset_settings_to_defaults as reset_settings,
+    set_setting, validate_user_profile, validation_error_to_domain, WorkdaySchedule, USER_SCOPE,
+};
+use pulsearc_domain::{
+    ConfigBundle, PulseArcError, Result as DomainResult, SettingsResetSummary, UserProfile,
+    WorkdayConfig,
+};
 use tauri::State;
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::context::AppContext;
 use crate::utils::logging::{log_command_execution, record_command_metric, MetricRecord};
@@ -92,7 +100,8 @@ pub async fn legacy_get_user_profile(ctx: &AppContext) -> DomainResult<Option<Us
             "SELECT id, auth0_id, email, org_id, name, first_name, last_name, display_name,
                     avatar_url, phone_number, title, department, location, bio,
                     timezone, language, locale, date_format, is_active, email_verified,
-                    two_factor_enabled, last_login_at, last_synced_at, created_at, updated_at
+                    two_factor_enabled, last_login_at, last_synced_at, created_at, updated_at,
+                    workday_start_minutes, workday_end_minutes, working_days
              FROM user_profiles
              ORDER BY created_at ASC
              LIMIT 1",
@@ -124,6 +133,11 @@ pub async fn legacy_get_user_profile(ctx: &AppContext) -> DomainResult<Option<Us
                     last_synced_at: row.get(22)?,
                     created_at: row.get(23)?,
                     updated_at: row.get(24)?,
+                    workday: WorkdayConfig {
+                        start_minutes: row.get(25)?,
+                        end_minutes: row.get(26)?,
+                        working_days: WorkdayConfig::parse_working_days(&row.get::<_, String>(27)?),
+                    },
                 })
             },
         );
@@ -220,6 +234,7 @@ pub async fn legacy_upsert_user_profile(
     // Legacy implementation using ON CONFLICT(auth0_id) directly in SQL
     // This matches the original legacy behavior from
     // legacy/api/src/domain/user_profile.rs:109
+    validate_user_profile(&profile).map_err(|err| validation_error_to_domain(&err))?;
     let db = ctx.db.clone();
 
     tokio::task::spawn_blocking(move || -> DomainResult<()> {
@@ -227,14 +242,17 @@ pub async fn legacy_upsert_user_profile(
             .get_connection()
             .map_err(|e| PulseArcError::Database(format!("Failed to get connection: {}", e)))?;
 
-        // Use INSERT ... ON CONFLICT(auth0_id) DO UPDATE (matches legacy exactly)
+        // Use INSERT ... ON CONFLICT(auth0_id) DO UPDATE (matches legacy exactly).
+        // Workday settings are only written for new profiles.
+        let working_days = profile.workday.working_days_csv();
         conn.execute(
             "INSERT INTO user_profiles (
                 id, auth0_id, email, org_id, name, first_name, last_name, display_name,
                 avatar_url, phone_number, title, department, location, bio,
                 timezone, language, locale, date_format, is_active, email_verified,
-                two_factor_enabled, last_login_at, last_synced_at, created_at, updated_at
-             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25)
+                two_factor_enabled, last_login_at, last_synced_at, created_at, updated_at,
+                workday_start_minutes, workday_end_minutes, working_days
+             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28)
              ON CONFLICT(auth0_id) DO UPDATE SET
                 id = excluded.id,
                 email = excluded.email,
@@ -285,6 +303,9 @@ pub async fn legacy_upsert_user_profile(
                 &profile.last_synced_at,
                 &profile.created_at,
                 &profile.updated_at,
+                &profile.workday.start_minutes,
+                &profile.workday.end_minutes,
+                &working_days,
             ],
         )
         .map_err(|e| PulseArcError::Database(format!("Failed to upsert profile: {}", e)))?;
@@ -295,6 +316,245 @@ pub async fn legacy_upsert_user_profile(
     .map_err(|e| PulseArcError::Internal(format!("spawn_blocking failed: {}", e)))?
 }
 
+// =============================================================================
+// Command 3: update_workday_settings
+// =============================================================================
+
+/// Update the current user's working hours and timezone.
+///
+/// The repository validates the config against the timezone before saving.
+/// Returns the updated profile.
+#[tauri::command]
+pub async fn update_workday_settings(
+    ctx: State<'_, Arc<AppContext>>,
+    workday: WorkdayConfig,
+    timezone: Option<String>,
+) -> Result<UserProfile, String> {
+    let command_name = "user_profile::update_workday_settings";
+    let implementation = "new";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(command = command_name, implementation, "Executing update_workday_settings");
+
+    let result = new_update_workday_settings(&app_ctx, workday, timezone).await;
+
+    let success = result.is_ok();
+    let elapsed = start.elapsed();
+    let error_label = result.as_ref().err().map(|e| format!("{:?}", e));
+    log_command_execution(command_name, implementation, elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation,
+            elapsed,
+            success,
+            error_type: error_label.as_deref(),
+        },
+    )
+    .await;
+
+    result.map_err(|e| e.to_string())
+}
+
+/// Implementation of `update_workday_settings` (public for integration tests)
+pub async fn new_update_workday_settings(
+    ctx: &AppContext,
+    workday: WorkdayConfig,
+    timezone: Option<String>,
+) -> DomainResult<UserProfile> {
+    let mut profile = ctx
+        .user_profile
+        .get_current_profile()
+        .await?
+        .ok_or_else(|| PulseArcError::NotFound("No user profile found".into()))?;
+
+    if let Some(timezone) = timezone {
+        profile.timezone = timezone.trim().to_string();
+    }
+    profile.workday = workday;
+    profile.updated_at = chrono::Utc::now().timestamp();
+    ctx.user_profile.update(profile.clone()).await?;
+
+    // The profile stays the source of truth; the store keeps the history
+    set_setting(ctx.user_settings.as_ref(), USER_SCOPE, &profile.workday).await?;
+
+    Ok(profile)
+}
+
+// =============================================================================
+// Command 4: reset_settings_to_defaults
+// =============================================================================
+
+/// Reset idle, workday and calendar sync settings and feature flag overrides
+/// to their defaults.
+///
+/// Destructive for settings, so `confirm` must be `true`. Tracked data
+/// (snapshots, entries, blocks) is not touched. Each changed setting is
+/// recorded in the settings history.
+#[tauri::command]
+pub async fn reset_settings_to_defaults(
+    ctx: State<'_, Arc<AppContext>>,
+    confirm: bool,
+) -> Result<SettingsResetSummary, String> {
+    let command_name = "user_profile::reset_settings_to_defaults";
+    let implementation = "new";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(command = command_name, implementation, confirm, "Executing reset_settings_to_defaults");
+
+    let result = new_reset_settings_to_defaults(&app_ctx, confirm).await;
+
+    let success = result.is_ok();
+    let elapsed = start.elapsed();
+    let error_label = result.as_ref().err().map(|e| format!("{:?}", e));
+    log_command_execution(command_name, implementation, elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation,
+            elapsed,
+            success,
+            error_type: error_label.as_deref(),
+        },
+    )
+    .await;
+
+    result.map_err(|e| e.to_string())
+}
+
+/// Implementation of `reset_settings_to_defaults` (public for integration
+/// tests)
+pub async fn new_reset_settings_to_defaults(
+    ctx: &AppContext,
+    confirm: bool,
+) -> DomainResult<SettingsResetSummary> {
+    if !confirm {
+        return Err(PulseArcError::InvalidInput("resetting settings must be confirmed".into()));
+    }
+
+    let summary = reset_settings(ctx.user_settings.as_ref()).await?;
+    ctx.feature_flags.refresh().await?;
+    info!(
+        settings_changed = summary.settings_changed,
+        calendar_accounts = summary.calendar_accounts,
+        feature_flags = summary.feature_flags,
+        "Settings reset to defaults"
+    );
+    Ok(summary)
+}
+
+// =============================================================================
+// Command 5: export_configuration
+// =============================================================================
+
+/// Export the profile, settings, feature flag overrides and calendar accounts
+/// as a versioned bundle.
+///
+/// Secrets stay in the keychain and are not included.
+#[tauri::command]
+pub async fn export_configuration(ctx: State<'_, Arc<AppContext>>) -> Result<ConfigBundle, String> {
+    let command_name = "user_profile::export_configuration";
+    let implementation = "new";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(command = command_name, implementation, "Executing export_configuration");
+
+    let result = app_ctx.configuration.export_configuration().await;
+
+    let success = result.is_ok();
+    let elapsed = start.elapsed();
+    let error_label = result.as_ref().err().map(|e| format!("{:?}", e));
+    log_command_execution(command_name, implementation, elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation,
+            elapsed,
+            success,
+            error_type: error_label.as_deref(),
+        },
+    )
+    .await;
+
+    result.map_err(|e| e.to_string())
+}
+
+// =============================================================================
+// Command 6: import_configuration
+// =============================================================================
+
+/// Import a bundle produced by `export_configuration`.
+///
+/// Bundles of another version or with invalid contents are rejected before
+/// anything is written. Calendar accounts must be reconnected afterwards.
+#[tauri::command]
+pub async fn import_configuration(
+    ctx: State<'_, Arc<AppContext>>,
+    bundle: ConfigBundle,
+) -> Result<(), String> {
+    let command_name = "user_profile::import_configuration";
+    let implementation = "new";
+    let start = Instant::now();
+    let app_ctx = Arc::clone(ctx.inner());
+
+    info!(
+        command = command_name,
+        implementation,
+        version = bundle.version,
+        "Executing import_configuration"
+    );
+
+    let result = new_import_configuration(&app_ctx, &bundle).await;
+
+    let success = result.is_ok();
+    let elapsed = start.elapsed();
+    let error_label = result.as_ref().err().map(|e| format!("{:?}", e));
+    log_command_execution(command_name, implementation, elapsed, success);
+    record_command_metric(
+        &app_ctx,
+        MetricRecord {
+            command: command_name,
+            implementation,
+            elapsed,
+            success,
+            error_type: error_label.as_deref(),
+        },
+    )
+    .await;
+
+    result.map_err(|e| e.to_string())
+}
+
+/// Implementation of `import_configuration` (public for integration tests)
+pub async fn new_import_configuration(ctx: &AppContext, bundle: &ConfigBundle) -> DomainResult<()> {
+    import_bundle(ctx.configuration.as_ref(), bundle).await?;
+    ctx.feature_flags.refresh().await
+}
+
+/// Working hours of the current user
+///
+/// Falls back to the default schedule (08:00-18:00 Monday-Friday, UTC) when
+/// no profile exists or its settings cannot be used.
+pub(crate) async fn current_workday_schedule(ctx: &AppContext) -> WorkdaySchedule {
+    match ctx.user_profile.get_current_profile().await {
+        Ok(Some(profile)) => WorkdaySchedule::from_profile(&profile).unwrap_or_else(|err| {
+            warn!(error = %err, "Invalid workday settings; using default schedule");
+            WorkdaySchedule::default()
+        }),
+        Ok(None) => WorkdaySchedule::default(),
+        Err(err) => {
+            warn!(error = %err, "Failed to load user profile; using default schedule");
+            WorkdaySchedule::default()
+        }
+    }
+}
+
 // =============================================================================
 // Helper Functions
 // =============================================================================
@@ -6,14 +6,40 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use pulsearc_common::lifecycle::{ShutdownCoordinator, ShutdownReason};
+#[cfg(feature = "audit-compliance")]
+use pulsearc_common::observability::NoOpAuditLogger;
+use pulsearc_common::observability::RecentErrors;
+#[cfg(feature = "sap")]
+use pulsearc_core::batch::ports::DlqRepository;
 use pulsearc_core::classification::ports::BlockRepository as BlockRepositoryPort;
+#[cfg(feature = "heuristic-classifier")]
+use pulsearc_core::classification::HeuristicClassifier;
+use pulsearc_core::classification::WbsSearch;
+use pulsearc_core::classification::{AppCategoryRules, SuggestionSuppressor};
+use pulsearc_core::classification::{ClassificationCorrector, ProjectAutocomplete};
 use pulsearc_core::sync::ports::OutboxQueue as OutboxQueuePort;
 use pulsearc_core::tracking::ports::{
-    IdlePeriodsRepository as IdlePeriodsRepositoryPort, SegmentRepository as SegmentRepositoryPort,
-    SnapshotRepository as SnapshotRepositoryPort,
+    ActivityProvider, IdlePeriodsRepository as IdlePeriodsRepositoryPort,
+    SegmentRepository as SegmentRepositoryPort, SnapshotRepository as SnapshotRepositoryPort,
+};
+use pulsearc_core::tracking::{
+    ActivationCapture, DwellConfig, PermissionMonitor, SnapshotDedupConfig,
+    DEFAULT_PERMISSION_RECHECK_INTERVAL,
+};
+#[cfg(feature = "sap")]
+use pulsearc_core::sap_ports::SapClient as SapClientTrait;
+use pulsearc_core::user::ports::{
+    ConfigurationRepository as ConfigurationRepositoryPort,
+    UserProfileRepository as UserProfileRepositoryPort,
+    UserSettingsRepository as UserSettingsRepositoryPort,
+};
+#[cfg(feature = "heuristic-classifier")]
+use pulsearc_core::ClassificationService;
+use pulsearc_core::{
+    CommandMetricsPort, DatabaseStatsPort, FeatureFlagsPort, SnapshotRetentionPolicy,
+    TrackingService,
 };
-use pulsearc_core::user::ports::UserProfileRepository as UserProfileRepositoryPort;
-use pulsearc_core::{CommandMetricsPort, DatabaseStatsPort, FeatureFlagsPort, TrackingService};
 use pulsearc_domain::types::{ActivitySegment, ActivitySnapshot};
 use pulsearc_domain::{Config, PulseArcError, Result};
 use pulsearc_infra::api::{AccessTokenProvider, ApiClientConfig, ApiError, ForwarderConfig};
@@ -23,6 +49,18 @@ use pulsearc_infra::calendar::{
 };
 #[cfg(feature = "calendar")]
 use pulsearc_infra::database::SqlCipherCalendarEventRepository;
+#[cfg(feature = "heuristic-classifier")]
+use pulsearc_infra::database::SqliteTimeEntryRepository;
+#[cfg(feature = "sap")]
+use pulsearc_infra::database::SqlCipherDlqRepository;
+#[cfg(feature = "sap")]
+use pulsearc_infra::integrations::sap::BatchForwarder;
+#[cfg(feature = "audit-compliance")]
+use pulsearc_infra::mdm::{
+    ComplianceScheduler, MdmConfig, PolicyEnforcer, SystemComplianceContext,
+    DEFAULT_COMPLIANCE_INTERVAL,
+};
+use pulsearc_infra::observability::collector::PerformanceMetricsCollector;
 use pulsearc_infra::observability::metrics::PerformanceMetrics;
 use pulsearc_infra::scheduling::block_scheduler::BlockJob;
 use pulsearc_infra::scheduling::classification_scheduler::ClassificationJob;
@@ -33,12 +71,20 @@ use pulsearc_infra::scheduling::sync_scheduler::{
 use pulsearc_infra::CalendarScheduler;
 use pulsearc_infra::{
     ApiClient, ApiCommands, ApiForwarder, BlockScheduler, BlockSchedulerConfig,
-    ClassificationScheduler, ClassificationSchedulerConfig, DbManager, FeatureFlagService,
-    InfraError, InstanceLock, KeyManager, MacOsActivityProvider, SqlCipherActivityRepository,
-    SqlCipherBlockRepository, SqlCipherCommandMetricsRepository, SqlCipherDatabaseStatsRepository,
-    SqlCipherIdlePeriodsRepository, SqlCipherOutboxRepository, SqlCipherSegmentRepository,
-    SqlCipherUserProfileRepository, SyncScheduler, SyncSchedulerConfig,
+    ClassificationScheduler, ClassificationSchedulerConfig, DbManager, DbTimeoutConfig,
+    FeatureFlagService, InfraError, InstanceLock, KeyManager, MacOsActivityProvider,
+    MacOsEventListener, NeonClient, OsEventListener, OutboxWorker, OutboxWorkerConfig,
+    SqlCipherActivityRepository, SqlCipherAppCategoryRuleRepository, SqlCipherBlockRepository,
+    SqlCipherCommandMetricsRepository, SqlCipherConfigurationRepository,
+    SqlCipherDatabaseStatsRepository, SqlCipherIdlePeriodsRepository, SqlCipherOutboxRepository,
+    SqlCipherProjectAcceptanceRepository, SqlCipherSegmentRepository,
+    SqlCipherSuggestionDismissalRepository, SqlCipherUserProfileRepository,
+    SqlCipherUserSettingsRepository, SqlCipherWbsRepository, SyncScheduler, SyncSchedulerConfig,
+    VacuumScheduler, VacuumSchedulerConfig, WalCheckpointPolicy, WalCheckpointer,
 };
+use tokio_util::sync::CancellationToken;
+
+use crate::adapters::blocks::OutboxBlockSyncQueue;
 
 /// Type alias for database stats port trait object
 type DynDatabaseStatsPort = dyn DatabaseStatsPort + Send + Sync + 'static;
@@ -55,6 +101,12 @@ type DynSnapshotRepositoryPort = dyn SnapshotRepositoryPort + Send + Sync + 'sta
 /// Type alias for user profile repository port trait object
 type DynUserProfileRepositoryPort = dyn UserProfileRepositoryPort + Send + Sync + 'static;
 
+/// Type alias for user settings repository port trait object
+type DynUserSettingsRepositoryPort = dyn UserSettingsRepositoryPort + Send + Sync + 'static;
+
+/// Type alias for configuration export/import port trait object
+type DynConfigurationRepositoryPort = dyn ConfigurationRepositoryPort + Send + Sync + 'static;
+
 /// Type alias for block repository port trait object
 type DynBlockRepositoryPort = dyn BlockRepositoryPort + Send + Sync + 'static;
 
@@ -67,26 +119,62 @@ type DynOutboxQueuePort = dyn OutboxQueuePort + Send + Sync + 'static;
 /// Type alias for idle periods repository port trait object
 type DynIdlePeriodsRepositoryPort = dyn IdlePeriodsRepositoryPort + Send + Sync + 'static;
 
+#[cfg(test)]
+pub(crate) mod testing;
+
 /// Application context - holds all services and dependencies
 pub struct AppContext {
     // Core services
     pub config: Config,
     pub db: Arc<DbManager>,
     pub tracking_service: Arc<TrackingService>,
+    // Accessibility permission status, re-checked in the background
+    pub accessibility_permission: Arc<PermissionMonitor>,
+    // NSWorkspace observer feeding `ActivationCapture`; dropping it closes the
+    // activation channel, which ends the capture loop
+    _app_activation_listener: MacOsEventListener,
     pub feature_flags: Arc<DynFeatureFlagsPort>,
     pub database_stats: Arc<DynDatabaseStatsPort>,
     pub command_metrics: Arc<DynCommandMetricsPort>,
     pub snapshots: Arc<DynSnapshotRepositoryPort>,
     pub user_profile: Arc<DynUserProfileRepositoryPort>,
+    pub user_settings: Arc<DynUserSettingsRepositoryPort>,
+    pub configuration: Arc<DynConfigurationRepositoryPort>,
     pub block_repository: Arc<DynBlockRepositoryPort>,
     pub segment_repository: Arc<DynSegmentRepositoryPort>,
     pub outbox_queue: Arc<DynOutboxQueuePort>,
     pub idle_periods: Arc<DynIdlePeriodsRepositoryPort>,
+    pub suggestion_suppressor: Arc<SuggestionSuppressor>,
+
+    // App category rules; user overrides applied to captures before defaults
+    pub app_category_rules: Arc<AppCategoryRules>,
+
+    // Applies user corrections to blocks and learns from them
+    pub classification_corrector: Arc<ClassificationCorrector>,
+
+    // Project code autocomplete; refreshed from the project list on each query
+    pub project_autocomplete: Arc<tokio::sync::RwLock<ProjectAutocomplete>>,
+
+    // Fuzzy search over the cached WBS elements
+    pub wbs_search: Arc<WbsSearch>,
+
+    // Metrics emitted by core services, bridged into `PerformanceMetrics`
+    pub service_metrics: Arc<PerformanceMetricsCollector>,
+
+    // Offline classification of snapshot blocks into time entries
+    #[cfg(feature = "heuristic-classifier")]
+    pub classification_service: Arc<ClassificationService>,
 
     // Schedulers (Phase 4.1.2: Added for command migration)
     pub block_scheduler: Arc<BlockScheduler>,
     pub classification_scheduler: Arc<ClassificationScheduler>,
     pub sync_scheduler: Arc<SyncScheduler>,
+    pub vacuum_scheduler: Arc<VacuumScheduler>,
+    pub wal_checkpointer: Arc<WalCheckpointer>,
+
+    // Outbox forwarding to Neon; `None` unless enabled in config, or when
+    // the worker failed to start
+    pub outbox_worker: Option<Arc<OutboxWorker>>,
 
     #[cfg(feature = "calendar")]
     pub calendar_scheduler: Arc<CalendarScheduler>,
@@ -98,6 +186,28 @@ pub struct AppContext {
     #[cfg(feature = "calendar")]
     pub calendar_events: Arc<dyn pulsearc_core::tracking::ports::CalendarEventRepository>,
 
+    // SAP dry-run validation; `None` until the SAP client is wired
+    #[cfg(feature = "sap")]
+    pub sap_validator: Option<Arc<BatchForwarder>>,
+
+    // Dead letter queue for SAP entries that fail permanently
+    #[cfg(feature = "sap")]
+    pub sap_dlq: Arc<dyn DlqRepository>,
+
+    // MDM policy, shared so an updated config applies to the next compliance
+    // evaluation
+    #[cfg(feature = "audit-compliance")]
+    pub mdm_config: Arc<tokio::sync::RwLock<MdmConfig>>,
+
+    // Periodic MDM compliance evaluation; the UI follows `subscribe()`
+    #[cfg(feature = "audit-compliance")]
+    pub compliance_scheduler: Arc<ComplianceScheduler>,
+
+    // Enforcement actions of failing MDM rules; sync and the outbox worker
+    // pause while it blocks sync
+    #[cfg(feature = "audit-compliance")]
+    pub policy_enforcer: Arc<PolicyEnforcer>,
+
     // TODO(Phase 4): Add ML infrastructure when Phase 3E is completed
     // #[cfg(feature = "tree-classifier")]
     // pub hybrid_classifier: Arc<HybridClassifier>,
@@ -107,6 +217,12 @@ pub struct AppContext {
     // Telemetry metrics for idle sync (Phase 4C.2)
     pub idle_sync_metrics: Arc<crate::utils::idle_sync_metrics::IdleSyncMetrics>,
 
+    // Last N command failures, surfaced to support via `get_recent_errors`
+    pub recent_errors: Arc<RecentErrors>,
+
+    // Components told why the app is stopping (drain vs skip) on shutdown
+    pub shutdown_coordinator: Arc<ShutdownCoordinator>,
+
     // Keep instance lock alive for the lifetime of the app
     _instance_lock: InstanceLock,
 }
@@ -168,8 +284,11 @@ async fn create_classification_scheduler() -> Result<Arc<ClassificationScheduler
     Ok(Arc::new(scheduler))
 }
 
-async fn create_sync_scheduler(config: &Config) -> Result<Arc<SyncScheduler>> {
-    let forwarder = build_api_forwarder()?;
+async fn create_sync_scheduler(
+    config: &Config,
+    forwarder: Arc<ApiForwarder>,
+    sync_blocked: Option<tokio::sync::watch::Receiver<bool>>,
+) -> Result<Arc<SyncScheduler>> {
     let segment_repo: Arc<dyn ActivitySegmentRepository> = Arc::new(EmptySegmentRepository);
     let snapshot_repo: Arc<dyn ActivitySnapshotRepository> = Arc::new(EmptySnapshotRepository);
 
@@ -181,6 +300,9 @@ async fn create_sync_scheduler(config: &Config) -> Result<Arc<SyncScheduler>> {
     let metrics = Arc::new(PerformanceMetrics::new());
     let mut scheduler =
         SyncScheduler::new(forwarder, segment_repo, snapshot_repo, scheduler_config, metrics);
+    if let Some(sync_blocked) = sync_blocked {
+        scheduler = scheduler.with_sync_blocked(sync_blocked);
+    }
 
     // Start the scheduler with timeout (fail-fast initialization)
     let start_timeout = Duration::from_secs(10);
@@ -198,6 +320,109 @@ async fn create_sync_scheduler(config: &Config) -> Result<Arc<SyncScheduler>> {
     Ok(Arc::new(scheduler))
 }
 
+async fn create_vacuum_scheduler(
+    database_stats: Arc<DynDatabaseStatsPort>,
+) -> Result<Arc<VacuumScheduler>> {
+    let metrics = Arc::new(PerformanceMetrics::new());
+    let config = VacuumSchedulerConfig::default();
+
+    let mut scheduler =
+        VacuumScheduler::with_config(config, database_stats, metrics).map_err(|err| {
+            tracing::error!(error = %err, "failed to construct VacuumScheduler");
+            PulseArcError::Internal(format!("failed to construct VacuumScheduler: {}", err))
+        })?;
+
+    // Start the scheduler with timeout (fail-fast initialization)
+    let start_timeout = Duration::from_secs(10);
+    tokio::time::timeout(start_timeout, scheduler.start())
+        .await
+        .map_err(|_| {
+            tracing::error!(timeout_secs = 10, "VacuumScheduler start timed out");
+            PulseArcError::Internal("VacuumScheduler start timed out after 10s".into())
+        })?
+        .map_err(|err| {
+            tracing::error!(error = %err, "failed to start VacuumScheduler");
+            PulseArcError::Internal(format!("failed to start VacuumScheduler: {}", err))
+        })?;
+
+    Ok(Arc::new(scheduler))
+}
+
+/// Start the Neon outbox worker when `sync.outbox_worker_enabled` is set.
+///
+/// The worker is optional: a construction or start failure is logged and
+/// the app runs without it instead of failing to start.
+async fn create_outbox_worker(
+    config: &Config,
+    outbox_queue: Arc<DynOutboxQueuePort>,
+    sync_blocked: Option<tokio::sync::watch::Receiver<bool>>,
+) -> Option<Arc<OutboxWorker>> {
+    if !config.sync.enabled || !config.sync.outbox_worker_enabled {
+        tracing::info!(
+            sync_enabled = config.sync.enabled,
+            outbox_worker_enabled = config.sync.outbox_worker_enabled,
+            "Outbox worker not enabled; not started"
+        );
+        return None;
+    }
+
+    let metrics = Arc::new(PerformanceMetrics::new());
+    let neon_client = match NeonClient::new() {
+        Ok(client) => client.with_metrics(Arc::clone(&metrics)),
+        Err(err) => {
+            tracing::error!(error = %err, "failed to construct NeonClient; outbox worker disabled");
+            return None;
+        }
+    };
+    let mut worker = OutboxWorker::new(
+        outbox_queue,
+        Arc::new(neon_client),
+        OutboxWorkerConfig::default(),
+        metrics,
+    );
+    if let Some(sync_blocked) = sync_blocked {
+        worker = worker.with_sync_blocked(sync_blocked);
+    }
+
+    let start_timeout = Duration::from_secs(10);
+    match tokio::time::timeout(start_timeout, worker.start()).await {
+        Ok(Ok(())) => Some(Arc::new(worker)),
+        Ok(Err(err)) => {
+            tracing::error!(error = %err, "failed to start OutboxWorker; outbox worker disabled");
+            None
+        }
+        Err(_) => {
+            tracing::error!(
+                timeout_secs = 10,
+                "OutboxWorker start timed out; outbox worker disabled"
+            );
+            None
+        }
+    }
+}
+
+/// Evaluate MDM compliance now and every [`DEFAULT_COMPLIANCE_INTERVAL`],
+/// enforcing failing rules through `enforcer`.
+///
+/// The evaluation task stops when the returned scheduler is dropped.
+#[cfg(feature = "audit-compliance")]
+fn create_compliance_scheduler(
+    mdm_config: Arc<tokio::sync::RwLock<MdmConfig>>,
+    enforcer: Arc<PolicyEnforcer>,
+) -> Arc<ComplianceScheduler> {
+    // No audit sink is wired yet; failed rules are still logged via tracing
+    let scheduler = Arc::new(
+        ComplianceScheduler::new(
+            mdm_config,
+            Arc::new(SystemComplianceContext),
+            Arc::new(NoOpAuditLogger),
+        )
+        .with_enforcer(enforcer),
+    );
+    scheduler.spawn(DEFAULT_COMPLIANCE_INTERVAL);
+    scheduler
+}
+
 #[cfg(feature = "calendar")]
 async fn create_calendar_scheduler(
     db: Arc<DbManager>,
@@ -234,19 +459,57 @@ async fn create_calendar_scheduler(
         })
 }
 
-fn build_api_forwarder() -> Result<Arc<ApiForwarder>> {
+fn build_api_forwarder(client_config: ApiClientConfig) -> Result<Arc<ApiForwarder>> {
     let token_provider: Arc<dyn AccessTokenProvider> =
         Arc::new(StaticAccessTokenProvider::new("stub-token"));
-    let client =
-        Arc::new(ApiClient::new(ApiClientConfig::default(), token_provider).map_err(|err| {
-            PulseArcError::Internal(format!("failed to construct ApiClient: {}", err))
-        })?);
+    let client = Arc::new(ApiClient::new(client_config, token_provider).map_err(|err| {
+        PulseArcError::Internal(format!("failed to construct ApiClient: {}", err))
+    })?);
     let commands = Arc::new(ApiCommands::new(client));
     let forwarder = ApiForwarder::new(commands, ForwarderConfig::default());
 
     Ok(Arc::new(forwarder))
 }
 
+fn acquire_instance_lock(lock_dir: &Path) -> Result<InstanceLock> {
+    fs::create_dir_all(lock_dir).map_err(|err| {
+        PulseArcError::Internal(format!(
+            "failed to create instance lock directory {}: {}",
+            lock_dir.display(),
+            err
+        ))
+    })?;
+
+    // Acquire instance lock to prevent multiple instances
+    InstanceLock::acquire(lock_dir)
+}
+
+/// Resolve the database encryption key with a test-friendly fallback chain:
+/// 1. TEST_DATABASE_ENCRYPTION_KEY (for tests, doesn't touch keychain)
+/// 2. DATABASE_ENCRYPTION_KEY (for production override)
+/// 3. KeyManager (production default, uses macOS keychain)
+fn resolve_encryption_key() -> Result<String> {
+    match std::env::var("TEST_DATABASE_ENCRYPTION_KEY") {
+        Ok(value) => {
+            tracing::debug!("using TEST_DATABASE_ENCRYPTION_KEY for database encryption");
+            Ok(value)
+        }
+        Err(_) => match std::env::var("DATABASE_ENCRYPTION_KEY") {
+            Ok(value) => {
+                tracing::info!("using DATABASE_ENCRYPTION_KEY for database encryption");
+                Ok(value)
+            }
+            Err(_) => {
+                tracing::info!("fetching encryption key from macOS keychain");
+                KeyManager::get_or_create_key().map_err(|e| {
+                    tracing::error!(error = %e, "failed to retrieve encryption key from keychain");
+                    e
+                })
+            }
+        },
+    }
+}
+
 #[derive(Default)]
 struct NoopBlockJob;
 
@@ -365,65 +628,108 @@ impl AppContext {
     where
         P: AsRef<Path>,
     {
-        let lock_dir_path = lock_dir.as_ref().to_path_buf();
-
-        fs::create_dir_all(&lock_dir_path).map_err(|err| {
-            PulseArcError::Internal(format!(
-                "failed to create instance lock directory {}: {}",
-                lock_dir_path.display(),
-                err
-            ))
-        })?;
+        let instance_lock = acquire_instance_lock(lock_dir.as_ref())?;
+        let encryption_key = resolve_encryption_key()?;
+        let forwarder = build_api_forwarder(ApiClientConfig::default())?;
 
-        // Acquire instance lock to prevent multiple instances
-        let instance_lock = InstanceLock::acquire(&lock_dir_path)?;
+        Self::build(config, instance_lock, &encryption_key, MacOsActivityProvider::new(), forwarder)
+            .await
+    }
 
-        // Resolve encryption key with test-friendly fallback chain:
-        // 1. TEST_DATABASE_ENCRYPTION_KEY (for tests, doesn't touch keychain)
-        // 2. DATABASE_ENCRYPTION_KEY (for production override)
-        // 3. KeyManager (production default, uses macOS keychain)
-        let encryption_key = match std::env::var("TEST_DATABASE_ENCRYPTION_KEY") {
-            Ok(value) => {
-                tracing::debug!("using TEST_DATABASE_ENCRYPTION_KEY for database encryption");
-                value
-            }
-            Err(_) => match std::env::var("DATABASE_ENCRYPTION_KEY") {
-                Ok(value) => {
-                    tracing::info!("using DATABASE_ENCRYPTION_KEY for database encryption");
-                    value
-                }
-                Err(_) => {
-                    tracing::info!("fetching encryption key from macOS keychain");
-                    KeyManager::get_or_create_key().map_err(|e| {
-                        tracing::error!(error = %e, "failed to retrieve encryption key from keychain");
-                        e
-                    })?
-                }
-            },
-        };
+    /// Wire all services around an already-acquired instance lock
+    ///
+    /// The encryption key, activity provider and API forwarder are injected so
+    /// tests can substitute hermetic stand-ins (see `context::testing`).
+    async fn build<A>(
+        config: Config,
+        instance_lock: InstanceLock,
+        encryption_key: &str,
+        provider: A,
+        forwarder: Arc<ApiForwarder>,
+    ) -> Result<Self>
+    where
+        A: ActivityProvider + 'static,
+    {
+        config
+            .costs
+            .validate()
+            .map_err(|err| PulseArcError::Config(format!("invalid cost rates: {err}")))?;
 
         // Initialize database with encryption
-        let db = Arc::new(DbManager::new(
+        let db = Arc::new(DbManager::with_timeouts(
             &config.database.path,
             config.database.pool_size,
-            Some(encryption_key.as_str()),
+            Some(encryption_key),
+            DbTimeoutConfig::from_database_config(&config.database),
         )?);
 
         // Run migrations
         db.run_migrations()?;
 
-        // Initialize activity provider
-        let provider = MacOsActivityProvider::new();
+        // Truncate the WAL in the background so it cannot grow unbounded
+        let wal_checkpointer =
+            Arc::new(WalCheckpointer::start(&db, WalCheckpointPolicy::default()));
 
         // Initialize activity repository
-        let repository = Arc::new(SqlCipherActivityRepository::new(db.clone()));
+        let dedup = SnapshotDedupConfig::from_tracking_config(&config.tracking);
+        let repository =
+            Arc::new(SqlCipherActivityRepository::new(db.clone()).with_deduplication(dedup));
+
+        // Core services report through the trait so core stays infra-free
+        let service_metrics =
+            Arc::new(PerformanceMetricsCollector::new(Arc::new(PerformanceMetrics::new())));
+
+        // App category rules categorize captures by app at capture time
+        let app_category_rules = Arc::new(AppCategoryRules::new(Arc::new(
+            SqlCipherAppCategoryRuleRepository::new(db.clone()),
+        )));
 
         // Create tracking service
-        let tracking_service = Arc::new(TrackingService::new(provider, repository.clone()));
+        let mut tracking_service = TrackingService::new(provider, repository.clone())
+            .with_enricher(app_category_rules.clone())
+            .with_deduplication(dedup)
+            .with_metrics(service_metrics.clone());
+        if let Some(policy) = SnapshotRetentionPolicy::from_tracking_config(&config.tracking) {
+            tracking_service = tracking_service.with_retention(policy);
+        }
+        if let Some(dwell) = DwellConfig::from_tracking_config(&config.tracking) {
+            tracking_service = tracking_service.with_min_dwell(dwell);
+        }
+        let tracking_service = Arc::new(tracking_service);
+
+        // Watch the provider's Accessibility permission so the UI can prompt
+        let accessibility_permission =
+            Arc::new(PermissionMonitor::new(tracking_service.clone()).await);
+        accessibility_permission.spawn(DEFAULT_PERMISSION_RECHECK_INTERVAL);
+
+        // Capture on app switches too, so switches shorter than the poll
+        // period are not missed
+        let mut app_activation_listener = MacOsEventListener::new();
+        match app_activation_listener.subscribe() {
+            Ok(activations) => {
+                let capture = ActivationCapture::new(
+                    tracking_service.clone(),
+                    activations,
+                    CancellationToken::new(),
+                );
+                tokio::spawn(capture.run());
+            }
+            Err(err) => {
+                tracing::warn!(error = %err, "app activation events unavailable; polling only");
+            }
+        }
 
         // Create feature flags service (cached implementation of FeatureFlagsPort)
         let feature_flags: Arc<DynFeatureFlagsPort> = Arc::new(FeatureFlagService::new(db.clone()));
 
+        // MDM enforcement acts on compliance results; sync follows its block
+        #[cfg(feature = "audit-compliance")]
+        let policy_enforcer = Arc::new(PolicyEnforcer::new(feature_flags.clone()));
+        #[cfg(feature = "audit-compliance")]
+        let sync_blocked = Some(policy_enforcer.subscribe_sync_blocked());
+        #[cfg(not(feature = "audit-compliance"))]
+        let sync_blocked = None;
+
         // Create database stats repository
         let database_stats = Arc::new(SqlCipherDatabaseStatsRepository::new(db.clone()));
 
@@ -438,6 +744,14 @@ impl AppContext {
         let user_profile: Arc<DynUserProfileRepositoryPort> =
             Arc::new(SqlCipherUserProfileRepository::new(db.clone()));
 
+        // Create user settings store (versioned settings with change history)
+        let user_settings: Arc<DynUserSettingsRepositoryPort> =
+            Arc::new(SqlCipherUserSettingsRepository::new(db.clone()));
+
+        // Create configuration export/import repository
+        let configuration: Arc<DynConfigurationRepositoryPort> =
+            Arc::new(SqlCipherConfigurationRepository::new(db.clone()));
+
         // Create block repository (Phase 4B.1 preparation)
         let block_repository: Arc<DynBlockRepositoryPort> =
             Arc::new(SqlCipherBlockRepository::new(db.clone()));
@@ -454,10 +768,33 @@ impl AppContext {
         let idle_periods: Arc<DynIdlePeriodsRepositoryPort> =
             Arc::new(SqlCipherIdlePeriodsRepository::new(db.clone()));
 
+        // Create suggestion suppressor (learns from dismissed suggestions)
+        let suggestion_suppressor = Arc::new(SuggestionSuppressor::new(Arc::new(
+            SqlCipherSuggestionDismissalRepository::new(db.clone()),
+        )));
+
+        // Create classification corrector (corrections feed project learning
+        // and suggestion suppression, then sync like accepted blocks)
+        let classification_corrector = Arc::new(ClassificationCorrector::new(
+            block_repository.clone(),
+            snapshots.clone(),
+            Arc::new(SqlCipherProjectAcceptanceRepository::new(db.clone())),
+            suggestion_suppressor.clone(),
+            Arc::new(OutboxBlockSyncQueue::new(user_profile.clone(), outbox_queue.clone())),
+        ));
+
+        // Create fuzzy WBS search over the local SAP cache
+        let wbs_search =
+            Arc::new(WbsSearch::new(Arc::new(SqlCipherWbsRepository::new(db.clone()))));
+
         // Initialize and start schedulers (fail-fast)
         let block_scheduler = create_block_scheduler().await?;
         let classification_scheduler = create_classification_scheduler().await?;
-        let sync_scheduler = create_sync_scheduler(&config).await?;
+        let sync_scheduler =
+            create_sync_scheduler(&config, forwarder, sync_blocked.clone()).await?;
+        let vacuum_scheduler = create_vacuum_scheduler(database_stats.clone()).await?;
+        let outbox_worker =
+            create_outbox_worker(&config, Arc::clone(&outbox_queue), sync_blocked).await;
 
         #[cfg(feature = "calendar")]
         let calendar_scheduler =
@@ -479,32 +816,91 @@ impl AppContext {
             dyn pulsearc_core::tracking::ports::CalendarEventRepository,
         > = Arc::new(SqlCipherCalendarEventRepository::new(Arc::clone(db.pool())));
 
+        // SAP validate-only forwarder (see `sap_validator` field)
+        #[cfg(feature = "sap")]
+        let sap_validator = None;
+
+        // SAP dead letter queue (see `sap_batch_forwarder`)
+        #[cfg(feature = "sap")]
+        let sap_dlq: Arc<dyn DlqRepository> = Arc::new(SqlCipherDlqRepository::new(db.clone()));
+
+        // MDM compliance starts from an empty local policy until one is loaded
+        #[cfg(feature = "audit-compliance")]
+        let mdm_config = Arc::new(tokio::sync::RwLock::new(MdmConfig::new()));
+        #[cfg(feature = "audit-compliance")]
+        let compliance_scheduler =
+            create_compliance_scheduler(Arc::clone(&mdm_config), Arc::clone(&policy_enforcer));
+
+        #[cfg(feature = "heuristic-classifier")]
+        let classification_service = Arc::new(
+            ClassificationService::new(
+                Arc::new(HeuristicClassifier::new()),
+                Arc::new(SqliteTimeEntryRepository::new(Arc::clone(&db))),
+            )
+            .with_metrics(service_metrics.clone()),
+        );
+
         // Initialize idle sync metrics (Phase 4C.2)
         let idle_sync_metrics = Arc::new(crate::utils::idle_sync_metrics::IdleSyncMetrics::new());
+        let recent_errors = Arc::new(RecentErrors::default());
+        let project_autocomplete = Arc::new(tokio::sync::RwLock::new(ProjectAutocomplete::new()));
+
+        // Components that need the shutdown reason (e.g. drain the outbox on quit)
+        let shutdown_coordinator = Arc::new(ShutdownCoordinator::new());
+        if let Some(worker) = &outbox_worker {
+            shutdown_coordinator.register(worker.clone());
+        }
 
         Ok(Self {
             config,
             db,
             tracking_service,
+            accessibility_permission,
+            _app_activation_listener: app_activation_listener,
             feature_flags,
             database_stats,
             command_metrics,
             snapshots,
             user_profile,
+            user_settings,
+            configuration,
             block_repository,
             segment_repository,
             outbox_queue,
             idle_periods,
+            suggestion_suppressor,
+            app_category_rules,
+            classification_corrector,
+            project_autocomplete,
+            wbs_search,
+            service_metrics,
+            #[cfg(feature = "heuristic-classifier")]
+            classification_service,
             block_scheduler,
             classification_scheduler,
             sync_scheduler,
+            vacuum_scheduler,
+            wal_checkpointer,
+            outbox_worker,
             #[cfg(feature = "calendar")]
             calendar_scheduler,
             #[cfg(feature = "calendar")]
             calendar_oauth,
             #[cfg(feature = "calendar")]
             calendar_events,
+            #[cfg(feature = "sap")]
+            sap_validator,
+            #[cfg(feature = "sap")]
+            sap_dlq,
+            #[cfg(feature = "audit-compliance")]
+            mdm_config,
+            #[cfg(feature = "audit-compliance")]
+            compliance_scheduler,
+            #[cfg(feature = "audit-compliance")]
+            policy_enforcer,
             idle_sync_metrics,
+            recent_errors,
+            shutdown_coordinator,
             _instance_lock: instance_lock,
         })
     }
@@ -590,14 +986,36 @@ impl AppContext {
         }
     }
 
+    /// Create a SAP batch forwarder for `client`
+    ///
+    /// Permanent failures (invalid WBS, closed period) are dead-lettered into
+    /// [`AppContext::sap_dlq`] instead of being retried.
+    #[cfg(feature = "sap")]
+    pub fn sap_batch_forwarder(&self, client: Arc<dyn SapClientTrait>) -> BatchForwarder {
+        BatchForwarder::new(client).with_dlq(Arc::clone(&self.sap_dlq))
+    }
+
     /// Shutdown the application context gracefully
     ///
+    /// Equivalent to [`AppContext::shutdown_with_reason`] with
+    /// [`ShutdownReason::UserQuit`].
+    pub async fn shutdown(&self) -> Result<()> {
+        self.shutdown_with_reason(ShutdownReason::UserQuit).await
+    }
+
+    /// Shutdown the application context, telling components why
+    ///
+    /// The reason is recorded on `shutdown_coordinator` and passed to every
+    /// registered hook, so components can drain pending work on a user quit
+    /// or update and skip it on a forced or crash-recovery shutdown. Only the
+    /// first call notifies the hooks.
+    ///
     /// # Implementation Note
     ///
-    /// This method is intentionally a no-op. Most services and schedulers in
-    /// AppContext don't require explicit shutdown because they use
-    /// `tokio::spawn` tasks that are automatically cancelled when the tokio
-    /// runtime shuts down.
+    /// Beyond the hooks this method is intentionally a no-op. Most services
+    /// and schedulers in AppContext don't require explicit shutdown because
+    /// they use `tokio::spawn` tasks that are automatically cancelled when the
+    /// tokio runtime shuts down.
     ///
     /// According to the scheduler lifecycle survey (Phase 0.2), all schedulers
     /// implement:
@@ -627,13 +1045,16 @@ impl AppContext {
     ///
     /// See: docs/SCHEDULER-LIFECYCLE-REFERENCE.md for complete scheduler
     /// lifecycle details
-    pub async fn shutdown(&self) -> Result<()> {
+    pub async fn shutdown_with_reason(&self, reason: ShutdownReason) -> Result<()> {
         use tracing::info;
 
-        info!("shutdown called on AppContext");
+        info!(reason = %reason, "shutdown called on AppContext");
+
+        // Notify components that act on the reason (outbox drain, ...)
+        let reason = self.shutdown_coordinator.shutdown(reason).await;
 
         // Log diagnostic information about component states
-        self.shutdown_diagnostics();
+        self.shutdown_diagnostics(reason);
 
         // NOTE: Explicit scheduler shutdown is not needed. All schedulers use
         // CancellationToken and tokio::spawn tasks that are automatically
@@ -650,12 +1071,16 @@ impl AppContext {
         // - BlockScheduler: No explicit shutdown needed (Drop handles it)
         // - ClassificationScheduler: No explicit shutdown needed (Drop handles it)
         // - SyncScheduler: No explicit shutdown needed (Drop handles it)
+        // - VacuumScheduler: No explicit shutdown needed (Drop handles it)
+        // - WalCheckpointer: No explicit shutdown needed (Drop handles it)
         // - CalendarScheduler: No explicit shutdown needed (Drop handles it)
+        // - ComplianceScheduler: No explicit shutdown needed (Drop handles it)
         // - TrackingService: No shutdown method (stateless)
         // - FeatureFlagService: No shutdown method (stateless)
         //
         // If a service is added in the future that requires explicit cleanup
-        // (e.g., flushing buffers, closing connections), add the call here.
+        // (e.g., flushing buffers, closing connections), register it with
+        // `shutdown_coordinator` so it also receives the shutdown reason.
 
         Ok(())
     }
@@ -665,9 +1090,17 @@ impl AppContext {
     /// This method provides observability during shutdown by logging the
     /// cleanup approach for each component. Useful for debugging shutdown
     /// issues.
-    fn shutdown_diagnostics(&self) {
+    fn shutdown_diagnostics(&self, reason: ShutdownReason) {
         use tracing::info;
 
+        info!(
+            component = "OutboxWorker",
+            cleanup_method =
+                if reason.allows_drain() { "drain via shutdown hook" } else { "skipped" },
+            enabled = self.outbox_worker.is_some(),
+            "sync_cleanup"
+        );
+
         info!(
             component = "BlockScheduler",
             cleanup_method = "Drop (CancellationToken)",
@@ -686,6 +1119,18 @@ impl AppContext {
             "scheduler_cleanup"
         );
 
+        info!(
+            component = "VacuumScheduler",
+            cleanup_method = "Drop (CancellationToken)",
+            "scheduler_cleanup"
+        );
+
+        info!(
+            component = "WalCheckpointer",
+            cleanup_method = "Drop (CancellationToken)",
+            "scheduler_cleanup"
+        );
+
         #[cfg(feature = "calendar")]
         info!(
             component = "CalendarScheduler",
@@ -693,6 +1138,13 @@ impl AppContext {
             "scheduler_cleanup"
         );
 
+        #[cfg(feature = "audit-compliance")]
+        info!(
+            component = "ComplianceScheduler",
+            cleanup_method = "Drop (CancellationToken)",
+            "scheduler_cleanup"
+        );
+
         info!(
             component = "TrackingService",
             cleanup_method = "stateless (no cleanup)",
@@ -712,3 +1164,46 @@ impl AppContext {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use pulsearc_common::lifecycle::ShutdownHook;
+
+    use super::*;
+
+    /// Hook recording every reason it is notified with
+    #[derive(Default)]
+    struct ReasonRecorder {
+        reasons: Mutex<Vec<ShutdownReason>>,
+    }
+
+    #[async_trait]
+    impl ShutdownHook for ReasonRecorder {
+        fn name(&self) -> &str {
+            "reason_recorder"
+        }
+
+        async fn on_shutdown(
+            &self,
+            reason: ShutdownReason,
+        ) -> std::result::Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            self.reasons.lock().unwrap().push(reason);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn shutdown_propagates_and_records_reason() {
+        let (ctx, _temp_dir) = AppContext::new_for_test().await.expect("test context");
+        let recorder = Arc::new(ReasonRecorder::default());
+        ctx.shutdown_coordinator.register(recorder.clone());
+
+        ctx.shutdown_with_reason(ShutdownReason::Forced).await.expect("shutdown succeeds");
+        ctx.shutdown().await.expect("repeated shutdown succeeds");
+
+        assert_eq!(*recorder.reasons.lock().unwrap(), vec![ShutdownReason::Forced]);
+        assert_eq!(ctx.shutdown_coordinator.reason(), Some(ShutdownReason::Forced));
+    }
+}
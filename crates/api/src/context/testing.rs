@@ -0,0 +1,119 @@
+//! Hermetic `AppContext` fixture for command tests
+//!
+//! [`AppContext::new_for_test`] wires the full context against a throwaway
+//! SQLCipher database keyed with a fixed test key, a recorded activity
+//! provider and an API forwarder pointed at an unroutable local address. It
+//! never reads the keychain, the production database or process-wide
+//! environment variables, so tests can build contexts in parallel.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use async_trait::async_trait;
+use pulsearc_common::testing::TempDir;
+use pulsearc_core::tracking::ports::ActivityProvider;
+use pulsearc_domain::{ActivityContext, Config, DatabaseConfig, PulseArcError, Result};
+use pulsearc_infra::api::ApiClientConfig;
+
+use super::{acquire_instance_lock, build_api_forwarder, AppContext};
+
+/// Encryption key used for fixture databases
+pub(crate) const TEST_DATABASE_KEY: &str =
+    "test_key_64_chars_long_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+
+/// Base URL for the fixture forwarder (discard port, nothing listens there)
+const UNROUTABLE_API_BASE_URL: &str = "http://127.0.0.1:9/v1";
+
+/// Activity provider that replays a fixed list of captured contexts
+///
+/// Contexts are returned round-robin; with no recordings every capture fails
+/// the same way the unsupported-platform fallback does.
+pub(crate) struct RecordedActivityProvider {
+    recordings: Vec<ActivityContext>,
+    cursor: AtomicUsize,
+    paused: AtomicBool,
+}
+
+impl RecordedActivityProvider {
+    /// Create a provider replaying `recordings` in order
+    pub(crate) fn new(recordings: Vec<ActivityContext>) -> Self {
+        Self { recordings, cursor: AtomicUsize::new(0), paused: AtomicBool::new(false) }
+    }
+}
+
+#[async_trait]
+impl ActivityProvider for RecordedActivityProvider {
+    async fn get_activity(&self) -> Result<ActivityContext> {
+        if self.recordings.is_empty() {
+            return Err(PulseArcError::Platform("no recorded activity available".to_string()));
+        }
+
+        let index = self.cursor.fetch_add(1, Ordering::Relaxed) % self.recordings.len();
+        Ok(self.recordings[index].clone())
+    }
+
+    fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    fn pause(&mut self) -> Result<()> {
+        self.paused.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn resume(&mut self) -> Result<()> {
+        self.paused.store(false, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+impl AppContext {
+    /// Build a fully wired context backed only by temporary resources
+    ///
+    /// Returns the context together with the temporary directory holding its
+    /// database and instance lock; keep the directory alive for as long as
+    /// the context is used.
+    pub(crate) async fn new_for_test() -> Result<(Self, TempDir)> {
+        Self::new_for_test_with_activity(Vec::new()).await
+    }
+
+    /// Like [`AppContext::new_for_test`], replaying `recordings` from the
+    /// activity provider
+    pub(crate) async fn new_for_test_with_activity(
+        recordings: Vec<ActivityContext>,
+    ) -> Result<(Self, TempDir)> {
+        let temp_dir = TempDir::new("app-context-test").map_err(|err| {
+            PulseArcError::Internal(format!("failed to create test directory: {err}"))
+        })?;
+        let lock_dir = temp_dir.create_dir("lock").map_err(|err| {
+            PulseArcError::Internal(format!("failed to create test lock directory: {err}"))
+        })?;
+
+        let config = Config {
+            database: DatabaseConfig {
+                path: temp_dir.path().join("pulsearc.db").to_string_lossy().to_string(),
+                pool_size: 4,
+                encryption_key: None,
+                busy_timeout_ms: None,
+                query_timeout_ms: None,
+            },
+            ..Config::default()
+        };
+
+        let instance_lock = acquire_instance_lock(&lock_dir)?;
+        let forwarder = build_api_forwarder(ApiClientConfig {
+            base_url: UNROUTABLE_API_BASE_URL.to_string(),
+            ..ApiClientConfig::default()
+        })?;
+
+        let ctx = Self::build(
+            config,
+            instance_lock,
+            TEST_DATABASE_KEY,
+            RecordedActivityProvider::new(recordings),
+            forwarder,
+        )
+        .await?;
+
+        Ok((ctx, temp_dir))
+    }
+}
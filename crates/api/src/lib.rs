@@ -20,5 +20,7 @@ pub mod utils;
 // Re-export for convenience
 pub use commands::*;
 // Re-export feature flag commands explicitly
-pub use commands::{is_feature_enabled, list_feature_flags, toggle_feature_flag};
+pub use commands::{
+    evaluate_feature_flags, is_feature_enabled, list_feature_flags, toggle_feature_flag,
+};
 pub use context::*;
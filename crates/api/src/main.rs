@@ -4,9 +4,10 @@
 
 use std::sync::Arc;
 
+use pulsearc_common::lifecycle::ShutdownReason;
 use pulsearc_lib::AppContext;
 use tauri::window::{Effect, EffectState, EffectsBuilder};
-use tauri::Manager;
+use tauri::{Manager, RunEvent};
 
 /// Type alias for main result to reduce complexity
 type MainResult = Result<(), Box<dyn std::error::Error>>;
@@ -48,6 +49,26 @@ pub fn run() -> MainResult {
             let ctx = tauri::async_runtime::block_on(AppContext::new())?;
             let ctx_arc = Arc::new(ctx);
 
+            // Tell the UI when Accessibility permission is granted or revoked
+            tauri::async_runtime::spawn(pulsearc_lib::emit_accessibility_permission_changes(
+                app.handle().clone(),
+                ctx_arc.accessibility_permission.subscribe(),
+            ));
+
+            // Tell the UI when MDM compliance status changes
+            #[cfg(feature = "audit-compliance")]
+            tauri::async_runtime::spawn(pulsearc_lib::emit_compliance_status_changes(
+                app.handle().clone(),
+                ctx_arc.compliance_scheduler.subscribe(),
+            ));
+
+            // Tell the UI when MDM enforcement changes (mandatory notices)
+            #[cfg(feature = "audit-compliance")]
+            tauri::async_runtime::spawn(pulsearc_lib::emit_enforcement_changes(
+                app.handle().clone(),
+                ctx_arc.policy_enforcer.subscribe(),
+            ));
+
             // Manage feature flags service separately for command access
             app.manage(ctx_arc.feature_flags.clone());
             app.manage(ctx_arc);
@@ -72,28 +93,53 @@ pub fn run() -> MainResult {
             pulsearc_lib::get_activity,
             pulsearc_lib::pause_tracker,
             pulsearc_lib::resume_tracker,
+            pulsearc_lib::get_accessibility_permission,
+            pulsearc_lib::request_accessibility_permission,
+            pulsearc_lib::get_accessibility_settings_url,
             pulsearc_lib::save_time_entry,
             // Projects
             pulsearc_lib::get_user_projects,
+            pulsearc_lib::autocomplete_project,
+            pulsearc_lib::search_wbs,
             // Suggestions & proposed blocks
             pulsearc_lib::get_dismissed_suggestions,
             pulsearc_lib::get_proposed_blocks,
             pulsearc_lib::get_outbox_status,
+            pulsearc_lib::get_outbox_summary,
+            pulsearc_lib::sync_now,
+            pulsearc_lib::get_suggestion_suppressions,
             pulsearc_lib::clear_suggestions,
             pulsearc_lib::delete_suggestion,
             pulsearc_lib::dismiss_suggestion,
             pulsearc_lib::restore_suggestion,
+            pulsearc_lib::restore_suggestions,
+            pulsearc_lib::restore_last_cleared,
             pulsearc_lib::update_suggestion,
             // Block management (Phase 4B.1)
             pulsearc_lib::build_my_day,
             pulsearc_lib::accept_proposed_block,
             pulsearc_lib::dismiss_proposed_block,
+            pulsearc_lib::detect_entry_overlaps,
+            pulsearc_lib::detect_untracked_gaps,
+            pulsearc_lib::regenerate_blocks_now,
+            pulsearc_lib::explain_classification,
+            pulsearc_lib::correct_classification,
+            // App category rules
+            pulsearc_lib::list_app_category_rules,
+            pulsearc_lib::add_app_category_rule,
+            pulsearc_lib::remove_app_category_rule,
+            pulsearc_lib::import_app_category_rules,
+            // Reports
+            pulsearc_lib::get_daily_summary,
+            pulsearc_lib::export_daily_summary,
+            pulsearc_lib::export_training_data,
             // Calendar integration (Phase 4B.2)
             pulsearc_lib::initiate_calendar_auth,
             pulsearc_lib::disconnect_calendar,
             pulsearc_lib::get_calendar_connection_status,
             pulsearc_lib::sync_calendar_events,
             pulsearc_lib::get_calendar_events_for_timeline,
+            pulsearc_lib::get_timeline,
             pulsearc_lib::get_calendar_sync_settings,
             pulsearc_lib::update_calendar_sync_settings,
             // Database commands (Phase 4A.1)
@@ -102,15 +148,26 @@ pub fn run() -> MainResult {
             pulsearc_lib::vacuum_database,
             pulsearc_lib::get_database_health,
             pulsearc_lib::clear_snapshots,
+            pulsearc_lib::browse_snapshots,
+            pulsearc_lib::get_fragmentation_report,
+            pulsearc_lib::check_database_integrity,
             // Feature flags (Phase 4)
             pulsearc_lib::is_feature_enabled,
+            pulsearc_lib::evaluate_feature_flags,
             pulsearc_lib::toggle_feature_flag,
             pulsearc_lib::list_feature_flags,
             // Health check (Phase 4.1.6)
             pulsearc_lib::get_app_health,
+            // Diagnostics
+            pulsearc_lib::get_recent_errors,
+            pulsearc_lib::get_cost_rates,
             // User profile commands (Phase 4A.2)
             pulsearc_lib::get_user_profile,
             pulsearc_lib::upsert_user_profile,
+            pulsearc_lib::update_workday_settings,
+            pulsearc_lib::reset_settings_to_defaults,
+            pulsearc_lib::export_configuration,
+            pulsearc_lib::import_configuration,
             // Window commands (Phase 4A.3)
             pulsearc_lib::animate_window_resize,
             // Idle period management (Phase 4B.3)
@@ -132,9 +189,30 @@ pub fn run() -> MainResult {
             // Debug commands (Phase 4E.1)
             #[cfg(debug_assertions)]
             pulsearc_lib::seed_activity_snapshots,
+            // QA demo data (demo-seed feature)
+            #[cfg(feature = "demo-seed")]
+            pulsearc_lib::seed_demo_data,
+            #[cfg(feature = "demo-seed")]
+            pulsearc_lib::clear_seeded_data,
+            // SAP dry-run validation (sap feature)
+            #[cfg(feature = "sap")]
+            pulsearc_lib::validate_sap_batch,
         ])
-        .run(tauri::generate_context!())
-        .map_err(Into::into)
+        .build(tauri::generate_context!())?
+        .run(|app, event| {
+            if let RunEvent::Exit = event {
+                if let Some(ctx) = app.try_state::<Arc<AppContext>>() {
+                    let result = tauri::async_runtime::block_on(
+                        ctx.shutdown_with_reason(ShutdownReason::UserQuit),
+                    );
+                    if let Err(e) = result {
+                        tracing::error!(event = "shutdown_failed", error = %e);
+                    }
+                }
+            }
+        });
+
+    Ok(())
 }
 
 fn main() -> MainResult {
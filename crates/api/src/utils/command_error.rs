@@ -0,0 +1,267 @@
+//! Structured error envelope returned by Tauri commands
+//!
+//! Commands that return [`CommandError`] give the frontend a stable shape to
+//! branch on, regardless of which layer produced the error:
+//!
+//! ```json
+//! {
+//!   "code": "timeout",
+//!   "message": "Operation 'sync' timed out after 5s",
+//!   "retryable": true,
+//!   "severity": "warning",
+//!   "details": { "operation": "sync", "duration_ms": 5000 }
+//! }
+//! ```
+//!
+//! `retryable` and `severity` come from [`ErrorClassification`] for
+//! [`CommonError`]; [`PulseArcError`] is classified here with the same rules
+//! (network failures are retryable, internal errors are critical). `code` uses
+//! the same snake_case labels as command metrics (see
+//! [`error_label`](crate::utils::logging::error_label)).
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::Duration;
+
+use pulsearc_common::error::{CommonError, ErrorClassification, ErrorSeverity};
+use pulsearc_domain::PulseArcError;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::utils::logging::error_label;
+
+/// Severity reported to the frontend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommandErrorSeverity {
+    Info,
+    Warning,
+    Error,
+    Critical,
+}
+
+impl From<ErrorSeverity> for CommandErrorSeverity {
+    fn from(severity: ErrorSeverity) -> Self {
+        match severity {
+            ErrorSeverity::Info => Self::Info,
+            ErrorSeverity::Warning => Self::Warning,
+            ErrorSeverity::Error => Self::Error,
+            ErrorSeverity::Critical => Self::Critical,
+        }
+    }
+}
+
+/// Error envelope serialized as `{ code, message, retryable, severity,
+/// details }`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CommandError {
+    /// Stable snake_case error kind (e.g. `"timeout"`, `"validation"`)
+    pub code: &'static str,
+    /// Human-readable description
+    pub message: String,
+    /// Whether retrying the command may succeed
+    pub retryable: bool,
+    /// Severity for display and telemetry
+    pub severity: CommandErrorSeverity,
+    /// Structured context (field names, durations, identifiers); empty when
+    /// there is none
+    pub details: BTreeMap<String, Value>,
+}
+
+impl CommandError {
+    /// Create an envelope with no details
+    pub fn new(
+        code: &'static str,
+        message: impl Into<String>,
+        retryable: bool,
+        severity: CommandErrorSeverity,
+    ) -> Self {
+        Self { code, message: message.into(), retryable, severity, details: BTreeMap::new() }
+    }
+
+    /// Attach a detail entry
+    pub fn with_detail(mut self, key: &str, value: impl Into<Value>) -> Self {
+        self.details.insert(key.to_string(), value.into());
+        self
+    }
+
+    /// Attach a detail entry when `value` is present
+    fn with_optional_detail(self, key: &str, value: Option<impl Into<Value>>) -> Self {
+        match value {
+            Some(value) => self.with_detail(key, value),
+            None => self,
+        }
+    }
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+impl From<PulseArcError> for CommandError {
+    fn from(err: PulseArcError) -> Self {
+        let (retryable, severity) = match &err {
+            PulseArcError::Network(_) => (true, CommandErrorSeverity::Warning),
+            PulseArcError::NotFound(_) => (false, CommandErrorSeverity::Info),
+            PulseArcError::Auth(_) => (false, CommandErrorSeverity::Warning),
+            PulseArcError::Security(_)
+            | PulseArcError::DatabaseOpen(_)
+            | PulseArcError::Internal(_) => (false, CommandErrorSeverity::Critical),
+            PulseArcError::Database(_)
+            | PulseArcError::Config(_)
+            | PulseArcError::Platform(_)
+            | PulseArcError::InvalidInput(_) => (false, CommandErrorSeverity::Error),
+        };
+
+        Self::new(error_label(&err), err.to_string(), retryable, severity)
+    }
+}
+
+impl From<CommonError> for CommandError {
+    fn from(err: CommonError) -> Self {
+        let envelope = Self::new(
+            common_error_code(&err),
+            err.to_string(),
+            err.is_retryable(),
+            err.severity().into(),
+        )
+        .with_optional_detail("retry_after_ms", err.retry_after().map(duration_ms));
+
+        match err {
+            CommonError::Config { field, .. } => envelope.with_optional_detail("field", field),
+            CommonError::Lock { resource, .. } => {
+                envelope.with_optional_detail("resource", resource)
+            }
+            CommonError::CircuitBreakerOpen { service, .. } => {
+                envelope.with_detail("service", service)
+            }
+            CommonError::Serialization { format, .. } => {
+                envelope.with_optional_detail("format", format)
+            }
+            CommonError::Persistence { operation, .. } | CommonError::Storage { operation, .. } => {
+                envelope.with_optional_detail("operation", operation)
+            }
+            CommonError::RateLimitExceeded { limit, window, .. } => envelope
+                .with_optional_detail("limit", limit)
+                .with_optional_detail("window_ms", window.map(duration_ms)),
+            CommonError::Timeout { operation, duration } => envelope
+                .with_detail("operation", operation)
+                .with_detail("duration_ms", duration_ms(duration)),
+            CommonError::AsyncTimeout { future_name, duration } => envelope
+                .with_detail("operation", future_name)
+                .with_detail("duration_ms", duration_ms(duration)),
+            CommonError::Backend { service, .. } => envelope.with_detail("service", service),
+            CommonError::Validation { field, value, .. } => {
+                envelope.with_detail("field", field).with_optional_detail("value", value)
+            }
+            CommonError::NotFound { resource_type, identifier, .. } => envelope
+                .with_detail("resource_type", resource_type)
+                .with_optional_detail("identifier", identifier),
+            CommonError::Unauthorized { operation, required_permission } => envelope
+                .with_detail("operation", operation)
+                .with_optional_detail("required_permission", required_permission),
+            CommonError::Internal { context, .. } | CommonError::Detailed { context, .. } => {
+                envelope.with_optional_detail("context", context)
+            }
+            CommonError::TaskCancelled { task_id, reason } => {
+                envelope.with_detail("task_id", task_id).with_optional_detail("reason", reason)
+            }
+        }
+    }
+}
+
+/// Stable code for a `CommonError` variant
+fn common_error_code(err: &CommonError) -> &'static str {
+    match err {
+        CommonError::Config { .. } => "config",
+        CommonError::Lock { .. } => "lock",
+        CommonError::CircuitBreakerOpen { .. } => "circuit_breaker_open",
+        CommonError::Serialization { .. } => "serialization",
+        CommonError::Persistence { .. } => "persistence",
+        CommonError::RateLimitExceeded { .. } => "rate_limited",
+        CommonError::Timeout { .. } | CommonError::AsyncTimeout { .. } => "timeout",
+        CommonError::Backend { .. } => "backend",
+        CommonError::Validation { .. } => "validation",
+        CommonError::NotFound { .. } => "not_found",
+        CommonError::Unauthorized { .. } => "unauthorized",
+        CommonError::Internal { .. } => "internal",
+        CommonError::Storage { .. } => "storage",
+        CommonError::Detailed { .. } => "error",
+        CommonError::TaskCancelled { .. } => "cancelled",
+    }
+}
+
+fn duration_ms(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn timeout_serializes_as_retryable() {
+        let err = CommandError::from(CommonError::Timeout {
+            operation: "sap_sync".to_string(),
+            duration: Duration::from_secs(5),
+        });
+
+        let value = serde_json::to_value(&err).expect("serializes");
+        assert_eq!(value["code"], "timeout");
+        assert_eq!(value["retryable"], true);
+        assert_eq!(value["severity"], "warning");
+        assert_eq!(value["details"], json!({ "operation": "sap_sync", "duration_ms": 5000 }));
+    }
+
+    #[test]
+    fn validation_error_carries_field_details() {
+        let err = CommandError::from(CommonError::Validation {
+            field: "day_epoch".to_string(),
+            message: "must not be negative".to_string(),
+            value: Some("-1".to_string()),
+        });
+
+        let value = serde_json::to_value(&err).expect("serializes");
+        assert_eq!(
+            value,
+            json!({
+                "code": "validation",
+                "message": "Validation error for field 'day_epoch' (value: '-1'): must not be negative",
+                "retryable": false,
+                "severity": "error",
+                "details": { "field": "day_epoch", "value": "-1" },
+            })
+        );
+    }
+
+    #[test]
+    fn domain_errors_use_metric_labels() {
+        let network = CommandError::from(PulseArcError::Network("connection reset".into()));
+        assert_eq!(network.code, "network");
+        assert!(network.retryable);
+
+        let invalid = CommandError::from(PulseArcError::InvalidInput("bad date".into()));
+        assert_eq!(invalid.code, "invalid_input");
+        assert!(!invalid.retryable);
+        assert!(invalid.details.is_empty());
+    }
+
+    #[test]
+    fn retry_after_is_reported() {
+        let err = CommandError::from(CommonError::circuit_breaker_with_retry(
+            "neon",
+            Duration::from_millis(1500),
+        ));
+
+        assert_eq!(err.code, "circuit_breaker_open");
+        assert!(err.retryable);
+        assert_eq!(err.details.get("retry_after_ms"), Some(&json!(1500)));
+        assert_eq!(err.details.get("service"), Some(&json!("neon")));
+    }
+}
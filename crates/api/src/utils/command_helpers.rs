@@ -6,6 +6,7 @@
 use std::sync::Arc;
 use std::time::Instant;
 
+use pulsearc_common::observability::RecordedError;
 use pulsearc_domain::{PulseArcError, Result as DomainResult};
 
 use crate::context::AppContext;
@@ -19,6 +20,7 @@ use crate::utils::logging::{
 /// - Timing command execution
 /// - Logging execution via tracing
 /// - Recording metrics to database for validation
+/// - Keeping failures in `AppContext::recent_errors` for diagnostics
 /// - Handling metrics recording errors gracefully
 ///
 /// # Example
@@ -60,6 +62,17 @@ where
     let elapsed = start.elapsed();
     let error_type = result.as_ref().err().map(error_label);
 
+    if let Err(err) = &result {
+        ctx.recent_errors.record(RecordedError::new(
+            err.to_string(),
+            vec![
+                ("error_type", error_label(err).to_string()),
+                ("command", command_name.to_string()),
+                ("implementation", implementation.to_string()),
+            ],
+        ));
+    }
+
     // Log to tracing and record metrics to database
     log_command_execution(command_name, implementation, elapsed, success);
     record_command_metric(
@@ -0,0 +1,126 @@
+//! Per-command correlation ids
+//!
+//! A single user action fans out into DB, HTTP and LLM calls. Wrapping a
+//! command body in [`with_correlation_id`] generates one id for the
+//! invocation, opens a `command` span carrying it (so every log emitted while
+//! the command runs includes `correlation_id`) and stores it in the infra
+//! task-local, where `HttpClient` and the DB layer pick it up.
+
+use std::future::Future;
+
+use pulsearc_infra::observability::correlation;
+use tracing::{info_span, Instrument};
+
+/// Run a command body under a fresh correlation id
+pub async fn with_correlation_id<F>(command: &'static str, fut: F) -> F::Output
+where
+    F: Future,
+{
+    let correlation_id = correlation::new_correlation_id();
+    let span = info_span!("command", command, correlation_id = %correlation_id);
+    correlation::scope(correlation_id, fut.instrument(span)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+
+    use pulsearc_infra::observability::correlation::CORRELATION_ID_HEADER;
+    use pulsearc_infra::HttpClient;
+    use reqwest::Method;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id};
+    use tracing::{info, Event, Subscriber};
+    use tracing_subscriber::layer::{Context, SubscriberExt};
+    use tracing_subscriber::registry::LookupSpan;
+    use tracing_subscriber::Layer;
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    /// `correlation_id` recorded on a span
+    struct SpanCorrelationId(String);
+
+    /// Finds the `correlation_id` field among recorded values
+    #[derive(Default)]
+    struct CorrelationVisitor(Option<String>);
+
+    impl Visit for CorrelationVisitor {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "correlation_id" {
+                self.0 = Some(value.to_string());
+            }
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            if field.name() == "correlation_id" {
+                self.0 = Some(format!("{value:?}"));
+            }
+        }
+    }
+
+    /// Layer recording the correlation id (if any) of each PulseArc event
+    #[derive(Clone, Default)]
+    struct CaptureLayer {
+        events: Arc<Mutex<Vec<Option<String>>>>,
+    }
+
+    impl<S> Layer<S> for CaptureLayer
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+            let mut visitor = CorrelationVisitor::default();
+            attrs.record(&mut visitor);
+            if let (Some(value), Some(span)) = (visitor.0, ctx.span(id)) {
+                span.extensions_mut().insert(SpanCorrelationId(value));
+            }
+        }
+
+        fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+            if !event.metadata().target().starts_with("pulsearc") {
+                return;
+            }
+            let mut visitor = CorrelationVisitor::default();
+            event.record(&mut visitor);
+            let from_span = || {
+                ctx.event_scope(event)?.find_map(|span| {
+                    span.extensions().get::<SpanCorrelationId>().map(|id| id.0.clone())
+                })
+            };
+            let correlation_id = visitor.0.or_else(from_span);
+            self.events.lock().unwrap().push(correlation_id);
+        }
+    }
+
+    #[tokio::test]
+    async fn wrapped_command_logs_and_requests_share_correlation_id() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET")).respond_with(ResponseTemplate::new(200)).mount(&server).await;
+        let client = HttpClient::builder().max_attempts(1).build().unwrap();
+
+        let layer = CaptureLayer::default();
+        let guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(layer.clone()));
+
+        with_correlation_id("test::command", async {
+            info!("command started");
+            client.send(client.request(Method::GET, server.uri())).await.unwrap();
+            info!("command finished");
+        })
+        .await;
+        drop(guard);
+
+        let events = layer.events.lock().unwrap().clone();
+        assert!(events.len() >= 4, "command and HTTP client logs captured: {events:?}");
+        let correlation_id = events[0].clone().unwrap();
+        assert!(events.iter().all(|id| id.as_deref() == Some(correlation_id.as_str())));
+
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+        let header = requests[0].headers.get(CORRELATION_ID_HEADER).unwrap();
+        assert_eq!(header.to_str().unwrap(), correlation_id);
+    }
+}
@@ -2,7 +2,7 @@ use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use pulsearc_core::CommandMetric;
-use pulsearc_domain::PulseArcError;
+use pulsearc_domain::{DbOpenError, PulseArcError};
 use tracing::{error, info, warn};
 
 use crate::AppContext;
@@ -38,9 +38,16 @@ pub fn log_command_execution(
 /// Log the outcome of a feature flag evaluation.
 ///
 /// `flag_name` should be a stable identifier without sensitive data.
+/// `unmet_dependency` names the disabled dependency that forced the flag off,
+/// if any.
 #[inline]
-pub fn log_feature_flag_check(flag_name: &str, is_enabled: bool, fallback_used: bool) {
-    info!(flag_name, is_enabled, fallback_used, "feature_flag_evaluated");
+pub fn log_feature_flag_check(
+    flag_name: &str,
+    is_enabled: bool,
+    fallback_used: bool,
+    unmet_dependency: Option<&str>,
+) {
+    info!(flag_name, is_enabled, fallback_used, unmet_dependency, "feature_flag_evaluated");
 }
 
 /// Convert a `PulseArcError` into a stable label suitable for metrics/logging.
@@ -48,6 +55,8 @@ pub fn log_feature_flag_check(flag_name: &str, is_enabled: bool, fallback_used:
 pub fn error_label(error: &PulseArcError) -> &'static str {
     match error {
         PulseArcError::Database(_) => "database",
+        PulseArcError::DatabaseOpen(DbOpenError::KeyMismatch(_)) => "database_key_mismatch",
+        PulseArcError::DatabaseOpen(DbOpenError::Corrupt(_)) => "database_corrupt",
         PulseArcError::Config(_) => "config",
         PulseArcError::Platform(_) => "platform",
         PulseArcError::Network(_) => "network",
@@ -1,3 +1,5 @@
+pub mod command_error;
+pub mod correlation;
 pub mod health;
 pub mod idle_sync_metrics;
 pub mod logging;
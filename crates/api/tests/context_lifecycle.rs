@@ -34,6 +34,8 @@ async fn create_test_context() -> pulsearc_domain::Result<(AppContext, TempDir)>
             path: test_db_path.to_string_lossy().to_string(),
             pool_size: 5,
             encryption_key: None, // Use TEST_DATABASE_ENCRYPTION_KEY env var
+            busy_timeout_ms: None,
+            query_timeout_ms: None,
         },
         ..Config::default()
     };
@@ -74,6 +76,11 @@ async fn test_context_creation_succeeds() {
         Arc::strong_count(&context.sync_scheduler) >= 1,
         "sync_scheduler should be initialized"
     );
+    assert!(
+        Arc::strong_count(&context.vacuum_scheduler) >= 1,
+        "vacuum_scheduler should be initialized"
+    );
+    assert!(context.wal_checkpointer.is_running(), "wal_checkpointer should be running");
 
     // Verify core services are initialized
     assert!(Arc::strong_count(&context.db) >= 1, "db should be initialized");
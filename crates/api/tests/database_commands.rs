@@ -33,6 +33,8 @@ async fn create_test_context() -> pulsearc_domain::Result<(AppContext, TempDir)>
             path: test_db_path.to_string_lossy().to_string(),
             pool_size: 5,
             encryption_key: None, // Use TEST_DATABASE_ENCRYPTION_KEY env var
+            busy_timeout_ms: None,
+            query_timeout_ms: None,
         },
         ..Config::default()
     };
@@ -31,6 +31,8 @@ async fn create_test_context() -> (Arc<AppContext>, TempDir) {
             path: test_db_path.to_string_lossy().to_string(),
             pool_size: 5,
             encryption_key: None, // Use TEST_DATABASE_ENCRYPTION_KEY env var
+            busy_timeout_ms: None,
+            query_timeout_ms: None,
         },
         ..Config::default()
     };
@@ -75,6 +77,7 @@ fn create_test_snapshot(id: &str, timestamp: i64, is_idle: bool) -> ActivitySnap
         processed_at: None,
         is_idle,
         idle_duration_secs: if is_idle { Some(0) } else { None },
+        content_hash: None,
     }
 }
 
@@ -26,6 +26,8 @@ async fn create_test_context() -> Result<(AppContext, TempDir), Box<dyn std::err
             path: test_db_path.to_string_lossy().to_string(),
             pool_size: 5,
             encryption_key: None,
+            busy_timeout_ms: None,
+            query_timeout_ms: None,
         },
         ..Config::default()
     };
@@ -63,6 +65,7 @@ fn create_test_snapshots(count: usize, base_timestamp: i64) -> Vec<ActivitySnaps
             processed_at: None,
             is_idle: false,
             idle_duration_secs: None,
+            content_hash: None,
         });
     }
     snapshots
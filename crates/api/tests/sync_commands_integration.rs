@@ -41,6 +41,8 @@ async fn create_test_context() -> (Arc<AppContext>, TempDir) {
             path: test_db_path.to_string_lossy().to_string(),
             pool_size: 5,
             encryption_key: None,
+            busy_timeout_ms: None,
+            query_timeout_ms: None,
         },
         ..Config::default()
     };
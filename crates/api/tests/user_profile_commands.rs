@@ -8,7 +8,7 @@ use std::sync::Arc;
 
 use chrono::Utc;
 use pulsearc_common::testing::TempDir;
-use pulsearc_domain::{Config, DatabaseConfig, UserProfile};
+use pulsearc_domain::{Config, DatabaseConfig, UserProfile, WorkdayConfig};
 use pulsearc_lib::AppContext;
 
 const TEST_KEY: &str = "test_key_64_chars_long_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
@@ -34,6 +34,8 @@ async fn create_test_context() -> (Arc<AppContext>, TempDir) {
             path: test_db_path.to_string_lossy().to_string(),
             pool_size: 5,
             encryption_key: None, // Use TEST_DATABASE_ENCRYPTION_KEY env var
+            busy_timeout_ms: None,
+            query_timeout_ms: None,
         },
         ..Config::default()
     };
@@ -74,6 +76,7 @@ fn create_test_profile() -> UserProfile {
         last_synced_at: now,
         created_at: now,
         updated_at: now,
+        workday: WorkdayConfig::default(),
     }
 }
 
@@ -506,3 +509,21 @@ async fn test_upsert_user_profile_command_legacy_path_updates_in_place() {
     assert_eq!(stored.name, Some("Updated via legacy command".to_string()));
     assert_eq!(stored.email, "updated-legacy-command@pulsearc.com");
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_upsert_user_profile_command_legacy_path_rejects_invalid_email() {
+    let (ctx, _temp_dir) = create_test_context().await;
+    let mut profile = create_test_profile();
+    profile.email = "test.pulsearc.com".to_string();
+
+    let result = pulsearc_lib::commands::user_profile::legacy_upsert_user_profile(
+        ctx.as_ref(),
+        profile.clone(),
+    )
+    .await;
+    let err = result.expect_err("invalid email should be rejected");
+    assert!(err.to_string().contains("email: Invalid email format"), "unexpected error: {err}");
+
+    let retrieved = ctx.user_profile.get_by_id(&profile.id).await.expect("get_by_id failed");
+    assert!(retrieved.is_none(), "invalid profile must not be persisted");
+}
@@ -119,7 +119,7 @@ pub use pkce::PKCEChallenge;
 // Re-export PKCE utility functions
 pub use pkce::{generate_code_challenge, generate_code_verifier, generate_state, validate_state};
 pub use service::{OAuthService, OAuthServiceError};
-pub use token_manager::{TokenManager, TokenManagerError};
+pub use token_manager::{RefreshBackoffConfig, RefreshMetrics, TokenManager, TokenManagerError};
 pub use traits::{KeychainTrait, OAuthClientTrait};
 pub use types::{OAuthConfig, OAuthError, TokenResponse, TokenSet};
 
@@ -389,13 +389,7 @@ mod tests {
         let test_service = format!("PulseArcTest.oauth.{}", uuid::Uuid::new_v4());
         let keychain = Arc::new(MockKeychainProvider::new(test_service));
 
-        OAuthService::new(
-            config,
-            keychain,
-            "test.service".to_string(),
-            "test.account".to_string(),
-            300,
-        )
+        OAuthService::new(config, keychain, "test.account".to_string(), 300)
     }
 
     /// Validates the oauth service creation scenario.
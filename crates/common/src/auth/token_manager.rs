@@ -4,9 +4,11 @@
 //! - Token retrieval from keychain
 //! - Auto-refresh before expiry (configurable threshold, default 5 min)
 //! - Background refresh task
+//! - Exponential backoff (with jitter) after failed refreshes
 //! - Token validation
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Instant;
 
 use tokio::sync::RwLock;
 use tokio::time::Duration;
@@ -15,6 +17,7 @@ use tracing::{debug, error, info};
 use super::client::OAuthClientError;
 use super::traits::{KeychainTrait, OAuthClientTrait};
 use super::types::TokenSet;
+use crate::resilience::{BackoffStrategy, Clock, Jitter, SystemClock};
 
 /// Error type for token manager operations
 #[derive(Debug)]
@@ -61,6 +64,98 @@ impl From<String> for TokenManagerError {
     }
 }
 
+/// Backoff between auto-refresh attempts after failures
+///
+/// The n-th consecutive failure delays the next attempt by
+/// `initial_delay * 2^(n-1)`, capped at `max_delay`, with `jitter` applied.
+#[derive(Debug, Clone)]
+pub struct RefreshBackoffConfig {
+    /// Delay after the first failure
+    pub initial_delay: Duration,
+    /// Upper bound for the delay before jitter
+    pub max_delay: Duration,
+    /// Randomization applied to each delay
+    pub jitter: Jitter,
+}
+
+impl Default for RefreshBackoffConfig {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(30),
+            max_delay: Duration::from_secs(30 * 60),
+            jitter: Jitter::Equal,
+        }
+    }
+}
+
+/// Token refresh health
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RefreshMetrics {
+    /// Successful refreshes
+    pub total_refreshes: u64,
+    /// Failed refreshes
+    pub total_failures: u64,
+    /// Failures since the last successful refresh
+    pub consecutive_failures: u32,
+    /// Error of the most recent failure, cleared on success
+    pub last_error: Option<String>,
+    /// Time left before auto-refresh retries, if backing off
+    pub retry_after: Option<Duration>,
+}
+
+/// Refresh outcome tracking and backoff state
+struct RefreshBackoff {
+    config: RefreshBackoffConfig,
+    clock: Arc<dyn Clock>,
+    metrics: RefreshMetrics,
+    next_attempt_at: Option<Instant>,
+}
+
+impl RefreshBackoff {
+    fn new(config: RefreshBackoffConfig, clock: Arc<dyn Clock>) -> Self {
+        Self { config, clock, metrics: RefreshMetrics::default(), next_attempt_at: None }
+    }
+
+    fn record_success(&mut self) {
+        self.metrics.total_refreshes += 1;
+        self.metrics.consecutive_failures = 0;
+        self.metrics.last_error = None;
+        self.next_attempt_at = None;
+    }
+
+    /// Record a failure and return the delay before the next attempt
+    fn record_failure(&mut self, error: &TokenManagerError) -> Duration {
+        let attempt = self.metrics.consecutive_failures;
+        let strategy = BackoffStrategy::Exponential {
+            initial_delay: self.config.initial_delay,
+            base: 2.0,
+            max_delay: self.config.max_delay,
+        };
+        let delay = self.config.jitter.apply(strategy.calculate_delay(attempt), attempt);
+
+        self.metrics.total_failures += 1;
+        self.metrics.consecutive_failures = attempt.saturating_add(1);
+        self.metrics.last_error = Some(error.to_string());
+        self.next_attempt_at = Some(self.clock.now() + delay);
+        delay
+    }
+
+    /// Time left before the next attempt is allowed
+    fn remaining(&self) -> Duration {
+        self.next_attempt_at
+            .map(|at| at.saturating_duration_since(self.clock.now()))
+            .unwrap_or_default()
+    }
+
+    fn metrics(&self) -> RefreshMetrics {
+        let remaining = self.remaining();
+        RefreshMetrics {
+            retry_after: (!remaining.is_zero()).then_some(remaining),
+            ..self.metrics.clone()
+        }
+    }
+}
+
 /// Token manager with auto-refresh capabilities
 ///
 /// Manages the full token lifecycle:
@@ -74,6 +169,7 @@ pub struct TokenManager<C: OAuthClientTrait + 'static, K: KeychainTrait + 'stati
     account_name: String,
     current_tokens: Arc<RwLock<Option<TokenSet>>>,
     refresh_threshold_seconds: i64,
+    refresh_backoff: Mutex<RefreshBackoff>,
 }
 
 impl<C: OAuthClientTrait + 'static, K: KeychainTrait + 'static> TokenManager<C, K> {
@@ -99,9 +195,31 @@ impl<C: OAuthClientTrait + 'static, K: KeychainTrait + 'static> TokenManager<C,
             account_name,
             current_tokens: Arc::new(RwLock::new(None)),
             refresh_threshold_seconds,
+            refresh_backoff: Mutex::new(RefreshBackoff::new(
+                RefreshBackoffConfig::default(),
+                Arc::new(SystemClock),
+            )),
         }
     }
 
+    /// Use `config` for backoff after failed refreshes
+    #[must_use]
+    pub fn with_refresh_backoff(self, config: RefreshBackoffConfig) -> Self {
+        self.backoff().config = config;
+        self
+    }
+
+    /// Use `clock` to time refresh backoff (e.g. `MockClock` in tests)
+    #[must_use]
+    pub fn with_clock(self, clock: impl Clock) -> Self {
+        self.backoff().clock = Arc::new(clock);
+        self
+    }
+
+    fn backoff(&self) -> std::sync::MutexGuard<'_, RefreshBackoff> {
+        self.refresh_backoff.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
     /// Initialize token manager by loading tokens from keychain
     ///
     /// Should be called on app startup. If tokens exist and are valid,
@@ -195,9 +313,37 @@ impl<C: OAuthClientTrait + 'static, K: KeychainTrait + 'static> TokenManager<C,
 
     /// Refresh access token using refresh token
     ///
+    /// The outcome is recorded in [`refresh_metrics`](Self::refresh_metrics);
+    /// a failure makes auto-refresh back off, a success resets the backoff.
+    ///
     /// # Errors
     /// Returns error if refresh fails or no refresh token available
     pub async fn refresh_tokens(&self) -> Result<(), TokenManagerError> {
+        let result = self.try_refresh_tokens().await;
+
+        let mut backoff = self.backoff();
+        match &result {
+            Ok(()) => backoff.record_success(),
+            Err(e) => {
+                let delay = backoff.record_failure(e);
+                debug!(
+                    consecutive_failures = backoff.metrics.consecutive_failures,
+                    retry_in_ms = delay.as_millis() as u64,
+                    "Token refresh failed, backing off"
+                );
+            }
+        }
+
+        result
+    }
+
+    /// Token refresh health, including consecutive failures
+    #[must_use]
+    pub fn refresh_metrics(&self) -> RefreshMetrics {
+        self.backoff().metrics()
+    }
+
+    async fn try_refresh_tokens(&self) -> Result<(), TokenManagerError> {
         // Get current refresh token
         let refresh_token = {
             let tokens = self.current_tokens.read().await;
@@ -238,6 +384,8 @@ impl<C: OAuthClientTrait + 'static, K: KeychainTrait + 'static> TokenManager<C,
     ///
     /// Wakes up only when tokens need refreshing (no polling).
     /// Sleeps until refresh threshold is reached, then refreshes tokens.
+    /// After a failed refresh it waits out the backoff (see
+    /// [`RefreshBackoffConfig`]) before retrying.
     /// Runs indefinitely until the app shuts down.
     ///
     /// # Example
@@ -252,7 +400,7 @@ impl<C: OAuthClientTrait + 'static, K: KeychainTrait + 'static> TokenManager<C,
     /// # }
     /// ```
     pub async fn start_auto_refresh(self: Arc<Self>) {
-        use tokio::time::{sleep, sleep_until, Instant};
+        use tokio::time::{sleep_until, Instant};
 
         info!("Starting token auto-refresh background task");
 
@@ -290,8 +438,11 @@ impl<C: OAuthClientTrait + 'static, K: KeychainTrait + 'static> TokenManager<C,
                 }
             };
 
+            // Never retry before the backoff from earlier failures has passed
+            let wake_duration = wake_duration.max(self.backoff().remaining());
+
             // Sleep until refresh is needed
-            if wake_duration.as_secs() > 0 {
+            if !wake_duration.is_zero() {
                 debug!(
                     "Auto-refresh: Sleeping for {} seconds until next check",
                     wake_duration.as_secs()
@@ -309,9 +460,12 @@ impl<C: OAuthClientTrait + 'static, K: KeychainTrait + 'static> TokenManager<C,
                 info!("Auto-refresh: Token expiring soon, refreshing...");
 
                 if let Err(e) = self.refresh_tokens().await {
-                    error!("Auto-refresh failed: {e}");
-                    // Retry after 60 seconds on failure
-                    sleep(Duration::from_secs(60)).await;
+                    let metrics = self.refresh_metrics();
+                    error!(
+                        consecutive_failures = metrics.consecutive_failures,
+                        retry_in_secs = metrics.retry_after.unwrap_or_default().as_secs(),
+                        "Auto-refresh failed: {e}"
+                    );
                 }
             }
         }
@@ -341,7 +495,8 @@ mod tests {
 
     use super::*;
     use crate::auth::{OAuthClient, OAuthConfig};
-    use crate::testing::MockKeychainProvider;
+    use crate::resilience::MockClock;
+    use crate::testing::{MockKeychainProvider, MockOAuthClient};
 
     fn disable_oauth_http() {
         static INIT: Once = Once::new();
@@ -511,4 +666,87 @@ mod tests {
         let manager = create_test_manager();
         assert_eq!(manager.refresh_threshold(), 300);
     }
+
+    fn create_backoff_manager(
+        oauth_client: MockOAuthClient,
+        clock: MockClock,
+    ) -> TokenManager<MockOAuthClient, MockKeychainProvider> {
+        let keychain = Arc::new(MockKeychainProvider::new("PulseArcTest.backoff".to_string()));
+        TokenManager::new(oauth_client, keychain, "test.account".to_string(), 300).with_clock(clock)
+    }
+
+    /// Validates that repeated refresh failures back off exponentially.
+    ///
+    /// Assertions:
+    /// - Each consecutive failure waits longer than the previous one.
+    /// - `consecutive_failures` counts the failures in the metrics.
+    /// - The backoff expires once the mock clock passes it.
+    #[tokio::test]
+    async fn test_refresh_failures_back_off_exponentially() {
+        let oauth_client = MockOAuthClient::new();
+        oauth_client.set_should_fail(true);
+        let clock = MockClock::new();
+        let manager = create_backoff_manager(oauth_client, clock.clone());
+        let tokens =
+            TokenSet::new("access".to_string(), Some("refresh".to_string()), None, 60, None);
+        manager.store_tokens(tokens).await.unwrap();
+
+        let mut delays = Vec::new();
+        for attempt in 1..=4 {
+            assert!(manager.refresh_tokens().await.is_err());
+            let metrics = manager.refresh_metrics();
+            assert_eq!(metrics.consecutive_failures, attempt);
+            let delay = metrics.retry_after.expect("backing off after a failure");
+            delays.push(delay);
+            clock.advance(delay);
+            assert_eq!(manager.refresh_metrics().retry_after, None);
+        }
+
+        assert!(delays.windows(2).all(|pair| pair[1] > pair[0]), "delays: {delays:?}");
+        // Equal jitter keeps the first delay within [initial/2, initial)
+        assert!(delays[0] >= Duration::from_secs(15) && delays[0] < Duration::from_secs(30));
+        assert_eq!(manager.refresh_metrics().total_failures, 4);
+    }
+
+    /// Validates that a successful refresh resets the backoff.
+    ///
+    /// Assertions:
+    /// - Success clears `consecutive_failures`, `last_error` and `retry_after`.
+    /// - The next failure backs off from the initial delay again.
+    #[tokio::test]
+    async fn test_successful_refresh_resets_backoff() {
+        let oauth_client = MockOAuthClient::new();
+        oauth_client.set_should_fail(true);
+        let clock = MockClock::new();
+        let manager = create_backoff_manager(oauth_client.clone(), clock.clone())
+            .with_refresh_backoff(RefreshBackoffConfig {
+                initial_delay: Duration::from_secs(10),
+                max_delay: Duration::from_secs(600),
+                jitter: Jitter::None,
+            });
+        let tokens =
+            TokenSet::new("access".to_string(), Some("refresh".to_string()), None, 60, None);
+        manager.store_tokens(tokens).await.unwrap();
+
+        for _ in 0..3 {
+            assert!(manager.refresh_tokens().await.is_err());
+        }
+        assert_eq!(manager.refresh_metrics().retry_after, Some(Duration::from_secs(40)));
+
+        clock.advance(Duration::from_secs(40));
+        oauth_client.set_should_fail(false);
+        manager.refresh_tokens().await.unwrap();
+        let metrics = manager.refresh_metrics();
+        assert_eq!(metrics.consecutive_failures, 0);
+        assert_eq!(metrics.last_error, None);
+        assert_eq!(metrics.retry_after, None);
+        assert_eq!(metrics.total_refreshes, 1);
+        assert_eq!(metrics.total_failures, 3);
+
+        oauth_client.set_should_fail(true);
+        assert!(manager.refresh_tokens().await.is_err());
+        let metrics = manager.refresh_metrics();
+        assert_eq!(metrics.consecutive_failures, 1);
+        assert_eq!(metrics.retry_after, Some(Duration::from_secs(10)));
+    }
 }
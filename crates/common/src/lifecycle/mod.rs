@@ -3,8 +3,10 @@
 //! This module provides standardized lifecycle management patterns including:
 //! - **[`manager`]**: Async component lifecycle management with health checks
 //! - **[`state`]**: Thread-safe state management with Arc<RwLock<T>> patterns
+//! - **[`shutdown`]**: Shutdown reasons propagated to registered components
 
 pub mod manager;
+pub mod shutdown;
 pub mod state;
 
 // Re-export commonly used types and traits for convenience
@@ -12,6 +14,7 @@ pub use manager::{
     AsyncManager, ComponentHealth, ManagerController, ManagerHealth, ManagerLifecycle,
     ManagerMetadata, ManagerStatus, SharedState,
 };
+pub use shutdown::{ShutdownCoordinator, ShutdownHook, ShutdownReason};
 pub use state::{
     shared_state, AtomicCounter, ManagedState, SafeShare, SharedState as AsyncSharedState,
     StateBuilder, StateConfig, StateRegistry,
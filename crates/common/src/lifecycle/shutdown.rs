@@ -0,0 +1,200 @@
+//! Structured shutdown reasons
+//!
+//! Components registered with a [`ShutdownCoordinator`] are told *why* the
+//! application is stopping so they can pick the right behaviour: drain queues
+//! and flush buffers when the user quits, skip slow work when the shutdown is
+//! forced or follows a crash.
+//!
+//! The first reason passed to [`ShutdownCoordinator::shutdown`] is recorded;
+//! later calls are no-ops that return the recorded reason.
+
+use std::fmt;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use tracing::{error, info};
+
+/// Why the application is shutting down
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShutdownReason {
+    /// The user quit the app
+    UserQuit,
+    /// The app is restarting to apply an update
+    Update,
+    /// Shutting down after an unrecoverable error, state may be inconsistent
+    CrashRecovery,
+    /// Immediate shutdown (OS termination, timeout); skip anything slow
+    Forced,
+}
+
+impl ShutdownReason {
+    /// Stable identifier used in logs
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::UserQuit => "user_quit",
+            Self::Update => "update",
+            Self::CrashRecovery => "crash_recovery",
+            Self::Forced => "forced",
+        }
+    }
+
+    /// Whether components should drain pending work (flush queues, finish
+    /// in-flight syncs) before exiting
+    pub fn allows_drain(&self) -> bool {
+        matches!(self, Self::UserQuit | Self::Update)
+    }
+}
+
+impl fmt::Display for ShutdownReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A component notified when the application shuts down
+#[async_trait::async_trait]
+pub trait ShutdownHook: Send + Sync {
+    /// Component name used in logs
+    fn name(&self) -> &str;
+
+    /// Release resources, draining pending work if `reason` allows it
+    async fn on_shutdown(
+        &self,
+        reason: ShutdownReason,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// Hooks in registration order
+type Hooks = Vec<Arc<dyn ShutdownHook>>;
+
+/// Runs registered [`ShutdownHook`]s once with the shutdown reason
+#[derive(Default)]
+pub struct ShutdownCoordinator {
+    hooks: Mutex<Hooks>,
+    reason: OnceLock<ShutdownReason>,
+}
+
+impl ShutdownCoordinator {
+    /// Create a coordinator with no hooks
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a hook; hooks run in reverse registration order
+    pub fn register(&self, hook: Arc<dyn ShutdownHook>) {
+        self.hooks.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).push(hook);
+    }
+
+    /// Reason recorded by the first shutdown, if one has started
+    pub fn reason(&self) -> Option<ShutdownReason> {
+        self.reason.get().copied()
+    }
+
+    /// Notify every hook of `reason`, in reverse registration order
+    ///
+    /// Hook failures are logged and do not stop the remaining hooks. Only
+    /// the first call runs the hooks; it returns the reason actually used.
+    pub async fn shutdown(&self, reason: ShutdownReason) -> ShutdownReason {
+        if let Err(recorded) = self.reason.set(reason) {
+            let recorded = self.reason.get().copied().unwrap_or(recorded);
+            info!(reason = %recorded, requested = %reason, "shutdown already in progress");
+            return recorded;
+        }
+
+        let hooks: Vec<Arc<dyn ShutdownHook>> =
+            self.hooks.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone();
+        info!(reason = %reason, drain = reason.allows_drain(), hooks = hooks.len(), "shutting down");
+
+        for hook in hooks.iter().rev() {
+            if let Err(e) = hook.on_shutdown(reason).await {
+                error!(component = hook.name(), reason = %reason, error = %e, "shutdown hook failed");
+            }
+        }
+
+        reason
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    /// Component with queued work that it flushes only on a drainable reason
+    struct QueueComponent {
+        pending: AtomicUsize,
+        flushed: AtomicUsize,
+        observed: Mutex<Vec<ShutdownReason>>,
+    }
+
+    impl QueueComponent {
+        fn with_pending(pending: usize) -> Arc<Self> {
+            Arc::new(Self {
+                pending: AtomicUsize::new(pending),
+                flushed: AtomicUsize::new(0),
+                observed: Mutex::new(Vec::new()),
+            })
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl ShutdownHook for QueueComponent {
+        fn name(&self) -> &str {
+            "queue"
+        }
+
+        async fn on_shutdown(
+            &self,
+            reason: ShutdownReason,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            self.observed.lock().unwrap().push(reason);
+            if reason.allows_drain() {
+                let drained = self.pending.swap(0, Ordering::SeqCst);
+                self.flushed.fetch_add(drained, Ordering::SeqCst);
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn user_quit_drains_pending_work() {
+        let coordinator = ShutdownCoordinator::new();
+        let component = QueueComponent::with_pending(3);
+        coordinator.register(component.clone());
+
+        let reason = coordinator.shutdown(ShutdownReason::UserQuit).await;
+
+        assert_eq!(reason, ShutdownReason::UserQuit);
+        assert_eq!(coordinator.reason(), Some(ShutdownReason::UserQuit));
+        assert_eq!(*component.observed.lock().unwrap(), vec![ShutdownReason::UserQuit]);
+        assert_eq!(component.flushed.load(Ordering::SeqCst), 3);
+        assert_eq!(component.pending.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn forced_shutdown_skips_drain() {
+        let coordinator = ShutdownCoordinator::new();
+        let component = QueueComponent::with_pending(3);
+        coordinator.register(component.clone());
+
+        coordinator.shutdown(ShutdownReason::Forced).await;
+
+        assert_eq!(*component.observed.lock().unwrap(), vec![ShutdownReason::Forced]);
+        assert_eq!(component.flushed.load(Ordering::SeqCst), 0);
+        assert_eq!(component.pending.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn only_first_shutdown_runs_hooks() {
+        let coordinator = ShutdownCoordinator::new();
+        let component = QueueComponent::with_pending(1);
+        coordinator.register(component.clone());
+
+        coordinator.shutdown(ShutdownReason::Forced).await;
+        let reason = coordinator.shutdown(ShutdownReason::UserQuit).await;
+
+        assert_eq!(reason, ShutdownReason::Forced);
+        assert_eq!(component.observed.lock().unwrap().len(), 1);
+        assert_eq!(component.pending.load(Ordering::SeqCst), 1);
+    }
+}
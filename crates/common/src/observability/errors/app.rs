@@ -235,6 +235,29 @@ impl AppError {
             ) | AppError::Metrics(MetricsError::TrackerUnavailable)
         )
     }
+
+    /// Convert error to structured logging fields, mirroring
+    /// `CommonError::as_tracing_fields`.
+    pub fn as_tracing_fields(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("error_type", self.error_type_name().to_string()),
+            ("code", format!("{:?}", self.code())),
+            ("message", self.to_string()),
+            ("retryable", self.is_retryable().to_string()),
+        ]
+    }
+
+    fn error_type_name(&self) -> &'static str {
+        match self {
+            AppError::Ai(_) => "ai",
+            AppError::Http(_) => "http",
+            AppError::Metrics(_) => "metrics",
+            AppError::Serde(_) => "serialization",
+            AppError::Io(_) => "io",
+            AppError::Validation(_) => "validation",
+            AppError::Other(_) => "other",
+        }
+    }
 }
 
 /* -------------------------------------------------------------------------- */
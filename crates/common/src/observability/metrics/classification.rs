@@ -3,10 +3,12 @@
 //! Tracks classification performance to measure ML classifier coverage and
 //! performance. Ported from macos-production/src-tauri/src/inference/metrics.rs
 
-use std::sync::{Arc, Mutex, MutexGuard};
+use std::sync::{Arc, MutexGuard};
 
 use serde::{Deserialize, Serialize};
 
+use crate::observability::PoisonSafeMutex;
+
 /// Metrics for classification performance
 ///
 /// Tracks which classifiers are used and performance metrics.
@@ -67,13 +69,18 @@ impl ClassificationMetrics {
 /// ```
 #[derive(Debug)]
 pub struct MetricsTracker {
-    metrics: Arc<Mutex<ClassificationMetrics>>,
+    metrics: Arc<PoisonSafeMutex<ClassificationMetrics>>,
 }
 
 impl MetricsTracker {
     /// Create new metrics tracker with zero initial state
     pub fn new() -> Self {
-        Self { metrics: Arc::new(Mutex::new(ClassificationMetrics::default())) }
+        Self {
+            metrics: Arc::new(PoisonSafeMutex::new(
+                "MetricsTracker::metrics",
+                ClassificationMetrics::default(),
+            )),
+        }
     }
 
     /// Record a successful linfa prediction
@@ -113,7 +120,7 @@ impl MetricsTracker {
     }
 
     fn lock_metrics(&self) -> MutexGuard<'_, ClassificationMetrics> {
-        self.metrics.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+        self.metrics.lock()
     }
 }
 
@@ -299,7 +306,7 @@ mod tests {
         let tracker = MetricsTracker::new();
         let tracker_for_panic = tracker.clone();
         let result = panic::catch_unwind(move || {
-            let _lock = tracker_for_panic.metrics.lock().unwrap();
+            let _lock = tracker_for_panic.metrics.lock();
             panic!("force poison");
         });
         assert!(result.is_err());
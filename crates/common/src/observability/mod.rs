@@ -4,12 +4,16 @@
 //! - Error types and handling (errors/)
 //! - Performance metrics and tracking (metrics/)
 //! - Trait abstractions for audit, metrics, and tracing (traits/)
+//! - Poison-tolerant locking for metrics state (poison_safe)
+//! - Bounded store of recent errors for diagnostics (recent_errors)
 //!
 //! Centralizing these concerns makes it easier to add logging, tracing,
 //! and other observability features in the future.
 
 pub mod errors;
 pub mod metrics;
+pub mod poison_safe;
+pub mod recent_errors;
 pub mod traits;
 
 // Re-export commonly used types for convenience
@@ -17,6 +21,8 @@ pub use errors::{
     ActionHint, AiError, AppError, AppResult, ErrorCode, HttpError, MetricsError, UiError,
 };
 pub use metrics::{ClassificationMetrics, MetricsTracker, PerformanceMetrics};
+pub use poison_safe::PoisonSafeMutex;
+pub use recent_errors::{RecentErrors, RecordedError};
 // Re-export trait abstractions
 pub use traits::{
     AuditLogEntry, AuditLogger, AuditSeverity, MetricsCollector, NoOpAuditLogger,
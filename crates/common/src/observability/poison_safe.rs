@@ -0,0 +1,123 @@
+//! Poison-tolerant mutex for metrics state
+//!
+//! Metrics must keep working after a thread panics while holding a lock: a
+//! half-updated counter or sample buffer is still more useful than a crashed
+//! collector. [`PoisonSafeMutex`] wraps [`std::sync::Mutex`] and recovers from
+//! poisoning inside [`PoisonSafeMutex::lock`], logging a warning once per
+//! poisoning, so call sites don't need to repeat the recovery pattern.
+
+use std::sync::{Mutex, MutexGuard};
+
+/// Mutex whose `lock()` recovers from poisoning instead of returning an error.
+///
+/// When the lock is found poisoned, a warning naming the mutex is logged, the
+/// poison flag is cleared and the guard is returned. Data may reflect a
+/// partial update from the panicking thread; use this only for state where
+/// that is acceptable (metrics, caches, diagnostics).
+///
+/// # Example
+/// ```
+/// use pulsearc_common::observability::PoisonSafeMutex;
+///
+/// let samples = PoisonSafeMutex::new("example::samples", Vec::<u64>::new());
+/// samples.lock().push(42);
+/// assert_eq!(samples.lock().len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct PoisonSafeMutex<T> {
+    name: &'static str,
+    inner: Mutex<T>,
+}
+
+impl<T> PoisonSafeMutex<T> {
+    /// Create a mutex guarding `value`.
+    ///
+    /// `name` identifies the mutex in the poisoning warning (e.g.
+    /// `"CallMetrics::fetch_times"`).
+    pub const fn new(name: &'static str, value: T) -> Self {
+        Self { name, inner: Mutex::new(value) }
+    }
+
+    /// Acquire the lock, recovering the guard if the mutex was poisoned.
+    pub fn lock(&self) -> MutexGuard<'_, T> {
+        match self.inner.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                tracing::warn!(mutex = self.name, "Mutex poisoned, recovering data");
+                self.inner.clear_poison();
+                poisoned.into_inner()
+            }
+        }
+    }
+
+    /// Name used when logging poison recovery.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Consume the mutex and return the guarded value, recovering it if the
+    /// mutex was poisoned.
+    pub fn into_inner(self) -> T {
+        self.inner.into_inner().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for observability::poison_safe.
+    use std::sync::Arc;
+    use std::thread;
+
+    use super::*;
+
+    /// Validates `PoisonSafeMutex::lock` behavior for the poisoned lock
+    /// scenario.
+    ///
+    /// Assertions:
+    /// - Confirms the lock is poisoned after the panicking thread exits.
+    /// - Ensures `lock()` returns the guard with the data written before the
+    ///   panic.
+    /// - Ensures the poison flag is cleared after recovery.
+    #[test]
+    fn test_lock_recovers_after_poisoning_panic() {
+        let mutex = Arc::new(PoisonSafeMutex::new("test::counter", 0_u32));
+
+        let mutex_for_panic = Arc::clone(&mutex);
+        let result = thread::spawn(move || {
+            let mut guard = mutex_for_panic.lock();
+            *guard += 1;
+            panic!("force poison");
+        })
+        .join();
+        assert!(result.is_err());
+        assert!(mutex.inner.is_poisoned());
+
+        {
+            let mut guard = mutex.lock();
+            assert_eq!(*guard, 1);
+            *guard += 1;
+        }
+        assert!(!mutex.inner.is_poisoned());
+        assert_eq!(*mutex.lock(), 2);
+    }
+
+    /// Validates `PoisonSafeMutex::into_inner` behavior for the poisoned
+    /// mutex scenario.
+    ///
+    /// Assertions:
+    /// - Confirms the value is returned despite poisoning.
+    #[test]
+    fn test_into_inner_recovers_value() {
+        let mutex = Arc::new(PoisonSafeMutex::new("test::samples", vec![1_u64]));
+
+        let mutex_for_panic = Arc::clone(&mutex);
+        let _ = thread::spawn(move || {
+            let _guard = mutex_for_panic.lock();
+            panic!("force poison");
+        })
+        .join();
+
+        let mutex = Arc::try_unwrap(mutex).unwrap();
+        assert_eq!(mutex.into_inner(), vec![1]);
+    }
+}
@@ -0,0 +1,214 @@
+//! Bounded store of recent errors for diagnostics
+//!
+//! Support often needs the last few failures with context, without asking
+//! users to enable verbose logging and reproduce. [`RecentErrors`] keeps the
+//! last N errors in a [`RingBuffer`], each stamped with the time it was
+//! recorded and the structured fields from `as_tracing_fields`. Reading a
+//! snapshot leaves the store untouched.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::collections::RingBuffer;
+use crate::error::CommonError;
+use crate::observability::{AppError, PoisonSafeMutex};
+
+/// A single captured error.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RecordedError {
+    /// When the error was recorded
+    pub recorded_at: DateTime<Utc>,
+    /// Error category (the `error_type` tracing field)
+    pub error_type: String,
+    /// Human-readable description
+    pub message: String,
+    /// Structured context from `as_tracing_fields`
+    pub fields: BTreeMap<String, String>,
+}
+
+impl RecordedError {
+    /// Build an entry from structured fields, stamped with the current time.
+    ///
+    /// `error_type` is taken from the `error_type` field when present.
+    pub fn new(message: impl Into<String>, fields: Vec<(&'static str, String)>) -> Self {
+        let fields: BTreeMap<String, String> =
+            fields.into_iter().map(|(key, value)| (key.to_string(), value)).collect();
+        let error_type = fields.get("error_type").cloned().unwrap_or_else(|| "unknown".into());
+        Self { recorded_at: Utc::now(), error_type, message: message.into(), fields }
+    }
+}
+
+impl From<&CommonError> for RecordedError {
+    fn from(error: &CommonError) -> Self {
+        Self::new(error.to_string(), error.as_tracing_fields())
+    }
+}
+
+impl From<&AppError> for RecordedError {
+    fn from(error: &AppError) -> Self {
+        Self::new(error.to_string(), error.as_tracing_fields())
+    }
+}
+
+/// Thread-safe store of the most recent errors.
+///
+/// Holds at most `capacity` entries; recording beyond that evicts the oldest.
+///
+/// # Example
+/// ```
+/// use pulsearc_common::error::CommonError;
+/// use pulsearc_common::observability::RecentErrors;
+///
+/// let errors = RecentErrors::new(2);
+/// errors.record(&CommonError::validation("email", "missing @"));
+/// errors.record(&CommonError::not_found_with_id("project", "p-1"));
+/// errors.record(&CommonError::not_found_with_id("project", "p-2"));
+///
+/// let snapshot = errors.snapshot();
+/// assert_eq!(snapshot.len(), 2);
+/// assert_eq!(snapshot[0].error_type, "not_found");
+/// ```
+#[derive(Debug)]
+pub struct RecentErrors {
+    entries: PoisonSafeMutex<RingBuffer<RecordedError>>,
+}
+
+impl RecentErrors {
+    /// Default number of errors retained.
+    pub const DEFAULT_CAPACITY: usize = 100;
+
+    /// Create a store retaining the last `capacity` errors (at least one).
+    pub fn new(capacity: usize) -> Self {
+        Self { entries: PoisonSafeMutex::new("RecentErrors::entries", RingBuffer::new(capacity)) }
+    }
+
+    /// Record an error, evicting the oldest entry when full.
+    pub fn record(&self, error: impl Into<RecordedError>) {
+        self.entries.lock().push(error.into());
+    }
+
+    /// Recorded errors, oldest first. Does not clear the store.
+    pub fn snapshot(&self) -> Vec<RecordedError> {
+        self.entries.lock().iter().cloned().collect()
+    }
+
+    /// Number of errors currently retained.
+    pub fn len(&self) -> usize {
+        self.entries.lock().len()
+    }
+
+    /// Whether no errors have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.lock().is_empty()
+    }
+
+    /// Maximum number of errors retained.
+    pub fn capacity(&self) -> usize {
+        self.entries.lock().capacity()
+    }
+
+    /// Drop all recorded errors.
+    pub fn clear(&self) {
+        self.entries.lock().clear();
+    }
+}
+
+impl Default for RecentErrors {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for observability::recent_errors.
+    use std::sync::Arc;
+    use std::thread;
+
+    use super::*;
+    use crate::observability::HttpError;
+
+    /// Validates `RecentErrors::record` behavior for the capacity overflow
+    /// scenario.
+    ///
+    /// Assertions:
+    /// - Confirms only the last `capacity` errors are retained.
+    /// - Confirms entries are returned oldest first.
+    #[test]
+    fn test_retains_last_n_errors_in_order() {
+        let errors = RecentErrors::new(3);
+        for id in 1..=5 {
+            errors.record(&CommonError::not_found_with_id("project", format!("p-{id}")));
+        }
+
+        let snapshot = errors.snapshot();
+        assert_eq!(snapshot.len(), 3);
+        let ids: Vec<&str> =
+            snapshot.iter().map(|entry| entry.fields["identifier"].as_str()).collect();
+        assert_eq!(ids, vec!["p-3", "p-4", "p-5"]);
+        assert!(snapshot.windows(2).all(|pair| pair[0].recorded_at <= pair[1].recorded_at));
+    }
+
+    /// Validates `RecentErrors::snapshot` behavior for the repeated read
+    /// scenario.
+    ///
+    /// Assertions:
+    /// - Ensures reading twice returns the same entries.
+    /// - Confirms `len()` is unchanged after reading.
+    #[test]
+    fn test_snapshot_does_not_clear() {
+        let errors = RecentErrors::new(4);
+        errors.record(&CommonError::validation("email", "missing @"));
+        errors.record(&AppError::Http(HttpError::Timeout));
+
+        let first = errors.snapshot();
+        let second = errors.snapshot();
+        assert_eq!(first, second);
+        assert_eq!(errors.len(), 2);
+    }
+
+    /// Validates `RecordedError` conversions for both error families.
+    ///
+    /// Assertions:
+    /// - Confirms `error_type` and fields come from `as_tracing_fields`.
+    #[test]
+    fn test_records_tracing_fields() {
+        let errors = RecentErrors::new(4);
+        errors.record(&CommonError::validation("email", "missing @"));
+        errors.record(&AppError::Http(HttpError::Timeout));
+
+        let snapshot = errors.snapshot();
+        assert_eq!(snapshot[0].error_type, "validation");
+        assert_eq!(snapshot[0].fields["field"], "email");
+        assert_eq!(snapshot[1].error_type, "http");
+        assert_eq!(snapshot[1].fields["code"], "HttpTimeout");
+        assert_eq!(snapshot[1].fields["retryable"], "true");
+    }
+
+    /// Validates `RecentErrors` behavior for the concurrent writers scenario.
+    ///
+    /// Assertions:
+    /// - Confirms the store never exceeds its capacity.
+    #[test]
+    fn test_concurrent_recording_stays_bounded() {
+        let errors = Arc::new(RecentErrors::new(10));
+        let handles: Vec<_> = (0..4)
+            .map(|worker| {
+                let errors = Arc::clone(&errors);
+                thread::spawn(move || {
+                    for i in 0..50 {
+                        errors.record(&CommonError::internal(format!("worker {worker} #{i}")));
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(errors.len(), 10);
+        assert_eq!(errors.capacity(), 10);
+    }
+}
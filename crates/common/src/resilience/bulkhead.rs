@@ -4,10 +4,15 @@
 //! of concurrent operations. Named after ship bulkheads that contain flooding
 //! to specific compartments, this pattern isolates failures and prevents
 //! cascading resource exhaustion.
+//!
+//! Callers beyond the concurrency limit wait in a bounded queue: up to
+//! `max_queue` callers wait at most `acquire_timeout` for a slot. A caller is
+//! rejected with [`ResilienceError::BulkheadFull`] when the queue is already
+//! full or its wait expires.
 
 use std::fmt;
 use std::future::Future;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -21,9 +26,11 @@ use super::ResilienceError;
 pub struct BulkheadConfig {
     /// Maximum number of concurrent operations allowed
     pub max_concurrent: usize,
-    /// Maximum number of operations waiting in queue
+    /// Maximum number of operations waiting for a slot (0 rejects
+    /// immediately when at capacity)
     pub max_queue: usize,
-    /// Optional timeout for acquiring a permit
+    /// Maximum time a queued operation waits for a slot (`None` waits
+    /// indefinitely)
     pub acquire_timeout: Option<Duration>,
 }
 
@@ -96,9 +103,11 @@ impl BulkheadConfigBuilder {
 pub struct BulkheadMetrics {
     /// Total number of operations executed
     pub total_operations: u64,
-    /// Total number of operations rejected (full)
+    /// Total number of operations rejected (queue full or wait expired)
     pub rejected_operations: u64,
-    /// Total number of timeouts waiting for permit
+    /// Number of rejections because the wait queue was full
+    pub queue_full_count: u64,
+    /// Number of rejections because the wait for a slot expired
     pub timeout_count: u64,
     /// Current number of concurrent operations
     pub current_concurrent: usize,
@@ -106,6 +115,8 @@ pub struct BulkheadMetrics {
     pub current_queued: usize,
     /// Maximum concurrent operations allowed
     pub max_concurrent: usize,
+    /// Maximum number of operations allowed to wait
+    pub max_queue: usize,
 }
 
 impl BulkheadMetrics {
@@ -131,10 +142,12 @@ impl BulkheadMetrics {
     /// Get a human-readable status message
     pub fn status_message(&self) -> String {
         format!(
-            "Bulkhead: {}/{} concurrent ({:.1}% utilized), {} rejected, {} timeouts",
+            "Bulkhead: {}/{} concurrent ({:.1}% utilized), {}/{} queued, {} rejected, {} timeouts",
             self.current_concurrent,
             self.max_concurrent,
             self.utilization() * 100.0,
+            self.current_queued,
+            self.max_queue,
             self.rejected_operations,
             self.timeout_count
         )
@@ -144,8 +157,9 @@ impl BulkheadMetrics {
 /// Bulkhead for limiting concurrent operations
 ///
 /// Limits the number of concurrent operations to prevent resource exhaustion.
-/// Operations that exceed the limit can either be rejected immediately or
-/// queued up to a maximum queue size.
+/// Operations that exceed the limit wait in a queue of at most `max_queue`
+/// entries for up to `acquire_timeout`; with `max_queue(0)` they are rejected
+/// immediately.
 ///
 /// # Examples
 ///
@@ -169,8 +183,10 @@ impl BulkheadMetrics {
 pub struct Bulkhead {
     config: BulkheadConfig,
     semaphore: Arc<Semaphore>,
+    queued: Arc<AtomicUsize>,
     total_operations: Arc<AtomicU64>,
     rejected_operations: Arc<AtomicU64>,
+    queue_full_count: Arc<AtomicU64>,
     timeout_count: Arc<AtomicU64>,
 }
 
@@ -179,11 +195,12 @@ impl Bulkhead {
     pub fn new(config: BulkheadConfig) -> Self {
         config.validate().expect("Invalid bulkhead configuration");
 
-        let total_permits = config.max_concurrent + config.max_queue;
         Self {
-            semaphore: Arc::new(Semaphore::new(total_permits)),
+            semaphore: Arc::new(Semaphore::new(config.max_concurrent)),
+            queued: Arc::new(AtomicUsize::new(0)),
             total_operations: Arc::new(AtomicU64::new(0)),
             rejected_operations: Arc::new(AtomicU64::new(0)),
+            queue_full_count: Arc::new(AtomicU64::new(0)),
             timeout_count: Arc::new(AtomicU64::new(0)),
             config,
         }
@@ -201,38 +218,45 @@ impl Bulkhead {
         self.semaphore.try_acquire().ok()
     }
 
-    /// Try to acquire a permit with the configured timeout
-    async fn acquire_with_timeout(
-        &self,
-    ) -> Result<SemaphorePermit<'_>, ResilienceError<std::io::Error>> {
-        match self.config.acquire_timeout {
-            Some(timeout) => {
-                match tokio::time::timeout(timeout, self.semaphore.acquire()).await {
-                    Ok(Ok(permit)) => Ok(permit),
-                    Ok(Err(_)) => {
-                        // Semaphore closed (should never happen)
-                        Err(ResilienceError::BulkheadFull { capacity: self.config.max_concurrent })
-                    }
+    /// Acquire a permit, queueing behind running operations if needed
+    ///
+    /// Returns `None` (after recording why) when the queue is full or the
+    /// wait expires.
+    async fn acquire(&self) -> Option<SemaphorePermit<'_>> {
+        if let Ok(permit) = self.semaphore.try_acquire() {
+            return Some(permit);
+        }
+
+        let Some(_slot) = QueueSlot::reserve(&self.queued, self.config.max_queue) else {
+            self.queue_full_count.fetch_add(1, Ordering::Relaxed);
+            debug!("Bulkhead queue full ({} waiting)", self.config.max_queue);
+            return None;
+        };
+
+        let acquired = match self.config.acquire_timeout {
+            Some(max_wait) => {
+                match tokio::time::timeout(max_wait, self.semaphore.acquire()).await {
+                    Ok(acquired) => acquired,
                     Err(_) => {
-                        // Timeout
                         self.timeout_count.fetch_add(1, Ordering::Relaxed);
-                        Err(ResilienceError::Timeout { timeout })
+                        debug!("Bulkhead wait expired after {:?}", max_wait);
+                        return None;
                     }
                 }
             }
-            None => {
-                // No timeout, wait indefinitely
-                self.semaphore.acquire().await.map_err(|_| ResilienceError::BulkheadFull {
-                    capacity: self.config.max_concurrent,
-                })
-            }
-        }
+            None => self.semaphore.acquire().await,
+        };
+
+        // The semaphore is never closed, so an error here is unreachable
+        acquired.ok()
     }
 
     /// Execute an operation with bulkhead protection
     ///
-    /// This method acquires a permit (waiting if necessary up to the configured
-    /// timeout), executes the operation, and releases the permit when done.
+    /// This method acquires a permit (queueing if necessary up to the
+    /// configured depth and wait time), executes the operation, and
+    /// releases the permit when done. Rejected operations are never
+    /// invoked.
     #[instrument(skip(self, operation), fields(concurrent = self.current_concurrent()))]
     pub async fn execute<F, Fut, T, E>(&self, operation: F) -> Result<T, ResilienceError<E>>
     where
@@ -240,25 +264,10 @@ impl Bulkhead {
         Fut: Future<Output = Result<T, E>>,
         E: std::error::Error + Send + Sync + 'static,
     {
-        // Try to acquire permit
-        let _permit = match self.acquire_with_timeout().await {
-            Ok(permit) => permit,
-            Err(e) => {
-                self.rejected_operations.fetch_add(1, Ordering::Relaxed);
-                debug!("Bulkhead rejected operation: {:?}", e);
-                // Map the error type
-                return match e {
-                    ResilienceError::Timeout { timeout } => {
-                        Err(ResilienceError::Timeout { timeout })
-                    }
-                    ResilienceError::BulkheadFull { capacity } => {
-                        Err(ResilienceError::BulkheadFull { capacity })
-                    }
-                    _ => {
-                        Err(ResilienceError::BulkheadFull { capacity: self.config.max_concurrent })
-                    }
-                };
-            }
+        let Some(_permit) = self.acquire().await else {
+            self.rejected_operations.fetch_add(1, Ordering::Relaxed);
+            debug!("Bulkhead rejected operation");
+            return Err(ResilienceError::BulkheadFull { capacity: self.config.max_concurrent });
         };
 
         self.total_operations.fetch_add(1, Ordering::Relaxed);
@@ -277,15 +286,12 @@ impl Bulkhead {
 
     /// Get the current number of concurrent operations
     pub fn current_concurrent(&self) -> usize {
-        let total_permits = self.config.max_concurrent + self.config.max_queue;
-        let available = self.semaphore.available_permits();
-        total_permits.saturating_sub(available)
+        self.config.max_concurrent.saturating_sub(self.semaphore.available_permits())
     }
 
     /// Get the current number of operations waiting in queue
     pub fn current_queued(&self) -> usize {
-        let concurrent = self.current_concurrent();
-        concurrent.saturating_sub(self.config.max_concurrent)
+        self.queued.load(Ordering::Acquire)
     }
 
     /// Get bulkhead metrics
@@ -293,10 +299,12 @@ impl Bulkhead {
         BulkheadMetrics {
             total_operations: self.total_operations.load(Ordering::Acquire),
             rejected_operations: self.rejected_operations.load(Ordering::Acquire),
+            queue_full_count: self.queue_full_count.load(Ordering::Acquire),
             timeout_count: self.timeout_count.load(Ordering::Acquire),
             current_concurrent: self.current_concurrent(),
             current_queued: self.current_queued(),
             max_concurrent: self.config.max_concurrent,
+            max_queue: self.config.max_queue,
         }
     }
 
@@ -304,17 +312,43 @@ impl Bulkhead {
     pub fn reset_metrics(&self) {
         self.total_operations.store(0, Ordering::Release);
         self.rejected_operations.store(0, Ordering::Release);
+        self.queue_full_count.store(0, Ordering::Release);
         self.timeout_count.store(0, Ordering::Release);
     }
 }
 
+/// A reserved place in the wait queue, released when dropped (including when
+/// the waiting future is cancelled)
+struct QueueSlot<'a> {
+    queued: &'a AtomicUsize,
+}
+
+impl<'a> QueueSlot<'a> {
+    fn reserve(queued: &'a AtomicUsize, max_queue: usize) -> Option<Self> {
+        queued
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |depth| {
+                (depth < max_queue).then_some(depth + 1)
+            })
+            .ok()
+            .map(|_| Self { queued })
+    }
+}
+
+impl Drop for QueueSlot<'_> {
+    fn drop(&mut self) {
+        self.queued.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
 impl Clone for Bulkhead {
     fn clone(&self) -> Self {
         Self {
             config: self.config.clone(),
             semaphore: Arc::clone(&self.semaphore),
+            queued: Arc::clone(&self.queued),
             total_operations: Arc::clone(&self.total_operations),
             rejected_operations: Arc::clone(&self.rejected_operations),
+            queue_full_count: Arc::clone(&self.queue_full_count),
             timeout_count: Arc::clone(&self.timeout_count),
         }
     }
@@ -442,6 +476,144 @@ mod tests {
         assert!(metrics.timeout_count > 0 || metrics.rejected_operations > 0);
     }
 
+    type HeldOperation = tokio::task::JoinHandle<Result<(), ResilienceError<std::io::Error>>>;
+
+    /// Starts an operation that holds its slot until `gate` is closed
+    fn spawn_held(bulkhead: &Arc<Bulkhead>, gate: &Arc<Semaphore>) -> HeldOperation {
+        let bulkhead = Arc::clone(bulkhead);
+        let gate = Arc::clone(gate);
+        tokio::spawn(async move {
+            bulkhead
+                .execute(|| async move {
+                    // Closing the gate fails every pending and future acquire
+                    let _ = gate.acquire().await;
+                    Ok(())
+                })
+                .await
+        })
+    }
+
+    /// Yields until the bulkhead reaches the expected running/queued counts
+    async fn wait_for(bulkhead: &Bulkhead, concurrent: usize, queued: usize) {
+        for _ in 0..100 {
+            if bulkhead.current_concurrent() == concurrent && bulkhead.current_queued() == queued {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        panic!(
+            "bulkhead never reached {concurrent} running / {queued} queued: {}",
+            bulkhead.metrics().status_message()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bulkhead_admits_within_limit() {
+        let config = BulkheadConfig::builder().max_concurrent(2).max_queue(0).build().unwrap();
+        let bulkhead = Arc::new(Bulkhead::new(config));
+        let gate = Arc::new(Semaphore::new(0));
+
+        let first = spawn_held(&bulkhead, &gate);
+        let second = spawn_held(&bulkhead, &gate);
+        wait_for(&bulkhead, 2, 0).await;
+
+        gate.close();
+        assert!(first.await.unwrap().is_ok());
+        assert!(second.await.unwrap().is_ok());
+        assert_eq!(bulkhead.metrics().rejected_operations, 0);
+    }
+
+    #[tokio::test]
+    async fn test_bulkhead_queues_within_depth() {
+        let config = BulkheadConfig::builder()
+            .max_concurrent(1)
+            .max_queue(1)
+            .acquire_timeout(Duration::from_secs(5))
+            .build()
+            .unwrap();
+        let bulkhead = Arc::new(Bulkhead::new(config));
+        let gate = Arc::new(Semaphore::new(0));
+
+        let running = spawn_held(&bulkhead, &gate);
+        wait_for(&bulkhead, 1, 0).await;
+
+        let queued_bulkhead = Arc::clone(&bulkhead);
+        let queued = tokio::spawn(async move {
+            queued_bulkhead.execute(|| async { Ok::<_, std::io::Error>(7) }).await
+        });
+        wait_for(&bulkhead, 1, 1).await;
+        assert_eq!(bulkhead.metrics().current_queued, 1);
+
+        gate.close();
+        assert!(running.await.unwrap().is_ok());
+        assert_eq!(queued.await.unwrap().unwrap(), 7);
+        assert_eq!(bulkhead.current_queued(), 0);
+        assert_eq!(bulkhead.metrics().rejected_operations, 0);
+    }
+
+    #[tokio::test]
+    async fn test_bulkhead_rejects_past_queue_depth() {
+        let config = BulkheadConfig::builder()
+            .max_concurrent(1)
+            .max_queue(1)
+            .acquire_timeout(Duration::from_secs(5))
+            .build()
+            .unwrap();
+        let bulkhead = Arc::new(Bulkhead::new(config));
+        let gate = Arc::new(Semaphore::new(0));
+
+        let running = spawn_held(&bulkhead, &gate);
+        wait_for(&bulkhead, 1, 0).await;
+        let waiting = spawn_held(&bulkhead, &gate);
+        wait_for(&bulkhead, 1, 1).await;
+
+        let invoked = Arc::new(AtomicU32::new(0));
+        let invoked_clone = Arc::clone(&invoked);
+        let result = bulkhead
+            .execute(|| {
+                invoked_clone.fetch_add(1, Ordering::SeqCst);
+                async { Ok::<_, std::io::Error>(()) }
+            })
+            .await;
+
+        assert!(matches!(result, Err(ResilienceError::BulkheadFull { capacity: 1 })));
+        assert_eq!(invoked.load(Ordering::SeqCst), 0);
+        let metrics = bulkhead.metrics();
+        assert_eq!(metrics.rejected_operations, 1);
+        assert_eq!(metrics.queue_full_count, 1);
+        assert_eq!(metrics.timeout_count, 0);
+
+        gate.close();
+        assert!(running.await.unwrap().is_ok());
+        assert!(waiting.await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_bulkhead_rejects_when_wait_expires() {
+        let config = BulkheadConfig::builder()
+            .max_concurrent(1)
+            .max_queue(1)
+            .acquire_timeout(Duration::from_millis(20))
+            .build()
+            .unwrap();
+        let bulkhead = Arc::new(Bulkhead::new(config));
+        let gate = Arc::new(Semaphore::new(0));
+
+        let running = spawn_held(&bulkhead, &gate);
+        wait_for(&bulkhead, 1, 0).await;
+
+        let result = bulkhead.execute(|| async { Ok::<_, std::io::Error>(()) }).await;
+
+        assert!(matches!(result, Err(ResilienceError::BulkheadFull { .. })));
+        assert_eq!(bulkhead.current_queued(), 0, "Expired waiter should leave the queue");
+        let metrics = bulkhead.metrics();
+        assert_eq!(metrics.timeout_count, 1);
+        assert_eq!(metrics.queue_full_count, 0);
+
+        gate.close();
+        assert!(running.await.unwrap().is_ok());
+    }
+
     #[test]
     fn test_bulkhead_config_validation() {
         assert!(BulkheadConfig::builder().max_concurrent(0).build().is_err());
@@ -453,10 +625,12 @@ mod tests {
         let metrics = BulkheadMetrics {
             total_operations: 80,
             rejected_operations: 20,
+            queue_full_count: 15,
             timeout_count: 5,
             current_concurrent: 5,
             current_queued: 0,
             max_concurrent: 10,
+            max_queue: 10,
         };
 
         assert_eq!(metrics.utilization(), 0.5);
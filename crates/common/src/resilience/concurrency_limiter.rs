@@ -0,0 +1,486 @@
+//! Adaptive concurrency limiting driven by observed latency
+//!
+//! Unlike a [`Bulkhead`](super::Bulkhead) with a fixed limit, the
+//! [`AdaptiveConcurrencyLimiter`] adjusts how many operations may be in
+//! flight using AIMD (additive increase, multiplicative decrease):
+//!
+//! - a round trip within `latency_tolerance` × baseline raises the limit by
+//!   one, pushing harder while the backend keeps up;
+//! - a slower round trip, or a failure, multiplies the limit by
+//!   `backoff_ratio`, backing off quickly when the backend slows down.
+//!
+//! The baseline is the configured `baseline_latency`, or otherwise the lowest
+//! round-trip time observed so far (the best estimate of unloaded latency).
+//! The limit always stays within `min_limit..=max_limit`.
+
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::debug;
+
+/// Configuration for adaptive concurrency limiting
+#[derive(Debug, Clone)]
+pub struct AdaptiveConcurrencyConfig {
+    /// Limit to start with
+    pub initial_limit: usize,
+    /// Lowest the limit may fall to
+    pub min_limit: usize,
+    /// Highest the limit may grow to
+    pub max_limit: usize,
+    /// Expected unloaded round-trip time (`None` learns it from the fastest
+    /// observed round trip)
+    pub baseline_latency: Option<Duration>,
+    /// How much slower than the baseline a round trip may be before it counts
+    /// as congestion (e.g. 2.0 = twice the baseline)
+    pub latency_tolerance: f64,
+    /// Factor applied to the limit on congestion or failure (0.0 to 1.0)
+    pub backoff_ratio: f64,
+}
+
+impl Default for AdaptiveConcurrencyConfig {
+    fn default() -> Self {
+        Self {
+            initial_limit: 4,
+            min_limit: 1,
+            max_limit: 32,
+            baseline_latency: None,
+            latency_tolerance: 2.0,
+            backoff_ratio: 0.75,
+        }
+    }
+}
+
+impl AdaptiveConcurrencyConfig {
+    /// Create a new configuration builder
+    pub fn builder() -> AdaptiveConcurrencyConfigBuilder {
+        AdaptiveConcurrencyConfigBuilder::new()
+    }
+
+    /// Validate the configuration
+    pub fn validate(&self) -> Result<(), String> {
+        if self.min_limit == 0 {
+            return Err("min_limit must be greater than 0".to_string());
+        }
+        if self.min_limit > self.max_limit {
+            return Err("min_limit must not exceed max_limit".to_string());
+        }
+        if self.initial_limit < self.min_limit || self.initial_limit > self.max_limit {
+            return Err("initial_limit must be between min_limit and max_limit".to_string());
+        }
+        if self.latency_tolerance < 1.0 {
+            return Err("latency_tolerance must be at least 1.0".to_string());
+        }
+        if self.backoff_ratio <= 0.0 || self.backoff_ratio >= 1.0 {
+            return Err("backoff_ratio must be between 0.0 and 1.0 (exclusive)".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Builder for AdaptiveConcurrencyConfig
+#[derive(Debug)]
+pub struct AdaptiveConcurrencyConfigBuilder {
+    config: AdaptiveConcurrencyConfig,
+}
+
+impl Default for AdaptiveConcurrencyConfigBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AdaptiveConcurrencyConfigBuilder {
+    pub fn new() -> Self {
+        Self { config: AdaptiveConcurrencyConfig::default() }
+    }
+
+    pub fn initial_limit(mut self, limit: usize) -> Self {
+        self.config.initial_limit = limit;
+        self
+    }
+
+    pub fn min_limit(mut self, limit: usize) -> Self {
+        self.config.min_limit = limit;
+        self
+    }
+
+    pub fn max_limit(mut self, limit: usize) -> Self {
+        self.config.max_limit = limit;
+        self
+    }
+
+    pub fn baseline_latency(mut self, latency: Duration) -> Self {
+        self.config.baseline_latency = Some(latency);
+        self
+    }
+
+    pub fn latency_tolerance(mut self, tolerance: f64) -> Self {
+        self.config.latency_tolerance = tolerance;
+        self
+    }
+
+    pub fn backoff_ratio(mut self, ratio: f64) -> Self {
+        self.config.backoff_ratio = ratio;
+        self
+    }
+
+    pub fn build(self) -> Result<AdaptiveConcurrencyConfig, String> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
+
+/// Snapshot of adaptive concurrency limiter state
+#[derive(Debug, Clone)]
+pub struct AdaptiveConcurrencyMetrics {
+    /// Current in-flight limit
+    pub limit: usize,
+    /// Operations currently holding a permit
+    pub in_flight: usize,
+    /// Baseline latency in use (configured or lowest observed)
+    pub baseline_latency: Option<Duration>,
+    /// Number of times the limit was raised
+    pub increases: u64,
+    /// Number of times the limit was lowered
+    pub decreases: u64,
+}
+
+impl AdaptiveConcurrencyMetrics {
+    /// Fraction of the current limit in use (0.0 to 1.0)
+    pub fn utilization(&self) -> f64 {
+        if self.limit == 0 {
+            return 0.0;
+        }
+        self.in_flight as f64 / self.limit as f64
+    }
+}
+
+#[derive(Debug)]
+struct LimitState {
+    limit: usize,
+    in_flight: usize,
+    /// Permits to retire as they are released after the limit was lowered
+    /// below the number handed out
+    debt: usize,
+    baseline: Option<Duration>,
+    increases: u64,
+    decreases: u64,
+}
+
+#[derive(Debug)]
+struct Inner {
+    config: AdaptiveConcurrencyConfig,
+    semaphore: Arc<Semaphore>,
+    state: Mutex<LimitState>,
+}
+
+impl Inner {
+    fn state(&self) -> MutexGuard<'_, LimitState> {
+        // State updates are single assignments, so a poisoned lock still holds
+        // consistent data
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn on_sample(&self, rtt: Duration) {
+        let mut state = self.state();
+        let baseline = match (self.config.baseline_latency, state.baseline) {
+            (Some(configured), _) => configured,
+            (None, Some(observed)) => observed.min(rtt),
+            (None, None) => rtt,
+        };
+        state.baseline = Some(baseline);
+
+        if rtt.as_secs_f64() <= baseline.as_secs_f64() * self.config.latency_tolerance {
+            self.increase(&mut state);
+        } else {
+            debug!(rtt_ms = rtt.as_millis() as u64, "Latency above baseline tolerance");
+            self.decrease(&mut state);
+        }
+    }
+
+    fn on_failure(&self) {
+        let mut state = self.state();
+        self.decrease(&mut state);
+    }
+
+    fn increase(&self, state: &mut LimitState) {
+        if state.limit >= self.config.max_limit {
+            return;
+        }
+        state.limit += 1;
+        state.increases += 1;
+        if state.debt > 0 {
+            state.debt -= 1;
+        } else {
+            self.semaphore.add_permits(1);
+        }
+    }
+
+    fn decrease(&self, state: &mut LimitState) {
+        let reduced = (state.limit as f64 * self.config.backoff_ratio).floor() as usize;
+        let new_limit = reduced.max(self.config.min_limit);
+        if new_limit >= state.limit {
+            return;
+        }
+
+        let mut to_remove = state.limit - new_limit;
+        while to_remove > 0 {
+            match self.semaphore.try_acquire() {
+                Ok(permit) => permit.forget(),
+                Err(_) => break,
+            }
+            to_remove -= 1;
+        }
+        // Permits currently held are retired when released
+        state.debt += to_remove;
+        state.limit = new_limit;
+        state.decreases += 1;
+        debug!(limit = new_limit, "Lowered adaptive concurrency limit");
+    }
+
+    fn release(&self, permit: OwnedSemaphorePermit) {
+        let mut state = self.state();
+        state.in_flight = state.in_flight.saturating_sub(1);
+        if state.debt > 0 {
+            state.debt -= 1;
+            permit.forget();
+        }
+        // Otherwise dropping the permit returns it to the semaphore
+    }
+}
+
+/// Concurrency limiter that adapts the in-flight limit to observed latency
+///
+/// Cloning is cheap and clones share the same limit, so one limiter can
+/// bound every sender talking to the same backend.
+///
+/// # Examples
+///
+/// ```rust
+/// use pulsearc_common::resilience::{AdaptiveConcurrencyConfig, AdaptiveConcurrencyLimiter};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let limiter = AdaptiveConcurrencyLimiter::new(
+///     AdaptiveConcurrencyConfig::builder().initial_limit(4).max_limit(16).build()?,
+/// )?;
+///
+/// let permit = limiter.acquire().await;
+/// // ... send the request ...
+/// permit.record_success();
+///
+/// println!("current limit: {}", limiter.limit());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AdaptiveConcurrencyLimiter {
+    inner: Arc<Inner>,
+}
+
+impl AdaptiveConcurrencyLimiter {
+    /// Create a limiter with the given configuration
+    pub fn new(config: AdaptiveConcurrencyConfig) -> Result<Self, String> {
+        config.validate()?;
+
+        let state = LimitState {
+            limit: config.initial_limit,
+            in_flight: 0,
+            debt: 0,
+            baseline: config.baseline_latency,
+            increases: 0,
+            decreases: 0,
+        };
+        Ok(Self {
+            inner: Arc::new(Inner {
+                semaphore: Arc::new(Semaphore::new(config.initial_limit)),
+                state: Mutex::new(state),
+                config,
+            }),
+        })
+    }
+
+    /// Wait for an in-flight slot
+    ///
+    /// Report how the operation went through the returned permit; dropping it
+    /// without reporting frees the slot without adjusting the limit.
+    pub async fn acquire(&self) -> ConcurrencyPermit {
+        // The semaphore is never closed
+        let permit = Arc::clone(&self.inner.semaphore).acquire_owned().await.ok();
+        self.inner.state().in_flight += 1;
+        ConcurrencyPermit { inner: Arc::clone(&self.inner), permit, started: Instant::now() }
+    }
+
+    /// Try to take an in-flight slot without waiting
+    pub fn try_acquire(&self) -> Option<ConcurrencyPermit> {
+        let permit = Arc::clone(&self.inner.semaphore).try_acquire_owned().ok()?;
+        self.inner.state().in_flight += 1;
+        Some(ConcurrencyPermit {
+            inner: Arc::clone(&self.inner),
+            permit: Some(permit),
+            started: Instant::now(),
+        })
+    }
+
+    /// Feed a round-trip latency sample into the limit
+    ///
+    /// [`ConcurrencyPermit::record_success`] calls this with the measured
+    /// time; call it directly when latency is measured elsewhere.
+    pub fn record_latency(&self, rtt: Duration) {
+        self.inner.on_sample(rtt);
+    }
+
+    /// Record a failed or timed-out operation, lowering the limit
+    pub fn record_failure(&self) {
+        self.inner.on_failure();
+    }
+
+    /// Current in-flight limit
+    pub fn limit(&self) -> usize {
+        self.inner.state().limit
+    }
+
+    /// Snapshot of the limiter state
+    pub fn metrics(&self) -> AdaptiveConcurrencyMetrics {
+        let state = self.inner.state();
+        AdaptiveConcurrencyMetrics {
+            limit: state.limit,
+            in_flight: state.in_flight,
+            baseline_latency: state.baseline,
+            increases: state.increases,
+            decreases: state.decreases,
+        }
+    }
+}
+
+impl fmt::Debug for AdaptiveConcurrencyLimiter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let metrics = self.metrics();
+        f.debug_struct("AdaptiveConcurrencyLimiter")
+            .field("limit", &metrics.limit)
+            .field("in_flight", &metrics.in_flight)
+            .finish()
+    }
+}
+
+/// An in-flight slot handed out by [`AdaptiveConcurrencyLimiter::acquire`]
+///
+/// The slot is freed when the permit is dropped or consumed.
+#[must_use = "the slot is released as soon as the permit is dropped"]
+pub struct ConcurrencyPermit {
+    inner: Arc<Inner>,
+    permit: Option<OwnedSemaphorePermit>,
+    started: Instant,
+}
+
+impl ConcurrencyPermit {
+    /// Time since the permit was acquired
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Record a successful round trip, using the time since acquisition as
+    /// the latency sample
+    pub fn record_success(self) {
+        self.inner.on_sample(self.started.elapsed());
+    }
+
+    /// Record a failed round trip, lowering the limit
+    pub fn record_failure(self) {
+        self.inner.on_failure();
+    }
+}
+
+impl Drop for ConcurrencyPermit {
+    fn drop(&mut self) {
+        if let Some(permit) = self.permit.take() {
+            self.inner.release(permit);
+        }
+    }
+}
+
+impl fmt::Debug for ConcurrencyPermit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConcurrencyPermit").field("elapsed", &self.elapsed()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for adaptive concurrency limiting
+
+    use super::*;
+
+    fn limiter(initial: usize, min: usize, max: usize) -> AdaptiveConcurrencyLimiter {
+        let config = AdaptiveConcurrencyConfig::builder()
+            .initial_limit(initial)
+            .min_limit(min)
+            .max_limit(max)
+            .latency_tolerance(2.0)
+            .backoff_ratio(0.5)
+            .build()
+            .expect("valid config");
+        AdaptiveConcurrencyLimiter::new(config).expect("valid limiter")
+    }
+
+    #[tokio::test]
+    async fn test_limit_rises_on_fast_responses_then_falls_on_slow_ones() {
+        let limiter = limiter(4, 2, 10);
+
+        for _ in 0..20 {
+            limiter.record_latency(Duration::from_millis(10));
+        }
+        assert_eq!(limiter.limit(), 10, "Fast responses should raise the limit to the max");
+        assert_eq!(limiter.metrics().baseline_latency, Some(Duration::from_millis(10)));
+
+        limiter.record_latency(Duration::from_millis(100));
+        assert_eq!(limiter.limit(), 5, "A slow response should halve the limit");
+
+        for _ in 0..5 {
+            limiter.record_latency(Duration::from_millis(100));
+        }
+        assert_eq!(limiter.limit(), 2, "The limit should not fall below the minimum");
+
+        let metrics = limiter.metrics();
+        assert_eq!(metrics.increases, 6);
+        assert!(metrics.decreases >= 2);
+    }
+
+    #[tokio::test]
+    async fn test_lowered_limit_is_enforced_once_held_permits_are_released() {
+        let limiter = limiter(3, 1, 3);
+
+        let held: Vec<_> =
+            vec![limiter.acquire().await, limiter.acquire().await, limiter.acquire().await];
+        assert!(limiter.try_acquire().is_none());
+
+        limiter.record_failure();
+        assert_eq!(limiter.limit(), 1);
+        drop(held);
+
+        assert_eq!(limiter.metrics().in_flight, 0);
+        let permit = limiter.try_acquire().expect("one slot should remain");
+        assert!(limiter.try_acquire().is_none(), "Only one slot after backing off");
+
+        permit.record_success();
+        assert_eq!(limiter.limit(), 2);
+        let _first = limiter.try_acquire().expect("limit raised to two");
+        let _second = limiter.try_acquire().expect("limit raised to two");
+        assert!(limiter.try_acquire().is_none());
+    }
+
+    #[test]
+    fn test_config_validation() {
+        assert!(AdaptiveConcurrencyConfig::builder().min_limit(0).build().is_err());
+        assert!(AdaptiveConcurrencyConfig::builder()
+            .min_limit(5)
+            .max_limit(4)
+            .initial_limit(4)
+            .build()
+            .is_err());
+        assert!(AdaptiveConcurrencyConfig::builder().backoff_ratio(1.0).build().is_err());
+        assert!(AdaptiveConcurrencyConfig::builder().latency_tolerance(0.5).build().is_err());
+        assert!(AdaptiveConcurrencyConfig::builder().build().is_ok());
+    }
+}
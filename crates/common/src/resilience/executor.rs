@@ -0,0 +1,225 @@
+//! Retry and circuit breaker composed into a single executor
+//!
+//! Wrapping `retry_with_policy` around `CircuitBreaker::execute` by hand is
+//! easy to get subtly wrong: the retry loop keeps hammering a breaker that is
+//! already open, sleeping through backoff delays only to be rejected again.
+//! [`ResilientExecutor`] runs every attempt through the breaker and stops
+//! retrying as soon as the breaker rejects a call, leaving recovery to the
+//! breaker's own open timeout. Only attempts that actually ran and failed are
+//! recorded against the breaker; rejected calls are not.
+
+use std::fmt;
+use std::future::Future;
+
+use tracing::{debug, instrument};
+
+use super::circuit_breaker::{CircuitBreaker, Clock, ResilienceError, SystemClock};
+use super::retry::policies::AlwaysRetry;
+use super::retry::{RetryConfig, RetryDecision, RetryExecutor, RetryPolicy, RetryResult};
+
+/// Executes operations with retries, protected by a circuit breaker
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use pulsearc_common::resilience::{CircuitBreaker, ResilientExecutor, RetryConfig};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let breaker = CircuitBreaker::new(
+///     CircuitBreaker::builder().failure_threshold(3).timeout(Duration::from_secs(30)).build()?,
+/// )?;
+/// let executor = ResilientExecutor::new(breaker, RetryConfig::network_default());
+///
+/// let result =
+///     executor.execute(|| async { Ok::<_, std::io::Error>("Protected operation") }).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ResilientExecutor<P = AlwaysRetry, C: Clock = SystemClock> {
+    breaker: CircuitBreaker<C>,
+    retry: RetryExecutor<BreakerAwarePolicy<P>>,
+}
+
+impl<C: Clock> ResilientExecutor<AlwaysRetry, C> {
+    /// Create an executor that retries every operation failure
+    pub fn new(breaker: CircuitBreaker<C>, retry_config: RetryConfig) -> Self {
+        Self::with_policy(breaker, retry_config, AlwaysRetry)
+    }
+}
+
+impl<P, C: Clock> ResilientExecutor<P, C> {
+    /// Create an executor with a custom retry policy
+    ///
+    /// `policy` only sees errors returned by the operation itself; breaker
+    /// rejections always stop the retry loop.
+    pub fn with_policy(breaker: CircuitBreaker<C>, retry_config: RetryConfig, policy: P) -> Self {
+        Self {
+            breaker,
+            retry: RetryExecutor::new(retry_config, BreakerAwarePolicy { inner: policy }),
+        }
+    }
+
+    /// The circuit breaker guarding each attempt
+    pub fn circuit_breaker(&self) -> &CircuitBreaker<C> {
+        &self.breaker
+    }
+
+    /// Execute `operation` with retries, running each attempt through the
+    /// circuit breaker
+    ///
+    /// Returns `RetryError::NonRetryable` wrapping
+    /// `ResilienceError::CircuitOpen` when the breaker rejects an attempt
+    /// (including the first), and `RetryError::AttemptsExhausted` when every
+    /// attempt ran and failed.
+    #[instrument(skip(self, operation), fields(state = %self.breaker.get_state()))]
+    pub async fn execute<F, Fut, T, E>(&self, operation: F) -> RetryResult<T, ResilienceError<E>>
+    where
+        P: RetryPolicy<E>,
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        let breaker = &self.breaker;
+        let operation = &operation;
+        // The operation is only invoked once the breaker admits the attempt
+        self.retry.execute(move || breaker.execute(operation)).await
+    }
+}
+
+impl<P, C: Clock> fmt::Debug for ResilientExecutor<P, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResilientExecutor").field("breaker", &self.breaker).finish_non_exhaustive()
+    }
+}
+
+/// Retry policy adapter that never retries breaker rejections and defers
+/// operation failures to the wrapped policy
+struct BreakerAwarePolicy<P> {
+    inner: P,
+}
+
+impl<P, E> RetryPolicy<ResilienceError<E>> for BreakerAwarePolicy<P>
+where
+    P: RetryPolicy<E>,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    fn should_retry(&self, error: &ResilienceError<E>, attempt: u32) -> RetryDecision {
+        match error {
+            ResilienceError::OperationFailed { source } => self.inner.should_retry(source, attempt),
+            ResilienceError::CircuitOpen => {
+                debug!("Circuit breaker is open, not retrying");
+                RetryDecision::Stop
+            }
+            _ => RetryDecision::Stop,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the combined retry and circuit breaker executor
+
+    use std::io;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use super::*;
+    use crate::resilience::{CircuitBreakerConfig, CircuitState, RetryError};
+
+    fn executor(failure_threshold: u64, max_attempts: u32) -> ResilientExecutor {
+        let breaker_config = CircuitBreakerConfig::new()
+            .failure_threshold(failure_threshold)
+            .timeout(Duration::from_secs(60))
+            .build()
+            .expect("Should build valid breaker config");
+        let retry_config = RetryConfig::new()
+            .max_attempts(max_attempts)
+            .fixed_backoff(Duration::from_millis(1))
+            .no_jitter()
+            .build()
+            .expect("Should build valid retry config");
+
+        ResilientExecutor::new(
+            CircuitBreaker::new(breaker_config).expect("Should create breaker"),
+            retry_config,
+        )
+    }
+
+    /// Tests that an already open breaker rejects the call without running
+    /// the operation or spending retry attempts.
+    #[tokio::test]
+    async fn test_open_breaker_short_circuits_without_retrying() {
+        let executor = executor(2, 5);
+        executor.circuit_breaker().record_failure();
+        executor.circuit_breaker().record_failure();
+        assert_eq!(executor.circuit_breaker().state(), CircuitState::Open);
+
+        let calls = Arc::new(AtomicU32::new(0));
+        let calls_clone = Arc::clone(&calls);
+        let result = executor
+            .execute(|| {
+                calls_clone.fetch_add(1, Ordering::SeqCst);
+                async { Ok::<_, io::Error>(()) }
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(RetryError::NonRetryable { source: ResilienceError::CircuitOpen })
+        ));
+        assert_eq!(calls.load(Ordering::SeqCst), 0, "Operation should not run");
+    }
+
+    /// Tests that persistent failures are retried until they trip the
+    /// breaker, after which retrying stops.
+    #[tokio::test]
+    async fn test_transient_failures_retry_then_trip_breaker() {
+        let executor = executor(2, 5);
+        let calls = Arc::new(AtomicU32::new(0));
+        let calls_clone = Arc::clone(&calls);
+
+        let result = executor
+            .execute(|| {
+                calls_clone.fetch_add(1, Ordering::SeqCst);
+                async { Err::<(), _>(io::Error::new(io::ErrorKind::TimedOut, "timed out")) }
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(RetryError::NonRetryable { source: ResilienceError::CircuitOpen })
+        ));
+        assert_eq!(calls.load(Ordering::SeqCst), 2, "Retries should stop once the breaker opens");
+        assert_eq!(executor.circuit_breaker().state(), CircuitState::Open);
+        assert_eq!(executor.circuit_breaker().metrics().failure_count, 2);
+    }
+
+    /// Tests that a transient failure followed by success is retried and
+    /// leaves the breaker closed.
+    #[tokio::test]
+    async fn test_transient_failure_recovers_on_retry() {
+        let executor = executor(3, 3);
+        let calls = Arc::new(AtomicU32::new(0));
+        let calls_clone = Arc::clone(&calls);
+
+        let result = executor
+            .execute(|| {
+                let attempt = calls_clone.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if attempt == 0 {
+                        Err(io::Error::new(io::ErrorKind::TimedOut, "timed out"))
+                    } else {
+                        Ok(42)
+                    }
+                }
+            })
+            .await;
+
+        assert_eq!(result.expect("Should succeed on retry"), 42);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(executor.circuit_breaker().state(), CircuitState::Closed);
+    }
+}
@@ -8,6 +8,7 @@
 //! - **Rate Limiting**: Token bucket and leaky bucket algorithms for rate
 //!   control
 //! - **Bulkhead**: Limits concurrent operations to prevent resource exhaustion
+//! - **Adaptive Concurrency**: Adjusts the in-flight limit to observed latency
 //!
 //! These patterns help build robust systems that can handle transient failures
 //! gracefully.
@@ -75,12 +76,15 @@
 //!
 //! ## Combining Circuit Breaker and Retry
 //!
+//! [`ResilientExecutor`] runs each retry attempt through the breaker and
+//! stops retrying as soon as the breaker opens, instead of backing off
+//! against a circuit that will keep rejecting calls.
+//!
 //! ```rust
-//! use std::sync::Arc;
 //! use std::time::Duration;
 //!
 //! use pulsearc_common::resilience::{
-//!     policies, retry_with_policy, CircuitBreaker, CircuitBreakerConfig, RetryConfig,
+//!     CircuitBreaker, CircuitBreakerConfig, ResilientExecutor, RetryConfig,
 //! };
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
@@ -88,21 +92,16 @@
 //!     .failure_threshold(3)
 //!     .timeout(Duration::from_secs(30))
 //!     .build()?;
-//! let breaker = Arc::new(CircuitBreaker::new(cb_config)?);
 //!
 //! let retry_config = RetryConfig::new()
 //!     .max_attempts(3)
 //!     .exponential_backoff(Duration::from_millis(100), 2.0, Duration::from_secs(5))
-//!     .build()?;
+//!     .build()
+//!     .map_err(|e| format!("{e:?}"))?;
 //!
+//! let executor = ResilientExecutor::new(CircuitBreaker::new(cb_config)?, retry_config);
 //! let result =
-//!     retry_with_policy(retry_config, policies::AlwaysRetry, || {
-//!         let breaker = Arc::clone(&breaker);
-//!         async move {
-//!             breaker.execute(|| async { Ok::<_, std::io::Error>("Protected operation") }).await
-//!         }
-//!     })
-//!     .await?;
+//!     executor.execute(|| async { Ok::<_, std::io::Error>("Protected operation") }).await?;
 //! # Ok(())
 //! # }
 //! ```
@@ -152,6 +151,8 @@
 pub mod adaptive;
 pub mod bulkhead;
 pub mod circuit_breaker;
+pub mod concurrency_limiter;
+pub mod executor;
 pub mod histogram;
 pub mod rate_limiter;
 pub mod retry;
@@ -169,6 +170,13 @@ pub use circuit_breaker::{
     CircuitBreakerMetrics, CircuitState, Clock, ConfigError, ConfigResult, MockClock,
     ResilienceError, ResilienceResult, SyncCircuitBreaker, SystemClock,
 };
+// Re-export adaptive concurrency limiter types
+pub use concurrency_limiter::{
+    AdaptiveConcurrencyConfig, AdaptiveConcurrencyConfigBuilder, AdaptiveConcurrencyLimiter,
+    AdaptiveConcurrencyMetrics, ConcurrencyPermit,
+};
+// Re-export combined retry + circuit breaker executor
+pub use executor::ResilientExecutor;
 // Re-export histogram types
 pub use histogram::{Histogram, HistogramSnapshot, Percentiles};
 // Re-export rate limiter types
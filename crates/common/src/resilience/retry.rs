@@ -13,6 +13,8 @@ use std::time::{Duration, Instant};
 use thiserror::Error;
 use tracing::{debug, instrument, warn};
 
+use crate::error::{ErrorClassification, ErrorSeverity};
+
 /// Errors that can occur during retry operations
 #[derive(Debug, Error)]
 pub enum RetryError<E> {
@@ -31,6 +33,54 @@ pub enum RetryError<E> {
     /// A timeout occurred during retry operations
     #[error("Retry timeout exceeded after {elapsed:?}")]
     TimeoutExceeded { elapsed: Duration },
+
+    /// All retry attempts have been exhausted and the configuration asked for
+    /// the final error to be escalated (see
+    /// [`RetryConfigBuilder::escalate_on_exhaustion`])
+    #[error("All retry attempts exhausted after {attempts} tries ({severity}): {source}")]
+    Escalated { attempts: u32, severity: ErrorSeverity, source: E },
+}
+
+impl<E: ErrorClassification> ErrorClassification for RetryError<E> {
+    fn is_retryable(&self) -> bool {
+        match self {
+            Self::NonRetryable { source } => source.is_retryable(),
+            // The executor has already given up; retrying again is the
+            // caller's decision, not the error's
+            Self::AttemptsExhausted { .. }
+            | Self::InvalidConfiguration { .. }
+            | Self::TimeoutExceeded { .. }
+            | Self::Escalated { .. } => false,
+        }
+    }
+
+    fn severity(&self) -> ErrorSeverity {
+        match self {
+            Self::NonRetryable { source } => source.severity(),
+            Self::AttemptsExhausted { .. } | Self::TimeoutExceeded { .. } => ErrorSeverity::Warning,
+            Self::InvalidConfiguration { .. } => ErrorSeverity::Error,
+            Self::Escalated { severity, source, .. } => (*severity).max(source.severity()),
+        }
+    }
+
+    fn is_critical(&self) -> bool {
+        match self {
+            Self::NonRetryable { source } => source.is_critical(),
+            Self::Escalated { severity, source, .. } => {
+                *severity == ErrorSeverity::Critical || source.is_critical()
+            }
+            Self::AttemptsExhausted { .. }
+            | Self::InvalidConfiguration { .. }
+            | Self::TimeoutExceeded { .. } => false,
+        }
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::NonRetryable { source } => source.retry_after(),
+            _ => None,
+        }
+    }
 }
 
 /// Result type for retry operations
@@ -187,6 +237,12 @@ pub struct RetryConfig {
     pub max_total_time: Option<Duration>,
     /// Whether to reset attempt count on certain conditions
     pub reset_on_success: bool,
+    /// Severity to report when all attempts are exhausted
+    ///
+    /// When set, exhaustion returns [`RetryError::Escalated`] wrapping the
+    /// last error instead of [`RetryError::AttemptsExhausted`], so alerting
+    /// sees the escalated severity. `None` keeps the plain exhaustion error.
+    pub exhaustion_severity: Option<ErrorSeverity>,
 }
 
 impl Default for RetryConfig {
@@ -201,6 +257,7 @@ impl Default for RetryConfig {
             jitter: Jitter::Equal,
             max_total_time: Some(Duration::from_secs(300)), // 5 minutes
             reset_on_success: false,
+            exhaustion_severity: None,
         }
     }
 }
@@ -217,6 +274,24 @@ impl RetryConfig {
         RetryConfigBuilder::new()
     }
 
+    /// Preset for cheap, latency-sensitive operations (see
+    /// [`RetryConfigBuilder::aggressive`])
+    pub fn aggressive() -> Self {
+        RetryConfigBuilder::aggressive().config
+    }
+
+    /// Preset for expensive operations against fragile dependencies (see
+    /// [`RetryConfigBuilder::conservative`])
+    pub fn conservative() -> Self {
+        RetryConfigBuilder::conservative().config
+    }
+
+    /// Preset for typical HTTP/API calls (see
+    /// [`RetryConfigBuilder::network_default`])
+    pub fn network_default() -> Self {
+        RetryConfigBuilder::network_default().config
+    }
+
     /// Validate the configuration
     pub fn validate(&self) -> Result<(), RetryError<()>> {
         if self.max_attempts == 0 {
@@ -255,6 +330,46 @@ impl RetryConfigBuilder {
         Self { config: RetryConfig::default() }
     }
 
+    /// Start from the aggressive preset: many quick retries
+    ///
+    /// - 5 attempts
+    /// - exponential backoff from 50ms, base 2.0, capped at 2s
+    /// - full jitter
+    /// - 10s total time limit
+    pub fn aggressive() -> Self {
+        Self::exponential_preset(5, Duration::from_millis(50), Duration::from_secs(2))
+            .max_total_time(Duration::from_secs(10))
+    }
+
+    /// Start from the conservative preset: few, widely spaced retries
+    ///
+    /// - 3 attempts
+    /// - exponential backoff from 1s, base 2.0, capped at 30s
+    /// - full jitter
+    /// - 2 minute total time limit
+    pub fn conservative() -> Self {
+        Self::exponential_preset(3, Duration::from_secs(1), Duration::from_secs(30))
+            .max_total_time(Duration::from_secs(120))
+    }
+
+    /// Start from the network preset used for HTTP/API calls
+    ///
+    /// - 4 attempts
+    /// - exponential backoff from 200ms, base 2.0, capped at 10s
+    /// - full jitter
+    /// - 60s total time limit
+    pub fn network_default() -> Self {
+        Self::exponential_preset(4, Duration::from_millis(200), Duration::from_secs(10))
+            .max_total_time(Duration::from_secs(60))
+    }
+
+    fn exponential_preset(attempts: u32, initial_delay: Duration, max_delay: Duration) -> Self {
+        Self::new()
+            .max_attempts(attempts)
+            .exponential_backoff(initial_delay, 2.0, max_delay)
+            .full_jitter()
+    }
+
     pub fn max_attempts(mut self, attempts: u32) -> Self {
         self.config.max_attempts = attempts;
         self
@@ -315,6 +430,16 @@ impl RetryConfigBuilder {
         self
     }
 
+    /// Escalate the final error to `severity` when all attempts are exhausted
+    ///
+    /// Intended for critical operations (e.g. syncing a user-accepted block)
+    /// where running out of retries needs to page someone rather than sit in
+    /// the logs as a warning.
+    pub fn escalate_on_exhaustion(mut self, severity: ErrorSeverity) -> Self {
+        self.config.exhaustion_severity = Some(severity);
+        self
+    }
+
     pub fn build(self) -> Result<RetryConfig, RetryError<()>> {
         self.config.validate()?;
         Ok(self.config)
@@ -451,8 +576,16 @@ impl<P> RetryExecutor<P> {
                             attempt_number, error
                         );
                         last_error = Some(error_description);
+                        let result = match self.config.exhaustion_severity {
+                            Some(severity) => Err(RetryError::Escalated {
+                                attempts: attempt_number,
+                                severity,
+                                source: error,
+                            }),
+                            None => Err(RetryError::AttemptsExhausted { attempts: attempt_number }),
+                        };
                         return RetryOutcome {
-                            result: Err(RetryError::AttemptsExhausted { attempts: attempt_number }),
+                            result,
                             attempts: attempt_number,
                             total_delay: context.total_delay,
                             timed_out: false,
@@ -591,6 +724,7 @@ mod tests {
 
     use super::policies::*;
     use super::*;
+    use crate::error::CommonError;
 
     /// Validates `RetryDecision::Retry` behavior for the retry decision
     /// equality scenario.
@@ -985,6 +1119,121 @@ mod tests {
         assert_eq!(counter.load(Ordering::SeqCst), 3, "Should have tried exactly 3 times");
     }
 
+    /// Tests that the named presets match their documented parameters and are
+    /// valid configurations.
+    #[test]
+    fn test_retry_config_presets() {
+        let cases = [
+            (RetryConfig::aggressive(), 5, 50, 2, 10),
+            (RetryConfig::conservative(), 3, 1_000, 30, 120),
+            (RetryConfig::network_default(), 4, 200, 10, 60),
+        ];
+
+        for (config, attempts, initial_ms, max_secs, total_secs) in cases {
+            assert_eq!(config.max_attempts, attempts);
+            match config.backoff {
+                BackoffStrategy::Exponential { initial_delay, base, max_delay } => {
+                    assert_eq!(initial_delay, Duration::from_millis(initial_ms));
+                    assert!((base - 2.0).abs() < f64::EPSILON);
+                    assert_eq!(max_delay, Duration::from_secs(max_secs));
+                }
+                other => panic!("Expected exponential backoff, got {other:?}"),
+            }
+            assert!(matches!(config.jitter, Jitter::Full));
+            assert_eq!(config.max_total_time, Some(Duration::from_secs(total_secs)));
+            assert!(config.validate().is_ok());
+        }
+    }
+
+    /// Tests that a preset builder can still be customized before building.
+    #[test]
+    fn test_retry_config_preset_builder_is_customizable() {
+        let config = RetryConfigBuilder::network_default()
+            .max_attempts(6)
+            .build()
+            .expect("Should build valid config");
+
+        assert_eq!(config.max_attempts, 6);
+        assert!(matches!(config.jitter, Jitter::Full));
+    }
+
+    /// Tests that exhausting attempts with escalation configured surfaces the
+    /// last error at the escalated severity.
+    ///
+    /// Verifies:
+    /// - Exhaustion returns `Escalated` wrapping the final error
+    /// - `severity()` reports `Critical` even though the error is a warning
+    #[tokio::test]
+    async fn test_retry_executor_escalates_severity_on_exhaustion() {
+        let config = RetryConfig::new()
+            .max_attempts(3)
+            .fixed_backoff(Duration::from_millis(1))
+            .no_jitter()
+            .escalate_on_exhaustion(ErrorSeverity::Critical)
+            .build()
+            .expect("Should build valid config");
+
+        let executor = RetryExecutor::new(config, AlwaysRetry);
+        let result = executor
+            .execute(|| async {
+                Err::<(), _>(CommonError::timeout("block sync", Duration::from_secs(5)))
+            })
+            .await;
+
+        let err = result.expect_err("Should fail after exhausting attempts");
+        assert!(matches!(
+            err,
+            RetryError::Escalated { attempts: 3, severity: ErrorSeverity::Critical, .. }
+        ));
+        assert_eq!(err.severity(), ErrorSeverity::Critical);
+        assert!(err.is_critical());
+    }
+
+    /// Tests that escalation only applies on exhaustion: a success on the
+    /// final attempt is returned as-is.
+    #[tokio::test]
+    async fn test_retry_executor_success_on_final_attempt_is_not_escalated() {
+        let config = RetryConfig::new()
+            .max_attempts(3)
+            .fixed_backoff(Duration::from_millis(1))
+            .no_jitter()
+            .escalate_on_exhaustion(ErrorSeverity::Critical)
+            .build()
+            .expect("Should build valid config");
+
+        let executor = RetryExecutor::new(config, AlwaysRetry);
+        let counter = Arc::new(AtomicU32::new(0));
+        let counter_clone = Arc::clone(&counter);
+
+        let outcome = executor
+            .execute_with_outcome(|| {
+                let c = Arc::clone(&counter_clone);
+                async move {
+                    if c.fetch_add(1, Ordering::SeqCst) < 2 {
+                        Err(CommonError::timeout("block sync", Duration::from_secs(5)))
+                    } else {
+                        Ok(7)
+                    }
+                }
+            })
+            .await;
+
+        assert_eq!(outcome.attempts, 3, "Should succeed on the final attempt");
+        assert_eq!(outcome.into_result().expect("final attempt should succeed"), 7);
+    }
+
+    /// Tests that exhaustion without escalation keeps the plain warning-level
+    /// error.
+    #[test]
+    fn test_retry_error_classification_without_escalation() {
+        let err = RetryError::<CommonError>::AttemptsExhausted { attempts: 3 };
+        assert_eq!(err.severity(), ErrorSeverity::Warning);
+        assert!(!err.is_critical());
+
+        let err = RetryError::NonRetryable { source: CommonError::config("bad") };
+        assert_eq!(err.severity(), ErrorSeverity::Error);
+    }
+
     /// Tests NeverRetry policy stops immediately without retrying.
     ///
     /// Verifies:
@@ -193,6 +193,9 @@ impl SqlCipherPool {
 
                 debug!("Connection acquired in {}ms", duration_ms);
 
+                // A previous holder may have left a query deadline installed
+                conn.progress_handler(0, None::<fn() -> bool>);
+
                 // Wrap in our connection type (conn is already PooledConnection)
                 Ok(SqlCipherConnection::new(conn))
             }
@@ -29,8 +29,9 @@ pub mod retry;
 // Re-export commonly used types from retry
 // Re-export commonly used types from queue
 pub use queue::{
-    CompressionAlgorithm, CompressionService, ItemStatus, Priority, QueueConfig, QueueError,
-    QueueMetrics, QueueMetricsSnapshot, QueueResult, SyncItem, SyncQueue,
+    CompressionAlgorithm, CompressionPolicy, CompressionService, ItemStatus, PressureLevel,
+    PressureThresholds, Priority, QueueConfig, QueueError, QueueMetrics, QueueMetricsSnapshot,
+    QueuePressure, QueueResult, SyncItem, SyncQueue,
 };
 // Re-export retry types
 pub use retry::{
@@ -3,17 +3,131 @@ use std::io::{Read, Write};
 use flate2::read::{GzDecoder, ZlibDecoder};
 use flate2::write::{GzEncoder, ZlibEncoder};
 use flate2::Compression;
+use serde::{Deserialize, Serialize};
 
 use crate::error::CommonError;
 use crate::sync::queue::errors::QueueResult;
 
 /// Compression algorithms supported
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CompressionAlgorithm {
     Gzip,
     Zlib,
 }
 
+impl CompressionAlgorithm {
+    /// Lowercase algorithm name, as recorded in persistence metadata
+    pub fn name(self) -> &'static str {
+        match self {
+            CompressionAlgorithm::Gzip => "gzip",
+            CompressionAlgorithm::Zlib => "zlib",
+        }
+    }
+
+    fn frame_tag(self) -> u8 {
+        match self {
+            CompressionAlgorithm::Gzip => FRAME_TAG_GZIP,
+            CompressionAlgorithm::Zlib => FRAME_TAG_ZLIB,
+        }
+    }
+}
+
+/// First byte of a framed payload. Distinct from the gzip (0x1f) and zlib
+/// (0x78) magic bytes and from JSON, so unframed legacy data is detectable.
+const FRAME_MAGIC: u8 = 0xC7;
+const FRAME_TAG_NONE: u8 = 0;
+const FRAME_TAG_GZIP: u8 = 1;
+const FRAME_TAG_ZLIB: u8 = 2;
+const FRAME_HEADER_LEN: usize = 2;
+
+/// Default payload size (bytes) below which compression is skipped
+pub const DEFAULT_COMPRESSION_THRESHOLD: usize = 512;
+
+/// Per-payload compression choice
+///
+/// Payloads below `threshold` are stored as-is, since headers and
+/// dictionary warm-up make compression a net loss for them. Larger payloads
+/// use `algorithm`, falling back to uncompressed when the result would not be
+/// smaller (already-compressed or random content). The choice is recorded in
+/// a two-byte frame header (`FRAME_MAGIC`, algorithm tag), so
+/// [`CompressionPolicy::decode`] needs no configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressionPolicy {
+    pub algorithm: CompressionAlgorithm,
+    pub level: u32,
+    pub threshold: usize,
+}
+
+impl CompressionPolicy {
+    /// Create a policy compressing payloads of at least `threshold` bytes
+    pub fn new(algorithm: CompressionAlgorithm, level: u32, threshold: usize) -> Self {
+        Self { algorithm, level: level.min(9), threshold }
+    }
+
+    /// Algorithm to try for `data`, or `None` if it is below the threshold
+    pub fn choose(&self, data: &[u8]) -> Option<CompressionAlgorithm> {
+        (data.len() >= self.threshold).then_some(self.algorithm)
+    }
+
+    /// Compress `data` according to the policy and frame the result
+    pub fn encode(&self, data: &[u8]) -> QueueResult<Vec<u8>> {
+        let compressed = match self.choose(data) {
+            Some(algorithm) => {
+                let compressed = CompressionService::new(algorithm, self.level).compress(data)?;
+                (compressed.len() < data.len()).then_some((algorithm, compressed))
+            }
+            None => None,
+        };
+
+        let (tag, body) = match &compressed {
+            Some((algorithm, compressed)) => (algorithm.frame_tag(), compressed.as_slice()),
+            None => (FRAME_TAG_NONE, data),
+        };
+
+        let mut frame = Vec::with_capacity(FRAME_HEADER_LEN + body.len());
+        frame.push(FRAME_MAGIC);
+        frame.push(tag);
+        frame.extend_from_slice(body);
+        Ok(frame)
+    }
+
+    /// Decode a frame produced by [`CompressionPolicy::encode`]
+    pub fn decode(frame: &[u8]) -> QueueResult<Vec<u8>> {
+        let body = frame.get(FRAME_HEADER_LEN..).unwrap_or_default();
+        match Self::framed_algorithm(frame)? {
+            Some(algorithm) => CompressionService::new(algorithm, 0).decompress(body),
+            None => Ok(body.to_vec()),
+        }
+    }
+
+    /// Algorithm recorded in a frame header (`None` for uncompressed)
+    pub fn framed_algorithm(frame: &[u8]) -> QueueResult<Option<CompressionAlgorithm>> {
+        if !Self::is_framed(frame) {
+            return Err(CommonError::internal("Payload is not a compression frame").into());
+        }
+
+        match frame[1] {
+            FRAME_TAG_NONE => Ok(None),
+            FRAME_TAG_GZIP => Ok(Some(CompressionAlgorithm::Gzip)),
+            FRAME_TAG_ZLIB => Ok(Some(CompressionAlgorithm::Zlib)),
+            tag => {
+                Err(CommonError::internal(format!("Unknown compression frame tag: {tag}")).into())
+            }
+        }
+    }
+
+    /// Whether `data` starts with a compression frame header
+    pub fn is_framed(data: &[u8]) -> bool {
+        data.len() >= FRAME_HEADER_LEN && data[0] == FRAME_MAGIC
+    }
+}
+
+impl Default for CompressionPolicy {
+    fn default() -> Self {
+        Self::new(CompressionAlgorithm::Gzip, 6, DEFAULT_COMPRESSION_THRESHOLD)
+    }
+}
+
 /// Compression service for queue data
 pub struct CompressionService {
     algorithm: CompressionAlgorithm,
@@ -386,4 +500,78 @@ mod tests {
 
         assert_eq!(decompressed, original);
     }
+
+    /// Validates `CompressionPolicy::encode` behavior for payloads below the
+    /// threshold.
+    ///
+    /// Assertions:
+    /// - Confirms the frame records no algorithm.
+    /// - Confirms `decoded` equals `original`.
+    #[test]
+    fn test_policy_stores_tiny_payload_uncompressed() {
+        let policy = CompressionPolicy::new(CompressionAlgorithm::Zlib, 6, 64);
+        let original = b"{\"id\":1}";
+
+        let frame = policy.encode(original).unwrap();
+        assert_eq!(CompressionPolicy::framed_algorithm(&frame).unwrap(), None);
+        assert_eq!(frame.len(), original.len() + FRAME_HEADER_LEN);
+
+        let decoded = CompressionPolicy::decode(&frame).unwrap();
+        assert_eq!(decoded, original);
+    }
+
+    /// Validates `CompressionPolicy::encode` behavior for a large compressible
+    /// payload.
+    ///
+    /// Assertions:
+    /// - Confirms the frame records the configured algorithm.
+    /// - Ensures the frame is smaller than the payload.
+    /// - Confirms `decoded` equals `original`.
+    #[test]
+    fn test_policy_compresses_large_payload_with_configured_algorithm() {
+        let original = b"{\"activity\":\"editing\",\"app\":\"Xcode\"}".repeat(100);
+
+        for algorithm in [CompressionAlgorithm::Gzip, CompressionAlgorithm::Zlib] {
+            let policy = CompressionPolicy::new(algorithm, 6, 64);
+
+            let frame = policy.encode(&original).unwrap();
+            assert_eq!(CompressionPolicy::framed_algorithm(&frame).unwrap(), Some(algorithm));
+            assert!(frame.len() < original.len());
+
+            let decoded = CompressionPolicy::decode(&frame).unwrap();
+            assert_eq!(decoded, original);
+        }
+    }
+
+    /// Validates `CompressionPolicy::encode` behavior when compression would
+    /// grow the payload.
+    ///
+    /// Assertions:
+    /// - Confirms the frame records no algorithm.
+    /// - Confirms `decoded` equals `original`.
+    #[test]
+    fn test_policy_skips_incompressible_payload() {
+        let policy = CompressionPolicy::new(CompressionAlgorithm::Gzip, 6, 16);
+        // Already-compressed data does not shrink further
+        let original = CompressionService::default().compress(&b"seed data ".repeat(50)).unwrap();
+
+        let frame = policy.encode(&original).unwrap();
+        assert_eq!(CompressionPolicy::framed_algorithm(&frame).unwrap(), None);
+
+        let decoded = CompressionPolicy::decode(&frame).unwrap();
+        assert_eq!(decoded, original);
+    }
+
+    /// Validates `CompressionPolicy::decode` behavior for unframed input.
+    ///
+    /// Assertions:
+    /// - Ensures legacy gzip data is not mistaken for a frame.
+    /// - Ensures decoding an unknown tag fails.
+    #[test]
+    fn test_policy_rejects_unframed_data() {
+        let legacy = CompressionService::default().compress(b"legacy").unwrap();
+        assert!(!CompressionPolicy::is_framed(&legacy));
+        assert!(CompressionPolicy::decode(&legacy).is_err());
+        assert!(CompressionPolicy::decode(&[FRAME_MAGIC, 9, 0]).is_err());
+    }
 }
@@ -4,13 +4,15 @@ use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use tokio::runtime::Handle;
-use tokio::sync::Notify;
+use tokio::sync::{watch, Notify};
 use tracing::{debug, error, info, instrument, warn};
 
+use super::compression::CompressionPolicy;
 use super::errors::{QueueError, QueueResult};
 use super::maintenance::{MaintenanceService, PriorityItem, QueueState};
 use super::metrics::QueueMetrics;
 use super::persistence::PersistenceService;
+use super::pressure::QueuePressure;
 use super::types::{ItemStatus, QueueConfig, SyncItem};
 use crate::error::CommonError;
 use crate::sync::retry::{CircuitBreaker, RetryStrategy};
@@ -39,6 +41,7 @@ pub struct SyncQueue {
     state: Arc<RwLock<QueueState>>,
     config: Arc<QueueConfig>,
     metrics: Arc<QueueMetrics>,
+    pressure: Arc<watch::Sender<QueuePressure>>,
     shutdown: Arc<AtomicBool>,
     notify: Arc<Notify>,
     retry_strategy: Arc<RetryStrategy>,
@@ -69,6 +72,9 @@ impl SyncQueue {
         }));
 
         let metrics = Arc::new(QueueMetrics::new());
+        let (pressure, _) =
+            watch::channel(QueuePressure::new(0, config.max_capacity, &config.pressure_thresholds));
+        let pressure = Arc::new(pressure);
         let shutdown = Arc::new(AtomicBool::new(false));
         let notify = Arc::new(Notify::new());
 
@@ -88,7 +94,11 @@ impl SyncQueue {
             let mut service = PersistenceService::new(path.clone()).with_metrics(metrics.clone());
 
             if config.enable_compression {
-                service = service.with_compression(config.compression_level);
+                service = service.with_compression(CompressionPolicy::new(
+                    config.compression_algorithm,
+                    config.compression_level,
+                    config.compression_threshold,
+                ));
             }
 
             if config.enable_encryption {
@@ -114,6 +124,7 @@ impl SyncQueue {
             state,
             config: Arc::new(config),
             metrics,
+            pressure,
             shutdown,
             notify,
             retry_strategy,
@@ -139,6 +150,8 @@ impl SyncQueue {
             let service = service.clone();
             let state = self.state.clone();
             let metrics = self.metrics.clone();
+            let pressure = self.pressure.clone();
+            let config = self.config.clone();
 
             // Use blocking task for initial load
             std::thread::spawn(move || {
@@ -167,6 +180,7 @@ impl SyncQueue {
                                         .push(PriorityItem { item: item_arc, sequence: seq });
                                 }
                                 metrics.update_size(state.item_map.len());
+                                publish_pressure(&pressure, &config, state.item_map.len());
                                 info!("Loaded {} persisted items", state.item_map.len());
                             }
                             Err(e) => {
@@ -318,6 +332,7 @@ impl SyncQueue {
 
         self.metrics.record_enqueue(1);
         self.metrics.update_size(state.item_map.len());
+        self.publish_pressure(state.item_map.len());
 
         // Notify waiters
         self.notify.notify_one();
@@ -367,6 +382,7 @@ impl SyncQueue {
 
         self.metrics.record_enqueue(added_ids.len() as u64);
         self.metrics.update_size(state.item_map.len());
+        self.publish_pressure(state.item_map.len());
 
         // Notify waiters
         if !added_ids.is_empty() {
@@ -439,6 +455,7 @@ impl SyncQueue {
             // Update metrics
             self.metrics.record_dequeue(1);
             self.metrics.update_size(state.item_map.len());
+            self.publish_pressure(state.item_map.len());
 
             debug!("Item dequeued: {}", item_id);
 
@@ -505,6 +522,7 @@ impl SyncQueue {
 
             self.metrics.record_completion(completed_item.processing_duration_ms);
             self.metrics.update_size(state.item_map.len());
+            self.publish_pressure(state.item_map.len());
 
             // Record success in circuit breaker
             self.circuit_breaker.record_success()?;
@@ -553,6 +571,7 @@ impl SyncQueue {
             }
 
             self.metrics.update_size(state.item_map.len());
+            self.publish_pressure(state.item_map.len());
 
             // Record failure in circuit breaker
             self.circuit_breaker.record_failure()?;
@@ -576,6 +595,7 @@ impl SyncQueue {
 
             self.metrics.record_cancellation();
             self.metrics.update_size(state.item_map.len());
+            self.publish_pressure(state.item_map.len());
 
             Ok(())
         } else {
@@ -641,6 +661,7 @@ impl SyncQueue {
         state.sequence_counter = 0;
 
         self.metrics.update_size(0);
+        self.publish_pressure(0);
 
         info!("Queue cleared: {} items removed", count);
         Ok(count)
@@ -668,6 +689,27 @@ impl SyncQueue {
         self.metrics.snapshot()
     }
 
+    /// Current fill ratio (0.0 - 1.0) relative to `max_capacity`
+    ///
+    /// Items being processed still count, matching the capacity check in
+    /// [`push`](Self::push).
+    pub fn pressure(&self) -> f32 {
+        self.pressure.borrow().ratio
+    }
+
+    /// Subscribe to backpressure changes
+    ///
+    /// The receiver always holds the latest ratio, but is only marked changed
+    /// when the [`PressureLevel`](super::PressureLevel) crosses one of the
+    /// configured [`PressureThresholds`](super::PressureThresholds).
+    pub fn subscribe_pressure(&self) -> watch::Receiver<QueuePressure> {
+        self.pressure.subscribe()
+    }
+
+    fn publish_pressure(&self, size: usize) {
+        publish_pressure(&self.pressure, &self.config, size);
+    }
+
     /// Force persistence
     pub async fn persist(&self) -> QueueResult<()> {
         if let Some(ref service) = self.persistence_service {
@@ -714,12 +756,23 @@ impl SyncQueue {
     }
 }
 
+/// Update the shared pressure state, waking subscribers only on a level change
+fn publish_pressure(sender: &watch::Sender<QueuePressure>, config: &QueueConfig, size: usize) {
+    let next = QueuePressure::new(size, config.max_capacity, &config.pressure_thresholds);
+    sender.send_if_modified(|current| {
+        let level_changed = current.level != next.level;
+        *current = next;
+        level_changed
+    });
+}
+
 impl Clone for SyncQueue {
     fn clone(&self) -> Self {
         Self {
             state: self.state.clone(),
             config: self.config.clone(),
             metrics: self.metrics.clone(),
+            pressure: self.pressure.clone(),
             shutdown: self.shutdown.clone(),
             notify: self.notify.clone(),
             retry_strategy: self.retry_strategy.clone(),
@@ -8,12 +8,16 @@ mod errors;
 mod maintenance;
 pub mod metrics;
 mod persistence;
+mod pressure;
 mod types;
 
-pub use self::compression::{CompressionAlgorithm, CompressionService};
+pub use self::compression::{
+    CompressionAlgorithm, CompressionPolicy, CompressionService, DEFAULT_COMPRESSION_THRESHOLD,
+};
 pub use self::core::SyncQueue;
 // Re-export for backward compatibility
 pub use self::core::SyncQueue as Queue;
 pub use self::errors::{QueueError, QueueResult};
 pub use self::metrics::{QueueMetrics, QueueMetricsSnapshot};
+pub use self::pressure::{PressureLevel, PressureThresholds, QueuePressure};
 pub use self::types::{ItemStatus, Priority, QueueConfig, SyncItem};
@@ -7,7 +7,9 @@ use tokio::io::AsyncWriteExt;
 use tracing::{debug, info, instrument, warn};
 
 use crate::error::CommonError;
-use crate::sync::queue::compression::{CompressionAlgorithm, CompressionService};
+use crate::sync::queue::compression::{
+    CompressionAlgorithm, CompressionPolicy, CompressionService,
+};
 use crate::sync::queue::encryption::EncryptionService;
 use crate::sync::queue::errors::{QueueError, QueueResult};
 use crate::sync::queue::metrics::QueueMetrics;
@@ -17,6 +19,9 @@ use crate::EncryptedData;
 /// Persistence format version
 const PERSISTENCE_VERSION: u32 = 1;
 
+/// Leading bytes of a gzip stream, as written before compression framing
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
 /// Persistence metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PersistenceMetadata {
@@ -40,7 +45,7 @@ pub struct PersistedQueue {
 /// Queue persistence service
 pub struct PersistenceService {
     path: PathBuf,
-    compression: Option<CompressionService>,
+    compression: Option<CompressionPolicy>,
     encryption: Option<EncryptionService>,
     metrics: Option<std::sync::Arc<QueueMetrics>>,
 }
@@ -51,9 +56,9 @@ impl PersistenceService {
         Self { path, compression: None, encryption: None, metrics: None }
     }
 
-    /// Enable compression
-    pub fn with_compression(mut self, level: u32) -> Self {
-        self.compression = Some(CompressionService::new(CompressionAlgorithm::Gzip, level));
+    /// Enable compression, choosing per save according to `policy`
+    pub fn with_compression(mut self, policy: CompressionPolicy) -> Self {
+        self.compression = Some(policy);
         self
     }
 
@@ -84,7 +89,10 @@ impl PersistenceService {
             item_count: items.len(),
             compressed: self.compression.is_some(),
             encrypted: self.encryption.is_some(),
-            compression_algorithm: self.compression.as_ref().map(|_| "gzip".to_string()),
+            compression_algorithm: self
+                .compression
+                .as_ref()
+                .map(|policy| policy.algorithm.name().to_string()),
             encryption_algorithm: self.encryption.as_ref().map(|_| "AES-256-GCM".to_string()),
             checksum: None,
         };
@@ -95,9 +103,9 @@ impl PersistenceService {
         let mut data = serde_json::to_vec(&queue_data)?;
         let original_size = data.len();
 
-        // Apply compression if enabled
-        if let Some(ref compression) = self.compression {
-            data = compression.compress(&data)?;
+        // Apply compression if enabled; the frame records whether it was used
+        if let Some(ref policy) = self.compression {
+            data = policy.encode(&data)?;
             let saved = original_size.saturating_sub(data.len()) as u64;
             if let Some(ref metrics) = self.metrics {
                 metrics.record_compression_savings(saved);
@@ -192,12 +200,14 @@ impl PersistenceService {
             }
         }
 
-        // Decompress if needed
-        if self.compression.is_some() {
-            if let Some(ref compression) = self.compression {
-                data = compression.decompress(&data)?;
-                debug!("Decompressed to {} bytes", data.len());
-            }
+        // Decompress if needed. Files written before compression framing
+        // are bare gzip streams.
+        if CompressionPolicy::is_framed(&data) {
+            data = CompressionPolicy::decode(&data)?;
+            debug!("Decoded to {} bytes", data.len());
+        } else if data.starts_with(&GZIP_MAGIC) {
+            data = CompressionService::new(CompressionAlgorithm::Gzip, 0).decompress(&data)?;
+            debug!("Decompressed legacy gzip to {} bytes", data.len());
         }
 
         // Deserialize
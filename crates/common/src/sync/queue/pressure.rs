@@ -0,0 +1,117 @@
+//! Backpressure signalling for producers
+//!
+//! The queue publishes its fill ratio on a `tokio::sync::watch` channel.
+//! Subscribers are only woken when the ratio crosses one of the configured
+//! thresholds, so producers can slow down before pushes start failing with
+//! `CapacityExceeded` without reacting to every single push and pop.
+
+use serde::{Deserialize, Serialize};
+
+/// Coarse backpressure level derived from the fill ratio
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum PressureLevel {
+    /// Below the elevated threshold
+    Normal,
+    /// At or above the elevated threshold: producers should slow down
+    Elevated,
+    /// At or above the critical threshold: producers should hold off
+    Critical,
+}
+
+/// Fill ratios (0.0 - 1.0) at which the pressure level changes
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PressureThresholds {
+    pub elevated: f32,
+    pub critical: f32,
+}
+
+impl Default for PressureThresholds {
+    fn default() -> Self {
+        Self { elevated: 0.75, critical: 0.9 }
+    }
+}
+
+impl PressureThresholds {
+    /// Level for a fill `ratio`
+    pub fn level(&self, ratio: f32) -> PressureLevel {
+        if ratio >= self.critical {
+            PressureLevel::Critical
+        } else if ratio >= self.elevated {
+            PressureLevel::Elevated
+        } else {
+            PressureLevel::Normal
+        }
+    }
+
+    /// Validate threshold ordering and range
+    pub fn validate(&self) -> Result<(), String> {
+        let in_range = |value: f32| value > 0.0 && value <= 1.0;
+        if !in_range(self.elevated) || !in_range(self.critical) {
+            return Err("Pressure thresholds must be within (0.0, 1.0]".to_string());
+        }
+        if self.elevated > self.critical {
+            return Err("Elevated pressure threshold cannot exceed critical".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Queue fill state published to subscribers
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct QueuePressure {
+    /// Items held (including those being processed) divided by capacity
+    pub ratio: f32,
+    /// Level of `ratio` under the queue's thresholds
+    pub level: PressureLevel,
+}
+
+impl QueuePressure {
+    /// Pressure of a queue holding `size` of `capacity` items
+    pub fn new(size: usize, capacity: usize, thresholds: &PressureThresholds) -> Self {
+        let ratio = if capacity == 0 { 1.0 } else { (size as f32 / capacity as f32).min(1.0) };
+        Self { ratio, level: thresholds.level(ratio) }
+    }
+
+    /// True at `Elevated` or above
+    pub fn is_under_pressure(&self) -> bool {
+        self.level >= PressureLevel::Elevated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for sync::queue::pressure.
+    use super::*;
+
+    #[test]
+    fn level_follows_thresholds() {
+        let thresholds = PressureThresholds { elevated: 0.5, critical: 0.8 };
+
+        assert_eq!(thresholds.level(0.0), PressureLevel::Normal);
+        assert_eq!(thresholds.level(0.49), PressureLevel::Normal);
+        assert_eq!(thresholds.level(0.5), PressureLevel::Elevated);
+        assert_eq!(thresholds.level(0.8), PressureLevel::Critical);
+        assert_eq!(thresholds.level(1.0), PressureLevel::Critical);
+    }
+
+    #[test]
+    fn pressure_ratio_is_fill_level() {
+        let thresholds = PressureThresholds::default();
+
+        let pressure = QueuePressure::new(25, 100, &thresholds);
+        assert!((pressure.ratio - 0.25).abs() < f32::EPSILON);
+        assert!(!pressure.is_under_pressure());
+
+        let pressure = QueuePressure::new(95, 100, &thresholds);
+        assert_eq!(pressure.level, PressureLevel::Critical);
+        assert!(pressure.is_under_pressure());
+    }
+
+    #[test]
+    fn thresholds_are_validated() {
+        assert!(PressureThresholds::default().validate().is_ok());
+        assert!(PressureThresholds { elevated: 0.9, critical: 0.5 }.validate().is_err());
+        assert!(PressureThresholds { elevated: 0.0, critical: 0.5 }.validate().is_err());
+        assert!(PressureThresholds { elevated: 0.5, critical: 1.5 }.validate().is_err());
+    }
+}
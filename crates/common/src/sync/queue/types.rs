@@ -6,6 +6,9 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::compression::{CompressionAlgorithm, DEFAULT_COMPRESSION_THRESHOLD};
+use super::pressure::PressureThresholds;
+
 /// Sync item priority levels
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Priority {
@@ -216,7 +219,10 @@ pub struct QueueConfig {
     pub persistence_interval: Duration,
     pub enable_deduplication: bool,
     pub enable_compression: bool,
+    pub compression_algorithm: CompressionAlgorithm,
     pub compression_level: u32,
+    /// Payloads smaller than this (bytes) are persisted uncompressed
+    pub compression_threshold: usize,
     pub enable_encryption: bool,
     pub encryption_key: Option<Vec<u8>>,
     pub retention_period: Duration,
@@ -226,6 +232,7 @@ pub struct QueueConfig {
     pub heap_cleanup_threshold: usize,
     pub enable_partitioning: bool,
     pub partition_count: usize,
+    pub pressure_thresholds: PressureThresholds,
 }
 
 impl Default for QueueConfig {
@@ -237,7 +244,9 @@ impl Default for QueueConfig {
             persistence_interval: Duration::from_secs(30),
             enable_deduplication: true,
             enable_compression: true,
+            compression_algorithm: CompressionAlgorithm::Gzip,
             compression_level: 6,
+            compression_threshold: DEFAULT_COMPRESSION_THRESHOLD,
             enable_encryption: false,
             encryption_key: None,
             retention_period: Duration::from_secs(7 * 24 * 3600), // 7 days
@@ -247,6 +256,7 @@ impl Default for QueueConfig {
             heap_cleanup_threshold: 1000,
             enable_partitioning: false,
             partition_count: 4,
+            pressure_thresholds: PressureThresholds::default(),
         }
     }
 }
@@ -309,6 +319,8 @@ impl QueueConfig {
             return Err("Partition count must be greater than 0".to_string());
         }
 
+        self.pressure_thresholds.validate()?;
+
         Ok(())
     }
 }
@@ -333,6 +333,13 @@ impl RetryStrategy {
                         self.finish_span(&instrumentation, |_| {});
                         RetryError::operation_failed(source)
                     }
+                    CoreRetryError::Escalated { source, .. } => {
+                        // Exhausted, but the final error is kept for the caller
+                        self.finish_span(&instrumentation, |span| {
+                            span.record_exhausted(metrics.total_delay);
+                        });
+                        RetryError::operation_failed(source)
+                    }
                 };
                 Err(mapped)
             }
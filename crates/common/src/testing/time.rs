@@ -26,6 +26,7 @@
 // and handles edge cases gracefully
 #![allow(clippy::missing_panics_doc)]
 
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
@@ -51,6 +52,17 @@ pub trait Clock: Send + Sync {
     fn millis_since_epoch(&self) -> u64 {
         self.system_time().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
     }
+
+    /// Note that a wait on this clock started
+    ///
+    /// Code that sleeps until the clock reaches a deadline calls this before
+    /// waiting and [`Clock::wait_finished`] once it stops. The default does
+    /// nothing; [`MockClock`] counts waiters so tests can advance time once
+    /// the code under test is parked on the clock.
+    fn wait_started(&self) {}
+
+    /// Note that a wait announced with [`Clock::wait_started`] ended
+    fn wait_finished(&self) {}
 }
 
 /// Real system clock implementation
@@ -107,6 +119,7 @@ pub struct MockClock {
     start: Instant,
     elapsed: Arc<Mutex<Duration>>,
     base_system_time: SystemTime,
+    waiters: Arc<AtomicUsize>,
 }
 
 impl MockClock {
@@ -119,6 +132,7 @@ impl MockClock {
             start: Instant::now(),
             elapsed: Arc::new(Mutex::new(Duration::ZERO)),
             base_system_time: SystemTime::now(),
+            waiters: Arc::new(AtomicUsize::new(0)),
         }
     }
 
@@ -173,6 +187,15 @@ impl MockClock {
         // Test utility: panic on poisoned mutex to fail tests early
         *self.elapsed.lock().expect("mutex poisoned")
     }
+
+    /// Number of waits currently parked on this clock
+    ///
+    /// Advance the clock once this is non-zero to be sure the waiting code
+    /// computed its deadline before the jump.
+    #[must_use]
+    pub fn waiters(&self) -> usize {
+        self.waiters.load(Ordering::SeqCst)
+    }
 }
 
 impl Default for MockClock {
@@ -191,6 +214,14 @@ impl Clock for MockClock {
         // Test utility: panic on poisoned mutex to fail tests early
         self.base_system_time + *self.elapsed.lock().expect("mutex poisoned")
     }
+
+    fn wait_started(&self) {
+        self.waiters.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn wait_finished(&self) {
+        self.waiters.fetch_sub(1, Ordering::SeqCst);
+    }
 }
 
 #[cfg(test)]
@@ -303,4 +334,22 @@ mod tests {
 
         assert_eq!(clock.elapsed(), Duration::from_secs(6));
     }
+
+    /// Validates `MockClock::waiters` counting across clones.
+    ///
+    /// Assertions:
+    /// - Confirms a wait started on a clone is visible on the original.
+    /// - Confirms the count drops back to zero once the wait finishes.
+    #[test]
+    fn test_mock_clock_waiters() {
+        let clock = MockClock::new();
+        let shared = clock.clone();
+        assert_eq!(clock.waiters(), 0);
+
+        shared.wait_started();
+        assert_eq!(clock.waiters(), 1);
+
+        shared.wait_finished();
+        assert_eq!(clock.waiters(), 0);
+    }
 }
@@ -100,6 +100,207 @@ impl CronExpression {
 
         None
     }
+
+    /// Describe the schedule in plain English
+    ///
+    /// Common patterns get a short phrase ("every 15 minutes", "daily at
+    /// 00:00", "every Monday at 09:00"); anything else falls back to a
+    /// clause per restricted field, e.g. `*/5 9-17 * * 1-5` becomes "every 5
+    /// minutes, during hours 9 through 17, on Monday through Friday".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "runtime")]
+    /// # {
+    /// use pulsearc_common::time::cron::CronExpression;
+    ///
+    /// let cron = CronExpression::parse("0 9 * * 1").unwrap();
+    /// assert_eq!(cron.describe(), "every Monday at 09:00");
+    /// # }
+    /// ```
+    pub fn describe(&self) -> String {
+        let days_unrestricted = self.day == CronField::Any
+            && self.month == CronField::Any
+            && self.weekday == CronField::Any;
+
+        if let (CronField::Single(minute), CronField::Single(hour)) = (&self.minute, &self.hour) {
+            let at = format!("at {:02}:{:02}", hour, minute);
+
+            if days_unrestricted {
+                return format!("daily {}", at);
+            }
+            if self.day == CronField::Any && self.month == CronField::Any {
+                match &self.weekday {
+                    CronField::Single(weekday) => {
+                        return format!("every {} {}", weekday_name(*weekday), at);
+                    }
+                    CronField::Range(1, 5) => return format!("every weekday {}", at),
+                    _ => {}
+                }
+            }
+            if let (CronField::Single(day), CronField::Any, CronField::Any) =
+                (&self.day, &self.month, &self.weekday)
+            {
+                return format!("monthly on day {} {}", day, at);
+            }
+
+            let mut clauses = vec![at];
+            clauses.extend(self.day_clauses());
+            return clauses.join(", ");
+        }
+
+        let mut clauses = self.time_clauses();
+        clauses.extend(self.day_clauses());
+        clauses.join(", ")
+    }
+
+    /// Clauses for the minute and hour fields when the time is not fixed
+    fn time_clauses(&self) -> Vec<String> {
+        match (&self.minute, &self.hour) {
+            (CronField::Any, CronField::Any) => return vec!["every minute".to_string()],
+            (CronField::Single(0), CronField::Any) => return vec!["every hour".to_string()],
+            (CronField::Single(0), CronField::Step(0, step)) => {
+                return vec![every(*step, "hour")];
+            }
+            _ => {}
+        }
+
+        let minute = match &self.minute {
+            CronField::Any => "every minute".to_string(),
+            CronField::Single(v) => format!("at minute {}", v),
+            CronField::List(values) => {
+                format!("at minutes {}", join_values(values, u32::to_string))
+            }
+            CronField::Range(start, end) => {
+                format!("every minute from minute {} through {}", start, end)
+            }
+            CronField::Step(start, step) => with_start(every(*step, "minute"), "minute", *start),
+        };
+
+        let hour = match &self.hour {
+            CronField::Any => None,
+            CronField::Single(v) => Some(format!("during hour {}", v)),
+            CronField::List(values) => {
+                Some(format!("during hours {}", join_values(values, u32::to_string)))
+            }
+            CronField::Range(start, end) => Some(format!("during hours {} through {}", start, end)),
+            CronField::Step(start, step) => Some(with_start(every(*step, "hour"), "hour", *start)),
+        };
+
+        std::iter::once(minute).chain(hour).collect()
+    }
+
+    /// Clauses for the day-of-month, month and weekday fields (unrestricted
+    /// fields are omitted)
+    fn day_clauses(&self) -> Vec<String> {
+        let day = match &self.day {
+            CronField::Any => None,
+            CronField::Single(v) => Some(format!("on day {} of the month", v)),
+            CronField::List(values) => {
+                Some(format!("on days {} of the month", join_values(values, u32::to_string)))
+            }
+            CronField::Range(start, end) => {
+                Some(format!("on days {} through {} of the month", start, end))
+            }
+            CronField::Step(start, step) => Some(if *start <= 1 {
+                format!("{} of the month", every(*step, "day"))
+            } else {
+                format!("{} of the month starting on day {}", every(*step, "day"), start)
+            }),
+        };
+
+        let month = match &self.month {
+            CronField::Any => None,
+            CronField::Single(v) => Some(format!("in {}", month_name(*v))),
+            CronField::List(values) => {
+                Some(format!("in {}", join_values(values, |v| month_name(*v).to_string())))
+            }
+            CronField::Range(start, end) => {
+                Some(format!("from {} through {}", month_name(*start), month_name(*end)))
+            }
+            CronField::Step(start, step) => Some(if *start <= 1 {
+                every(*step, "month")
+            } else {
+                format!("{} starting in {}", every(*step, "month"), month_name(*start))
+            }),
+        };
+
+        let weekday = match &self.weekday {
+            CronField::Any => None,
+            CronField::Single(v) => Some(format!("on {}", weekday_name(*v))),
+            CronField::List(values) => {
+                Some(format!("on {}", join_values(values, |v| weekday_name(*v).to_string())))
+            }
+            CronField::Range(start, end) => {
+                Some(format!("on {} through {}", weekday_name(*start), weekday_name(*end)))
+            }
+            CronField::Step(start, step) => Some(if *start == 0 {
+                format!("{} of the week", every(*step, "day"))
+            } else {
+                format!("{} of the week starting on {}", every(*step, "day"), weekday_name(*start))
+            }),
+        };
+
+        [day, month, weekday].into_iter().flatten().collect()
+    }
+}
+
+/// `"every minute"` for a step of 1, `"every 15 minutes"` otherwise
+fn every(step: u32, unit: &str) -> String {
+    if step == 1 {
+        format!("every {}", unit)
+    } else {
+        format!("every {} {}s", step, unit)
+    }
+}
+
+/// Append the start value of a step field when it is not the default of 0
+fn with_start(phrase: String, unit: &str, start: u32) -> String {
+    if start == 0 {
+        phrase
+    } else {
+        format!("{} starting at {} {}", phrase, unit, start)
+    }
+}
+
+/// Join values as `"a"`, `"a and b"` or `"a, b and c"`
+fn join_values(values: &[u32], name: impl Fn(&u32) -> String) -> String {
+    let names: Vec<String> = values.iter().map(name).collect();
+    match names.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} and {}", rest.join(", "), last),
+        Some((last, _)) => last.clone(),
+        None => String::new(),
+    }
+}
+
+fn weekday_name(value: u32) -> &'static str {
+    match value {
+        0 => "Sunday",
+        1 => "Monday",
+        2 => "Tuesday",
+        3 => "Wednesday",
+        4 => "Thursday",
+        5 => "Friday",
+        _ => "Saturday",
+    }
+}
+
+fn month_name(value: u32) -> &'static str {
+    match value {
+        1 => "January",
+        2 => "February",
+        3 => "March",
+        4 => "April",
+        5 => "May",
+        6 => "June",
+        7 => "July",
+        8 => "August",
+        9 => "September",
+        10 => "October",
+        11 => "November",
+        _ => "December",
+    }
 }
 
 impl fmt::Display for CronExpression {
@@ -367,4 +568,47 @@ mod tests {
         let next = schedule.next();
         assert!(next.is_some());
     }
+
+    /// Validates `CronExpression::describe` behavior for the common patterns
+    /// scenario.
+    ///
+    /// Assertions:
+    /// - Confirms `*/15 * * * *` describes as `"every 15 minutes"`.
+    /// - Confirms `0 0 * * *` describes as `"daily at 00:00"`.
+    /// - Confirms `0 9 * * 1` describes as `"every Monday at 09:00"`.
+    /// - Confirms hourly, weekday and monthly shorthands.
+    #[test]
+    fn test_describe_common_patterns() {
+        let describe = |expr: &str| CronExpression::parse(expr).unwrap().describe();
+
+        assert_eq!(describe("*/15 * * * *"), "every 15 minutes");
+        assert_eq!(describe("0 0 * * *"), "daily at 00:00");
+        assert_eq!(describe("0 9 * * 1"), "every Monday at 09:00");
+        assert_eq!(describe("* * * * *"), "every minute");
+        assert_eq!(describe("0 * * * *"), "every hour");
+        assert_eq!(describe("0 */6 * * *"), "every 6 hours");
+        assert_eq!(describe("30 8 * * 1-5"), "every weekday at 08:30");
+        assert_eq!(describe("0 2 1 * *"), "monthly on day 1 at 02:00");
+    }
+
+    /// Validates `CronExpression::describe` behavior for the complex
+    /// expression scenario.
+    ///
+    /// Assertions:
+    /// - Confirms each restricted field contributes a clause to the fallback
+    ///   description.
+    #[test]
+    fn test_describe_complex_expression() {
+        let cron = CronExpression::parse("*/5 9-17 1,15 1-6 1-5").unwrap();
+
+        assert_eq!(
+            cron.describe(),
+            "every 5 minutes, during hours 9 through 17, on days 1 and 15 of the month, from \
+             January through June, on Monday through Friday"
+        );
+        assert_eq!(
+            CronExpression::parse("15 10 * 3 0,6").unwrap().describe(),
+            "at 10:15, in March, on Sunday and Saturday"
+        );
+    }
 }
@@ -1,9 +1,18 @@
 //! Human-readable duration formatting
 //!
-//! Provides utilities to format durations into human-readable strings.
+//! Provides utilities to format durations into human-readable strings, and
+//! timestamps relative to "now" ("2 hours ago", "in 5 minutes").
 
 use std::time::Duration;
 
+use chrono::{DateTime, Utc};
+
+const SECS_PER_MINUTE: u64 = 60;
+const SECS_PER_HOUR: u64 = 3600;
+const SECS_PER_DAY: u64 = 86400;
+const DAYS_PER_MONTH: u64 = 30;
+const DAYS_PER_YEAR: u64 = 365;
+
 /// Format a duration into a human-readable string
 ///
 /// # Examples
@@ -153,6 +162,80 @@ pub fn format_duration_verbose(duration: Duration) -> String {
     parts.join(" ")
 }
 
+/// Format `then` relative to `now` ("2 hours ago", "in 5 minutes")
+///
+/// Differences under a minute in either direction are reported as
+/// `"just now"`. Larger differences are rounded to the nearest minute, hour,
+/// day, month (30 days) or year (365 days); from hours upwards the phrase is
+/// prefixed with "about" since the rounding is coarse.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "runtime")]
+/// # {
+/// use chrono::{Duration, Utc};
+/// use pulsearc_common::time::format::format_relative;
+///
+/// let now = Utc::now();
+/// assert_eq!(format_relative(now - Duration::seconds(30), now), "just now");
+/// assert_eq!(format_relative(now - Duration::minutes(90), now), "about 2 hours ago");
+/// assert_eq!(format_relative(now + Duration::minutes(5), now), "in 5 minutes");
+/// # }
+/// ```
+pub fn format_relative(then: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let delta = now.signed_duration_since(then).num_seconds();
+    let secs = delta.unsigned_abs();
+
+    if secs < SECS_PER_MINUTE {
+        return String::from("just now");
+    }
+
+    let (count, unit, approximate) = relative_unit(secs);
+    let plural = if count == 1 { "" } else { "s" };
+    let about = if approximate { "about " } else { "" };
+
+    if delta >= 0 {
+        format!("{about}{count} {unit}{plural} ago")
+    } else {
+        format!("in {about}{count} {unit}{plural}")
+    }
+}
+
+/// Pick the largest unit that keeps the rounded count readable
+///
+/// Each step rounds half up, so a value that rounds to the next unit's
+/// boundary (e.g. 59m 30s) moves up to that unit instead of reading "60
+/// minutes".
+fn relative_unit(secs: u64) -> (u64, &'static str, bool) {
+    let minutes = round_div(secs, SECS_PER_MINUTE);
+    if minutes < 60 {
+        return (minutes, "minute", false);
+    }
+
+    let hours = round_div(secs, SECS_PER_HOUR);
+    if hours < 24 {
+        return (hours, "hour", true);
+    }
+
+    let days = round_div(secs, SECS_PER_DAY);
+    if days < DAYS_PER_MONTH {
+        return (days, "day", true);
+    }
+
+    let months = round_div(days, DAYS_PER_MONTH);
+    if months < 12 {
+        return (months, "month", true);
+    }
+
+    (round_div(days, DAYS_PER_YEAR), "year", true)
+}
+
+/// Integer division rounding half up
+const fn round_div(value: u64, divisor: u64) -> u64 {
+    (value + divisor / 2) / divisor
+}
+
 #[cfg(test)]
 mod tests {
     //! Unit tests for time::format.
@@ -266,4 +349,55 @@ mod tests {
         assert_eq!(format_duration_verbose(Duration::from_secs(65)), "1 minute 5 seconds");
         assert_eq!(format_duration_verbose(Duration::from_secs(3665)), "1 hour 1 minute 5 seconds");
     }
+
+    /// Validates `format_relative` behavior for the just now threshold
+    /// scenario.
+    ///
+    /// Assertions:
+    /// - Confirms a 59s difference in either direction formats as `"just now"`.
+    /// - Confirms 60s formats as `"1 minute ago"` (singular).
+    #[test]
+    fn test_format_relative_just_now_threshold() {
+        let now = Utc::now();
+        assert_eq!(format_relative(now - chrono::Duration::seconds(59), now), "just now");
+        assert_eq!(format_relative(now + chrono::Duration::seconds(59), now), "just now");
+        assert_eq!(format_relative(now, now), "just now");
+        assert_eq!(format_relative(now - chrono::Duration::seconds(60), now), "1 minute ago");
+    }
+
+    /// Validates `format_relative` behavior for the rounding scenario.
+    ///
+    /// Assertions:
+    /// - Confirms 90 minutes formats as `"about 2 hours ago"`.
+    /// - Confirms 59m 30s rounds up to `"about 1 hour ago"` rather than `"60
+    ///   minutes ago"`.
+    /// - Confirms day, month and year units are rounded and pluralized.
+    #[test]
+    fn test_format_relative_rounds_to_nearest_unit() {
+        let now = Utc::now();
+        let ago = |secs: i64| format_relative(now - chrono::Duration::seconds(secs), now);
+
+        assert_eq!(ago(5 * 60 + 29), "5 minutes ago");
+        assert_eq!(ago(90 * 60), "about 2 hours ago");
+        assert_eq!(ago(59 * 60 + 30), "about 1 hour ago");
+        assert_eq!(ago(36 * 3600), "about 2 days ago");
+        assert_eq!(ago(45 * 86400), "about 2 months ago");
+        assert_eq!(ago(400 * 86400), "about 1 year ago");
+        assert_eq!(ago(3 * 365 * 86400), "about 3 years ago");
+    }
+
+    /// Validates `format_relative` behavior for the future time scenario.
+    ///
+    /// Assertions:
+    /// - Confirms future times are phrased as `"in ..."`.
+    #[test]
+    fn test_format_relative_future() {
+        let now = Utc::now();
+        let until = |secs: i64| format_relative(now + chrono::Duration::seconds(secs), now);
+
+        assert_eq!(until(5 * 60), "in 5 minutes");
+        assert_eq!(until(60), "in 1 minute");
+        assert_eq!(until(90 * 60), "in about 2 hours");
+        assert_eq!(until(86400), "in about 1 day");
+    }
 }
@@ -1,12 +1,16 @@
 //! Recurring intervals with jitter support
 //!
-//! Provides utilities for creating recurring intervals with optional jitter.
+//! Provides utilities for creating recurring intervals with optional jitter,
+//! and [`DriftCorrectingInterval`], which keeps ticks on their original
+//! schedule when handlers run long.
 
 use std::time::Duration;
 
 use rand::Rng;
 use tokio::time::{sleep, Instant, Interval as TokioInterval};
 
+use super::{Clock, SystemClock};
+
 /// Configuration for an interval
 #[derive(Debug, Clone)]
 pub struct IntervalConfig {
@@ -100,6 +104,135 @@ impl Interval {
     }
 }
 
+/// How a [`DriftCorrectingInterval`] handles ticks missed while a handler
+/// was running
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriftCorrection {
+    /// Fire the late tick once, then drop any other missed ticks and resume
+    /// at the next point on the original schedule
+    Skip,
+    /// Fire every missed tick back-to-back until caught up with the schedule
+    Burst,
+}
+
+/// A recurring interval whose ticks stay on the schedule
+/// `start, start + period, start + 2 * period, ...`
+///
+/// The next deadline is derived from the previous deadline rather than from
+/// when the previous tick was handled, so slow handlers do not push later
+/// ticks back. Missed ticks are skipped or replayed according to
+/// [`DriftCorrection`]. Time comes from a [`Clock`], so the schedule can be
+/// driven deterministically with `MockClock` via [`Self::poll_tick`].
+pub struct DriftCorrectingInterval<C: Clock = SystemClock> {
+    clock: C,
+    period: Duration,
+    mode: DriftCorrection,
+    next_deadline: std::time::Instant,
+    skipped_ticks: u64,
+    ticked: bool,
+}
+
+impl DriftCorrectingInterval<SystemClock> {
+    /// Create an interval on the system clock; the first tick is immediate
+    pub fn new(period: Duration, mode: DriftCorrection) -> Self {
+        Self::with_clock(period, mode, SystemClock)
+    }
+}
+
+impl<C: Clock> DriftCorrectingInterval<C> {
+    /// Create an interval on a custom clock; the first tick is immediate
+    ///
+    /// A zero `period` is treated as one millisecond.
+    pub fn with_clock(period: Duration, mode: DriftCorrection, clock: C) -> Self {
+        let next_deadline = clock.now();
+        Self {
+            clock,
+            period: period.max(Duration::from_millis(1)),
+            mode,
+            next_deadline,
+            skipped_ticks: 0,
+            ticked: false,
+        }
+    }
+
+    /// Tick period
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Change the tick period while keeping the schedule anchored at the
+    /// last tick
+    ///
+    /// The pending tick moves to the previous tick plus the new period, so a
+    /// shorter period takes effect without waiting out the old one. Before
+    /// the first tick the pending (immediate) tick is left alone. A zero
+    /// `period` is treated as one millisecond.
+    pub fn set_period(&mut self, period: Duration) {
+        let period = period.max(Duration::from_millis(1));
+        if self.ticked {
+            let previous = self.next_deadline - self.period;
+            self.next_deadline = previous + period;
+        }
+        self.period = period;
+    }
+
+    /// Missed-tick behaviour
+    pub fn mode(&self) -> DriftCorrection {
+        self.mode
+    }
+
+    /// Total number of ticks dropped in [`DriftCorrection::Skip`] mode
+    pub fn skipped_ticks(&self) -> u64 {
+        self.skipped_ticks
+    }
+
+    /// Time remaining until the next tick is due (zero if it is due now)
+    pub fn time_until_next(&self) -> Duration {
+        self.next_deadline.saturating_duration_since(self.clock.now())
+    }
+
+    /// Consume the next tick if it is due, returning its scheduled instant
+    ///
+    /// Returns `None` when the next tick lies in the future.
+    pub fn poll_tick(&mut self) -> Option<std::time::Instant> {
+        let now = self.clock.now();
+        if now < self.next_deadline {
+            return None;
+        }
+
+        let scheduled = self.next_deadline;
+        self.next_deadline = scheduled + self.period;
+        self.ticked = true;
+
+        if self.mode == DriftCorrection::Skip && self.next_deadline <= now {
+            // Jump to the first schedule point after `now`
+            let behind = now.duration_since(self.next_deadline).as_nanos();
+            let missed = behind / self.period.as_nanos() + 1;
+            let missed_u32 = u32::try_from(missed).unwrap_or(u32::MAX);
+            self.next_deadline += self.period.saturating_mul(missed_u32);
+            self.skipped_ticks = self.skipped_ticks.saturating_add(missed_u32.into());
+        }
+
+        Some(scheduled)
+    }
+
+    /// Wait for the next tick, returning its scheduled instant
+    pub async fn tick(&mut self) -> std::time::Instant {
+        loop {
+            if let Some(scheduled) = self.poll_tick() {
+                return scheduled;
+            }
+            sleep(self.time_until_next()).await;
+        }
+    }
+
+    /// Restart the schedule so the next tick is due immediately
+    pub fn reset(&mut self) {
+        self.next_deadline = self.clock.now();
+        self.ticked = false;
+    }
+}
+
 /// Create a simple interval
 pub fn interval(duration: Duration) -> Interval {
     Interval::simple(duration)
@@ -114,6 +247,7 @@ pub fn interval_with_jitter(duration: Duration, jitter: f64) -> Interval {
 mod tests {
     //! Unit tests for time::interval.
     use super::*;
+    use crate::time::MockClock;
 
     /// Validates `Interval::simple` behavior for the simple interval scenario.
     ///
@@ -195,4 +329,108 @@ mod tests {
         let config = IntervalConfig::new(Duration::from_secs(1)).with_jitter(-0.5);
         assert_eq!(config.jitter, Some(0.0));
     }
+
+    /// Validates `DriftCorrectingInterval::poll_tick` in skip mode when a
+    /// handler overruns by more than one period.
+    ///
+    /// Assertions:
+    /// - The late tick fires once with its original scheduled instant.
+    /// - The tick at `start + 20s` is skipped and counted.
+    /// - The next tick is due at `start + 30s`, on the original schedule.
+    #[test]
+    fn test_drift_correcting_interval_skips_missed_ticks() {
+        let clock = MockClock::new();
+        let start = clock.now();
+        let period = Duration::from_secs(10);
+        let mut interval =
+            DriftCorrectingInterval::with_clock(period, DriftCorrection::Skip, clock.clone());
+
+        assert_eq!(interval.poll_tick(), Some(start));
+        assert_eq!(interval.poll_tick(), None);
+
+        // Slow handler: 25s pass before the next poll
+        clock.advance(Duration::from_secs(25));
+
+        assert_eq!(interval.poll_tick(), Some(start + period));
+        assert_eq!(interval.poll_tick(), None);
+        assert_eq!(interval.skipped_ticks(), 1);
+        assert_eq!(interval.time_until_next(), Duration::from_secs(5));
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(interval.poll_tick(), Some(start + period * 3));
+    }
+
+    /// Validates `DriftCorrectingInterval::poll_tick` in burst mode when a
+    /// handler overruns by more than one period.
+    ///
+    /// Assertions:
+    /// - Both missed ticks fire back-to-back with their scheduled instants.
+    /// - No ticks are counted as skipped.
+    /// - The following tick stays on the original schedule.
+    #[test]
+    fn test_drift_correcting_interval_bursts_to_catch_up() {
+        let clock = MockClock::new();
+        let start = clock.now();
+        let period = Duration::from_secs(10);
+        let mut interval =
+            DriftCorrectingInterval::with_clock(period, DriftCorrection::Burst, clock.clone());
+
+        assert_eq!(interval.poll_tick(), Some(start));
+
+        clock.advance(Duration::from_secs(25));
+
+        assert_eq!(interval.poll_tick(), Some(start + period));
+        assert_eq!(interval.poll_tick(), Some(start + period * 2));
+        assert_eq!(interval.poll_tick(), None);
+        assert_eq!(interval.skipped_ticks(), 0);
+        assert_eq!(interval.time_until_next(), Duration::from_secs(5));
+    }
+
+    /// Validates that a handler finishing late within a period does not delay
+    /// the next tick.
+    ///
+    /// Assertions:
+    /// - The next tick is due `period` after the previous scheduled tick, not
+    ///   after the handler finished.
+    #[test]
+    fn test_drift_correcting_interval_does_not_drift() {
+        let clock = MockClock::new();
+        let mut interval = DriftCorrectingInterval::with_clock(
+            Duration::from_secs(10),
+            DriftCorrection::Skip,
+            clock.clone(),
+        );
+
+        interval.poll_tick();
+        clock.advance(Duration::from_secs(7));
+
+        assert_eq!(interval.time_until_next(), Duration::from_secs(3));
+    }
+
+    /// Validates `DriftCorrectingInterval::set_period` re-anchors the pending
+    /// tick on the previous one.
+    ///
+    /// Assertions:
+    /// - Shortening the period brings the pending tick forward.
+    /// - Lengthening the period pushes it back.
+    #[test]
+    fn test_drift_correcting_interval_set_period() {
+        let clock = MockClock::new();
+        let start = clock.now();
+        let mut interval = DriftCorrectingInterval::with_clock(
+            Duration::from_secs(30),
+            DriftCorrection::Skip,
+            clock.clone(),
+        );
+
+        assert_eq!(interval.poll_tick(), Some(start));
+        clock.advance(Duration::from_secs(4));
+
+        interval.set_period(Duration::from_secs(10));
+        assert_eq!(interval.period(), Duration::from_secs(10));
+        assert_eq!(interval.time_until_next(), Duration::from_secs(6));
+
+        interval.set_period(Duration::from_secs(60));
+        assert_eq!(interval.time_until_next(), Duration::from_secs(56));
+    }
 }
@@ -4,8 +4,8 @@
 //! - **Clock abstractions**: Real and mock time for testing (re-exported from
 //!   testing)
 //! - **[`duration`]**: Duration formatting and parsing
-//! - **[`format`]**: Human-readable duration formatting
-//! - **[`interval`]**: Recurring intervals with jitter
+//! - **[`format`]**: Human-readable duration and relative time formatting
+//! - **[`interval`]**: Recurring intervals with jitter or drift correction
 //! - **[`timer`]**: One-shot and recurring timers
 //! - **[`cron`]**: Cron expression parsing and evaluation
 //!
@@ -41,8 +41,8 @@ pub mod timer;
 // Re-export commonly used items
 pub use cron::{CronExpression, CronParseError, CronSchedule};
 pub use duration::{parse_duration, DurationParseError};
-pub use format::format_duration;
-pub use interval::{Interval, IntervalConfig};
+pub use format::{format_duration, format_relative};
+pub use interval::{DriftCorrectingInterval, DriftCorrection, Interval, IntervalConfig};
 pub use timer::{Timer, TimerHandle};
 
 // Re-export Clock abstractions from testing module
@@ -1,42 +1,72 @@
 //! One-shot and recurring timers
 //!
 //! Provides utilities for creating timers with cancellation support.
+//!
+//! A one-shot timer ends in exactly one of two states, cancelled or fired.
+//! Both transitions are a single compare-and-swap on shared state, so a
+//! cancel racing the fire has one winner and [`TimerHandle::cancel`] reports
+//! which one it was.
 
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicU8, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
 use tokio::time::sleep;
 
+use super::{Clock, SystemClock};
+
+const PENDING: u8 = 0;
+const CANCELLED: u8 = 1;
+const FIRED: u8 = 2;
+
 /// A timer handle that can be used to cancel a timer
 #[derive(Debug, Clone)]
 pub struct TimerHandle {
-    cancelled: Arc<AtomicBool>,
+    state: Arc<AtomicU8>,
 }
 
 impl TimerHandle {
     /// Create a new timer handle
     fn new() -> Self {
-        Self { cancelled: Arc::new(AtomicBool::new(false)) }
+        Self { state: Arc::new(AtomicU8::new(PENDING)) }
     }
 
     /// Cancel the timer
-    pub fn cancel(&self) {
-        self.cancelled.store(true, Ordering::SeqCst);
+    ///
+    /// Returns `true` if this call cancelled a pending timer, and `false` if
+    /// the timer had already fired or was already cancelled.
+    pub fn cancel(&self) -> bool {
+        self.state.compare_exchange(PENDING, CANCELLED, Ordering::SeqCst, Ordering::SeqCst).is_ok()
     }
 
     /// Check if the timer has been cancelled
     pub fn is_cancelled(&self) -> bool {
-        self.cancelled.load(Ordering::SeqCst)
+        self.state.load(Ordering::SeqCst) == CANCELLED
+    }
+
+    /// Check if the timer has fired
+    pub fn is_elapsed(&self) -> bool {
+        self.state.load(Ordering::SeqCst) == FIRED
+    }
+
+    /// Mark the timer as fired; returns `false` if it was cancelled (or had
+    /// already fired), in which case the callback must not run
+    fn fire(&self) -> bool {
+        self.state.compare_exchange(PENDING, FIRED, Ordering::SeqCst, Ordering::SeqCst).is_ok()
     }
 }
 
 /// A one-shot timer
-pub struct Timer {
+///
+/// The deadline is measured on a [`Clock`]; use [`Timer::after_with_clock`]
+/// with `MockClock` and [`Timer::poll`] for deterministic tests.
+pub struct Timer<C: Clock = SystemClock> {
     handle: TimerHandle,
+    clock: C,
+    deadline: std::time::Instant,
 }
 
-impl Timer {
+impl Timer<SystemClock> {
     /// Create a new timer that fires after a duration
     ///
     /// # Examples
@@ -56,8 +86,16 @@ impl Timer {
     /// }
     /// # }
     /// ```
-    pub fn after(_duration: Duration) -> Self {
-        Self { handle: TimerHandle::new() }
+    pub fn after(duration: Duration) -> Self {
+        Self::after_with_clock(duration, SystemClock)
+    }
+}
+
+impl<C: Clock> Timer<C> {
+    /// Create a timer that fires `duration` after now on `clock`
+    pub fn after_with_clock(duration: Duration, clock: C) -> Self {
+        let deadline = clock.now() + duration;
+        Self { handle: TimerHandle::new(), clock, deadline }
     }
 
     /// Get a handle to cancel the timer
@@ -66,14 +104,33 @@ impl Timer {
     }
 
     /// Wait for the timer to fire
+    ///
+    /// Marks the timer as fired afterwards unless it was cancelled meanwhile.
     pub async fn wait(self, duration: Duration) {
         sleep(duration).await;
+        self.handle.fire();
+    }
+
+    /// Fire the timer if its deadline has passed
+    ///
+    /// Returns `true` if the timer has fired (now or earlier) and `false` if
+    /// it is still pending or was cancelled first.
+    pub fn poll(&self) -> bool {
+        if self.clock.now() >= self.deadline {
+            self.handle.fire();
+        }
+        self.handle.is_elapsed()
     }
 
     /// Check if the timer was cancelled
     pub fn is_cancelled(&self) -> bool {
         self.handle.is_cancelled()
     }
+
+    /// Check if the timer has fired
+    pub fn is_elapsed(&self) -> bool {
+        self.handle.is_elapsed()
+    }
 }
 
 /// Create a one-shot timer
@@ -105,7 +162,7 @@ where
 
     tokio::spawn(async move {
         sleep(duration).await;
-        if !handle_clone.is_cancelled() {
+        if handle_clone.fire() {
             callback();
         }
     });
@@ -163,6 +220,7 @@ mod tests {
     use std::sync::atomic::AtomicU32;
 
     use super::*;
+    use crate::time::MockClock;
 
     /// Validates `Timer::after` behavior for the timer fires scenario.
     ///
@@ -259,4 +317,83 @@ mod tests {
         let count = counter.load(Ordering::SeqCst);
         assert!((2..=4).contains(&count)); // Allow some timing variance
     }
+
+    /// Validates `TimerHandle::cancel` before the deadline.
+    ///
+    /// Assertions:
+    /// - `cancel()` returns `true` while the timer is pending.
+    /// - The timer never fires after its deadline passes.
+    #[test]
+    fn test_cancel_before_fire() {
+        let clock = MockClock::new();
+        let timer = Timer::after_with_clock(Duration::from_secs(10), clock.clone());
+        let handle = timer.handle();
+
+        clock.advance(Duration::from_secs(5));
+        assert!(!timer.poll());
+        assert!(handle.cancel());
+
+        clock.advance(Duration::from_secs(10));
+        assert!(!timer.poll());
+        assert!(handle.is_cancelled());
+        assert!(!handle.is_elapsed());
+    }
+
+    /// Validates `TimerHandle::cancel` after the timer fired.
+    ///
+    /// Assertions:
+    /// - `cancel()` returns `false` once the timer has fired.
+    /// - The timer stays elapsed and is not reported as cancelled.
+    #[test]
+    fn test_cancel_after_fire() {
+        let clock = MockClock::new();
+        let timer = Timer::after_with_clock(Duration::from_secs(10), clock.clone());
+        let handle = timer.handle();
+
+        clock.advance(Duration::from_secs(10));
+        assert!(timer.poll());
+        assert!(!handle.cancel());
+
+        assert!(handle.is_elapsed());
+        assert!(!handle.is_cancelled());
+        assert!(timer.poll());
+    }
+
+    /// Validates that cancelling twice only reports the first cancellation.
+    ///
+    /// Assertions:
+    /// - The first `cancel()` returns `true`, the second `false`.
+    /// - The timer remains cancelled.
+    #[test]
+    fn test_double_cancel() {
+        let clock = MockClock::new();
+        let timer = Timer::after_with_clock(Duration::from_secs(10), clock.clone());
+        let handle = timer.handle();
+
+        assert!(handle.cancel());
+        assert!(!handle.cancel());
+
+        clock.advance(Duration::from_secs(10));
+        assert!(!timer.poll());
+        assert!(handle.is_cancelled());
+    }
+
+    /// Validates that a cancel racing the fire has exactly one winner.
+    ///
+    /// Assertions:
+    /// - For every race, exactly one of `cancel()` and `fire()` succeeds.
+    #[test]
+    fn test_cancel_races_fire() {
+        for _ in 0..200 {
+            let handle = TimerHandle::new();
+            let firing = handle.clone();
+
+            let fire_thread = std::thread::spawn(move || firing.fire());
+            let cancelled = handle.cancel();
+            let fired = fire_thread.join().expect("fire thread panicked");
+
+            assert_ne!(cancelled, fired);
+            assert_eq!(handle.is_elapsed(), fired);
+        }
+    }
 }
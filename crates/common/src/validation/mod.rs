@@ -3,9 +3,11 @@ use std::collections::HashMap;
 use std::fmt;
 
 mod rules;
+mod spec;
 mod validators;
 
 pub use rules::{NamedRule, RuleBuilder, RuleSet, ValidationRule};
+pub use spec::{RangeBound, RuleSetSpec, RuleSpec, SpecOperator, RULE_TYPES};
 pub use validators::{
     CollectionValidator, CustomValidator, EmailValidator, FieldValidator, IpValidator,
     RangeValidator, StringValidator, UrlValidator,
@@ -224,13 +226,18 @@ impl Validator {
         self.context.stop_on_first && self.stopped
     }
 
-    /// Add an error
-    pub fn add_error(&mut self, field: impl Into<String>, message: impl Into<String>) {
-        let field = if self.context.path.is_empty() {
+    /// Qualify `field` with the current nesting path
+    fn qualify(&self, field: impl Into<String>) -> String {
+        if self.context.path.is_empty() {
             field.into()
         } else {
             format!("{}.{}", self.context.current_path(), field.into())
-        };
+        }
+    }
+
+    /// Add an error
+    pub fn add_error(&mut self, field: impl Into<String>, message: impl Into<String>) {
+        let field = self.qualify(field);
         self.errors.add_field_error(field, message);
 
         if self.context.stop_on_first && !self.errors.is_empty() {
@@ -280,6 +287,39 @@ impl Validator {
         result
     }
 
+    /// Validate a rule spanning several fields of `value`
+    ///
+    /// `rule` inspects the whole object and returns the field to blame and a
+    /// message when the rule is violated (e.g. `end_time` when it is not
+    /// after `start_time`). The error is attached to that field, qualified by
+    /// the current nesting path, with `name` as its error code.
+    pub fn validate_cross_field<T, F, S, M>(
+        &mut self,
+        name: &str,
+        value: &T,
+        rule: F,
+    ) -> ValidationResult<()>
+    where
+        T: ?Sized,
+        F: Fn(&T) -> Option<(S, M)>,
+        S: Into<String>,
+        M: Into<String>,
+    {
+        if self.should_short_circuit() {
+            return Ok(());
+        }
+
+        if let Some((field, message)) = rule(value) {
+            let field = self.qualify(field);
+            self.errors.add_error_with_code(field, message, name);
+
+            if self.context.stop_on_first {
+                self.stopped = true;
+            }
+        }
+        Ok(())
+    }
+
     /// Validate a numeric range
     pub fn validate_range<T>(
         &mut self,
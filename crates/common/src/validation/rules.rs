@@ -278,6 +278,12 @@ impl RuleBuilder {
         self
     }
 
+    /// Add a rule rejecting empty or whitespace-only strings
+    pub fn not_empty(mut self, field: &str) -> Self {
+        self.rules.push(Box::new(NotEmptyRule { field: field.to_string() }));
+        self
+    }
+
     /// Add a range rule
     pub fn range<T: 'static + PartialOrd + Clone + Debug + Send + Sync>(
         mut self,
@@ -296,6 +302,23 @@ impl RuleBuilder {
         self
     }
 
+    /// Add a string length rule (in characters); `None` leaves that end open
+    pub fn length(mut self, field: &str, min: Option<usize>, max: Option<usize>) -> Self {
+        self.rules.push(Box::new(LengthRule { field: field.to_string(), min, max }));
+        self
+    }
+
+    /// Add a rule requiring a string to be one of `values`
+    pub fn one_of<S: Into<String>>(
+        mut self,
+        field: &str,
+        values: impl IntoIterator<Item = S>,
+    ) -> Self {
+        let values = values.into_iter().map(Into::into).collect();
+        self.rules.push(Box::new(OneOfRule { field: field.to_string(), values }));
+        self
+    }
+
     /// Add a custom rule
     pub fn custom<F>(mut self, validator: F) -> Self
     where
@@ -361,6 +384,41 @@ impl ValidationRule for RequiredRule {
     }
 }
 
+/// Non-empty string rule
+#[derive(Debug, Clone)]
+struct NotEmptyRule {
+    field: String,
+}
+
+impl ValidationRule for NotEmptyRule {
+    fn validate(
+        &self,
+        value: &dyn std::any::Any,
+        errors: &mut ValidationError,
+        _context: &ValidationContext,
+    ) -> ValidationResult<()> {
+        let empty = if let Some(s) = value.downcast_ref::<String>() {
+            s.trim().is_empty()
+        } else if let Some(opt) = value.downcast_ref::<Option<String>>() {
+            opt.as_deref().is_none_or(|s| s.trim().is_empty())
+        } else {
+            false
+        };
+        if empty {
+            errors.add_field_error(&self.field, format!("{} cannot be empty", self.field));
+        }
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!("{} cannot be empty", self.field)
+    }
+
+    fn clone_box(&self) -> Box<dyn ValidationRule> {
+        Box::new(self.clone())
+    }
+}
+
 /// Range validation rule
 #[derive(Debug, Clone)]
 struct RangeRule<T: PartialOrd + Clone + Debug + Send + Sync> {
@@ -432,6 +490,86 @@ impl ValidationRule for PatternRule {
     }
 }
 
+/// String length rule
+#[derive(Debug, Clone)]
+struct LengthRule {
+    field: String,
+    min: Option<usize>,
+    max: Option<usize>,
+}
+
+impl ValidationRule for LengthRule {
+    fn validate(
+        &self,
+        value: &dyn std::any::Any,
+        errors: &mut ValidationError,
+        _context: &ValidationContext,
+    ) -> ValidationResult<()> {
+        if let Some(s) = value.downcast_ref::<String>() {
+            let length = s.chars().count();
+            if let Some(min) = self.min.filter(|&min| length < min) {
+                errors.add_field_error(
+                    &self.field,
+                    format!("{} must be at least {} characters", self.field, min),
+                );
+            }
+            if let Some(max) = self.max.filter(|&max| length > max) {
+                errors.add_field_error(
+                    &self.field,
+                    format!("{} must not exceed {} characters", self.field, max),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        match (self.min, self.max) {
+            (Some(min), Some(max)) => {
+                format!("{} must be between {} and {} characters", self.field, min, max)
+            }
+            (Some(min), None) => format!("{} must be at least {} characters", self.field, min),
+            (None, Some(max)) => format!("{} must not exceed {} characters", self.field, max),
+            (None, None) => format!("{} may have any length", self.field),
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn ValidationRule> {
+        Box::new(self.clone())
+    }
+}
+
+/// Allowed values rule
+#[derive(Debug, Clone)]
+struct OneOfRule {
+    field: String,
+    values: Vec<String>,
+}
+
+impl ValidationRule for OneOfRule {
+    fn validate(
+        &self,
+        value: &dyn std::any::Any,
+        errors: &mut ValidationError,
+        _context: &ValidationContext,
+    ) -> ValidationResult<()> {
+        if let Some(s) = value.downcast_ref::<String>() {
+            if !self.values.contains(s) {
+                errors.add_field_error(&self.field, self.description());
+            }
+        }
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!("{} must be one of: {}", self.field, self.values.join(", "))
+    }
+
+    fn clone_box(&self) -> Box<dyn ValidationRule> {
+        Box::new(self.clone())
+    }
+}
+
 /// Custom validation rule
 struct CustomRule {
     validator: ValidationFn,
@@ -0,0 +1,234 @@
+// Rule Specs - Declarative, serializable validation rules
+//
+// A `RuleSetSpec` describes a `RuleSet` as data so rules can live in a JSON
+// or TOML file instead of code:
+//
+// ```toml
+// operator = "all"
+//
+// [[rules]]
+// type = "length"
+// field = "project_code"
+// min = 3
+// max = 12
+//
+// [[rules]]
+// type = "one_of"
+// field = "project_code"
+// values = ["USC0063201", "USC0042105"]
+// ```
+//
+// Each spec is built through `RuleBuilder`, so a loaded rule set validates
+// exactly like the equivalent code-built one.
+use serde::{Deserialize, Serialize};
+
+use super::{RuleBuilder, RuleSet, ValidationError, ValidationResult};
+
+/// Rule types a spec may use, as written in the `type` key
+pub const RULE_TYPES: [&str; 5] = ["not_empty", "range", "pattern", "length", "one_of"];
+
+/// How the rules of a spec combine
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpecOperator {
+    /// Every rule must pass (`RuleSet::all`)
+    #[default]
+    All,
+    /// At least one rule must pass (`RuleSet::any`)
+    Any,
+}
+
+/// Bound of a `range` rule
+///
+/// Two integer bounds validate `i64` values; otherwise the rule validates
+/// `f64` values.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RangeBound {
+    Int(i64),
+    Float(f64),
+}
+
+impl RangeBound {
+    fn as_f64(self) -> f64 {
+        match self {
+            Self::Int(value) => value as f64,
+            Self::Float(value) => value,
+        }
+    }
+}
+
+/// A single declarative validation rule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum RuleSpec {
+    /// String must not be empty or whitespace
+    NotEmpty { field: String },
+    /// Number must be within `min..=max`
+    Range { field: String, min: RangeBound, max: RangeBound },
+    /// String must match a regular expression
+    Pattern { field: String, pattern: String },
+    /// String length in characters must be within the given bounds
+    Length {
+        field: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min: Option<usize>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max: Option<usize>,
+    },
+    /// String must be one of the listed values
+    OneOf { field: String, values: Vec<String> },
+}
+
+impl RuleSpec {
+    /// Field the rule reports errors against
+    pub fn field(&self) -> &str {
+        match self {
+            Self::NotEmpty { field }
+            | Self::Range { field, .. }
+            | Self::Pattern { field, .. }
+            | Self::Length { field, .. }
+            | Self::OneOf { field, .. } => field,
+        }
+    }
+
+    /// Check the rule's parameters
+    fn check(&self) -> Result<(), String> {
+        match self {
+            Self::Range { min, max, .. } if min.as_f64() > max.as_f64() => {
+                Err(format!("range min {:?} exceeds max {:?}", min, max))
+            }
+            Self::Pattern { pattern, .. } => regex::Regex::new(pattern)
+                .map(|_| ())
+                .map_err(|e| format!("invalid pattern {:?}: {}", pattern, e)),
+            Self::Length { min: Some(min), max: Some(max), .. } if min > max => {
+                Err(format!("length min {} exceeds max {}", min, max))
+            }
+            Self::OneOf { values, .. } if values.is_empty() => {
+                Err("one_of needs at least one value".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Add this rule to `builder`
+    fn apply(&self, builder: RuleBuilder) -> RuleBuilder {
+        match self {
+            Self::NotEmpty { field } => builder.not_empty(field),
+            Self::Range { field, min: RangeBound::Int(min), max: RangeBound::Int(max) } => {
+                builder.range(field, *min, *max)
+            }
+            Self::Range { field, min, max } => builder.range(field, min.as_f64(), max.as_f64()),
+            Self::Pattern { field, pattern } => builder.pattern(field, pattern),
+            Self::Length { field, min, max } => builder.length(field, *min, *max),
+            Self::OneOf { field, values } => builder.one_of(field, values.iter().cloned()),
+        }
+    }
+}
+
+/// Declarative description of a `RuleSet`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RuleSetSpec {
+    #[serde(default)]
+    pub operator: SpecOperator,
+    #[serde(default)]
+    pub rules: Vec<RuleSpec>,
+}
+
+/// Spec with rules kept raw, so each rule can be reported on by index
+#[derive(Deserialize)]
+struct RawRuleSetSpec {
+    #[serde(default)]
+    operator: SpecOperator,
+    #[serde(default)]
+    rules: Vec<serde_json::Value>,
+}
+
+impl RuleSetSpec {
+    /// Parse a spec from JSON
+    pub fn from_json(contents: &str) -> ValidationResult<Self> {
+        let raw = serde_json::from_str(contents).map_err(|e| {
+            ValidationError::field("rules", format!("invalid JSON rule spec: {}", e))
+        })?;
+        Self::from_raw(raw)
+    }
+
+    /// Parse a spec from TOML
+    pub fn from_toml(contents: &str) -> ValidationResult<Self> {
+        let raw = toml::from_str(contents).map_err(|e| {
+            ValidationError::field("rules", format!("invalid TOML rule spec: {}", e))
+        })?;
+        Self::from_raw(raw)
+    }
+
+    /// Render the spec as pretty-printed JSON
+    pub fn to_json(&self) -> ValidationResult<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| ValidationError::field("rules", format!("cannot render rule spec: {}", e)))
+    }
+
+    /// Build the described `RuleSet`
+    ///
+    /// Every rule is checked first; all problems are reported together with
+    /// the rule's index (`rules[2]`).
+    pub fn build(&self) -> ValidationResult<RuleSet> {
+        let mut errors = ValidationError::new();
+        for (index, rule) in self.rules.iter().enumerate() {
+            if let Err(message) = rule.check() {
+                errors.add_field_error(format!("rules[{}]", index), message);
+            }
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(match self.operator {
+            SpecOperator::All => {
+                self.rules.iter().fold(RuleBuilder::empty(), |b, rule| rule.apply(b)).build_set()
+            }
+            SpecOperator::Any => self.rules.iter().fold(RuleSet::any(), |set, rule| {
+                set.add_rule(Box::new(rule.apply(RuleBuilder::empty()).build_set()))
+            }),
+        })
+    }
+
+    fn from_raw(raw: RawRuleSetSpec) -> ValidationResult<Self> {
+        let mut errors = ValidationError::new();
+        let mut rules = Vec::with_capacity(raw.rules.len());
+
+        for (index, value) in raw.rules.into_iter().enumerate() {
+            let field = format!("rules[{}]", index);
+            let kind = value.get("type").map(|t| t.as_str().unwrap_or_default().to_string());
+            match kind {
+                None => errors.add_field_error(field, "rule is missing a type"),
+                Some(kind) if !RULE_TYPES.contains(&kind.as_str()) => errors.add_error_with_code(
+                    field,
+                    format!("unknown rule type '{}' (supported: {})", kind, RULE_TYPES.join(", ")),
+                    "unknown_rule_type",
+                ),
+                Some(_) => match serde_json::from_value(value) {
+                    Ok(rule) => rules.push(rule),
+                    Err(e) => errors.add_field_error(field, e.to_string()),
+                },
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(Self { operator: raw.operator, rules })
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl RuleSet {
+    /// Build a rule set from a JSON spec (see [`RuleSetSpec`])
+    pub fn from_json_spec(contents: &str) -> ValidationResult<Self> {
+        RuleSetSpec::from_json(contents)?.build()
+    }
+
+    /// Build a rule set from a TOML spec (see [`RuleSetSpec`])
+    pub fn from_toml_spec(contents: &str) -> ValidationResult<Self> {
+        RuleSetSpec::from_toml(contents)?.build()
+    }
+}
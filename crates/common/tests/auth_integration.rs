@@ -161,14 +161,12 @@ fn test_token_set_operations() {
 async fn test_token_manager_with_mocks() {
     let keychain = Arc::new(MockKeychainProvider::new("PulseArcTest".to_string()));
     let oauth_client = MockOAuthClient::new();
-    let service_name = "test_oauth_service";
     let account_name = "test_user";
 
     // Create token manager with mocks
     let token_manager = pulsearc_common::auth::TokenManager::new(
         oauth_client,
         keychain.clone(),
-        service_name.to_string(),
         account_name.to_string(),
         300, // 5 minutes before expiry
     );
@@ -239,7 +237,6 @@ async fn test_token_manager_auto_refresh() {
     let token_manager = pulsearc_common::auth::TokenManager::new(
         oauth_client.clone(),
         keychain.clone(),
-        "test_service".to_string(),
         account_name.to_string(),
         60, // 1 minute threshold
     );
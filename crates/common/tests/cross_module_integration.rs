@@ -111,8 +111,7 @@ async fn test_cache_with_validation() {
 #[tokio::test(flavor = "multi_thread")]
 async fn test_resilience_with_auth() {
     disable_oauth_http();
-    let keychain = Arc::new(MockKeychainProvider::new("PulseArcTest".to_string()));
-    let service_name = unique_test_id("test_auth_resilience");
+    let keychain = Arc::new(MockKeychainProvider::new(unique_test_id("test_auth_resilience")));
     let account_name = "test_user";
 
     // Create a mock OAuth client for testing
@@ -128,7 +127,6 @@ async fn test_resilience_with_auth() {
     let token_manager = Arc::new(TokenManager::new(
         oauth_client,
         keychain.clone(),
-        service_name.clone(),
         account_name.to_string(),
         300,
     ));
@@ -1,14 +1,16 @@
 //! Integration tests for sync queue module
 //!
-//! Covers priority scheduling, deduplication/capacity safeguards, and retry
-//! behavior to ensure the queue works end-to-end with metrics tracking.
+//! Covers priority scheduling, deduplication/capacity safeguards, retry
+//! behavior, compressed persistence and backpressure signalling to ensure the
+//! queue works end-to-end with metrics tracking.
 
 #![cfg(feature = "runtime")]
 
 use std::time::Duration;
 
 use pulsearc_common::sync::{
-    ItemStatus, Priority, QueueConfig, QueueError, QueueResult, SyncItem, SyncQueue,
+    CompressionAlgorithm, CompressionPolicy, ItemStatus, PressureLevel, PressureThresholds,
+    Priority, QueueConfig, QueueError, QueueResult, SyncItem, SyncQueue,
 };
 use serde_json::json;
 
@@ -167,3 +169,118 @@ async fn test_queue_retry_flow_reschedules_and_exhausts() -> QueueResult<()> {
     queue.shutdown().await?;
     Ok(())
 }
+
+/// Verifies that `pressure()` tracks the fill level, counting items that are
+/// still being processed.
+#[tokio::test(flavor = "multi_thread")]
+async fn test_queue_pressure_reflects_fill_level() -> QueueResult<()> {
+    let config = QueueConfig { max_capacity: 4, batch_size: 2, ..Default::default() };
+    let queue = SyncQueue::with_config(config)?;
+
+    assert!(queue.pressure().abs() < f32::EPSILON);
+
+    for idx in 0..3 {
+        queue
+            .push(SyncItem::with_id(format!("item-{idx}"), json!({ "idx": idx }), Priority::Normal))
+            .await?;
+    }
+    assert!((queue.pressure() - 0.75).abs() < f32::EPSILON);
+
+    // Dequeued items still occupy capacity until they complete.
+    let item = queue.pop().await?.unwrap_or_else(|| panic!("expected an item"));
+    assert!((queue.pressure() - 0.75).abs() < f32::EPSILON);
+
+    queue.mark_completed(&item.id).await?;
+    assert!((queue.pressure() - 0.5).abs() < f32::EPSILON);
+
+    queue.clear().await?;
+    assert!(queue.pressure().abs() < f32::EPSILON);
+
+    queue.shutdown().await?;
+    Ok(())
+}
+
+/// Ensures subscribers are notified only when the fill ratio crosses one of
+/// the configured thresholds.
+#[tokio::test(flavor = "multi_thread")]
+async fn test_queue_pressure_notifies_on_threshold_crossings() -> QueueResult<()> {
+    let config = QueueConfig {
+        max_capacity: 10,
+        batch_size: 5,
+        pressure_thresholds: PressureThresholds { elevated: 0.5, critical: 0.8 },
+        ..Default::default()
+    };
+    let queue = SyncQueue::with_config(config)?;
+    let mut pressure = queue.subscribe_pressure();
+
+    let make_item = |idx: usize| {
+        SyncItem::with_id(format!("item-{idx}"), json!({ "idx": idx }), Priority::Normal)
+    };
+
+    for idx in 0..4 {
+        queue.push(make_item(idx)).await?;
+    }
+    assert!(!pressure.has_changed().unwrap_or(true), "no threshold crossed below 50%");
+
+    queue.push(make_item(4)).await?;
+    assert!(pressure.has_changed().unwrap_or(false));
+    assert_eq!(pressure.borrow_and_update().level, PressureLevel::Elevated);
+
+    for idx in 5..8 {
+        queue.push(make_item(idx)).await?;
+    }
+    assert!(pressure.has_changed().unwrap_or(false));
+    let current = *pressure.borrow_and_update();
+    assert_eq!(current.level, PressureLevel::Critical);
+    assert!((current.ratio - 0.8).abs() < f32::EPSILON);
+
+    // Dropping back below the elevated threshold is signalled as well.
+    for idx in 0..4 {
+        queue.cancel_item(&format!("item-{idx}")).await?;
+    }
+    assert!(pressure.has_changed().unwrap_or(false));
+    assert_eq!(pressure.borrow_and_update().level, PressureLevel::Normal);
+
+    queue.shutdown().await?;
+    Ok(())
+}
+
+/// Persists and reloads a queue under different compression policies: one
+/// whose threshold keeps the payload uncompressed and one that compresses it
+/// with the configured algorithm.
+#[tokio::test(flavor = "multi_thread")]
+async fn test_queue_persistence_round_trips_with_compression_policy() -> QueueResult<()> {
+    let dir = tempfile::tempdir()?;
+    let notes = "billable review ".repeat(200);
+
+    for (threshold, expected) in [(usize::MAX, None), (64, Some(CompressionAlgorithm::Zlib))] {
+        let path = dir.path().join(format!("queue-{threshold}.dat"));
+        let config = QueueConfig {
+            persistence_path: Some(path.clone()),
+            persistence_interval: Duration::from_secs(3600),
+            compression_algorithm: CompressionAlgorithm::Zlib,
+            compression_threshold: threshold,
+            ..Default::default()
+        };
+
+        let queue = SyncQueue::with_config(config.clone())?;
+        queue
+            .push(SyncItem::with_id(
+                "entry".to_string(),
+                json!({ "notes": notes }),
+                Priority::Normal,
+            ))
+            .await?;
+        queue.shutdown().await?;
+
+        let frame = std::fs::read(&path)?;
+        assert_eq!(CompressionPolicy::framed_algorithm(&frame)?, expected);
+
+        let reloaded = SyncQueue::with_config(config)?;
+        let item = reloaded.get_item("entry").unwrap_or_else(|| panic!("entry should reload"));
+        assert_eq!(item.data, json!({ "notes": notes }));
+        reloaded.shutdown().await?;
+    }
+
+    Ok(())
+}
@@ -621,3 +621,84 @@ fn test_comprehensive_collection_validation() {
     assert!(error_msg.contains("index 2"));
     assert!(error_msg.contains("must not exceed 65"));
 }
+
+/// Time entry fields used by the cross-field tests
+struct TimeEntryDraft {
+    start_time: i64,
+    end_time: i64,
+    billable: bool,
+    project: Option<String>,
+}
+
+fn end_after_start(entry: &TimeEntryDraft) -> Option<(&'static str, String)> {
+    (entry.end_time <= entry.start_time).then(|| ("end_time", "must be after start_time".into()))
+}
+
+fn billable_requires_project(entry: &TimeEntryDraft) -> Option<(&'static str, &'static str)> {
+    let has_project = entry.project.as_deref().is_some_and(|p| !p.trim().is_empty());
+    (entry.billable && !has_project).then_some(("project", "is required for billable entries"))
+}
+
+/// Test cross-field rule attaching an end-before-start failure to end_time
+#[test]
+fn test_cross_field_end_before_start() {
+    let entry =
+        TimeEntryDraft { start_time: 3_600, end_time: 1_800, billable: false, project: None };
+
+    let mut validator = Validator::new();
+    validator
+        .validate_nested("entry", |v| {
+            let _ = v.validate_cross_field("end_after_start", &entry, end_after_start);
+        })
+        .unwrap();
+
+    let err = validator.finalize().expect_err("end before start should fail");
+    let errors = err.field_errors("entry.end_time");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].message, "must be after start_time");
+    assert_eq!(errors[0].code.as_deref(), Some("end_after_start"));
+
+    let valid = TimeEntryDraft { end_time: 7_200, ..entry };
+    let mut validator = Validator::new();
+    validator.validate_cross_field("end_after_start", &valid, end_after_start).unwrap();
+    assert!(validator.finalize().is_ok());
+}
+
+/// Test conditional required field: billable entries need a project
+#[test]
+fn test_cross_field_conditional_required() {
+    let check = |entry: &TimeEntryDraft| {
+        let mut validator = Validator::new();
+        validator
+            .validate_cross_field("billable_project", entry, billable_requires_project)
+            .unwrap();
+        validator.validate_cross_field("end_after_start", entry, end_after_start).unwrap();
+        validator.finalize()
+    };
+
+    let billable = TimeEntryDraft { start_time: 0, end_time: 60, billable: true, project: None };
+    let err = check(&billable).expect_err("billable entry without project should fail");
+    assert_eq!(err.error_count(), 1);
+    assert_eq!(err.field_errors("project")[0].code.as_deref(), Some("billable_project"));
+
+    let blank = TimeEntryDraft { project: Some("  ".into()), ..billable };
+    assert!(check(&blank).is_err(), "blank project does not count");
+
+    let with_project = TimeEntryDraft { project: Some("USC0063201".into()), ..blank };
+    assert!(check(&with_project).is_ok());
+
+    let non_billable = TimeEntryDraft { billable: false, project: None, ..with_project };
+    assert!(check(&non_billable).is_ok(), "project is optional for non-billable entries");
+}
+
+/// Test cross-field rules respect stop-on-first-error
+#[test]
+fn test_cross_field_stops_on_first_error() {
+    let entry = TimeEntryDraft { start_time: 60, end_time: 0, billable: true, project: None };
+    let mut validator = Validator::with_context(ValidationContext::new().stop_on_first_error());
+
+    validator.validate_cross_field("end_after_start", &entry, end_after_start).unwrap();
+    validator.validate_cross_field("billable_project", &entry, billable_requires_project).unwrap();
+
+    assert_eq!(validator.error_count(), 1);
+}
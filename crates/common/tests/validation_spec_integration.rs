@@ -0,0 +1,153 @@
+//! Integration tests for declarative validation rule specs.
+//!
+//! Rule sets loaded from JSON/TOML specs must behave exactly like the same
+//! rules built in code, and malformed specs must fail with actionable errors.
+
+use pulsearc_common::validation::{
+    RuleBuilder, RuleSet, RuleSetSpec, ValidationContext, ValidationError, ValidationRule,
+};
+
+const PROJECT_CODE_TOML: &str = r#"
+[[rules]]
+type = "not_empty"
+field = "project_code"
+
+[[rules]]
+type = "length"
+field = "project_code"
+min = 3
+max = 10
+
+[[rules]]
+type = "pattern"
+field = "project_code"
+pattern = "^[A-Z0-9]+$"
+
+[[rules]]
+type = "one_of"
+field = "project_code"
+values = ["USC0063201", "USC0042105", "ADMIN"]
+"#;
+
+fn code_built_project_rules() -> RuleSet {
+    RuleBuilder::empty()
+        .not_empty("project_code")
+        .length("project_code", Some(3), Some(10))
+        .pattern("project_code", "^[A-Z0-9]+$")
+        .one_of("project_code", ["USC0063201", "USC0042105", "ADMIN"])
+        .build_set()
+}
+
+/// Error messages a rule set produces for `value`
+fn messages<T: 'static>(rules: &RuleSet, value: &T) -> Vec<String> {
+    let mut errors = ValidationError::new();
+    rules.validate(value, &mut errors, &ValidationContext::new()).expect("rules run");
+    errors.errors.into_iter().map(|e| format!("{}: {}", e.field, e.message)).collect()
+}
+
+/// A TOML-loaded rule set accepts and rejects the same inputs, with the same
+/// messages, as its code-built equivalent.
+#[test]
+fn loaded_string_rules_match_code_built_rules() {
+    let loaded = RuleSet::from_toml_spec(PROJECT_CODE_TOML).expect("spec should load");
+    let built = code_built_project_rules();
+    assert_eq!(loaded.len(), built.len());
+
+    let inputs = ["USC0063201", "ADMIN", "", "  ", "ab", "usc0063201", "USC00632019999", "ZZZ999"];
+    for input in inputs {
+        let input = input.to_string();
+        assert_eq!(messages(&loaded, &input), messages(&built, &input), "input {input:?}");
+    }
+    assert!(messages(&loaded, &"USC0042105".to_string()).is_empty());
+    assert_eq!(messages(&loaded, &"ab".to_string()).len(), 3, "length, pattern and one_of fail");
+}
+
+/// Integer bounds load as an `i64` range and fractional bounds as `f64`.
+#[test]
+fn loaded_range_rules_match_code_built_rules() {
+    let json = r#"{
+        "rules": [
+            { "type": "range", "field": "hours", "min": 0, "max": 24 },
+            { "type": "range", "field": "confidence", "min": 0, "max": 1.0 }
+        ]
+    }"#;
+    let loaded = RuleSet::from_json_spec(json).expect("spec should load");
+    let hours = RuleBuilder::empty().range("hours", 0_i64, 24_i64).build_set();
+    let confidence = RuleBuilder::empty().range("confidence", 0.0_f64, 1.0_f64).build_set();
+
+    for value in [-1_i64, 0, 8, 24, 25] {
+        assert_eq!(messages(&loaded, &value), messages(&hours, &value), "hours {value}");
+    }
+    for value in [-0.1_f64, 0.5, 1.0, 1.5] {
+        assert_eq!(messages(&loaded, &value), messages(&confidence, &value), "confidence {value}");
+    }
+}
+
+/// An `any` spec passes when one branch passes, like `RuleSet::any`.
+#[test]
+fn any_operator_matches_code_built_any_set() {
+    let json = r#"{
+        "operator": "any",
+        "rules": [
+            { "type": "pattern", "field": "id", "pattern": "^[A-Z]+$" },
+            { "type": "pattern", "field": "id", "pattern": "^\\d+$" }
+        ]
+    }"#;
+    let loaded = RuleSet::from_json_spec(json).expect("spec should load");
+    let built = RuleSet::any()
+        .add_rule(Box::new(RuleBuilder::empty().pattern("id", "^[A-Z]+$").build_set()))
+        .add_rule(Box::new(RuleBuilder::empty().pattern("id", r"^\d+$").build_set()));
+
+    for input in ["ABC", "123", "abc123"] {
+        let input = input.to_string();
+        assert_eq!(messages(&loaded, &input), messages(&built, &input), "input {input:?}");
+    }
+    assert!(loaded.description().starts_with("Any of 2"));
+}
+
+/// Unknown rule types are rejected with the offending index and the list of
+/// supported types.
+#[test]
+fn unknown_rule_type_is_rejected() {
+    let json = r#"{ "rules": [
+        { "type": "not_empty", "field": "name" },
+        { "type": "uppercase", "field": "name" }
+    ] }"#;
+
+    let err = RuleSet::from_json_spec(json).expect_err("unknown type should fail");
+    let errors = err.field_errors("rules[1]");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].code.as_deref(), Some("unknown_rule_type"));
+    assert!(errors[0].message.contains("'uppercase'"));
+    assert!(errors[0].message.contains("not_empty, range, pattern, length, one_of"));
+}
+
+/// Invalid parameters are reported for every bad rule at once.
+#[test]
+fn invalid_rule_parameters_are_reported() {
+    let toml = r#"
+[[rules]]
+type = "pattern"
+field = "code"
+pattern = "["
+
+[[rules]]
+type = "range"
+field = "hours"
+min = 10
+max = 1
+"#;
+
+    let err = RuleSet::from_toml_spec(toml).expect_err("bad parameters should fail");
+    assert_eq!(err.error_count(), 2);
+    assert!(err.field_errors("rules[0]")[0].message.contains("invalid pattern"));
+    assert!(err.field_errors("rules[1]")[0].message.contains("exceeds max"));
+}
+
+/// Specs survive a JSON round trip.
+#[test]
+fn spec_round_trips_through_json() {
+    let spec = RuleSetSpec::from_toml(PROJECT_CODE_TOML).expect("spec should load");
+    let json = spec.to_json().expect("spec should render");
+    assert_eq!(RuleSetSpec::from_json(&json).expect("rendered spec should load"), spec);
+}
@@ -26,6 +26,7 @@ fn sample_snapshots() -> Vec<ActivitySnapshot> {
             processed_at: None,
             is_idle: false,
             idle_duration_secs: Some(0),
+            content_hash: None,
         })
         .collect()
 }
@@ -91,4 +91,14 @@ pub trait DlqRepository: Send + Sync {
 
     /// Retry a failed batch from the DLQ
     async fn retry_failed_batch(&self, batch_id: &str) -> Result<()>;
+
+    /// Dead-letter a single outbox entry that failed permanently
+    ///
+    /// The entry is marked failed in the outbox so it is no longer retried.
+    async fn move_outbox_entry_to_dlq(
+        &self,
+        outbox_id: &str,
+        error_code: &str,
+        error: &str,
+    ) -> Result<()>;
 }
@@ -0,0 +1,584 @@
+//! App category rules with user overrides
+//!
+//! [`AppCategoryRules`] assigns an [`ActivityCategory`], [`WorkType`] and/or
+//! project to captured activity based on the active app. Rules match a bundle
+//! id, optionally narrowed by window title and URL substrings, so a user can
+//! say "treat Slack as client work for project X" or only for one channel.
+//!
+//! Lookup order:
+//! - User rules (stored through [`AppCategoryRuleRepository`]) before the
+//!   built-in defaults, so user overrides always win.
+//! - Within each tier, rules with more patterns before broader ones; ties go
+//!   to the older rule.
+//!
+//! Windows matching no rule keep whatever category the capture already had.
+//! Registered as an [`ActivityEnricher`] the rules are applied at capture
+//! time, before snapshots are stored and classified.
+//!
+//! Teams can share rules: [`AppCategoryRules::import`] merges rules from an
+//! admin-maintained file into the user's, resolving rules that match the same
+//! windows with an [`AppCategoryConflictPolicy`].
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use pulsearc_domain::types::classification::{
+    AppCategoryConflictPolicy, AppCategoryRule, AppCategoryRuleImport, AppCategoryRuleParams,
+    InvalidAppCategoryRule,
+};
+use pulsearc_domain::types::{ActivityCategory, ActivityContext, WindowContext, WorkType};
+use pulsearc_domain::{PulseArcError, Result};
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use super::ports::AppCategoryRuleRepository;
+use crate::tracking::ports::ActivityEnricher;
+
+/// Id prefix of built-in default rules
+const DEFAULT_RULE_PREFIX: &str = "default:";
+
+/// Categorizes activity by app, with user rules overriding the defaults
+pub struct AppCategoryRules {
+    repository: Arc<dyn AppCategoryRuleRepository>,
+    defaults: Vec<AppCategoryRule>,
+}
+
+impl AppCategoryRules {
+    /// Create rules backed by `repository` with the built-in defaults
+    pub fn new(repository: Arc<dyn AppCategoryRuleRepository>) -> Self {
+        Self::with_defaults(repository, default_rules())
+    }
+
+    /// Create rules backed by `repository` with custom defaults
+    pub fn with_defaults(
+        repository: Arc<dyn AppCategoryRuleRepository>,
+        defaults: Vec<AppCategoryRule>,
+    ) -> Self {
+        Self { repository, defaults }
+    }
+
+    /// Built-in rules consulted after the user's
+    pub fn defaults(&self) -> &[AppCategoryRule] {
+        &self.defaults
+    }
+
+    /// User rules, oldest first
+    pub async fn list(&self) -> Result<Vec<AppCategoryRule>> {
+        self.repository.list_rules().await
+    }
+
+    /// Validate and store a new user rule created at `created_at`.
+    ///
+    /// # Errors
+    /// Returns `PulseArcError::InvalidInput` if the rule is invalid (see
+    /// [`validate_rule_params`]).
+    pub async fn add(
+        &self,
+        params: AppCategoryRuleParams,
+        created_at: i64,
+    ) -> Result<AppCategoryRule> {
+        let params = normalize_params(params);
+        validate_rule_params(&params)?;
+
+        let rule = new_rule(params, created_at);
+        self.repository.save_rule(&rule).await?;
+        Ok(rule)
+    }
+
+    /// Merge imported rules (e.g. a team's shared rules file) into the
+    /// user's rules.
+    ///
+    /// Each entry is decoded and validated on its own; rejected entries are
+    /// listed in the report and the rest are still imported. An entry that
+    /// matches the same windows as an existing rule (same bundle id and
+    /// patterns) is resolved with `policy`: a replaced rule keeps its id.
+    ///
+    /// # Errors
+    /// Returns an error only if the stored rules cannot be read or written.
+    pub async fn import(
+        &self,
+        entries: Vec<serde_json::Value>,
+        policy: AppCategoryConflictPolicy,
+        imported_at: i64,
+    ) -> Result<AppCategoryRuleImport> {
+        let mut rules = self.repository.list_rules().await?;
+        let mut report = AppCategoryRuleImport::default();
+
+        for (index, entry) in entries.into_iter().enumerate() {
+            let bundle_id = entry.get("bundle_id").and_then(|v| v.as_str()).map(str::to_string);
+            let params = serde_json::from_value::<AppCategoryRuleParams>(entry)
+                .map_err(|err| PulseArcError::InvalidInput(err.to_string()))
+                .map(normalize_params)
+                .and_then(|params| validate_rule_params(&params).map(|()| params));
+            let params = match params {
+                Ok(params) => params,
+                Err(err) => {
+                    warn!(index, error = %err, "Skipping invalid imported app category rule");
+                    report.invalid.push(InvalidAppCategoryRule {
+                        index,
+                        bundle_id,
+                        reason: err.to_string(),
+                    });
+                    continue;
+                }
+            };
+
+            let rule = match rules.iter().position(|rule| same_windows(rule, &params)) {
+                None => {
+                    report.added += 1;
+                    new_rule(params, imported_at)
+                }
+                Some(_) if policy == AppCategoryConflictPolicy::LocalWins => {
+                    report.skipped += 1;
+                    continue;
+                }
+                Some(position) => {
+                    report.replaced += 1;
+                    let local = rules.remove(position);
+                    AppCategoryRule { id: local.id, ..new_rule(params, local.created_at) }
+                }
+            };
+            self.repository.save_rule(&rule).await?;
+            rules.push(rule);
+        }
+
+        debug!(
+            added = report.added,
+            replaced = report.replaced,
+            skipped = report.skipped,
+            invalid = report.invalid.len(),
+            "Imported app category rules"
+        );
+        Ok(report)
+    }
+
+    /// Remove a user rule, returning whether it existed
+    pub async fn remove(&self, id: &str) -> Result<bool> {
+        self.repository.delete_rule(id).await
+    }
+
+    /// The rule that applies to `window`, if any
+    pub async fn resolve(&self, window: &WindowContext) -> Result<Option<AppCategoryRule>> {
+        let user_rules = self.repository.list_rules().await?;
+        Ok(most_specific_match(&user_rules, window)
+            .or_else(|| most_specific_match(&self.defaults, window))
+            .cloned())
+    }
+
+    /// Apply the matching rule's outputs to `context`, returning the rule
+    pub async fn apply(&self, context: &mut ActivityContext) -> Result<Option<AppCategoryRule>> {
+        let Some(rule) = self.resolve(&context.active_app).await? else {
+            return Ok(None);
+        };
+
+        if let Some(category) = &rule.category {
+            context.activity_category = category.clone();
+        }
+        if let Some(work_type) = &rule.work_type {
+            context.work_type = Some(work_type.clone());
+        }
+        if let Some(project_id) = &rule.project_id {
+            context.suggested_matter = Some(project_id.clone());
+        }
+        debug!(rule_id = %rule.id, bundle_id = %rule.bundle_id, "Applied app category rule");
+        Ok(Some(rule))
+    }
+}
+
+#[async_trait]
+impl ActivityEnricher for AppCategoryRules {
+    /// Apply the matching rule; a failure to load rules leaves the capture
+    /// uncategorized rather than failing it
+    async fn enrich(&self, context: &mut ActivityContext) -> Result<()> {
+        if let Err(err) = self.apply(context).await {
+            warn!(error = %err, "Failed to apply app category rules");
+        }
+        Ok(())
+    }
+}
+
+/// Validate the fields of a rule.
+///
+/// # Errors
+/// Returns `PulseArcError::InvalidInput` if the bundle id is blank, a pattern
+/// or project id is present but blank, or the rule assigns nothing.
+pub fn validate_rule_params(params: &AppCategoryRuleParams) -> Result<()> {
+    if params.bundle_id.trim().is_empty() {
+        return Err(PulseArcError::InvalidInput("bundle_id must not be empty".to_string()));
+    }
+
+    let blank = |value: &Option<String>| value.as_deref().is_some_and(|v| v.trim().is_empty());
+    if blank(&params.title_pattern) || blank(&params.url_pattern) {
+        return Err(PulseArcError::InvalidInput(format!(
+            "rule for {} has an empty pattern",
+            params.bundle_id
+        )));
+    }
+    if blank(&params.project_id) {
+        return Err(PulseArcError::InvalidInput(format!(
+            "rule for {} has an empty project_id",
+            params.bundle_id
+        )));
+    }
+    if params.category.is_none() && params.work_type.is_none() && params.project_id.is_none() {
+        return Err(PulseArcError::InvalidInput(format!(
+            "rule for {} must set a category, work type or project",
+            params.bundle_id
+        )));
+    }
+    Ok(())
+}
+
+/// Whether `rule` applies to `window`
+pub fn rule_matches(rule: &AppCategoryRule, window: &WindowContext) -> bool {
+    let bundle_matches = window
+        .bundle_id
+        .as_deref()
+        .is_some_and(|bundle_id| bundle_id.trim().eq_ignore_ascii_case(&rule.bundle_id));
+    if !bundle_matches {
+        return false;
+    }
+
+    let title_matches = rule
+        .title_pattern
+        .as_deref()
+        .is_none_or(|pattern| contains(&window.window_title, pattern));
+    let url_matches = rule.url_pattern.as_deref().is_none_or(|pattern| {
+        window
+            .url
+            .as_deref()
+            .or(window.url_host.as_deref())
+            .is_some_and(|url| contains(url, pattern))
+    });
+    title_matches && url_matches
+}
+
+/// Whether `rule` and `params` match the same windows
+fn same_windows(rule: &AppCategoryRule, params: &AppCategoryRuleParams) -> bool {
+    let same_pattern = |a: &Option<String>, b: &Option<String>| match (a, b) {
+        (Some(a), Some(b)) => a.to_lowercase() == b.to_lowercase(),
+        (None, None) => true,
+        _ => false,
+    };
+    rule.bundle_id.eq_ignore_ascii_case(&params.bundle_id)
+        && same_pattern(&rule.title_pattern, &params.title_pattern)
+        && same_pattern(&rule.url_pattern, &params.url_pattern)
+}
+
+/// A new rule with a fresh id built from validated `params`
+fn new_rule(params: AppCategoryRuleParams, created_at: i64) -> AppCategoryRule {
+    AppCategoryRule {
+        id: Uuid::now_v7().to_string(),
+        bundle_id: params.bundle_id,
+        title_pattern: params.title_pattern,
+        url_pattern: params.url_pattern,
+        category: params.category,
+        work_type: params.work_type,
+        project_id: params.project_id,
+        created_at,
+    }
+}
+
+/// Trim the text fields of `params`
+fn normalize_params(params: AppCategoryRuleParams) -> AppCategoryRuleParams {
+    let trim = |value: Option<String>| value.map(|v| v.trim().to_string());
+    AppCategoryRuleParams {
+        bundle_id: params.bundle_id.trim().to_string(),
+        title_pattern: trim(params.title_pattern),
+        url_pattern: trim(params.url_pattern),
+        project_id: trim(params.project_id),
+        ..params
+    }
+}
+
+/// The matching rule with the most patterns; the earliest wins ties
+fn most_specific_match<'a>(
+    rules: &'a [AppCategoryRule],
+    window: &WindowContext,
+) -> Option<&'a AppCategoryRule> {
+    rules.iter().filter(|rule| rule_matches(rule, window)).fold(
+        None,
+        |best: Option<&AppCategoryRule>, rule| match best {
+            Some(best) if specificity(best) >= specificity(rule) => Some(best),
+            _ => Some(rule),
+        },
+    )
+}
+
+fn specificity(rule: &AppCategoryRule) -> usize {
+    usize::from(rule.title_pattern.is_some()) + usize::from(rule.url_pattern.is_some())
+}
+
+fn contains(haystack: &str, needle: &str) -> bool {
+    haystack.to_lowercase().contains(&needle.to_lowercase())
+}
+
+/// Bundle id, category and work type of a built-in rule
+type DefaultRule = (&'static str, ActivityCategory, Option<WorkType>);
+
+fn default_rules() -> Vec<AppCategoryRule> {
+    use ActivityCategory::{Administrative, Communication, Documentation, Meeting, Research};
+
+    let table: &[DefaultRule] = &[
+        ("com.tinyspeck.slackmacgap", Communication, None),
+        ("com.microsoft.teams", Communication, None),
+        ("com.microsoft.teams2", Communication, None),
+        ("com.apple.mail", Communication, Some(WorkType::Email)),
+        ("com.microsoft.outlook", Communication, Some(WorkType::Email)),
+        ("us.zoom.xos", Meeting, Some(WorkType::Meeting)),
+        ("com.apple.ical", Administrative, None),
+        ("com.microsoft.word", Documentation, Some(WorkType::Documentation)),
+        ("com.bloomberg.terminal", Research, Some(WorkType::Research)),
+    ];
+
+    table
+        .iter()
+        .map(|(bundle_id, category, work_type)| AppCategoryRule {
+            id: format!("{DEFAULT_RULE_PREFIX}{bundle_id}"),
+            bundle_id: (*bundle_id).to_string(),
+            title_pattern: None,
+            url_pattern: None,
+            category: Some(category.clone()),
+            work_type: work_type.clone(),
+            project_id: None,
+            created_at: 0,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+    const SLACK: &str = "com.tinyspeck.slackmacgap";
+
+    #[derive(Default)]
+    struct InMemoryRules {
+        rules: Mutex<Vec<AppCategoryRule>>,
+    }
+
+    #[async_trait]
+    impl AppCategoryRuleRepository for InMemoryRules {
+        async fn list_rules(&self) -> Result<Vec<AppCategoryRule>> {
+            Ok(self.rules.lock().unwrap().clone())
+        }
+
+        async fn save_rule(&self, rule: &AppCategoryRule) -> Result<()> {
+            let mut rules = self.rules.lock().unwrap();
+            rules.retain(|existing| existing.id != rule.id);
+            rules.push(rule.clone());
+            Ok(())
+        }
+
+        async fn delete_rule(&self, id: &str) -> Result<bool> {
+            let mut rules = self.rules.lock().unwrap();
+            let before = rules.len();
+            rules.retain(|rule| rule.id != id);
+            Ok(rules.len() < before)
+        }
+    }
+
+    fn rules() -> AppCategoryRules {
+        AppCategoryRules::new(Arc::new(InMemoryRules::default()))
+    }
+
+    fn window(bundle_id: &str, title: &str) -> WindowContext {
+        WindowContext {
+            app_name: bundle_id.rsplit('.').next().unwrap_or_default().to_string(),
+            window_title: title.to_string(),
+            bundle_id: Some(bundle_id.to_string()),
+            url: None,
+            url_host: None,
+            document_name: None,
+            file_path: None,
+        }
+    }
+
+    fn context(active_app: WindowContext) -> ActivityContext {
+        ActivityContext {
+            active_app,
+            recent_apps: vec![],
+            detected_activity: "chat".to_string(),
+            work_type: None,
+            activity_category: ActivityCategory::Internal,
+            billable_confidence: 0.0,
+            suggested_client: None,
+            suggested_matter: None,
+            suggested_task_code: None,
+            extracted_metadata: Default::default(),
+            evidence: Default::default(),
+            calendar_event: None,
+            location: None,
+            temporal_context: None,
+            classification: None,
+            capture_mode: Default::default(),
+        }
+    }
+
+    fn client_work_for(project_id: &str) -> AppCategoryRuleParams {
+        AppCategoryRuleParams {
+            bundle_id: SLACK.to_string(),
+            category: Some(ActivityCategory::ClientWork),
+            project_id: Some(project_id.to_string()),
+            ..AppCategoryRuleParams::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn user_override_changes_category_of_matching_app() {
+        let rules = rules();
+        let slack = window(SLACK, "#general");
+
+        let default = rules.resolve(&slack).await.unwrap().expect("default Slack rule");
+        assert_eq!(default.category, Some(ActivityCategory::Communication));
+
+        rules.add(client_work_for("USC0063201"), NOW).await.unwrap();
+
+        let mut context = context(slack);
+        let applied = rules.apply(&mut context).await.unwrap().expect("user rule");
+        assert!(!applied.id.starts_with(DEFAULT_RULE_PREFIX));
+        assert_eq!(context.activity_category, ActivityCategory::ClientWork);
+        assert_eq!(context.suggested_matter.as_deref(), Some("USC0063201"));
+    }
+
+    #[tokio::test]
+    async fn non_matching_app_falls_back_to_defaults() {
+        let rules = rules();
+        rules.add(client_work_for("USC0063201"), NOW).await.unwrap();
+
+        let zoom = rules.resolve(&window("us.zoom.xos", "Standup")).await.unwrap();
+        assert_eq!(zoom.and_then(|rule| rule.category), Some(ActivityCategory::Meeting));
+
+        let unknown = rules.resolve(&window("com.example.editor", "notes.txt")).await.unwrap();
+        assert_eq!(unknown, None);
+    }
+
+    #[tokio::test]
+    async fn more_specific_rule_wins_within_user_rules() {
+        let rules = rules();
+        rules.add(client_work_for("USC0063201"), NOW).await.unwrap();
+        rules
+            .add(
+                AppCategoryRuleParams {
+                    title_pattern: Some("#astro-deal".to_string()),
+                    ..client_work_for("USC0042105")
+                },
+                NOW + 1,
+            )
+            .await
+            .unwrap();
+
+        let deal = rules.resolve(&window(SLACK, "#ASTRO-DEAL | Slack")).await.unwrap().unwrap();
+        assert_eq!(deal.project_id.as_deref(), Some("USC0042105"));
+
+        let other = rules.resolve(&window(SLACK, "#general | Slack")).await.unwrap().unwrap();
+        assert_eq!(other.project_id.as_deref(), Some("USC0063201"));
+    }
+
+    #[tokio::test]
+    async fn removing_override_restores_default() {
+        let rules = rules();
+        let rule = rules.add(client_work_for("USC0063201"), NOW).await.unwrap();
+
+        assert!(rules.remove(&rule.id).await.unwrap());
+        assert!(!rules.remove(&rule.id).await.unwrap());
+
+        let slack = rules.resolve(&window(SLACK, "#general")).await.unwrap().unwrap();
+        assert_eq!(slack.category, Some(ActivityCategory::Communication));
+        assert!(rules.list().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn invalid_rules_are_rejected() {
+        let rules = rules();
+
+        let blank_bundle =
+            AppCategoryRuleParams { bundle_id: "  ".to_string(), ..client_work_for("X") };
+        assert!(matches!(rules.add(blank_bundle, NOW).await, Err(PulseArcError::InvalidInput(_))));
+
+        let no_output = AppCategoryRuleParams {
+            bundle_id: SLACK.to_string(),
+            ..AppCategoryRuleParams::default()
+        };
+        assert!(matches!(rules.add(no_output, NOW).await, Err(PulseArcError::InvalidInput(_))));
+        assert!(rules.list().await.unwrap().is_empty());
+    }
+
+    fn team_rule(bundle_id: &str, project_id: &str) -> serde_json::Value {
+        serde_json::json!({
+            "bundle_id": bundle_id,
+            "category": "client_work",
+            "project_id": project_id,
+        })
+    }
+
+    #[tokio::test]
+    async fn import_merges_new_rules() {
+        let rules = rules();
+        let local = rules.add(client_work_for("USC0063201"), NOW).await.unwrap();
+
+        let report = rules
+            .import(
+                vec![team_rule("us.zoom.xos", "USC0042105"), team_rule("com.microsoft.Word", "X1")],
+                AppCategoryConflictPolicy::TeamWins,
+                NOW + 10,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(report.added, 2);
+        assert_eq!((report.replaced, report.skipped), (0, 0));
+        let stored = rules.list().await.unwrap();
+        assert_eq!(stored.len(), 3);
+        assert!(stored.contains(&local));
+
+        let zoom = rules.resolve(&window("us.zoom.xos", "Standup")).await.unwrap().unwrap();
+        assert_eq!(zoom.project_id.as_deref(), Some("USC0042105"));
+        assert_eq!(zoom.created_at, NOW + 10);
+    }
+
+    #[tokio::test]
+    async fn import_conflict_policy_decides_overlapping_bundle_ids() {
+        let team = || vec![team_rule(&SLACK.to_uppercase(), "TEAM-PROJECT")];
+
+        let rules = rules();
+        let local = rules.add(client_work_for("LOCAL-PROJECT"), NOW).await.unwrap();
+        let report = rules.import(team(), AppCategoryConflictPolicy::LocalWins, NOW).await.unwrap();
+        assert_eq!((report.added, report.replaced, report.skipped), (0, 0, 1));
+        assert_eq!(rules.list().await.unwrap(), vec![local.clone()]);
+
+        let report = rules.import(team(), AppCategoryConflictPolicy::TeamWins, NOW).await.unwrap();
+        assert_eq!((report.added, report.replaced, report.skipped), (0, 1, 0));
+        let stored = rules.list().await.unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].id, local.id, "replaced rule keeps its id");
+        assert_eq!(stored[0].project_id.as_deref(), Some("TEAM-PROJECT"));
+    }
+
+    #[tokio::test]
+    async fn import_reports_invalid_rules_without_aborting() {
+        let rules = rules();
+
+        let report = rules
+            .import(
+                vec![
+                    serde_json::json!({ "bundle_id": SLACK, "category": "napping" }),
+                    team_rule("us.zoom.xos", "USC0042105"),
+                    serde_json::json!({ "bundle_id": "com.apple.mail" }),
+                ],
+                AppCategoryConflictPolicy::TeamWins,
+                NOW,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(report.added, 1);
+        let invalid: Vec<_> =
+            report.invalid.iter().map(|rule| (rule.index, rule.bundle_id.as_deref())).collect();
+        assert_eq!(invalid, vec![(0, Some(SLACK)), (2, Some("com.apple.mail"))]);
+        assert_eq!(rules.list().await.unwrap().len(), 1);
+    }
+}
@@ -16,6 +16,8 @@ use pulsearc_domain::classification::{ActivityBreakdown, BlockConfig, ProposedBl
 use pulsearc_domain::types::ActivitySegment;
 use pulsearc_domain::Result;
 
+use crate::user::WorkdaySchedule;
+
 /// Enriched segment (simplified - no project match)
 /// REFACTOR-004: Removed project_match field (inference moved to OpenAI)
 #[derive(Clone)]
@@ -27,13 +29,24 @@ struct EnrichedSegment {
 /// REFACTOR-004: Simplified to only consolidate by time gaps (no inference)
 pub struct BlockBuilder {
     config: BlockConfig,
+    /// User's working hours; blocks keep default temporal flags without one
+    workday: Option<WorkdaySchedule>,
 }
 
 impl BlockBuilder {
     /// Create new block builder (simplified - no dependencies)
     /// REFACTOR-004: Removed SignalExtractor and ProjectMatcher dependencies
     pub fn new(config: BlockConfig) -> Result<Self> {
-        Ok(Self { config })
+        Ok(Self { config, workday: None })
+    }
+
+    /// Flag blocks as weekend/after-hours using the user's working hours
+    ///
+    /// Each block is judged at its midpoint and records the schedule's
+    /// timezone.
+    pub fn with_workday(mut self, schedule: WorkdaySchedule) -> Self {
+        self.workday = Some(schedule);
+        self
     }
 
     // ✅ REMOVED: Deprecated build_daily_blocks() method removed in REFACTOR-003
@@ -289,6 +302,25 @@ impl BlockBuilder {
         // REFACTOR-004: Collect segment IDs for traceability
         let segment_ids: Vec<String> = segments.iter().map(|s| s.segment.id.clone()).collect();
 
+        // Temporal context from the user's working hours, judged at the midpoint
+        let (timezone, is_weekend, is_after_hours) = match &self.workday {
+            Some(schedule) => {
+                let midpoint = start_ts + duration_secs / 2;
+                match chrono::DateTime::from_timestamp(midpoint, 0) {
+                    Some(at) => {
+                        let context = schedule.temporal_context(at);
+                        (
+                            Some(schedule.timezone().name().to_string()),
+                            context.is_weekend,
+                            context.is_after_hours,
+                        )
+                    }
+                    None => (None, false, false),
+                }
+            }
+            None => (None, false, false),
+        };
+
         // REFACTOR-004: Default values - OpenAI will populate these during
         // classification
         Ok(Some(ProposedBlock {
@@ -321,12 +353,13 @@ impl BlockBuilder {
             // FEATURE-028 Phase 3: Idle time calculated from segments
             total_idle_secs,
             idle_handling,
-            // FEATURE-033 Phase 2: Location context (defaults, will be populated later)
-            timezone: None,
+            // FEATURE-033 Phase 2: Location context (timezone and temporal flags
+            // come from the workday schedule when set)
+            timezone,
             work_location: None,
             is_travel: false,
-            is_weekend: false,
-            is_after_hours: false,
+            is_weekend,
+            is_after_hours,
             // FEATURE-033 Phase 5: Overlap detection (defaults, will be populated by
             // detect_overlaps)
             has_calendar_overlap: false,
@@ -1053,4 +1086,34 @@ mod tests {
         // Assert: Block is created despite DST transition
         assert_eq!(blocks.len(), 1, "Should handle DST fall back (25-hour day)");
     }
+
+    #[test]
+    fn test_workday_schedule_sets_temporal_flags() {
+        // AC: Evening work is after hours for a 9-5 user but not for a 9-8 user
+        use chrono::TimeZone;
+        use chrono_tz::America::Los_Angeles;
+        use pulsearc_domain::WorkdayConfig;
+
+        // Thursday 2024-10-24, 18:00-19:00 PDT
+        let start = Los_Angeles.with_ymd_and_hms(2024, 10, 24, 18, 0, 0).unwrap().timestamp();
+        let day_start = Los_Angeles.with_ymd_and_hms(2024, 10, 24, 0, 0, 0).unwrap().timestamp();
+        let segment = create_test_segment("seg1", start, start + 3600, "Excel", 0);
+
+        let build = |end_minutes: u32| {
+            let config = WorkdayConfig { start_minutes: 540, end_minutes, ..Default::default() };
+            let schedule = WorkdaySchedule::new(&config, "America/Los_Angeles").unwrap();
+            create_test_builder()
+                .with_workday(schedule)
+                .build_daily_blocks_from_segments(std::slice::from_ref(&segment), day_start)
+                .unwrap()
+        };
+
+        let nine_to_five = build(17 * 60);
+        assert!(nine_to_five[0].is_after_hours);
+        assert!(!nine_to_five[0].is_weekend);
+        assert_eq!(nine_to_five[0].timezone.as_deref(), Some("America/Los_Angeles"));
+
+        let nine_to_eight = build(20 * 60);
+        assert!(!nine_to_eight[0].is_after_hours);
+    }
 }
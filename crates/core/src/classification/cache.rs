@@ -0,0 +1,351 @@
+//! Classification result cache
+//!
+//! Re-classifying a block whose salient inputs have not changed re-invokes the
+//! classifier (and, for the OpenAI classifier, re-spends tokens). The
+//! [`ClassificationCache`] stores results keyed by a [`context_signature`] of
+//! those inputs so an identical block is answered without calling the
+//! classifier.
+//!
+//! The signature covers, per snapshot and in timestamp order: detected
+//! activity, work type, activity category, primary app, idle flag and the
+//! snapshot content hash (the raw context JSON when no hash was stored).
+//! Snapshot ids and timestamps are excluded, so a cached result is re-timed to
+//! the new block before it is returned.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use chrono::Duration as ChronoDuration;
+use pulsearc_common::cache::{Cache, CacheConfig};
+use pulsearc_domain::{ActivitySnapshot, TimeEntry};
+use uuid::Uuid;
+
+/// Default lifetime of a cached classification (1 hour)
+pub const DEFAULT_CLASSIFICATION_CACHE_TTL: Duration = Duration::from_secs(3600);
+
+/// Default number of cached classifications
+pub const DEFAULT_CLASSIFICATION_CACHE_SIZE: usize = 1_000;
+
+/// Default estimated cost of one classifier call in USD (gpt-4o-mini with a
+/// typical block prompt)
+pub const DEFAULT_COST_PER_CLASSIFICATION_USD: f64 = 0.0005;
+
+/// Cost counters are kept in micro-dollars so they fit an atomic integer
+const MICRO_USD: f64 = 1_000_000.0;
+
+/// Classification cache configuration
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassificationCacheConfig {
+    /// How long a cached result stays valid
+    pub ttl: Duration,
+    /// Maximum cached results (least recently used are evicted first)
+    pub max_entries: usize,
+    /// Estimated classifier cost per call, used for the cost-saved metric
+    pub estimated_cost_per_call_usd: f64,
+}
+
+impl Default for ClassificationCacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl: DEFAULT_CLASSIFICATION_CACHE_TTL,
+            max_entries: DEFAULT_CLASSIFICATION_CACHE_SIZE,
+            estimated_cost_per_call_usd: DEFAULT_COST_PER_CLASSIFICATION_USD,
+        }
+    }
+}
+
+/// Point-in-time view of the cache counters
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ClassificationCacheMetrics {
+    /// Lookups answered from the cache
+    pub hits: u64,
+    /// Lookups that fell through to the classifier
+    pub misses: u64,
+    /// Estimated classifier spend avoided by cache hits, in USD
+    pub estimated_cost_saved_usd: f64,
+}
+
+impl ClassificationCacheMetrics {
+    /// Fraction of lookups served from the cache (0.0 when unused)
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+/// Cached entry plus the block start it was classified for
+#[derive(Debug, Clone)]
+struct CachedClassification {
+    entry: TimeEntry,
+    block_start: i64,
+}
+
+/// TTL + LRU cache of classification results
+pub struct ClassificationCache {
+    entries: Cache<u64, CachedClassification>,
+    cost_per_call_micro_usd: u64,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    cost_saved_micro_usd: AtomicU64,
+}
+
+impl ClassificationCache {
+    /// Create an empty cache
+    pub fn new(config: ClassificationCacheConfig) -> Self {
+        let cost_per_call = (config.estimated_cost_per_call_usd.max(0.0) * MICRO_USD).round();
+        Self {
+            entries: Cache::new(CacheConfig::ttl_lru(config.ttl, config.max_entries)),
+            cost_per_call_micro_usd: cost_per_call as u64,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            cost_saved_micro_usd: AtomicU64::new(0),
+        }
+    }
+
+    /// Look up a cached classification for `snapshots`, recording a hit or
+    /// miss.
+    ///
+    /// On a hit the cached entry is returned with a fresh id and its times
+    /// shifted to start at the first snapshot of this block.
+    pub fn get(&self, signature: u64, snapshots: &[ActivitySnapshot]) -> Option<TimeEntry> {
+        let Some(cached) = self.entries.get(&signature) else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return None;
+        };
+
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        self.cost_saved_micro_usd.fetch_add(self.cost_per_call_micro_usd, Ordering::Relaxed);
+
+        let offset = block_start(snapshots).unwrap_or(cached.block_start) - cached.block_start;
+        Some(retime(cached.entry, offset))
+    }
+
+    /// Cache `entry` as the classification of `snapshots`.
+    pub fn insert(&self, signature: u64, snapshots: &[ActivitySnapshot], entry: &TimeEntry) {
+        let Some(block_start) = block_start(snapshots) else {
+            return;
+        };
+        self.entries.insert(signature, CachedClassification { entry: entry.clone(), block_start });
+    }
+
+    /// Current hit/miss and cost-saved counters
+    pub fn metrics(&self) -> ClassificationCacheMetrics {
+        ClassificationCacheMetrics {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            estimated_cost_saved_usd: self.cost_saved_micro_usd.load(Ordering::Relaxed) as f64
+                / MICRO_USD,
+        }
+    }
+
+    /// Drop every cached classification (counters are kept)
+    pub fn clear(&self) {
+        self.entries.clear();
+    }
+}
+
+/// Hash of the salient classification inputs of a block
+///
+/// Independent of snapshot ids, timestamps and input order.
+pub fn context_signature(snapshots: &[ActivitySnapshot]) -> u64 {
+    let mut ordered: Vec<&ActivitySnapshot> = snapshots.iter().collect();
+    ordered.sort_by_key(|snapshot| snapshot.timestamp);
+
+    let mut hasher = DefaultHasher::new();
+    ordered.len().hash(&mut hasher);
+    for snapshot in ordered {
+        snapshot.detected_activity.hash(&mut hasher);
+        snapshot.work_type.hash(&mut hasher);
+        snapshot.activity_category.hash(&mut hasher);
+        snapshot.primary_app.hash(&mut hasher);
+        snapshot.is_idle.hash(&mut hasher);
+        match &snapshot.content_hash {
+            Some(content_hash) => content_hash.hash(&mut hasher),
+            None => snapshot.activity_context_json.hash(&mut hasher),
+        }
+    }
+    hasher.finish()
+}
+
+fn block_start(snapshots: &[ActivitySnapshot]) -> Option<i64> {
+    snapshots.iter().map(|snapshot| snapshot.timestamp).min()
+}
+
+/// Give a cached entry a new id and shift its times by `offset_secs`
+fn retime(mut entry: TimeEntry, offset_secs: i64) -> TimeEntry {
+    let offset = ChronoDuration::seconds(offset_secs);
+    entry.id = Uuid::now_v7();
+    entry.start_time += offset;
+    entry.end_time = entry.end_time.map(|end| end + offset);
+    entry.entry_date = Some(entry.start_time.date_naive().to_string());
+    entry
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use pulsearc_domain::{Result, TimeEntryParams};
+
+    use super::*;
+    use crate::classification::ports::{Classifier, TimeEntryRepository};
+    use crate::classification::ClassificationService;
+
+    /// Classifier that counts calls and bills everything
+    #[derive(Default)]
+    struct CountingClassifier {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl Classifier for CountingClassifier {
+        async fn classify(&self, snapshots: Vec<ActivitySnapshot>) -> Result<TimeEntry> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let start = block_start(&snapshots).unwrap_or_default();
+            let mut entry = TimeEntry::new(TimeEntryParams {
+                id: Uuid::now_v7(),
+                start_time: DateTime::from_timestamp(start, 0).unwrap_or_default(),
+                end_time: DateTime::from_timestamp(start + 60, 0),
+                duration_seconds: Some(60),
+                description: "Client work".to_string(),
+                project_id: Some("USC0063201".to_string()),
+                wbs_code: None,
+            });
+            entry.billable = Some(true);
+            Ok(entry)
+        }
+    }
+
+    /// Repository that accepts and forgets every entry
+    struct NullRepository;
+
+    #[async_trait]
+    impl TimeEntryRepository for NullRepository {
+        async fn save_entry(&self, _entry: TimeEntry) -> Result<()> {
+            Ok(())
+        }
+
+        async fn get_entries(
+            &self,
+            _start: DateTime<Utc>,
+            _end: DateTime<Utc>,
+        ) -> Result<Vec<TimeEntry>> {
+            Ok(Vec::new())
+        }
+
+        async fn update_entry(&self, _entry: TimeEntry) -> Result<()> {
+            Ok(())
+        }
+
+        async fn delete_entry(&self, _id: Uuid) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn snapshot(id: &str, timestamp: i64, window_hash: &str) -> ActivitySnapshot {
+        ActivitySnapshot {
+            id: id.to_string(),
+            timestamp,
+            activity_context_json: "{}".to_string(),
+            detected_activity: "working".to_string(),
+            work_type: Some("modeling".to_string()),
+            activity_category: Some("client_work".to_string()),
+            primary_app: "com.microsoft.Excel".to_string(),
+            processed: false,
+            batch_id: None,
+            created_at: timestamp,
+            processed_at: None,
+            is_idle: false,
+            idle_duration_secs: None,
+            content_hash: Some(window_hash.to_string()),
+        }
+    }
+
+    fn block(prefix: &str, start: i64, window_hash: &str) -> Vec<ActivitySnapshot> {
+        (0..3).map(|i| snapshot(&format!("{prefix}-{i}"), start + i * 30, window_hash)).collect()
+    }
+
+    fn service(
+        classifier: Arc<CountingClassifier>,
+        config: ClassificationCacheConfig,
+    ) -> ClassificationService {
+        ClassificationService::new(classifier, Arc::new(NullRepository)).with_cache(config)
+    }
+
+    #[tokio::test]
+    async fn identical_blocks_invoke_classifier_once() {
+        let classifier = Arc::new(CountingClassifier::default());
+        let config = ClassificationCacheConfig {
+            estimated_cost_per_call_usd: 0.002,
+            ..ClassificationCacheConfig::default()
+        };
+        let service = service(classifier.clone(), config);
+
+        let first = service.classify_and_save(block("a", 1_000, "model-xlsx")).await.unwrap();
+        let second = service.classify_and_save(block("b", 5_000, "model-xlsx")).await.unwrap();
+
+        assert_eq!(classifier.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(second.billable, first.billable);
+        assert_eq!(second.project_id, first.project_id);
+        assert_ne!(second.id, first.id);
+        assert_eq!(second.start_time.timestamp(), 5_000);
+        assert_eq!(second.end_time.map(|end| end.timestamp()), Some(5_060));
+
+        let metrics = service.cache_metrics().expect("cache enabled");
+        assert_eq!(metrics.hits, 1);
+        assert_eq!(metrics.misses, 1);
+        assert!((metrics.estimated_cost_saved_usd - 0.002).abs() < 1e-9);
+        assert!((metrics.hit_rate() - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn changed_salient_field_misses_cache() {
+        let classifier = Arc::new(CountingClassifier::default());
+        let service = service(classifier.clone(), ClassificationCacheConfig::default());
+
+        service.classify_and_save(block("a", 1_000, "model-xlsx")).await.unwrap();
+        service.classify_and_save(block("b", 1_000, "deck-pptx")).await.unwrap();
+
+        let mut recategorized = block("c", 1_000, "model-xlsx");
+        recategorized[1].activity_category = Some("administrative".to_string());
+        service.classify_and_save(recategorized).await.unwrap();
+
+        assert_eq!(classifier.calls.load(Ordering::SeqCst), 3);
+        let metrics = service.cache_metrics().expect("cache enabled");
+        assert_eq!(metrics.hits, 0);
+        assert_eq!(metrics.misses, 3);
+        assert_eq!(metrics.estimated_cost_saved_usd, 0.0);
+    }
+
+    #[test]
+    fn signature_ignores_ids_timestamps_and_order() {
+        let mut reversed = block("b", 9_000, "model-xlsx");
+        reversed.reverse();
+
+        assert_eq!(
+            context_signature(&block("a", 1_000, "model-xlsx")),
+            context_signature(&reversed)
+        );
+    }
+
+    #[tokio::test]
+    async fn service_without_cache_always_classifies() {
+        let classifier = Arc::new(CountingClassifier::default());
+        let service = ClassificationService::new(classifier.clone(), Arc::new(NullRepository));
+
+        service.classify_and_save(block("a", 1_000, "model-xlsx")).await.unwrap();
+        service.classify_and_save(block("b", 1_000, "model-xlsx")).await.unwrap();
+
+        assert_eq!(classifier.calls.load(Ordering::SeqCst), 2);
+        assert!(service.cache_metrics().is_none());
+    }
+}
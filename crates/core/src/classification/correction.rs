@@ -0,0 +1,544 @@
+//! User corrections of block classifications
+//!
+//! A correction rewrites the block's project and/or billable flag, marks it
+//! `edited`, and queues it for sync like an accepted block. It also feeds
+//! both learning layers when the project changed:
+//! - The corrected project is recorded as a project acceptance for the
+//!   block's context signature, so [`ProjectMatcher`] learning suggests it for
+//!   similar activity (see [`project_context_signature`]).
+//! - The original suggestion is recorded as a dismissal, so a suggestion the
+//!   user keeps correcting away from is eventually suppressed.
+//!
+//! [`ProjectMatcher`]: crate::classification::ProjectMatcher
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use pulsearc_domain::types::classification::{
+    ClassificationCorrection, ProjectAcceptance, ProposedBlock,
+};
+use pulsearc_domain::{ActivitySnapshot, PulseArcError, Result};
+use tracing::{info, warn};
+
+use crate::classification::ports::{BlockRepository, BlockSyncQueue, ProjectAcceptanceRepository};
+use crate::classification::project_matcher::project_context_signature;
+use crate::classification::signal_extractor::SignalExtractor;
+use crate::classification::suppression::SuggestionSuppressor;
+use crate::tracking::ports::SnapshotRepository;
+
+/// Status of a block whose classification the user changed
+const EDITED_STATUS: &str = "edited";
+
+/// Applies user corrections to blocks and learns from them
+pub struct ClassificationCorrector {
+    block_repo: Arc<dyn BlockRepository>,
+    snapshot_repo: Arc<dyn SnapshotRepository>,
+    acceptances: Arc<dyn ProjectAcceptanceRepository>,
+    suppressor: Arc<SuggestionSuppressor>,
+    sync_queue: Arc<dyn BlockSyncQueue>,
+    signal_extractor: SignalExtractor,
+}
+
+impl ClassificationCorrector {
+    /// Create a corrector
+    pub fn new(
+        block_repo: Arc<dyn BlockRepository>,
+        snapshot_repo: Arc<dyn SnapshotRepository>,
+        acceptances: Arc<dyn ProjectAcceptanceRepository>,
+        suppressor: Arc<SuggestionSuppressor>,
+        sync_queue: Arc<dyn BlockSyncQueue>,
+    ) -> Self {
+        Self {
+            block_repo,
+            snapshot_repo,
+            acceptances,
+            suppressor,
+            sync_queue,
+            signal_extractor: SignalExtractor::new(),
+        }
+    }
+
+    /// Apply `correction` to the block with `block_id`.
+    ///
+    /// Returns the corrected block. Failing to record a learning signal is
+    /// logged and does not fail the correction.
+    ///
+    /// # Errors
+    ///
+    /// `InvalidInput` if the correction changes nothing or sets an empty
+    /// project, `NotFound` if the block does not exist, and any error from
+    /// saving or enqueueing the block.
+    pub async fn correct_classification(
+        &self,
+        block_id: &str,
+        correction: &ClassificationCorrection,
+    ) -> Result<ProposedBlock> {
+        self.correct_classification_at(block_id, correction, Utc::now().timestamp()).await
+    }
+
+    /// [`Self::correct_classification`] with an explicit correction time
+    pub async fn correct_classification_at(
+        &self,
+        block_id: &str,
+        correction: &ClassificationCorrection,
+        corrected_at: i64,
+    ) -> Result<ProposedBlock> {
+        validate(correction)?;
+        let original = self
+            .block_repo
+            .get_proposed_block(block_id)
+            .await?
+            .ok_or_else(|| PulseArcError::NotFound(format!("Block {} not found", block_id)))?;
+
+        let corrected = apply(&original, correction, corrected_at);
+        self.block_repo.save_proposed_block(&corrected).await?;
+
+        let project_changed = corrected.inferred_project_id != original.inferred_project_id;
+        if project_changed {
+            self.learn_project(&corrected, corrected_at).await;
+            if let Err(err) = self.suppressor.record_dismissal(&original, corrected_at).await {
+                warn!(block_id, error = %err, "Failed to record corrected suggestion");
+            }
+        }
+
+        self.sync_queue.enqueue_block(&corrected).await?;
+
+        info!(
+            block_id,
+            project_changed,
+            billable = corrected.billable,
+            "Classification corrected and queued for sync"
+        );
+        Ok(corrected)
+    }
+
+    /// Record the corrected project for the block's context signature
+    async fn learn_project(&self, block: &ProposedBlock, accepted_at: i64) {
+        let Some(project_id) = block.inferred_project_id.clone() else { return };
+
+        let snapshots = match self.block_snapshots(block) {
+            Ok(snapshots) if !snapshots.is_empty() => snapshots,
+            Ok(_) => {
+                warn!(block_id = %block.id, "No snapshots to learn the correction from");
+                return;
+            }
+            Err(err) => {
+                warn!(block_id = %block.id, error = %err, "Failed to load snapshots for learning");
+                return;
+            }
+        };
+
+        let signals = self.signal_extractor.extract_and_merge(&snapshots).await;
+        let acceptance = ProjectAcceptance {
+            signature: project_context_signature(&signals),
+            project_id,
+            accepted_at,
+        };
+        if let Err(err) = self.acceptances.record_acceptance(&acceptance) {
+            warn!(block_id = %block.id, error = %err, "Failed to record corrected project");
+        }
+    }
+
+    fn block_snapshots(&self, block: &ProposedBlock) -> Result<Vec<ActivitySnapshot>> {
+        let start = timestamp(block.start_ts)?;
+        let end = timestamp(block.end_ts)?;
+        let ids: HashSet<&str> = block.snapshot_ids.iter().map(String::as_str).collect();
+
+        Ok(self
+            .snapshot_repo
+            .find_snapshots_by_time_range(start, end)
+            .map_err(|e| PulseArcError::Database(e.to_string()))?
+            .into_iter()
+            .filter(|snapshot| ids.contains(snapshot.id.as_str()))
+            .collect())
+    }
+}
+
+fn validate(correction: &ClassificationCorrection) -> Result<()> {
+    let blank = |value: &Option<String>| value.as_deref().is_some_and(|v| v.trim().is_empty());
+    if blank(&correction.project_id) || blank(&correction.wbs_code) {
+        return Err(PulseArcError::InvalidInput(
+            "corrected project and WBS code must not be empty".to_string(),
+        ));
+    }
+    if correction.project_id.is_none()
+        && correction.wbs_code.is_none()
+        && correction.deal_name.is_none()
+        && correction.billable.is_none()
+    {
+        return Err(PulseArcError::InvalidInput("correction changes nothing".to_string()));
+    }
+    Ok(())
+}
+
+/// `original` with the correction applied and marked as reviewed
+fn apply(
+    original: &ProposedBlock,
+    correction: &ClassificationCorrection,
+    corrected_at: i64,
+) -> ProposedBlock {
+    let mut block = original.clone();
+    let trimmed = |value: &Option<String>| value.as_deref().map(|v| v.trim().to_string());
+
+    if let Some(project_id) = trimmed(&correction.project_id) {
+        // A different project invalidates the old WBS code and deal name
+        // unless the correction supplies them
+        if block.inferred_project_id.as_deref() != Some(project_id.as_str()) {
+            block.inferred_wbs_code = None;
+            block.inferred_deal_name = None;
+        }
+        block.inferred_project_id = Some(project_id);
+    }
+    if let Some(wbs_code) = trimmed(&correction.wbs_code) {
+        block.inferred_wbs_code = Some(wbs_code);
+    }
+    if let Some(deal_name) = trimmed(&correction.deal_name) {
+        block.inferred_deal_name = Some(deal_name).filter(|name| !name.is_empty());
+    }
+    if let Some(billable) = correction.billable {
+        block.billable = billable;
+    }
+
+    block.status = EDITED_STATUS.to_string();
+    block.reviewed_at = Some(corrected_at);
+    block
+}
+
+fn timestamp(ts: i64) -> Result<DateTime<Utc>> {
+    DateTime::from_timestamp(ts, 0)
+        .ok_or_else(|| PulseArcError::InvalidInput(format!("invalid timestamp: {ts}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use chrono::NaiveDate;
+    use pulsearc_common::error::CommonResult;
+    use pulsearc_domain::types::classification::{
+        ActivityBreakdown, BlockConfig, SuggestionDismissal,
+    };
+    use pulsearc_domain::{Page, PageRequest, SnapshotFilter};
+    use serde_json::json;
+
+    use super::*;
+    use crate::classification::ports::SuggestionDismissalRepository;
+    use crate::classification::project_matcher::{compute_learned_projects, ProjectLearningConfig};
+
+    const START_TS: i64 = 1_741_600_800; // 2025-03-10 10:00:00 UTC
+    const NOW: i64 = START_TS + 7_200;
+
+    #[derive(Default)]
+    struct InMemoryBlocks {
+        blocks: Mutex<HashMap<String, ProposedBlock>>,
+    }
+
+    #[async_trait]
+    impl BlockRepository for InMemoryBlocks {
+        async fn save_proposed_block(&self, block: &ProposedBlock) -> Result<()> {
+            self.blocks.lock().unwrap().insert(block.id.clone(), block.clone());
+            Ok(())
+        }
+
+        async fn get_proposed_blocks(&self, _date: NaiveDate) -> Result<Vec<ProposedBlock>> {
+            Ok(self.blocks.lock().unwrap().values().cloned().collect())
+        }
+
+        async fn get_proposed_block(&self, block_id: &str) -> Result<Option<ProposedBlock>> {
+            Ok(self.blocks.lock().unwrap().get(block_id).cloned())
+        }
+
+        async fn approve_block(&self, _block_id: &str, _at: DateTime<Utc>) -> Result<()> {
+            Ok(())
+        }
+
+        async fn reject_block(&self, _block_id: &str, _at: DateTime<Utc>) -> Result<()> {
+            Ok(())
+        }
+
+        async fn get_block_history(&self, _snapshot_id: &str) -> Result<Vec<ProposedBlock>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_block_config(&self) -> Result<BlockConfig> {
+            Ok(BlockConfig::default())
+        }
+    }
+
+    struct FixedSnapshots(Vec<ActivitySnapshot>);
+
+    impl SnapshotRepository for FixedSnapshots {
+        fn find_snapshots_by_time_range(
+            &self,
+            start: DateTime<Utc>,
+            end: DateTime<Utc>,
+        ) -> CommonResult<Vec<ActivitySnapshot>> {
+            let range = start.timestamp()..=end.timestamp();
+            Ok(self.0.iter().filter(|s| range.contains(&s.timestamp)).cloned().collect())
+        }
+
+        fn count_snapshots_by_date(&self, _date: NaiveDate) -> CommonResult<usize> {
+            Ok(0)
+        }
+
+        fn store_snapshot(&self, _snapshot: &ActivitySnapshot) -> CommonResult<()> {
+            Ok(())
+        }
+
+        fn store_snapshots_batch(&self, _snapshots: &[ActivitySnapshot]) -> CommonResult<()> {
+            Ok(())
+        }
+
+        fn insert_batch(&self, _snapshots: &[ActivitySnapshot]) -> CommonResult<usize> {
+            Ok(0)
+        }
+
+        fn count_active_snapshots(
+            &self,
+            _start: DateTime<Utc>,
+            _end: DateTime<Utc>,
+        ) -> CommonResult<i64> {
+            Ok(0)
+        }
+
+        fn browse_snapshots(
+            &self,
+            _filter: &SnapshotFilter,
+            page: PageRequest,
+        ) -> CommonResult<Page<ActivitySnapshot>> {
+            Ok(Page { items: Vec::new(), page: page.page, page_size: page.page_size, total: 0 })
+        }
+
+        fn find_by_content_hash(&self, _content_hash: &str) -> CommonResult<Vec<ActivitySnapshot>> {
+            Ok(Vec::new())
+        }
+    }
+
+    #[derive(Default)]
+    struct InMemoryAcceptances(Mutex<Vec<ProjectAcceptance>>);
+
+    impl ProjectAcceptanceRepository for InMemoryAcceptances {
+        fn record_acceptance(&self, acceptance: &ProjectAcceptance) -> Result<()> {
+            self.0.lock().unwrap().push(acceptance.clone());
+            Ok(())
+        }
+
+        fn get_acceptances(
+            &self,
+            signature: &str,
+            since_ts: i64,
+        ) -> Result<Vec<ProjectAcceptance>> {
+            Ok(self
+                .0
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|a| a.signature == signature && a.accepted_at >= since_ts)
+                .cloned()
+                .collect())
+        }
+    }
+
+    #[derive(Default)]
+    struct InMemoryDismissals(Mutex<Vec<SuggestionDismissal>>);
+
+    #[async_trait]
+    impl SuggestionDismissalRepository for InMemoryDismissals {
+        async fn record_dismissal(&self, dismissal: &SuggestionDismissal) -> Result<()> {
+            self.0.lock().unwrap().push(dismissal.clone());
+            Ok(())
+        }
+
+        async fn get_dismissals_since(&self, since_ts: i64) -> Result<Vec<SuggestionDismissal>> {
+            Ok(self
+                .0
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|d| d.dismissed_at >= since_ts)
+                .cloned()
+                .collect())
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingSyncQueue(Mutex<Vec<ProposedBlock>>);
+
+    #[async_trait]
+    impl BlockSyncQueue for RecordingSyncQueue {
+        async fn enqueue_block(&self, block: &ProposedBlock) -> Result<()> {
+            self.0.lock().unwrap().push(block.clone());
+            Ok(())
+        }
+    }
+
+    struct Harness {
+        blocks: Arc<InMemoryBlocks>,
+        acceptances: Arc<InMemoryAcceptances>,
+        dismissals: Arc<InMemoryDismissals>,
+        queue: Arc<RecordingSyncQueue>,
+        corrector: ClassificationCorrector,
+    }
+
+    fn harness(snapshots: Vec<ActivitySnapshot>) -> Harness {
+        let blocks = Arc::new(InMemoryBlocks::default());
+        let acceptances = Arc::new(InMemoryAcceptances::default());
+        let dismissals = Arc::new(InMemoryDismissals::default());
+        let queue = Arc::new(RecordingSyncQueue::default());
+        let corrector = ClassificationCorrector::new(
+            blocks.clone(),
+            Arc::new(FixedSnapshots(snapshots)),
+            acceptances.clone(),
+            Arc::new(SuggestionSuppressor::new(dismissals.clone())),
+            queue.clone(),
+        );
+        Harness { blocks, acceptances, dismissals, queue, corrector }
+    }
+
+    fn snapshot(id: &str, timestamp: i64) -> ActivitySnapshot {
+        let context = json!({
+            "active_app": {
+                "app_name": "Microsoft Excel",
+                "window_title": "Luna modeling.xlsx - Excel",
+                "bundle_id": null
+            },
+            "recent_apps": [],
+            "detected_activity": "modeling"
+        });
+        ActivitySnapshot {
+            id: id.into(),
+            timestamp,
+            activity_context_json: context.to_string(),
+            detected_activity: "modeling".into(),
+            work_type: None,
+            activity_category: None,
+            primary_app: "Microsoft Excel".into(),
+            processed: true,
+            batch_id: None,
+            created_at: timestamp,
+            processed_at: None,
+            is_idle: false,
+            idle_duration_secs: None,
+            content_hash: None,
+        }
+    }
+
+    fn block(id: &str) -> ProposedBlock {
+        ProposedBlock {
+            id: id.into(),
+            start_ts: START_TS,
+            end_ts: START_TS + 1_800,
+            duration_secs: 1_800,
+            inferred_project_id: Some("USC0063201".into()),
+            inferred_wbs_code: Some("USC0063201.1.1".into()),
+            inferred_deal_name: Some("Project Astro".into()),
+            inferred_workstream: Some("modeling".into()),
+            billable: false,
+            confidence: 0.6,
+            classifier_used: Some("hybrid".into()),
+            activities: vec![ActivityBreakdown {
+                name: "Microsoft Excel".into(),
+                duration_secs: 1_800,
+                percentage: 100.0,
+            }],
+            snapshot_ids: vec![format!("{id}-s1")],
+            segment_ids: vec![],
+            reasons: vec!["fts5_keyword:astro".into()],
+            status: "suggested".into(),
+            created_at: START_TS + 1_800,
+            reviewed_at: None,
+            total_idle_secs: 0,
+            idle_handling: "exclude".into(),
+            timezone: None,
+            work_location: None,
+            is_travel: false,
+            is_weekend: false,
+            is_after_hours: false,
+            has_calendar_overlap: false,
+            overlapping_event_ids: vec![],
+            is_double_booked: false,
+        }
+    }
+
+    fn to_luna() -> ClassificationCorrection {
+        ClassificationCorrection {
+            project_id: Some("USC0058923".into()),
+            wbs_code: Some("USC0058923.3.1".into()),
+            deal_name: None,
+            billable: Some(true),
+        }
+    }
+
+    #[tokio::test]
+    async fn correction_updates_block_learns_and_enqueues() {
+        let h = harness(vec![snapshot("b1-s1", START_TS + 60)]);
+        h.blocks.save_proposed_block(&block("b1")).await.unwrap();
+
+        let corrected =
+            h.corrector.correct_classification_at("b1", &to_luna(), NOW).await.expect("corrected");
+
+        let stored = h.blocks.get_proposed_block("b1").await.unwrap().expect("stored");
+        assert_eq!(stored.inferred_project_id.as_deref(), Some("USC0058923"));
+        assert_eq!(stored.inferred_wbs_code.as_deref(), Some("USC0058923.3.1"));
+        assert_eq!(stored.inferred_deal_name, None, "stale deal name is dropped");
+        assert!(stored.billable);
+        assert_eq!(stored.status, "edited");
+        assert_eq!(stored.reviewed_at, Some(NOW));
+
+        let acceptances = h.acceptances.0.lock().unwrap().clone();
+        assert_eq!(acceptances.len(), 1);
+        assert_eq!(acceptances[0].project_id, "USC0058923");
+        assert_eq!(acceptances[0].signature, "excel|luna,modeling|-|-");
+
+        let dismissals = h.dismissals.0.lock().unwrap().clone();
+        assert_eq!(dismissals.len(), 1);
+        assert_eq!(dismissals[0].signature, "microsoft excel|usc0063201");
+
+        let queued = h.queue.0.lock().unwrap().clone();
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].inferred_project_id, corrected.inferred_project_id);
+    }
+
+    #[tokio::test]
+    async fn similar_future_block_learns_corrected_project() {
+        let h = harness(vec![snapshot("b1-s1", START_TS + 60), snapshot("b2-s1", NOW + 60)]);
+        h.blocks.save_proposed_block(&block("b1")).await.unwrap();
+        h.corrector.correct_classification_at("b1", &to_luna(), NOW).await.expect("corrected");
+
+        // A later block with the same activity resolves to the same signature,
+        // where project learning now ranks the corrected project first
+        let later = ProposedBlock { start_ts: NOW, end_ts: NOW + 1_800, ..block("b2") };
+        let signals = SignalExtractor::new()
+            .extract_and_merge(&h.corrector.block_snapshots(&later).unwrap())
+            .await;
+        let signature = project_context_signature(&signals);
+        let acceptances = h.acceptances.get_acceptances(&signature, 0).unwrap();
+        let learned =
+            compute_learned_projects(&acceptances, &ProjectLearningConfig::default(), NOW + 60);
+
+        assert_eq!(learned.first().map(|l| l.project_id.as_str()), Some("USC0058923"));
+    }
+
+    #[tokio::test]
+    async fn billable_only_correction_skips_project_learning() {
+        let h = harness(vec![snapshot("b1-s1", START_TS + 60)]);
+        h.blocks.save_proposed_block(&block("b1")).await.unwrap();
+        let correction = ClassificationCorrection { billable: Some(true), ..Default::default() };
+
+        let corrected =
+            h.corrector.correct_classification_at("b1", &correction, NOW).await.expect("corrected");
+
+        assert!(corrected.billable);
+        assert_eq!(corrected.inferred_project_id.as_deref(), Some("USC0063201"));
+        assert!(h.acceptances.0.lock().unwrap().is_empty());
+        assert!(h.dismissals.0.lock().unwrap().is_empty());
+        assert_eq!(h.queue.0.lock().unwrap().len(), 1);
+
+        let empty = ClassificationCorrection::default();
+        let result = h.corrector.correct_classification_at("b1", &empty, NOW).await;
+        assert!(matches!(result, Err(PulseArcError::InvalidInput(_))));
+    }
+}
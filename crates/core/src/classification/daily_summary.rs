@@ -0,0 +1,393 @@
+//! End-of-day summary of accepted time
+//!
+//! Aggregates the day's accepted blocks into billable and non-billable totals
+//! with a per-project breakdown, and renders the result as plain text or
+//! Markdown for export. Suggested, pending and rejected blocks are not
+//! counted.
+//!
+//! After-hours and non-working-day time is judged against the user's
+//! [`WorkdaySchedule`], at each block's midpoint.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDate};
+use pulsearc_common::time::format::format_duration;
+use pulsearc_domain::types::classification::{DailySummary, ProjectDaySummary, ProposedBlock};
+use pulsearc_domain::{PulseArcError, Result};
+
+use crate::classification::ports::BlockRepository;
+use crate::user::WorkdaySchedule;
+
+/// Block statuses that count towards the summary
+const COUNTED_STATUSES: [&str; 2] = ["accepted", "edited"];
+
+/// Label used for blocks without an inferred project
+const UNASSIGNED_LABEL: &str = "Unassigned";
+
+/// Export format for [`render_daily_summary`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SummaryFormat {
+    /// Plain text, one line per figure
+    Text,
+    /// Markdown with a per-project table
+    #[default]
+    Markdown,
+}
+
+impl FromStr for SummaryFormat {
+    type Err = PulseArcError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "text" | "txt" => Ok(Self::Text),
+            "markdown" | "md" => Ok(Self::Markdown),
+            other => Err(PulseArcError::InvalidInput(format!("unknown summary format: {other}"))),
+        }
+    }
+}
+
+/// Load the blocks for `date` and summarize the accepted ones
+pub async fn daily_summary(
+    block_repo: &dyn BlockRepository,
+    date: NaiveDate,
+    workday: &WorkdaySchedule,
+) -> Result<DailySummary> {
+    let blocks = block_repo.get_proposed_blocks(date).await?;
+    Ok(summarize_blocks(date, &blocks, workday))
+}
+
+/// Summarize accepted blocks into billable/non-billable and per-project totals
+pub fn summarize_blocks(
+    date: NaiveDate,
+    blocks: &[ProposedBlock],
+    workday: &WorkdaySchedule,
+) -> DailySummary {
+    let mut projects: BTreeMap<Option<String>, ProjectDaySummary> = BTreeMap::new();
+    let mut summary = DailySummary {
+        date: date.format("%Y-%m-%d").to_string(),
+        total_secs: 0,
+        billable_secs: 0,
+        non_billable_secs: 0,
+        after_hours_secs: 0,
+        weekend_secs: 0,
+        block_count: 0,
+        projects: Vec::new(),
+    };
+
+    for block in blocks.iter().filter(|block| is_counted(block)) {
+        let duration = block.duration_secs.max(0);
+        let project_id = block
+            .inferred_project_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|project| !project.is_empty())
+            .map(str::to_string);
+
+        let project = projects.entry(project_id.clone()).or_insert_with(|| ProjectDaySummary {
+            project_id,
+            project_name: None,
+            total_secs: 0,
+            billable_secs: 0,
+            non_billable_secs: 0,
+            block_count: 0,
+        });
+        if project.project_name.is_none() {
+            project.project_name = block
+                .inferred_deal_name
+                .as_deref()
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(str::to_string);
+        }
+
+        project.total_secs += duration;
+        project.block_count += 1;
+        summary.total_secs += duration;
+        summary.block_count += 1;
+        if block.billable {
+            project.billable_secs += duration;
+            summary.billable_secs += duration;
+        } else {
+            project.non_billable_secs += duration;
+            summary.non_billable_secs += duration;
+        }
+
+        if let Some(midpoint) = DateTime::from_timestamp(block.start_ts + duration / 2, 0) {
+            if workday.is_after_hours(midpoint) {
+                summary.after_hours_secs += duration;
+            }
+            if workday.is_weekend(midpoint) {
+                summary.weekend_secs += duration;
+            }
+        }
+    }
+
+    summary.projects = projects.into_values().collect();
+    // Largest first; unassigned time sorts after projects with the same total
+    summary.projects.sort_by(|a, b| {
+        b.total_secs
+            .cmp(&a.total_secs)
+            .then_with(|| a.project_id.is_none().cmp(&b.project_id.is_none()))
+            .then_with(|| a.project_id.cmp(&b.project_id))
+    });
+    summary
+}
+
+/// Render a summary for export
+pub fn render_daily_summary(summary: &DailySummary, format: SummaryFormat) -> String {
+    match format {
+        SummaryFormat::Text => render_text(summary),
+        SummaryFormat::Markdown => render_markdown(summary),
+    }
+}
+
+fn render_text(summary: &DailySummary) -> String {
+    let mut lines = vec![
+        format!("Daily summary for {}", summary.date),
+        format!("Total tracked: {}", format_secs(summary.total_secs)),
+        format!("Billable: {}", format_secs(summary.billable_secs)),
+        format!("Non-billable: {}", format_secs(summary.non_billable_secs)),
+    ];
+    if summary.after_hours_secs > 0 {
+        lines.push(format!("After hours: {}", format_secs(summary.after_hours_secs)));
+    }
+    if summary.weekend_secs > 0 {
+        lines.push(format!("Non-working days: {}", format_secs(summary.weekend_secs)));
+    }
+
+    if !summary.projects.is_empty() {
+        lines.push(String::new());
+        lines.push("By project:".to_string());
+        for project in &summary.projects {
+            lines.push(format!(
+                "  {}: {} (billable {}, non-billable {})",
+                project_label(project),
+                format_secs(project.total_secs),
+                format_secs(project.billable_secs),
+                format_secs(project.non_billable_secs),
+            ));
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+fn render_markdown(summary: &DailySummary) -> String {
+    let mut lines = vec![
+        format!("# Daily Summary: {}", summary.date),
+        String::new(),
+        format!("- **Total tracked:** {}", format_secs(summary.total_secs)),
+        format!("- **Billable:** {}", format_secs(summary.billable_secs)),
+        format!("- **Non-billable:** {}", format_secs(summary.non_billable_secs)),
+    ];
+    if summary.after_hours_secs > 0 {
+        lines.push(format!("- **After hours:** {}", format_secs(summary.after_hours_secs)));
+    }
+    if summary.weekend_secs > 0 {
+        lines.push(format!("- **Non-working days:** {}", format_secs(summary.weekend_secs)));
+    }
+
+    if !summary.projects.is_empty() {
+        lines.push(String::new());
+        lines.push("## By Project".to_string());
+        lines.push(String::new());
+        lines.push("| Project | Billable | Non-billable | Total |".to_string());
+        lines.push("| --- | --- | --- | --- |".to_string());
+        for project in &summary.projects {
+            lines.push(format!(
+                "| {} | {} | {} | {} |",
+                project_label(project).replace('|', "\\|"),
+                format_secs(project.billable_secs),
+                format_secs(project.non_billable_secs),
+                format_secs(project.total_secs),
+            ));
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+fn is_counted(block: &ProposedBlock) -> bool {
+    COUNTED_STATUSES.iter().any(|status| block.status.eq_ignore_ascii_case(status))
+}
+
+fn project_label(project: &ProjectDaySummary) -> String {
+    match (project.project_id.as_deref(), project.project_name.as_deref()) {
+        (Some(id), Some(name)) => format!("{id} ({name})"),
+        (Some(id), None) => id.to_string(),
+        (None, _) => UNASSIGNED_LABEL.to_string(),
+    }
+}
+
+/// `format_duration` renders zero as microseconds; summaries use whole seconds
+fn format_secs(secs: i64) -> String {
+    if secs <= 0 {
+        return "0s".to_string();
+    }
+    format_duration(Duration::from_secs(secs.unsigned_abs()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, 14).expect("valid date")
+    }
+
+    /// 08:00-18:00 Monday-Friday UTC; test blocks start Friday 08:00
+    fn workday() -> WorkdaySchedule {
+        WorkdaySchedule::default()
+    }
+
+    fn block(
+        id: &str,
+        project: Option<&str>,
+        duration_secs: i64,
+        billable: bool,
+        status: &str,
+    ) -> ProposedBlock {
+        ProposedBlock {
+            id: id.to_string(),
+            start_ts: 1_741_939_200,
+            end_ts: 1_741_939_200 + duration_secs,
+            duration_secs,
+            inferred_project_id: project.map(str::to_string),
+            inferred_wbs_code: None,
+            inferred_deal_name: (project == Some("USC0063201"))
+                .then(|| "Project Astro".to_string()),
+            inferred_workstream: None,
+            billable,
+            confidence: 0.9,
+            classifier_used: None,
+            activities: vec![],
+            snapshot_ids: vec![],
+            segment_ids: vec![],
+            reasons: vec![],
+            status: status.to_string(),
+            created_at: 1_741_939_200,
+            reviewed_at: None,
+            total_idle_secs: 0,
+            idle_handling: "exclude".to_string(),
+            timezone: None,
+            work_location: None,
+            is_travel: false,
+            is_weekend: false,
+            is_after_hours: false,
+            has_calendar_overlap: false,
+            overlapping_event_ids: vec![],
+            is_double_booked: false,
+        }
+    }
+
+    fn mixed_blocks() -> Vec<ProposedBlock> {
+        vec![
+            block("b1", Some("USC0063201"), 7_200, true, "accepted"),
+            block("b2", Some("USC0063201"), 1_800, false, "edited"),
+            block("b3", Some("USC0042105"), 3_600, true, "accepted"),
+            block("b4", None, 2_700, false, "accepted"),
+            block("b5", Some("USC0042105"), 5_400, true, "suggested"),
+            block("b6", None, 900, false, "rejected"),
+        ]
+    }
+
+    #[test]
+    fn totals_count_only_accepted_blocks() {
+        let summary = summarize_blocks(date(), &mixed_blocks(), &workday());
+
+        assert_eq!(summary.date, "2025-03-14");
+        assert_eq!(summary.block_count, 4);
+        assert_eq!(summary.total_secs, 15_300);
+        assert_eq!(summary.billable_secs, 10_800);
+        assert_eq!(summary.non_billable_secs, 4_500);
+    }
+
+    #[test]
+    fn project_rollups_reconcile_to_grand_total() {
+        let summary = summarize_blocks(date(), &mixed_blocks(), &workday());
+
+        let ids: Vec<_> =
+            summary.projects.iter().map(|project| project.project_id.as_deref()).collect();
+        assert_eq!(ids, vec![Some("USC0063201"), Some("USC0042105"), None]);
+
+        let astro = &summary.projects[0];
+        assert_eq!(astro.total_secs, 9_000);
+        assert_eq!(astro.billable_secs, 7_200);
+        assert_eq!(astro.non_billable_secs, 1_800);
+        assert_eq!(astro.block_count, 2);
+
+        let total: i64 = summary.projects.iter().map(|project| project.total_secs).sum();
+        let billable: i64 = summary.projects.iter().map(|project| project.billable_secs).sum();
+        let non_billable: i64 =
+            summary.projects.iter().map(|project| project.non_billable_secs).sum();
+        assert_eq!(total, summary.total_secs);
+        assert_eq!(billable, summary.billable_secs);
+        assert_eq!(non_billable, summary.non_billable_secs);
+        assert_eq!(summary.billable_secs + summary.non_billable_secs, summary.total_secs);
+    }
+
+    #[test]
+    fn renders_markdown_and_text() {
+        let summary = summarize_blocks(date(), &mixed_blocks(), &workday());
+
+        let markdown = render_daily_summary(&summary, SummaryFormat::Markdown);
+        assert!(markdown.starts_with("# Daily Summary: 2025-03-14\n"));
+        assert!(markdown.contains("- **Total tracked:** 4h 15m 0s"));
+        assert!(markdown.contains("| USC0063201 (Project Astro) | 2h 0m 0s | 30m 0s | 2h 30m 0s |"));
+        assert!(markdown.contains("| Unassigned | 0s | 45m 0s | 45m 0s |"));
+
+        let text = render_daily_summary(&summary, SummaryFormat::Text);
+        assert!(text.contains("Billable: 3h 0m 0s"));
+        assert!(text.contains("Non-billable: 1h 15m 0s"));
+    }
+
+    #[test]
+    fn empty_day_has_zero_totals() {
+        let summary = summarize_blocks(date(), &[], &workday());
+
+        assert_eq!(summary.total_secs, 0);
+        assert!(summary.projects.is_empty());
+        assert!(render_daily_summary(&summary, SummaryFormat::Text).contains("Total tracked: 0s"));
+    }
+
+    #[test]
+    fn parses_summary_format() {
+        assert_eq!("md".parse::<SummaryFormat>().ok(), Some(SummaryFormat::Markdown));
+        assert_eq!("Text".parse::<SummaryFormat>().ok(), Some(SummaryFormat::Text));
+        assert!("pdf".parse::<SummaryFormat>().is_err());
+    }
+
+    #[test]
+    fn splits_out_after_hours_and_non_working_time() {
+        use pulsearc_domain::WorkdayConfig;
+
+        let mut evening = block("b1", Some("USC0063201"), 3_600, true, "accepted");
+        evening.start_ts += 11 * 3_600; // Friday 19:00 UTC
+        evening.end_ts += 11 * 3_600;
+        let blocks = vec![evening, block("b2", Some("USC0063201"), 3_600, true, "accepted")];
+
+        let nine_to_five =
+            WorkdayConfig { start_minutes: 540, end_minutes: 1020, ..Default::default() };
+        let summary =
+            summarize_blocks(date(), &blocks, &WorkdaySchedule::new(&nine_to_five, "UTC").unwrap());
+        assert_eq!(summary.after_hours_secs, 3_600 + 3_600);
+        assert_eq!(summary.weekend_secs, 0);
+
+        let sunday_to_thursday = WorkdayConfig {
+            start_minutes: 420,
+            end_minutes: 1260,
+            working_days: vec![7, 1, 2, 3, 4],
+        };
+        let summary = summarize_blocks(
+            date(),
+            &blocks,
+            &WorkdaySchedule::new(&sunday_to_thursday, "UTC").unwrap(),
+        );
+        assert_eq!(summary.after_hours_secs, 0);
+        assert_eq!(summary.weekend_secs, 7_200);
+        assert!(render_daily_summary(&summary, SummaryFormat::Text)
+            .contains("Non-working days: 2h 0m 0s"));
+    }
+}
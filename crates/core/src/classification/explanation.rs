@@ -0,0 +1,297 @@
+//! "Why was this block classified this way?"
+//!
+//! Assembles a [`ClassificationExplanation`] from what a classified
+//! [`ProposedBlock`] already records. The project matcher stores its evidence
+//! as tagged reasons (`fts5_keyword:astro`, `url:domain_match`, ...) with
+//! fixed weights, so the explanation can list each piece of evidence next to
+//! what it added to the confidence score. Where the stored confidence differs
+//! from the sum of those weights (the classifier re-scored the candidate, or a
+//! learned acceptance contributed a decayed weight), a final step accounts
+//! for the difference so the steps always end at the block's confidence.
+//!
+//! Blocks classified by a language model carry `llm:<model>` as their
+//! classifier; the model's justification is stored as a `rationale:` reason
+//! and its remaining untagged reasons are reported as stated by the model.
+
+use pulsearc_domain::types::classification::{
+    CalendarOverlapExplanation, ClassificationExplanation, ConfidenceStep, EvidenceKind,
+    ExplanationEvidence, InferredProject, LlmRationale, ProposedBlock,
+};
+use pulsearc_domain::{PulseArcError, Result};
+
+use crate::classification::ports::BlockRepository;
+use crate::classification::project_matcher::{
+    EXACT_MATCH_CONFIDENCE, FALLBACK_CONFIDENCE, FTS_KEYWORD_WEIGHT, URL_DOMAIN_WEIGHT,
+    VDR_PROVIDER_WEIGHT,
+};
+
+/// Classifier prefix marking a language-model classification
+pub const LLM_CLASSIFIER_PREFIX: &str = "llm:";
+
+/// Reason prefix holding a language model's justification
+pub const RATIONALE_REASON_PREFIX: &str = "rationale:";
+
+/// Differences below this are rounding, not a re-score
+const CONFIDENCE_EPSILON: f32 = 0.005;
+
+/// Explain the classification of the block with `block_id`.
+///
+/// # Errors
+///
+/// `NotFound` if no such block exists.
+pub async fn explain_classification(
+    block_repo: &dyn BlockRepository,
+    block_id: &str,
+) -> Result<ClassificationExplanation> {
+    let block = block_repo
+        .get_proposed_block(block_id)
+        .await?
+        .ok_or_else(|| PulseArcError::NotFound(format!("Block {} not found", block_id)))?;
+    Ok(explain_block(&block))
+}
+
+/// Explain the classification recorded on `block`
+pub fn explain_block(block: &ProposedBlock) -> ClassificationExplanation {
+    let model = block
+        .classifier_used
+        .as_deref()
+        .and_then(|classifier| classifier.strip_prefix(LLM_CLASSIFIER_PREFIX));
+
+    let mut evidence = Vec::new();
+    let mut rationale = None;
+    let mut exact_match_counted = false;
+    for reason in &block.reasons {
+        if let Some(text) = reason.strip_prefix(RATIONALE_REASON_PREFIX) {
+            rationale.get_or_insert_with(|| text.trim().to_string());
+            continue;
+        }
+        let mut item = parse_reason(reason, model.is_some());
+        // All keywords of a project-name match share its single score
+        if item.kind == EvidenceKind::ProjectNameMatch {
+            if exact_match_counted {
+                item.contribution = None;
+            }
+            exact_match_counted = true;
+        }
+        evidence.push(item);
+    }
+
+    let confidence_steps = match model {
+        Some(model) => vec![ConfidenceStep {
+            description: format!("Confidence reported by {}", model),
+            delta: block.confidence,
+            total: block.confidence,
+        }],
+        None => rule_confidence_steps(&evidence, block.confidence),
+    };
+
+    let llm = model.map(|model| LlmRationale {
+        model: model.to_string(),
+        rationale,
+        reasons: evidence
+            .iter()
+            .filter(|item| item.kind == EvidenceKind::ModelReason)
+            .map(|item| item.detail.clone())
+            .collect(),
+    });
+
+    ClassificationExplanation {
+        block_id: block.id.clone(),
+        classifier: block.classifier_used.clone(),
+        confidence: block.confidence,
+        billable: block.billable,
+        project: InferredProject {
+            project_id: block.inferred_project_id.clone(),
+            wbs_code: block.inferred_wbs_code.clone(),
+            deal_name: block.inferred_deal_name.clone(),
+            workstream: block.inferred_workstream.clone(),
+        },
+        evidence,
+        calendar: CalendarOverlapExplanation {
+            has_overlap: block.has_calendar_overlap,
+            overlapping_event_ids: block.overlapping_event_ids.clone(),
+            is_double_booked: block.is_double_booked,
+        },
+        confidence_steps,
+        llm,
+    }
+}
+
+/// Evidence recorded by one tagged reason
+fn parse_reason(reason: &str, by_model: bool) -> ExplanationEvidence {
+    let (kind, detail, contribution) = match reason.split_once(':') {
+        Some(("keyword", keyword)) => {
+            (EvidenceKind::ProjectNameMatch, keyword, Some(EXACT_MATCH_CONFIDENCE))
+        }
+        Some(("fts5_keyword", keyword)) => {
+            (EvidenceKind::KeywordMatch, keyword, Some(FTS_KEYWORD_WEIGHT))
+        }
+        Some(("url", detail)) => (EvidenceKind::UrlDomain, detail, Some(URL_DOMAIN_WEIGHT)),
+        Some(("vdr", provider)) => (EvidenceKind::VdrProvider, provider, Some(VDR_PROVIDER_WEIGHT)),
+        // Weight depends on how closely the folder matched the project name
+        Some(("file_path", folder)) => (EvidenceKind::FilePath, folder, None),
+        // Weight decays with the age of each acceptance
+        Some(("learned", detail)) => (EvidenceKind::LearnedAcceptance, detail, None),
+        Some(("fallback", detail)) => (EvidenceKind::Fallback, detail, Some(FALLBACK_CONFIDENCE)),
+        _ if by_model => (EvidenceKind::ModelReason, reason, None),
+        _ => (EvidenceKind::Other, reason, None),
+    };
+    ExplanationEvidence { kind, detail: detail.to_string(), contribution }
+}
+
+/// Confidence steps of a rules classification, ending at `confidence`
+fn rule_confidence_steps(evidence: &[ExplanationEvidence], confidence: f32) -> Vec<ConfidenceStep> {
+    let mut steps = Vec::new();
+    let mut total = 0.0_f32;
+    for item in evidence {
+        let Some(delta) = item.contribution else { continue };
+        total += delta;
+        steps.push(ConfidenceStep {
+            description: format!("{} ({})", evidence_label(item.kind), item.detail),
+            delta,
+            total,
+        });
+    }
+
+    if total > 1.0 {
+        steps.push(ConfidenceStep {
+            description: "Capped at 1.0".to_string(),
+            delta: 1.0 - total,
+            total: 1.0,
+        });
+        total = 1.0;
+    }
+
+    if (confidence - total).abs() > CONFIDENCE_EPSILON {
+        steps.push(ConfidenceStep {
+            description: "Adjusted by classifier scoring".to_string(),
+            delta: confidence - total,
+            total: confidence,
+        });
+    }
+    steps
+}
+
+fn evidence_label(kind: EvidenceKind) -> &'static str {
+    match kind {
+        EvidenceKind::ProjectNameMatch => "Project name match",
+        EvidenceKind::KeywordMatch => "Keyword match",
+        EvidenceKind::UrlDomain => "URL domain match",
+        EvidenceKind::VdrProvider => "VDR provider",
+        EvidenceKind::FilePath => "Project folder match",
+        EvidenceKind::LearnedAcceptance => "Learned acceptance",
+        EvidenceKind::Fallback => "G&A fallback",
+        EvidenceKind::ModelReason => "Model reason",
+        EvidenceKind::Other => "Other",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pulsearc_domain::types::classification::ActivityBreakdown;
+
+    use super::*;
+
+    fn block(classifier: &str, confidence: f32, reasons: &[&str]) -> ProposedBlock {
+        ProposedBlock {
+            id: "block-1".into(),
+            start_ts: 1_741_600_800,
+            end_ts: 1_741_604_400,
+            duration_secs: 3_600,
+            inferred_project_id: Some("USC0063201".into()),
+            inferred_wbs_code: Some("USC0063201.1.1".into()),
+            inferred_deal_name: Some("Project Astro".into()),
+            inferred_workstream: Some("modeling".into()),
+            billable: true,
+            confidence,
+            classifier_used: Some(classifier.into()),
+            activities: vec![ActivityBreakdown {
+                name: "Microsoft Excel".into(),
+                duration_secs: 3_600,
+                percentage: 100.0,
+            }],
+            snapshot_ids: vec![],
+            segment_ids: vec![],
+            reasons: reasons.iter().map(|reason| reason.to_string()).collect(),
+            status: "suggested".into(),
+            created_at: 1_741_604_400,
+            reviewed_at: None,
+            total_idle_secs: 0,
+            idle_handling: "exclude".into(),
+            timezone: None,
+            work_location: None,
+            is_travel: false,
+            is_weekend: false,
+            is_after_hours: false,
+            has_calendar_overlap: true,
+            overlapping_event_ids: vec!["evt-1".into()],
+            is_double_booked: false,
+        }
+    }
+
+    #[test]
+    fn rules_classification_lists_evidence_and_contributions() {
+        let block = block(
+            "hybrid",
+            0.9,
+            &["fts5_keyword:astro", "url:domain_match", "vdr:provider", "learned:acceptances=3"],
+        );
+
+        let explanation = explain_block(&block);
+
+        let evidence: Vec<_> = explanation
+            .evidence
+            .iter()
+            .map(|item| (item.kind, item.detail.as_str(), item.contribution))
+            .collect();
+        assert_eq!(
+            evidence,
+            vec![
+                (EvidenceKind::KeywordMatch, "astro", Some(FTS_KEYWORD_WEIGHT)),
+                (EvidenceKind::UrlDomain, "domain_match", Some(URL_DOMAIN_WEIGHT)),
+                (EvidenceKind::VdrProvider, "provider", Some(VDR_PROVIDER_WEIGHT)),
+                (EvidenceKind::LearnedAcceptance, "acceptances=3", None),
+            ]
+        );
+        assert_eq!(explanation.project.wbs_code.as_deref(), Some("USC0063201.1.1"));
+        assert_eq!(explanation.calendar.overlapping_event_ids, vec!["evt-1".to_string()]);
+        assert!(explanation.llm.is_none());
+
+        // 0.40 + 0.20 + 0.30 already adds up to the stored 0.90
+        assert_eq!(explanation.confidence_steps.len(), 3);
+        let last = explanation.confidence_steps.last().unwrap();
+        assert!((last.total - 0.9).abs() < 1e-6, "steps end at the block's confidence");
+
+        let json = serde_json::to_value(&explanation).unwrap();
+        assert_eq!(json["evidence"][0]["kind"], "keyword_match");
+    }
+
+    #[test]
+    fn llm_classification_includes_model_and_rationale() {
+        let block = block(
+            "llm:gpt-4o-mini",
+            0.82,
+            &[
+                "rationale: Excel model named after Project Astro",
+                "Workbook title mentions Astro",
+                "Calendar event with client attendees",
+            ],
+        );
+
+        let explanation = explain_block(&block);
+
+        let llm = explanation.llm.expect("model rationale");
+        assert_eq!(llm.model, "gpt-4o-mini");
+        assert_eq!(llm.rationale.as_deref(), Some("Excel model named after Project Astro"));
+        assert_eq!(
+            llm.reasons,
+            vec![
+                "Workbook title mentions Astro".to_string(),
+                "Calendar event with client attendees".to_string()
+            ]
+        );
+        assert!(explanation.evidence.iter().all(|item| item.kind == EvidenceKind::ModelReason));
+        assert_eq!(explanation.confidence_steps.len(), 1);
+        assert_eq!(explanation.confidence_steps[0].total, 0.82);
+    }
+}
@@ -0,0 +1,208 @@
+//! Untracked-time (gap) detection
+//!
+//! Finds the parts of a workday not covered by any time entry. Entries are
+//! clipped to the workday and merged, so overlapping entries never create
+//! negative or duplicate gaps. Only gaps longer than `min_gap` are returned.
+//!
+//! Gaps can be labelled with the calendar event that overlaps them most, as
+//! a hint that the time was probably spent in that meeting. All-day events
+//! are never used as labels.
+
+use std::time::Duration;
+
+use pulsearc_domain::{CalendarEventRow, Gap, TimeEntry, TimeRange};
+
+/// Find untracked intervals in `workday` longer than `min_gap`
+///
+/// Gaps are returned in chronological order and carry no calendar label; see
+/// [`label_gaps`].
+pub fn detect_gaps(entries: &[TimeEntry], workday: TimeRange, min_gap: Duration) -> Vec<Gap> {
+    let min_gap_secs = i64::try_from(min_gap.as_secs()).unwrap_or(i64::MAX);
+
+    let mut covered: Vec<(i64, i64)> = entries
+        .iter()
+        .filter_map(entry_span)
+        .map(|(start, end)| (start.max(workday.start_ts), end.min(workday.end_ts)))
+        .filter(|(start, end)| end > start)
+        .collect();
+    covered.sort_unstable();
+
+    let mut gaps = Vec::new();
+    let mut cursor = workday.start_ts;
+    for (start, end) in covered {
+        if start > cursor {
+            push_gap(&mut gaps, cursor, start, min_gap_secs);
+        }
+        cursor = cursor.max(end);
+    }
+    if workday.end_ts > cursor {
+        push_gap(&mut gaps, cursor, workday.end_ts, min_gap_secs);
+    }
+
+    gaps
+}
+
+/// Label each gap with the calendar event that overlaps it the most
+///
+/// Ties go to the event that starts first.
+pub fn label_gaps(gaps: &mut [Gap], events: &[CalendarEventRow]) {
+    for gap in gaps {
+        let best = events
+            .iter()
+            .filter(|event| !event.is_all_day)
+            .map(|event| (overlap_secs(gap, event), event))
+            .filter(|(overlap, _)| *overlap > 0)
+            .max_by(|(a_overlap, a), (b_overlap, b)| {
+                a_overlap.cmp(b_overlap).then_with(|| b.start_ts.cmp(&a.start_ts))
+            });
+
+        if let Some((_, event)) = best {
+            gap.likely_meeting = Some(event.summary.clone());
+            gap.calendar_event_id = Some(event.id.clone());
+        }
+    }
+}
+
+fn push_gap(gaps: &mut Vec<Gap>, start_ts: i64, end_ts: i64, min_gap_secs: i64) {
+    let duration_secs = end_ts - start_ts;
+    if duration_secs > min_gap_secs {
+        gaps.push(Gap {
+            start_ts,
+            end_ts,
+            duration_secs,
+            likely_meeting: None,
+            calendar_event_id: None,
+        });
+    }
+}
+
+/// Entry span as Unix seconds; end is `end_time` or start + duration
+fn entry_span(entry: &TimeEntry) -> Option<(i64, i64)> {
+    let start = entry.start_time.timestamp();
+    let end = entry
+        .end_time
+        .map(|end| end.timestamp())
+        .or_else(|| entry.duration_seconds.map(|secs| start.saturating_add(secs)))?;
+    Some((start, end))
+}
+
+fn overlap_secs(gap: &Gap, event: &CalendarEventRow) -> i64 {
+    (gap.end_ts.min(event.end_ts) - gap.start_ts.max(event.start_ts)).max(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+    use pulsearc_domain::TimeEntryParams;
+    use uuid::Uuid;
+
+    use super::*;
+
+    const DAY_START: i64 = 1_741_597_200; // 2025-03-10 09:00:00 UTC
+    const HOUR: i64 = 3_600;
+    const MINUTE: i64 = 60;
+
+    fn workday() -> TimeRange {
+        TimeRange { start_ts: DAY_START, end_ts: DAY_START + 8 * HOUR, is_all_day: false }
+    }
+
+    fn entry(start_offset: i64, end_offset: i64) -> TimeEntry {
+        let start = DAY_START + start_offset;
+        TimeEntry::new(TimeEntryParams {
+            id: Uuid::new_v4(),
+            start_time: DateTime::from_timestamp(start, 0).expect("valid timestamp"),
+            end_time: DateTime::from_timestamp(DAY_START + end_offset, 0),
+            duration_seconds: Some(end_offset - start_offset),
+            description: "work".to_string(),
+            project_id: None,
+            wbs_code: None,
+        })
+    }
+
+    fn event(id: &str, summary: &str, start_offset: i64, end_offset: i64) -> CalendarEventRow {
+        CalendarEventRow {
+            id: id.to_string(),
+            google_event_id: format!("g-{id}"),
+            user_email: "user@example.com".to_string(),
+            summary: summary.to_string(),
+            description: None,
+            start_ts: DAY_START + start_offset,
+            end_ts: DAY_START + end_offset,
+            is_all_day: false,
+            recurring_event_id: None,
+            parsed_project: None,
+            parsed_workstream: None,
+            parsed_task: None,
+            confidence_score: None,
+            meeting_platform: None,
+            is_recurring_series: false,
+            is_online_meeting: false,
+            has_external_attendees: None,
+            organizer_email: None,
+            organizer_domain: None,
+            meeting_id: None,
+            attendee_count: None,
+            external_attendee_count: None,
+            created_at: DAY_START,
+        }
+    }
+
+    #[test]
+    fn contiguous_day_has_no_gaps() {
+        let entries =
+            vec![entry(0, 3 * HOUR), entry(3 * HOUR, 5 * HOUR), entry(4 * HOUR, 8 * HOUR)];
+
+        let gaps = detect_gaps(&entries, workday(), Duration::from_secs(60));
+
+        assert!(gaps.is_empty());
+    }
+
+    #[test]
+    fn lunch_break_shows_as_gap() {
+        let entries = vec![entry(0, 3 * HOUR), entry(4 * HOUR, 8 * HOUR)];
+
+        let gaps = detect_gaps(&entries, workday(), Duration::from_secs(15 * 60));
+
+        assert_eq!(
+            gaps,
+            vec![Gap {
+                start_ts: DAY_START + 3 * HOUR,
+                end_ts: DAY_START + 4 * HOUR,
+                duration_secs: HOUR,
+                likely_meeting: None,
+                calendar_event_id: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn ignores_gaps_shorter_than_min_gap() {
+        let entries = vec![
+            entry(0, 2 * HOUR),
+            entry(2 * HOUR + 5 * MINUTE, 6 * HOUR),
+            entry(6 * HOUR, 7 * HOUR + 30 * MINUTE),
+        ];
+
+        let gaps = detect_gaps(&entries, workday(), Duration::from_secs(15 * 60));
+
+        // 5-minute gap dropped; trailing 30 minutes kept
+        assert_eq!(gaps.len(), 1);
+        assert_eq!(gaps[0].start_ts, DAY_START + 7 * HOUR + 30 * MINUTE);
+        assert_eq!(gaps[0].duration_secs, 30 * MINUTE);
+    }
+
+    #[test]
+    fn labels_gap_with_most_overlapping_meeting() {
+        let entries = vec![entry(0, 2 * HOUR), entry(4 * HOUR, 8 * HOUR)];
+        let mut gaps = detect_gaps(&entries, workday(), Duration::from_secs(15 * 60));
+
+        let events = vec![
+            event("standup", "Standup", 2 * HOUR - 15 * MINUTE, 2 * HOUR + 15 * MINUTE),
+            event("review", "Project Astro review", 2 * HOUR + 30 * MINUTE, 4 * HOUR),
+        ];
+        label_gaps(&mut gaps, &events);
+
+        assert_eq!(gaps[0].likely_meeting.as_deref(), Some("Project Astro review"));
+        assert_eq!(gaps[0].calendar_event_id.as_deref(), Some("review"));
+    }
+}
@@ -0,0 +1,349 @@
+//! Offline, deterministic classifier for demos and tests
+//!
+//! [`HeuristicClassifier`] implements [`Classifier`] without any network
+//! call. It reads the `ActivityCategory`/`WorkType` recorded on each snapshot
+//! and any project hint (suggested matter, extracted project code or parsed
+//! calendar project), then applies simple rules:
+//!
+//! - `ClientWork` is billable.
+//! - `Research`, `Communication`, `Meeting` and `Documentation` are billable
+//!   only when a project matched.
+//! - `Administrative` and `Internal` are never billable.
+//!
+//! The same snapshots always produce the same entry (including its id), the
+//! confidence is a fixed pseudo-value, and classification has no token cost.
+//!
+//! Enabled with the `heuristic-classifier` feature (implied by `demo-seed`).
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use pulsearc_domain::types::{ActivityCategory, WorkType};
+use pulsearc_domain::{
+    ActivityContext, ActivitySnapshot, PulseArcError, Result, TimeEntry, TimeEntryParams,
+};
+use uuid::Uuid;
+
+use super::ports::Classifier;
+
+/// Confidence reported for every heuristic classification
+pub const HEURISTIC_CONFIDENCE: f32 = 0.5;
+
+/// Token cost of a heuristic classification (always zero)
+pub const HEURISTIC_COST_USD: f64 = 0.0;
+
+/// `source` recorded on entries produced by this classifier
+const HEURISTIC_SOURCE: &str = "heuristic";
+
+/// Seconds covered by a single snapshot
+const SNAPSHOT_INTERVAL_SECS: i64 = 30;
+
+/// Rule-based classifier that never leaves the process
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeuristicClassifier;
+
+impl HeuristicClassifier {
+    /// Create a heuristic classifier
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Whether `category` bills, given whether a project matched
+    pub fn is_billable(category: &ActivityCategory, has_project: bool) -> bool {
+        match category {
+            ActivityCategory::ClientWork => true,
+            ActivityCategory::Research
+            | ActivityCategory::Communication
+            | ActivityCategory::Meeting
+            | ActivityCategory::Documentation => has_project,
+            ActivityCategory::Administrative | ActivityCategory::Internal => false,
+        }
+    }
+}
+
+#[async_trait]
+impl Classifier for HeuristicClassifier {
+    async fn classify(&self, mut snapshots: Vec<ActivitySnapshot>) -> Result<TimeEntry> {
+        snapshots.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then_with(|| a.id.cmp(&b.id)));
+        let (Some(first), Some(last)) =
+            (snapshots.first().map(|s| s.timestamp), snapshots.last().map(|s| s.timestamp))
+        else {
+            return Err(PulseArcError::InvalidInput("no snapshots to classify".to_string()));
+        };
+
+        let signals: Vec<SnapshotSignals> = snapshots.iter().map(SnapshotSignals::from).collect();
+        let category =
+            dominant(signals.iter().map(|signal| signal.category.clone())).unwrap_or_default();
+        let work_type = dominant(signals.iter().filter_map(|signal| signal.work_type.clone()));
+        let project_id = signals.iter().find_map(|signal| signal.project.clone());
+        let billable = Self::is_billable(&category, project_id.is_some());
+
+        let start_time = timestamp_to_utc(first)?;
+        let end_ts = last.saturating_add(SNAPSHOT_INTERVAL_SECS);
+        let end_time = timestamp_to_utc(end_ts)?;
+
+        let mut entry = TimeEntry::new(TimeEntryParams {
+            id: entry_id(&snapshots),
+            start_time,
+            end_time: Some(end_time),
+            duration_seconds: Some(end_ts - first),
+            description: describe(&category, work_type.as_ref()),
+            project_id,
+            wbs_code: None,
+        });
+        entry.billable = Some(billable);
+        entry.confidence = Some(HEURISTIC_CONFIDENCE);
+        entry.source = Some(HEURISTIC_SOURCE.to_string());
+
+        Ok(entry)
+    }
+}
+
+/// Category, work type and project hint read from one snapshot
+struct SnapshotSignals {
+    category: ActivityCategory,
+    work_type: Option<WorkType>,
+    project: Option<String>,
+}
+
+impl From<&ActivitySnapshot> for SnapshotSignals {
+    fn from(snapshot: &ActivitySnapshot) -> Self {
+        match serde_json::from_str::<ActivityContext>(&snapshot.activity_context_json) {
+            Ok(context) => Self {
+                project: project_hint(&context),
+                category: context.activity_category,
+                work_type: context.work_type,
+            },
+            // Fall back to the denormalized columns
+            Err(_) => Self {
+                category: parse_label(snapshot.activity_category.as_deref()).unwrap_or_default(),
+                work_type: parse_label(snapshot.work_type.as_deref()),
+                project: None,
+            },
+        }
+    }
+}
+
+fn project_hint(context: &ActivityContext) -> Option<String> {
+    [
+        context.suggested_matter.as_deref(),
+        context.extracted_metadata.project_code.as_deref(),
+        context.calendar_event.as_ref().and_then(|event| event.parsed_project.as_deref()),
+    ]
+    .into_iter()
+    .flatten()
+    .map(str::trim)
+    .find(|value| !value.is_empty())
+    .map(str::to_string)
+}
+
+/// Parse a snake_case enum label stored in a snapshot column
+fn parse_label<T: serde::de::DeserializeOwned>(label: Option<&str>) -> Option<T> {
+    label.and_then(|label| serde_json::from_value(serde_json::Value::from(label)).ok())
+}
+
+/// Most frequent value; ties go to the value seen first
+fn dominant<T: PartialEq>(values: impl Iterator<Item = T>) -> Option<T> {
+    let mut counts: Vec<(T, usize)> = Vec::new();
+    for value in values {
+        match counts.iter_mut().find(|(seen, _)| *seen == value) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((value, 1)),
+        }
+    }
+
+    let mut best: Option<(T, usize)> = None;
+    for (value, count) in counts {
+        if best.as_ref().is_none_or(|(_, best_count)| count > *best_count) {
+            best = Some((value, count));
+        }
+    }
+    best.map(|(value, _)| value)
+}
+
+fn describe(category: &ActivityCategory, work_type: Option<&WorkType>) -> String {
+    let category = match category {
+        ActivityCategory::ClientWork => "Client work",
+        ActivityCategory::Research => "Research",
+        ActivityCategory::Communication => "Communication",
+        ActivityCategory::Administrative => "Administrative",
+        ActivityCategory::Meeting => "Meeting",
+        ActivityCategory::Documentation => "Documentation",
+        ActivityCategory::Internal => "Internal",
+    };
+    let work_type = work_type.map(|work_type| match work_type {
+        WorkType::Modeling => "modeling",
+        WorkType::DocReview => "document review",
+        WorkType::Research => "research",
+        WorkType::Email => "email",
+        WorkType::Meeting => "meeting",
+        WorkType::DMS => "document management",
+        WorkType::DataRoom => "data room",
+        WorkType::AccountingSuite => "accounting",
+        WorkType::Documentation => "documentation",
+        WorkType::Unknown => "general",
+    });
+
+    match work_type {
+        Some(work_type) => format!("{category} ({work_type})"),
+        None => category.to_string(),
+    }
+}
+
+/// Deterministic entry id derived from the snapshot ids
+fn entry_id(snapshots: &[ActivitySnapshot]) -> Uuid {
+    let mut ids = DefaultHasher::new();
+    let mut span = DefaultHasher::new();
+    for snapshot in snapshots {
+        snapshot.id.hash(&mut ids);
+        snapshot.timestamp.hash(&mut span);
+    }
+    Uuid::from_u64_pair(ids.finish(), span.finish())
+}
+
+fn timestamp_to_utc(timestamp: i64) -> Result<DateTime<Utc>> {
+    DateTime::from_timestamp(timestamp, 0)
+        .ok_or_else(|| PulseArcError::InvalidInput(format!("invalid timestamp {timestamp}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use pulsearc_domain::types::database::SnapshotMetadata;
+    use pulsearc_domain::types::{ActivityMetadata, WindowContext};
+
+    use super::*;
+
+    fn snapshot(
+        id: &str,
+        timestamp: i64,
+        category: ActivityCategory,
+        work_type: Option<WorkType>,
+        project_code: Option<&str>,
+    ) -> ActivitySnapshot {
+        let context = ActivityContext {
+            active_app: WindowContext {
+                app_name: "Excel".to_string(),
+                window_title: "Model.xlsx".to_string(),
+                bundle_id: None,
+                url: None,
+                url_host: None,
+                document_name: None,
+                file_path: None,
+            },
+            recent_apps: vec![],
+            detected_activity: "working".to_string(),
+            work_type,
+            activity_category: category,
+            billable_confidence: 0.0,
+            suggested_client: None,
+            suggested_matter: None,
+            suggested_task_code: None,
+            extracted_metadata: ActivityMetadata {
+                project_code: project_code.map(str::to_string),
+                ..Default::default()
+            },
+            evidence: Default::default(),
+            calendar_event: None,
+            location: None,
+            temporal_context: None,
+            classification: None,
+            capture_mode: Default::default(),
+        };
+        let metadata = SnapshotMetadata {
+            id: id.to_string(),
+            timestamp,
+            created_at: timestamp,
+            batch_id: None,
+        };
+        ActivitySnapshot::from_activity_context(&context, metadata).expect("snapshot")
+    }
+
+    fn client_work_block() -> Vec<ActivitySnapshot> {
+        vec![
+            snapshot(
+                "snap-1",
+                1_700_000_000,
+                ActivityCategory::ClientWork,
+                Some(WorkType::Modeling),
+                Some("USC0063201"),
+            ),
+            snapshot(
+                "snap-2",
+                1_700_000_030,
+                ActivityCategory::ClientWork,
+                Some(WorkType::Modeling),
+                None,
+            ),
+            snapshot("snap-3", 1_700_000_060, ActivityCategory::Research, None, None),
+        ]
+    }
+
+    #[tokio::test]
+    async fn classifies_client_work_as_billable() {
+        let entry = HeuristicClassifier::new().classify(client_work_block()).await.unwrap();
+
+        assert_eq!(entry.billable, Some(true));
+        assert_eq!(entry.project_id.as_deref(), Some("USC0063201"));
+        assert_eq!(entry.description, "Client work (modeling)");
+        assert_eq!(entry.confidence, Some(HEURISTIC_CONFIDENCE));
+        assert_eq!(entry.source.as_deref(), Some("heuristic"));
+        assert_eq!(entry.duration_seconds, Some(90));
+    }
+
+    #[tokio::test]
+    async fn classifies_administrative_work_as_non_billable() {
+        let snapshots = vec![
+            snapshot(
+                "snap-1",
+                1_700_000_000,
+                ActivityCategory::Administrative,
+                Some(WorkType::Email),
+                Some("USC0063201"),
+            ),
+            snapshot(
+                "snap-2",
+                1_700_000_030,
+                ActivityCategory::Administrative,
+                Some(WorkType::Email),
+                None,
+            ),
+        ];
+
+        let entry = HeuristicClassifier::new().classify(snapshots).await.unwrap();
+
+        assert_eq!(entry.billable, Some(false));
+        assert_eq!(entry.description, "Administrative (email)");
+    }
+
+    #[tokio::test]
+    async fn classification_is_deterministic() {
+        let classifier = HeuristicClassifier::new();
+        let mut reversed = client_work_block();
+        reversed.reverse();
+
+        let first = classifier.classify(client_work_block()).await.unwrap();
+        let second = classifier.classify(reversed).await.unwrap();
+
+        assert_eq!(first.id, second.id);
+        assert_eq!(first.billable, second.billable);
+        assert_eq!(first.description, second.description);
+        assert_eq!(first.start_time, second.start_time);
+        assert_eq!(first.end_time, second.end_time);
+    }
+
+    #[test]
+    fn context_dependent_categories_need_a_project() {
+        assert!(HeuristicClassifier::is_billable(&ActivityCategory::Meeting, true));
+        assert!(!HeuristicClassifier::is_billable(&ActivityCategory::Meeting, false));
+        assert!(!HeuristicClassifier::is_billable(&ActivityCategory::Internal, true));
+    }
+
+    #[tokio::test]
+    async fn rejects_empty_input() {
+        let result = HeuristicClassifier::new().classify(Vec::new()).await;
+
+        assert!(matches!(result, Err(PulseArcError::InvalidInput(_))));
+    }
+}
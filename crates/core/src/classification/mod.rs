@@ -1,15 +1,50 @@
 //! Activity classification domain
 
+pub mod app_categories;
 pub mod block_builder;
+pub mod cache;
+pub mod correction;
+pub mod daily_summary;
 pub mod evidence_extractor;
+pub mod explanation;
+pub mod gaps;
+#[cfg(feature = "heuristic-classifier")]
+pub mod heuristic;
+pub mod overlap;
 pub mod ports;
+pub mod project_autocomplete;
 pub mod project_matcher;
+pub mod ranking;
 pub mod service;
 pub mod signal_extractor;
+pub mod suppression;
+pub mod timesheet;
+pub mod training_data;
+pub mod wbs_search;
+pub mod work_type;
 
+pub use app_categories::{rule_matches, validate_rule_params, AppCategoryRules};
 pub use block_builder::BlockBuilder;
+pub use cache::{ClassificationCacheConfig, ClassificationCacheMetrics};
+pub use correction::ClassificationCorrector;
+pub use daily_summary::{daily_summary, render_daily_summary, summarize_blocks, SummaryFormat};
 pub use evidence_extractor::EvidenceExtractor;
+pub use explanation::{explain_block, explain_classification};
+pub use gaps::{detect_gaps, label_gaps};
+#[cfg(feature = "heuristic-classifier")]
+pub use heuristic::HeuristicClassifier;
+pub use overlap::detect_overlaps;
 pub use ports::*;
-pub use project_matcher::ProjectMatcher;
+pub use project_autocomplete::ProjectAutocomplete;
+pub use project_matcher::{project_context_signature, ProjectLearningConfig, ProjectMatcher};
+pub use ranking::{BlockRanker, RankingWeights};
 pub use service::*;
 pub use signal_extractor::SignalExtractor;
+pub use suppression::{SuggestionSuppressor, SuppressionConfig};
+pub use timesheet::{
+    assemble_timesheet, build_timesheet, RoundingMode, RoundingRule, Timesheet, TimesheetCell,
+    TimesheetDay,
+};
+pub use training_data::{build_training_examples, export_training_data, training_data_jsonl};
+pub use wbs_search::{rank_wbs_elements, WbsSearch};
+pub use work_type::{WorkTypeClassifier, WorkTypeRule, WorkTypeSignal};
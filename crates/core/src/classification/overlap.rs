@@ -0,0 +1,147 @@
+//! Overlap (double-booking) detection for time entries
+//!
+//! Entries are treated as half-open `[start, end)` intervals, so an entry
+//! ending exactly when the next one starts does not conflict. The end of an
+//! entry is its `end_time`, or `start_time + duration_seconds` when no end
+//! was recorded; entries with neither, or with a non-positive length, are
+//! ignored.
+//!
+//! Detection sorts by start time and sweeps once, keeping only the entries
+//! still open at the current start. That costs O(n log n + k) for `k`
+//! reported conflicts instead of comparing every pair.
+
+use chrono::{DateTime, Duration, Utc};
+use pulsearc_domain::{OverlapConflict, TimeEntry};
+use uuid::Uuid;
+
+/// An entry reduced to its interval
+#[derive(Debug, Clone, Copy)]
+struct Interval {
+    id: Uuid,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+}
+
+/// A conflict keyed by its first entry's start
+type StartedConflict = (DateTime<Utc>, OverlapConflict);
+
+/// Find every pair of entries whose intervals intersect
+///
+/// Conflicts are ordered by the first entry's start, then the second's.
+pub fn detect_overlaps(entries: &[TimeEntry]) -> Vec<OverlapConflict> {
+    let mut intervals: Vec<Interval> = entries.iter().filter_map(interval).collect();
+    intervals.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.id.cmp(&b.id)));
+
+    // Each conflict carries its first entry's start so the result can be
+    // reordered without looking entries up again
+    let mut conflicts: Vec<StartedConflict> = Vec::new();
+    let mut open: Vec<Interval> = Vec::new();
+    for current in intervals {
+        // Entries that ended at or before this start can no longer overlap
+        open.retain(|earlier| earlier.end > current.start);
+
+        for earlier in &open {
+            let overlap_end = earlier.end.min(current.end);
+            conflicts.push((
+                earlier.start,
+                OverlapConflict {
+                    first_entry_id: earlier.id,
+                    second_entry_id: current.id,
+                    overlap_start: current.start,
+                    overlap_end,
+                    overlap_secs: (overlap_end - current.start).num_seconds(),
+                },
+            ));
+        }
+        open.push(current);
+    }
+
+    conflicts.sort_by(|(a_start, a), (b_start, b)| {
+        a_start
+            .cmp(b_start)
+            .then_with(|| a.first_entry_id.cmp(&b.first_entry_id))
+            .then_with(|| a.overlap_start.cmp(&b.overlap_start))
+            .then_with(|| a.second_entry_id.cmp(&b.second_entry_id))
+    });
+    conflicts.into_iter().map(|(_, conflict)| conflict).collect()
+}
+
+fn interval(entry: &TimeEntry) -> Option<Interval> {
+    let end = entry.end_time.or_else(|| {
+        entry.duration_seconds.map(|secs| entry.start_time + Duration::seconds(secs))
+    })?;
+    (end > entry.start_time).then_some(Interval { id: entry.id, start: entry.start_time, end })
+}
+
+#[cfg(test)]
+mod tests {
+    use pulsearc_domain::TimeEntryParams;
+
+    use super::*;
+
+    const BASE_TS: i64 = 1_741_600_800; // 2025-03-10 10:00:00 UTC
+
+    fn at(minutes: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(BASE_TS + minutes * 60, 0).expect("valid timestamp")
+    }
+
+    fn entry(id: u128, start_min: i64, end_min: i64) -> TimeEntry {
+        TimeEntry::new(TimeEntryParams {
+            id: Uuid::from_u128(id),
+            start_time: at(start_min),
+            end_time: Some(at(end_min)),
+            duration_seconds: Some((end_min - start_min) * 60),
+            description: format!("entry {id}"),
+            project_id: None,
+            wbs_code: None,
+        })
+    }
+
+    #[test]
+    fn adjacent_entries_do_not_overlap() {
+        let entries = vec![entry(1, 0, 30), entry(2, 30, 60), entry(3, 60, 90)];
+
+        assert!(detect_overlaps(&entries).is_empty());
+    }
+
+    #[test]
+    fn reports_partial_overlap_span() {
+        let entries = vec![entry(2, 20, 60), entry(1, 0, 30)];
+
+        let conflicts = detect_overlaps(&entries);
+
+        assert_eq!(
+            conflicts,
+            vec![OverlapConflict {
+                first_entry_id: Uuid::from_u128(1),
+                second_entry_id: Uuid::from_u128(2),
+                overlap_start: at(20),
+                overlap_end: at(30),
+                overlap_secs: 600,
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_fully_contained_entry() {
+        let entries = vec![entry(1, 0, 120), entry(2, 30, 45), entry(3, 90, 150)];
+
+        let conflicts = detect_overlaps(&entries);
+
+        assert_eq!(conflicts.len(), 2);
+        assert_eq!(conflicts[0].second_entry_id, Uuid::from_u128(2));
+        assert_eq!((conflicts[0].overlap_start, conflicts[0].overlap_end), (at(30), at(45)));
+        assert_eq!(conflicts[0].overlap_secs, 900);
+        assert_eq!(conflicts[1].second_entry_id, Uuid::from_u128(3));
+        assert_eq!((conflicts[1].overlap_start, conflicts[1].overlap_end), (at(90), at(120)));
+    }
+
+    #[test]
+    fn ignores_entries_without_an_end() {
+        let mut open_ended = entry(2, 10, 20);
+        open_ended.end_time = None;
+        open_ended.duration_seconds = None;
+
+        assert!(detect_overlaps(&[entry(1, 0, 30), open_ended]).is_empty());
+    }
+}
@@ -3,7 +3,8 @@
 use async_trait::async_trait;
 use chrono::{DateTime, NaiveDate, Utc};
 use pulsearc_domain::types::classification::{
-    BlockConfig, ContextSignals, ProjectMatch, ProposedBlock,
+    AppCategoryRule, BlockConfig, ContextSignals, ProjectAcceptance, ProjectMatch, ProposedBlock,
+    SuggestionDismissal,
 };
 use pulsearc_domain::types::sap::WbsElement;
 use pulsearc_domain::{ActivitySnapshot, Result, TimeEntry};
@@ -62,6 +63,58 @@ pub trait BlockRepository: Send + Sync {
     async fn get_block_config(&self) -> Result<BlockConfig>;
 }
 
+/// Queues reviewed blocks for sync as time entries
+///
+/// Used by [`ClassificationCorrector`](crate::classification::ClassificationCorrector)
+/// to send a corrected block to the backend.
+#[async_trait]
+pub trait BlockSyncQueue: Send + Sync {
+    /// Enqueue `block` as a time entry for sync
+    async fn enqueue_block(&self, block: &ProposedBlock) -> Result<()>;
+}
+
+/// Trait for persisting suggestion dismissals
+///
+/// Backs the dismissal-learning layer in
+/// [`SuggestionSuppressor`](crate::classification::SuggestionSuppressor).
+#[async_trait]
+pub trait SuggestionDismissalRepository: Send + Sync {
+    /// Record a dismissal
+    async fn record_dismissal(&self, dismissal: &SuggestionDismissal) -> Result<()>;
+
+    /// Get dismissals recorded at or after `since_ts` (Unix epoch seconds)
+    async fn get_dismissals_since(&self, since_ts: i64) -> Result<Vec<SuggestionDismissal>>;
+}
+
+/// Repository for user app category rules
+///
+/// Backs [`AppCategoryRules`](crate::classification::AppCategoryRules).
+#[async_trait]
+pub trait AppCategoryRuleRepository: Send + Sync {
+    /// All stored rules, oldest first
+    async fn list_rules(&self) -> Result<Vec<AppCategoryRule>>;
+
+    /// Store `rule`, replacing any rule with the same id
+    async fn save_rule(&self, rule: &AppCategoryRule) -> Result<()>;
+
+    /// Delete the rule with `id`, returning whether it existed
+    async fn delete_rule(&self, id: &str) -> Result<bool>;
+}
+
+/// Repository for project acceptances learned by the project matcher
+///
+/// Backs the learning mode of
+/// [`ProjectMatcher`](crate::classification::ProjectMatcher). Synchronous, like
+/// [`WbsRepository`], because project matching is synchronous.
+pub trait ProjectAcceptanceRepository: Send + Sync {
+    /// Record an acceptance
+    fn record_acceptance(&self, acceptance: &ProjectAcceptance) -> Result<()>;
+
+    /// Get acceptances for `signature` recorded at or after `since_ts` (Unix
+    /// epoch seconds)
+    fn get_acceptances(&self, signature: &str, since_ts: i64) -> Result<Vec<ProjectAcceptance>>;
+}
+
 /// Trait for matching activity signals to projects
 ///
 /// Analyzes context signals extracted from activity snapshots and matches them
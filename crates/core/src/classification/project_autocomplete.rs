@@ -0,0 +1,213 @@
+//! Project code autocomplete
+//!
+//! Suggests projects as the user types a project code. Project ids, WBS codes
+//! and names are indexed (lowercased) in a [`Trie`], so a prefix lookup only
+//! walks the matching subtree. Matches are ranked by how often the user has
+//! picked each project, then by id for a stable order.
+//!
+//! The index is rebuilt from the project list with
+//! [`ProjectAutocomplete::set_projects`]; usage counts survive rebuilds.
+
+use std::collections::{BTreeSet, HashMap};
+
+use pulsearc_common::collections::Trie;
+use pulsearc_domain::types::{ProjectSuggestion, ProjectWithWbs};
+
+/// Prefix index over the user's projects, ranked by usage frequency.
+#[derive(Debug, Default)]
+pub struct ProjectAutocomplete {
+    trie: Trie,
+    projects: Vec<ProjectWithWbs>,
+    /// Lowercased indexed key -> positions in `projects`
+    keys: HashMap<String, BTreeSet<usize>>,
+    /// Project id -> times picked
+    usage: HashMap<String, u64>,
+}
+
+impl ProjectAutocomplete {
+    /// Create an empty index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build an index over `projects`.
+    pub fn from_projects(projects: impl IntoIterator<Item = impl Into<ProjectWithWbs>>) -> Self {
+        let mut autocomplete = Self::new();
+        autocomplete.set_projects(projects);
+        autocomplete
+    }
+
+    /// Replace the indexed projects, rebuilding the trie if the list changed.
+    ///
+    /// Usage counts are kept, so a project that disappears and comes back
+    /// keeps its rank. Returns `true` when the index was rebuilt.
+    pub fn set_projects(
+        &mut self,
+        projects: impl IntoIterator<Item = impl Into<ProjectWithWbs>>,
+    ) -> bool {
+        let projects: Vec<ProjectWithWbs> = projects.into_iter().map(Into::into).collect();
+        if projects == self.projects {
+            return false;
+        }
+
+        self.trie.clear();
+        self.keys.clear();
+        for (index, project) in projects.iter().enumerate() {
+            let keys = [
+                Some(project.id.as_str()),
+                project.wbs_code.as_deref(),
+                Some(project.name.as_str()),
+            ];
+            for key in keys.into_iter().flatten() {
+                let key = key.trim().to_lowercase();
+                if key.is_empty() {
+                    continue;
+                }
+                self.trie.insert(&key);
+                self.keys.entry(key).or_default().insert(index);
+            }
+        }
+        self.projects = projects;
+        true
+    }
+
+    /// Record that the user picked `project_id`, raising its rank.
+    pub fn record_usage(&mut self, project_id: &str) {
+        *self.usage.entry(project_id.to_string()).or_insert(0) += 1;
+    }
+
+    /// Times the user picked `project_id`.
+    pub fn usage_count(&self, project_id: &str) -> u64 {
+        self.usage.get(project_id).copied().unwrap_or(0)
+    }
+
+    /// Projects whose id, WBS code or name starts with `prefix`
+    /// (case-insensitive), most used first, at most `limit` results.
+    ///
+    /// An empty prefix matches every project.
+    pub fn complete(&self, prefix: &str, limit: usize) -> Vec<ProjectSuggestion> {
+        if limit == 0 {
+            return Vec::new();
+        }
+
+        let prefix = prefix.trim().to_lowercase();
+        let matched: BTreeSet<usize> = self
+            .trie
+            .iter_prefix(&prefix)
+            .filter_map(|key| self.keys.get(&key))
+            .flatten()
+            .copied()
+            .collect();
+
+        let mut suggestions: Vec<ProjectSuggestion> = matched
+            .into_iter()
+            .map(|index| {
+                let project = &self.projects[index];
+                ProjectSuggestion {
+                    id: project.id.clone(),
+                    name: project.name.clone(),
+                    wbs_code: project.wbs_code.clone(),
+                    usage_count: self.usage_count(&project.id),
+                }
+            })
+            .collect();
+        suggestions.sort_by(|a, b| b.usage_count.cmp(&a.usage_count).then_with(|| a.id.cmp(&b.id)));
+        suggestions.truncate(limit);
+        suggestions
+    }
+
+    /// Number of indexed projects.
+    pub fn len(&self) -> usize {
+        self.projects.len()
+    }
+
+    /// Whether no projects are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.projects.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pulsearc_domain::types::Project;
+
+    use super::*;
+
+    fn project(id: &str, name: &str, wbs_code: Option<&str>) -> ProjectWithWbs {
+        ProjectWithWbs {
+            id: id.to_string(),
+            name: name.to_string(),
+            wbs_code: wbs_code.map(str::to_string),
+        }
+    }
+
+    fn sample() -> ProjectAutocomplete {
+        ProjectAutocomplete::from_projects(vec![
+            project("PRJ-100", "Astro Migration", Some("USC0063201.1.1")),
+            project("PRJ-101", "Astro Audit", Some("USC0063202.1.1")),
+            project("PRJ-200", "Internal Tools", None),
+        ])
+    }
+
+    fn ids(suggestions: &[ProjectSuggestion]) -> Vec<&str> {
+        suggestions.iter().map(|s| s.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_prefix_matches_id_wbs_code_and_name() {
+        let autocomplete = sample();
+
+        assert_eq!(ids(&autocomplete.complete("PRJ-1", 10)), vec!["PRJ-100", "PRJ-101"]);
+        assert_eq!(ids(&autocomplete.complete("USC00632", 10)), vec!["PRJ-100", "PRJ-101"]);
+        assert_eq!(ids(&autocomplete.complete("Internal", 10)), vec!["PRJ-200"]);
+        assert!(autocomplete.complete("XYZ", 10).is_empty());
+    }
+
+    #[test]
+    fn test_prefix_is_case_insensitive() {
+        let autocomplete = sample();
+
+        assert_eq!(ids(&autocomplete.complete("prj-2", 10)), vec!["PRJ-200"]);
+        assert_eq!(ids(&autocomplete.complete("usc0063201", 10)), vec!["PRJ-100"]);
+        assert_eq!(ids(&autocomplete.complete("ASTRO A", 10)), vec!["PRJ-101"]);
+    }
+
+    #[test]
+    fn test_limit_is_respected() {
+        let autocomplete = sample();
+
+        assert_eq!(autocomplete.complete("", 2).len(), 2);
+        assert_eq!(autocomplete.complete("prj", 1).len(), 1);
+        assert!(autocomplete.complete("prj", 0).is_empty());
+        assert_eq!(autocomplete.complete("", 10).len(), 3);
+    }
+
+    #[test]
+    fn test_frequently_used_projects_rank_higher() {
+        let mut autocomplete = sample();
+        autocomplete.record_usage("PRJ-101");
+        autocomplete.record_usage("PRJ-101");
+        autocomplete.record_usage("PRJ-200");
+
+        let suggestions = autocomplete.complete("", 10);
+        assert_eq!(ids(&suggestions), vec!["PRJ-101", "PRJ-200", "PRJ-100"]);
+        assert_eq!(suggestions[0].usage_count, 2);
+        assert_eq!(ids(&autocomplete.complete("prj-1", 1)), vec!["PRJ-101"]);
+    }
+
+    #[test]
+    fn test_set_projects_rebuilds_and_keeps_usage() {
+        let mut autocomplete = sample();
+        autocomplete.record_usage("PRJ-200");
+
+        assert!(!autocomplete.set_projects(autocomplete.projects.clone()));
+        assert!(autocomplete.set_projects(vec![Project {
+            id: "PRJ-200".to_string(),
+            name: "Internal Tools".to_string(),
+        }]));
+
+        assert_eq!(autocomplete.len(), 1);
+        assert!(autocomplete.complete("prj-1", 10).is_empty());
+        assert_eq!(autocomplete.complete("prj", 10)[0].usage_count, 1);
+    }
+}
@@ -3,6 +3,8 @@
 //! Matches activity signals to WBS codes using a hybrid approach:
 //! - Fast path: Exact match in top 20 common projects (HashMap)
 //! - Slow path: FTS5 fuzzy search for typo-tolerant matching
+//! - Learning mode (optional): projects the user accepted for the same context
+//!   signature, weighted by recency so stale associations fade
 //!
 //! # REFACTOR-004: ADR-003 Migration
 //! Migrated from legacy/api/src/inference/project_matcher.rs
@@ -13,21 +15,168 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use pulsearc_domain::classification::{AppCategory, ContextSignals, ProjectMatch};
+use pulsearc_domain::classification::{
+    AppCategory, ContextSignals, ProjectAcceptance, ProjectMatch,
+};
 use pulsearc_domain::types::WbsElement;
-use pulsearc_domain::Result;
-use tracing::warn;
+use pulsearc_domain::{PulseArcError, Result};
+use tracing::{debug, warn};
 
-use crate::classification::ports::WbsRepository;
+use crate::classification::ports::{ProjectAcceptanceRepository, WbsRepository};
 
 // Type aliases to avoid clippy type-complexity warnings
 type CandidateMap = HashMap<String, (f32, Vec<String>)>;
 type MatchInfo<'a> = (&'a str, usize, Vec<String>);
+/// Acceptance count and decayed weight per project id
+type AcceptanceTally<'a> = HashMap<&'a str, (u32, f64)>;
+
+/// Confidence of a common-project exact match (learned matches at or above
+/// this short-circuit matching)
+pub(crate) const EXACT_MATCH_CONFIDENCE: f32 = 0.50;
+
+/// Score added per title keyword found by FTS5 search
+pub(crate) const FTS_KEYWORD_WEIGHT: f32 = 0.40;
+
+/// Score added when the URL domain is found by FTS5 search
+pub(crate) const URL_DOMAIN_WEIGHT: f32 = 0.20;
+
+/// Score added to every candidate while a VDR provider is in use
+pub(crate) const VDR_PROVIDER_WEIGHT: f32 = 0.30;
+
+/// Confidence of the G&A fallback when nothing matched
+pub(crate) const FALLBACK_CONFIDENCE: f32 = 0.10;
+
+/// Confidence a learned association approaches as acceptances accumulate
+const LEARNED_MAX_CONFIDENCE: f32 = 0.95;
+
+/// Signature component used when a signal is absent
+const NO_SIGNAL: &str = "-";
+
+/// Learning-mode thresholds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProjectLearningConfig {
+    /// Age at which an acceptance counts half as much (seconds)
+    pub half_life_secs: i64,
+    /// Acceptances older than this are ignored entirely (seconds)
+    pub window_secs: i64,
+    /// Learned confidence required before a learned project is used
+    pub min_confidence: f32,
+}
+
+impl Default for ProjectLearningConfig {
+    fn default() -> Self {
+        Self { half_life_secs: 14 * 86_400, window_secs: 90 * 86_400, min_confidence: 0.25 }
+    }
+}
+
+impl ProjectLearningConfig {
+    /// Validate the thresholds.
+    ///
+    /// # Errors
+    /// Returns `PulseArcError::InvalidInput` if a duration is not positive or
+    /// `min_confidence` is outside `(0.0, 1.0]`.
+    pub fn validate(&self) -> Result<()> {
+        if self.half_life_secs <= 0 {
+            return Err(PulseArcError::InvalidInput("half_life_secs must be positive".into()));
+        }
+        if self.window_secs <= 0 {
+            return Err(PulseArcError::InvalidInput("window_secs must be positive".into()));
+        }
+        if !(self.min_confidence > 0.0 && self.min_confidence <= 1.0) {
+            return Err(PulseArcError::InvalidInput("min_confidence must be in (0.0, 1.0]".into()));
+        }
+        Ok(())
+    }
+}
+
+/// A project learned for a context signature
+#[derive(Debug, Clone, PartialEq)]
+pub struct LearnedProject {
+    /// Accepted project (project definition)
+    pub project_id: String,
+    /// Acceptances inside the learning window
+    pub acceptance_count: u32,
+    /// Sum of recency weights (1.0 for an acceptance made now)
+    pub weight: f64,
+    /// Confidence derived from `weight`
+    pub confidence: f32,
+}
+
+/// Build the context signature used to learn project acceptances.
+///
+/// Combines the app category, the sorted title keywords, the URL domain and
+/// the project folder, all lowercased.
+pub fn project_context_signature(signals: &ContextSignals) -> String {
+    let mut keywords: Vec<String> = signals
+        .title_keywords
+        .iter()
+        .map(|keyword| keyword.trim().to_lowercase())
+        .filter(|keyword| !keyword.is_empty())
+        .collect();
+    keywords.sort();
+    keywords.dedup();
+
+    let normalize = |value: Option<&String>| {
+        value
+            .map(|value| value.trim().to_lowercase())
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| NO_SIGNAL.to_string())
+    };
+
+    let app = format!("{:?}", signals.app_category).to_lowercase();
+    let keywords = if keywords.is_empty() { NO_SIGNAL.to_string() } else { keywords.join(",") };
+    let domain = normalize(signals.url_domain.as_ref());
+    let folder = normalize(signals.project_folder.as_ref());
+
+    format!("{app}|{keywords}|{domain}|{folder}")
+}
+
+/// Weight acceptances by recency as of `now_ts`.
+///
+/// Each acceptance inside the window contributes `0.5^(age / half_life)`;
+/// confidence grows with the total weight towards 0.95. Results are sorted
+/// by confidence (highest first), then project id.
+pub fn compute_learned_projects(
+    acceptances: &[ProjectAcceptance],
+    config: &ProjectLearningConfig,
+    now_ts: i64,
+) -> Vec<LearnedProject> {
+    let cutoff = now_ts.saturating_sub(config.window_secs);
+    let mut by_project: AcceptanceTally<'_> = HashMap::new();
+    for acceptance in acceptances.iter().filter(|a| a.accepted_at > cutoff) {
+        let age = now_ts.saturating_sub(acceptance.accepted_at).max(0) as f64;
+        let weight = 0.5_f64.powf(age / config.half_life_secs as f64);
+        let entry = by_project.entry(acceptance.project_id.as_str()).or_insert((0, 0.0));
+        entry.0 = entry.0.saturating_add(1);
+        entry.1 += weight;
+    }
+
+    let mut learned: Vec<LearnedProject> = by_project
+        .into_iter()
+        .map(|(project_id, (acceptance_count, weight))| LearnedProject {
+            project_id: project_id.to_string(),
+            acceptance_count,
+            weight,
+            confidence: (f64::from(LEARNED_MAX_CONFIDENCE) * weight / (weight + 1.0)) as f32,
+        })
+        .collect();
+    learned.sort_by(|a, b| {
+        b.confidence.total_cmp(&a.confidence).then_with(|| a.project_id.cmp(&b.project_id))
+    });
+    learned
+}
+
+/// Acceptance store and thresholds for learning mode
+struct ProjectLearning {
+    repository: Arc<dyn ProjectAcceptanceRepository>,
+    config: ProjectLearningConfig,
+}
 
 /// Matches activity signals to WBS codes/projects using FTS5 search
 pub struct ProjectMatcher {
     wbs_repo: Arc<dyn WbsRepository>,
     common_projects: HashMap<String, String>, // project_name_lower -> project_def
+    learning: Option<ProjectLearning>,
 }
 
 impl ProjectMatcher {
@@ -82,7 +231,118 @@ impl ProjectMatcher {
             common_projects.len()
         );
 
-        Ok(Self { wbs_repo, common_projects })
+        Ok(Self { wbs_repo, common_projects, learning: None })
+    }
+
+    /// Enable learning mode backed by `repository`
+    ///
+    /// Accepted projects recorded with [`Self::record_acceptance`] are matched
+    /// for the same context signature, with confidence growing per acceptance
+    /// and fading as acceptances age.
+    ///
+    /// # Errors
+    /// Returns `PulseArcError::InvalidInput` if the config is invalid.
+    pub fn with_learning(
+        mut self,
+        repository: Arc<dyn ProjectAcceptanceRepository>,
+        config: ProjectLearningConfig,
+    ) -> Result<Self> {
+        config.validate()?;
+        self.learning = Some(ProjectLearning { repository, config });
+        Ok(self)
+    }
+
+    /// Record that the user accepted `project_id` for `context_signature`
+    ///
+    /// See [`project_context_signature`] for how signatures are built.
+    ///
+    /// # Errors
+    /// Returns `PulseArcError::Config` if learning mode is not enabled and
+    /// `PulseArcError::InvalidInput` for an empty signature or project.
+    pub fn record_acceptance(&self, context_signature: &str, project_id: &str) -> Result<()> {
+        self.record_acceptance_at(context_signature, project_id, chrono::Utc::now().timestamp())
+    }
+
+    /// [`Self::record_acceptance`] with an explicit acceptance time
+    pub fn record_acceptance_at(
+        &self,
+        context_signature: &str,
+        project_id: &str,
+        accepted_at: i64,
+    ) -> Result<()> {
+        let learning = self.learning.as_ref().ok_or_else(|| {
+            PulseArcError::Config("project matcher learning mode is not enabled".to_string())
+        })?;
+
+        let signature = context_signature.trim();
+        let project_id = project_id.trim();
+        if signature.is_empty() || project_id.is_empty() {
+            return Err(PulseArcError::InvalidInput(
+                "context signature and project id are required".to_string(),
+            ));
+        }
+
+        learning.repository.record_acceptance(&ProjectAcceptance {
+            signature: signature.to_string(),
+            project_id: project_id.to_string(),
+            accepted_at,
+        })
+    }
+
+    /// Best learned project for `context_signature` as of `now_ts`
+    ///
+    /// Returns `None` when learning is disabled, nothing was learned, the
+    /// learned confidence is below `min_confidence`, or the project is no
+    /// longer in the WBS cache.
+    pub fn learned_match(
+        &self,
+        context_signature: &str,
+        now_ts: i64,
+    ) -> Result<Option<ProjectMatch>> {
+        let Some(learning) = &self.learning else {
+            return Ok(None);
+        };
+
+        let since = now_ts.saturating_sub(learning.config.window_secs);
+        let acceptances = learning.repository.get_acceptances(context_signature, since)?;
+        let Some(best) = compute_learned_projects(&acceptances, &learning.config, now_ts)
+            .into_iter()
+            .next()
+            .filter(|learned| learned.confidence >= learning.config.min_confidence)
+        else {
+            return Ok(None);
+        };
+
+        let Some(wbs) = self.get_wbs_by_project_def(&best.project_id)? else {
+            debug!(project_id = %best.project_id, "Learned project missing from WBS cache");
+            return Ok(None);
+        };
+
+        Ok(Some(ProjectMatch {
+            project_id: Some(wbs.project_def.clone()),
+            wbs_code: Some(wbs.wbs_code.clone()),
+            deal_name: wbs.project_name.clone(),
+            workstream: None,
+            confidence: best.confidence,
+            reasons: vec![format!("learned:acceptances={}", best.acceptance_count)],
+        }))
+    }
+
+    /// Learned match for `signals`, if learning is enabled (errors are logged)
+    fn learned_candidate(&self, signals: &ContextSignals) -> Option<ProjectMatch> {
+        self.learning.as_ref()?;
+
+        let signature = project_context_signature(signals);
+        match self.learned_match(&signature, chrono::Utc::now().timestamp()) {
+            Ok(learned) => learned.map(|learned| ProjectMatch {
+                workstream: self.infer_workstream(signals),
+                ..learned
+            }),
+            Err(err) => {
+                warn!(error = %err, "Failed to load learned project acceptances");
+                None
+            }
+        }
     }
 
     /// Get all candidate projects that match the signals (for RulesClassifier
@@ -117,7 +377,7 @@ impl ProjectMatcher {
                 if let Ok(Some(wbs)) = self.get_wbs_by_project_def(project_def) {
                     let reasons: Vec<String> =
                         matched_keywords.iter().map(|k| format!("keyword:{}", k)).collect();
-                    candidates.insert(wbs.wbs_code.clone(), (0.50, reasons));
+                    candidates.insert(wbs.wbs_code.clone(), (EXACT_MATCH_CONFIDENCE, reasons));
                 }
             }
         }
@@ -130,7 +390,7 @@ impl ProjectMatcher {
                         continue;
                     }
                     let entry = candidates.entry(wbs.wbs_code.clone()).or_insert((0.0, vec![]));
-                    entry.0 += 0.40;
+                    entry.0 += FTS_KEYWORD_WEIGHT;
                     entry.1.push(format!("fts5_keyword:{}", keyword));
                 }
             }
@@ -144,7 +404,7 @@ impl ProjectMatcher {
                         continue;
                     }
                     let entry = candidates.entry(wbs.wbs_code.clone()).or_insert((0.0, vec![]));
-                    entry.0 += 0.20;
+                    entry.0 += URL_DOMAIN_WEIGHT;
                     entry.1.push("url:domain_match".to_string());
                 }
             }
@@ -153,7 +413,7 @@ impl ProjectMatcher {
         // 4. VDR provider bonus (only if URL present)
         if signals.is_vdr_provider && signals.url_domain.is_some() {
             for (_, (score, reasons)) in candidates.iter_mut() {
-                *score += 0.30;
+                *score += VDR_PROVIDER_WEIGHT;
                 reasons.push("vdr:provider".to_string());
             }
         }
@@ -178,6 +438,11 @@ impl ProjectMatcher {
             }
         }
 
+        // 6. Learned acceptances for this context
+        if let Some(learned) = self.learned_candidate(signals) {
+            add_learned_candidate(&mut candidates, learned);
+        }
+
         // Convert HashMap to Vec<ProjectMatch>
         let mut matches: Vec<ProjectMatch> = candidates
             .into_iter()
@@ -213,6 +478,14 @@ impl ProjectMatcher {
     /// 1-4 weighted classification, use get_candidate_projects() +
     /// RulesClassifier.
     pub fn match_project(&self, signals: &ContextSignals) -> ProjectMatch {
+        // Learned path: a well-established acceptance beats an exact name match
+        let learned = self.learned_candidate(signals);
+        if let Some(learned) =
+            learned.as_ref().filter(|learned| learned.confidence >= EXACT_MATCH_CONFIDENCE)
+        {
+            return learned.clone();
+        }
+
         // Fast path: Check exact match in common_projects HashMap
         // Look for project that matches ALL keywords (not just one)
         let mut best_match: Option<MatchInfo> = None;
@@ -257,7 +530,7 @@ impl ProjectMatcher {
                         wbs_code: Some(wbs.wbs_code.clone()),
                         deal_name: wbs.project_name.clone(),
                         workstream: self.infer_workstream(signals),
-                        confidence: 0.50, // Exact match confidence
+                        confidence: EXACT_MATCH_CONFIDENCE,
                         reasons,
                     };
                 }
@@ -276,7 +549,7 @@ impl ProjectMatcher {
                             continue;
                         }
 
-                        let score = 0.40; // FTS5 fuzzy match confidence
+                        let score = FTS_KEYWORD_WEIGHT;
                         let reason = format!("fts5_keyword:{}", keyword);
 
                         let entry = candidates.entry(wbs.wbs_code.clone()).or_insert((0.0, vec![]));
@@ -297,7 +570,7 @@ impl ProjectMatcher {
                     }
 
                     let entry = candidates.entry(wbs.wbs_code.clone()).or_insert((0.0, vec![]));
-                    entry.0 += 0.20; // URL match weight
+                    entry.0 += URL_DOMAIN_WEIGHT;
                     entry.1.push("url:domain_match".to_string());
                 }
             }
@@ -307,7 +580,7 @@ impl ProjectMatcher {
         if signals.is_vdr_provider && signals.url_domain.is_some() {
             // Boost all candidates when VDR detected
             for (_, (score, reasons)) in candidates.iter_mut() {
-                *score += 0.30;
+                *score += VDR_PROVIDER_WEIGHT;
                 reasons.push("vdr:provider".to_string());
             }
         }
@@ -336,6 +609,11 @@ impl ProjectMatcher {
             }
         }
 
+        // 5. Weaker learned acceptances still add evidence
+        if let Some(learned) = learned {
+            add_learned_candidate(&mut candidates, learned);
+        }
+
         // Return best match if confidence meets threshold
         // Lower threshold to 0.25 to accommodate file path-only matches
         if let Some((wbs_code, (confidence, reasons))) =
@@ -362,7 +640,7 @@ impl ProjectMatcher {
             wbs_code: Some("USC0000000.1.0".to_string()),
             deal_name: Some("General & Administrative".to_string()),
             workstream: self.infer_workstream(signals),
-            confidence: 0.10, // Low confidence - fallback match
+            confidence: FALLBACK_CONFIDENCE,
             reasons: vec!["fallback:g_a".to_string()],
         }
     }
@@ -397,9 +675,19 @@ impl ProjectMatcher {
     }
 }
 
+/// Add a learned match to the candidate scores
+fn add_learned_candidate(candidates: &mut CandidateMap, learned: ProjectMatch) {
+    let Some(wbs_code) = learned.wbs_code else {
+        return;
+    };
+    let entry = candidates.entry(wbs_code).or_insert((0.0, vec![]));
+    entry.0 += learned.confidence;
+    entry.1.extend(learned.reasons);
+}
+
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
+    use std::sync::{Arc, Mutex};
 
     use pulsearc_domain::classification::{AppCategory, ContextSignals};
     use pulsearc_domain::types::WbsElement;
@@ -747,6 +1035,150 @@ mod tests {
         }
     }
 
+    const NOW: i64 = 1_700_000_000;
+    const DAY: i64 = 86_400;
+
+    #[derive(Default)]
+    struct InMemoryAcceptances {
+        acceptances: Mutex<Vec<ProjectAcceptance>>,
+    }
+
+    impl ProjectAcceptanceRepository for InMemoryAcceptances {
+        fn record_acceptance(&self, acceptance: &ProjectAcceptance) -> DomainResult<()> {
+            self.acceptances.lock().unwrap().push(acceptance.clone());
+            Ok(())
+        }
+
+        fn get_acceptances(
+            &self,
+            signature: &str,
+            since_ts: i64,
+        ) -> DomainResult<Vec<ProjectAcceptance>> {
+            Ok(self
+                .acceptances
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|a| a.signature == signature && a.accepted_at >= since_ts)
+                .cloned()
+                .collect())
+        }
+    }
+
+    fn learning_matcher(config: ProjectLearningConfig) -> ProjectMatcher {
+        ProjectMatcher::new(Arc::new(MockWbsRepository::new()))
+            .unwrap()
+            .with_learning(Arc::new(InMemoryAcceptances::default()), config)
+            .expect("valid config")
+    }
+
+    #[test]
+    fn test_context_signature_normalizes_signals() {
+        let mut signals = create_test_signals(
+            vec!["Model".to_string(), "astro".to_string(), "model".to_string()],
+            AppCategory::Excel,
+        );
+        assert_eq!(project_context_signature(&signals), "excel|astro,model|-|-");
+
+        signals.url_domain = Some("App.Datasite.com".to_string());
+        signals.project_folder = Some("Astro".to_string());
+        assert_eq!(project_context_signature(&signals), "excel|astro,model|app.datasite.com|astro");
+    }
+
+    #[test]
+    fn test_learned_acceptances_match_signature() {
+        // AC: after acceptances, the signature matches the learned project
+        let matcher = learning_matcher(ProjectLearningConfig::default());
+        let signals = create_test_signals(vec!["nonexistent".to_string()], AppCategory::Excel);
+        let signature = project_context_signature(&signals);
+
+        assert!(matcher.learned_match(&signature, NOW).unwrap().is_none());
+
+        matcher.record_acceptance_at(&signature, "USC0042105", NOW - 2 * DAY).unwrap();
+        matcher.record_acceptance_at(&signature, "USC0042105", NOW - DAY).unwrap();
+
+        let learned = matcher.learned_match(&signature, NOW).unwrap().expect("learned match");
+        assert_eq!(learned.project_id, Some("USC0042105".to_string()));
+        assert_eq!(learned.wbs_code, Some("USC0042105.2.3".to_string()));
+        assert!(learned.confidence >= ProjectLearningConfig::default().min_confidence);
+        assert!(learned.reasons.contains(&"learned:acceptances=2".to_string()));
+
+        // match_project learns as of the wall clock; without learning these
+        // signals fall back to G&A
+        let matcher = learning_matcher(ProjectLearningConfig::default());
+        matcher.record_acceptance(&signature, "USC0042105").unwrap();
+        matcher.record_acceptance(&signature, "USC0042105").unwrap();
+        let matched = matcher.match_project(&signals);
+        assert_eq!(matched.project_id, Some("USC0042105".to_string()));
+        assert_eq!(matched.workstream, Some("modeling".to_string()));
+        assert!(matched.confidence > 0.50);
+    }
+
+    #[test]
+    fn test_learned_acceptances_decay() {
+        let config = ProjectLearningConfig {
+            half_life_secs: 7 * DAY,
+            window_secs: 60 * DAY,
+            min_confidence: 0.25,
+        };
+        let matcher = learning_matcher(config);
+        let signature = "excel|nonexistent|-|-";
+
+        // Four half-lives old: weight 1/16, well below threshold
+        matcher.record_acceptance_at(signature, "USC0042105", NOW - 28 * DAY).unwrap();
+        assert!(matcher.learned_match(signature, NOW).unwrap().is_none());
+
+        // Outside the window: ignored even when repeated
+        for _ in 0..5 {
+            matcher.record_acceptance_at(signature, "USC0058923", NOW - 61 * DAY).unwrap();
+        }
+        assert!(matcher.learned_match(signature, NOW).unwrap().is_none());
+
+        // Fresh acceptances outweigh stale ones
+        matcher.record_acceptance_at(signature, "USC0071234", NOW).unwrap();
+        let learned = matcher.learned_match(signature, NOW).unwrap().expect("learned match");
+        assert_eq!(learned.project_id, Some("USC0071234".to_string()));
+    }
+
+    #[test]
+    fn test_learned_confidence_grows_with_acceptances() {
+        let config = ProjectLearningConfig::default();
+        let acceptance = |accepted_at| ProjectAcceptance {
+            signature: "sig".to_string(),
+            project_id: "USC0063201".to_string(),
+            accepted_at,
+        };
+
+        let one = compute_learned_projects(&[acceptance(NOW)], &config, NOW);
+        let three = compute_learned_projects(
+            &[acceptance(NOW), acceptance(NOW), acceptance(NOW)],
+            &config,
+            NOW,
+        );
+
+        assert_eq!(three[0].acceptance_count, 3);
+        assert!(three[0].confidence > one[0].confidence);
+        assert!(three[0].confidence < LEARNED_MAX_CONFIDENCE);
+    }
+
+    #[test]
+    fn test_record_acceptance_requires_learning_mode() {
+        let matcher = ProjectMatcher::new(Arc::new(MockWbsRepository::new())).unwrap();
+
+        let result = matcher.record_acceptance("excel|astro|-|-", "USC0063201");
+
+        assert!(matches!(result, Err(PulseArcError::Config(_))));
+        assert!(matcher.learned_match("excel|astro|-|-", NOW).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_rejects_invalid_learning_config() {
+        let matcher = ProjectMatcher::new(Arc::new(MockWbsRepository::new())).unwrap();
+        let config = ProjectLearningConfig { half_life_secs: 0, ..Default::default() };
+
+        assert!(matcher.with_learning(Arc::new(InMemoryAcceptances::default()), config).is_err());
+    }
+
     #[test]
     fn test_fallback_to_ga() {
         // AC: Returns G&A fallback if no project match found
@@ -0,0 +1,277 @@
+//! Suggestion ranking
+//!
+//! Scores proposed blocks so the UI can present the most useful suggestions
+//! first. A score combines three normalized components:
+//!
+//! - **Confidence**: the classifier confidence, clamped to `0.0..=1.0`
+//! - **Duration**: block length relative to a saturation point (blocks at or
+//!   beyond it score `1.0`)
+//! - **Recency**: exponential decay on the block's age with a configurable
+//!   half-life
+//!
+//! The weighted sum is divided by the total weight, so the final score always
+//! lies in `0.0..=1.0`. Ties are broken by start time and then id, which keeps
+//! the ordering stable across calls.
+
+use std::cmp::Ordering;
+
+use pulsearc_domain::types::classification::{ProposedBlock, RankedProposedBlock};
+use pulsearc_domain::{PulseArcError, Result};
+
+/// Weights and normalization parameters for [`BlockRanker`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RankingWeights {
+    /// Weight of the classifier confidence component
+    pub confidence: f64,
+    /// Weight of the duration component
+    pub duration: f64,
+    /// Weight of the recency component
+    pub recency: f64,
+    /// Duration (seconds) at which the duration component saturates at 1.0
+    pub duration_saturation_secs: i64,
+    /// Age (seconds) at which the recency component halves
+    pub recency_half_life_secs: i64,
+}
+
+impl Default for RankingWeights {
+    fn default() -> Self {
+        Self {
+            confidence: 0.5,
+            duration: 0.3,
+            recency: 0.2,
+            duration_saturation_secs: 4 * 3_600,
+            recency_half_life_secs: 24 * 3_600,
+        }
+    }
+}
+
+impl RankingWeights {
+    /// Validate the weights.
+    ///
+    /// # Errors
+    /// Returns `PulseArcError::InvalidInput` if any weight is negative or not
+    /// finite, if all weights are zero, or if a normalization window is not
+    /// positive.
+    pub fn validate(&self) -> Result<()> {
+        for (name, weight) in [
+            ("confidence", self.confidence),
+            ("duration", self.duration),
+            ("recency", self.recency),
+        ] {
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(PulseArcError::InvalidInput(format!(
+                    "ranking weight '{name}' must be a finite non-negative number, got {weight}"
+                )));
+            }
+        }
+
+        if self.total() <= 0.0 {
+            return Err(PulseArcError::InvalidInput(
+                "at least one ranking weight must be positive".to_string(),
+            ));
+        }
+
+        if self.duration_saturation_secs <= 0 {
+            return Err(PulseArcError::InvalidInput(
+                "duration_saturation_secs must be positive".to_string(),
+            ));
+        }
+
+        if self.recency_half_life_secs <= 0 {
+            return Err(PulseArcError::InvalidInput(
+                "recency_half_life_secs must be positive".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn total(&self) -> f64 {
+        self.confidence + self.duration + self.recency
+    }
+}
+
+/// Ranks proposed blocks by a weighted, normalized score
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BlockRanker {
+    weights: RankingWeights,
+}
+
+impl BlockRanker {
+    /// Create a ranker with custom weights.
+    ///
+    /// # Errors
+    /// Returns `PulseArcError::InvalidInput` if the weights are invalid (see
+    /// [`RankingWeights::validate`]).
+    pub fn new(weights: RankingWeights) -> Result<Self> {
+        weights.validate()?;
+        Ok(Self { weights })
+    }
+
+    /// Weights used by this ranker
+    pub fn weights(&self) -> &RankingWeights {
+        &self.weights
+    }
+
+    /// Score a single block as of `now_ts` (Unix epoch seconds).
+    ///
+    /// Returns a value in `0.0..=1.0`.
+    pub fn score(&self, block: &ProposedBlock, now_ts: i64) -> f64 {
+        let weights = &self.weights;
+
+        let confidence = f64::from(block.confidence);
+        let confidence = if confidence.is_finite() { confidence.clamp(0.0, 1.0) } else { 0.0 };
+
+        let duration =
+            (block.duration_secs.max(0) as f64 / weights.duration_saturation_secs as f64).min(1.0);
+
+        // Blocks ending in the future (clock skew) count as brand new
+        let age_secs = now_ts.saturating_sub(block.end_ts).max(0) as f64;
+        let recency = 0.5_f64.powf(age_secs / weights.recency_half_life_secs as f64);
+
+        let weighted = weights.confidence * confidence
+            + weights.duration * duration
+            + weights.recency * recency;
+
+        (weighted / weights.total()).clamp(0.0, 1.0)
+    }
+
+    /// Score and sort blocks, highest score first.
+    ///
+    /// Equal scores are ordered by `start_ts` ascending and then by `id`, so
+    /// the result is deterministic regardless of input order.
+    pub fn rank(&self, blocks: Vec<ProposedBlock>, now_ts: i64) -> Vec<RankedProposedBlock> {
+        let mut ranked: Vec<RankedProposedBlock> = blocks
+            .into_iter()
+            .map(|block| {
+                let score = self.score(&block, now_ts);
+                RankedProposedBlock { block, score }
+            })
+            .collect();
+
+        ranked.sort_by(compare_ranked);
+        ranked
+    }
+}
+
+fn compare_ranked(a: &RankedProposedBlock, b: &RankedProposedBlock) -> Ordering {
+    b.score
+        .total_cmp(&a.score)
+        .then_with(|| a.block.start_ts.cmp(&b.block.start_ts))
+        .then_with(|| a.block.id.cmp(&b.block.id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn block(id: &str, start_ts: i64, duration_secs: i64, confidence: f32) -> ProposedBlock {
+        ProposedBlock {
+            id: id.into(),
+            start_ts,
+            end_ts: start_ts + duration_secs,
+            duration_secs,
+            inferred_project_id: None,
+            inferred_wbs_code: None,
+            inferred_deal_name: None,
+            inferred_workstream: None,
+            billable: true,
+            confidence,
+            classifier_used: None,
+            activities: vec![],
+            snapshot_ids: vec![],
+            segment_ids: vec![],
+            reasons: vec![],
+            status: "suggested".into(),
+            created_at: start_ts,
+            reviewed_at: None,
+            total_idle_secs: 0,
+            idle_handling: "exclude".into(),
+            timezone: None,
+            work_location: None,
+            is_travel: false,
+            is_weekend: false,
+            is_after_hours: false,
+            has_calendar_overlap: false,
+            overlapping_event_ids: vec![],
+            is_double_booked: false,
+        }
+    }
+
+    #[test]
+    fn high_confidence_long_recent_block_outranks_weak_one() {
+        let ranker = BlockRanker::default();
+        let strong = block("strong", NOW - 3 * 3_600, 3 * 3_600, 0.95);
+        let weak = block("weak", NOW - 5 * 86_400, 1_800, 0.3);
+
+        let ranked = ranker.rank(vec![weak, strong], NOW);
+
+        assert_eq!(ranked[0].block.id, "strong");
+        assert_eq!(ranked[1].block.id, "weak");
+        assert!(ranked[0].score > ranked[1].score);
+        assert!(ranked.iter().all(|r| (0.0..=1.0).contains(&r.score)));
+    }
+
+    #[test]
+    fn ties_break_by_start_then_id() {
+        let ranker = BlockRanker::default();
+        // Identical score inputs: same duration, confidence and end time
+        let a = block("a", NOW - 3_600, 1_800, 0.8);
+        let b = block("b", NOW - 3_600, 1_800, 0.8);
+        let mut earlier = block("c", NOW - 5_400, 3_600, 0.8);
+        earlier.duration_secs = 1_800;
+
+        let first = ranker.rank(vec![b.clone(), a.clone(), earlier.clone()], NOW);
+        let second = ranker.rank(vec![earlier, a, b], NOW);
+
+        let ids = |ranked: &[RankedProposedBlock]| {
+            ranked.iter().map(|r| r.block.id.clone()).collect::<Vec<_>>()
+        };
+        assert_eq!(ids(&first), vec!["c", "a", "b"]);
+        assert_eq!(ids(&first), ids(&second));
+    }
+
+    #[test]
+    fn custom_weights_change_ordering() {
+        let long_unsure = block("long", NOW - 3_600, 4 * 3_600, 0.4);
+        let short_sure = block("short", NOW - 3_600, 1_800, 0.99);
+
+        let duration_only = BlockRanker::new(RankingWeights {
+            confidence: 0.0,
+            duration: 1.0,
+            recency: 0.0,
+            ..RankingWeights::default()
+        })
+        .expect("valid weights");
+        let ranked = duration_only.rank(vec![short_sure.clone(), long_unsure.clone()], NOW);
+        assert_eq!(ranked[0].block.id, "long");
+
+        let confidence_only = BlockRanker::new(RankingWeights {
+            confidence: 1.0,
+            duration: 0.0,
+            recency: 0.0,
+            ..RankingWeights::default()
+        })
+        .expect("valid weights");
+        let ranked = confidence_only.rank(vec![long_unsure, short_sure], NOW);
+        assert_eq!(ranked[0].block.id, "short");
+    }
+
+    #[test]
+    fn rejects_invalid_weights() {
+        let zero =
+            RankingWeights { confidence: 0.0, duration: 0.0, recency: 0.0, ..Default::default() };
+        assert!(BlockRanker::new(zero).is_err());
+
+        let negative = RankingWeights { recency: -0.1, ..Default::default() };
+        assert!(BlockRanker::new(negative).is_err());
+
+        let nan = RankingWeights { confidence: f64::NAN, ..Default::default() };
+        assert!(BlockRanker::new(nan).is_err());
+
+        let no_half_life = RankingWeights { recency_half_life_secs: 0, ..Default::default() };
+        assert!(BlockRanker::new(no_half_life).is_err());
+    }
+}
@@ -1,27 +1,68 @@
 //! Classification service - core business logic
 
 use std::sync::Arc;
+use std::time::Instant;
 
+use pulsearc_common::observability::{MetricsCollector, NoOpMetricsCollector};
 use pulsearc_domain::{ActivitySnapshot, Result, TimeEntry};
+use tracing::debug;
 
+use super::cache::{
+    context_signature, ClassificationCache, ClassificationCacheConfig, ClassificationCacheMetrics,
+};
 use super::ports::{Classifier, TimeEntryRepository};
 
+/// Counter incremented once per classification, labelled by `outcome`
+pub const CLASSIFICATION_RUNS_METRIC: &str = "classification.runs";
+
+/// Counter incremented when a classification is served from the cache
+pub const CLASSIFICATION_CACHE_HIT_METRIC: &str = "classification.cache.hit";
+
+/// Counter incremented when a cached classification has to call the classifier
+pub const CLASSIFICATION_CACHE_MISS_METRIC: &str = "classification.cache.miss";
+
+/// Timing of each classifier call, in milliseconds
+pub const CLASSIFICATION_DURATION_METRIC: &str = "classification.duration_ms";
+
 /// Classification service for converting snapshots to time entries
 pub struct ClassificationService {
     classifier: Arc<dyn Classifier>,
     repository: Arc<dyn TimeEntryRepository>,
+    cache: Option<ClassificationCache>,
+    metrics: Arc<dyn MetricsCollector>,
 }
 
 impl ClassificationService {
     /// Create a new classification service
     pub fn new(classifier: Arc<dyn Classifier>, repository: Arc<dyn TimeEntryRepository>) -> Self {
-        Self { classifier, repository }
+        Self { classifier, repository, cache: None, metrics: Arc::new(NoOpMetricsCollector) }
+    }
+
+    /// Emit run, cache and timing metrics through `metrics`
+    ///
+    /// Defaults to a no-op collector.
+    pub fn with_metrics(mut self, metrics: Arc<dyn MetricsCollector>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Cache classification results keyed by the block's context signature
+    ///
+    /// Blocks whose salient inputs match a cached result skip the classifier.
+    pub fn with_cache(mut self, config: ClassificationCacheConfig) -> Self {
+        self.cache = Some(ClassificationCache::new(config));
+        self
+    }
+
+    /// Cache hit/miss counters, or `None` when caching is disabled
+    pub fn cache_metrics(&self) -> Option<ClassificationCacheMetrics> {
+        self.cache.as_ref().map(ClassificationCache::metrics)
     }
 
     /// Classify snapshots into a time entry and save it
     pub async fn classify_and_save(&self, snapshots: Vec<ActivitySnapshot>) -> Result<TimeEntry> {
-        // Classify the snapshots
-        let entry = self.classifier.classify(snapshots).await?;
+        // Classify the snapshots (served from cache when the context matches)
+        let entry = self.classify(snapshots).await?;
 
         // Save the entry
         self.repository.save_entry(entry.clone()).await?;
@@ -29,6 +70,46 @@ impl ClassificationService {
         Ok(entry)
     }
 
+    async fn classify(&self, snapshots: Vec<ActivitySnapshot>) -> Result<TimeEntry> {
+        let result = self.classify_cached(snapshots).await;
+        let outcome = if result.is_ok() { "success" } else { "error" };
+        self.metrics.increment_counter(CLASSIFICATION_RUNS_METRIC, &[("outcome", outcome)]);
+        result
+    }
+
+    async fn classify_cached(&self, snapshots: Vec<ActivitySnapshot>) -> Result<TimeEntry> {
+        let Some(cache) = &self.cache else {
+            return self.run_classifier(snapshots).await;
+        };
+
+        let signature = context_signature(&snapshots);
+        if let Some(entry) = cache.get(signature, &snapshots) {
+            self.metrics.increment_counter(CLASSIFICATION_CACHE_HIT_METRIC, &[]);
+            let metrics = cache.metrics();
+            debug!(
+                signature,
+                hits = metrics.hits,
+                cost_saved_usd = metrics.estimated_cost_saved_usd,
+                "Classification served from cache"
+            );
+            return Ok(entry);
+        }
+
+        self.metrics.increment_counter(CLASSIFICATION_CACHE_MISS_METRIC, &[]);
+        let entry = self.run_classifier(snapshots.clone()).await?;
+        cache.insert(signature, &snapshots, &entry);
+        debug!(signature, misses = cache.metrics().misses, "Classification cached");
+        Ok(entry)
+    }
+
+    async fn run_classifier(&self, snapshots: Vec<ActivitySnapshot>) -> Result<TimeEntry> {
+        let started = Instant::now();
+        let result = self.classifier.classify(snapshots).await;
+        let elapsed_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+        self.metrics.record_timing(CLASSIFICATION_DURATION_METRIC, elapsed_ms, &[]);
+        result
+    }
+
     /// Get time entries within a time range
     pub async fn get_entries(
         &self,
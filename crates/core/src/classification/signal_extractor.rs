@@ -606,6 +606,7 @@ mod tests {
             processed_at: None,
             is_idle: false,
             idle_duration_secs: None,
+            content_hash: None,
         }
     }
 
@@ -624,6 +625,7 @@ mod tests {
             processed_at: None,
             is_idle: false,
             idle_duration_secs: None,
+            content_hash: None,
         }
     }
 
@@ -0,0 +1,397 @@
+//! Dismissal learning for suggestions
+//!
+//! Every dismissed block is recorded under an app/context signature (the
+//! block's dominant activity plus its inferred project). Once a signature has
+//! been dismissed `min_dismissals` times within the decay window, new
+//! suggestions with the same signature are suppressed. Dismissals older than
+//! the window no longer count, so a suppression lapses on its own once the
+//! user stops dismissing that kind of suggestion.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use pulsearc_domain::types::classification::{
+    ProposedBlock, SuggestionDismissal, SuggestionSuppression,
+};
+use pulsearc_domain::{PulseArcError, Result};
+use tracing::debug;
+
+use crate::classification::ports::SuggestionDismissalRepository;
+
+/// Signature context used when a block has no inferred project
+const NO_CONTEXT: &str = "-";
+
+/// Block status eligible for suppression
+const SUGGESTED_STATUS: &str = "suggested";
+
+/// Suppression thresholds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuppressionConfig {
+    /// Dismissals inside the decay window required to suppress a signature
+    pub min_dismissals: u32,
+    /// How long a dismissal keeps counting (seconds)
+    pub decay_secs: i64,
+}
+
+impl Default for SuppressionConfig {
+    fn default() -> Self {
+        Self { min_dismissals: 3, decay_secs: 30 * 86_400 }
+    }
+}
+
+impl SuppressionConfig {
+    /// Validate the thresholds.
+    ///
+    /// # Errors
+    /// Returns `PulseArcError::InvalidInput` if `min_dismissals` is zero or
+    /// `decay_secs` is not positive.
+    pub fn validate(&self) -> Result<()> {
+        if self.min_dismissals == 0 {
+            return Err(PulseArcError::InvalidInput("min_dismissals must be at least 1".into()));
+        }
+        if self.decay_secs <= 0 {
+            return Err(PulseArcError::InvalidInput("decay_secs must be positive".into()));
+        }
+        Ok(())
+    }
+}
+
+/// Build the app/context signature for a block.
+///
+/// The app is the activity with the largest share of the block; the context
+/// is the inferred project. Returns `None` when the block has no activities.
+pub fn suggestion_signature(block: &ProposedBlock) -> Option<String> {
+    let app = block
+        .activities
+        .iter()
+        .filter(|activity| !activity.name.trim().is_empty())
+        .max_by(|a, b| a.duration_secs.cmp(&b.duration_secs).then_with(|| b.name.cmp(&a.name)))?
+        .name
+        .trim()
+        .to_lowercase();
+
+    let context = block
+        .inferred_project_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|project| !project.is_empty())
+        .map_or_else(|| NO_CONTEXT.to_string(), str::to_lowercase);
+
+    Some(format!("{app}|{context}"))
+}
+
+/// Derive suppressions from raw dismissals as of `now_ts`.
+///
+/// Dismissals outside the decay window are ignored. Results are sorted by
+/// signature.
+pub fn compute_suppressions(
+    dismissals: &[SuggestionDismissal],
+    config: &SuppressionConfig,
+    now_ts: i64,
+) -> Vec<SuggestionSuppression> {
+    let cutoff = now_ts.saturating_sub(config.decay_secs);
+    let mut by_signature: BTreeMap<&str, Vec<i64>> = BTreeMap::new();
+    for dismissal in dismissals.iter().filter(|d| d.dismissed_at > cutoff) {
+        by_signature.entry(dismissal.signature.as_str()).or_default().push(dismissal.dismissed_at);
+    }
+
+    let threshold = usize::try_from(config.min_dismissals).unwrap_or(usize::MAX);
+    by_signature
+        .into_iter()
+        .filter_map(|(signature, mut times)| {
+            // Newest first, so the threshold-th entry is the one whose decay
+            // drops the signature back below the threshold
+            times.sort_unstable_by(|a, b| b.cmp(a));
+            let last_dismissed_at = *times.first()?;
+            let active = times.len() >= threshold;
+            let expires_at = if active {
+                times
+                    .get(threshold.saturating_sub(1))
+                    .map(|ts| ts.saturating_add(config.decay_secs))
+            } else {
+                None
+            };
+
+            Some(SuggestionSuppression {
+                signature: signature.to_string(),
+                dismissal_count: u32::try_from(times.len()).unwrap_or(u32::MAX),
+                last_dismissed_at,
+                active,
+                expires_at,
+            })
+        })
+        .collect()
+}
+
+/// Records dismissals and filters suggestions matching learned suppressions
+pub struct SuggestionSuppressor {
+    repository: Arc<dyn SuggestionDismissalRepository>,
+    config: SuppressionConfig,
+}
+
+impl SuggestionSuppressor {
+    /// Create a suppressor with default thresholds
+    pub fn new(repository: Arc<dyn SuggestionDismissalRepository>) -> Self {
+        Self { repository, config: SuppressionConfig::default() }
+    }
+
+    /// Create a suppressor with custom thresholds.
+    ///
+    /// # Errors
+    /// Returns `PulseArcError::InvalidInput` if the config is invalid.
+    pub fn with_config(
+        repository: Arc<dyn SuggestionDismissalRepository>,
+        config: SuppressionConfig,
+    ) -> Result<Self> {
+        config.validate()?;
+        Ok(Self { repository, config })
+    }
+
+    /// Thresholds used by this suppressor
+    pub fn config(&self) -> &SuppressionConfig {
+        &self.config
+    }
+
+    /// Record the dismissal of `block`.
+    ///
+    /// Returns the recorded signature, or `None` if the block has no
+    /// activities to derive one from.
+    pub async fn record_dismissal(
+        &self,
+        block: &ProposedBlock,
+        dismissed_at: i64,
+    ) -> Result<Option<String>> {
+        let Some(signature) = suggestion_signature(block) else {
+            return Ok(None);
+        };
+
+        self.repository
+            .record_dismissal(&SuggestionDismissal {
+                signature: signature.clone(),
+                source_id: block.id.clone(),
+                dismissed_at,
+            })
+            .await?;
+
+        Ok(Some(signature))
+    }
+
+    /// Learned suppressions as of `now_ts`, including signatures that have
+    /// not reached the threshold yet.
+    pub async fn suppressions(&self, now_ts: i64) -> Result<Vec<SuggestionSuppression>> {
+        let since = now_ts.saturating_sub(self.config.decay_secs);
+        let dismissals = self.repository.get_dismissals_since(since).await?;
+        Ok(compute_suppressions(&dismissals, &self.config, now_ts))
+    }
+
+    /// Drop suggested blocks whose signature is actively suppressed.
+    ///
+    /// Blocks the user already reviewed are kept regardless of signature.
+    pub async fn filter_blocks(
+        &self,
+        blocks: Vec<ProposedBlock>,
+        now_ts: i64,
+    ) -> Result<Vec<ProposedBlock>> {
+        let suppressed: Vec<String> = self
+            .suppressions(now_ts)
+            .await?
+            .into_iter()
+            .filter(|suppression| suppression.active)
+            .map(|suppression| suppression.signature)
+            .collect();
+
+        if suppressed.is_empty() {
+            return Ok(blocks);
+        }
+
+        let before = blocks.len();
+        let kept: Vec<ProposedBlock> = blocks
+            .into_iter()
+            .filter(|block| {
+                if !block.status.eq_ignore_ascii_case(SUGGESTED_STATUS) {
+                    return true;
+                }
+                match suggestion_signature(block) {
+                    Some(signature) => !suppressed.contains(&signature),
+                    None => true,
+                }
+            })
+            .collect();
+
+        debug!(suppressed = before - kept.len(), "Filtered suppressed suggestions");
+        Ok(kept)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use pulsearc_domain::types::classification::ActivityBreakdown;
+
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+    const DAY: i64 = 86_400;
+
+    #[derive(Default)]
+    struct InMemoryDismissals {
+        dismissals: Mutex<Vec<SuggestionDismissal>>,
+    }
+
+    #[async_trait]
+    impl SuggestionDismissalRepository for InMemoryDismissals {
+        async fn record_dismissal(&self, dismissal: &SuggestionDismissal) -> Result<()> {
+            self.dismissals
+                .lock()
+                .map_err(|_| PulseArcError::Internal("lock poisoned".into()))?
+                .push(dismissal.clone());
+            Ok(())
+        }
+
+        async fn get_dismissals_since(&self, since_ts: i64) -> Result<Vec<SuggestionDismissal>> {
+            Ok(self
+                .dismissals
+                .lock()
+                .map_err(|_| PulseArcError::Internal("lock poisoned".into()))?
+                .iter()
+                .filter(|d| d.dismissed_at >= since_ts)
+                .cloned()
+                .collect())
+        }
+    }
+
+    fn block(id: &str, app: &str) -> ProposedBlock {
+        ProposedBlock {
+            id: id.into(),
+            start_ts: NOW - 3_600,
+            end_ts: NOW - 1_800,
+            duration_secs: 1_800,
+            inferred_project_id: None,
+            inferred_wbs_code: None,
+            inferred_deal_name: None,
+            inferred_workstream: None,
+            billable: false,
+            confidence: 0.7,
+            classifier_used: None,
+            activities: vec![
+                ActivityBreakdown { name: app.into(), duration_secs: 1_500, percentage: 83.3 },
+                ActivityBreakdown { name: "Finder".into(), duration_secs: 300, percentage: 16.7 },
+            ],
+            snapshot_ids: vec![],
+            segment_ids: vec![],
+            reasons: vec![],
+            status: "suggested".into(),
+            created_at: NOW - 1_800,
+            reviewed_at: None,
+            total_idle_secs: 0,
+            idle_handling: "exclude".into(),
+            timezone: None,
+            work_location: None,
+            is_travel: false,
+            is_weekend: false,
+            is_after_hours: false,
+            has_calendar_overlap: false,
+            overlapping_event_ids: vec![],
+            is_double_booked: false,
+        }
+    }
+
+    fn suppressor(config: SuppressionConfig) -> SuggestionSuppressor {
+        SuggestionSuppressor::with_config(Arc::new(InMemoryDismissals::default()), config)
+            .expect("valid config")
+    }
+
+    #[test]
+    fn signature_uses_dominant_app_and_project() {
+        let mut tagged = block("b1", "Spotify");
+        assert_eq!(suggestion_signature(&tagged).as_deref(), Some("spotify|-"));
+
+        tagged.inferred_project_id = Some("USC0063201".into());
+        assert_eq!(suggestion_signature(&tagged).as_deref(), Some("spotify|usc0063201"));
+
+        tagged.activities.clear();
+        assert_eq!(suggestion_signature(&tagged), None);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn suppresses_after_repeated_dismissals() {
+        let config = SuppressionConfig { min_dismissals: 3, decay_secs: 7 * DAY };
+        let suppressor = suppressor(config);
+
+        for (i, offset) in [3 * DAY, 2 * DAY].iter().enumerate() {
+            suppressor
+                .record_dismissal(&block(&format!("old-{i}"), "Spotify"), NOW - offset)
+                .await
+                .expect("record");
+        }
+
+        // Two dismissals: below threshold, still suggested
+        let kept =
+            suppressor.filter_blocks(vec![block("new", "Spotify")], NOW).await.expect("filter");
+        assert_eq!(kept.len(), 1);
+
+        suppressor.record_dismissal(&block("old-2", "Spotify"), NOW - DAY).await.expect("record");
+
+        let kept = suppressor
+            .filter_blocks(vec![block("new", "Spotify"), block("work", "Excel")], NOW)
+            .await
+            .expect("filter");
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].id, "work");
+
+        let suppressions = suppressor.suppressions(NOW).await.expect("suppressions");
+        assert_eq!(suppressions.len(), 1);
+        assert_eq!(suppressions[0].signature, "spotify|-");
+        assert_eq!(suppressions[0].dismissal_count, 3);
+        assert!(suppressions[0].active);
+        assert_eq!(suppressions[0].expires_at, Some(NOW - 3 * DAY + 7 * DAY));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn suppression_decays_after_window() {
+        let config = SuppressionConfig { min_dismissals: 2, decay_secs: 7 * DAY };
+        let suppressor = suppressor(config);
+
+        for i in 0..2 {
+            suppressor
+                .record_dismissal(&block(&format!("d-{i}"), "Spotify"), NOW)
+                .await
+                .expect("record");
+        }
+
+        let kept =
+            suppressor.filter_blocks(vec![block("new", "Spotify")], NOW).await.expect("filter");
+        assert!(kept.is_empty());
+
+        let later = NOW + 7 * DAY;
+        let kept =
+            suppressor.filter_blocks(vec![block("new", "Spotify")], later).await.expect("filter");
+        assert_eq!(kept.len(), 1);
+        assert!(suppressor.suppressions(later).await.expect("suppressions").is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn reviewed_blocks_are_never_filtered() {
+        let config = SuppressionConfig { min_dismissals: 1, decay_secs: DAY };
+        let suppressor = suppressor(config);
+        suppressor.record_dismissal(&block("d", "Spotify"), NOW).await.expect("record");
+
+        let mut accepted = block("accepted", "Spotify");
+        accepted.status = "accepted".into();
+
+        let kept = suppressor.filter_blocks(vec![accepted], NOW).await.expect("filter");
+        assert_eq!(kept.len(), 1);
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        let repo: Arc<dyn SuggestionDismissalRepository> = Arc::new(InMemoryDismissals::default());
+        let zero = SuppressionConfig { min_dismissals: 0, ..Default::default() };
+        assert!(SuggestionSuppressor::with_config(Arc::clone(&repo), zero).is_err());
+
+        let no_decay = SuppressionConfig { decay_secs: 0, ..Default::default() };
+        assert!(SuggestionSuppressor::with_config(repo, no_decay).is_err());
+    }
+}
@@ -0,0 +1,325 @@
+//! Weekly timesheet assembly with billing rounding
+//!
+//! Groups a week's time entries into per-day, per-project cells and applies a
+//! [`RoundingRule`] to each entry before it is added to its cell, the way
+//! billing increments (6 or 15 minutes) are usually applied. Raw and rounded
+//! totals are both kept so the difference introduced by rounding is visible.
+//!
+//! Days are UTC calendar days of each entry's start time; the week runs from
+//! the Monday on or before the requested date.
+//!
+//! # Tie-breaking
+//!
+//! [`RoundingMode::Nearest`] rounds an exact half increment up (3 minutes
+//! with a 6-minute increment becomes 6 minutes). `Up` and `Down` never move
+//! values already on an increment boundary.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use pulsearc_domain::{PulseArcError, Result, TimeEntry};
+
+use crate::classification::ports::TimeEntryRepository;
+
+const DAYS_PER_WEEK: i64 = 7;
+
+/// Direction applied when a duration is not on an increment boundary
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round to the closest increment; exact halves round up
+    Nearest,
+    /// Round up to the next increment
+    Up,
+    /// Round down to the previous increment
+    Down,
+}
+
+/// Rounding applied to each entry's duration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundingRule {
+    /// Increment to round to (seconds)
+    pub increment_secs: i64,
+    pub mode: RoundingMode,
+}
+
+impl Default for RoundingRule {
+    /// No rounding (1-second increment)
+    fn default() -> Self {
+        Self { increment_secs: 1, mode: RoundingMode::Nearest }
+    }
+}
+
+impl RoundingRule {
+    /// Round to the nearest `minutes` increment
+    pub fn nearest(minutes: i64) -> Self {
+        Self { increment_secs: minutes.saturating_mul(60), mode: RoundingMode::Nearest }
+    }
+
+    /// Round up to the next `minutes` increment
+    pub fn up(minutes: i64) -> Self {
+        Self { increment_secs: minutes.saturating_mul(60), mode: RoundingMode::Up }
+    }
+
+    /// Round down to the previous `minutes` increment
+    pub fn down(minutes: i64) -> Self {
+        Self { increment_secs: minutes.saturating_mul(60), mode: RoundingMode::Down }
+    }
+
+    /// Validate the rule.
+    ///
+    /// # Errors
+    /// Returns `PulseArcError::InvalidInput` if the increment is not positive.
+    pub fn validate(&self) -> Result<()> {
+        if self.increment_secs <= 0 {
+            return Err(PulseArcError::InvalidInput("rounding increment must be positive".into()));
+        }
+        Ok(())
+    }
+
+    /// Round a duration in seconds (negative durations count as zero)
+    pub fn round(&self, secs: i64) -> i64 {
+        let secs = secs.max(0);
+        let increment = self.increment_secs.max(1);
+        let remainder = secs % increment;
+        if remainder == 0 {
+            return secs;
+        }
+
+        let down = secs - remainder;
+        let up = down.saturating_add(increment);
+        match self.mode {
+            RoundingMode::Down => down,
+            RoundingMode::Up => up,
+            RoundingMode::Nearest if remainder.saturating_mul(2) >= increment => up,
+            RoundingMode::Nearest => down,
+        }
+    }
+}
+
+/// Time for one project on one day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimesheetCell {
+    pub date: NaiveDate,
+    /// `None` for entries without a project
+    pub project_id: Option<String>,
+    /// Sum of unrounded entry durations (seconds)
+    pub raw_secs: i64,
+    /// Sum of rounded entry durations (seconds)
+    pub rounded_secs: i64,
+    pub entry_count: u32,
+}
+
+/// Totals for one day of the week
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimesheetDay {
+    pub date: NaiveDate,
+    pub raw_secs: i64,
+    pub rounded_secs: i64,
+}
+
+/// A week of rounded time, by day and project
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timesheet {
+    /// Monday the week starts on
+    pub week_start: NaiveDate,
+    pub rounding: RoundingRule,
+    /// Cells ordered by date, then project (unassigned last)
+    pub cells: Vec<TimesheetCell>,
+    /// All seven days, Monday first (days without entries are zero)
+    pub days: Vec<TimesheetDay>,
+    /// Sum of unrounded entry durations (seconds)
+    pub raw_total_secs: i64,
+    /// Sum of rounded entry durations (seconds)
+    pub rounded_total_secs: i64,
+}
+
+impl Timesheet {
+    /// Seconds added (positive) or removed (negative) by rounding
+    pub fn rounding_difference_secs(&self) -> i64 {
+        self.rounded_total_secs - self.raw_total_secs
+    }
+}
+
+/// Load the entries for the week containing `week` and build its timesheet
+pub async fn build_timesheet(
+    entry_repo: &dyn TimeEntryRepository,
+    week: NaiveDate,
+    rounding: RoundingRule,
+) -> Result<Timesheet> {
+    rounding.validate()?;
+    let week_start = week_start(week);
+    let start = day_start(week_start)?;
+    let end = start + Duration::days(DAYS_PER_WEEK);
+
+    let entries = entry_repo.get_entries(start, end).await?;
+    assemble_timesheet(week_start, &entries, rounding)
+}
+
+/// Cell key: day, whether the entry is unassigned, and project
+type CellKey = (NaiveDate, bool, Option<String>);
+
+/// Build a timesheet from entries; entries outside the week are ignored
+pub fn assemble_timesheet(
+    week: NaiveDate,
+    entries: &[TimeEntry],
+    rounding: RoundingRule,
+) -> Result<Timesheet> {
+    rounding.validate()?;
+    let week_start = week_start(week);
+    let week_end = week_start + Duration::days(DAYS_PER_WEEK);
+
+    // Key sorts unassigned (`true`) after named projects on the same day
+    let mut cells: BTreeMap<CellKey, TimesheetCell> = BTreeMap::new();
+    for entry in entries {
+        let date = entry.start_time.date_naive();
+        if date < week_start || date >= week_end {
+            continue;
+        }
+
+        let raw = entry_duration_secs(entry);
+        let rounded = rounding.round(raw);
+        let project_id = entry
+            .project_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|project| !project.is_empty())
+            .map(str::to_string);
+
+        let cell =
+            cells.entry((date, project_id.is_none(), project_id.clone())).or_insert_with(|| {
+                TimesheetCell { date, project_id, raw_secs: 0, rounded_secs: 0, entry_count: 0 }
+            });
+        cell.raw_secs += raw;
+        cell.rounded_secs += rounded;
+        cell.entry_count += 1;
+    }
+
+    let cells: Vec<TimesheetCell> = cells.into_values().collect();
+    let days = (0..DAYS_PER_WEEK)
+        .map(|offset| {
+            let date = week_start + Duration::days(offset);
+            let day_cells = cells.iter().filter(|cell| cell.date == date);
+            TimesheetDay {
+                date,
+                raw_secs: day_cells.clone().map(|cell| cell.raw_secs).sum(),
+                rounded_secs: day_cells.map(|cell| cell.rounded_secs).sum(),
+            }
+        })
+        .collect();
+
+    Ok(Timesheet {
+        week_start,
+        rounding,
+        raw_total_secs: cells.iter().map(|cell| cell.raw_secs).sum(),
+        rounded_total_secs: cells.iter().map(|cell| cell.rounded_secs).sum(),
+        cells,
+        days,
+    })
+}
+
+/// Entry duration: recorded seconds, else end - start, else recorded minutes
+fn entry_duration_secs(entry: &TimeEntry) -> i64 {
+    entry
+        .duration_seconds
+        .or_else(|| entry.end_time.map(|end| (end - entry.start_time).num_seconds()))
+        .or_else(|| entry.duration_minutes.map(|minutes| i64::from(minutes) * 60))
+        .unwrap_or(0)
+        .max(0)
+}
+
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+}
+
+fn day_start(date: NaiveDate) -> Result<DateTime<Utc>> {
+    date.and_hms_opt(0, 0, 0)
+        .map(|midnight| midnight.and_utc())
+        .ok_or_else(|| PulseArcError::InvalidInput(format!("invalid week start {date}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use pulsearc_domain::TimeEntryParams;
+    use uuid::Uuid;
+
+    use super::*;
+
+    /// Monday 2025-03-10
+    fn monday() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, 10).expect("valid date")
+    }
+
+    fn entry(day_offset: i64, hour: u32, minutes: i64, project: Option<&str>) -> TimeEntry {
+        let start = (monday() + Duration::days(day_offset))
+            .and_hms_opt(hour, 0, 0)
+            .expect("valid time")
+            .and_utc();
+        TimeEntry::new(TimeEntryParams {
+            id: Uuid::new_v4(),
+            start_time: start,
+            end_time: Some(start + Duration::minutes(minutes)),
+            duration_seconds: Some(minutes * 60),
+            description: "work".to_string(),
+            project_id: project.map(str::to_string),
+            wbs_code: None,
+        })
+    }
+
+    #[test]
+    fn six_minute_nearest_rounds_seven_minutes_to_six() {
+        let rule = RoundingRule::nearest(6);
+
+        assert_eq!(rule.round(7 * 60), 6 * 60);
+        assert_eq!(rule.round(9 * 60), 12 * 60); // exact half rounds up
+        assert_eq!(RoundingRule::up(6).round(7 * 60), 12 * 60);
+        assert_eq!(RoundingRule::down(15).round(29 * 60), 15 * 60);
+        assert_eq!(RoundingRule::up(15).round(30 * 60), 30 * 60);
+    }
+
+    #[test]
+    fn reports_rounded_sum_alongside_raw_sum() {
+        let entries = vec![
+            entry(0, 9, 7, Some("USC0063201")),
+            entry(0, 10, 7, Some("USC0063201")),
+            entry(0, 11, 20, None),
+            entry(2, 9, 44, Some("USC0042105")),
+        ];
+
+        let sheet = assemble_timesheet(monday(), &entries, RoundingRule::nearest(6)).unwrap();
+
+        // Raw: 7 + 7 + 20 + 44 = 78 min; rounded: 6 + 6 + 18 + 42 = 72 min
+        assert_eq!(sheet.raw_total_secs, 78 * 60);
+        assert_eq!(sheet.rounded_total_secs, 72 * 60);
+        assert_eq!(sheet.rounding_difference_secs(), -6 * 60);
+
+        let first = &sheet.cells[0];
+        assert_eq!(first.project_id.as_deref(), Some("USC0063201"));
+        assert_eq!((first.raw_secs, first.rounded_secs, first.entry_count), (14 * 60, 12 * 60, 2));
+        assert_eq!(sheet.cells[1].project_id, None);
+
+        assert_eq!(sheet.days.len(), 7);
+        assert_eq!(sheet.days[0].rounded_secs, 30 * 60);
+        assert_eq!(sheet.days[1].raw_secs, 0);
+        let day_sum: i64 = sheet.days.iter().map(|day| day.rounded_secs).sum();
+        assert_eq!(day_sum, sheet.rounded_total_secs);
+    }
+
+    #[test]
+    fn normalizes_to_monday_and_skips_other_weeks() {
+        let entries = vec![entry(3, 9, 30, None), entry(7, 9, 30, None), entry(-1, 9, 30, None)];
+
+        let thursday = monday() + Duration::days(3);
+        let sheet = assemble_timesheet(thursday, &entries, RoundingRule::default()).unwrap();
+
+        assert_eq!(sheet.week_start, monday());
+        assert_eq!(sheet.raw_total_secs, 30 * 60);
+        assert_eq!(sheet.rounded_total_secs, 30 * 60);
+    }
+
+    #[test]
+    fn rejects_non_positive_increment() {
+        let result = assemble_timesheet(monday(), &[], RoundingRule::nearest(0));
+
+        assert!(matches!(result, Err(PulseArcError::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,351 @@
+//! Export of accepted blocks as labeled training data
+//!
+//! Each block the user accepted (as suggested or after editing) becomes one
+//! [`TrainingExample`]: the signals a classifier sees for the block - apps,
+//! work type and activity category from its snapshots, calendar and temporal
+//! flags, and the project match evidence - labeled with the accepted
+//! billable flag and project. Suggested and rejected blocks are left out.
+//!
+//! App names, calendar event titles and match reasons can carry names and
+//! addresses, so they are run through the privacy [`PatternMatcher`]. If
+//! redaction fails the text is dropped from the example rather than exported
+//! raw. Organizer emails and window titles are never exported.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use chrono::{DateTime, Duration, Utc};
+use pulsearc_common::privacy::PatternMatcher;
+use pulsearc_domain::types::classification::{
+    ActivityBreakdown, ProposedBlock, TrainingExample, TrainingFeatures, TrainingLabel,
+};
+use pulsearc_domain::{ActivitySnapshot, CalendarEventContext, PulseArcError, Result, TimeRange};
+use serde::Deserialize;
+use tracing::warn;
+
+use crate::classification::ports::BlockRepository;
+use crate::tracking::ports::SnapshotRepository;
+
+/// Block statuses that carry a user-confirmed label
+const LABELED_STATUSES: [&str; 2] = ["accepted", "edited"];
+
+/// The part of a snapshot's activity context used for calendar features
+#[derive(Deserialize)]
+struct SnapshotCalendar {
+    #[serde(default)]
+    calendar_event: Option<CalendarEventContext>,
+}
+
+/// Export a labeled example for every accepted block starting in `range`.
+///
+/// Examples are ordered by block start time.
+pub async fn export_training_data(
+    block_repo: &dyn BlockRepository,
+    snapshot_repo: &dyn SnapshotRepository,
+    redactor: &PatternMatcher,
+    range: TimeRange,
+) -> Result<Vec<TrainingExample>> {
+    let start = timestamp(range.start_ts)?;
+    let end = timestamp(range.end_ts)?;
+    if end <= start {
+        return Err(PulseArcError::InvalidInput(format!(
+            "training data range is empty: {}..{}",
+            range.start_ts, range.end_ts
+        )));
+    }
+
+    let mut seen = HashSet::new();
+    let mut blocks = Vec::new();
+    let mut date = start.date_naive();
+    while date <= end.date_naive() {
+        for block in block_repo.get_proposed_blocks(date).await? {
+            let in_range = block.start_ts >= range.start_ts && block.start_ts < range.end_ts;
+            if in_range && seen.insert(block.id.clone()) {
+                blocks.push(block);
+            }
+        }
+        date += Duration::days(1);
+    }
+    blocks.sort_by(|a, b| a.start_ts.cmp(&b.start_ts).then_with(|| a.id.cmp(&b.id)));
+
+    // Blocks starting near the end of the range can run past it
+    let snapshot_end = blocks.iter().map(|block| block.end_ts).fold(range.end_ts, i64::max);
+    let snapshots = snapshot_repo
+        .find_snapshots_by_time_range(start, timestamp(snapshot_end)?)
+        .map_err(|e| PulseArcError::Database(e.to_string()))?;
+
+    Ok(build_training_examples(&blocks, &snapshots, redactor).await)
+}
+
+/// Build examples for the accepted blocks among `blocks`
+///
+/// Each block's snapshot features come from the entries of `snapshots` it
+/// references.
+pub async fn build_training_examples(
+    blocks: &[ProposedBlock],
+    snapshots: &[ActivitySnapshot],
+    redactor: &PatternMatcher,
+) -> Vec<TrainingExample> {
+    let by_id: HashMap<&str, &ActivitySnapshot> =
+        snapshots.iter().map(|snapshot| (snapshot.id.as_str(), snapshot)).collect();
+
+    let mut examples = Vec::new();
+    for block in blocks.iter().filter(|block| LABELED_STATUSES.contains(&block.status.as_str())) {
+        let block_snapshots: Vec<&ActivitySnapshot> =
+            block.snapshot_ids.iter().filter_map(|id| by_id.get(id.as_str()).copied()).collect();
+        examples.push(TrainingExample {
+            block_id: block.id.clone(),
+            start_ts: block.start_ts,
+            end_ts: block.end_ts,
+            features: features(block, &block_snapshots, redactor).await,
+            label: TrainingLabel {
+                billable: block.billable,
+                project_id: block.inferred_project_id.clone(),
+                wbs_code: block.inferred_wbs_code.clone(),
+                workstream: block.inferred_workstream.clone(),
+            },
+        });
+    }
+    examples
+}
+
+/// Serialize examples as JSON Lines (one example per line)
+pub fn training_data_jsonl(examples: &[TrainingExample]) -> Result<String> {
+    let mut jsonl = String::new();
+    for example in examples {
+        let line = serde_json::to_string(example).map_err(|e| {
+            PulseArcError::Internal(format!("Failed to serialize training example: {e}"))
+        })?;
+        jsonl.push_str(&line);
+        jsonl.push('\n');
+    }
+    Ok(jsonl)
+}
+
+async fn features(
+    block: &ProposedBlock,
+    snapshots: &[&ActivitySnapshot],
+    redactor: &PatternMatcher,
+) -> TrainingFeatures {
+    let mut apps = Vec::with_capacity(block.activities.len());
+    for activity in &block.activities {
+        if let Some(name) = redact(redactor, &activity.name).await {
+            apps.push(ActivityBreakdown { name, ..activity.clone() });
+        }
+    }
+
+    let mut event_titles = BTreeSet::new();
+    let mut meeting_platforms = BTreeSet::new();
+    let mut has_external_attendees = false;
+    for snapshot in snapshots {
+        let Some(event) = snapshot_calendar(snapshot) else { continue };
+        if let Some(title) = event.event_title.as_deref().filter(|title| !title.is_empty()) {
+            if let Some(title) = redact(redactor, title).await {
+                event_titles.insert(title);
+            }
+        }
+        if let Some(platform) = event.meeting_platform {
+            meeting_platforms.insert(platform);
+        }
+        has_external_attendees |= event.has_external_attendees;
+    }
+
+    let mut match_reasons = Vec::with_capacity(block.reasons.len());
+    for reason in &block.reasons {
+        if let Some(reason) = redact(redactor, reason).await {
+            match_reasons.push(reason);
+        }
+    }
+
+    TrainingFeatures {
+        apps,
+        work_type: most_common(snapshots.iter().filter_map(|s| s.work_type.as_deref())),
+        activity_category: most_common(
+            snapshots.iter().filter_map(|s| s.activity_category.as_deref()),
+        ),
+        has_calendar_overlap: block.has_calendar_overlap,
+        is_double_booked: block.is_double_booked,
+        calendar_event_titles: event_titles.into_iter().collect(),
+        has_external_attendees,
+        meeting_platforms: meeting_platforms.into_iter().collect(),
+        is_weekend: block.is_weekend,
+        is_after_hours: block.is_after_hours,
+        is_travel: block.is_travel,
+        classifier: block.classifier_used.clone(),
+        match_confidence: block.confidence,
+        match_reasons,
+    }
+}
+
+fn snapshot_calendar(snapshot: &ActivitySnapshot) -> Option<CalendarEventContext> {
+    match serde_json::from_str::<SnapshotCalendar>(&snapshot.activity_context_json) {
+        Ok(context) => context.calendar_event,
+        Err(err) => {
+            warn!(snapshot_id = %snapshot.id, error = %err, "Skipping unparseable activity context");
+            None
+        }
+    }
+}
+
+/// Most frequent value; ties go to the alphabetically first
+fn most_common<'a>(values: impl Iterator<Item = &'a str>) -> Option<String> {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for value in values {
+        *counts.entry(value).or_insert(0) += 1;
+    }
+    counts.into_iter().rev().max_by_key(|(_, count)| *count).map(|(value, _)| value.to_string())
+}
+
+async fn redact(redactor: &PatternMatcher, text: &str) -> Option<String> {
+    match redactor.redact_pii(text).await {
+        Ok(redacted) => Some(redacted),
+        Err(err) => {
+            warn!(error = %err, "Failed to redact training feature; dropping text");
+            None
+        }
+    }
+}
+
+fn timestamp(ts: i64) -> Result<DateTime<Utc>> {
+    DateTime::from_timestamp(ts, 0)
+        .ok_or_else(|| PulseArcError::InvalidInput(format!("invalid timestamp: {ts}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const START_TS: i64 = 1_741_600_800; // 2025-03-10 10:00:00 UTC
+
+    fn block(id: &str, status: &str) -> ProposedBlock {
+        ProposedBlock {
+            id: id.into(),
+            start_ts: START_TS,
+            end_ts: START_TS + 1_800,
+            duration_secs: 1_800,
+            inferred_project_id: Some("USC0063201".into()),
+            inferred_wbs_code: Some("USC0063201.1.1".into()),
+            inferred_deal_name: Some("Project Astro".into()),
+            inferred_workstream: Some("modeling".into()),
+            billable: true,
+            confidence: 0.85,
+            classifier_used: Some("hybrid".into()),
+            activities: vec![
+                ActivityBreakdown {
+                    name: "Microsoft Excel".into(),
+                    duration_secs: 1_500,
+                    percentage: 83.3,
+                },
+                ActivityBreakdown {
+                    name: "Mail - jane.doe@example.com".into(),
+                    duration_secs: 300,
+                    percentage: 16.7,
+                },
+            ],
+            snapshot_ids: vec![format!("{id}-s1"), format!("{id}-s2")],
+            segment_ids: vec![],
+            reasons: vec!["fts5_keyword:astro".into()],
+            status: status.into(),
+            created_at: START_TS + 1_800,
+            reviewed_at: Some(START_TS + 3_600),
+            total_idle_secs: 0,
+            idle_handling: "exclude".into(),
+            timezone: None,
+            work_location: None,
+            is_travel: false,
+            is_weekend: false,
+            is_after_hours: true,
+            has_calendar_overlap: true,
+            overlapping_event_ids: vec!["evt-1".into()],
+            is_double_booked: false,
+        }
+    }
+
+    fn snapshot(id: &str, work_type: &str, category: &str) -> ActivitySnapshot {
+        let context = json!({
+            "active_app": { "app_name": "Microsoft Excel", "window_title": "Astro model.xlsx" },
+            "calendar_event": {
+                "event_title": "Astro sync with john.smith@client.com",
+                "has_external_attendees": true,
+                "organizer_email": "jane.doe@example.com",
+                "meeting_platform": "zoom"
+            }
+        });
+        ActivitySnapshot {
+            id: id.into(),
+            timestamp: START_TS,
+            activity_context_json: context.to_string(),
+            detected_activity: "modeling".into(),
+            work_type: Some(work_type.into()),
+            activity_category: Some(category.into()),
+            primary_app: "Microsoft Excel".into(),
+            processed: true,
+            batch_id: None,
+            created_at: START_TS,
+            processed_at: None,
+            is_idle: false,
+            idle_duration_secs: None,
+            content_hash: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn accepted_block_yields_labeled_example() {
+        let redactor = PatternMatcher::with_defaults().await.expect("matcher");
+        let snapshots = vec![
+            snapshot("b1-s1", "modeling", "client_work"),
+            snapshot("b1-s2", "modeling", "client_work"),
+            snapshot("other", "email", "communication"),
+        ];
+
+        let examples =
+            build_training_examples(&[block("b1", "accepted")], &snapshots, &redactor).await;
+
+        assert_eq!(examples.len(), 1);
+        let example = &examples[0];
+        assert_eq!(example.block_id, "b1");
+        assert_eq!(
+            example.label,
+            TrainingLabel {
+                billable: true,
+                project_id: Some("USC0063201".into()),
+                wbs_code: Some("USC0063201.1.1".into()),
+                workstream: Some("modeling".into()),
+            }
+        );
+
+        let features = &example.features;
+        assert_eq!(features.work_type.as_deref(), Some("modeling"));
+        assert_eq!(features.activity_category.as_deref(), Some("client_work"));
+        assert!(features.has_calendar_overlap);
+        assert!(features.has_external_attendees);
+        assert!(features.is_after_hours);
+        assert_eq!(features.meeting_platforms, vec!["zoom".to_string()]);
+        assert_eq!(features.match_reasons, vec!["fts5_keyword:astro".to_string()]);
+        assert_eq!(features.apps[0].name, "Microsoft Excel");
+
+        // No PII survives into the exported dataset
+        let jsonl = training_data_jsonl(&examples).expect("serializes");
+        assert_eq!(jsonl.lines().count(), 1);
+        assert!(!jsonl.contains("jane.doe@example.com"), "{jsonl}");
+        assert!(!jsonl.contains("john.smith@client.com"), "{jsonl}");
+        assert!(!jsonl.contains("Astro model.xlsx"), "window titles are not exported");
+    }
+
+    #[tokio::test]
+    async fn dismissed_and_pending_blocks_are_excluded() {
+        let redactor = PatternMatcher::with_defaults().await.expect("matcher");
+        let blocks = vec![
+            block("rejected", "rejected"),
+            block("suggested", "suggested"),
+            block("edited", "edited"),
+        ];
+
+        let examples = build_training_examples(&blocks, &[], &redactor).await;
+
+        let ids: Vec<&str> = examples.iter().map(|example| example.block_id.as_str()).collect();
+        assert_eq!(ids, vec!["edited"]);
+        assert_eq!(examples[0].features.work_type, None, "no snapshots, no snapshot features");
+    }
+}
@@ -0,0 +1,307 @@
+//! Fuzzy search over cached WBS elements
+//!
+//! Users rarely remember exact WBS codes, so search scores every cached
+//! element against the query and returns the best matches:
+//! - Codes (`wbs_code`, `project_def`, `project_code`): exact match scores
+//!   1.0, prefixes are boosted, anything else falls back to bigram similarity
+//! - Text (`description`, `project_name`, `deal_name`, `target_company_name`):
+//!   each query word is matched against the best field word (prefix boosted,
+//!   typo tolerant via bigram similarity) and the scores are averaged
+//!
+//! Text matches are capped below an exact code match so typing a full code
+//! always puts that element first.
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use pulsearc_domain::types::{WbsElement, WbsSearchHit};
+use pulsearc_domain::Result;
+
+use crate::classification::ports::WbsRepository;
+
+/// Maximum number of cached elements scored per search
+pub const DEFAULT_MAX_CANDIDATES: usize = 5_000;
+
+/// Matches scoring below this are dropped
+pub const DEFAULT_MIN_SCORE: f32 = 0.35;
+
+/// Weight applied to text-field matches so they never beat an exact code
+const TEXT_WEIGHT: f32 = 0.9;
+
+/// Weight applied to code similarity that is neither exact nor a prefix
+const FUZZY_CODE_WEIGHT: f32 = 0.7;
+
+/// Word score when a field word starts with the query word
+const WORD_PREFIX_SCORE: f32 = 0.9;
+
+/// Fuzzy WBS search backed by a [`WbsRepository`].
+pub struct WbsSearch {
+    repository: Arc<dyn WbsRepository>,
+    max_candidates: usize,
+    min_score: f32,
+}
+
+impl WbsSearch {
+    /// Create a search over the repository's active WBS elements.
+    pub fn new(repository: Arc<dyn WbsRepository>) -> Self {
+        Self { repository, max_candidates: DEFAULT_MAX_CANDIDATES, min_score: DEFAULT_MIN_SCORE }
+    }
+
+    /// Override the minimum score a match needs to be returned.
+    pub fn with_min_score(mut self, min_score: f32) -> Self {
+        self.min_score = min_score;
+        self
+    }
+
+    /// Override how many cached elements are scored per search.
+    pub fn with_max_candidates(mut self, max_candidates: usize) -> Self {
+        self.max_candidates = max_candidates;
+        self
+    }
+
+    /// Best matches for `query`, highest score first, at most `limit`.
+    ///
+    /// # Errors
+    /// Returns an error if the repository cannot load the cached elements.
+    pub fn search(&self, query: &str, limit: usize) -> Result<Vec<WbsSearchHit>> {
+        if query.trim().is_empty() || limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let candidates = self.repository.load_common_projects(self.max_candidates)?;
+        Ok(rank_wbs_elements(candidates, query, limit, self.min_score))
+    }
+}
+
+/// Score `elements` against `query` and return the top `limit` matches at or
+/// above `min_score`, ordered by score then WBS code.
+pub fn rank_wbs_elements(
+    elements: impl IntoIterator<Item = WbsElement>,
+    query: &str,
+    limit: usize,
+    min_score: f32,
+) -> Vec<WbsSearchHit> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() || limit == 0 {
+        return Vec::new();
+    }
+    let query_words = words(&query);
+
+    let mut hits: Vec<WbsSearchHit> = elements
+        .into_iter()
+        .filter_map(|element| {
+            let score = score_element(&element, &query, &query_words);
+            (score >= min_score).then_some(WbsSearchHit { element, score })
+        })
+        .collect();
+
+    hits.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.element.wbs_code.cmp(&b.element.wbs_code))
+    });
+    hits.truncate(limit);
+    hits
+}
+
+/// Similarity of `element` to the lowercased `query` in `0.0..=1.0`.
+fn score_element(element: &WbsElement, query: &str, query_words: &[String]) -> f32 {
+    let codes =
+        [Some(&element.wbs_code), Some(&element.project_def), element.project_code.as_ref()];
+    let code_score = codes
+        .into_iter()
+        .flatten()
+        .map(|code| code_similarity(&code.to_lowercase(), query))
+        .fold(0.0, f32::max);
+
+    let texts = [
+        element.description.as_ref(),
+        element.project_name.as_ref(),
+        element.deal_name.as_ref(),
+        element.target_company_name.as_ref(),
+    ];
+    let text_score = texts
+        .into_iter()
+        .flatten()
+        .map(|text| text_similarity(&words(&text.to_lowercase()), query_words) * TEXT_WEIGHT)
+        .fold(0.0, f32::max);
+
+    code_score.max(text_score)
+}
+
+fn code_similarity(code: &str, query: &str) -> f32 {
+    if code.is_empty() {
+        return 0.0;
+    }
+    if code == query {
+        return 1.0;
+    }
+    let coverage = query.chars().count() as f32 / code.chars().count() as f32;
+    if code.starts_with(query) {
+        return 0.8 + 0.15 * coverage;
+    }
+    if code.contains(query) {
+        return 0.6 + 0.1 * coverage;
+    }
+    dice_coefficient(code, query) * FUZZY_CODE_WEIGHT
+}
+
+/// Average over query words of the best-matching field word.
+fn text_similarity(field_words: &[String], query_words: &[String]) -> f32 {
+    if field_words.is_empty() || query_words.is_empty() {
+        return 0.0;
+    }
+    let total: f32 = query_words
+        .iter()
+        .map(|query_word| {
+            field_words.iter().map(|word| word_similarity(word, query_word)).fold(0.0, f32::max)
+        })
+        .sum();
+    total / query_words.len() as f32
+}
+
+fn word_similarity(word: &str, query_word: &str) -> f32 {
+    if word == query_word {
+        1.0
+    } else if word.starts_with(query_word) {
+        WORD_PREFIX_SCORE
+    } else {
+        dice_coefficient(word, query_word)
+    }
+}
+
+/// Sørensen–Dice coefficient over character bigrams.
+fn dice_coefficient(a: &str, b: &str) -> f32 {
+    let a = bigrams(a);
+    let mut b = bigrams(b);
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let total = a.len() + b.len();
+    let mut shared = 0;
+    for pair in a {
+        if let Some(pos) = b.iter().position(|other| *other == pair) {
+            b.swap_remove(pos);
+            shared += 1;
+        }
+    }
+    (2 * shared) as f32 / total as f32
+}
+
+fn bigrams(text: &str) -> Vec<(char, char)> {
+    let chars: Vec<char> = text.chars().collect();
+    chars.windows(2).map(|pair| (pair[0], pair[1])).collect()
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn element(wbs_code: &str, project_name: &str, description: &str) -> WbsElement {
+        WbsElement {
+            wbs_code: wbs_code.to_string(),
+            project_def: wbs_code.split('.').next().unwrap_or(wbs_code).to_string(),
+            project_name: Some(project_name.to_string()),
+            description: Some(description.to_string()),
+            status: "REL".to_string(),
+            cached_at: 1_700_000_000,
+            opportunity_id: None,
+            deal_name: None,
+            target_company_name: None,
+            counterparty: None,
+            industry: None,
+            region: None,
+            amount: None,
+            stage_name: None,
+            project_code: None,
+        }
+    }
+
+    fn sample() -> Vec<WbsElement> {
+        vec![
+            element("USC0063201.1.1", "Project Astro", "Cloud migration workstream"),
+            element("USC0063201.1.2", "Project Astro", "Financial due diligence"),
+            element("USC0063300.1.1", "Internal", "Practice development and training"),
+        ]
+    }
+
+    struct StaticRepository(Vec<WbsElement>);
+
+    impl WbsRepository for StaticRepository {
+        fn count_active_wbs(&self) -> Result<i64> {
+            Ok(self.0.len() as i64)
+        }
+
+        fn get_last_sync_timestamp(&self) -> Result<Option<i64>> {
+            Ok(None)
+        }
+
+        fn load_common_projects(&self, limit: usize) -> Result<Vec<WbsElement>> {
+            Ok(self.0.iter().take(limit).cloned().collect())
+        }
+
+        fn fts5_search_keyword(&self, _keyword: &str, _limit: usize) -> Result<Vec<WbsElement>> {
+            Ok(Vec::new())
+        }
+
+        fn get_wbs_by_project_def(&self, _project_def: &str) -> Result<Option<WbsElement>> {
+            Ok(None)
+        }
+
+        fn get_wbs_by_wbs_code(&self, _wbs_code: &str) -> Result<Option<WbsElement>> {
+            Ok(None)
+        }
+    }
+
+    #[test]
+    fn test_partial_description_ranks_expected_element_above_non_matches() {
+        let hits = rank_wbs_elements(sample(), "migrat cloud", 10, DEFAULT_MIN_SCORE);
+
+        assert!(!hits.is_empty());
+        assert_eq!(hits[0].element.wbs_code, "USC0063201.1.1");
+        assert!(hits.iter().all(|hit| hit.element.wbs_code != "USC0063300.1.1"));
+        assert!(hits.iter().skip(1).all(|hit| hit.score < hits[0].score));
+    }
+
+    #[test]
+    fn test_description_query_tolerates_typos() {
+        let hits = rank_wbs_elements(sample(), "diligance", 10, DEFAULT_MIN_SCORE);
+
+        assert_eq!(hits[0].element.wbs_code, "USC0063201.1.2");
+    }
+
+    #[test]
+    fn test_exact_code_match_ranks_first() {
+        let hits = rank_wbs_elements(sample(), "usc0063201.1.2", 10, DEFAULT_MIN_SCORE);
+
+        assert_eq!(hits[0].element.wbs_code, "USC0063201.1.2");
+        assert_eq!(hits[0].score, 1.0);
+        assert!(hits[1].score < 1.0);
+    }
+
+    #[test]
+    fn test_code_prefix_is_boosted() {
+        let hits = rank_wbs_elements(sample(), "USC00632", 10, DEFAULT_MIN_SCORE);
+
+        let codes: Vec<&str> = hits.iter().map(|hit| hit.element.wbs_code.as_str()).collect();
+        assert_eq!(&codes[..2], &["USC0063201.1.1", "USC0063201.1.2"]);
+        assert!(hits[0].score > 0.8);
+    }
+
+    #[test]
+    fn test_search_respects_limit_and_empty_query() {
+        let search = WbsSearch::new(Arc::new(StaticRepository(sample())));
+
+        assert_eq!(search.search("usc", 1).expect("search").len(), 1);
+        assert!(search.search("  ", 10).expect("search").is_empty());
+        assert!(search.search("usc", 0).expect("search").is_empty());
+    }
+}
@@ -0,0 +1,262 @@
+//! Work type classification from app and window signals
+//!
+//! [`WorkTypeClassifier`] maps the active window (bundle id, app name, URL
+//! and window title) to a [`WorkType`] using an ordered rule table. The first
+//! matching rule wins, so more specific signals (bundle ids, hosts) should be
+//! listed before broad title keywords. The default table covers the apps
+//! common in tax/advisory work; callers can replace or extend it.
+//!
+//! Unmatched windows classify as `WorkType::Unknown`.
+
+use pulsearc_domain::types::{ActivityContext, WindowContext, WorkType};
+
+/// Window signal a rule is matched against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkTypeSignal {
+    /// Exact bundle id (case-insensitive)
+    BundleId,
+    /// Substring of the app name (case-insensitive)
+    AppName,
+    /// Substring of the URL host, falling back to the full URL
+    Url,
+    /// Substring of the window title (case-insensitive)
+    WindowTitle,
+}
+
+/// A single rule: when `signal` matches `pattern`, the window is `work_type`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkTypeRule {
+    pub signal: WorkTypeSignal,
+    pub pattern: String,
+    pub work_type: WorkType,
+}
+
+impl WorkTypeRule {
+    /// Create a rule; `pattern` is stored lowercased
+    pub fn new(signal: WorkTypeSignal, pattern: &str, work_type: WorkType) -> Self {
+        Self { signal, pattern: pattern.trim().to_lowercase(), work_type }
+    }
+
+    fn matches(&self, window: &WindowContext) -> bool {
+        if self.pattern.is_empty() {
+            return false;
+        }
+
+        match self.signal {
+            WorkTypeSignal::BundleId => window
+                .bundle_id
+                .as_deref()
+                .is_some_and(|bundle_id| bundle_id.trim().eq_ignore_ascii_case(&self.pattern)),
+            WorkTypeSignal::AppName => contains(&window.app_name, &self.pattern),
+            WorkTypeSignal::Url => window
+                .url_host
+                .as_deref()
+                .or(window.url.as_deref())
+                .is_some_and(|url| contains(url, &self.pattern)),
+            WorkTypeSignal::WindowTitle => contains(&window.window_title, &self.pattern),
+        }
+    }
+}
+
+/// Maps window signals to a [`WorkType`] via an ordered rule table
+#[derive(Debug, Clone)]
+pub struct WorkTypeClassifier {
+    rules: Vec<WorkTypeRule>,
+}
+
+impl Default for WorkTypeClassifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorkTypeClassifier {
+    /// Create a classifier with the default rule table
+    pub fn new() -> Self {
+        Self { rules: default_rules() }
+    }
+
+    /// Create a classifier with a custom rule table (evaluated in order)
+    pub fn with_rules(rules: Vec<WorkTypeRule>) -> Self {
+        Self { rules }
+    }
+
+    /// Add a rule that takes precedence over the existing table
+    pub fn prepend_rule(&mut self, rule: WorkTypeRule) {
+        self.rules.insert(0, rule);
+    }
+
+    /// Rules in evaluation order
+    pub fn rules(&self) -> &[WorkTypeRule] {
+        &self.rules
+    }
+
+    /// Classify a window, returning `WorkType::Unknown` when no rule matches
+    pub fn classify(&self, window: &WindowContext) -> WorkType {
+        self.rules
+            .iter()
+            .find(|rule| rule.matches(window))
+            .map_or(WorkType::Unknown, |rule| rule.work_type.clone())
+    }
+
+    /// Set `context.work_type` from its active window
+    pub fn apply(&self, context: &mut ActivityContext) {
+        context.work_type = Some(self.classify(&context.active_app));
+    }
+}
+
+fn contains(haystack: &str, needle: &str) -> bool {
+    haystack.to_lowercase().contains(needle)
+}
+
+/// Signal, pattern and work type of a built-in rule
+type DefaultRule = (WorkTypeSignal, &'static str, WorkType);
+
+fn default_rules() -> Vec<WorkTypeRule> {
+    use WorkTypeSignal::{AppName, BundleId, Url, WindowTitle};
+
+    let table: &[DefaultRule] = &[
+        // Bundle ids
+        (BundleId, "com.microsoft.excel", WorkType::Modeling),
+        (BundleId, "com.apple.iwork.numbers", WorkType::Modeling),
+        (BundleId, "com.bloomberg.terminal", WorkType::Research),
+        (BundleId, "us.zoom.xos", WorkType::Meeting),
+        (BundleId, "com.microsoft.teams", WorkType::Meeting),
+        (BundleId, "com.microsoft.teams2", WorkType::Meeting),
+        (BundleId, "com.cisco.webexmeetingsapp", WorkType::Meeting),
+        (BundleId, "com.microsoft.outlook", WorkType::Email),
+        (BundleId, "com.apple.mail", WorkType::Email),
+        (BundleId, "com.adobe.acrobat.pro", WorkType::DocReview),
+        (BundleId, "com.adobe.reader", WorkType::DocReview),
+        (BundleId, "com.apple.preview", WorkType::DocReview),
+        (BundleId, "com.microsoft.word", WorkType::Documentation),
+        (BundleId, "com.apple.iwork.pages", WorkType::Documentation),
+        // App names
+        (AppName, "excel", WorkType::Modeling),
+        (AppName, "bloomberg", WorkType::Research),
+        (AppName, "factset", WorkType::Research),
+        (AppName, "capital iq", WorkType::Research),
+        (AppName, "pitchbook", WorkType::Research),
+        (AppName, "zoom", WorkType::Meeting),
+        (AppName, "teams", WorkType::Meeting),
+        (AppName, "webex", WorkType::Meeting),
+        (AppName, "outlook", WorkType::Email),
+        (AppName, "mail", WorkType::Email),
+        (AppName, "imanage", WorkType::DMS),
+        (AppName, "netdocuments", WorkType::DMS),
+        (AppName, "quickbooks", WorkType::AccountingSuite),
+        (AppName, "lacerte", WorkType::AccountingSuite),
+        (AppName, "ultratax", WorkType::AccountingSuite),
+        (AppName, "acrobat", WorkType::DocReview),
+        (AppName, "microsoft word", WorkType::Documentation),
+        // URL hosts
+        (Url, "datasite.com", WorkType::DataRoom),
+        (Url, "intralinks.com", WorkType::DataRoom),
+        (Url, "firmex.com", WorkType::DataRoom),
+        (Url, "sharepoint.com", WorkType::DMS),
+        (Url, "netdocuments.com", WorkType::DMS),
+        (Url, "imanage.work", WorkType::DMS),
+        (Url, "factset.com", WorkType::Research),
+        (Url, "pitchbook.com", WorkType::Research),
+        (Url, "capitaliq.com", WorkType::Research),
+        (Url, "zoom.us", WorkType::Meeting),
+        (Url, "meet.google.com", WorkType::Meeting),
+        (Url, "teams.microsoft.com", WorkType::Meeting),
+        (Url, "mail.google.com", WorkType::Email),
+        (Url, "outlook.office.com", WorkType::Email),
+        (Url, "qbo.intuit.com", WorkType::AccountingSuite),
+        (Url, "docs.google.com", WorkType::Documentation),
+        // Window titles
+        (WindowTitle, ".xlsx", WorkType::Modeling),
+        (WindowTitle, ".pdf", WorkType::DocReview),
+        (WindowTitle, ".docx", WorkType::Documentation),
+    ];
+
+    table
+        .iter()
+        .map(|(signal, pattern, work_type)| WorkTypeRule::new(*signal, pattern, work_type.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(
+        app_name: &str,
+        bundle_id: Option<&str>,
+        title: &str,
+        url: Option<&str>,
+    ) -> WindowContext {
+        WindowContext {
+            app_name: app_name.to_string(),
+            window_title: title.to_string(),
+            bundle_id: bundle_id.map(str::to_string),
+            url: url.map(str::to_string),
+            url_host: None,
+            document_name: None,
+            file_path: None,
+        }
+    }
+
+    #[test]
+    fn maps_known_apps_to_work_types() {
+        let classifier = WorkTypeClassifier::new();
+
+        let cases = [
+            (
+                window("Microsoft Excel", Some("com.microsoft.Excel"), "Model.xlsx", None),
+                WorkType::Modeling,
+            ),
+            (window("Bloomberg", None, "BBG Terminal", None), WorkType::Research),
+            (window("zoom.us", Some("us.zoom.xos"), "Zoom Meeting", None), WorkType::Meeting),
+            (
+                window("Microsoft Outlook", Some("com.microsoft.Outlook"), "Inbox", None),
+                WorkType::Email,
+            ),
+        ];
+
+        for (window, expected) in cases {
+            assert_eq!(classifier.classify(&window), expected, "app {}", window.app_name);
+        }
+    }
+
+    #[test]
+    fn maps_browser_urls_and_titles() {
+        let classifier = WorkTypeClassifier::new();
+
+        let data_room = window(
+            "Google Chrome",
+            Some("com.google.Chrome"),
+            "Project Astro - VDR",
+            Some("https://app.datasite.com/projects/astro"),
+        );
+        let pdf = window("Google Chrome", Some("com.google.Chrome"), "SPA_draft.pdf", None);
+
+        assert_eq!(classifier.classify(&data_room), WorkType::DataRoom);
+        assert_eq!(classifier.classify(&pdf), WorkType::DocReview);
+    }
+
+    #[test]
+    fn unknown_app_returns_unknown() {
+        let classifier = WorkTypeClassifier::new();
+
+        let window = window("Finder", Some("com.apple.finder"), "Downloads", None);
+
+        assert_eq!(classifier.classify(&window), WorkType::Unknown);
+    }
+
+    #[test]
+    fn custom_rules_take_precedence() {
+        let mut classifier = WorkTypeClassifier::new();
+        classifier.prepend_rule(WorkTypeRule::new(
+            WorkTypeSignal::WindowTitle,
+            "Tax Memo",
+            WorkType::Documentation,
+        ));
+
+        let window = window("Microsoft Excel", None, "Tax memo support.xlsx", None);
+
+        assert_eq!(classifier.classify(&window), WorkType::Documentation);
+    }
+}
@@ -16,8 +16,12 @@
 //! }
 //! ```
 
+use std::path::Path;
+
 use async_trait::async_trait;
-use pulsearc_domain::types::{DatabaseSize, HealthStatus, TableStats};
+use pulsearc_domain::types::{
+    DatabaseSize, FragmentationReport, HealthStatus, IntegrityReport, RecoveryReport, TableStats,
+};
 use pulsearc_domain::Result;
 
 /// Port for database statistics and maintenance operations.
@@ -29,7 +33,7 @@ pub trait DatabaseStatsPort: Send + Sync {
     /// Get database size information.
     ///
     /// Queries PRAGMA introspection to gather page counts and sizes,
-    /// plus filesystem metadata for total file size.
+    /// plus filesystem metadata for total file size and write-ahead log size.
     ///
     /// # Example
     ///
@@ -39,6 +43,7 @@ pub trait DatabaseStatsPort: Send + Sync {
     /// let size = db_stats.get_database_size().await.unwrap();
     /// println!("Database: {} MB", size.size_bytes / 1024 / 1024);
     /// println!("Free pages: {}", size.freelist_count);
+    /// println!("WAL: {} KB", size.wal_size_bytes / 1024);
     /// # }
     /// ```
     async fn get_database_size(&self) -> Result<DatabaseSize>;
@@ -79,9 +84,11 @@ pub trait DatabaseStatsPort: Send + Sync {
 
     /// Run VACUUM to reclaim unused space.
     ///
-    /// Rebuilds the database file to remove fragmentation and unused pages.
-    /// This is a safe but potentially slow operation (locks database during
-    /// execution). Should be run during maintenance windows.
+    /// Rebuilds the database file to remove fragmentation and unused pages,
+    /// switching it to `auto_vacuum = INCREMENTAL` so later maintenance can use
+    /// [`incremental_vacuum`](Self::incremental_vacuum). This is a safe but
+    /// potentially slow operation (locks database during execution). Should be
+    /// run during maintenance windows.
     ///
     /// # Example
     ///
@@ -94,6 +101,78 @@ pub trait DatabaseStatsPort: Send + Sync {
     /// ```
     async fn vacuum_database(&self) -> Result<()>;
 
+    /// Report free pages and fragmentation, with a vacuum recommendation.
+    ///
+    /// The recommendation uses
+    /// [`DEFAULT_VACUUM_THRESHOLD`](pulsearc_domain::DEFAULT_VACUUM_THRESHOLD);
+    /// callers with their own threshold can use
+    /// [`FragmentationReport::recommendation_for`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use pulsearc_core::DatabaseStatsPort;
+    /// # async fn example(db_stats: &impl DatabaseStatsPort) {
+    /// let report = db_stats.fragmentation_report().await.unwrap();
+    /// println!("{:.0}% free: {}", report.fragmentation_ratio * 100.0, report.summary);
+    /// # }
+    /// ```
+    async fn fragmentation_report(&self) -> Result<FragmentationReport>;
+
+    /// Reclaim up to `max_pages` free pages with `PRAGMA incremental_vacuum`.
+    ///
+    /// Returns the number of pages reclaimed. Only has an effect when the
+    /// database uses `auto_vacuum = INCREMENTAL`; otherwise returns 0 and a
+    /// full [`vacuum_database`](Self::vacuum_database) is needed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use pulsearc_core::DatabaseStatsPort;
+    /// # async fn example(db_stats: &impl DatabaseStatsPort) {
+    /// let reclaimed = db_stats.incremental_vacuum(1_000).await.unwrap();
+    /// println!("Reclaimed {} pages", reclaimed);
+    /// # }
+    /// ```
+    async fn incremental_vacuum(&self, max_pages: u64) -> Result<u64>;
+
+    /// Run `PRAGMA integrity_check` and `PRAGMA foreign_key_check`.
+    ///
+    /// Reads every page, so it can take a while on large databases. A report
+    /// with `ok == false` means corruption is likely; see
+    /// [`recover_to`](Self::recover_to).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use pulsearc_core::DatabaseStatsPort;
+    /// # async fn example(db_stats: &impl DatabaseStatsPort) {
+    /// let report = db_stats.integrity_check().await.unwrap();
+    /// if !report.ok {
+    ///     eprintln!("Integrity check failed: {}", report.summary);
+    /// }
+    /// # }
+    /// ```
+    async fn integrity_check(&self) -> Result<IntegrityReport>;
+
+    /// Copy everything still readable into a new database at `target`.
+    ///
+    /// Best effort: the schema is recreated and each table copied on its own,
+    /// so one damaged table does not stop the others. The new database uses
+    /// the same encryption key. Fails if `target` already exists.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use std::path::Path;
+    /// # use pulsearc_core::DatabaseStatsPort;
+    /// # async fn example(db_stats: &impl DatabaseStatsPort) {
+    /// let report = db_stats.recover_to(Path::new("recovered.db")).await.unwrap();
+    /// println!("Recovered {} rows", report.rows_recovered);
+    /// # }
+    /// ```
+    async fn recover_to(&self, target: &Path) -> Result<RecoveryReport>;
+
     /// Check database health with a simple connectivity test.
     ///
     /// Executes a trivial query to verify database is responsive.
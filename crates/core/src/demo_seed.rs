@@ -0,0 +1,538 @@
+//! Demo data seeding for QA builds (`demo-seed` feature)
+//!
+//! Generates a deterministic set of snapshots, segments and proposed blocks
+//! for a date range and writes them through the regular repository ports.
+//! Every seeded row is clearly labeled as test data:
+//!
+//! - IDs start with [`DEMO_SEED_ID_PREFIX`]
+//! - Snapshots carry the [`DEMO_SEED_BATCH_ID`] batch id
+//! - Blocks use the `demo-seed` classifier and say so in their reasons
+//!
+//! The ID prefix is what [`DemoSeedRepository::clear_seeded_data`] keys on, so
+//! clearing never touches real activity.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use pulsearc_domain::types::classification::{ActivityBreakdown, ProposedBlock};
+use pulsearc_domain::{ActivitySegment, ActivitySnapshot, PulseArcError, Result};
+use serde::Serialize;
+
+use crate::classification::ports::BlockRepository;
+use crate::tracking::ports::{SegmentRepository, SnapshotRepository};
+
+/// Prefix of every seeded row ID
+pub const DEMO_SEED_ID_PREFIX: &str = "demo-seed-";
+
+/// Batch ID and classifier name attached to seeded rows
+pub const DEMO_SEED_BATCH_ID: &str = "demo-seed";
+
+/// Longest date range that can be seeded in one call (days)
+pub const MAX_DEMO_SEED_DAYS: i64 = 31;
+
+/// Seeded work days start at 09:00 UTC
+const DAY_START_SECS: i64 = 9 * 3600;
+
+/// Gap between consecutive seeded blocks (seconds)
+const BLOCK_GAP_SECS: i64 = 15 * 60;
+
+/// Reason attached to every seeded block
+const DEMO_SEED_REASON: &str = "Demo seed data (not real activity)";
+
+/// Work pattern a seeded block is generated from
+struct BlockTemplate {
+    app: &'static str,
+    bundle_id: &'static str,
+    window_title: &'static str,
+    activity_category: &'static str,
+    duration_secs: i64,
+    project: Option<DemoProject>,
+}
+
+struct DemoProject {
+    project_id: &'static str,
+    wbs_code: &'static str,
+    deal_name: &'static str,
+    workstream: &'static str,
+}
+
+const ATLAS_MODELING: DemoProject = DemoProject {
+    project_id: "DEMO-PRJ-001",
+    wbs_code: "DEMO-PRJ-001.1",
+    deal_name: "Demo Project Atlas",
+    workstream: "modeling",
+};
+
+const BOREALIS_DILIGENCE: DemoProject = DemoProject {
+    project_id: "DEMO-PRJ-002",
+    wbs_code: "DEMO-PRJ-002.1",
+    deal_name: "Demo Project Borealis",
+    workstream: "due_diligence",
+};
+
+const TEMPLATES: [BlockTemplate; 6] = [
+    BlockTemplate {
+        app: "Microsoft Excel",
+        bundle_id: "com.microsoft.Excel",
+        window_title: "Atlas Valuation Model.xlsx",
+        activity_category: "client_work",
+        duration_secs: 5400,
+        project: Some(ATLAS_MODELING),
+    },
+    BlockTemplate {
+        app: "Zoom",
+        bundle_id: "us.zoom.xos",
+        window_title: "Atlas Client Sync - Zoom Meeting",
+        activity_category: "meeting",
+        duration_secs: 1800,
+        project: Some(ATLAS_MODELING),
+    },
+    BlockTemplate {
+        app: "Google Chrome",
+        bundle_id: "com.google.Chrome",
+        window_title: "Borealis Data Room",
+        activity_category: "client_work",
+        duration_secs: 3600,
+        project: Some(BOREALIS_DILIGENCE),
+    },
+    BlockTemplate {
+        app: "Microsoft Word",
+        bundle_id: "com.microsoft.Word",
+        window_title: "Borealis Diligence Memo.docx",
+        activity_category: "documentation",
+        duration_secs: 2700,
+        project: Some(BOREALIS_DILIGENCE),
+    },
+    BlockTemplate {
+        app: "Slack",
+        bundle_id: "com.tinyspeck.slackmacgap",
+        window_title: "#general - Slack",
+        activity_category: "communication",
+        duration_secs: 900,
+        project: None,
+    },
+    BlockTemplate {
+        app: "Microsoft PowerPoint",
+        bundle_id: "com.microsoft.Powerpoint",
+        window_title: "Atlas Board Deck.pptx",
+        activity_category: "client_work",
+        duration_secs: 2400,
+        project: Some(ATLAS_MODELING),
+    },
+];
+
+/// Options controlling the seeded dataset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DemoSeedConfig {
+    /// First day to seed
+    pub start_date: NaiveDate,
+    /// Last day to seed (inclusive)
+    pub end_date: NaiveDate,
+    /// Blocks generated per seeded day (1..=6)
+    pub blocks_per_day: usize,
+    /// Spacing between seeded snapshots (seconds, at least 30)
+    pub snapshot_interval_secs: i64,
+    /// Also seed Saturdays and Sundays
+    pub include_weekends: bool,
+}
+
+impl DemoSeedConfig {
+    /// Seed every weekday in `start_date..=end_date` with the default pattern
+    pub fn new(start_date: NaiveDate, end_date: NaiveDate) -> Self {
+        Self {
+            start_date,
+            end_date,
+            blocks_per_day: TEMPLATES.len(),
+            snapshot_interval_secs: 300,
+            include_weekends: false,
+        }
+    }
+
+    /// Validate the options.
+    ///
+    /// # Errors
+    /// Returns `PulseArcError::InvalidInput` if the range is inverted or longer
+    /// than [`MAX_DEMO_SEED_DAYS`], or if the block count or snapshot interval
+    /// is out of range.
+    pub fn validate(&self) -> Result<()> {
+        let days = (self.end_date - self.start_date).num_days() + 1;
+        if days < 1 {
+            return Err(PulseArcError::InvalidInput(format!(
+                "end_date ({}) is before start_date ({})",
+                self.end_date, self.start_date
+            )));
+        }
+        if days > MAX_DEMO_SEED_DAYS {
+            return Err(PulseArcError::InvalidInput(format!(
+                "cannot seed {days} days (max {MAX_DEMO_SEED_DAYS})"
+            )));
+        }
+        if self.blocks_per_day == 0 || self.blocks_per_day > TEMPLATES.len() {
+            return Err(PulseArcError::InvalidInput(format!(
+                "blocks_per_day must be between 1 and {}",
+                TEMPLATES.len()
+            )));
+        }
+        if self.snapshot_interval_secs < 30 {
+            return Err(PulseArcError::InvalidInput(
+                "snapshot_interval_secs must be at least 30".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Rows making up a seeded dataset
+#[derive(Debug, Clone, Default)]
+pub struct DemoDataset {
+    /// Seeded activity snapshots
+    pub snapshots: Vec<ActivitySnapshot>,
+    /// Seeded activity segments (one per block)
+    pub segments: Vec<ActivitySegment>,
+    /// Seeded proposed blocks
+    pub blocks: Vec<ProposedBlock>,
+}
+
+impl DemoDataset {
+    /// Row counts of this dataset
+    pub fn summary(&self) -> DemoSeedSummary {
+        DemoSeedSummary {
+            snapshots: self.snapshots.len(),
+            segments: self.segments.len(),
+            blocks: self.blocks.len(),
+        }
+    }
+}
+
+/// Number of seeded rows written or removed, per table
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DemoSeedSummary {
+    /// Activity snapshots
+    pub snapshots: usize,
+    /// Activity segments
+    pub segments: usize,
+    /// Proposed blocks
+    pub blocks: usize,
+}
+
+/// Port removing seeded rows from storage
+#[async_trait]
+pub trait DemoSeedRepository: Send + Sync {
+    /// Delete every row whose ID starts with [`DEMO_SEED_ID_PREFIX`]
+    async fn clear_seeded_data(&self) -> Result<DemoSeedSummary>;
+}
+
+/// Generate the dataset described by `config`.
+///
+/// Output depends only on `config`: the same options always produce the same
+/// IDs, timestamps and content.
+///
+/// # Errors
+/// Returns `PulseArcError::InvalidInput` if the config is invalid.
+pub fn generate_demo_dataset(config: &DemoSeedConfig) -> Result<DemoDataset> {
+    config.validate()?;
+
+    let mut dataset = DemoDataset::default();
+    let mut date = config.start_date;
+
+    while date <= config.end_date {
+        let weekend = matches!(date.weekday(), Weekday::Sat | Weekday::Sun);
+        if config.include_weekends || !weekend {
+            seed_day(&mut dataset, config, date, weekend);
+        }
+        date += Duration::days(1);
+    }
+
+    Ok(dataset)
+}
+
+fn seed_day(dataset: &mut DemoDataset, config: &DemoSeedConfig, date: NaiveDate, weekend: bool) {
+    let day_start = date.and_hms_opt(0, 0, 0).map(|dt| dt.and_utc().timestamp()).unwrap_or(0);
+    let mut cursor = day_start + DAY_START_SECS;
+
+    for (index, template) in TEMPLATES.iter().take(config.blocks_per_day).enumerate() {
+        let start_ts = cursor;
+        let end_ts = start_ts + template.duration_secs;
+        let key = format!("{date}-{index}");
+
+        let snapshots: Vec<ActivitySnapshot> = (start_ts..end_ts)
+            .step_by(usize::try_from(config.snapshot_interval_secs).unwrap_or(300))
+            .enumerate()
+            .map(|(n, timestamp)| demo_snapshot(template, &format!("{key}-{n}"), timestamp))
+            .collect();
+        let snapshot_ids: Vec<String> = snapshots.iter().map(|s| s.id.clone()).collect();
+
+        let segment = demo_segment(template, &key, start_ts, end_ts, snapshot_ids.clone());
+        let block = demo_block(template, &key, &segment, snapshot_ids, weekend);
+
+        dataset.snapshots.extend(snapshots);
+        dataset.segments.push(segment);
+        dataset.blocks.push(block);
+
+        cursor = end_ts + BLOCK_GAP_SECS;
+    }
+}
+
+fn demo_snapshot(template: &BlockTemplate, key: &str, timestamp: i64) -> ActivitySnapshot {
+    let activity_context = serde_json::json!({
+        "active_app": {
+            "app_name": template.app,
+            "window_title": template.window_title,
+            "bundle_id": template.bundle_id,
+        },
+        "recent_apps": [],
+        "detected_activity": template.window_title,
+    });
+
+    ActivitySnapshot {
+        id: format!("{DEMO_SEED_ID_PREFIX}snap-{key}"),
+        timestamp,
+        activity_context_json: activity_context.to_string(),
+        detected_activity: template.window_title.to_string(),
+        work_type: None,
+        activity_category: Some(template.activity_category.to_string()),
+        primary_app: template.app.to_string(),
+        processed: true,
+        batch_id: Some(DEMO_SEED_BATCH_ID.to_string()),
+        created_at: timestamp,
+        processed_at: Some(timestamp),
+        is_idle: false,
+        idle_duration_secs: None,
+        content_hash: None,
+    }
+}
+
+fn demo_segment(
+    template: &BlockTemplate,
+    key: &str,
+    start_ts: i64,
+    end_ts: i64,
+    snapshot_ids: Vec<String>,
+) -> ActivitySegment {
+    ActivitySegment {
+        id: format!("{DEMO_SEED_ID_PREFIX}seg-{key}"),
+        start_ts,
+        end_ts,
+        primary_app: template.app.to_string(),
+        normalized_label: template.app.to_lowercase(),
+        sample_count: i32::try_from(snapshot_ids.len()).unwrap_or(i32::MAX),
+        dictionary_keys: None,
+        created_at: end_ts,
+        processed: true,
+        snapshot_ids,
+        work_type: None,
+        activity_category: template.activity_category.to_string(),
+        detected_activity: template.window_title.to_string(),
+        extracted_signals_json: None,
+        project_match_json: None,
+        idle_time_secs: 0,
+        active_time_secs: i32::try_from(end_ts - start_ts).unwrap_or(i32::MAX),
+        user_action: None,
+    }
+}
+
+fn demo_block(
+    template: &BlockTemplate,
+    key: &str,
+    segment: &ActivitySegment,
+    snapshot_ids: Vec<String>,
+    weekend: bool,
+) -> ProposedBlock {
+    let (start_ts, end_ts) = (segment.start_ts, segment.end_ts);
+    let duration_secs = end_ts - start_ts;
+    let project = template.project.as_ref();
+
+    ProposedBlock {
+        id: format!("{DEMO_SEED_ID_PREFIX}block-{key}"),
+        start_ts,
+        end_ts,
+        duration_secs,
+        inferred_project_id: project.map(|p| p.project_id.to_string()),
+        inferred_wbs_code: project.map(|p| p.wbs_code.to_string()),
+        inferred_deal_name: project.map(|p| p.deal_name.to_string()),
+        inferred_workstream: project.map(|p| p.workstream.to_string()),
+        billable: project.is_some(),
+        confidence: if project.is_some() { 0.85 } else { 0.6 },
+        classifier_used: Some(DEMO_SEED_BATCH_ID.to_string()),
+        activities: vec![ActivityBreakdown {
+            name: template.app.to_string(),
+            duration_secs,
+            percentage: 100.0,
+        }],
+        snapshot_ids,
+        segment_ids: vec![segment.id.clone()],
+        reasons: vec![DEMO_SEED_REASON.to_string()],
+        status: "suggested".to_string(),
+        created_at: end_ts,
+        reviewed_at: None,
+        total_idle_secs: 0,
+        idle_handling: "exclude".to_string(),
+        timezone: Some("UTC".to_string()),
+        work_location: None,
+        is_travel: false,
+        is_weekend: weekend,
+        is_after_hours: false,
+        has_calendar_overlap: false,
+        overlapping_event_ids: Vec::new(),
+        is_double_booked: false,
+    }
+}
+
+/// Writes and removes demo datasets through the regular repository ports
+pub struct DemoSeedService {
+    snapshots: Arc<dyn SnapshotRepository>,
+    segments: Arc<dyn SegmentRepository>,
+    blocks: Arc<dyn BlockRepository>,
+    seeded: Arc<dyn DemoSeedRepository>,
+}
+
+impl DemoSeedService {
+    /// Create a seeding service
+    pub fn new(
+        snapshots: Arc<dyn SnapshotRepository>,
+        segments: Arc<dyn SegmentRepository>,
+        blocks: Arc<dyn BlockRepository>,
+        seeded: Arc<dyn DemoSeedRepository>,
+    ) -> Self {
+        Self { snapshots, segments, blocks, seeded }
+    }
+
+    /// Generate and store the dataset described by `config`.
+    ///
+    /// Seeding is idempotent: rows are upserted by ID, so seeding the same
+    /// range twice does not duplicate data.
+    ///
+    /// # Errors
+    /// Returns `PulseArcError::InvalidInput` for an invalid config, or the
+    /// storage error of the first failed write.
+    pub async fn seed(&self, config: &DemoSeedConfig) -> Result<DemoSeedSummary> {
+        let dataset = generate_demo_dataset(config)?;
+        let summary = dataset.summary();
+        let DemoDataset { snapshots, segments, blocks } = dataset;
+
+        let snapshot_repo = Arc::clone(&self.snapshots);
+        let segment_repo = Arc::clone(&self.segments);
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            snapshot_repo.store_snapshots_batch(&snapshots).map_err(map_common_error)?;
+            for segment in &segments {
+                segment_repo.save_segment(segment).map_err(map_common_error)?;
+            }
+            Ok(())
+        })
+        .await
+        .map_err(|err| PulseArcError::Internal(format!("Task join error: {err}")))??;
+
+        for block in &blocks {
+            self.blocks.save_proposed_block(block).await?;
+        }
+
+        tracing::info!(
+            snapshots = summary.snapshots,
+            segments = summary.segments,
+            blocks = summary.blocks,
+            "seeded demo data"
+        );
+        Ok(summary)
+    }
+
+    /// Remove every seeded row, leaving real activity untouched
+    pub async fn clear(&self) -> Result<DemoSeedSummary> {
+        let summary = self.seeded.clear_seeded_data().await?;
+        tracing::info!(
+            snapshots = summary.snapshots,
+            segments = summary.segments,
+            blocks = summary.blocks,
+            "cleared seeded demo data"
+        );
+        Ok(summary)
+    }
+}
+
+fn map_common_error(err: pulsearc_common::error::CommonError) -> PulseArcError {
+    PulseArcError::Database(err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        // 2025-03-03 is a Monday
+        NaiveDate::from_ymd_opt(2025, 3, day).expect("valid date")
+    }
+
+    #[test]
+    fn generates_deterministic_labeled_dataset() {
+        let config = DemoSeedConfig::new(date(3), date(4));
+
+        let first = generate_demo_dataset(&config).expect("dataset");
+        let second = generate_demo_dataset(&config).expect("dataset");
+
+        assert_eq!(first.summary(), second.summary());
+        assert_eq!(first.summary().blocks, 2 * TEMPLATES.len());
+        assert_eq!(first.summary().segments, first.summary().blocks);
+        assert!(first
+            .snapshots
+            .iter()
+            .zip(&second.snapshots)
+            .all(|(a, b)| a.id == b.id && a.timestamp == b.timestamp));
+
+        assert!(first.snapshots.iter().all(|s| s.id.starts_with(DEMO_SEED_ID_PREFIX)
+            && s.batch_id.as_deref() == Some(DEMO_SEED_BATCH_ID)));
+        assert!(first.segments.iter().all(|s| s.id.starts_with(DEMO_SEED_ID_PREFIX)));
+        assert!(first.blocks.iter().all(|b| b.id.starts_with(DEMO_SEED_ID_PREFIX)
+            && b.reasons.iter().any(|r| r == DEMO_SEED_REASON)));
+    }
+
+    #[test]
+    fn blocks_stay_within_range_and_reference_their_rows() {
+        let mut config = DemoSeedConfig::new(date(3), date(3));
+        config.blocks_per_day = 2;
+        config.snapshot_interval_secs = 600;
+
+        let dataset = generate_demo_dataset(&config).expect("dataset");
+        let day_start = date(3).and_hms_opt(0, 0, 0).expect("midnight").and_utc().timestamp();
+
+        assert_eq!(dataset.blocks.len(), 2);
+        assert_eq!(dataset.blocks[0].start_ts, day_start + DAY_START_SECS);
+        assert_eq!(dataset.blocks[1].start_ts, dataset.blocks[0].end_ts + BLOCK_GAP_SECS);
+        // 5400s / 600s + 1800s / 600s
+        assert_eq!(dataset.snapshots.len(), 9 + 3);
+        assert_eq!(dataset.blocks[0].segment_ids, vec![dataset.segments[0].id.clone()]);
+        assert_eq!(dataset.blocks[0].snapshot_ids.len(), 9);
+    }
+
+    #[test]
+    fn skips_weekends_unless_requested() {
+        // Friday through Monday
+        let mut config = DemoSeedConfig::new(date(7), date(10));
+        config.blocks_per_day = 1;
+
+        let weekdays = generate_demo_dataset(&config).expect("dataset");
+        assert_eq!(weekdays.blocks.len(), 2);
+        assert!(weekdays.blocks.iter().all(|b| !b.is_weekend));
+
+        config.include_weekends = true;
+        let all_days = generate_demo_dataset(&config).expect("dataset");
+        assert_eq!(all_days.blocks.len(), 4);
+        assert_eq!(all_days.blocks.iter().filter(|b| b.is_weekend).count(), 2);
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        assert!(DemoSeedConfig::new(date(4), date(3)).validate().is_err());
+        assert!(DemoSeedConfig::new(date(1), date(1) + Duration::days(MAX_DEMO_SEED_DAYS))
+            .validate()
+            .is_err());
+
+        let mut config = DemoSeedConfig::new(date(3), date(3));
+        config.blocks_per_day = TEMPLATES.len() + 1;
+        assert!(config.validate().is_err());
+
+        config.blocks_per_day = 1;
+        config.snapshot_interval_secs = 10;
+        assert!(config.validate().is_err());
+    }
+}
@@ -4,6 +4,10 @@
 //! and quick rollback of Phase 4 command rewiring. Flags persist across app
 //! restarts, suitable for macOS GUI applications.
 //!
+//! A flag may declare dependencies on other flags (e.g. `sap_batch_retry`
+//! requires `sap`). A flag whose dependencies are not all enabled evaluates
+//! as disabled, and the evaluation names the unmet dependency.
+//!
 //! # Example
 //!
 //! ```no_run
@@ -14,8 +18,11 @@
 //! }
 //! ```
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use pulsearc_domain::Result;
+use serde::Serialize;
 
 /// Feature flag data transfer object.
 #[derive(Debug, Clone)]
@@ -28,6 +35,8 @@ pub struct FeatureFlag {
     pub description: Option<String>,
     /// Timestamp when the flag was last modified (Unix epoch seconds)
     pub updated_at: i64,
+    /// Flags that must also be enabled for this flag to take effect
+    pub requires: Vec<String>,
 }
 
 /// Result of evaluating a feature flag.
@@ -35,12 +44,16 @@ pub struct FeatureFlag {
 /// Provides both the effective enabled state and whether the value came from
 /// the caller-provided default (meaning the flag is currently missing from the
 /// backing store).
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct FeatureFlagEvaluation {
     /// Whether the flag should be considered enabled.
     pub enabled: bool,
     /// Whether the returned value originated from the supplied fallback.
     pub fallback_used: bool,
+    /// Dependency that is disabled, forcing this flag off.
+    ///
+    /// `None` when the flag is enabled or is switched off itself.
+    pub unmet_dependency: Option<String>,
 }
 
 /// Port for querying and managing feature flags.
@@ -53,7 +66,9 @@ pub trait FeatureFlagsPort: Send + Sync {
     ///
     /// Returns [`FeatureFlagEvaluation`] containing both the effective enabled
     /// value and whether the caller-provided default was returned because the
-    /// flag is missing from storage.
+    /// flag is missing from storage. An enabled flag evaluates as disabled if
+    /// any of its dependencies (transitively) is disabled or missing, with the
+    /// dependency reported in `unmet_dependency`.
     ///
     /// # Arguments
     /// * `flag_name` - The unique identifier for the feature flag
@@ -88,6 +103,36 @@ pub trait FeatureFlagsPort: Send + Sync {
         self.evaluate(flag_name, default).await.map(|evaluation| evaluation.enabled)
     }
 
+    /// Evaluate several feature flags at once.
+    ///
+    /// Flags missing from storage evaluate as disabled with `fallback_used`
+    /// set, so callers can apply their own default. Duplicate names are
+    /// evaluated once. The default implementation evaluates flags one at a
+    /// time; implementations should override it to evaluate the batch in a
+    /// single pass.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use pulsearc_core::FeatureFlagsPort;
+    /// # async fn example(flags: &impl FeatureFlagsPort) {
+    /// let names = vec!["new_blocks_cmd".to_string(), "use_new_infra".to_string()];
+    /// let evaluations = flags.evaluate_feature_flags(&names).await.unwrap();
+    /// # }
+    /// ```
+    async fn evaluate_feature_flags(
+        &self,
+        flag_names: &[String],
+    ) -> Result<HashMap<String, FeatureFlagEvaluation>> {
+        let mut evaluations = HashMap::with_capacity(flag_names.len());
+        for flag_name in flag_names {
+            if !evaluations.contains_key(flag_name) {
+                evaluations.insert(flag_name.clone(), self.evaluate(flag_name, false).await?);
+            }
+        }
+        Ok(evaluations)
+    }
+
     /// Set a feature flag's enabled status.
     ///
     /// Creates the flag if it doesn't exist (upsert semantics).
@@ -108,6 +153,26 @@ pub trait FeatureFlagsPort: Send + Sync {
     /// ```
     async fn set_enabled(&self, flag_name: &str, enabled: bool) -> Result<()>;
 
+    /// Declare the flags that `flag_name` requires.
+    ///
+    /// Replaces any previously declared dependencies; an empty slice removes
+    /// them. Dependencies may name flags that do not exist yet (they count as
+    /// disabled until created).
+    ///
+    /// # Errors
+    /// Returns `PulseArcError::InvalidInput` if the declaration would create a
+    /// dependency cycle (including a flag requiring itself).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use pulsearc_core::FeatureFlagsPort;
+    /// # async fn example(flags: &impl FeatureFlagsPort) {
+    /// flags.set_dependencies("sap_batch_retry", &["sap".to_string()]).await.unwrap();
+    /// # }
+    /// ```
+    async fn set_dependencies(&self, flag_name: &str, requires: &[String]) -> Result<()>;
+
     /// List all feature flags ordered by name.
     ///
     /// Returns all flags currently in the database, including their current
@@ -125,4 +190,12 @@ pub trait FeatureFlagsPort: Send + Sync {
     /// # }
     /// ```
     async fn list_all(&self) -> Result<Vec<FeatureFlag>>;
+
+    /// Drop any cached evaluations after flags were changed in storage by
+    /// something other than this port (e.g. a settings reset).
+    ///
+    /// Uncached implementations need not override this.
+    async fn refresh(&self) -> Result<()> {
+        Ok(())
+    }
 }
@@ -32,10 +32,14 @@ pub mod calendar_ports;
 #[cfg(feature = "sap")]
 pub mod sap_ports;
 
+#[cfg(feature = "demo-seed")]
+pub mod demo_seed;
+
 // Re-export specific items to avoid ambiguity
 pub use batch::ports::{BatchRepository, DlqRepository};
 pub use classification::ports::{
-    BlockRepository, Classifier, ProjectMatcher, TimeEntryRepository, WbsRepository,
+    BlockRepository, Classifier, ProjectMatcher, SuggestionDismissalRepository,
+    TimeEntryRepository, WbsRepository,
 };
 pub use classification::ClassificationService;
 pub use command_metrics_ports::{CommandMetric, CommandMetricsPort, CommandStats};
@@ -46,7 +50,8 @@ pub use tracking::ports::{
     ActivityEnricher, ActivityProvider, ActivityRepository, CalendarEventRepository,
     SegmentRepository, SnapshotRepository,
 };
-pub use tracking::TrackingService;
-pub use user::ports::UserProfileRepository;
+pub use tracking::{SnapshotRetentionPolicy, TrackingService};
+pub use user::ports::{UserProfileRepository, UserSettingsRepository};
+pub use user::WorkdaySchedule;
 // Re-export utilities
 pub use utils::patterns;
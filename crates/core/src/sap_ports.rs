@@ -24,4 +24,8 @@ pub trait SapClient: Send + Sync {
 
     /// Validate a WBS code
     async fn validate_wbs(&self, wbs_code: &str) -> Result<bool>;
+
+    /// Check whether the SAP posting period containing `date` (YYYY-MM-DD)
+    /// accepts time entries
+    async fn is_period_open(&self, date: &str) -> Result<bool>;
 }
@@ -10,6 +10,10 @@ pub trait OutboxQueue: Send + Sync {
     async fn enqueue(&self, entry: &TimeEntryOutbox) -> Result<()>;
 
     /// Dequeue a batch of entries for processing
+    ///
+    /// An entry is never returned ahead of an earlier unsent entry for the
+    /// same entity (target and payload `id`), including one waiting out its
+    /// retry window or dead-lettered.
     async fn dequeue_batch(&self, limit: usize) -> Result<Vec<TimeEntryOutbox>>;
 
     /// Mark an entry as successfully sent
@@ -0,0 +1,301 @@
+//! Event-driven activity capture on app activation
+//!
+//! Polling misses app switches shorter than the poll period and keeps waking
+//! up while nothing changes. [`ActivationCapture`] instead captures when the
+//! OS reports that another app was activated:
+//! - Activations arrive on a channel fed by the platform listener (e.g.
+//!   `NSWorkspace.didActivateApplicationNotification` on macOS)
+//! - A burst of activations (Cmd-Tab through several apps) is debounced into
+//!   a single capture of the app the user settled on
+//! - No capture is made while tracking is paused
+//!
+//! It can run next to [`TrackingPoller`](super::TrackingPoller), which then
+//! only has to catch changes within an app (e.g. a new window title).
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+use tracing::debug;
+
+use super::poller::poll_once;
+use super::service::TrackingService;
+
+/// Default quiet period after an activation before capturing
+pub const DEFAULT_ACTIVATION_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Notification that the frontmost application changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AppActivation {
+    /// When the notification was received
+    pub observed_at: DateTime<Utc>,
+}
+
+impl AppActivation {
+    /// Activation observed now
+    pub fn now() -> Self {
+        Self { observed_at: Utc::now() }
+    }
+}
+
+/// Captures activity whenever the active application changes
+pub struct ActivationCapture {
+    service: Arc<TrackingService>,
+    activations: mpsc::UnboundedReceiver<AppActivation>,
+    debounce: Duration,
+    cancel: CancellationToken,
+}
+
+impl ActivationCapture {
+    /// Create a capture loop fed by `activations`, using
+    /// [`DEFAULT_ACTIVATION_DEBOUNCE`].
+    pub fn new(
+        service: Arc<TrackingService>,
+        activations: mpsc::UnboundedReceiver<AppActivation>,
+        cancel: CancellationToken,
+    ) -> Self {
+        Self { service, activations, debounce: DEFAULT_ACTIVATION_DEBOUNCE, cancel }
+    }
+
+    /// Wait for `debounce` without further activations before capturing
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Capture on activations until cancelled or the listener goes away.
+    ///
+    /// An activation still being debounced when the channel closes is
+    /// captured before returning.
+    pub async fn run(mut self) {
+        loop {
+            tokio::select! {
+                _ = self.cancel.cancelled() => break,
+                activation = self.activations.recv() => {
+                    if activation.is_none() {
+                        break;
+                    }
+                }
+            }
+
+            let (coalesced, open) = tokio::select! {
+                _ = self.cancel.cancelled() => break,
+                settled = settle(&mut self.activations, self.debounce) => settled,
+            };
+            debug!(coalesced, "App activation settled; capturing activity");
+
+            tokio::select! {
+                _ = self.cancel.cancelled() => break,
+                _ = poll_once(&self.service) => {}
+            }
+
+            if !open {
+                break;
+            }
+        }
+
+        debug!("App activation capture loop exited");
+    }
+}
+
+/// Absorb activations until none arrives for `debounce`.
+///
+/// Returns how many extra activations were absorbed and whether the channel
+/// is still open.
+async fn settle(
+    activations: &mut mpsc::UnboundedReceiver<AppActivation>,
+    debounce: Duration,
+) -> (usize, bool) {
+    let mut coalesced = 0;
+    loop {
+        match tokio::time::timeout(debounce, activations.recv()).await {
+            Ok(Some(_)) => coalesced += 1,
+            Ok(None) => return (coalesced, false),
+            Err(_) => return (coalesced, true),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use async_trait::async_trait;
+    use pulsearc_domain::types::database::ActivitySnapshot;
+    use pulsearc_domain::types::WindowContext;
+    use pulsearc_domain::{ActivityContext, Result};
+
+    use super::*;
+    use crate::tracking::dedup::SnapshotRun;
+    use crate::tracking::ports::{ActivityProvider, ActivityRepository};
+    use crate::tracking::retention::SnapshotStorageUsage;
+
+    const DEBOUNCE: Duration = Duration::from_millis(250);
+
+    /// Provider counting how often activity was captured
+    struct CountingProvider(Arc<AtomicUsize>);
+
+    #[async_trait]
+    impl ActivityProvider for CountingProvider {
+        async fn get_activity(&self) -> Result<ActivityContext> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(ActivityContext {
+                active_app: WindowContext {
+                    app_name: "Safari".to_string(),
+                    window_title: "Docs".to_string(),
+                    bundle_id: None,
+                    url: None,
+                    url_host: None,
+                    document_name: None,
+                    file_path: None,
+                },
+                recent_apps: vec![],
+                detected_activity: "browsing".to_string(),
+                work_type: None,
+                activity_category: Default::default(),
+                billable_confidence: 0.0,
+                suggested_client: None,
+                suggested_matter: None,
+                suggested_task_code: None,
+                extracted_metadata: Default::default(),
+                evidence: Default::default(),
+                calendar_event: None,
+                location: None,
+                temporal_context: None,
+                classification: None,
+                capture_mode: Default::default(),
+            })
+        }
+
+        fn is_paused(&self) -> bool {
+            false
+        }
+
+        fn pause(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn resume(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    struct NullRepository;
+
+    #[async_trait]
+    impl ActivityRepository for NullRepository {
+        async fn save_snapshot(&self, _snapshot: ActivitySnapshot) -> Result<()> {
+            Ok(())
+        }
+
+        async fn get_snapshots(
+            &self,
+            _start: DateTime<Utc>,
+            _end: DateTime<Utc>,
+        ) -> Result<Vec<ActivitySnapshot>> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_old_snapshots(&self, _before: DateTime<Utc>) -> Result<usize> {
+            Ok(0)
+        }
+
+        async fn snapshot_storage_usage(&self) -> Result<SnapshotStorageUsage> {
+            Ok(SnapshotStorageUsage::default())
+        }
+
+        async fn evict_synced_snapshots(&self, _limit: usize) -> Result<usize> {
+            Ok(0)
+        }
+
+        async fn extend_snapshot(&self, _snapshot_id: &str, _run: SnapshotRun) -> Result<bool> {
+            Ok(false)
+        }
+
+        async fn snapshot_run(&self, _snapshot_id: &str) -> Result<Option<SnapshotRun>> {
+            Ok(None)
+        }
+    }
+
+    /// Capture loop fed by a mocked notification source
+    struct Harness {
+        captures: Arc<AtomicUsize>,
+        activations: mpsc::UnboundedSender<AppActivation>,
+        cancel: CancellationToken,
+        handle: tokio::task::JoinHandle<()>,
+    }
+
+    impl Harness {
+        fn start() -> Self {
+            let captures = Arc::new(AtomicUsize::new(0));
+            let service = Arc::new(
+                TrackingService::new(
+                    CountingProvider(Arc::clone(&captures)),
+                    Arc::new(NullRepository),
+                )
+                .with_persistence(false),
+            );
+            let (activations, receiver) = mpsc::unbounded_channel();
+            let cancel = CancellationToken::new();
+            let capture =
+                ActivationCapture::new(service, receiver, cancel.clone()).with_debounce(DEBOUNCE);
+            let handle = tokio::spawn(capture.run());
+            Self { captures, activations, cancel, handle }
+        }
+
+        fn activate(&self) {
+            self.activations.send(AppActivation::now()).unwrap();
+        }
+
+        fn captures(&self) -> usize {
+            self.captures.load(Ordering::SeqCst)
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn activation_triggers_a_capture() {
+        let harness = Harness::start();
+        tokio::time::sleep(DEBOUNCE * 4).await;
+        assert_eq!(harness.captures(), 0, "no capture without an activation");
+
+        harness.activate();
+        tokio::time::sleep(DEBOUNCE * 2).await;
+        assert_eq!(harness.captures(), 1);
+
+        harness.activate();
+        tokio::time::sleep(DEBOUNCE * 2).await;
+        assert_eq!(harness.captures(), 2);
+
+        harness.cancel.cancel();
+        harness.handle.await.unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn rapid_activations_are_debounced() {
+        let harness = Harness::start();
+
+        for _ in 0..5 {
+            harness.activate();
+            tokio::time::sleep(DEBOUNCE / 5).await;
+        }
+        assert_eq!(harness.captures(), 0, "still switching apps");
+
+        tokio::time::sleep(DEBOUNCE * 2).await;
+        assert_eq!(harness.captures(), 1, "one capture once the user settles");
+
+        harness.cancel.cancel();
+        harness.handle.await.unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn closed_source_flushes_pending_activation_and_exits() {
+        let harness = Harness::start();
+        harness.activate();
+        drop(harness.activations);
+
+        harness.handle.await.unwrap();
+        assert_eq!(harness.captures.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,424 @@
+//! Consecutive snapshot deduplication
+//!
+//! Staring at the same window for ten minutes would otherwise store a
+//! near-identical snapshot on every poll. With deduplication enabled,
+//! [`TrackingService`](super::TrackingService) keeps the first snapshot of a
+//! run as its representative and extends that snapshot's [`SnapshotRun`]
+//! (poll count and duration) for every identical poll that follows.
+//!
+//! - Polls are compared by the snapshot content hash (app name, bundle id,
+//!   window title and URL); enrichment metadata does not break a run.
+//! - A poll only extends the run if it arrives within `max_gap_secs` of the
+//!   previous one, so a sleep/wake gap starts a new snapshot.
+//! - Each poll accounts for `poll_interval_secs`, so a run of N polls carries
+//!   the same duration as N separate snapshots would.
+//! - Runs are only extended while the representative is unprocessed; once it
+//!   has been synced (or evicted) the next poll stores a new snapshot.
+
+use pulsearc_domain::{ActivityContext, ActivitySnapshot, TrackingConfig};
+
+/// Duration accounted to a coalesced snapshot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotRun {
+    /// Identical polls represented by the snapshot (including the first)
+    pub poll_count: u32,
+    /// Summed duration of those polls in seconds
+    pub duration_secs: i64,
+    /// Timestamp of the most recent poll in the run
+    pub last_seen_at: i64,
+}
+
+/// Deduplication settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotDedupConfig {
+    /// Seconds each poll accounts for
+    pub poll_interval_secs: u64,
+    /// Largest gap between polls that still extends a run
+    pub max_gap_secs: u64,
+}
+
+impl SnapshotDedupConfig {
+    /// Settings for the configured snapshot interval, tolerating one missed
+    /// poll before a run is broken.
+    pub fn from_tracking_config(config: &TrackingConfig) -> Self {
+        let interval = config.snapshot_interval_seconds.max(1);
+        Self { poll_interval_secs: interval, max_gap_secs: interval.saturating_mul(2) }
+    }
+}
+
+/// Hash of the fields that identify a poll for deduplication
+///
+/// Same value as the stored [`ActivitySnapshot::content_hash`].
+pub fn content_hash(context: &ActivityContext) -> String {
+    ActivitySnapshot::compute_content_hash(context)
+}
+
+/// Collapse runs of identical consecutive snapshots in an in-memory batch
+///
+/// Applies the polling rules to snapshots that are already captured: each
+/// entry of the result is the first snapshot of a run paired with that run.
+/// Snapshots without a content hash never coalesce.
+pub fn coalesce_snapshots(
+    config: SnapshotDedupConfig,
+    snapshots: &[ActivitySnapshot],
+) -> Vec<(&ActivitySnapshot, SnapshotRun)> {
+    let mut dedup = SnapshotDeduplicator::new(config);
+    let mut runs: Vec<(&ActivitySnapshot, SnapshotRun)> = Vec::new();
+
+    for snapshot in snapshots {
+        let extension = snapshot
+            .content_hash
+            .as_deref()
+            .and_then(|hash| dedup.extension(hash, snapshot.timestamp));
+        if let (Some((_, run)), Some(last)) = (extension, runs.last_mut()) {
+            dedup.commit_extension(run);
+            last.1 = run;
+            continue;
+        }
+
+        match &snapshot.content_hash {
+            Some(hash) => dedup.start_run(snapshot.id.clone(), hash.clone(), snapshot.timestamp),
+            None => dedup.reset(),
+        }
+        let run = SnapshotRun {
+            poll_count: 1,
+            duration_secs: dedup.poll_secs(),
+            last_seen_at: snapshot.timestamp,
+        };
+        runs.push((snapshot, run));
+    }
+
+    runs
+}
+
+/// Last stored snapshot and its run
+#[derive(Debug, Clone)]
+struct RunState {
+    snapshot_id: String,
+    hash: String,
+    run: SnapshotRun,
+}
+
+/// Tracks the current run of identical polls
+#[derive(Debug)]
+pub(crate) struct SnapshotDeduplicator {
+    config: SnapshotDedupConfig,
+    current: Option<RunState>,
+}
+
+impl SnapshotDeduplicator {
+    pub(crate) fn new(config: SnapshotDedupConfig) -> Self {
+        Self { config, current: None }
+    }
+
+    /// Run the poll would extend, if it continues the current one.
+    ///
+    /// Returns the representative snapshot id and the extended run; the state
+    /// only changes once the caller confirms with [`Self::commit_extension`].
+    pub(crate) fn extension(&self, hash: &str, timestamp: i64) -> Option<(String, SnapshotRun)> {
+        let current = self.current.as_ref()?;
+        let gap = timestamp.saturating_sub(current.run.last_seen_at);
+        let max_gap = i64::try_from(self.config.max_gap_secs).unwrap_or(i64::MAX);
+        if current.hash != hash || !(0..=max_gap).contains(&gap) {
+            return None;
+        }
+
+        let run = SnapshotRun {
+            poll_count: current.run.poll_count.saturating_add(1),
+            duration_secs: current.run.duration_secs.saturating_add(self.poll_secs()),
+            last_seen_at: timestamp,
+        };
+        Some((current.snapshot_id.clone(), run))
+    }
+
+    /// Record that the current run was extended.
+    pub(crate) fn commit_extension(&mut self, run: SnapshotRun) {
+        if let Some(current) = self.current.as_mut() {
+            current.run = run;
+        }
+    }
+
+    /// Start a new run at a freshly stored snapshot.
+    pub(crate) fn start_run(&mut self, snapshot_id: String, hash: String, timestamp: i64) {
+        let run =
+            SnapshotRun { poll_count: 1, duration_secs: self.poll_secs(), last_seen_at: timestamp };
+        self.current = Some(RunState { snapshot_id, hash, run });
+    }
+
+    /// Forget the current run so the next poll stores a new snapshot.
+    pub(crate) fn reset(&mut self) {
+        self.current = None;
+    }
+
+    fn poll_secs(&self) -> i64 {
+        i64::try_from(self.config.poll_interval_secs).unwrap_or(i64::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use pulsearc_domain::types::WindowContext;
+    use pulsearc_domain::{ActivitySnapshot, Result};
+
+    use super::*;
+    use crate::tracking::ports::{ActivityProvider, ActivityRepository};
+    use crate::tracking::retention::SnapshotStorageUsage;
+    use crate::tracking::TrackingService;
+
+    const INTERVAL: u64 = 30;
+
+    fn config() -> SnapshotDedupConfig {
+        SnapshotDedupConfig { poll_interval_secs: INTERVAL, max_gap_secs: 2 * INTERVAL }
+    }
+
+    fn context(app_name: &str, window_title: &str) -> ActivityContext {
+        ActivityContext {
+            active_app: WindowContext {
+                app_name: app_name.to_string(),
+                window_title: window_title.to_string(),
+                bundle_id: None,
+                url: None,
+                url_host: None,
+                document_name: None,
+                file_path: None,
+            },
+            recent_apps: vec![],
+            detected_activity: "working".to_string(),
+            work_type: None,
+            activity_category: Default::default(),
+            billable_confidence: 0.0,
+            suggested_client: None,
+            suggested_matter: None,
+            suggested_task_code: None,
+            extracted_metadata: Default::default(),
+            evidence: Default::default(),
+            calendar_event: None,
+            location: None,
+            temporal_context: None,
+            classification: None,
+            capture_mode: Default::default(),
+        }
+    }
+
+    /// Provider replaying a fixed sequence of contexts
+    struct ScriptedProvider {
+        contexts: Mutex<Vec<ActivityContext>>,
+    }
+
+    impl ScriptedProvider {
+        fn new(mut contexts: Vec<ActivityContext>) -> Self {
+            contexts.reverse();
+            Self { contexts: Mutex::new(contexts) }
+        }
+    }
+
+    #[async_trait]
+    impl ActivityProvider for ScriptedProvider {
+        async fn get_activity(&self) -> Result<ActivityContext> {
+            self.contexts.lock().unwrap().pop().ok_or_else(|| {
+                pulsearc_domain::PulseArcError::Internal("script exhausted".to_string())
+            })
+        }
+
+        fn is_paused(&self) -> bool {
+            false
+        }
+
+        fn pause(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn resume(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Run recorded for a snapshot id
+    type RecordedRun = (String, SnapshotRun);
+
+    /// Repository keeping stored snapshots and runs in memory
+    #[derive(Default)]
+    struct InMemoryRepository {
+        snapshots: Mutex<Vec<ActivitySnapshot>>,
+        runs: Mutex<Vec<RecordedRun>>,
+    }
+
+    impl InMemoryRepository {
+        fn run(&self, snapshot_id: &str) -> Option<SnapshotRun> {
+            self.runs.lock().unwrap().iter().find(|(id, _)| id == snapshot_id).map(|(_, run)| *run)
+        }
+    }
+
+    #[async_trait]
+    impl ActivityRepository for InMemoryRepository {
+        async fn save_snapshot(&self, snapshot: ActivitySnapshot) -> Result<()> {
+            self.snapshots.lock().unwrap().push(snapshot);
+            Ok(())
+        }
+
+        async fn get_snapshots(
+            &self,
+            _start: DateTime<Utc>,
+            _end: DateTime<Utc>,
+        ) -> Result<Vec<ActivitySnapshot>> {
+            Ok(self.snapshots.lock().unwrap().clone())
+        }
+
+        async fn delete_old_snapshots(&self, _before: DateTime<Utc>) -> Result<usize> {
+            Ok(0)
+        }
+
+        async fn snapshot_storage_usage(&self) -> Result<SnapshotStorageUsage> {
+            Ok(SnapshotStorageUsage::default())
+        }
+
+        async fn evict_synced_snapshots(&self, _limit: usize) -> Result<usize> {
+            Ok(0)
+        }
+
+        async fn extend_snapshot(&self, snapshot_id: &str, run: SnapshotRun) -> Result<bool> {
+            let mut runs = self.runs.lock().unwrap();
+            runs.retain(|(id, _)| id != snapshot_id);
+            runs.push((snapshot_id.to_string(), run));
+            Ok(true)
+        }
+
+        async fn snapshot_run(&self, snapshot_id: &str) -> Result<Option<SnapshotRun>> {
+            Ok(self.run(snapshot_id))
+        }
+    }
+
+    fn service(contexts: Vec<ActivityContext>) -> (TrackingService, Arc<InMemoryRepository>) {
+        let repository = Arc::new(InMemoryRepository::default());
+        let service = TrackingService::new(ScriptedProvider::new(contexts), repository.clone())
+            .with_deduplication(config());
+        (service, repository)
+    }
+
+    #[test]
+    fn identical_polls_extend_the_run() {
+        let mut dedup = SnapshotDeduplicator::new(config());
+        let hash = content_hash(&context("Xcode", "main.rs"));
+
+        assert!(dedup.extension(&hash, 100).is_none());
+        dedup.start_run("snap-1".to_string(), hash.clone(), 100);
+
+        for (i, timestamp) in [130, 160, 190].into_iter().enumerate() {
+            let (id, run) = dedup.extension(&hash, timestamp).expect("run continues");
+            assert_eq!(id, "snap-1");
+            assert_eq!(run.poll_count, i as u32 + 2);
+            dedup.commit_extension(run);
+        }
+
+        let (_, run) = dedup.extension(&hash, 220).expect("run continues");
+        assert_eq!(run, SnapshotRun { poll_count: 5, duration_secs: 150, last_seen_at: 220 });
+    }
+
+    #[test]
+    fn context_change_or_gap_breaks_the_run() {
+        let mut dedup = SnapshotDeduplicator::new(config());
+        let editing = content_hash(&context("Xcode", "main.rs"));
+        let browsing = content_hash(&context("Safari", "Docs"));
+        dedup.start_run("snap-1".to_string(), editing.clone(), 100);
+
+        assert!(dedup.extension(&browsing, 130).is_none(), "different window");
+        assert!(dedup.extension(&editing, 100 + 2 * INTERVAL as i64 + 1).is_none(), "gap too long");
+        assert!(dedup.extension(&editing, 99).is_none(), "clock went backwards");
+        assert!(dedup.extension(&editing, 130).is_some());
+
+        dedup.reset();
+        assert!(dedup.extension(&editing, 130).is_none());
+    }
+
+    #[test]
+    fn coalesce_keeps_the_first_snapshot_of_each_run() {
+        let editing = Some(content_hash(&context("Xcode", "main.rs")));
+        let browsing = Some(content_hash(&context("Safari", "Docs")));
+        let snapshot = |id: &str, hash: &Option<String>, timestamp: i64| ActivitySnapshot {
+            id: id.to_string(),
+            timestamp,
+            activity_context_json: "{}".to_string(),
+            detected_activity: "working".to_string(),
+            work_type: None,
+            activity_category: None,
+            primary_app: "Xcode".to_string(),
+            processed: false,
+            batch_id: None,
+            created_at: timestamp,
+            processed_at: None,
+            is_idle: false,
+            idle_duration_secs: None,
+            content_hash: hash.clone(),
+        };
+        let batch = vec![
+            snapshot("a", &editing, 100),
+            snapshot("b", &editing, 130),
+            snapshot("c", &editing, 160),
+            snapshot("d", &browsing, 190),
+            snapshot("e", &None, 220),
+            snapshot("f", &editing, 250),
+            snapshot("g", &editing, 400),
+        ];
+
+        let runs = coalesce_snapshots(config(), &batch);
+
+        let ids: Vec<&str> = runs.iter().map(|(snapshot, _)| snapshot.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "d", "e", "f", "g"]);
+        assert_eq!(runs[0].1, SnapshotRun { poll_count: 3, duration_secs: 90, last_seen_at: 160 });
+        assert!(runs[1..].iter().all(|(_, run)| run.poll_count == 1));
+    }
+
+    #[test]
+    fn hash_ignores_enrichment_fields() {
+        let base = context("Safari", "Docs");
+        let mut enriched = base.clone();
+        enriched.detected_activity = "reading".to_string();
+        enriched.billable_confidence = 0.9;
+        assert_eq!(content_hash(&base), content_hash(&enriched));
+
+        let mut navigated = base.clone();
+        navigated.active_app.url = Some("https://example.com".to_string());
+        assert_ne!(content_hash(&base), content_hash(&navigated));
+    }
+
+    #[tokio::test]
+    async fn identical_polls_store_one_coalesced_snapshot() {
+        let polls = 6;
+        let (service, repository) = service(vec![context("Xcode", "main.rs"); polls]);
+
+        for _ in 0..polls {
+            service.capture_activity().await.expect("capture");
+        }
+
+        let snapshots = repository.snapshots.lock().unwrap().clone();
+        assert_eq!(snapshots.len(), 1);
+        let run = repository.run(&snapshots[0].id).expect("run recorded");
+        assert_eq!(run.poll_count, polls as u32);
+        assert_eq!(run.duration_secs, polls as i64 * INTERVAL as i64);
+    }
+
+    #[tokio::test]
+    async fn context_change_starts_a_new_snapshot() {
+        let (service, repository) = service(vec![
+            context("Xcode", "main.rs"),
+            context("Xcode", "main.rs"),
+            context("Safari", "Docs"),
+            context("Xcode", "main.rs"),
+        ]);
+
+        for _ in 0..4 {
+            service.capture_activity().await.expect("capture");
+        }
+
+        let snapshots = repository.snapshots.lock().unwrap().clone();
+        let apps: Vec<&str> = snapshots.iter().map(|s| s.primary_app.as_str()).collect();
+        assert_eq!(apps, vec!["Xcode", "Safari", "Xcode"]);
+        assert_eq!(repository.run(&snapshots[0].id).map(|run| run.poll_count), Some(2));
+        assert_eq!(repository.run(&snapshots[1].id), None);
+        assert_eq!(repository.run(&snapshots[2].id), None);
+    }
+}
@@ -0,0 +1,201 @@
+//! Minimum dwell before an app counts as active
+//!
+//! Alt-tabbing past a few apps on the way to the one the user wants focuses
+//! each of them briefly. Recording every one of those focuses fills the
+//! timeline with sub-second segments. [`DwellFilter`] holds back captures of
+//! a newly focused app until it has stayed focused for the minimum dwell:
+//!
+//! - A capture after the app has been focused for `min_dwell` is recorded.
+//! - If focus moves on after `min_dwell` but before another capture, the held
+//!   capture is recorded then, so focus changes between polls are not lost.
+//! - A focus that ends before `min_dwell` is discarded.
+//!
+//! Dwell is measured with an injectable [`Clock`], so tests advance a
+//! `MockClock` instead of sleeping.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use pulsearc_common::time::{Clock, SystemClock};
+use pulsearc_domain::TrackingConfig;
+use tracing::debug;
+
+/// Minimum dwell settings for [`DwellFilter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DwellConfig {
+    /// How long an app must stay focused before it is recorded
+    pub min_dwell: Duration,
+}
+
+impl DwellConfig {
+    /// Build the settings from the tracking configuration.
+    ///
+    /// Returns `None` when `min_dwell_ms` is unset or zero.
+    pub fn from_tracking_config(config: &TrackingConfig) -> Option<Self> {
+        config
+            .min_dwell_ms
+            .filter(|&ms| ms > 0)
+            .map(|ms| Self { min_dwell: Duration::from_millis(ms) })
+    }
+}
+
+/// The focused app and the capture held back for it
+struct Focus<T> {
+    app: String,
+    since: Instant,
+    held: Option<T>,
+    recorded: bool,
+}
+
+/// Holds captures back until their app has been focused for the minimum dwell
+///
+/// Generic over the capture so the filter stays independent of how captures
+/// are persisted.
+pub struct DwellFilter<T> {
+    config: DwellConfig,
+    clock: Arc<dyn Clock>,
+    focus: Option<Focus<T>>,
+    discarded: u64,
+}
+
+impl<T> DwellFilter<T> {
+    /// Create a filter on the system clock
+    pub fn new(config: DwellConfig) -> Self {
+        Self::with_clock(config, Arc::new(SystemClock))
+    }
+
+    /// Create a filter on a custom clock (e.g. `MockClock` in tests)
+    pub fn with_clock(config: DwellConfig, clock: Arc<dyn Clock>) -> Self {
+        Self { config, clock, focus: None, discarded: 0 }
+    }
+
+    /// Feed a capture of `app`, returning the capture to record, if any.
+    ///
+    /// The returned capture is either `capture` itself (its app has dwelled
+    /// long enough) or the held capture of the previous app (focus moved on
+    /// after the minimum dwell). Otherwise `capture` is held back.
+    pub fn observe(&mut self, app: &str, capture: T) -> Option<T> {
+        let now = self.clock.now();
+
+        if let Some(focus) = self.focus.as_mut().filter(|focus| focus.app == app) {
+            if focus.recorded || now.duration_since(focus.since) >= self.config.min_dwell {
+                focus.recorded = true;
+                focus.held = None;
+                return Some(capture);
+            }
+            focus.held = Some(capture);
+            return None;
+        }
+
+        let departed = self.focus.take().and_then(|focus| self.depart(focus, now));
+        let recorded = self.config.min_dwell.is_zero();
+        let (held, ready) = if recorded { (None, Some(capture)) } else { (Some(capture), None) };
+        self.focus = Some(Focus { app: app.to_string(), since: now, held, recorded });
+
+        departed.or(ready)
+    }
+
+    /// Number of focuses discarded for ending before the minimum dwell
+    pub fn discarded(&self) -> u64 {
+        self.discarded
+    }
+
+    /// Close `focus`, returning its held capture if it dwelled long enough
+    fn depart(&mut self, focus: Focus<T>, now: Instant) -> Option<T> {
+        let dwell = now.duration_since(focus.since);
+        if focus.recorded || dwell >= self.config.min_dwell {
+            return focus.held;
+        }
+
+        self.discarded += 1;
+        debug!(app = %focus.app, dwell_ms = dwell.as_millis() as u64, "Discarding transient focus");
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pulsearc_common::time::MockClock;
+
+    use super::*;
+
+    const MIN_DWELL: Duration = Duration::from_secs(1);
+
+    fn filter() -> (DwellFilter<&'static str>, MockClock) {
+        let clock = MockClock::new();
+        let config = DwellConfig { min_dwell: MIN_DWELL };
+        (DwellFilter::with_clock(config, Arc::new(clock.clone())), clock)
+    }
+
+    #[test]
+    fn brief_focus_below_min_dwell_is_discarded() {
+        let (mut filter, clock) = filter();
+
+        assert_eq!(filter.observe("Slack", "slack"), None);
+        clock.advance(Duration::from_millis(200));
+        assert_eq!(filter.observe("Xcode", "xcode"), None, "200ms Slack focus is discarded");
+        assert_eq!(filter.discarded(), 1);
+    }
+
+    #[test]
+    fn focus_past_min_dwell_is_recorded() {
+        let (mut filter, clock) = filter();
+
+        assert_eq!(filter.observe("Xcode", "xcode@0s"), None);
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(filter.observe("Xcode", "xcode@5s"), Some("xcode@5s"));
+
+        // Once recorded, later captures of the same focus pass straight through
+        assert_eq!(filter.observe("Xcode", "xcode@5s+"), Some("xcode@5s+"));
+        assert_eq!(filter.discarded(), 0);
+    }
+
+    #[test]
+    fn held_capture_is_recorded_when_focus_moves_on_after_min_dwell() {
+        let (mut filter, clock) = filter();
+
+        assert_eq!(filter.observe("Xcode", "xcode"), None);
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(filter.observe("Slack", "slack"), Some("xcode"));
+
+        clock.advance(Duration::from_millis(200));
+        assert_eq!(filter.observe("Xcode", "xcode again"), None);
+        assert_eq!(filter.discarded(), 1);
+    }
+
+    #[test]
+    fn dwell_is_measured_on_the_injected_clock() {
+        let (mut filter, clock) = filter();
+
+        assert_eq!(filter.observe("Xcode", "xcode"), None);
+        // Real time passes, but the mock clock has not moved
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(filter.observe("Xcode", "xcode"), None);
+
+        clock.advance(MIN_DWELL);
+        assert_eq!(filter.observe("Xcode", "xcode"), Some("xcode"));
+    }
+
+    #[test]
+    fn zero_min_dwell_records_every_capture() {
+        let mut filter = DwellFilter::new(DwellConfig { min_dwell: Duration::ZERO });
+        assert_eq!(filter.observe("Slack", 1), Some(1));
+        assert_eq!(filter.observe("Xcode", 2), Some(2));
+        assert_eq!(filter.discarded(), 0);
+    }
+
+    #[test]
+    fn config_from_tracking_config() {
+        let mut tracking = pulsearc_domain::Config::default().tracking;
+        assert_eq!(DwellConfig::from_tracking_config(&tracking), None);
+
+        tracking.min_dwell_ms = Some(0);
+        assert_eq!(DwellConfig::from_tracking_config(&tracking), None);
+
+        tracking.min_dwell_ms = Some(1_500);
+        assert_eq!(
+            DwellConfig::from_tracking_config(&tracking),
+            Some(DwellConfig { min_dwell: Duration::from_millis(1_500) })
+        );
+    }
+}
@@ -0,0 +1,279 @@
+//! Idle detection with hysteresis
+//!
+//! A single idle threshold makes the tracker flap around the boundary: one
+//! mouse twitch ends an idle period, and the next pause starts a new one.
+//! [`IdleDetector`] separates the two transitions:
+//!
+//! - **Enter idle** once input has been absent for `enter_idle_secs`. The idle
+//!   period starts at the last input, not when the threshold was crossed.
+//! - **Exit idle** only after activity has been sustained for `exit_idle_secs`.
+//!   Input that stops again (no input for `exit_idle_secs`) before that is
+//!   treated as a blip and the idle period continues.
+//!
+//! The detector is a pure state machine fed with samples of "seconds since the
+//! last input" so it can be driven by any activity provider and tested without
+//! a clock.
+
+use pulsearc_domain::{PulseArcError, Result, TrackingConfig};
+
+/// Smallest derived exit threshold (seconds)
+const MIN_DERIVED_EXIT_SECS: u64 = 5;
+
+/// Largest derived exit threshold (seconds)
+const MAX_DERIVED_EXIT_SECS: u64 = 60;
+
+/// Hysteresis thresholds for [`IdleDetector`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdleHysteresisConfig {
+    /// Seconds without input before an idle period starts
+    pub enter_idle_secs: u64,
+    /// Seconds of sustained activity before an idle period ends
+    pub exit_idle_secs: u64,
+}
+
+impl IdleHysteresisConfig {
+    /// Derive hysteresis from a single idle threshold.
+    ///
+    /// The enter threshold is the idle threshold itself; the exit threshold is
+    /// a tenth of it, clamped to 5..=60 seconds (30s for the default 5 minute
+    /// threshold).
+    pub fn from_threshold(threshold_secs: u64) -> Self {
+        let exit_idle_secs =
+            (threshold_secs / 10).clamp(MIN_DERIVED_EXIT_SECS, MAX_DERIVED_EXIT_SECS);
+        Self { enter_idle_secs: threshold_secs, exit_idle_secs }
+    }
+
+    /// Build thresholds from the tracking configuration.
+    ///
+    /// Uses `idle_exit_threshold_seconds` when set, otherwise derives the exit
+    /// threshold from `idle_threshold_seconds`.
+    pub fn from_tracking_config(config: &TrackingConfig) -> Self {
+        let derived = Self::from_threshold(config.idle_threshold_seconds);
+        match config.idle_exit_threshold_seconds {
+            Some(exit_idle_secs) => Self { exit_idle_secs, ..derived },
+            None => derived,
+        }
+    }
+
+    /// Validate the thresholds.
+    ///
+    /// # Errors
+    /// Returns `PulseArcError::InvalidInput` if either threshold is zero or the
+    /// exit threshold is not below the enter threshold.
+    pub fn validate(&self) -> Result<()> {
+        if self.enter_idle_secs == 0 || self.exit_idle_secs == 0 {
+            return Err(PulseArcError::InvalidInput(
+                "idle hysteresis thresholds must be positive".to_string(),
+            ));
+        }
+        if self.exit_idle_secs >= self.enter_idle_secs {
+            return Err(PulseArcError::InvalidInput(format!(
+                "exit_idle_secs ({}) must be below enter_idle_secs ({})",
+                self.exit_idle_secs, self.enter_idle_secs
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Default for IdleHysteresisConfig {
+    fn default() -> Self {
+        Self::from_threshold(300)
+    }
+}
+
+/// Idle/active state tracked by [`IdleDetector`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleState {
+    /// User is active
+    Active,
+    /// User is idle since `since` (Unix epoch seconds)
+    Idle {
+        /// Start of the idle period (last input before going idle)
+        since: i64,
+        /// First input of a not-yet-sustained activity run, if any
+        activity_since: Option<i64>,
+    },
+}
+
+/// Transition emitted by [`IdleDetector::observe`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleTransition {
+    /// An idle period started at `since`
+    Started {
+        /// Start of the idle period (Unix epoch seconds)
+        since: i64,
+    },
+    /// The idle period `[since, until)` ended
+    Ended {
+        /// Start of the idle period (Unix epoch seconds)
+        since: i64,
+        /// First input of the sustained activity that ended it
+        until: i64,
+    },
+}
+
+/// Idle detector with separate enter and exit thresholds
+#[derive(Debug, Clone)]
+pub struct IdleDetector {
+    config: IdleHysteresisConfig,
+    state: IdleState,
+}
+
+impl IdleDetector {
+    /// Create a detector with explicit thresholds.
+    ///
+    /// # Errors
+    /// Returns `PulseArcError::InvalidInput` if the config is invalid.
+    pub fn new(config: IdleHysteresisConfig) -> Result<Self> {
+        config.validate()?;
+        Ok(Self { config, state: IdleState::Active })
+    }
+
+    /// Create a detector with hysteresis derived from a single threshold.
+    ///
+    /// # Errors
+    /// Returns `PulseArcError::InvalidInput` if the threshold is too small to
+    /// derive a valid exit threshold.
+    pub fn from_threshold(threshold_secs: u64) -> Result<Self> {
+        Self::new(IdleHysteresisConfig::from_threshold(threshold_secs))
+    }
+
+    /// Thresholds used by this detector
+    pub fn config(&self) -> &IdleHysteresisConfig {
+        &self.config
+    }
+
+    /// Current state
+    pub fn state(&self) -> IdleState {
+        self.state
+    }
+
+    /// Whether the detector currently considers the user idle
+    pub fn is_idle(&self) -> bool {
+        matches!(self.state, IdleState::Idle { .. })
+    }
+
+    /// Feed a sample taken at `now_ts` reporting `secs_since_input` seconds
+    /// since the last user input. Returns the transition it caused, if any.
+    pub fn observe(&mut self, now_ts: i64, secs_since_input: u64) -> Option<IdleTransition> {
+        let idle_for = i64::try_from(secs_since_input).unwrap_or(i64::MAX);
+        let last_input = now_ts.saturating_sub(idle_for);
+
+        match self.state {
+            IdleState::Active => {
+                if secs_since_input >= self.config.enter_idle_secs {
+                    self.state = IdleState::Idle { since: last_input, activity_since: None };
+                    Some(IdleTransition::Started { since: last_input })
+                } else {
+                    None
+                }
+            }
+            IdleState::Idle { since, activity_since } => {
+                if last_input <= since {
+                    // No input since the idle period started
+                    return None;
+                }
+
+                if secs_since_input >= self.config.exit_idle_secs {
+                    // Input stopped again before it was sustained: a blip
+                    self.state = IdleState::Idle { since, activity_since: None };
+                    return None;
+                }
+
+                let run_start = activity_since.unwrap_or(last_input);
+                let sustained = u64::try_from(now_ts.saturating_sub(run_start)).unwrap_or(0);
+                if sustained >= self.config.exit_idle_secs {
+                    self.state = IdleState::Active;
+                    Some(IdleTransition::Ended { since, until: run_start })
+                } else {
+                    self.state = IdleState::Idle { since, activity_since: Some(run_start) };
+                    None
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const T0: i64 = 1_700_000_000;
+
+    fn detector() -> IdleDetector {
+        IdleDetector::new(IdleHysteresisConfig { enter_idle_secs: 300, exit_idle_secs: 30 })
+            .expect("valid config")
+    }
+
+    #[test]
+    fn derives_exit_threshold_from_single_threshold() {
+        assert_eq!(
+            IdleHysteresisConfig::from_threshold(300),
+            IdleHysteresisConfig { enter_idle_secs: 300, exit_idle_secs: 30 }
+        );
+        assert_eq!(IdleHysteresisConfig::from_threshold(20).exit_idle_secs, 5);
+        assert_eq!(IdleHysteresisConfig::from_threshold(3_600).exit_idle_secs, 60);
+        assert!(IdleDetector::from_threshold(5).is_err());
+    }
+
+    #[test]
+    fn brief_pause_does_not_start_idle() {
+        let mut detector = detector();
+        assert_eq!(detector.observe(T0, 10), None);
+        assert_eq!(detector.observe(T0 + 250, 260), None);
+        assert!(!detector.is_idle());
+
+        assert_eq!(
+            detector.observe(T0 + 300, 310),
+            Some(IdleTransition::Started { since: T0 - 10 })
+        );
+    }
+
+    #[test]
+    fn activity_blip_does_not_end_idle() {
+        let mut detector = detector();
+        assert_eq!(detector.observe(T0 + 300, 300), Some(IdleTransition::Started { since: T0 }));
+
+        // Mouse twitch at T0 + 400, seen 5 seconds later
+        assert_eq!(detector.observe(T0 + 405, 5), None);
+        assert!(detector.is_idle());
+
+        // No further input: the twitch is discarded
+        assert_eq!(detector.observe(T0 + 440, 40), None);
+        assert_eq!(detector.state(), IdleState::Idle { since: T0, activity_since: None });
+    }
+
+    #[test]
+    fn sustained_activity_ends_idle_at_first_input() {
+        let mut detector = detector();
+        detector.observe(T0 + 300, 300);
+
+        assert_eq!(detector.observe(T0 + 405, 5), None);
+        assert_eq!(detector.observe(T0 + 420, 2), None);
+        assert_eq!(
+            detector.observe(T0 + 436, 1),
+            Some(IdleTransition::Ended { since: T0, until: T0 + 400 })
+        );
+        assert!(!detector.is_idle());
+    }
+
+    #[test]
+    fn tracking_config_overrides_exit_threshold() {
+        let mut tracking = pulsearc_domain::Config::default().tracking;
+        assert_eq!(
+            IdleHysteresisConfig::from_tracking_config(&tracking),
+            IdleHysteresisConfig::default()
+        );
+
+        tracking.idle_exit_threshold_seconds = Some(90);
+        let config = IdleHysteresisConfig::from_tracking_config(&tracking);
+        assert_eq!(config, IdleHysteresisConfig { enter_idle_secs: 300, exit_idle_secs: 90 });
+    }
+
+    #[test]
+    fn rejects_exit_threshold_not_below_enter() {
+        let config = IdleHysteresisConfig { enter_idle_secs: 60, exit_idle_secs: 60 };
+        assert!(IdleDetector::new(config).is_err());
+    }
+}
@@ -0,0 +1,286 @@
+//! Attribution of short idle periods to the surrounding activity
+//!
+//! Short idles (a coffee refill, a bio break) between two segments of the same
+//! work context are usually part of that work. [`IdleAttributionPolicy`]
+//! folds such idles into the preceding segment so the timeline has no gap,
+//! while longer idles, idles at a context switch and idles the user already
+//! reviewed stay separate and user-actionable.
+//!
+//! The surrounding context matches when both segments resolve to the same
+//! project, or, if either side has no matched project, when both segments
+//! share the same primary app.
+
+use pulsearc_domain::types::classification::SerializedProjectMatch;
+use pulsearc_domain::{ActivitySegment, IdlePeriod, PulseArcError, Result};
+
+/// Idle periods in these states have not been reviewed by the user yet
+const UNREVIEWED_ACTIONS: [&str; 1] = ["pending"];
+
+/// Policy deciding which idle periods are absorbed into surrounding work
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdleAttributionPolicy {
+    /// Longest idle period (seconds) that may be absorbed
+    pub max_absorb_secs: i64,
+    /// Largest gap (seconds) tolerated between an idle period and the segment
+    /// bounding it on either side
+    pub max_boundary_gap_secs: i64,
+}
+
+impl Default for IdleAttributionPolicy {
+    fn default() -> Self {
+        Self { max_absorb_secs: 5 * 60, max_boundary_gap_secs: 60 }
+    }
+}
+
+/// An idle period folded into the activity before it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdleAbsorption {
+    /// Absorbed idle period
+    pub idle_period_id: String,
+    /// Segment extended over the idle period
+    pub preceding_segment_id: String,
+    /// Segment following the idle period
+    pub following_segment_id: String,
+    /// Seconds of idle time attributed to the activity
+    pub duration_secs: i64,
+}
+
+/// Outcome of [`IdleAttributionPolicy::apply`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IdleAttributionReport {
+    /// Idle periods merged into surrounding activity
+    pub absorbed: Vec<IdleAbsorption>,
+    /// Idle periods left separate for the user to review
+    pub separate: Vec<String>,
+}
+
+impl IdleAttributionPolicy {
+    /// Create a policy with a custom absorb threshold.
+    ///
+    /// # Errors
+    /// Returns `PulseArcError::InvalidInput` if the threshold is negative.
+    pub fn with_max_absorb_secs(max_absorb_secs: i64) -> Result<Self> {
+        if max_absorb_secs < 0 {
+            return Err(PulseArcError::InvalidInput(
+                "max_absorb_secs must be non-negative".to_string(),
+            ));
+        }
+        Ok(Self { max_absorb_secs, ..Self::default() })
+    }
+
+    /// Find the segments bounding `idle` that it can be absorbed into.
+    ///
+    /// Returns the indices of the preceding and following segments, or `None`
+    /// when the idle must stay separate.
+    pub fn absorbing_segments(
+        &self,
+        idle: &IdlePeriod,
+        segments: &[ActivitySegment],
+    ) -> Option<(usize, usize)> {
+        if !is_unreviewed(idle) || idle.end_ts - idle.start_ts > self.max_absorb_secs {
+            return None;
+        }
+
+        let before = segments
+            .iter()
+            .enumerate()
+            .filter(|(_, seg)| {
+                seg.end_ts <= idle.start_ts
+                    && idle.start_ts - seg.end_ts <= self.max_boundary_gap_secs
+            })
+            .max_by_key(|(_, seg)| seg.end_ts)?;
+        let after = segments
+            .iter()
+            .enumerate()
+            .filter(|(_, seg)| {
+                seg.start_ts >= idle.end_ts
+                    && seg.start_ts - idle.end_ts <= self.max_boundary_gap_secs
+            })
+            .min_by_key(|(_, seg)| seg.start_ts)?;
+
+        same_context(before.1, after.1).then_some((before.0, after.0))
+    }
+
+    /// Absorb eligible idle periods into `segments`.
+    ///
+    /// The preceding segment is extended up to the following segment and the
+    /// idle time is counted as active time, so no gap is left between them.
+    pub fn apply(
+        &self,
+        segments: &mut [ActivitySegment],
+        idle_periods: &[IdlePeriod],
+    ) -> IdleAttributionReport {
+        let mut report = IdleAttributionReport::default();
+
+        for idle in idle_periods {
+            let Some((before_idx, after_idx)) = self.absorbing_segments(idle, segments) else {
+                report.separate.push(idle.id.clone());
+                continue;
+            };
+
+            let following_start = segments[after_idx].start_ts;
+            let following_segment_id = segments[after_idx].id.clone();
+            let before = &mut segments[before_idx];
+            let duration_secs = following_start - before.end_ts;
+
+            before.end_ts = following_start;
+            before.active_time_secs = before
+                .active_time_secs
+                .saturating_add(i32::try_from(duration_secs).unwrap_or(i32::MAX));
+
+            report.absorbed.push(IdleAbsorption {
+                idle_period_id: idle.id.clone(),
+                preceding_segment_id: before.id.clone(),
+                following_segment_id,
+                duration_secs,
+            });
+        }
+
+        report
+    }
+}
+
+fn is_unreviewed(idle: &IdlePeriod) -> bool {
+    match idle.user_action.as_deref() {
+        Some(action) => UNREVIEWED_ACTIONS.contains(&action),
+        None => true,
+    }
+}
+
+fn project_id(segment: &ActivitySegment) -> Option<String> {
+    let json = segment.project_match_json.as_deref()?;
+    SerializedProjectMatch::from_json(json).ok()?.data.project_id.filter(|id| !id.is_empty())
+}
+
+fn same_context(before: &ActivitySegment, after: &ActivitySegment) -> bool {
+    match (project_id(before), project_id(after)) {
+        (Some(a), Some(b)) => a == b,
+        _ => before.primary_app.eq_ignore_ascii_case(&after.primary_app),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pulsearc_domain::types::classification::ProjectMatch;
+
+    use super::*;
+
+    const T0: i64 = 1_700_000_000;
+
+    fn segment(id: &str, start_ts: i64, end_ts: i64, app: &str, project: &str) -> ActivitySegment {
+        let project_match = ProjectMatch {
+            project_id: Some(project.to_string()),
+            wbs_code: None,
+            deal_name: None,
+            workstream: None,
+            confidence: 0.9,
+            reasons: vec![],
+        };
+
+        ActivitySegment {
+            id: id.to_string(),
+            start_ts,
+            end_ts,
+            primary_app: app.to_string(),
+            normalized_label: app.to_lowercase(),
+            sample_count: 1,
+            dictionary_keys: None,
+            created_at: start_ts,
+            processed: false,
+            snapshot_ids: vec![],
+            work_type: None,
+            activity_category: "work".to_string(),
+            detected_activity: "computer_work".to_string(),
+            extracted_signals_json: None,
+            project_match_json: SerializedProjectMatch::new(project_match).to_json().ok(),
+            idle_time_secs: 0,
+            active_time_secs: i32::try_from(end_ts - start_ts).unwrap_or(0),
+            user_action: None,
+        }
+    }
+
+    fn idle(id: &str, start_ts: i64, end_ts: i64) -> IdlePeriod {
+        IdlePeriod {
+            id: id.to_string(),
+            start_ts,
+            end_ts,
+            duration_secs: i32::try_from(end_ts - start_ts).unwrap_or(0),
+            system_trigger: "threshold".to_string(),
+            user_action: None,
+            threshold_secs: 60,
+            created_at: end_ts,
+            reviewed_at: None,
+            notes: None,
+        }
+    }
+
+    #[test]
+    fn short_idle_between_same_project_segments_is_absorbed() {
+        let policy = IdleAttributionPolicy::default();
+        let mut segments = vec![
+            segment("before", T0, T0 + 1_800, "Excel", "PRJ-1"),
+            segment("after", T0 + 1_920, T0 + 3_600, "Chrome", "PRJ-1"),
+        ];
+        let periods = vec![idle("short", T0 + 1_800, T0 + 1_920)];
+
+        let report = policy.apply(&mut segments, &periods);
+
+        assert_eq!(
+            report.absorbed,
+            vec![IdleAbsorption {
+                idle_period_id: "short".into(),
+                preceding_segment_id: "before".into(),
+                following_segment_id: "after".into(),
+                duration_secs: 120,
+            }]
+        );
+        assert!(report.separate.is_empty());
+        assert_eq!(segments[0].end_ts, segments[1].start_ts);
+        assert_eq!(segments[0].active_time_secs, 1_920);
+    }
+
+    #[test]
+    fn long_idle_stays_separate() {
+        let policy = IdleAttributionPolicy::default();
+        let mut segments = vec![
+            segment("before", T0, T0 + 1_800, "Excel", "PRJ-1"),
+            segment("after", T0 + 3_600, T0 + 5_400, "Excel", "PRJ-1"),
+        ];
+        let periods = vec![idle("long", T0 + 1_800, T0 + 3_600)];
+
+        let report = policy.apply(&mut segments, &periods);
+
+        assert!(report.absorbed.is_empty());
+        assert_eq!(report.separate, vec!["long".to_string()]);
+        assert_eq!(segments[0].end_ts, T0 + 1_800);
+    }
+
+    #[test]
+    fn idle_at_context_switch_or_reviewed_stays_separate() {
+        let policy = IdleAttributionPolicy::default();
+        let mut segments = vec![
+            segment("before", T0, T0 + 1_800, "Excel", "PRJ-1"),
+            segment("after", T0 + 1_920, T0 + 3_600, "Excel", "PRJ-2"),
+        ];
+        let switch = idle("switch", T0 + 1_800, T0 + 1_920);
+        assert_eq!(policy.absorbing_segments(&switch, &segments), None);
+
+        segments[1] = segment("after", T0 + 1_920, T0 + 3_600, "Excel", "PRJ-1");
+        let mut reviewed = idle("reviewed", T0 + 1_800, T0 + 1_920);
+        reviewed.user_action = Some("kept".into());
+        assert_eq!(policy.absorbing_segments(&reviewed, &segments), None);
+    }
+
+    #[test]
+    fn threshold_is_configurable() {
+        let policy = IdleAttributionPolicy::with_max_absorb_secs(3_600).expect("valid threshold");
+        let segments = vec![
+            segment("before", T0, T0 + 1_800, "Excel", "PRJ-1"),
+            segment("after", T0 + 3_600, T0 + 5_400, "Excel", "PRJ-1"),
+        ];
+
+        let long = idle("long", T0 + 1_800, T0 + 3_600);
+        assert_eq!(policy.absorbing_segments(&long, &segments), Some((0, 1)));
+        assert!(IdleAttributionPolicy::with_max_absorb_secs(-1).is_err());
+    }
+}
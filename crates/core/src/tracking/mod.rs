@@ -1,7 +1,25 @@
 //! Activity tracking domain
 
+pub mod activation;
+pub mod dedup;
+pub mod dwell;
+pub mod idle;
+pub mod idle_attribution;
+pub mod permissions;
+pub mod poller;
 pub mod ports;
+pub mod retention;
 pub mod service;
+pub mod timeline;
 
+pub use activation::{ActivationCapture, AppActivation, DEFAULT_ACTIVATION_DEBOUNCE};
+pub use dedup::{coalesce_snapshots, content_hash, SnapshotDedupConfig, SnapshotRun};
+pub use dwell::{DwellConfig, DwellFilter};
+pub use idle::{IdleDetector, IdleHysteresisConfig, IdleState, IdleTransition};
+pub use idle_attribution::{IdleAbsorption, IdleAttributionPolicy, IdleAttributionReport};
+pub use permissions::{PermissionMonitor, DEFAULT_PERMISSION_RECHECK_INTERVAL};
+pub use poller::TrackingPoller;
 pub use ports::*;
+pub use retention::{SnapshotRetentionPolicy, SnapshotStorageUsage, Watermarks};
 pub use service::*;
+pub use timeline::{get_timeline, merge_timeline, timeline_calendar_event};
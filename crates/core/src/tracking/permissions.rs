@@ -0,0 +1,161 @@
+//! Accessibility permission monitoring
+//!
+//! Without Accessibility permission the tracker silently degrades to
+//! app-only captures. [`PermissionMonitor`] re-checks the permission
+//! periodically and publishes the status on a `watch` channel, so the UI can
+//! be told when the user grants or revokes it.
+
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use pulsearc_domain::PermissionStatus;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info};
+
+use super::ports::PermissionChecker;
+
+/// Default interval between permission re-checks
+pub const DEFAULT_PERMISSION_RECHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Tracks the Accessibility permission status and announces changes
+pub struct PermissionMonitor {
+    checker: Arc<dyn PermissionChecker>,
+    status: watch::Sender<PermissionStatus>,
+    cancel: CancellationToken,
+}
+
+impl PermissionMonitor {
+    /// Create a monitor, checking the current status once
+    pub async fn new(checker: Arc<dyn PermissionChecker>) -> Self {
+        let initial = checker.accessibility_permission().await;
+        let (status, _) = watch::channel(initial);
+        Self { checker, status, cancel: CancellationToken::new() }
+    }
+
+    /// Last observed status
+    pub fn status(&self) -> PermissionStatus {
+        *self.status.borrow()
+    }
+
+    /// Receiver notified whenever the status changes
+    pub fn subscribe(&self) -> watch::Receiver<PermissionStatus> {
+        self.status.subscribe()
+    }
+
+    /// Re-check the permission, publishing the status if it changed
+    ///
+    /// Returns `true` when the status changed.
+    pub async fn check(&self) -> bool {
+        let current = self.checker.accessibility_permission().await;
+        let changed = self.status.send_if_modified(|status| {
+            let changed = *status != current;
+            *status = current;
+            changed
+        });
+        if changed {
+            info!(status = ?current, "Accessibility permission status changed");
+        }
+        changed
+    }
+
+    /// Re-check every `interval` on a background task
+    ///
+    /// The task holds a weak reference and exits once the monitor is dropped
+    /// or [`stop`](Self::stop) is called.
+    pub fn spawn(self: &Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        let monitor = Arc::downgrade(self);
+        let cancel = self.cancel.clone();
+        tokio::spawn(run(monitor, interval, cancel))
+    }
+
+    /// Stop the background re-check task
+    pub fn stop(&self) {
+        self.cancel.cancel();
+    }
+}
+
+impl Drop for PermissionMonitor {
+    fn drop(&mut self) {
+        self.cancel.cancel();
+    }
+}
+
+async fn run(monitor: Weak<PermissionMonitor>, interval: Duration, cancel: CancellationToken) {
+    let mut ticker = tokio::time::interval(interval);
+    // The first tick completes immediately; the status was just checked
+    ticker.tick().await;
+
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => break,
+            _ = ticker.tick() => {
+                let Some(monitor) = monitor.upgrade() else { break };
+                monitor.check().await;
+            }
+        }
+    }
+
+    debug!("Permission monitor exited");
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use super::*;
+
+    /// Checker whose status the test flips
+    struct FakeChecker(Mutex<PermissionStatus>);
+
+    impl FakeChecker {
+        fn set(&self, status: PermissionStatus) {
+            *self.0.lock().unwrap() = status;
+        }
+    }
+
+    #[async_trait]
+    impl PermissionChecker for FakeChecker {
+        async fn accessibility_permission(&self) -> PermissionStatus {
+            *self.0.lock().unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn status_change_notifies_subscribers() {
+        let checker = Arc::new(FakeChecker(Mutex::new(PermissionStatus::Denied)));
+        let monitor = PermissionMonitor::new(checker.clone()).await;
+        let mut events = monitor.subscribe();
+
+        assert!(!monitor.check().await, "unchanged status is not announced");
+        assert!(!events.has_changed().unwrap());
+
+        checker.set(PermissionStatus::Granted);
+        assert!(monitor.check().await);
+
+        assert!(events.has_changed().unwrap());
+        assert_eq!(*events.borrow_and_update(), PermissionStatus::Granted);
+        assert_eq!(monitor.status(), PermissionStatus::Granted);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn background_task_rechecks_periodically() {
+        let checker = Arc::new(FakeChecker(Mutex::new(PermissionStatus::Denied)));
+        let monitor = Arc::new(PermissionMonitor::new(checker.clone()).await);
+        let mut events = monitor.subscribe();
+        let handle = monitor.spawn(Duration::from_secs(30));
+
+        checker.set(PermissionStatus::Granted);
+        tokio::time::timeout(Duration::from_secs(60), events.changed())
+            .await
+            .expect("status change within one interval")
+            .unwrap();
+        assert_eq!(*events.borrow(), PermissionStatus::Granted);
+
+        monitor.stop();
+        handle.await.unwrap();
+    }
+}
@@ -0,0 +1,326 @@
+//! Clock-driven activity poll loop
+//!
+//! [`TrackingPoller`] calls [`TrackingService::capture_activity`] on each tick
+//! of a [`DriftCorrectingInterval`] until its cancellation token fires:
+//! - Time comes from an injectable [`Clock`], so tests drive the loop with a
+//!   `MockClock` instead of sleeping
+//! - The period is read from a `watch` channel and applied live, letting
+//!   adaptive or power-aware callers slow down or speed up polling without
+//!   restarting the loop
+//! - Ticks missed while a capture runs (or while the machine sleeps) are
+//!   skipped rather than replayed
+//! - No capture is made while tracking is paused
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use pulsearc_common::time::{Clock, DriftCorrectingInterval, DriftCorrection, SystemClock};
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, warn};
+
+use super::service::TrackingService;
+
+/// Longest single wait before the clock is re-read
+const MAX_WAIT_SLICE: Duration = Duration::from_secs(1);
+
+/// Periodic activity capture driven by an injectable clock
+pub struct TrackingPoller<C: Clock = SystemClock> {
+    service: Arc<TrackingService>,
+    interval: DriftCorrectingInterval<C>,
+    period: watch::Receiver<Duration>,
+    cancel: CancellationToken,
+}
+
+impl TrackingPoller<SystemClock> {
+    /// Create a poller on the system clock.
+    ///
+    /// The first capture happens immediately; later ones follow the period
+    /// currently held by `period`.
+    pub fn new(
+        service: Arc<TrackingService>,
+        period: watch::Receiver<Duration>,
+        cancel: CancellationToken,
+    ) -> Self {
+        Self::with_clock(service, period, cancel, SystemClock)
+    }
+}
+
+impl<C: Clock> TrackingPoller<C> {
+    /// Create a poller on a custom clock (e.g. `MockClock` in tests).
+    pub fn with_clock(
+        service: Arc<TrackingService>,
+        mut period: watch::Receiver<Duration>,
+        cancel: CancellationToken,
+        clock: C,
+    ) -> Self {
+        let initial = *period.borrow_and_update();
+        Self {
+            service,
+            interval: DriftCorrectingInterval::with_clock(initial, DriftCorrection::Skip, clock),
+            period,
+            cancel,
+        }
+    }
+
+    /// Current poll period
+    pub fn period(&self) -> Duration {
+        self.interval.period()
+    }
+
+    /// Poll until cancelled.
+    ///
+    /// Cancellation also interrupts a capture in progress. If the period
+    /// sender is dropped the last period stays in effect.
+    pub async fn run(mut self) {
+        let mut period_open = true;
+
+        loop {
+            if self.cancel.is_cancelled() {
+                break;
+            }
+
+            if self.interval.poll_tick().is_some() {
+                tokio::select! {
+                    _ = self.cancel.cancelled() => break,
+                    _ = poll_once(&self.service) => {}
+                }
+                continue;
+            }
+
+            let wait = self.interval.time_until_next().min(MAX_WAIT_SLICE);
+            tokio::select! {
+                _ = self.cancel.cancelled() => break,
+                changed = self.period.changed(), if period_open => match changed {
+                    Ok(()) => {
+                        let period = *self.period.borrow_and_update();
+                        debug!(period = ?period, "Tracking poll period changed");
+                        self.interval.set_period(period);
+                    }
+                    Err(_) => period_open = false,
+                },
+                _ = tokio::time::sleep(wait) => {}
+            }
+        }
+
+        debug!("Tracking poll loop exited");
+    }
+}
+
+/// Capture one activity unless tracking is paused
+pub(super) async fn poll_once(service: &TrackingService) {
+    if service.is_paused().await {
+        return;
+    }
+    if let Err(err) = service.capture_activity().await {
+        warn!(error = %err, "Tracking poll failed to capture activity");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use pulsearc_common::time::MockClock;
+    use pulsearc_domain::types::database::ActivitySnapshot;
+    use pulsearc_domain::types::WindowContext;
+    use pulsearc_domain::{ActivityContext, Result};
+
+    use super::*;
+    use crate::tracking::dedup::SnapshotRun;
+    use crate::tracking::ports::{ActivityProvider, ActivityRepository};
+    use crate::tracking::retention::SnapshotStorageUsage;
+
+    const PERIOD: Duration = Duration::from_secs(30);
+
+    /// Provider counting how often it was polled
+    struct CountingProvider(Arc<AtomicUsize>);
+
+    #[async_trait]
+    impl ActivityProvider for CountingProvider {
+        async fn get_activity(&self) -> Result<ActivityContext> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(ActivityContext {
+                active_app: WindowContext {
+                    app_name: "Xcode".to_string(),
+                    window_title: "main.rs".to_string(),
+                    bundle_id: None,
+                    url: None,
+                    url_host: None,
+                    document_name: None,
+                    file_path: None,
+                },
+                recent_apps: vec![],
+                detected_activity: "working".to_string(),
+                work_type: None,
+                activity_category: Default::default(),
+                billable_confidence: 0.0,
+                suggested_client: None,
+                suggested_matter: None,
+                suggested_task_code: None,
+                extracted_metadata: Default::default(),
+                evidence: Default::default(),
+                calendar_event: None,
+                location: None,
+                temporal_context: None,
+                classification: None,
+                capture_mode: Default::default(),
+            })
+        }
+
+        fn is_paused(&self) -> bool {
+            false
+        }
+
+        fn pause(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn resume(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    struct NullRepository;
+
+    #[async_trait]
+    impl ActivityRepository for NullRepository {
+        async fn save_snapshot(&self, _snapshot: ActivitySnapshot) -> Result<()> {
+            Ok(())
+        }
+
+        async fn get_snapshots(
+            &self,
+            _start: DateTime<Utc>,
+            _end: DateTime<Utc>,
+        ) -> Result<Vec<ActivitySnapshot>> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_old_snapshots(&self, _before: DateTime<Utc>) -> Result<usize> {
+            Ok(0)
+        }
+
+        async fn snapshot_storage_usage(&self) -> Result<SnapshotStorageUsage> {
+            Ok(SnapshotStorageUsage::default())
+        }
+
+        async fn evict_synced_snapshots(&self, _limit: usize) -> Result<usize> {
+            Ok(0)
+        }
+
+        async fn extend_snapshot(&self, _snapshot_id: &str, _run: SnapshotRun) -> Result<bool> {
+            Ok(false)
+        }
+
+        async fn snapshot_run(&self, _snapshot_id: &str) -> Result<Option<SnapshotRun>> {
+            Ok(None)
+        }
+    }
+
+    struct Harness {
+        polls: Arc<AtomicUsize>,
+        clock: MockClock,
+        period: watch::Sender<Duration>,
+        cancel: CancellationToken,
+        handle: tokio::task::JoinHandle<()>,
+    }
+
+    impl Harness {
+        fn start() -> Self {
+            let polls = Arc::new(AtomicUsize::new(0));
+            let service = Arc::new(
+                TrackingService::new(
+                    CountingProvider(Arc::clone(&polls)),
+                    Arc::new(NullRepository),
+                )
+                .with_persistence(false),
+            );
+            let clock = MockClock::new();
+            let (period, receiver) = watch::channel(PERIOD);
+            let cancel = CancellationToken::new();
+            let poller =
+                TrackingPoller::with_clock(service, receiver, cancel.clone(), clock.clone());
+            let handle = tokio::spawn(poller.run());
+            Self { polls, clock, period, cancel, handle }
+        }
+
+        fn polls(&self) -> usize {
+            self.polls.load(Ordering::SeqCst)
+        }
+
+        /// Advance the mock clock and let the loop observe it
+        async fn advance(&self, by: Duration) {
+            self.clock.advance(by);
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        }
+
+        /// Change the poll period and let the loop apply it before the clock
+        /// moves again
+        async fn set_period(&self, period: Duration) {
+            self.period.send_replace(period);
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn advancing_the_clock_polls_once_per_tick() {
+        let harness = Harness::start();
+
+        harness.advance(Duration::ZERO).await;
+        assert_eq!(harness.polls(), 1, "first tick is immediate");
+
+        harness.advance(PERIOD / 2).await;
+        assert_eq!(harness.polls(), 1, "no tick before the period elapses");
+
+        harness.advance(PERIOD / 2).await;
+        assert_eq!(harness.polls(), 2);
+
+        harness.advance(PERIOD).await;
+        assert_eq!(harness.polls(), 3);
+
+        harness.advance(PERIOD * 4).await;
+        assert_eq!(harness.polls(), 4, "missed ticks are skipped, not replayed");
+
+        harness.cancel.cancel();
+        harness.handle.await.expect("poll loop exits cleanly");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn period_changes_apply_without_restarting() {
+        let harness = Harness::start();
+        harness.advance(Duration::ZERO).await;
+        assert_eq!(harness.polls(), 1);
+
+        harness.set_period(Duration::from_secs(10)).await;
+        harness.advance(Duration::from_secs(10)).await;
+        assert_eq!(harness.polls(), 2, "shorter period takes effect immediately");
+
+        harness.set_period(Duration::from_secs(60)).await;
+        harness.advance(Duration::from_secs(30)).await;
+        assert_eq!(harness.polls(), 2);
+        harness.advance(Duration::from_secs(30)).await;
+        assert_eq!(harness.polls(), 3);
+
+        harness.cancel.cancel();
+        harness.handle.await.expect("poll loop exits cleanly");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cancellation_stops_the_loop_promptly() {
+        let harness = Harness::start();
+        harness.advance(Duration::ZERO).await;
+
+        harness.cancel.cancel();
+        tokio::time::timeout(Duration::from_millis(10), harness.handle)
+            .await
+            .expect("loop stops without waiting for the next tick")
+            .expect("poll loop exits cleanly");
+
+        harness.clock.advance(PERIOD);
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert_eq!(harness.polls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -6,8 +6,16 @@
 use async_trait::async_trait;
 use chrono::{DateTime, NaiveDate, Utc};
 use pulsearc_common::error::CommonResult;
-use pulsearc_domain::types::database::{ActivitySegment, ActivitySnapshot, CalendarEventParams};
-use pulsearc_domain::{ActivityContext, CalendarEventRow, IdlePeriod, IdleSummary, Result};
+use pulsearc_domain::types::database::{
+    ActivitySegment, ActivitySnapshot, CalendarBatchOutcome, CalendarEventParams, Page,
+    PageRequest, SnapshotFilter,
+};
+use pulsearc_domain::{
+    ActivityContext, CalendarEventRow, IdlePeriod, IdleSummary, PermissionStatus, Result,
+};
+
+use super::dedup::SnapshotRun;
+use super::retention::SnapshotStorageUsage;
 
 /// Trait for capturing activity from the operating system
 #[async_trait]
@@ -23,6 +31,24 @@ pub trait ActivityProvider: Send + Sync {
 
     /// Resume activity tracking
     fn resume(&mut self) -> Result<()>;
+
+    /// Whether the OS permission needed for full context capture (window
+    /// titles, enrichment) is granted
+    ///
+    /// Providers that need no permission keep the default, `Granted`.
+    fn permission_status(&self) -> PermissionStatus {
+        PermissionStatus::Granted
+    }
+}
+
+/// Reports the current Accessibility permission status
+///
+/// Polled by [`PermissionMonitor`](crate::tracking::PermissionMonitor) to
+/// notice when the user grants or revokes the permission.
+#[async_trait]
+pub trait PermissionChecker: Send + Sync {
+    /// Check the permission now
+    async fn accessibility_permission(&self) -> PermissionStatus;
 }
 
 /// Trait for persisting activity snapshots
@@ -42,6 +68,25 @@ pub trait ActivityRepository: Send + Sync {
 
     /// Delete snapshots older than the specified date
     async fn delete_old_snapshots(&self, before: chrono::DateTime<chrono::Utc>) -> Result<usize>;
+
+    /// Report stored snapshot count and database bytes in use
+    async fn snapshot_storage_usage(&self) -> Result<SnapshotStorageUsage>;
+
+    /// Delete up to `limit` of the oldest synced snapshots
+    ///
+    /// A snapshot is synced once it was segmented and its segment was
+    /// processed. Unsynced snapshots (including segmented ones whose segment
+    /// is still pending) must never be deleted. Returns the number deleted.
+    async fn evict_synced_snapshots(&self, limit: usize) -> Result<usize>;
+
+    /// Record `run` as the coalesced duration of `snapshot_id`
+    ///
+    /// Returns `false` (and records nothing) when the snapshot no longer
+    /// exists or has already been processed, so the caller stores a new one.
+    async fn extend_snapshot(&self, snapshot_id: &str, run: SnapshotRun) -> Result<bool>;
+
+    /// Coalesced run for `snapshot_id`, or `None` if it covers a single poll
+    async fn snapshot_run(&self, snapshot_id: &str) -> Result<Option<SnapshotRun>>;
 }
 
 /// Trait for enriching activity context with additional metadata
@@ -104,6 +149,17 @@ pub trait SnapshotRepository: Send + Sync {
     /// Uses INSERT OR REPLACE to handle duplicate IDs gracefully.
     fn store_snapshots_batch(&self, snapshots: &[ActivitySnapshot]) -> CommonResult<()>;
 
+    /// Insert new snapshots in a single transaction
+    ///
+    /// Unlike [`Self::store_snapshots_batch`] this is a plain insert: a
+    /// duplicate id fails the call and rolls the whole batch back. When the
+    /// repository deduplicates, identical consecutive snapshots are collapsed
+    /// into the first of their run (see [`super::coalesce_snapshots`]).
+    ///
+    /// # Returns
+    /// Number of snapshots inserted
+    fn insert_batch(&self, snapshots: &[ActivitySnapshot]) -> CommonResult<usize>;
+
     /// Count active snapshots (is_idle = 0) within a time range
     ///
     /// Used for calculating total active time in idle summaries.
@@ -121,6 +177,27 @@ pub trait SnapshotRepository: Send + Sync {
     /// arithmetic operations (e.g., count × 30 seconds)
     fn count_active_snapshots(&self, start: DateTime<Utc>, end: DateTime<Utc>)
         -> CommonResult<i64>;
+
+    /// Browse snapshots matching `filter`, one page at a time
+    ///
+    /// Results are ordered by `(timestamp, id)` so pages are stable while
+    /// rows are appended. `page_size` is clamped to
+    /// [`PageRequest::MAX_PAGE_SIZE`].
+    ///
+    /// # Returns
+    /// The requested page and the total number of matching snapshots
+    fn browse_snapshots(
+        &self,
+        filter: &SnapshotFilter,
+        page: PageRequest,
+    ) -> CommonResult<Page<ActivitySnapshot>>;
+
+    /// Find snapshots whose salient content hashes to `content_hash`
+    ///
+    /// See [`ActivitySnapshot::compute_content_hash`]. Results are ordered by
+    /// `(timestamp, id)`; snapshots stored before the hash column existed
+    /// never match.
+    fn find_by_content_hash(&self, content_hash: &str) -> CommonResult<Vec<ActivitySnapshot>>;
 }
 
 /// Repository for querying calendar events
@@ -154,6 +231,25 @@ pub trait CalendarEventRepository: Send + Sync {
     /// Success or error if insertion fails
     async fn insert_calendar_event(&self, params: CalendarEventParams) -> Result<()>;
 
+    /// Upsert calendar events in a single transaction
+    ///
+    /// Events are matched on `google_event_id` and `user_email`, like
+    /// [`Self::insert_calendar_event`]. Either every event is written or, if
+    /// any write fails, none are.
+    ///
+    /// # Returns
+    /// One outcome per event, in input order (`Inserted` or `Updated`)
+    async fn upsert_batch(
+        &self,
+        events: Vec<CalendarEventParams>,
+    ) -> Result<Vec<CalendarBatchOutcome>>;
+
+    /// Delete calendar events by id in a single transaction
+    ///
+    /// # Returns
+    /// One outcome per id, in input order (`Deleted` or `NotFound`)
+    async fn delete_batch(&self, ids: &[String]) -> Result<Vec<CalendarBatchOutcome>>;
+
     /// Get calendar events within a time range for a specific user
     ///
     /// # Arguments
@@ -0,0 +1,320 @@
+//! Capture-time snapshot retention
+//!
+//! `CleanupService` prunes snapshots on a timer, so a busy day can grow the
+//! database well past its usual size before the next run. A
+//! [`SnapshotRetentionPolicy`] caps growth at capture time instead:
+//!
+//! - Eviction starts when the stored snapshot count or the database size
+//!   exceeds its **high-water mark**, and continues until both are back under
+//!   their **low-water mark**. The gap between the two keeps captures from
+//!   evicting on every tick.
+//! - Only already-synced snapshots (segmented into a processed segment) are
+//!   evicted, oldest first. Unsynced snapshots are never removed; if only
+//!   those remain, eviction stops and the cap is exceeded until they sync.
+//! - At most `max_evictions_per_capture` snapshots are removed per capture so
+//!   capture latency stays bounded; the remainder is handled by later captures.
+
+use pulsearc_domain::{Result, TrackingConfig};
+use tracing::{debug, warn};
+
+use super::ports::ActivityRepository;
+
+/// Snapshots evicted per round while the size cap is exceeded
+const BYTES_EVICTION_CHUNK: usize = 100;
+
+/// Default bound on evictions per capture
+const DEFAULT_MAX_EVICTIONS_PER_CAPTURE: usize = 500;
+
+/// High/low watermark pair
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Watermarks {
+    /// Eviction starts above this value
+    pub high: u64,
+    /// Eviction stops at or below this value
+    pub low: u64,
+}
+
+impl Watermarks {
+    /// Watermarks with the low mark derived as 80% of `high`.
+    pub fn from_high(high: u64) -> Self {
+        Self { high, low: high - high / 5 }
+    }
+}
+
+/// Stored snapshot volume, as reported by the repository
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SnapshotStorageUsage {
+    /// Rows in `activity_snapshots`
+    pub snapshot_count: u64,
+    /// Database bytes in use (excluding free pages)
+    pub used_bytes: u64,
+}
+
+/// Capture-time eviction policy for activity snapshots
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotRetentionPolicy {
+    /// Snapshot count watermarks (`None` disables the count cap)
+    pub count: Option<Watermarks>,
+    /// Database size watermarks in bytes (`None` disables the size cap)
+    pub bytes: Option<Watermarks>,
+    /// Upper bound on snapshots evicted during a single capture
+    pub max_evictions_per_capture: usize,
+}
+
+impl SnapshotRetentionPolicy {
+    /// Build a policy from the tracking configuration.
+    ///
+    /// Returns `None` when neither high-water mark is configured. Low-water
+    /// marks are derived as 80% of the high-water marks.
+    pub fn from_tracking_config(config: &TrackingConfig) -> Option<Self> {
+        let count = config.snapshot_high_water_count.map(Watermarks::from_high);
+        let bytes = config.snapshot_high_water_bytes.map(Watermarks::from_high);
+        if count.is_none() && bytes.is_none() {
+            return None;
+        }
+
+        Some(Self { count, bytes, max_evictions_per_capture: DEFAULT_MAX_EVICTIONS_PER_CAPTURE })
+    }
+
+    /// Whether `usage` exceeds any high-water mark.
+    pub fn exceeds_high_water(&self, usage: &SnapshotStorageUsage) -> bool {
+        self.count.is_some_and(|marks| usage.snapshot_count > marks.high)
+            || self.bytes.is_some_and(|marks| usage.used_bytes > marks.high)
+    }
+
+    /// Whether `usage` is at or below every low-water mark.
+    pub fn within_low_water(&self, usage: &SnapshotStorageUsage) -> bool {
+        self.count.is_none_or(|marks| usage.snapshot_count <= marks.low)
+            && self.bytes.is_none_or(|marks| usage.used_bytes <= marks.low)
+    }
+
+    /// Evict synced snapshots if `repository` is above a high-water mark.
+    ///
+    /// Returns the number of snapshots evicted (0 when under the high-water
+    /// mark).
+    ///
+    /// # Errors
+    ///
+    /// Propagates repository errors; snapshots evicted before the error stay
+    /// evicted.
+    pub async fn enforce(&self, repository: &dyn ActivityRepository) -> Result<usize> {
+        let mut usage = repository.snapshot_storage_usage().await?;
+        if !self.exceeds_high_water(&usage) {
+            return Ok(0);
+        }
+
+        let mut evicted = 0;
+        while evicted < self.max_evictions_per_capture && !self.within_low_water(&usage) {
+            let chunk = self.next_chunk(&usage).min(self.max_evictions_per_capture - evicted);
+            let removed = repository.evict_synced_snapshots(chunk).await?;
+            if removed == 0 {
+                warn!(
+                    snapshot_count = usage.snapshot_count,
+                    used_bytes = usage.used_bytes,
+                    "Snapshot high-water mark exceeded but no synced snapshots left to evict"
+                );
+                break;
+            }
+
+            evicted += removed;
+            usage = repository.snapshot_storage_usage().await?;
+        }
+
+        debug!(
+            evicted,
+            snapshot_count = usage.snapshot_count,
+            used_bytes = usage.used_bytes,
+            "Evicted synced snapshots at capture time"
+        );
+        Ok(evicted)
+    }
+
+    /// Snapshots to evict in the next round.
+    ///
+    /// The count cap is exact; the size cap cannot be mapped to a row count,
+    /// so it evicts a fixed chunk and re-measures.
+    fn next_chunk(&self, usage: &SnapshotStorageUsage) -> usize {
+        let by_count = self
+            .count
+            .map(|marks| usage.snapshot_count.saturating_sub(marks.low))
+            .map_or(0, |excess| usize::try_from(excess).unwrap_or(usize::MAX));
+        let by_bytes = match self.bytes {
+            Some(marks) if usage.used_bytes > marks.low => BYTES_EVICTION_CHUNK,
+            _ => 0,
+        };
+
+        by_count.max(by_bytes).max(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use pulsearc_domain::ActivitySnapshot;
+
+    use super::*;
+    use crate::tracking::dedup::SnapshotRun;
+
+    /// Bytes each in-memory snapshot contributes to `used_bytes`
+    const SNAPSHOT_BYTES: u64 = 1_000;
+
+    /// Snapshot row as `(timestamp, synced)`
+    type Row = (i64, bool);
+
+    /// In-memory repository holding snapshot rows
+    struct InMemoryRepository {
+        rows: Mutex<Vec<Row>>,
+        evict_calls: Mutex<usize>,
+    }
+
+    impl InMemoryRepository {
+        fn new(rows: Vec<Row>) -> Self {
+            Self { rows: Mutex::new(rows), evict_calls: Mutex::new(0) }
+        }
+
+        fn rows(&self) -> Vec<Row> {
+            self.rows.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl ActivityRepository for InMemoryRepository {
+        async fn save_snapshot(&self, _snapshot: ActivitySnapshot) -> Result<()> {
+            Ok(())
+        }
+
+        async fn get_snapshots(
+            &self,
+            _start: DateTime<Utc>,
+            _end: DateTime<Utc>,
+        ) -> Result<Vec<ActivitySnapshot>> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_old_snapshots(&self, _before: DateTime<Utc>) -> Result<usize> {
+            Ok(0)
+        }
+
+        async fn snapshot_storage_usage(&self) -> Result<SnapshotStorageUsage> {
+            let count = self.rows.lock().unwrap().len() as u64;
+            Ok(SnapshotStorageUsage { snapshot_count: count, used_bytes: count * SNAPSHOT_BYTES })
+        }
+
+        async fn evict_synced_snapshots(&self, limit: usize) -> Result<usize> {
+            *self.evict_calls.lock().unwrap() += 1;
+            let mut rows = self.rows.lock().unwrap();
+            rows.sort_by_key(|(timestamp, _)| *timestamp);
+
+            let mut evicted = 0;
+            rows.retain(|(_, synced)| {
+                if *synced && evicted < limit {
+                    evicted += 1;
+                    false
+                } else {
+                    true
+                }
+            });
+            Ok(evicted)
+        }
+
+        async fn extend_snapshot(&self, _snapshot_id: &str, _run: SnapshotRun) -> Result<bool> {
+            Ok(false)
+        }
+
+        async fn snapshot_run(&self, _snapshot_id: &str) -> Result<Option<SnapshotRun>> {
+            Ok(None)
+        }
+    }
+
+    fn count_policy(high: u64, low: u64) -> SnapshotRetentionPolicy {
+        SnapshotRetentionPolicy {
+            count: Some(Watermarks { high, low }),
+            bytes: None,
+            max_evictions_per_capture: 500,
+        }
+    }
+
+    fn synced_rows(count: i64) -> Vec<Row> {
+        (0..count).map(|ts| (ts, true)).collect()
+    }
+
+    #[tokio::test]
+    async fn no_eviction_at_or_below_high_water() {
+        let repo = InMemoryRepository::new(synced_rows(10));
+
+        let evicted = count_policy(10, 5).enforce(&repo).await.unwrap();
+
+        assert_eq!(evicted, 0);
+        assert_eq!(repo.rows().len(), 10);
+        assert_eq!(*repo.evict_calls.lock().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn eviction_above_high_water_stops_at_low_water() {
+        let repo = InMemoryRepository::new(synced_rows(11));
+
+        let evicted = count_policy(10, 5).enforce(&repo).await.unwrap();
+
+        assert_eq!(evicted, 6);
+        // Oldest rows went first
+        let remaining: Vec<i64> = repo.rows().iter().map(|(ts, _)| *ts).collect();
+        assert_eq!(remaining, vec![6, 7, 8, 9, 10]);
+    }
+
+    #[tokio::test]
+    async fn unsynced_snapshots_are_never_evicted() {
+        let mut rows = synced_rows(4);
+        rows.extend((4..12).map(|ts| (ts, false)));
+        let repo = InMemoryRepository::new(rows);
+
+        let evicted = count_policy(10, 5).enforce(&repo).await.unwrap();
+
+        assert_eq!(evicted, 4);
+        let remaining = repo.rows();
+        assert_eq!(remaining.len(), 8);
+        assert!(remaining.iter().all(|(_, synced)| !synced));
+    }
+
+    #[tokio::test]
+    async fn size_cap_evicts_in_chunks_until_under_low_water() {
+        let repo = InMemoryRepository::new(synced_rows(350));
+        let policy = SnapshotRetentionPolicy {
+            count: None,
+            bytes: Some(Watermarks { high: 300 * SNAPSHOT_BYTES, low: 120 * SNAPSHOT_BYTES }),
+            max_evictions_per_capture: 500,
+        };
+
+        let evicted = policy.enforce(&repo).await.unwrap();
+
+        assert_eq!(evicted, 300);
+        assert_eq!(repo.rows().len(), 50);
+        assert_eq!(*repo.evict_calls.lock().unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn evictions_per_capture_are_bounded() {
+        let repo = InMemoryRepository::new(synced_rows(100));
+        let policy =
+            SnapshotRetentionPolicy { max_evictions_per_capture: 20, ..count_policy(50, 10) };
+
+        let evicted = policy.enforce(&repo).await.unwrap();
+
+        assert_eq!(evicted, 20);
+        assert_eq!(repo.rows().len(), 80);
+    }
+
+    #[test]
+    fn policy_from_tracking_config_derives_low_water() {
+        let mut tracking = pulsearc_domain::Config::default().tracking;
+        assert!(SnapshotRetentionPolicy::from_tracking_config(&tracking).is_none());
+
+        tracking.snapshot_high_water_count = Some(10_000);
+        let policy =
+            SnapshotRetentionPolicy::from_tracking_config(&tracking).expect("count cap configured");
+        assert_eq!(policy.count, Some(Watermarks { high: 10_000, low: 8_000 }));
+        assert_eq!(policy.bytes, None);
+    }
+}
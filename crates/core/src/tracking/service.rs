@@ -1,23 +1,49 @@
 //! Activity tracking service - core business logic
 
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
+use async_trait::async_trait;
+use pulsearc_common::observability::{MetricsCollector, NoOpMetricsCollector};
+use pulsearc_common::sync::{PressureLevel, QueuePressure};
+use pulsearc_common::time::Clock;
 use pulsearc_domain::types::database::{ActivitySnapshot, SnapshotMetadata};
-use pulsearc_domain::{ActivityContext, Result};
-use tokio::sync::Mutex;
-use tracing::error;
+use pulsearc_domain::{ActivityContext, CaptureMode, PermissionStatus, Result};
+use tokio::sync::{watch, Mutex};
+use tracing::{debug, error, warn};
 
-use super::ports::{ActivityEnricher, ActivityProvider, ActivityRepository};
+use super::dedup::{content_hash, SnapshotDedupConfig, SnapshotDeduplicator};
+use super::dwell::{DwellConfig, DwellFilter};
+use super::ports::{ActivityEnricher, ActivityProvider, ActivityRepository, PermissionChecker};
+use super::retention::SnapshotRetentionPolicy;
+
+/// Counter incremented per capture, labelled by whether it was `persisted`
+pub const CAPTURES_METRIC: &str = "tracking.captures";
+
+/// Counter incremented when backpressure skips persisting a capture
+pub const CAPTURES_THROTTLED_METRIC: &str = "tracking.captures.throttled";
+
+/// Counter incremented when persisting a capture fails
+pub const CAPTURE_PERSIST_ERRORS_METRIC: &str = "tracking.captures.persist_errors";
 
 /// Shared, thread-safe activity provider
 type SharedProvider = Arc<Mutex<Box<dyn ActivityProvider + Send + Sync>>>;
 
+/// A capture held by the dwell filter, stamped when it was taken
+type HeldCapture = (ActivityContext, SnapshotMetadata);
+
 /// Activity tracking service
 pub struct TrackingService {
     provider: SharedProvider,
     repository: Arc<dyn ActivityRepository>,
     enrichers: Vec<Arc<dyn ActivityEnricher>>,
     persist_captures: bool,
+    retention: Option<SnapshotRetentionPolicy>,
+    dedup: Option<Mutex<SnapshotDeduplicator>>,
+    dwell: Option<Mutex<DwellFilter<HeldCapture>>>,
+    backpressure: Option<watch::Receiver<QueuePressure>>,
+    captures_under_pressure: AtomicU64,
+    metrics: Arc<dyn MetricsCollector>,
 }
 
 impl TrackingService {
@@ -31,6 +57,12 @@ impl TrackingService {
             repository,
             enrichers: Vec::new(),
             persist_captures: true,
+            retention: None,
+            dedup: None,
+            dwell: None,
+            backpressure: None,
+            captures_under_pressure: AtomicU64::new(0),
+            metrics: Arc::new(NoOpMetricsCollector),
         }
     }
 
@@ -49,15 +81,79 @@ impl TrackingService {
         self
     }
 
+    /// Evict old synced snapshots during capture when the store grows past
+    /// the policy's high-water mark.
+    ///
+    /// Disabled by default, leaving pruning to `CleanupService`.
+    pub fn with_retention(mut self, policy: SnapshotRetentionPolicy) -> Self {
+        self.retention = Some(policy);
+        self
+    }
+
+    /// Coalesce identical consecutive captures into one snapshot.
+    ///
+    /// Instead of storing a row per poll, the first snapshot of a run is kept
+    /// and its duration extended (see [`super::dedup`]). Disabled by default.
+    pub fn with_deduplication(mut self, config: SnapshotDedupConfig) -> Self {
+        self.dedup = Some(Mutex::new(SnapshotDeduplicator::new(config)));
+        self
+    }
+
+    /// Only record an app once it has stayed focused for the minimum dwell.
+    ///
+    /// Captures of brief focuses (e.g. alt-tabbing past an app) are not
+    /// persisted; see [`super::dwell`]. Disabled by default.
+    pub fn with_min_dwell(self, config: DwellConfig) -> Self {
+        self.with_dwell_filter(DwellFilter::new(config))
+    }
+
+    /// Like [`with_min_dwell`](Self::with_min_dwell), measuring dwell on
+    /// `clock`
+    pub fn with_min_dwell_clock(self, config: DwellConfig, clock: Arc<dyn Clock>) -> Self {
+        self.with_dwell_filter(DwellFilter::with_clock(config, clock))
+    }
+
+    fn with_dwell_filter(mut self, filter: DwellFilter<HeldCapture>) -> Self {
+        self.dwell = Some(Mutex::new(filter));
+        self
+    }
+
+    /// Throttle snapshot persistence while the sync queue is backed up.
+    ///
+    /// At `Elevated` pressure only every other capture is persisted; at
+    /// `Critical` persistence is skipped until the queue drains. Captured
+    /// contexts are still returned to the caller either way.
+    pub fn with_backpressure(mut self, pressure: watch::Receiver<QueuePressure>) -> Self {
+        self.backpressure = Some(pressure);
+        self
+    }
+
+    /// Emit capture counters through `metrics`
+    ///
+    /// Defaults to a no-op collector.
+    pub fn with_metrics(mut self, metrics: Arc<dyn MetricsCollector>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     /// Capture and save the current activity
     ///
+    /// Captures made while the provider lacks its OS permission are marked
+    /// [`CaptureMode::AppOnly`] so degraded snapshots can be told apart. With
+    /// a minimum dwell configured, the snapshot persisted (if any) may be a
+    /// held capture of the previously focused app.
+    ///
     /// PHASE-0: Returns ActivityContext instead of ActivitySnapshot
     /// Snapshot creation happens in infra layer for proper type compatibility
     pub async fn capture_activity(&self) -> Result<ActivityContext> {
         // Get activity from provider
         let mut context = {
             let provider = self.provider.lock().await;
-            provider.get_activity().await?
+            let mut context = provider.get_activity().await?;
+            if !provider.permission_status().is_granted() {
+                context.capture_mode = CaptureMode::AppOnly;
+            }
+            context
         };
 
         // Enrich the context
@@ -65,11 +161,26 @@ impl TrackingService {
             enricher.enrich(&mut context).await?;
         }
 
-        if self.persist_captures {
-            if let Err(err) = self.persist_activity(&context).await {
-                error!(error = %err, "Failed to persist captured activity snapshot");
+        let persisted = if self.persist_captures {
+            let metadata = SnapshotMetadata::now();
+            match &self.dwell {
+                Some(dwell) => {
+                    let ready = dwell
+                        .lock()
+                        .await
+                        .observe(focus_key(&context), (context.clone(), metadata));
+                    match ready {
+                        Some((held, metadata)) => self.record(&held, metadata).await,
+                        None => false,
+                    }
+                }
+                None => self.record(&context, metadata).await,
             }
-        }
+        } else {
+            false
+        };
+        let persisted_label = if persisted { "true" } else { "false" };
+        self.metrics.increment_counter(CAPTURES_METRIC, &[("persisted", persisted_label)]);
 
         // Return enriched context - snapshot creation happens in infra layer
         Ok(context)
@@ -147,6 +258,7 @@ impl TrackingService {
             location: None,
             temporal_context: None,
             classification: None,
+            capture_mode: Default::default(),
         };
 
         // Create and save the snapshot
@@ -158,9 +270,359 @@ impl TrackingService {
         Ok(snapshot_id)
     }
 
-    async fn persist_activity(&self, context: &ActivityContext) -> Result<()> {
-        let metadata = SnapshotMetadata::now();
+    /// Persist a capture unless backpressure skips it, returning whether it
+    /// was persisted
+    async fn record(&self, context: &ActivityContext, metadata: SnapshotMetadata) -> bool {
+        if !self.admit_under_pressure() {
+            return false;
+        }
+
+        if let Err(err) = self.persist_activity(context, metadata).await {
+            error!(error = %err, "Failed to persist captured activity snapshot");
+            self.metrics.increment_counter(CAPTURE_PERSIST_ERRORS_METRIC, &[]);
+            return false;
+        }
+
+        if let Some(policy) = &self.retention {
+            if let Err(err) = policy.enforce(self.repository.as_ref()).await {
+                warn!(error = %err, "Capture-time snapshot eviction failed");
+            }
+        }
+        true
+    }
+
+    /// Whether the current capture may be persisted under queue backpressure
+    fn admit_under_pressure(&self) -> bool {
+        let Some(pressure) = &self.backpressure else {
+            return true;
+        };
+
+        let current = *pressure.borrow();
+        let admitted = match current.level {
+            PressureLevel::Normal => {
+                self.captures_under_pressure.store(0, Ordering::Relaxed);
+                true
+            }
+            PressureLevel::Elevated => {
+                self.captures_under_pressure.fetch_add(1, Ordering::Relaxed).is_multiple_of(2)
+            }
+            PressureLevel::Critical => false,
+        };
+
+        if !admitted {
+            self.metrics.increment_counter(CAPTURES_THROTTLED_METRIC, &[]);
+            debug!(
+                ratio = current.ratio,
+                level = ?current.level,
+                "Skipping snapshot persistence under sync queue backpressure"
+            );
+        }
+        admitted
+    }
+
+    async fn persist_activity(
+        &self,
+        context: &ActivityContext,
+        metadata: SnapshotMetadata,
+    ) -> Result<()> {
+        let Some(dedup) = &self.dedup else {
+            let snapshot = ActivitySnapshot::from_activity_context(context, metadata)?;
+            return self.repository.save_snapshot(snapshot).await;
+        };
+
+        let hash = content_hash(context);
+        let timestamp = metadata.timestamp;
+        let mut dedup = dedup.lock().await;
+
+        if let Some((snapshot_id, run)) = dedup.extension(&hash, timestamp) {
+            if self.repository.extend_snapshot(&snapshot_id, run).await? {
+                dedup.commit_extension(run);
+                return Ok(());
+            }
+            // Representative was processed or evicted; start a new run
+            dedup.reset();
+        }
+
         let snapshot = ActivitySnapshot::from_activity_context(context, metadata)?;
-        self.repository.save_snapshot(snapshot).await
+        let snapshot_id = snapshot.id.clone();
+        self.repository.save_snapshot(snapshot).await?;
+        dedup.start_run(snapshot_id, hash, timestamp);
+        Ok(())
+    }
+}
+
+/// App identity used to measure focus dwell
+fn focus_key(context: &ActivityContext) -> &str {
+    context.active_app.bundle_id.as_deref().unwrap_or(&context.active_app.app_name)
+}
+
+#[async_trait]
+impl PermissionChecker for TrackingService {
+    /// Permission status reported by the activity provider
+    async fn accessibility_permission(&self) -> PermissionStatus {
+        self.provider.lock().await.permission_status()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex as StdMutex;
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use pulsearc_common::sync::PressureThresholds;
+    use pulsearc_common::time::MockClock;
+    use pulsearc_domain::types::WindowContext;
+
+    use super::*;
+    use crate::tracking::dedup::SnapshotRun;
+    use crate::tracking::retention::SnapshotStorageUsage;
+
+    fn context() -> ActivityContext {
+        ActivityContext {
+            active_app: WindowContext {
+                app_name: "Xcode".to_string(),
+                window_title: "main.rs".to_string(),
+                bundle_id: None,
+                url: None,
+                url_host: None,
+                document_name: None,
+                file_path: None,
+            },
+            recent_apps: vec![],
+            detected_activity: "working".to_string(),
+            work_type: None,
+            activity_category: Default::default(),
+            billable_confidence: 0.0,
+            suggested_client: None,
+            suggested_matter: None,
+            suggested_task_code: None,
+            extracted_metadata: Default::default(),
+            evidence: Default::default(),
+            calendar_event: None,
+            location: None,
+            temporal_context: None,
+            classification: None,
+            capture_mode: Default::default(),
+        }
+    }
+
+    /// Provider returning the same context on every poll
+    struct FixedProvider;
+
+    #[async_trait]
+    impl ActivityProvider for FixedProvider {
+        async fn get_activity(&self) -> Result<ActivityContext> {
+            Ok(context())
+        }
+
+        fn is_paused(&self) -> bool {
+            false
+        }
+
+        fn pause(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn resume(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Provider without Accessibility permission
+    struct UnpermittedProvider;
+
+    #[async_trait]
+    impl ActivityProvider for UnpermittedProvider {
+        async fn get_activity(&self) -> Result<ActivityContext> {
+            Ok(context())
+        }
+
+        fn is_paused(&self) -> bool {
+            false
+        }
+
+        fn pause(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn resume(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn permission_status(&self) -> PermissionStatus {
+            PermissionStatus::Denied
+        }
+    }
+
+    /// Provider reporting whichever app the test last focused
+    #[derive(Clone, Default)]
+    struct FocusProvider(Arc<StdMutex<&'static str>>);
+
+    impl FocusProvider {
+        fn focus(&self, app: &'static str) {
+            *self.0.lock().unwrap() = app;
+        }
+    }
+
+    #[async_trait]
+    impl ActivityProvider for FocusProvider {
+        async fn get_activity(&self) -> Result<ActivityContext> {
+            let mut context = context();
+            context.active_app.app_name = self.0.lock().unwrap().to_string();
+            Ok(context)
+        }
+
+        fn is_paused(&self) -> bool {
+            false
+        }
+
+        fn pause(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn resume(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Repository counting saved snapshots
+    #[derive(Default)]
+    struct CountingRepository {
+        saved: StdMutex<usize>,
+        last: StdMutex<Option<ActivitySnapshot>>,
+    }
+
+    impl CountingRepository {
+        fn saved(&self) -> usize {
+            *self.saved.lock().unwrap()
+        }
+
+        fn last_context(&self) -> ActivityContext {
+            let last = self.last.lock().unwrap();
+            let snapshot = last.as_ref().expect("a saved snapshot");
+            serde_json::from_str(&snapshot.activity_context_json).unwrap()
+        }
+    }
+
+    #[async_trait]
+    impl ActivityRepository for CountingRepository {
+        async fn save_snapshot(&self, snapshot: ActivitySnapshot) -> Result<()> {
+            *self.saved.lock().unwrap() += 1;
+            *self.last.lock().unwrap() = Some(snapshot);
+            Ok(())
+        }
+
+        async fn get_snapshots(
+            &self,
+            _start: DateTime<Utc>,
+            _end: DateTime<Utc>,
+        ) -> Result<Vec<ActivitySnapshot>> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_old_snapshots(&self, _before: DateTime<Utc>) -> Result<usize> {
+            Ok(0)
+        }
+
+        async fn snapshot_storage_usage(&self) -> Result<SnapshotStorageUsage> {
+            Ok(SnapshotStorageUsage::default())
+        }
+
+        async fn evict_synced_snapshots(&self, _limit: usize) -> Result<usize> {
+            Ok(0)
+        }
+
+        async fn extend_snapshot(&self, _snapshot_id: &str, _run: SnapshotRun) -> Result<bool> {
+            Ok(false)
+        }
+
+        async fn snapshot_run(&self, _snapshot_id: &str) -> Result<Option<SnapshotRun>> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn backpressure_throttles_persistence() {
+        let thresholds = PressureThresholds::default();
+        let (pressure, receiver) = watch::channel(QueuePressure::new(0, 100, &thresholds));
+        let repository = Arc::new(CountingRepository::default());
+        let service =
+            TrackingService::new(FixedProvider, repository.clone()).with_backpressure(receiver);
+
+        let capture = |count: usize| {
+            let service = &service;
+            async move {
+                for _ in 0..count {
+                    service.capture_activity().await.expect("capture");
+                }
+            }
+        };
+
+        capture(4).await;
+        assert_eq!(repository.saved(), 4, "normal pressure persists every capture");
+
+        pressure.send_replace(QueuePressure::new(80, 100, &thresholds));
+        capture(4).await;
+        assert_eq!(repository.saved(), 6, "elevated pressure persists every other capture");
+
+        pressure.send_replace(QueuePressure::new(95, 100, &thresholds));
+        capture(3).await;
+        assert_eq!(repository.saved(), 6, "critical pressure skips persistence");
+
+        pressure.send_replace(QueuePressure::new(10, 100, &thresholds));
+        capture(1).await;
+        assert_eq!(repository.saved(), 7);
+    }
+
+    #[tokio::test]
+    async fn captures_without_permission_are_flagged_app_only() {
+        let repository = Arc::new(CountingRepository::default());
+        let service = TrackingService::new(UnpermittedProvider, repository.clone());
+
+        let context = service.capture_activity().await.expect("capture");
+
+        assert_eq!(context.capture_mode, CaptureMode::AppOnly);
+        assert_eq!(repository.last_context().capture_mode, CaptureMode::AppOnly);
+        assert_eq!(service.accessibility_permission().await, PermissionStatus::Denied);
+    }
+
+    #[tokio::test]
+    async fn captures_with_permission_are_full() {
+        let repository = Arc::new(CountingRepository::default());
+        let service = TrackingService::new(FixedProvider, repository.clone());
+
+        service.capture_activity().await.expect("capture");
+
+        assert_eq!(repository.last_context().capture_mode, CaptureMode::Full);
+    }
+
+    #[tokio::test]
+    async fn min_dwell_discards_brief_focus_and_records_sustained_focus() {
+        let provider = FocusProvider::default();
+        let repository = Arc::new(CountingRepository::default());
+        let clock = MockClock::new();
+        let service = TrackingService::new(provider.clone(), repository.clone())
+            .with_min_dwell_clock(
+                DwellConfig { min_dwell: Duration::from_secs(1) },
+                Arc::new(clock.clone()),
+            );
+
+        // Alt-tab past Slack for 200ms on the way to Xcode
+        provider.focus("Slack");
+        service.capture_activity().await.expect("capture");
+        clock.advance(Duration::from_millis(200));
+        provider.focus("Xcode");
+        service.capture_activity().await.expect("capture");
+        assert_eq!(repository.saved(), 0, "neither focus has dwelled long enough");
+
+        // Xcode stays focused for 5s, then the user moves on
+        clock.advance(Duration::from_secs(5));
+        provider.focus("Safari");
+        service.capture_activity().await.expect("capture");
+
+        assert_eq!(repository.saved(), 1);
+        assert_eq!(repository.last_context().active_app.app_name, "Xcode");
     }
 }
@@ -0,0 +1,388 @@
+//! Merged day timeline
+//!
+//! Combines calendar events, proposed blocks and activity segments into one
+//! list ordered by start time, so the frontend does not have to stitch them
+//! together. Segments already covered by a block are left out (the block
+//! stands for them), as are rejected blocks.
+//!
+//! Times are rendered in one caller-supplied timezone. All-day calendar
+//! events are stored at UTC midnight of their date; they are anchored at
+//! midnight of the same date in that timezone so they do not drift onto the
+//! previous or next day.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use pulsearc_domain::types::classification::ProposedBlock;
+use pulsearc_domain::{
+    ActivitySegment, CalendarEventRow, PulseArcError, Result, TimeRange, TimelineActivity,
+    TimelineCalendarEvent, TimelineItem,
+};
+
+use crate::classification::ports::BlockRepository;
+use crate::tracking::ports::SegmentRepository;
+
+/// Format of `start_time` strings on the timeline
+const START_TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Block status hidden from the timeline
+const REJECTED_STATUS: &str = "rejected";
+
+/// Build the timeline for `range` from stored activity and `calendar_events`.
+///
+/// Blocks and segments are loaded per UTC day covering the range; items that
+/// overlap the range are kept.
+///
+/// # Errors
+///
+/// `InvalidInput` for an empty range, or any repository error.
+pub async fn get_timeline<Tz: TimeZone>(
+    block_repo: &dyn BlockRepository,
+    segment_repo: &dyn SegmentRepository,
+    calendar_events: Vec<CalendarEventRow>,
+    range: TimeRange,
+    tz: &Tz,
+) -> Result<Vec<TimelineItem>> {
+    let start = timestamp(range.start_ts)?;
+    let end = timestamp(range.end_ts)?;
+    if end <= start {
+        return Err(PulseArcError::InvalidInput(format!(
+            "timeline range is empty: {}..{}",
+            range.start_ts, range.end_ts
+        )));
+    }
+
+    let overlaps = |start_ts: i64, end_ts: i64| start_ts < range.end_ts && end_ts > range.start_ts;
+
+    let mut blocks = Vec::new();
+    let mut segments = Vec::new();
+    let mut date: NaiveDate = start.date_naive();
+    while date <= end.date_naive() {
+        blocks.extend(
+            block_repo
+                .get_proposed_blocks(date)
+                .await?
+                .into_iter()
+                .filter(|block| overlaps(block.start_ts, block.end_ts)),
+        );
+        segments.extend(
+            segment_repo
+                .find_segments_by_date(date)
+                .map_err(|e| PulseArcError::Database(e.to_string()))?
+                .into_iter()
+                .filter(|segment| overlaps(segment.start_ts, segment.end_ts)),
+        );
+        date += Duration::days(1);
+    }
+
+    Ok(merge_timeline(&calendar_events, &blocks, &segments, tz))
+}
+
+/// Merge calendar events, blocks and segments into one timeline.
+///
+/// Items are ordered by start time; items starting together are ordered
+/// calendar events first, then blocks, then segments, then by id. Duplicate
+/// blocks or segments (e.g. from overlapping day queries) appear once.
+pub fn merge_timeline<Tz: TimeZone>(
+    calendar_events: &[CalendarEventRow],
+    blocks: &[ProposedBlock],
+    segments: &[ActivitySegment],
+    tz: &Tz,
+) -> Vec<TimelineItem> {
+    let mut seen = HashSet::new();
+    let mut items: Vec<TimelineItem> = calendar_events
+        .iter()
+        .map(|event| TimelineItem::CalendarEvent(timeline_calendar_event(event, tz)))
+        .collect();
+
+    let mut covered_segments = HashSet::new();
+    for block in blocks.iter().filter(|block| block.status != REJECTED_STATUS) {
+        if !seen.insert(("block", block.id.as_str())) {
+            continue;
+        }
+        covered_segments.extend(block.segment_ids.iter().map(String::as_str));
+        items.push(TimelineItem::Block(block_activity(block, tz)));
+    }
+
+    for segment in segments {
+        if covered_segments.contains(segment.id.as_str())
+            || !seen.insert(("segment", segment.id.as_str()))
+        {
+            continue;
+        }
+        items.push(TimelineItem::Segment(segment_activity(segment, tz)));
+    }
+
+    items.sort_by(|a, b| {
+        a.start_epoch()
+            .cmp(&b.start_epoch())
+            .then_with(|| kind_rank(a).cmp(&kind_rank(b)))
+            .then_with(|| a.id().cmp(b.id()))
+    });
+    items
+}
+
+/// Timeline entry for a stored calendar event
+pub fn timeline_calendar_event<Tz: TimeZone>(
+    event: &CalendarEventRow,
+    tz: &Tz,
+) -> TimelineCalendarEvent {
+    let start_epoch =
+        if event.is_all_day { local_midnight(event.start_ts, tz) } else { event.start_ts };
+
+    TimelineCalendarEvent {
+        id: event.id.clone(),
+        project: event.parsed_project.clone().unwrap_or_default(),
+        task: event.parsed_task.clone().unwrap_or_default(),
+        start_time: format_start(start_epoch, tz),
+        start_epoch,
+        duration: event.end_ts - event.start_ts,
+        status: "active".to_string(), // Calendar events are always active
+        is_calendar_event: true,
+        is_all_day: event.is_all_day,
+        original_summary: event.summary.clone(),
+    }
+}
+
+fn block_activity<Tz: TimeZone>(block: &ProposedBlock, tz: &Tz) -> TimelineActivity {
+    let dominant_app = block
+        .activities
+        .iter()
+        .max_by(|a, b| a.duration_secs.cmp(&b.duration_secs).then_with(|| b.name.cmp(&a.name)))
+        .map(|activity| activity.name.clone())
+        .unwrap_or_default();
+    let label = block
+        .inferred_deal_name
+        .clone()
+        .or_else(|| block.inferred_project_id.clone())
+        .unwrap_or_else(|| dominant_app.clone());
+
+    TimelineActivity {
+        id: block.id.clone(),
+        label,
+        primary_app: dominant_app,
+        project_id: block.inferred_project_id.clone(),
+        status: Some(block.status.clone()),
+        start_time: format_start(block.start_ts, tz),
+        start_epoch: block.start_ts,
+        duration: block.end_ts - block.start_ts,
+    }
+}
+
+fn segment_activity<Tz: TimeZone>(segment: &ActivitySegment, tz: &Tz) -> TimelineActivity {
+    TimelineActivity {
+        id: segment.id.clone(),
+        label: segment.normalized_label.clone(),
+        primary_app: segment.primary_app.clone(),
+        project_id: None,
+        status: None,
+        start_time: format_start(segment.start_ts, tz),
+        start_epoch: segment.start_ts,
+        duration: segment.end_ts - segment.start_ts,
+    }
+}
+
+fn kind_rank(item: &TimelineItem) -> u8 {
+    match item {
+        TimelineItem::CalendarEvent(_) => 0,
+        TimelineItem::Block(_) => 1,
+        TimelineItem::Segment(_) => 2,
+    }
+}
+
+/// Midnight in `tz` of the UTC date of `ts`
+fn local_midnight<Tz: TimeZone>(ts: i64, tz: &Tz) -> i64 {
+    DateTime::from_timestamp(ts, 0)
+        .and_then(|utc| utc.date_naive().and_hms_opt(0, 0, 0))
+        .and_then(|midnight| tz.from_local_datetime(&midnight).earliest())
+        .map_or(ts, |local| local.timestamp())
+}
+
+fn format_start<Tz: TimeZone>(ts: i64, tz: &Tz) -> String {
+    tz.timestamp_opt(ts, 0)
+        .single()
+        .map(|dt| dt.naive_local().format(START_TIME_FORMAT).to_string())
+        .unwrap_or_else(|| "Unknown".to_string())
+}
+
+fn timestamp(ts: i64) -> Result<DateTime<Utc>> {
+    DateTime::from_timestamp(ts, 0)
+        .ok_or_else(|| PulseArcError::InvalidInput(format!("invalid timestamp: {ts}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::FixedOffset;
+    use pulsearc_domain::types::classification::ActivityBreakdown;
+
+    use super::*;
+
+    const NINE_AM: i64 = 1_741_597_200; // 2025-03-10 09:00:00 UTC
+
+    fn utc() -> FixedOffset {
+        FixedOffset::east_opt(0).unwrap()
+    }
+
+    fn event(id: &str, start_ts: i64, end_ts: i64, is_all_day: bool) -> CalendarEventRow {
+        CalendarEventRow {
+            id: id.into(),
+            google_event_id: format!("g-{id}"),
+            user_email: "user@example.com".into(),
+            summary: format!("Event {id}"),
+            description: None,
+            start_ts,
+            end_ts,
+            is_all_day,
+            recurring_event_id: None,
+            parsed_project: Some("Astro".into()),
+            parsed_workstream: None,
+            parsed_task: Some("sync".into()),
+            confidence_score: None,
+            meeting_platform: None,
+            is_recurring_series: false,
+            is_online_meeting: false,
+            has_external_attendees: None,
+            organizer_email: None,
+            organizer_domain: None,
+            meeting_id: None,
+            attendee_count: None,
+            external_attendee_count: None,
+            created_at: start_ts,
+        }
+    }
+
+    fn segment(id: &str, start_ts: i64, end_ts: i64) -> ActivitySegment {
+        ActivitySegment {
+            id: id.into(),
+            start_ts,
+            end_ts,
+            primary_app: "Microsoft Excel".into(),
+            normalized_label: "Astro model".into(),
+            sample_count: 10,
+            dictionary_keys: None,
+            created_at: end_ts,
+            processed: true,
+            snapshot_ids: vec![],
+            work_type: None,
+            activity_category: "client_work".into(),
+            detected_activity: "modeling".into(),
+            extracted_signals_json: None,
+            project_match_json: None,
+            idle_time_secs: 0,
+            active_time_secs: (end_ts - start_ts) as i32,
+            user_action: None,
+        }
+    }
+
+    fn block(id: &str, start_ts: i64, end_ts: i64, segment_ids: &[&str]) -> ProposedBlock {
+        ProposedBlock {
+            id: id.into(),
+            start_ts,
+            end_ts,
+            duration_secs: end_ts - start_ts,
+            inferred_project_id: Some("USC0063201".into()),
+            inferred_wbs_code: None,
+            inferred_deal_name: Some("Project Astro".into()),
+            inferred_workstream: None,
+            billable: true,
+            confidence: 0.8,
+            classifier_used: Some("hybrid".into()),
+            activities: vec![ActivityBreakdown {
+                name: "Microsoft Excel".into(),
+                duration_secs: end_ts - start_ts,
+                percentage: 100.0,
+            }],
+            snapshot_ids: vec![],
+            segment_ids: segment_ids.iter().map(|id| id.to_string()).collect(),
+            reasons: vec![],
+            status: "suggested".into(),
+            created_at: end_ts,
+            reviewed_at: None,
+            total_idle_secs: 0,
+            idle_handling: "exclude".into(),
+            timezone: None,
+            work_location: None,
+            is_travel: false,
+            is_weekend: false,
+            is_after_hours: false,
+            has_calendar_overlap: false,
+            overlapping_event_ids: vec![],
+            is_double_booked: false,
+        }
+    }
+
+    fn kinds_and_ids(items: &[TimelineItem]) -> Vec<(String, String)> {
+        items
+            .iter()
+            .map(|item| {
+                let json = serde_json::to_value(item).unwrap();
+                (json["kind"].as_str().unwrap().to_string(), item.id().to_string())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn merged_timeline_is_ordered_by_start_time() {
+        let events = vec![event("e2", NINE_AM + 7_200, NINE_AM + 9_000, false)];
+        let blocks = vec![block("b1", NINE_AM + 1_800, NINE_AM + 3_600, &["s2"])];
+        let segments = vec![
+            segment("s3", NINE_AM + 10_800, NINE_AM + 11_400),
+            segment("s1", NINE_AM, NINE_AM + 600),
+            segment("s2", NINE_AM + 1_800, NINE_AM + 3_600),
+        ];
+
+        let items = merge_timeline(&events, &blocks, &segments, &utc());
+
+        let starts: Vec<i64> = items.iter().map(TimelineItem::start_epoch).collect();
+        assert!(starts.windows(2).all(|pair| pair[0] <= pair[1]), "ordered: {starts:?}");
+        assert_eq!(
+            kinds_and_ids(&items),
+            vec![
+                ("segment".to_string(), "s1".to_string()),
+                ("block".to_string(), "b1".to_string()),
+                ("calendar_event".to_string(), "e2".to_string()),
+                ("segment".to_string(), "s3".to_string()),
+            ],
+            "s2 is represented by its block"
+        );
+    }
+
+    #[test]
+    fn overlapping_calendar_and_activity_items_both_appear() {
+        let events = vec![event("standup", NINE_AM, NINE_AM + 1_800, false)];
+        let blocks = vec![block("b1", NINE_AM, NINE_AM + 3_600, &[])];
+        let segments = vec![segment("s1", NINE_AM + 600, NINE_AM + 1_200)];
+
+        let items = merge_timeline(&events, &blocks, &segments, &utc());
+
+        assert_eq!(
+            kinds_and_ids(&items),
+            vec![
+                ("calendar_event".to_string(), "standup".to_string()),
+                ("block".to_string(), "b1".to_string()),
+                ("segment".to_string(), "s1".to_string()),
+            ]
+        );
+        let json = serde_json::to_value(&items[1]).unwrap();
+        assert_eq!(json["label"], "Project Astro");
+        assert_eq!(json["startTime"], "2025-03-10 09:00:00");
+    }
+
+    #[test]
+    fn times_use_the_given_timezone_and_all_day_events_keep_their_date() {
+        let new_york = FixedOffset::west_opt(4 * 3_600).unwrap();
+        let march_10 = 1_741_564_800; // 2025-03-10 00:00:00 UTC
+        let events = vec![event("holiday", march_10, march_10 + 86_400, true)];
+        let segments = vec![segment("s1", NINE_AM, NINE_AM + 600)];
+
+        let items = merge_timeline(&events, &[], &segments, &new_york);
+
+        let TimelineItem::CalendarEvent(holiday) = &items[0] else {
+            panic!("all-day event sorts first in its local day");
+        };
+        assert_eq!(holiday.start_time, "2025-03-10 00:00:00");
+        assert_eq!(holiday.start_epoch, march_10 + 4 * 3_600);
+        let TimelineItem::Segment(activity) = &items[1] else { panic!("segment") };
+        assert_eq!(activity.start_time, "2025-03-10 05:00:00");
+    }
+}
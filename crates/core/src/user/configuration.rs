@@ -0,0 +1,135 @@
+//! Configuration export and import
+//!
+//! A [`ConfigBundle`] carries the profile, settings store entries, feature flag
+//! overrides and calendar accounts between machines. Bundles are versioned;
+//! importing checks the version and every part of the bundle before anything
+//! is written.
+
+use pulsearc_domain::{
+    CalendarSyncSettings, ConfigBundle, ConfigSetting, IdleSettings, PulseArcError, Result,
+    WorkdayConfig, CONFIG_BUNDLE_VERSION,
+};
+
+use super::ports::ConfigurationRepository;
+use super::settings::UserSetting;
+use super::validation::{validate_user_profile, validation_error_to_domain};
+use super::workday::WorkdaySchedule;
+
+/// Validate `bundle` and apply it
+///
+/// # Errors
+/// Returns `PulseArcError::InvalidInput` if the bundle version is not
+/// [`CONFIG_BUNDLE_VERSION`] or any part of it is invalid; nothing is written
+/// in that case.
+pub async fn import_configuration(
+    repo: &dyn ConfigurationRepository,
+    bundle: &ConfigBundle,
+) -> Result<()> {
+    validate_config_bundle(bundle)?;
+    repo.import_configuration(bundle).await
+}
+
+/// Check that a bundle can be imported by this build
+pub fn validate_config_bundle(bundle: &ConfigBundle) -> Result<()> {
+    if bundle.version != CONFIG_BUNDLE_VERSION {
+        return Err(PulseArcError::InvalidInput(format!(
+            "configuration bundle version {} is not supported (expected version {})",
+            bundle.version, CONFIG_BUNDLE_VERSION
+        )));
+    }
+
+    if let Some(profile) = &bundle.profile {
+        validate_user_profile(profile).map_err(|err| validation_error_to_domain(&err))?;
+    }
+
+    for setting in &bundle.settings {
+        validate_setting(setting)?;
+    }
+
+    if bundle.feature_flags.iter().any(|flag| flag.flag_name.trim().is_empty()) {
+        return Err(invalid("feature flag names must not be empty"));
+    }
+
+    for connection in &bundle.calendar_connections {
+        if connection.email.trim().is_empty() || connection.provider.trim().is_empty() {
+            return Err(invalid("calendar connections need a provider and an email"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Known keys must decode as their type; unknown keys only need to be JSON
+fn validate_setting(setting: &ConfigSetting) -> Result<()> {
+    if setting.key.trim().is_empty() {
+        return Err(invalid("setting keys must not be empty"));
+    }
+
+    let key = setting.key.as_str();
+    if key == IdleSettings::KEY {
+        decode::<IdleSettings>(setting).map(drop)
+    } else if key == CalendarSyncSettings::KEY {
+        decode::<CalendarSyncSettings>(setting).map(drop)
+    } else if key == WorkdayConfig::KEY {
+        let workday = decode::<WorkdayConfig>(setting)?;
+        WorkdaySchedule::new(&workday, "UTC")
+            .map(drop)
+            .map_err(|err| invalid(&format!("setting '{key}': {err}")))
+    } else {
+        serde_json::from_str::<serde_json::Value>(&setting.value)
+            .map(drop)
+            .map_err(|err| invalid(&format!("setting '{key}' is not JSON: {err}")))
+    }
+}
+
+fn decode<S: UserSetting>(setting: &ConfigSetting) -> Result<S> {
+    serde_json::from_str(&setting.value)
+        .map_err(|err| invalid(&format!("setting '{}' is unreadable: {err}", setting.key)))
+}
+
+fn invalid(message: &str) -> PulseArcError {
+    PulseArcError::InvalidInput(format!("invalid configuration bundle: {message}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bundle() -> ConfigBundle {
+        ConfigBundle {
+            version: CONFIG_BUNDLE_VERSION,
+            exported_at: 0,
+            profile: None,
+            settings: vec![ConfigSetting {
+                key: "idle".into(),
+                scope: String::new(),
+                value: r#"{"pause_on_idle":false,"idle_threshold_secs":300}"#.into(),
+            }],
+            feature_flags: Vec::new(),
+            calendar_connections: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn accepts_current_version() {
+        assert!(validate_config_bundle(&bundle()).is_ok());
+    }
+
+    #[test]
+    fn rejects_incompatible_version() {
+        let mut future = bundle();
+        future.version = CONFIG_BUNDLE_VERSION + 1;
+
+        let err = validate_config_bundle(&future).unwrap_err();
+
+        assert!(matches!(err, PulseArcError::InvalidInput(ref msg) if msg.contains("version 2")));
+    }
+
+    #[test]
+    fn rejects_unreadable_known_setting() {
+        let mut broken = bundle();
+        broken.settings[0].value = r#"{"pause_on_idle":"sometimes"}"#.into();
+
+        assert!(validate_config_bundle(&broken).is_err());
+    }
+}
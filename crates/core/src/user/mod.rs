@@ -1,7 +1,21 @@
 //! User profile management
 //!
-//! Port definitions for user profile operations
+//! Port definitions for user profile and settings operations, profile
+//! validation, typed settings access, configuration export/import and
+//! per-user workday schedules
 
+pub mod configuration;
 pub mod ports;
+pub mod settings;
+pub mod validation;
+pub mod workday;
 
-pub use ports::UserProfileRepository;
+pub use configuration::{import_configuration, validate_config_bundle};
+pub use ports::{
+    ConfigurationRepository, SettingDefault, UserProfileRepository, UserSettingsRepository,
+};
+pub use settings::{
+    get_setting, reset_settings_to_defaults, set_setting, setting_history, UserSetting, USER_SCOPE,
+};
+pub use validation::{validate_user_profile, validation_error_to_domain};
+pub use workday::WorkdaySchedule;
@@ -4,7 +4,7 @@
 //! and infrastructure implementations for user profile operations.
 
 use async_trait::async_trait;
-use pulsearc_domain::{Result, UserProfile};
+use pulsearc_domain::{ConfigBundle, Result, SettingsResetSummary, UserProfile, UserSettingChange};
 
 /// Trait for user profile persistence and retrieval
 #[async_trait]
@@ -41,3 +41,65 @@ pub trait UserProfileRepository: Send + Sync {
     /// Delete a user profile by ID
     async fn delete(&self, id: &str) -> Result<()>;
 }
+
+/// Trait for the versioned user settings store
+///
+/// Settings are JSON values addressed by `(key, scope)`. Every change is
+/// appended to a history that is never rewritten. Use the typed helpers in
+/// [`crate::user::settings`] rather than calling this directly.
+#[async_trait]
+pub trait UserSettingsRepository: Send + Sync {
+    /// Get the latest value for a setting, if one was ever stored
+    async fn get_value(&self, key: &str, scope: &str) -> Result<Option<String>>;
+
+    /// Store a new value and record the change
+    ///
+    /// Returns `None` without recording anything if the value is unchanged.
+    async fn set_value(
+        &self,
+        key: &str,
+        scope: &str,
+        value: &str,
+    ) -> Result<Option<UserSettingChange>>;
+
+    /// All recorded changes for a setting, oldest first
+    async fn history(&self, key: &str, scope: &str) -> Result<Vec<UserSettingChange>>;
+
+    /// Restore every setting to its default in one transaction
+    ///
+    /// Each default replaces the stored value in every scope of its key (and
+    /// in [`crate::user::USER_SCOPE`] for user-wide settings), recording a
+    /// change for each value that differs. Tables that mirror a setting
+    /// (workday columns, calendar sync preferences) and feature flag
+    /// overrides are reset with it. Tracked data is never touched.
+    async fn reset_to_defaults(&self, defaults: &[SettingDefault]) -> Result<SettingsResetSummary>;
+}
+
+/// Default value for one setting key, passed to
+/// [`UserSettingsRepository::reset_to_defaults`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettingDefault {
+    pub key: &'static str,
+    /// JSON encoding of the default
+    pub value: String,
+    /// Whether the setting applies to the whole user rather than per account
+    pub user_wide: bool,
+}
+
+/// Trait for exporting and importing the whole user configuration
+///
+/// Use [`crate::user::import_configuration`] to import, which validates the
+/// bundle first.
+#[async_trait]
+pub trait ConfigurationRepository: Send + Sync {
+    /// Collect the profile, settings store entries, feature flag overrides and
+    /// calendar accounts into a bundle of the current version
+    async fn export_configuration(&self) -> Result<ConfigBundle>;
+
+    /// Apply a bundle in one transaction
+    ///
+    /// The profile is upserted by `auth0_id` (including its workday), each
+    /// setting is stored with a history entry, flag overrides are applied,
+    /// and calendar sync preferences are written for each account.
+    async fn import_configuration(&self, bundle: &ConfigBundle) -> Result<()>;
+}
@@ -0,0 +1,189 @@
+//! Typed access to the user settings store
+//!
+//! Each known setting type implements [`UserSetting`], which names the key it
+//! is stored under. Values are serialized as JSON, so adding a field with a
+//! serde default keeps older stored values readable.
+
+use pulsearc_domain::{
+    CalendarSyncSettings, IdleSettings, PulseArcError, Result, SettingsResetSummary,
+    UserSettingChange, WorkdayConfig,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::ports::{SettingDefault, UserSettingsRepository};
+
+/// Scope for settings that apply to the whole user rather than one account
+pub const USER_SCOPE: &str = "";
+
+/// A setting type with a fixed key in the settings store
+pub trait UserSetting: Serialize + DeserializeOwned {
+    /// Key the setting is stored under
+    const KEY: &'static str;
+}
+
+impl UserSetting for IdleSettings {
+    const KEY: &'static str = "idle";
+}
+
+impl UserSetting for WorkdayConfig {
+    const KEY: &'static str = "workday";
+}
+
+/// Stored per account, scoped by the account email. The sync engine reads the
+/// `calendar_sync_settings` table; the store keeps the change history.
+impl UserSetting for CalendarSyncSettings {
+    const KEY: &'static str = "calendar_sync";
+}
+
+/// Read the latest stored value of `S`, if any
+pub async fn get_setting<S: UserSetting>(
+    repo: &dyn UserSettingsRepository,
+    scope: &str,
+) -> Result<Option<S>> {
+    match repo.get_value(S::KEY, scope).await? {
+        Some(value) => serde_json::from_str(&value).map(Some).map_err(|err| {
+            PulseArcError::Internal(format!("stored setting '{}' is unreadable: {err}", S::KEY))
+        }),
+        None => Ok(None),
+    }
+}
+
+/// Store `value` as the latest `S`, recording the change in history
+///
+/// Returns `None` if the stored value was already equal.
+pub async fn set_setting<S: UserSetting>(
+    repo: &dyn UserSettingsRepository,
+    scope: &str,
+    value: &S,
+) -> Result<Option<UserSettingChange>> {
+    repo.set_value(S::KEY, scope, &encode(value)?).await
+}
+
+/// Recorded changes to `S`, oldest first
+pub async fn setting_history<S: UserSetting>(
+    repo: &dyn UserSettingsRepository,
+    scope: &str,
+) -> Result<Vec<UserSettingChange>> {
+    repo.history(S::KEY, scope).await
+}
+
+/// Restore idle, workday and calendar sync settings and feature flag
+/// overrides to their defaults
+///
+/// Runs as one transaction in the repository, with a history entry for each
+/// setting that changed. Snapshots, entries and blocks are left intact.
+pub async fn reset_settings_to_defaults(
+    repo: &dyn UserSettingsRepository,
+) -> Result<SettingsResetSummary> {
+    let defaults = vec![
+        setting_default(&IdleSettings::default(), true)?,
+        setting_default(&WorkdayConfig::default(), true)?,
+        setting_default(&CalendarSyncSettings::default(), false)?,
+    ];
+    repo.reset_to_defaults(&defaults).await
+}
+
+fn setting_default<S: UserSetting>(value: &S, user_wide: bool) -> Result<SettingDefault> {
+    Ok(SettingDefault { key: S::KEY, value: encode(value)?, user_wide })
+}
+
+fn encode<S: UserSetting>(value: &S) -> Result<String> {
+    serde_json::to_string(value).map_err(|err| {
+        PulseArcError::Internal(format!("failed to encode setting '{}': {err}", S::KEY))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use super::*;
+
+    /// Values keyed by `(key, scope)`
+    type Values = HashMap<(String, String), String>;
+
+    /// In-memory store keeping only the latest value per key
+    #[derive(Default)]
+    struct MemoryStore {
+        values: Mutex<Values>,
+    }
+
+    #[async_trait]
+    impl UserSettingsRepository for MemoryStore {
+        async fn get_value(&self, key: &str, scope: &str) -> Result<Option<String>> {
+            let values = self.values.lock().expect("lock");
+            Ok(values.get(&(key.to_string(), scope.to_string())).cloned())
+        }
+
+        async fn set_value(
+            &self,
+            key: &str,
+            scope: &str,
+            value: &str,
+        ) -> Result<Option<UserSettingChange>> {
+            let mut values = self.values.lock().expect("lock");
+            let old_value = values.insert((key.to_string(), scope.to_string()), value.to_string());
+            Ok(Some(UserSettingChange {
+                key: key.to_string(),
+                scope: scope.to_string(),
+                version: 1,
+                old_value,
+                new_value: value.to_string(),
+                changed_at: 0,
+            }))
+        }
+
+        async fn history(&self, _key: &str, _scope: &str) -> Result<Vec<UserSettingChange>> {
+            Ok(Vec::new())
+        }
+
+        async fn reset_to_defaults(
+            &self,
+            defaults: &[SettingDefault],
+        ) -> Result<SettingsResetSummary> {
+            let mut values = self.values.lock().expect("lock");
+            for default in defaults.iter().filter(|default| default.user_wide) {
+                values.insert((default.key.to_string(), String::new()), default.value.clone());
+            }
+            Ok(SettingsResetSummary::default())
+        }
+    }
+
+    #[tokio::test]
+    async fn round_trips_typed_settings_under_their_keys() {
+        let store = MemoryStore::default();
+        let idle = IdleSettings { pause_on_idle: false, idle_threshold_secs: 300 };
+
+        set_setting(&store, USER_SCOPE, &idle).await.unwrap();
+
+        assert_eq!(get_setting::<IdleSettings>(&store, USER_SCOPE).await.unwrap(), Some(idle));
+        assert_eq!(get_setting::<WorkdayConfig>(&store, USER_SCOPE).await.unwrap(), None);
+        assert!(store.values.lock().unwrap().contains_key(&("idle".to_string(), String::new())));
+    }
+
+    #[tokio::test]
+    async fn reset_restores_user_wide_defaults() {
+        let store = MemoryStore::default();
+        let idle = IdleSettings { pause_on_idle: false, idle_threshold_secs: 60 };
+        set_setting(&store, USER_SCOPE, &idle).await.unwrap();
+
+        reset_settings_to_defaults(&store).await.unwrap();
+
+        let idle = get_setting::<IdleSettings>(&store, USER_SCOPE).await.unwrap();
+        let workday = get_setting::<WorkdayConfig>(&store, USER_SCOPE).await.unwrap();
+        assert_eq!(idle, Some(IdleSettings::default()));
+        assert_eq!(workday, Some(WorkdayConfig::default()));
+    }
+
+    #[tokio::test]
+    async fn unreadable_value_is_an_error() {
+        let store = MemoryStore::default();
+        store.set_value("idle", USER_SCOPE, "{\"pause_on_idle\":").await.unwrap();
+
+        assert!(get_setting::<IdleSettings>(&store, USER_SCOPE).await.is_err());
+    }
+}
@@ -0,0 +1,121 @@
+//! User profile validation
+//!
+//! Checks a profile before it is persisted so malformed data from Auth0 sync
+//! or the frontend never reaches the database. All problems are collected
+//! into one [`ValidationError`] with an entry per field.
+
+use chrono_tz::Tz;
+use pulsearc_common::validation::{EmailValidator, ValidationError, ValidationResult, Validator};
+use pulsearc_domain::{PulseArcError, UserProfile};
+
+use super::workday::WorkdaySchedule;
+
+/// Validate a profile's identity, email, name, timezone and workday fields
+pub fn validate_user_profile(profile: &UserProfile) -> ValidationResult<()> {
+    let mut validator = Validator::new();
+
+    validator.validate_not_empty("id", &profile.id)?;
+    validator.validate_not_empty("auth0_id", &profile.auth0_id)?;
+    validator.validate_field("email", &profile.email.trim(), &EmailValidator::new())?;
+
+    if let Some(name) = &profile.name {
+        validator.validate_not_empty("name", name)?;
+    }
+
+    if profile.timezone.trim().parse::<Tz>().is_err() {
+        validator.add_error("timezone", format!("unknown timezone: {}", profile.timezone));
+    } else if let Err(err) = WorkdaySchedule::new(&profile.workday, &profile.timezone) {
+        validator.add_error("workday", workday_message(err));
+    }
+
+    validator.finalize()
+}
+
+/// Convert a validation failure into a domain error, keeping field names
+pub fn validation_error_to_domain(err: &ValidationError) -> PulseArcError {
+    let fields = err
+        .errors
+        .iter()
+        .map(|error| format!("{}: {}", error.field, error.message))
+        .collect::<Vec<_>>()
+        .join("; ");
+    PulseArcError::InvalidInput(format!("invalid user profile ({fields})"))
+}
+
+fn workday_message(err: PulseArcError) -> String {
+    match err {
+        PulseArcError::InvalidInput(message) => message,
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pulsearc_domain::WorkdayConfig;
+
+    use super::*;
+
+    fn profile() -> UserProfile {
+        UserProfile {
+            id: "user-1".into(),
+            auth0_id: "auth0|1".into(),
+            email: "ana@example.com".into(),
+            org_id: "org-1".into(),
+            name: Some("Ana Example".into()),
+            first_name: None,
+            last_name: None,
+            display_name: None,
+            avatar_url: None,
+            phone_number: None,
+            title: None,
+            department: None,
+            location: None,
+            bio: None,
+            timezone: "Europe/Berlin".into(),
+            language: "en".into(),
+            locale: "en-US".into(),
+            date_format: "YYYY-MM-DD".into(),
+            is_active: true,
+            email_verified: true,
+            two_factor_enabled: false,
+            last_login_at: 0,
+            last_synced_at: 0,
+            created_at: 0,
+            updated_at: 0,
+            workday: WorkdayConfig::default(),
+        }
+    }
+
+    #[test]
+    fn accepts_valid_profile() {
+        assert!(validate_user_profile(&profile()).is_ok());
+    }
+
+    #[test]
+    fn reports_each_invalid_field() {
+        let mut invalid = profile();
+        invalid.email = "not-an-email".into();
+        invalid.name = Some("   ".into());
+        invalid.timezone = "Mars/Olympus".into();
+
+        let err = validate_user_profile(&invalid).unwrap_err();
+
+        assert_eq!(err.error_count(), 3);
+        assert_eq!(err.field_errors("email")[0].message, "Invalid email format");
+        assert_eq!(err.field_errors("name").len(), 1);
+        assert_eq!(err.field_errors("timezone").len(), 1);
+
+        let domain = validation_error_to_domain(&err);
+        assert!(domain.to_string().contains("email: Invalid email format"));
+    }
+
+    #[test]
+    fn rejects_invalid_workday() {
+        let mut invalid = profile();
+        invalid.workday.working_days = vec![8];
+
+        let err = validate_user_profile(&invalid).unwrap_err();
+
+        assert_eq!(err.field_errors("workday").len(), 1);
+    }
+}
@@ -0,0 +1,255 @@
+//! Per-user working hours
+//!
+//! [`WorkdaySchedule`] evaluates a user's [`WorkdayConfig`] in their
+//! timezone. It decides whether a moment is after hours or on a non-working
+//! day (`TemporalContext`), and gives the UTC bounds of a local workday for
+//! gap detection and summaries.
+//!
+//! After-hours is judged on local time of day only. Work at 10:00 on a
+//! Saturday is not after hours; it is flagged by `is_weekend` instead.
+
+use chrono::{DateTime, Datelike, Duration, LocalResult, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use pulsearc_domain::{
+    PulseArcError, Result, TemporalContext, TimeRange, UserProfile, WorkdayConfig,
+};
+
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// A workday configuration bound to a timezone
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkdaySchedule {
+    timezone: Tz,
+    start: NaiveTime,
+    end: NaiveTime,
+    /// Indexed by days from Monday
+    working_days: [bool; 7],
+}
+
+impl Default for WorkdaySchedule {
+    /// 08:00-18:00, Monday-Friday, UTC
+    fn default() -> Self {
+        let config = WorkdayConfig::default();
+        Self {
+            timezone: Tz::UTC,
+            start: minutes_to_time(config.start_minutes),
+            end: minutes_to_time(config.end_minutes),
+            working_days: [true, true, true, true, true, false, false],
+        }
+    }
+}
+
+impl WorkdaySchedule {
+    /// Build a schedule from a workday config and an IANA timezone name
+    ///
+    /// # Errors
+    /// Returns `PulseArcError::InvalidInput` if the timezone is unknown, a
+    /// time is outside the day, the start equals the end, or a working day is
+    /// not 1-7.
+    pub fn new(config: &WorkdayConfig, timezone: &str) -> Result<Self> {
+        let timezone = timezone
+            .trim()
+            .parse::<Tz>()
+            .map_err(|_| PulseArcError::InvalidInput(format!("unknown timezone: {timezone}")))?;
+        validate(config)?;
+
+        let mut working_days = [false; 7];
+        for day in &config.working_days {
+            working_days[usize::from(day - 1)] = true;
+        }
+
+        Ok(Self {
+            timezone,
+            start: minutes_to_time(config.start_minutes),
+            end: minutes_to_time(config.end_minutes),
+            working_days,
+        })
+    }
+
+    /// Build the schedule configured on a user profile
+    pub fn from_profile(profile: &UserProfile) -> Result<Self> {
+        Self::new(&profile.workday, &profile.timezone)
+    }
+
+    /// Timezone the schedule is evaluated in
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    /// Whether `at` falls on a configured working day (local date)
+    pub fn is_working_day(&self, at: DateTime<Utc>) -> bool {
+        let weekday = at.with_timezone(&self.timezone).weekday();
+        self.working_days[weekday.num_days_from_monday() as usize]
+    }
+
+    /// Whether `at` falls on a non-working day (local date)
+    pub fn is_weekend(&self, at: DateTime<Utc>) -> bool {
+        !self.is_working_day(at)
+    }
+
+    /// Whether `at` is outside working hours (local time of day)
+    pub fn is_after_hours(&self, at: DateTime<Utc>) -> bool {
+        let time = at.with_timezone(&self.timezone).time();
+        if self.start < self.end {
+            time < self.start || time >= self.end
+        } else {
+            // Workday runs past midnight
+            time >= self.end && time < self.start
+        }
+    }
+
+    /// Temporal flags for `at`
+    pub fn temporal_context(&self, at: DateTime<Utc>) -> TemporalContext {
+        TemporalContext { is_weekend: self.is_weekend(at), is_after_hours: self.is_after_hours(at) }
+    }
+
+    /// UTC bounds of the workday starting on local `date`
+    ///
+    /// Returned for any date, working day or not. A local start or end that
+    /// does not exist (DST gap) is taken as the first valid instant after it.
+    pub fn workday_range(&self, date: NaiveDate) -> TimeRange {
+        let start = self.local_to_utc(date, self.start);
+        let end_date = if self.start < self.end { date } else { date + Duration::days(1) };
+        let end = self.local_to_utc(end_date, self.end);
+        TimeRange { start_ts: start.timestamp(), end_ts: end.timestamp(), is_all_day: false }
+    }
+
+    /// Local calendar date of `at`
+    pub fn local_date(&self, at: DateTime<Utc>) -> NaiveDate {
+        at.with_timezone(&self.timezone).date_naive()
+    }
+
+    fn local_to_utc(&self, date: NaiveDate, time: NaiveTime) -> DateTime<Utc> {
+        let local = date.and_time(time);
+        match self.timezone.from_local_datetime(&local) {
+            LocalResult::Single(at) | LocalResult::Ambiguous(at, _) => at.with_timezone(&Utc),
+            // Skipped by a DST change: DST gaps are at most an hour, so the
+            // same wall time an hour later exists
+            LocalResult::None => self
+                .timezone
+                .from_local_datetime(&(local + Duration::hours(1)))
+                .earliest()
+                .map_or_else(|| local.and_utc(), |at| at.with_timezone(&Utc)),
+        }
+    }
+}
+
+fn validate(config: &WorkdayConfig) -> Result<()> {
+    if config.start_minutes >= MINUTES_PER_DAY || config.end_minutes >= MINUTES_PER_DAY {
+        return Err(PulseArcError::InvalidInput(
+            "workday start and end must be within the day (0-1439 minutes)".into(),
+        ));
+    }
+    if config.start_minutes == config.end_minutes {
+        return Err(PulseArcError::InvalidInput("workday start and end must differ".into()));
+    }
+    if let Some(day) = config.working_days.iter().find(|day| !(1..=7).contains(*day)) {
+        return Err(PulseArcError::InvalidInput(format!(
+            "working day {day} is not an ISO weekday (1-7)"
+        )));
+    }
+    Ok(())
+}
+
+fn minutes_to_time(minutes: u32) -> NaiveTime {
+    NaiveTime::from_num_seconds_from_midnight_opt((minutes % MINUTES_PER_DAY) * 60, 0)
+        .unwrap_or(NaiveTime::MIN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(start_hour: u32, end_hour: u32, days: &[u8], timezone: &str) -> WorkdaySchedule {
+        let config = WorkdayConfig {
+            start_minutes: start_hour * 60,
+            end_minutes: end_hour * 60,
+            working_days: days.to_vec(),
+        };
+        WorkdaySchedule::new(&config, timezone).expect("valid schedule")
+    }
+
+    /// Wednesday 2025-03-12 at `hour`:`minute` in New York (EDT, UTC-4)
+    fn new_york(hour: u32, minute: u32) -> DateTime<Utc> {
+        chrono_tz::America::New_York
+            .with_ymd_and_hms(2025, 3, 12, hour, minute, 0)
+            .single()
+            .expect("valid local time")
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn evening_is_after_hours_only_for_early_finishers() {
+        let nine_to_five = schedule(9, 17, &[1, 2, 3, 4, 5], "America/New_York");
+        let late_finisher = schedule(9, 20, &[1, 2, 3, 4, 5], "America/New_York");
+        let evening = new_york(18, 30);
+
+        assert!(nine_to_five.is_after_hours(evening));
+        assert!(!late_finisher.is_after_hours(evening));
+        assert!(!nine_to_five.is_after_hours(new_york(9, 0)));
+        assert!(nine_to_five.is_after_hours(new_york(17, 0)));
+    }
+
+    #[test]
+    fn after_hours_uses_the_users_timezone() {
+        let london = schedule(9, 17, &[1, 2, 3, 4, 5], "Europe/London");
+
+        // 18:30 in New York is 22:30 in London
+        assert!(london.is_after_hours(new_york(18, 30)));
+        // 06:00 in New York is 10:00 in London
+        assert!(!london.is_after_hours(new_york(6, 0)));
+    }
+
+    #[test]
+    fn weekend_detection_respects_working_days() {
+        let weekdays = schedule(9, 17, &[1, 2, 3, 4, 5], "UTC");
+        let sunday_to_thursday = schedule(9, 17, &[7, 1, 2, 3, 4], "UTC");
+        let friday = Utc.with_ymd_and_hms(2025, 3, 14, 12, 0, 0).unwrap();
+        let sunday = Utc.with_ymd_and_hms(2025, 3, 16, 12, 0, 0).unwrap();
+
+        assert!(!weekdays.is_weekend(friday));
+        assert!(weekdays.is_weekend(sunday));
+        assert!(sunday_to_thursday.is_weekend(friday));
+        assert!(!sunday_to_thursday.is_weekend(sunday));
+
+        let context = weekdays.temporal_context(sunday);
+        assert!(context.is_weekend);
+        assert!(!context.is_after_hours);
+    }
+
+    #[test]
+    fn overnight_workday_wraps_midnight() {
+        let night_shift = schedule(22, 6, &[1, 2, 3, 4, 5], "UTC");
+        let date = NaiveDate::from_ymd_opt(2025, 3, 12).unwrap();
+
+        assert!(!night_shift.is_after_hours(Utc.with_ymd_and_hms(2025, 3, 12, 23, 0, 0).unwrap()));
+        assert!(!night_shift.is_after_hours(Utc.with_ymd_and_hms(2025, 3, 12, 2, 0, 0).unwrap()));
+        assert!(night_shift.is_after_hours(Utc.with_ymd_and_hms(2025, 3, 12, 12, 0, 0).unwrap()));
+
+        let range = night_shift.workday_range(date);
+        assert_eq!(range.end_ts - range.start_ts, 8 * 3_600);
+    }
+
+    #[test]
+    fn workday_range_is_in_local_time() {
+        let nine_to_five = schedule(9, 17, &[1, 2, 3, 4, 5], "America/New_York");
+        let date = NaiveDate::from_ymd_opt(2025, 3, 12).unwrap();
+
+        let range = nine_to_five.workday_range(date);
+
+        assert_eq!(range.start_ts, new_york(9, 0).timestamp());
+        assert_eq!(range.end_ts, new_york(17, 0).timestamp());
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        let mut config = WorkdayConfig::default();
+        assert!(WorkdaySchedule::new(&config, "Mars/Olympus").is_err());
+
+        config.working_days = vec![0];
+        assert!(WorkdaySchedule::new(&config, "UTC").is_err());
+
+        config = WorkdayConfig { start_minutes: 540, end_minutes: 540, ..Default::default() };
+        assert!(WorkdaySchedule::new(&config, "UTC").is_err());
+    }
+}
@@ -3,7 +3,9 @@ use std::sync::{Arc, Mutex};
 use async_trait::async_trait;
 use chrono::Utc;
 use pulsearc_core::tracking::ports::CalendarEventRepository;
-use pulsearc_domain::{CalendarEventParams, CalendarEventRow, Result as DomainResult};
+use pulsearc_domain::{
+    CalendarBatchOutcome, CalendarEventParams, CalendarEventRow, Result as DomainResult,
+};
 
 /// In-memory mock for `CalendarEventRepository`.
 ///
@@ -83,6 +85,44 @@ impl CalendarEventRepository for MockCalendarEventRepository {
         Ok(())
     }
 
+    async fn upsert_batch(
+        &self,
+        events: Vec<CalendarEventParams>,
+    ) -> DomainResult<Vec<CalendarBatchOutcome>> {
+        let mut outcomes = Vec::with_capacity(events.len());
+        for params in events {
+            // Mock: replace any stored event with the same Google id and user
+            let mut stored = self.events.lock().unwrap();
+            let existing = stored.iter().position(|e| {
+                e.google_event_id == params.google_event_id && e.user_email == params.user_email
+            });
+            outcomes.push(match existing {
+                Some(index) => {
+                    stored.remove(index);
+                    CalendarBatchOutcome::Updated
+                }
+                None => CalendarBatchOutcome::Inserted,
+            });
+            drop(stored);
+            self.insert_calendar_event(params).await?;
+        }
+        Ok(outcomes)
+    }
+
+    async fn delete_batch(&self, ids: &[String]) -> DomainResult<Vec<CalendarBatchOutcome>> {
+        let mut events = self.events.lock().unwrap();
+        Ok(ids
+            .iter()
+            .map(|id| match events.iter().position(|e| &e.id == id) {
+                Some(index) => {
+                    events.remove(index);
+                    CalendarBatchOutcome::Deleted
+                }
+                None => CalendarBatchOutcome::NotFound,
+            })
+            .collect())
+    }
+
     async fn get_calendar_events_by_time_range(
         &self,
         user_email: &str,
@@ -94,9 +134,7 @@ impl CalendarEventRepository for MockCalendarEventRepository {
             .lock()
             .unwrap()
             .iter()
-            .filter(|e| {
-                e.user_email == user_email && e.start_ts >= start_ts && e.end_ts <= end_ts
-            })
+            .filter(|e| e.user_email == user_email && e.start_ts >= start_ts && e.end_ts <= end_ts)
             .cloned()
             .collect())
     }
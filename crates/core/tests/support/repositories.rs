@@ -21,9 +21,7 @@ pub struct MockSnapshotRepository {
 impl MockSnapshotRepository {
     /// Create a new mock seeded with the provided snapshots.
     pub fn new(snapshots: Vec<ActivitySnapshot>) -> Self {
-        Self {
-            snapshots: Arc::new(snapshots),
-        }
+        Self { snapshots: Arc::new(snapshots) }
     }
 
     /// Convenience helper for adding a single snapshot to the mock.
@@ -66,9 +64,7 @@ pub struct MockSegmentRepository {
 impl MockSegmentRepository {
     /// Create a new mock seeded with the provided segments.
     pub fn new(segments: Vec<ActivitySegment>) -> Self {
-        Self {
-            segments: Arc::new(segments),
-        }
+        Self { segments: Arc::new(segments) }
     }
 
     /// Convenience helper for adding a single segment to the mock.
@@ -91,9 +87,7 @@ impl SegmentRepository for MockSegmentRepository {
             .iter()
             .filter(|seg| {
                 // Simple date filtering - assumes date is formatted as YYYY-MM-DD
-                seg.start_timestamp
-                    .to_string()
-                    .starts_with(&date.replace('-', ""))
+                seg.start_timestamp.to_string().starts_with(&date.replace('-', ""))
             })
             .cloned()
             .collect())
@@ -166,13 +160,15 @@ mod tests {
             work_type: Some("development".to_string()),
             primary_app: "Microsoft Excel".to_string(),
             activity_category: Some("work".to_string()),
-            activity_context_json: r#"{"active_app": {"app_name": "Excel", "window_title": "Test"}}"#.to_string(),
+            activity_context_json:
+                r#"{"active_app": {"app_name": "Excel", "window_title": "Test"}}"#.to_string(),
             processed: false,
             batch_id: None,
             created_at: timestamp,
             processed_at: None,
             is_idle: false,
             idle_duration_secs: None,
+            content_hash: None,
         }
     }
 
@@ -1,39 +1,200 @@
 //! Configuration management
 
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
+/// Placeholder recorded instead of secret values in a [`ConfigChange`]
+const REDACTED: &str = "<redacted>";
+
 /// Application configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Config {
     pub database: DatabaseConfig,
     pub sync: SyncConfig,
     pub tracking: TrackingConfig,
+    /// API cost rates; built-in defaults apply when the section is missing
+    #[serde(default)]
+    pub costs: CostRateConfig,
 }
 
 /// Database configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DatabaseConfig {
     pub path: String,
     pub pool_size: u32,
     #[serde(skip_serializing)]
     pub encryption_key: Option<String>,
+    /// Milliseconds a statement waits for a locked database before failing.
+    /// Defaults to 5 seconds when unset.
+    #[serde(default)]
+    pub busy_timeout_ms: Option<u64>,
+    /// Milliseconds a query may run before it is interrupted. Defaults to
+    /// 30 seconds when unset.
+    #[serde(default)]
+    pub query_timeout_ms: Option<u64>,
 }
 
 /// Sync configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SyncConfig {
     pub interval_seconds: u64,
     pub enabled: bool,
+    /// Forward outbox entries to Neon with the background outbox worker.
+    /// Off unless set explicitly, even when sync is enabled.
+    #[serde(default)]
+    pub outbox_worker_enabled: bool,
 }
 
 /// Activity tracking configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TrackingConfig {
     pub snapshot_interval_seconds: u64,
     pub idle_threshold_seconds: u64,
+    /// Seconds of sustained activity required to end an idle period.
+    /// Derived from `idle_threshold_seconds` when unset.
+    #[serde(default)]
+    pub idle_exit_threshold_seconds: Option<u64>,
+    /// Stored snapshot count above which synced snapshots are evicted at
+    /// capture time. Unset disables the count cap.
+    #[serde(default)]
+    pub snapshot_high_water_count: Option<u64>,
+    /// Database size (bytes in use) above which synced snapshots are evicted
+    /// at capture time. Unset disables the size cap.
+    #[serde(default)]
+    pub snapshot_high_water_bytes: Option<u64>,
+    /// Milliseconds an app must stay focused before it is recorded as
+    /// active. Unset (or zero) records every focus.
+    #[serde(default)]
+    pub min_dwell_ms: Option<u64>,
     pub enabled: bool,
 }
 
+/// Token pricing for a model in USD per 1M tokens
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ModelPricing {
+    /// Cost per 1M prompt (input) tokens
+    pub input_per_1m: f64,
+    /// Cost per 1M completion (output) tokens
+    pub output_per_1m: f64,
+}
+
+impl ModelPricing {
+    /// gpt-4o-mini pricing (as of 2025)
+    pub const GPT_4O_MINI: Self = Self { input_per_1m: 0.150, output_per_1m: 0.600 };
+
+    /// gpt-4o pricing (as of 2025)
+    pub const GPT_4O: Self = Self { input_per_1m: 2.50, output_per_1m: 10.00 };
+
+    /// Cost in USD of a request with the given token counts.
+    pub fn cost_usd(
+        &self,
+        prompt_tokens: impl Into<f64>,
+        completion_tokens: impl Into<f64>,
+    ) -> f64 {
+        (prompt_tokens.into() * self.input_per_1m / 1_000_000.0)
+            + (completion_tokens.into() * self.output_per_1m / 1_000_000.0)
+    }
+}
+
+impl Default for ModelPricing {
+    fn default() -> Self {
+        Self::GPT_4O_MINI
+    }
+}
+
+impl fmt::Display for ModelPricing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{} per 1M tokens", self.input_per_1m, self.output_per_1m)
+    }
+}
+
+/// API cost rates used for cost tracking and caps
+///
+/// Every field falls back to its default when missing from the config file,
+/// so a file only needs the settings it changes. A `models` table replaces
+/// the default table as a whole.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CostRateConfig {
+    /// Maximum monthly cost in USD (default: $5.00)
+    pub max_monthly_cost_usd: f64,
+    /// Monthly cost in USD that raises an alert (default: $4.00)
+    pub alert_threshold_usd: f64,
+    /// Model whose rates apply when no model is named (default:
+    /// `gpt-4o-mini`)
+    pub default_model: String,
+    /// Token pricing keyed by model name
+    pub models: BTreeMap<String, ModelPricing>,
+    /// Flat cost in USD per call for services not billed by token, keyed by
+    /// service name (`sap`, `calendar`, `neon`). Unlisted services are free.
+    pub call_costs: BTreeMap<String, f64>,
+}
+
+impl Default for CostRateConfig {
+    fn default() -> Self {
+        Self {
+            max_monthly_cost_usd: 5.0,
+            alert_threshold_usd: 4.0,
+            default_model: "gpt-4o-mini".to_string(),
+            models: BTreeMap::from([
+                ("gpt-4o".to_string(), ModelPricing::GPT_4O),
+                ("gpt-4o-mini".to_string(), ModelPricing::GPT_4O_MINI),
+            ]),
+            call_costs: BTreeMap::new(),
+        }
+    }
+}
+
+impl CostRateConfig {
+    /// Pricing configured for `model`, if any
+    pub fn model_pricing(&self, model: &str) -> Option<ModelPricing> {
+        self.models.get(model).copied()
+    }
+
+    /// Pricing of [`Self::default_model`]
+    ///
+    /// Falls back to gpt-4o-mini pricing if the default model has no rates;
+    /// [`Self::validate`] rejects that configuration.
+    pub fn default_pricing(&self) -> ModelPricing {
+        self.model_pricing(&self.default_model).unwrap_or_default()
+    }
+
+    /// Cost in USD of one call to `service` (zero when not configured)
+    pub fn call_cost(&self, service: &str) -> f64 {
+        self.call_costs.get(service).copied().unwrap_or(0.0)
+    }
+
+    /// Check that caps are positive, rates are non-negative and the default
+    /// model has rates
+    ///
+    /// # Errors
+    /// Returns a description of the first invalid setting.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.max_monthly_cost_usd <= 0.0 {
+            return Err("max_monthly_cost_usd must be positive".to_string());
+        }
+        if self.alert_threshold_usd < 0.0 {
+            return Err("alert_threshold_usd must not be negative".to_string());
+        }
+        if !self.models.contains_key(&self.default_model) {
+            return Err(format!("no rates configured for default model '{}'", self.default_model));
+        }
+        if let Some((model, _)) = self
+            .models
+            .iter()
+            .find(|(_, pricing)| pricing.input_per_1m < 0.0 || pricing.output_per_1m < 0.0)
+        {
+            return Err(format!("rates for model '{model}' must not be negative"));
+        }
+        if let Some((service, _)) = self.call_costs.iter().find(|(_, cost)| **cost < 0.0) {
+            return Err(format!("call cost for '{service}' must not be negative"));
+        }
+        Ok(())
+    }
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -41,13 +202,285 @@ impl Default for Config {
                 path: "pulsearc.db".to_string(),
                 pool_size: 8,
                 encryption_key: None,
+                busy_timeout_ms: None,
+                query_timeout_ms: None,
             },
-            sync: SyncConfig { interval_seconds: 10, enabled: true },
+            sync: SyncConfig { interval_seconds: 10, enabled: true, outbox_worker_enabled: false },
             tracking: TrackingConfig {
                 snapshot_interval_seconds: 30,
                 idle_threshold_seconds: 300,
+                idle_exit_threshold_seconds: None,
+                snapshot_high_water_count: None,
+                snapshot_high_water_bytes: None,
+                min_dwell_ms: None,
                 enabled: true,
             },
+            costs: CostRateConfig::default(),
+        }
+    }
+}
+
+impl Config {
+    /// List the fields that differ between `self` (old) and `other` (new)
+    ///
+    /// Changes are returned in field declaration order with dotted paths
+    /// (`sync.interval_seconds`). Optional fields record `None` on the side
+    /// where they are unset. The encryption key is reported as changed but
+    /// its values are redacted, so the result is safe to write to audit logs.
+    pub fn diff(&self, other: &Config) -> Vec<ConfigChange> {
+        let mut changes = Vec::new();
+
+        let (old, new) = (&self.database, &other.database);
+        diff_field(&mut changes, "database.path", &old.path, &new.path);
+        diff_field(&mut changes, "database.pool_size", &old.pool_size, &new.pool_size);
+        if old.encryption_key != new.encryption_key {
+            changes.push(ConfigChange {
+                path: "database.encryption_key".to_string(),
+                old: old.encryption_key.as_ref().map(|_| REDACTED.to_string()),
+                new: new.encryption_key.as_ref().map(|_| REDACTED.to_string()),
+            });
+        }
+        diff_optional_field(
+            &mut changes,
+            "database.busy_timeout_ms",
+            &old.busy_timeout_ms,
+            &new.busy_timeout_ms,
+        );
+        diff_optional_field(
+            &mut changes,
+            "database.query_timeout_ms",
+            &old.query_timeout_ms,
+            &new.query_timeout_ms,
+        );
+
+        let (old, new) = (&self.sync, &other.sync);
+        diff_field(
+            &mut changes,
+            "sync.interval_seconds",
+            &old.interval_seconds,
+            &new.interval_seconds,
+        );
+        diff_field(&mut changes, "sync.enabled", &old.enabled, &new.enabled);
+        diff_field(
+            &mut changes,
+            "sync.outbox_worker_enabled",
+            &old.outbox_worker_enabled,
+            &new.outbox_worker_enabled,
+        );
+
+        let (old, new) = (&self.tracking, &other.tracking);
+        diff_field(
+            &mut changes,
+            "tracking.snapshot_interval_seconds",
+            &old.snapshot_interval_seconds,
+            &new.snapshot_interval_seconds,
+        );
+        diff_field(
+            &mut changes,
+            "tracking.idle_threshold_seconds",
+            &old.idle_threshold_seconds,
+            &new.idle_threshold_seconds,
+        );
+        diff_optional_field(
+            &mut changes,
+            "tracking.idle_exit_threshold_seconds",
+            &old.idle_exit_threshold_seconds,
+            &new.idle_exit_threshold_seconds,
+        );
+        diff_optional_field(
+            &mut changes,
+            "tracking.snapshot_high_water_count",
+            &old.snapshot_high_water_count,
+            &new.snapshot_high_water_count,
+        );
+        diff_optional_field(
+            &mut changes,
+            "tracking.snapshot_high_water_bytes",
+            &old.snapshot_high_water_bytes,
+            &new.snapshot_high_water_bytes,
+        );
+        diff_optional_field(
+            &mut changes,
+            "tracking.min_dwell_ms",
+            &old.min_dwell_ms,
+            &new.min_dwell_ms,
+        );
+        diff_field(&mut changes, "tracking.enabled", &old.enabled, &new.enabled);
+
+        let (old, new) = (&self.costs, &other.costs);
+        diff_field(
+            &mut changes,
+            "costs.max_monthly_cost_usd",
+            &old.max_monthly_cost_usd,
+            &new.max_monthly_cost_usd,
+        );
+        diff_field(
+            &mut changes,
+            "costs.alert_threshold_usd",
+            &old.alert_threshold_usd,
+            &new.alert_threshold_usd,
+        );
+        diff_field(&mut changes, "costs.default_model", &old.default_model, &new.default_model);
+        for model in old.models.keys().chain(new.models.keys()).collect::<BTreeSet<_>>() {
+            diff_optional_field(
+                &mut changes,
+                &format!("costs.models.{model}"),
+                &old.model_pricing(model),
+                &new.model_pricing(model),
+            );
+        }
+        for service in old.call_costs.keys().chain(new.call_costs.keys()).collect::<BTreeSet<_>>() {
+            diff_optional_field(
+                &mut changes,
+                &format!("costs.call_costs.{service}"),
+                &old.call_costs.get(service),
+                &new.call_costs.get(service),
+            );
+        }
+
+        changes
+    }
+}
+
+/// A single changed config field, as produced by [`Config::diff`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigChange {
+    /// Dotted field path, e.g. `sync.interval_seconds`
+    pub path: String,
+    /// Previous value (`None` if the field was unset)
+    pub old: Option<String>,
+    /// New value (`None` if the field is now unset)
+    pub new: Option<String>,
+}
+
+impl fmt::Display for ConfigChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let old = self.old.as_deref().unwrap_or("<unset>");
+        let new = self.new.as_deref().unwrap_or("<unset>");
+        write!(f, "{}: {} -> {}", self.path, old, new)
+    }
+}
+
+fn diff_field<T: PartialEq + ToString>(
+    changes: &mut Vec<ConfigChange>,
+    path: &str,
+    old: &T,
+    new: &T,
+) {
+    if old != new {
+        changes.push(ConfigChange {
+            path: path.to_string(),
+            old: Some(old.to_string()),
+            new: Some(new.to_string()),
+        });
+    }
+}
+
+fn diff_optional_field<T: PartialEq + ToString>(
+    changes: &mut Vec<ConfigChange>,
+    path: &str,
+    old: &Option<T>,
+    new: &Option<T>,
+) {
+    if old != new {
+        changes.push(ConfigChange {
+            path: path.to_string(),
+            old: old.as_ref().map(ToString::to_string),
+            new: new.as_ref().map(ToString::to_string),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_configs_have_no_changes() {
+        let config = Config::default();
+        assert!(config.diff(&config.clone()).is_empty());
+    }
+
+    #[test]
+    fn changed_and_added_fields_are_reported() {
+        let old = Config::default();
+        let mut new = old.clone();
+        new.sync.interval_seconds = 60;
+        new.tracking.idle_exit_threshold_seconds = Some(15);
+
+        let changes = old.diff(&new);
+
+        assert_eq!(
+            changes,
+            vec![
+                ConfigChange {
+                    path: "sync.interval_seconds".to_string(),
+                    old: Some("10".to_string()),
+                    new: Some("60".to_string()),
+                },
+                ConfigChange {
+                    path: "tracking.idle_exit_threshold_seconds".to_string(),
+                    old: None,
+                    new: Some("15".to_string()),
+                },
+            ]
+        );
+        assert_eq!(changes[1].to_string(), "tracking.idle_exit_threshold_seconds: <unset> -> 15");
+    }
+
+    #[test]
+    fn encryption_key_changes_are_redacted() {
+        let old = Config::default();
+        let mut new = old.clone();
+        new.database.encryption_key = Some("super-secret".to_string());
+
+        let changes = old.diff(&new);
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "database.encryption_key");
+        assert_eq!(changes[0].old, None);
+        assert_eq!(changes[0].new.as_deref(), Some(REDACTED));
+    }
+
+    #[test]
+    fn changed_model_rates_are_reported() {
+        let old = Config::default();
+        let mut new = old.clone();
+        new.costs.models.insert(
+            "gpt-4o-mini".to_string(),
+            ModelPricing { input_per_1m: 0.30, output_per_1m: 1.20 },
+        );
+
+        let changes = old.diff(&new);
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "costs.models.gpt-4o-mini");
+        assert_eq!(changes[0].new.as_deref(), Some("0.3/1.2 per 1M tokens"));
+    }
+
+    #[test]
+    fn partial_cost_section_keeps_defaults() {
+        let costs: CostRateConfig = serde_json::from_str(
+            r#"{"models": {"gpt-4o-mini": {"input_per_1m": 0.3, "output_per_1m": 1.2}}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(costs.max_monthly_cost_usd, 5.0);
+        assert!((costs.default_pricing().cost_usd(1_000_000, 1_000_000) - 1.5).abs() < 1e-9);
+        assert!(costs.validate().is_ok());
+    }
+
+    #[test]
+    fn cost_rates_validation() {
+        assert!(CostRateConfig::default().validate().is_ok());
+        assert!(CostRateConfig { default_model: "unknown".to_string(), ..Default::default() }
+            .validate()
+            .is_err());
+        assert!(CostRateConfig {
+            call_costs: BTreeMap::from([("sap".to_string(), -0.01)]),
+            ..Default::default()
         }
+        .validate()
+        .is_err());
     }
 }
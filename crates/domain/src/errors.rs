@@ -10,6 +10,9 @@ pub enum PulseArcError {
     #[error("Database error: {0}")]
     Database(String),
 
+    #[error("Database open failed: {0}")]
+    DatabaseOpen(#[from] DbOpenError),
+
     #[error("Configuration error: {0}")]
     Config(String),
 
@@ -35,5 +38,22 @@ pub enum PulseArcError {
     Internal(String),
 }
 
+/// Why the encrypted database could not be opened
+///
+/// Lets callers choose between prompting for the right key (or a rekey) and
+/// restoring from a backup.
+#[derive(Error, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "detail", rename_all = "snake_case")]
+pub enum DbOpenError {
+    /// The file is intact but the key does not decrypt it (`SQLITE_NOTADB`,
+    /// failed page HMAC), or it is not an encrypted database at all
+    #[error("encryption key does not match the database: {0}")]
+    KeyMismatch(String),
+
+    /// The file decrypts but its contents are damaged or truncated
+    #[error("database file is corrupt: {0}")]
+    Corrupt(String),
+}
+
 /// Result type alias for PulseArc operations
 pub type Result<T> = std::result::Result<T, PulseArcError>;
@@ -7,6 +7,8 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "ts-gen")]
 use ts_rs::TS;
 
+use super::{ActivityCategory, WorkType};
+
 /// Configuration for block building behavior
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "ts-gen", derive(TS))]
@@ -188,6 +190,397 @@ impl ProposedBlock {
     }
 }
 
+/// A proposed block paired with its ranking score
+///
+/// Serialized flat, so consumers expecting a `ProposedBlock` keep working and
+/// simply see an extra `score` field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct RankedProposedBlock {
+    /// The ranked block
+    #[serde(flatten)]
+    #[cfg_attr(feature = "ts-gen", ts(flatten))]
+    pub block: ProposedBlock,
+
+    /// Normalized ranking score (0.0 to 1.0, higher ranks first)
+    pub score: f64,
+}
+
+/// A recorded dismissal of a suggestion
+///
+/// This type is internal and not exported to TypeScript.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuggestionDismissal {
+    /// App/context signature of the dismissed suggestion
+    pub signature: String,
+
+    /// ID of the dismissed suggestion (block id)
+    pub source_id: String,
+
+    /// When the suggestion was dismissed (Unix epoch seconds)
+    pub dismissed_at: i64,
+}
+
+/// A suppression learned from repeated dismissals of the same signature
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct SuggestionSuppression {
+    /// App/context signature (e.g., "spotify|-")
+    pub signature: String,
+
+    /// Dismissals of this signature still inside the decay window
+    pub dismissal_count: u32,
+
+    /// Most recent dismissal (Unix epoch seconds)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub last_dismissed_at: i64,
+
+    /// Whether matching suggestions are currently suppressed
+    pub active: bool,
+
+    /// When the suppression lapses as dismissals decay (Unix epoch seconds)
+    ///
+    /// `None` while the signature is below the suppression threshold.
+    #[cfg_attr(feature = "ts-gen", ts(type = "number | null"))]
+    pub expires_at: Option<i64>,
+}
+
+/// A recorded acceptance of a project for a context signature
+///
+/// Feeds project-matcher learning. This type is internal and not exported to
+/// TypeScript.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectAcceptance {
+    /// Context signature of the accepted block
+    pub signature: String,
+
+    /// Project the user assigned (project definition, e.g. "USC0063201")
+    pub project_id: String,
+
+    /// When the block was accepted (Unix epoch seconds)
+    pub accepted_at: i64,
+}
+
+/// A user's correction of a block's classification
+///
+/// Fields left `None` keep the block's current value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct ClassificationCorrection {
+    /// Corrected project ID (e.g., "USC0063201")
+    #[serde(default)]
+    pub project_id: Option<String>,
+
+    /// Corrected WBS code (e.g., "USC0063201.1.1")
+    #[serde(default)]
+    pub wbs_code: Option<String>,
+
+    /// Corrected deal/project name
+    #[serde(default)]
+    pub deal_name: Option<String>,
+
+    /// Corrected billable flag
+    #[serde(default)]
+    pub billable: Option<bool>,
+}
+
+/// End-of-day totals for accepted blocks
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct DailySummary {
+    /// Day being summarized (YYYY-MM-DD)
+    pub date: String,
+
+    /// Total tracked time across accepted blocks (seconds)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub total_secs: i64,
+
+    /// Billable time (seconds)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub billable_secs: i64,
+
+    /// Non-billable time (seconds)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub non_billable_secs: i64,
+
+    /// Time outside the user's working hours (seconds)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub after_hours_secs: i64,
+
+    /// Time on the user's non-working days (seconds)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub weekend_secs: i64,
+
+    /// Number of accepted blocks
+    pub block_count: u32,
+
+    /// Per-project breakdown, largest total first
+    pub projects: Vec<ProjectDaySummary>,
+}
+
+/// One project's share of a [`DailySummary`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct ProjectDaySummary {
+    /// Project ID (`None` for blocks without an inferred project)
+    pub project_id: Option<String>,
+
+    /// Deal/project name, when known (e.g., "Project Astro")
+    pub project_name: Option<String>,
+
+    /// Total time on this project (seconds)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub total_secs: i64,
+
+    /// Billable time on this project (seconds)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub billable_secs: i64,
+
+    /// Non-billable time on this project (seconds)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub non_billable_secs: i64,
+
+    /// Number of accepted blocks for this project
+    pub block_count: u32,
+}
+
+/// Structured answer to "why was this block classified this way?"
+///
+/// Assembled from a [`ProposedBlock`]'s stored classification: the evidence
+/// behind its project assignment, how the confidence score was reached, its
+/// calendar overlap, and - for model classifications - the model's rationale.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct ClassificationExplanation {
+    /// Block being explained
+    pub block_id: String,
+
+    /// Classifier that produced the block (e.g., "hybrid", "llm:gpt-4o-mini")
+    pub classifier: Option<String>,
+
+    /// Final confidence score stored on the block (0.0 to 1.0)
+    pub confidence: f32,
+
+    /// Whether the block was classified as billable
+    pub billable: bool,
+
+    /// Project/WBS the block was assigned to
+    pub project: InferredProject,
+
+    /// Evidence behind the classification, in the order it was recorded
+    pub evidence: Vec<ExplanationEvidence>,
+
+    /// Calendar events overlapping the block
+    pub calendar: CalendarOverlapExplanation,
+
+    /// How the confidence score was reached, step by step
+    pub confidence_steps: Vec<ConfidenceStep>,
+
+    /// Model and stated rationale, when a language model classified the block
+    pub llm: Option<LlmRationale>,
+}
+
+/// Project/WBS inference of a classified block
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct InferredProject {
+    /// Inferred project ID (e.g., "USC0063201")
+    pub project_id: Option<String>,
+
+    /// Inferred WBS code (e.g., "USC0063201.1.1")
+    pub wbs_code: Option<String>,
+
+    /// Inferred deal/project name (e.g., "Project Astro")
+    pub deal_name: Option<String>,
+
+    /// Inferred workstream (e.g., "modeling")
+    pub workstream: Option<String>,
+}
+
+/// Source of one piece of classification evidence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum EvidenceKind {
+    /// Title keyword matching a common project name
+    ProjectNameMatch,
+    /// Title keyword found by full-text WBS search
+    KeywordMatch,
+    /// URL domain found by full-text WBS search
+    UrlDomain,
+    /// Virtual data room provider in use
+    VdrProvider,
+    /// Project folder found by full-text WBS search
+    FilePath,
+    /// Project previously accepted for the same context
+    LearnedAcceptance,
+    /// No project matched; general & administrative fallback
+    Fallback,
+    /// Reason stated by a language model
+    ModelReason,
+    /// Any other recorded reason
+    Other,
+}
+
+/// One piece of evidence and what it added to the confidence score
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct ExplanationEvidence {
+    /// Where the evidence came from
+    pub kind: EvidenceKind,
+
+    /// Matched value (keyword, folder, ...) or the reason text
+    pub detail: String,
+
+    /// Confidence added by this evidence, when it has a fixed weight
+    pub contribution: Option<f32>,
+}
+
+/// Calendar overlap of a classified block
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct CalendarOverlapExplanation {
+    /// Whether any calendar event overlaps the block
+    pub has_overlap: bool,
+
+    /// IDs of the overlapping events
+    pub overlapping_event_ids: Vec<String>,
+
+    /// Whether several overlapping events are at the same time
+    pub is_double_booked: bool,
+}
+
+/// One step of the confidence calculation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct ConfidenceStep {
+    /// What this step accounts for
+    pub description: String,
+
+    /// Change to the score made by this step
+    pub delta: f32,
+
+    /// Score after this step
+    pub total: f32,
+}
+
+/// A language model's account of its classification
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct LlmRationale {
+    /// Model that classified the block
+    pub model: String,
+
+    /// The model's stated justification, if it gave one
+    pub rationale: Option<String>,
+
+    /// Individual reasons the model listed
+    pub reasons: Vec<String>,
+}
+
+/// One accepted block as a labeled example for classifier training
+///
+/// Free-text features are PII-redacted before export.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct TrainingExample {
+    /// Block the example was taken from
+    pub block_id: String,
+
+    /// Start timestamp (Unix epoch seconds)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub start_ts: i64,
+
+    /// End timestamp (Unix epoch seconds)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub end_ts: i64,
+
+    /// Signals available to the classifier
+    pub features: TrainingFeatures,
+
+    /// What the user accepted
+    pub label: TrainingLabel,
+}
+
+/// Signals a classifier sees for one block
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct TrainingFeatures {
+    /// Apps used in the block with their share of its time (names redacted)
+    pub apps: Vec<ActivityBreakdown>,
+
+    /// Most frequent work type across the block's snapshots
+    pub work_type: Option<String>,
+
+    /// Most frequent activity category across the block's snapshots
+    pub activity_category: Option<String>,
+
+    /// Whether any calendar event overlaps the block
+    pub has_calendar_overlap: bool,
+
+    /// Whether several overlapping events are at the same time
+    pub is_double_booked: bool,
+
+    /// Titles of calendar events seen during the block (redacted)
+    pub calendar_event_titles: Vec<String>,
+
+    /// Whether any of those events had external attendees
+    pub has_external_attendees: bool,
+
+    /// Meeting platforms seen during the block (e.g., "zoom")
+    pub meeting_platforms: Vec<String>,
+
+    /// Block falls on a weekend
+    pub is_weekend: bool,
+
+    /// Block falls outside working hours
+    pub is_after_hours: bool,
+
+    /// Block is travel time
+    pub is_travel: bool,
+
+    /// Classifier that proposed the block
+    pub classifier: Option<String>,
+
+    /// Confidence of the proposed classification (0.0 to 1.0)
+    pub match_confidence: f32,
+
+    /// Project match evidence recorded on the block (redacted)
+    pub match_reasons: Vec<String>,
+}
+
+/// Classification accepted by the user for a block
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct TrainingLabel {
+    /// Whether the time is billable
+    pub billable: bool,
+
+    /// Accepted project ID (`None` for unassigned time)
+    pub project_id: Option<String>,
+
+    /// Accepted WBS code
+    pub wbs_code: Option<String>,
+
+    /// Accepted workstream
+    pub workstream: Option<String>,
+}
+
 /// Individual activity within a block
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "ts-gen", derive(TS))]
@@ -652,3 +1045,105 @@ pub struct EvidenceSignals {
     /// True if any calendar events in this block have online meeting links
     pub has_online_meeting: bool,
 }
+
+/// A user rule assigning a category, work type or project to an app
+///
+/// Matches windows of `bundle_id`, optionally narrowed by case-insensitive
+/// substrings of the window title and URL. Unset outputs leave the captured
+/// value as is.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct AppCategoryRule {
+    /// Rule identifier (UUIDv7)
+    pub id: String,
+
+    /// Bundle id the rule applies to (case-insensitive, e.g.
+    /// "com.tinyspeck.slackmacgap")
+    pub bundle_id: String,
+
+    /// Only match windows whose title contains this text
+    pub title_pattern: Option<String>,
+
+    /// Only match windows whose URL contains this text
+    pub url_pattern: Option<String>,
+
+    /// Category assigned to matching activity
+    pub category: Option<ActivityCategory>,
+
+    /// Work type assigned to matching activity
+    pub work_type: Option<WorkType>,
+
+    /// Project assigned to matching activity (project definition, e.g.
+    /// "USC0063201")
+    pub project_id: Option<String>,
+
+    /// When the rule was created (Unix epoch seconds)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub created_at: i64,
+}
+
+/// Fields of a new [`AppCategoryRule`]; the id and timestamp are assigned on
+/// creation
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct AppCategoryRuleParams {
+    pub bundle_id: String,
+    #[serde(default)]
+    pub title_pattern: Option<String>,
+    #[serde(default)]
+    pub url_pattern: Option<String>,
+    #[serde(default)]
+    pub category: Option<ActivityCategory>,
+    #[serde(default)]
+    pub work_type: Option<WorkType>,
+    #[serde(default)]
+    pub project_id: Option<String>,
+}
+
+/// Which rule wins when an imported rule matches the same windows as a local
+/// rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub enum AppCategoryConflictPolicy {
+    /// The imported rule replaces the local one
+    TeamWins,
+    /// The local rule is kept and the imported one skipped
+    LocalWins,
+}
+
+/// An imported rule that was rejected
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct InvalidAppCategoryRule {
+    /// Position of the rule in the imported file (0-based)
+    pub index: usize,
+
+    /// Bundle id of the rule, if it could be read
+    pub bundle_id: Option<String>,
+
+    /// Why the rule was rejected
+    pub reason: String,
+}
+
+/// Outcome of importing app category rules
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct AppCategoryRuleImport {
+    /// Rules added because no local rule matched the same windows
+    pub added: usize,
+
+    /// Local rules replaced by imported ones (team wins)
+    pub replaced: usize,
+
+    /// Imported rules skipped in favor of local ones (local wins)
+    pub skipped: usize,
+
+    /// Imported rules rejected by validation
+    pub invalid: Vec<InvalidAppCategoryRule>,
+}
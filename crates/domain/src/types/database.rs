@@ -37,6 +37,12 @@ pub struct ActivitySnapshot {
     pub processed_at: Option<i64>,
     pub is_idle: bool,
     pub idle_duration_secs: Option<i32>,
+    /// Stable hash of the salient context fields (see
+    /// [`ActivitySnapshot::compute_content_hash`]); `None` for rows captured
+    /// before hashing was introduced.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts-gen", ts(optional))]
+    pub content_hash: Option<String>,
 }
 
 /// Metadata required to construct a new `ActivitySnapshot`.
@@ -64,6 +70,63 @@ impl SnapshotMetadata {
     }
 }
 
+/// Filter for browsing raw activity snapshots.
+///
+/// All criteria are optional and combined with AND.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct SnapshotFilter {
+    /// Case-insensitive substring of the active app name or primary app
+    #[serde(default)]
+    pub app_name: Option<String>,
+    /// Exact bundle identifier (e.g. `com.apple.Safari`)
+    #[serde(default)]
+    pub bundle_id: Option<String>,
+    /// Inclusive lower bound (Unix seconds)
+    #[serde(default)]
+    #[cfg_attr(feature = "ts-gen", ts(type = "number | null"))]
+    pub start_ts: Option<i64>,
+    /// Exclusive upper bound (Unix seconds)
+    #[serde(default)]
+    #[cfg_attr(feature = "ts-gen", ts(type = "number | null"))]
+    pub end_ts: Option<i64>,
+}
+
+/// Zero-based page request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct PageRequest {
+    /// Page index, starting at 0
+    pub page: u32,
+    /// Items per page
+    pub page_size: u32,
+}
+
+impl PageRequest {
+    /// Largest page size served in one request
+    pub const MAX_PAGE_SIZE: u32 = 500;
+
+    /// Row offset of the first item on this page
+    pub fn offset(&self) -> u64 {
+        u64::from(self.page) * u64::from(self.page_size)
+    }
+}
+
+/// One page of results plus the total number of matching items.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub page: u32,
+    pub page_size: u32,
+    /// Matching items across all pages
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub total: u64,
+}
+
 /// Activity segment - 5-minute aggregated segment
 ///
 /// This is a minimal definition for Phase 0. Full type with all fields
@@ -142,9 +205,52 @@ impl ActivitySnapshot {
             processed_at: None,
             is_idle: context.detected_activity.eq_ignore_ascii_case("idle"),
             idle_duration_secs: None,
+            content_hash: Some(Self::compute_content_hash(context)),
         })
     }
 
+    /// Stable content hash of the fields that identify what the user was
+    /// looking at: app name, bundle id, window title and URL.
+    ///
+    /// Enrichment (classification, confidence, metadata) is deliberately left
+    /// out, so re-classifying the same window does not change the hash. The
+    /// hash is 64-bit FNV-1a over length-prefixed fields, rendered as 16 hex
+    /// digits; it is stable across builds and safe to persist.
+    pub fn compute_content_hash(context: &ActivityContext) -> String {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        fn feed(hash: &mut u64, bytes: &[u8]) {
+            for byte in bytes {
+                *hash ^= u64::from(*byte);
+                *hash = hash.wrapping_mul(FNV_PRIME);
+            }
+        }
+
+        let app = &context.active_app;
+        let fields = [
+            Some(app.app_name.as_str()),
+            app.bundle_id.as_deref(),
+            Some(app.window_title.as_str()),
+            app.url.as_deref(),
+        ];
+
+        let mut hash = FNV_OFFSET;
+        for field in fields {
+            match field {
+                // Length prefix keeps ("ab", "c") distinct from ("a", "bc")
+                Some(value) => {
+                    feed(&mut hash, &[1]);
+                    feed(&mut hash, &(value.len() as u64).to_le_bytes());
+                    feed(&mut hash, value.as_bytes());
+                }
+                None => feed(&mut hash, &[0]),
+            }
+        }
+
+        format!("{hash:016x}")
+    }
+
     /// Deserialize the embedded activity context JSON into the strongly-typed
     /// structure.
     pub fn activity_context(&self) -> DomainResult<ActivityContext> {
@@ -290,6 +396,39 @@ crate::impl_domain_status_conversions!(OutboxStatus {
     Dismissed => "dismissed"
 });
 
+impl TimeEntryOutbox {
+    /// Payload `status` value marking an outbox entry as the deletion of the
+    /// underlying time entry.
+    pub const DELETED_PAYLOAD_STATUS: &'static str = "deleted";
+
+    /// Identifier of the logical time entry carried by this outbox entry.
+    ///
+    /// Read from the payload's `id` field. Several outbox entries share the
+    /// same entity id when a time entry is edited before it syncs. Returns
+    /// `None` when the payload has no (non-empty) id or is not valid JSON.
+    pub fn entity_id(&self) -> Option<String> {
+        let payload = self.payload_value()?;
+        payload.get("id")?.as_str().filter(|id| !id.is_empty()).map(str::to_owned)
+    }
+
+    /// Whether this entry deletes the underlying time entry (payload `status`
+    /// equals [`Self::DELETED_PAYLOAD_STATUS`], case-insensitive).
+    pub fn is_delete(&self) -> bool {
+        self.payload_value()
+            .and_then(|payload| {
+                payload
+                    .get("status")
+                    .and_then(serde_json::Value::as_str)
+                    .map(|status| status.eq_ignore_ascii_case(Self::DELETED_PAYLOAD_STATUS))
+            })
+            .unwrap_or(false)
+    }
+
+    fn payload_value(&self) -> Option<serde_json::Value> {
+        serde_json::from_str(&self.payload_json).ok()
+    }
+}
+
 /// IdMapping - Maps local UUIDv7 to backend CUID
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "ts-gen", derive(TS))]
@@ -401,6 +540,25 @@ pub struct ProjectWithWbs {
     pub wbs_code: Option<String>,
 }
 
+impl From<Project> for ProjectWithWbs {
+    fn from(project: Project) -> Self {
+        Self { id: project.id, name: project.name, wbs_code: None }
+    }
+}
+
+/// ProjectSuggestion - Autocomplete match for a typed project code
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct ProjectSuggestion {
+    pub id: String,
+    pub name: String,
+    pub wbs_code: Option<String>,
+    /// Times the user picked this project (ranks suggestions)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub usage_count: u64,
+}
+
 /// Row type for calendar_tokens table
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "ts-gen", derive(TS))]
@@ -418,6 +576,41 @@ pub struct CalendarTokenRow {
     pub provider: String,
 }
 
+/// Calendar sync preferences for one account
+///
+/// `sync_token` and `last_sync_epoch` are sync state maintained by the sync
+/// engine; the remaining fields are user preferences.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarSyncSettings {
+    pub enabled: bool,
+    pub sync_interval_minutes: u32,
+    pub include_all_day_events: bool,
+    pub min_event_duration_minutes: u32,
+    pub lookback_hours: u32,
+    pub lookahead_hours: u32,
+    pub excluded_calendar_ids: Vec<String>,
+    pub sync_token: Option<String>,
+    pub last_sync_epoch: Option<i64>,
+}
+
+impl Default for CalendarSyncSettings {
+    /// Matches the column defaults of `calendar_sync_settings`
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            sync_interval_minutes: 30,
+            include_all_day_events: true,
+            min_event_duration_minutes: 15,
+            lookback_hours: 336,
+            lookahead_hours: 168,
+            excluded_calendar_ids: Vec::new(),
+            sync_token: None,
+            last_sync_epoch: None,
+        }
+    }
+}
+
 /// Row type for calendar_sync_settings table
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "ts-gen", derive(TS))]
@@ -557,6 +750,24 @@ pub struct CalendarEventParams {
     pub external_attendee_count: Option<i32>,
 }
 
+/// Outcome of one event in a calendar event batch operation
+///
+/// Batch operations return one outcome per input, in input order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum CalendarBatchOutcome {
+    /// The event was new and has been inserted
+    Inserted,
+    /// An event with the same `google_event_id` and `user_email` was updated
+    Updated,
+    /// The event was deleted
+    Deleted,
+    /// No event with the given id existed
+    NotFound,
+}
+
 /// Parameters for upserting calendar sync settings.
 ///
 /// # Field Invariants
@@ -633,6 +844,9 @@ pub struct DatabaseSize {
     /// Number of unused pages (PRAGMA freelist_count)
     #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
     pub freelist_count: u64,
+    /// Size of the write-ahead log file in bytes (0 when there is none)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub wal_size_bytes: u64,
 }
 
 /// Statistics for a single database table.
@@ -661,6 +875,162 @@ pub struct HealthStatus {
     pub response_time_ms: u64,
 }
 
+/// Fragmentation ratio at or above which vacuuming is recommended.
+pub const DEFAULT_VACUUM_THRESHOLD: f64 = 0.2;
+
+/// Free pages below which vacuuming is not worth it, whatever the ratio.
+pub const MIN_VACUUM_FREE_PAGES: u64 = 64;
+
+/// Maintenance action suggested by a [`FragmentationReport`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub enum VacuumRecommendation {
+    /// Free space is below the threshold
+    None,
+    /// Reclaim free pages with `PRAGMA incremental_vacuum`
+    IncrementalVacuum,
+    /// Free space can only be reclaimed by a full VACUUM (auto_vacuum is off)
+    Vacuum,
+}
+
+/// Free-page and fragmentation report from PRAGMA introspection.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct FragmentationReport {
+    /// Number of pages in the database (PRAGMA page_count)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub page_count: u64,
+    /// Size of each page in bytes (PRAGMA page_size)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub page_size: u64,
+    /// Number of unused pages (PRAGMA freelist_count)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub freelist_count: u64,
+    /// Size of the free list in bytes
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub freelist_bytes: u64,
+    /// Free pages divided by total pages (0.0 - 1.0)
+    pub fragmentation_ratio: f64,
+    /// Whether the database uses `auto_vacuum = INCREMENTAL`
+    pub incremental_vacuum_enabled: bool,
+    /// Suggested maintenance action
+    pub recommendation: VacuumRecommendation,
+    /// Human-readable recommendation, e.g. "incremental vacuum 128 pages"
+    pub summary: String,
+}
+
+impl FragmentationReport {
+    /// Build a report from PRAGMA values, recommending a vacuum once the
+    /// fragmentation ratio reaches `threshold`.
+    pub fn new(
+        page_count: u64,
+        page_size: u64,
+        freelist_count: u64,
+        incremental_vacuum_enabled: bool,
+        threshold: f64,
+    ) -> Self {
+        let fragmentation_ratio =
+            if page_count == 0 { 0.0 } else { freelist_count as f64 / page_count as f64 };
+
+        let mut report = Self {
+            page_count,
+            page_size,
+            freelist_count,
+            freelist_bytes: freelist_count.saturating_mul(page_size),
+            fragmentation_ratio,
+            incremental_vacuum_enabled,
+            recommendation: VacuumRecommendation::None,
+            summary: String::new(),
+        };
+        report.recommendation = report.recommendation_for(threshold);
+        report.summary = match report.recommendation {
+            VacuumRecommendation::None => "no vacuum needed".to_string(),
+            VacuumRecommendation::IncrementalVacuum => {
+                format!("incremental vacuum {freelist_count} pages")
+            }
+            VacuumRecommendation::Vacuum => format!("vacuum ({freelist_count} free pages)"),
+        };
+        report
+    }
+
+    /// Recommendation for this report under a different `threshold`.
+    pub fn recommendation_for(&self, threshold: f64) -> VacuumRecommendation {
+        if self.freelist_count < MIN_VACUUM_FREE_PAGES || self.fragmentation_ratio < threshold {
+            VacuumRecommendation::None
+        } else if self.incremental_vacuum_enabled {
+            VacuumRecommendation::IncrementalVacuum
+        } else {
+            VacuumRecommendation::Vacuum
+        }
+    }
+}
+
+/// Row that violates a foreign key (PRAGMA foreign_key_check).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct ForeignKeyViolation {
+    /// Table holding the offending row
+    pub table: String,
+    /// Rowid of the offending row (None for WITHOUT ROWID tables)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number | null"))]
+    pub rowid: Option<i64>,
+    /// Table the foreign key refers to
+    pub parent: String,
+    /// Index of the foreign key constraint on `table`
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub constraint_index: i64,
+}
+
+/// Findings of PRAGMA integrity_check and PRAGMA foreign_key_check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct IntegrityReport {
+    /// No integrity problems or foreign key violations were found
+    pub ok: bool,
+    /// Problems reported by PRAGMA integrity_check (empty when it says "ok")
+    pub integrity_errors: Vec<String>,
+    /// Rows reported by PRAGMA foreign_key_check
+    pub foreign_key_violations: Vec<ForeignKeyViolation>,
+    /// Human-readable result, e.g. "2 integrity errors"
+    pub summary: String,
+}
+
+impl IntegrityReport {
+    /// Build a report from the rows returned by the two checks.
+    pub fn new(
+        integrity_errors: Vec<String>,
+        foreign_key_violations: Vec<ForeignKeyViolation>,
+    ) -> Self {
+        let ok = integrity_errors.is_empty() && foreign_key_violations.is_empty();
+        let summary = if ok {
+            "ok".to_string()
+        } else {
+            format!(
+                "{} integrity errors, {} foreign key violations",
+                integrity_errors.len(),
+                foreign_key_violations.len()
+            )
+        };
+        Self { ok, integrity_errors, foreign_key_violations, summary }
+    }
+}
+
+/// Outcome of a best-effort recovery into a new database.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecoveryReport {
+    /// Tables whose rows were copied in full
+    pub tables_recovered: u32,
+    /// Rows copied across all tables
+    pub rows_recovered: u64,
+    /// Objects that could not be recreated or copied, with the error
+    pub failures: Vec<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -668,6 +1038,23 @@ mod tests {
         ActivityCategory, ActivityContext, ActivityMetadata, ConfidenceEvidence, WindowContext,
     };
 
+    #[test]
+    fn integrity_report_is_ok_without_findings() {
+        let report = IntegrityReport::new(vec![], vec![]);
+        assert!(report.ok);
+        assert_eq!(report.summary, "ok");
+
+        let violation = ForeignKeyViolation {
+            table: "block_members".into(),
+            rowid: Some(7),
+            parent: "proposed_blocks".into(),
+            constraint_index: 0,
+        };
+        let report = IntegrityReport::new(vec!["row 3 missing from index".into()], vec![violation]);
+        assert!(!report.ok);
+        assert_eq!(report.summary, "1 integrity errors, 1 foreign key violations");
+    }
+
     #[test]
     fn snapshot_round_trip_from_context() {
         let context = ActivityContext {
@@ -694,6 +1081,7 @@ mod tests {
             location: None,
             temporal_context: None,
             classification: None,
+            capture_mode: Default::default(),
         };
 
         let metadata = SnapshotMetadata {
@@ -711,4 +1099,136 @@ mod tests {
         assert_eq!(round_trip.active_app.app_name, "Safari");
         assert_eq!(round_trip.activity_category, ActivityCategory::Communication);
     }
+
+    fn window_context(app_name: &str, window_title: &str, url: Option<&str>) -> ActivityContext {
+        ActivityContext {
+            active_app: WindowContext {
+                app_name: app_name.into(),
+                window_title: window_title.into(),
+                bundle_id: None,
+                url: url.map(Into::into),
+                url_host: None,
+                document_name: None,
+                file_path: None,
+            },
+            recent_apps: vec![],
+            detected_activity: "Browsing".into(),
+            work_type: None,
+            activity_category: ActivityCategory::default(),
+            billable_confidence: 0.0,
+            suggested_client: None,
+            suggested_matter: None,
+            suggested_task_code: None,
+            extracted_metadata: ActivityMetadata::default(),
+            evidence: ConfidenceEvidence::default(),
+            calendar_event: None,
+            location: None,
+            temporal_context: None,
+            classification: None,
+            capture_mode: Default::default(),
+        }
+    }
+
+    #[test]
+    fn content_hash_matches_for_identical_salient_fields() {
+        let first = window_context("Safari", "PulseArc Docs", Some("https://pulsearc.dev"));
+        let mut second = first.clone();
+        second.detected_activity = "Researching".into();
+        second.billable_confidence = 0.9;
+        second.active_app.url_host = Some("pulsearc.dev".into());
+
+        let hash = ActivitySnapshot::compute_content_hash(&first);
+        assert_eq!(hash.len(), 16);
+        assert_eq!(hash, ActivitySnapshot::compute_content_hash(&second));
+
+        let metadata = SnapshotMetadata {
+            id: "snapshot-1".into(),
+            timestamp: 1_700_000_000,
+            created_at: 1_700_000_000,
+            batch_id: None,
+        };
+        let snapshot = ActivitySnapshot::from_activity_context(&first, metadata).unwrap();
+        assert_eq!(snapshot.content_hash.as_deref(), Some(hash.as_str()));
+    }
+
+    #[test]
+    fn content_hash_differs_for_different_salient_fields() {
+        let base = window_context("Safari", "PulseArc Docs", Some("https://pulsearc.dev"));
+        let hash = ActivitySnapshot::compute_content_hash(&base);
+
+        let variants = [
+            window_context("Chrome", "PulseArc Docs", Some("https://pulsearc.dev")),
+            window_context("Safari", "PulseArc Blog", Some("https://pulsearc.dev")),
+            window_context("Safari", "PulseArc Docs", Some("https://pulsearc.dev/blog")),
+            window_context("Safari", "PulseArc Docs", None),
+            // Same concatenated bytes, different field boundaries
+            window_context("SafariPulseArc", " Docs", Some("https://pulsearc.dev")),
+        ];
+        for variant in &variants {
+            assert_ne!(hash, ActivitySnapshot::compute_content_hash(variant), "{variant:?}");
+        }
+    }
+
+    #[test]
+    fn outbox_entity_id_and_delete_marker_read_from_payload() {
+        let mut entry = TimeEntryOutbox {
+            id: "outbox-1".into(),
+            idempotency_key: "idem-1".into(),
+            user_id: "user-1".into(),
+            payload_json: r#"{"id":"entry-42","status":"Deleted"}"#.into(),
+            backend_cuid: None,
+            status: OutboxStatus::Pending,
+            attempts: 0,
+            last_error: None,
+            retry_after: None,
+            created_at: 1_700_000_000,
+            sent_at: None,
+            correlation_id: None,
+            local_status: None,
+            remote_status: None,
+            sap_entry_id: None,
+            next_attempt_at: None,
+            error_code: None,
+            last_forwarded_at: None,
+            wbs_code: None,
+            target: "sap".into(),
+            description: None,
+            auto_applied: false,
+            version: 1,
+            last_modified_by: "system".into(),
+            last_modified_at: None,
+        };
+
+        assert_eq!(entry.entity_id().as_deref(), Some("entry-42"));
+        assert!(entry.is_delete());
+
+        entry.payload_json = r#"{"id":"","status":"submitted"}"#.into();
+        assert_eq!(entry.entity_id(), None);
+        assert!(!entry.is_delete());
+
+        entry.payload_json = "not json".into();
+        assert_eq!(entry.entity_id(), None);
+        assert!(!entry.is_delete());
+    }
+
+    #[test]
+    fn fragmentation_report_recommends_by_ratio_and_vacuum_mode() {
+        let clean = FragmentationReport::new(1000, 4096, 10, true, DEFAULT_VACUUM_THRESHOLD);
+        assert_eq!(clean.recommendation, VacuumRecommendation::None);
+        assert_eq!(clean.freelist_bytes, 40_960);
+
+        let fragmented = FragmentationReport::new(1000, 4096, 400, true, DEFAULT_VACUUM_THRESHOLD);
+        assert!((fragmented.fragmentation_ratio - 0.4).abs() < f64::EPSILON);
+        assert_eq!(fragmented.recommendation, VacuumRecommendation::IncrementalVacuum);
+        assert_eq!(fragmented.summary, "incremental vacuum 400 pages");
+        assert_eq!(fragmented.recommendation_for(0.5), VacuumRecommendation::None);
+
+        let legacy = FragmentationReport::new(1000, 4096, 400, false, DEFAULT_VACUUM_THRESHOLD);
+        assert_eq!(legacy.recommendation, VacuumRecommendation::Vacuum);
+
+        // A tiny database is never worth vacuuming
+        let tiny = FragmentationReport::new(20, 4096, 15, true, DEFAULT_VACUUM_THRESHOLD);
+        assert_eq!(tiny.recommendation, VacuumRecommendation::None);
+        assert_eq!(FragmentationReport::new(0, 4096, 0, true, 0.0).fragmentation_ratio, 0.0);
+    }
 }
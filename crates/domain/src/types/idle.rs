@@ -39,3 +39,18 @@ pub struct IdleSummary {
     pub idle_discarded_secs: i32, // Idle time user chose to discard
     pub idle_pending_secs: i32,   // Idle time awaiting user decision
 }
+
+/// Idle detection settings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdleSettings {
+    /// Pause tracking when the user goes idle
+    pub pause_on_idle: bool,
+    /// Seconds of inactivity before the user counts as idle
+    pub idle_threshold_secs: i64,
+}
+
+impl Default for IdleSettings {
+    fn default() -> Self {
+        Self { pause_on_idle: true, idle_threshold_secs: 600 }
+    }
+}
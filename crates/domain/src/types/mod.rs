@@ -7,27 +7,44 @@ pub mod database;
 pub mod idle;
 pub mod sap;
 pub mod stats;
+pub mod timeline;
 pub mod user;
 
 use chrono::{DateTime, Utc};
 // Re-export classification types
-pub use classification::ProposedBlock;
+pub use classification::{
+    AppCategoryConflictPolicy, AppCategoryRule, AppCategoryRuleImport, AppCategoryRuleParams,
+    CalendarOverlapExplanation, ClassificationCorrection, ClassificationExplanation,
+    ConfidenceStep, DailySummary, EvidenceKind, ExplanationEvidence, InferredProject,
+    InvalidAppCategoryRule, LlmRationale, ProjectAcceptance, ProjectDaySummary, ProposedBlock,
+    RankedProposedBlock, SuggestionDismissal, SuggestionSuppression, TrainingExample,
+    TrainingFeatures, TrainingLabel,
+};
 // Re-export database types for convenience
 pub use database::{
-    AcceptPatch, ActivitySegment, ActivitySnapshot, BatchQueue, BatchStatus, CalendarEventParams,
-    CalendarEventRow, CalendarSyncSettingsParams, CalendarSyncSettingsRow, CalendarTokenRow,
-    ContextPart, DatabaseSize, HealthStatus, IdMapping, OutboxStatus, ParsedFields,
-    PrismaTimeEntryDto, Project, ProjectWithWbs, SuggestionFeedbackParams, TableStats,
-    TimeEntryOutbox, TimeRange,
+    AcceptPatch, ActivitySegment, ActivitySnapshot, BatchQueue, BatchStatus, CalendarBatchOutcome,
+    CalendarEventParams, CalendarEventRow, CalendarSyncSettings, CalendarSyncSettingsParams,
+    CalendarSyncSettingsRow, CalendarTokenRow, ContextPart, DatabaseSize, ForeignKeyViolation,
+    FragmentationReport, HealthStatus, IdMapping, IntegrityReport, OutboxStatus, Page, PageRequest,
+    ParsedFields, PrismaTimeEntryDto, Project, ProjectSuggestion, ProjectWithWbs, RecoveryReport,
+    SnapshotFilter, SuggestionFeedbackParams, TableStats, TimeEntryOutbox, TimeRange,
+    VacuumRecommendation, DEFAULT_VACUUM_THRESHOLD, MIN_VACUUM_FREE_PAGES,
+};
+pub use idle::{IdlePeriod, IdleSettings, IdleSummary};
+pub use sap::{
+    OutboxAgeBuckets, OutboxFlushReport, OutboxStatusSummary, SapSyncSettings, WbsElement,
+    WbsSearchHit,
 };
-pub use idle::{IdlePeriod, IdleSummary};
-pub use sap::{OutboxStatusSummary, SapSyncSettings, WbsElement};
 use serde::{Deserialize, Serialize};
 pub use stats::{
     BatchStats, ClassificationMode, DatabaseStats, DlqBatch, OutboxStats, SyncStats, TokenUsage,
     TokenVariance, UserCostSummary,
 };
-pub use user::UserProfile;
+pub use timeline::{TimelineActivity, TimelineCalendarEvent, TimelineItem};
+pub use user::{
+    CalendarConnection, ConfigBundle, ConfigSetting, FeatureFlagOverride, SettingsResetSummary,
+    UserProfile, UserSettingChange, WorkdayConfig, CONFIG_BUNDLE_VERSION,
+};
 
 // Type alias for API compatibility
 /// Block is an alias for ProposedBlock (used in API contexts)
@@ -144,6 +161,48 @@ pub struct TimeEntryParams {
     pub wbs_code: Option<String>,
 }
 
+/// Two time entries whose `[start, end)` intervals intersect
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct OverlapConflict {
+    /// Entry that starts first
+    #[cfg_attr(feature = "ts-gen", ts(type = "string"))]
+    pub first_entry_id: Uuid,
+    /// Entry that starts later (or at the same time)
+    #[cfg_attr(feature = "ts-gen", ts(type = "string"))]
+    pub second_entry_id: Uuid,
+    /// Start of the overlapping span
+    #[cfg_attr(feature = "ts-gen", ts(type = "string"))]
+    pub overlap_start: DateTime<Utc>,
+    /// End of the overlapping span (exclusive)
+    #[cfg_attr(feature = "ts-gen", ts(type = "string"))]
+    pub overlap_end: DateTime<Utc>,
+    /// Length of the overlapping span in seconds
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub overlap_secs: i64,
+}
+
+/// Untracked interval within a workday
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct Gap {
+    /// Start of the gap (Unix epoch seconds)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub start_ts: i64,
+    /// End of the gap, exclusive (Unix epoch seconds)
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub end_ts: i64,
+    /// Length of the gap in seconds
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub duration_secs: i64,
+    /// Title of the calendar event that most overlaps the gap, if any
+    pub likely_meeting: Option<String>,
+    /// ID of that calendar event
+    pub calendar_event_id: Option<String>,
+}
+
 // ============================================================================
 // Core Activity Types
 // ============================================================================
@@ -151,6 +210,8 @@ pub struct TimeEntryParams {
 /// WorkType: WHAT you're doing (separate from billable classification)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
 pub enum WorkType {
     Modeling,        // Spreadsheet modeling, financial analysis
     DocReview,       // PDF/document review, contracts
@@ -167,6 +228,8 @@ pub enum WorkType {
 /// ActivityCategory: SHOULD it bill (drives billing classification)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
 pub enum ActivityCategory {
     ClientWork,     // Direct billable work (0.95 base)
     Research,       // Potentially billable (0.60 base)
@@ -198,6 +261,38 @@ impl Default for ActivityCategory {
     }
 }
 
+/// Status of an OS permission the tracker depends on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub enum PermissionStatus {
+    /// Permission granted
+    Granted,
+    /// Permission denied or not yet granted
+    Denied,
+    /// Permission does not exist on this platform
+    Unsupported,
+}
+
+impl PermissionStatus {
+    /// Whether the permission is granted
+    pub fn is_granted(self) -> bool {
+        self == Self::Granted
+    }
+}
+
+/// How much of the activity context could be captured
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptureMode {
+    /// App, window title and enrichment were available
+    #[default]
+    Full,
+    /// Accessibility permission missing: app name and bundle ID only
+    AppOnly,
+}
+
 /// Confidence evidence for auditability
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ConfidenceEvidence {
@@ -324,4 +419,7 @@ pub struct ActivityContext {
     pub temporal_context: Option<TemporalContext>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub classification: Option<ClassificationContext>,
+    /// Whether the capture had full OS access or fell back to app-only
+    #[serde(default)]
+    pub capture_mode: CaptureMode,
 }
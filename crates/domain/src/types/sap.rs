@@ -37,7 +37,21 @@ pub struct WbsElement {
     pub project_code: Option<String>,
 }
 
+/// A WBS element matched by fuzzy search, with its similarity score.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct WbsSearchHit {
+    pub element: WbsElement,
+    /// Similarity in `0.0..=1.0`; 1.0 is an exact code match
+    pub score: f32,
+}
+
 /// Aggregate counters for the SAP time-entry outbox.
+///
+/// Besides the per-status counts, the summary carries the age of the oldest
+/// pending entry and an age histogram of pending entries so the UI can flag a
+/// stuck sync (entries piling up in the older buckets).
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "ts-gen", derive(TS))]
 #[cfg_attr(feature = "ts-gen", ts(export))]
@@ -45,6 +59,79 @@ pub struct OutboxStatusSummary {
     pub pending_count: u32,
     pub sent_count: u32,
     pub failed_count: u32,
+    #[serde(default)]
+    pub dismissed_count: u32,
+    /// Age in seconds of the oldest pending entry (`None` when nothing is
+    /// pending)
+    #[serde(default)]
+    #[cfg_attr(feature = "ts-gen", ts(type = "number | null"))]
+    pub oldest_pending_age_secs: Option<i64>,
+    /// Histogram of pending entry ages
+    #[serde(default)]
+    pub pending_age_buckets: OutboxAgeBuckets,
+}
+
+/// Histogram of outbox entry ages.
+///
+/// Bucket boundaries are exclusive upper bounds: an entry exactly 60 seconds
+/// old lands in `under_10m`, not `under_1m`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct OutboxAgeBuckets {
+    pub under_1m: u32,
+    pub under_10m: u32,
+    pub under_1h: u32,
+    pub older: u32,
+}
+
+impl OutboxAgeBuckets {
+    /// Upper bound (exclusive) of the `under_1m` bucket, in seconds.
+    pub const ONE_MINUTE_SECS: i64 = 60;
+    /// Upper bound (exclusive) of the `under_10m` bucket, in seconds.
+    pub const TEN_MINUTES_SECS: i64 = 600;
+    /// Upper bound (exclusive) of the `under_1h` bucket, in seconds.
+    pub const ONE_HOUR_SECS: i64 = 3_600;
+
+    /// Record a single entry of the given age (seconds) in the matching
+    /// bucket.
+    ///
+    /// Negative ages (entries created "in the future" due to clock skew) are
+    /// counted as fresh.
+    pub fn record(&mut self, age_secs: i64) {
+        let bucket = if age_secs < Self::ONE_MINUTE_SECS {
+            &mut self.under_1m
+        } else if age_secs < Self::TEN_MINUTES_SECS {
+            &mut self.under_10m
+        } else if age_secs < Self::ONE_HOUR_SECS {
+            &mut self.under_1h
+        } else {
+            &mut self.older
+        };
+        *bucket = bucket.saturating_add(1);
+    }
+
+    /// Total number of entries across all buckets.
+    #[must_use]
+    pub fn total(&self) -> u32 {
+        self.under_1m
+            .saturating_add(self.under_10m)
+            .saturating_add(self.under_1h)
+            .saturating_add(self.older)
+    }
+}
+
+/// Outcome of a user-initiated outbox flush (`sync_now`).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct OutboxFlushReport {
+    /// Entries forwarded and marked sent
+    pub forwarded: u32,
+    /// Entries that failed to parse or forward (scheduled for retry)
+    pub failed: u32,
+    /// SAP-bound entries left for the SAP scheduler
+    pub skipped: u32,
 }
 
 /// Local synchronisation settings for the SAP integration.
@@ -59,3 +146,33 @@ pub struct SapSyncSettings {
     pub last_sync_epoch: Option<i64>,
     pub last_sync_status: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn age_buckets_use_exclusive_upper_bounds() {
+        let mut buckets = OutboxAgeBuckets::default();
+        for age in [-5, 0, 59, 60, 599, 600, 3_599, 3_600, 86_400] {
+            buckets.record(age);
+        }
+
+        assert_eq!(buckets.under_1m, 3);
+        assert_eq!(buckets.under_10m, 2);
+        assert_eq!(buckets.under_1h, 2);
+        assert_eq!(buckets.older, 2);
+        assert_eq!(buckets.total(), 9);
+    }
+
+    #[test]
+    fn summary_deserializes_legacy_payload_without_age_fields() {
+        let json = r#"{"pending_count":1,"sent_count":2,"failed_count":3}"#;
+        let summary: OutboxStatusSummary = serde_json::from_str(json).expect("legacy payload");
+
+        assert_eq!(summary.pending_count, 1);
+        assert_eq!(summary.dismissed_count, 0);
+        assert_eq!(summary.oldest_pending_age_secs, None);
+        assert_eq!(summary.pending_age_buckets, OutboxAgeBuckets::default());
+    }
+}
@@ -0,0 +1,85 @@
+//! Timeline types
+//!
+//! Items shown on the day timeline: calendar events next to tracked activity.
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "ts-gen")]
+use ts_rs::TS;
+
+/// Timeline calendar event used for timeline visualisations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export, rename_all = "camelCase"))]
+pub struct TimelineCalendarEvent {
+    pub id: String,
+    pub project: String,
+    pub task: String,
+    pub start_time: String,
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub start_epoch: i64,
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub duration: i64,
+    pub status: String,
+    pub is_calendar_event: bool,
+    pub is_all_day: bool,
+    pub original_summary: String,
+}
+
+/// Tracked activity (a proposed block or a raw segment) on the timeline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export, rename_all = "camelCase"))]
+pub struct TimelineActivity {
+    /// Block or segment id
+    pub id: String,
+    /// Deal/project name for classified blocks, else the activity label
+    pub label: String,
+    /// App with the most time in the activity
+    pub primary_app: String,
+    /// Inferred project ID, for blocks
+    pub project_id: Option<String>,
+    /// Block review status ("suggested", "accepted", ...); `None` for
+    /// segments
+    pub status: Option<String>,
+    /// Start time formatted in the timeline's timezone
+    pub start_time: String,
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub start_epoch: i64,
+    /// Duration in seconds
+    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
+    pub duration: i64,
+}
+
+/// One entry of the merged timeline, tagged by `kind`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub enum TimelineItem {
+    /// A calendar event
+    CalendarEvent(TimelineCalendarEvent),
+    /// A proposed block built from activity
+    Block(TimelineActivity),
+    /// Tracked activity not (yet) part of a block
+    Segment(TimelineActivity),
+}
+
+impl TimelineItem {
+    /// Start of the item (Unix epoch seconds)
+    pub fn start_epoch(&self) -> i64 {
+        match self {
+            Self::CalendarEvent(event) => event.start_epoch,
+            Self::Block(activity) | Self::Segment(activity) => activity.start_epoch,
+        }
+    }
+
+    /// Id of the underlying event, block or segment
+    pub fn id(&self) -> &str {
+        match self {
+            Self::CalendarEvent(event) => &event.id,
+            Self::Block(activity) | Self::Segment(activity) => &activity.id,
+        }
+    }
+}
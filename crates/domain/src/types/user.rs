@@ -6,8 +6,10 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "ts-gen")]
 use ts_rs::TS;
 
+use crate::types::CalendarSyncSettings;
+
 /// User profile stored in local database
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "ts-gen", derive(TS))]
 #[cfg_attr(feature = "ts-gen", ts(export))]
 pub struct UserProfile {
@@ -41,4 +43,126 @@ pub struct UserProfile {
     pub created_at: i64,
     #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
     pub updated_at: i64,
+    /// Working hours, evaluated in `timezone`
+    #[serde(default)]
+    pub workday: WorkdayConfig,
+}
+
+/// A user's working hours
+///
+/// Times are local to the profile's `timezone`. A workday whose end is
+/// earlier than its start runs past midnight.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-gen", derive(TS))]
+#[cfg_attr(feature = "ts-gen", ts(export))]
+pub struct WorkdayConfig {
+    /// Start of the workday, minutes after local midnight (default 480 =
+    /// 08:00)
+    pub start_minutes: u32,
+    /// End of the workday, minutes after local midnight (default 1080 =
+    /// 18:00)
+    pub end_minutes: u32,
+    /// Working days as ISO weekday numbers, 1 = Monday .. 7 = Sunday
+    /// (default Monday-Friday)
+    pub working_days: Vec<u8>,
+}
+
+impl Default for WorkdayConfig {
+    fn default() -> Self {
+        Self { start_minutes: 8 * 60, end_minutes: 18 * 60, working_days: vec![1, 2, 3, 4, 5] }
+    }
+}
+
+impl WorkdayConfig {
+    /// Working days as stored in the database (e.g., "1,2,3,4,5")
+    pub fn working_days_csv(&self) -> String {
+        self.working_days.iter().map(u8::to_string).collect::<Vec<_>>().join(",")
+    }
+
+    /// Parse stored working days, skipping anything that is not 1-7
+    pub fn parse_working_days(value: &str) -> Vec<u8> {
+        value
+            .split(',')
+            .filter_map(|day| day.trim().parse::<u8>().ok())
+            .filter(|day| (1..=7).contains(day))
+            .collect()
+    }
+}
+
+/// One change to a stored user setting
+///
+/// Values are the setting's JSON encoding. The first change for a key has no
+/// `old_value`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserSettingChange {
+    /// Setting key (e.g., "idle", "workday")
+    pub key: String,
+    /// Scope within the key ("" for user-wide settings, an account email for
+    /// per-account ones)
+    pub scope: String,
+    /// Version this change produced, starting at 1
+    pub version: i64,
+    pub old_value: Option<String>,
+    pub new_value: String,
+    /// When the change was made (Unix epoch seconds)
+    pub changed_at: i64,
+}
+
+/// Outcome of resetting all settings to their defaults
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SettingsResetSummary {
+    /// Settings store entries whose value changed (one history entry each)
+    pub settings_changed: usize,
+    /// Calendar accounts whose sync preferences were restored
+    pub calendar_accounts: usize,
+    /// Feature flags restored to their shipped values
+    pub feature_flags: usize,
+}
+
+/// [`ConfigBundle`] version written by this build
+///
+/// Bump when the bundle layout changes; bundles with any other version are
+/// rejected on import.
+pub const CONFIG_BUNDLE_VERSION: u32 = 1;
+
+/// Portable copy of a user's configuration, for moving between machines
+///
+/// Secrets (OAuth tokens, API keys) stay in the keychain and are never
+/// included, so calendar accounts must be reconnected after an import.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigBundle {
+    pub version: u32,
+    /// When the bundle was exported (Unix epoch seconds)
+    pub exported_at: i64,
+    pub profile: Option<UserProfile>,
+    /// Entries of the settings store
+    pub settings: Vec<ConfigSetting>,
+    /// Feature flags whose state differs from the shipped defaults
+    pub feature_flags: Vec<FeatureFlagOverride>,
+    pub calendar_connections: Vec<CalendarConnection>,
+}
+
+/// One settings store entry in a [`ConfigBundle`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigSetting {
+    pub key: String,
+    pub scope: String,
+    /// JSON encoding of the setting, as stored
+    pub value: String,
+}
+
+/// Feature flag state carried in a [`ConfigBundle`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatureFlagOverride {
+    pub flag_name: String,
+    pub enabled: bool,
+}
+
+/// A connected calendar account and its sync preferences, without tokens
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalendarConnection {
+    pub provider: String,
+    pub email: String,
+    /// Sync preferences; `sync_token` and `last_sync_epoch` are never carried
+    pub sync: CalendarSyncSettings,
 }
@@ -31,7 +31,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     if let Ok(ca_cert_path) = std::env::var("MDM_CA_CERT") {
         println!("🔐 Using CA certificate from: {}", ca_cert_path);
 
-        let _client = MdmClient::with_ca_cert("https://localhost:8080/mdm/config", &ca_cert_path)?;
+        let client = MdmClient::with_ca_cert("https://localhost:8080/mdm/config", &ca_cert_path)?;
+        // Remote configuration is only merged if signed with this key
+        let _client = match std::env::var("MDM_PUBLIC_KEY") {
+            Ok(public_key) => client.with_public_key(&public_key)?,
+            Err(_) => client,
+        };
 
         println!("✓ MDM client created with custom CA");
         println!("  URL: https://localhost:8080/mdm/config");
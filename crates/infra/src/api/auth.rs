@@ -34,8 +34,8 @@ impl ApiAuthService {
     /// # Arguments
     ///
     /// * `config` - OAuth configuration
-    /// * `keychain` - Keychain provider for token storage
-    /// * `service_name` - Keychain service name (e.g., "PulseArc.api")
+    /// * `keychain` - Keychain provider for token storage (carries the service
+    ///   name, e.g. "PulseArc.api")
     /// * `account_name` - Keychain account name (e.g., "main")
     ///
     /// # Returns
@@ -44,13 +44,11 @@ impl ApiAuthService {
     pub fn new(
         config: OAuthConfig,
         keychain: Arc<KeychainProvider>,
-        service_name: String,
         account_name: String,
     ) -> Self {
         let oauth = Arc::new(OAuthService::new(
             config,
             keychain,
-            service_name,
             account_name,
             300, // Refresh 5 minutes before expiry
         ));
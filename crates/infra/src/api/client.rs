@@ -16,6 +16,7 @@ use tracing::{debug, info, instrument, warn};
 use super::auth::AccessTokenProvider;
 use super::errors::ApiError;
 use crate::http::HttpClient;
+use crate::observability::metrics::PerformanceMetrics;
 
 /// Configuration for API client
 #[derive(Debug, Clone)]
@@ -88,6 +89,14 @@ impl ApiClient {
         })
     }
 
+    /// Record per-operation retry metrics (attempts histogram, exhausted
+    /// retries) in `metrics`
+    pub fn with_metrics(mut self, metrics: Arc<PerformanceMetrics>) -> Self {
+        let http_client = (*self.http_client).clone().with_retry_metrics(metrics);
+        self.http_client = Arc::new(http_client);
+        self
+    }
+
     /// Create a builder for fluent configuration
     pub fn builder() -> ApiClientBuilder {
         ApiClientBuilder::default()
@@ -114,6 +123,7 @@ impl ApiClient {
 
         let client = self.http_client.clone();
         let url_clone = url.clone();
+        let operation = operation_name(&Method::GET, path);
         let auth = self.auth.clone();
         let timeout = self.config.timeout;
 
@@ -123,6 +133,7 @@ impl ApiClient {
                 let client = client.clone();
                 let url = url_clone.clone();
                 let auth = auth.clone();
+                let operation = operation.clone();
                 async move {
                     // Fetch token inside retry loop to allow refresh on auth errors
                     let token = auth.access_token().await?;
@@ -132,7 +143,9 @@ impl ApiClient {
                         .header("Authorization", format!("Bearer {}", token))
                         .header("Content-Type", "application/json");
 
-                    match tokio::time::timeout(timeout, client.send(request)).await {
+                    match tokio::time::timeout(timeout, client.send_operation(&operation, request))
+                        .await
+                    {
                         Ok(Ok(resp)) => Ok(resp),
                         Ok(Err(err)) => Err(Self::map_pulsearc_error(err)),
                         Err(_) => Err(ApiError::Timeout(timeout)),
@@ -194,6 +207,7 @@ impl ApiClient {
 
         let client = self.http_client.clone();
         let url_clone = url.clone();
+        let operation = operation_name(&Method::POST, path);
         let auth = self.auth.clone();
         let body_json = serde_json::to_value(body)
             .map_err(|e| ApiError::Client(format!("Failed to serialize body: {}", e)))?;
@@ -205,6 +219,7 @@ impl ApiClient {
                 let client = client.clone();
                 let url = url_clone.clone();
                 let auth = auth.clone();
+                let operation = operation.clone();
                 let body = body_json.clone();
                 async move {
                     // Fetch token inside retry loop to allow refresh on auth errors
@@ -216,7 +231,9 @@ impl ApiClient {
                         .header("Content-Type", "application/json")
                         .json(&body);
 
-                    match tokio::time::timeout(timeout, client.send(request)).await {
+                    match tokio::time::timeout(timeout, client.send_operation(&operation, request))
+                        .await
+                    {
                         Ok(Ok(resp)) => Ok(resp),
                         Ok(Err(err)) => Err(Self::map_pulsearc_error(err)),
                         Err(_) => Err(ApiError::Timeout(timeout)),
@@ -343,6 +360,7 @@ impl ApiClient {
             PulseArcError::NotFound(message) | PulseArcError::InvalidInput(message) => {
                 ApiError::Client(message)
             }
+            PulseArcError::DatabaseOpen(err) => ApiError::Server(err.to_string()),
             PulseArcError::Database(message)
             | PulseArcError::Platform(message)
             | PulseArcError::Internal(message) => ApiError::Server(message),
@@ -350,6 +368,14 @@ impl ApiClient {
     }
 }
 
+/// Retry-metrics operation name for a request: method plus the first path
+/// segment, so ids and query strings don't create new series
+/// (`/segments/abc?x=1` → `api.GET /segments`)
+fn operation_name(method: &Method, path: &str) -> String {
+    let resource = path.trim_start_matches('/').split(['/', '?']).next().unwrap_or_default();
+    format!("api.{} /{}", method, resource)
+}
+
 /// Builder for API client
 #[derive(Default)]
 pub struct ApiClientBuilder {
@@ -730,4 +756,11 @@ mod tests {
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), ApiError::Auth(_)));
     }
+
+    #[test]
+    fn test_operation_name_ignores_ids_and_queries() {
+        assert_eq!(operation_name(&Method::GET, "/segments/abc-123"), "api.GET /segments");
+        assert_eq!(operation_name(&Method::GET, "/blocks?limit=10"), "api.GET /blocks");
+        assert_eq!(operation_name(&Method::POST, "/snapshots"), "api.POST /snapshots");
+    }
 }
@@ -1,17 +1,20 @@
 //! API batch forwarder with partial success handling
 //!
 //! Provides batch submission of segments and snapshots with resilience
-//! patterns and partial success handling.
+//! patterns and partial success handling. An optional adaptive concurrency
+//! limiter caps in-flight submissions below `max_parallel` based on observed
+//! API latency.
 
 use std::sync::Arc;
 
+use pulsearc_common::resilience::{AdaptiveConcurrencyLimiter, AdaptiveConcurrencyMetrics};
 use pulsearc_domain::types::{ActivitySegment, ActivitySnapshot};
 use serde::{Deserialize, Serialize};
 use tokio::task::JoinHandle;
 use tracing::{debug, info, instrument, warn};
 
 use super::commands::ApiCommands;
-use super::errors::ApiError;
+use super::errors::{ApiError, ApiErrorCategory};
 
 /// Type alias for task list to avoid complexity warnings
 type TaskList = Vec<(usize, JoinHandle<Result<(), ApiError>>)>;
@@ -46,6 +49,7 @@ pub struct BatchSubmissionResult {
 pub struct ApiForwarder {
     commands: Arc<ApiCommands>,
     config: ForwarderConfig,
+    concurrency_limiter: Option<AdaptiveConcurrencyLimiter>,
 }
 
 impl ApiForwarder {
@@ -56,7 +60,22 @@ impl ApiForwarder {
     /// * `commands` - API commands instance
     /// * `config` - Forwarder configuration
     pub fn new(commands: Arc<ApiCommands>, config: ForwarderConfig) -> Self {
-        Self { commands, config }
+        Self { commands, config, concurrency_limiter: None }
+    }
+
+    /// Gate each submission on a permit from an adaptive concurrency limiter
+    ///
+    /// The limiter can be shared with other senders (e.g. the outbox worker)
+    /// so they adapt to the same backend together.
+    pub fn with_concurrency_limiter(mut self, limiter: AdaptiveConcurrencyLimiter) -> Self {
+        self.concurrency_limiter = Some(limiter);
+        self
+    }
+
+    /// Current adaptive concurrency limit and in-flight count, if a limiter is
+    /// configured
+    pub fn concurrency_metrics(&self) -> Option<AdaptiveConcurrencyMetrics> {
+        self.concurrency_limiter.as_ref().map(AdaptiveConcurrencyLimiter::metrics)
     }
 
     /// Forward a batch of segments to the API
@@ -152,10 +171,24 @@ impl ApiForwarder {
                 let commands = Arc::clone(&self.commands);
                 let item_clone = item.clone();
                 let global_idx = batch_idx * batch_size + idx;
+                let limiter = self.concurrency_limiter.clone();
 
                 tasks.push((
                     global_idx,
-                    tokio::spawn(async move { submit(commands, &item_clone).await }),
+                    tokio::spawn(async move {
+                        let Some(limiter) = limiter else {
+                            return submit(commands, &item_clone).await;
+                        };
+
+                        let permit = limiter.acquire().await;
+                        let result = submit(commands, &item_clone).await;
+                        match &result {
+                            Ok(()) => permit.record_success(),
+                            Err(err) if signals_overload(err) => permit.record_failure(),
+                            Err(_) => drop(permit),
+                        }
+                        result
+                    }),
                 ));
 
                 if tasks.len() >= max_parallel {
@@ -202,6 +235,15 @@ impl ApiForwarder {
     }
 }
 
+/// Whether a failed submission suggests the backend is overloaded (as opposed
+/// to a problem with the request or credentials)
+fn signals_overload(err: &ApiError) -> bool {
+    matches!(
+        err.category(),
+        ApiErrorCategory::RateLimit | ApiErrorCategory::Server | ApiErrorCategory::Network
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,100 @@
+//! Team app category rules files
+//!
+//! Admins share app categorization across a team as a JSON or TOML file with
+//! a `rules` list, each entry shaped like
+//! [`AppCategoryRuleParams`](pulsearc_domain::types::classification::AppCategoryRuleParams):
+//!
+//! ```toml
+//! [[rules]]
+//! bundle_id = "com.tinyspeck.slackmacgap"
+//! title_pattern = "#astro-deal"
+//! category = "client_work"
+//! project_id = "USC0042105"
+//! ```
+//!
+//! Entries are kept undecoded here so that one malformed rule is reported by
+//! [`AppCategoryRules::import`] instead of failing the whole file.
+
+use std::path::Path;
+
+use pulsearc_core::classification::AppCategoryRules;
+use pulsearc_domain::types::classification::{AppCategoryConflictPolicy, AppCategoryRuleImport};
+use pulsearc_domain::{PulseArcError, Result};
+use serde::Deserialize;
+
+use super::format::ConfigFormat;
+
+/// Top-level shape of a rules file
+#[derive(Debug, Deserialize)]
+struct AppCategoryRuleFile {
+    #[serde(default)]
+    rules: Vec<serde_json::Value>,
+}
+
+/// Parse the rule entries of a rules file
+///
+/// # Errors
+/// Returns `PulseArcError::InvalidInput` if the file is not valid `format`
+/// or has no `rules` list.
+pub fn parse_app_category_rules(
+    contents: &str,
+    format: ConfigFormat,
+) -> Result<Vec<serde_json::Value>> {
+    let file: AppCategoryRuleFile = format.parse(contents).map_err(|e| {
+        PulseArcError::InvalidInput(format!("Invalid app category rules file: {e}"))
+    })?;
+    Ok(file.rules)
+}
+
+/// Import the rules in the file at `source` into `rules`
+///
+/// The format is detected from the extension or contents (see
+/// [`ConfigFormat::detect`]). Conflicts with local rules are resolved with
+/// `policy`; invalid entries are reported in the result.
+///
+/// # Errors
+/// Returns `PulseArcError::Config` if the file cannot be read,
+/// `PulseArcError::InvalidInput` if it cannot be parsed, or the storage error
+/// if the rules cannot be saved.
+pub async fn import_app_category_rules(
+    rules: &AppCategoryRules,
+    source: &Path,
+    policy: AppCategoryConflictPolicy,
+    imported_at: i64,
+) -> Result<AppCategoryRuleImport> {
+    tracing::info!(path = %source.display(), ?policy, "Importing app category rules");
+
+    let contents = tokio::fs::read_to_string(source).await.map_err(|e| {
+        PulseArcError::Config(format!(
+            "Failed to read app category rules file {}: {e}",
+            source.display()
+        ))
+    })?;
+    let entries = parse_app_category_rules(&contents, ConfigFormat::detect(source, &contents))?;
+
+    rules.import(entries, policy, imported_at).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_json_and_toml_rule_files() {
+        let json = r#"{ "rules": [{ "bundle_id": "us.zoom.xos", "category": "meeting" }] }"#;
+        let toml = "[[rules]]\nbundle_id = \"us.zoom.xos\"\ncategory = \"meeting\"\n";
+
+        let from_json = parse_app_category_rules(json, ConfigFormat::Json).expect("json");
+        let from_toml = parse_app_category_rules(toml, ConfigFormat::Toml).expect("toml");
+
+        assert_eq!(from_json, from_toml);
+        assert_eq!(from_json[0]["bundle_id"], "us.zoom.xos");
+    }
+
+    #[test]
+    fn malformed_file_is_rejected() {
+        let err = parse_app_category_rules("[[rules]\nbundle_id =", ConfigFormat::Toml)
+            .expect_err("invalid TOML");
+        assert!(matches!(err, PulseArcError::InvalidInput(_)));
+    }
+}
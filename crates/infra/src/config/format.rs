@@ -0,0 +1,130 @@
+//! Config file format detection
+//!
+//! Config files may be JSON or TOML. The format is taken from the file
+//! extension when it is `.json` or `.toml`; otherwise the contents are
+//! sniffed (a document starting with `{` is JSON, anything else TOML).
+//! Parse and render failures are reported as
+//! [`CommonError::Serialization`] carrying the format that was attempted.
+
+use std::fmt;
+use std::path::Path;
+
+use pulsearc_common::error::{CommonError, CommonResult};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Serialization format of a config file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Json,
+    Toml,
+}
+
+impl ConfigFormat {
+    /// Format name used in error messages (`"JSON"` or `"TOML"`)
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Json => "JSON",
+            Self::Toml => "TOML",
+        }
+    }
+
+    /// Format implied by the file extension, if it is a known one
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?;
+        if extension.eq_ignore_ascii_case("json") {
+            Some(Self::Json)
+        } else if extension.eq_ignore_ascii_case("toml") {
+            Some(Self::Toml)
+        } else {
+            None
+        }
+    }
+
+    /// Guess the format from file contents
+    ///
+    /// A config is always an object, so JSON documents start with `{`; TOML
+    /// documents start with a table header, key or comment.
+    pub fn sniff(contents: &str) -> Self {
+        let trimmed = contents.trim_start_matches('\u{FEFF}').trim_start();
+        if trimmed.starts_with('{') {
+            Self::Json
+        } else {
+            Self::Toml
+        }
+    }
+
+    /// Detect the format by extension, falling back to sniffing the contents
+    pub fn detect(path: &Path, contents: &str) -> Self {
+        Self::from_path(path).unwrap_or_else(|| Self::sniff(contents))
+    }
+
+    /// Deserialize `contents` in this format
+    ///
+    /// # Errors
+    /// Returns `CommonError::Serialization` with this format on parse failure.
+    pub fn parse<T: DeserializeOwned>(self, contents: &str) -> CommonResult<T> {
+        match self {
+            Self::Json => serde_json::from_str(contents)
+                .map_err(|e| CommonError::serialization_format(self.as_str(), e.to_string())),
+            Self::Toml => toml::from_str(contents)
+                .map_err(|e| CommonError::serialization_format(self.as_str(), e.to_string())),
+        }
+    }
+
+    /// Serialize `value` in this format (pretty-printed)
+    ///
+    /// # Errors
+    /// Returns `CommonError::Serialization` with this format if `value`
+    /// cannot be represented.
+    pub fn render<T: Serialize>(self, value: &T) -> CommonResult<String> {
+        match self {
+            Self::Json => serde_json::to_string_pretty(value)
+                .map_err(|e| CommonError::serialization_format(self.as_str(), e.to_string())),
+            Self::Toml => toml::to_string_pretty(value)
+                .map_err(|e| CommonError::serialization_format(self.as_str(), e.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for ConfigFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn test_detect_prefers_extension() {
+        let path = PathBuf::from("config.TOML");
+        assert_eq!(ConfigFormat::detect(&path, "{}"), ConfigFormat::Toml);
+        assert_eq!(ConfigFormat::from_path(Path::new("config.json")), Some(ConfigFormat::Json));
+        assert_eq!(ConfigFormat::from_path(Path::new("config.yaml")), None);
+    }
+
+    #[test]
+    fn test_detect_sniffs_unknown_extensions() {
+        let path = PathBuf::from("pulsearc.conf");
+        assert_eq!(ConfigFormat::detect(&path, "\n  {\"sync\": {}}"), ConfigFormat::Json);
+        assert_eq!(ConfigFormat::detect(&path, "# comment\n[sync]"), ConfigFormat::Toml);
+    }
+
+    #[test]
+    fn test_parse_error_reports_attempted_format() {
+        let err = ConfigFormat::Toml
+            .parse::<toml::Value>("[unterminated")
+            .expect_err("invalid TOML should fail");
+
+        match err {
+            CommonError::Serialization { format, .. } => {
+                assert_eq!(format.as_deref(), Some("TOML"));
+            }
+            other => panic!("expected serialization error, got {other:?}"),
+        }
+    }
+}
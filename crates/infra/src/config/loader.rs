@@ -6,18 +6,36 @@
 //! 1. First, attempts to load from environment variables
 //! 2. If incomplete, falls back to loading from file
 //! 3. Probes multiple paths for config files
-//! 4. Supports JSON and TOML formats
+//! 4. Supports JSON and TOML formats (by extension, else by content; see
+//!    [`ConfigFormat`])
 //!
 //! ## Environment Variables
 //! - `PULSEARC_DB_PATH`: Database file path
 //! - `PULSEARC_DB_POOL_SIZE`: Connection pool size
 //! - `PULSEARC_DB_ENCRYPTION_KEY`: Database encryption key
+//! - `PULSEARC_DB_BUSY_TIMEOUT_MS`: Milliseconds to wait for a locked database
+//!   (optional, default 5000)
+//! - `PULSEARC_DB_QUERY_TIMEOUT_MS`: Milliseconds a query may run before it is
+//!   interrupted (optional, default 30000)
 //! - `PULSEARC_SYNC_INTERVAL`: Sync interval in seconds
 //! - `PULSEARC_SYNC_ENABLED`: Whether sync is enabled (true/false)
+//! - `PULSEARC_SYNC_OUTBOX_WORKER_ENABLED`: Whether the background outbox
+//!   worker forwards entries to Neon (true/false, default false)
 //! - `PULSEARC_TRACKING_SNAPSHOT_INTERVAL`: Snapshot interval in seconds
 //! - `PULSEARC_TRACKING_IDLE_THRESHOLD`: Idle threshold in seconds
+//! - `PULSEARC_TRACKING_IDLE_EXIT_THRESHOLD`: Sustained activity (seconds)
+//!   needed to end an idle period (optional, derived from the idle threshold)
+//! - `PULSEARC_TRACKING_SNAPSHOT_HIGH_WATER_COUNT`: Snapshot count that
+//!   triggers capture-time eviction of synced snapshots (optional)
+//! - `PULSEARC_TRACKING_SNAPSHOT_HIGH_WATER_BYTES`: Database size in bytes that
+//!   triggers capture-time eviction of synced snapshots (optional)
+//! - `PULSEARC_TRACKING_MIN_DWELL_MS`: Milliseconds an app must stay focused
+//!   before it is recorded (optional, unset records every focus)
 //! - `PULSEARC_TRACKING_ENABLED`: Whether tracking is enabled (true/false)
 //!
+//! Cost rates (`[costs]`) are only read from config files; environment
+//! configuration uses the built-in defaults.
+//!
 //! ## File Locations
 //! The loader probes the following paths (in order):
 //! 1. `./config.json` or `./config.toml` (current working directory)
@@ -28,7 +46,11 @@
 
 use std::path::{Path, PathBuf};
 
-use pulsearc_domain::{Config, DatabaseConfig, PulseArcError, Result, SyncConfig, TrackingConfig};
+use pulsearc_domain::{
+    Config, CostRateConfig, DatabaseConfig, PulseArcError, Result, SyncConfig, TrackingConfig,
+};
+
+use super::format::ConfigFormat;
 
 /// Load configuration with automatic fallback strategy
 ///
@@ -72,11 +94,14 @@ pub fn load_from_env() -> Result<Config> {
         s.parse::<u32>().map_err(|e| PulseArcError::Config(format!("Invalid pool size: {}", e)))
     })?;
     let db_encryption_key = std::env::var("PULSEARC_DB_ENCRYPTION_KEY").ok();
+    let db_busy_timeout_ms = optional_env_u64("PULSEARC_DB_BUSY_TIMEOUT_MS", "busy timeout")?;
+    let db_query_timeout_ms = optional_env_u64("PULSEARC_DB_QUERY_TIMEOUT_MS", "query timeout")?;
 
     let sync_interval = env_var("PULSEARC_SYNC_INTERVAL").and_then(|s| {
         s.parse::<u64>().map_err(|e| PulseArcError::Config(format!("Invalid sync interval: {}", e)))
     })?;
     let sync_enabled = env_bool("PULSEARC_SYNC_ENABLED", true);
+    let outbox_worker_enabled = env_bool("PULSEARC_SYNC_OUTBOX_WORKER_ENABLED", false);
 
     let tracking_snapshot_interval =
         env_var("PULSEARC_TRACKING_SNAPSHOT_INTERVAL").and_then(|s| {
@@ -88,6 +113,22 @@ pub fn load_from_env() -> Result<Config> {
         s.parse::<u64>()
             .map_err(|e| PulseArcError::Config(format!("Invalid idle threshold: {}", e)))
     })?;
+    let tracking_idle_exit_threshold = std::env::var("PULSEARC_TRACKING_IDLE_EXIT_THRESHOLD")
+        .ok()
+        .map(|s| {
+            s.parse::<u64>()
+                .map_err(|e| PulseArcError::Config(format!("Invalid idle exit threshold: {}", e)))
+        })
+        .transpose()?;
+    let snapshot_high_water_count = optional_env_u64(
+        "PULSEARC_TRACKING_SNAPSHOT_HIGH_WATER_COUNT",
+        "snapshot high-water count",
+    )?;
+    let snapshot_high_water_bytes = optional_env_u64(
+        "PULSEARC_TRACKING_SNAPSHOT_HIGH_WATER_BYTES",
+        "snapshot high-water bytes",
+    )?;
+    let min_dwell_ms = optional_env_u64("PULSEARC_TRACKING_MIN_DWELL_MS", "minimum dwell")?;
     let tracking_enabled = env_bool("PULSEARC_TRACKING_ENABLED", true);
 
     Ok(Config {
@@ -95,20 +136,31 @@ pub fn load_from_env() -> Result<Config> {
             path: db_path,
             pool_size: db_pool_size,
             encryption_key: db_encryption_key,
+            busy_timeout_ms: db_busy_timeout_ms,
+            query_timeout_ms: db_query_timeout_ms,
+        },
+        sync: SyncConfig {
+            interval_seconds: sync_interval,
+            enabled: sync_enabled,
+            outbox_worker_enabled,
         },
-        sync: SyncConfig { interval_seconds: sync_interval, enabled: sync_enabled },
         tracking: TrackingConfig {
             snapshot_interval_seconds: tracking_snapshot_interval,
             idle_threshold_seconds: tracking_idle_threshold,
+            idle_exit_threshold_seconds: tracking_idle_exit_threshold,
+            snapshot_high_water_count,
+            snapshot_high_water_bytes,
+            min_dwell_ms,
             enabled: tracking_enabled,
         },
+        costs: CostRateConfig::default(),
     })
 }
 
 /// Load configuration from a file
 ///
 /// If `path` is `None`, probes multiple locations for config files.
-/// Supports both JSON and TOML formats (detected by file extension).
+/// Supports both JSON and TOML formats (see [`ConfigFormat::detect`]).
 ///
 /// # Arguments
 /// * `path` - Optional path to config file. If `None`, uses
@@ -121,6 +173,16 @@ pub fn load_from_env() -> Result<Config> {
 /// - File format is invalid
 /// - Required fields are missing
 pub fn load_from_file(path: Option<PathBuf>) -> Result<Config> {
+    load_from_file_with_format(path).map(|(config, _)| config)
+}
+
+/// Like [`load_from_file`], also returning the detected file format
+///
+/// Pass the format to [`save`] to write the config back the way it was read.
+///
+/// # Errors
+/// Same as [`load_from_file`].
+pub fn load_from_file_with_format(path: Option<PathBuf>) -> Result<(Config, ConfigFormat)> {
     let config_path = match path {
         Some(p) => {
             if !p.exists() {
@@ -146,26 +208,44 @@ pub fn load_from_file(path: Option<PathBuf>) -> Result<Config> {
     parse_config(&contents, &config_path)
 }
 
+/// Write configuration to `path` in `format`
+///
+/// The database encryption key is never written (it is skipped during
+/// serialization), so it must keep coming from the environment or keychain.
+///
+/// # Errors
+/// Returns `PulseArcError::Config` if serialization or the write fails.
+pub fn save(config: &Config, path: &Path, format: ConfigFormat) -> Result<()> {
+    let contents = format.render(config).map_err(|e| PulseArcError::Config(e.to_string()))?;
+
+    std::fs::write(path, contents).map_err(|e| {
+        PulseArcError::Config(format!("Failed to write config file {}: {}", path.display(), e))
+    })?;
+
+    tracing::info!(path = %path.display(), %format, "Configuration saved to file");
+    Ok(())
+}
+
 /// Parse configuration from string content
 ///
-/// Format is detected by file extension (`.json` or `.toml`).
+/// Format is detected by file extension (`.json` or `.toml`), falling back
+/// to sniffing the contents for other extensions.
 ///
 /// # Arguments
 /// * `contents` - File contents as string
 /// * `path` - Path to the file (for format detection and error messages)
 ///
 /// # Errors
-/// Returns `PulseArcError::Config` if format is invalid or parsing fails.
-fn parse_config(contents: &str, path: &Path) -> Result<Config> {
-    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("json");
-
-    match extension {
-        "toml" => toml::from_str(contents)
-            .map_err(|e| PulseArcError::Config(format!("Invalid TOML format: {}", e))),
-        "json" => serde_json::from_str(contents)
-            .map_err(|e| PulseArcError::Config(format!("Invalid JSON format: {}", e))),
-        _ => Err(PulseArcError::Config(format!("Unsupported config format: {}", extension))),
-    }
+/// Returns `PulseArcError::Config` wrapping the format-tagged serialization
+/// error if parsing fails.
+fn parse_config(contents: &str, path: &Path) -> Result<(Config, ConfigFormat)> {
+    let format = ConfigFormat::detect(path, contents);
+
+    let config = format.parse(contents).map_err(|e| {
+        PulseArcError::Config(format!("Invalid {format} format in {}: {}", path.display(), e))
+    })?;
+
+    Ok((config, format))
 }
 
 /// Probe multiple paths for configuration files
@@ -242,12 +322,25 @@ fn env_bool(key: &str, default: bool) -> bool {
         .unwrap_or(default)
 }
 
+/// Parse an optional unsigned integer from an environment variable
+///
+/// Returns `Ok(None)` when the variable is not set.
+fn optional_env_u64(key: &str, label: &str) -> Result<Option<u64>> {
+    std::env::var(key)
+        .ok()
+        .map(|s| {
+            s.parse::<u64>().map_err(|e| PulseArcError::Config(format!("Invalid {}: {}", label, e)))
+        })
+        .transpose()
+}
+
 #[cfg(test)]
 mod tests {
     use std::io::Write;
     use std::sync::Mutex;
 
     use once_cell::sync::Lazy;
+    use pulsearc_domain::ModelPricing;
     use tempfile::NamedTempFile;
 
     use super::*;
@@ -309,6 +402,7 @@ mod tests {
         std::env::set_var("PULSEARC_DB_ENCRYPTION_KEY", "test-key");
         std::env::set_var("PULSEARC_SYNC_INTERVAL", "15");
         std::env::set_var("PULSEARC_SYNC_ENABLED", "true");
+        std::env::set_var("PULSEARC_SYNC_OUTBOX_WORKER_ENABLED", "true");
         std::env::set_var("PULSEARC_TRACKING_SNAPSHOT_INTERVAL", "45");
         std::env::set_var("PULSEARC_TRACKING_IDLE_THRESHOLD", "600");
         std::env::set_var("PULSEARC_TRACKING_ENABLED", "false");
@@ -322,6 +416,7 @@ mod tests {
         assert_eq!(config.database.encryption_key, Some("test-key".to_string()));
         assert_eq!(config.sync.interval_seconds, 15);
         assert!(config.sync.enabled);
+        assert!(config.sync.outbox_worker_enabled);
         assert_eq!(config.tracking.snapshot_interval_seconds, 45);
         assert_eq!(config.tracking.idle_threshold_seconds, 600);
         assert!(!config.tracking.enabled);
@@ -332,6 +427,7 @@ mod tests {
         std::env::remove_var("PULSEARC_DB_ENCRYPTION_KEY");
         std::env::remove_var("PULSEARC_SYNC_INTERVAL");
         std::env::remove_var("PULSEARC_SYNC_ENABLED");
+        std::env::remove_var("PULSEARC_SYNC_OUTBOX_WORKER_ENABLED");
         std::env::remove_var("PULSEARC_TRACKING_SNAPSHOT_INTERVAL");
         std::env::remove_var("PULSEARC_TRACKING_IDLE_THRESHOLD");
         std::env::remove_var("PULSEARC_TRACKING_ENABLED");
@@ -539,4 +635,104 @@ enabled = true
         let result = parse_config(content, &path);
         assert!(result.is_err(), "Should fail with unsupported format");
     }
+
+    const EQUIVALENT_JSON: &str = r#"{
+        "database": { "path": "shared.db", "pool_size": 8 },
+        "sync": { "interval_seconds": 30, "enabled": true },
+        "tracking": {
+            "snapshot_interval_seconds": 45,
+            "idle_threshold_seconds": 300,
+            "idle_exit_threshold_seconds": 20,
+            "enabled": false
+        }
+    }"#;
+
+    const EQUIVALENT_TOML: &str = r#"
+[database]
+path = "shared.db"
+pool_size = 8
+
+[sync]
+interval_seconds = 30
+enabled = true
+
+[tracking]
+snapshot_interval_seconds = 45
+idle_threshold_seconds = 300
+idle_exit_threshold_seconds = 20
+enabled = false
+"#;
+
+    #[test]
+    fn test_json_and_toml_files_load_identically() {
+        let dir = tempfile::tempdir().unwrap();
+        let json_path = dir.path().join("config.json");
+        let toml_path = dir.path().join("config.toml");
+        std::fs::write(&json_path, EQUIVALENT_JSON).unwrap();
+        std::fs::write(&toml_path, EQUIVALENT_TOML).unwrap();
+
+        let (from_json, json_format) = load_from_file_with_format(Some(json_path)).unwrap();
+        let (from_toml, toml_format) = load_from_file_with_format(Some(toml_path)).unwrap();
+
+        assert_eq!(json_format, ConfigFormat::Json);
+        assert_eq!(toml_format, ConfigFormat::Toml);
+        assert_eq!(from_json, from_toml);
+        assert_eq!(from_json.tracking.idle_exit_threshold_seconds, Some(20));
+    }
+
+    #[test]
+    fn test_unknown_extension_falls_back_to_content_sniffing() {
+        let dir = tempfile::tempdir().unwrap();
+        let json_path = dir.path().join("pulsearc.conf");
+        let toml_path = dir.path().join("pulsearc.cfg");
+        std::fs::write(&json_path, EQUIVALENT_JSON).unwrap();
+        std::fs::write(&toml_path, EQUIVALENT_TOML).unwrap();
+
+        let (from_json, json_format) = load_from_file_with_format(Some(json_path)).unwrap();
+        let (from_toml, toml_format) = load_from_file_with_format(Some(toml_path)).unwrap();
+
+        assert_eq!(json_format, ConfigFormat::Json);
+        assert_eq!(toml_format, ConfigFormat::Toml);
+        assert_eq!(from_json, from_toml);
+    }
+
+    #[test]
+    fn test_save_writes_back_in_loaded_format() {
+        let dir = tempfile::tempdir().unwrap();
+
+        for (name, contents) in [("config.json", EQUIVALENT_JSON), ("config.toml", EQUIVALENT_TOML)]
+        {
+            let path = dir.path().join(name);
+            std::fs::write(&path, contents).unwrap();
+
+            let (mut config, format) = load_from_file_with_format(Some(path.clone())).unwrap();
+            config.sync.interval_seconds = 90;
+            save(&config, &path, format).unwrap();
+
+            let written = std::fs::read_to_string(&path).unwrap();
+            assert_eq!(ConfigFormat::sniff(&written), format);
+            assert_eq!(load_from_file(Some(path)).unwrap(), config);
+        }
+    }
+
+    #[test]
+    fn test_invalid_toml_error_names_format() {
+        let path = PathBuf::from("broken.toml");
+        let err = parse_config("[database\npath = ", &path).unwrap_err();
+
+        assert!(matches!(&err, PulseArcError::Config(message) if message.contains("(TOML)")));
+    }
+    #[test]
+    fn test_cost_rates_from_file_override_defaults() {
+        let contents = format!(
+            "{EQUIVALENT_TOML}\n[costs.models.gpt-4o-mini]\ninput_per_1m = 0.30\noutput_per_1m = 1.20\n"
+        );
+        let (config, _) = parse_config(&contents, Path::new("config.toml")).unwrap();
+        let (defaults, _) = parse_config(EQUIVALENT_TOML, Path::new("config.toml")).unwrap();
+
+        let pricing = config.costs.model_pricing("gpt-4o-mini").unwrap();
+        assert_eq!(pricing, ModelPricing { input_per_1m: 0.30, output_per_1m: 1.20 });
+        assert_eq!(config.costs.max_monthly_cost_usd, defaults.costs.max_monthly_cost_usd);
+        assert_eq!(defaults.costs, CostRateConfig::default());
+    }
 }
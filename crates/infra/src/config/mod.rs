@@ -3,7 +3,13 @@
 //! This module provides utilities for loading application configuration
 //! from environment variables and files.
 
+pub mod app_category_rules;
+pub mod format;
 pub mod loader;
 
 // Re-export commonly used items
-pub use loader::{load, load_from_env, load_from_file, probe_config_paths};
+pub use app_category_rules::{import_app_category_rules, parse_app_category_rules};
+pub use format::ConfigFormat;
+pub use loader::{
+    load, load_from_env, load_from_file, load_from_file_with_format, probe_config_paths, save,
+};
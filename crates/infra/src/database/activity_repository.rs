@@ -12,28 +12,42 @@ use async_trait::async_trait;
 use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
 use pulsearc_common::error::{CommonError, CommonResult};
 use pulsearc_common::storage::error::{StorageError, StorageResult};
-use pulsearc_common::storage::sqlcipher::connection::SqlCipherStatement;
 use pulsearc_common::storage::sqlcipher::SqlCipherConnection;
 use pulsearc_core::tracking::ports::SnapshotRepository as SnapshotRepositoryPort;
+use pulsearc_core::tracking::{
+    coalesce_snapshots, SnapshotDedupConfig, SnapshotRun, SnapshotStorageUsage,
+};
 use pulsearc_core::ActivityRepository as ActivityRepositoryPort;
-use pulsearc_domain::types::database::ActivitySnapshot;
+use pulsearc_domain::types::database::{ActivitySnapshot, Page, PageRequest, SnapshotFilter};
 use pulsearc_domain::{PulseArcError, Result as DomainResult};
 use rusqlite::{Row, ToSql};
 use tokio::task;
 
 use super::manager::DbManager;
+use super::statement_cache::{execute_cached, query_map_cached};
 use crate::errors::InfraError;
+use crate::observability::correlation;
 
 /// Async activity repository + synchronous snapshot repository backed by
 /// SQLCipher.
 pub struct SqlCipherActivityRepository {
     db: Arc<DbManager>,
+    dedup: Option<SnapshotDedupConfig>,
 }
 
 impl SqlCipherActivityRepository {
     /// Construct a repository backed by the shared database manager.
     pub fn new(db: Arc<DbManager>) -> Self {
-        Self { db }
+        Self { db, dedup: None }
+    }
+
+    /// Collapse identical consecutive snapshots in batch inserts.
+    ///
+    /// Disabled by default; single-snapshot writes are deduplicated by the
+    /// tracking service instead.
+    pub fn with_deduplication(mut self, config: SnapshotDedupConfig) -> Self {
+        self.dedup = Some(config);
+        self
     }
 
     /// Fetch snapshots in the provided range with pagination support.
@@ -63,11 +77,11 @@ impl SqlCipherActivityRepository {
 impl ActivityRepositoryPort for SqlCipherActivityRepository {
     async fn save_snapshot(&self, snapshot: ActivitySnapshot) -> DomainResult<()> {
         let db = Arc::clone(&self.db);
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             insert_snapshot(&conn, &snapshot).map_err(map_storage_error)?;
             Ok(())
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -80,21 +94,73 @@ impl ActivityRepositoryPort for SqlCipherActivityRepository {
         validate_range(start, end)?;
 
         let db = Arc::clone(&self.db);
-        task::spawn_blocking(move || -> DomainResult<Vec<ActivitySnapshot>> {
-            let conn = db.get_connection()?;
-            query_snapshots(&conn, start.timestamp(), end.timestamp(), None, None)
-                .map_err(map_storage_error)
-        })
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<Vec<ActivitySnapshot>> {
+                let conn = db.get_connection()?;
+                query_snapshots(&conn, start.timestamp(), end.timestamp(), None, None)
+                    .map_err(map_storage_error)
+            },
+        ))
         .await
         .map_err(map_join_error)?
     }
 
     async fn delete_old_snapshots(&self, before: DateTime<Utc>) -> DomainResult<usize> {
         let db = Arc::clone(&self.db);
-        task::spawn_blocking(move || -> DomainResult<usize> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<usize> {
             let conn = db.get_connection()?;
             delete_snapshots_before(&conn, before.timestamp()).map_err(map_storage_error)
-        })
+        }))
+        .await
+        .map_err(map_join_error)?
+    }
+
+    async fn snapshot_storage_usage(&self) -> DomainResult<SnapshotStorageUsage> {
+        let db = Arc::clone(&self.db);
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<SnapshotStorageUsage> {
+                let conn = db.get_connection()?;
+                query_storage_usage(&conn).map_err(map_storage_error)
+            },
+        ))
+        .await
+        .map_err(map_join_error)?
+    }
+
+    async fn evict_synced_snapshots(&self, limit: usize) -> DomainResult<usize> {
+        if limit == 0 {
+            return Ok(0);
+        }
+
+        let db = Arc::clone(&self.db);
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<usize> {
+            let conn = db.get_connection()?;
+            delete_oldest_synced(&conn, limit).map_err(map_storage_error)
+        }))
+        .await
+        .map_err(map_join_error)?
+    }
+
+    async fn extend_snapshot(&self, snapshot_id: &str, run: SnapshotRun) -> DomainResult<bool> {
+        let db = Arc::clone(&self.db);
+        let snapshot_id = snapshot_id.to_string();
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<bool> {
+            let conn = db.get_connection()?;
+            upsert_snapshot_run(&conn, &snapshot_id, &run).map_err(map_storage_error)
+        }))
+        .await
+        .map_err(map_join_error)?
+    }
+
+    async fn snapshot_run(&self, snapshot_id: &str) -> DomainResult<Option<SnapshotRun>> {
+        let db = Arc::clone(&self.db);
+        let snapshot_id = snapshot_id.to_string();
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<Option<SnapshotRun>> {
+                let conn = db.get_connection()?;
+                query_snapshot_run(&conn, &snapshot_id).map_err(map_storage_error)
+            },
+        ))
         .await
         .map_err(map_join_error)?
     }
@@ -171,6 +237,35 @@ impl SnapshotRepositoryPort for SqlCipherActivityRepository {
         Ok(())
     }
 
+    fn insert_batch(&self, snapshots: &[ActivitySnapshot]) -> CommonResult<usize> {
+        if snapshots.is_empty() {
+            return Ok(0);
+        }
+
+        let conn = self.db.get_connection().map_err(|err| {
+            map_to_common_error("activity_snapshots.insert_batch_connection", err)
+        })?;
+
+        conn.execute("BEGIN TRANSACTION", []).map_err(|err| {
+            map_storage_to_common("activity_snapshots.insert_batch_begin", StorageError::from(err))
+        })?;
+
+        let inserted = match insert_snapshot_batch(&conn, self.dedup, snapshots) {
+            Ok(inserted) => inserted,
+            Err(err) => {
+                let _ = conn.execute("ROLLBACK", []);
+                return Err(map_storage_to_common("activity_snapshots.insert_batch", err));
+            }
+        };
+
+        conn.execute("COMMIT", []).map_err(|err| {
+            let _ = conn.execute("ROLLBACK", []);
+            map_storage_to_common("activity_snapshots.insert_batch_commit", StorageError::from(err))
+        })?;
+
+        Ok(inserted)
+    }
+
     fn count_active_snapshots(
         &self,
         start: DateTime<Utc>,
@@ -192,17 +287,41 @@ impl SnapshotRepositoryPort for SqlCipherActivityRepository {
 
         Ok(count)
     }
+
+    fn browse_snapshots(
+        &self,
+        filter: &SnapshotFilter,
+        page: PageRequest,
+    ) -> CommonResult<Page<ActivitySnapshot>> {
+        let conn = self
+            .db
+            .get_connection()
+            .map_err(|err| map_to_common_error("activity_snapshots.browse_connection", err))?;
+
+        query_snapshot_page(&conn, filter, page)
+            .map_err(|err| map_storage_to_common("activity_snapshots.browse_query", err))
+    }
+
+    fn find_by_content_hash(&self, content_hash: &str) -> CommonResult<Vec<ActivitySnapshot>> {
+        let conn = self
+            .db
+            .get_connection()
+            .map_err(|err| map_to_common_error("activity_snapshots.hash_connection", err))?;
+
+        query_snapshots_by_hash(&conn, content_hash)
+            .map_err(|err| map_storage_to_common("activity_snapshots.hash_query", err))
+    }
 }
 
 const INSERT_SNAPSHOT_SQL: &str = "INSERT INTO activity_snapshots (
         id, timestamp, activity_context_json, detected_activity,
         work_type, activity_category, primary_app, processed,
-        batch_id, created_at, processed_at, is_idle, idle_duration_secs
-    ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)";
+        batch_id, created_at, processed_at, is_idle, idle_duration_secs, content_hash
+    ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)";
 
 const SNAPSHOT_RANGE_BASE: &str = "SELECT id, timestamp, activity_context_json, detected_activity,
         work_type, activity_category, primary_app, processed, batch_id,
-        created_at, processed_at, is_idle, idle_duration_secs
+        created_at, processed_at, is_idle, idle_duration_secs, content_hash
     FROM activity_snapshots
     WHERE timestamp >= ?1 AND timestamp < ?2
     ORDER BY timestamp";
@@ -210,7 +329,7 @@ const SNAPSHOT_RANGE_BASE: &str = "SELECT id, timestamp, activity_context_json,
 const SNAPSHOT_RANGE_WITH_LIMIT: &str =
     "SELECT id, timestamp, activity_context_json, detected_activity,
         work_type, activity_category, primary_app, processed, batch_id,
-        created_at, processed_at, is_idle, idle_duration_secs
+        created_at, processed_at, is_idle, idle_duration_secs, content_hash
     FROM activity_snapshots
     WHERE timestamp >= ?1 AND timestamp < ?2
     ORDER BY timestamp
@@ -219,22 +338,63 @@ const SNAPSHOT_RANGE_WITH_LIMIT: &str =
 const SNAPSHOT_RANGE_WITH_LIMIT_OFFSET: &str =
     "SELECT id, timestamp, activity_context_json, detected_activity,
         work_type, activity_category, primary_app, processed, batch_id,
-        created_at, processed_at, is_idle, idle_duration_secs
+        created_at, processed_at, is_idle, idle_duration_secs, content_hash
     FROM activity_snapshots
     WHERE timestamp >= ?1 AND timestamp < ?2
     ORDER BY timestamp
     LIMIT ?3 OFFSET ?4";
 
+const SNAPSHOT_BROWSE_COLUMNS: &str =
+    "SELECT id, timestamp, activity_context_json, detected_activity,
+        work_type, activity_category, primary_app, processed, batch_id,
+        created_at, processed_at, is_idle, idle_duration_secs, content_hash
+    FROM activity_snapshots";
+
 const DELETE_OLD_SNAPSHOTS_SQL: &str = "DELETE FROM activity_snapshots WHERE timestamp < ?1";
 
+// A snapshot counts as synced once it was segmented (`processed = 1`) and the
+// segment holding it was processed too. Segmented snapshots whose segment is
+// still pending are the only local copy of that activity and are kept.
+const EVICT_SYNCED_SNAPSHOTS_SQL: &str = "WITH synced(id) AS (
+        SELECT ids.value
+        FROM activity_segments seg, json_each(seg.snapshot_ids) ids
+        WHERE seg.processed = 1
+    )
+    DELETE FROM activity_snapshots
+    WHERE id IN (
+        SELECT snap.id FROM activity_snapshots snap
+        WHERE snap.processed = 1
+          AND snap.id IN (SELECT id FROM synced)
+        ORDER BY snap.timestamp, snap.id
+        LIMIT ?1
+    )";
+
+// Only unprocessed snapshots are extended; processed ones were already synced
+// with their previous duration.
+const UPSERT_SNAPSHOT_RUN_SQL: &str = "INSERT INTO activity_snapshot_runs
+        (snapshot_id, poll_count, duration_secs, last_seen_at)
+    SELECT id, ?2, ?3, ?4 FROM activity_snapshots WHERE id = ?1 AND processed = 0
+    ON CONFLICT(snapshot_id) DO UPDATE SET
+        poll_count = excluded.poll_count,
+        duration_secs = excluded.duration_secs,
+        last_seen_at = excluded.last_seen_at";
+
+const SNAPSHOT_RUN_SQL: &str = "SELECT poll_count, duration_secs, last_seen_at
+    FROM activity_snapshot_runs WHERE snapshot_id = ?1";
+
+const STORAGE_USAGE_SQL: &str = "SELECT
+        (SELECT COUNT(*) FROM activity_snapshots),
+        (page_count - freelist_count) * page_size
+    FROM pragma_page_count(), pragma_freelist_count(), pragma_page_size()";
+
 const SNAPSHOT_COUNT_BY_DATE_QUERY: &str =
     "SELECT COUNT(*) FROM activity_snapshots WHERE timestamp >= ?1 AND timestamp < ?2";
 
 const INSERT_OR_REPLACE_SNAPSHOT_SQL: &str = "INSERT OR REPLACE INTO activity_snapshots (
         id, timestamp, activity_context_json, detected_activity,
         work_type, activity_category, primary_app, processed,
-        batch_id, created_at, processed_at, is_idle, idle_duration_secs
-    ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)";
+        batch_id, created_at, processed_at, is_idle, idle_duration_secs, content_hash
+    ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)";
 
 fn insert_or_replace_snapshot(
     conn: &SqlCipherConnection,
@@ -243,7 +403,7 @@ fn insert_or_replace_snapshot(
     let processed = bool_to_int(snapshot.processed);
     let is_idle = bool_to_int(snapshot.is_idle);
 
-    let params: [&dyn ToSql; 13] = [
+    let params: [&dyn ToSql; 14] = [
         &snapshot.id,
         &snapshot.timestamp,
         &snapshot.activity_context_json,
@@ -257,9 +417,10 @@ fn insert_or_replace_snapshot(
         &snapshot.processed_at,
         &is_idle,
         &snapshot.idle_duration_secs,
+        &snapshot.content_hash,
     ];
 
-    conn.execute(INSERT_OR_REPLACE_SNAPSHOT_SQL, params.as_slice())?;
+    execute_cached(conn, INSERT_OR_REPLACE_SNAPSHOT_SQL, params.as_slice())?;
     Ok(())
 }
 
@@ -267,7 +428,7 @@ fn insert_snapshot(conn: &SqlCipherConnection, snapshot: &ActivitySnapshot) -> S
     let processed = bool_to_int(snapshot.processed);
     let is_idle = bool_to_int(snapshot.is_idle);
 
-    let params: [&dyn ToSql; 13] = [
+    let params: [&dyn ToSql; 14] = [
         &snapshot.id,
         &snapshot.timestamp,
         &snapshot.activity_context_json,
@@ -281,12 +442,37 @@ fn insert_snapshot(conn: &SqlCipherConnection, snapshot: &ActivitySnapshot) -> S
         &snapshot.processed_at,
         &is_idle,
         &snapshot.idle_duration_secs,
+        &snapshot.content_hash,
     ];
 
-    conn.execute(INSERT_SNAPSHOT_SQL, params.as_slice())?;
+    execute_cached(conn, INSERT_SNAPSHOT_SQL, params.as_slice())?;
     Ok(())
 }
 
+/// Insert `snapshots` on the current transaction, recording the run of every
+/// snapshot that absorbed duplicates.
+fn insert_snapshot_batch(
+    conn: &SqlCipherConnection,
+    dedup: Option<SnapshotDedupConfig>,
+    snapshots: &[ActivitySnapshot],
+) -> StorageResult<usize> {
+    let Some(config) = dedup else {
+        for snapshot in snapshots {
+            insert_snapshot(conn, snapshot)?;
+        }
+        return Ok(snapshots.len());
+    };
+
+    let runs = coalesce_snapshots(config, snapshots);
+    for (snapshot, run) in &runs {
+        insert_snapshot(conn, snapshot)?;
+        if run.poll_count > 1 {
+            upsert_snapshot_run(conn, &snapshot.id, run)?;
+        }
+    }
+    Ok(runs.len())
+}
+
 fn query_snapshots(
     conn: &SqlCipherConnection,
     start_ts: i64,
@@ -294,7 +480,6 @@ fn query_snapshots(
     limit: Option<usize>,
     offset: Option<usize>,
 ) -> StorageResult<Vec<ActivitySnapshot>> {
-    let mut stmt = build_snapshot_statement(conn, limit, offset)?;
     let start_param = start_ts;
     let end_param = end_ts;
 
@@ -303,30 +488,116 @@ fn query_snapshots(
             let limit_param = usize_to_i64(limit);
             let offset_param = usize_to_i64(offset);
             let params: [&dyn ToSql; 4] = [&start_param, &end_param, &limit_param, &offset_param];
-            stmt.query_map(params.as_slice(), map_snapshot_row)
+            query_map_cached(
+                conn,
+                SNAPSHOT_RANGE_WITH_LIMIT_OFFSET,
+                params.as_slice(),
+                map_snapshot_row,
+            )
         }
         (Some(limit), None) => {
             let limit_param = usize_to_i64(limit);
             let params: [&dyn ToSql; 3] = [&start_param, &end_param, &limit_param];
-            stmt.query_map(params.as_slice(), map_snapshot_row)
+            query_map_cached(conn, SNAPSHOT_RANGE_WITH_LIMIT, params.as_slice(), map_snapshot_row)
         }
         _ => {
             let params: [&dyn ToSql; 2] = [&start_param, &end_param];
-            stmt.query_map(params.as_slice(), map_snapshot_row)
+            query_map_cached(conn, SNAPSHOT_RANGE_BASE, params.as_slice(), map_snapshot_row)
         }
     }
 }
 
-fn build_snapshot_statement<'conn>(
-    conn: &'conn SqlCipherConnection,
-    limit: Option<usize>,
-    offset: Option<usize>,
-) -> StorageResult<SqlCipherStatement<'conn>> {
-    match (limit, offset) {
-        (Some(_), Some(_)) => conn.prepare(SNAPSHOT_RANGE_WITH_LIMIT_OFFSET),
-        (Some(_), None) => conn.prepare(SNAPSHOT_RANGE_WITH_LIMIT),
-        _ => conn.prepare(SNAPSHOT_RANGE_BASE),
+/// Run a filtered, paginated snapshot query plus its total count.
+///
+/// Filters map onto `idx_snapshots_timestamp_id` (time range, ordering) and
+/// `idx_snapshots_app_timestamp` (bundle id); the app name substring is
+/// evaluated on the rows those indexes select.
+fn query_snapshot_page(
+    conn: &SqlCipherConnection,
+    filter: &SnapshotFilter,
+    page: PageRequest,
+) -> StorageResult<Page<ActivitySnapshot>> {
+    let page_size = page.page_size.clamp(1, PageRequest::MAX_PAGE_SIZE);
+    let page = PageRequest { page: page.page, page_size };
+
+    let mut clauses: Vec<&str> = Vec::new();
+    let mut values: Vec<Box<dyn ToSql>> = Vec::new();
+
+    if let Some(start_ts) = filter.start_ts {
+        clauses.push("timestamp >= ?");
+        values.push(Box::new(start_ts));
+    }
+    if let Some(end_ts) = filter.end_ts {
+        clauses.push("timestamp < ?");
+        values.push(Box::new(end_ts));
     }
+    if let Some(bundle_id) = non_empty(filter.bundle_id.as_deref()) {
+        // primary_app holds the bundle id whenever the capture reported one
+        clauses.push("primary_app = ?");
+        values.push(Box::new(bundle_id.to_string()));
+    }
+    if let Some(app_name) = non_empty(filter.app_name.as_deref()) {
+        clauses.push(
+            "(primary_app LIKE ? ESCAPE '\\'
+              OR json_extract(activity_context_json, '$.active_app.app_name') LIKE ? ESCAPE '\\')",
+        );
+        let pattern = format!("%{}%", escape_like(app_name));
+        values.push(Box::new(pattern.clone()));
+        values.push(Box::new(pattern));
+    }
+
+    let where_sql = if clauses.is_empty() {
+        String::new()
+    } else {
+        format!(" WHERE {}", clauses.join(" AND "))
+    };
+    let params: Vec<&dyn ToSql> = values.iter().map(|value| value.as_ref()).collect();
+
+    let total: i64 = conn.query_row(
+        &format!("SELECT COUNT(*) FROM activity_snapshots{where_sql}"),
+        params.as_slice(),
+        |row| row.get(0),
+    )?;
+
+    let limit_param = i64::from(page_size);
+    let offset_param = i64::try_from(page.offset()).unwrap_or(i64::MAX);
+    let mut page_params = params;
+    page_params.push(&limit_param);
+    page_params.push(&offset_param);
+
+    let mut stmt = conn.prepare(&format!(
+        "{SNAPSHOT_BROWSE_COLUMNS}{where_sql} ORDER BY timestamp, id LIMIT ? OFFSET ?"
+    ))?;
+    let items = stmt.query_map(page_params.as_slice(), map_snapshot_row)?;
+
+    Ok(Page { items, page: page.page, page_size, total: u64::try_from(total).unwrap_or(0) })
+}
+
+fn query_snapshots_by_hash(
+    conn: &SqlCipherConnection,
+    content_hash: &str,
+) -> StorageResult<Vec<ActivitySnapshot>> {
+    let params: [&dyn ToSql; 1] = [&content_hash];
+    let mut stmt = conn.prepare(&format!(
+        "{SNAPSHOT_BROWSE_COLUMNS} WHERE content_hash = ?1 ORDER BY timestamp, id"
+    ))?;
+    stmt.query_map(params.as_slice(), map_snapshot_row)
+}
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty())
+}
+
+/// Escape `%`, `_` and the escape character itself for a LIKE pattern.
+fn escape_like(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
 }
 
 fn delete_snapshots_before(conn: &SqlCipherConnection, before_ts: i64) -> StorageResult<usize> {
@@ -334,6 +605,55 @@ fn delete_snapshots_before(conn: &SqlCipherConnection, before_ts: i64) -> Storag
     conn.execute(DELETE_OLD_SNAPSHOTS_SQL, params.as_slice()).map_err(StorageError::from)
 }
 
+/// Delete up to `limit` of the oldest synced snapshots (segmented into a
+/// processed segment); every other row is left alone.
+fn delete_oldest_synced(conn: &SqlCipherConnection, limit: usize) -> StorageResult<usize> {
+    let limit_param = i64::try_from(limit).unwrap_or(i64::MAX);
+    let params: [&dyn ToSql; 1] = [&limit_param];
+    conn.execute(EVICT_SYNCED_SNAPSHOTS_SQL, params.as_slice()).map_err(StorageError::from)
+}
+
+/// Record `run` for `snapshot_id`; returns `false` if the snapshot is missing
+/// or already processed.
+fn upsert_snapshot_run(
+    conn: &SqlCipherConnection,
+    snapshot_id: &str,
+    run: &SnapshotRun,
+) -> StorageResult<bool> {
+    let poll_count = i64::from(run.poll_count);
+    let params: [&dyn ToSql; 4] =
+        [&snapshot_id, &poll_count, &run.duration_secs, &run.last_seen_at];
+    let changed = execute_cached(conn, UPSERT_SNAPSHOT_RUN_SQL, params.as_slice())?;
+    Ok(changed > 0)
+}
+
+fn query_snapshot_run(
+    conn: &SqlCipherConnection,
+    snapshot_id: &str,
+) -> StorageResult<Option<SnapshotRun>> {
+    let params: [&dyn ToSql; 1] = [&snapshot_id];
+    let runs = query_map_cached(conn, SNAPSHOT_RUN_SQL, params.as_slice(), |row| {
+        Ok(SnapshotRun {
+            poll_count: u32::try_from(row.get::<_, i64>(0)?).unwrap_or(u32::MAX),
+            duration_secs: row.get(1)?,
+            last_seen_at: row.get(2)?,
+        })
+    })?;
+    Ok(runs.into_iter().next())
+}
+
+/// Snapshot row count plus database bytes in use (free pages excluded, so
+/// evictions show up before the next VACUUM).
+fn query_storage_usage(conn: &SqlCipherConnection) -> StorageResult<SnapshotStorageUsage> {
+    let (count, used_bytes): (i64, i64) =
+        conn.query_row(STORAGE_USAGE_SQL, &[], |row| Ok((row.get(0)?, row.get(1)?)))?;
+
+    Ok(SnapshotStorageUsage {
+        snapshot_count: u64::try_from(count).unwrap_or(0),
+        used_bytes: u64::try_from(used_bytes).unwrap_or(0),
+    })
+}
+
 fn map_snapshot_row(row: &Row<'_>) -> rusqlite::Result<ActivitySnapshot> {
     Ok(ActivitySnapshot {
         id: row.get(0)?,
@@ -349,6 +669,7 @@ fn map_snapshot_row(row: &Row<'_>) -> rusqlite::Result<ActivitySnapshot> {
         processed_at: row.get(10)?,
         is_idle: int_to_bool(row.get(11)?),
         idle_duration_secs: row.get(12)?,
+        content_hash: row.get(13)?,
     })
 }
 
@@ -431,6 +752,7 @@ fn map_join_error(err: task::JoinError) -> PulseArcError {
 
 #[cfg(test)]
 mod tests {
+    use pulsearc_core::tracking::{SnapshotRetentionPolicy, Watermarks};
     use tempfile::TempDir;
 
     use super::*;
@@ -482,6 +804,121 @@ mod tests {
         assert!(matches!(err, PulseArcError::InvalidInput(_)));
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn evict_synced_snapshots_removes_oldest_synced_only() {
+        let (repo, manager, _temp_dir) = setup_repository().await;
+
+        {
+            let conn = manager.get_connection().expect("connection");
+            for (id, timestamp, processed) in [
+                ("unsynced-old", 10, false),
+                ("synced-a", 20, true),
+                ("synced-b", 30, true),
+                ("segmented-pending", 35, true),
+                ("unsynced-new", 40, false),
+                ("synced-c", 50, true),
+            ] {
+                let snapshot = ActivitySnapshot { processed, ..sample_snapshot(id, timestamp) };
+                insert_snapshot(&conn, &snapshot).expect("insert snapshot");
+            }
+            insert_segment(&conn, "seg-synced", true, &["synced-a", "synced-b", "synced-c"]);
+            insert_segment(&conn, "seg-pending", false, &["segmented-pending"]);
+        }
+
+        let before = repo.snapshot_storage_usage().await.expect("usage");
+        assert_eq!(before.snapshot_count, 6);
+        assert!(before.used_bytes > 0);
+
+        let evicted = repo.evict_synced_snapshots(2).await.expect("evict");
+        assert_eq!(evicted, 2);
+
+        let start = DateTime::<Utc>::from_timestamp(0, 0).expect("start valid");
+        let end = DateTime::<Utc>::from_timestamp(100, 0).expect("end valid");
+        let remaining: Vec<String> = repo
+            .get_snapshots(start, end)
+            .await
+            .expect("snapshots fetched")
+            .into_iter()
+            .map(|s| s.id)
+            .collect();
+        assert_eq!(
+            remaining,
+            vec!["unsynced-old", "segmented-pending", "unsynced-new", "synced-c"]
+        );
+
+        // Only unsynced rows left after the last synced one goes; a segmented
+        // snapshot whose segment has not been processed is still unsynced
+        assert_eq!(repo.evict_synced_snapshots(10).await.expect("evict"), 1);
+        assert_eq!(repo.evict_synced_snapshots(10).await.expect("evict"), 0);
+        assert_eq!(repo.snapshot_storage_usage().await.expect("usage").snapshot_count, 3);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn extend_snapshot_records_run_for_unprocessed_snapshots_only() {
+        let (repo, manager, _temp_dir) = setup_repository().await;
+
+        {
+            let conn = manager.get_connection().expect("connection");
+            insert_snapshot(&conn, &sample_snapshot("open", 100)).expect("insert open");
+            let processed = ActivitySnapshot { processed: true, ..sample_snapshot("synced", 50) };
+            insert_snapshot(&conn, &processed).expect("insert synced");
+        }
+
+        assert_eq!(repo.snapshot_run("open").await.expect("run"), None);
+
+        let run = SnapshotRun { poll_count: 2, duration_secs: 60, last_seen_at: 130 };
+        assert!(repo.extend_snapshot("open", run).await.expect("extend"));
+        let run = SnapshotRun { poll_count: 3, duration_secs: 90, last_seen_at: 160 };
+        assert!(repo.extend_snapshot("open", run).await.expect("extend"));
+        assert_eq!(repo.snapshot_run("open").await.expect("run"), Some(run));
+
+        assert!(!repo.extend_snapshot("synced", run).await.expect("extend"));
+        assert!(!repo.extend_snapshot("missing", run).await.expect("extend"));
+        assert_eq!(repo.snapshot_run("synced").await.expect("run"), None);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn retention_policy_evicts_down_to_low_water() {
+        let (repo, manager, _temp_dir) = setup_repository().await;
+
+        {
+            let conn = manager.get_connection().expect("connection");
+            let mut segmented = Vec::new();
+            for i in 0..12 {
+                let snapshot = ActivitySnapshot {
+                    processed: i % 4 != 0,
+                    ..sample_snapshot(&format!("snap-{i:02}"), i)
+                };
+                insert_snapshot(&conn, &snapshot).expect("insert snapshot");
+                if snapshot.processed {
+                    segmented.push(snapshot.id);
+                }
+            }
+            let segmented: Vec<&str> = segmented.iter().map(String::as_str).collect();
+            insert_segment(&conn, "seg-synced", true, &segmented);
+        }
+
+        let policy = SnapshotRetentionPolicy {
+            count: Some(Watermarks { high: 10, low: 6 }),
+            bytes: None,
+            max_evictions_per_capture: 100,
+        };
+        let evicted = policy.enforce(&repo).await.expect("enforce");
+        assert_eq!(evicted, 6);
+
+        let usage = repo.snapshot_storage_usage().await.expect("usage");
+        assert_eq!(usage.snapshot_count, 6);
+
+        // Every unsynced snapshot survives
+        let start = DateTime::<Utc>::from_timestamp(0, 0).expect("start valid");
+        let end = DateTime::<Utc>::from_timestamp(100, 0).expect("end valid");
+        let remaining = repo.get_snapshots(start, end).await.expect("snapshots fetched");
+        assert_eq!(remaining.iter().filter(|s| !s.processed).count(), 3);
+
+        // Under the high-water mark nothing more is evicted
+        assert_eq!(policy.enforce(&repo).await.expect("enforce"), 0);
+    }
+
     #[test]
     fn find_snapshots_by_time_range_uses_half_open_bounds() {
         let (_repo_async, manager, _temp_dir) = setup_repository_sync();
@@ -530,6 +967,199 @@ mod tests {
         assert!(matches!(err, CommonError::Storage { .. }));
     }
 
+    #[test]
+    fn browse_snapshots_filters_by_app() {
+        let (_repo_async, manager, _temp_dir) = setup_repository_sync();
+        let repo = SqlCipherActivityRepository::new(manager.clone());
+
+        let conn = manager.get_connection().expect("connection");
+        insert_snapshot(&conn, &app_snapshot("snap-1", 10, "com.apple.Safari"))
+            .expect("insert snap-1");
+        insert_snapshot(&conn, &app_snapshot("snap-2", 20, "com.microsoft.VSCode"))
+            .expect("insert snap-2");
+        insert_snapshot(&conn, &app_snapshot("snap-3", 30, "com.apple.Safari"))
+            .expect("insert snap-3");
+        insert_snapshot(&conn, &app_snapshot("snap-4", 40, "com.apple.Safari"))
+            .expect("insert snap-4");
+
+        let by_name = SnapshotFilter { app_name: Some("safari".to_string()), ..Default::default() };
+        let page = repo
+            .browse_snapshots(&by_name, PageRequest { page: 0, page_size: 2 })
+            .expect("browse by name");
+        assert_eq!(page.total, 3);
+        let ids: Vec<&str> = page.items.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["snap-1", "snap-3"]);
+
+        let by_bundle = SnapshotFilter {
+            bundle_id: Some("com.microsoft.VSCode".to_string()),
+            ..Default::default()
+        };
+        let page = repo
+            .browse_snapshots(&by_bundle, PageRequest { page: 0, page_size: 10 })
+            .expect("browse by bundle id");
+        assert_eq!(page.total, 1);
+        assert_eq!(page.items[0].id, "snap-2");
+
+        let in_range = SnapshotFilter {
+            app_name: Some("Safari".to_string()),
+            start_ts: Some(20),
+            end_ts: Some(40),
+            ..Default::default()
+        };
+        let page = repo
+            .browse_snapshots(&in_range, PageRequest { page: 0, page_size: 10 })
+            .expect("browse by range");
+        assert_eq!(page.total, 1);
+        assert_eq!(page.items[0].id, "snap-3");
+    }
+
+    #[test]
+    fn browse_snapshots_orders_stably_across_pages() {
+        let (_repo_async, manager, _temp_dir) = setup_repository_sync();
+        let repo = SqlCipherActivityRepository::new(manager.clone());
+
+        let conn = manager.get_connection().expect("connection");
+        // Shared timestamps force the id tie-breaker to decide page boundaries
+        for (id, timestamp) in [("e", 20), ("b", 10), ("d", 10), ("a", 20), ("c", 10)] {
+            insert_snapshot(&conn, &sample_snapshot(id, timestamp)).expect("insert snapshot");
+        }
+
+        let filter = SnapshotFilter::default();
+        let mut seen = Vec::new();
+        for page_number in 0..3 {
+            let page = repo
+                .browse_snapshots(&filter, PageRequest { page: page_number, page_size: 2 })
+                .expect("browse page");
+            assert_eq!(page.total, 5);
+            assert_eq!(page.page, page_number);
+            seen.extend(page.items.into_iter().map(|s| s.id));
+        }
+
+        assert_eq!(seen, vec!["b", "c", "d", "a", "e"]);
+    }
+
+    #[test]
+    fn browse_snapshots_uses_indexes() {
+        let (_repo_async, manager, _temp_dir) = setup_repository_sync();
+        let conn = manager.get_connection().expect("connection");
+
+        let mut stmt = conn
+            .prepare(
+                "EXPLAIN QUERY PLAN SELECT id FROM activity_snapshots
+                 WHERE timestamp >= ?1 AND timestamp < ?2
+                 ORDER BY timestamp, id LIMIT 10",
+            )
+            .expect("prepare plan");
+        let params: [&dyn ToSql; 2] = [&0_i64, &100_i64];
+        let plan: Vec<String> =
+            stmt.query_map(params.as_slice(), |row| row.get(3)).expect("query plan");
+
+        assert!(plan.iter().any(|step| step.contains("USING")), "plan: {plan:?}");
+        assert!(!plan.iter().any(|step| step == "SCAN activity_snapshots"), "plan: {plan:?}");
+    }
+
+    #[test]
+    fn find_by_content_hash_returns_matching_snapshots_in_order() {
+        let (_repo_async, manager, _temp_dir) = setup_repository_sync();
+        let repo = SqlCipherActivityRepository::new(manager.clone());
+
+        let conn = manager.get_connection().expect("connection");
+        insert_snapshot(&conn, &hashed_snapshot("snap-3", 30, Some("aaaa")))
+            .expect("insert snap-3");
+        insert_snapshot(&conn, &hashed_snapshot("snap-1", 10, Some("aaaa")))
+            .expect("insert snap-1");
+        insert_snapshot(&conn, &hashed_snapshot("snap-2", 20, Some("bbbb")))
+            .expect("insert snap-2");
+        insert_snapshot(&conn, &hashed_snapshot("snap-4", 40, None)).expect("insert snap-4");
+
+        let matches = repo.find_by_content_hash("aaaa").expect("hash lookup");
+        let ids: Vec<&str> = matches.iter().map(|snapshot| snapshot.id.as_str()).collect();
+        assert_eq!(ids, vec!["snap-1", "snap-3"]);
+        assert_eq!(matches[0].content_hash.as_deref(), Some("aaaa"));
+
+        assert!(repo.find_by_content_hash("cccc").expect("hash lookup").is_empty());
+    }
+
+    fn total_snapshots(manager: &DbManager) -> i64 {
+        let conn = manager.get_connection().expect("connection");
+        conn.query_row("SELECT COUNT(*) FROM activity_snapshots", &[], |row| row.get(0))
+            .expect("count snapshots")
+    }
+
+    #[test]
+    fn insert_batch_inserts_every_snapshot() {
+        let (repo, manager, _temp_dir) = setup_repository_sync();
+        let batch: Vec<_> =
+            (0..200).map(|i| sample_snapshot(&format!("snap-{i:03}"), 1_000 + i * 30)).collect();
+
+        assert_eq!(repo.insert_batch(&batch).expect("batch inserted"), 200);
+        assert_eq!(total_snapshots(&manager), 200);
+        assert_eq!(repo.insert_batch(&[]).expect("empty batch"), 0);
+    }
+
+    #[test]
+    fn insert_batch_collapses_duplicates_when_deduplicating() {
+        let (_repo, manager, _temp_dir) = setup_repository_sync();
+        let config = SnapshotDedupConfig { poll_interval_secs: 30, max_gap_secs: 60 };
+        let repo = SqlCipherActivityRepository::new(manager.clone()).with_deduplication(config);
+        let batch = vec![
+            hashed_snapshot("a", 100, Some("aaaa")),
+            hashed_snapshot("b", 130, Some("aaaa")),
+            hashed_snapshot("c", 160, Some("aaaa")),
+            hashed_snapshot("d", 190, Some("bbbb")),
+            hashed_snapshot("e", 220, Some("aaaa")),
+            sample_snapshot("f", 250),
+        ];
+
+        assert_eq!(repo.insert_batch(&batch).expect("batch inserted"), 4);
+        assert_eq!(total_snapshots(&manager), 4);
+
+        let conn = manager.get_connection().expect("connection");
+        let run = query_snapshot_run(&conn, "a").expect("run query");
+        assert_eq!(run, Some(SnapshotRun { poll_count: 3, duration_secs: 90, last_seen_at: 160 }));
+        assert_eq!(query_snapshot_run(&conn, "d").expect("run query"), None);
+    }
+
+    #[test]
+    fn insert_batch_rolls_back_on_failure() {
+        let (repo, manager, _temp_dir) = setup_repository_sync();
+        repo.store_snapshot(&sample_snapshot("existing", 50)).expect("seed snapshot");
+
+        let batch = vec![
+            sample_snapshot("new-1", 100),
+            sample_snapshot("new-2", 130),
+            sample_snapshot("existing", 160),
+            sample_snapshot("new-3", 190),
+        ];
+
+        assert!(repo.insert_batch(&batch).is_err(), "duplicate id fails the batch");
+        assert_eq!(total_snapshots(&manager), 1, "no row of the failed batch was kept");
+
+        // The connection is usable again once the transaction is rolled back
+        let retry = &batch[..2];
+        assert_eq!(repo.insert_batch(retry).expect("retry inserted"), 2);
+        assert_eq!(total_snapshots(&manager), 3);
+    }
+
+    /// Store a segment covering `snapshot_ids`
+    fn insert_segment(
+        conn: &SqlCipherConnection,
+        id: &str,
+        processed: bool,
+        snapshot_ids: &[&str],
+    ) {
+        let snapshot_ids = serde_json::to_string(snapshot_ids).expect("serialize ids");
+        let processed = bool_to_int(processed);
+        let params: [&dyn ToSql; 3] = [&id, &processed, &snapshot_ids];
+        conn.execute(
+            "INSERT INTO activity_segments (id, start_ts, end_ts, primary_app, normalized_label,
+                sample_count, created_at, processed, snapshot_ids)
+             VALUES (?1, 0, 60, 'app', 'label', 1, 0, ?2, ?3)",
+            params.as_slice(),
+        )
+        .expect("insert segment");
+    }
+
     async fn setup_repository() -> (SqlCipherActivityRepository, Arc<DbManager>, TempDir) {
         let temp_dir = TempDir::new().expect("tempdir created");
         let db_path = temp_dir.path().join("activity.db");
@@ -569,6 +1199,18 @@ mod tests {
             processed_at: None,
             is_idle: false,
             idle_duration_secs: None,
+            content_hash: None,
+        }
+    }
+
+    fn app_snapshot(id: &str, timestamp: i64, primary_app: &str) -> ActivitySnapshot {
+        ActivitySnapshot { primary_app: primary_app.to_string(), ..sample_snapshot(id, timestamp) }
+    }
+
+    fn hashed_snapshot(id: &str, timestamp: i64, content_hash: Option<&str>) -> ActivitySnapshot {
+        ActivitySnapshot {
+            content_hash: content_hash.map(str::to_string),
+            ..sample_snapshot(id, timestamp)
         }
     }
 }
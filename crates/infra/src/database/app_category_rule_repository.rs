@@ -0,0 +1,208 @@
+//! App category rule repository implementation using SQLCipher
+//!
+//! Persists the user's app category overrides. Categories and work types are
+//! stored as their JSON representation (e.g. `"client_work"`).
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use pulsearc_common::storage::error::{StorageError, StorageResult};
+use pulsearc_common::storage::sqlcipher::SqlCipherConnection;
+use pulsearc_core::classification::ports::AppCategoryRuleRepository as AppCategoryRuleRepositoryPort;
+use pulsearc_domain::types::classification::AppCategoryRule;
+use pulsearc_domain::{PulseArcError, Result as DomainResult};
+use rusqlite::{Row, ToSql};
+use tokio::task;
+
+use super::manager::DbManager;
+use crate::observability::correlation;
+
+const UPSERT_RULE_SQL: &str = "INSERT OR REPLACE INTO app_category_rules
+     (id, bundle_id, title_pattern, url_pattern, category, work_type, project_id, created_at)
+     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)";
+
+const SELECT_RULES_SQL: &str =
+    "SELECT id, bundle_id, title_pattern, url_pattern, category, work_type, project_id, created_at
+     FROM app_category_rules
+     ORDER BY created_at ASC, id ASC";
+
+const DELETE_RULE_SQL: &str = "DELETE FROM app_category_rules WHERE id = ?1";
+
+/// SQLCipher-backed implementation of `AppCategoryRuleRepository`
+pub struct SqlCipherAppCategoryRuleRepository {
+    db: Arc<DbManager>,
+}
+
+impl SqlCipherAppCategoryRuleRepository {
+    /// Create a new repository instance
+    pub fn new(db: Arc<DbManager>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl AppCategoryRuleRepositoryPort for SqlCipherAppCategoryRuleRepository {
+    async fn list_rules(&self) -> DomainResult<Vec<AppCategoryRule>> {
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<Vec<AppCategoryRule>> {
+                let conn = db.get_connection()?;
+                query_rules(&conn).map_err(map_storage_error)
+            },
+        ))
+        .await
+        .map_err(map_join_error)?
+    }
+
+    async fn save_rule(&self, rule: &AppCategoryRule) -> DomainResult<()> {
+        let db = Arc::clone(&self.db);
+        let rule = rule.clone();
+
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
+            let conn = db.get_connection()?;
+            upsert_rule(&conn, &rule).map_err(map_storage_error)
+        }))
+        .await
+        .map_err(map_join_error)?
+    }
+
+    async fn delete_rule(&self, id: &str) -> DomainResult<bool> {
+        let db = Arc::clone(&self.db);
+        let id = id.to_string();
+
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<bool> {
+            let conn = db.get_connection()?;
+            let params: [&dyn ToSql; 1] = [&id];
+            let deleted = conn
+                .execute(DELETE_RULE_SQL, params.as_slice())
+                .map_err(|err| map_storage_error(StorageError::from(err)))?;
+            Ok(deleted > 0)
+        }))
+        .await
+        .map_err(map_join_error)?
+    }
+}
+
+fn upsert_rule(conn: &SqlCipherConnection, rule: &AppCategoryRule) -> StorageResult<()> {
+    let category = rule.category.as_ref().map(serialize_json).transpose()?;
+    let work_type = rule.work_type.as_ref().map(serialize_json).transpose()?;
+    let params: [&dyn ToSql; 8] = [
+        &rule.id,
+        &rule.bundle_id,
+        &rule.title_pattern,
+        &rule.url_pattern,
+        &category,
+        &work_type,
+        &rule.project_id,
+        &rule.created_at,
+    ];
+    conn.execute(UPSERT_RULE_SQL, params.as_slice())?;
+    Ok(())
+}
+
+fn query_rules(conn: &SqlCipherConnection) -> StorageResult<Vec<AppCategoryRule>> {
+    let mut stmt = conn.prepare(SELECT_RULES_SQL)?;
+    stmt.query_map(&[], map_rule_row)
+}
+
+fn map_rule_row(row: &Row<'_>) -> rusqlite::Result<AppCategoryRule> {
+    Ok(AppCategoryRule {
+        id: row.get(0)?,
+        bundle_id: row.get(1)?,
+        title_pattern: row.get(2)?,
+        url_pattern: row.get(3)?,
+        category: row.get::<_, Option<String>>(4)?.map(|v| deserialize_json(v, 4)).transpose()?,
+        work_type: row.get::<_, Option<String>>(5)?.map(|v| deserialize_json(v, 5)).transpose()?,
+        project_id: row.get(6)?,
+        created_at: row.get(7)?,
+    })
+}
+
+fn serialize_json<T: serde::Serialize>(value: &T) -> StorageResult<String> {
+    serde_json::to_string(value).map_err(StorageError::SerdeJson)
+}
+
+fn deserialize_json<T: serde::de::DeserializeOwned>(
+    value: String,
+    column_index: usize,
+) -> rusqlite::Result<T> {
+    serde_json::from_str(&value).map_err(|err| {
+        rusqlite::Error::FromSqlConversionFailure(
+            column_index,
+            rusqlite::types::Type::Text,
+            Box::new(err),
+        )
+    })
+}
+
+fn map_storage_error(err: StorageError) -> PulseArcError {
+    match err {
+        StorageError::WrongKeyOrNotEncrypted => {
+            PulseArcError::Database("Database key error or not encrypted".into())
+        }
+        StorageError::Connection(msg) => PulseArcError::Database(msg),
+        StorageError::Query(msg) => PulseArcError::Database(msg),
+        StorageError::DatabaseError(msg) => PulseArcError::Database(msg),
+        StorageError::Rusqlite(err) => PulseArcError::Database(format!("SQLite error: {err}")),
+        _ => PulseArcError::Database(format!("Storage error: {err}")),
+    }
+}
+
+fn map_join_error(err: task::JoinError) -> PulseArcError {
+    PulseArcError::Internal(format!("Task join error: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use pulsearc_domain::types::{ActivityCategory, WorkType};
+    use tempfile::TempDir;
+
+    use super::*;
+
+    const TEST_KEY: &str = "test_key_64_chars_long_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+
+    fn setup_repo() -> (SqlCipherAppCategoryRuleRepository, TempDir) {
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let db_path = temp_dir.path().join("app_category_rules.db");
+        let manager =
+            DbManager::new(db_path.to_str().expect("utf8 path"), 4, Some(TEST_KEY)).expect("db");
+        manager.run_migrations().expect("run migrations");
+        (SqlCipherAppCategoryRuleRepository::new(Arc::new(manager)), temp_dir)
+    }
+
+    fn rule(id: &str, created_at: i64) -> AppCategoryRule {
+        AppCategoryRule {
+            id: id.into(),
+            bundle_id: "com.tinyspeck.slackmacgap".into(),
+            title_pattern: None,
+            url_pattern: None,
+            category: Some(ActivityCategory::ClientWork),
+            work_type: None,
+            project_id: Some("USC0063201".into()),
+            created_at,
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn saves_updates_and_deletes_rules() {
+        let (repo, _temp_dir) = setup_repo();
+
+        let narrow = AppCategoryRule {
+            title_pattern: Some("#astro-deal".into()),
+            work_type: Some(WorkType::Email),
+            ..rule("b", 200)
+        };
+        repo.save_rule(&narrow).await.expect("save");
+        repo.save_rule(&rule("a", 100)).await.expect("save");
+        assert_eq!(repo.list_rules().await.expect("list"), vec![rule("a", 100), narrow.clone()]);
+
+        let updated = AppCategoryRule { category: None, ..rule("a", 100) };
+        repo.save_rule(&updated).await.expect("update");
+        assert_eq!(repo.list_rules().await.expect("list"), vec![updated, narrow.clone()]);
+
+        assert!(repo.delete_rule("a").await.expect("delete"));
+        assert!(!repo.delete_rule("a").await.expect("delete missing"));
+        assert_eq!(repo.list_rules().await.expect("list"), vec![narrow]);
+    }
+}
@@ -16,6 +16,7 @@ use rusqlite::{params, Row};
 use tokio::task;
 
 use super::manager::DbManager;
+use crate::observability::correlation;
 
 /// SqlCipher-based Batch repository
 pub struct SqlCipherBatchRepository {
@@ -38,10 +39,10 @@ impl BatchRepositoryPort for SqlCipherBatchRepository {
         let db = Arc::clone(&self.db);
         let batch = batch.clone();
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             insert_batch(&conn, &batch).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -50,10 +51,10 @@ impl BatchRepositoryPort for SqlCipherBatchRepository {
         let db = Arc::clone(&self.db);
         let batch_id = batch_id.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<BatchQueue> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<BatchQueue> {
             let conn = db.get_connection()?;
             query_batch_by_id(&conn, &batch_id).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -63,10 +64,10 @@ impl BatchRepositoryPort for SqlCipherBatchRepository {
         let batch_id = batch_id.to_string();
         let status_str = status.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             update_batch_status_sql(&conn, &batch_id, &status_str).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -86,10 +87,10 @@ impl BatchRepositoryPort for SqlCipherBatchRepository {
         let worker_id = worker_id.to_string();
         let duration_secs = duration.as_secs() as i64;
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             acquire_lease(&conn, &batch_id, &worker_id, duration_secs).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -105,10 +106,10 @@ impl BatchRepositoryPort for SqlCipherBatchRepository {
         let worker_id = worker_id.to_string();
         let duration_secs = duration.as_secs() as i64;
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             renew_lease(&conn, &batch_id, &worker_id, duration_secs).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -116,10 +117,10 @@ impl BatchRepositoryPort for SqlCipherBatchRepository {
     async fn get_stale_leases(&self, ttl_secs: i64) -> DomainResult<Vec<BatchQueue>> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<Vec<BatchQueue>> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<Vec<BatchQueue>> {
             let conn = db.get_connection()?;
             query_stale_leases(&conn, ttl_secs).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -127,10 +128,10 @@ impl BatchRepositoryPort for SqlCipherBatchRepository {
     async fn recover_stale_leases(&self) -> DomainResult<Vec<String>> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<Vec<String>> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<Vec<String>> {
             let conn = db.get_connection()?;
             recover_stale_leases_sql(&conn).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -148,11 +149,13 @@ impl BatchRepositoryPort for SqlCipherBatchRepository {
         let db = Arc::clone(&self.db);
         let worker_id = worker_id.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<Option<(String, Vec<String>)>> {
-            let conn = db.get_connection()?;
-            create_batch_from_snapshots(&conn, max_snapshots, &worker_id, lease_duration_secs)
-                .map_err(map_storage_error)
-        })
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<Option<(String, Vec<String>)>> {
+                let conn = db.get_connection()?;
+                create_batch_from_snapshots(&conn, max_snapshots, &worker_id, lease_duration_secs)
+                    .map_err(map_storage_error)
+            },
+        ))
         .await
         .map_err(map_join_error)?
     }
@@ -161,10 +164,10 @@ impl BatchRepositoryPort for SqlCipherBatchRepository {
         let db = Arc::clone(&self.db);
         let batch_id = batch_id.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             mark_batch_completed(&conn, &batch_id).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -174,10 +177,10 @@ impl BatchRepositoryPort for SqlCipherBatchRepository {
         let batch_id = batch_id.to_string();
         let error = error.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             mark_batch_failed_sql(&conn, &batch_id, &error).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -190,10 +193,10 @@ impl BatchRepositoryPort for SqlCipherBatchRepository {
         let db = Arc::clone(&self.db);
         let status_str = status.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<Vec<BatchQueue>> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<Vec<BatchQueue>> {
             let conn = db.get_connection()?;
             query_batches_by_status(&conn, &status_str).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -201,10 +204,10 @@ impl BatchRepositoryPort for SqlCipherBatchRepository {
     async fn get_batch_stats(&self) -> DomainResult<BatchStats> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<BatchStats> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<BatchStats> {
             let conn = db.get_connection()?;
             query_batch_stats(&conn).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -220,10 +223,10 @@ impl BatchRepositoryPort for SqlCipherBatchRepository {
     async fn cleanup_old_batches(&self, older_than_seconds: i64) -> DomainResult<usize> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<usize> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<usize> {
             let conn = db.get_connection()?;
             delete_old_batches(&conn, older_than_seconds).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -232,10 +235,10 @@ impl BatchRepositoryPort for SqlCipherBatchRepository {
         let db = Arc::clone(&self.db);
         let batch_id = batch_id.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             delete_batch_by_id(&conn, &batch_id).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
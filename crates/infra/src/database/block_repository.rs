@@ -20,6 +20,7 @@ use tokio::task;
 use tracing::warn;
 
 use super::manager::DbManager;
+use crate::observability::correlation;
 
 type UtcDateTime = DateTime<Utc>;
 
@@ -60,11 +61,11 @@ impl SqlCipherBlockRepository {
         let snapshot_pattern = format!("%\"{}\"%", snapshot_id);
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<Vec<ProposedBlock>> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<Vec<ProposedBlock>> {
             let conn = db.get_connection()?;
             let params: [&dyn ToSql; 1] = [&snapshot_pattern];
             query_blocks(&conn, BLOCK_SELECT_BY_SNAPSHOT, &params).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -79,7 +80,7 @@ impl SqlCipherBlockRepository {
         let status = status.to_owned();
         let block_id = block_id.to_owned();
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             conn.execute(
                 "UPDATE proposed_time_blocks SET status = ?, reviewed_at = ? WHERE id = ?",
@@ -88,7 +89,7 @@ impl SqlCipherBlockRepository {
             .map_err(StorageError::from)
             .map_err(map_storage_error)?;
             Ok(())
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -100,10 +101,10 @@ impl BlockRepositoryPort for SqlCipherBlockRepository {
         let db = Arc::clone(&self.db);
         let block = block.clone();
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             insert_block(&conn, &block).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -112,13 +113,13 @@ impl BlockRepositoryPort for SqlCipherBlockRepository {
         let (start, end) = day_bounds(date);
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<Vec<ProposedBlock>> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<Vec<ProposedBlock>> {
             let conn = db.get_connection()?;
             let start_ts = start.timestamp();
             let end_ts = end.timestamp();
             let params: [&dyn ToSql; 2] = [&start_ts, &end_ts];
             query_blocks(&conn, BLOCK_SELECT_FOR_DAY, &params).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -127,13 +128,15 @@ impl BlockRepositoryPort for SqlCipherBlockRepository {
         let db = Arc::clone(&self.db);
         let block_id = block_id.to_owned();
 
-        task::spawn_blocking(move || -> DomainResult<Option<ProposedBlock>> {
-            let conn = db.get_connection()?;
-            let params: [&dyn ToSql; 1] = [&block_id];
-            let rows =
-                query_blocks(&conn, BLOCK_SELECT_BY_ID, &params).map_err(map_storage_error)?;
-            Ok(rows.into_iter().next())
-        })
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<Option<ProposedBlock>> {
+                let conn = db.get_connection()?;
+                let params: [&dyn ToSql; 1] = [&block_id];
+                let rows =
+                    query_blocks(&conn, BLOCK_SELECT_BY_ID, &params).map_err(map_storage_error)?;
+                Ok(rows.into_iter().next())
+            },
+        ))
         .await
         .map_err(map_join_error)?
     }
@@ -153,7 +156,7 @@ impl BlockRepositoryPort for SqlCipherBlockRepository {
     async fn get_block_config(&self) -> DomainResult<BlockConfig> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<BlockConfig> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<BlockConfig> {
             let conn = db.get_connection()?;
             let config = conn
                 .inner()
@@ -162,7 +165,7 @@ impl BlockRepositoryPort for SqlCipherBlockRepository {
                 .map_err(StorageError::from)
                 .map_err(map_storage_error)?;
             Ok(config.unwrap_or_default())
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -6,7 +6,7 @@ use async_trait::async_trait;
 use chrono::Utc;
 use pulsearc_common::storage::sqlcipher::SqlCipherPool;
 use pulsearc_core::tracking::ports::CalendarEventRepository;
-use pulsearc_domain::{CalendarEventParams, CalendarEventRow, Result};
+use pulsearc_domain::{CalendarBatchOutcome, CalendarEventParams, CalendarEventRow, Result};
 use rusqlite::ToSql;
 use tracing::{debug, instrument};
 
@@ -24,6 +24,83 @@ impl SqlCipherCalendarEventRepository {
     }
 }
 
+/// Columns and values shared by the calendar event insert statements
+macro_rules! insert_event_sql {
+    ($on_conflict:literal) => {
+        concat!(
+            "INSERT INTO calendar_events (
+                id, google_event_id, user_email, summary, description,
+                start_ts, end_ts, is_all_day, recurring_event_id,
+                parsed_project, parsed_workstream, parsed_task,
+                confidence_score, meeting_platform, is_recurring_series,
+                is_online_meeting, has_external_attendees, organizer_email,
+                organizer_domain, meeting_id, attendee_count, external_attendee_count,
+                created_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23)
+            ",
+            $on_conflict
+        )
+    };
+}
+
+/// Insert an event, or update the event with the same Google id and user
+const UPSERT_EVENT_SQL: &str = insert_event_sql!(
+    "ON CONFLICT(google_event_id, user_email) DO UPDATE SET
+                summary = excluded.summary,
+                description = excluded.description,
+                start_ts = excluded.start_ts,
+                end_ts = excluded.end_ts,
+                is_all_day = excluded.is_all_day,
+                parsed_project = excluded.parsed_project,
+                parsed_workstream = excluded.parsed_workstream,
+                parsed_task = excluded.parsed_task,
+                confidence_score = excluded.confidence_score,
+                meeting_platform = excluded.meeting_platform,
+                is_recurring_series = excluded.is_recurring_series,
+                is_online_meeting = excluded.is_online_meeting,
+                has_external_attendees = excluded.has_external_attendees,
+                organizer_email = excluded.organizer_email,
+                organizer_domain = excluded.organizer_domain,
+                meeting_id = excluded.meeting_id,
+                attendee_count = excluded.attendee_count,
+                external_attendee_count = excluded.external_attendee_count"
+);
+
+/// Insert an event only if no event with the same Google id and user exists
+const INSERT_NEW_EVENT_SQL: &str =
+    insert_event_sql!("ON CONFLICT(google_event_id, user_email) DO NOTHING");
+
+const DELETE_EVENT_SQL: &str = "DELETE FROM calendar_events WHERE id = ?1";
+
+/// Bind parameters for [`UPSERT_EVENT_SQL`] and [`INSERT_NEW_EVENT_SQL`]
+fn event_sql_params<'a>(params: &'a CalendarEventParams, now: &'a i64) -> [&'a dyn ToSql; 23] {
+    [
+        &params.id,
+        &params.google_event_id,
+        &params.user_email,
+        &params.summary,
+        &params.description,
+        &params.when.start_ts,
+        &params.when.end_ts,
+        &params.when.is_all_day,
+        &params.recurring_event_id,
+        &params.parsed.project,
+        &params.parsed.workstream,
+        &params.parsed.task,
+        &params.parsed.confidence_score,
+        &params.meeting_platform,
+        &params.is_recurring_series,
+        &params.is_online_meeting,
+        &params.has_external_attendees,
+        &params.organizer_email,
+        &params.organizer_domain,
+        &params.meeting_id,
+        &params.attendee_count,
+        &params.external_attendee_count,
+        now,
+    ]
+}
+
 #[async_trait]
 impl CalendarEventRepository for SqlCipherCalendarEventRepository {
     #[instrument(skip(self), fields(timestamp, window_secs))]
@@ -103,62 +180,8 @@ impl CalendarEventRepository for SqlCipherCalendarEventRepository {
         let now = Utc::now().timestamp();
 
         // UPSERT logic: INSERT with ON CONFLICT UPDATE
-        conn.execute(
-            "INSERT INTO calendar_events (
-                id, google_event_id, user_email, summary, description,
-                start_ts, end_ts, is_all_day, recurring_event_id,
-                parsed_project, parsed_workstream, parsed_task,
-                confidence_score, meeting_platform, is_recurring_series,
-                is_online_meeting, has_external_attendees, organizer_email,
-                organizer_domain, meeting_id, attendee_count, external_attendee_count,
-                created_at
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23)
-            ON CONFLICT(google_event_id, user_email) DO UPDATE SET
-                summary = excluded.summary,
-                description = excluded.description,
-                start_ts = excluded.start_ts,
-                end_ts = excluded.end_ts,
-                is_all_day = excluded.is_all_day,
-                parsed_project = excluded.parsed_project,
-                parsed_workstream = excluded.parsed_workstream,
-                parsed_task = excluded.parsed_task,
-                confidence_score = excluded.confidence_score,
-                meeting_platform = excluded.meeting_platform,
-                is_recurring_series = excluded.is_recurring_series,
-                is_online_meeting = excluded.is_online_meeting,
-                has_external_attendees = excluded.has_external_attendees,
-                organizer_email = excluded.organizer_email,
-                organizer_domain = excluded.organizer_domain,
-                meeting_id = excluded.meeting_id,
-                attendee_count = excluded.attendee_count,
-                external_attendee_count = excluded.external_attendee_count",
-            [
-                &params.id as &dyn ToSql,
-                &params.google_event_id,
-                &params.user_email,
-                &params.summary,
-                &params.description,
-                &params.when.start_ts,
-                &params.when.end_ts,
-                &params.when.is_all_day,
-                &params.recurring_event_id,
-                &params.parsed.project,
-                &params.parsed.workstream,
-                &params.parsed.task,
-                &params.parsed.confidence_score,
-                &params.meeting_platform,
-                &params.is_recurring_series,
-                &params.is_online_meeting,
-                &params.has_external_attendees,
-                &params.organizer_email,
-                &params.organizer_domain,
-                &params.meeting_id,
-                &params.attendee_count,
-                &params.external_attendee_count,
-                &now,
-            ].as_ref(),
-        )
-        .map_err(InfraError::from)?;
+        conn.execute(UPSERT_EVENT_SQL, &event_sql_params(&params, &now))
+            .map_err(InfraError::from)?;
 
         debug!(
             google_event_id = %params.google_event_id,
@@ -169,6 +192,76 @@ impl CalendarEventRepository for SqlCipherCalendarEventRepository {
         Ok(())
     }
 
+    #[instrument(skip(self, events), fields(count = events.len()))]
+    async fn upsert_batch(
+        &self,
+        events: Vec<CalendarEventParams>,
+    ) -> Result<Vec<CalendarBatchOutcome>> {
+        if events.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = self.pool.get_sqlcipher_connection().map_err(|e| {
+            InfraError(pulsearc_domain::PulseArcError::Database(format!("pool error: {}", e)))
+        })?;
+
+        let now = Utc::now().timestamp();
+        let tx = conn.transaction().map_err(InfraError::from)?;
+
+        let mut outcomes = Vec::with_capacity(events.len());
+        for params in &events {
+            let sql_params = event_sql_params(params, &now);
+            // A conflict leaves the insert a no-op, so the upsert then updates
+            let inserted =
+                tx.execute(INSERT_NEW_EVENT_SQL, &sql_params).map_err(InfraError::from)? > 0;
+            if !inserted {
+                tx.execute(UPSERT_EVENT_SQL, &sql_params).map_err(InfraError::from)?;
+            }
+            outcomes.push(if inserted {
+                CalendarBatchOutcome::Inserted
+            } else {
+                CalendarBatchOutcome::Updated
+            });
+        }
+
+        // Dropping the transaction on an error above rolls the batch back
+        tx.commit().map_err(InfraError::from)?;
+
+        debug!(count = outcomes.len(), "upserted calendar event batch");
+
+        Ok(outcomes)
+    }
+
+    #[instrument(skip(self, ids), fields(count = ids.len()))]
+    async fn delete_batch(&self, ids: &[String]) -> Result<Vec<CalendarBatchOutcome>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = self.pool.get_sqlcipher_connection().map_err(|e| {
+            InfraError(pulsearc_domain::PulseArcError::Database(format!("pool error: {}", e)))
+        })?;
+
+        let tx = conn.transaction().map_err(InfraError::from)?;
+
+        let mut outcomes = Vec::with_capacity(ids.len());
+        for id in ids {
+            let deleted =
+                tx.execute(DELETE_EVENT_SQL, &[id as &dyn ToSql]).map_err(InfraError::from)?;
+            outcomes.push(if deleted > 0 {
+                CalendarBatchOutcome::Deleted
+            } else {
+                CalendarBatchOutcome::NotFound
+            });
+        }
+
+        tx.commit().map_err(InfraError::from)?;
+
+        debug!(count = outcomes.len(), "deleted calendar event batch");
+
+        Ok(outcomes)
+    }
+
     #[instrument(skip(self))]
     async fn get_calendar_events_by_time_range(
         &self,
@@ -480,4 +573,114 @@ mod tests {
         assert_eq!(event.description, Some("New description".to_string()));
         assert_eq!(event.parsed_project, Some("PulseArc".to_string()));
     }
+
+    fn batch_event(id: &str, google_event_id: &str, start_ts: i64) -> CalendarEventParams {
+        CalendarEventParams {
+            id: id.to_string(),
+            google_event_id: google_event_id.to_string(),
+            user_email: "test@example.com".to_string(),
+            summary: format!("Meeting {google_event_id}"),
+            description: None,
+            when: TimeRange { start_ts, end_ts: start_ts + 1800, is_all_day: false },
+            recurring_event_id: None,
+            parsed: ParsedFields {
+                project: None,
+                workstream: None,
+                task: None,
+                confidence_score: None,
+            },
+            meeting_platform: None,
+            is_recurring_series: false,
+            is_online_meeting: false,
+            has_external_attendees: None,
+            organizer_email: None,
+            organizer_domain: None,
+            meeting_id: None,
+            attendee_count: None,
+            external_attendee_count: None,
+        }
+    }
+
+    fn stored_google_ids(pool: &SqlCipherPool) -> Vec<String> {
+        let conn = pool.get_sqlcipher_connection().unwrap();
+        let mut stmt = conn
+            .prepare("SELECT google_event_id FROM calendar_events ORDER BY google_event_id")
+            .unwrap();
+        stmt.query_map(&[], |row| row.get(0)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_upsert_batch_commits_all_events_with_outcomes() {
+        let (pool, _temp) = setup_test_db();
+        let repo = SqlCipherCalendarEventRepository::new(pool.clone());
+        let now = Utc::now().timestamp();
+
+        repo.insert_calendar_event(batch_event("row-1", "evt-1", now)).await.unwrap();
+
+        let mut updated = batch_event("row-1-new", "evt-1", now);
+        updated.summary = "Rescheduled".to_string();
+        let events = vec![
+            updated,
+            batch_event("row-2", "evt-2", now + 3600),
+            batch_event("row-3", "evt-3", now + 7200),
+        ];
+
+        let outcomes = repo.upsert_batch(events).await.unwrap();
+
+        assert_eq!(
+            outcomes,
+            vec![
+                CalendarBatchOutcome::Updated,
+                CalendarBatchOutcome::Inserted,
+                CalendarBatchOutcome::Inserted
+            ]
+        );
+        assert_eq!(stored_google_ids(&pool), vec!["evt-1", "evt-2", "evt-3"]);
+        let event = repo.find_event_by_timestamp(now, 60).await.unwrap().unwrap();
+        assert_eq!(event.summary, "Rescheduled");
+        assert_eq!(event.id, "row-1", "an update keeps the stored row id");
+    }
+
+    #[tokio::test]
+    async fn test_upsert_batch_rolls_back_on_failure() {
+        let (pool, _temp) = setup_test_db();
+        let repo = SqlCipherCalendarEventRepository::new(pool.clone());
+        let now = Utc::now().timestamp();
+
+        // The third event reuses the first one's row id, violating the
+        // primary key after two events have already been written
+        let events = vec![
+            batch_event("row-1", "evt-1", now),
+            batch_event("row-2", "evt-2", now + 3600),
+            batch_event("row-1", "evt-3", now + 7200),
+        ];
+
+        assert!(repo.upsert_batch(events).await.is_err());
+        assert!(stored_google_ids(&pool).is_empty(), "no event from a failed batch is kept");
+    }
+
+    #[tokio::test]
+    async fn test_delete_batch_removes_only_given_events() {
+        let (pool, _temp) = setup_test_db();
+        let repo = SqlCipherCalendarEventRepository::new(pool.clone());
+        let now = Utc::now().timestamp();
+
+        let events = (1..=4)
+            .map(|n| batch_event(&format!("row-{n}"), &format!("evt-{n}"), now + n * 3600))
+            .collect();
+        repo.upsert_batch(events).await.unwrap();
+
+        let ids = vec!["row-2".to_string(), "row-missing".to_string(), "row-4".to_string()];
+        let outcomes = repo.delete_batch(&ids).await.unwrap();
+
+        assert_eq!(
+            outcomes,
+            vec![
+                CalendarBatchOutcome::Deleted,
+                CalendarBatchOutcome::NotFound,
+                CalendarBatchOutcome::Deleted
+            ]
+        );
+        assert_eq!(stored_google_ids(&pool), vec!["evt-1", "evt-3"]);
+    }
 }
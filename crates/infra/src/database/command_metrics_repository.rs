@@ -16,6 +16,7 @@ use tokio::task;
 use tracing::{debug, warn};
 
 use super::manager::DbManager;
+use crate::observability::correlation;
 
 /// Command metrics repository backed by SQLCipher.
 pub struct SqlCipherCommandMetricsRepository {
@@ -34,7 +35,7 @@ impl CommandMetricsPort for SqlCipherCommandMetricsRepository {
     async fn record_execution(&self, metric: CommandMetric) -> DomainResult<()> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
 
             conn.execute(
@@ -62,7 +63,7 @@ impl CommandMetricsPort for SqlCipherCommandMetricsRepository {
             );
 
             Ok(())
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -78,7 +79,7 @@ impl CommandMetricsPort for SqlCipherCommandMetricsRepository {
         let implementation_filter = implementation.map(String::from);
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<CommandStats> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<CommandStats> {
             let conn = db.get_connection()?;
 
             // Build query with optional implementation filter
@@ -164,7 +165,7 @@ impl CommandMetricsPort for SqlCipherCommandMetricsRepository {
                 p99_latency_ms: percentiles.2,
                 avg_latency_ms: avg_latency,
             })
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -177,7 +178,7 @@ impl CommandMetricsPort for SqlCipherCommandMetricsRepository {
         let command = command.to_string();
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<Vec<CommandMetric>> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<Vec<CommandMetric>> {
             let conn = db.get_connection()?;
 
             let mut stmt = conn
@@ -205,7 +206,7 @@ impl CommandMetricsPort for SqlCipherCommandMetricsRepository {
                 .map_err(map_storage_error)?;
 
             Ok(metrics)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -228,7 +229,7 @@ impl CommandMetricsPort for SqlCipherCommandMetricsRepository {
     async fn cleanup_old_metrics(&self, older_than_ts: i64) -> DomainResult<u64> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<u64> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<u64> {
             let conn = db.get_connection()?;
 
             let deleted = conn
@@ -241,7 +242,7 @@ impl CommandMetricsPort for SqlCipherCommandMetricsRepository {
             debug!(deleted_count = deleted, older_than_ts, "Cleaned up old command metrics");
 
             Ok(deleted as u64)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -0,0 +1,407 @@
+//! Configuration export/import using SQLCipher
+//!
+//! Reads and writes the tables behind a `ConfigBundle`: `user_profiles`,
+//! `user_settings` (with history), `feature_flags` and the calendar account
+//! tables. OAuth tokens live in the keychain and are referenced only by
+//! `calendar_tokens.token_ref`, so they are neither exported nor imported.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use pulsearc_common::storage::error::StorageError;
+use pulsearc_common::storage::sqlcipher::SqlCipherConnection;
+use pulsearc_common::storage::types::Transaction;
+use pulsearc_core::user::ports::ConfigurationRepository as ConfigurationRepositoryPort;
+use pulsearc_domain::{
+    CalendarConnection, CalendarSyncSettings, ConfigBundle, ConfigSetting, FeatureFlagOverride,
+    PulseArcError, Result as DomainResult, CONFIG_BUNDLE_VERSION,
+};
+use rusqlite::params;
+use tokio::task;
+
+use super::feature_flags_repository::DEFAULT_FEATURE_FLAGS;
+use super::manager::DbManager;
+use super::user_profile_repository::{import_user_profile, query_current_profile};
+use super::user_settings_repository::record_setting;
+use crate::observability::correlation;
+
+/// SQLCipher-backed implementation of `ConfigurationRepository`
+pub struct SqlCipherConfigurationRepository {
+    db: Arc<DbManager>,
+}
+
+impl SqlCipherConfigurationRepository {
+    /// Create a new repository instance
+    pub fn new(db: Arc<DbManager>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ConfigurationRepositoryPort for SqlCipherConfigurationRepository {
+    async fn export_configuration(&self) -> DomainResult<ConfigBundle> {
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<ConfigBundle> {
+            let conn = db.get_connection()?;
+            export_bundle(&conn).map_err(map_storage_error)
+        }))
+        .await
+        .map_err(map_join_error)?
+    }
+
+    async fn import_configuration(&self, bundle: &ConfigBundle) -> DomainResult<()> {
+        let db = Arc::clone(&self.db);
+        let bundle = bundle.clone();
+
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
+            let mut conn = db.get_connection()?;
+            let tx = conn.transaction().map_err(map_storage_error)?;
+            import_bundle(&tx, &bundle).map_err(map_storage_error)?;
+            tx.commit().map_err(map_storage_error)
+        }))
+        .await
+        .map_err(map_join_error)?
+    }
+}
+
+// =============================================================================
+// Export
+// =============================================================================
+
+fn export_bundle(conn: &SqlCipherConnection) -> Result<ConfigBundle, StorageError> {
+    Ok(ConfigBundle {
+        version: CONFIG_BUNDLE_VERSION,
+        exported_at: Utc::now().timestamp(),
+        profile: query_current_profile(conn)?,
+        settings: query_settings(conn)?,
+        feature_flags: query_flag_overrides(conn)?,
+        calendar_connections: query_calendar_connections(conn)?,
+    })
+}
+
+fn query_settings(conn: &SqlCipherConnection) -> Result<Vec<ConfigSetting>, StorageError> {
+    let mut stmt =
+        conn.prepare("SELECT key, scope, value FROM user_settings ORDER BY key, scope")?;
+    stmt.query_map(params![], |row| {
+        Ok(ConfigSetting { key: row.get(0)?, scope: row.get(1)?, value: row.get(2)? })
+    })
+}
+
+/// Flags that are not shipped or differ from their shipped value
+fn query_flag_overrides(
+    conn: &SqlCipherConnection,
+) -> Result<Vec<FeatureFlagOverride>, StorageError> {
+    let mut stmt =
+        conn.prepare("SELECT flag_name, enabled FROM feature_flags ORDER BY flag_name")?;
+    let flags = stmt.query_map(params![], |row| {
+        Ok(FeatureFlagOverride { flag_name: row.get(0)?, enabled: row.get::<_, i64>(1)? != 0 })
+    })?;
+
+    Ok(flags
+        .into_iter()
+        .filter(|flag| {
+            !DEFAULT_FEATURE_FLAGS
+                .iter()
+                .any(|(name, enabled, _)| *name == flag.flag_name && *enabled == flag.enabled)
+        })
+        .collect())
+}
+
+/// Connected accounts with their sync preferences (defaults if none stored)
+fn query_calendar_connections(
+    conn: &SqlCipherConnection,
+) -> Result<Vec<CalendarConnection>, StorageError> {
+    let mut stmt = conn.prepare(
+        "SELECT t.provider, t.user_email, s.enabled, s.sync_interval_minutes,
+                s.include_all_day_events, s.min_event_duration_minutes, s.lookback_hours,
+                s.lookahead_hours, s.excluded_calendar_ids
+         FROM calendar_tokens t
+         LEFT JOIN calendar_sync_settings s ON s.user_email = t.user_email
+         ORDER BY t.provider, t.user_email",
+    )?;
+    stmt.query_map(params![], |row| {
+        let defaults = CalendarSyncSettings::default();
+        let excluded: Option<String> = row.get(8)?;
+        Ok(CalendarConnection {
+            provider: row.get(0)?,
+            email: row.get(1)?,
+            sync: CalendarSyncSettings {
+                enabled: row.get::<_, Option<bool>>(2)?.unwrap_or(defaults.enabled),
+                sync_interval_minutes: row
+                    .get::<_, Option<u32>>(3)?
+                    .unwrap_or(defaults.sync_interval_minutes),
+                include_all_day_events: row
+                    .get::<_, Option<bool>>(4)?
+                    .unwrap_or(defaults.include_all_day_events),
+                min_event_duration_minutes: row
+                    .get::<_, Option<u32>>(5)?
+                    .unwrap_or(defaults.min_event_duration_minutes),
+                lookback_hours: row.get::<_, Option<u32>>(6)?.unwrap_or(defaults.lookback_hours),
+                lookahead_hours: row.get::<_, Option<u32>>(7)?.unwrap_or(defaults.lookahead_hours),
+                excluded_calendar_ids: excluded
+                    .map(|ids| {
+                        ids.split(',').filter(|id| !id.is_empty()).map(str::to_string).collect()
+                    })
+                    .unwrap_or_default(),
+                sync_token: None,
+                last_sync_epoch: None,
+            },
+        })
+    })
+}
+
+// =============================================================================
+// Import
+// =============================================================================
+
+fn import_bundle(tx: &Transaction<'_>, bundle: &ConfigBundle) -> Result<(), StorageError> {
+    let now = Utc::now().timestamp();
+
+    if let Some(profile) = &bundle.profile {
+        import_user_profile(tx, profile)?;
+    }
+
+    for setting in &bundle.settings {
+        record_setting(tx, &setting.key, &setting.scope, &setting.value, now)?;
+    }
+
+    for flag in &bundle.feature_flags {
+        tx.execute(
+            "INSERT INTO feature_flags (flag_name, enabled, description, updated_at)
+             VALUES (?1, ?2, NULL, ?3)
+             ON CONFLICT(flag_name) DO UPDATE SET
+                enabled = excluded.enabled,
+                updated_at = excluded.updated_at",
+            params![&flag.flag_name, flag.enabled, now],
+        )?;
+    }
+
+    for connection in &bundle.calendar_connections {
+        import_calendar_preferences(tx, connection, now)?;
+    }
+
+    Ok(())
+}
+
+/// Write sync preferences for an account, keeping any local sync state
+///
+/// The account still has to be reconnected, since its tokens are not part of
+/// the bundle.
+fn import_calendar_preferences(
+    tx: &Transaction<'_>,
+    connection: &CalendarConnection,
+    now: i64,
+) -> Result<(), StorageError> {
+    let sync = &connection.sync;
+    let id = uuid::Uuid::new_v4().to_string();
+    let idempotency_key = format!("calendar_sync_settings:{}", connection.email);
+
+    tx.execute(
+        "INSERT INTO calendar_sync_settings (
+            id, user_email, enabled, sync_interval_minutes, include_all_day_events,
+            min_event_duration_minutes, lookback_hours, lookahead_hours,
+            excluded_calendar_ids, created_at, updated_at, idempotency_key
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?10, ?11)
+        ON CONFLICT(idempotency_key) DO UPDATE SET
+            enabled = excluded.enabled,
+            sync_interval_minutes = excluded.sync_interval_minutes,
+            include_all_day_events = excluded.include_all_day_events,
+            min_event_duration_minutes = excluded.min_event_duration_minutes,
+            lookback_hours = excluded.lookback_hours,
+            lookahead_hours = excluded.lookahead_hours,
+            excluded_calendar_ids = excluded.excluded_calendar_ids,
+            updated_at = excluded.updated_at",
+        params![
+            &id,
+            &connection.email,
+            sync.enabled,
+            sync.sync_interval_minutes,
+            sync.include_all_day_events,
+            sync.min_event_duration_minutes,
+            sync.lookback_hours,
+            sync.lookahead_hours,
+            sync.excluded_calendar_ids.join(","),
+            now,
+            &idempotency_key,
+        ],
+    )?;
+    Ok(())
+}
+
+// =============================================================================
+// Error Mapping
+// =============================================================================
+
+fn map_storage_error(err: StorageError) -> PulseArcError {
+    match err {
+        StorageError::Rusqlite(err) => PulseArcError::Database(format!("SQLite error: {err}")),
+        other => PulseArcError::Database(format!("Storage error: {other}")),
+    }
+}
+
+fn map_join_error(err: task::JoinError) -> PulseArcError {
+    PulseArcError::Internal(format!("Task join error: {err}"))
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use pulsearc_core::user::ports::{UserProfileRepository, UserSettingsRepository};
+    use pulsearc_core::user::{import_configuration, set_setting, USER_SCOPE};
+    use pulsearc_domain::{IdleSettings, UserProfile, WorkdayConfig};
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::database::{SqlCipherUserProfileRepository, SqlCipherUserSettingsRepository};
+
+    fn setup_test_db() -> (Arc<DbManager>, TempDir) {
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let db_path = temp_dir.path().join("test.db");
+        let manager = DbManager::new(db_path.to_str().unwrap(), 5, Some("test-key"))
+            .expect("create db manager");
+        manager.run_migrations().expect("run migrations");
+        (Arc::new(manager), temp_dir)
+    }
+
+    fn profile() -> UserProfile {
+        UserProfile {
+            id: "user-1".into(),
+            auth0_id: "auth0|1".into(),
+            email: "ana@example.com".into(),
+            org_id: "org-1".into(),
+            name: Some("Ana Example".into()),
+            first_name: None,
+            last_name: None,
+            display_name: None,
+            avatar_url: None,
+            phone_number: None,
+            title: None,
+            department: None,
+            location: None,
+            bio: None,
+            timezone: "Europe/Berlin".into(),
+            language: "en".into(),
+            locale: "en-US".into(),
+            date_format: "YYYY-MM-DD".into(),
+            is_active: true,
+            email_verified: true,
+            two_factor_enabled: false,
+            last_login_at: 0,
+            last_synced_at: 0,
+            created_at: 0,
+            updated_at: 0,
+            workday: WorkdayConfig {
+                start_minutes: 540,
+                end_minutes: 1020,
+                working_days: vec![1, 2, 3, 4],
+            },
+        }
+    }
+
+    /// Profile, idle setting, one flag override and one calendar account
+    async fn seed_configuration(db: &Arc<DbManager>) {
+        SqlCipherUserProfileRepository::new(Arc::clone(db))
+            .create(profile())
+            .await
+            .expect("create profile");
+        let settings = SqlCipherUserSettingsRepository::new(Arc::clone(db));
+        let idle = IdleSettings { pause_on_idle: false, idle_threshold_secs: 120 };
+        set_setting(&settings, USER_SCOPE, &idle).await.expect("set idle");
+
+        let conn = db.get_connection().expect("connection");
+        conn.execute(
+            "UPDATE feature_flags SET enabled = 1 WHERE flag_name = 'new_idle_commands'",
+            params![],
+        )
+        .expect("override flag");
+        conn.execute(
+            "INSERT INTO calendar_tokens (id, token_ref, user_email, expires_at, created_at,
+                 updated_at, idempotency_key, provider)
+             VALUES ('tok-1', 'keychain-ref', 'ana@example.com', 0, 0, 0, 'tok:ana', 'google')",
+            params![],
+        )
+        .expect("insert token");
+        conn.execute(
+            "INSERT INTO calendar_sync_settings (id, user_email, enabled, sync_interval_minutes,
+                 excluded_calendar_ids, sync_token, created_at, updated_at, idempotency_key)
+             VALUES ('cal-1', 'ana@example.com', 1, 60, 'holidays', 'secret-sync-token', 0, 0,
+                 'calendar_sync_settings:ana@example.com')",
+            params![],
+        )
+        .expect("insert calendar settings");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_bundle_round_trips_between_databases() {
+        let (source_db, _source_dir) = setup_test_db();
+        seed_configuration(&source_db).await;
+        let source = SqlCipherConfigurationRepository::new(Arc::clone(&source_db));
+
+        let bundle = source.export_configuration().await.expect("export");
+
+        assert_eq!(bundle.version, CONFIG_BUNDLE_VERSION);
+        assert_eq!(bundle.profile, Some(profile()));
+        assert_eq!(bundle.settings.len(), 1);
+        assert_eq!(
+            bundle.feature_flags,
+            vec![FeatureFlagOverride { flag_name: "new_idle_commands".into(), enabled: true }]
+        );
+        assert_eq!(bundle.calendar_connections.len(), 1);
+        let sync = &bundle.calendar_connections[0].sync;
+        assert_eq!(sync.sync_interval_minutes, 60);
+        assert_eq!(sync.excluded_calendar_ids, vec!["holidays".to_string()]);
+        assert_eq!(sync.sync_token, None);
+
+        // Through JSON, as the bundle travels between machines
+        let json = serde_json::to_string(&bundle).expect("serialize");
+        let bundle: ConfigBundle = serde_json::from_str(&json).expect("deserialize");
+
+        let (target_db, _target_dir) = setup_test_db();
+        let target = SqlCipherConfigurationRepository::new(Arc::clone(&target_db));
+        import_configuration(&target, &bundle).await.expect("import");
+
+        let imported = target.export_configuration().await.expect("export imported");
+        assert_eq!(imported.profile, bundle.profile);
+        assert_eq!(imported.settings, bundle.settings);
+        assert_eq!(imported.feature_flags, bundle.feature_flags);
+        // Calendar accounts need reconnecting, but their preferences are kept
+        assert!(imported.calendar_connections.is_empty());
+        let conn = target_db.get_connection().expect("connection");
+        let interval: i64 = conn
+            .query_row(
+                "SELECT sync_interval_minutes FROM calendar_sync_settings WHERE user_email = ?1",
+                params!["ana@example.com"],
+                |row| row.get(0),
+            )
+            .expect("calendar settings imported");
+        assert_eq!(interval, 60);
+
+        let settings = SqlCipherUserSettingsRepository::new(target_db);
+        let history = settings.history("idle", USER_SCOPE).await.expect("history");
+        assert_eq!(history.len(), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_incompatible_version_is_rejected_without_writes() {
+        let (source_db, _source_dir) = setup_test_db();
+        seed_configuration(&source_db).await;
+        let mut bundle = SqlCipherConfigurationRepository::new(source_db)
+            .export_configuration()
+            .await
+            .expect("export");
+        bundle.version = CONFIG_BUNDLE_VERSION + 1;
+
+        let (target_db, _target_dir) = setup_test_db();
+        let target = SqlCipherConfigurationRepository::new(Arc::clone(&target_db));
+        let err = import_configuration(&target, &bundle).await.expect_err("version mismatch");
+
+        assert!(matches!(err, PulseArcError::InvalidInput(ref msg) if msg.contains("version")));
+        let profiles = SqlCipherUserProfileRepository::new(target_db);
+        assert!(profiles.get_current_profile().await.expect("query").is_none());
+    }
+}
@@ -1,24 +1,31 @@
 //! SQLCipher-backed database statistics repository.
 //!
 //! Provides read-only introspection of database state via PRAGMA queries
-//! and maintenance operations (VACUUM). All operations use spawn_blocking
-//! to avoid blocking the async runtime.
+//! and maintenance operations (VACUUM, incremental vacuum). All operations use
+//! spawn_blocking to avoid blocking the async runtime.
 
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Instant;
 
 use async_trait::async_trait;
 use pulsearc_common::storage::error::StorageError;
 use pulsearc_common::storage::sqlcipher::SqlCipherConnection;
-use pulsearc_common::storage::types::Connection as ConnectionTrait;
 use pulsearc_core::database_stats_ports::DatabaseStatsPort;
-use pulsearc_domain::types::{DatabaseSize, HealthStatus, TableStats};
+use pulsearc_domain::types::{
+    DatabaseSize, FragmentationReport, HealthStatus, IntegrityReport, RecoveryReport, TableStats,
+    DEFAULT_VACUUM_THRESHOLD,
+};
 use pulsearc_domain::{PulseArcError, Result as DomainResult};
 use rusqlite::types::{Type, ValueRef};
 use tokio::task;
 
 use super::manager::DbManager;
 use crate::errors::InfraError;
+use crate::observability::correlation;
+
+/// `PRAGMA auto_vacuum` value for incremental mode.
+const INCREMENTAL_AUTO_VACUUM: u64 = 2;
 
 /// Database statistics repository backed by SQLCipher.
 pub struct SqlCipherDatabaseStatsRepository {
@@ -37,7 +44,7 @@ impl DatabaseStatsPort for SqlCipherDatabaseStatsRepository {
     async fn get_database_size(&self) -> DomainResult<DatabaseSize> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<DatabaseSize> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<DatabaseSize> {
             let conn = db.get_connection()?;
 
             // Read-only PRAGMA introspection - no parameterization needed. Some PRAGMAs
@@ -52,8 +59,10 @@ impl DatabaseStatsPort for SqlCipherDatabaseStatsRepository {
                 .map(|meta| meta.len())
                 .unwrap_or_else(|_| page_count.saturating_mul(page_size));
 
-            Ok(DatabaseSize { size_bytes, page_count, page_size, freelist_count })
-        })
+            let wal_size_bytes = db.wal_size_bytes();
+
+            Ok(DatabaseSize { size_bytes, page_count, page_size, freelist_count, wal_size_bytes })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -61,7 +70,7 @@ impl DatabaseStatsPort for SqlCipherDatabaseStatsRepository {
     async fn get_table_stats(&self) -> DomainResult<Vec<TableStats>> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<Vec<TableStats>> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<Vec<TableStats>> {
             let conn = db.get_connection()?;
 
             // Query all table names from sqlite_master
@@ -94,7 +103,7 @@ impl DatabaseStatsPort for SqlCipherDatabaseStatsRepository {
             }
 
             Ok(stats)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -102,7 +111,7 @@ impl DatabaseStatsPort for SqlCipherDatabaseStatsRepository {
     async fn get_unprocessed_count(&self) -> DomainResult<i64> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<i64> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<i64> {
             let conn = db.get_connection()?;
 
             // Count snapshots that haven't been processed yet
@@ -115,7 +124,7 @@ impl DatabaseStatsPort for SqlCipherDatabaseStatsRepository {
                 .map_err(map_storage_error)?;
 
             Ok(count)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -123,22 +132,90 @@ impl DatabaseStatsPort for SqlCipherDatabaseStatsRepository {
     async fn vacuum_database(&self) -> DomainResult<()> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<()> {
-            let conn = db.get_connection()?;
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
+            let conn = db.get_maintenance_connection()?;
 
-            // VACUUM rebuilds the database to reclaim space
-            ConnectionTrait::execute(&conn, "VACUUM", &[]).map_err(map_storage_error)?;
+            // VACUUM rebuilds the database to reclaim space; setting auto_vacuum
+            // first converts databases created before incremental vacuum was
+            // enabled
+            conn.execute_batch("PRAGMA auto_vacuum = INCREMENTAL; VACUUM;")
+                .map_err(map_sql_error)?;
 
             Ok(())
-        })
+        }))
+        .await
+        .map_err(map_join_error)?
+    }
+
+    async fn fragmentation_report(&self) -> DomainResult<FragmentationReport> {
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<FragmentationReport> {
+                let conn = db.get_connection()?;
+
+                let page_count = read_pragma_u64(&conn, "page_count")?;
+                let page_size = read_pragma_u64(&conn, "page_size")?;
+                let freelist_count = read_pragma_u64(&conn, "freelist_count")?;
+                // 0 = NONE, 1 = FULL, 2 = INCREMENTAL
+                let auto_vacuum = read_pragma_u64(&conn, "auto_vacuum")?;
+
+                Ok(FragmentationReport::new(
+                    page_count,
+                    page_size,
+                    freelist_count,
+                    auto_vacuum == INCREMENTAL_AUTO_VACUUM,
+                    DEFAULT_VACUUM_THRESHOLD,
+                ))
+            },
+        ))
+        .await
+        .map_err(map_join_error)?
+    }
+
+    async fn incremental_vacuum(&self, max_pages: u64) -> DomainResult<u64> {
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<u64> {
+            let conn = db.get_maintenance_connection()?;
+
+            let before = read_pragma_u64(&conn, "freelist_count")?;
+
+            // The pragma frees one page per step, so drain every row to let it
+            // finish; a limit of 0 would free every page
+            let pages = max_pages.clamp(1, i64::MAX as u64);
+            conn.prepare(&format!("PRAGMA incremental_vacuum({pages})"))
+                .and_then(|mut stmt| stmt.query_map(&[], |_| Ok(())))
+                .map_err(map_storage_error)?;
+
+            let after = read_pragma_u64(&conn, "freelist_count")?;
+            Ok(before.saturating_sub(after))
+        }))
         .await
         .map_err(map_join_error)?
     }
 
+    async fn integrity_check(&self) -> DomainResult<IntegrityReport> {
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(correlation::propagate(move || db.integrity_check()))
+            .await
+            .map_err(map_join_error)?
+    }
+
+    async fn recover_to(&self, target: &Path) -> DomainResult<RecoveryReport> {
+        let db = Arc::clone(&self.db);
+        let target = target.to_path_buf();
+
+        task::spawn_blocking(correlation::propagate(move || db.recover_to(&target)))
+            .await
+            .map_err(map_join_error)?
+    }
+
     async fn check_database_health(&self) -> DomainResult<HealthStatus> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<HealthStatus> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<HealthStatus> {
             let start = Instant::now();
             let conn_result = db.get_connection();
 
@@ -172,7 +249,7 @@ impl DatabaseStatsPort for SqlCipherDatabaseStatsRepository {
                     response_time_ms: start.elapsed().as_millis() as u64,
                 }),
             }
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -210,6 +287,11 @@ fn map_storage_error(err: StorageError) -> PulseArcError {
     }
 }
 
+/// Map `rusqlite::Error` from batch statements to `PulseArcError`.
+fn map_sql_error(err: rusqlite::Error) -> PulseArcError {
+    PulseArcError::from(InfraError::from(err))
+}
+
 /// Map `JoinError` to `PulseArcError` for async task failures.
 fn map_join_error(err: task::JoinError) -> PulseArcError {
     if err.is_cancelled() {
@@ -259,6 +341,7 @@ fn value_ref_to_i64(value: ValueRef<'_>) -> Result<i64, rusqlite::Error> {
 
 #[cfg(test)]
 mod tests {
+    use pulsearc_domain::types::VacuumRecommendation;
     use tempfile::TempDir;
 
     use super::*;
@@ -267,7 +350,7 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn test_get_database_size() {
-        let (repo, _manager, _temp_dir) = setup_repository().await;
+        let (repo, manager, _temp_dir) = setup_repository().await;
 
         let size = repo.get_database_size().await.expect("get database size");
 
@@ -276,6 +359,7 @@ mod tests {
         assert!(size.page_count > 0, "page_count should be > 0");
         assert_eq!(size.page_size, 4096, "default page_size is 4096");
         // freelist_count can be 0 for a new database
+        assert_eq!(size.wal_size_bytes, manager.wal_size_bytes(), "WAL size from the -wal file");
     }
 
     #[tokio::test(flavor = "multi_thread")]
@@ -326,6 +410,79 @@ mod tests {
         repo.vacuum_database().await.expect("vacuum succeeds");
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_fragmentation_report_after_bulk_delete() {
+        let (repo, manager, _temp_dir) = setup_repository().await;
+
+        insert_snapshots(&manager, 2_000);
+        let before = repo.fragmentation_report().await.expect("report before delete");
+        assert!(before.incremental_vacuum_enabled, "migrated databases vacuum incrementally");
+        assert_eq!(before.recommendation, VacuumRecommendation::None);
+
+        delete_snapshots(&manager);
+        let after = repo.fragmentation_report().await.expect("report after delete");
+        assert!(
+            after.freelist_count > before.freelist_count,
+            "deleting rows should free pages ({} -> {})",
+            before.freelist_count,
+            after.freelist_count
+        );
+        assert!(after.fragmentation_ratio > DEFAULT_VACUUM_THRESHOLD);
+        assert_eq!(after.freelist_bytes, after.freelist_count * after.page_size);
+        assert_eq!(after.recommendation, VacuumRecommendation::IncrementalVacuum);
+        assert_eq!(after.summary, format!("incremental vacuum {} pages", after.freelist_count));
+
+        let reclaimed =
+            repo.incremental_vacuum(after.freelist_count).await.expect("incremental vacuum");
+        assert_eq!(reclaimed, after.freelist_count);
+
+        let vacuumed = repo.fragmentation_report().await.expect("report after vacuum");
+        assert_eq!(vacuumed.freelist_count, 0);
+        assert_eq!(vacuumed.recommendation, VacuumRecommendation::None);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_fragmentation_report_recommends_full_vacuum_without_auto_vacuum() {
+        let temp_dir = TempDir::new().expect("tempdir created");
+        let db_path = temp_dir.path().join("legacy_stats_test.db");
+        let manager =
+            Arc::new(DbManager::new(&db_path, 4, Some(TEST_KEY)).expect("db manager created"));
+        // Tables created outside `run_migrations` keep auto_vacuum = NONE, like
+        // databases created before incremental vacuum was enabled
+        manager
+            .get_connection()
+            .expect("connection")
+            .execute_batch(
+                "CREATE TABLE activity_snapshots (
+                    id TEXT PRIMARY KEY,
+                    timestamp INTEGER NOT NULL,
+                    activity_context_json TEXT NOT NULL,
+                    detected_activity TEXT NOT NULL,
+                    primary_app TEXT NOT NULL,
+                    processed BOOLEAN NOT NULL DEFAULT 0,
+                    created_at INTEGER NOT NULL,
+                    is_idle INTEGER NOT NULL DEFAULT 0
+                );",
+            )
+            .expect("legacy table created");
+        let repo = SqlCipherDatabaseStatsRepository::new(manager.clone());
+
+        insert_snapshots(&manager, 2_000);
+        delete_snapshots(&manager);
+
+        let report = repo.fragmentation_report().await.expect("report");
+        assert!(!report.incremental_vacuum_enabled);
+        assert_eq!(report.recommendation, VacuumRecommendation::Vacuum);
+        assert_eq!(repo.incremental_vacuum(100).await.expect("incremental vacuum"), 0);
+
+        // A full vacuum reclaims the space and switches to incremental mode
+        repo.vacuum_database().await.expect("vacuum succeeds");
+        let vacuumed = repo.fragmentation_report().await.expect("report after vacuum");
+        assert_eq!(vacuumed.freelist_count, 0);
+        assert!(vacuumed.incremental_vacuum_enabled);
+        assert_eq!(vacuumed.recommendation, VacuumRecommendation::None);
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_health_check() {
         let (repo, _manager, _temp_dir) = setup_repository().await;
@@ -348,4 +505,22 @@ mod tests {
         let repo = SqlCipherDatabaseStatsRepository::new(manager.clone());
         (repo, manager, temp_dir)
     }
+
+    /// Insert `count` snapshots with ~2 KB of context each.
+    fn insert_snapshots(manager: &DbManager, count: i64) {
+        let conn = manager.get_connection().expect("connection");
+        conn.execute(
+            "WITH RECURSIVE seq(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM seq WHERE n < ?1)
+             INSERT INTO activity_snapshots (id, timestamp, activity_context_json, detected_activity, primary_app, processed, created_at, is_idle)
+             SELECT 'snap-' || n, 1700000000 + n, hex(randomblob(1000)), 'working', 'VSCode', 0, 1700000000, 0
+             FROM seq",
+            rusqlite::params![count],
+        )
+        .expect("insert snapshots");
+    }
+
+    fn delete_snapshots(manager: &DbManager) {
+        let conn = manager.get_connection().expect("connection");
+        conn.execute("DELETE FROM activity_snapshots", rusqlite::params![]).expect("delete");
+    }
 }
@@ -0,0 +1,174 @@
+//! Removal of seeded demo data backed by SQLCipher (`demo-seed` feature)
+//!
+//! Seeded rows are written through the regular repositories; this module only
+//! deletes them again. Rows are matched by the `demo-seed-` ID prefix, so real
+//! activity is never touched.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use pulsearc_common::storage::error::StorageError;
+use pulsearc_common::storage::sqlcipher::SqlCipherConnection;
+use pulsearc_core::demo_seed::{DemoSeedRepository, DemoSeedSummary, DEMO_SEED_ID_PREFIX};
+use pulsearc_domain::{PulseArcError, Result as DomainResult};
+use rusqlite::ToSql;
+use tokio::task;
+
+use super::manager::DbManager;
+use crate::observability::correlation;
+
+const DELETE_SEEDED_BLOCKS_SQL: &str = "DELETE FROM proposed_time_blocks WHERE id LIKE ?1";
+
+const DELETE_SEEDED_SEGMENTS_SQL: &str = "DELETE FROM activity_segments WHERE id LIKE ?1";
+
+const DELETE_SEEDED_SNAPSHOTS_SQL: &str = "DELETE FROM activity_snapshots WHERE id LIKE ?1";
+
+const DELETE_SEEDED_DISMISSALS_SQL: &str =
+    "DELETE FROM suggestion_dismissals WHERE source_id LIKE ?1";
+
+/// SQLCipher-backed implementation of `DemoSeedRepository`
+pub struct SqlCipherDemoSeedRepository {
+    db: Arc<DbManager>,
+}
+
+impl SqlCipherDemoSeedRepository {
+    /// Create a new repository instance
+    pub fn new(db: Arc<DbManager>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DemoSeedRepository for SqlCipherDemoSeedRepository {
+    async fn clear_seeded_data(&self) -> DomainResult<DemoSeedSummary> {
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<DemoSeedSummary> {
+            let mut conn = db.get_connection()?;
+            delete_seeded_rows(&mut conn).map_err(map_storage_error)
+        }))
+        .await
+        .map_err(map_join_error)?
+    }
+}
+
+fn delete_seeded_rows(conn: &mut SqlCipherConnection) -> Result<DemoSeedSummary, StorageError> {
+    let pattern = format!("{DEMO_SEED_ID_PREFIX}%");
+    let params: [&dyn ToSql; 1] = [&pattern];
+
+    let tx = conn.transaction()?;
+    // Dismissals of seeded blocks would otherwise keep suppressing suggestions
+    tx.execute(DELETE_SEEDED_DISMISSALS_SQL, params.as_slice())?;
+    let blocks = tx.execute(DELETE_SEEDED_BLOCKS_SQL, params.as_slice())?;
+    let segments = tx.execute(DELETE_SEEDED_SEGMENTS_SQL, params.as_slice())?;
+    let snapshots = tx.execute(DELETE_SEEDED_SNAPSHOTS_SQL, params.as_slice())?;
+    tx.commit()?;
+
+    Ok(DemoSeedSummary { snapshots, segments, blocks })
+}
+
+fn map_storage_error(err: StorageError) -> PulseArcError {
+    match err {
+        StorageError::WrongKeyOrNotEncrypted => {
+            PulseArcError::Database("Database key error or not encrypted".into())
+        }
+        StorageError::Connection(msg) => PulseArcError::Database(msg),
+        StorageError::Query(msg) => PulseArcError::Database(msg),
+        StorageError::DatabaseError(msg) => PulseArcError::Database(msg),
+        StorageError::Rusqlite(err) => PulseArcError::Database(format!("SQLite error: {err}")),
+        _ => PulseArcError::Database(format!("Storage error: {err}")),
+    }
+}
+
+fn map_join_error(err: task::JoinError) -> PulseArcError {
+    PulseArcError::Internal(format!("Task join error: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use pulsearc_core::demo_seed::{generate_demo_dataset, DemoSeedConfig, DemoSeedService};
+    use pulsearc_core::tracking::ports::SegmentRepository;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::database::{
+        SqlCipherActivityRepository, SqlCipherBlockRepository, SqlCipherSegmentRepository,
+    };
+
+    const TEST_KEY: &str = "test_key_64_chars_long_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+
+    struct Fixture {
+        db: Arc<DbManager>,
+        service: DemoSeedService,
+        _temp_dir: TempDir,
+    }
+
+    fn setup() -> Fixture {
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let db_path = temp_dir.path().join("demo-seed.db");
+        let db = Arc::new(
+            DbManager::new(db_path.to_str().expect("utf8 path"), 4, Some(TEST_KEY)).expect("db"),
+        );
+        db.run_migrations().expect("run migrations");
+
+        let service = DemoSeedService::new(
+            Arc::new(SqlCipherActivityRepository::new(Arc::clone(&db))),
+            Arc::new(SqlCipherSegmentRepository::new(Arc::clone(&db))),
+            Arc::new(SqlCipherBlockRepository::new(Arc::clone(&db))),
+            Arc::new(SqlCipherDemoSeedRepository::new(Arc::clone(&db))),
+        );
+
+        Fixture { db, service, _temp_dir: temp_dir }
+    }
+
+    fn row_counts(db: &DbManager) -> [i64; 3] {
+        let conn = db.get_connection().expect("connection");
+        ["activity_snapshots", "activity_segments", "proposed_time_blocks"].map(|table| {
+            conn.query_row(&format!("SELECT COUNT(*) FROM {table}"), &[], |row| row.get(0))
+                .expect("count rows")
+        })
+    }
+
+    fn config() -> DemoSeedConfig {
+        let start = NaiveDate::from_ymd_opt(2025, 3, 3).expect("valid date");
+        DemoSeedConfig::new(start, start + chrono::Duration::days(4))
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn seed_then_clear_restores_pre_seed_state() {
+        let fixture = setup();
+
+        // Real activity that must survive the clear
+        let mut real = generate_demo_dataset(&config()).expect("dataset").segments.remove(0);
+        real.id = "segment-real".into();
+        SqlCipherSegmentRepository::new(Arc::clone(&fixture.db))
+            .save_segment(&real)
+            .expect("save real segment");
+        let before = row_counts(&fixture.db);
+
+        let seeded = fixture.service.seed(&config()).await.expect("seed");
+        assert_eq!(seeded.blocks, 5 * 6);
+        let after_seed = row_counts(&fixture.db);
+        assert_eq!(after_seed[0], before[0] + seeded.snapshots as i64);
+        assert_eq!(after_seed[1], before[1] + seeded.segments as i64);
+        assert_eq!(after_seed[2], before[2] + seeded.blocks as i64);
+
+        let cleared = fixture.service.clear().await.expect("clear");
+
+        assert_eq!(cleared, seeded);
+        assert_eq!(row_counts(&fixture.db), before);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn reseeding_upserts_instead_of_duplicating() {
+        let fixture = setup();
+
+        let first = fixture.service.seed(&config()).await.expect("seed");
+        let after_first = row_counts(&fixture.db);
+        fixture.service.seed(&config()).await.expect("reseed");
+
+        assert_eq!(row_counts(&fixture.db), after_first);
+        assert_eq!(fixture.service.clear().await.expect("clear"), first);
+    }
+}
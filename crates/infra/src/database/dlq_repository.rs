@@ -14,6 +14,7 @@ use rusqlite::{params, Row};
 use tokio::task;
 
 use super::manager::DbManager;
+use crate::observability::correlation;
 
 /// SqlCipher-based DLQ repository
 pub struct SqlCipherDlqRepository {
@@ -33,10 +34,10 @@ impl DlqRepositoryPort for SqlCipherDlqRepository {
         let batch_id = batch_id.to_string();
         let error = error.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             move_to_dlq(&conn, &batch_id, &error).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -44,10 +45,10 @@ impl DlqRepositoryPort for SqlCipherDlqRepository {
     async fn get_dlq_batches(&self) -> DomainResult<Vec<BatchQueue>> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<Vec<BatchQueue>> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<Vec<BatchQueue>> {
             let conn = db.get_connection()?;
             query_dlq_batches(&conn).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -55,10 +56,10 @@ impl DlqRepositoryPort for SqlCipherDlqRepository {
     async fn get_dlq_batches_with_details(&self) -> DomainResult<Vec<DlqBatch>> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<Vec<DlqBatch>> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<Vec<DlqBatch>> {
             let conn = db.get_connection()?;
             query_dlq_batches_with_details(&conn).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -67,10 +68,10 @@ impl DlqRepositoryPort for SqlCipherDlqRepository {
         let db = Arc::clone(&self.db);
         let batch_id = batch_id.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             reset_batch_to_pending(&conn, &batch_id).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -78,6 +79,25 @@ impl DlqRepositoryPort for SqlCipherDlqRepository {
     async fn retry_failed_batch(&self, batch_id: &str) -> DomainResult<()> {
         self.reset_batch_for_retry(batch_id).await
     }
+
+    async fn move_outbox_entry_to_dlq(
+        &self,
+        outbox_id: &str,
+        error_code: &str,
+        error: &str,
+    ) -> DomainResult<()> {
+        let db = Arc::clone(&self.db);
+        let outbox_id = outbox_id.to_string();
+        let error_code = error_code.to_string();
+        let error = error.to_string();
+
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
+            let mut conn = db.get_connection()?;
+            move_outbox_entry(&mut conn, &outbox_id, &error_code, &error).map_err(map_storage_error)
+        }))
+        .await
+        .map_err(map_join_error)?
+    }
 }
 
 // ============================================================================
@@ -114,6 +134,36 @@ fn move_to_dlq(conn: &SqlCipherConnection, batch_id: &str, error: &str) -> Stora
     Ok(())
 }
 
+fn move_outbox_entry(
+    conn: &mut SqlCipherConnection,
+    outbox_id: &str,
+    error_code: &str,
+    error: &str,
+) -> StorageResult<()> {
+    let now = Utc::now().timestamp();
+    let tx = conn.transaction()?;
+
+    // Mark the entry as failed without scheduling another attempt
+    let updated = tx.execute(
+        "UPDATE time_entry_outbox
+         SET status = 'failed', attempts = attempts + 1, last_error = ?1, error_code = ?2,
+             retry_after = NULL, next_attempt_at = NULL, last_forwarded_at = ?3
+         WHERE id = ?4",
+        params![error, error_code, now, outbox_id],
+    )?;
+    if updated == 0 {
+        return Err(StorageError::Rusqlite(rusqlite::Error::QueryReturnedNoRows));
+    }
+
+    tx.execute(
+        "INSERT OR REPLACE INTO outbox_dlq (outbox_id, error_code, error_message, failed_at, attempts)
+         SELECT id, ?2, ?3, ?4, attempts FROM time_entry_outbox WHERE id = ?1",
+        params![outbox_id, error_code, error, now],
+    )?;
+
+    tx.commit()
+}
+
 fn query_dlq_batches(conn: &SqlCipherConnection) -> StorageResult<Vec<BatchQueue>> {
     let sql = "SELECT bq.batch_id, bq.activity_count, bq.status, bq.created_at, bq.processed_at,
                       bq.error_message, bq.processing_started_at, bq.worker_id, bq.lease_expires_at,
@@ -244,6 +294,9 @@ mod tests {
 
     const TEST_KEY: &str = "test_key_64_chars_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
 
+    /// Status, attempts, last error and retry time of an outbox row
+    type OutboxDlqState = (String, i32, String, Option<i64>);
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_move_batch_to_dlq() {
         let (repo, manager, _dir) = setup_repository().await;
@@ -307,6 +360,48 @@ mod tests {
         assert!(!dlq_batches.iter().any(|b| b.batch_id == "batch-4"));
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_move_outbox_entry_to_dlq() {
+        let (repo, manager, _dir) = setup_repository().await;
+        save_outbox_entry_directly(&manager, "outbox-1").await;
+
+        repo.move_outbox_entry_to_dlq("outbox-1", "Validation Error", "WBS code is closed")
+            .await
+            .expect("entry moved to DLQ");
+
+        let conn = manager.get_connection().expect("connection");
+        let (status, attempts, last_error, retry_after): OutboxDlqState = conn
+            .query_row(
+                "SELECT status, attempts, last_error, retry_after FROM time_entry_outbox WHERE id = ?1",
+                params!["outbox-1"],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+            )
+            .expect("outbox query");
+        assert_eq!(status, "failed");
+        assert_eq!(attempts, 1);
+        assert_eq!(last_error, "WBS code is closed");
+        assert_eq!(retry_after, None);
+
+        let (error_code, dlq_attempts): (String, i32) = conn
+            .query_row(
+                "SELECT error_code, attempts FROM outbox_dlq WHERE outbox_id = ?1",
+                params!["outbox-1"],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .expect("dlq query");
+        assert_eq!(error_code, "Validation Error");
+        assert_eq!(dlq_attempts, 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_move_missing_outbox_entry_to_dlq_fails() {
+        let (repo, _manager, _dir) = setup_repository().await;
+
+        let result = repo.move_outbox_entry_to_dlq("missing", "Validation Error", "error").await;
+
+        assert!(result.is_err());
+    }
+
     // ========================================================================
     // Test Helpers
     // ========================================================================
@@ -364,4 +459,14 @@ mod tests {
         )
         .expect("batch inserted");
     }
+
+    async fn save_outbox_entry_directly(manager: &Arc<DbManager>, outbox_id: &str) {
+        let conn = manager.get_connection().expect("connection");
+        conn.execute(
+            "INSERT INTO time_entry_outbox (id, idempotency_key, user_id, payload_json, created_at)
+             VALUES (?1, ?2, 'user-1', '{}', 1700000000)",
+            params![outbox_id, format!("{outbox_id}-key")],
+        )
+        .expect("outbox entry inserted");
+    }
 }
@@ -3,7 +3,11 @@
 //! Provides persistence for runtime feature flags with helpers for detecting
 //! fallback usage (when a flag is missing from storage and the caller's
 //! default is used instead).
+//!
+//! Flag dependencies live in `feature_flag_dependencies`. Evaluation walks
+//! them transitively, and cycles are rejected when dependencies are declared.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -15,6 +19,28 @@ use rusqlite::params;
 use tokio::task;
 
 use super::manager::DbManager;
+use crate::observability::correlation;
+
+/// Seeded flag as `(name, enabled, description)`
+pub(crate) type DefaultFeatureFlag = (&'static str, bool, &'static str);
+
+/// Flags seeded by `schema.sql`
+///
+/// Must match the seed; resetting settings restores exactly these and drops
+/// any other stored flag.
+pub(crate) const DEFAULT_FEATURE_FLAGS: &[DefaultFeatureFlag] = &[
+    ("new_blocks_cmd", true, "Use new block builder infrastructure"),
+    ("use_new_infra", true, "Enable Phase 4 infrastructure globally"),
+    ("new_database_commands", false, "Phase 4A.1: New database command infrastructure"),
+    ("new_user_profile_commands", false, "Phase 4A.2: New user profile commands"),
+    ("new_window_commands", false, "Phase 4A.3: New window management commands"),
+    ("new_block_commands", false, "Phase 4B.1: New block building commands"),
+    ("new_calendar_commands", false, "Phase 4B.2: New calendar integration commands"),
+    ("new_idle_commands", false, "Phase 4C.1: New idle management commands"),
+    ("new_monitoring_commands", false, "Phase 4C.2: New monitoring & stats commands"),
+    ("new_idle_sync_commands", false, "Phase 4C.3: New idle sync telemetry commands"),
+    ("new_seed_commands", false, "Phase 4C.4: New seed snapshot commands"),
+];
 
 /// SQLCipher-backed feature flags repository.
 pub struct SqlCipherFeatureFlagsRepository {
@@ -28,20 +54,67 @@ impl SqlCipherFeatureFlagsRepository {
     }
 
     /// Evaluate a feature flag, reporting whether the fallback default was
-    /// used.
+    /// used and which dependency (if any) forces it off.
     pub async fn evaluate(
         &self,
         flag_name: &str,
         default: bool,
     ) -> DomainResult<FeatureFlagEvaluation> {
+        self.evaluate_with(flag_name, default, |_| None).await
+    }
+
+    /// Evaluate a feature flag, consulting `overrides` before storage for the
+    /// flag itself and for each of its dependencies.
+    pub async fn evaluate_with<O>(
+        &self,
+        flag_name: &str,
+        default: bool,
+        overrides: O,
+    ) -> DomainResult<FeatureFlagEvaluation>
+    where
+        O: Fn(&str) -> Option<bool> + Send + 'static,
+    {
         let db = Arc::clone(&self.db);
         let flag = flag_name.to_owned();
 
-        task::spawn_blocking(move || -> DomainResult<FeatureFlagEvaluation> {
-            let conn = db.get_connection().map_err(|e| PulseArcError::Database(e.to_string()))?;
-            query_flag_evaluation(&conn, &flag, default)
-                .map_err(|e| PulseArcError::Database(e.to_string()))
-        })
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<FeatureFlagEvaluation> {
+                let conn =
+                    db.get_connection().map_err(|e| PulseArcError::Database(e.to_string()))?;
+                resolve_flag(&conn, &flag, default, &overrides, &mut Vec::new())
+                    .map_err(|e| PulseArcError::Database(e.to_string()))
+            },
+        ))
+        .await
+        .map_err(map_join_error)?
+    }
+
+    /// Evaluate several flags on a single connection; missing flags evaluate
+    /// to `default`.
+    pub async fn evaluate_many(
+        &self,
+        flag_names: Vec<String>,
+        default: bool,
+    ) -> DomainResult<HashMap<String, FeatureFlagEvaluation>> {
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<HashMap<String, FeatureFlagEvaluation>> {
+                let conn =
+                    db.get_connection().map_err(|e| PulseArcError::Database(e.to_string()))?;
+                let mut evaluations = HashMap::with_capacity(flag_names.len());
+                for flag in flag_names {
+                    if evaluations.contains_key(&flag) {
+                        continue;
+                    }
+                    let evaluation =
+                        resolve_flag(&conn, &flag, default, &|_: &str| None, &mut Vec::new())
+                            .map_err(|e| PulseArcError::Database(e.to_string()))?;
+                    evaluations.insert(flag, evaluation);
+                }
+                Ok(evaluations)
+            },
+        ))
         .await
         .map_err(map_join_error)?
     }
@@ -57,11 +130,44 @@ impl SqlCipherFeatureFlagsRepository {
         let db = Arc::clone(&self.db);
         let flag = flag_name.to_owned();
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection().map_err(|e| PulseArcError::Database(e.to_string()))?;
             update_flag_enabled(&conn, &flag, enabled)
                 .map_err(|e| PulseArcError::Database(e.to_string()))
-        })
+        }))
+        .await
+        .map_err(map_join_error)?
+    }
+
+    /// Replace the dependencies declared for a flag.
+    ///
+    /// Returns `PulseArcError::InvalidInput` if the new edges would form a
+    /// cycle.
+    pub async fn set_dependencies(
+        &self,
+        flag_name: &str,
+        requires: Vec<String>,
+    ) -> DomainResult<()> {
+        let db = Arc::clone(&self.db);
+        let flag = flag_name.to_owned();
+
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
+            let mut conn =
+                db.get_connection().map_err(|e| PulseArcError::Database(e.to_string()))?;
+
+            let mut edges = query_all_requirements(&conn)
+                .map_err(|e| PulseArcError::Database(e.to_string()))?;
+            edges.insert(flag.clone(), requires.clone());
+            if let Some(cycle) = find_cycle(&edges, &flag) {
+                return Err(PulseArcError::InvalidInput(format!(
+                    "Feature flag dependency cycle: {}",
+                    cycle.join(" -> ")
+                )));
+            }
+
+            replace_requirements(&mut conn, &flag, &requires)
+                .map_err(|e| PulseArcError::Database(e.to_string()))
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -70,10 +176,10 @@ impl SqlCipherFeatureFlagsRepository {
     pub async fn list_all(&self) -> DomainResult<Vec<FeatureFlag>> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<Vec<FeatureFlag>> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<Vec<FeatureFlag>> {
             let conn = db.get_connection().map_err(|e| PulseArcError::Database(e.to_string()))?;
             query_all_flags(&conn).map_err(|e| PulseArcError::Database(e.to_string()))
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -88,10 +194,23 @@ impl FeatureFlagsPort for SqlCipherFeatureFlagsRepository {
     ) -> DomainResult<FeatureFlagEvaluation> {
         <SqlCipherFeatureFlagsRepository>::evaluate(self, flag_name, default).await
     }
+
+    async fn evaluate_feature_flags(
+        &self,
+        flag_names: &[String],
+    ) -> DomainResult<HashMap<String, FeatureFlagEvaluation>> {
+        <SqlCipherFeatureFlagsRepository>::evaluate_many(self, flag_names.to_vec(), false).await
+    }
+
     async fn set_enabled(&self, flag_name: &str, enabled: bool) -> DomainResult<()> {
         <SqlCipherFeatureFlagsRepository>::set_enabled(self, flag_name, enabled).await
     }
 
+    async fn set_dependencies(&self, flag_name: &str, requires: &[String]) -> DomainResult<()> {
+        <SqlCipherFeatureFlagsRepository>::set_dependencies(self, flag_name, requires.to_vec())
+            .await
+    }
+
     async fn list_all(&self) -> DomainResult<Vec<FeatureFlag>> {
         <SqlCipherFeatureFlagsRepository>::list_all(self).await
     }
@@ -101,6 +220,51 @@ impl FeatureFlagsPort for SqlCipherFeatureFlagsRepository {
 // Synchronous SQL helpers (invoked inside spawn_blocking)
 // ============================================================================
 
+/// Per-flag override lookup consulted before the stored value
+type OverrideLookup = dyn Fn(&str) -> Option<bool>;
+
+/// Flags mapped to the flags they require
+type Requirements = HashMap<String, Vec<String>>;
+
+/// Evaluate `flag_name` and, if it is enabled, each flag it requires.
+///
+/// `path` holds the flags currently being resolved; a dependency already on
+/// the path (a cycle that predates cycle checks) counts as unmet.
+fn resolve_flag(
+    conn: &SqlCipherConnection,
+    flag_name: &str,
+    default: bool,
+    overrides: &OverrideLookup,
+    path: &mut Vec<String>,
+) -> Result<FeatureFlagEvaluation, StorageError> {
+    let own = match overrides(flag_name) {
+        Some(enabled) => {
+            FeatureFlagEvaluation { enabled, fallback_used: false, unmet_dependency: None }
+        }
+        None => query_flag_evaluation(conn, flag_name, default)?,
+    };
+    if !own.enabled {
+        return Ok(own);
+    }
+
+    path.push(flag_name.to_owned());
+    for dependency in query_requirements(conn, flag_name)? {
+        let met = !path.contains(&dependency)
+            && resolve_flag(conn, &dependency, false, overrides, path)?.enabled;
+        if !met {
+            path.pop();
+            return Ok(FeatureFlagEvaluation {
+                enabled: false,
+                unmet_dependency: Some(dependency),
+                ..own
+            });
+        }
+    }
+    path.pop();
+
+    Ok(own)
+}
+
 fn query_flag_evaluation(
     conn: &SqlCipherConnection,
     flag_name: &str,
@@ -111,9 +275,17 @@ fn query_flag_evaluation(
         params![flag_name],
         |row| row.get::<_, i64>(0),
     ) {
-        Ok(value) => Ok(FeatureFlagEvaluation { enabled: value != 0, fallback_used: false }),
+        Ok(value) => Ok(FeatureFlagEvaluation {
+            enabled: value != 0,
+            fallback_used: false,
+            unmet_dependency: None,
+        }),
         Err(StorageError::Rusqlite(rusqlite::Error::QueryReturnedNoRows)) => {
-            Ok(FeatureFlagEvaluation { enabled: default, fallback_used: true })
+            Ok(FeatureFlagEvaluation {
+                enabled: default,
+                fallback_used: true,
+                unmet_dependency: None,
+            })
         }
         Err(e) => Err(e),
     }
@@ -138,21 +310,96 @@ fn update_flag_enabled(
     Ok(())
 }
 
+fn query_requirements(
+    conn: &SqlCipherConnection,
+    flag_name: &str,
+) -> Result<Vec<String>, StorageError> {
+    let mut stmt = conn.prepare(
+        "SELECT requires FROM feature_flag_dependencies WHERE flag_name = ?1 ORDER BY requires",
+    )?;
+    stmt.query_map(params![flag_name], |row| row.get(0))
+}
+
+fn query_all_requirements(conn: &SqlCipherConnection) -> Result<Requirements, StorageError> {
+    let mut stmt = conn.prepare(
+        "SELECT flag_name, requires FROM feature_flag_dependencies ORDER BY flag_name, requires",
+    )?;
+    let rows: Vec<(String, String)> =
+        stmt.query_map(params![], |row| Ok((row.get(0)?, row.get(1)?)))?;
+
+    let mut edges: Requirements = HashMap::new();
+    for (flag_name, requires) in rows {
+        edges.entry(flag_name).or_default().push(requires);
+    }
+    Ok(edges)
+}
+
+fn replace_requirements(
+    conn: &mut SqlCipherConnection,
+    flag_name: &str,
+    requires: &[String],
+) -> Result<(), StorageError> {
+    let tx = conn.transaction()?;
+
+    tx.execute("DELETE FROM feature_flag_dependencies WHERE flag_name = ?1", params![flag_name])?;
+    for dependency in requires {
+        tx.execute(
+            "INSERT OR IGNORE INTO feature_flag_dependencies (flag_name, requires) VALUES (?1, ?2)",
+            params![flag_name, dependency],
+        )?;
+    }
+
+    tx.commit()
+}
+
+/// Find a dependency path that leads from `flag_name` back to itself.
+///
+/// Returns the cycle as a list of flag names starting and ending with
+/// `flag_name`.
+fn find_cycle(edges: &Requirements, flag_name: &str) -> Option<Vec<String>> {
+    fn visit(edges: &Requirements, target: &str, current: &str, path: &mut Vec<String>) -> bool {
+        for next in edges.get(current).into_iter().flatten() {
+            if next == target {
+                path.push(next.clone());
+                return true;
+            }
+            if path.contains(next) {
+                continue;
+            }
+            path.push(next.clone());
+            if visit(edges, target, next, path) {
+                return true;
+            }
+            path.pop();
+        }
+        false
+    }
+
+    let mut path = vec![flag_name.to_owned()];
+    visit(edges, flag_name, flag_name, &mut path).then_some(path)
+}
+
 fn query_all_flags(conn: &SqlCipherConnection) -> Result<Vec<FeatureFlag>, StorageError> {
+    let mut requirements = query_all_requirements(conn)?;
     let mut stmt = conn.prepare(
         "SELECT flag_name, enabled, description, updated_at
          FROM feature_flags
          ORDER BY flag_name",
     )?;
 
-    stmt.query_map(params![], |row| {
+    let mut flags = stmt.query_map(params![], |row| {
         Ok(FeatureFlag {
             flag_name: row.get(0)?,
             enabled: row.get::<_, i64>(1)? != 0,
             description: row.get(2)?,
             updated_at: row.get(3)?,
+            requires: Vec::new(),
         })
-    })
+    })?;
+    for flag in &mut flags {
+        flag.requires = requirements.remove(&flag.flag_name).unwrap_or_default();
+    }
+    Ok(flags)
 }
 
 fn map_join_error(err: task::JoinError) -> PulseArcError {
@@ -213,6 +460,99 @@ mod tests {
         assert!(flags.len() >= 2, "bootstrap migration inserts default flags");
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn unmet_dependency_disables_flag_with_reason() {
+        let (repo, _mgr, _dir) = setup().await;
+        repo.set_enabled("sap", false).await.expect("update succeeded");
+        repo.set_enabled("sap_batch_retry", true).await.expect("update succeeded");
+        repo.set_dependencies("sap_batch_retry", vec!["sap".to_string()])
+            .await
+            .expect("dependencies declared");
+
+        let state = repo.evaluate("sap_batch_retry", false).await.expect("query succeeded");
+        assert!(!state.enabled);
+        assert_eq!(state.unmet_dependency.as_deref(), Some("sap"));
+
+        repo.set_enabled("sap", true).await.expect("update succeeded");
+
+        let state = repo.evaluate("sap_batch_retry", false).await.expect("query succeeded");
+        assert!(state.enabled);
+        assert_eq!(state.unmet_dependency, None);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn transitive_dependency_is_enforced() {
+        let (repo, _mgr, _dir) = setup().await;
+        for flag in ["a", "b", "c"] {
+            repo.set_enabled(flag, true).await.expect("update succeeded");
+        }
+        repo.set_dependencies("a", vec!["b".to_string()]).await.expect("dependencies declared");
+        repo.set_dependencies("b", vec!["c".to_string()]).await.expect("dependencies declared");
+        repo.set_enabled("c", false).await.expect("update succeeded");
+
+        let state = repo.evaluate("a", false).await.expect("query succeeded");
+        assert!(!state.enabled);
+        assert_eq!(state.unmet_dependency.as_deref(), Some("b"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn dependency_cycles_are_rejected() {
+        let (repo, _mgr, _dir) = setup().await;
+        repo.set_enabled("c", true).await.expect("update succeeded");
+        repo.set_dependencies("a", vec!["b".to_string()]).await.expect("dependencies declared");
+        repo.set_dependencies("b", vec!["c".to_string()]).await.expect("dependencies declared");
+
+        let err = repo.set_dependencies("c", vec!["a".to_string()]).await.expect_err("cycle");
+        assert!(
+            matches!(err, PulseArcError::InvalidInput(ref msg) if msg.contains("c -> a -> b -> c"))
+        );
+
+        let err = repo.set_dependencies("a", vec!["a".to_string()]).await.expect_err("self cycle");
+        assert!(matches!(err, PulseArcError::InvalidInput(_)));
+
+        // Rejected declarations leave the stored edges untouched
+        let flags = repo.list_all().await.expect("list_all succeeded");
+        let c = flags.iter().find(|flag| flag.flag_name == "c").expect("flag listed");
+        assert!(c.requires.is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn list_all_reports_dependencies() {
+        let (repo, _mgr, _dir) = setup().await;
+        repo.set_enabled("sap_batch_retry", true).await.expect("update succeeded");
+        repo.set_dependencies("sap_batch_retry", vec!["sap".to_string()])
+            .await
+            .expect("dependencies declared");
+
+        let flags = repo.list_all().await.expect("list_all succeeded");
+        let flag =
+            flags.iter().find(|flag| flag.flag_name == "sap_batch_retry").expect("flag listed");
+        assert_eq!(flag.requires, vec!["sap".to_string()]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn default_flags_match_schema_seed() {
+        let (repo, _mgr, _dir) = setup().await;
+
+        let mut seeded: Vec<_> = repo
+            .list_all()
+            .await
+            .expect("list_all succeeded")
+            .into_iter()
+            .map(|flag| (flag.flag_name, flag.enabled, flag.description.unwrap_or_default()))
+            .collect();
+        let mut defaults: Vec<_> = DEFAULT_FEATURE_FLAGS
+            .iter()
+            .map(|(name, enabled, description)| {
+                (name.to_string(), *enabled, description.to_string())
+            })
+            .collect();
+        seeded.sort();
+        defaults.sort();
+
+        assert_eq!(seeded, defaults);
+    }
+
     async fn setup() -> (SqlCipherFeatureFlagsRepository, Arc<DbManager>, TempDir) {
         let temp_dir = TempDir::new().expect("temp dir created");
         let db_path = temp_dir.path().join("flags.db");
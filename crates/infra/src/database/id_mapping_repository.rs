@@ -13,6 +13,7 @@ use rusqlite::{params, Row};
 use tokio::task;
 
 use super::manager::DbManager;
+use crate::observability::correlation;
 
 /// SqlCipher-based ID Mapping repository
 pub struct SqlCipherIdMappingRepository {
@@ -31,10 +32,10 @@ impl IdMappingRepositoryPort for SqlCipherIdMappingRepository {
         let db = Arc::clone(&self.db);
         let mapping = mapping.clone();
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             insert_id_mapping(&conn, &mapping).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -43,10 +44,10 @@ impl IdMappingRepositoryPort for SqlCipherIdMappingRepository {
         let db = Arc::clone(&self.db);
         let uuid = uuid.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<Option<IdMapping>> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<Option<IdMapping>> {
             let conn = db.get_connection()?;
             query_id_mapping_by_local_uuid(&conn, &uuid).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -55,10 +56,10 @@ impl IdMappingRepositoryPort for SqlCipherIdMappingRepository {
         let db = Arc::clone(&self.db);
         let uuid = uuid.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<Option<String>> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<Option<String>> {
             let conn = db.get_connection()?;
             query_backend_cuid(&conn, &uuid).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -67,10 +68,10 @@ impl IdMappingRepositoryPort for SqlCipherIdMappingRepository {
         let db = Arc::clone(&self.db);
         let cuid = cuid.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<Option<String>> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<Option<String>> {
             let conn = db.get_connection()?;
             query_local_uuid(&conn, &cuid).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -82,10 +83,10 @@ impl IdMappingRepositoryPort for SqlCipherIdMappingRepository {
         let db = Arc::clone(&self.db);
         let entity_type = entity_type.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<Vec<IdMapping>> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<Vec<IdMapping>> {
             let conn = db.get_connection()?;
             query_mappings_by_entity_type(&conn, &entity_type).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -14,6 +14,7 @@ use rusqlite::{params, Row, ToSql};
 use tokio::task;
 
 use super::manager::DbManager;
+use crate::observability::correlation;
 
 /// SQLCipher-backed implementation of `IdlePeriodsRepository`
 pub struct SqlCipherIdlePeriodsRepository {
@@ -32,11 +33,11 @@ impl IdlePeriodsRepositoryPort for SqlCipherIdlePeriodsRepository {
     async fn save_idle_period(&self, period: IdlePeriod) -> DomainResult<()> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             insert_idle_period(&conn, &period).map_err(map_storage_error)?;
             Ok(())
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -45,7 +46,7 @@ impl IdlePeriodsRepositoryPort for SqlCipherIdlePeriodsRepository {
         let db = Arc::clone(&self.db);
         let id = id.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<Option<IdlePeriod>> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<Option<IdlePeriod>> {
             let conn = db.get_connection()?;
 
             let result = conn.query_row(
@@ -61,7 +62,7 @@ impl IdlePeriodsRepositoryPort for SqlCipherIdlePeriodsRepository {
                 Err(StorageError::Rusqlite(rusqlite::Error::QueryReturnedNoRows)) => Ok(None),
                 Err(err) => Err(map_storage_error(err)),
             }
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -73,10 +74,10 @@ impl IdlePeriodsRepositoryPort for SqlCipherIdlePeriodsRepository {
     ) -> DomainResult<Vec<IdlePeriod>> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<Vec<IdlePeriod>> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<Vec<IdlePeriod>> {
             let conn = db.get_connection()?;
             query_idle_periods_in_range(&conn, start_ts, end_ts).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -84,10 +85,10 @@ impl IdlePeriodsRepositoryPort for SqlCipherIdlePeriodsRepository {
     async fn get_pending_idle_periods(&self) -> DomainResult<Vec<IdlePeriod>> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<Vec<IdlePeriod>> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<Vec<IdlePeriod>> {
             let conn = db.get_connection()?;
             query_pending_idle_periods(&conn).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -102,12 +103,12 @@ impl IdlePeriodsRepositoryPort for SqlCipherIdlePeriodsRepository {
         let id = id.to_string();
         let user_action = user_action.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             update_idle_period_user_action(&conn, &id, &user_action, notes)
                 .map_err(map_storage_error)?;
             Ok(())
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -115,10 +116,10 @@ impl IdlePeriodsRepositoryPort for SqlCipherIdlePeriodsRepository {
     async fn delete_idle_periods_before(&self, before_ts: i64) -> DomainResult<usize> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<usize> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<usize> {
             let conn = db.get_connection()?;
             delete_idle_periods_before(&conn, before_ts).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -126,10 +127,10 @@ impl IdlePeriodsRepositoryPort for SqlCipherIdlePeriodsRepository {
     async fn get_idle_summary(&self, start_ts: i64, end_ts: i64) -> DomainResult<IdleSummary> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<IdleSummary> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<IdleSummary> {
             let conn = db.get_connection()?;
             calculate_idle_summary(&conn, start_ts, end_ts).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -0,0 +1,364 @@
+//! Integrity checking and best-effort recovery.
+//!
+//! [`integrity_check`] runs `PRAGMA integrity_check` and `PRAGMA
+//! foreign_key_check` and reports what they find. [`recover_to`] copies
+//! whatever can still be read into a new database, in the spirit of the
+//! sqlite3 shell's `.recover`: the schema is recreated object by object and
+//! each table is copied on its own, so a damaged table does not stop the rest
+//! from being recovered.
+//!
+//! The new database is attached to the source connection, so it is encrypted
+//! with the same SQLCipher key and can be opened by [`DbManager`] directly.
+//!
+//! [`DbManager`]: super::manager::DbManager
+
+use std::path::Path;
+
+use pulsearc_common::storage::sqlcipher::SqlCipherConnection;
+use pulsearc_domain::types::{ForeignKeyViolation, IntegrityReport, RecoveryReport};
+use pulsearc_domain::{PulseArcError, Result};
+use rusqlite::{params, ToSql};
+use tracing::{info, warn};
+
+use crate::errors::InfraError;
+
+/// Most problems reported by `PRAGMA integrity_check`.
+pub const MAX_INTEGRITY_ERRORS: u32 = 100;
+
+/// Schema name the recovery target is attached under.
+const RECOVERY_SCHEMA: &str = "recovered";
+
+/// Run `PRAGMA integrity_check` and `PRAGMA foreign_key_check`.
+pub fn integrity_check(conn: &SqlCipherConnection) -> Result<IntegrityReport> {
+    let mut stmt = conn
+        .prepare(&format!("PRAGMA integrity_check({MAX_INTEGRITY_ERRORS})"))
+        .map_err(|err| PulseArcError::Database(err.to_string()))?;
+    let integrity_errors: Vec<String> = stmt
+        .query_map(&[], |row| row.get::<_, String>(0))
+        .map_err(|err| PulseArcError::Database(err.to_string()))?
+        .into_iter()
+        .filter(|message| message != "ok")
+        .collect();
+
+    let mut stmt = conn
+        .prepare("PRAGMA foreign_key_check")
+        .map_err(|err| PulseArcError::Database(err.to_string()))?;
+    let foreign_key_violations = stmt
+        .query_map(&[], |row| {
+            Ok(ForeignKeyViolation {
+                table: row.get(0)?,
+                rowid: row.get(1)?,
+                parent: row.get(2)?,
+                constraint_index: row.get(3)?,
+            })
+        })
+        .map_err(|err| PulseArcError::Database(err.to_string()))?;
+
+    let report = IntegrityReport::new(integrity_errors, foreign_key_violations);
+    if report.ok {
+        info!("database integrity check passed");
+    } else {
+        warn!(
+            integrity_errors = report.integrity_errors.len(),
+            foreign_key_violations = report.foreign_key_violations.len(),
+            "database integrity check found problems"
+        );
+    }
+    Ok(report)
+}
+
+/// Copy everything readable from the connection's database into a new
+/// database at `target`.
+///
+/// Fails if `target` already exists or cannot be attached; problems with
+/// individual objects are collected in [`RecoveryReport::failures`] instead.
+pub fn recover_to(conn: &SqlCipherConnection, target: &Path) -> Result<RecoveryReport> {
+    if target.exists() {
+        return Err(PulseArcError::InvalidInput(format!(
+            "recovery target {} already exists",
+            target.display()
+        )));
+    }
+    let target_path = target.to_string_lossy().into_owned();
+
+    // Without a KEY clause SQLCipher encrypts the attachment with the main key
+    conn.execute(&format!("ATTACH DATABASE ?1 AS {RECOVERY_SCHEMA}"), params![target_path])
+        .map_err(map_sql_error)?;
+    // Tables are filled in arbitrary order, so parents may arrive after children
+    let foreign_keys: i64 =
+        conn.pragma_query_value(None, "foreign_keys", |row| row.get(0)).map_err(map_sql_error)?;
+    conn.execute_batch("PRAGMA foreign_keys = OFF").map_err(map_sql_error)?;
+
+    let result = copy_schema_and_rows(conn);
+
+    if let Err(err) = conn.pragma_update(None, "foreign_keys", foreign_keys) {
+        warn!(error = %err, "failed to restore foreign_keys after recovery");
+    }
+    if let Err(err) = conn.execute_batch(&format!("DETACH DATABASE {RECOVERY_SCHEMA}")) {
+        warn!(error = %err, "failed to detach recovery target");
+    }
+
+    let report = result?;
+    info!(
+        target = %target.display(),
+        tables_recovered = report.tables_recovered,
+        rows_recovered = report.rows_recovered,
+        failures = report.failures.len(),
+        "database recovery finished"
+    );
+    Ok(report)
+}
+
+/// Schema object as stored in `sqlite_master`.
+struct SchemaObject {
+    kind: String,
+    name: String,
+    sql: String,
+}
+
+impl SchemaObject {
+    fn is_virtual_table(&self) -> bool {
+        self.sql.starts_with("CREATE VIRTUAL TABLE ")
+    }
+}
+
+fn copy_schema_and_rows(conn: &SqlCipherConnection) -> Result<RecoveryReport> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT type, name, sql FROM main.sqlite_master
+             WHERE sql IS NOT NULL AND name NOT LIKE 'sqlite_%'
+             ORDER BY rowid",
+        )
+        .map_err(|err| PulseArcError::Database(err.to_string()))?;
+    let objects = stmt
+        .query_map(&[], |row| {
+            Ok(SchemaObject { kind: row.get(0)?, name: row.get(1)?, sql: row.get(2)? })
+        })
+        .map_err(|err| PulseArcError::Database(err.to_string()))?;
+
+    let virtual_tables: Vec<&str> = objects
+        .iter()
+        .filter(|object| object.is_virtual_table())
+        .map(|object| object.name.as_str())
+        .collect();
+    // Shadow tables (e.g. FTS5 `<name>_data`) are created with their virtual table
+    let is_shadow = |object: &SchemaObject| {
+        virtual_tables.iter().any(|vtab| {
+            object.name.strip_prefix(vtab).is_some_and(|suffix| suffix.starts_with('_'))
+        })
+    };
+
+    let mut report = RecoveryReport::default();
+
+    // Tables first, then rows, then indexes, views and triggers so triggers do
+    // not fire while rows are copied
+    for object in objects.iter().filter(|object| object.kind == "table" && !is_shadow(object)) {
+        if let Err(err) = create_in_target(conn, object) {
+            report.failures.push(format!("{}: {err}", object.name));
+        }
+    }
+
+    for object in objects.iter().filter(|object| object.kind == "table") {
+        if object.is_virtual_table() {
+            continue;
+        }
+        match copy_rows(conn, object) {
+            Ok(rows) => {
+                report.tables_recovered += 1;
+                report.rows_recovered += rows as u64;
+            }
+            Err(err) => {
+                warn!(table = %object.name, error = %err, "failed to recover table rows");
+                report.failures.push(format!("{}: {err}", object.name));
+            }
+        }
+    }
+
+    for object in objects.iter().filter(|object| object.kind != "table") {
+        if let Err(err) = create_in_target(conn, object) {
+            report.failures.push(format!("{}: {err}", object.name));
+        }
+    }
+
+    Ok(report)
+}
+
+/// Copy the rows of table `object` into the recovery schema.
+///
+/// Rowids are carried over so external-content indexes (FTS5) still match
+/// their content table.
+fn copy_rows(conn: &SqlCipherConnection, object: &SchemaObject) -> Result<usize> {
+    let name = quote_identifier(&object.name);
+    let mut stmt = conn
+        .prepare("SELECT name FROM pragma_table_info(?1, 'main')")
+        .map_err(|err| PulseArcError::Database(err.to_string()))?;
+    let params: [&dyn ToSql; 1] = [&object.name];
+    let mut columns: Vec<String> = stmt
+        .query_map(&params, |row| row.get::<_, String>(0))
+        .map_err(|err| PulseArcError::Database(err.to_string()))?
+        .iter()
+        .map(|column| quote_identifier(column))
+        .collect();
+    if !object.sql.to_ascii_uppercase().contains("WITHOUT ROWID") {
+        columns.insert(0, "rowid".to_string());
+    }
+
+    let columns = columns.join(", ");
+    let sql = format!(
+        "INSERT OR REPLACE INTO {RECOVERY_SCHEMA}.{name} ({columns}) SELECT {columns} FROM main.{name}"
+    );
+    conn.execute(&sql, params![]).map_err(map_sql_error)
+}
+
+fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// Recreate `object` in the recovery schema by qualifying its name.
+fn create_in_target(conn: &SqlCipherConnection, object: &SchemaObject) -> Result<()> {
+    // sqlite_master stores normalised statements: upper-case leading keywords,
+    // single spaces and no IF NOT EXISTS or schema qualifier
+    const PREFIXES: [&str; 6] = [
+        "CREATE TABLE ",
+        "CREATE VIRTUAL TABLE ",
+        "CREATE UNIQUE INDEX ",
+        "CREATE INDEX ",
+        "CREATE VIEW ",
+        "CREATE TRIGGER ",
+    ];
+
+    let sql = PREFIXES
+        .iter()
+        .find_map(|prefix| {
+            object.sql.strip_prefix(prefix).map(|rest| format!("{prefix}{RECOVERY_SCHEMA}.{rest}"))
+        })
+        .ok_or_else(|| {
+            PulseArcError::Database(format!("unrecognised schema statement for {}", object.name))
+        })?;
+
+    conn.execute_batch(&sql).map_err(|err| {
+        warn!(object = %object.name, error = %err, "failed to recreate schema object");
+        map_sql_error(err)
+    })
+}
+
+fn map_sql_error(err: rusqlite::Error) -> PulseArcError {
+    PulseArcError::from(InfraError::from(err))
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::database::manager::DbManager;
+
+    const TEST_KEY: &str = "test_key_64_chars_long_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+
+    /// Migrated database with snapshots, a segment and a WBS entry (which
+    /// feeds the FTS index through triggers).
+    fn fixture(temp_dir: &TempDir) -> DbManager {
+        let manager = DbManager::new(temp_dir.path().join("source.db"), 2, Some(TEST_KEY))
+            .expect("manager created");
+        manager.run_migrations().expect("migrations run");
+        let conn = manager.get_connection().expect("connection acquired");
+        conn.execute_batch(
+            "WITH RECURSIVE seq(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM seq WHERE n < 50)
+             INSERT INTO activity_snapshots (id, timestamp, activity_context_json, detected_activity, primary_app, processed, created_at, is_idle)
+             SELECT 'snap-' || n, 1700000000 + n * 30, '{}', 'working', 'VSCode', 0, 1700000000, 0
+             FROM seq;
+             INSERT INTO wbs_cache (wbs_code, project_def, project_name, description, status, cached_at, expires_at)
+             VALUES ('USC0063201.1.1', 'USC0063201', 'Astro Migration', 'Cloud migration', 'REL', 1700000000, 1800000000);",
+        )
+        .expect("fixture rows inserted");
+        manager
+    }
+
+    fn count(conn: &SqlCipherConnection, sql: &str) -> i64 {
+        conn.query_row(sql, &[], |row| row.get(0)).expect("count query")
+    }
+
+    #[test]
+    fn healthy_database_reports_ok() {
+        let temp_dir = TempDir::new().expect("temp dir created");
+        let manager = fixture(&temp_dir);
+        let conn = manager.get_connection().expect("connection acquired");
+
+        let report = integrity_check(&conn).expect("integrity check runs");
+
+        assert!(report.ok, "unexpected findings: {report:?}");
+        assert!(report.integrity_errors.is_empty());
+        assert!(report.foreign_key_violations.is_empty());
+    }
+
+    #[test]
+    fn foreign_key_violations_are_reported() {
+        let temp_dir = TempDir::new().expect("temp dir created");
+        let manager = fixture(&temp_dir);
+        let conn = manager.get_connection().expect("connection acquired");
+        conn.execute_batch(
+            "CREATE TABLE parents (id INTEGER PRIMARY KEY);
+             CREATE TABLE children (id INTEGER PRIMARY KEY, parent_id INTEGER REFERENCES parents(id));
+             PRAGMA foreign_keys = OFF;
+             INSERT INTO children (id, parent_id) VALUES (7, 42);",
+        )
+        .expect("orphan inserted");
+
+        let report = integrity_check(&conn).expect("integrity check runs");
+
+        assert!(!report.ok);
+        assert_eq!(
+            report.foreign_key_violations,
+            vec![ForeignKeyViolation {
+                table: "children".into(),
+                rowid: Some(7),
+                parent: "parents".into(),
+                constraint_index: 0,
+            }]
+        );
+    }
+
+    #[test]
+    fn recovery_produces_a_queryable_database() {
+        let temp_dir = TempDir::new().expect("temp dir created");
+        let manager = fixture(&temp_dir);
+        let target = temp_dir.path().join("recovered.db");
+
+        let report = {
+            let conn = manager.get_connection().expect("connection acquired");
+            recover_to(&conn, &target).expect("recovery runs")
+        };
+
+        assert!(report.failures.is_empty(), "unexpected failures: {:?}", report.failures);
+        assert!(report.tables_recovered > 0);
+        assert!(report.rows_recovered >= 51);
+
+        let recovered = DbManager::new(&target, 1, Some(TEST_KEY)).expect("recovered db opens");
+        let conn = recovered.get_connection().expect("connection acquired");
+        assert_eq!(count(&conn, "SELECT COUNT(*) FROM activity_snapshots"), 50);
+        assert_eq!(
+            count(&conn, "SELECT COUNT(*) FROM wbs_cache_fts WHERE wbs_cache_fts MATCH 'astro'"),
+            1,
+            "full-text index is recovered"
+        );
+        assert!(integrity_check(&conn).expect("integrity check runs").ok);
+        drop(conn);
+
+        // Migrations treat the recovered copy as an existing database
+        recovered.run_migrations().expect("migrations run on recovered db");
+    }
+
+    #[test]
+    fn recovery_refuses_to_overwrite_an_existing_file() {
+        let temp_dir = TempDir::new().expect("temp dir created");
+        let manager = fixture(&temp_dir);
+        let conn = manager.get_connection().expect("connection acquired");
+        let target = temp_dir.path().join("existing.db");
+        std::fs::write(&target, b"keep me").expect("file written");
+
+        let result = recover_to(&conn, &target);
+
+        assert!(matches!(result, Err(PulseArcError::InvalidInput(_))));
+        assert_eq!(std::fs::read(&target).expect("file read"), b"keep me");
+    }
+}
@@ -1,18 +1,24 @@
 //! Database connection manager backed by the shared SQLCipher pool.
 
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use pulsearc_common::storage::sqlcipher::{
     SqlCipherConnection, SqlCipherPool, SqlCipherPoolConfig,
 };
 use pulsearc_common::storage::StorageError;
-use pulsearc_domain::{PulseArcError, Result};
+use pulsearc_domain::types::{IntegrityReport, RecoveryReport};
+use pulsearc_domain::{DatabaseConfig, PulseArcError, Result};
 use rusqlite::params;
-use tracing::info;
+use tokio::sync::oneshot;
+use tracing::{info, warn};
 
 use super::sqlcipher_pool::create_sqlcipher_pool;
+use super::{integrity, statement_cache};
 use crate::errors::InfraError;
+use crate::observability::correlation;
 
 // Schema evolution within version 1 (additive changes via CREATE TABLE IF NOT
 // EXISTS)
@@ -20,14 +26,120 @@ use crate::errors::InfraError;
 // - Added in Phase 4 prep: feature_flags, idle_periods tables
 // - No version bump needed: schema is idempotent, existing v1 databases
 //   compatible
-// - Future: Implement proper migration logic when breaking changes needed
-const SCHEMA_VERSION: i32 = 1;
+// Version 2: columns added to existing tables (see `ADDED_COLUMNS`), applied
+// with ALTER TABLE before the schema batch so indexes on them can be created
+// Version 3: per-user workday settings on `user_profiles`
+// Version 4: `user_settings` store with append-only `user_settings_history`
+// Version 5: per-user idle hysteresis thresholds on `user_profiles`
+const SCHEMA_VERSION: i32 = 5;
 const SCHEMA_SQL: &str = include_str!("schema.sql");
 
+/// Virtual machine instructions between checks of a connection's query
+/// deadline.
+const DEADLINE_CHECK_OPS: i32 = 1_000;
+
+/// A column added after its table was first created
+struct AddedColumn {
+    /// Schema version that introduced the column
+    version: i32,
+    table: &'static str,
+    column: &'static str,
+    /// `ALTER TABLE ... ADD COLUMN` definition, including the default
+    definition: &'static str,
+}
+
+/// Columns added after a table was first created. New databases get them
+/// from `schema.sql`; existing ones are altered in place, and
+/// [`DbManager::downgrade_to`] drops them again.
+const ADDED_COLUMNS: &[AddedColumn] = &[
+    AddedColumn {
+        version: 2,
+        table: "activity_snapshots",
+        column: "content_hash",
+        definition: "content_hash TEXT",
+    },
+    AddedColumn {
+        version: 3,
+        table: "user_profiles",
+        column: "workday_start_minutes",
+        definition: "workday_start_minutes INTEGER NOT NULL DEFAULT 480",
+    },
+    AddedColumn {
+        version: 3,
+        table: "user_profiles",
+        column: "workday_end_minutes",
+        definition: "workday_end_minutes INTEGER NOT NULL DEFAULT 1080",
+    },
+    AddedColumn {
+        version: 3,
+        table: "user_profiles",
+        column: "working_days",
+        definition: "working_days TEXT NOT NULL DEFAULT '1,2,3,4,5'",
+    },
+    AddedColumn {
+        version: 5,
+        table: "user_profiles",
+        column: "idle_enter_secs",
+        definition: "idle_enter_secs INTEGER NOT NULL DEFAULT 300",
+    },
+    AddedColumn {
+        version: 5,
+        table: "user_profiles",
+        column: "idle_exit_secs",
+        definition: "idle_exit_secs INTEGER NOT NULL DEFAULT 30",
+    },
+];
+
+/// Outcome of [`DbManager::checkpoint_wal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WalCheckpoint {
+    /// A reader or writer kept the checkpoint from completing
+    pub busy: bool,
+    /// Frames in the WAL before the checkpoint (-1 when not in WAL mode)
+    pub log_frames: i64,
+    /// Frames copied back into the database (-1 when not in WAL mode)
+    pub checkpointed_frames: i64,
+}
+
+/// Lock and query time limits applied by [`DbManager`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DbTimeoutConfig {
+    /// How long a statement waits for a locked database (`PRAGMA
+    /// busy_timeout`) before failing with `SQLITE_BUSY`.
+    pub busy_timeout: Duration,
+    /// How long work on a connection from [`DbManager::get_connection`] (or
+    /// [`DbManager::run_with_timeout`]) may run; statements still running on
+    /// expiry are interrupted.
+    pub query_timeout: Duration,
+}
+
+impl DbTimeoutConfig {
+    /// Timeouts from the `database` config section, with defaults for the
+    /// fields left unset.
+    pub fn from_database_config(config: &DatabaseConfig) -> Self {
+        let defaults = Self::default();
+        Self {
+            busy_timeout: config
+                .busy_timeout_ms
+                .map_or(defaults.busy_timeout, Duration::from_millis),
+            query_timeout: config
+                .query_timeout_ms
+                .map_or(defaults.query_timeout, Duration::from_millis),
+        }
+    }
+}
+
+impl Default for DbTimeoutConfig {
+    fn default() -> Self {
+        Self { busy_timeout: Duration::from_secs(5), query_timeout: Duration::from_secs(30) }
+    }
+}
+
 /// Database manager that wraps an [`SqlCipherPool`].
 pub struct DbManager {
     pool: Arc<SqlCipherPool>,
     path: PathBuf,
+    query_timeout: Duration,
 }
 
 impl DbManager {
@@ -36,6 +148,16 @@ impl DbManager {
         db_path: P,
         pool_size: u32,
         encryption_key: Option<&str>,
+    ) -> Result<Self> {
+        Self::with_timeouts(db_path, pool_size, encryption_key, DbTimeoutConfig::default())
+    }
+
+    /// Create a new manager with custom busy and query timeouts.
+    pub fn with_timeouts<P: AsRef<Path>>(
+        db_path: P,
+        pool_size: u32,
+        encryption_key: Option<&str>,
+        timeouts: DbTimeoutConfig,
     ) -> Result<Self> {
         let key = encryption_key.map(std::borrow::ToOwned::to_owned).ok_or_else(|| {
             PulseArcError::Security("database encryption key not provided".into())
@@ -43,18 +165,80 @@ impl DbManager {
 
         let path = db_path.as_ref().to_path_buf();
 
-        let config =
-            SqlCipherPoolConfig { max_size: pool_size.max(1), ..SqlCipherPoolConfig::default() };
+        let config = SqlCipherPoolConfig {
+            max_size: pool_size.max(1),
+            busy_timeout: timeouts.busy_timeout,
+            ..SqlCipherPoolConfig::default()
+        };
 
         let pool = create_sqlcipher_pool(&path, key, config)?;
 
         info!(
             db_path = %path.display(),
             max_connections = pool.metrics().max_pool_size(),
+            busy_timeout_ms = timeouts.busy_timeout.as_millis() as u64,
+            query_timeout_ms = timeouts.query_timeout.as_millis() as u64,
             "sqlcipher pool initialised"
         );
 
-        Ok(Self { pool, path })
+        Ok(Self { pool, path, query_timeout: timeouts.query_timeout })
+    }
+
+    /// Configured limit for work on a connection from [`Self::get_connection`].
+    pub fn query_timeout(&self) -> Duration {
+        self.query_timeout
+    }
+
+    /// Run `operation` on a pooled connection off the async runtime, giving up
+    /// after the configured query timeout.
+    ///
+    /// The connection is acquired on the blocking thread, so waiting for the
+    /// pool counts against the timeout without stalling the runtime. On expiry
+    /// the running statement is interrupted (`sqlite3_interrupt`) so the
+    /// connection returns to the pool, an operation still waiting for a
+    /// connection is skipped, and a `PulseArcError::Database` naming the
+    /// timeout is returned instead of waiting for the query.
+    pub async fn run_with_timeout<T, F>(&self, operation: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&SqlCipherConnection) -> Result<T> + Send + 'static,
+    {
+        let pool = Arc::clone(&self.pool);
+        let query_timeout = self.query_timeout;
+        let abandoned = Arc::new(AtomicBool::new(false));
+        let (interrupt_tx, mut interrupt_rx) = oneshot::channel();
+
+        let task = tokio::task::spawn_blocking(correlation::propagate({
+            let abandoned = Arc::clone(&abandoned);
+            move || {
+                // No deadline of its own: the timeout below interrupts it
+                let conn = checkout(&pool, None)?;
+                let _ = interrupt_tx.send(conn.get_interrupt_handle());
+                // Checked after publishing the handle: either the caller sees
+                // the handle and interrupts, or this sees the flag and skips
+                if abandoned.load(Ordering::SeqCst) {
+                    return Err(PulseArcError::Database(
+                        "query abandoned after timeout".to_string(),
+                    ));
+                }
+                operation(&conn)
+            }
+        }));
+
+        match tokio::time::timeout(query_timeout, task).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(err)) => Err(PulseArcError::Internal(format!("database task failed: {err}"))),
+            Err(_) => {
+                abandoned.store(true, Ordering::SeqCst);
+                if let Ok(interrupt) = interrupt_rx.try_recv() {
+                    interrupt.interrupt();
+                }
+                let timeout_ms = self.query_timeout.as_millis() as u64;
+                let correlation_id = correlation::current_correlation_id();
+                warn!(timeout_ms, correlation_id, "database query timed out; interrupted");
+                Err(PulseArcError::Database(format!("query timed out after {timeout_ms}ms")))
+            }
+        }
     }
 
     /// Borrow the underlying SQLCipher pool.
@@ -63,22 +247,106 @@ impl DbManager {
     }
 
     /// Acquire a SQLCipher connection from the pool.
+    ///
+    /// Statements on the connection are interrupted once the configured query
+    /// timeout has passed since checkout, so hold it for one unit of work and
+    /// use [`Self::get_maintenance_connection`] for work that runs long by
+    /// design. The connection's prepared-statement cache is sized to
+    /// [`STATEMENT_CACHE_CAPACITY`](super::statement_cache::STATEMENT_CACHE_CAPACITY).
     pub fn get_connection(&self) -> Result<SqlCipherConnection> {
-        self.pool.get_sqlcipher_connection().map_err(map_storage_error)
+        checkout(&self.pool, Some(self.query_timeout))
+    }
+
+    /// Acquire a connection without a query deadline, for maintenance such as
+    /// VACUUM, integrity checks and migrations that scan the whole database.
+    pub fn get_maintenance_connection(&self) -> Result<SqlCipherConnection> {
+        checkout(&self.pool, None)
     }
 
     /// Ensure the full schema exists on the current database.
     pub fn run_migrations(&self) -> Result<()> {
-        let conn = self.get_connection()?;
+        let conn = self.get_maintenance_connection()?;
         create_schema(&conn)?;
         Ok(())
     }
 
+    /// Highest schema version recorded in `schema_version` (0 for a database
+    /// that has never been migrated).
+    pub fn schema_version(&self) -> Result<i32> {
+        let conn = self.get_connection()?;
+        current_schema_version(&conn)
+    }
+
+    /// Revert column migrations newer than `version`.
+    ///
+    /// Drops the columns those versions added (and any index on them) and
+    /// removes their `schema_version` rows in one transaction, leaving
+    /// existing rows otherwise intact. Tables created by later versions are
+    /// left in place; older builds ignore them. Running it again, or with a
+    /// `version` at or above the current one, is a no-op.
+    pub fn downgrade_to(&self, version: i32) -> Result<()> {
+        let conn = self.get_maintenance_connection()?;
+        conn.execute_batch("BEGIN IMMEDIATE").map_err(map_sql_error)?;
+        match drop_columns_after(&conn, version) {
+            Ok(()) => conn.execute_batch("COMMIT").map_err(map_sql_error),
+            Err(err) => {
+                if let Err(rollback) = conn.execute_batch("ROLLBACK") {
+                    warn!(error = %rollback, "failed to roll back schema downgrade");
+                }
+                Err(err)
+            }
+        }
+    }
+
     /// Return the configured database path.
     pub fn path(&self) -> &Path {
         &self.path
     }
 
+    /// Return the path of the write-ahead log next to the database file.
+    pub fn wal_path(&self) -> PathBuf {
+        let mut wal = self.path.clone().into_os_string();
+        wal.push("-wal");
+        PathBuf::from(wal)
+    }
+
+    /// Current size of the write-ahead log in bytes (0 when there is none).
+    pub fn wal_size_bytes(&self) -> u64 {
+        std::fs::metadata(self.wal_path()).map(|meta| meta.len()).unwrap_or(0)
+    }
+
+    /// Copy the write-ahead log into the database and truncate it to zero
+    /// bytes.
+    ///
+    /// Runs `PRAGMA wal_checkpoint(TRUNCATE)`, which waits (up to the busy
+    /// timeout) for readers and writers; call it off the async runtime.
+    pub fn checkpoint_wal(&self) -> Result<WalCheckpoint> {
+        let conn = self.get_maintenance_connection()?;
+        conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", params![], |row| {
+            Ok(WalCheckpoint {
+                busy: row.get::<_, i64>(0)? != 0,
+                log_frames: row.get(1)?,
+                checkpointed_frames: row.get(2)?,
+            })
+        })
+        .map_err(map_storage_error)
+    }
+
+    /// Run `PRAGMA integrity_check` and `PRAGMA foreign_key_check`.
+    ///
+    /// Reads every page of the database; call it off the async runtime.
+    pub fn integrity_check(&self) -> Result<IntegrityReport> {
+        let conn = self.get_maintenance_connection()?;
+        integrity::integrity_check(&conn)
+    }
+
+    /// Copy everything still readable into a new database at `target`,
+    /// encrypted with the same key (see [`super::integrity`]).
+    pub fn recover_to(&self, target: &Path) -> Result<RecoveryReport> {
+        let conn = self.get_maintenance_connection()?;
+        integrity::recover_to(&conn, target)
+    }
+
     /// Perform a health check to verify database connectivity.
     ///
     /// This method acquires a connection from the pool and executes a simple
@@ -92,7 +360,21 @@ impl DbManager {
     }
 }
 
+/// Take a connection from `pool`, interrupting its statements once
+/// `query_timeout` has passed.
+fn checkout(pool: &SqlCipherPool, query_timeout: Option<Duration>) -> Result<SqlCipherConnection> {
+    let conn = pool.get_sqlcipher_connection().map_err(map_storage_error)?;
+    statement_cache::configure(&conn);
+    if let Some(timeout) = query_timeout {
+        let deadline = Instant::now() + timeout;
+        conn.progress_handler(DEADLINE_CHECK_OPS, Some(move || Instant::now() >= deadline));
+    }
+    Ok(conn)
+}
+
 fn create_schema(conn: &SqlCipherConnection) -> Result<()> {
+    enable_incremental_vacuum(conn)?;
+    add_missing_columns(conn)?;
     conn.execute_batch(SCHEMA_SQL).map_err(map_sql_error)?;
     conn.execute(
         "INSERT OR IGNORE INTO schema_version (version, applied_at) VALUES (?, CAST(strftime('%s','now') AS INTEGER))",
@@ -102,6 +384,107 @@ fn create_schema(conn: &SqlCipherConnection) -> Result<()> {
     Ok(())
 }
 
+/// Switch a new, empty database to `auto_vacuum = INCREMENTAL`.
+///
+/// The mode only sticks after a VACUUM once WAL has written the header, which
+/// is cheap while the database has no tables. Existing databases are converted
+/// by the next full vacuum instead.
+fn enable_incremental_vacuum(conn: &SqlCipherConnection) -> Result<()> {
+    let tables: i64 = conn
+        .query_row("SELECT COUNT(*) FROM sqlite_master WHERE type = 'table'", params![], |row| {
+            row.get(0)
+        })
+        .map_err(map_storage_error)?;
+    if tables == 0 {
+        conn.execute_batch("PRAGMA auto_vacuum = INCREMENTAL; VACUUM;").map_err(map_sql_error)?;
+    }
+    Ok(())
+}
+
+/// Add `ADDED_COLUMNS` to tables that exist but predate them.
+///
+/// Tables that do not exist yet are skipped; `schema.sql` creates them with
+/// the column.
+fn add_missing_columns(conn: &SqlCipherConnection) -> Result<()> {
+    for AddedColumn { table, column, definition, .. } in ADDED_COLUMNS {
+        if !table_exists(conn, table)? {
+            continue;
+        }
+        if !column_exists(conn, table, column)? {
+            conn.execute(&format!("ALTER TABLE {table} ADD COLUMN {definition}"), params![])
+                .map_err(map_sql_error)?;
+            info!(table, column, "added column to existing table");
+        }
+    }
+    Ok(())
+}
+
+/// Drop `ADDED_COLUMNS` introduced after `version`, newest first, and forget
+/// the versions that added them.
+fn drop_columns_after(conn: &SqlCipherConnection, version: i32) -> Result<()> {
+    for AddedColumn { table, column, .. } in
+        ADDED_COLUMNS.iter().rev().filter(|added| added.version > version)
+    {
+        if !column_exists(conn, table, column)? {
+            continue;
+        }
+        let indexes: Vec<String> = conn
+            .prepare(
+                "SELECT DISTINCT il.name FROM pragma_index_list(?1) il
+                 JOIN pragma_index_info(il.name) ii
+                 WHERE ii.name = ?2 AND il.origin = 'c'",
+            )
+            .and_then(|mut stmt| stmt.query_map(params![table, column], |row| row.get(0)))
+            .map_err(map_storage_error)?;
+        for index in indexes {
+            conn.execute(&format!("DROP INDEX IF EXISTS \"{index}\""), params![])
+                .map_err(map_sql_error)?;
+        }
+        conn.execute(&format!("ALTER TABLE {table} DROP COLUMN {column}"), params![])
+            .map_err(map_sql_error)?;
+        info!(table, column, "dropped column during schema downgrade");
+    }
+
+    conn.execute("DELETE FROM schema_version WHERE version > ?1", params![version])
+        .map_err(map_sql_error)?;
+    if current_schema_version(conn)? < version {
+        conn.execute(
+            "INSERT OR IGNORE INTO schema_version (version, applied_at) VALUES (?, CAST(strftime('%s','now') AS INTEGER))",
+            params![version],
+        )
+        .map_err(map_sql_error)?;
+    }
+    Ok(())
+}
+
+fn current_schema_version(conn: &SqlCipherConnection) -> Result<i32> {
+    if !table_exists(conn, "schema_version")? {
+        return Ok(0);
+    }
+    conn.query_row("SELECT COALESCE(MAX(version), 0) FROM schema_version", params![], |row| {
+        row.get(0)
+    })
+    .map_err(map_storage_error)
+}
+
+fn table_exists(conn: &SqlCipherConnection, table: &str) -> Result<bool> {
+    let columns: i64 = conn
+        .query_row("SELECT COUNT(*) FROM pragma_table_info(?1)", params![table], |row| row.get(0))
+        .map_err(map_storage_error)?;
+    Ok(columns > 0)
+}
+
+fn column_exists(conn: &SqlCipherConnection, table: &str, column: &str) -> Result<bool> {
+    let present: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM pragma_table_info(?1) WHERE name = ?2",
+            params![table, column],
+            |row| row.get(0),
+        )
+        .map_err(map_storage_error)?;
+    Ok(present > 0)
+}
+
 fn map_sql_error(err: rusqlite::Error) -> PulseArcError {
     PulseArcError::from(InfraError::from(err))
 }
@@ -112,6 +495,7 @@ fn map_storage_error(err: StorageError) -> PulseArcError {
 
 #[cfg(test)]
 mod tests {
+    use pulsearc_domain::DbOpenError;
     use tempfile::TempDir;
 
     use super::*;
@@ -132,6 +516,359 @@ mod tests {
         assert_eq!(version, SCHEMA_VERSION);
     }
 
+    #[test]
+    fn migrations_add_columns_to_existing_tables() {
+        let temp_dir = TempDir::new().expect("temp dir created");
+        let db_path = temp_dir.path().join("test.db");
+
+        let manager = DbManager::new(&db_path, 4, Some(TEST_KEY)).expect("manager created");
+        {
+            let conn = manager.get_connection().expect("connection acquired");
+            conn.execute_batch(
+                "CREATE TABLE activity_snapshots (
+                    id TEXT PRIMARY KEY,
+                    timestamp INTEGER NOT NULL,
+                    activity_context_json TEXT NOT NULL,
+                    detected_activity TEXT NOT NULL,
+                    work_type TEXT,
+                    activity_category TEXT,
+                    primary_app TEXT NOT NULL,
+                    processed BOOLEAN NOT NULL DEFAULT 0,
+                    batch_id TEXT,
+                    created_at INTEGER NOT NULL,
+                    processed_at INTEGER,
+                    is_idle INTEGER NOT NULL DEFAULT 0,
+                    idle_duration_secs INTEGER
+                );",
+            )
+            .expect("legacy table created");
+        }
+
+        manager.run_migrations().expect("migrations run");
+        // Re-running is a no-op
+        manager.run_migrations().expect("migrations re-run");
+
+        let conn = manager.get_connection().expect("connection acquired");
+        let has_column: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('activity_snapshots')
+                 WHERE name = 'content_hash'",
+                &[],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(has_column, 1);
+    }
+
+    /// Database with the version 4 `user_profiles` table and one profile
+    fn version_4_database(temp_dir: &TempDir) -> DbManager {
+        let manager = DbManager::new(temp_dir.path().join("test.db"), 4, Some(TEST_KEY))
+            .expect("manager created");
+        let conn = manager.get_connection().expect("connection acquired");
+        conn.execute_batch(
+            "CREATE TABLE user_profiles (
+                id TEXT NOT NULL PRIMARY KEY,
+                auth0_id TEXT NOT NULL UNIQUE,
+                email TEXT NOT NULL UNIQUE,
+                org_id TEXT NOT NULL DEFAULT 'default_org',
+                name TEXT,
+                first_name TEXT,
+                last_name TEXT,
+                display_name TEXT,
+                avatar_url TEXT,
+                phone_number TEXT,
+                title TEXT,
+                department TEXT,
+                location TEXT,
+                bio TEXT,
+                timezone TEXT NOT NULL,
+                language TEXT NOT NULL,
+                locale TEXT NOT NULL,
+                date_format TEXT NOT NULL,
+                is_active INTEGER NOT NULL DEFAULT 1,
+                email_verified INTEGER NOT NULL DEFAULT 0,
+                two_factor_enabled INTEGER NOT NULL DEFAULT 0,
+                last_login_at INTEGER NOT NULL,
+                last_synced_at INTEGER NOT NULL,
+                created_at INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL,
+                workday_start_minutes INTEGER NOT NULL DEFAULT 480,
+                workday_end_minutes INTEGER NOT NULL DEFAULT 1080,
+                working_days TEXT NOT NULL DEFAULT '1,2,3,4,5'
+            );
+            CREATE TABLE schema_version (version INTEGER PRIMARY KEY, applied_at INTEGER NOT NULL);
+            INSERT INTO schema_version (version, applied_at) VALUES (4, 1700000000);
+            INSERT INTO user_profiles (id, auth0_id, email, timezone, language, locale,
+                                       date_format, last_login_at, last_synced_at, created_at,
+                                       updated_at, workday_start_minutes)
+            VALUES ('user-1', 'auth0|1', 'ada@example.com', 'Europe/London', 'en', 'en-GB',
+                    'DD/MM/YYYY', 1, 2, 3, 4, 540);",
+        )
+        .expect("version 4 schema created");
+        drop(conn);
+        manager
+    }
+
+    fn table_sql(manager: &DbManager) -> Vec<String> {
+        let conn = manager.get_connection().expect("connection acquired");
+        let mut stmt = conn
+            .prepare("SELECT sql FROM sqlite_master WHERE sql IS NOT NULL ORDER BY name")
+            .expect("statement prepared");
+        stmt.query_map(&[], |row| row.get(0)).expect("schema listed")
+    }
+
+    #[test]
+    fn migrating_version_4_adds_idle_columns_with_defaults() {
+        let temp_dir = TempDir::new().expect("temp dir created");
+        let manager = version_4_database(&temp_dir);
+
+        manager.run_migrations().expect("migrations run");
+        let migrated = table_sql(&manager);
+        manager.run_migrations().expect("migrations re-run");
+
+        assert_eq!(table_sql(&manager), migrated, "re-running is a no-op");
+        assert_eq!(manager.schema_version().expect("version read"), SCHEMA_VERSION);
+
+        let conn = manager.get_connection().expect("connection acquired");
+        let (email, workday_start, enter, exit): (String, i64, i64, i64) = conn
+            .query_row(
+                "SELECT email, workday_start_minutes, idle_enter_secs, idle_exit_secs
+                 FROM user_profiles WHERE id = 'user-1'",
+                &[],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+            )
+            .unwrap();
+        assert_eq!(email, "ada@example.com");
+        assert_eq!(workday_start, 540);
+        assert_eq!((enter, exit), (300, 30));
+    }
+
+    #[test]
+    fn downgrade_drops_newer_columns_and_keeps_rows() {
+        let temp_dir = TempDir::new().expect("temp dir created");
+        let manager = version_4_database(&temp_dir);
+        manager.run_migrations().expect("migrations run");
+
+        manager.downgrade_to(4).expect("downgraded");
+        manager.downgrade_to(4).expect("repeated downgrade is a no-op");
+
+        assert_eq!(manager.schema_version().expect("version read"), 4);
+        let conn = manager.get_connection().expect("connection acquired");
+        let idle_columns: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('user_profiles')
+                 WHERE name IN ('idle_enter_secs', 'idle_exit_secs')",
+                &[],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(idle_columns, 0);
+        let workday_start: i64 = conn
+            .query_row(
+                "SELECT workday_start_minutes FROM user_profiles WHERE id = 'user-1'",
+                &[],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(workday_start, 540);
+        drop(conn);
+
+        manager.run_migrations().expect("upgrade again");
+        assert_eq!(manager.schema_version().expect("version read"), SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn migrations_enable_incremental_vacuum_on_new_databases() {
+        let temp_dir = TempDir::new().expect("temp dir created");
+        let db_path = temp_dir.path().join("test.db");
+
+        let manager = DbManager::new(&db_path, 4, Some(TEST_KEY)).expect("manager created");
+        manager.run_migrations().expect("migrations run");
+
+        let conn = manager.get_connection().expect("connection acquired");
+        let auto_vacuum: i64 = conn.query_row("PRAGMA auto_vacuum", &[], |row| row.get(0)).unwrap();
+        assert_eq!(auto_vacuum, 2, "new databases use auto_vacuum = INCREMENTAL");
+    }
+
+    fn short_timeouts() -> DbTimeoutConfig {
+        DbTimeoutConfig {
+            busy_timeout: Duration::from_millis(250),
+            query_timeout: Duration::from_millis(200),
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn long_query_is_interrupted_at_query_timeout() {
+        let temp_dir = TempDir::new().expect("temp dir created");
+        let db_path = temp_dir.path().join("test.db");
+        // A single connection: the follow-up query only succeeds if the
+        // interrupted one released it
+        let manager = DbManager::with_timeouts(&db_path, 1, Some(TEST_KEY), short_timeouts())
+            .expect("manager created");
+
+        let started = std::time::Instant::now();
+        let err = manager
+            .run_with_timeout(|conn| {
+                conn.query_row(
+                    "WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c)
+                     SELECT COUNT(*) FROM c",
+                    params![],
+                    |row| row.get::<_, i64>(0),
+                )
+                .map_err(map_storage_error)
+            })
+            .await
+            .expect_err("unbounded query times out");
+
+        assert!(
+            matches!(err, PulseArcError::Database(ref msg) if msg.contains("timed out after 200ms")),
+            "unexpected error: {err:?}"
+        );
+        assert!(started.elapsed() < Duration::from_secs(2), "returned at the timeout");
+
+        let value = manager
+            .run_with_timeout(|conn| {
+                conn.query_row("SELECT 1", params![], |row| row.get::<_, i32>(0))
+                    .map_err(map_storage_error)
+            })
+            .await
+            .expect("connection is usable after the interrupt");
+        assert_eq!(value, 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn normal_queries_are_unaffected_by_query_timeout() {
+        let temp_dir = TempDir::new().expect("temp dir created");
+        let db_path = temp_dir.path().join("test.db");
+        let manager = DbManager::with_timeouts(&db_path, 2, Some(TEST_KEY), short_timeouts())
+            .expect("manager created");
+        manager.run_migrations().expect("migrations run");
+
+        let versions = manager
+            .run_with_timeout(|conn| {
+                conn.query_row("SELECT COUNT(*) FROM schema_version", params![], |row| {
+                    row.get::<_, i64>(0)
+                })
+                .map_err(map_storage_error)
+            })
+            .await
+            .expect("query completes");
+
+        assert_eq!(versions, 1);
+        assert_eq!(manager.query_timeout(), Duration::from_millis(200));
+    }
+
+    #[test]
+    fn busy_timeout_is_applied_to_connections() {
+        let temp_dir = TempDir::new().expect("temp dir created");
+        let db_path = temp_dir.path().join("test.db");
+        let manager = DbManager::with_timeouts(&db_path, 1, Some(TEST_KEY), short_timeouts())
+            .expect("manager created");
+
+        let conn = manager.get_connection().expect("connection acquired");
+        let busy_timeout: i64 =
+            conn.query_row("PRAGMA busy_timeout", &[], |row| row.get(0)).unwrap();
+
+        assert_eq!(busy_timeout, 250);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn operation_abandoned_while_waiting_for_pool_is_skipped() {
+        let temp_dir = TempDir::new().expect("temp dir created");
+        let db_path = temp_dir.path().join("test.db");
+        let manager = DbManager::with_timeouts(&db_path, 1, Some(TEST_KEY), short_timeouts())
+            .expect("manager created");
+        let held = manager.get_maintenance_connection().expect("connection acquired");
+
+        let ran = Arc::new(AtomicBool::new(false));
+        let started = std::time::Instant::now();
+        let err = manager
+            .run_with_timeout({
+                let ran = Arc::clone(&ran);
+                move |_| {
+                    ran.store(true, Ordering::SeqCst);
+                    Ok(())
+                }
+            })
+            .await
+            .expect_err("waiting for the pool times out");
+
+        assert!(
+            matches!(err, PulseArcError::Database(ref msg) if msg.contains("timed out after 200ms")),
+            "unexpected error: {err:?}"
+        );
+        assert!(started.elapsed() < Duration::from_secs(2), "returned at the timeout");
+
+        drop(held);
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(!ran.load(Ordering::SeqCst), "abandoned operation never runs");
+    }
+
+    const UNBOUNDED_COUNT: &str = "WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c)
+                                   SELECT COUNT(*) FROM c";
+    const BOUNDED_COUNT: &str =
+        "WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c WHERE x < 100000)
+         SELECT COUNT(*) FROM c";
+
+    #[test]
+    fn pooled_connection_interrupts_work_past_query_timeout() {
+        let temp_dir = TempDir::new().expect("temp dir created");
+        let db_path = temp_dir.path().join("test.db");
+        let manager = DbManager::with_timeouts(&db_path, 1, Some(TEST_KEY), short_timeouts())
+            .expect("manager created");
+
+        let conn = manager.get_connection().expect("connection acquired");
+        let started = std::time::Instant::now();
+        let err = conn
+            .query_row(UNBOUNDED_COUNT, &[], |row| row.get::<_, i64>(0))
+            .expect_err("unbounded query is interrupted");
+        assert!(err.to_string().contains("interrupted"), "unexpected error: {err}");
+        assert!(started.elapsed() < Duration::from_secs(2), "interrupted at the timeout");
+        drop(conn);
+
+        // The next checkout of the same connection gets a fresh deadline
+        let conn = manager.get_connection().expect("connection acquired");
+        let count: i64 = conn.query_row(BOUNDED_COUNT, &[], |row| row.get(0)).unwrap();
+        assert_eq!(count, 100_000);
+    }
+
+    #[test]
+    fn query_deadline_does_not_outlive_its_checkout() {
+        let temp_dir = TempDir::new().expect("temp dir created");
+        let db_path = temp_dir.path().join("test.db");
+        let manager = DbManager::with_timeouts(&db_path, 1, Some(TEST_KEY), short_timeouts())
+            .expect("manager created");
+        drop(manager.get_connection().expect("connection acquired"));
+        std::thread::sleep(Duration::from_millis(300));
+
+        // Repositories that check out from the pool directly are unaffected
+        let conn = manager.pool().get_sqlcipher_connection().expect("connection acquired");
+        let count: i64 = conn.query_row(BOUNDED_COUNT, &[], |row| row.get(0)).unwrap();
+        assert_eq!(count, 100_000);
+        drop(conn);
+
+        let conn = manager.get_maintenance_connection().expect("connection acquired");
+        std::thread::sleep(Duration::from_millis(300));
+        let count: i64 = conn.query_row(BOUNDED_COUNT, &[], |row| row.get(0)).unwrap();
+        assert_eq!(count, 100_000, "maintenance connections have no deadline");
+    }
+
+    #[test]
+    fn timeouts_come_from_database_config() {
+        let mut config = pulsearc_domain::Config::default().database;
+        assert_eq!(DbTimeoutConfig::from_database_config(&config), DbTimeoutConfig::default());
+
+        config.busy_timeout_ms = Some(1_500);
+        config.query_timeout_ms = Some(10_000);
+        assert_eq!(
+            DbTimeoutConfig::from_database_config(&config),
+            DbTimeoutConfig {
+                busy_timeout: Duration::from_millis(1_500),
+                query_timeout: Duration::from_secs(10),
+            }
+        );
+    }
+
     #[test]
     fn health_check_succeeds_for_valid_database() {
         let temp_dir = TempDir::new().expect("temp dir created");
@@ -153,4 +890,71 @@ mod tests {
         let result = DbManager::new(&db_path, 4, None);
         assert!(result.is_err());
     }
+
+    /// Create a migrated database with enough rows to span many pages, then
+    /// close it.
+    fn create_populated_database(db_path: &Path) {
+        let manager = DbManager::new(db_path, 1, Some(TEST_KEY)).expect("manager created");
+        manager.run_migrations().expect("migrations run");
+        let conn = manager.get_connection().expect("connection acquired");
+        conn.execute_batch(
+            "WITH RECURSIVE seq(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM seq WHERE n < 200)
+             INSERT INTO activity_snapshots (id, timestamp, activity_context_json, detected_activity, primary_app, processed, created_at, is_idle)
+             SELECT 'snap-' || n, 1700000000 + n, hex(randomblob(1000)), 'working', 'VSCode', 0, 1700000000, 0
+             FROM seq",
+        )
+        .expect("snapshots inserted");
+    }
+
+    #[test]
+    fn open_with_wrong_key_reports_key_mismatch() {
+        let temp_dir = TempDir::new().expect("temp dir created");
+        let db_path = temp_dir.path().join("test.db");
+        create_populated_database(&db_path);
+
+        let wrong_key = "wrong_key_64_chars_long_bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb";
+        let result = DbManager::new(&db_path, 1, Some(wrong_key));
+
+        assert!(
+            matches!(result, Err(PulseArcError::DatabaseOpen(DbOpenError::KeyMismatch(_)))),
+            "unexpected result: {:?}",
+            result.err()
+        );
+    }
+
+    #[test]
+    fn open_truncated_file_reports_corrupt() {
+        let temp_dir = TempDir::new().expect("temp dir created");
+        let db_path = temp_dir.path().join("test.db");
+        create_populated_database(&db_path);
+
+        // Keep whole pages so the damage is only visible once decrypted
+        let file = std::fs::OpenOptions::new().write(true).open(&db_path).expect("file opened");
+        let len = file.metadata().expect("metadata").len();
+        file.set_len(len / 2 / 4096 * 4096).expect("file truncated");
+        drop(file);
+
+        let result = DbManager::new(&db_path, 1, Some(TEST_KEY));
+
+        assert!(
+            matches!(result, Err(PulseArcError::DatabaseOpen(DbOpenError::Corrupt(_)))),
+            "unexpected result: {:?}",
+            result.err()
+        );
+    }
+
+    #[test]
+    fn open_partial_page_reports_corrupt() {
+        let temp_dir = TempDir::new().expect("temp dir created");
+        let db_path = temp_dir.path().join("test.db");
+        create_populated_database(&db_path);
+
+        let file = std::fs::OpenOptions::new().write(true).open(&db_path).expect("file opened");
+        file.set_len(4096 * 3 + 100).expect("file truncated");
+        drop(file);
+
+        let result = DbManager::new(&db_path, 1, Some(TEST_KEY));
+
+        assert!(matches!(result, Err(PulseArcError::DatabaseOpen(DbOpenError::Corrupt(_)))));
+    }
 }
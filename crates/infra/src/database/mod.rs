@@ -1,39 +1,63 @@
 //! Database implementations
 
 pub mod activity_repository;
+pub mod app_category_rule_repository;
 pub mod batch_repository;
 pub mod block_repository;
 #[cfg(feature = "calendar")]
 pub mod calendar_event_repository;
 pub mod command_metrics_repository;
+pub mod configuration_repository;
 pub mod database_stats_repository;
+#[cfg(feature = "demo-seed")]
+pub mod demo_seed_repository;
 pub mod dlq_repository;
 pub mod feature_flags_repository;
 pub mod id_mapping_repository;
 pub mod idle_periods_repository;
+pub mod integrity;
 pub mod manager;
+pub mod outbox_compaction;
 pub mod outbox_repository;
+pub mod project_acceptance_repository;
 pub mod repository;
 pub mod segment_repository;
 pub mod sqlcipher_pool;
+pub mod statement_cache;
+pub mod suggestion_batch_repository;
+pub mod suggestion_dismissal_repository;
 pub mod token_usage_repository;
 pub mod user_profile_repository;
+pub mod user_settings_repository;
+pub mod wal_checkpoint;
+pub mod wbs_repository;
 
 pub use activity_repository::*;
+pub use app_category_rule_repository::SqlCipherAppCategoryRuleRepository;
 pub use batch_repository::*;
 pub use block_repository::*;
 #[cfg(feature = "calendar")]
 pub use calendar_event_repository::*;
 pub use command_metrics_repository::SqlCipherCommandMetricsRepository;
+pub use configuration_repository::SqlCipherConfigurationRepository;
 pub use database_stats_repository::SqlCipherDatabaseStatsRepository;
+#[cfg(feature = "demo-seed")]
+pub use demo_seed_repository::SqlCipherDemoSeedRepository;
 pub use dlq_repository::*;
 pub use feature_flags_repository::SqlCipherFeatureFlagsRepository;
 pub use id_mapping_repository::*;
 pub use idle_periods_repository::*;
 pub use manager::*;
+pub use outbox_compaction::{OutboxCompactionPlan, OutboxCompactionReport};
 pub use outbox_repository::*;
+pub use project_acceptance_repository::SqlCipherProjectAcceptanceRepository;
 pub use repository::*;
 pub use segment_repository::*;
 pub use sqlcipher_pool::*;
+pub use suggestion_batch_repository::SqlCipherSuggestionBatchRepository;
+pub use suggestion_dismissal_repository::SqlCipherSuggestionDismissalRepository;
 pub use token_usage_repository::*;
 pub use user_profile_repository::*;
+pub use user_settings_repository::SqlCipherUserSettingsRepository;
+pub use wal_checkpoint::{WalCheckpointPolicy, WalCheckpointer};
+pub use wbs_repository::SqlCipherWbsRepository;
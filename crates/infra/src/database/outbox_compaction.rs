@@ -0,0 +1,216 @@
+//! Compaction of superseded outbox entries.
+//!
+//! Editing a time entry several times before the outbox flushes enqueues one
+//! outbox entry per edit, all carrying the same logical entity id (see
+//! [`TimeEntryOutbox::entity_id`]). Without compaction every stale version is
+//! forwarded in order. The planner below collapses those entries before a
+//! flush:
+//!
+//! - Multiple pending entries for the same `(target, entity_id)` collapse to
+//!   the latest version (highest `version`, then `created_at`, then `id`).
+//! - When the latest pending entry is a delete and the entity never synced (no
+//!   sent entry and no backend id), the create/edit/delete chain cancels out
+//!   and every pending entry for the entity is dropped.
+//!
+//! Entries without an entity id are never compacted. Entries that were already
+//! attempted (`attempts > 0`) may have reached the backend, so they are never
+//! removed and their entity is treated as synced: they are retried in place,
+//! and the dequeue query holds the entity's later entries behind them.
+
+use std::collections::{BTreeMap, HashSet};
+
+use pulsearc_domain::{OutboxStatus, TimeEntryOutbox};
+
+/// Key identifying a logical entity within a sync target.
+pub type OutboxEntityKey = (String, String);
+
+/// Outbox entry ids to remove before flushing.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OutboxCompactionPlan {
+    /// Pending entries replaced by a newer version of the same entity
+    pub superseded: Vec<String>,
+    /// Pending entries dropped because the entity was created and deleted
+    /// without ever syncing
+    pub cancelled: Vec<String>,
+}
+
+impl OutboxCompactionPlan {
+    /// Returns `true` when nothing needs to be removed.
+    pub fn is_empty(&self) -> bool {
+        self.superseded.is_empty() && self.cancelled.is_empty()
+    }
+
+    /// Iterate over every entry id scheduled for removal.
+    pub fn removed_ids(&self) -> impl Iterator<Item = &str> {
+        self.superseded.iter().chain(self.cancelled.iter()).map(String::as_str)
+    }
+}
+
+/// Outcome of a compaction pass.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OutboxCompactionReport {
+    /// Number of superseded entries removed
+    pub superseded: usize,
+    /// Number of entries removed by create/delete cancellation
+    pub cancelled: usize,
+}
+
+/// Build the entity key for an entry, if it carries an entity id.
+pub fn entity_key(entry: &TimeEntryOutbox) -> Option<OutboxEntityKey> {
+    entry.entity_id().map(|entity_id| (entry.target.to_ascii_lowercase(), entity_id))
+}
+
+/// Plan the compaction of `pending` entries.
+///
+/// `synced` holds the keys of entities that already reached the remote side
+/// (a sent entry or a backend id exists); deletes for those entities must
+/// still be forwarded. Entries whose status is not `pending` are ignored.
+pub fn plan_compaction(
+    pending: &[TimeEntryOutbox],
+    synced: &HashSet<OutboxEntityKey>,
+) -> OutboxCompactionPlan {
+    // BTreeMap keeps the plan deterministic regardless of input order
+    let mut groups: BTreeMap<OutboxEntityKey, Vec<&TimeEntryOutbox>> = BTreeMap::new();
+    for entry in pending.iter().filter(|entry| entry.status == OutboxStatus::Pending) {
+        if let Some(key) = entity_key(entry) {
+            groups.entry(key).or_default().push(entry);
+        }
+    }
+
+    let mut plan = OutboxCompactionPlan::default();
+    for (key, mut entries) in groups {
+        if entries.len() < 2 {
+            continue;
+        }
+
+        entries.sort_by(|a, b| {
+            a.version
+                .cmp(&b.version)
+                .then_with(|| a.created_at.cmp(&b.created_at))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+
+        let Some((latest, older)) = entries.split_last() else {
+            continue;
+        };
+
+        let never_synced = !synced.contains(&key)
+            && entries.iter().all(|entry| {
+                entry.backend_cuid.is_none() && entry.sent_at.is_none() && entry.attempts == 0
+            });
+
+        if latest.is_delete() && never_synced {
+            plan.cancelled.extend(entries.iter().map(|entry| entry.id.clone()));
+        } else {
+            plan.superseded.extend(
+                older.iter().filter(|entry| entry.attempts == 0).map(|entry| entry.id.clone()),
+            );
+        }
+    }
+
+    plan
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str, entity: &str, version: i32, payload_status: &str) -> TimeEntryOutbox {
+        TimeEntryOutbox {
+            id: id.into(),
+            idempotency_key: format!("{id}-idem"),
+            user_id: "user-1".into(),
+            payload_json: format!(r#"{{"id":"{entity}","status":"{payload_status}"}}"#),
+            backend_cuid: None,
+            status: OutboxStatus::Pending,
+            attempts: 0,
+            last_error: None,
+            retry_after: None,
+            created_at: 1_700_000_000 + i64::from(version),
+            sent_at: None,
+            correlation_id: None,
+            local_status: None,
+            remote_status: None,
+            sap_entry_id: None,
+            next_attempt_at: None,
+            error_code: None,
+            last_forwarded_at: None,
+            wbs_code: None,
+            target: "sap".into(),
+            description: None,
+            auto_applied: false,
+            version,
+            last_modified_by: "system".into(),
+            last_modified_at: None,
+        }
+    }
+
+    #[test]
+    fn keeps_only_latest_version_per_entity() {
+        let pending = vec![
+            entry("v2", "entry-1", 2, "draft"),
+            entry("v3", "entry-1", 3, "draft"),
+            entry("v1", "entry-1", 1, "draft"),
+            entry("other", "entry-2", 1, "draft"),
+        ];
+
+        let plan = plan_compaction(&pending, &HashSet::new());
+
+        assert_eq!(plan.superseded, vec!["v1".to_string(), "v2".to_string()]);
+        assert!(plan.cancelled.is_empty());
+    }
+
+    #[test]
+    fn cancels_create_delete_pair_for_unsynced_entity() {
+        let pending =
+            vec![entry("create", "entry-1", 1, "draft"), entry("delete", "entry-1", 2, "deleted")];
+
+        let plan = plan_compaction(&pending, &HashSet::new());
+
+        assert!(plan.superseded.is_empty());
+        assert_eq!(plan.cancelled, vec!["create".to_string(), "delete".to_string()]);
+    }
+
+    #[test]
+    fn keeps_delete_for_synced_entity() {
+        let pending =
+            vec![entry("edit", "entry-1", 2, "draft"), entry("delete", "entry-1", 3, "deleted")];
+        let synced = HashSet::from([("sap".to_string(), "entry-1".to_string())]);
+
+        let plan = plan_compaction(&pending, &synced);
+
+        assert_eq!(plan.superseded, vec!["edit".to_string()]);
+        assert!(plan.cancelled.is_empty());
+    }
+
+    #[test]
+    fn keeps_attempted_entries_and_their_deletes() {
+        let mut create = entry("create", "entry-1", 1, "draft");
+        create.attempts = 1;
+        let pending = vec![
+            create,
+            entry("edit-1", "entry-1", 2, "draft"),
+            entry("edit-2", "entry-1", 3, "draft"),
+            entry("delete", "entry-1", 4, "deleted"),
+        ];
+
+        let plan = plan_compaction(&pending, &HashSet::new());
+
+        // The create may have reached the backend, so the delete must follow it
+        assert_eq!(plan.superseded, vec!["edit-1".to_string(), "edit-2".to_string()]);
+        assert!(plan.cancelled.is_empty());
+    }
+
+    #[test]
+    fn ignores_entries_without_entity_id_and_single_entries() {
+        let mut anonymous_a = entry("anon-a", "", 1, "draft");
+        anonymous_a.payload_json = "{}".into();
+        let mut anonymous_b = entry("anon-b", "", 2, "draft");
+        anonymous_b.payload_json = "{}".into();
+        let pending = vec![anonymous_a, anonymous_b, entry("single", "entry-9", 1, "deleted")];
+
+        let plan = plan_compaction(&pending, &HashSet::new());
+
+        assert!(plan.is_empty());
+    }
+}
@@ -4,7 +4,12 @@
 //! dequeuing and updating outbox entries with retry bookkeeping. The
 //! implementation mirrors the legacy SQLite behaviour while adopting the
 //! new SQLCipher connection manager introduced in Phase 3A.
+//!
+//! Every dequeue first compacts superseded pending entries (see
+//! [`super::outbox_compaction`]) so only the latest version of an edited time
+//! entry is flushed.
 
+use std::collections::{BTreeSet, HashSet};
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -12,13 +17,20 @@ use chrono::Utc;
 use pulsearc_common::storage::error::StorageError;
 use pulsearc_common::storage::sqlcipher::SqlCipherConnection;
 use pulsearc_core::OutboxQueue as OutboxQueuePort;
-use pulsearc_domain::{OutboxStatus, PulseArcError, Result as DomainResult, TimeEntryOutbox};
+use pulsearc_domain::{
+    OutboxAgeBuckets, OutboxStatus, OutboxStatusSummary, PulseArcError, Result as DomainResult,
+    TimeEntryOutbox,
+};
 use rusqlite::{Row, ToSql};
 use tokio::task;
-use tracing::warn;
+use tracing::{info, warn};
 
 use super::manager::DbManager;
+use super::outbox_compaction::{
+    entity_key, plan_compaction, OutboxCompactionPlan, OutboxCompactionReport, OutboxEntityKey,
+};
 use crate::errors::InfraError;
+use crate::observability::correlation;
 
 const MAX_RETRY_ATTEMPTS: i32 = 5;
 const BASE_RETRY_DELAY_SECS: i64 = 60;
@@ -86,6 +98,83 @@ impl SqlCipherOutboxRepository {
         stmt.query_map(params.as_slice(), map_outbox_row).map_err(map_storage_error)
     }
 
+    fn compact(conn: &mut SqlCipherConnection) -> DomainResult<OutboxCompactionReport> {
+        let pending = {
+            let mut stmt = conn.prepare(OUTBOX_SELECT_PENDING_SQL).map_err(map_storage_error)?;
+            stmt.query_map(&[], map_outbox_row).map_err(map_storage_error)?
+        };
+
+        if pending.len() < 2 {
+            return Ok(OutboxCompactionReport::default());
+        }
+
+        // Only deletes can cancel out, so the sent history is looked up just
+        // for the entities being deleted
+        let deleted_ids: BTreeSet<String> = pending
+            .iter()
+            .filter(|entry| entry.is_delete())
+            .filter_map(TimeEntryOutbox::entity_id)
+            .collect();
+        let synced = Self::fetch_synced_entity_keys(conn, &deleted_ids)?;
+
+        let plan = plan_compaction(&pending, &synced);
+        if plan.is_empty() {
+            return Ok(OutboxCompactionReport::default());
+        }
+
+        Self::apply_compaction(conn, &plan)?;
+
+        let report = OutboxCompactionReport {
+            superseded: plan.superseded.len(),
+            cancelled: plan.cancelled.len(),
+        };
+        info!(
+            superseded = report.superseded,
+            cancelled = report.cancelled,
+            "compacted outbox before flush"
+        );
+
+        Ok(report)
+    }
+
+    /// Keys of the entities among `entity_ids` that already reached the
+    /// remote side.
+    fn fetch_synced_entity_keys(
+        conn: &SqlCipherConnection,
+        entity_ids: &BTreeSet<String>,
+    ) -> DomainResult<HashSet<OutboxEntityKey>> {
+        if entity_ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let placeholders = vec!["?"; entity_ids.len()].join(", ");
+        let sql = format!(
+            "{OUTBOX_SELECT_SYNCED_SQL} AND json_extract(payload_json, '$.id') IN ({placeholders})"
+        );
+        let params: Vec<&dyn ToSql> = entity_ids.iter().map(|id| id as &dyn ToSql).collect();
+
+        let mut stmt = conn.prepare(&sql).map_err(map_storage_error)?;
+        let synced =
+            stmt.query_map(params.as_slice(), map_outbox_row).map_err(map_storage_error)?;
+
+        Ok(synced.iter().filter_map(entity_key).collect())
+    }
+
+    fn apply_compaction(
+        conn: &mut SqlCipherConnection,
+        plan: &OutboxCompactionPlan,
+    ) -> DomainResult<()> {
+        let tx = conn.transaction().map_err(map_storage_error)?;
+
+        for id in plan.removed_ids() {
+            // Guard on status: an entry may have been sent since it was read
+            tx.execute(OUTBOX_DELETE_PENDING_SQL, &[&id as &dyn ToSql])
+                .map_err(map_storage_error)?;
+        }
+
+        tx.commit().map_err(map_storage_error)
+    }
+
     fn set_entry_sent(conn: &SqlCipherConnection, id: &str) -> DomainResult<()> {
         let now = now_timestamp();
         let status = OutboxStatus::Sent.to_string();
@@ -138,15 +227,102 @@ impl SqlCipherOutboxRepository {
         }
     }
 
+    fn fetch_status_summary(
+        conn: &SqlCipherConnection,
+        as_of_timestamp: i64,
+    ) -> DomainResult<OutboxStatusSummary> {
+        let mut summary = OutboxStatusSummary::default();
+
+        let mut stmt = conn.prepare(OUTBOX_STATUS_COUNTS_SQL).map_err(map_storage_error)?;
+        let counts = stmt
+            .query_map(&[], |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?)))
+            .map_err(map_storage_error)?;
+
+        for (raw_status, count) in counts {
+            let count = u32::try_from(count).unwrap_or(u32::MAX);
+            match parse_status("<aggregate>", &raw_status) {
+                OutboxStatus::Pending => {
+                    summary.pending_count = summary.pending_count.saturating_add(count)
+                }
+                OutboxStatus::Sent => summary.sent_count = summary.sent_count.saturating_add(count),
+                OutboxStatus::Failed => {
+                    summary.failed_count = summary.failed_count.saturating_add(count)
+                }
+                OutboxStatus::Dismissed => {
+                    summary.dismissed_count = summary.dismissed_count.saturating_add(count)
+                }
+            }
+        }
+
+        let params: [&dyn ToSql; 4] = [
+            &as_of_timestamp,
+            &OutboxAgeBuckets::ONE_MINUTE_SECS,
+            &OutboxAgeBuckets::TEN_MINUTES_SECS,
+            &OutboxAgeBuckets::ONE_HOUR_SECS,
+        ];
+        let (oldest_created_at, under_1m, under_10m, under_1h, older): PendingAgeRow = conn
+            .query_row(OUTBOX_PENDING_AGE_SQL, params.as_slice(), |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?))
+            })
+            .map_err(map_storage_error)?;
+
+        summary.oldest_pending_age_secs =
+            oldest_created_at.map(|created_at| as_of_timestamp.saturating_sub(created_at).max(0));
+        summary.pending_age_buckets = OutboxAgeBuckets {
+            under_1m: i64_to_u32(under_1m),
+            under_10m: i64_to_u32(under_10m),
+            under_1h: i64_to_u32(under_1h),
+            older: i64_to_u32(older),
+        };
+
+        Ok(summary)
+    }
+
+    /// Collapse superseded pending entries without dequeuing anything.
+    ///
+    /// Runs automatically at the start of every
+    /// [`dequeue_batch`](OutboxQueuePort::dequeue_batch); exposed for callers
+    /// that want to compact eagerly (e.g. after a burst of edits).
+    pub async fn compact_pending(&self) -> DomainResult<OutboxCompactionReport> {
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<OutboxCompactionReport> {
+                let mut conn = db.get_connection()?;
+                Self::compact(&mut conn)
+            },
+        ))
+        .await
+        .map_err(map_join_error)?
+    }
+
+    /// Summarise the outbox: counts per status, the age of the oldest
+    /// pending entry and an age histogram of pending entries.
+    ///
+    /// Used to detect a stuck sync (pending entries accumulating in the older
+    /// buckets).
+    pub async fn status_summary(&self) -> DomainResult<OutboxStatusSummary> {
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<OutboxStatusSummary> {
+                let conn = db.get_connection()?;
+                Self::fetch_status_summary(&conn, now_timestamp())
+            },
+        ))
+        .await
+        .map_err(map_join_error)?
+    }
+
     /// Return the number of entries currently queued with `pending` status.
     pub async fn pending_count(&self) -> DomainResult<i64> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<i64> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<i64> {
             let conn = db.get_connection()?;
             conn.query_row(OUTBOX_PENDING_COUNT_SQL, &[], |row| row.get(0))
                 .map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -158,10 +334,10 @@ impl OutboxQueuePort for SqlCipherOutboxRepository {
         let db = Arc::clone(&self.db);
         let to_insert = entry.clone();
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             Self::insert_entry(&conn, &to_insert)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -169,11 +345,14 @@ impl OutboxQueuePort for SqlCipherOutboxRepository {
     async fn dequeue_batch(&self, limit: usize) -> DomainResult<Vec<TimeEntryOutbox>> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<Vec<TimeEntryOutbox>> {
-            let conn = db.get_connection()?;
-            let as_of = now_timestamp();
-            Self::fetch_pending_ready(&conn, limit, as_of)
-        })
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<Vec<TimeEntryOutbox>> {
+                let mut conn = db.get_connection()?;
+                Self::compact(&mut conn)?;
+                let as_of = now_timestamp();
+                Self::fetch_pending_ready(&conn, limit, as_of)
+            },
+        ))
         .await
         .map_err(map_join_error)?
     }
@@ -182,10 +361,10 @@ impl OutboxQueuePort for SqlCipherOutboxRepository {
         let db = Arc::clone(&self.db);
         let id = id.to_owned();
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             Self::set_entry_sent(&conn, &id)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -195,10 +374,10 @@ impl OutboxQueuePort for SqlCipherOutboxRepository {
         let id = id.to_owned();
         let error = error.to_owned();
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             Self::register_failure(&conn, &id, &error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -214,16 +393,52 @@ const OUTBOX_INSERT_SQL: &str = "INSERT INTO time_entry_outbox (
         ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25
     )";
 
+// An entry is held back while an earlier entry for the same entity (target +
+// payload `$.id`) is still pending or has failed, so per-entity FIFO holds
+// across batches: an update never goes out while its create waits out a retry
+// window or sits dead-lettered. Entries without an entity id (or with a payload
+// that is not valid JSON) are never held.
 const OUTBOX_DEQUEUE_SQL: &str = "SELECT
+        o.id, o.idempotency_key, o.user_id, o.payload_json, o.backend_cuid, o.status, o.attempts,
+        o.last_error, o.retry_after, o.created_at, o.sent_at, o.correlation_id, o.local_status,
+        o.remote_status, o.sap_entry_id, o.next_attempt_at, o.error_code, o.last_forwarded_at,
+        o.wbs_code, o.target, o.description, o.auto_applied, o.version, o.last_modified_by,
+        o.last_modified_at
+    FROM time_entry_outbox o
+    WHERE o.status = 'pending'
+      AND (o.retry_after IS NULL OR o.retry_after <= ?1)
+      AND NOT EXISTS (
+          SELECT 1 FROM time_entry_outbox prior
+          WHERE prior.status IN ('pending', 'failed')
+            AND lower(prior.target) = lower(o.target)
+            AND CASE WHEN json_valid(prior.payload_json)
+                     THEN json_extract(prior.payload_json, '$.id') END
+              = CASE WHEN json_valid(o.payload_json)
+                     THEN NULLIF(json_extract(o.payload_json, '$.id'), '') END
+            AND (prior.created_at < o.created_at
+                 OR (prior.created_at = o.created_at AND prior.id < o.id))
+      )
+    ORDER BY o.created_at ASC, o.id ASC
+    LIMIT ?2";
+
+const OUTBOX_SELECT_PENDING_SQL: &str = "SELECT
         id, idempotency_key, user_id, payload_json, backend_cuid, status, attempts, last_error,
         retry_after, created_at, sent_at, correlation_id, local_status, remote_status, sap_entry_id,
         next_attempt_at, error_code, last_forwarded_at, wbs_code, target, description, auto_applied,
         version, last_modified_by, last_modified_at
     FROM time_entry_outbox
-    WHERE status = 'pending'
-      AND (retry_after IS NULL OR retry_after <= ?1)
-    ORDER BY created_at ASC, id ASC
-    LIMIT ?2";
+    WHERE status = 'pending'";
+
+const OUTBOX_SELECT_SYNCED_SQL: &str = "SELECT
+        id, idempotency_key, user_id, payload_json, backend_cuid, status, attempts, last_error,
+        retry_after, created_at, sent_at, correlation_id, local_status, remote_status, sap_entry_id,
+        next_attempt_at, error_code, last_forwarded_at, wbs_code, target, description, auto_applied,
+        version, last_modified_by, last_modified_at
+    FROM time_entry_outbox
+    WHERE (status = 'sent' OR backend_cuid IS NOT NULL)";
+
+const OUTBOX_DELETE_PENDING_SQL: &str =
+    "DELETE FROM time_entry_outbox WHERE id = ?1 AND status = 'pending'";
 
 const OUTBOX_MARK_SENT_SQL: &str = "UPDATE time_entry_outbox
     SET status = ?1,
@@ -249,6 +464,22 @@ const OUTBOX_SELECT_ATTEMPTS_SQL: &str = "SELECT attempts FROM time_entry_outbox
 const OUTBOX_PENDING_COUNT_SQL: &str =
     "SELECT COUNT(*) FROM time_entry_outbox WHERE status = 'pending'";
 
+const OUTBOX_STATUS_COUNTS_SQL: &str =
+    "SELECT status, COUNT(*) FROM time_entry_outbox GROUP BY status";
+
+// Bucket boundaries are bound as parameters (?2..?4) so they stay in sync with
+// `OutboxAgeBuckets`.
+const OUTBOX_PENDING_AGE_SQL: &str = "SELECT
+        MIN(created_at),
+        COALESCE(SUM(CASE WHEN ?1 - created_at < ?2 THEN 1 ELSE 0 END), 0),
+        COALESCE(SUM(CASE WHEN ?1 - created_at >= ?2 AND ?1 - created_at < ?3 THEN 1 ELSE 0 END), 0),
+        COALESCE(SUM(CASE WHEN ?1 - created_at >= ?3 AND ?1 - created_at < ?4 THEN 1 ELSE 0 END), 0),
+        COALESCE(SUM(CASE WHEN ?1 - created_at >= ?4 THEN 1 ELSE 0 END), 0)
+    FROM time_entry_outbox
+    WHERE status = 'pending'";
+
+type PendingAgeRow = (Option<i64>, i64, i64, i64, i64);
+
 fn map_outbox_row(row: &Row<'_>) -> rusqlite::Result<TimeEntryOutbox> {
     let id: String = row.get(0)?;
     let status_raw: String = row.get(5)?;
@@ -329,6 +560,10 @@ fn usize_to_i64(value: usize) -> i64 {
     i64::try_from(value).unwrap_or(i64::MAX)
 }
 
+fn i64_to_u32(value: i64) -> u32 {
+    u32::try_from(value.max(0)).unwrap_or(u32::MAX)
+}
+
 fn failure_transition(new_attempts: i32, now: i64) -> (OutboxStatus, Option<i64>) {
     if new_attempts >= MAX_RETRY_ATTEMPTS {
         (OutboxStatus::Failed, None)
@@ -504,6 +739,210 @@ mod tests {
         assert_eq!(count, 1);
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn status_summary_buckets_pending_entries_by_age() {
+        let (repo, manager, _temp_dir) = setup_repository().await;
+        let as_of = 1_700_010_000;
+
+        // Pending entries: 30s, 5m, 30m, 2h and 3h old
+        for (id, age) in
+            [("p-30s", 30), ("p-5m", 300), ("p-30m", 1_800), ("p-2h", 7_200), ("p-3h", 10_800)]
+        {
+            repo.enqueue(&sample_entry(id, as_of - age)).await.expect("enqueue pending");
+        }
+
+        // Non-pending entries must not influence the age histogram
+        let mut sent = sample_entry("sent-old", as_of - 86_400);
+        sent.status = OutboxStatus::Sent;
+        repo.enqueue(&sent).await.expect("enqueue sent");
+        let mut failed = sample_entry("failed-old", as_of - 86_400);
+        failed.status = OutboxStatus::Failed;
+        repo.enqueue(&failed).await.expect("enqueue failed");
+        let mut dismissed = sample_entry("dismissed", as_of - 10);
+        dismissed.status = OutboxStatus::Dismissed;
+        repo.enqueue(&dismissed).await.expect("enqueue dismissed");
+
+        let conn = manager.get_connection().expect("connection");
+        let summary = SqlCipherOutboxRepository::fetch_status_summary(&conn, as_of)
+            .expect("summary succeeds");
+
+        assert_eq!(summary.pending_count, 5);
+        assert_eq!(summary.sent_count, 1);
+        assert_eq!(summary.failed_count, 1);
+        assert_eq!(summary.dismissed_count, 1);
+        assert_eq!(summary.oldest_pending_age_secs, Some(10_800));
+        assert_eq!(
+            summary.pending_age_buckets,
+            OutboxAgeBuckets { under_1m: 1, under_10m: 1, under_1h: 1, older: 2 }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn status_summary_for_empty_outbox_has_no_oldest_age() {
+        let (repo, _manager, _temp_dir) = setup_repository().await;
+
+        let summary = repo.status_summary().await.expect("summary succeeds");
+
+        assert_eq!(summary, OutboxStatusSummary::default());
+        assert!(summary.oldest_pending_age_secs.is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn dequeue_compacts_repeated_edits_to_latest_version() {
+        let (repo, _manager, _temp_dir) = setup_repository().await;
+
+        for version in 1..=3 {
+            let mut edit =
+                sample_entry(&format!("edit-{version}"), 1_700_000_000 + i64::from(version));
+            edit.payload_json = format!(r#"{{"id":"entry-1","notes":"edit {version}"}}"#);
+            edit.version = version;
+            repo.enqueue(&edit).await.expect("enqueue edit");
+        }
+
+        let entries = repo.dequeue_batch(10).await.expect("dequeue succeeds");
+        assert_eq!(entries.len(), 1, "edits must compact to a single entry");
+        assert_eq!(entries[0].id, "edit-3");
+        assert_eq!(entries[0].version, 3);
+        assert_eq!(repo.pending_count().await.expect("pending count"), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn create_then_delete_of_unsynced_entry_sends_nothing() {
+        let (repo, _manager, _temp_dir) = setup_repository().await;
+
+        let mut create = sample_entry("create", 1_700_000_000);
+        create.payload_json = r#"{"id":"entry-1","status":"draft"}"#.into();
+        repo.enqueue(&create).await.expect("enqueue create");
+
+        let mut delete = sample_entry("delete", 1_700_000_060);
+        delete.payload_json = r#"{"id":"entry-1","status":"deleted"}"#.into();
+        delete.version = 2;
+        repo.enqueue(&delete).await.expect("enqueue delete");
+
+        let entries = repo.dequeue_batch(10).await.expect("dequeue succeeds");
+        assert!(entries.is_empty(), "create+delete of an unsynced entry must cancel out");
+        assert_eq!(repo.pending_count().await.expect("pending count"), 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn delete_of_synced_entry_is_still_forwarded() {
+        let (repo, _manager, _temp_dir) = setup_repository().await;
+
+        let mut sent = sample_entry("create", 1_700_000_000);
+        sent.payload_json = r#"{"id":"entry-1","status":"draft"}"#.into();
+        sent.status = OutboxStatus::Sent;
+        sent.sent_at = Some(1_700_000_010);
+        repo.enqueue(&sent).await.expect("enqueue sent");
+
+        let mut edit = sample_entry("edit", 1_700_000_020);
+        edit.payload_json = r#"{"id":"entry-1","status":"draft"}"#.into();
+        edit.version = 2;
+        repo.enqueue(&edit).await.expect("enqueue edit");
+
+        let mut delete = sample_entry("delete", 1_700_000_030);
+        delete.payload_json = r#"{"id":"entry-1","status":"deleted"}"#.into();
+        delete.version = 3;
+        repo.enqueue(&delete).await.expect("enqueue delete");
+
+        let report = repo.compact_pending().await.expect("compaction succeeds");
+        assert_eq!(report, OutboxCompactionReport { superseded: 1, cancelled: 0 });
+
+        let entries = repo.dequeue_batch(10).await.expect("dequeue succeeds");
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].id, "delete");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn dequeue_holds_update_behind_create_in_retry_window() {
+        let (repo, manager, _temp_dir) = setup_repository().await;
+
+        let mut create = sample_entry("create", 1_700_000_000);
+        create.payload_json = r#"{"id":"entry-1","status":"draft"}"#.into();
+        repo.enqueue(&create).await.expect("enqueue create");
+
+        // Tick 1: the create goes out and fails
+        let first = repo.dequeue_batch(10).await.expect("first dequeue");
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].id, "create");
+        repo.mark_failed("create", "503").await.expect("mark_failed succeeds");
+
+        let mut update = sample_entry("update", 1_700_000_060);
+        update.payload_json = r#"{"id":"entry-1","status":"submitted"}"#.into();
+        update.version = 2;
+        repo.enqueue(&update).await.expect("enqueue update");
+
+        // Tick 2: the create waits out its retry window and the update stays
+        // behind it
+        let second = repo.dequeue_batch(10).await.expect("second dequeue");
+        assert!(second.is_empty(), "update must not overtake the failed create");
+        assert_eq!(repo.pending_count().await.expect("pending count"), 2);
+
+        // Clear the create's retry window so the next tick retries it
+        {
+            let conn = manager.get_connection().expect("connection");
+            conn.execute(
+                "UPDATE time_entry_outbox SET retry_after = NULL WHERE id = ?1",
+                [&create.id as &dyn ToSql],
+            )
+            .expect("clear retry_after");
+        }
+
+        let third = repo.dequeue_batch(10).await.expect("third dequeue");
+        assert_eq!(third.iter().map(|entry| entry.id.as_str()).collect::<Vec<_>>(), ["create"]);
+        repo.mark_sent("create").await.expect("mark_sent succeeds");
+
+        let fourth = repo.dequeue_batch(10).await.expect("fourth dequeue");
+        assert_eq!(fourth.iter().map(|entry| entry.id.as_str()).collect::<Vec<_>>(), ["update"]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn dequeue_holds_entity_behind_dead_lettered_entry() {
+        let (repo, _manager, _temp_dir) = setup_repository().await;
+
+        let mut create = sample_entry("create", 1_700_000_000);
+        create.payload_json = r#"{"id":"entry-1","status":"draft"}"#.into();
+        create.attempts = MAX_RETRY_ATTEMPTS - 1;
+        repo.enqueue(&create).await.expect("enqueue create");
+        repo.mark_failed("create", "permanent failure").await.expect("dead-lettered");
+
+        let mut update = sample_entry("update", 1_700_000_060);
+        update.payload_json = r#"{"id":"entry-1","status":"submitted"}"#.into();
+        update.version = 2;
+        repo.enqueue(&update).await.expect("enqueue update");
+
+        let mut unrelated = sample_entry("unrelated", 1_700_000_120);
+        unrelated.payload_json = r#"{"id":"entry-2","status":"draft"}"#.into();
+        repo.enqueue(&unrelated).await.expect("enqueue unrelated");
+
+        let entries = repo.dequeue_batch(10).await.expect("dequeue succeeds");
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].id, "unrelated");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn synced_lookup_only_covers_requested_entities() {
+        let (repo, manager, _temp_dir) = setup_repository().await;
+
+        for (id, entity) in [("sent-1", "entry-1"), ("sent-2", "entry-2")] {
+            let mut sent = sample_entry(id, 1_700_000_000);
+            sent.payload_json = format!(r#"{{"id":"{entity}","status":"draft"}}"#);
+            sent.status = OutboxStatus::Sent;
+            sent.sent_at = Some(1_700_000_010);
+            repo.enqueue(&sent).await.expect("enqueue sent");
+        }
+
+        let conn = manager.get_connection().expect("connection");
+        let requested = BTreeSet::from(["entry-1".to_string()]);
+        let synced = SqlCipherOutboxRepository::fetch_synced_entity_keys(&conn, &requested)
+            .expect("lookup succeeds");
+
+        let keys: Vec<&str> = synced.iter().map(|(_, entity)| entity.as_str()).collect();
+        assert_eq!(keys, ["entry-1"]);
+        assert!(SqlCipherOutboxRepository::fetch_synced_entity_keys(&conn, &BTreeSet::new())
+            .expect("empty lookup succeeds")
+            .is_empty());
+    }
+
     async fn setup_repository() -> (SqlCipherOutboxRepository, Arc<DbManager>, TempDir) {
         let temp_dir = TempDir::new().expect("temp dir created");
         let db_path = temp_dir.path().join("test.db");
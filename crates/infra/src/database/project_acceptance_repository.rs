@@ -0,0 +1,138 @@
+//! Project acceptance repository implementation using SQLCipher
+//!
+//! Persists accepted project assignments used by the project matcher's
+//! learning mode.
+
+use std::sync::Arc;
+
+use pulsearc_common::storage::error::StorageError;
+use pulsearc_common::storage::sqlcipher::SqlCipherConnection;
+use pulsearc_core::classification::ports::ProjectAcceptanceRepository as ProjectAcceptanceRepositoryPort;
+use pulsearc_domain::types::classification::ProjectAcceptance;
+use pulsearc_domain::{PulseArcError, Result as DomainResult};
+use rusqlite::{Row, ToSql};
+
+use super::manager::DbManager;
+
+const INSERT_ACCEPTANCE_SQL: &str =
+    "INSERT INTO project_acceptances (signature, project_id, accepted_at) VALUES (?1, ?2, ?3)";
+
+const SELECT_ACCEPTANCES_SQL: &str = "SELECT signature, project_id, accepted_at
+     FROM project_acceptances
+     WHERE signature = ?1 AND accepted_at >= ?2
+     ORDER BY accepted_at ASC, id ASC";
+
+/// SQLCipher-backed implementation of `ProjectAcceptanceRepository`
+pub struct SqlCipherProjectAcceptanceRepository {
+    db: Arc<DbManager>,
+}
+
+impl SqlCipherProjectAcceptanceRepository {
+    /// Create a new repository instance
+    pub fn new(db: Arc<DbManager>) -> Self {
+        Self { db }
+    }
+}
+
+impl ProjectAcceptanceRepositoryPort for SqlCipherProjectAcceptanceRepository {
+    fn record_acceptance(&self, acceptance: &ProjectAcceptance) -> DomainResult<()> {
+        let conn = self.db.get_connection()?;
+        insert_acceptance(&conn, acceptance).map_err(map_storage_error)
+    }
+
+    fn get_acceptances(
+        &self,
+        signature: &str,
+        since_ts: i64,
+    ) -> DomainResult<Vec<ProjectAcceptance>> {
+        let conn = self.db.get_connection()?;
+        query_acceptances(&conn, signature, since_ts).map_err(map_storage_error)
+    }
+}
+
+fn insert_acceptance(
+    conn: &SqlCipherConnection,
+    acceptance: &ProjectAcceptance,
+) -> Result<(), StorageError> {
+    let params: [&dyn ToSql; 3] =
+        [&acceptance.signature, &acceptance.project_id, &acceptance.accepted_at];
+    conn.execute(INSERT_ACCEPTANCE_SQL, params.as_slice())?;
+    Ok(())
+}
+
+fn query_acceptances(
+    conn: &SqlCipherConnection,
+    signature: &str,
+    since_ts: i64,
+) -> Result<Vec<ProjectAcceptance>, StorageError> {
+    let mut stmt = conn.prepare(SELECT_ACCEPTANCES_SQL)?;
+    let params: [&dyn ToSql; 2] = [&signature, &since_ts];
+    stmt.query_map(params.as_slice(), map_acceptance_row)
+}
+
+fn map_acceptance_row(row: &Row<'_>) -> rusqlite::Result<ProjectAcceptance> {
+    Ok(ProjectAcceptance {
+        signature: row.get(0)?,
+        project_id: row.get(1)?,
+        accepted_at: row.get(2)?,
+    })
+}
+
+fn map_storage_error(err: StorageError) -> PulseArcError {
+    match err {
+        StorageError::WrongKeyOrNotEncrypted => {
+            PulseArcError::Database("Database key error or not encrypted".into())
+        }
+        StorageError::Connection(msg) => PulseArcError::Database(msg),
+        StorageError::Query(msg) => PulseArcError::Database(msg),
+        StorageError::DatabaseError(msg) => PulseArcError::Database(msg),
+        StorageError::Rusqlite(err) => PulseArcError::Database(format!("SQLite error: {err}")),
+        _ => PulseArcError::Database(format!("Storage error: {err}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    const TEST_KEY: &str = "test_key_64_chars_long_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+
+    fn setup_repo() -> (SqlCipherProjectAcceptanceRepository, TempDir) {
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let db_path = temp_dir.path().join("acceptances.db");
+        let manager =
+            DbManager::new(db_path.to_str().expect("utf8 path"), 4, Some(TEST_KEY)).expect("db");
+        manager.run_migrations().expect("run migrations");
+        (SqlCipherProjectAcceptanceRepository::new(Arc::new(manager)), temp_dir)
+    }
+
+    fn acceptance(signature: &str, project_id: &str, accepted_at: i64) -> ProjectAcceptance {
+        ProjectAcceptance {
+            signature: signature.into(),
+            project_id: project_id.into(),
+            accepted_at,
+        }
+    }
+
+    #[test]
+    fn returns_acceptances_for_signature_since_cutoff_in_order() {
+        let (repo, _temp_dir) = setup_repo();
+
+        repo.record_acceptance(&acceptance("excel|astro|-|-", "USC0063201", 300)).expect("record");
+        repo.record_acceptance(&acceptance("excel|astro|-|-", "USC0042105", 100)).expect("record");
+        repo.record_acceptance(&acceptance("word|beta|-|-", "USC0042105", 200)).expect("record");
+        repo.record_acceptance(&acceptance("excel|astro|-|-", "USC0058923", 200)).expect("record");
+
+        let since = repo.get_acceptances("excel|astro|-|-", 200).expect("query");
+
+        assert_eq!(
+            since,
+            vec![
+                acceptance("excel|astro|-|-", "USC0058923", 200),
+                acceptance("excel|astro|-|-", "USC0063201", 300),
+            ]
+        );
+    }
+}
@@ -14,6 +14,7 @@ use tracing::warn;
 use uuid::Uuid;
 
 use super::manager::DbManager;
+use crate::observability::correlation;
 
 /// SQLite implementation of TimeEntryRepository
 pub struct SqliteTimeEntryRepository {
@@ -30,7 +31,7 @@ impl SqliteTimeEntryRepository {
 impl TimeEntryRepository for SqliteTimeEntryRepository {
     async fn save_entry(&self, entry: TimeEntry) -> Result<()> {
         let db = self.db.clone();
-        tokio::task::spawn_blocking(move || {
+        tokio::task::spawn_blocking(correlation::propagate(move || {
             let conn = db.get_connection()?;
 
             conn.inner().execute(
@@ -49,7 +50,7 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
             .map_err(|e| PulseArcError::Database(e.to_string()))?;
 
             Ok(())
-        })
+        }))
         .await
         .map_err(|e| PulseArcError::Internal(e.to_string()))?
     }
@@ -60,7 +61,7 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
         end: DateTime<Utc>,
     ) -> Result<Vec<TimeEntry>> {
         let db = self.db.clone();
-        tokio::task::spawn_blocking(move || {
+        tokio::task::spawn_blocking(correlation::propagate(move || {
             let conn = db.get_connection()?;
             let mut stmt = conn.inner().prepare("SELECT id, start_time, end_time, duration_seconds, description, project_id, wbs_code FROM time_entries WHERE start_time BETWEEN ?1 AND ?2")
                 .map_err(|e| PulseArcError::Database(e.to_string()))?;
@@ -129,14 +130,14 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
             }
 
             Ok(entries)
-        })
+        }))
         .await
         .map_err(|e| PulseArcError::Internal(e.to_string()))?
     }
 
     async fn update_entry(&self, entry: TimeEntry) -> Result<()> {
         let db = self.db.clone();
-        tokio::task::spawn_blocking(move || {
+        tokio::task::spawn_blocking(correlation::propagate(move || {
             let conn = db.get_connection()?;
 
             conn.inner().execute(
@@ -154,14 +155,14 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
             .map_err(|e| PulseArcError::Database(e.to_string()))?;
 
             Ok(())
-        })
+        }))
         .await
         .map_err(|e| PulseArcError::Internal(e.to_string()))?
     }
 
     async fn delete_entry(&self, id: Uuid) -> Result<()> {
         let db = self.db.clone();
-        tokio::task::spawn_blocking(move || {
+        tokio::task::spawn_blocking(correlation::propagate(move || {
             let conn = db.get_connection()?;
 
             conn.inner()
@@ -169,7 +170,7 @@ impl TimeEntryRepository for SqliteTimeEntryRepository {
                 .map_err(|e| PulseArcError::Database(e.to_string()))?;
 
             Ok(())
-        })
+        }))
         .await
         .map_err(|e| PulseArcError::Internal(e.to_string()))?
     }
@@ -203,7 +204,7 @@ impl SqliteOutboxRepository {
         let db = self.db.clone();
         let entry = entry.clone();
 
-        tokio::task::spawn_blocking(move || {
+        tokio::task::spawn_blocking(correlation::propagate(move || {
             let conn = db.get_connection()?;
 
             conn.inner().execute(
@@ -245,7 +246,7 @@ impl SqliteOutboxRepository {
             .map_err(|e| PulseArcError::Database(e.to_string()))?;
 
             Ok(())
-        })
+        }))
         .await
         .map_err(|e| PulseArcError::Internal(e.to_string()))?
     }
@@ -258,7 +259,7 @@ impl SqliteOutboxRepository {
     ) -> Result<Vec<TimeEntryOutbox>> {
         let db = self.db.clone();
 
-        tokio::task::spawn_blocking(move || {
+        tokio::task::spawn_blocking(correlation::propagate(move || {
             let conn = db.get_connection()?;
 
             let mut stmt = conn
@@ -273,7 +274,7 @@ impl SqliteOutboxRepository {
                 .map_err(|e| PulseArcError::Database(e.to_string()))?;
 
             Ok(entries)
-        })
+        }))
         .await
         .map_err(|e| PulseArcError::Internal(e.to_string()))?
     }
@@ -283,7 +284,7 @@ impl SqliteOutboxRepository {
     pub async fn list_all_entries(&self) -> Result<Vec<TimeEntryOutbox>> {
         let db = self.db.clone();
 
-        tokio::task::spawn_blocking(move || {
+        tokio::task::spawn_blocking(correlation::propagate(move || {
             let conn = db.get_connection()?;
 
             let mut stmt = conn
@@ -298,7 +299,7 @@ impl SqliteOutboxRepository {
                 .map_err(|e| PulseArcError::Database(e.to_string()))?;
 
             Ok(entries)
-        })
+        }))
         .await
         .map_err(|e| PulseArcError::Internal(e.to_string()))?
     }
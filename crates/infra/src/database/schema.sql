@@ -11,7 +11,8 @@ CREATE TABLE IF NOT EXISTS activity_snapshots (
             created_at INTEGER NOT NULL,
             processed_at INTEGER,
             is_idle INTEGER NOT NULL DEFAULT 0,
-            idle_duration_secs INTEGER
+            idle_duration_secs INTEGER,
+            content_hash TEXT
         );
 CREATE INDEX IF NOT EXISTS idx_activity_unprocessed 
          ON activity_snapshots(processed, timestamp);
@@ -22,6 +23,19 @@ CREATE INDEX IF NOT EXISTS idx_activity_type
          WHERE processed = 0;
 CREATE INDEX IF NOT EXISTS idx_snapshots_recent 
          ON activity_snapshots(timestamp DESC, processed);
+CREATE INDEX IF NOT EXISTS idx_snapshots_timestamp_id
+         ON activity_snapshots(timestamp, id);
+CREATE INDEX IF NOT EXISTS idx_snapshots_app_timestamp
+         ON activity_snapshots(primary_app, timestamp, id);
+CREATE INDEX IF NOT EXISTS idx_snapshots_content_hash
+         ON activity_snapshots(content_hash, timestamp);
+CREATE TABLE IF NOT EXISTS activity_snapshot_runs (
+            snapshot_id TEXT PRIMARY KEY,
+            poll_count INTEGER NOT NULL,
+            duration_secs INTEGER NOT NULL,
+            last_seen_at INTEGER NOT NULL,
+            FOREIGN KEY (snapshot_id) REFERENCES activity_snapshots(id) ON DELETE CASCADE
+        );
 CREATE TABLE IF NOT EXISTS time_entries (
             id TEXT PRIMARY KEY,
             start_time INTEGER NOT NULL,
@@ -108,6 +122,11 @@ CREATE INDEX IF NOT EXISTS idx_outbox_user
 CREATE UNIQUE INDEX IF NOT EXISTS idx_outbox_correlation ON time_entry_outbox(correlation_id) WHERE correlation_id != '';
 CREATE INDEX IF NOT EXISTS idx_outbox_next_attempt ON time_entry_outbox(local_status, next_attempt_at) WHERE local_status = 'pending';
 CREATE INDEX IF NOT EXISTS idx_outbox_local_status ON time_entry_outbox(local_status);
+CREATE TABLE IF NOT EXISTS suggestion_clear_batch (
+            outbox_id TEXT PRIMARY KEY,
+            previous_status TEXT NOT NULL,
+            cleared_at INTEGER NOT NULL
+        );
 CREATE TABLE IF NOT EXISTS id_mapping (
             local_uuid TEXT PRIMARY KEY,
             backend_cuid TEXT UNIQUE,
@@ -145,6 +164,15 @@ CREATE TABLE IF NOT EXISTS batch_dlq (
         );
 CREATE INDEX IF NOT EXISTS idx_dlq_failed_at 
          ON batch_dlq(failed_at);
+CREATE TABLE IF NOT EXISTS outbox_dlq (
+            outbox_id TEXT PRIMARY KEY,
+            error_code TEXT NOT NULL,
+            error_message TEXT NOT NULL,
+            failed_at INTEGER NOT NULL,
+            attempts INTEGER NOT NULL
+        );
+CREATE INDEX IF NOT EXISTS idx_outbox_dlq_failed_at 
+         ON outbox_dlq(failed_at);
 CREATE TABLE IF NOT EXISTS metrics_snapshots (
             id TEXT PRIMARY KEY,
             test_id TEXT,
@@ -405,6 +433,32 @@ CREATE INDEX IF NOT EXISTS idx_idle_periods_time_range
          ON idle_periods(start_ts, end_ts);
 CREATE INDEX IF NOT EXISTS idx_idle_periods_user_action
          ON idle_periods(user_action, start_ts);
+CREATE TABLE IF NOT EXISTS suggestion_dismissals (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            signature TEXT NOT NULL,
+            source_id TEXT NOT NULL,
+            dismissed_at INTEGER NOT NULL
+        );
+CREATE INDEX IF NOT EXISTS idx_suggestion_dismissals_dismissed_at
+         ON suggestion_dismissals(dismissed_at);
+CREATE TABLE IF NOT EXISTS project_acceptances (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            signature TEXT NOT NULL,
+            project_id TEXT NOT NULL,
+            accepted_at INTEGER NOT NULL
+        );
+CREATE INDEX IF NOT EXISTS idx_project_acceptances_signature
+         ON project_acceptances(signature, accepted_at);
+CREATE TABLE IF NOT EXISTS app_category_rules (
+            id TEXT PRIMARY KEY,
+            bundle_id TEXT NOT NULL,
+            title_pattern TEXT,
+            url_pattern TEXT,
+            category TEXT,
+            work_type TEXT,
+            project_id TEXT,
+            created_at INTEGER NOT NULL
+        );
 CREATE TABLE IF NOT EXISTS feature_flags (
             flag_name TEXT PRIMARY KEY,
             enabled INTEGER NOT NULL DEFAULT 0,
@@ -426,6 +480,13 @@ VALUES
     ('new_monitoring_commands', 0, 'Phase 4C.2: New monitoring & stats commands', CAST(strftime('%s','now') AS INTEGER)),
     ('new_idle_sync_commands', 0, 'Phase 4C.3: New idle sync telemetry commands', CAST(strftime('%s','now') AS INTEGER)),
     ('new_seed_commands', 0, 'Phase 4C.4: New seed snapshot commands', CAST(strftime('%s','now') AS INTEGER));
+-- Feature flag dependencies: flag_name only takes effect while every flag it
+-- requires is enabled (cycles are rejected when dependencies are declared)
+CREATE TABLE IF NOT EXISTS feature_flag_dependencies (
+            flag_name TEXT NOT NULL,
+            requires TEXT NOT NULL,
+            PRIMARY KEY (flag_name, requires)
+        );
 CREATE TABLE IF NOT EXISTS command_metrics (
             id TEXT PRIMARY KEY,
             command TEXT NOT NULL,
@@ -467,7 +528,12 @@ CREATE TABLE IF NOT EXISTS user_profiles (
             last_login_at INTEGER NOT NULL,
             last_synced_at INTEGER NOT NULL,
             created_at INTEGER NOT NULL,
-            updated_at INTEGER NOT NULL
+            updated_at INTEGER NOT NULL,
+            workday_start_minutes INTEGER NOT NULL DEFAULT 480,
+            workday_end_minutes INTEGER NOT NULL DEFAULT 1080,
+            working_days TEXT NOT NULL DEFAULT '1,2,3,4,5',
+            idle_enter_secs INTEGER NOT NULL DEFAULT 300,
+            idle_exit_secs INTEGER NOT NULL DEFAULT 30
         );
 CREATE INDEX IF NOT EXISTS idx_user_profiles_auth0_id
          ON user_profiles(auth0_id);
@@ -475,6 +541,24 @@ CREATE INDEX IF NOT EXISTS idx_user_profiles_email
          ON user_profiles(email);
 CREATE INDEX IF NOT EXISTS idx_user_profiles_org_id
          ON user_profiles(org_id);
+CREATE TABLE IF NOT EXISTS user_settings (
+            key TEXT NOT NULL,
+            scope TEXT NOT NULL DEFAULT '',
+            value TEXT NOT NULL,
+            version INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL,
+            PRIMARY KEY (key, scope)
+        );
+CREATE TABLE IF NOT EXISTS user_settings_history (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            key TEXT NOT NULL,
+            scope TEXT NOT NULL DEFAULT '',
+            version INTEGER NOT NULL,
+            old_value TEXT,
+            new_value TEXT NOT NULL,
+            changed_at INTEGER NOT NULL,
+            UNIQUE (key, scope, version)
+        );
 CREATE TABLE IF NOT EXISTS schema_version (
             version INTEGER PRIMARY KEY,
             applied_at INTEGER NOT NULL
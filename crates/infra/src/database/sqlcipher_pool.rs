@@ -2,15 +2,27 @@
 //!
 //! Thin wrapper around the shared SQLCipher connection pool that converts
 //! storage errors into the domain error type used by infrastructure code.
+//!
+//! The pool reports a wrong key and a damaged file alike (as
+//! `WrongKeyOrNotEncrypted`). When opening fails, the file is reopened on a
+//! bare connection and SQLite's result code tells the two apart:
+//! `SQLITE_NOTADB` (page 1 fails its HMAC) is a key mismatch, while
+//! `SQLITE_CORRUPT` means the file decrypts but is damaged. A file that is not
+//! a whole number of pages is truncated whatever the key.
 
 use std::path::Path;
 use std::sync::Arc;
 
 use pulsearc_common::storage::sqlcipher::{
-    SqlCipherPool as CommonSqlCipherPool, SqlCipherPoolConfig,
+    configure_sqlcipher, SqlCipherConfig, SqlCipherPool as CommonSqlCipherPool, SqlCipherPoolConfig,
 };
 use pulsearc_common::storage::StorageError;
-use pulsearc_domain::{PulseArcError, Result as DomainResult};
+use pulsearc_domain::{DbOpenError, PulseArcError, Result as DomainResult};
+use rusqlite::{Connection, ErrorCode, OpenFlags};
+use tracing::warn;
+
+/// Page size of SQLCipher 4 databases (`cipher_page_size`).
+const CIPHER_PAGE_SIZE: u64 = 4096;
 
 /// Re-export the common SQLCipher pool so callers can depend on the shared
 /// type.
@@ -18,18 +30,59 @@ pub type SqlCipherPool = CommonSqlCipherPool;
 
 /// Convenience helper for creating an `Arc<SqlCipherPool>` using domain error
 /// semantics.
+///
+/// A wrong key surfaces as [`DbOpenError::KeyMismatch`] and a damaged file as
+/// [`DbOpenError::Corrupt`]; other failures stay `PulseArcError::Database`.
 pub fn create_sqlcipher_pool<P: AsRef<Path>>(
     path: P,
     encryption_key: String,
     config: SqlCipherPoolConfig,
 ) -> DomainResult<Arc<SqlCipherPool>> {
-    SqlCipherPool::new(path.as_ref(), encryption_key, config)
+    let path = path.as_ref();
+    SqlCipherPool::new(path, encryption_key.clone(), config)
         .map(Arc::new)
-        .map_err(map_storage_error)
+        .map_err(|err| classify_open_error(path, &encryption_key, err))
+}
+
+fn classify_open_error(path: &Path, encryption_key: &str, err: StorageError) -> PulseArcError {
+    match diagnose_open_failure(path, encryption_key) {
+        Some(open_err) => {
+            warn!(db_path = %path.display(), error = %open_err, "database could not be opened");
+            PulseArcError::DatabaseOpen(open_err)
+        }
+        None => PulseArcError::Database(err.to_string()),
+    }
 }
 
-fn map_storage_error(err: StorageError) -> PulseArcError {
-    PulseArcError::Database(err.to_string())
+/// Reopen `path` outside the pool to find out why it failed to open.
+///
+/// Returns `None` when the failure is not about the file itself (missing
+/// file, permissions, pool timeouts).
+fn diagnose_open_failure(path: &Path, encryption_key: &str) -> Option<DbOpenError> {
+    let file_len = std::fs::metadata(path).ok()?.len();
+    if file_len == 0 {
+        return None;
+    }
+    if file_len % CIPHER_PAGE_SIZE != 0 {
+        return Some(DbOpenError::Corrupt(format!(
+            "file size {file_len} is not a whole number of {CIPHER_PAGE_SIZE}-byte pages"
+        )));
+    }
+
+    let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_WRITE).ok()?;
+    configure_sqlcipher(&conn, &SqlCipherConfig::new(encryption_key.to_string())).ok()?;
+
+    match conn.query_row("SELECT count(*) FROM sqlite_master", [], |_| Ok(())) {
+        Err(rusqlite::Error::SqliteFailure(failure, message)) => {
+            let detail = message.unwrap_or_else(|| failure.to_string());
+            match failure.code {
+                ErrorCode::NotADatabase => Some(DbOpenError::KeyMismatch(detail)),
+                ErrorCode::DatabaseCorrupt => Some(DbOpenError::Corrupt(detail)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,322 @@
+//! Bulk suggestion clear/restore backed by SQLCipher.
+//!
+//! Clearing suggestions dismisses every pending or failed outbox entry and
+//! records the previous status of each entry in `suggestion_clear_batch`.
+//! Only the most recent clear is kept, so `restore_last_cleared` can undo it
+//! within an undo window. Entries hard-deleted since the clear no longer exist
+//! in the outbox and are therefore never restored.
+
+use std::sync::Arc;
+
+use pulsearc_common::storage::error::StorageError;
+use pulsearc_common::storage::sqlcipher::SqlCipherConnection;
+use pulsearc_domain::{OutboxStatus, PulseArcError, Result as DomainResult};
+use rusqlite::ToSql;
+use tokio::task;
+use tracing::info;
+
+use super::manager::DbManager;
+use crate::observability::correlation;
+
+const CLEAR_BATCH_DELETE_SQL: &str = "DELETE FROM suggestion_clear_batch";
+
+const CLEAR_BATCH_RECORD_SQL: &str = "INSERT INTO suggestion_clear_batch
+         (outbox_id, previous_status, cleared_at)
+     SELECT id, status, ?3
+     FROM time_entry_outbox
+     WHERE status IN (?1, ?2)";
+
+const CLEAR_DISMISS_SQL: &str = "UPDATE time_entry_outbox
+     SET status = ?1, last_modified_at = ?2
+     WHERE status IN (?3, ?4)";
+
+const CLEAR_BATCH_CLEARED_AT_SQL: &str = "SELECT MAX(cleared_at) FROM suggestion_clear_batch";
+
+const CLEAR_BATCH_RESTORE_SQL: &str = "UPDATE time_entry_outbox
+     SET status = (
+             SELECT previous_status FROM suggestion_clear_batch
+             WHERE suggestion_clear_batch.outbox_id = time_entry_outbox.id
+         ),
+         last_modified_at = ?1
+     WHERE status = ?2
+       AND id IN (SELECT outbox_id FROM suggestion_clear_batch)";
+
+const RESTORE_ONE_SQL: &str = "UPDATE time_entry_outbox
+     SET status = ?1, last_error = NULL, last_modified_at = ?2
+     WHERE id = ?3 AND status = ?4";
+
+const CLEAR_BATCH_FORGET_SQL: &str = "DELETE FROM suggestion_clear_batch WHERE outbox_id = ?1";
+
+/// Repository for bulk clearing and restoring outbox suggestions.
+pub struct SqlCipherSuggestionBatchRepository {
+    db: Arc<DbManager>,
+}
+
+impl SqlCipherSuggestionBatchRepository {
+    /// Construct a repository backed by the shared SQLCipher manager.
+    pub fn new(db: Arc<DbManager>) -> Self {
+        Self { db }
+    }
+
+    /// Dismiss every pending or failed suggestion and remember the batch so
+    /// it can be undone. Replaces any previously recorded batch.
+    ///
+    /// Returns the number of suggestions cleared.
+    pub async fn clear_all(&self, cleared_at: i64) -> DomainResult<usize> {
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<usize> {
+            let mut conn = db.get_connection()?;
+            clear_all(&mut conn, cleared_at).map_err(map_storage_error)
+        }))
+        .await
+        .map_err(map_join_error)?
+    }
+
+    /// Restore the given dismissed suggestions back to pending.
+    ///
+    /// Ids that do not exist (e.g. hard-deleted) or are not dismissed are
+    /// skipped. Returns the number of suggestions restored.
+    pub async fn restore(&self, ids: &[String], restored_at: i64) -> DomainResult<usize> {
+        let db = Arc::clone(&self.db);
+        let ids = ids.to_vec();
+
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<usize> {
+            let mut conn = db.get_connection()?;
+            restore_ids(&mut conn, &ids, restored_at).map_err(map_storage_error)
+        }))
+        .await
+        .map_err(map_join_error)?
+    }
+
+    /// Undo the most recent clear if it happened within `undo_window_secs`.
+    ///
+    /// Each entry returns to the status it had before the clear. Entries that
+    /// were hard-deleted or changed since the clear are left alone. An expired
+    /// batch is discarded. Returns the number of suggestions restored.
+    pub async fn restore_last_cleared(
+        &self,
+        now: i64,
+        undo_window_secs: i64,
+    ) -> DomainResult<usize> {
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<usize> {
+            let mut conn = db.get_connection()?;
+            restore_last_cleared(&mut conn, now, undo_window_secs).map_err(map_storage_error)
+        }))
+        .await
+        .map_err(map_join_error)?
+    }
+}
+
+fn clear_all(conn: &mut SqlCipherConnection, cleared_at: i64) -> Result<usize, StorageError> {
+    let pending = OutboxStatus::Pending.to_string();
+    let failed = OutboxStatus::Failed.to_string();
+    let dismissed = OutboxStatus::Dismissed.to_string();
+
+    let record_params: [&dyn ToSql; 3] = [&pending, &failed, &cleared_at];
+    let dismiss_params: [&dyn ToSql; 4] = [&dismissed, &cleared_at, &pending, &failed];
+
+    let tx = conn.transaction()?;
+    tx.execute(CLEAR_BATCH_DELETE_SQL, &[])?;
+    tx.execute(CLEAR_BATCH_RECORD_SQL, record_params.as_slice())?;
+    let cleared = tx.execute(CLEAR_DISMISS_SQL, dismiss_params.as_slice())?;
+    tx.commit()?;
+
+    info!(cleared, "Cleared suggestions");
+    Ok(cleared)
+}
+
+fn restore_ids(
+    conn: &mut SqlCipherConnection,
+    ids: &[String],
+    restored_at: i64,
+) -> Result<usize, StorageError> {
+    let pending = OutboxStatus::Pending.to_string();
+    let dismissed = OutboxStatus::Dismissed.to_string();
+
+    let tx = conn.transaction()?;
+    let mut restored = 0;
+    for id in ids {
+        let params: [&dyn ToSql; 4] = [&pending, &restored_at, id, &dismissed];
+        restored += tx.execute(RESTORE_ONE_SQL, params.as_slice())?;
+        tx.execute(CLEAR_BATCH_FORGET_SQL, &[id as &dyn ToSql])?;
+    }
+    tx.commit()?;
+
+    Ok(restored)
+}
+
+fn restore_last_cleared(
+    conn: &mut SqlCipherConnection,
+    now: i64,
+    undo_window_secs: i64,
+) -> Result<usize, StorageError> {
+    let cleared_at: Option<i64> =
+        conn.query_row(CLEAR_BATCH_CLEARED_AT_SQL, &[], |row| row.get(0))?;
+    let Some(cleared_at) = cleared_at else {
+        return Ok(0);
+    };
+
+    let tx = conn.transaction()?;
+    let restored = if now.saturating_sub(cleared_at) <= undo_window_secs {
+        let dismissed = OutboxStatus::Dismissed.to_string();
+        let params: [&dyn ToSql; 2] = [&now, &dismissed];
+        tx.execute(CLEAR_BATCH_RESTORE_SQL, params.as_slice())?
+    } else {
+        info!(cleared_at, "Last suggestion clear is outside the undo window");
+        0
+    };
+    tx.execute(CLEAR_BATCH_DELETE_SQL, &[])?;
+    tx.commit()?;
+
+    Ok(restored)
+}
+
+fn map_storage_error(err: StorageError) -> PulseArcError {
+    match err {
+        StorageError::WrongKeyOrNotEncrypted => {
+            PulseArcError::Database("Database key error or not encrypted".into())
+        }
+        StorageError::Connection(msg) => PulseArcError::Database(msg),
+        StorageError::Query(msg) => PulseArcError::Database(msg),
+        StorageError::DatabaseError(msg) => PulseArcError::Database(msg),
+        StorageError::Rusqlite(err) => PulseArcError::Database(format!("SQLite error: {err}")),
+        _ => PulseArcError::Database(format!("Storage error: {err}")),
+    }
+}
+
+fn map_join_error(err: task::JoinError) -> PulseArcError {
+    PulseArcError::Internal(format!("Task join error: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use pulsearc_core::OutboxQueue;
+    use pulsearc_domain::TimeEntryOutbox;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::database::SqlCipherOutboxRepository;
+
+    const TEST_KEY: &str = "test_key_64_chars_long_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+    const NOW: i64 = 1_700_000_000;
+    const WINDOW: i64 = 600;
+
+    fn setup() -> (Arc<DbManager>, TempDir) {
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let db_path = temp_dir.path().join("suggestions.db");
+        let manager =
+            DbManager::new(db_path.to_str().expect("utf8 path"), 4, Some(TEST_KEY)).expect("db");
+        manager.run_migrations().expect("run migrations");
+        (Arc::new(manager), temp_dir)
+    }
+
+    fn entry(id: &str, status: OutboxStatus) -> TimeEntryOutbox {
+        TimeEntryOutbox {
+            id: id.into(),
+            idempotency_key: format!("{id}-idem"),
+            user_id: "user-1".into(),
+            payload_json: "{}".into(),
+            backend_cuid: None,
+            status,
+            attempts: 0,
+            last_error: None,
+            retry_after: None,
+            created_at: NOW - 3_600,
+            sent_at: None,
+            correlation_id: Some(String::new()),
+            local_status: Some("pending".into()),
+            remote_status: None,
+            sap_entry_id: None,
+            next_attempt_at: None,
+            error_code: None,
+            last_forwarded_at: None,
+            wbs_code: None,
+            target: "sap".into(),
+            description: None,
+            auto_applied: false,
+            version: 1,
+            last_modified_by: "system".into(),
+            last_modified_at: None,
+        }
+    }
+
+    fn status_of(db: &DbManager, id: &str) -> Option<OutboxStatus> {
+        let conn = db.get_connection().expect("connection");
+        match conn.query_row(
+            "SELECT status FROM time_entry_outbox WHERE id = ?1",
+            &[&id as &dyn ToSql],
+            |row| row.get::<_, String>(0),
+        ) {
+            Ok(status) => Some(status.parse().expect("valid status")),
+            Err(StorageError::Rusqlite(rusqlite::Error::QueryReturnedNoRows)) => None,
+            Err(err) => panic!("query status: {err}"),
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn bulk_clear_can_be_fully_undone() {
+        let (db, _temp_dir) = setup();
+        let outbox = SqlCipherOutboxRepository::new(Arc::clone(&db));
+        let repo = SqlCipherSuggestionBatchRepository::new(Arc::clone(&db));
+
+        outbox.enqueue(&entry("pending", OutboxStatus::Pending)).await.expect("enqueue");
+        outbox.enqueue(&entry("failed", OutboxStatus::Failed)).await.expect("enqueue");
+        outbox.enqueue(&entry("sent", OutboxStatus::Sent)).await.expect("enqueue");
+
+        assert_eq!(repo.clear_all(NOW).await.expect("clear"), 2);
+        assert_eq!(status_of(&db, "pending"), Some(OutboxStatus::Dismissed));
+        assert_eq!(status_of(&db, "sent"), Some(OutboxStatus::Sent));
+
+        assert_eq!(repo.restore_last_cleared(NOW + 60, WINDOW).await.expect("undo"), 2);
+        assert_eq!(status_of(&db, "pending"), Some(OutboxStatus::Pending));
+        assert_eq!(status_of(&db, "failed"), Some(OutboxStatus::Failed));
+
+        // The batch is consumed by the undo
+        assert_eq!(repo.restore_last_cleared(NOW + 60, WINDOW).await.expect("undo"), 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn hard_deleted_suggestion_is_not_restored() {
+        let (db, _temp_dir) = setup();
+        let outbox = SqlCipherOutboxRepository::new(Arc::clone(&db));
+        let repo = SqlCipherSuggestionBatchRepository::new(Arc::clone(&db));
+
+        outbox.enqueue(&entry("kept", OutboxStatus::Pending)).await.expect("enqueue");
+        outbox.enqueue(&entry("deleted", OutboxStatus::Pending)).await.expect("enqueue");
+        repo.clear_all(NOW).await.expect("clear");
+
+        {
+            let conn = db.get_connection().expect("connection");
+            conn.execute("DELETE FROM time_entry_outbox WHERE id = ?1", ["deleted"])
+                .expect("hard delete");
+        }
+
+        assert_eq!(repo.restore_last_cleared(NOW + 60, WINDOW).await.expect("undo"), 1);
+        assert_eq!(status_of(&db, "kept"), Some(OutboxStatus::Pending));
+        assert_eq!(status_of(&db, "deleted"), None);
+
+        let ids = vec!["deleted".to_string()];
+        assert_eq!(repo.restore(&ids, NOW + 60).await.expect("restore"), 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn undo_window_expires() {
+        let (db, _temp_dir) = setup();
+        let outbox = SqlCipherOutboxRepository::new(Arc::clone(&db));
+        let repo = SqlCipherSuggestionBatchRepository::new(Arc::clone(&db));
+
+        outbox.enqueue(&entry("pending", OutboxStatus::Pending)).await.expect("enqueue");
+        repo.clear_all(NOW).await.expect("clear");
+
+        assert_eq!(repo.restore_last_cleared(NOW + WINDOW + 1, WINDOW).await.expect("undo"), 0);
+        assert_eq!(status_of(&db, "pending"), Some(OutboxStatus::Dismissed));
+
+        // Manual restore still works after the window
+        let ids = vec!["pending".to_string()];
+        assert_eq!(repo.restore(&ids, NOW + WINDOW + 1).await.expect("restore"), 1);
+        assert_eq!(status_of(&db, "pending"), Some(OutboxStatus::Pending));
+    }
+}
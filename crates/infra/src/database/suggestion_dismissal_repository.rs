@@ -0,0 +1,151 @@
+//! Suggestion dismissal repository implementation using SQLCipher
+//!
+//! Persists dismissal history used to learn suggestion suppressions.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use pulsearc_common::storage::error::StorageError;
+use pulsearc_common::storage::sqlcipher::SqlCipherConnection;
+use pulsearc_core::classification::ports::SuggestionDismissalRepository as SuggestionDismissalRepositoryPort;
+use pulsearc_domain::types::classification::SuggestionDismissal;
+use pulsearc_domain::{PulseArcError, Result as DomainResult};
+use rusqlite::{Row, ToSql};
+use tokio::task;
+
+use super::manager::DbManager;
+use crate::observability::correlation;
+
+const INSERT_DISMISSAL_SQL: &str =
+    "INSERT INTO suggestion_dismissals (signature, source_id, dismissed_at) VALUES (?1, ?2, ?3)";
+
+const SELECT_DISMISSALS_SINCE_SQL: &str = "SELECT signature, source_id, dismissed_at
+     FROM suggestion_dismissals
+     WHERE dismissed_at >= ?1
+     ORDER BY dismissed_at ASC, id ASC";
+
+/// SQLCipher-backed implementation of `SuggestionDismissalRepository`
+pub struct SqlCipherSuggestionDismissalRepository {
+    db: Arc<DbManager>,
+}
+
+impl SqlCipherSuggestionDismissalRepository {
+    /// Create a new repository instance
+    pub fn new(db: Arc<DbManager>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl SuggestionDismissalRepositoryPort for SqlCipherSuggestionDismissalRepository {
+    async fn record_dismissal(&self, dismissal: &SuggestionDismissal) -> DomainResult<()> {
+        let db = Arc::clone(&self.db);
+        let dismissal = dismissal.clone();
+
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
+            let conn = db.get_connection()?;
+            insert_dismissal(&conn, &dismissal).map_err(map_storage_error)
+        }))
+        .await
+        .map_err(map_join_error)?
+    }
+
+    async fn get_dismissals_since(&self, since_ts: i64) -> DomainResult<Vec<SuggestionDismissal>> {
+        let db = Arc::clone(&self.db);
+
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<Vec<SuggestionDismissal>> {
+                let conn = db.get_connection()?;
+                query_dismissals_since(&conn, since_ts).map_err(map_storage_error)
+            },
+        ))
+        .await
+        .map_err(map_join_error)?
+    }
+}
+
+fn insert_dismissal(
+    conn: &SqlCipherConnection,
+    dismissal: &SuggestionDismissal,
+) -> Result<(), StorageError> {
+    let params: [&dyn ToSql; 3] =
+        [&dismissal.signature, &dismissal.source_id, &dismissal.dismissed_at];
+    conn.execute(INSERT_DISMISSAL_SQL, params.as_slice())?;
+    Ok(())
+}
+
+fn query_dismissals_since(
+    conn: &SqlCipherConnection,
+    since_ts: i64,
+) -> Result<Vec<SuggestionDismissal>, StorageError> {
+    let mut stmt = conn.prepare(SELECT_DISMISSALS_SINCE_SQL)?;
+    let params: [&dyn ToSql; 1] = [&since_ts];
+    stmt.query_map(params.as_slice(), map_dismissal_row)
+}
+
+fn map_dismissal_row(row: &Row<'_>) -> rusqlite::Result<SuggestionDismissal> {
+    Ok(SuggestionDismissal {
+        signature: row.get(0)?,
+        source_id: row.get(1)?,
+        dismissed_at: row.get(2)?,
+    })
+}
+
+fn map_storage_error(err: StorageError) -> PulseArcError {
+    match err {
+        StorageError::WrongKeyOrNotEncrypted => {
+            PulseArcError::Database("Database key error or not encrypted".into())
+        }
+        StorageError::Connection(msg) => PulseArcError::Database(msg),
+        StorageError::Query(msg) => PulseArcError::Database(msg),
+        StorageError::DatabaseError(msg) => PulseArcError::Database(msg),
+        StorageError::Rusqlite(err) => PulseArcError::Database(format!("SQLite error: {err}")),
+        _ => PulseArcError::Database(format!("Storage error: {err}")),
+    }
+}
+
+fn map_join_error(err: task::JoinError) -> PulseArcError {
+    PulseArcError::Internal(format!("Task join error: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    const TEST_KEY: &str = "test_key_64_chars_long_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+
+    fn setup_repo() -> (SqlCipherSuggestionDismissalRepository, TempDir) {
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let db_path = temp_dir.path().join("dismissals.db");
+        let manager =
+            DbManager::new(db_path.to_str().expect("utf8 path"), 4, Some(TEST_KEY)).expect("db");
+        manager.run_migrations().expect("run migrations");
+        (SqlCipherSuggestionDismissalRepository::new(Arc::new(manager)), temp_dir)
+    }
+
+    fn dismissal(signature: &str, source_id: &str, dismissed_at: i64) -> SuggestionDismissal {
+        SuggestionDismissal {
+            signature: signature.into(),
+            source_id: source_id.into(),
+            dismissed_at,
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn returns_dismissals_since_cutoff_in_order() {
+        let (repo, _temp_dir) = setup_repo();
+
+        repo.record_dismissal(&dismissal("spotify|-", "b3", 300)).await.expect("record");
+        repo.record_dismissal(&dismissal("spotify|-", "b1", 100)).await.expect("record");
+        repo.record_dismissal(&dismissal("excel|prj", "b2", 200)).await.expect("record");
+
+        let since = repo.get_dismissals_since(200).await.expect("query");
+
+        assert_eq!(
+            since,
+            vec![dismissal("excel|prj", "b2", 200), dismissal("spotify|-", "b3", 300)]
+        );
+    }
+}
@@ -14,6 +14,7 @@ use tokio::task;
 use uuid::Uuid;
 
 use super::manager::DbManager;
+use crate::observability::correlation;
 
 /// SqlCipher-based Token Usage repository
 pub struct SqlCipherTokenUsageRepository {
@@ -32,10 +33,10 @@ impl TokenUsageRepositoryPort for SqlCipherTokenUsageRepository {
         let db = Arc::clone(&self.db);
         let usage = usage.clone();
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             insert_token_usage(&conn, &usage, false).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -44,10 +45,10 @@ impl TokenUsageRepositoryPort for SqlCipherTokenUsageRepository {
         let db = Arc::clone(&self.db);
         let batch_id = batch_id.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<TokenUsage> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<TokenUsage> {
             let conn = db.get_connection()?;
             query_token_usage_by_batch(&conn, &batch_id).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -56,10 +57,10 @@ impl TokenUsageRepositoryPort for SqlCipherTokenUsageRepository {
         let db = Arc::clone(&self.db);
         let usage = usage.clone();
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             insert_token_usage(&conn, &usage, false).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -68,10 +69,10 @@ impl TokenUsageRepositoryPort for SqlCipherTokenUsageRepository {
         let db = Arc::clone(&self.db);
         let usage = usage.clone();
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             insert_token_usage(&conn, &usage, true).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -80,10 +81,10 @@ impl TokenUsageRepositoryPort for SqlCipherTokenUsageRepository {
         let db = Arc::clone(&self.db);
         let batch_id = batch_id.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<usize> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<usize> {
             let conn = db.get_connection()?;
             delete_token_usage(&conn, &batch_id).map_err(map_storage_error)
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
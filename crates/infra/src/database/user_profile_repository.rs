@@ -7,12 +7,15 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use pulsearc_common::storage::error::StorageError;
 use pulsearc_common::storage::sqlcipher::SqlCipherConnection;
+use pulsearc_common::storage::types::Transaction;
 use pulsearc_core::user::ports::UserProfileRepository as UserProfileRepositoryPort;
-use pulsearc_domain::{PulseArcError, Result as DomainResult, UserProfile};
+use pulsearc_core::user::{validate_user_profile, validation_error_to_domain};
+use pulsearc_domain::{PulseArcError, Result as DomainResult, UserProfile, WorkdayConfig};
 use rusqlite::{params, Row, ToSql};
 use tokio::task;
 
 use super::manager::DbManager;
+use crate::observability::correlation;
 
 /// SQLCipher-backed implementation of `UserProfileRepository`
 pub struct SqlCipherUserProfileRepository {
@@ -32,25 +35,28 @@ impl UserProfileRepositoryPort for SqlCipherUserProfileRepository {
         let db = Arc::clone(&self.db);
         let id = id.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<Option<UserProfile>> {
-            let conn = db.get_connection()?;
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<Option<UserProfile>> {
+                let conn = db.get_connection()?;
 
-            let result = conn.query_row(
-                "SELECT id, auth0_id, email, org_id, name, first_name, last_name, display_name,
+                let result = conn.query_row(
+                    "SELECT id, auth0_id, email, org_id, name, first_name, last_name, display_name,
                         avatar_url, phone_number, title, department, location, bio,
                         timezone, language, locale, date_format, is_active, email_verified,
-                        two_factor_enabled, last_login_at, last_synced_at, created_at, updated_at
+                        two_factor_enabled, last_login_at, last_synced_at, created_at, updated_at,
+                        workday_start_minutes, workday_end_minutes, working_days
                  FROM user_profiles WHERE id = ?1",
-                params![&id],
-                map_user_profile_row,
-            );
-
-            match result {
-                Ok(profile) => Ok(Some(profile)),
-                Err(StorageError::Rusqlite(rusqlite::Error::QueryReturnedNoRows)) => Ok(None),
-                Err(err) => Err(map_storage_error(err)),
-            }
-        })
+                    params![&id],
+                    map_user_profile_row,
+                );
+
+                match result {
+                    Ok(profile) => Ok(Some(profile)),
+                    Err(StorageError::Rusqlite(rusqlite::Error::QueryReturnedNoRows)) => Ok(None),
+                    Err(err) => Err(map_storage_error(err)),
+                }
+            },
+        ))
         .await
         .map_err(map_join_error)?
     }
@@ -59,25 +65,28 @@ impl UserProfileRepositoryPort for SqlCipherUserProfileRepository {
         let db = Arc::clone(&self.db);
         let auth0_id = auth0_id.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<Option<UserProfile>> {
-            let conn = db.get_connection()?;
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<Option<UserProfile>> {
+                let conn = db.get_connection()?;
 
-            let result = conn.query_row(
-                "SELECT id, auth0_id, email, org_id, name, first_name, last_name, display_name,
+                let result = conn.query_row(
+                    "SELECT id, auth0_id, email, org_id, name, first_name, last_name, display_name,
                         avatar_url, phone_number, title, department, location, bio,
                         timezone, language, locale, date_format, is_active, email_verified,
-                        two_factor_enabled, last_login_at, last_synced_at, created_at, updated_at
+                        two_factor_enabled, last_login_at, last_synced_at, created_at, updated_at,
+                        workday_start_minutes, workday_end_minutes, working_days
                  FROM user_profiles WHERE auth0_id = ?1",
-                params![&auth0_id],
-                map_user_profile_row,
-            );
-
-            match result {
-                Ok(profile) => Ok(Some(profile)),
-                Err(StorageError::Rusqlite(rusqlite::Error::QueryReturnedNoRows)) => Ok(None),
-                Err(err) => Err(map_storage_error(err)),
-            }
-        })
+                    params![&auth0_id],
+                    map_user_profile_row,
+                );
+
+                match result {
+                    Ok(profile) => Ok(Some(profile)),
+                    Err(StorageError::Rusqlite(rusqlite::Error::QueryReturnedNoRows)) => Ok(None),
+                    Err(err) => Err(map_storage_error(err)),
+                }
+            },
+        ))
         .await
         .map_err(map_join_error)?
     }
@@ -86,49 +95,54 @@ impl UserProfileRepositoryPort for SqlCipherUserProfileRepository {
         let db = Arc::clone(&self.db);
         let email = email.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<Option<UserProfile>> {
-            let conn = db.get_connection()?;
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<Option<UserProfile>> {
+                let conn = db.get_connection()?;
 
-            let result = conn.query_row(
-                "SELECT id, auth0_id, email, org_id, name, first_name, last_name, display_name,
+                let result = conn.query_row(
+                    "SELECT id, auth0_id, email, org_id, name, first_name, last_name, display_name,
                         avatar_url, phone_number, title, department, location, bio,
                         timezone, language, locale, date_format, is_active, email_verified,
-                        two_factor_enabled, last_login_at, last_synced_at, created_at, updated_at
+                        two_factor_enabled, last_login_at, last_synced_at, created_at, updated_at,
+                        workday_start_minutes, workday_end_minutes, working_days
                  FROM user_profiles WHERE email = ?1",
-                params![&email],
-                map_user_profile_row,
-            );
-
-            match result {
-                Ok(profile) => Ok(Some(profile)),
-                Err(StorageError::Rusqlite(rusqlite::Error::QueryReturnedNoRows)) => Ok(None),
-                Err(err) => Err(map_storage_error(err)),
-            }
-        })
+                    params![&email],
+                    map_user_profile_row,
+                );
+
+                match result {
+                    Ok(profile) => Ok(Some(profile)),
+                    Err(StorageError::Rusqlite(rusqlite::Error::QueryReturnedNoRows)) => Ok(None),
+                    Err(err) => Err(map_storage_error(err)),
+                }
+            },
+        ))
         .await
         .map_err(map_join_error)?
     }
 
     async fn create(&self, profile: UserProfile) -> DomainResult<()> {
+        validate_profile(&profile)?;
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             insert_user_profile(&conn, &profile).map_err(map_storage_error)?;
             Ok(())
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
 
     async fn update(&self, profile: UserProfile) -> DomainResult<()> {
+        validate_profile(&profile)?;
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             update_user_profile(&conn, &profile).map_err(map_storage_error)?;
             Ok(())
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -136,39 +150,25 @@ impl UserProfileRepositoryPort for SqlCipherUserProfileRepository {
     async fn get_current_profile(&self) -> DomainResult<Option<UserProfile>> {
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<Option<UserProfile>> {
-            let conn = db.get_connection()?;
-
-            let result = conn.query_row(
-                "SELECT id, auth0_id, email, org_id, name, first_name, last_name, display_name,
-                        avatar_url, phone_number, title, department, location, bio,
-                        timezone, language, locale, date_format, is_active, email_verified,
-                        two_factor_enabled, last_login_at, last_synced_at, created_at, updated_at
-                 FROM user_profiles
-                 ORDER BY created_at ASC
-                 LIMIT 1",
-                &[],
-                map_user_profile_row,
-            );
-
-            match result {
-                Ok(profile) => Ok(Some(profile)),
-                Err(StorageError::Rusqlite(rusqlite::Error::QueryReturnedNoRows)) => Ok(None),
-                Err(err) => Err(map_storage_error(err)),
-            }
-        })
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<Option<UserProfile>> {
+                let conn = db.get_connection()?;
+                query_current_profile(&conn).map_err(map_storage_error)
+            },
+        ))
         .await
         .map_err(map_join_error)?
     }
 
     async fn upsert(&self, profile: UserProfile) -> DomainResult<()> {
+        validate_profile(&profile)?;
         let db = Arc::clone(&self.db);
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             upsert_user_profile(&conn, &profile).map_err(map_storage_error)?;
             Ok(())
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -177,13 +177,13 @@ impl UserProfileRepositoryPort for SqlCipherUserProfileRepository {
         let db = Arc::clone(&self.db);
         let id = id.to_string();
 
-        task::spawn_blocking(move || -> DomainResult<()> {
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<()> {
             let conn = db.get_connection()?;
             conn.execute("DELETE FROM user_profiles WHERE id = ?1", params![&id])
                 .map_err(StorageError::from)
                 .map_err(map_storage_error)?;
             Ok(())
-        })
+        }))
         .await
         .map_err(map_join_error)?
     }
@@ -193,6 +193,11 @@ impl UserProfileRepositoryPort for SqlCipherUserProfileRepository {
 // Helper Functions
 // =============================================================================
 
+/// Reject profiles that fail validation before touching the database
+fn validate_profile(profile: &UserProfile) -> DomainResult<()> {
+    validate_user_profile(profile).map_err(|err| validation_error_to_domain(&err))
+}
+
 /// Map a row to a UserProfile
 fn map_user_profile_row(row: &Row) -> rusqlite::Result<UserProfile> {
     Ok(UserProfile {
@@ -221,6 +226,11 @@ fn map_user_profile_row(row: &Row) -> rusqlite::Result<UserProfile> {
         last_synced_at: row.get(22)?,
         created_at: row.get(23)?,
         updated_at: row.get(24)?,
+        workday: WorkdayConfig {
+            start_minutes: row.get(25)?,
+            end_minutes: row.get(26)?,
+            working_days: WorkdayConfig::parse_working_days(&row.get::<_, String>(27)?),
+        },
     })
 }
 
@@ -229,43 +239,18 @@ fn insert_user_profile(
     conn: &SqlCipherConnection,
     profile: &UserProfile,
 ) -> Result<(), StorageError> {
-    let params: [&dyn ToSql; 25] = [
-        &profile.id,
-        &profile.auth0_id,
-        &profile.email,
-        &profile.org_id,
-        &profile.name,
-        &profile.first_name,
-        &profile.last_name,
-        &profile.display_name,
-        &profile.avatar_url,
-        &profile.phone_number,
-        &profile.title,
-        &profile.department,
-        &profile.location,
-        &profile.bio,
-        &profile.timezone,
-        &profile.language,
-        &profile.locale,
-        &profile.date_format,
-        &bool_to_int(profile.is_active),
-        &bool_to_int(profile.email_verified),
-        &bool_to_int(profile.two_factor_enabled),
-        &profile.last_login_at,
-        &profile.last_synced_at,
-        &profile.created_at,
-        &profile.updated_at,
-    ];
-
-    conn.execute(
-        "INSERT INTO user_profiles (
-            id, auth0_id, email, org_id, name, first_name, last_name, display_name,
-            avatar_url, phone_number, title, department, location, bio,
-            timezone, language, locale, date_format, is_active, email_verified,
-            two_factor_enabled, last_login_at, last_synced_at, created_at, updated_at
-         ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25)",
-        params.as_slice(),
-    )?;
+    with_profile_params(profile, |params| {
+        conn.execute(
+            "INSERT INTO user_profiles (
+                id, auth0_id, email, org_id, name, first_name, last_name, display_name,
+                avatar_url, phone_number, title, department, location, bio,
+                timezone, language, locale, date_format, is_active, email_verified,
+                two_factor_enabled, last_login_at, last_synced_at, created_at, updated_at,
+                workday_start_minutes, workday_end_minutes, working_days
+             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28)",
+            params,
+        )
+    })?;
 
     Ok(())
 }
@@ -275,7 +260,8 @@ fn update_user_profile(
     conn: &SqlCipherConnection,
     profile: &UserProfile,
 ) -> Result<(), StorageError> {
-    let params: [&dyn ToSql; 24] = [
+    let working_days = profile.workday.working_days_csv();
+    let params: [&dyn ToSql; 27] = [
         &profile.auth0_id,
         &profile.email,
         &profile.org_id,
@@ -299,6 +285,9 @@ fn update_user_profile(
         &profile.last_login_at,
         &profile.last_synced_at,
         &profile.updated_at,
+        &profile.workday.start_minutes,
+        &profile.workday.end_minutes,
+        &working_days,
         &profile.id, // WHERE clause
     ];
 
@@ -308,8 +297,9 @@ fn update_user_profile(
             display_name = ?7, avatar_url = ?8, phone_number = ?9, title = ?10,
             department = ?11, location = ?12, bio = ?13, timezone = ?14, language = ?15,
             locale = ?16, date_format = ?17, is_active = ?18, email_verified = ?19,
-            two_factor_enabled = ?20, last_login_at = ?21, last_synced_at = ?22, updated_at = ?23
-         WHERE id = ?24",
+            two_factor_enabled = ?20, last_login_at = ?21, last_synced_at = ?22, updated_at = ?23,
+            workday_start_minutes = ?24, workday_end_minutes = ?25, working_days = ?26
+         WHERE id = ?27",
         params.as_slice(),
     )?;
 
@@ -320,45 +310,71 @@ fn update_user_profile(
 ///
 /// This matches the legacy behavior where the unique constraint is on auth0_id.
 /// If a profile with the same auth0_id already exists, it will be updated.
+/// Workday settings are only written for new profiles; Auth0 sync does not
+/// know them, so an existing profile keeps its own.
 fn upsert_user_profile(
     conn: &SqlCipherConnection,
     profile: &UserProfile,
 ) -> Result<(), StorageError> {
-    let params: [&dyn ToSql; 25] = [
-        &profile.id,
-        &profile.auth0_id,
-        &profile.email,
-        &profile.org_id,
-        &profile.name,
-        &profile.first_name,
-        &profile.last_name,
-        &profile.display_name,
-        &profile.avatar_url,
-        &profile.phone_number,
-        &profile.title,
-        &profile.department,
-        &profile.location,
-        &profile.bio,
-        &profile.timezone,
-        &profile.language,
-        &profile.locale,
-        &profile.date_format,
-        &bool_to_int(profile.is_active),
-        &bool_to_int(profile.email_verified),
-        &bool_to_int(profile.two_factor_enabled),
-        &profile.last_login_at,
-        &profile.last_synced_at,
-        &profile.created_at,
-        &profile.updated_at,
-    ];
+    with_profile_params(profile, |params| conn.execute(UPSERT_USER_PROFILE_SQL, params))?;
+    Ok(())
+}
 
-    conn.execute(
-        "INSERT INTO user_profiles (
+/// Upsert a user profile within `tx`, including its workday settings
+///
+/// Used by configuration import, where the bundle's workday should replace
+/// the local one.
+pub(super) fn import_user_profile(
+    tx: &Transaction<'_>,
+    profile: &UserProfile,
+) -> Result<(), StorageError> {
+    with_profile_params(profile, |params| tx.execute(UPSERT_USER_PROFILE_SQL, params))?;
+    tx.execute(
+        "UPDATE user_profiles
+         SET workday_start_minutes = ?2, workday_end_minutes = ?3, working_days = ?4
+         WHERE auth0_id = ?1",
+        params![
+            &profile.auth0_id,
+            profile.workday.start_minutes,
+            profile.workday.end_minutes,
+            profile.workday.working_days_csv(),
+        ],
+    )?;
+    Ok(())
+}
+
+/// Current profile (first by `created_at`), if any
+pub(super) fn query_current_profile(
+    conn: &SqlCipherConnection,
+) -> Result<Option<UserProfile>, StorageError> {
+    let result = conn.query_row(
+        "SELECT id, auth0_id, email, org_id, name, first_name, last_name, display_name,
+                avatar_url, phone_number, title, department, location, bio,
+                timezone, language, locale, date_format, is_active, email_verified,
+                two_factor_enabled, last_login_at, last_synced_at, created_at, updated_at,
+                workday_start_minutes, workday_end_minutes, working_days
+         FROM user_profiles
+         ORDER BY created_at ASC
+         LIMIT 1",
+        &[],
+        map_user_profile_row,
+    );
+
+    match result {
+        Ok(profile) => Ok(Some(profile)),
+        Err(StorageError::Rusqlite(rusqlite::Error::QueryReturnedNoRows)) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Insert-or-update on `auth0_id`; the workday columns are only set on insert
+const UPSERT_USER_PROFILE_SQL: &str = "INSERT INTO user_profiles (
             id, auth0_id, email, org_id, name, first_name, last_name, display_name,
             avatar_url, phone_number, title, department, location, bio,
             timezone, language, locale, date_format, is_active, email_verified,
-            two_factor_enabled, last_login_at, last_synced_at, created_at, updated_at
-         ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25)
+            two_factor_enabled, last_login_at, last_synced_at, created_at, updated_at,
+            workday_start_minutes, workday_end_minutes, working_days
+         ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28)
          ON CONFLICT(auth0_id) DO UPDATE SET
             id = excluded.id,
             email = excluded.email,
@@ -382,11 +398,43 @@ fn upsert_user_profile(
             two_factor_enabled = excluded.two_factor_enabled,
             last_login_at = excluded.last_login_at,
             last_synced_at = excluded.last_synced_at,
-            updated_at = excluded.updated_at",
-        params.as_slice(),
-    )?;
+            updated_at = excluded.updated_at";
 
-    Ok(())
+/// Call `f` with the 28 column values of `profile`, in insert order
+fn with_profile_params<R>(profile: &UserProfile, f: impl FnOnce(&[&dyn ToSql]) -> R) -> R {
+    let working_days = profile.workday.working_days_csv();
+    let params: [&dyn ToSql; 28] = [
+        &profile.id,
+        &profile.auth0_id,
+        &profile.email,
+        &profile.org_id,
+        &profile.name,
+        &profile.first_name,
+        &profile.last_name,
+        &profile.display_name,
+        &profile.avatar_url,
+        &profile.phone_number,
+        &profile.title,
+        &profile.department,
+        &profile.location,
+        &profile.bio,
+        &profile.timezone,
+        &profile.language,
+        &profile.locale,
+        &profile.date_format,
+        &bool_to_int(profile.is_active),
+        &bool_to_int(profile.email_verified),
+        &bool_to_int(profile.two_factor_enabled),
+        &profile.last_login_at,
+        &profile.last_synced_at,
+        &profile.created_at,
+        &profile.updated_at,
+        &profile.workday.start_minutes,
+        &profile.workday.end_minutes,
+        &working_days,
+    ];
+
+    f(params.as_slice())
 }
 
 // =============================================================================
@@ -479,6 +527,7 @@ mod tests {
             last_synced_at: now,
             created_at: now,
             updated_at: now,
+            workday: WorkdayConfig::default(),
         }
     }
 
@@ -586,4 +635,61 @@ mod tests {
         assert!(!retrieved.email_verified);
         assert!(retrieved.two_factor_enabled);
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_workday_round_trip() {
+        let (db, _temp_dir) = setup_test_db();
+        let repo = SqlCipherUserProfileRepository::new(db);
+        let mut profile = create_test_profile();
+
+        repo.create(profile.clone()).await.expect("create profile");
+        let retrieved = repo.get_by_id(&profile.id).await.expect("get profile").unwrap();
+        assert_eq!(retrieved.workday, WorkdayConfig::default());
+
+        profile.workday = WorkdayConfig {
+            start_minutes: 540,
+            end_minutes: 1200,
+            working_days: vec![7, 1, 2, 3, 4],
+        };
+        repo.update(profile.clone()).await.expect("update profile");
+
+        let retrieved = repo.get_by_id(&profile.id).await.expect("get profile").unwrap();
+        assert_eq!(retrieved.workday, profile.workday);
+
+        // Auth0 sync must not reset configured working hours
+        let mut synced = create_test_profile();
+        synced.name = Some("Synced Name".into());
+        repo.upsert(synced).await.expect("upsert profile");
+
+        let retrieved = repo.get_by_id(&profile.id).await.expect("get profile").unwrap();
+        assert_eq!(retrieved.name, Some("Synced Name".into()));
+        assert_eq!(retrieved.workday, profile.workday);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_upsert_rejects_invalid_email() {
+        let (db, _temp_dir) = setup_test_db();
+        let repo = SqlCipherUserProfileRepository::new(db);
+        let mut profile = create_test_profile();
+        profile.email = "not-an-email".into();
+
+        let err = repo.upsert(profile.clone()).await.expect_err("invalid email rejected");
+        assert!(matches!(err, PulseArcError::InvalidInput(_)));
+        assert!(err.to_string().contains("email: Invalid email format"));
+
+        let retrieved = repo.get_by_id(&profile.id).await.expect("get profile");
+        assert!(retrieved.is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_upsert_accepts_valid_profile() {
+        let (db, _temp_dir) = setup_test_db();
+        let repo = SqlCipherUserProfileRepository::new(db);
+        let profile = create_test_profile();
+
+        repo.upsert(profile.clone()).await.expect("valid profile upserted");
+
+        let retrieved = repo.get_by_id(&profile.id).await.expect("get profile");
+        assert_eq!(retrieved.map(|p| p.email), Some(profile.email));
+    }
 }
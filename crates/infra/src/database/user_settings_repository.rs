@@ -0,0 +1,521 @@
+//! User settings repository implementation using SQLCipher
+//!
+//! Settings live in `user_settings` as one JSON value per `(key, scope)` with
+//! a version counter. Each change is also appended to `user_settings_history`
+//! (old value, new value, timestamp), which is never updated or pruned.
+//!
+//! Some settings are mirrored in the tables their consumers read: the workday
+//! in `user_profiles` and calendar sync preferences in
+//! `calendar_sync_settings`. Resetting to defaults updates those as well.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use pulsearc_common::storage::error::StorageError;
+use pulsearc_common::storage::sqlcipher::SqlCipherConnection;
+use pulsearc_common::storage::types::Transaction;
+use pulsearc_core::user::ports::{
+    SettingDefault, UserSettingsRepository as UserSettingsRepositoryPort,
+};
+use pulsearc_core::user::{UserSetting, USER_SCOPE};
+use pulsearc_domain::{
+    CalendarSyncSettings, PulseArcError, Result as DomainResult, SettingsResetSummary,
+    UserSettingChange, WorkdayConfig,
+};
+use rusqlite::{params, Row, ToSql};
+use serde::de::DeserializeOwned;
+use tokio::task;
+
+use super::feature_flags_repository::DEFAULT_FEATURE_FLAGS;
+use super::manager::DbManager;
+use crate::observability::correlation;
+
+/// SQLCipher-backed implementation of `UserSettingsRepository`
+pub struct SqlCipherUserSettingsRepository {
+    db: Arc<DbManager>,
+}
+
+impl SqlCipherUserSettingsRepository {
+    /// Create a new repository instance
+    pub fn new(db: Arc<DbManager>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl UserSettingsRepositoryPort for SqlCipherUserSettingsRepository {
+    async fn get_value(&self, key: &str, scope: &str) -> DomainResult<Option<String>> {
+        let db = Arc::clone(&self.db);
+        let (key, scope) = (key.to_string(), scope.to_string());
+
+        task::spawn_blocking(correlation::propagate(move || -> DomainResult<Option<String>> {
+            let conn = db.get_connection()?;
+
+            let result = conn.query_row(
+                "SELECT value FROM user_settings WHERE key = ?1 AND scope = ?2",
+                params![&key, &scope],
+                |row| row.get(0),
+            );
+
+            match result {
+                Ok(value) => Ok(Some(value)),
+                Err(StorageError::Rusqlite(rusqlite::Error::QueryReturnedNoRows)) => Ok(None),
+                Err(err) => Err(map_storage_error(err)),
+            }
+        }))
+        .await
+        .map_err(map_join_error)?
+    }
+
+    async fn set_value(
+        &self,
+        key: &str,
+        scope: &str,
+        value: &str,
+    ) -> DomainResult<Option<UserSettingChange>> {
+        let db = Arc::clone(&self.db);
+        let (key, scope, value) = (key.to_string(), scope.to_string(), value.to_string());
+
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<Option<UserSettingChange>> {
+                let mut conn = db.get_connection()?;
+                let changed =
+                    write_setting(&mut conn, &key, &scope, &value).map_err(map_storage_error)?;
+                if !changed {
+                    return Ok(None);
+                }
+
+                conn.query_row(
+                    "SELECT key, scope, version, old_value, new_value, changed_at
+                 FROM user_settings_history
+                 WHERE key = ?1 AND scope = ?2
+                 ORDER BY version DESC
+                 LIMIT 1",
+                    params![&key, &scope],
+                    map_change_row,
+                )
+                .map(Some)
+                .map_err(map_storage_error)
+            },
+        ))
+        .await
+        .map_err(map_join_error)?
+    }
+
+    async fn history(&self, key: &str, scope: &str) -> DomainResult<Vec<UserSettingChange>> {
+        let db = Arc::clone(&self.db);
+        let (key, scope) = (key.to_string(), scope.to_string());
+
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<Vec<UserSettingChange>> {
+                let conn = db.get_connection()?;
+                let mut stmt = conn
+                    .prepare(
+                        "SELECT key, scope, version, old_value, new_value, changed_at
+                     FROM user_settings_history
+                     WHERE key = ?1 AND scope = ?2
+                     ORDER BY version ASC",
+                    )
+                    .map_err(map_storage_error)?;
+                stmt.query_map(params![&key, &scope], map_change_row).map_err(map_storage_error)
+            },
+        ))
+        .await
+        .map_err(map_join_error)?
+    }
+
+    async fn reset_to_defaults(
+        &self,
+        defaults: &[SettingDefault],
+    ) -> DomainResult<SettingsResetSummary> {
+        let db = Arc::clone(&self.db);
+        let defaults = defaults.to_vec();
+
+        task::spawn_blocking(correlation::propagate(
+            move || -> DomainResult<SettingsResetSummary> {
+                let mut conn = db.get_connection()?;
+                reset_settings(&mut conn, &defaults)
+            },
+        ))
+        .await
+        .map_err(map_join_error)?
+    }
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Record the change and store the new value in one transaction
+///
+/// Returns `false` (and writes nothing) if the stored value is already equal.
+fn write_setting(
+    conn: &mut SqlCipherConnection,
+    key: &str,
+    scope: &str,
+    value: &str,
+) -> Result<bool, StorageError> {
+    let now = Utc::now().timestamp();
+    let tx = conn.transaction()?;
+    let changed = record_setting(&tx, key, scope, value, now)?;
+    if changed {
+        tx.commit()?;
+    }
+    Ok(changed)
+}
+
+/// Record and store one value within `tx`, unless it is already stored
+pub(super) fn record_setting(
+    tx: &Transaction<'_>,
+    key: &str,
+    scope: &str,
+    value: &str,
+    now: i64,
+) -> Result<bool, StorageError> {
+    let recorded = tx.execute(
+        "INSERT INTO user_settings_history (key, scope, version, old_value, new_value, changed_at)
+         SELECT ?1, ?2,
+                COALESCE((SELECT version FROM user_settings WHERE key = ?1 AND scope = ?2), 0) + 1,
+                (SELECT value FROM user_settings WHERE key = ?1 AND scope = ?2),
+                ?3, ?4
+         WHERE NOT EXISTS (
+             SELECT 1 FROM user_settings WHERE key = ?1 AND scope = ?2 AND value = ?3
+         )",
+        params![key, scope, value, now],
+    )?;
+    if recorded == 0 {
+        return Ok(false);
+    }
+
+    tx.execute(
+        "INSERT INTO user_settings (key, scope, value, version, updated_at)
+         VALUES (?1, ?2, ?3, 1, ?4)
+         ON CONFLICT(key, scope) DO UPDATE SET
+            value = excluded.value,
+            version = user_settings.version + 1,
+            updated_at = excluded.updated_at",
+        params![key, scope, value, now],
+    )?;
+    Ok(true)
+}
+
+/// Apply every default, the mirrored tables and the feature flag reset in one
+/// transaction
+fn reset_settings(
+    conn: &mut SqlCipherConnection,
+    defaults: &[SettingDefault],
+) -> DomainResult<SettingsResetSummary> {
+    let now = Utc::now().timestamp();
+    let tx = conn.transaction().map_err(map_storage_error)?;
+    let mut summary = SettingsResetSummary::default();
+
+    for default in defaults {
+        summary.settings_changed +=
+            reset_stored_scopes(&tx, default, now).map_err(map_storage_error)?;
+        if default.user_wide
+            && record_setting(&tx, default.key, USER_SCOPE, &default.value, now)
+                .map_err(map_storage_error)?
+        {
+            summary.settings_changed += 1;
+        }
+
+        if default.key == WorkdayConfig::KEY {
+            let workday: WorkdayConfig = decode_default(default)?;
+            reset_profile_workdays(&tx, &workday, now).map_err(map_storage_error)?;
+        } else if default.key == CalendarSyncSettings::KEY {
+            let calendar: CalendarSyncSettings = decode_default(default)?;
+            summary.calendar_accounts =
+                reset_calendar_preferences(&tx, &calendar, now).map_err(map_storage_error)?;
+        }
+    }
+
+    summary.feature_flags = reset_feature_flags(&tx, now).map_err(map_storage_error)?;
+
+    tx.commit().map_err(map_storage_error)?;
+    Ok(summary)
+}
+
+/// Set every stored scope of the key to the default, recording each change
+fn reset_stored_scopes(
+    tx: &Transaction<'_>,
+    default: &SettingDefault,
+    now: i64,
+) -> Result<usize, StorageError> {
+    let recorded = tx.execute(
+        "INSERT INTO user_settings_history (key, scope, version, old_value, new_value, changed_at)
+         SELECT key, scope, version + 1, value, ?2, ?3
+         FROM user_settings
+         WHERE key = ?1 AND value <> ?2",
+        params![default.key, &default.value, now],
+    )?;
+    tx.execute(
+        "UPDATE user_settings
+         SET value = ?2, version = version + 1, updated_at = ?3
+         WHERE key = ?1 AND value <> ?2",
+        params![default.key, &default.value, now],
+    )?;
+    Ok(recorded)
+}
+
+fn reset_profile_workdays(
+    tx: &Transaction<'_>,
+    workday: &WorkdayConfig,
+    now: i64,
+) -> Result<usize, StorageError> {
+    tx.execute(
+        "UPDATE user_profiles
+         SET workday_start_minutes = ?1, workday_end_minutes = ?2, working_days = ?3,
+             updated_at = ?4
+         WHERE workday_start_minutes <> ?1 OR workday_end_minutes <> ?2 OR working_days <> ?3",
+        params![workday.start_minutes, workday.end_minutes, workday.working_days_csv(), now],
+    )
+}
+
+/// Restore preference columns; sync token and last sync time are kept
+fn reset_calendar_preferences(
+    tx: &Transaction<'_>,
+    calendar: &CalendarSyncSettings,
+    now: i64,
+) -> Result<usize, StorageError> {
+    tx.execute(
+        "UPDATE calendar_sync_settings
+         SET enabled = ?1, sync_interval_minutes = ?2, include_all_day_events = ?3,
+             min_event_duration_minutes = ?4, lookback_hours = ?5, lookahead_hours = ?6,
+             excluded_calendar_ids = ?7, updated_at = ?8
+         WHERE enabled <> ?1 OR sync_interval_minutes <> ?2 OR include_all_day_events <> ?3
+            OR min_event_duration_minutes <> ?4 OR lookback_hours <> ?5
+            OR lookahead_hours <> ?6 OR excluded_calendar_ids <> ?7",
+        params![
+            calendar.enabled,
+            calendar.sync_interval_minutes,
+            calendar.include_all_day_events,
+            calendar.min_event_duration_minutes,
+            calendar.lookback_hours,
+            calendar.lookahead_hours,
+            calendar.excluded_calendar_ids.join(","),
+            now,
+        ],
+    )
+}
+
+/// Restore the seeded flags and drop every other stored flag
+///
+/// Returns how many flags were changed, restored or dropped.
+fn reset_feature_flags(tx: &Transaction<'_>, now: i64) -> Result<usize, StorageError> {
+    let placeholders = vec!["?"; DEFAULT_FEATURE_FLAGS.len()].join(", ");
+    let names: Vec<&dyn ToSql> =
+        DEFAULT_FEATURE_FLAGS.iter().map(|(name, _, _)| name as &dyn ToSql).collect();
+    let mut changed = tx.execute(
+        &format!("DELETE FROM feature_flags WHERE flag_name NOT IN ({placeholders})"),
+        &names,
+    )?;
+
+    for (name, enabled, description) in DEFAULT_FEATURE_FLAGS {
+        changed += tx.execute(
+            "INSERT INTO feature_flags (flag_name, enabled, description, updated_at)
+             VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT(flag_name) DO UPDATE SET
+                enabled = excluded.enabled,
+                updated_at = excluded.updated_at
+             WHERE feature_flags.enabled <> excluded.enabled",
+            params![name, enabled, description, now],
+        )?;
+    }
+    Ok(changed)
+}
+
+fn decode_default<S: DeserializeOwned>(default: &SettingDefault) -> DomainResult<S> {
+    serde_json::from_str(&default.value).map_err(|err| {
+        PulseArcError::InvalidInput(format!("invalid default for '{}': {err}", default.key))
+    })
+}
+
+fn map_change_row(row: &Row) -> rusqlite::Result<UserSettingChange> {
+    Ok(UserSettingChange {
+        key: row.get(0)?,
+        scope: row.get(1)?,
+        version: row.get(2)?,
+        old_value: row.get(3)?,
+        new_value: row.get(4)?,
+        changed_at: row.get(5)?,
+    })
+}
+
+// =============================================================================
+// Error Mapping
+// =============================================================================
+
+fn map_storage_error(err: StorageError) -> PulseArcError {
+    match err {
+        StorageError::Rusqlite(err) => PulseArcError::Database(format!("SQLite error: {err}")),
+        other => PulseArcError::Database(format!("Storage error: {other}")),
+    }
+}
+
+fn map_join_error(err: task::JoinError) -> PulseArcError {
+    PulseArcError::Internal(format!("Task join error: {err}"))
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use pulsearc_core::user::{get_setting, reset_settings_to_defaults, set_setting};
+    use pulsearc_domain::IdleSettings;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn setup_test_db() -> (Arc<DbManager>, TempDir) {
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let db_path = temp_dir.path().join("test.db");
+        let manager = DbManager::new(db_path.to_str().unwrap(), 5, Some("test-key"))
+            .expect("create db manager");
+        manager.run_migrations().expect("run migrations");
+        (Arc::new(manager), temp_dir)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_set_records_history_and_get_returns_latest() {
+        let (db, _temp_dir) = setup_test_db();
+        let repo = SqlCipherUserSettingsRepository::new(db);
+
+        let first = repo.set_value("idle", "", r#"{"v":1}"#).await.expect("first set");
+        let first = first.expect("first change recorded");
+        assert_eq!(first.version, 1);
+        assert_eq!(first.old_value, None);
+
+        repo.set_value("idle", "", r#"{"v":2}"#).await.expect("second set");
+        let third = repo.set_value("idle", "", r#"{"v":3}"#).await.expect("third set");
+        assert_eq!(third.map(|change| change.old_value), Some(Some(r#"{"v":2}"#.to_string())));
+
+        let latest = repo.get_value("idle", "").await.expect("get");
+        assert_eq!(latest.as_deref(), Some(r#"{"v":3}"#));
+
+        let history = repo.history("idle", "").await.expect("history");
+        let versions: Vec<_> = history.iter().map(|change| change.version).collect();
+        let old_values: Vec<_> = history.iter().map(|change| change.old_value.as_deref()).collect();
+        assert_eq!(versions, vec![1, 2, 3]);
+        assert_eq!(old_values, vec![None, Some(r#"{"v":1}"#), Some(r#"{"v":2}"#)]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_unchanged_value_is_not_recorded() {
+        let (db, _temp_dir) = setup_test_db();
+        let repo = SqlCipherUserSettingsRepository::new(db);
+
+        repo.set_value("workday", "", "{}").await.expect("first set");
+        let repeat = repo.set_value("workday", "", "{}").await.expect("repeat set");
+
+        assert!(repeat.is_none());
+        assert_eq!(repo.history("workday", "").await.expect("history").len(), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_scopes_are_independent() {
+        let (db, _temp_dir) = setup_test_db();
+        let repo = SqlCipherUserSettingsRepository::new(db);
+
+        repo.set_value("calendar_sync", "a@example.com", "1").await.expect("set a");
+        repo.set_value("calendar_sync", "b@example.com", "2").await.expect("set b");
+
+        let a = repo.get_value("calendar_sync", "a@example.com").await.expect("get a");
+        assert_eq!(a.as_deref(), Some("1"));
+        assert!(repo.get_value("calendar_sync", "").await.expect("get user").is_none());
+        assert_eq!(repo.history("calendar_sync", "b@example.com").await.expect("h").len(), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_reset_restores_defaults_and_keeps_tracked_data() {
+        let (db, _temp_dir) = setup_test_db();
+        let repo = SqlCipherUserSettingsRepository::new(Arc::clone(&db));
+        let account = "a@example.com";
+
+        let idle = IdleSettings { pause_on_idle: false, idle_threshold_secs: 60 };
+        let workday = WorkdayConfig { start_minutes: 600, end_minutes: 900, working_days: vec![1] };
+        let calendar = CalendarSyncSettings {
+            enabled: false,
+            sync_token: Some("token".into()),
+            ..CalendarSyncSettings::default()
+        };
+        set_setting(&repo, USER_SCOPE, &idle).await.expect("set idle");
+        set_setting(&repo, USER_SCOPE, &workday).await.expect("set workday");
+        set_setting(&repo, account, &calendar).await.expect("set calendar");
+
+        {
+            let conn = db.get_connection().expect("connection");
+            conn.execute(
+                "INSERT INTO calendar_sync_settings (id, user_email, enabled, sync_interval_minutes,
+                     sync_token, created_at, updated_at, idempotency_key)
+                 VALUES ('cal-1', ?1, 0, 5, 'token', 0, 0, 'calendar_sync_settings:a')",
+                params![account],
+            )
+            .expect("insert calendar settings");
+            conn.execute(
+                "UPDATE feature_flags SET enabled = 0 WHERE flag_name = 'new_blocks_cmd'",
+                params![],
+            )
+            .expect("override flag");
+            conn.execute(
+                "INSERT INTO feature_flags (flag_name, enabled, updated_at) VALUES ('sap', 1, 0)",
+                params![],
+            )
+            .expect("add flag");
+            conn.execute(
+                "INSERT INTO activity_snapshots (id, timestamp, activity_context_json,
+                     detected_activity, primary_app, created_at)
+                 VALUES ('snap-1', 100, '{}', 'coding', 'Code', 100)",
+                params![],
+            )
+            .expect("insert snapshot");
+            conn.execute(
+                "INSERT INTO time_entries (id, start_time, description) VALUES ('entry-1', 100, 'work')",
+                params![],
+            )
+            .expect("insert entry");
+        }
+
+        let summary = reset_settings_to_defaults(&repo).await.expect("reset");
+
+        assert_eq!(summary.settings_changed, 3);
+        assert_eq!(summary.calendar_accounts, 1);
+        assert_eq!(summary.feature_flags, 2);
+        assert_eq!(
+            get_setting::<IdleSettings>(&repo, USER_SCOPE).await.expect("idle"),
+            Some(IdleSettings::default())
+        );
+        assert_eq!(
+            get_setting::<WorkdayConfig>(&repo, USER_SCOPE).await.expect("workday"),
+            Some(WorkdayConfig::default())
+        );
+        assert_eq!(
+            get_setting::<CalendarSyncSettings>(&repo, account).await.expect("calendar"),
+            Some(CalendarSyncSettings::default())
+        );
+        let history = repo.history(IdleSettings::KEY, USER_SCOPE).await.expect("history");
+        assert_eq!(history.len(), 2);
+
+        let conn = db.get_connection().expect("connection");
+        let (enabled, interval, token): (bool, i64, Option<String>) = conn
+            .query_row(
+                "SELECT enabled, sync_interval_minutes, sync_token FROM calendar_sync_settings",
+                &[],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .expect("calendar row");
+        assert_eq!((enabled, interval, token.as_deref()), (true, 30, Some("token")));
+
+        let count =
+            |sql: &str| -> i64 { conn.query_row(sql, &[], |row| row.get(0)).expect("count") };
+        assert_eq!(
+            count("SELECT enabled FROM feature_flags WHERE flag_name = 'new_blocks_cmd'"),
+            1
+        );
+        assert_eq!(count("SELECT COUNT(*) FROM feature_flags WHERE flag_name = 'sap'"), 0);
+        assert_eq!(count("SELECT COUNT(*) FROM activity_snapshots"), 1);
+        assert_eq!(count("SELECT COUNT(*) FROM time_entries"), 1);
+    }
+}
@@ -0,0 +1,263 @@
+//! Background WAL checkpointing.
+//!
+//! SQLite's automatic checkpoints (`wal_autocheckpoint`) copy frames back into
+//! the database but never shrink the `-wal` file, and they fall behind under
+//! sustained writes. [`WalCheckpointer`] runs `PRAGMA wal_checkpoint(TRUNCATE)`
+//! on a background task whenever the WAL reaches a size threshold or the
+//! configured interval has passed since the last checkpoint.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+use super::manager::DbManager;
+use crate::observability::correlation;
+
+/// When the background task truncates the WAL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WalCheckpointPolicy {
+    /// How often the WAL size is checked.
+    pub check_interval: Duration,
+    /// Checkpoint a non-empty WAL at least this often.
+    pub max_interval: Duration,
+    /// Checkpoint as soon as the WAL reaches this many bytes.
+    pub size_threshold_bytes: u64,
+}
+
+impl Default for WalCheckpointPolicy {
+    fn default() -> Self {
+        Self {
+            check_interval: Duration::from_secs(30),
+            max_interval: Duration::from_secs(300),
+            size_threshold_bytes: 32 * 1024 * 1024,
+        }
+    }
+}
+
+impl WalCheckpointPolicy {
+    /// Whether a WAL of `wal_size_bytes`, last checkpointed `since_last` ago,
+    /// should be checkpointed now. An empty WAL never is.
+    pub fn should_checkpoint(&self, wal_size_bytes: u64, since_last: Duration) -> bool {
+        wal_size_bytes > 0
+            && (wal_size_bytes >= self.size_threshold_bytes || since_last >= self.max_interval)
+    }
+}
+
+/// Handle to the background checkpoint task.
+///
+/// The task holds only a weak reference to the [`DbManager`] and exits when
+/// the manager is dropped, [`stop`](Self::stop) is called, or the handle is
+/// dropped.
+pub struct WalCheckpointer {
+    cancellation: CancellationToken,
+    handle: Option<JoinHandle<()>>,
+    checkpoints: Arc<AtomicU64>,
+}
+
+impl WalCheckpointer {
+    /// Spawn the checkpoint task for `db` on the current Tokio runtime.
+    pub fn start(db: &Arc<DbManager>, policy: WalCheckpointPolicy) -> Self {
+        let cancellation = CancellationToken::new();
+        let checkpoints = Arc::new(AtomicU64::new(0));
+        let handle = tokio::spawn(run(
+            Arc::downgrade(db),
+            policy,
+            cancellation.clone(),
+            Arc::clone(&checkpoints),
+        ));
+
+        info!(
+            check_interval_secs = policy.check_interval.as_secs(),
+            max_interval_secs = policy.max_interval.as_secs(),
+            size_threshold_bytes = policy.size_threshold_bytes,
+            "WAL checkpointer started"
+        );
+
+        Self { cancellation, handle: Some(handle), checkpoints }
+    }
+
+    /// Number of checkpoints the task has completed.
+    pub fn checkpoint_count(&self) -> u64 {
+        self.checkpoints.load(Ordering::SeqCst)
+    }
+
+    /// Returns true while the background task is running.
+    pub fn is_running(&self) -> bool {
+        self.handle.as_ref().is_some_and(|handle| !handle.is_finished())
+    }
+
+    /// Cancel the task and wait for it to exit.
+    pub async fn stop(&mut self) {
+        self.cancellation.cancel();
+        if let Some(handle) = self.handle.take() {
+            if let Err(err) = handle.await {
+                warn!(error = %err, "WAL checkpointer task failed");
+            }
+        }
+    }
+}
+
+impl Drop for WalCheckpointer {
+    fn drop(&mut self) {
+        self.cancellation.cancel();
+    }
+}
+
+async fn run(
+    db: Weak<DbManager>,
+    policy: WalCheckpointPolicy,
+    cancel: CancellationToken,
+    checkpoints: Arc<AtomicU64>,
+) {
+    let mut last_checkpoint = Instant::now();
+
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => break,
+            _ = tokio::time::sleep(policy.check_interval) => {}
+        }
+
+        let Some(db) = db.upgrade() else {
+            debug!("database manager dropped; stopping WAL checkpointer");
+            break;
+        };
+
+        let wal_size = db.wal_size_bytes();
+        if !policy.should_checkpoint(wal_size, last_checkpoint.elapsed()) {
+            continue;
+        }
+
+        // The checkpoint blocks on SQLite locks; keep it off the runtime
+        let result =
+            tokio::task::spawn_blocking(correlation::propagate(move || db.checkpoint_wal())).await;
+        match result {
+            Ok(Ok(checkpoint)) if !checkpoint.busy => {
+                last_checkpoint = Instant::now();
+                checkpoints.fetch_add(1, Ordering::SeqCst);
+                debug!(
+                    wal_size_before = wal_size,
+                    frames = checkpoint.checkpointed_frames,
+                    "WAL checkpoint completed"
+                );
+            }
+            Ok(Ok(checkpoint)) => {
+                debug!(
+                    log_frames = checkpoint.log_frames,
+                    checkpointed_frames = checkpoint.checkpointed_frames,
+                    "WAL checkpoint blocked by active connections; retrying next tick"
+                );
+            }
+            Ok(Err(err)) => warn!(error = %err, "WAL checkpoint failed"),
+            Err(err) => warn!(error = %err, "WAL checkpoint task failed"),
+        }
+    }
+
+    debug!("WAL checkpointer stopped");
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    const TEST_KEY: &str = "test_key_64_chars_long_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+
+    fn setup_manager() -> (Arc<DbManager>, TempDir) {
+        let temp_dir = TempDir::new().expect("temp dir created");
+        let db_path = temp_dir.path().join("wal_test.db");
+        let manager =
+            Arc::new(DbManager::new(&db_path, 4, Some(TEST_KEY)).expect("manager created"));
+        manager.run_migrations().expect("migrations run");
+        (manager, temp_dir)
+    }
+
+    /// Write ~4 MB of snapshots in separate transactions.
+    fn sustained_writes(manager: &DbManager) {
+        let conn = manager.get_connection().expect("connection acquired");
+        for batch in 0..20_i64 {
+            conn.execute(
+                "WITH RECURSIVE seq(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM seq WHERE n < 100)
+                 INSERT INTO activity_snapshots (id, timestamp, activity_context_json, detected_activity, primary_app, processed, created_at, is_idle)
+                 SELECT 'snap-' || ?1 || '-' || n, 1700000000 + n, hex(randomblob(1000)), 'working', 'VSCode', 0, 1700000000, 0
+                 FROM seq",
+                rusqlite::params![batch],
+            )
+            .expect("insert snapshots");
+        }
+    }
+
+    fn policy(size_threshold_bytes: u64) -> WalCheckpointPolicy {
+        WalCheckpointPolicy {
+            check_interval: Duration::from_millis(50),
+            max_interval: Duration::from_secs(3600),
+            size_threshold_bytes,
+        }
+    }
+
+    #[test]
+    fn policy_checkpoints_on_size_or_interval() {
+        let policy = WalCheckpointPolicy {
+            check_interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(60),
+            size_threshold_bytes: 1024,
+        };
+
+        assert!(!policy.should_checkpoint(512, Duration::from_secs(10)));
+        assert!(policy.should_checkpoint(1024, Duration::from_secs(10)));
+        assert!(policy.should_checkpoint(512, Duration::from_secs(60)));
+        assert!(!policy.should_checkpoint(0, Duration::from_secs(600)), "empty WAL is skipped");
+    }
+
+    #[test]
+    fn checkpoint_truncates_wal_after_sustained_writes() {
+        let (manager, _temp_dir) = setup_manager();
+
+        sustained_writes(&manager);
+        let before = manager.wal_size_bytes();
+        assert!(before > 0, "writes should grow the WAL");
+
+        let checkpoint = manager.checkpoint_wal().expect("checkpoint succeeds");
+
+        assert!(!checkpoint.busy);
+        assert_eq!(manager.wal_size_bytes(), 0, "TRUNCATE empties the WAL ({before} bytes before)");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn checkpointer_respects_size_threshold() {
+        let (manager, _temp_dir) = setup_manager();
+        sustained_writes(&manager);
+        let wal_size = manager.wal_size_bytes();
+
+        // Threshold above the current WAL size: nothing happens
+        let mut idle = WalCheckpointer::start(&manager, policy(wal_size + 1));
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert_eq!(idle.checkpoint_count(), 0);
+        assert_eq!(manager.wal_size_bytes(), wal_size);
+        idle.stop().await;
+        assert!(!idle.is_running());
+
+        // Threshold reached: the WAL is truncated
+        let mut active = WalCheckpointer::start(&manager, policy(wal_size));
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(active.checkpoint_count() >= 1);
+        assert_eq!(manager.wal_size_bytes(), 0);
+        active.stop().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn checkpointer_exits_when_manager_is_dropped() {
+        let (manager, _temp_dir) = setup_manager();
+        let checkpointer = WalCheckpointer::start(&manager, policy(u64::MAX));
+
+        drop(manager);
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        assert!(!checkpointer.is_running());
+    }
+}
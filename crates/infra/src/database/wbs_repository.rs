@@ -0,0 +1,218 @@
+//! WBS repository implementation using SQLCipher
+//!
+//! Reads the locally cached SAP WBS elements (`wbs_cache`) for project
+//! matching, validation and search. Only released (`REL`) elements are
+//! returned.
+
+use std::sync::Arc;
+
+use pulsearc_common::storage::error::StorageError;
+use pulsearc_common::storage::sqlcipher::SqlCipherConnection;
+use pulsearc_core::classification::ports::WbsRepository as WbsRepositoryPort;
+use pulsearc_domain::types::WbsElement;
+use pulsearc_domain::{PulseArcError, Result as DomainResult};
+use rusqlite::{Row, ToSql};
+
+use super::manager::DbManager;
+
+const WBS_COLUMNS: &str = "w.wbs_code, w.project_def, w.project_name, w.description, w.status,
+     w.cached_at, w.opportunity_id, w.deal_name, w.target_company_name, w.counterparty,
+     w.industry, w.region, w.amount, w.stage_name, w.project_code";
+
+const COUNT_ACTIVE_SQL: &str = "SELECT COUNT(*) FROM wbs_cache WHERE status = 'REL'";
+
+const LAST_SYNC_SQL: &str = "SELECT MAX(cached_at) FROM wbs_cache";
+
+/// SQLCipher-backed implementation of `WbsRepository`
+pub struct SqlCipherWbsRepository {
+    db: Arc<DbManager>,
+}
+
+impl SqlCipherWbsRepository {
+    /// Create a new repository instance
+    pub fn new(db: Arc<DbManager>) -> Self {
+        Self { db }
+    }
+}
+
+impl WbsRepositoryPort for SqlCipherWbsRepository {
+    fn count_active_wbs(&self) -> DomainResult<i64> {
+        let conn = self.db.get_connection()?;
+        conn.query_row(COUNT_ACTIVE_SQL, &[], |row| row.get(0)).map_err(map_storage_error)
+    }
+
+    fn get_last_sync_timestamp(&self) -> DomainResult<Option<i64>> {
+        let conn = self.db.get_connection()?;
+        conn.query_row(LAST_SYNC_SQL, &[], |row| row.get(0)).map_err(map_storage_error)
+    }
+
+    fn load_common_projects(&self, limit: usize) -> DomainResult<Vec<WbsElement>> {
+        let conn = self.db.get_connection()?;
+        let sql = format!(
+            "SELECT {WBS_COLUMNS} FROM wbs_cache w
+             WHERE w.status = 'REL'
+             ORDER BY w.cached_at DESC, w.wbs_code ASC
+             LIMIT ?1"
+        );
+        let limit = sql_limit(limit);
+        query_elements(&conn, &sql, &[&limit]).map_err(map_storage_error)
+    }
+
+    fn fts5_search_keyword(&self, keyword: &str, limit: usize) -> DomainResult<Vec<WbsElement>> {
+        let conn = self.db.get_connection()?;
+        let sql = format!(
+            "SELECT {WBS_COLUMNS} FROM wbs_cache_fts f
+             JOIN wbs_cache w ON w.rowid = f.rowid
+             WHERE wbs_cache_fts MATCH ?1 AND w.status = 'REL'
+             ORDER BY bm25(wbs_cache_fts)
+             LIMIT ?2"
+        );
+        let phrase = fts5_phrase(keyword);
+        let limit = sql_limit(limit);
+        query_elements(&conn, &sql, &[&phrase, &limit]).map_err(map_storage_error)
+    }
+
+    fn get_wbs_by_project_def(&self, project_def: &str) -> DomainResult<Option<WbsElement>> {
+        let conn = self.db.get_connection()?;
+        let sql = format!(
+            "SELECT {WBS_COLUMNS} FROM wbs_cache w
+             WHERE w.project_def = ?1 AND w.status = 'REL'
+             ORDER BY w.cached_at DESC, w.wbs_code ASC
+             LIMIT 1"
+        );
+        query_elements(&conn, &sql, &[&project_def])
+            .map(|elements| elements.into_iter().next())
+            .map_err(map_storage_error)
+    }
+
+    fn get_wbs_by_wbs_code(&self, wbs_code: &str) -> DomainResult<Option<WbsElement>> {
+        let conn = self.db.get_connection()?;
+        let sql = format!(
+            "SELECT {WBS_COLUMNS} FROM wbs_cache w
+             WHERE w.wbs_code = ?1 AND w.status = 'REL'"
+        );
+        query_elements(&conn, &sql, &[&wbs_code])
+            .map(|elements| elements.into_iter().next())
+            .map_err(map_storage_error)
+    }
+}
+
+fn query_elements(
+    conn: &SqlCipherConnection,
+    sql: &str,
+    params: &[&dyn ToSql],
+) -> Result<Vec<WbsElement>, StorageError> {
+    let mut stmt = conn.prepare(sql)?;
+    stmt.query_map(params, map_wbs_row)
+}
+
+fn map_wbs_row(row: &Row<'_>) -> rusqlite::Result<WbsElement> {
+    Ok(WbsElement {
+        wbs_code: row.get(0)?,
+        project_def: row.get(1)?,
+        project_name: row.get(2)?,
+        description: row.get(3)?,
+        status: row.get(4)?,
+        cached_at: row.get(5)?,
+        opportunity_id: row.get(6)?,
+        deal_name: row.get(7)?,
+        target_company_name: row.get(8)?,
+        counterparty: row.get(9)?,
+        industry: row.get(10)?,
+        region: row.get(11)?,
+        amount: row.get(12)?,
+        stage_name: row.get(13)?,
+        project_code: row.get(14)?,
+    })
+}
+
+/// Quote `keyword` as a single FTS5 phrase so operators in user input are
+/// matched literally.
+fn fts5_phrase(keyword: &str) -> String {
+    format!("\"{}\"", keyword.replace('"', "\"\""))
+}
+
+fn sql_limit(limit: usize) -> i64 {
+    i64::try_from(limit).unwrap_or(i64::MAX)
+}
+
+fn map_storage_error(err: StorageError) -> PulseArcError {
+    match err {
+        StorageError::WrongKeyOrNotEncrypted => {
+            PulseArcError::Database("Database key error or not encrypted".into())
+        }
+        StorageError::Connection(msg) => PulseArcError::Database(msg),
+        StorageError::Query(msg) => PulseArcError::Database(msg),
+        StorageError::DatabaseError(msg) => PulseArcError::Database(msg),
+        StorageError::Rusqlite(err) => PulseArcError::Database(format!("SQLite error: {err}")),
+        _ => PulseArcError::Database(format!("Storage error: {err}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    const TEST_KEY: &str = "test_key_64_chars_long_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+
+    fn setup_repo() -> (SqlCipherWbsRepository, Arc<DbManager>, TempDir) {
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let db_path = temp_dir.path().join("wbs.db");
+        let manager = Arc::new(
+            DbManager::new(db_path.to_str().expect("utf8 path"), 4, Some(TEST_KEY)).expect("db"),
+        );
+        manager.run_migrations().expect("run migrations");
+        (SqlCipherWbsRepository::new(Arc::clone(&manager)), manager, temp_dir)
+    }
+
+    fn insert(db: &DbManager, wbs_code: &str, description: &str, status: &str, cached_at: i64) {
+        let conn = db.get_connection().expect("connection");
+        let project_def = wbs_code.split('.').next().unwrap_or(wbs_code).to_string();
+        let params: [&dyn ToSql; 6] =
+            [&wbs_code, &project_def, &description, &status, &cached_at, &(cached_at + 86_400)];
+        conn.execute(
+            "INSERT INTO wbs_cache (wbs_code, project_def, project_name, description, status,
+                                    cached_at, expires_at)
+             VALUES (?1, ?2, 'Project Astro', ?3, ?4, ?5, ?6)",
+            params.as_slice(),
+        )
+        .expect("insert wbs");
+    }
+
+    #[test]
+    fn loads_active_elements_most_recent_first() {
+        let (repo, db, _temp_dir) = setup_repo();
+        insert(&db, "USC0063201.1.1", "Cloud migration", "REL", 100);
+        insert(&db, "USC0063201.1.2", "Due diligence", "REL", 200);
+        insert(&db, "USC0063300.1.1", "Closed work", "CLSD", 300);
+
+        let elements = repo.load_common_projects(10).expect("load");
+        let codes: Vec<&str> = elements.iter().map(|e| e.wbs_code.as_str()).collect();
+
+        assert_eq!(codes, vec!["USC0063201.1.2", "USC0063201.1.1"]);
+        assert_eq!(repo.count_active_wbs().expect("count"), 2);
+        assert_eq!(repo.get_last_sync_timestamp().expect("last sync"), Some(300));
+        assert_eq!(repo.load_common_projects(1).expect("load").len(), 1);
+    }
+
+    #[test]
+    fn looks_up_by_code_and_searches_descriptions() {
+        let (repo, db, _temp_dir) = setup_repo();
+        insert(&db, "USC0063201.1.1", "Cloud migration", "REL", 100);
+        insert(&db, "USC0063201.1.2", "Due diligence", "REL", 200);
+
+        let element = repo.get_wbs_by_wbs_code("USC0063201.1.1").expect("lookup").expect("found");
+        assert_eq!(element.description.as_deref(), Some("Cloud migration"));
+        assert!(repo.get_wbs_by_wbs_code("missing").expect("lookup").is_none());
+
+        let by_def = repo.get_wbs_by_project_def("USC0063201").expect("lookup").expect("found");
+        assert_eq!(by_def.wbs_code, "USC0063201.1.2");
+
+        let matches = repo.fts5_search_keyword("diligence", 5).expect("search");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].wbs_code, "USC0063201.1.2");
+        assert!(repo.fts5_search_keyword("\"unbalanced", 5).expect("search").is_empty());
+    }
+}
@@ -1,10 +1,17 @@
+use std::sync::Arc;
 use std::time::Duration;
 
 use pulsearc_domain::PulseArcError;
+use reqwest::header::HeaderValue;
 use reqwest::{Client as ReqwestClient, Method, RequestBuilder, Response};
-use tracing::debug;
+use tracing::{debug, warn};
 
 use crate::errors::InfraError;
+use crate::observability::correlation::{current_correlation_id, CORRELATION_ID_HEADER};
+use crate::observability::metrics::PerformanceMetrics;
+
+/// Operation name used for retry metrics by [`HttpClient::send`]
+pub const DEFAULT_OPERATION: &str = "http.request";
 
 /// HTTP client with built-in retry and timeout support.
 #[derive(Clone)]
@@ -12,6 +19,7 @@ pub struct HttpClient {
     client: ReqwestClient,
     max_attempts: usize,
     base_backoff: Duration,
+    retry_metrics: Option<Arc<PerformanceMetrics>>,
 }
 
 impl HttpClient {
@@ -33,9 +41,33 @@ impl HttpClient {
         self.client.request(method, url)
     }
 
+    /// Record attempt counts of every request in `metrics`.
+    pub fn with_retry_metrics(mut self, metrics: Arc<PerformanceMetrics>) -> Self {
+        self.retry_metrics = Some(metrics);
+        self
+    }
+
     /// Execute the provided request builder with retry semantics.
+    ///
+    /// Inside a correlation scope the id is sent as the
+    /// [`CORRELATION_ID_HEADER`] header and attached to request logs.
     pub async fn send(&self, builder: RequestBuilder) -> Result<Response, PulseArcError> {
+        self.send_operation(DEFAULT_OPERATION, builder).await
+    }
+
+    /// Like [`send`](Self::send), recording retry metrics under `operation`.
+    ///
+    /// With retry metrics configured, a 2xx/3xx response records the number
+    /// of attempts it took, and a retryable failure (5xx or transport error)
+    /// on the last attempt counts as exhausted. Other failures are not
+    /// retried and record nothing.
+    pub async fn send_operation(
+        &self,
+        operation: &str,
+        builder: RequestBuilder,
+    ) -> Result<Response, PulseArcError> {
         let attempts = self.max_attempts.max(1);
+        let correlation_id = current_correlation_id();
 
         for attempt in 0..attempts {
             let cloned_builder = builder.try_clone().ok_or_else(|| {
@@ -44,35 +76,69 @@ impl HttpClient {
                 )
             })?;
 
-            let request = cloned_builder.build().map_err(|err| {
+            let mut request = cloned_builder.build().map_err(|err| {
                 let infra: InfraError = err.into();
                 PulseArcError::from(infra)
             })?;
 
+            if let Some(id) = correlation_id.as_deref() {
+                match HeaderValue::from_str(id) {
+                    Ok(value) => {
+                        request.headers_mut().insert(CORRELATION_ID_HEADER, value);
+                    }
+                    Err(err) => warn!(error = %err, "correlation id is not a valid header value"),
+                }
+            }
+
             let method = request.method().clone();
             let url = request.url().clone();
-            debug!(attempt = attempt + 1, %method, %url, "sending HTTP request");
+            let correlation_id = correlation_id.as_deref();
+            debug!(attempt = attempt + 1, %method, %url, correlation_id, "sending HTTP request");
 
             match self.client.execute(request).await {
                 Ok(response) => {
                     let status = response.status();
-                    debug!(attempt = attempt + 1, %method, %url, %status, "received HTTP response");
+                    debug!(
+                        attempt = attempt + 1,
+                        %method,
+                        %url,
+                        %status,
+                        correlation_id,
+                        "received HTTP response"
+                    );
 
                     if status.is_server_error() && attempt + 1 < attempts {
                         self.sleep_with_backoff(attempt + 1).await;
                         continue;
                     }
 
+                    if status.is_server_error() {
+                        self.record_exhausted(operation);
+                    } else if status.is_success() || status.is_redirection() {
+                        self.record_success(operation, attempt + 1);
+                    }
                     return Ok(response);
                 }
                 Err(err) => {
-                    debug!(attempt = attempt + 1, %method, %url, error = %err, "HTTP request failed");
-
-                    if attempt + 1 < attempts && should_retry_error(&err) {
+                    debug!(
+                        attempt = attempt + 1,
+                        %method,
+                        %url,
+                        correlation_id,
+                        error = %err,
+                        "HTTP request failed"
+                    );
+
+                    let retryable = should_retry_error(&err);
+                    if attempt + 1 < attempts && retryable {
                         self.sleep_with_backoff(attempt + 1).await;
                         continue;
                     }
 
+                    if retryable {
+                        self.record_exhausted(operation);
+                    }
+
                     let infra: InfraError = err.into();
                     return Err(PulseArcError::from(infra));
                 }
@@ -84,6 +150,23 @@ impl HttpClient {
         ))
     }
 
+    fn record_success(&self, operation: &str, attempts: usize) {
+        if let Some(metrics) = &self.retry_metrics {
+            if let Err(err) = metrics.record_retry_success(operation, attempts) {
+                warn!(operation, error = ?err, "failed to record retry metric");
+            }
+        }
+    }
+
+    fn record_exhausted(&self, operation: &str) {
+        if let Some(metrics) = &self.retry_metrics {
+            warn!(operation, attempts = self.max_attempts, "HTTP request exhausted retries");
+            if let Err(err) = metrics.record_retry_exhausted(operation) {
+                warn!(operation, error = ?err, "failed to record retry metric");
+            }
+        }
+    }
+
     fn backoff_delay(&self, retry_number: usize) -> Duration {
         let shift = retry_number.saturating_sub(1).min(8) as u32;
         let multiplier = 1u32 << shift;
@@ -180,6 +263,7 @@ impl HttpClientBuilder {
             client,
             max_attempts: self.max_attempts.max(1),
             base_backoff: self.base_backoff,
+            retry_metrics: None,
         })
     }
 }
@@ -208,6 +292,7 @@ mod tests {
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use super::*;
+    use crate::observability::correlation;
 
     fn client_with_defaults() -> HttpClient {
         HttpClient::builder()
@@ -262,6 +347,50 @@ mod tests {
         assert_eq!(requests.len(), 3);
     }
 
+    #[tokio::test]
+    async fn records_attempts_of_operation_succeeding_on_third_attempt() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET")).respond_with(ResponseTemplate::new(200)).mount(&server).await;
+
+        let metrics = Arc::new(PerformanceMetrics::new());
+        let client = client_with_defaults().with_retry_metrics(Arc::clone(&metrics));
+        client
+            .send_operation("neon.submit_time_entry", client.request(Method::GET, server.uri()))
+            .await
+            .expect("response");
+
+        let stats = metrics.retry.stats("neon.submit_time_entry").expect("stats recorded");
+        assert_eq!(stats.attempts_histogram[2], 1, "succeeded on the third attempt");
+        assert_eq!(stats.successes(), 1);
+        assert_eq!(stats.exhausted, 0);
+    }
+
+    #[tokio::test]
+    async fn records_operation_that_exhausts_retries() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let metrics = Arc::new(PerformanceMetrics::new());
+        let client = client_with_defaults().with_retry_metrics(Arc::clone(&metrics));
+        let response =
+            client.send(client.request(Method::GET, server.uri())).await.expect("response");
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        let stats = metrics.retry.stats(DEFAULT_OPERATION).expect("stats recorded");
+        assert_eq!(stats.exhausted, 1);
+        assert_eq!(stats.successes(), 0);
+        assert_eq!(metrics.retry_exhausted_count(), 1);
+    }
+
     #[tokio::test]
     async fn does_not_retry_client_errors() {
         let server = MockServer::start().await;
@@ -280,6 +409,34 @@ mod tests {
         assert_eq!(requests.len(), 1);
     }
 
+    #[tokio::test]
+    async fn sends_correlation_id_header_inside_scope() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = client_with_defaults();
+        let id = correlation::new_correlation_id();
+        correlation::scope(id.clone(), client.send(client.request(Method::GET, server.uri())))
+            .await
+            .expect("response");
+        client.send(client.request(Method::GET, server.uri())).await.expect("response");
+
+        let requests = server.received_requests().await.unwrap();
+        let header = |idx: usize| {
+            requests[idx]
+                .headers
+                .get(CORRELATION_ID_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        assert_eq!(header(0), Some(id));
+        assert_eq!(header(1), None);
+    }
+
     #[tokio::test]
     async fn retries_on_network_failure() {
         let listener = TcpListener::bind("127.0.0.1:0").unwrap();
@@ -2,4 +2,4 @@
 
 pub mod client;
 
-pub use client::{HttpClient, HttpClientBuilder};
+pub use client::{HttpClient, HttpClientBuilder, DEFAULT_OPERATION};
@@ -11,7 +11,8 @@ use pulsearc_core::tracking::ports::CalendarEventRepository;
 use pulsearc_core::OutboxQueue;
 use pulsearc_domain::types::database::{ParsedFields, TimeRange};
 use pulsearc_domain::{
-    parse_event_title, CalendarEventParams, ParsedEventTitle, PulseArcError, Result,
+    parse_event_title, CalendarBatchOutcome, CalendarEventParams, ParsedEventTitle, PulseArcError,
+    Result,
 };
 use tracing::{debug, error, info, instrument, warn};
 use url::{form_urlencoded, Url};
@@ -402,6 +403,7 @@ impl CalendarSyncWorker {
             meeting_id: raw.meeting_id,
             attendee_count: raw.attendee_count,
             external_attendee_count: raw.external_attendee_count,
+            attendees: raw.attendees.unwrap_or_default(),
         })
     }
 
@@ -472,15 +474,17 @@ impl CalendarSyncWorker {
     }
 
     /// Save calendar events to database
+    ///
+    /// All events are upserted in one transaction: if any fails, none are
+    /// saved and the sync fails, leaving the sync token for a retry.
     async fn save_calendar_events(
         &self,
         events: &[CalendarEvent],
         user_email: &str,
     ) -> Result<usize> {
-        let mut saved_count = 0;
-
-        for event in events {
-            let params = CalendarEventParams {
+        let params: Vec<CalendarEventParams> = events
+            .iter()
+            .map(|event| CalendarEventParams {
                 id: Uuid::now_v7().to_string(),
                 google_event_id: event.id.clone(),
                 user_email: user_email.to_string(),
@@ -507,22 +511,19 @@ impl CalendarSyncWorker {
                 meeting_id: event.meeting_id.clone(),
                 attendee_count: event.attendee_count,
                 external_attendee_count: event.external_attendee_count,
-            };
+            })
+            .collect();
 
-            match self.calendar_repo.insert_calendar_event(params).await {
-                Ok(_) => saved_count += 1,
-                Err(e) => {
-                    error!(
-                        event_id = %event.id,
-                        error = %e,
-                        "failed to save calendar event"
-                    );
-                    // Continue processing other events
-                }
-            }
-        }
+        let outcomes = self.calendar_repo.upsert_batch(params).await.map_err(|e| {
+            error!(user_email, count = events.len(), error = %e, "failed to save calendar events");
+            e
+        })?;
+
+        let inserted =
+            outcomes.iter().filter(|outcome| **outcome == CalendarBatchOutcome::Inserted).count();
+        debug!(user_email, inserted, updated = outcomes.len() - inserted, "saved calendar events");
 
-        Ok(saved_count)
+        Ok(outcomes.len())
     }
 
     /// Generate time entry suggestions from calendar events
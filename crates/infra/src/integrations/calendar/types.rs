@@ -80,22 +80,5 @@ pub struct CalendarConnectionStatus {
     pub sync_enabled: bool,
 }
 
-/// Timeline calendar event used for timeline visualisations
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-#[cfg_attr(feature = "ts-gen", derive(TS))]
-#[cfg_attr(feature = "ts-gen", ts(export, rename_all = "camelCase"))]
-pub struct TimelineCalendarEvent {
-    pub id: String,
-    pub project: String,
-    pub task: String,
-    pub start_time: String,
-    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
-    pub start_epoch: i64,
-    #[cfg_attr(feature = "ts-gen", ts(type = "number"))]
-    pub duration: i64,
-    pub status: String,
-    pub is_calendar_event: bool,
-    pub is_all_day: bool,
-    pub original_summary: String,
-}
+// Defined in the domain so the core timeline can merge it with activity
+pub use pulsearc_domain::TimelineCalendarEvent;
@@ -0,0 +1,239 @@
+/// OpenAI Batch API support for offline classification backfills
+///
+/// The Batch API runs Chat Completions requests asynchronously (within 24h)
+/// at half the synchronous price, which suits classifying months of
+/// historical blocks. The flow used by `OpenAIClient`:
+///
+/// 1. Upload a JSONL file with one Chat Completions request per block
+///    (`custom_id` = block id) to `/files` with `purpose=batch`
+/// 2. Create a batch for the file via `/batches`
+/// 3. Poll `/batches/{id}` until it reaches a terminal status
+/// 4. Download the output file from `/files/{id}/content` and parse each
+///    line's completion
+///
+/// `failed`, `expired` and `cancelled` batches surface as
+/// [`OpenAIError::BatchFailed`].
+use serde::{Deserialize, Serialize};
+
+use super::types::{ChatCompletionRequest, ChatCompletionResponse, OpenAIError};
+
+/// Batch requests are billed at 50% of the synchronous price
+pub const BATCH_COST_DISCOUNT: f64 = 0.5;
+
+/// Endpoint each batch line is sent to
+pub(crate) const CHAT_COMPLETIONS_ENDPOINT: &str = "/v1/chat/completions";
+
+/// Only completion window offered by the Batch API
+pub(crate) const COMPLETION_WINDOW: &str = "24h";
+
+/// Boundary for the multipart file upload
+pub(crate) const MULTIPART_BOUNDARY: &str = "pulsearc-batch-upload-boundary";
+
+/// Handle to a submitted batch job, used to poll for its results
+///
+/// Serializable so callers can persist it and resume polling after a
+/// restart.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchJobHandle {
+    /// OpenAI batch id
+    pub batch_id: String,
+    /// Uploaded JSONL input file id
+    pub input_file_id: String,
+    /// Model the requests were sent to (determines pricing)
+    pub model: String,
+    /// Prompt template version used for the requests
+    pub prompt_version: String,
+    /// Number of blocks submitted
+    pub block_count: usize,
+}
+
+/// Progress of a batch as reported by `/batches/{id}`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BatchState {
+    /// `validating`, `in_progress`, `finalizing` or `cancelling`
+    Pending,
+    /// `completed`: the output file is ready
+    Completed,
+    /// `failed`, `expired` or `cancelled`
+    Failed,
+}
+
+impl BatchState {
+    pub(crate) fn from_status(status: &str) -> Self {
+        match status {
+            "completed" => Self::Completed,
+            "failed" | "expired" | "cancelled" => Self::Failed,
+            _ => Self::Pending,
+        }
+    }
+}
+
+/// One line of the uploaded JSONL input
+#[derive(Debug, Serialize)]
+pub(crate) struct BatchRequestLine<'a> {
+    pub custom_id: &'a str,
+    pub method: &'static str,
+    pub url: &'static str,
+    pub body: &'a ChatCompletionRequest,
+}
+
+/// Body of `POST /batches`
+#[derive(Debug, Serialize)]
+pub(crate) struct CreateBatchRequest<'a> {
+    pub input_file_id: &'a str,
+    pub endpoint: &'static str,
+    pub completion_window: &'static str,
+}
+
+/// Response of `POST /files`
+#[derive(Debug, Deserialize)]
+pub(crate) struct FileObject {
+    pub id: String,
+}
+
+/// Batch object returned by `/batches`
+#[derive(Debug, Deserialize)]
+pub(crate) struct BatchObject {
+    pub id: String,
+    pub status: String,
+    #[serde(default)]
+    pub output_file_id: Option<String>,
+    #[serde(default)]
+    pub errors: Option<BatchErrors>,
+}
+
+impl BatchObject {
+    /// Batch-level error messages joined for display
+    pub(crate) fn error_message(&self) -> String {
+        let messages: Vec<&str> = self
+            .errors
+            .iter()
+            .flat_map(|errors| errors.data.iter())
+            .map(|error| error.message.as_str())
+            .collect();
+        if messages.is_empty() {
+            format!("batch {}", self.status)
+        } else {
+            messages.join("; ")
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct BatchErrors {
+    #[serde(default)]
+    pub data: Vec<BatchErrorDetail>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct BatchErrorDetail {
+    #[serde(default)]
+    pub message: String,
+}
+
+/// One line of the downloaded JSONL output
+#[derive(Debug, Deserialize)]
+struct BatchOutputLine {
+    custom_id: String,
+    #[serde(default)]
+    response: Option<BatchOutputResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchOutputResponse {
+    status_code: u16,
+    body: serde_json::Value,
+}
+
+/// Parsed batch output: successful completions and the ids of failed lines
+#[derive(Debug, Default)]
+pub(crate) struct BatchOutput {
+    pub completions: Vec<ChatCompletionResponse>,
+    pub failed_ids: Vec<String>,
+}
+
+/// Parse the JSONL output file.
+///
+/// Lines without a 200 response are reported in `failed_ids` rather than
+/// failing the whole batch.
+pub(crate) fn parse_batch_output(text: &str) -> Result<BatchOutput, OpenAIError> {
+    let mut output = BatchOutput::default();
+    for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let line: BatchOutputLine = serde_json::from_str(line).map_err(|e| {
+            OpenAIError::InvalidSchema(format!("Failed to parse batch output line: {}", e))
+        })?;
+        match line.response {
+            Some(response) if response.status_code == 200 => {
+                let completion = serde_json::from_value(response.body).map_err(|e| {
+                    OpenAIError::InvalidSchema(format!(
+                        "Failed to parse batch completion for {}: {}",
+                        line.custom_id, e
+                    ))
+                })?;
+                output.completions.push(completion);
+            }
+            _ => output.failed_ids.push(line.custom_id),
+        }
+    }
+    Ok(output)
+}
+
+/// Build a `multipart/form-data` body (with [`MULTIPART_BOUNDARY`]) holding
+/// the `purpose` field and the JSONL `file`.
+pub(crate) fn multipart_body(purpose: &str, filename: &str, content: &str) -> Vec<u8> {
+    format!(
+        "--{boundary}\r\n\
+         Content-Disposition: form-data; name=\"purpose\"\r\n\r\n\
+         {purpose}\r\n\
+         --{boundary}\r\n\
+         Content-Disposition: form-data; name=\"file\"; filename=\"{filename}\"\r\n\
+         Content-Type: application/jsonl\r\n\r\n\
+         {content}\r\n\
+         --{boundary}--\r\n",
+        boundary = MULTIPART_BOUNDARY,
+    )
+    .into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_batch_statuses() {
+        assert_eq!(BatchState::from_status("validating"), BatchState::Pending);
+        assert_eq!(BatchState::from_status("in_progress"), BatchState::Pending);
+        assert_eq!(BatchState::from_status("finalizing"), BatchState::Pending);
+        assert_eq!(BatchState::from_status("completed"), BatchState::Completed);
+        assert_eq!(BatchState::from_status("failed"), BatchState::Failed);
+        assert_eq!(BatchState::from_status("expired"), BatchState::Failed);
+        assert_eq!(BatchState::from_status("cancelled"), BatchState::Failed);
+    }
+
+    #[test]
+    fn parses_output_and_collects_failed_lines() {
+        let text = concat!(
+            r#"{"custom_id":"block-1","response":{"status_code":200,"body":{"choices":[{"message":{"content":"{\"classifications\":[]}"}}],"usage":{"total_tokens":10,"prompt_tokens":8,"completion_tokens":2}}}}"#,
+            "\n",
+            r#"{"custom_id":"block-2","response":{"status_code":500,"body":{}}}"#,
+            "\n",
+            r#"{"custom_id":"block-3","response":null,"error":{"message":"boom"}}"#,
+            "\n"
+        );
+
+        let output = parse_batch_output(text).expect("parse");
+
+        assert_eq!(output.completions.len(), 1);
+        assert_eq!(output.completions[0].usage.total_tokens, 10);
+        assert_eq!(output.failed_ids, vec!["block-2", "block-3"]);
+    }
+
+    #[test]
+    fn multipart_body_contains_purpose_and_file() {
+        let body = String::from_utf8(multipart_body("batch", "input.jsonl", "{}\n")).unwrap();
+
+        assert!(body.contains("name=\"purpose\"\r\n\r\nbatch\r\n"));
+        assert!(body.contains("filename=\"input.jsonl\""));
+        assert!(body.ends_with(&format!("--{MULTIPART_BOUNDARY}--\r\n")));
+    }
+}
@@ -1,31 +1,48 @@
 /// OpenAI API client for block classification
+use std::sync::Arc;
+use std::time::Instant;
+
+use pulsearc_common::privacy::PatternMatcher;
 use pulsearc_domain::types::classification::ProposedBlock;
-use pulsearc_domain::PulseArcError;
-use reqwest::Method;
+use pulsearc_domain::{CostRateConfig, PulseArcError};
+use reqwest::{Method, RequestBuilder};
 use serde_json::json;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
+use super::batch::{
+    multipart_body, parse_batch_output, BatchJobHandle, BatchObject, BatchRequestLine, BatchState,
+    CreateBatchRequest, FileObject, BATCH_COST_DISCOUNT, CHAT_COMPLETIONS_ENDPOINT,
+    COMPLETION_WINDOW, MULTIPART_BOUNDARY,
+};
+use super::logging::{ExchangeLogger, ExchangeOutcome, OpenAILogConfig, OpenAILogSink};
+use super::models::{should_fall_back, ModelChainConfig, ModelConfig};
+use super::prompts::{PromptConfig, PromptTemplate, RenderedPrompt};
 use super::types::{
-    BlockClassificationResponse, ChatCompletionRequest, ChatCompletionResponse, ChatMessage,
-    JsonSchema, LLMBlockResponse, OpenAIError, ResponseFormat,
+    BlockClassification, BlockClassificationResponse, ChatCompletionRequest,
+    ChatCompletionResponse, ChatMessage, JsonSchema, LLMBlockResponse, OpenAIError, ResponseFormat,
 };
 use crate::http::HttpClient;
 
 const OPENAI_API_URL: &str = "https://api.openai.com/v1/chat/completions";
-const DEFAULT_MODEL: &str = "gpt-4o-mini";
+const OPENAI_API_BASE: &str = "https://api.openai.com/v1";
 const DEFAULT_MAX_TOKENS: u32 = 50_000;
 const DEFAULT_TEMPERATURE: f32 = 0.3;
 
-/// Cost per 1M tokens for gpt-4o-mini (as of 2025)
-const COST_PER_1M_INPUT_TOKENS: f64 = 0.150;
-const COST_PER_1M_OUTPUT_TOKENS: f64 = 0.600;
-
 /// OpenAI API client for classifying time blocks
 pub struct OpenAIClient {
     http_client: HttpClient,
     api_key: String,
-    model: String,
+    models: ModelChainConfig,
+    prompt_template: PromptTemplate,
     api_url: String,
+    api_base: String,
+    exchange_log: Option<ExchangeLogger>,
+}
+
+/// Parsed API reply plus the raw completion content
+struct ApiReply {
+    response: BlockClassificationResponse,
+    content: String,
 }
 
 impl OpenAIClient {
@@ -41,14 +58,64 @@ impl OpenAIClient {
         Self {
             http_client,
             api_key,
-            model: DEFAULT_MODEL.to_string(),
+            models: ModelChainConfig::default(),
+            prompt_template: PromptTemplate::default(),
             api_url: OPENAI_API_URL.to_string(),
+            api_base: OPENAI_API_BASE.to_string(),
+            exchange_log: None,
         }
     }
 
     /// Create a new client with custom model
+    ///
+    /// Replaces the fallback chain with this single model, priced like the
+    /// current primary. Use [`Self::with_model_chain`] to set pricing or
+    /// fallbacks.
     pub fn with_model(mut self, model: impl Into<String>) -> Self {
-        self.model = model.into();
+        let pricing = self.models.primary().pricing;
+        self.models = ModelChainConfig::single(ModelConfig::new(model, pricing));
+        self
+    }
+
+    /// Try `chain` in order, falling back on rate limits and server errors
+    pub fn with_model_chain(mut self, chain: ModelChainConfig) -> Self {
+        self.models = chain;
+        self
+    }
+
+    /// Price responses with the configured `rates`
+    ///
+    /// Applies to the models currently in the chain, so call it after
+    /// [`Self::with_model`] or [`Self::with_model_chain`]. Models without
+    /// configured rates keep their chain pricing.
+    pub fn with_cost_rates(mut self, rates: &CostRateConfig) -> Self {
+        self.models = self.models.with_cost_rates(rates);
+        self
+    }
+
+    /// Render prompts from `template`; its version is recorded on each
+    /// response
+    pub fn with_prompt_template(mut self, template: PromptTemplate) -> Self {
+        self.prompt_template = template;
+        self
+    }
+
+    /// Use the built-in template selected by `config`
+    pub fn with_prompt_config(self, config: &PromptConfig) -> Self {
+        self.with_prompt_template(config.template())
+    }
+
+    /// Log each request/response to `sink` when `config.enabled` is set
+    ///
+    /// Prompts, responses and error messages are redacted with `matcher`
+    /// before they reach the sink. A disabled config leaves logging off.
+    pub fn with_exchange_log(
+        mut self,
+        config: &OpenAILogConfig,
+        sink: Arc<dyn OpenAILogSink>,
+        matcher: Arc<PatternMatcher>,
+    ) -> Self {
+        self.exchange_log = config.enabled.then(|| ExchangeLogger::new(sink, matcher));
         self
     }
 
@@ -59,6 +126,14 @@ impl OpenAIClient {
         self
     }
 
+    /// Create a new client with custom API base URL for the Batch and Files
+    /// endpoints (for testing)
+    #[cfg(test)]
+    pub fn with_api_base(mut self, url: impl Into<String>) -> Self {
+        self.api_base = url.into();
+        self
+    }
+
     /// Classify a batch of blocks using OpenAI API
     ///
     /// # Arguments
@@ -70,7 +145,9 @@ impl OpenAIClient {
     ///
     /// # Errors
     /// Returns `OpenAIError` for network failures, API errors, or invalid
-    /// responses
+    /// responses. Rate limits (429) and server errors (5xx) move on to the
+    /// next model in the chain; the last model's error is returned when every
+    /// model fails.
     pub async fn classify_blocks(
         &self,
         blocks: &[ProposedBlock],
@@ -82,18 +159,39 @@ impl OpenAIClient {
                 prompt_tokens: 0,
                 completion_tokens: 0,
                 cost_usd: 0.0,
+                model: self.models.primary().name.clone(),
+                prompt_version: self.prompt_template.version.clone(),
             });
         }
 
         info!(block_count = blocks.len(), "Classifying blocks with OpenAI");
 
-        // 1. Build prompt from blocks
-        let prompt = self.build_classification_prompt(blocks);
+        // 1. Render prompt from blocks
+        let prompt = self.prompt_template.render(blocks);
 
-        // 2. Call OpenAI API
-        let response = self.call_api(prompt).await?;
+        // 2. Call OpenAI API, walking the fallback chain
+        let models = self.models.models();
+        let mut index = 0;
+        let response = loop {
+            let model = &models[index];
+            match self.classify_with_model(&prompt, model).await {
+                Ok(response) => break response,
+                Err(err) if should_fall_back(&err) && index + 1 < models.len() => {
+                    warn!(
+                        model = %model.name,
+                        fallback = %models[index + 1].name,
+                        error = %err,
+                        "OpenAI model unavailable, falling back"
+                    );
+                    index += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        };
 
         info!(
+            model = %response.model,
+            prompt_version = %response.prompt_version,
             tokens = response.tokens_used,
             cost = response.cost_usd,
             "OpenAI classification complete"
@@ -102,53 +200,256 @@ impl OpenAIClient {
         Ok(response)
     }
 
-    /// Build classification prompt from blocks
+    /// Submit `blocks` for offline classification via the Batch API
     ///
-    /// Constructs a structured prompt that includes:
-    /// - System message defining the task
-    /// - User message with block details and activities
-    fn build_classification_prompt(&self, blocks: &[ProposedBlock]) -> String {
-        let mut prompt = String::from(
-            "Classify each time block as billable (client work) or G&A (non-billable).\n\n",
-        );
+    /// Uploads one Chat Completions request per block (rendered with the
+    /// configured prompt template, sent to the primary model) and creates a
+    /// batch for it. Poll the returned handle with [`Self::poll_batch_job`].
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::InvalidRequest` for an empty block list, or the
+    /// usual network/API errors from the upload and batch creation.
+    pub async fn submit_batch_job(
+        &self,
+        blocks: &[ProposedBlock],
+    ) -> Result<BatchJobHandle, OpenAIError> {
+        if blocks.is_empty() {
+            return Err(OpenAIError::InvalidRequest("no blocks to submit".to_string()));
+        }
 
+        let model = self.models.primary();
+        let mut jsonl = String::new();
         for block in blocks {
-            prompt.push_str(&format!(
-                "Block ID: {}\nDuration: {} seconds\nStart: {}\nEnd: {}\n",
-                block.id, block.duration_secs, block.start_ts, block.end_ts
-            ));
-
-            if !block.activities.is_empty() {
-                prompt.push_str("Activities:\n");
-                for activity in &block.activities {
-                    prompt.push_str(&format!(
-                        "  - {} ({:.1}%) - {}s\n",
-                        activity.name, activity.percentage, activity.duration_secs
-                    ));
-                }
+            let prompt = self.prompt_template.render(std::slice::from_ref(block));
+            let request = self.chat_request(&prompt, model);
+            let line = serde_json::to_string(&BatchRequestLine {
+                custom_id: &block.id,
+                method: "POST",
+                url: CHAT_COMPLETIONS_ENDPOINT,
+                body: &request,
+            })
+            .map_err(|e| {
+                OpenAIError::InvalidRequest(format!("Failed to encode batch line: {e}"))
+            })?;
+            jsonl.push_str(&line);
+            jsonl.push('\n');
+        }
+
+        // 1. Upload the JSONL input
+        let upload = self
+            .http_client
+            .request(Method::POST, format!("{}/files", self.api_base))
+            .header("Content-Type", format!("multipart/form-data; boundary={MULTIPART_BOUNDARY}"))
+            .body(multipart_body("batch", "classification_batch.jsonl", &jsonl));
+        let file: FileObject = self.send(upload).await?.json().await.map_err(|e| {
+            OpenAIError::InvalidSchema(format!("Failed to parse file upload response: {}", e))
+        })?;
+
+        // 2. Create the batch
+        let create = self
+            .http_client
+            .request(Method::POST, format!("{}/batches", self.api_base))
+            .header("Content-Type", "application/json")
+            .json(&CreateBatchRequest {
+                input_file_id: &file.id,
+                endpoint: CHAT_COMPLETIONS_ENDPOINT,
+                completion_window: COMPLETION_WINDOW,
+            });
+        let batch: BatchObject = self.send(create).await?.json().await.map_err(|e| {
+            OpenAIError::InvalidSchema(format!("Failed to parse batch response: {}", e))
+        })?;
+
+        info!(
+            batch_id = %batch.id,
+            block_count = blocks.len(),
+            model = %model.name,
+            "Submitted OpenAI classification batch"
+        );
+
+        Ok(BatchJobHandle {
+            batch_id: batch.id,
+            input_file_id: file.id,
+            model: model.name.clone(),
+            prompt_version: self.prompt_template.version.clone(),
+            block_count: blocks.len(),
+        })
+    }
+
+    /// Check a submitted batch job
+    ///
+    /// Returns `Ok(None)` while the batch is still running and the combined
+    /// classifications once it completes. Cost is computed with the model's
+    /// pricing and the Batch API discount. Lines that failed individually
+    /// are skipped (their blocks stay unclassified).
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::BatchFailed` when the batch failed, expired or
+    /// was cancelled.
+    pub async fn poll_batch_job(
+        &self,
+        handle: &BatchJobHandle,
+    ) -> Result<Option<BlockClassificationResponse>, OpenAIError> {
+        let request = self
+            .http_client
+            .request(Method::GET, format!("{}/batches/{}", self.api_base, handle.batch_id));
+        let batch: BatchObject = self.send(request).await?.json().await.map_err(|e| {
+            OpenAIError::InvalidSchema(format!("Failed to parse batch response: {}", e))
+        })?;
+
+        match BatchState::from_status(&batch.status) {
+            BatchState::Pending => {
+                debug!(batch_id = %batch.id, status = %batch.status, "Batch still running");
+                return Ok(None);
+            }
+            BatchState::Failed => {
+                return Err(OpenAIError::BatchFailed {
+                    message: batch.error_message(),
+                    batch_id: batch.id,
+                    status: batch.status,
+                });
             }
+            BatchState::Completed => {}
+        }
 
-            prompt.push('\n');
+        let mut response = BlockClassificationResponse {
+            classifications: vec![],
+            tokens_used: 0,
+            prompt_tokens: 0,
+            completion_tokens: 0,
+            cost_usd: 0.0,
+            model: handle.model.clone(),
+            prompt_version: handle.prompt_version.clone(),
+        };
+        let Some(output_file_id) = batch.output_file_id else {
+            warn!(batch_id = %batch.id, "Completed batch has no output file");
+            return Ok(Some(response));
+        };
+
+        let download = self
+            .http_client
+            .request(Method::GET, format!("{}/files/{}/content", self.api_base, output_file_id));
+        let text =
+            self.send(download).await?.text().await.map_err(|e| {
+                OpenAIError::Network(format!("Failed to download batch output: {}", e))
+            })?;
+        let output = parse_batch_output(&text)?;
+        if !output.failed_ids.is_empty() {
+            warn!(
+                batch_id = %batch.id,
+                failed = output.failed_ids.len(),
+                "Some batch requests failed; their blocks were not classified"
+            );
+        }
+
+        for completion in &output.completions {
+            let (classifications, _) = parse_classifications(completion)?;
+            response.classifications.extend(classifications);
+            response.tokens_used += completion.usage.total_tokens;
+            response.prompt_tokens += completion.usage.prompt_tokens;
+            response.completion_tokens += completion.usage.completion_tokens;
         }
+        let pricing = self
+            .models
+            .models()
+            .iter()
+            .find(|model| model.name == handle.model)
+            .unwrap_or(self.models.primary())
+            .pricing;
+        response.cost_usd = pricing.cost_usd(response.prompt_tokens, response.completion_tokens)
+            * BATCH_COST_DISCOUNT;
 
-        prompt.push_str("Return JSON with 'classifications' array. Each item must have: id, billable (bool), description, confidence (0.0-1.0), reasons (array), and optionally: project_id, wbs_code, deal_name, workstream.");
+        info!(
+            batch_id = %batch.id,
+            classified = response.classifications.len(),
+            cost = response.cost_usd,
+            "OpenAI batch classification complete"
+        );
 
-        prompt
+        Ok(Some(response))
+    }
+
+    /// Send the prompt to a single model, logging the exchange if enabled
+    async fn classify_with_model(
+        &self,
+        prompt: &RenderedPrompt,
+        model: &ModelConfig,
+    ) -> Result<BlockClassificationResponse, OpenAIError> {
+        let started = Instant::now();
+        let result = self.call_api(prompt, model).await;
+        if let Some(exchange_log) = &self.exchange_log {
+            let outcome = match &result {
+                Ok(reply) => ExchangeOutcome {
+                    content: Some(&reply.content),
+                    error: None,
+                    prompt_tokens: reply.response.prompt_tokens,
+                    completion_tokens: reply.response.completion_tokens,
+                    total_tokens: reply.response.tokens_used,
+                },
+                Err(err) => ExchangeOutcome {
+                    content: None,
+                    error: Some(err),
+                    prompt_tokens: 0,
+                    completion_tokens: 0,
+                    total_tokens: 0,
+                },
+            };
+            exchange_log.record(&model.name, &prompt.user, outcome, started.elapsed()).await;
+        }
+        result.map(|reply| reply.response)
     }
 
     /// Call OpenAI Chat Completions API
-    async fn call_api(&self, prompt: String) -> Result<BlockClassificationResponse, OpenAIError> {
-        // Build request payload
-        let request_payload = ChatCompletionRequest {
-            model: self.model.clone(),
+    async fn call_api(
+        &self,
+        prompt: &RenderedPrompt,
+        model: &ModelConfig,
+    ) -> Result<ApiReply, OpenAIError> {
+        // Build HTTP request
+        let request_builder = self
+            .http_client
+            .request(Method::POST, &self.api_url)
+            .header("Content-Type", "application/json")
+            .json(&self.chat_request(prompt, model));
+
+        // Execute with retry (handled by HttpClient)
+        let response = self.send(request_builder).await?;
+
+        // Parse successful response
+        let chat_response: ChatCompletionResponse = response
+            .json()
+            .await
+            .map_err(|e| OpenAIError::InvalidSchema(format!("Failed to parse response: {}", e)))?;
+        let (classifications, content) = parse_classifications(&chat_response)?;
+
+        // Extract token usage
+        let tokens_used = chat_response.usage.total_tokens;
+        let prompt_tokens = chat_response.usage.prompt_tokens;
+        let completion_tokens = chat_response.usage.completion_tokens;
+
+        // Calculate cost with the serving model's pricing
+        let cost_usd = model.pricing.cost_usd(prompt_tokens, completion_tokens);
+
+        Ok(ApiReply {
+            response: BlockClassificationResponse {
+                classifications,
+                tokens_used,
+                prompt_tokens,
+                completion_tokens,
+                cost_usd,
+                model: model.name.clone(),
+                prompt_version: prompt.version.clone(),
+            },
+            content,
+        })
+    }
+
+    /// Chat Completions payload for `prompt` (shared by sync and batch calls)
+    fn chat_request(&self, prompt: &RenderedPrompt, model: &ModelConfig) -> ChatCompletionRequest {
+        ChatCompletionRequest {
+            model: model.name.clone(),
             messages: vec![
-                ChatMessage {
-                    role: "system".to_string(),
-                    content:
-                        "You are an M&A tax professional time entry classifier. Analyze work blocks and classify them as billable or G&A (non-billable) based on activity signals."
-                            .to_string(),
-                },
-                ChatMessage { role: "user".to_string(), content: prompt },
+                ChatMessage { role: "system".to_string(), content: prompt.system.clone() },
+                ChatMessage { role: "user".to_string(), content: prompt.user.clone() },
             ],
             max_tokens: DEFAULT_MAX_TOKENS,
             temperature: DEFAULT_TEMPERATURE,
@@ -192,18 +493,13 @@ impl OpenAIClient {
                     strict: Some(true),
                 }),
             },
-        };
-
-        // Build HTTP request
-        let request_builder = self
-            .http_client
-            .request(Method::POST, &self.api_url)
-            .header("Authorization", format!("Bearer {}", self.api_key))
-            .header("Content-Type", "application/json")
-            .json(&request_payload);
+        }
+    }
 
-        // Execute with retry (handled by HttpClient)
-        let response = self.http_client.send(request_builder).await.map_err(|err| match err {
+    /// Send an authenticated request, mapping transport and HTTP errors
+    async fn send(&self, builder: RequestBuilder) -> Result<reqwest::Response, OpenAIError> {
+        let builder = builder.header("Authorization", format!("Bearer {}", self.api_key));
+        let response = self.http_client.send(builder).await.map_err(|err| match err {
             PulseArcError::Network(msg) => OpenAIError::Network(msg.to_string()),
             PulseArcError::Internal(msg) => OpenAIError::Network(msg.to_string()),
             other => OpenAIError::Network(format!("HTTP error: {}", other)),
@@ -217,40 +513,7 @@ impl OpenAIClient {
             return Err(self.handle_error_status(status.as_u16(), response).await);
         }
 
-        // Parse successful response
-        let chat_response: ChatCompletionResponse = response
-            .json()
-            .await
-            .map_err(|e| OpenAIError::InvalidSchema(format!("Failed to parse response: {}", e)))?;
-
-        // Extract and parse classifications from JSON content
-        let choice = chat_response.choices.first().ok_or_else(|| {
-            OpenAIError::InvalidSchema("Response contained no choices".to_string())
-        })?;
-        let content = &choice.message.content;
-        let llm_response: LLMBlockResponse = serde_json::from_str(content).map_err(|e| {
-            OpenAIError::InvalidSchema(format!(
-                "Failed to parse classifications: {}. Content: {}",
-                e, content
-            ))
-        })?;
-
-        // Extract token usage
-        let tokens_used = chat_response.usage.total_tokens;
-        let prompt_tokens = chat_response.usage.prompt_tokens;
-        let completion_tokens = chat_response.usage.completion_tokens;
-
-        // Calculate cost (gpt-4o-mini pricing)
-        let cost_usd = (f64::from(prompt_tokens) * COST_PER_1M_INPUT_TOKENS / 1_000_000.0)
-            + (f64::from(completion_tokens) * COST_PER_1M_OUTPUT_TOKENS / 1_000_000.0);
-
-        Ok(BlockClassificationResponse {
-            classifications: llm_response.classifications,
-            tokens_used,
-            prompt_tokens,
-            completion_tokens,
-            cost_usd,
-        })
+        Ok(response)
     }
 
     /// Handle HTTP error status codes
@@ -269,15 +532,35 @@ impl OpenAIClient {
     }
 }
 
+/// Extract the classifications and raw content from a completion
+fn parse_classifications(
+    chat_response: &ChatCompletionResponse,
+) -> Result<(Vec<BlockClassification>, String), OpenAIError> {
+    let choice = chat_response
+        .choices
+        .first()
+        .ok_or_else(|| OpenAIError::InvalidSchema("Response contained no choices".to_string()))?;
+    let content = choice.message.content.clone();
+    let llm_response: LLMBlockResponse = serde_json::from_str(&content).map_err(|e| {
+        OpenAIError::InvalidSchema(format!(
+            "Failed to parse classifications: {}. Content: {}",
+            e, content
+        ))
+    })?;
+
+    Ok((llm_response.classifications, content))
+}
+
 #[cfg(test)]
 mod tests {
     use std::time::Duration;
 
     use pulsearc_domain::types::classification::ActivityBreakdown;
-    use wiremock::matchers::{header, method, path};
+    use wiremock::matchers::{body_partial_json, body_string_contains, header, method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use super::*;
+    use crate::integrations::openai::{InMemoryLogSink, ModelPricing};
 
     fn test_client(api_url: String) -> OpenAIClient {
         let http_client = HttpClient::builder()
@@ -437,6 +720,385 @@ mod tests {
         assert!(matches!(result, Err(OpenAIError::InvalidSchema(_))));
     }
 
+    async fn mount_success(mock_server: &MockServer) {
+        Mock::given(method("POST"))
+            .and(path("/v1/chat/completions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "choices": [{
+                    "message": {
+                        "content": r#"{
+                            "classifications": [{
+                                "id": "block-123",
+                                "billable": false,
+                                "description": "Replied to jane.doe@example.com",
+                                "confidence": 0.7,
+                                "reasons": ["Mail"]
+                            }]
+                        }"#
+                    }
+                }],
+                "usage": {
+                    "total_tokens": 300,
+                    "prompt_tokens": 250,
+                    "completion_tokens": 50
+                }
+            })))
+            .mount(mock_server)
+            .await;
+    }
+
+    fn email_block() -> ProposedBlock {
+        let mut block = sample_block();
+        block.activities[0].name = "Mail - jane.doe@example.com".to_string();
+        block
+    }
+
+    #[tokio::test]
+    async fn logs_exchange_with_email_redacted() {
+        let mock_server = MockServer::start().await;
+        mount_success(&mock_server).await;
+
+        let sink = Arc::new(InMemoryLogSink::new());
+        let matcher = Arc::new(PatternMatcher::with_defaults().await.expect("matcher"));
+        let client = test_client(format!("{}/v1/chat/completions", mock_server.uri()))
+            .with_exchange_log(&OpenAILogConfig { enabled: true }, sink.clone(), matcher);
+
+        client.classify_blocks(&[email_block()]).await.expect("should classify");
+
+        let exchanges = sink.exchanges();
+        assert_eq!(exchanges.len(), 1);
+        let exchange = &exchanges[0];
+        assert!(!exchange.prompt.contains("jane.doe@example.com"), "prompt: {}", exchange.prompt);
+        assert!(exchange.prompt.contains("[REDACTED:email]"), "prompt: {}", exchange.prompt);
+        let response = exchange.response.as_deref().expect("response logged");
+        assert!(!response.contains("jane.doe@example.com"), "response: {response}");
+        assert_eq!(exchange.prompt_tokens, 250);
+        assert_eq!(exchange.completion_tokens, 50);
+        assert_eq!(exchange.total_tokens, 300);
+        assert!(exchange.error.is_none());
+    }
+
+    #[tokio::test]
+    async fn disabled_exchange_log_records_nothing() {
+        let mock_server = MockServer::start().await;
+        mount_success(&mock_server).await;
+
+        let sink = Arc::new(InMemoryLogSink::new());
+        let matcher = Arc::new(PatternMatcher::with_defaults().await.expect("matcher"));
+        let client = test_client(format!("{}/v1/chat/completions", mock_server.uri()))
+            .with_exchange_log(&OpenAILogConfig::default(), sink.clone(), matcher);
+
+        client.classify_blocks(&[email_block()]).await.expect("should classify");
+
+        assert!(sink.exchanges().is_empty());
+    }
+
+    fn fallback_chain() -> ModelChainConfig {
+        ModelChainConfig::single(ModelConfig::new(
+            "gpt-4o",
+            ModelPricing { input_per_1m: 2.5, output_per_1m: 10.0 },
+        ))
+        .with_fallback(ModelConfig::new("gpt-4o-mini", ModelPricing::GPT_4O_MINI))
+    }
+
+    async fn mount_model_response(mock_server: &MockServer, model: &str, status: u16) {
+        let template = if status == 200 {
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "choices": [{
+                    "message": {
+                        "content": r#"{"classifications": [{
+                            "id": "block-123",
+                            "billable": true,
+                            "description": "Client work",
+                            "confidence": 0.9,
+                            "reasons": []
+                        }]}"#
+                    }
+                }],
+                "usage": {
+                    "total_tokens": 1_200_000,
+                    "prompt_tokens": 1_000_000,
+                    "completion_tokens": 200_000
+                }
+            }))
+        } else {
+            ResponseTemplate::new(status).set_body_string("overloaded")
+        };
+
+        Mock::given(method("POST"))
+            .and(path("/v1/chat/completions"))
+            .and(body_partial_json(serde_json::json!({ "model": model })))
+            .respond_with(template)
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn falls_back_when_primary_is_rate_limited() {
+        let mock_server = MockServer::start().await;
+        mount_model_response(&mock_server, "gpt-4o", 429).await;
+        mount_model_response(&mock_server, "gpt-4o-mini", 200).await;
+
+        let client = test_client(format!("{}/v1/chat/completions", mock_server.uri()))
+            .with_model_chain(fallback_chain());
+
+        let response = client.classify_blocks(&[sample_block()]).await.expect("should classify");
+
+        assert_eq!(response.model, "gpt-4o-mini");
+        // 1M prompt tokens at $0.15 + 200k completion tokens at $0.60 per 1M
+        assert!((response.cost_usd - 0.27).abs() < 1e-9, "cost: {}", response.cost_usd);
+        assert_eq!(response.classifications.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn falls_back_on_server_error_and_reports_last_error() {
+        let mock_server = MockServer::start().await;
+        mount_model_response(&mock_server, "gpt-4o", 503).await;
+        mount_model_response(&mock_server, "gpt-4o-mini", 429).await;
+
+        let client = test_client(format!("{}/v1/chat/completions", mock_server.uri()))
+            .with_model_chain(fallback_chain());
+
+        let result = client.classify_blocks(&[sample_block()]).await;
+
+        assert!(matches!(result, Err(OpenAIError::RateLimit(_))));
+    }
+
+    #[tokio::test]
+    async fn does_not_fall_back_on_authentication_error() {
+        let mock_server = MockServer::start().await;
+        mount_model_response(&mock_server, "gpt-4o", 401).await;
+        mount_model_response(&mock_server, "gpt-4o-mini", 200).await;
+
+        let client = test_client(format!("{}/v1/chat/completions", mock_server.uri()))
+            .with_model_chain(fallback_chain());
+
+        let result = client.classify_blocks(&[sample_block()]).await;
+
+        assert!(matches!(result, Err(OpenAIError::Authentication(_))));
+    }
+
+    #[tokio::test]
+    async fn primary_response_records_primary_model_and_cost() {
+        let mock_server = MockServer::start().await;
+        mount_model_response(&mock_server, "gpt-4o", 200).await;
+
+        let client = test_client(format!("{}/v1/chat/completions", mock_server.uri()))
+            .with_model_chain(fallback_chain());
+
+        let response = client.classify_blocks(&[sample_block()]).await.expect("should classify");
+
+        assert_eq!(response.model, "gpt-4o");
+        // 1M prompt tokens at $2.50 + 200k completion tokens at $10.00 per 1M
+        assert!((response.cost_usd - 4.5).abs() < 1e-9, "cost: {}", response.cost_usd);
+    }
+
+    #[tokio::test]
+    async fn configured_rates_change_cost_for_same_tokens() {
+        let mock_server = MockServer::start().await;
+        mount_model_response(&mock_server, "gpt-4o-mini", 200).await;
+        let mut rates = CostRateConfig::default();
+        rates.models.insert(
+            "gpt-4o-mini".to_string(),
+            ModelPricing { input_per_1m: 0.30, output_per_1m: 1.20 },
+        );
+
+        let default_client = test_client(format!("{}/v1/chat/completions", mock_server.uri()))
+            .with_cost_rates(&CostRateConfig::default());
+        let repriced_client = test_client(format!("{}/v1/chat/completions", mock_server.uri()))
+            .with_cost_rates(&rates);
+
+        let default = default_client.classify_blocks(&[sample_block()]).await.expect("classify");
+        let repriced = repriced_client.classify_blocks(&[sample_block()]).await.expect("classify");
+
+        assert_eq!(default.prompt_tokens, repriced.prompt_tokens);
+        assert!((default.cost_usd - 0.27).abs() < 1e-9, "cost: {}", default.cost_usd);
+        // 1M prompt tokens at $0.30 + 200k completion tokens at $1.20 per 1M
+        assert!((repriced.cost_usd - 0.54).abs() < 1e-9, "cost: {}", repriced.cost_usd);
+    }
+
+    #[test]
+    fn prompt_versions_render_differently_for_same_block() {
+        let mut block = sample_block();
+        block.is_after_hours = true;
+
+        let v1 = PromptTemplate::v1().render(std::slice::from_ref(&block));
+        let v2 = PromptTemplate::v2().render(std::slice::from_ref(&block));
+
+        assert_ne!(v1.user, v2.user);
+        assert_eq!(v1.system, v2.system);
+        assert!(v1.user.contains("Block ID: block-123\nDuration: 3600 seconds\n"));
+        assert!(v1.user.contains("  - Chrome (100.0%) - 3600s\n"));
+        assert!(!v1.user.contains("after_hours"));
+        assert!(v2.user.contains("after_hours=true"));
+        assert_eq!((v1.version.as_str(), v2.version.as_str()), ("v1", "v2"));
+    }
+
+    #[tokio::test]
+    async fn records_prompt_version_in_response() {
+        let mock_server = MockServer::start().await;
+        mount_success(&mock_server).await;
+
+        let client = test_client(format!("{}/v1/chat/completions", mock_server.uri()))
+            .with_prompt_config(&PromptConfig { version: "v2".to_string() });
+
+        let response = client.classify_blocks(&[sample_block()]).await.expect("should classify");
+        assert_eq!(response.prompt_version, "v2");
+
+        let client = test_client(format!("{}/v1/chat/completions", mock_server.uri()));
+        let response = client.classify_blocks(&[sample_block()]).await.expect("should classify");
+        assert_eq!(response.prompt_version, "v1");
+    }
+
+    fn batch_client(mock_server: &MockServer) -> OpenAIClient {
+        test_client(format!("{}/v1/chat/completions", mock_server.uri()))
+            .with_api_base(format!("{}/v1", mock_server.uri()))
+    }
+
+    async fn mount_batch_submission(mock_server: &MockServer) {
+        Mock::given(method("POST"))
+            .and(path("/v1/files"))
+            .and(body_string_contains("\"custom_id\":\"block-123\""))
+            .and(body_string_contains("name=\"purpose\"\r\n\r\nbatch"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "file-input",
+                "purpose": "batch"
+            })))
+            .expect(1)
+            .mount(mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/batches"))
+            .and(body_partial_json(serde_json::json!({
+                "input_file_id": "file-input",
+                "endpoint": "/v1/chat/completions",
+                "completion_window": "24h"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "batch_abc",
+                "status": "validating"
+            })))
+            .expect(1)
+            .mount(mock_server)
+            .await;
+    }
+
+    async fn mount_batch_status(mock_server: &MockServer, body: serde_json::Value, times: u64) {
+        Mock::given(method("GET"))
+            .and(path("/v1/batches/batch_abc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .up_to_n_times(times)
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn batch_job_submit_poll_pending_then_complete() {
+        let mock_server = MockServer::start().await;
+        mount_batch_submission(&mock_server).await;
+        mount_batch_status(
+            &mock_server,
+            serde_json::json!({ "id": "batch_abc", "status": "in_progress" }),
+            1,
+        )
+        .await;
+        mount_batch_status(
+            &mock_server,
+            serde_json::json!({
+                "id": "batch_abc",
+                "status": "completed",
+                "output_file_id": "file-output"
+            }),
+            1,
+        )
+        .await;
+
+        let completion = serde_json::json!({
+            "custom_id": "block-123",
+            "response": {
+                "status_code": 200,
+                "body": {
+                    "choices": [{
+                        "message": {
+                            "content": r#"{"classifications": [{
+                                "id": "block-123",
+                                "billable": true,
+                                "description": "Client work",
+                                "confidence": 0.8,
+                                "reasons": []
+                            }]}"#
+                        }
+                    }],
+                    "usage": {
+                        "total_tokens": 1_200_000,
+                        "prompt_tokens": 1_000_000,
+                        "completion_tokens": 200_000
+                    }
+                }
+            }
+        });
+        Mock::given(method("GET"))
+            .and(path("/v1/files/file-output/content"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(format!("{completion}\n")))
+            .mount(&mock_server)
+            .await;
+
+        let client = batch_client(&mock_server);
+        let handle = client.submit_batch_job(&[sample_block()]).await.expect("submit");
+        assert_eq!(handle.batch_id, "batch_abc");
+        assert_eq!(handle.input_file_id, "file-input");
+        assert_eq!(handle.block_count, 1);
+
+        assert!(client.poll_batch_job(&handle).await.expect("poll").is_none());
+
+        let response = client.poll_batch_job(&handle).await.expect("poll").expect("batch complete");
+        assert_eq!(response.classifications.len(), 1);
+        assert_eq!(response.classifications[0].id, "block-123");
+        assert_eq!(response.tokens_used, 1_200_000);
+        // gpt-4o-mini: $0.15 + $0.12 at full price, halved by the batch discount
+        assert!((response.cost_usd - 0.135).abs() < 1e-9, "cost: {}", response.cost_usd);
+    }
+
+    #[tokio::test]
+    async fn batch_job_failure_is_reported() {
+        let mock_server = MockServer::start().await;
+        mount_batch_submission(&mock_server).await;
+        mount_batch_status(
+            &mock_server,
+            serde_json::json!({
+                "id": "batch_abc",
+                "status": "failed",
+                "errors": { "data": [{ "code": "invalid_request", "message": "bad line 1" }] }
+            }),
+            1,
+        )
+        .await;
+
+        let client = batch_client(&mock_server);
+        let handle = client.submit_batch_job(&[sample_block()]).await.expect("submit");
+        let result = client.poll_batch_job(&handle).await;
+
+        match result {
+            Err(OpenAIError::BatchFailed { batch_id, status, message }) => {
+                assert_eq!(batch_id, "batch_abc");
+                assert_eq!(status, "failed");
+                assert_eq!(message, "bad line 1");
+            }
+            other => panic!("expected BatchFailed, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn batch_job_rejects_empty_blocks() {
+        let mock_server = MockServer::start().await;
+        let client = batch_client(&mock_server);
+
+        let result = client.submit_batch_job(&[]).await;
+
+        assert!(matches!(result, Err(OpenAIError::InvalidRequest(_))));
+    }
+
     #[tokio::test]
     async fn returns_empty_for_empty_blocks() {
         let http_client =
@@ -0,0 +1,174 @@
+/// Opt-in request/response logging for the OpenAI client
+///
+/// Used when debugging misclassifications: each Chat Completions exchange is
+/// handed to an [`OpenAILogSink`] together with token counts and latency.
+/// Prompts and responses carry window titles and other sensitive activity
+/// data, so both are run through the privacy [`PatternMatcher`] before they
+/// reach the sink. If redaction fails the text is dropped rather than logged
+/// raw.
+///
+/// Logging is disabled unless [`OpenAILogConfig::enabled`] is set (or
+/// `OPENAI_LOG_EXCHANGES=true`).
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use pulsearc_common::privacy::PatternMatcher;
+use tracing::{debug, warn};
+
+use super::types::OpenAIError;
+
+/// Placeholder stored when redaction fails
+const REDACTION_FAILED: &str = "[REDACTION FAILED]";
+
+/// Exchange logging configuration
+#[derive(Debug, Clone, Default)]
+pub struct OpenAILogConfig {
+    /// Record exchanges to the configured sink (off by default)
+    pub enabled: bool,
+}
+
+impl OpenAILogConfig {
+    /// Read the flag from `OPENAI_LOG_EXCHANGES` (`true`/`1` enables it).
+    pub fn from_env() -> Self {
+        let enabled = std::env::var("OPENAI_LOG_EXCHANGES")
+            .map(|value| matches!(value.trim().to_ascii_lowercase().as_str(), "1" | "true"))
+            .unwrap_or(false);
+        Self { enabled }
+    }
+}
+
+/// One redacted request/response exchange
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenAIExchange {
+    /// Model the request was sent to
+    pub model: String,
+    /// User prompt, redacted
+    pub prompt: String,
+    /// Raw completion content, redacted (`None` on failure)
+    pub response: Option<String>,
+    /// Error message, redacted (`None` on success)
+    pub error: Option<String>,
+    /// Tokens used in the prompt (0 when the request failed)
+    pub prompt_tokens: i32,
+    /// Tokens used in the completion (0 when the request failed)
+    pub completion_tokens: i32,
+    /// Total tokens used (0 when the request failed)
+    pub total_tokens: i32,
+    /// Wall-clock time from sending the request to parsing the reply
+    pub latency: Duration,
+}
+
+/// Destination for logged exchanges
+///
+/// `record` is called on the request path, so implementations should be
+/// cheap (buffer or hand off rather than perform blocking I/O).
+pub trait OpenAILogSink: Send + Sync {
+    /// Record a single exchange.
+    fn record(&self, exchange: OpenAIExchange);
+}
+
+/// Sink that emits each exchange as a `debug` tracing event
+#[derive(Debug, Default)]
+pub struct TracingLogSink;
+
+impl OpenAILogSink for TracingLogSink {
+    fn record(&self, exchange: OpenAIExchange) {
+        debug!(
+            model = %exchange.model,
+            prompt = %exchange.prompt,
+            response = exchange.response.as_deref().unwrap_or_default(),
+            error = exchange.error.as_deref().unwrap_or_default(),
+            prompt_tokens = exchange.prompt_tokens,
+            completion_tokens = exchange.completion_tokens,
+            total_tokens = exchange.total_tokens,
+            latency_ms = exchange.latency.as_millis() as u64,
+            "OpenAI exchange"
+        );
+    }
+}
+
+/// Sink that keeps exchanges in memory (for inspection and tests)
+#[derive(Debug, Default)]
+pub struct InMemoryLogSink {
+    exchanges: Mutex<Vec<OpenAIExchange>>,
+}
+
+impl InMemoryLogSink {
+    /// Create an empty sink
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Exchanges recorded so far, oldest first
+    pub fn exchanges(&self) -> Vec<OpenAIExchange> {
+        self.exchanges.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+}
+
+impl OpenAILogSink for InMemoryLogSink {
+    fn record(&self, exchange: OpenAIExchange) {
+        self.exchanges.lock().unwrap_or_else(PoisonError::into_inner).push(exchange);
+    }
+}
+
+/// Unredacted outcome of a single API call
+pub(crate) struct ExchangeOutcome<'a> {
+    pub content: Option<&'a str>,
+    pub error: Option<&'a OpenAIError>,
+    pub prompt_tokens: i32,
+    pub completion_tokens: i32,
+    pub total_tokens: i32,
+}
+
+/// Redacts exchanges and forwards them to a sink
+#[derive(Clone)]
+pub(crate) struct ExchangeLogger {
+    sink: Arc<dyn OpenAILogSink>,
+    matcher: Arc<PatternMatcher>,
+}
+
+impl ExchangeLogger {
+    pub(crate) fn new(sink: Arc<dyn OpenAILogSink>, matcher: Arc<PatternMatcher>) -> Self {
+        Self { sink, matcher }
+    }
+
+    /// Redact `prompt` and `outcome`, then record them.
+    pub(crate) async fn record(
+        &self,
+        model: &str,
+        prompt: &str,
+        outcome: ExchangeOutcome<'_>,
+        latency: Duration,
+    ) {
+        let prompt = self.redact(prompt).await;
+        let response = match outcome.content {
+            Some(content) => Some(self.redact(content).await),
+            None => None,
+        };
+        let error = match outcome.error {
+            Some(err) => Some(self.redact(&err.to_string()).await),
+            None => None,
+        };
+
+        self.sink.record(OpenAIExchange {
+            model: model.to_string(),
+            prompt,
+            response,
+            error,
+            prompt_tokens: outcome.prompt_tokens,
+            completion_tokens: outcome.completion_tokens,
+            total_tokens: outcome.total_tokens,
+            latency,
+        });
+    }
+
+    async fn redact(&self, text: &str) -> String {
+        match self.matcher.redact_pii(text).await {
+            Ok(redacted) => redacted,
+            Err(err) => {
+                warn!(error = %err, "Failed to redact OpenAI exchange; dropping text");
+                REDACTION_FAILED.to_string()
+            }
+        }
+    }
+}
@@ -8,6 +8,13 @@
 /// - **Client**: `OpenAIClient` - HTTP client wrapper for OpenAI Chat
 ///   Completions API
 /// - **Types**: Request/response types for block classification
+/// - **Models**: Fallback chain and per-model pricing (`ModelChainConfig`)
+/// - **Batch**: Offline classification via the Batch API
+///   (`submit_batch_job` / `poll_batch_job`, billed at 50%)
+/// - **Prompts**: Versioned prompt templates (`PromptTemplate`), selected via
+///   `PromptConfig`
+/// - **Logging**: Opt-in, redacted request/response logging
+///   (`OpenAIClient::with_exchange_log`)
 /// - **Error Handling**: Structured error types with retry support
 /// # Usage
 ///
@@ -36,7 +43,8 @@
 /// # API Integration
 ///
 /// Uses OpenAI's Chat Completions API with:
-/// - Model: `gpt-4o-mini` (configurable via `with_model()`)
+/// - Model: `gpt-4o-mini` (configurable via `with_model()`, or a fallback
+///   chain via `with_model_chain()`)
 /// - Temperature: 0.3 (low variability for consistent classifications)
 /// - Response format: JSON object
 /// - Max tokens: 50,000
@@ -47,17 +55,31 @@
 /// - **Server errors (5xx)**: Retried with exponential backoff
 /// - **Client errors (4xx)**: Not retried (except 429 rate limits)
 /// - **Rate limits (429)**: Should be retried after delay
+/// - **Fallback**: 429 and 5xx (after retries) move on to the next model in
+///   the chain
 /// # Cost Tracking
 ///
 /// Token usage and costs are included in responses:
 /// - `tokens_used`: Total tokens (prompt + completion)
-/// - `cost_usd`: Estimated cost based on gpt-4o-mini pricing
+/// - `cost_usd`: Estimated cost based on the serving model's pricing
+/// - `model`: Model that served the response
+/// - `prompt_version`: Prompt template version used
 ///
-/// Current pricing (as of 2025):
-/// - Input: $0.150 per 1M tokens
-/// - Output: $0.600 per 1M tokens
+/// Per-model rates come from the `[costs]` config section
+/// (`CostRateConfig`, applied with `OpenAIClient::with_cost_rates`); the
+/// built-in defaults are used when it is missing.
+pub mod batch;
 pub mod client;
+pub mod logging;
+pub mod models;
+pub mod prompts;
 pub mod types;
 
+pub use batch::{BatchJobHandle, BATCH_COST_DISCOUNT};
 pub use client::OpenAIClient;
+pub use logging::{
+    InMemoryLogSink, OpenAIExchange, OpenAILogConfig, OpenAILogSink, TracingLogSink,
+};
+pub use models::{ModelChainConfig, ModelConfig, ModelPricing};
+pub use prompts::{PromptConfig, PromptTemplate, RenderedPrompt};
 pub use types::{BlockClassification, BlockClassificationResponse, OpenAIError};
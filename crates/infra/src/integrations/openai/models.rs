@@ -0,0 +1,182 @@
+/// Model fallback chain and per-model pricing for the OpenAI client
+///
+/// Classification tries the primary model first. When it is rate limited
+/// (429) or unavailable (5xx) after the `HttpClient` retries, the request is
+/// repeated with the next model in the chain, typically a cheaper or faster
+/// one. Each model carries its own token pricing so the reported cost matches
+/// the model that actually served the response.
+///
+/// The chain is read from `OPENAI_MODEL_CHAIN` as comma-separated
+/// `model:input_per_1m:output_per_1m` entries, primary first, e.g.
+/// `gpt-4o:2.50:10.00,gpt-4o-mini:0.15:0.60`. Rates from the app config
+/// (`CostRateConfig`) take precedence for the models they list; see
+/// [`ModelChainConfig::with_cost_rates`].
+pub use pulsearc_domain::ModelPricing;
+use tracing::warn;
+
+use super::types::OpenAIError;
+
+/// Default primary model
+pub const DEFAULT_MODEL: &str = "gpt-4o-mini";
+
+/// A model in the fallback chain
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelConfig {
+    /// Model name sent to the API (e.g. `gpt-4o-mini`)
+    pub name: String,
+    /// Pricing used to compute `cost_usd` for responses from this model
+    pub pricing: ModelPricing,
+}
+
+impl ModelConfig {
+    /// Create a model entry with its pricing
+    pub fn new(name: impl Into<String>, pricing: ModelPricing) -> Self {
+        Self { name: name.into(), pricing }
+    }
+}
+
+/// Ordered list of models to try, primary first
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelChainConfig {
+    models: Vec<ModelConfig>,
+}
+
+impl Default for ModelChainConfig {
+    fn default() -> Self {
+        Self::single(ModelConfig::new(DEFAULT_MODEL, ModelPricing::GPT_4O_MINI))
+    }
+}
+
+impl ModelChainConfig {
+    /// Chain with only a primary model (no fallback).
+    pub fn single(primary: ModelConfig) -> Self {
+        Self { models: vec![primary] }
+    }
+
+    /// Append a fallback model tried after the models already in the chain.
+    pub fn with_fallback(mut self, model: ModelConfig) -> Self {
+        self.models.push(model);
+        self
+    }
+
+    /// Read the chain from `OPENAI_MODEL_CHAIN`, falling back to the default
+    /// when unset or invalid.
+    pub fn from_env() -> Self {
+        match std::env::var("OPENAI_MODEL_CHAIN") {
+            Ok(value) => Self::parse(&value).unwrap_or_else(|err| {
+                warn!(error = %err, "invalid OPENAI_MODEL_CHAIN, using default model");
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Parse comma-separated `model:input_per_1m:output_per_1m` entries.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let models = value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(parse_model_entry)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if models.is_empty() {
+            return Err("model chain is empty".to_string());
+        }
+        Ok(Self { models })
+    }
+
+    /// Reprice every model that has rates in `rates`
+    ///
+    /// Models without configured rates keep their current pricing.
+    pub fn with_cost_rates(mut self, rates: &pulsearc_domain::CostRateConfig) -> Self {
+        for model in &mut self.models {
+            if let Some(pricing) = rates.model_pricing(&model.name) {
+                model.pricing = pricing;
+            }
+        }
+        self
+    }
+
+    /// Models in the order they are tried.
+    pub fn models(&self) -> &[ModelConfig] {
+        &self.models
+    }
+
+    /// The primary model.
+    pub fn primary(&self) -> &ModelConfig {
+        // `single`, `parse` and `Default` all guarantee at least one model
+        &self.models[0]
+    }
+}
+
+fn parse_model_entry(entry: &str) -> Result<ModelConfig, String> {
+    let mut parts = entry.split(':').map(str::trim);
+    let name = parts.next().filter(|name| !name.is_empty());
+    let input = parts.next().map(str::parse::<f64>);
+    let output = parts.next().map(str::parse::<f64>);
+
+    match (name, input, output, parts.next()) {
+        (Some(name), Some(Ok(input)), Some(Ok(output)), None) if input >= 0.0 && output >= 0.0 => {
+            Ok(ModelConfig::new(name, ModelPricing { input_per_1m: input, output_per_1m: output }))
+        }
+        _ => Err(format!("expected model:input_per_1m:output_per_1m, got '{entry}'")),
+    }
+}
+
+/// Whether `err` means the model is overloaded or unavailable, so the next
+/// model in the chain should be tried.
+pub(crate) fn should_fall_back(err: &OpenAIError) -> bool {
+    match err {
+        OpenAIError::RateLimit(_) => true,
+        OpenAIError::Api { status, .. } => (500..600).contains(status),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pulsearc_domain::CostRateConfig;
+
+    use super::*;
+
+    #[test]
+    fn parses_chain_with_pricing() {
+        let chain = ModelChainConfig::parse("gpt-4o:2.50:10.00, gpt-4o-mini:0.15:0.60").unwrap();
+
+        assert_eq!(chain.primary().name, "gpt-4o");
+        assert_eq!(chain.models().len(), 2);
+        assert_eq!(chain.models()[1].pricing, ModelPricing::GPT_4O_MINI);
+    }
+
+    #[test]
+    fn configured_rates_override_chain_pricing() {
+        let chain = ModelChainConfig::parse("gpt-4o:2.50:10.00,custom-model:1:1").unwrap();
+        let mut rates = CostRateConfig::default();
+        rates
+            .models
+            .insert("gpt-4o".to_string(), ModelPricing { input_per_1m: 5.0, output_per_1m: 20.0 });
+
+        let chain = chain.with_cost_rates(&rates);
+
+        assert_eq!(chain.primary().pricing.cost_usd(1_000_000, 1_000_000), 25.0);
+        assert_eq!(chain.models()[1].pricing.input_per_1m, 1.0);
+    }
+
+    #[test]
+    fn rejects_malformed_entries() {
+        assert!(ModelChainConfig::parse("").is_err());
+        assert!(ModelChainConfig::parse("gpt-4o:2.50").is_err());
+        assert!(ModelChainConfig::parse("gpt-4o:abc:1").is_err());
+        assert!(ModelChainConfig::parse("gpt-4o:-1:1").is_err());
+    }
+
+    #[test]
+    fn falls_back_only_on_rate_limit_and_server_errors() {
+        assert!(should_fall_back(&OpenAIError::RateLimit(60)));
+        assert!(should_fall_back(&OpenAIError::Api { status: 503, message: String::new() }));
+        assert!(!should_fall_back(&OpenAIError::Api { status: 400, message: String::new() }));
+        assert!(!should_fall_back(&OpenAIError::Authentication("bad key".into())));
+        assert!(!should_fall_back(&OpenAIError::InvalidSchema("bad json".into())));
+    }
+}
@@ -0,0 +1,231 @@
+/// Versioned prompt templates for block classification
+///
+/// The system and user prompts are rendered from a [`PromptTemplate`] so the
+/// wording can be tuned (and A/B tested) without touching the client. Each
+/// template has a version; the version used is recorded on every
+/// `BlockClassificationResponse` for later analysis.
+///
+/// Templates use `{placeholder}` interpolation. Per-block placeholders:
+/// `{id}`, `{duration_secs}`, `{start_ts}`, `{end_ts}`, `{activities}`,
+/// `{total_idle_secs}`, `{timezone}`, `{is_weekend}`, `{is_after_hours}`,
+/// `{is_travel}`, `{has_calendar_overlap}`. Per-activity placeholders:
+/// `{name}`, `{percentage}`, `{duration_secs}`. Unknown placeholders are left
+/// as-is.
+///
+/// The version is selected with `OPENAI_PROMPT_VERSION` (default `v1`).
+use pulsearc_domain::types::classification::{ActivityBreakdown, ProposedBlock};
+use tracing::warn;
+
+/// Version used when none is configured
+pub const DEFAULT_PROMPT_VERSION: &str = "v1";
+
+const SYSTEM_PROMPT: &str = "You are an M&A tax professional time entry classifier. Analyze work blocks and classify them as billable or G&A (non-billable) based on activity signals.";
+
+const RESPONSE_INSTRUCTIONS: &str = "Return JSON with 'classifications' array. Each item must have: id, billable (bool), description, confidence (0.0-1.0), reasons (array), and optionally: project_id, wbs_code, deal_name, workstream.";
+
+/// A rendered system/user prompt pair
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedPrompt {
+    /// Template version that produced this prompt
+    pub version: String,
+    pub system: String,
+    pub user: String,
+}
+
+/// Classification prompt template
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PromptTemplate {
+    /// Version recorded on each classification (e.g. `v1`)
+    pub version: String,
+    /// System message
+    pub system: String,
+    /// Start of the user message, before the blocks
+    pub preamble: String,
+    /// Rendered once per block
+    pub block: String,
+    /// Prepended to `{activities}` when the block has activities
+    pub activities_header: String,
+    /// Rendered once per activity into `{activities}`
+    pub activity: String,
+    /// End of the user message, after the blocks
+    pub instructions: String,
+}
+
+impl PromptTemplate {
+    /// Original classification prompt
+    pub fn v1() -> Self {
+        Self {
+            version: "v1".to_string(),
+            system: SYSTEM_PROMPT.to_string(),
+            preamble: "Classify each time block as billable (client work) or G&A (non-billable).\n\n"
+                .to_string(),
+            block: "Block ID: {id}\nDuration: {duration_secs} seconds\nStart: {start_ts}\nEnd: {end_ts}\n{activities}\n"
+                .to_string(),
+            activities_header: "Activities:\n".to_string(),
+            activity: "  - {name} ({percentage}%) - {duration_secs}s\n".to_string(),
+            instructions: RESPONSE_INSTRUCTIONS.to_string(),
+        }
+    }
+
+    /// Adds the block's context flags (weekend, after hours, travel,
+    /// calendar overlap, idle time) so the model can weigh them
+    pub fn v2() -> Self {
+        Self {
+            version: "v2".to_string(),
+            block: "Block ID: {id}\nDuration: {duration_secs} seconds ({total_idle_secs}s idle)\nStart: {start_ts}\nEnd: {end_ts}\nTimezone: {timezone}\nContext: weekend={is_weekend}, after_hours={is_after_hours}, travel={is_travel}, calendar_overlap={has_calendar_overlap}\n{activities}\n"
+                .to_string(),
+            ..Self::v1()
+        }
+    }
+
+    /// Built-in template for `version`, if one exists.
+    pub fn builtin(version: &str) -> Option<Self> {
+        match version {
+            "v1" => Some(Self::v1()),
+            "v2" => Some(Self::v2()),
+            _ => None,
+        }
+    }
+
+    /// Render the system and user prompts for `blocks`.
+    pub fn render(&self, blocks: &[ProposedBlock]) -> RenderedPrompt {
+        let mut user = self.preamble.clone();
+        for block in blocks {
+            user.push_str(&self.render_block(block));
+        }
+        user.push_str(&self.instructions);
+
+        RenderedPrompt { version: self.version.clone(), system: self.system.clone(), user }
+    }
+
+    fn render_block(&self, block: &ProposedBlock) -> String {
+        let activities = if block.activities.is_empty() {
+            String::new()
+        } else {
+            let mut rendered = self.activities_header.clone();
+            for activity in &block.activities {
+                rendered.push_str(&self.render_activity(activity));
+            }
+            rendered
+        };
+
+        interpolate(
+            &self.block,
+            &[
+                ("id", block.id.clone()),
+                ("duration_secs", block.duration_secs.to_string()),
+                ("start_ts", block.start_ts.to_string()),
+                ("end_ts", block.end_ts.to_string()),
+                ("total_idle_secs", block.total_idle_secs.to_string()),
+                ("timezone", block.timezone.clone().unwrap_or_else(|| "unknown".to_string())),
+                ("is_weekend", block.is_weekend.to_string()),
+                ("is_after_hours", block.is_after_hours.to_string()),
+                ("is_travel", block.is_travel.to_string()),
+                ("has_calendar_overlap", block.has_calendar_overlap.to_string()),
+                ("activities", activities),
+            ],
+        )
+    }
+
+    fn render_activity(&self, activity: &ActivityBreakdown) -> String {
+        interpolate(
+            &self.activity,
+            &[
+                ("name", activity.name.clone()),
+                ("percentage", format!("{:.1}", activity.percentage)),
+                ("duration_secs", activity.duration_secs.to_string()),
+            ],
+        )
+    }
+}
+
+impl Default for PromptTemplate {
+    fn default() -> Self {
+        Self::v1()
+    }
+}
+
+/// Prompt template selection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PromptConfig {
+    /// Built-in template version to use
+    pub version: String,
+}
+
+impl Default for PromptConfig {
+    fn default() -> Self {
+        Self { version: DEFAULT_PROMPT_VERSION.to_string() }
+    }
+}
+
+impl PromptConfig {
+    /// Read the version from `OPENAI_PROMPT_VERSION`.
+    pub fn from_env() -> Self {
+        std::env::var("OPENAI_PROMPT_VERSION")
+            .ok()
+            .map(|version| version.trim().to_string())
+            .filter(|version| !version.is_empty())
+            .map(|version| Self { version })
+            .unwrap_or_default()
+    }
+
+    /// The configured template, or the default when the version is unknown.
+    pub fn template(&self) -> PromptTemplate {
+        PromptTemplate::builtin(&self.version).unwrap_or_else(|| {
+            warn!(version = %self.version, "unknown prompt template version, using default");
+            PromptTemplate::default()
+        })
+    }
+}
+
+/// Replace each `{key}` in `template` with its value in a single pass, so
+/// values containing braces are never re-interpolated.
+fn interpolate(template: &str, values: &[(&str, String)]) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(open) = rest.find('{') {
+        rendered.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let value = after.find('}').and_then(|close| {
+            let key = &after[..close];
+            values.iter().find(|(name, _)| *name == key).map(|(_, value)| (value, close))
+        });
+        match value {
+            Some((value, close)) => {
+                rendered.push_str(value);
+                rest = &after[close + 1..];
+            }
+            None => {
+                rendered.push('{');
+                rest = after;
+            }
+        }
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolates_known_placeholders_only() {
+        let rendered = interpolate(
+            "{a} and {b} but not {c} or {",
+            &[("a", "one".to_string()), ("b", "{a}".to_string())],
+        );
+
+        assert_eq!(rendered, "one and {a} but not {c} or {");
+    }
+
+    #[test]
+    fn builtin_versions_and_fallback() {
+        assert_eq!(PromptTemplate::builtin("v2").map(|t| t.version), Some("v2".to_string()));
+        assert!(PromptTemplate::builtin("v9").is_none());
+
+        let config = PromptConfig { version: "v9".to_string() };
+        assert_eq!(config.template().version, DEFAULT_PROMPT_VERSION);
+    }
+}
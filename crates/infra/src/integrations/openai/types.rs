@@ -16,6 +16,13 @@ pub struct BlockClassificationResponse {
     /// Estimated cost in USD (computed post-response)
     #[serde(default)]
     pub cost_usd: f64,
+    /// Model that served the response (differs from the primary after a
+    /// fallback)
+    #[serde(default)]
+    pub model: String,
+    /// Prompt template version used for the request
+    #[serde(default)]
+    pub prompt_version: String,
 }
 
 /// A single block classification result from OpenAI
@@ -72,6 +79,15 @@ pub enum OpenAIError {
     /// Request timeout
     #[error("Request timeout after {0:?}")]
     Timeout(std::time::Duration),
+
+    /// Request rejected before it was sent (e.g. empty batch)
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+
+    /// Batch job reached a terminal failure status (`failed`, `expired`,
+    /// `cancelled`)
+    #[error("Batch {batch_id} {status}: {message}")]
+    BatchFailed { batch_id: String, status: String, message: String },
 }
 
 /// Internal types for OpenAI Chat Completions API
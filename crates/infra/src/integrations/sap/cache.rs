@@ -1,8 +1,9 @@
-//! WBS code caching with moka
+//! WBS code and posting period caching with moka
 //!
 //! Provides in-memory cache for WBS validation results to reduce database
 //! queries. Uses WbsRepository as backing store with separate positive and
-//! negative caches.
+//! negative caches. `PeriodCache` applies the same TTL to SAP posting period
+//! status lookups.
 //!
 //! # Architecture
 //!
@@ -35,6 +36,8 @@ use pulsearc_core::classification::ports::WbsRepository;
 use pulsearc_domain::types::sap::WbsElement;
 use pulsearc_domain::{PulseArcError, Result};
 
+use super::validation::normalize_wbs_code;
+
 /// Default TTL for WBS cache entries (5 minutes)
 ///
 /// Override via `SAP_CACHE_TTL_SECONDS` environment variable
@@ -296,9 +299,10 @@ impl<C: Clock> WbsCache<C> {
         }
     }
 
-    /// Normalize WBS code (uppercase, trim)
+    /// Cache key for a WBS code (same normalization as validation and
+    /// submission)
     fn normalize(code: &str) -> String {
-        code.trim().to_uppercase()
+        normalize_wbs_code(code)
     }
 
     fn expiration_deadline(&self) -> Instant {
@@ -362,6 +366,70 @@ impl CacheStats {
     }
 }
 
+/// In-memory cache for SAP posting period status, keyed by date
+///
+/// Uses the same TTL configuration as `WbsCache`. Both open and closed
+/// results are cached; lookup errors never are.
+pub struct PeriodCache<C: Clock = SystemClock> {
+    cache: Cache<String, CachedPeriodEntry>,
+    clock: Arc<C>,
+    config: WbsCacheConfig,
+}
+
+impl PeriodCache<SystemClock> {
+    /// Create a new period cache with the system clock
+    pub fn new(config: WbsCacheConfig) -> Self {
+        Self::with_clock(config, SystemClock)
+    }
+}
+
+impl<C: Clock> PeriodCache<C> {
+    /// Create a new period cache with custom clock (for testing)
+    pub fn with_clock(config: WbsCacheConfig, clock: C) -> Self {
+        let cache = Cache::builder().max_capacity(config.max_capacity).build();
+        Self { cache, clock: Arc::new(clock), config }
+    }
+
+    /// Cached open/closed status for `date`, if present and not expired
+    pub fn get(&self, date: &str) -> Option<bool> {
+        let key = date.trim();
+        let entry = self.cache.get(key)?;
+
+        if entry.is_expired(self.clock.now()) {
+            self.cache.invalidate(key);
+            tracing::debug!(date = key, "Posting period cache entry expired");
+            return None;
+        }
+
+        tracing::debug!(date = key, open = entry.open, "Posting period cache hit");
+        Some(entry.open)
+    }
+
+    /// Cache the open/closed status for `date`
+    pub fn insert(&self, date: &str, open: bool) {
+        let expires_at = self.clock.now() + self.config.ttl;
+        self.cache.insert(date.trim().to_string(), CachedPeriodEntry { open, expires_at });
+    }
+
+    /// Clear all cached period statuses
+    pub fn clear(&self) {
+        self.cache.invalidate_all();
+    }
+}
+
+/// Cached posting period status with expiry metadata
+#[derive(Clone)]
+struct CachedPeriodEntry {
+    open: bool,
+    expires_at: Instant,
+}
+
+impl CachedPeriodEntry {
+    fn is_expired(&self, now: Instant) -> bool {
+        now >= self.expires_at
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Mutex;
@@ -453,6 +521,26 @@ mod tests {
         assert_eq!(repo.query_count(), 1); // Still 1, not 2
     }
 
+    #[test]
+    fn test_equivalent_codes_share_cache_entry() {
+        let config = WbsCacheConfig::with_ttl(Duration::from_secs(60));
+        let cache = WbsCache::new(config);
+        let repo = MockWbsRepository::new(vec!["USC0063201.1.1".to_string()]);
+
+        cache.get_or_fetch("usc0063201.1.1.", &repo).unwrap();
+        assert_eq!(repo.query_count(), 1);
+
+        for variant in
+            ["USC0063201.1.1", "\u{00A0}Usc0063201\u{FF0E}1.1 ", "\u{FEFF}usc0063201.1.1"]
+        {
+            assert!(matches!(cache.get(variant), CacheResult::Hit(_)), "variant {variant:?}");
+        }
+        assert_eq!(repo.query_count(), 1);
+
+        cache.invalidate("usc0063201.1.1..");
+        assert!(matches!(cache.get("USC0063201.1.1"), CacheResult::Miss));
+    }
+
     #[test]
     fn test_negative_caching() {
         let config = WbsCacheConfig::with_ttl(Duration::from_secs(60));
@@ -584,4 +672,19 @@ mod tests {
         // Verify it's expired
         assert!(matches!(cache.get("USC0063201.1.1"), CacheResult::Miss));
     }
+
+    #[test]
+    fn test_period_cache_expires_after_ttl() {
+        let clock = MockClock::new();
+        let config = WbsCacheConfig::with_ttl(Duration::from_secs(300));
+        let cache = PeriodCache::with_clock(config, clock.clone());
+
+        assert_eq!(cache.get("2025-10-31"), None);
+        cache.insert("2025-10-31", false);
+        assert_eq!(cache.get(" 2025-10-31 "), Some(false));
+
+        clock.advance(Duration::from_secs(301));
+
+        assert_eq!(cache.get("2025-10-31"), None);
+    }
 }
@@ -7,11 +7,13 @@ use pulsearc_core::classification::ports::WbsRepository;
 use pulsearc_core::sap_ports::{SapClient as SapClientTrait, SapEntryId, TimeEntry};
 use pulsearc_domain::{PulseArcError, Result};
 use reqwest::Method;
-use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
 use tracing::{debug, info, warn};
 
-use super::cache::{WbsCache, WbsCacheConfig};
-use super::validation::WbsValidator;
+use super::cache::{PeriodCache, WbsCache, WbsCacheConfig};
+use super::graphql::{SapGraphQlConfig, TimeEntryValues};
+use super::validation::{normalize_wbs_code, WbsValidator};
 use crate::http::HttpClient;
 
 const DEFAULT_TIMEOUT_SECS: u64 = 30;
@@ -22,6 +24,8 @@ pub struct SapClient {
     base_url: String,
     http_client: HttpClient,
     wbs_validator: Arc<WbsValidator>,
+    period_cache: Arc<PeriodCache>,
+    graphql: SapGraphQlConfig,
     user_id: String,
     access_token_provider: Arc<dyn AccessTokenProvider>,
 }
@@ -85,7 +89,31 @@ impl SapClient {
             .max_attempts(3)
             .build()?;
 
-        Ok(Self { base_url, http_client, wbs_validator, user_id, access_token_provider })
+        Ok(Self {
+            base_url,
+            http_client,
+            wbs_validator,
+            period_cache: Arc::new(PeriodCache::new(WbsCacheConfig::default())),
+            graphql: SapGraphQlConfig::default(),
+            user_id,
+            access_token_provider,
+        })
+    }
+
+    /// Use custom GraphQL operations and variable mappings
+    ///
+    /// The config is validated again so hand-assembled configs cannot bypass
+    /// the required-variable checks.
+    pub fn with_graphql_config(mut self, graphql: SapGraphQlConfig) -> Result<Self> {
+        graphql.validate()?;
+        self.graphql = graphql;
+        Ok(self)
+    }
+
+    /// Replace the posting period cache (for testing with `MockClock`)
+    pub fn with_period_cache(mut self, period_cache: Arc<PeriodCache>) -> Self {
+        self.period_cache = period_cache;
+        self
     }
 
     /// Check if SAP connector server is reachable
@@ -127,44 +155,32 @@ impl SapClient {
     async fn submit_time_entry(&self, entry: &TimeEntry, access_token: &str) -> Result<SapEntryId> {
         let correlation_id = uuid::Uuid::new_v4().to_string();
 
-        let query = r#"
-            mutation SubmitTimeEntries($entries: [TimeEntryInput!]!) {
-                submitTimeEntries(entries: $entries) {
-                    acceptedCount
-                    duplicateCount
-                    errors {
-                        correlationId
-                        code
-                        message
-                    }
-                }
-            }
-        "#;
-
         // Convert duration from hours to seconds for API
         let duration_seconds = (entry.duration_hours * 3600.0).round() as i32;
 
-        let time_entry_input = TimeEntryInput {
+        let values = TimeEntryValues {
             user_id: self.user_id.clone(),
             date: entry.date.clone(),
-            wbs_code: entry.wbs_code.clone(),
-            duration: duration_seconds,
+            wbs_code: normalize_wbs_code(&entry.wbs_code),
+            duration_seconds,
             note: if entry.description.is_empty() { None } else { Some(entry.description.clone()) },
             correlation_id: correlation_id.clone(),
         };
-
-        let variables = serde_json::json!({
-            "entries": [time_entry_input]
-        });
-
-        let result = self
-            .execute_graphql::<SubmitTimeEntriesResponse>(query, Some(variables), access_token)
+        let variables = self.graphql.submit_variables(&values);
+
+        let data = self
+            .execute_graphql::<serde_json::Value>(
+                &self.graphql.submit_mutation,
+                Some(variables),
+                access_token,
+            )
             .await?;
+        let result: TimeEntryBatchResult =
+            extract_result_field(data, &self.graphql.submit_result_field)?;
 
         // Check for errors in the response and include all correlation IDs
-        if !result.submit_time_entries.errors.is_empty() {
+        if !result.errors.is_empty() {
             let error_details: Vec<String> = result
-                .submit_time_entries
                 .errors
                 .iter()
                 .map(|e| {
@@ -181,7 +197,7 @@ impl SapClient {
             )));
         }
 
-        if result.submit_time_entries.accepted_count == 0 {
+        if result.accepted_count == 0 {
             return Err(PulseArcError::Network(format!(
                 "Time entry was not accepted by SAP (correlation_id={})",
                 correlation_id
@@ -190,14 +206,34 @@ impl SapClient {
 
         info!(
             correlation_id = %correlation_id,
-            accepted = result.submit_time_entries.accepted_count,
-            duplicates = result.submit_time_entries.duplicate_count,
+            accepted = result.accepted_count,
+            duplicates = result.duplicate_count,
             "Successfully submitted time entry to SAP"
         );
 
         Ok(correlation_id)
     }
 
+    /// Query SAP for the posting period status of `date`
+    ///
+    /// # Returns
+    /// `true` if the period containing `date` accepts postings
+    async fn fetch_period_status(&self, date: &str, access_token: &str) -> Result<bool> {
+        let variables = self.graphql.period_variables(date);
+        let data = self
+            .execute_graphql::<serde_json::Value>(
+                &self.graphql.period_query,
+                Some(variables),
+                access_token,
+            )
+            .await?;
+        let status: PostingPeriodStatus =
+            extract_result_field(data, &self.graphql.period_result_field)?;
+
+        debug!(date, open = status.is_open, "Fetched SAP posting period status");
+        Ok(status.is_open)
+    }
+
     /// Execute a GraphQL query/mutation
     ///
     /// # Arguments
@@ -282,28 +318,36 @@ impl SapClientTrait for SapClient {
 
         Ok(result.is_ok())
     }
+
+    async fn is_period_open(&self, date: &str) -> Result<bool> {
+        if let Some(open) = self.period_cache.get(date) {
+            return Ok(open);
+        }
+
+        let access_token = self.access_token_provider.access_token().await?;
+        let open = self.fetch_period_status(date, &access_token).await?;
+        self.period_cache.insert(date, open);
+        Ok(open)
+    }
 }
 
 // =============================================================================
 // GraphQL Types
 // =============================================================================
 
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
-struct TimeEntryInput {
-    user_id: String,
-    date: String, // YYYY-MM-DD
-    wbs_code: String,
-    duration: i32, // seconds
-    #[serde(skip_serializing_if = "Option::is_none")]
-    note: Option<String>,
-    correlation_id: String,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct SubmitTimeEntriesResponse {
-    submit_time_entries: TimeEntryBatchResult,
+/// Take the operation's root field (configured per operation) out of the
+/// GraphQL `data` object
+fn extract_result_field<T: DeserializeOwned>(
+    mut data: serde_json::Value,
+    field: &str,
+) -> Result<T> {
+    let value = data.get_mut(field).map(serde_json::Value::take).ok_or_else(|| {
+        PulseArcError::Internal(format!("GraphQL response missing '{field}' field"))
+    })?;
+
+    serde_json::from_value(value).map_err(|e| {
+        PulseArcError::Internal(format!("Failed to parse GraphQL '{field}' result: {e}"))
+    })
 }
 
 #[derive(Debug, Deserialize)]
@@ -322,6 +366,12 @@ struct TimeEntryError {
     message: String,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PostingPeriodStatus {
+    is_open: bool,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct GraphQLResponse<T> {
@@ -339,10 +389,11 @@ mod tests {
     use std::sync::Arc;
 
     use pulsearc_domain::types::sap::WbsElement;
-    use wiremock::matchers::{header, method, path};
+    use wiremock::matchers::{body_partial_json, header, method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use super::*;
+    use crate::integrations::sap::graphql::TimeEntryFieldMapping;
 
     // Mock WBS Repository for testing
     struct MockWbsRepository {
@@ -553,6 +604,84 @@ mod tests {
         assert!(error_msg.contains("WBS code not found"));
     }
 
+    #[tokio::test]
+    async fn submits_with_custom_mutation_template() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_partial_json(serde_json::json!({
+                "variables": {
+                    "items": [{
+                        "sapUser": "test-user",
+                        "wbsElement": "USC0063201.1.1",
+                        "seconds": 9000,
+                        "day": "2025-10-31"
+                    }]
+                }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "postTime": {
+                        "acceptedCount": 1,
+                        "duplicateCount": 0,
+                        "errors": []
+                    }
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let graphql = SapGraphQlConfig::builder()
+            .submit_mutation(
+                "mutation Post($items: [TimeInput!]!) { postTime(items: $items) { acceptedCount duplicateCount errors { correlationId code message } } }",
+            )
+            .entries_variable("items")
+            .submit_result_field("postTime")
+            .entry_fields(TimeEntryFieldMapping {
+                user_id: "sapUser".to_string(),
+                date: "day".to_string(),
+                wbs_code: "wbsElement".to_string(),
+                duration: "seconds".to_string(),
+                ..TimeEntryFieldMapping::default()
+            })
+            .build()
+            .expect("valid GraphQL config");
+        let client = create_test_client(mock_server.uri())
+            .with_graphql_config(graphql)
+            .expect("config accepted");
+        let entry = TimeEntry {
+            wbs_code: "usc0063201.1.1".to_string(),
+            description: "Test work".to_string(),
+            duration_hours: 2.5,
+            date: "2025-10-31".to_string(),
+        };
+
+        let entry_id = client.submit_time_entry(&entry, "test-token").await.expect("submitted");
+
+        assert!(!entry_id.is_empty());
+    }
+
+    #[tokio::test]
+    async fn caches_posting_period_status() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "postingPeriod": { "isOpen": false } }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = create_test_client(mock_server.uri());
+
+        assert!(!client.is_period_open("2025-09-30").await.expect("period status"));
+        assert!(!client.is_period_open("2025-09-30").await.expect("cached period status"));
+    }
+
     #[tokio::test]
     async fn fails_fast_without_access_token() {
         let provider: Arc<dyn AccessTokenProvider> =
@@ -49,6 +49,16 @@ impl SapErrorCategory {
         )
     }
 
+    /// Returns true if resubmitting the same entry can never succeed
+    ///
+    /// Only validation failures (invalid WBS, closed posting period) are
+    /// permanent. Authentication and unknown errors are not retryable as-is
+    /// but may clear up without changing the entry, so they are not
+    /// dead-lettered.
+    pub fn is_permanent(&self) -> bool {
+        matches!(self, Self::Validation)
+    }
+
     /// Returns recommended retry delay in seconds
     pub fn retry_delay_secs(&self) -> Option<u64> {
         match self {
@@ -305,6 +315,14 @@ mod tests {
         assert!(category.user_message().contains("unexpected error"));
     }
 
+    #[test]
+    fn only_validation_is_permanent() {
+        assert!(SapErrorCategory::Validation.is_permanent());
+        assert!(!SapErrorCategory::Authentication.is_permanent());
+        assert!(!SapErrorCategory::Unknown.is_permanent());
+        assert!(!SapErrorCategory::NetworkOffline.is_permanent());
+    }
+
     #[test]
     fn status_401_maps_to_authentication() {
         let err = SapError::from_status_code(StatusCode::UNAUTHORIZED);
@@ -2,8 +2,12 @@
 //!
 //! This module provides:
 //! - Pure data conversion functions (easily testable)
-//! - Batch processing with exponential backoff
+//! - Batch processing with exponential backoff, optionally capped by a shared
+//!   retry budget
 //! - Circuit breaker integration for fault tolerance
+//! - Dead-letter routing for permanently failing entries
+//! - Posting period checks that defer entries for closed or future periods
+//! - Validate-only (dry-run) mode that checks a batch without posting it
 //! - Structured tracing for observability
 //!
 //! # Architecture
@@ -37,6 +41,9 @@
 //!     match outcome.status {
 //!         EntrySubmissionStatus::Submitted { sap_entry_id } => println!("Sent {}", sap_entry_id),
 //!         EntrySubmissionStatus::Failed { error } => eprintln!("Failed {}: {}", outcome.outbox_id, error),
+//!         EntrySubmissionStatus::DeadLettered { error } => eprintln!("Dead-lettered {}: {}", outcome.outbox_id, error),
+//!         EntrySubmissionStatus::Deferred { date, reason } => println!("Deferred {} ({date}): {reason:?}", outcome.outbox_id),
+//!         EntrySubmissionStatus::Validated => println!("Valid {}", outcome.outbox_id),
 //!     }
 //! }
 //! # Ok(())
@@ -46,8 +53,12 @@
 use std::sync::Arc;
 use std::time::Duration;
 
-use chrono::{DateTime, Utc};
-use pulsearc_common::resilience::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig};
+use chrono::{DateTime, NaiveDate, Utc};
+use pulsearc_common::resilience::circuit_breaker::{
+    CircuitBreaker, CircuitBreakerConfig, ResilienceError,
+};
+use pulsearc_common::sync::RetryBudget;
+use pulsearc_core::batch::ports::DlqRepository;
 use pulsearc_core::sap_ports::{
     SapClient as SapClientTrait, SapEntryId, TimeEntry as SapTimeEntry,
 };
@@ -188,16 +199,32 @@ fn map_submission_error(err: PulseArcError, outbox_id: &str) -> SapError {
     map_pulsearc_error(err, outbox_id, "submission")
 }
 
+fn map_validation_error(err: PulseArcError, outbox_id: &str) -> SapError {
+    map_pulsearc_error(err, outbox_id, "validation")
+}
+
 fn map_pulsearc_error(err: PulseArcError, outbox_id: &str, stage: &str) -> SapError {
-    let category = match &err {
+    let category = classify_pulsearc_error(&err);
+    let message = err.to_string();
+    SapError::new(category, message).with_context(format!("outbox_id={outbox_id}, stage={stage}"))
+}
+
+/// Closed periods in the future have not opened yet; everything else
+/// (including unparseable dates) is treated as closed.
+fn deferral_reason(date: &str, today: NaiveDate) -> DeferralReason {
+    match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+        Ok(date) if date > today => DeferralReason::PeriodNotYetOpen,
+        _ => DeferralReason::PeriodClosed,
+    }
+}
+
+fn classify_pulsearc_error(err: &PulseArcError) -> SapErrorCategory {
+    match err {
         PulseArcError::InvalidInput(_) | PulseArcError::Config(_) => SapErrorCategory::Validation,
         PulseArcError::Network(_) => SapErrorCategory::NetworkOffline,
         PulseArcError::Auth(_) => SapErrorCategory::Authentication,
         _ => SapErrorCategory::Unknown,
-    };
-
-    let message = err.to_string();
-    SapError::new(category, message).with_context(format!("outbox_id={outbox_id}, stage={stage}"))
+    }
 }
 
 #[cfg(test)]
@@ -207,7 +234,7 @@ mod tests {
 
     use async_trait::async_trait;
     use pulsearc_core::sap_ports::SapClient as SapClientTrait;
-    use pulsearc_domain::{OutboxStatus, PulseArcError, TimeEntryOutbox};
+    use pulsearc_domain::{BatchQueue, DlqBatch, OutboxStatus, PulseArcError, TimeEntryOutbox};
 
     use super::*;
     use crate::integrations::sap::SapErrorCategory;
@@ -255,11 +282,31 @@ mod tests {
 
     struct MockSapClient {
         responses: Mutex<VecDeque<Result<SapEntryId>>>,
+        closed_dates: Vec<String>,
+        invalid_wbs: Vec<String>,
     }
 
     impl MockSapClient {
         fn new(responses: Vec<Result<SapEntryId>>) -> Self {
-            Self { responses: Mutex::new(VecDeque::from(responses)) }
+            Self {
+                responses: Mutex::new(VecDeque::from(responses)),
+                closed_dates: Vec::new(),
+                invalid_wbs: Vec::new(),
+            }
+        }
+
+        fn with_invalid_wbs(mut self, codes: &[&str]) -> Self {
+            self.invalid_wbs = codes.iter().map(|code| code.to_string()).collect();
+            self
+        }
+
+        fn with_closed_dates(mut self, dates: &[&str]) -> Self {
+            self.closed_dates = dates.iter().map(|date| date.to_string()).collect();
+            self
+        }
+
+        fn remaining(&self) -> usize {
+            self.responses.lock().expect("responses mutex poisoned").len()
         }
     }
 
@@ -273,8 +320,12 @@ mod tests {
                 .unwrap_or_else(|| Err(PulseArcError::Internal("no mock response".to_string())))
         }
 
-        async fn validate_wbs(&self, _wbs_code: &str) -> Result<bool> {
-            Ok(true)
+        async fn validate_wbs(&self, wbs_code: &str) -> Result<bool> {
+            Ok(!self.invalid_wbs.iter().any(|invalid| invalid == wbs_code))
+        }
+
+        async fn is_period_open(&self, date: &str) -> Result<bool> {
+            Ok(!self.closed_dates.iter().any(|closed| closed == date))
         }
     }
 
@@ -329,6 +380,281 @@ mod tests {
             _ => panic!("expected submission failure"),
         }
     }
+
+    /// Outbox id, error code and error message of a dead-lettered entry
+    type DlqRecord = (String, String, String);
+
+    #[derive(Default)]
+    struct RecordingDlq {
+        entries: Mutex<Vec<DlqRecord>>,
+    }
+
+    impl RecordingDlq {
+        fn entries(&self) -> Vec<DlqRecord> {
+            self.entries.lock().expect("dlq mutex poisoned").clone()
+        }
+    }
+
+    #[async_trait]
+    impl DlqRepository for RecordingDlq {
+        async fn move_batch_to_dlq(&self, _batch_id: &str, _error: &str) -> Result<()> {
+            Ok(())
+        }
+
+        async fn get_dlq_batches(&self) -> Result<Vec<BatchQueue>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_dlq_batches_with_details(&self) -> Result<Vec<DlqBatch>> {
+            Ok(Vec::new())
+        }
+
+        async fn reset_batch_for_retry(&self, _batch_id: &str) -> Result<()> {
+            Ok(())
+        }
+
+        async fn retry_failed_batch(&self, _batch_id: &str) -> Result<()> {
+            Ok(())
+        }
+
+        async fn move_outbox_entry_to_dlq(
+            &self,
+            outbox_id: &str,
+            error_code: &str,
+            error: &str,
+        ) -> Result<()> {
+            self.entries.lock().expect("dlq mutex poisoned").push((
+                outbox_id.to_string(),
+                error_code.to_string(),
+                error.to_string(),
+            ));
+            Ok(())
+        }
+    }
+
+    fn fast_forwarder(client: Arc<dyn SapClientTrait>) -> BatchForwarder {
+        let retry_config = BatchRetryConfig {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(1),
+            multiplier: 1.0,
+            max_delay: Duration::from_millis(1),
+        };
+        BatchForwarder::with_config(client, retry_config, CircuitBreakerConfig::default())
+            .expect("valid forwarder config")
+    }
+
+    #[tokio::test]
+    async fn mixed_batch_dead_letters_only_permanent_failures() {
+        let responses = vec![
+            // transient-1: network blip, then accepted on retry
+            Err(PulseArcError::Network("connection reset".to_string())),
+            Ok("sap-entry-1".to_string()),
+            // permanent-1: rejected WBS, must not be retried
+            Err(PulseArcError::InvalidInput("WBS element is closed".to_string())),
+        ];
+        let client = Arc::new(MockSapClient::new(responses));
+        let dlq = Arc::new(RecordingDlq::default());
+        let forwarder = fast_forwarder(client.clone()).with_dlq(dlq.clone());
+
+        let results = forwarder
+            .submit_batch(&[base_outbox_entry("transient-1"), base_outbox_entry("permanent-1")])
+            .await
+            .expect("batch submission should complete");
+
+        assert_eq!(results.successful, 1);
+        assert_eq!(results.failed, 1);
+        assert_eq!(results.dead_lettered, 1);
+        assert_eq!(client.remaining(), 0, "transient entry should be retried exactly once");
+
+        match &results.entry_results[0].status {
+            EntrySubmissionStatus::Submitted { sap_entry_id } => {
+                assert_eq!(sap_entry_id, "sap-entry-1");
+            }
+            other => panic!("expected transient entry to succeed on retry, got {other:?}"),
+        }
+        match &results.entry_results[1].status {
+            EntrySubmissionStatus::DeadLettered { error } => {
+                assert_eq!(*error.category(), SapErrorCategory::Validation);
+            }
+            other => panic!("expected permanent entry to be dead-lettered, got {other:?}"),
+        }
+
+        let dead_lettered = dlq.entries();
+        assert_eq!(dead_lettered.len(), 1);
+        assert_eq!(dead_lettered[0].0, "permanent-1");
+        assert_eq!(dead_lettered[0].1, SapErrorCategory::Validation.to_string());
+        assert!(dead_lettered[0].2.contains("WBS element is closed"));
+    }
+
+    #[tokio::test]
+    async fn permanent_failure_without_dlq_is_reported_as_failed() {
+        let responses = vec![
+            Err(PulseArcError::InvalidInput("WBS element is closed".to_string())),
+            Ok("never-used".to_string()),
+        ];
+        let client = Arc::new(MockSapClient::new(responses));
+        let forwarder = fast_forwarder(client.clone());
+
+        let results = forwarder
+            .submit_batch(&[base_outbox_entry("permanent-1")])
+            .await
+            .expect("batch submission should complete");
+
+        assert_eq!(results.failed, 1);
+        assert_eq!(results.dead_lettered, 0);
+        assert_eq!(client.remaining(), 1, "permanent failure should not be retried");
+        assert!(matches!(
+            &results.entry_results[0].status,
+            EntrySubmissionStatus::Failed { error } if *error.category() == SapErrorCategory::Validation
+        ));
+    }
+
+    #[tokio::test]
+    async fn exhausted_retry_budget_stops_retries() {
+        let responses =
+            (0..6).map(|_| Err(PulseArcError::Network("connection reset".to_string()))).collect();
+        let client = Arc::new(MockSapClient::new(responses));
+        // No refill, so the two tokens are all the retries this batch gets
+        let budget = RetryBudget::new(2, 0.0);
+        let forwarder = fast_forwarder(client.clone()).with_retry_budget(budget.clone());
+
+        let results = forwarder
+            .submit_batch(&[base_outbox_entry("entry-1"), base_outbox_entry("entry-2")])
+            .await
+            .expect("batch submission should complete");
+
+        assert_eq!(results.failed, 2);
+        assert_eq!(budget.available(), 0);
+        // entry-1: 1 attempt + 2 budgeted retries; entry-2: 1 attempt, no retry
+        assert_eq!(client.remaining(), 2);
+        for result in &results.entry_results {
+            assert!(matches!(
+                &result.status,
+                EntrySubmissionStatus::Failed { error } if error.category().is_retryable()
+            ));
+        }
+    }
+
+    fn entry_dated(id: &str, date: &str) -> TimeEntryOutbox {
+        let mut entry = base_outbox_entry(id);
+        entry.payload_json = format!(r#"{{"duration":3600,"note":"Work","date":"{date}"}}"#);
+        entry
+    }
+
+    #[tokio::test]
+    async fn closed_period_entries_are_deferred_while_open_periods_submit() {
+        let client = Arc::new(
+            MockSapClient::new(vec![Ok("sap-entry-1".to_string())])
+                .with_closed_dates(&["2025-09-30", "2999-01-04"]),
+        );
+        let forwarder = fast_forwarder(client.clone());
+
+        let results = forwarder
+            .submit_batch(&[
+                entry_dated("closed-1", "2025-09-30"),
+                entry_dated("open-1", "2025-10-31"),
+                entry_dated("future-1", "2999-01-04"),
+            ])
+            .await
+            .expect("batch submission should complete");
+
+        assert_eq!(results.successful, 1);
+        assert_eq!(results.deferred, 2);
+        assert_eq!(results.failed, 0);
+        assert_eq!(client.remaining(), 0);
+
+        match &results.entry_results[0].status {
+            EntrySubmissionStatus::Deferred { date, reason } => {
+                assert_eq!(date, "2025-09-30");
+                assert_eq!(*reason, DeferralReason::PeriodClosed);
+            }
+            other => panic!("expected closed-period entry to be deferred, got {other:?}"),
+        }
+        assert!(matches!(
+            &results.entry_results[1].status,
+            EntrySubmissionStatus::Submitted { sap_entry_id } if sap_entry_id == "sap-entry-1"
+        ));
+        match &results.entry_results[2].status {
+            EntrySubmissionStatus::Deferred { reason, .. } => {
+                assert_eq!(*reason, DeferralReason::PeriodNotYetOpen);
+            }
+            other => panic!("expected future-period entry to be held, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn deferral_reason_distinguishes_future_periods() {
+        let today = NaiveDate::from_ymd_opt(2025, 11, 15).expect("valid date");
+
+        assert_eq!(deferral_reason("2025-10-31", today), DeferralReason::PeriodClosed);
+        assert_eq!(deferral_reason("2025-11-15", today), DeferralReason::PeriodClosed);
+        assert_eq!(deferral_reason("2025-12-01", today), DeferralReason::PeriodNotYetOpen);
+        assert_eq!(deferral_reason("not-a-date", today), DeferralReason::PeriodClosed);
+    }
+
+    #[tokio::test]
+    async fn validate_only_reports_per_entry_outcomes_without_submitting() {
+        let client = Arc::new(
+            MockSapClient::new(vec![Ok("never-sent".to_string())])
+                .with_invalid_wbs(&["USC9999999.9.9"])
+                .with_closed_dates(&["2025-09-30"]),
+        );
+        let dlq = Arc::new(RecordingDlq::default());
+        let forwarder =
+            fast_forwarder(client.clone()).with_dlq(dlq.clone()).with_validate_only(true);
+
+        let mut missing_wbs = base_outbox_entry("missing-wbs");
+        missing_wbs.wbs_code = None;
+        let mut unknown_wbs = base_outbox_entry("unknown-wbs");
+        unknown_wbs.wbs_code = Some("USC9999999.9.9".to_string());
+
+        let results = forwarder
+            .submit_batch(&[
+                base_outbox_entry("valid-1"),
+                missing_wbs,
+                unknown_wbs,
+                entry_dated("closed-1", "2025-09-30"),
+            ])
+            .await
+            .expect("validation should complete");
+
+        assert_eq!(results.validated, 1);
+        assert_eq!(results.failed, 2);
+        assert_eq!(results.deferred, 1);
+        assert_eq!(results.successful, 0);
+        assert_eq!(results.dead_lettered, 0);
+        assert_eq!(client.remaining(), 1, "validate-only must not submit any entry");
+        assert!(dlq.entries().is_empty(), "validate-only must not dead-letter entries");
+
+        assert!(matches!(results.entry_results[0].status, EntrySubmissionStatus::Validated));
+        for (index, outbox_id) in [(1, "missing-wbs"), (2, "unknown-wbs")] {
+            assert_eq!(results.entry_results[index].outbox_id, outbox_id);
+            assert!(matches!(
+                &results.entry_results[index].status,
+                EntrySubmissionStatus::Failed { error } if *error.category() == SapErrorCategory::Validation
+            ));
+        }
+        assert!(matches!(
+            &results.entry_results[3].status,
+            EntrySubmissionStatus::Deferred { reason: DeferralReason::PeriodClosed, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn submission_mode_does_not_report_validated_entries() {
+        let client = Arc::new(MockSapClient::new(vec![Ok("sap-entry-1".to_string())]));
+        let forwarder = fast_forwarder(client.clone());
+        assert!(!forwarder.is_validate_only());
+
+        let results = forwarder
+            .submit_batch(&[base_outbox_entry("entry-1")])
+            .await
+            .expect("batch submission should complete");
+
+        assert_eq!(results.successful, 1);
+        assert_eq!(results.validated, 0);
+        assert_eq!(client.remaining(), 0);
+    }
 }
 
 impl Default for SapForwarder {
@@ -362,6 +688,23 @@ pub enum EntrySubmissionStatus {
     Submitted { sap_entry_id: SapEntryId },
     /// Entry failed (either conversion or submission) with classified error.
     Failed { error: SapError },
+    /// Entry failed permanently and was moved to the dead letter queue.
+    DeadLettered { error: SapError },
+    /// Entry was not submitted because its posting period is not open; it
+    /// stays queued and is checked again on the next batch.
+    Deferred { date: String, reason: DeferralReason },
+    /// Entry passed validation in validate-only mode; nothing was submitted.
+    Validated,
+}
+
+/// Why an entry was deferred instead of submitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeferralReason {
+    /// The entry's date falls in a posting period that has been closed.
+    PeriodClosed,
+    /// The entry's date lies in a future period that has not opened yet; the
+    /// entry is held until it does.
+    PeriodNotYetOpen,
 }
 
 /// Result of batch submission
@@ -369,8 +712,14 @@ pub enum EntrySubmissionStatus {
 pub struct BatchSubmissionResult {
     /// Number of entries successfully submitted
     pub successful: usize,
-    /// Number of entries that failed
+    /// Number of entries that failed (including dead-lettered entries)
     pub failed: usize,
+    /// Number of failed entries moved to the dead letter queue
+    pub dead_lettered: usize,
+    /// Number of entries held back because their posting period is not open
+    pub deferred: usize,
+    /// Number of entries that passed validation (validate-only mode)
+    pub validated: usize,
     /// Detailed per-entry outcomes (success or failure metadata)
     pub entry_results: Vec<EntrySubmissionResult>,
 }
@@ -406,11 +755,16 @@ impl Default for BatchRetryConfig {
 /// - Circuit breaker for fault isolation
 /// - Structured tracing with batch metrics
 /// - Per-entry error handling (partial batch success)
+/// - Optional dead-lettering of permanent failures (see [`Self::with_dlq`])
+/// - Optional validate-only mode (see [`Self::with_validate_only`])
 pub struct BatchForwarder {
     client: Arc<dyn SapClientTrait>,
     converter: SapForwarder,
     circuit_breaker: Arc<CircuitBreaker>,
     retry_config: BatchRetryConfig,
+    dlq: Option<Arc<dyn DlqRepository>>,
+    retry_budget: Option<RetryBudget>,
+    validate_only: bool,
 }
 
 impl BatchForwarder {
@@ -442,6 +796,9 @@ impl BatchForwarder {
             converter: SapForwarder::new(),
             circuit_breaker: Arc::new(circuit_breaker),
             retry_config,
+            dlq: None,
+            retry_budget: None,
+            validate_only: false,
         }
     }
 
@@ -462,18 +819,65 @@ impl BatchForwarder {
             converter: SapForwarder::new(),
             circuit_breaker: Arc::new(circuit_breaker),
             retry_config,
+            dlq: None,
+            retry_budget: None,
+            validate_only: false,
         })
     }
 
+    /// Route permanently failing entries to a dead letter queue.
+    ///
+    /// Entries whose error category is permanent (e.g. invalid WBS, closed
+    /// period) are moved to the DLQ with the SAP error attached instead of
+    /// being reported as `Failed`, so callers do not schedule them for
+    /// another attempt. Without a DLQ they are reported as `Failed`.
+    pub fn with_dlq(mut self, dlq: Arc<dyn DlqRepository>) -> Self {
+        self.dlq = Some(dlq);
+        self
+    }
+
+    /// Spend a token from `budget` for every retry.
+    ///
+    /// When the budget is exhausted, failing entries are not retried again
+    /// until it refills. Clones of a `RetryBudget` share their tokens, so pass
+    /// a clone of the same budget to `SapHealthMonitor::with_retry_budget` to
+    /// surface throttling in health status.
+    pub fn with_retry_budget(mut self, budget: RetryBudget) -> Self {
+        self.retry_budget = Some(budget);
+        self
+    }
+
+    /// Validate batches instead of submitting them.
+    ///
+    /// In validate-only mode `submit_batch` runs the same checks a real
+    /// submission relies on: conversion, the three-layer WBS validation
+    /// (format, existence, status) and the posting period check. Entries that
+    /// pass are reported as `Validated`; no `submitTimeEntries` mutation is
+    /// sent and nothing is dead-lettered.
+    pub fn with_validate_only(mut self, validate_only: bool) -> Self {
+        self.validate_only = validate_only;
+        self
+    }
+
+    /// Whether `submit_batch` only validates entries.
+    pub fn is_validate_only(&self) -> bool {
+        self.validate_only
+    }
+
     /// Submit a batch of outbox entries to SAP with retry logic.
     ///
     /// Conversion and submission happen entry-by-entry so that failures can be
     /// reported precisely. Conversion failures are surfaced alongside
     /// submission failures, allowing callers to update outbox status
-    /// deterministically.
+    /// deterministically. Permanent failures are dead-lettered when a DLQ is
+    /// configured. Entries whose posting period is not open are reported as
+    /// `Deferred` without being submitted, so one closed period does not fail
+    /// the rest of the batch. In validate-only mode entries are checked but
+    /// never submitted (see [`Self::with_validate_only`]).
     pub async fn submit_batch(&self, entries: &[TimeEntryOutbox]) -> Result<BatchSubmissionResult> {
         let batch_size = entries.len();
-        debug!(batch_size, "Starting batch submission to SAP");
+        let validate_only = self.validate_only;
+        debug!(batch_size, validate_only, "Starting batch submission to SAP");
 
         let prepared = self.converter.prepare_batch(entries);
         let converted_count = prepared.iter().filter(|entry| entry.result.is_ok()).count();
@@ -487,34 +891,69 @@ impl BatchForwarder {
         for (index, prepared_entry) in prepared.into_iter().enumerate() {
             let outbox_id = prepared_entry.outbox_id;
 
-            match prepared_entry.result {
-                Ok(sap_entry) => match self.submit_with_retry(&sap_entry).await {
-                    Ok(sap_entry_id) => {
-                        entry_results.push(EntrySubmissionResult {
-                            outbox_id,
-                            status: EntrySubmissionStatus::Submitted { sap_entry_id },
-                        });
-                    }
-                    Err(err) => {
-                        let sap_error = map_submission_error(err, &outbox_id);
-                        warn!(
-                            outbox_id = %outbox_id,
-                            entry_index = index,
-                            error = %sap_error,
-                            "SAP submission failed after retries"
-                        );
-                        entry_results.push(EntrySubmissionResult {
-                            outbox_id,
-                            status: EntrySubmissionStatus::Failed { error: sap_error },
-                        });
-                    }
-                },
+            let sap_entry = match prepared_entry.result {
+                Ok(sap_entry) => sap_entry,
                 Err(err) => {
                     let sap_error = map_conversion_error(err, &outbox_id);
+                    let status = if validate_only {
+                        EntrySubmissionStatus::Failed { error: sap_error }
+                    } else {
+                        self.failure_status(&outbox_id, sap_error).await
+                    };
+                    entry_results.push(EntrySubmissionResult { outbox_id, status });
+                    continue;
+                }
+            };
+
+            if validate_only {
+                if let Some(error) = self.wbs_validation_error(&outbox_id, &sap_entry).await {
                     entry_results.push(EntrySubmissionResult {
                         outbox_id,
-                        status: EntrySubmissionStatus::Failed { error: sap_error },
+                        status: EntrySubmissionStatus::Failed { error },
                     });
+                    continue;
+                }
+            }
+
+            if let Some(reason) = self.period_deferral(&sap_entry).await {
+                info!(
+                    outbox_id = %outbox_id,
+                    date = %sap_entry.date,
+                    reason = ?reason,
+                    "Deferring entry; SAP posting period is not open"
+                );
+                entry_results.push(EntrySubmissionResult {
+                    outbox_id,
+                    status: EntrySubmissionStatus::Deferred { date: sap_entry.date, reason },
+                });
+                continue;
+            }
+
+            if validate_only {
+                entry_results.push(EntrySubmissionResult {
+                    outbox_id,
+                    status: EntrySubmissionStatus::Validated,
+                });
+                continue;
+            }
+
+            match self.submit_with_retry(&sap_entry).await {
+                Ok(sap_entry_id) => {
+                    entry_results.push(EntrySubmissionResult {
+                        outbox_id,
+                        status: EntrySubmissionStatus::Submitted { sap_entry_id },
+                    });
+                }
+                Err(err) => {
+                    let sap_error = map_submission_error(err, &outbox_id);
+                    warn!(
+                        outbox_id = %outbox_id,
+                        entry_index = index,
+                        error = %sap_error,
+                        "SAP submission failed"
+                    );
+                    let status = self.failure_status(&outbox_id, sap_error).await;
+                    entry_results.push(EntrySubmissionResult { outbox_id, status });
                 }
             }
         }
@@ -523,7 +962,19 @@ impl BatchForwarder {
             .iter()
             .filter(|result| matches!(result.status, EntrySubmissionStatus::Submitted { .. }))
             .count();
-        let failed = entry_results.len().saturating_sub(successful);
+        let dead_lettered = entry_results
+            .iter()
+            .filter(|result| matches!(result.status, EntrySubmissionStatus::DeadLettered { .. }))
+            .count();
+        let deferred = entry_results
+            .iter()
+            .filter(|result| matches!(result.status, EntrySubmissionStatus::Deferred { .. }))
+            .count();
+        let validated = entry_results
+            .iter()
+            .filter(|result| matches!(result.status, EntrySubmissionStatus::Validated))
+            .count();
+        let failed = entry_results.len().saturating_sub(successful + deferred + validated);
 
         info!(
             batch_size,
@@ -531,17 +982,98 @@ impl BatchForwarder {
             filtered_out = batch_size.saturating_sub(converted_count),
             successful,
             failed,
+            dead_lettered,
+            deferred,
+            validated,
+            validate_only,
             "Batch submission complete"
         );
 
-        Ok(BatchSubmissionResult { successful, failed, entry_results })
+        Ok(BatchSubmissionResult {
+            successful,
+            failed,
+            dead_lettered,
+            deferred,
+            validated,
+            entry_results,
+        })
+    }
+
+    /// Run the client's WBS validation (format, existence, status) for a
+    /// prepared entry, returning the classified error if it does not pass.
+    async fn wbs_validation_error(
+        &self,
+        outbox_id: &str,
+        entry: &SapTimeEntry,
+    ) -> Option<SapError> {
+        match self.client.validate_wbs(&entry.wbs_code).await {
+            Ok(true) => None,
+            Ok(false) => Some(
+                SapError::new(
+                    SapErrorCategory::Validation,
+                    format!("WBS code {} failed validation", entry.wbs_code),
+                )
+                .with_context(format!("outbox_id={outbox_id}, stage=validation")),
+            ),
+            Err(err) => Some(map_validation_error(err, outbox_id)),
+        }
+    }
+
+    /// Check the entry's posting period, returning why it must wait if the
+    /// period is not open.
+    ///
+    /// A failed check does not block submission: SAP rejects entries for
+    /// closed periods itself, so the entry is submitted as before.
+    async fn period_deferral(&self, entry: &SapTimeEntry) -> Option<DeferralReason> {
+        match self.client.is_period_open(&entry.date).await {
+            Ok(true) => None,
+            Ok(false) => Some(deferral_reason(&entry.date, Utc::now().date_naive())),
+            Err(err) => {
+                warn!(
+                    date = %entry.date,
+                    error = %err,
+                    "SAP posting period check failed; submitting without it"
+                );
+                None
+            }
+        }
+    }
+
+    /// Dead-letter permanent failures, falling back to `Failed` when no DLQ
+    /// is configured or the DLQ write fails.
+    async fn failure_status(&self, outbox_id: &str, error: SapError) -> EntrySubmissionStatus {
+        let dlq = match &self.dlq {
+            Some(dlq) if error.category().is_permanent() => dlq,
+            _ => return EntrySubmissionStatus::Failed { error },
+        };
+
+        let error_code = error.category().to_string();
+        match dlq.move_outbox_entry_to_dlq(outbox_id, &error_code, &error.to_string()).await {
+            Ok(()) => {
+                info!(
+                    outbox_id = %outbox_id,
+                    category = %error.category(),
+                    "Moved permanently failing entry to DLQ"
+                );
+                EntrySubmissionStatus::DeadLettered { error }
+            }
+            Err(dlq_err) => {
+                warn!(
+                    outbox_id = %outbox_id,
+                    error = %dlq_err,
+                    "Failed to move entry to DLQ; reporting as failed"
+                );
+                EntrySubmissionStatus::Failed { error }
+            }
+        }
     }
 
     /// Submit a single entry with retry and circuit breaker.
     ///
     /// This is an internal method that wraps the SAP client call with:
     /// - Circuit breaker check (fail fast if open)
-    /// - Retry logic with exponential backoff
+    /// - Retry logic with exponential backoff (permanent errors fail
+    ///   immediately with their original classification)
     /// - Error conversion to domain errors
     async fn submit_with_retry(&self, entry: &SapTimeEntry) -> Result<SapEntryId> {
         let mut attempt = 0;
@@ -568,6 +1100,17 @@ impl BatchForwarder {
                     }
                     return Ok(entry_id);
                 }
+                Err(ResilienceError::OperationFailed { source })
+                    if classify_pulsearc_error(&source).is_permanent() =>
+                {
+                    warn!(
+                        wbs_code = %entry.wbs_code,
+                        attempt,
+                        error = %source,
+                        "Entry submission failed permanently; not retrying"
+                    );
+                    return Err(source);
+                }
                 Err(e) => {
                     if attempt >= self.retry_config.max_attempts {
                         warn!(
@@ -582,6 +1125,21 @@ impl BatchForwarder {
                         )));
                     }
 
+                    if let Some(budget) = &self.retry_budget {
+                        if !budget.try_acquire() {
+                            warn!(
+                                wbs_code = %entry.wbs_code,
+                                attempt,
+                                error = %e,
+                                "Retry budget exhausted; not retrying entry"
+                            );
+                            return Err(PulseArcError::Network(format!(
+                                "SAP submission failed (retry budget exhausted): {}",
+                                e
+                            )));
+                        }
+                    }
+
                     debug!(
                         wbs_code = %entry.wbs_code,
                         attempt,
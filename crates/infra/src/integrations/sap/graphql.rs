@@ -0,0 +1,415 @@
+//! Configurable GraphQL operations for the SAP connector
+//!
+//! The connector schema evolves independently of the desktop app, so the
+//! operation documents and the mapping from `TimeEntry` fields to GraphQL
+//! input fields live in [`SapGraphQlConfig`] instead of being hardcoded in
+//! `SapClient`. The defaults match the current connector schema; overrides can
+//! be loaded from JSON and are validated when the config is built.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use pulsearc_infra::integrations::sap::SapGraphQlConfig;
+//!
+//! let config = SapGraphQlConfig::builder()
+//!     .submit_mutation(
+//!         "mutation Post($items: [TimeInput!]!) { postTime(items: $items) { acceptedCount duplicateCount errors { correlationId code message } } }",
+//!     )
+//!     .entries_variable("items")
+//!     .submit_result_field("postTime")
+//!     .build()?;
+//! ```
+
+use pulsearc_domain::{PulseArcError, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+const DEFAULT_SUBMIT_MUTATION: &str = r#"
+    mutation SubmitTimeEntries($entries: [TimeEntryInput!]!) {
+        submitTimeEntries(entries: $entries) {
+            acceptedCount
+            duplicateCount
+            errors {
+                correlationId
+                code
+                message
+            }
+        }
+    }
+"#;
+
+const DEFAULT_PERIOD_QUERY: &str = r#"
+    query PostingPeriod($date: String!) {
+        postingPeriod(date: $date) {
+            isOpen
+        }
+    }
+"#;
+
+/// GraphQL operations and variable mappings used by `SapClient`
+///
+/// The submit mutation must declare `$<entries_variable>` (a list of time
+/// entry inputs) and return `acceptedCount`, `duplicateCount` and `errors`
+/// under `submit_result_field`. The period query must declare
+/// `$<period_date_variable>` and return `isOpen` under `period_result_field`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SapGraphQlConfig {
+    /// Mutation document used to submit time entries
+    pub submit_mutation: String,
+
+    /// Name of the list variable holding the time entry inputs
+    pub entries_variable: String,
+
+    /// Root field of the submit mutation's response
+    pub submit_result_field: String,
+
+    /// Input field names for each `TimeEntry` attribute
+    pub entry_fields: TimeEntryFieldMapping,
+
+    /// Query document used to check whether a posting period is open
+    pub period_query: String,
+
+    /// Name of the date variable in the period query
+    pub period_date_variable: String,
+
+    /// Root field of the period query's response
+    pub period_result_field: String,
+}
+
+/// GraphQL input field names for a submitted time entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TimeEntryFieldMapping {
+    /// SAP user ID
+    pub user_id: String,
+    /// Entry date (YYYY-MM-DD)
+    pub date: String,
+    /// Normalized WBS code
+    pub wbs_code: String,
+    /// Duration in seconds
+    pub duration: String,
+    /// Optional note, omitted when the entry has no description
+    pub note: String,
+    /// Client-generated correlation ID
+    pub correlation_id: String,
+}
+
+/// Values for one time entry input, before field mapping
+#[derive(Debug, Clone)]
+pub(crate) struct TimeEntryValues {
+    pub user_id: String,
+    pub date: String,
+    pub wbs_code: String,
+    pub duration_seconds: i32,
+    pub note: Option<String>,
+    pub correlation_id: String,
+}
+
+impl SapGraphQlConfig {
+    /// Create a builder starting from the default operations
+    pub fn builder() -> SapGraphQlConfigBuilder {
+        SapGraphQlConfigBuilder::new()
+    }
+
+    /// Parse and validate a config from JSON; omitted fields keep defaults
+    pub fn from_json(json: &str) -> Result<Self> {
+        let config: Self = serde_json::from_str(json)
+            .map_err(|e| PulseArcError::Config(format!("Invalid SAP GraphQL config: {e}")))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Validate that operations declare their required variables and all
+    /// mapped names are valid GraphQL names
+    pub fn validate(&self) -> Result<()> {
+        require_name("entriesVariable", &self.entries_variable)?;
+        require_name("submitResultField", &self.submit_result_field)?;
+        require_name("periodDateVariable", &self.period_date_variable)?;
+        require_name("periodResultField", &self.period_result_field)?;
+        self.entry_fields.validate()?;
+
+        require_variable("submitMutation", &self.submit_mutation, &self.entries_variable)?;
+        require_variable("periodQuery", &self.period_query, &self.period_date_variable)?;
+
+        Ok(())
+    }
+
+    /// Variables for submitting a single time entry
+    pub(crate) fn submit_variables(&self, values: &TimeEntryValues) -> Value {
+        let fields = &self.entry_fields;
+        let mut input = Map::new();
+        input.insert(fields.user_id.clone(), Value::from(values.user_id.clone()));
+        input.insert(fields.date.clone(), Value::from(values.date.clone()));
+        input.insert(fields.wbs_code.clone(), Value::from(values.wbs_code.clone()));
+        input.insert(fields.duration.clone(), Value::from(values.duration_seconds));
+        if let Some(note) = &values.note {
+            input.insert(fields.note.clone(), Value::from(note.clone()));
+        }
+        input.insert(fields.correlation_id.clone(), Value::from(values.correlation_id.clone()));
+
+        let mut variables = Map::new();
+        variables.insert(self.entries_variable.clone(), Value::Array(vec![Value::Object(input)]));
+        Value::Object(variables)
+    }
+
+    /// Variables for checking the posting period of `date`
+    pub(crate) fn period_variables(&self, date: &str) -> Value {
+        let mut variables = Map::new();
+        variables.insert(self.period_date_variable.clone(), Value::from(date));
+        Value::Object(variables)
+    }
+}
+
+impl Default for SapGraphQlConfig {
+    fn default() -> Self {
+        Self {
+            submit_mutation: DEFAULT_SUBMIT_MUTATION.to_string(),
+            entries_variable: "entries".to_string(),
+            submit_result_field: "submitTimeEntries".to_string(),
+            entry_fields: TimeEntryFieldMapping::default(),
+            period_query: DEFAULT_PERIOD_QUERY.to_string(),
+            period_date_variable: "date".to_string(),
+            period_result_field: "postingPeriod".to_string(),
+        }
+    }
+}
+
+impl TimeEntryFieldMapping {
+    fn validate(&self) -> Result<()> {
+        let fields = [
+            ("entryFields.userId", &self.user_id),
+            ("entryFields.date", &self.date),
+            ("entryFields.wbsCode", &self.wbs_code),
+            ("entryFields.duration", &self.duration),
+            ("entryFields.note", &self.note),
+            ("entryFields.correlationId", &self.correlation_id),
+        ];
+
+        for (index, (setting, name)) in fields.iter().enumerate() {
+            require_name(setting, name)?;
+            if fields[..index].iter().any(|(_, other)| other == name) {
+                return Err(PulseArcError::Config(format!(
+                    "SAP GraphQL config: {setting} '{name}' is mapped more than once"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for TimeEntryFieldMapping {
+    fn default() -> Self {
+        Self {
+            user_id: "userId".to_string(),
+            date: "date".to_string(),
+            wbs_code: "wbsCode".to_string(),
+            duration: "duration".to_string(),
+            note: "note".to_string(),
+            correlation_id: "correlationId".to_string(),
+        }
+    }
+}
+
+/// Builder for SAP GraphQL configuration
+pub struct SapGraphQlConfigBuilder {
+    config: SapGraphQlConfig,
+}
+
+impl Default for SapGraphQlConfigBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SapGraphQlConfigBuilder {
+    pub fn new() -> Self {
+        Self { config: SapGraphQlConfig::default() }
+    }
+
+    pub fn submit_mutation(mut self, mutation: impl Into<String>) -> Self {
+        self.config.submit_mutation = mutation.into();
+        self
+    }
+
+    pub fn entries_variable(mut self, name: impl Into<String>) -> Self {
+        self.config.entries_variable = name.into();
+        self
+    }
+
+    pub fn submit_result_field(mut self, name: impl Into<String>) -> Self {
+        self.config.submit_result_field = name.into();
+        self
+    }
+
+    pub fn entry_fields(mut self, fields: TimeEntryFieldMapping) -> Self {
+        self.config.entry_fields = fields;
+        self
+    }
+
+    pub fn period_query(mut self, query: impl Into<String>) -> Self {
+        self.config.period_query = query.into();
+        self
+    }
+
+    pub fn period_date_variable(mut self, name: impl Into<String>) -> Self {
+        self.config.period_date_variable = name.into();
+        self
+    }
+
+    pub fn period_result_field(mut self, name: impl Into<String>) -> Self {
+        self.config.period_result_field = name.into();
+        self
+    }
+
+    pub fn build(self) -> Result<SapGraphQlConfig> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
+
+fn is_graphql_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first == '_' || first.is_ascii_alphabetic() => {
+            chars.all(|c| c == '_' || c.is_ascii_alphanumeric())
+        }
+        _ => false,
+    }
+}
+
+fn require_name(setting: &str, name: &str) -> Result<()> {
+    if is_graphql_name(name) {
+        Ok(())
+    } else {
+        Err(PulseArcError::Config(format!(
+            "SAP GraphQL config: {setting} '{name}' is not a valid GraphQL name"
+        )))
+    }
+}
+
+/// Require `document` to reference `$variable` (not just a longer name
+/// starting with it)
+fn require_variable(setting: &str, document: &str, variable: &str) -> Result<()> {
+    let needle = format!("${variable}");
+    let declared = document.match_indices(&needle).any(|(start, _)| {
+        match document[start + needle.len()..].chars().next() {
+            Some(next) => !(next == '_' || next.is_ascii_alphanumeric()),
+            None => true,
+        }
+    });
+
+    if declared {
+        Ok(())
+    } else {
+        Err(PulseArcError::Config(format!(
+            "SAP GraphQL config: {setting} does not use required variable ${variable}"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values() -> TimeEntryValues {
+        TimeEntryValues {
+            user_id: "user@example.com".to_string(),
+            date: "2025-10-31".to_string(),
+            wbs_code: "USC0063201.1.1".to_string(),
+            duration_seconds: 3600,
+            note: None,
+            correlation_id: "corr-1".to_string(),
+        }
+    }
+
+    #[test]
+    fn default_config_is_valid() {
+        assert!(SapGraphQlConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn default_submit_variables_match_connector_schema() {
+        let variables = SapGraphQlConfig::default().submit_variables(&values());
+
+        assert_eq!(
+            variables,
+            serde_json::json!({
+                "entries": [{
+                    "userId": "user@example.com",
+                    "date": "2025-10-31",
+                    "wbsCode": "USC0063201.1.1",
+                    "duration": 3600,
+                    "correlationId": "corr-1"
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_mutation_missing_required_variable() {
+        let result = SapGraphQlConfig::builder()
+            .submit_mutation("mutation Post($items: [TimeInput!]!) { postTime(items: $items) { acceptedCount } }")
+            .build();
+
+        assert!(
+            matches!(result, Err(PulseArcError::Config(message)) if message.contains("$entries"))
+        );
+    }
+
+    #[test]
+    fn variable_prefix_does_not_count_as_declared() {
+        let result = SapGraphQlConfig::builder()
+            .period_query(
+                "query P($dateRange: String!) { postingPeriod(range: $dateRange) { isOpen } }",
+            )
+            .build();
+
+        assert!(matches!(result, Err(PulseArcError::Config(_))));
+    }
+
+    #[test]
+    fn rejects_invalid_or_duplicate_field_names() {
+        let invalid = SapGraphQlConfig::builder()
+            .entry_fields(TimeEntryFieldMapping {
+                wbs_code: "wbs-code".to_string(),
+                ..TimeEntryFieldMapping::default()
+            })
+            .build();
+        assert!(matches!(invalid, Err(PulseArcError::Config(_))));
+
+        let duplicate = SapGraphQlConfig::builder()
+            .entry_fields(TimeEntryFieldMapping {
+                note: "date".to_string(),
+                ..TimeEntryFieldMapping::default()
+            })
+            .build();
+        assert!(matches!(duplicate, Err(PulseArcError::Config(_))));
+    }
+
+    #[test]
+    fn from_json_keeps_defaults_for_omitted_fields() {
+        let config = SapGraphQlConfig::from_json(
+            r#"{
+                "submitMutation": "mutation Post($items: [TimeInput!]!) { postTime(items: $items) { acceptedCount duplicateCount errors { correlationId code message } } }",
+                "entriesVariable": "items",
+                "submitResultField": "postTime",
+                "entryFields": { "wbsCode": "wbsElement" }
+            }"#,
+        )
+        .expect("valid config");
+
+        assert_eq!(config.entries_variable, "items");
+        assert_eq!(config.entry_fields.wbs_code, "wbsElement");
+        assert_eq!(config.entry_fields.user_id, "userId");
+        assert_eq!(config.period_result_field, "postingPeriod");
+    }
+
+    #[test]
+    fn from_json_rejects_missing_variable() {
+        let result = SapGraphQlConfig::from_json(r#"{ "entriesVariable": "items" }"#);
+
+        assert!(matches!(result, Err(PulseArcError::Config(_))));
+    }
+}
@@ -6,6 +6,7 @@
 //! - Cancellation support
 //! - Timeout protection
 //! - Event callbacks for status changes
+//! - Retry budget awareness (`Degraded` when low, `Unhealthy` when exhausted)
 //! - Structured tracing
 //!
 //! # Architecture
@@ -57,6 +58,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use pulsearc_common::sync::{Clock, RetryBudget};
 use pulsearc_domain::{PulseArcError, Result};
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
@@ -64,13 +66,21 @@ use tracing::{info, warn};
 
 use super::client::SapClient;
 
+/// Fraction of the retry budget below which health is reported as `Degraded`
+const LOW_RETRY_BUDGET_RATIO: f64 = 0.25;
+
 /// Health status of the SAP connector
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum HealthStatus {
     /// SAP connector is reachable and responding
     Healthy,
 
-    /// SAP connector is unreachable or not responding
+    /// SAP connector is reachable, but failing submissions have drained most
+    /// of the retry budget
+    Degraded,
+
+    /// SAP connector is unreachable or not responding, or the retry budget is
+    /// exhausted so failed submissions are no longer retried
     Unhealthy,
 
     /// Health status is unknown (initial state or after errors)
@@ -121,6 +131,7 @@ pub struct SapHealthMonitor {
     client: Arc<SapClient>,
     listener: Arc<dyn HealthStatusListener>,
     interval_secs: u64,
+    retry_budget: Option<RetryBudget>,
     task_handle: Option<JoinHandle<()>>,
     cancellation: CancellationToken,
 }
@@ -147,11 +158,23 @@ impl SapHealthMonitor {
             client,
             listener,
             interval_secs,
+            retry_budget: None,
             task_handle: None,
             cancellation: CancellationToken::new(),
         }
     }
 
+    /// Factor the forwarding retry budget into reported health
+    ///
+    /// Pass a clone of the budget given to `BatchForwarder::with_retry_budget`
+    /// (clones share tokens). A reachable connector is reported as `Degraded`
+    /// when less than a quarter of the budget remains and `Unhealthy` once it
+    /// is exhausted; health recovers as the budget refills.
+    pub fn with_retry_budget(mut self, budget: RetryBudget) -> Self {
+        self.retry_budget = Some(budget);
+        self
+    }
+
     /// Start background health monitoring
     ///
     /// Spawns a Tokio task that checks SAP health at the configured interval.
@@ -175,12 +198,13 @@ impl SapHealthMonitor {
         let cancel = self.cancellation.clone();
         let client = self.client.clone();
         let listener = self.listener.clone();
+        let retry_budget = self.retry_budget.clone();
         let interval = Duration::from_secs(self.interval_secs);
 
         info!(interval_secs = self.interval_secs, "Starting SAP health monitor");
 
         let handle = tokio::spawn(async move {
-            health_worker(client, listener, interval, cancel).await;
+            health_worker(client, listener, retry_budget, interval, cancel).await;
         });
 
         self.task_handle = Some(handle);
@@ -231,6 +255,7 @@ impl SapHealthMonitor {
 ///
 /// * `client` - SAP client for health checks
 /// * `listener` - Callback for status changes
+/// * `retry_budget` - Optional forwarding retry budget factored into status
 /// * `interval` - Check interval duration
 /// * `cancel` - Cancellation token for shutdown
 ///
@@ -245,6 +270,7 @@ impl SapHealthMonitor {
 async fn health_worker(
     client: Arc<SapClient>,
     listener: Arc<dyn HealthStatusListener>,
+    retry_budget: Option<RetryBudget>,
     interval: Duration,
     cancel: CancellationToken,
 ) {
@@ -275,6 +301,10 @@ async fn health_worker(
                         HealthStatus::Unknown
                     }
                 };
+                let new_status = match &retry_budget {
+                    Some(budget) => apply_retry_budget(new_status, budget),
+                    None => new_status,
+                };
 
                 // Only emit on transition
                 if new_status != current_status {
@@ -292,17 +322,43 @@ async fn health_worker(
     }
 }
 
+/// Downgrade a `Healthy` reachability result based on remaining retry budget
+///
+/// Other statuses are returned unchanged: an unreachable connector stays
+/// `Unhealthy` regardless of the budget.
+fn apply_retry_budget<C: Clock>(status: HealthStatus, budget: &RetryBudget<C>) -> HealthStatus {
+    if status != HealthStatus::Healthy {
+        return status;
+    }
+
+    let available = budget.available();
+    let capacity = budget.capacity();
+    if available == 0 {
+        warn!(capacity, "SAP retry budget exhausted");
+        HealthStatus::Unhealthy
+    } else if f64::from(available) < f64::from(capacity) * LOW_RETRY_BUDGET_RATIO {
+        HealthStatus::Degraded
+    } else {
+        HealthStatus::Healthy
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::{Arc, Mutex};
 
+    use pulsearc_common::resilience::circuit_breaker::CircuitBreakerConfig;
+    use pulsearc_common::sync::MockClock;
     use pulsearc_core::classification::ports::WbsRepository;
+    use pulsearc_core::sap_ports::{SapClient as SapClientTrait, SapEntryId, TimeEntry};
     use pulsearc_domain::types::sap::WbsElement;
+    use pulsearc_domain::{OutboxStatus, TimeEntryOutbox};
     use wiremock::matchers::{method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use super::*;
     use crate::integrations::sap::client::AccessTokenProvider;
+    use crate::integrations::sap::forwarder::{BatchForwarder, BatchRetryConfig};
 
     // Mock WBS Repository
     struct MockWbsRepository;
@@ -391,8 +447,14 @@ mod tests {
         let cancel_clone = cancel.clone();
 
         let worker_handle = tokio::spawn(async move {
-            health_worker(client, Arc::new(listener), Duration::from_millis(100), cancel_clone)
-                .await;
+            health_worker(
+                client,
+                Arc::new(listener),
+                None,
+                Duration::from_millis(100),
+                cancel_clone,
+            )
+            .await;
         });
 
         // Wait for at least one check
@@ -426,8 +488,14 @@ mod tests {
         let cancel_clone = cancel.clone();
 
         let worker_handle = tokio::spawn(async move {
-            health_worker(client, Arc::new(listener), Duration::from_millis(100), cancel_clone)
-                .await;
+            health_worker(
+                client,
+                Arc::new(listener),
+                None,
+                Duration::from_millis(100),
+                cancel_clone,
+            )
+            .await;
         });
 
         tokio::time::sleep(Duration::from_millis(150)).await;
@@ -510,4 +578,151 @@ mod tests {
         assert!(result.unwrap().is_ok());
         assert!(!monitor.is_running());
     }
+
+    // SAP client whose submissions always fail with a transient error
+    struct OfflineSapClient;
+
+    #[async_trait]
+    impl SapClientTrait for OfflineSapClient {
+        async fn forward_entry(&self, _entry: &TimeEntry) -> Result<SapEntryId> {
+            Err(PulseArcError::Network("connection refused".to_string()))
+        }
+
+        async fn validate_wbs(&self, _wbs_code: &str) -> Result<bool> {
+            Ok(true)
+        }
+
+        async fn is_period_open(&self, _date: &str) -> Result<bool> {
+            Ok(true)
+        }
+    }
+
+    fn outbox_entry(id: &str) -> TimeEntryOutbox {
+        TimeEntryOutbox {
+            id: id.to_string(),
+            idempotency_key: format!("{id}-key"),
+            user_id: "user-123".to_string(),
+            payload_json: r#"{"duration":3600,"note":"Work","date":"2025-10-31"}"#.to_string(),
+            backend_cuid: None,
+            status: OutboxStatus::Pending,
+            attempts: 0,
+            last_error: None,
+            retry_after: None,
+            created_at: 1_700_000_000,
+            sent_at: None,
+            correlation_id: None,
+            local_status: None,
+            remote_status: None,
+            sap_entry_id: None,
+            next_attempt_at: None,
+            error_code: None,
+            last_forwarded_at: None,
+            wbs_code: Some("USC0063201.1.1".to_string()),
+            target: "sap".to_string(),
+            description: Some("Work item".to_string()),
+            auto_applied: false,
+            version: 1,
+            last_modified_by: "user-123".to_string(),
+            last_modified_at: None,
+        }
+    }
+
+    #[test]
+    fn retry_budget_drives_degradation_and_recovery() {
+        let clock = MockClock::new();
+        let budget = RetryBudget::with_clock(10, 2.0, clock.clone());
+        let status = || apply_retry_budget(HealthStatus::Healthy, &budget);
+
+        assert_eq!(status(), HealthStatus::Healthy);
+
+        // Each failed submission spends a retry token
+        for _ in 0..8 {
+            assert!(budget.try_acquire());
+        }
+        assert_eq!(status(), HealthStatus::Degraded);
+
+        while budget.try_acquire() {}
+        assert_eq!(status(), HealthStatus::Unhealthy);
+
+        // Refill at 2 tokens/sec: low after one second, healthy after two
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(status(), HealthStatus::Degraded);
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(status(), HealthStatus::Healthy);
+    }
+
+    #[test]
+    fn unreachable_connector_is_unhealthy_regardless_of_budget() {
+        let budget = RetryBudget::with_clock(10, 2.0, MockClock::new());
+
+        assert_eq!(apply_retry_budget(HealthStatus::Unhealthy, &budget), HealthStatus::Unhealthy);
+        assert_eq!(apply_retry_budget(HealthStatus::Unknown, &budget), HealthStatus::Unknown);
+    }
+
+    #[tokio::test]
+    async fn forwarding_failures_exhaust_budget_and_monitor_recovers() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("HEAD"))
+            .and(path("/health"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        // No automatic refill; recovery is simulated with `reset()`
+        let budget = RetryBudget::new(4, 0.0);
+        let retry_config = BatchRetryConfig {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(1),
+            multiplier: 1.0,
+            max_delay: Duration::from_millis(1),
+        };
+        let forwarder = BatchForwarder::with_config(
+            Arc::new(OfflineSapClient),
+            retry_config,
+            CircuitBreakerConfig::default(),
+        )
+        .unwrap()
+        .with_retry_budget(budget.clone());
+
+        let entries: Vec<_> = (0..3).map(|i| outbox_entry(&format!("entry-{i}"))).collect();
+        let results = forwarder.submit_batch(&entries).await.unwrap();
+        assert_eq!(results.failed, 3);
+        assert_eq!(budget.available(), 0);
+
+        let (listener, statuses) = TestListener::new();
+        let client = Arc::new(
+            SapClient::new(
+                mock_server.uri(),
+                Arc::new(MockWbsRepository),
+                "test".to_string(),
+                Arc::new(MockTokenProvider),
+            )
+            .unwrap(),
+        );
+
+        let cancel = CancellationToken::new();
+        let cancel_clone = cancel.clone();
+        let worker_budget = budget.clone();
+        let worker_handle = tokio::spawn(async move {
+            health_worker(
+                client,
+                Arc::new(listener),
+                Some(worker_budget),
+                Duration::from_millis(100),
+                cancel_clone,
+            )
+            .await;
+        });
+
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        budget.reset();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        cancel.cancel();
+        let _ = tokio::time::timeout(Duration::from_secs(1), worker_handle).await;
+
+        let recorded = statuses.lock().unwrap();
+        assert_eq!(recorded.as_slice(), [HealthStatus::Unhealthy, HealthStatus::Healthy]);
+    }
 }
@@ -7,7 +7,8 @@
 ///
 /// - **Client**: `SapClient` - GraphQL client for sap-connector API
 /// - **Forwarder**: `SapForwarder` - Converts outbox entries to SAP format
-/// - **Cache**: `WbsCache` - In-memory WBS code caching with TTL
+/// - **Cache**: `WbsCache` - In-memory WBS code caching with TTL; `PeriodCache`
+///   - posting period status caching with the same TTL
 /// - **Validation**: `WbsValidator` - Three-layer WBS validation (format,
 ///   existence, status)
 /// - **Errors**: `SapError` - SAP-specific error classification with retry
@@ -62,6 +63,11 @@
 ///
 /// Communicates with sap-connector GraphQL API:
 /// - `submitTimeEntries` mutation - Submit time entries
+/// - `postingPeriod` query - Check whether a posting period is open (cached)
+///
+/// Operation documents and variable mappings come from `SapGraphQlConfig`
+/// (defaults shown above); use `SapClient::with_graphql_config` when the
+/// connector schema changes.
 /// - Health check endpoint at `/health`
 ///
 /// # Error Handling
@@ -76,17 +82,19 @@ pub mod cache;
 pub mod client;
 pub mod errors;
 pub mod forwarder;
+pub mod graphql;
 pub mod health;
 pub mod validation;
 
 pub use auth::{create_sap_oauth_config, SapAuthService};
-pub use cache::{CacheResult, CacheStats, WbsCache, WbsCacheConfig};
+pub use cache::{CacheResult, CacheStats, PeriodCache, WbsCache, WbsCacheConfig};
 pub use client::{AccessTokenProvider, SapClient};
 pub use errors::{SapError, SapErrorCategory};
 pub use forwarder::{
-    BatchForwarder, BatchRetryConfig, BatchSubmissionResult, EntrySubmissionResult,
+    BatchForwarder, BatchRetryConfig, BatchSubmissionResult, DeferralReason, EntrySubmissionResult,
     EntrySubmissionStatus, PreparedEntry, SapForwarder,
 };
+pub use graphql::{SapGraphQlConfig, SapGraphQlConfigBuilder, TimeEntryFieldMapping};
 pub use health::{HealthStatus, HealthStatusListener, SapHealthMonitor};
 pub use validation::{
     normalize_wbs_code, validate_wbs_format, validate_wbs_status, WbsValidationCode,
@@ -161,9 +161,38 @@ fn wbs_regex() -> &'static Regex {
     })
 }
 
-/// Normalize WBS code (uppercase, trim)
+/// Normalize a WBS code into its canonical form
+///
+/// This is the single normalization used for validation, cache keys and
+/// submission, so equivalent spellings of a code always hit the same cache
+/// entry. The result is idempotent:
+/// `normalize_wbs_code(&normalize_wbs_code(x)) == normalize_wbs_code(x)`.
+///
+/// - Zero-width characters (U+200B..U+200D, U+2060, U+FEFF) are removed
+/// - Unicode dashes and full-width separators map to `-` and `.`
+/// - Letters are uppercased
+/// - Leading and trailing (Unicode) whitespace is trimmed, as are trailing
+///   `.`/`-` separators (`"usc0063201.1.1."` → `"USC0063201.1.1"`)
+///
+/// Interior whitespace is kept so format validation still rejects it.
 pub fn normalize_wbs_code(code: &str) -> String {
-    code.trim().to_uppercase()
+    let mapped: String = code
+        .chars()
+        .filter(|c| !is_zero_width(*c))
+        .map(|c| match c {
+            '\u{2010}' | '\u{2011}' | '\u{2012}' | '\u{2013}' | '\u{2014}' | '\u{2212}'
+            | '\u{FF0D}' => '-',
+            '\u{FF0E}' | '\u{3002}' => '.',
+            other => other,
+        })
+        .collect::<String>()
+        .to_uppercase();
+
+    mapped.trim().trim_end_matches(|c: char| c.is_whitespace() || c == '.' || c == '-').to_string()
+}
+
+fn is_zero_width(c: char) -> bool {
+    matches!(c, '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{2060}' | '\u{FEFF}')
 }
 
 /// Validate WBS code format (static, no database)
@@ -482,12 +511,15 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(result.code(), WbsValidationCode::FormatInvalid);
 
-        // Invalid start/end characters
+        // Invalid start character (trailing separators are normalized away)
         let result = validate_wbs_format(".USC001");
         assert!(result.is_err());
         assert_eq!(result.code(), WbsValidationCode::FormatInvalid);
 
-        let result = validate_wbs_format("USC001.");
+        assert_eq!(validate_wbs_format("USC001."), WbsValidationResult::Valid);
+
+        // Interior whitespace is not normalized away
+        let result = validate_wbs_format("USC 001");
         assert!(result.is_err());
         assert_eq!(result.code(), WbsValidationCode::FormatInvalid);
     }
@@ -521,6 +553,74 @@ mod tests {
         assert_eq!(normalize_wbs_code("  usc0063201.1.1  "), "USC0063201.1.1");
     }
 
+    #[test]
+    fn test_normalize_common_input_variants() {
+        let canonical = "USC0063201.1.1";
+        for variant in [
+            "usc0063201.1.1.",
+            "Usc0063201.1.1..",
+            "\u{00A0}USC0063201.1.1\u{3000}",
+            "\u{FEFF}usc0063201.1.1\u{200B}",
+            "USC0063201\u{FF0E}1\u{FF0E}1",
+            "USC0063201.1.1 -",
+        ] {
+            assert_eq!(normalize_wbs_code(variant), canonical, "variant {variant:?}");
+        }
+
+        assert_eq!(normalize_wbs_code("p\u{2013}12345\u{2011}01"), "P-12345-01");
+        assert_eq!(normalize_wbs_code("USC 001"), "USC 001");
+        assert_eq!(normalize_wbs_code(" . "), "");
+    }
+
+    #[test]
+    fn test_normalize_is_idempotent_over_generated_inputs() {
+        let bases =
+            ["usc0063201.1.1", "P-12345-01-001", "wbs-001-002", "ab", "", "ß-straße", "x.y"];
+        let prefixes = ["", " ", "\t", "\u{00A0}", "\u{2003}", "\u{FEFF}", ".", "-"];
+        let suffixes = ["", " ", ".", "..", "-", ". ", "\u{00A0}.", "\u{200B}", "\n"];
+        type Case = fn(&str) -> String;
+        let cases: [Case; 3] = [|s| s.to_string(), |s| s.to_uppercase(), |s| s.to_lowercase()];
+
+        let mut checked = 0;
+        for base in bases {
+            for case in cases {
+                let base = case(base);
+                // Swap separators for their Unicode look-alikes
+                let lookalike = base.replace('-', "\u{2013}").replace('.', "\u{FF0E}");
+                for body in [base.as_str(), lookalike.as_str()] {
+                    for prefix in prefixes {
+                        for suffix in suffixes {
+                            let input = format!("{prefix}{body}{suffix}");
+                            let once = normalize_wbs_code(&input);
+                            assert_eq!(normalize_wbs_code(&once), once, "input {input:?}");
+                            assert_eq!(
+                                once,
+                                normalize_wbs_code(&format!("{prefix}{base}{suffix}")),
+                                "look-alike separators changed the key for {input:?}"
+                            );
+                            checked += 1;
+                        }
+                    }
+                }
+            }
+        }
+        assert_eq!(checked, bases.len() * 3 * 2 * prefixes.len() * suffixes.len());
+    }
+
+    #[test]
+    fn test_validation_normalizes_before_cache_lookup() {
+        let cache = Arc::new(WbsCache::new(WbsCacheConfig::with_ttl(Duration::from_secs(60))));
+        let repo = Arc::new(MockWbsRepository::new(vec!["USC0063201.1.1".to_string()]));
+        let validator = WbsValidator::new(Arc::clone(&cache), repo.clone());
+
+        for variant in ["USC0063201.1.1", "usc0063201.1.1.", "\u{00A0}Usc0063201.1.1\u{200B}"] {
+            assert_eq!(validator.validate(variant).unwrap(), WbsValidationResult::Valid);
+        }
+
+        // All variants share one cache entry, so the repository is hit once
+        assert_eq!(*repo.query_count.lock().unwrap(), 1);
+    }
+
     #[test]
     fn test_full_validation_valid_code() {
         let repo = Arc::new(MockWbsRepository::new(vec!["USC0063201.1.1".to_string()]));
@@ -46,7 +46,7 @@ pub use platform::*;
 pub use scheduling::{
     BlockJob, BlockScheduler, BlockSchedulerConfig, ClassificationJob, ClassificationScheduler,
     ClassificationSchedulerConfig, SchedulerError, SchedulerResult, SyncScheduler,
-    SyncSchedulerConfig,
+    SyncSchedulerConfig, VacuumScheduler, VacuumSchedulerConfig,
 };
 #[cfg(feature = "calendar")]
 pub use scheduling::{CalendarScheduler, CalendarSchedulerConfig};
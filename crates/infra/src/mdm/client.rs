@@ -1,12 +1,17 @@
 //! MDM Remote Configuration Client
 //!
 //! Fetches MDM configuration from remote servers over HTTPS.
+//!
+//! With a public key configured, fetched configuration must carry a valid
+//! detached signature (see [`super::signature`]); merging remote
+//! configuration requires one.
 
 use std::path::Path;
 use std::time::Duration;
 
 use reqwest::Certificate;
 
+use super::signature::{ConfigVerifier, SIGNATURE_HEADER};
 use super::{MdmConfig, MdmError, MdmResult};
 
 /// Client for fetching remote MDM configuration
@@ -14,6 +19,7 @@ pub struct MdmClient {
     client: reqwest::Client,
     config_url: String,
     timeout: Duration,
+    verifier: Option<ConfigVerifier>,
 }
 
 impl MdmClient {
@@ -38,7 +44,7 @@ impl MdmClient {
                 MdmError::ConfigurationError(format!("Failed to build HTTP client: {}", e))
             })?;
 
-        Ok(Self { client, config_url, timeout: Duration::from_secs(30) })
+        Ok(Self { client, config_url, timeout: Duration::from_secs(30), verifier: None })
     }
 
     /// Create a new MDM client with custom CA certificate
@@ -84,7 +90,7 @@ impl MdmClient {
                 MdmError::ConfigurationError(format!("Failed to build HTTP client: {}", e))
             })?;
 
-        Ok(Self { client, config_url, timeout: Duration::from_secs(30) })
+        Ok(Self { client, config_url, timeout: Duration::from_secs(30), verifier: None })
     }
 
     /// Create a new MDM client for testing (disables certificate validation)
@@ -117,7 +123,7 @@ impl MdmClient {
                 MdmError::ConfigurationError(format!("Failed to build HTTP client: {}", e))
             })?;
 
-        Ok(Self { client, config_url, timeout: Duration::from_secs(30) })
+        Ok(Self { client, config_url, timeout: Duration::from_secs(30), verifier: None })
     }
 
     /// Set custom timeout for HTTP requests
@@ -126,9 +132,26 @@ impl MdmClient {
         self
     }
 
+    /// Require configuration signed by the holder of this Ed25519 key
+    ///
+    /// # Arguments
+    /// * `public_key` - Base64-encoded 32-byte Ed25519 public key
+    ///
+    /// # Errors
+    /// Returns `MdmError::ConfigurationError` if the key is malformed
+    pub fn with_public_key(mut self, public_key: &str) -> MdmResult<Self> {
+        self.verifier = Some(ConfigVerifier::from_base64(public_key)?);
+        Ok(self)
+    }
+
     /// Fetch MDM configuration from the remote server
     ///
+    /// If a public key is configured, the response must be signed with it.
+    ///
     /// # Errors
+    /// Returns `MdmError::SignatureInvalid` if a public key is configured and
+    /// the response is unsigned or its signature does not verify.
+    ///
     /// Returns `MdmError::ConfigurationError` if:
     /// - Network request fails
     /// - Response is not valid JSON
@@ -150,13 +173,26 @@ impl MdmClient {
             )));
         }
 
-        // Parse JSON response
-        let config: MdmConfig = response.json().await.map_err(|e| {
-            MdmError::ConfigurationError(format!("Failed to parse configuration: {}", e))
+        let signature = response
+            .headers()
+            .get(SIGNATURE_HEADER)
+            .map(|value| value.to_str().unwrap_or_default().to_string());
+        let body = response.bytes().await.map_err(|e| {
+            MdmError::ConfigurationError(format!("Failed to read configuration: {}", e))
         })?;
 
-        // Validate configuration
-        config.validate()?;
+        let config = match &self.verifier {
+            // Verify before parsing so an unsigned payload is never interpreted
+            Some(verifier) => verifier.verify_config(&body, signature.as_deref())?,
+            None => {
+                tracing::warn!("No MDM public key configured; configuration is not verified");
+                let config: MdmConfig = serde_json::from_slice(&body).map_err(|e| {
+                    MdmError::ConfigurationError(format!("Failed to parse configuration: {}", e))
+                })?;
+                config.validate()?;
+                config
+            }
+        };
 
         tracing::info!("MDM configuration fetched and validated successfully");
         Ok(config)
@@ -164,12 +200,26 @@ impl MdmClient {
 
     /// Fetch and merge remote configuration with local config
     ///
+    /// Only signed configuration is merged, so a public key must be set with
+    /// [`with_public_key`](Self::with_public_key).
+    ///
     /// # Arguments
     /// * `local_config` - The local configuration to merge with
     ///
     /// # Returns
     /// The merged configuration
+    ///
+    /// # Errors
+    /// Returns `MdmError::SignatureInvalid` if no public key is configured or
+    /// the fetched configuration fails verification; `local_config` is not
+    /// modified then.
     pub async fn fetch_and_merge(&self, mut local_config: MdmConfig) -> MdmResult<MdmConfig> {
+        if self.verifier.is_none() {
+            return Err(MdmError::SignatureInvalid(
+                "no public key configured to verify remote configuration".into(),
+            ));
+        }
+
         let remote_config = self.fetch_config().await?;
         local_config.merge_remote(remote_config)?;
         Ok(local_config)
@@ -178,7 +228,11 @@ impl MdmClient {
 
 #[cfg(test)]
 mod tests {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
     use super::*;
+    use crate::mdm::signature::test_keys::{key_pair, public_key, sign};
 
     #[test]
     fn test_mdm_client_new_valid_url() {
@@ -206,4 +260,76 @@ mod tests {
         let client = MdmClient::with_insecure_tls("https://localhost:8080/config");
         assert!(client.is_ok());
     }
+
+    #[test]
+    fn test_mdm_client_with_invalid_public_key() {
+        let client = MdmClient::new("https://example.com/config").unwrap().with_public_key("short");
+        assert!(matches!(client, Err(MdmError::ConfigurationError(_))));
+    }
+
+    const REMOTE: &str = r#"{"policyEnforcement":true,"updateIntervalSecs":600}"#;
+
+    /// Server returning `body` with an optional signature header
+    async fn serve(body: &str, signature: Option<String>) -> MockServer {
+        let server = MockServer::start().await;
+        let mut response = ResponseTemplate::new(200).set_body_string(body);
+        if let Some(signature) = signature {
+            response = response.insert_header(SIGNATURE_HEADER, signature.as_str());
+        }
+        Mock::given(method("GET")).and(path("/config")).respond_with(response).mount(&server).await;
+        server
+    }
+
+    fn local_config() -> MdmConfig {
+        MdmConfig::builder().allow_local_override(true).build().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_fetch_and_merge_signed_config() {
+        let keys = key_pair();
+        let server = serve(REMOTE, Some(sign(&keys, REMOTE.as_bytes()))).await;
+        let client = MdmClient::new(format!("{}/config", server.uri()))
+            .unwrap()
+            .with_public_key(&public_key(&keys))
+            .unwrap();
+
+        let merged = client.fetch_and_merge(local_config()).await.unwrap();
+        assert!(merged.policy_enforcement);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_and_merge_rejects_tampered_config() {
+        let keys = key_pair();
+        let tampered = REMOTE.replace("600", "60");
+        let server = serve(&tampered, Some(sign(&keys, REMOTE.as_bytes()))).await;
+        let client = MdmClient::new(format!("{}/config", server.uri()))
+            .unwrap()
+            .with_public_key(&public_key(&keys))
+            .unwrap();
+
+        let result = client.fetch_and_merge(local_config()).await;
+        assert!(matches!(result, Err(MdmError::SignatureInvalid(_))));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_and_merge_rejects_unsigned_config() {
+        let keys = key_pair();
+        let server = serve(REMOTE, None).await;
+        let client = MdmClient::new(format!("{}/config", server.uri()))
+            .unwrap()
+            .with_public_key(&public_key(&keys))
+            .unwrap();
+
+        let result = client.fetch_and_merge(local_config()).await;
+        assert!(matches!(result, Err(MdmError::SignatureInvalid(_))));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_and_merge_requires_public_key() {
+        let server = serve(REMOTE, None).await;
+        let client = MdmClient::new(format!("{}/config", server.uri())).unwrap();
+
+        let result = client.fetch_and_merge(local_config()).await;
+        assert!(matches!(result, Err(MdmError::SignatureInvalid(_))));
+    }
 }
@@ -0,0 +1,385 @@
+//! Continuous MDM compliance evaluation
+//!
+//! [`MdmConfig::check_compliance`] answers "is this device compliant right
+//! now?". [`ComplianceScheduler`] asks that question on an interval:
+//! - Builds a [`ComplianceContext`] from current app/device state through a
+//!   [`ComplianceContextProvider`]
+//! - Writes every [`ComplianceReport`] to an audit sink ([`AuditLogger`])
+//! - Publishes a [`ComplianceStatus`] on a `watch` channel whenever it
+//!   changes, so the UI can be told (the API layer forwards it as a Tauri
+//!   event)
+//!
+//! Failed required rules are logged at `warn`, failed critical rules at
+//! `error`, and both are listed by name in the status. With a
+//! [`PolicyEnforcer`] attached, each evaluation also applies or reverts the
+//! rules' enforcement actions, and every change is audited.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Weak};
+use std::time::{Duration, SystemTime};
+
+use async_trait::async_trait;
+use pulsearc_common::observability::{AuditLogEntry, AuditLogger, AuditSeverity};
+use serde::Serialize;
+use tokio::sync::{watch, RwLock};
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info, warn};
+
+use super::enforcement::{EnforcementChange, PolicyEnforcer};
+use super::{ComplianceContext, ComplianceReport, ComplianceSeverity, MdmConfig, MdmResult};
+
+/// Default interval between compliance evaluations
+pub const DEFAULT_COMPLIANCE_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// Audit event type recorded for every evaluation
+pub const COMPLIANCE_AUDIT_EVENT: &str = "mdm.compliance.evaluated";
+
+/// Audit event type recorded when an enforcement action is applied
+pub const ENFORCEMENT_APPLIED_AUDIT_EVENT: &str = "mdm.enforcement.applied";
+
+/// Audit event type recorded when an enforcement action is reverted
+pub const ENFORCEMENT_REVERTED_AUDIT_EVENT: &str = "mdm.enforcement.reverted";
+
+/// Source of the app/device state compliance rules are checked against
+#[async_trait]
+pub trait ComplianceContextProvider: Send + Sync {
+    /// Snapshot the current state
+    async fn compliance_context(&self) -> MdmResult<ComplianceContext>;
+}
+
+/// Context from the running app and host OS
+///
+/// Fields: `app.version`, `os.family` (e.g. "macos") and `os.arch`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemComplianceContext;
+
+#[async_trait]
+impl ComplianceContextProvider for SystemComplianceContext {
+    async fn compliance_context(&self) -> MdmResult<ComplianceContext> {
+        Ok(ComplianceContext::new()
+            .with_field("app.version", env!("CARGO_PKG_VERSION"))
+            .with_field("os.family", std::env::consts::OS)
+            .with_field("os.arch", std::env::consts::ARCH))
+    }
+}
+
+/// Summary of the latest compliance evaluation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComplianceStatus {
+    /// Whether every required rule passed
+    pub compliant: bool,
+    /// Required rules that failed with critical severity
+    pub critical_failures: Vec<String>,
+    /// Other required rules that failed
+    pub required_failures: Vec<String>,
+    /// Optional rules that failed
+    pub warnings: Vec<String>,
+}
+
+impl ComplianceStatus {
+    /// Summarize `report`
+    pub fn from_report(report: &ComplianceReport) -> Self {
+        let mut status = Self {
+            compliant: report.is_compliant(),
+            critical_failures: Vec::new(),
+            required_failures: Vec::new(),
+            warnings: Vec::new(),
+        };
+        for result in report.results.iter().filter(|result| !result.passed) {
+            let name = result.rule_name.clone();
+            match (result.required, &result.severity) {
+                (true, ComplianceSeverity::Critical) => status.critical_failures.push(name),
+                (true, _) => status.required_failures.push(name),
+                (false, _) => status.warnings.push(name),
+            }
+        }
+        status
+    }
+}
+
+/// Evaluates compliance periodically and reports the results
+pub struct ComplianceScheduler {
+    config: Arc<RwLock<MdmConfig>>,
+    context: Arc<dyn ComplianceContextProvider>,
+    audit: Arc<dyn AuditLogger>,
+    enforcer: Option<Arc<PolicyEnforcer>>,
+    status: watch::Sender<Option<ComplianceStatus>>,
+    cancel: CancellationToken,
+}
+
+impl ComplianceScheduler {
+    /// Create a scheduler evaluating the rules of `config`
+    ///
+    /// The config is shared so remote updates apply to the next evaluation.
+    pub fn new(
+        config: Arc<RwLock<MdmConfig>>,
+        context: Arc<dyn ComplianceContextProvider>,
+        audit: Arc<dyn AuditLogger>,
+    ) -> Self {
+        let (status, _) = watch::channel(None);
+        Self { config, context, audit, enforcer: None, status, cancel: CancellationToken::new() }
+    }
+
+    /// Enforce failing rules' actions after each evaluation
+    pub fn with_enforcer(mut self, enforcer: Arc<PolicyEnforcer>) -> Self {
+        self.enforcer = Some(enforcer);
+        self
+    }
+
+    /// Status of the last evaluation, `None` before the first
+    pub fn status(&self) -> Option<ComplianceStatus> {
+        self.status.borrow().clone()
+    }
+
+    /// Receiver notified whenever the status changes
+    pub fn subscribe(&self) -> watch::Receiver<Option<ComplianceStatus>> {
+        self.status.subscribe()
+    }
+
+    /// Evaluate compliance now
+    ///
+    /// Audits the report, publishes the status if it changed and, with an
+    /// enforcer attached, enforces the result.
+    ///
+    /// # Errors
+    /// Returns the error if the context cannot be built or a rule cannot be
+    /// evaluated (nothing is audited or published then), or if enforcement
+    /// fails.
+    pub async fn evaluate(&self) -> MdmResult<ComplianceReport> {
+        let context = self.context.compliance_context().await?;
+        let config = self.config.read().await;
+        let report = config.check_compliance(&context)?;
+        let status = ComplianceStatus::from_report(&report);
+
+        for rule in &status.critical_failures {
+            error!(rule = %rule, "Critical MDM compliance rule failed");
+        }
+        for rule in &status.required_failures {
+            warn!(rule = %rule, "Required MDM compliance rule failed");
+        }
+
+        self.audit.log(audit_entry(&status)).await;
+
+        let changed = self.status.send_if_modified(|current| {
+            let changed = current.as_ref() != Some(&status);
+            if changed {
+                *current = Some(status.clone());
+            }
+            changed
+        });
+        if changed {
+            info!(compliant = status.compliant, "MDM compliance status changed");
+        }
+
+        if let Some(enforcer) = &self.enforcer {
+            for change in enforcer.enforce(&config, &report).await? {
+                self.audit.log(enforcement_audit_entry(&change)).await;
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Evaluate now and then every `interval` on a background task
+    ///
+    /// The task holds a weak reference and exits once the scheduler is
+    /// dropped or [`stop`](Self::stop) is called.
+    pub fn spawn(self: &Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        let scheduler = Arc::downgrade(self);
+        let cancel = self.cancel.clone();
+        tokio::spawn(run(scheduler, interval, cancel))
+    }
+
+    /// Stop the background evaluation task
+    pub fn stop(&self) {
+        self.cancel.cancel();
+    }
+}
+
+impl Drop for ComplianceScheduler {
+    fn drop(&mut self) {
+        self.cancel.cancel();
+    }
+}
+
+async fn run(scheduler: Weak<ComplianceScheduler>, interval: Duration, cancel: CancellationToken) {
+    let mut ticker = tokio::time::interval(interval);
+
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => break,
+            _ = ticker.tick() => {
+                let Some(scheduler) = scheduler.upgrade() else { break };
+                if let Err(err) = scheduler.evaluate().await {
+                    warn!(error = %err, "Scheduled MDM compliance evaluation failed");
+                }
+            }
+        }
+    }
+
+    debug!("Compliance scheduler exited");
+}
+
+fn audit_entry(status: &ComplianceStatus) -> AuditLogEntry {
+    let severity = if !status.critical_failures.is_empty() {
+        AuditSeverity::Critical
+    } else if !status.compliant {
+        AuditSeverity::Warning
+    } else {
+        AuditSeverity::Info
+    };
+
+    let metadata = HashMap::from([
+        ("compliant".to_string(), status.compliant.to_string()),
+        ("critical_failures".to_string(), status.critical_failures.join(",")),
+        ("required_failures".to_string(), status.required_failures.join(",")),
+        ("warnings".to_string(), status.warnings.join(",")),
+    ]);
+
+    AuditLogEntry {
+        event_type: COMPLIANCE_AUDIT_EVENT.to_string(),
+        severity,
+        metadata,
+        timestamp: SystemTime::now(),
+        ..AuditLogEntry::default()
+    }
+}
+
+fn enforcement_audit_entry(change: &EnforcementChange) -> AuditLogEntry {
+    let (event_type, severity) = if change.applied {
+        (ENFORCEMENT_APPLIED_AUDIT_EVENT, AuditSeverity::Warning)
+    } else {
+        (ENFORCEMENT_REVERTED_AUDIT_EVENT, AuditSeverity::Info)
+    };
+
+    let metadata = HashMap::from([
+        ("rule".to_string(), change.rule.clone()),
+        ("action".to_string(), format!("{:?}", change.action)),
+    ]);
+
+    AuditLogEntry {
+        event_type: event_type.to_string(),
+        severity,
+        metadata,
+        timestamp: SystemTime::now(),
+        ..AuditLogEntry::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::mdm::{ComplianceRule, ValidationType};
+
+    const INTERVAL: Duration = Duration::from_secs(60);
+
+    /// Device whose disk encryption the test toggles
+    struct FakeDevice(AtomicBool);
+
+    #[async_trait]
+    impl ComplianceContextProvider for FakeDevice {
+        async fn compliance_context(&self) -> MdmResult<ComplianceContext> {
+            let encrypted = self.0.load(Ordering::SeqCst);
+            Ok(ComplianceContext::new().with_field("disk.encrypted", encrypted.to_string()))
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingAudit(Mutex<Vec<AuditLogEntry>>);
+
+    #[async_trait]
+    impl AuditLogger for RecordingAudit {
+        async fn log(&self, event: AuditLogEntry) {
+            self.0.lock().unwrap().push(event);
+        }
+
+        async fn entry_count(&self) -> usize {
+            self.0.lock().unwrap().len()
+        }
+    }
+
+    fn config() -> MdmConfig {
+        let mut encryption = ComplianceRule::new(
+            "disk_encryption",
+            ValidationType::FieldEquals {
+                field: "disk.encrypted".to_string(),
+                value: "true".to_string(),
+            },
+        );
+        encryption.severity = ComplianceSeverity::Critical;
+        MdmConfig::builder().add_compliance_check(encryption).build().unwrap()
+    }
+
+    /// Scheduler under test plus the fakes it was wired with
+    type Harness = (Arc<ComplianceScheduler>, Arc<FakeDevice>, Arc<RecordingAudit>);
+
+    fn scheduler(encrypted: bool) -> Harness {
+        let device = Arc::new(FakeDevice(AtomicBool::new(encrypted)));
+        let audit = Arc::new(RecordingAudit::default());
+        let scheduler = Arc::new(ComplianceScheduler::new(
+            Arc::new(RwLock::new(config())),
+            device.clone(),
+            audit.clone(),
+        ));
+        (scheduler, device, audit)
+    }
+
+    async fn next_status(
+        events: &mut watch::Receiver<Option<ComplianceStatus>>,
+    ) -> ComplianceStatus {
+        tokio::time::timeout(INTERVAL * 2, events.changed())
+            .await
+            .expect("status change within one interval")
+            .unwrap();
+        events.borrow_and_update().clone().expect("status after an evaluation")
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn failing_rule_produces_non_compliant_report_on_scheduled_run() {
+        let (scheduler, _device, audit) = scheduler(false);
+        let mut events = scheduler.subscribe();
+        let handle = scheduler.spawn(INTERVAL);
+
+        let status = next_status(&mut events).await;
+        assert!(!status.compliant);
+        assert_eq!(status.critical_failures, vec!["disk_encryption".to_string()]);
+
+        let entries = audit.0.lock().unwrap().clone();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].event_type, COMPLIANCE_AUDIT_EVENT);
+        assert_eq!(entries[0].severity, AuditSeverity::Critical);
+        assert_eq!(entries[0].metadata["compliant"], "false");
+
+        scheduler.stop();
+        handle.await.unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn recovery_flips_status_back_to_compliant() {
+        let (scheduler, device, audit) = scheduler(false);
+        let mut events = scheduler.subscribe();
+        let handle = scheduler.spawn(INTERVAL);
+        assert!(!next_status(&mut events).await.compliant);
+
+        device.0.store(true, Ordering::SeqCst);
+        let status = next_status(&mut events).await;
+        assert!(status.compliant);
+        assert!(status.critical_failures.is_empty());
+        assert_eq!(scheduler.status(), Some(status));
+
+        // Unchanged status is audited but not re-announced
+        tokio::time::sleep(INTERVAL + Duration::from_secs(1)).await;
+        assert!(!events.has_changed().unwrap());
+        let entries = audit.0.lock().unwrap().clone();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries.last().map(|entry| entry.severity), Some(AuditSeverity::Info));
+
+        scheduler.stop();
+        handle.await.unwrap();
+    }
+}
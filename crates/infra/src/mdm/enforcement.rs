@@ -0,0 +1,520 @@
+//! MDM policy enforcement
+//!
+//! When [`MdmConfig::policy_enforcement`] is on, a failing rule that is
+//! required or critical triggers the [`EnforcementAction`] mapped to it:
+//! - `DisableFeature` turns a feature flag off through [`FeatureFlagsPort`]
+//! - `BlockSync` sets [`EnforcementState::sync_blocked`]
+//! - `Notify` adds a mandatory [`EnforcementNotice`]
+//!
+//! [`PolicyEnforcer`] remembers which actions are active, so enforcing the
+//! same report twice changes nothing, and reverts an action once its rule
+//! passes again (or enforcement is switched off). A flag disabled by several
+//! rules stays off until the last of them is reverted, and is then restored
+//! to the value it had before enforcement.
+//!
+//! The UI follows [`PolicyEnforcer::subscribe`]; sync workers follow
+//! [`PolicyEnforcer::subscribe_sync_blocked`].
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use pulsearc_core::FeatureFlagsPort;
+use serde::Serialize;
+use tokio::sync::{watch, Mutex};
+use tracing::{info, warn};
+
+use super::{
+    ComplianceReport, ComplianceSeverity, EnforcementAction, MdmConfig, MdmError, MdmResult,
+};
+
+/// Mandatory notification raised by a failing rule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnforcementNotice {
+    pub rule: String,
+    pub message: String,
+}
+
+/// Restrictions currently in force
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnforcementState {
+    /// Feature flags switched off by enforcement
+    pub disabled_features: Vec<String>,
+    /// Whether sync must not run
+    pub sync_blocked: bool,
+    /// Notifications the user must see
+    pub notices: Vec<EnforcementNotice>,
+}
+
+/// An action applied or reverted by [`PolicyEnforcer::enforce`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnforcementChange {
+    pub rule: String,
+    pub action: EnforcementAction,
+    /// `true` if applied, `false` if reverted
+    pub applied: bool,
+}
+
+/// An action currently in force
+#[derive(Debug, Clone)]
+struct ActiveAction {
+    action: EnforcementAction,
+    /// Flag value before `DisableFeature` was applied
+    previous_flag: Option<bool>,
+}
+
+/// Applies and reverts enforcement actions from compliance reports
+pub struct PolicyEnforcer {
+    flags: Arc<dyn FeatureFlagsPort>,
+    /// Active actions by rule name
+    active: Mutex<BTreeMap<String, ActiveAction>>,
+    state: watch::Sender<EnforcementState>,
+    sync_blocked: watch::Sender<bool>,
+}
+
+impl PolicyEnforcer {
+    pub fn new(flags: Arc<dyn FeatureFlagsPort>) -> Self {
+        let (state, _) = watch::channel(EnforcementState::default());
+        let (sync_blocked, _) = watch::channel(false);
+        Self { flags, active: Mutex::new(BTreeMap::new()), state, sync_blocked }
+    }
+
+    /// Restrictions currently in force
+    pub fn state(&self) -> EnforcementState {
+        self.state.borrow().clone()
+    }
+
+    /// Receiver notified whenever the restrictions change
+    pub fn subscribe(&self) -> watch::Receiver<EnforcementState> {
+        self.state.subscribe()
+    }
+
+    /// Receiver holding [`EnforcementState::sync_blocked`], for sync workers
+    pub fn subscribe_sync_blocked(&self) -> watch::Receiver<bool> {
+        self.sync_blocked.subscribe()
+    }
+
+    /// Bring enforcement in line with `report`
+    ///
+    /// Applies the action of every failing required or critical rule that
+    /// has one, and reverts actions whose rule passes, has lost its action or
+    /// was removed. With `policy_enforcement` off, every action is reverted.
+    ///
+    /// # Errors
+    /// Returns [`MdmError::EnforcementFailed`] if a feature flag cannot be
+    /// read or written. Actions handled before the failure stay in effect;
+    /// the next call retries the rest.
+    pub async fn enforce(
+        &self,
+        config: &MdmConfig,
+        report: &ComplianceReport,
+    ) -> MdmResult<Vec<EnforcementChange>> {
+        let mut wanted = BTreeMap::new();
+        if config.policy_enforcement {
+            for rule in &config.compliance_checks {
+                let Some(action) = &rule.enforcement else { continue };
+                let failing = report.results.iter().any(|result| {
+                    result.rule_name == rule.name
+                        && !result.passed
+                        && (result.required
+                            || matches!(result.severity, ComplianceSeverity::Critical))
+                });
+                if failing {
+                    wanted.insert(rule.name.clone(), action.clone());
+                }
+            }
+        }
+
+        let mut active = self.active.lock().await;
+        let mut changes = Vec::new();
+        let result = self.reconcile(&mut active, &wanted, &mut changes).await;
+
+        let next = state_of(&active);
+        self.sync_blocked.send_if_modified(|blocked| {
+            let changed = *blocked != next.sync_blocked;
+            *blocked = next.sync_blocked;
+            changed
+        });
+        self.state.send_if_modified(|state| {
+            let changed = *state != next;
+            *state = next;
+            changed
+        });
+
+        result.map(|()| changes)
+    }
+
+    async fn reconcile(
+        &self,
+        active: &mut BTreeMap<String, ActiveAction>,
+        wanted: &BTreeMap<String, EnforcementAction>,
+        changes: &mut Vec<EnforcementChange>,
+    ) -> MdmResult<()> {
+        // Revert first so a rule whose action changed releases the old one.
+        let stale: Vec<String> = active
+            .iter()
+            .filter(|(rule, current)| wanted.get(*rule) != Some(&current.action))
+            .map(|(rule, _)| rule.clone())
+            .collect();
+        for rule in stale {
+            if let Some(current) = active.get(&rule).cloned() {
+                self.revert(&rule, &current, active).await?;
+                active.remove(&rule);
+                info!(rule = %rule, action = ?current.action, "Reverted MDM enforcement action");
+                changes.push(EnforcementChange { rule, action: current.action, applied: false });
+            }
+        }
+
+        for (rule, action) in wanted {
+            if active.contains_key(rule) {
+                continue;
+            }
+            let applied = self.apply(rule, action, active).await?;
+            warn!(rule = %rule, action = ?action, "Applied MDM enforcement action");
+            active.insert(rule.clone(), applied);
+            changes.push(EnforcementChange {
+                rule: rule.clone(),
+                action: action.clone(),
+                applied: true,
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn apply(
+        &self,
+        rule: &str,
+        action: &EnforcementAction,
+        active: &BTreeMap<String, ActiveAction>,
+    ) -> MdmResult<ActiveAction> {
+        let previous_flag = match action {
+            EnforcementAction::DisableFeature { flag } => {
+                // Already off for another rule: share its pre-enforcement value
+                if let Some(holder) = flag_holder(active, rule, flag) {
+                    return Ok(ActiveAction {
+                        action: action.clone(),
+                        previous_flag: holder.previous_flag,
+                    });
+                }
+                let evaluation = self
+                    .flags
+                    .evaluate(flag, true)
+                    .await
+                    .map_err(|e| enforcement_failed(rule, e))?;
+                // A flag held off by a dependency is still stored as enabled.
+                let previous = evaluation.enabled || evaluation.unmet_dependency.is_some();
+                self.flags
+                    .set_enabled(flag, false)
+                    .await
+                    .map_err(|e| enforcement_failed(rule, e))?;
+                Some(previous)
+            }
+            EnforcementAction::BlockSync | EnforcementAction::Notify { .. } => None,
+        };
+        Ok(ActiveAction { action: action.clone(), previous_flag })
+    }
+
+    async fn revert(
+        &self,
+        rule: &str,
+        current: &ActiveAction,
+        active: &BTreeMap<String, ActiveAction>,
+    ) -> MdmResult<()> {
+        if let (EnforcementAction::DisableFeature { flag }, Some(previous)) =
+            (&current.action, current.previous_flag)
+        {
+            // Another rule still needs the flag off
+            if flag_holder(active, rule, flag).is_some() {
+                return Ok(());
+            }
+            self.flags
+                .set_enabled(flag, previous)
+                .await
+                .map_err(|e| enforcement_failed(rule, e))?;
+        }
+        Ok(())
+    }
+}
+
+/// Another rule's active action disabling `flag`, if any
+fn flag_holder<'a>(
+    active: &'a BTreeMap<String, ActiveAction>,
+    rule: &str,
+    flag: &str,
+) -> Option<&'a ActiveAction> {
+    active.iter().find_map(|(other, current)| match &current.action {
+        EnforcementAction::DisableFeature { flag: held } if other != rule && held == flag => {
+            Some(current)
+        }
+        _ => None,
+    })
+}
+
+fn state_of(active: &BTreeMap<String, ActiveAction>) -> EnforcementState {
+    let mut state = EnforcementState::default();
+    for (rule, current) in active {
+        match &current.action {
+            EnforcementAction::DisableFeature { flag } => {
+                if !state.disabled_features.contains(flag) {
+                    state.disabled_features.push(flag.clone());
+                }
+            }
+            EnforcementAction::BlockSync => state.sync_blocked = true,
+            EnforcementAction::Notify { message } => state
+                .notices
+                .push(EnforcementNotice { rule: rule.clone(), message: message.clone() }),
+        }
+    }
+    state
+}
+
+fn enforcement_failed(rule: &str, err: impl std::fmt::Display) -> MdmError {
+    MdmError::EnforcementFailed { rule: rule.to_string(), reason: err.to_string() }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex as StdMutex;
+
+    use async_trait::async_trait;
+    use pulsearc_core::feature_flags_ports::{FeatureFlag, FeatureFlagEvaluation};
+    use pulsearc_domain::Result as DomainResult;
+
+    use super::*;
+    use crate::mdm::{ComplianceContext, ComplianceRule, ValidationType};
+
+    /// Flag name and the value written to it
+    type FlagWrite = (String, bool);
+
+    #[derive(Default)]
+    struct InMemoryFlags {
+        flags: StdMutex<HashMap<String, bool>>,
+        writes: StdMutex<Vec<FlagWrite>>,
+    }
+
+    impl InMemoryFlags {
+        fn with_flag(flag: &str, enabled: bool) -> Arc<Self> {
+            let flags = Self::default();
+            flags.flags.lock().unwrap().insert(flag.to_string(), enabled);
+            Arc::new(flags)
+        }
+
+        fn get(&self, flag: &str) -> Option<bool> {
+            self.flags.lock().unwrap().get(flag).copied()
+        }
+
+        fn write_count(&self) -> usize {
+            self.writes.lock().unwrap().len()
+        }
+    }
+
+    #[async_trait]
+    impl FeatureFlagsPort for InMemoryFlags {
+        async fn evaluate(
+            &self,
+            flag_name: &str,
+            default: bool,
+        ) -> DomainResult<FeatureFlagEvaluation> {
+            let stored = self.get(flag_name);
+            Ok(FeatureFlagEvaluation {
+                enabled: stored.unwrap_or(default),
+                fallback_used: stored.is_none(),
+                unmet_dependency: None,
+            })
+        }
+
+        async fn set_enabled(&self, flag_name: &str, enabled: bool) -> DomainResult<()> {
+            self.flags.lock().unwrap().insert(flag_name.to_string(), enabled);
+            self.writes.lock().unwrap().push((flag_name.to_string(), enabled));
+            Ok(())
+        }
+
+        async fn set_dependencies(
+            &self,
+            _flag_name: &str,
+            _requires: &[String],
+        ) -> DomainResult<()> {
+            Ok(())
+        }
+
+        async fn list_all(&self) -> DomainResult<Vec<FeatureFlag>> {
+            Ok(Vec::new())
+        }
+    }
+
+    fn encryption_rule(action: EnforcementAction) -> ComplianceRule {
+        let mut rule = ComplianceRule::new(
+            "disk_encryption",
+            ValidationType::FieldEquals {
+                field: "disk.encrypted".to_string(),
+                value: "true".to_string(),
+            },
+        )
+        .with_enforcement(action);
+        rule.severity = ComplianceSeverity::Critical;
+        rule
+    }
+
+    fn config(enforcement: bool, rule: ComplianceRule) -> MdmConfig {
+        MdmConfig::builder()
+            .policy_enforcement(enforcement)
+            .add_compliance_check(rule)
+            .build()
+            .unwrap()
+    }
+
+    fn report(config: &MdmConfig, encrypted: bool) -> ComplianceReport {
+        let context = ComplianceContext::new().with_field("disk.encrypted", encrypted.to_string());
+        config.check_compliance(&context).unwrap()
+    }
+
+    fn disable_sap() -> EnforcementAction {
+        EnforcementAction::DisableFeature { flag: "sap".to_string() }
+    }
+
+    #[tokio::test]
+    async fn failed_critical_rule_disables_mapped_feature() {
+        let flags = InMemoryFlags::with_flag("sap", true);
+        let enforcer = PolicyEnforcer::new(flags.clone());
+        let config = config(true, encryption_rule(disable_sap()));
+
+        let changes = enforcer.enforce(&config, &report(&config, false)).await.unwrap();
+        assert_eq!(
+            changes,
+            vec![EnforcementChange {
+                rule: "disk_encryption".to_string(),
+                action: disable_sap(),
+                applied: true,
+            }]
+        );
+        assert_eq!(flags.get("sap"), Some(false));
+        assert_eq!(enforcer.state().disabled_features, vec!["sap".to_string()]);
+
+        // Enforcing the same failure again is a no-op
+        let changes = enforcer.enforce(&config, &report(&config, false)).await.unwrap();
+        assert!(changes.is_empty());
+        assert_eq!(flags.write_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn passing_rule_re_enables_feature() {
+        let flags = InMemoryFlags::with_flag("sap", true);
+        let enforcer = PolicyEnforcer::new(flags.clone());
+        let config = config(true, encryption_rule(disable_sap()));
+        let mut state = enforcer.subscribe();
+
+        enforcer.enforce(&config, &report(&config, false)).await.unwrap();
+        assert!(state.has_changed().unwrap());
+        state.mark_unchanged();
+
+        let changes = enforcer.enforce(&config, &report(&config, true)).await.unwrap();
+        assert_eq!(changes.len(), 1);
+        assert!(!changes[0].applied);
+        assert_eq!(flags.get("sap"), Some(true));
+        assert_eq!(enforcer.state(), EnforcementState::default());
+        assert!(state.has_changed().unwrap());
+    }
+
+    #[tokio::test]
+    async fn revert_restores_previously_disabled_flag() {
+        let flags = InMemoryFlags::with_flag("sap", false);
+        let enforcer = PolicyEnforcer::new(flags.clone());
+        let config = config(true, encryption_rule(disable_sap()));
+
+        enforcer.enforce(&config, &report(&config, false)).await.unwrap();
+        enforcer.enforce(&config, &report(&config, true)).await.unwrap();
+        assert_eq!(flags.get("sap"), Some(false));
+    }
+
+    #[tokio::test]
+    async fn shared_flag_stays_disabled_until_last_rule_passes() {
+        let flags = InMemoryFlags::with_flag("sap", true);
+        let enforcer = PolicyEnforcer::new(flags.clone());
+        let mut firewall = ComplianceRule::new(
+            "firewall",
+            ValidationType::FieldEquals {
+                field: "firewall.enabled".to_string(),
+                value: "true".to_string(),
+            },
+        )
+        .with_enforcement(disable_sap());
+        firewall.severity = ComplianceSeverity::Critical;
+        let config = MdmConfig::builder()
+            .policy_enforcement(true)
+            .add_compliance_check(encryption_rule(disable_sap()))
+            .add_compliance_check(firewall)
+            .build()
+            .unwrap();
+        let device = |encrypted: bool, firewall: bool| {
+            let context = ComplianceContext::new()
+                .with_field("disk.encrypted", encrypted.to_string())
+                .with_field("firewall.enabled", firewall.to_string());
+            config.check_compliance(&context).unwrap()
+        };
+
+        enforcer.enforce(&config, &device(false, false)).await.unwrap();
+        assert_eq!(flags.get("sap"), Some(false));
+        assert_eq!(enforcer.state().disabled_features, vec!["sap".to_string()]);
+
+        // The firewall rule still holds the flag off
+        let changes = enforcer.enforce(&config, &device(true, false)).await.unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(flags.get("sap"), Some(false));
+        assert_eq!(enforcer.state().disabled_features, vec!["sap".to_string()]);
+
+        enforcer.enforce(&config, &device(true, true)).await.unwrap();
+        assert_eq!(flags.get("sap"), Some(true));
+        assert_eq!(flags.write_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn block_sync_and_notice_follow_rule() {
+        let enforcer = PolicyEnforcer::new(Arc::new(InMemoryFlags::default()));
+        let mut notify = ComplianceRule::new(
+            "os_updates",
+            ValidationType::FieldExists("os.patched".to_string()),
+        )
+        .with_enforcement(EnforcementAction::Notify {
+            message: "Install pending OS updates".to_string(),
+        });
+        notify.required = false;
+        notify.severity = ComplianceSeverity::Critical;
+        let config = MdmConfig::builder()
+            .policy_enforcement(true)
+            .add_compliance_check(encryption_rule(EnforcementAction::BlockSync))
+            .add_compliance_check(notify)
+            .build()
+            .unwrap();
+
+        let sync_blocked = enforcer.subscribe_sync_blocked();
+
+        enforcer.enforce(&config, &report(&config, false)).await.unwrap();
+        let state = enforcer.state();
+        assert!(state.sync_blocked);
+        assert!(*sync_blocked.borrow());
+        assert_eq!(state.notices.len(), 1);
+        assert_eq!(state.notices[0].rule, "os_updates");
+
+        enforcer.enforce(&config, &report(&config, true)).await.unwrap();
+        let state = enforcer.state();
+        assert!(!state.sync_blocked);
+        assert!(!*sync_blocked.borrow());
+        assert_eq!(state.notices.len(), 1, "notice stays until os_updates passes");
+    }
+
+    #[tokio::test]
+    async fn disabling_policy_enforcement_reverts_actions() {
+        let flags = InMemoryFlags::with_flag("sap", true);
+        let enforcer = PolicyEnforcer::new(flags.clone());
+        let enforced = config(true, encryption_rule(disable_sap()));
+        enforcer.enforce(&enforced, &report(&enforced, false)).await.unwrap();
+
+        let relaxed = config(false, encryption_rule(disable_sap()));
+        let changes = enforcer.enforce(&relaxed, &report(&relaxed, false)).await.unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(flags.get("sap"), Some(true));
+    }
+}
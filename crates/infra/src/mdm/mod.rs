@@ -7,8 +7,21 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 
 pub mod client;
+#[cfg(any(feature = "audit-compliance", test))]
+pub mod compliance_scheduler;
+#[cfg(any(feature = "audit-compliance", test))]
+pub mod enforcement;
+pub mod signature;
 
 pub use client::MdmClient;
+#[cfg(any(feature = "audit-compliance", test))]
+pub use compliance_scheduler::{
+    ComplianceContextProvider, ComplianceScheduler, ComplianceStatus, SystemComplianceContext,
+    DEFAULT_COMPLIANCE_INTERVAL,
+};
+#[cfg(any(feature = "audit-compliance", test))]
+pub use enforcement::{EnforcementChange, EnforcementNotice, EnforcementState, PolicyEnforcer};
+pub use signature::{ConfigVerifier, SIGNATURE_HEADER};
 
 /// Result type for MDM operations
 pub type MdmResult<T> = Result<T, MdmError>;
@@ -21,6 +34,8 @@ pub enum MdmError {
     ComplianceCheckFailed { rule: String, reason: String },
     ConfigurationError(String),
     ValidationError(String),
+    EnforcementFailed { rule: String, reason: String },
+    SignatureInvalid(String),
 }
 
 impl fmt::Display for MdmError {
@@ -33,6 +48,12 @@ impl fmt::Display for MdmError {
             }
             Self::ConfigurationError(msg) => write!(f, "MDM configuration error: {}", msg),
             Self::ValidationError(msg) => write!(f, "Validation error: {}", msg),
+            Self::EnforcementFailed { rule, reason } => {
+                write!(f, "Enforcement for rule '{}' failed: {}", rule, reason)
+            }
+            Self::SignatureInvalid(reason) => {
+                write!(f, "MDM configuration signature rejected: {}", reason)
+            }
         }
     }
 }
@@ -244,6 +265,10 @@ pub struct ComplianceRule {
     /// Severity level if the check fails
     #[serde(default)]
     pub severity: ComplianceSeverity,
+
+    /// Action taken while the rule fails and policy enforcement is on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enforcement: Option<EnforcementAction>,
 }
 
 impl ComplianceRule {
@@ -255,14 +280,35 @@ impl ComplianceRule {
             criteria: ComplianceCriteria::new(),
             description: None,
             severity: ComplianceSeverity::default(),
+            enforcement: None,
         }
     }
 
+    /// Set the action enforced while this rule fails
+    pub fn with_enforcement(mut self, action: EnforcementAction) -> Self {
+        self.enforcement = Some(action);
+        self
+    }
+
     pub fn validate(&self) -> MdmResult<()> {
         if self.name.is_empty() {
             return Err(MdmError::ValidationError("Rule name cannot be empty".into()));
         }
-        Ok(())
+        match &self.enforcement {
+            Some(EnforcementAction::DisableFeature { flag }) if flag.is_empty() => {
+                Err(MdmError::ValidationError(format!(
+                    "Rule '{}': enforcement flag cannot be empty",
+                    self.name
+                )))
+            }
+            Some(EnforcementAction::Notify { message }) if message.is_empty() => {
+                Err(MdmError::ValidationError(format!(
+                    "Rule '{}': enforcement message cannot be empty",
+                    self.name
+                )))
+            }
+            _ => Ok(()),
+        }
     }
 
     #[cfg(any(feature = "audit-compliance", test))]
@@ -306,6 +352,20 @@ impl ComplianceRule {
     }
 }
 
+/// Action enforced while a required or critical rule fails
+///
+/// Actions are reverted once the rule passes again.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum EnforcementAction {
+    /// Turn a feature flag off
+    DisableFeature { flag: String },
+    /// Stop syncing data to the backend
+    BlockSync,
+    /// Show a notification the user cannot dismiss
+    Notify { message: String },
+}
+
 /// Types of validation that can be performed
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", tag = "type")]
@@ -616,6 +676,7 @@ mod tests {
             criteria: ComplianceCriteria::new(),
             description: None,
             severity: ComplianceSeverity::Medium,
+            enforcement: None,
         };
 
         let result = rule.validate();
@@ -911,6 +972,32 @@ mod tests {
         assert_eq!(local.update_interval_secs, 1800);
     }
 
+    #[test]
+    fn test_compliance_rule_enforcement_serialization() {
+        let check = ValidationType::FieldEquals {
+            field: "disk.encrypted".to_string(),
+            value: "true".to_string(),
+        };
+        let rule = ComplianceRule::new("disk_encryption", check.clone())
+            .with_enforcement(EnforcementAction::DisableFeature { flag: "sap".to_string() });
+        let json = serde_json::to_string(&rule).expect("Should serialize");
+        assert!(json.contains(r#""enforcement":{"type":"disableFeature","flag":"sap"}"#));
+
+        let parsed: ComplianceRule = serde_json::from_str(&json).expect("Should deserialize");
+        assert_eq!(parsed.enforcement, rule.enforcement);
+
+        let plain = ComplianceRule::new("plain", check);
+        let json = serde_json::to_string(&plain).expect("Should serialize");
+        assert!(!json.contains("enforcement"));
+    }
+
+    #[test]
+    fn test_compliance_rule_validate_empty_enforcement_flag() {
+        let rule = ComplianceRule::new("rule", ValidationType::FieldExists("field1".to_string()))
+            .with_enforcement(EnforcementAction::DisableFeature { flag: String::new() });
+        assert!(rule.validate().is_err());
+    }
+
     #[test]
     fn test_policy_value_serialization() {
         let string_value = PolicyValue::String("test".to_string());
@@ -0,0 +1,169 @@
+//! MDM Remote Configuration Signatures
+//!
+//! Remote configuration is signed by the MDM server with Ed25519. The
+//! signature covers the exact response body and travels detached from it,
+//! base64-encoded in the [`SIGNATURE_HEADER`] response header. The client
+//! verifies it against a public key configured locally, so a compromised or
+//! intercepted endpoint cannot push a policy of its own.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use ring::signature::{UnparsedPublicKey, ED25519};
+
+use super::{MdmConfig, MdmError, MdmResult};
+
+/// Response header carrying the base64 signature of the body
+pub const SIGNATURE_HEADER: &str = "x-mdm-signature";
+
+/// Length of an Ed25519 public key in bytes
+const PUBLIC_KEY_LEN: usize = 32;
+
+/// Verifies signed remote MDM configuration
+#[derive(Debug, Clone)]
+pub struct ConfigVerifier {
+    public_key: Vec<u8>,
+}
+
+impl ConfigVerifier {
+    /// Create a verifier from a raw 32-byte Ed25519 public key
+    ///
+    /// # Errors
+    /// Returns `MdmError::ConfigurationError` if the key has the wrong length
+    pub fn from_bytes(public_key: &[u8]) -> MdmResult<Self> {
+        if public_key.len() != PUBLIC_KEY_LEN {
+            return Err(MdmError::ConfigurationError(format!(
+                "MDM public key must be {} bytes, got {}",
+                PUBLIC_KEY_LEN,
+                public_key.len()
+            )));
+        }
+        Ok(Self { public_key: public_key.to_vec() })
+    }
+
+    /// Create a verifier from a base64-encoded Ed25519 public key
+    ///
+    /// # Errors
+    /// Returns `MdmError::ConfigurationError` if the key is not valid base64
+    /// or has the wrong length
+    pub fn from_base64(public_key: &str) -> MdmResult<Self> {
+        let bytes = STANDARD.decode(public_key.trim()).map_err(|e| {
+            MdmError::ConfigurationError(format!("MDM public key is not valid base64: {}", e))
+        })?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Check `signature` (base64) over `payload`
+    ///
+    /// # Errors
+    /// Returns `MdmError::SignatureInvalid` if the signature is missing,
+    /// malformed, or does not match
+    pub fn verify(&self, payload: &[u8], signature: Option<&str>) -> MdmResult<()> {
+        let signature = signature
+            .ok_or_else(|| MdmError::SignatureInvalid("configuration is unsigned".into()))?;
+        let signature = STANDARD.decode(signature.trim()).map_err(|e| {
+            MdmError::SignatureInvalid(format!("signature is not valid base64: {}", e))
+        })?;
+
+        UnparsedPublicKey::new(&ED25519, &self.public_key).verify(payload, &signature).map_err(
+            |_| MdmError::SignatureInvalid("signature does not match configuration".into()),
+        )
+    }
+
+    /// Verify `payload` and parse it as an [`MdmConfig`]
+    ///
+    /// Nothing is parsed until the signature checks out.
+    ///
+    /// # Errors
+    /// Returns `MdmError::SignatureInvalid` if verification fails and
+    /// `MdmError::ConfigurationError` if the verified payload is not a valid
+    /// configuration
+    pub fn verify_config(&self, payload: &[u8], signature: Option<&str>) -> MdmResult<MdmConfig> {
+        self.verify(payload, signature)?;
+
+        let config: MdmConfig = serde_json::from_slice(payload).map_err(|e| {
+            MdmError::ConfigurationError(format!("Failed to parse configuration: {}", e))
+        })?;
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_keys {
+    use ring::rand::SystemRandom;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+
+    use super::*;
+
+    /// Fresh Ed25519 signing key
+    pub(crate) fn key_pair() -> Ed25519KeyPair {
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+        Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap()
+    }
+
+    /// Base64 public key of `key_pair`
+    pub(crate) fn public_key(key_pair: &Ed25519KeyPair) -> String {
+        STANDARD.encode(key_pair.public_key().as_ref())
+    }
+
+    /// Base64 signature of `payload`
+    pub(crate) fn sign(key_pair: &Ed25519KeyPair, payload: &[u8]) -> String {
+        STANDARD.encode(key_pair.sign(payload).as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_keys::{key_pair, public_key, sign};
+    use super::*;
+
+    const REMOTE: &str = r#"{"policyEnforcement":true,"updateIntervalSecs":600}"#;
+
+    #[test]
+    fn test_signed_config_verifies_and_merges() {
+        let keys = key_pair();
+        let verifier = ConfigVerifier::from_base64(&public_key(&keys)).unwrap();
+
+        let remote = verifier
+            .verify_config(REMOTE.as_bytes(), Some(&sign(&keys, REMOTE.as_bytes())))
+            .expect("validly signed config should verify");
+
+        let mut local = MdmConfig::builder().allow_local_override(true).build().unwrap();
+        local.merge_remote(remote).unwrap();
+        assert!(local.policy_enforcement);
+    }
+
+    #[test]
+    fn test_tampered_config_rejected() {
+        let keys = key_pair();
+        let verifier = ConfigVerifier::from_base64(&public_key(&keys)).unwrap();
+        let signature = sign(&keys, REMOTE.as_bytes());
+        let tampered = REMOTE.replace("true", "false");
+
+        let result = verifier.verify_config(tampered.as_bytes(), Some(&signature));
+        assert!(matches!(result, Err(MdmError::SignatureInvalid(_))));
+    }
+
+    #[test]
+    fn test_unsigned_config_rejected() {
+        let verifier = ConfigVerifier::from_base64(&public_key(&key_pair())).unwrap();
+
+        let result = verifier.verify_config(REMOTE.as_bytes(), None);
+        assert!(matches!(result, Err(MdmError::SignatureInvalid(_))));
+    }
+
+    #[test]
+    fn test_config_signed_by_other_key_rejected() {
+        let verifier = ConfigVerifier::from_base64(&public_key(&key_pair())).unwrap();
+        let signature = sign(&key_pair(), REMOTE.as_bytes());
+
+        let result = verifier.verify_config(REMOTE.as_bytes(), Some(&signature));
+        assert!(matches!(result, Err(MdmError::SignatureInvalid(_))));
+    }
+
+    #[test]
+    fn test_malformed_public_key_rejected() {
+        assert!(ConfigVerifier::from_base64("not base64!").is_err());
+        assert!(ConfigVerifier::from_bytes(&[0u8; 16]).is_err());
+    }
+}
@@ -0,0 +1,229 @@
+//! `MetricsCollector` adapter backed by `PerformanceMetrics`
+//!
+//! Core services emit metrics through the
+//! [`pulsearc_common::observability::MetricsCollector`] trait so they stay
+//! free of infrastructure dependencies. [`PerformanceMetricsCollector`]
+//! bridges those calls into this crate's metrics.
+//!
+//! ## Design
+//! - **Named counters** - Every counter is aggregated per metric name and label
+//!   set in a [`MetricAggregator`], so cardinality is bounded
+//! - **Known metrics** - Classification runs, cache hits/misses and classifier
+//!   timings also feed the matching [`PerformanceMetrics`] call and cache
+//!   metrics
+//! - **Best effort** - Recording errors are logged and the metric dropped
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use pulsearc_common::observability::MetricsCollector;
+use pulsearc_core::classification::{
+    CLASSIFICATION_CACHE_HIT_METRIC, CLASSIFICATION_CACHE_MISS_METRIC,
+    CLASSIFICATION_DURATION_METRIC, CLASSIFICATION_RUNS_METRIC,
+};
+
+use super::metrics::{AggregationConfig, LabelCount, MetricAggregator, PerformanceMetrics};
+use super::MetricsResult;
+
+/// Label recorded for counters emitted without labels
+pub const UNLABELLED: &str = "all";
+
+/// [`MetricsCollector`] that records into [`PerformanceMetrics`]
+#[derive(Debug)]
+pub struct PerformanceMetricsCollector {
+    /// Shared performance metrics fed by well-known metric names
+    performance: Arc<PerformanceMetrics>,
+    /// Per-name, per-label counter totals
+    counters: MetricAggregator,
+}
+
+impl PerformanceMetricsCollector {
+    /// Create a collector recording into `performance`
+    pub fn new(performance: Arc<PerformanceMetrics>) -> Self {
+        Self { performance, counters: MetricAggregator::new(AggregationConfig::default()) }
+    }
+
+    /// Underlying performance metrics
+    pub fn performance(&self) -> &Arc<PerformanceMetrics> {
+        &self.performance
+    }
+
+    /// Total count for counter `name` across all labels
+    pub fn counter_total(&self, name: &str) -> u64 {
+        self.counters.total(name)
+    }
+
+    /// Rolled-up counts for counter `name`, one entry per label set
+    pub fn counter_rollup(&self, name: &str) -> Vec<LabelCount> {
+        self.counters.rollup(name)
+    }
+
+    fn record_known_counter(&self, name: &str) -> MetricsResult<()> {
+        match name {
+            CLASSIFICATION_RUNS_METRIC => self.performance.record_call(),
+            CLASSIFICATION_CACHE_HIT_METRIC => self.performance.record_cache_hit(),
+            CLASSIFICATION_CACHE_MISS_METRIC => self.performance.record_cache_miss(),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl MetricsCollector for PerformanceMetricsCollector {
+    fn increment_counter(&self, name: &str, labels: &[(&str, &str)]) {
+        let label = label_key(labels);
+        let result =
+            self.counters.increment(name, &label).and_then(|()| self.record_known_counter(name));
+        if let Err(err) = result {
+            tracing::warn!(metric = name, error = %err, "Failed to record counter");
+        }
+    }
+
+    fn record_gauge(&self, name: &str, value: f64, labels: &[(&str, &str)]) {
+        tracing::trace!(metric = name, value, labels = %label_key(labels), "Gauge recorded");
+    }
+
+    fn record_histogram(&self, name: &str, value: f64, labels: &[(&str, &str)]) {
+        if name == CLASSIFICATION_DURATION_METRIC && value.is_finite() && value >= 0.0 {
+            let duration = Duration::from_millis(value as u64);
+            if let Err(err) = self.performance.call.record_fetch_time(duration) {
+                tracing::warn!(metric = name, error = %err, "Failed to record timing");
+            }
+            return;
+        }
+        tracing::trace!(metric = name, value, labels = %label_key(labels), "Histogram recorded");
+    }
+}
+
+/// Stable `key=value` label string for the aggregator
+fn label_key(labels: &[(&str, &str)]) -> String {
+    if labels.is_empty() {
+        return UNLABELLED.to_string();
+    }
+    labels.iter().map(|(key, value)| format!("{key}={value}")).collect::<Vec<_>>().join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering;
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use pulsearc_core::classification::ClassificationCacheConfig;
+    use pulsearc_core::{ClassificationService, Classifier, TimeEntryRepository};
+    use pulsearc_domain::{ActivitySnapshot, Result, TimeEntry, TimeEntryParams};
+    use uuid::Uuid;
+
+    use super::*;
+
+    /// Classifier returning a fixed one-minute entry
+    struct FixedClassifier;
+
+    #[async_trait]
+    impl Classifier for FixedClassifier {
+        async fn classify(&self, snapshots: Vec<ActivitySnapshot>) -> Result<TimeEntry> {
+            let start = snapshots.first().map(|s| s.timestamp).unwrap_or_default();
+            Ok(TimeEntry::new(TimeEntryParams {
+                id: Uuid::now_v7(),
+                start_time: DateTime::from_timestamp(start, 0).unwrap_or_default(),
+                end_time: DateTime::from_timestamp(start + 60, 0),
+                duration_seconds: Some(60),
+                description: "Client work".to_string(),
+                project_id: None,
+                wbs_code: None,
+            }))
+        }
+    }
+
+    /// Repository that accepts and forgets every entry
+    struct NullRepository;
+
+    #[async_trait]
+    impl TimeEntryRepository for NullRepository {
+        async fn save_entry(&self, _entry: TimeEntry) -> Result<()> {
+            Ok(())
+        }
+
+        async fn get_entries(
+            &self,
+            _start: DateTime<Utc>,
+            _end: DateTime<Utc>,
+        ) -> Result<Vec<TimeEntry>> {
+            Ok(Vec::new())
+        }
+
+        async fn update_entry(&self, _entry: TimeEntry) -> Result<()> {
+            Ok(())
+        }
+
+        async fn delete_entry(&self, _id: Uuid) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn block(prefix: &str, start: i64) -> Vec<ActivitySnapshot> {
+        (0..3)
+            .map(|i| ActivitySnapshot {
+                id: format!("{prefix}-{i}"),
+                timestamp: start + i * 30,
+                activity_context_json: "{}".to_string(),
+                detected_activity: "working".to_string(),
+                work_type: Some("modeling".to_string()),
+                activity_category: Some("client_work".to_string()),
+                primary_app: "com.microsoft.Excel".to_string(),
+                processed: false,
+                batch_id: None,
+                created_at: start,
+                processed_at: None,
+                is_idle: false,
+                idle_duration_secs: None,
+                content_hash: Some("model-xlsx".to_string()),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn counters_are_grouped_by_label_set() {
+        let collector = PerformanceMetricsCollector::new(Arc::new(PerformanceMetrics::new()));
+
+        collector.increment_counter("tracking.captures", &[("persisted", "true")]);
+        collector.increment_counter("tracking.captures", &[("persisted", "true")]);
+        collector.increment_counter("tracking.captures", &[("persisted", "false")]);
+        collector.increment_counter("tracking.captures.throttled", &[]);
+
+        assert_eq!(collector.counter_total("tracking.captures"), 3);
+        assert_eq!(
+            collector.counter_rollup("tracking.captures")[0],
+            LabelCount { label: "persisted=true".to_string(), count: 2 }
+        );
+        assert_eq!(
+            collector.counter_rollup("tracking.captures.throttled"),
+            vec![LabelCount { label: UNLABELLED.to_string(), count: 1 }]
+        );
+    }
+
+    #[tokio::test]
+    async fn classification_run_increments_counters() {
+        let performance = Arc::new(PerformanceMetrics::new());
+        let collector = Arc::new(PerformanceMetricsCollector::new(performance.clone()));
+        let service =
+            ClassificationService::new(Arc::new(FixedClassifier), Arc::new(NullRepository))
+                .with_cache(ClassificationCacheConfig::default())
+                .with_metrics(collector.clone());
+
+        service.classify_and_save(block("a", 1_000)).await.unwrap();
+        service.classify_and_save(block("b", 5_000)).await.unwrap();
+
+        assert_eq!(collector.counter_total(CLASSIFICATION_RUNS_METRIC), 2);
+        assert_eq!(
+            collector.counter_rollup(CLASSIFICATION_RUNS_METRIC),
+            vec![LabelCount { label: "outcome=success".to_string(), count: 2 }]
+        );
+        assert_eq!(collector.counter_total(CLASSIFICATION_CACHE_MISS_METRIC), 1);
+        assert_eq!(collector.counter_total(CLASSIFICATION_CACHE_HIT_METRIC), 1);
+
+        assert_eq!(performance.call.total_calls.load(Ordering::SeqCst), 2);
+        assert_eq!(performance.cache.get_hits(), 1);
+        assert_eq!(performance.cache.get_misses(), 1);
+        assert!(performance.p50_fetch_time_ms().is_ok());
+    }
+}
@@ -0,0 +1,87 @@
+//! Correlation ids tying one user action to the calls it triggers
+//!
+//! The API layer opens a scope per Tauri command with [`scope`]. Code running
+//! inside it reads the id with [`current_correlation_id`]: `HttpClient` sends
+//! it as the [`CORRELATION_ID_HEADER`] header, and DB and observability logs
+//! record it as a `correlation_id` field.
+//!
+//! The id lives in a tokio task-local, so it follows the command's future but
+//! not work handed to other tasks. Blocking closures can carry it across
+//! `spawn_blocking` with [`propagate`].
+
+use std::future::Future;
+
+use uuid::Uuid;
+
+/// Header carrying the correlation id on outbound HTTP requests
+pub const CORRELATION_ID_HEADER: &str = "x-correlation-id";
+
+tokio::task_local! {
+    static CORRELATION_ID: String;
+}
+
+/// Generate a fresh correlation id
+pub fn new_correlation_id() -> String {
+    Uuid::now_v7().to_string()
+}
+
+/// Correlation id of the enclosing scope, if any
+pub fn current_correlation_id() -> Option<String> {
+    CORRELATION_ID.try_with(Clone::clone).ok()
+}
+
+/// Run `fut` with `correlation_id` as the current correlation id
+pub async fn scope<F>(correlation_id: String, fut: F) -> F::Output
+where
+    F: Future,
+{
+    CORRELATION_ID.scope(correlation_id, fut).await
+}
+
+/// Wrap a blocking closure so it runs with the caller's correlation id and
+/// tracing span
+///
+/// Task-locals and the current span do not cross `spawn_blocking`; wrapping
+/// the closure before spawning keeps DB logs tied to the command.
+pub fn propagate<F, T>(operation: F) -> impl FnOnce() -> T
+where
+    F: FnOnce() -> T,
+{
+    let correlation_id = current_correlation_id();
+    let span = tracing::Span::current();
+    move || {
+        let _entered = span.enter();
+        match correlation_id {
+            Some(id) => CORRELATION_ID.sync_scope(id, operation),
+            None => operation(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn id_is_visible_only_inside_scope() {
+        assert_eq!(current_correlation_id(), None);
+
+        let id = new_correlation_id();
+        let seen = scope(id.clone(), async { current_correlation_id() }).await;
+
+        assert_eq!(seen, Some(id));
+        assert_eq!(current_correlation_id(), None);
+    }
+
+    #[tokio::test]
+    async fn propagate_carries_id_into_blocking_task() {
+        let id = new_correlation_id();
+
+        let seen = scope(id.clone(), async {
+            tokio::task::spawn_blocking(propagate(current_correlation_id)).await.unwrap()
+        })
+        .await;
+
+        assert_eq!(seen, Some(id));
+    }
+}
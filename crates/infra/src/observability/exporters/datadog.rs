@@ -8,7 +8,12 @@
 //! - **Raw UDP sockets** - No cadence dependency, lightweight implementation
 //! - **Non-blocking** - Set to non-blocking mode to avoid blocking on send
 //! - **Best-effort delivery** - UDP is fire-and-forget, no retry logic
-//! - **Tag support** - DogStatsD tags for dimensional metrics
+//! - **Batched datagrams** - Metrics are buffered and packed into
+//!   newline-separated datagrams, sent when the flush interval elapses or the
+//!   next metric would push the packet past `max_packet_size`. Call
+//!   [`DatadogClient::flush`] on shutdown; dropping the client also flushes.
+//! - **Tag support** - DogStatsD tags for dimensional metrics, plus global
+//!   `env`/`service`/`version`/`host` tags from [`DatadogConfig`]
 //! - **Float support** - Preserves floating-point precision for
 //!   gauges/histograms
 //!
@@ -26,16 +31,108 @@
 use std::fmt::{Display, Write};
 use std::io;
 use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
 
-use crate::observability::metrics::DbStats;
+use crate::observability::metrics::{DbStats, LabelCount};
 use crate::observability::MetricsResult;
 
 /// Default Datadog agent address (DogStatsD default port)
 pub const DEFAULT_DATADOG_ADDR: &str = "127.0.0.1:8125";
 
+/// Default datagram size limit (Ethernet MTU minus IP/UDP headers)
+pub const DEFAULT_MAX_PACKET_SIZE: usize = 1432;
+
+/// Default interval after which buffered metrics are sent
+pub const DEFAULT_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Datadog exporter configuration
+#[derive(Debug, Clone)]
+pub struct DatadogConfig {
+    /// Datadog agent address
+    pub agent_addr: SocketAddr,
+    /// Metric namespace prepended to every name (e.g., "pulsearc"); empty for
+    /// none
+    pub namespace: String,
+    /// `service:` tag value
+    pub service: String,
+    /// `env:` tag value
+    pub env: Option<String>,
+    /// `version:` tag value
+    pub version: Option<String>,
+    /// `host:` tag value
+    pub host: Option<String>,
+    /// Additional `key:value` tags applied to all metrics
+    pub tags: Vec<String>,
+    /// Largest datagram to send; metrics are packed up to this size
+    pub max_packet_size: usize,
+    /// Buffered metrics are sent once the oldest has waited this long
+    /// (`Duration::ZERO` sends every metric immediately)
+    pub flush_interval: Duration,
+}
+
+impl Default for DatadogConfig {
+    fn default() -> Self {
+        Self {
+            agent_addr: SocketAddr::from(([127, 0, 0, 1], 8125)),
+            namespace: "pulsearc".to_string(),
+            service: "pulsearc".to_string(),
+            env: None,
+            version: None,
+            host: None,
+            tags: Vec::new(),
+            max_packet_size: DEFAULT_MAX_PACKET_SIZE,
+            flush_interval: DEFAULT_FLUSH_INTERVAL,
+        }
+    }
+}
+
+impl DatadogConfig {
+    /// Default configuration with global tags read from the standard Datadog
+    /// environment variables (`DD_ENV`, `DD_SERVICE`, `DD_VERSION`,
+    /// `DD_HOSTNAME`)
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Ok(service) = std::env::var("DD_SERVICE") {
+            config.service = service;
+        }
+        config.env = std::env::var("DD_ENV").ok();
+        config.version = std::env::var("DD_VERSION").ok();
+        config.host = std::env::var("DD_HOSTNAME").ok();
+        config
+    }
+
+    /// Global tags in the order they are written
+    fn global_tags(&self) -> Vec<String> {
+        let mut tags = Vec::with_capacity(self.tags.len() + 4);
+        if let Some(env) = &self.env {
+            tags.push(format!("env:{}", env));
+        }
+        tags.push(format!("service:{}", self.service));
+        if let Some(version) = &self.version {
+            tags.push(format!("version:{}", version));
+        }
+        if let Some(host) = &self.host {
+            tags.push(format!("host:{}", host));
+        }
+        tags.extend(self.tags.iter().cloned());
+        tags
+    }
+}
+
+/// Metrics waiting to be sent in the next datagram
+#[derive(Debug)]
+struct PacketBuffer {
+    /// Newline-separated DogStatsD lines
+    payload: String,
+    /// When the first line in `payload` was added
+    opened_at: Option<Instant>,
+}
+
 /// Datadog DogStatsD client using raw UDP sockets
 ///
 /// Thread-safe, non-blocking UDP socket for sending metrics to Datadog agent.
+/// Metrics are buffered and sent in batches; see [`DatadogClient::flush`].
 #[derive(Debug)]
 pub struct DatadogClient {
     /// UDP socket for sending metrics
@@ -46,6 +143,12 @@ pub struct DatadogClient {
     prefix: String,
     /// Default tags applied to all metrics
     default_tags: Vec<String>,
+    /// Largest datagram to send
+    max_packet_size: usize,
+    /// Maximum time a metric waits in the buffer
+    flush_interval: Duration,
+    /// Metrics waiting to be sent
+    buffer: Mutex<PacketBuffer>,
 }
 
 impl DatadogClient {
@@ -68,26 +171,35 @@ impl DatadogClient {
         Self::with_config(prefix, addr)
     }
 
-    /// Create new Datadog client with custom configuration
+    /// Create new Datadog client with custom prefix and agent address
+    ///
+    /// Global tags are read from the environment (see
+    /// [`DatadogConfig::from_env`]).
     pub fn with_config(prefix: &str, agent_addr: SocketAddr) -> io::Result<Self> {
+        Self::from_config(DatadogConfig {
+            agent_addr,
+            namespace: prefix.to_string(),
+            ..DatadogConfig::from_env()
+        })
+    }
+
+    /// Create new Datadog client from a full configuration
+    pub fn from_config(config: DatadogConfig) -> io::Result<Self> {
         // Bind to any available port (OS will assign)
         let socket = UdpSocket::bind("0.0.0.0:0")?;
 
         // Set non-blocking to avoid blocking on send
         socket.set_nonblocking(true)?;
 
-        // Default tags from environment
-        let mut default_tags = Vec::new();
-        if let Ok(env) = std::env::var("DD_ENV") {
-            default_tags.push(format!("env:{}", env));
-        }
-        if let Ok(service) = std::env::var("DD_SERVICE") {
-            default_tags.push(format!("service:{}", service));
-        } else {
-            default_tags.push("service:pulsearc".to_string());
-        }
-
-        Ok(Self { socket, agent_addr, prefix: prefix.to_string(), default_tags })
+        Ok(Self {
+            socket,
+            agent_addr: config.agent_addr,
+            default_tags: config.global_tags(),
+            prefix: config.namespace,
+            max_packet_size: config.max_packet_size,
+            flush_interval: config.flush_interval,
+            buffer: Mutex::new(PacketBuffer { payload: String::new(), opened_at: None }),
+        })
     }
 
     /// Add a default tag applied to all metrics
@@ -287,11 +399,35 @@ impl DatadogClient {
         Ok(())
     }
 
+    /// Send all buffered metrics now
+    ///
+    /// Call before shutdown so the last partial batch is not lost.
+    pub fn flush(&self) -> MetricsResult<()> {
+        let mut buffer = self.buffer.lock().unwrap_or_else(PoisonError::into_inner);
+        self.send_buffer(&mut buffer)
+    }
+
+    /// Send a rolled-up counter as one count per label
+    ///
+    /// Each entry is tagged `<label_key>:<label>`; see
+    /// [`MetricAggregator::rollup`](crate::observability::metrics::MetricAggregator::rollup).
+    pub fn send_rollup(
+        &self,
+        name: &str,
+        label_key: &str,
+        rollup: &[LabelCount],
+    ) -> MetricsResult<()> {
+        for entry in rollup {
+            self.count_with_tags(name, entry.count, &[(label_key, entry.label.as_str())])?;
+        }
+        Ok(())
+    }
+
     // ========================================================================
     // Internal
     // ========================================================================
 
-    /// Send metric with custom tags (generic over Display types)
+    /// Buffer metric with custom tags (generic over Display types)
     fn send_metric<V: Display>(
         &self,
         name: &str,
@@ -299,10 +435,47 @@ impl DatadogClient {
         metric_type: &str,
         custom_tags: &[(&str, &str)],
     ) -> MetricsResult<()> {
-        // Build DogStatsD metric string: <PREFIX>.<NAME>:<VALUE>|<TYPE>|#<TAGS>
-        let full_name = format!("{}.{}", self.prefix, name);
+        let metric = self.format_metric(name, value, metric_type, custom_tags);
+        tracing::trace!(metric = %metric, "Buffered metric for Datadog");
 
-        let mut metric = format!("{}:{}|{}", full_name, value, metric_type);
+        let mut buffer = self.buffer.lock().unwrap_or_else(PoisonError::into_inner);
+
+        // Send what we have if this metric would overflow the packet
+        if !buffer.payload.is_empty()
+            && buffer.payload.len() + 1 + metric.len() > self.max_packet_size
+        {
+            self.send_buffer(&mut buffer)?;
+        }
+
+        if buffer.payload.is_empty() {
+            buffer.opened_at = Some(Instant::now());
+        } else {
+            buffer.payload.push('\n');
+        }
+        buffer.payload.push_str(&metric);
+
+        let interval_elapsed =
+            buffer.opened_at.is_some_and(|opened| opened.elapsed() >= self.flush_interval);
+        if interval_elapsed || buffer.payload.len() >= self.max_packet_size {
+            self.send_buffer(&mut buffer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Build a DogStatsD line: <PREFIX>.<NAME>:<VALUE>|<TYPE>|#<TAGS>
+    fn format_metric<V: Display>(
+        &self,
+        name: &str,
+        value: V,
+        metric_type: &str,
+        custom_tags: &[(&str, &str)],
+    ) -> String {
+        let mut metric = if self.prefix.is_empty() {
+            format!("{}:{}|{}", name, value, metric_type)
+        } else {
+            format!("{}.{}:{}|{}", self.prefix, name, value, metric_type)
+        };
         if !self.default_tags.is_empty() || !custom_tags.is_empty() {
             metric.push_str("|#");
             let mut first = true;
@@ -321,34 +494,45 @@ impl DatadogClient {
                 let _ = write!(&mut metric, "{}:{}", key, val);
             }
         }
+        metric
+    }
+
+    /// Send the buffered datagram and reset the buffer
+    fn send_buffer(&self, buffer: &mut PacketBuffer) -> MetricsResult<()> {
+        if buffer.payload.is_empty() {
+            return Ok(());
+        }
+
+        let payload = std::mem::take(&mut buffer.payload);
+        buffer.opened_at = None;
+        let metrics = payload.lines().count();
 
         // Send via UDP (non-blocking, best-effort)
-        match self.socket.send_to(metric.as_bytes(), self.agent_addr) {
+        match self.socket.send_to(payload.as_bytes(), self.agent_addr) {
             Ok(_) => {
-                tracing::trace!(metric = %full_name, %value, "Sent metric to Datadog");
+                tracing::trace!(metrics, bytes = payload.len(), "Sent metrics to Datadog");
                 Ok(())
             }
             Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
-                // Non-blocking socket would block, drop metric
-                tracing::warn!(
-                    metric = %full_name,
-                    error = %e,
-                    "Dropped metric: send would block"
-                );
+                // Non-blocking socket would block, drop batch
+                tracing::warn!(metrics, error = %e, "Dropped metrics: send would block");
                 Ok(()) // Don't fail on dropped metrics
             }
             Err(e) => {
-                tracing::warn!(
-                    metric = %full_name,
-                    error = %e,
-                    "Failed to send metric to Datadog"
-                );
+                tracing::warn!(metrics, error = %e, "Failed to send metrics to Datadog");
                 Err(crate::observability::MetricsError::SendFailed { source: e })
             }
         }
     }
 }
 
+impl Drop for DatadogClient {
+    fn drop(&mut self) {
+        // Best-effort: deliver the last partial batch
+        let _ = self.flush();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -500,6 +684,89 @@ mod tests {
         let _ = result;
     }
 
+    /// Client that batches until flushed, sending to a local receiver
+    fn client_with_receiver(max_packet_size: usize) -> Option<(DatadogClient, UdpSocket)> {
+        let receiver = UdpSocket::bind("127.0.0.1:0").ok()?;
+        receiver.set_read_timeout(Some(Duration::from_secs(2))).ok()?;
+        let config = DatadogConfig {
+            agent_addr: receiver.local_addr().ok()?,
+            namespace: "app".to_string(),
+            service: "timer".to_string(),
+            env: Some("test".to_string()),
+            version: Some("1.2.3".to_string()),
+            host: Some("build-01".to_string()),
+            tags: vec!["team:core".to_string()],
+            max_packet_size,
+            flush_interval: Duration::from_secs(3600),
+        };
+        let client = DatadogClient::from_config(config).ok()?;
+        Some((client, receiver))
+    }
+
+    fn recv_datagram(receiver: &UdpSocket) -> String {
+        let mut buf = [0u8; 65_536];
+        let len = receiver.recv(&mut buf).expect("datagram received");
+        String::from_utf8_lossy(&buf[..len]).into_owned()
+    }
+
+    #[test]
+    fn test_metrics_are_prefixed_and_tagged() {
+        let Some((client, receiver)) = client_with_receiver(DEFAULT_MAX_PACKET_SIZE) else {
+            return;
+        };
+
+        client.gauge_with_tags("db.pool.size", 4, &[("region", "eu")]).expect("buffered");
+        client.flush().expect("flushed");
+
+        assert_eq!(
+            recv_datagram(&receiver),
+            "app.db.pool.size:4|g|#env:test,service:timer,version:1.2.3,host:build-01,team:core,region:eu"
+        );
+    }
+
+    #[test]
+    fn test_metrics_within_flush_window_share_a_datagram() {
+        let Some((client, receiver)) = client_with_receiver(DEFAULT_MAX_PACKET_SIZE) else {
+            return;
+        };
+        receiver.set_nonblocking(true).expect("nonblocking receiver");
+
+        client.increment("requests").expect("buffered");
+        client.timing("latency", 12).expect("buffered");
+        client.gauge_f64("ratio", 0.5).expect("buffered");
+
+        let mut buf = [0u8; 64];
+        assert!(receiver.recv(&mut buf).is_err(), "nothing is sent before the flush");
+
+        client.flush().expect("flushed");
+        receiver.set_nonblocking(false).expect("blocking receiver");
+        let datagram = recv_datagram(&receiver);
+        let names: Vec<&str> = datagram.lines().filter_map(|line| line.split(':').next()).collect();
+        assert_eq!(names, vec!["app.requests", "app.latency", "app.ratio"]);
+    }
+
+    #[test]
+    fn test_batches_split_before_exceeding_packet_size() {
+        let max_packet_size = 200;
+        let Some((client, receiver)) = client_with_receiver(max_packet_size) else {
+            return;
+        };
+
+        for i in 0..6 {
+            client.count("events", i).expect("buffered");
+        }
+        client.flush().expect("flushed");
+
+        let mut lines = 0;
+        while lines < 6 {
+            let datagram = recv_datagram(&receiver);
+            assert!(datagram.len() <= max_packet_size, "datagram too large: {}", datagram.len());
+            assert!(datagram.lines().count() > 1, "metrics should be packed together");
+            lines += datagram.lines().count();
+        }
+        assert_eq!(lines, 6);
+    }
+
     #[test]
     fn test_send_db_stats_with_missing_percentiles() {
         let client = match DatadogClient::new() {
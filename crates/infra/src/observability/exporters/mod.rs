@@ -5,4 +5,4 @@
 pub mod datadog;
 
 // Re-export exporter types for convenience
-pub use datadog::{DatadogClient, DEFAULT_DATADOG_ADDR};
+pub use datadog::{DatadogClient, DatadogConfig, DEFAULT_DATADOG_ADDR};
@@ -0,0 +1,306 @@
+//! Label roll-up and sampling for high-cardinality counters
+//!
+//! Per-snapshot metrics labelled by app (or similar open-ended values) can
+//! produce an unbounded number of series. This module aggregates counters per
+//! label and rolls the long tail up before export.
+//!
+//! ## Design
+//! - **Top-N roll-up** - Snapshots keep the `top_n` labels by count and fold
+//!   the rest into an [`OTHER_LABEL`] bucket, so totals are preserved
+//! - **Cardinality eviction** - At most `max_tracked_labels` labels are held
+//!   per metric; when a new label arrives, the smallest one is evicted into
+//!   "other" (logs warning, no error)
+//! - **Counter sampling** - With `sample_every = N`, only every Nth record is
+//!   kept and weighted by N, trading precision for less lock traffic
+//! - **Per-metric configuration** - Policies override the default by metric
+//!   name
+//! - **Poison recovery** - Mutex locks recover from poisoning
+
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+use crate::observability::MetricsResult;
+
+/// Label that collects rolled-up and evicted series
+pub const OTHER_LABEL: &str = "other";
+
+/// Aggregation policy for a single metric
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AggregationConfig {
+    /// Number of distinct labels reported before folding into "other"
+    pub top_n: usize,
+    /// Maximum distinct labels tracked in memory (excluding "other")
+    pub max_tracked_labels: usize,
+    /// Keep one record in every `sample_every` (1 = no sampling)
+    pub sample_every: u64,
+}
+
+impl Default for AggregationConfig {
+    fn default() -> Self {
+        Self { top_n: 20, max_tracked_labels: 1_000, sample_every: 1 }
+    }
+}
+
+/// Aggregated count for one label
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelCount {
+    /// Label value (or [`OTHER_LABEL`])
+    pub label: String,
+    /// Accumulated count
+    pub count: u64,
+}
+
+/// Accumulated counts for one metric
+#[derive(Debug, Default)]
+struct MetricSeries {
+    /// Count per tracked label
+    counts: HashMap<String, u64>,
+    /// Count folded in by eviction
+    other: u64,
+    /// Records seen, used to pick sampled records
+    records: u64,
+}
+
+/// Counter aggregator with per-metric roll-up and sampling
+///
+/// All record methods return `MetricsResult<()>` for future extensibility
+/// (hard cardinality limits), but currently always succeed.
+#[derive(Debug, Default)]
+pub struct MetricAggregator {
+    /// Policy for metrics without an override
+    default_config: AggregationConfig,
+    /// Per-metric policy overrides
+    overrides: HashMap<String, AggregationConfig>,
+    /// Series per metric name
+    series: Mutex<HashMap<String, MetricSeries>>,
+}
+
+impl MetricAggregator {
+    /// Create an aggregator applying `default_config` to every metric
+    pub fn new(default_config: AggregationConfig) -> Self {
+        Self { default_config, overrides: HashMap::new(), series: Mutex::new(HashMap::new()) }
+    }
+
+    /// Override the policy for one metric
+    pub fn with_metric_config(mut self, metric: &str, config: AggregationConfig) -> Self {
+        self.overrides.insert(metric.to_string(), config);
+        self
+    }
+
+    /// Policy in effect for `metric`
+    pub fn config_for(&self, metric: &str) -> AggregationConfig {
+        self.overrides.get(metric).copied().unwrap_or(self.default_config)
+    }
+
+    /// Add `value` to `metric` under `label`
+    ///
+    /// Currently always succeeds. When the metric is sampled, skipped records
+    /// return `Ok(())` without touching the counts.
+    pub fn record_count(&self, metric: &str, label: &str, value: u64) -> MetricsResult<()> {
+        let config = self.config_for(metric);
+        let sample_every = config.sample_every.max(1);
+
+        let mut series = self.lock_series("recording");
+        let entry = series.entry(metric.to_string()).or_default();
+
+        entry.records += 1;
+        if !(entry.records - 1).is_multiple_of(sample_every) {
+            return Ok(());
+        }
+        let weighted = value.saturating_mul(sample_every);
+
+        if label == OTHER_LABEL {
+            entry.other = entry.other.saturating_add(weighted);
+            return Ok(());
+        }
+
+        if let Some(count) = entry.counts.get_mut(label) {
+            *count = count.saturating_add(weighted);
+            return Ok(());
+        }
+
+        if entry.counts.len() >= config.max_tracked_labels {
+            evict_smallest(metric, entry, config.max_tracked_labels);
+        }
+        if config.max_tracked_labels == 0 {
+            entry.other = entry.other.saturating_add(weighted);
+        } else {
+            entry.counts.insert(label.to_string(), weighted);
+        }
+
+        Ok(())
+    }
+
+    /// Increment `metric` under `label` by 1
+    pub fn increment(&self, metric: &str, label: &str) -> MetricsResult<()> {
+        self.record_count(metric, label, 1)
+    }
+
+    /// Rolled-up counts for `metric`
+    ///
+    /// Returns the `top_n` labels by count (ties broken by label), followed by
+    /// an [`OTHER_LABEL`] entry holding everything else when non-zero.
+    pub fn rollup(&self, metric: &str) -> Vec<LabelCount> {
+        let config = self.config_for(metric);
+        let series = self.lock_series("roll-up");
+        let Some(entry) = series.get(metric) else {
+            return Vec::new();
+        };
+
+        let mut ranked: Vec<(&String, &u64)> = entry.counts.iter().collect();
+        ranked.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+
+        let mut rolled: Vec<LabelCount> = ranked
+            .iter()
+            .take(config.top_n)
+            .map(|(label, count)| LabelCount { label: (*label).clone(), count: **count })
+            .collect();
+
+        let other = ranked
+            .iter()
+            .skip(config.top_n)
+            .fold(entry.other, |acc, (_, count)| acc.saturating_add(**count));
+        if other > 0 {
+            rolled.push(LabelCount { label: OTHER_LABEL.to_string(), count: other });
+        }
+
+        rolled
+    }
+
+    /// Total count for `metric` across all labels
+    pub fn total(&self, metric: &str) -> u64 {
+        let series = self.lock_series("total calculation");
+        series
+            .get(metric)
+            .map(|entry| entry.counts.values().fold(entry.other, |acc, c| acc.saturating_add(*c)))
+            .unwrap_or(0)
+    }
+
+    /// Clear all series (e.g. after exporting a window)
+    pub fn reset(&self) {
+        let mut series = self.lock_series("reset");
+        series.clear();
+    }
+
+    /// Lock the series map, recovering from poisoning
+    fn lock_series(&self, operation: &str) -> MutexGuard<'_, HashMap<String, MetricSeries>> {
+        match self.series.lock() {
+            Ok(guard) => guard,
+            Err(poison_err) => {
+                tracing::warn!(
+                    metric = "MetricAggregator::series",
+                    operation,
+                    "Mutex poisoned during aggregation, recovering data"
+                );
+                poison_err.into_inner()
+            }
+        }
+    }
+}
+
+/// Fold the smallest tracked label into "other" to make room for a new one
+fn evict_smallest(metric: &str, entry: &mut MetricSeries, limit: usize) {
+    let smallest = entry
+        .counts
+        .iter()
+        .min_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+        .map(|(label, _)| label.clone());
+
+    if let Some(label) = smallest {
+        if let Some(count) = entry.counts.remove(&label) {
+            entry.other = entry.other.saturating_add(count);
+        }
+        tracing::warn!(
+            metric,
+            limit,
+            evicted = %label,
+            "Label cardinality limit reached, folding label into 'other'"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(top_n: usize, max_tracked_labels: usize, sample_every: u64) -> AggregationConfig {
+        AggregationConfig { top_n, max_tracked_labels, sample_every }
+    }
+
+    #[test]
+    fn test_labels_beyond_top_n_fold_into_other() {
+        let aggregator = MetricAggregator::new(config(2, 100, 1));
+
+        aggregator.record_count("snapshots", "Safari", 10).unwrap();
+        aggregator.record_count("snapshots", "Xcode", 7).unwrap();
+        aggregator.record_count("snapshots", "Mail", 3).unwrap();
+        aggregator.record_count("snapshots", "Notes", 2).unwrap();
+
+        let rollup = aggregator.rollup("snapshots");
+        assert_eq!(
+            rollup,
+            vec![
+                LabelCount { label: "Safari".to_string(), count: 10 },
+                LabelCount { label: "Xcode".to_string(), count: 7 },
+                LabelCount { label: OTHER_LABEL.to_string(), count: 5 },
+            ]
+        );
+
+        let rolled_total: u64 = rollup.iter().map(|entry| entry.count).sum();
+        assert_eq!(rolled_total, 22);
+        assert_eq!(aggregator.total("snapshots"), 22);
+    }
+
+    #[test]
+    fn test_no_other_bucket_within_top_n() {
+        let aggregator = MetricAggregator::new(config(5, 100, 1));
+
+        aggregator.increment("snapshots", "Safari").unwrap();
+        aggregator.increment("snapshots", "Xcode").unwrap();
+
+        let rollup = aggregator.rollup("snapshots");
+        assert_eq!(rollup.len(), 2);
+        assert!(rollup.iter().all(|entry| entry.label != OTHER_LABEL));
+    }
+
+    #[test]
+    fn test_cardinality_eviction_preserves_total() {
+        let aggregator = MetricAggregator::new(config(10, 3, 1));
+
+        for (i, app) in ["a", "b", "c", "d", "e"].iter().enumerate() {
+            aggregator.record_count("snapshots", app, (i as u64 + 1) * 10).unwrap();
+        }
+
+        // Only three labels are tracked; the two smallest were evicted
+        let rollup = aggregator.rollup("snapshots");
+        let labels: Vec<&str> = rollup.iter().map(|entry| entry.label.as_str()).collect();
+        assert_eq!(labels, vec!["e", "d", "c", OTHER_LABEL]);
+        assert_eq!(rollup[3].count, 30);
+        assert_eq!(aggregator.total("snapshots"), 150);
+    }
+
+    #[test]
+    fn test_sampling_weights_kept_records() {
+        let aggregator = MetricAggregator::new(AggregationConfig::default())
+            .with_metric_config("events", config(20, 100, 4));
+
+        for _ in 0..8 {
+            aggregator.increment("events", "Safari").unwrap();
+        }
+
+        // 2 of 8 records kept, each weighted by 4
+        assert_eq!(aggregator.total("events"), 8);
+        assert_eq!(aggregator.config_for("events").sample_every, 4);
+        assert_eq!(aggregator.config_for("snapshots").sample_every, 1);
+    }
+
+    #[test]
+    fn test_reset_clears_series() {
+        let aggregator = MetricAggregator::new(AggregationConfig::default());
+        aggregator.increment("snapshots", "Safari").unwrap();
+
+        aggregator.reset();
+        assert!(aggregator.rollup("snapshots").is_empty());
+        assert_eq!(aggregator.total("snapshots"), 0);
+    }
+}
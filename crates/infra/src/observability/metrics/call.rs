@@ -6,15 +6,16 @@
 //!
 //! ## Design
 //! - **VecDeque ring buffer** for O(1) eviction (not Vec with remove(0))
-//! - **Poison-safe locking** with explicit match pattern (no .expect())
+//! - **Poison-safe locking** via [`PoisonSafeMutex`] (no .expect())
 //! - **SeqCst ordering** for atomics used in derived metrics
 //! - **MetricsResult returns** for future extensibility (currently always Ok)
 
 use std::collections::VecDeque;
 use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
-use std::sync::Mutex;
 use std::time::{Duration, Instant};
 
+use pulsearc_common::observability::PoisonSafeMutex;
+
 use super::DEFAULT_RING_BUFFER_CAPACITY;
 use crate::observability::{MetricsError, MetricsResult};
 
@@ -31,10 +32,10 @@ pub struct CallMetrics {
     /// Whether the first call has been made
     pub has_first_call: AtomicBool,
     /// Start time for calculating calls per minute
-    pub start_time: Mutex<Option<Instant>>,
+    pub start_time: PoisonSafeMutex<Option<Instant>>,
     /// Individual fetch times for percentile calculations (ring buffer, max
     /// 1000)
-    pub fetch_times: Mutex<VecDeque<u64>>,
+    pub fetch_times: PoisonSafeMutex<VecDeque<u64>>,
 }
 
 impl Default for CallMetrics {
@@ -50,8 +51,11 @@ impl CallMetrics {
             total_calls: AtomicUsize::new(0),
             first_call_time_ms: AtomicU64::new(0),
             has_first_call: AtomicBool::new(false),
-            start_time: Mutex::new(Some(Instant::now())),
-            fetch_times: Mutex::new(VecDeque::with_capacity(DEFAULT_RING_BUFFER_CAPACITY)),
+            start_time: PoisonSafeMutex::new("CallMetrics::start_time", Some(Instant::now())),
+            fetch_times: PoisonSafeMutex::new(
+                "CallMetrics::fetch_times",
+                VecDeque::with_capacity(DEFAULT_RING_BUFFER_CAPACITY),
+            ),
         }
     }
 
@@ -90,17 +94,7 @@ impl CallMetrics {
             self.record_first_call_time(ms)?;
         }
 
-        // Poison-safe locking: explicit match, no .expect()
-        let mut times = match self.fetch_times.lock() {
-            Ok(guard) => guard,
-            Err(poison_err) => {
-                tracing::warn!(
-                    metric = "CallMetrics::fetch_times",
-                    "Mutex poisoned during fetch_time recording, recovering data"
-                );
-                poison_err.into_inner()
-            }
-        };
+        let mut times = self.fetch_times.lock();
 
         // Ring buffer: O(1) push_back + pop_front
         times.push_back(ms);
@@ -123,16 +117,7 @@ impl CallMetrics {
         // SeqCst for consistent snapshot with record_call
         let total = self.total_calls.load(Ordering::SeqCst);
 
-        let start_time_opt = match self.start_time.lock() {
-            Ok(guard) => guard,
-            Err(poison_err) => {
-                tracing::warn!(
-                    metric = "CallMetrics::start_time",
-                    "Mutex poisoned during calls_per_minute read, recovering"
-                );
-                poison_err.into_inner()
-            }
-        };
+        let start_time_opt = self.start_time.lock();
 
         if let Some(start) = *start_time_opt {
             let elapsed = start.elapsed().as_secs_f64();
@@ -182,18 +167,7 @@ impl CallMetrics {
         percentile: f64,
         metric_name: &'static str,
     ) -> MetricsResult<u64> {
-        // Poison-safe locking
-        let times = match self.fetch_times.lock() {
-            Ok(guard) => guard,
-            Err(poison_err) => {
-                tracing::warn!(
-                    metric = "CallMetrics::fetch_times",
-                    percentile = percentile,
-                    "Mutex poisoned during percentile read, recovering"
-                );
-                poison_err.into_inner()
-            }
-        };
+        let times = self.fetch_times.lock();
 
         if times.is_empty() {
             return Err(MetricsError::EmptyData { metric: metric_name });
@@ -257,10 +231,7 @@ mod tests {
         metrics.record_fetch_time(Duration::from_millis(200)).unwrap();
         metrics.record_fetch_time(Duration::from_millis(150)).unwrap();
 
-        let times = match metrics.fetch_times.lock() {
-            Ok(guard) => guard,
-            Err(e) => e.into_inner(),
-        };
+        let times = metrics.fetch_times.lock();
         assert_eq!(times.len(), 3);
         assert_eq!(times[0], 100);
         assert_eq!(times[1], 200);
@@ -314,10 +285,7 @@ mod tests {
         }
 
         // Should only keep last N (ring buffer with FIFO eviction)
-        let times = match metrics.fetch_times.lock() {
-            Ok(guard) => guard,
-            Err(e) => e.into_inner(),
-        };
+        let times = metrics.fetch_times.lock();
         assert_eq!(times.len(), DEFAULT_RING_BUFFER_CAPACITY);
         // First entry should be 100 (0-99 were evicted via pop_front)
         assert_eq!(times[0], 100);
@@ -362,7 +330,7 @@ mod tests {
         // Poison the mutex by panicking during lock
         let metrics_clone = Arc::clone(&metrics);
         let _ = thread::spawn(move || {
-            let _guard = metrics_clone.fetch_times.lock().unwrap();
+            let _guard = metrics_clone.fetch_times.lock();
             panic!("intentional poison");
         })
         .join();
@@ -372,10 +340,7 @@ mod tests {
         assert!(result.is_ok(), "Should recover from poison");
 
         // Verify data was recorded despite poison
-        let times = match metrics.fetch_times.lock() {
-            Ok(guard) => guard,
-            Err(e) => e.into_inner(),
-        };
+        let times = metrics.fetch_times.lock();
         assert_eq!(times.len(), 1);
         assert_eq!(times[0], 100);
     }
@@ -394,7 +359,7 @@ mod tests {
         // Poison the mutex
         let metrics_clone = Arc::clone(&metrics);
         let _ = thread::spawn(move || {
-            let _guard = metrics_clone.fetch_times.lock().unwrap();
+            let _guard = metrics_clone.fetch_times.lock();
             panic!("intentional poison");
         })
         .join();
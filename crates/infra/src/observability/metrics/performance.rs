@@ -34,7 +34,10 @@
 
 use std::time::Duration;
 
-use super::{CacheMetrics, CallMetrics, DbMetrics, FetchMetrics, ObserverMetrics};
+use super::{
+    CacheMetrics, CallMetrics, DbMetrics, FetchMetrics, ObserverMetrics, RetryMetrics,
+    SchedulerMetrics,
+};
 use crate::observability::MetricsResult;
 
 /// Performance metrics for tracking infrastructure operations
@@ -54,6 +57,10 @@ pub struct PerformanceMetrics {
     pub fetch: FetchMetrics,
     /// macOS Accessibility API observer metrics (notifications, registration)
     pub observer: ObserverMetrics,
+    /// Retry metrics per operation (attempts histogram, exhausted retries)
+    pub retry: RetryMetrics,
+    /// Scheduler overlap and catch-up metrics
+    pub scheduler: SchedulerMetrics,
 }
 
 impl Default for PerformanceMetrics {
@@ -73,6 +80,8 @@ impl PerformanceMetrics {
             db: DbMetrics::new(),
             fetch: FetchMetrics::new(),
             observer: ObserverMetrics::new(),
+            retry: RetryMetrics::new(),
+            scheduler: SchedulerMetrics::new(),
         }
     }
 
@@ -252,6 +261,51 @@ impl PerformanceMetrics {
     pub fn record_observer_failure(&self, error: &str) -> MetricsResult<()> {
         self.observer.record_failure(error)
     }
+
+    // ========================================================================
+    // Convenience Methods - Retry Metrics
+    // ========================================================================
+
+    /// Record an operation that succeeded on attempt `attempts` (1 = no
+    /// retry)
+    pub fn record_retry_success(&self, operation: &str, attempts: usize) -> MetricsResult<()> {
+        self.retry.record_success(operation, attempts)
+    }
+
+    /// Record an operation that failed after exhausting its retries
+    pub fn record_retry_exhausted(&self, operation: &str) -> MetricsResult<()> {
+        self.retry.record_exhausted(operation)
+    }
+
+    /// Total operations that exhausted their retries
+    pub fn retry_exhausted_count(&self) -> u64 {
+        self.retry.total_exhausted()
+    }
+
+    // ========================================================================
+    // Convenience Methods - Scheduler Metrics
+    // ========================================================================
+
+    /// Record a completed scheduler job run
+    pub fn record_scheduler_run(&self, duration: Duration) -> MetricsResult<()> {
+        self.scheduler.record_run(duration)
+    }
+
+    /// Record a scheduler trigger dropped because the scheduler was at
+    /// capacity
+    pub fn record_scheduler_run_skipped(&self) -> MetricsResult<()> {
+        self.scheduler.record_run_skipped()
+    }
+
+    /// Record a scheduler trigger queued behind running jobs
+    pub fn record_scheduler_run_queued(&self) -> MetricsResult<()> {
+        self.scheduler.record_run_queued()
+    }
+
+    /// Record entries forwarded by a scheduler's start-up catch-up
+    pub fn record_scheduler_catch_up(&self, count: usize) -> MetricsResult<()> {
+        self.scheduler.record_catch_up(count)
+    }
 }
 
 #[cfg(test)]
@@ -377,6 +431,19 @@ mod tests {
         assert_eq!(observer_stats.failures, 1);
     }
 
+    #[test]
+    fn test_retry_metrics_delegation() {
+        let metrics = PerformanceMetrics::new();
+
+        metrics.record_retry_success("neon.submit_time_entry", 2).unwrap();
+        metrics.record_retry_exhausted("neon.submit_time_entry").unwrap();
+
+        let stats = metrics.retry.stats("neon.submit_time_entry").unwrap();
+        assert_eq!(stats.attempts_histogram[1], 1);
+        assert_eq!(stats.exhausted, 1);
+        assert_eq!(metrics.retry_exhausted_count(), 1);
+    }
+
     #[test]
     fn test_integrated_workflow() {
         let metrics = PerformanceMetrics::new();
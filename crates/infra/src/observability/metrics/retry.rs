@@ -0,0 +1,158 @@
+//! Retry metrics for tracking how many attempts sync operations take
+//!
+//! Success/failure counts hide a degrading backend when requests only
+//! succeed after retries. This module records, per operation, a histogram of
+//! attempts taken by successful operations and a counter of operations that
+//! ran out of retries.
+//!
+//! ## Design
+//! - **Per-operation series** keyed by a caller-chosen name (e.g.
+//!   `neon.submit_time_entry`)
+//! - **Bounded cardinality** - at most [`MAX_TRACKED_OPERATIONS`] names are
+//!   tracked; later ones are folded into [`OTHER_LABEL`]
+//! - **Fixed buckets** - bucket `i` counts successes on attempt `i + 1`; the
+//!   last bucket also counts anything beyond it
+//! - **Poison-safe locking** via [`PoisonSafeMutex`] (no .expect())
+//! - **MetricsResult returns** for future extensibility (currently always Ok)
+
+use std::collections::BTreeMap;
+
+use pulsearc_common::observability::PoisonSafeMutex;
+
+use super::OTHER_LABEL;
+use crate::observability::MetricsResult;
+
+/// Number of attempt-count buckets; the last one is open-ended
+pub const ATTEMPT_BUCKETS: usize = 10;
+
+/// Distinct operation names tracked before folding into [`OTHER_LABEL`]
+pub const MAX_TRACKED_OPERATIONS: usize = 64;
+
+/// Retry statistics for one operation
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RetryStats {
+    /// Successful operations by attempt count (`[i]` = succeeded on attempt
+    /// `i + 1`, last bucket = that many attempts or more)
+    pub attempts_histogram: [u64; ATTEMPT_BUCKETS],
+    /// Operations that failed after using every allowed attempt
+    pub exhausted: u64,
+}
+
+impl RetryStats {
+    /// Number of successful operations
+    pub fn successes(&self) -> u64 {
+        self.attempts_histogram.iter().sum()
+    }
+
+    /// Successful operations that needed at least one retry
+    pub fn retried_successes(&self) -> u64 {
+        self.successes() - self.attempts_histogram[0]
+    }
+}
+
+/// Metrics for tracking retry behaviour per operation
+///
+/// All record methods return `MetricsResult<()>` for future extensibility
+/// (quotas, validation), but currently always succeed.
+#[derive(Debug)]
+pub struct RetryMetrics {
+    operations: PoisonSafeMutex<BTreeMap<String, RetryStats>>,
+}
+
+impl Default for RetryMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RetryMetrics {
+    /// Create new RetryMetrics instance
+    pub fn new() -> Self {
+        Self { operations: PoisonSafeMutex::new("RetryMetrics::operations", BTreeMap::new()) }
+    }
+
+    /// Record an operation that succeeded on attempt `attempts` (1 = no
+    /// retry)
+    ///
+    /// Currently always succeeds. Future versions may enforce quotas.
+    pub fn record_success(&self, operation: &str, attempts: usize) -> MetricsResult<()> {
+        let bucket = attempts.clamp(1, ATTEMPT_BUCKETS) - 1;
+        self.update(operation, |stats| {
+            stats.attempts_histogram[bucket] = stats.attempts_histogram[bucket].saturating_add(1);
+        });
+        Ok(())
+    }
+
+    /// Record an operation that exhausted its retries
+    ///
+    /// Currently always succeeds. Future versions may enforce quotas.
+    pub fn record_exhausted(&self, operation: &str) -> MetricsResult<()> {
+        self.update(operation, |stats| stats.exhausted = stats.exhausted.saturating_add(1));
+        Ok(())
+    }
+
+    /// Statistics for `operation`, if anything was recorded for it
+    pub fn stats(&self, operation: &str) -> Option<RetryStats> {
+        self.operations.lock().get(operation).cloned()
+    }
+
+    /// Statistics for every tracked operation, ordered by name
+    pub fn snapshot(&self) -> Vec<(String, RetryStats)> {
+        self.operations.lock().iter().map(|(name, stats)| (name.clone(), stats.clone())).collect()
+    }
+
+    /// Total operations that exhausted their retries, across operations
+    pub fn total_exhausted(&self) -> u64 {
+        self.operations.lock().values().map(|stats| stats.exhausted).sum()
+    }
+
+    fn update(&self, operation: &str, apply: impl FnOnce(&mut RetryStats)) {
+        let mut operations = self.operations.lock();
+        let key = if operations.contains_key(operation) || operations.len() < MAX_TRACKED_OPERATIONS
+        {
+            operation
+        } else {
+            OTHER_LABEL
+        };
+        apply(operations.entry(key.to_string()).or_default());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_buckets_by_attempt() {
+        let metrics = RetryMetrics::new();
+
+        metrics.record_success("neon.submit_time_entry", 1).unwrap();
+        metrics.record_success("neon.submit_time_entry", 3).unwrap();
+        metrics.record_success("neon.submit_time_entry", ATTEMPT_BUCKETS + 5).unwrap();
+
+        let stats = metrics.stats("neon.submit_time_entry").unwrap();
+        assert_eq!(stats.attempts_histogram[0], 1);
+        assert_eq!(stats.attempts_histogram[2], 1);
+        assert_eq!(stats.attempts_histogram[ATTEMPT_BUCKETS - 1], 1, "overflow bucket");
+        assert_eq!(stats.successes(), 3);
+        assert_eq!(stats.retried_successes(), 2);
+        assert!(metrics.stats("api.POST /segments").is_none());
+    }
+
+    #[test]
+    fn test_operations_beyond_limit_fold_into_other() {
+        let metrics = RetryMetrics::new();
+
+        for i in 0..MAX_TRACKED_OPERATIONS {
+            metrics.record_success(&format!("op-{i}"), 1).unwrap();
+        }
+        metrics.record_exhausted("one-too-many").unwrap();
+        metrics.record_exhausted("op-0").unwrap();
+
+        assert!(metrics.stats("one-too-many").is_none());
+        assert_eq!(metrics.stats(OTHER_LABEL).unwrap().exhausted, 1);
+        assert_eq!(metrics.stats("op-0").unwrap().exhausted, 1);
+        assert_eq!(metrics.total_exhausted(), 2);
+        assert_eq!(metrics.snapshot().len(), MAX_TRACKED_OPERATIONS + 1);
+    }
+}
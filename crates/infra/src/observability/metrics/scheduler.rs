@@ -0,0 +1,146 @@
+//! Scheduler run metrics for tracking job runs, overlapping triggers and
+//! catch-up runs
+//!
+//! This module counts completed scheduler job runs and their total duration,
+//! scheduler triggers that could not start a run straight away because the
+//! scheduler was already at its in-flight limit, and entries forwarded on
+//! start-up because their posting window was missed.
+//!
+//! ## Design
+//! - **Relaxed ordering** - independent counters, no derived metrics
+//! - **No locking needed** - simple atomic counters
+//! - **MetricsResult returns** for future extensibility (currently always Ok)
+
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::Duration;
+
+use crate::observability::MetricsResult;
+
+/// Metrics for tracking scheduler job runs, triggers that hit the in-flight
+/// limit and catch-up forwarding
+///
+/// All record methods return `MetricsResult<()>` for future extensibility
+/// (quotas, validation), but currently always succeed.
+#[derive(Debug, Default)]
+pub struct SchedulerMetrics {
+    /// Job runs that completed (successfully or not)
+    pub runs_completed: AtomicUsize,
+    /// Total duration of completed job runs in milliseconds
+    pub run_time_ms: AtomicU64,
+    /// Triggers dropped because the scheduler was at capacity
+    pub runs_skipped: AtomicUsize,
+    /// Triggers queued to run once a slot frees up
+    pub runs_queued: AtomicUsize,
+    /// Entries forwarded on start-up after their posting window was missed
+    pub catch_up_entries: AtomicUsize,
+}
+
+impl SchedulerMetrics {
+    /// Create new SchedulerMetrics instance
+    pub fn new() -> Self {
+        Self {
+            runs_completed: AtomicUsize::new(0),
+            run_time_ms: AtomicU64::new(0),
+            runs_skipped: AtomicUsize::new(0),
+            runs_queued: AtomicUsize::new(0),
+            catch_up_entries: AtomicUsize::new(0),
+        }
+    }
+
+    /// Record a completed job run that took `duration`
+    ///
+    /// Currently always succeeds. Future versions may enforce quotas.
+    pub fn record_run(&self, duration: Duration) -> MetricsResult<()> {
+        // Relaxed OK: independent counters; readers don't pair them atomically
+        self.runs_completed.fetch_add(1, Ordering::Relaxed);
+        let millis = u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
+        self.run_time_ms.fetch_add(millis, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Record a trigger dropped at capacity
+    ///
+    /// Currently always succeeds. Future versions may enforce quotas.
+    pub fn record_run_skipped(&self) -> MetricsResult<()> {
+        // Relaxed OK: independent counter
+        self.runs_skipped.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Record a trigger queued behind a running job
+    ///
+    /// Currently always succeeds. Future versions may enforce quotas.
+    pub fn record_run_queued(&self) -> MetricsResult<()> {
+        // Relaxed OK: independent counter
+        self.runs_queued.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Record `count` entries forwarded by a start-up catch-up
+    ///
+    /// Currently always succeeds. Future versions may enforce quotas.
+    pub fn record_catch_up(&self, count: usize) -> MetricsResult<()> {
+        // Relaxed OK: independent counter
+        self.catch_up_entries.fetch_add(count, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Get the number of completed job runs
+    pub fn get_runs_completed(&self) -> usize {
+        self.runs_completed.load(Ordering::Relaxed)
+    }
+
+    /// Get the total duration of completed job runs
+    pub fn get_total_run_time(&self) -> Duration {
+        Duration::from_millis(self.run_time_ms.load(Ordering::Relaxed))
+    }
+
+    /// Get the number of skipped runs
+    pub fn get_runs_skipped(&self) -> usize {
+        self.runs_skipped.load(Ordering::Relaxed)
+    }
+
+    /// Get the number of queued runs
+    pub fn get_runs_queued(&self) -> usize {
+        self.runs_queued.load(Ordering::Relaxed)
+    }
+
+    /// Get the number of entries forwarded by catch-up runs
+    pub fn get_catch_up_entries(&self) -> usize {
+        self.catch_up_entries.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counters_start_at_zero_and_increment() {
+        let metrics = SchedulerMetrics::new();
+        assert_eq!(metrics.get_runs_skipped(), 0);
+        assert_eq!(metrics.get_runs_queued(), 0);
+        assert_eq!(metrics.get_catch_up_entries(), 0);
+
+        metrics.record_run_skipped().unwrap();
+        metrics.record_run_skipped().unwrap();
+        metrics.record_run_queued().unwrap();
+        metrics.record_catch_up(3).unwrap();
+
+        assert_eq!(metrics.get_runs_skipped(), 2);
+        assert_eq!(metrics.get_runs_queued(), 1);
+        assert_eq!(metrics.get_catch_up_entries(), 3);
+    }
+
+    #[test]
+    fn test_record_run_accumulates_duration() {
+        let metrics = SchedulerMetrics::new();
+        assert_eq!(metrics.get_runs_completed(), 0);
+
+        metrics.record_run(Duration::from_millis(120)).unwrap();
+        metrics.record_run(Duration::from_millis(30)).unwrap();
+
+        assert_eq!(metrics.get_runs_completed(), 2);
+        assert_eq!(metrics.get_total_run_time(), Duration::from_millis(150));
+    }
+}
@@ -5,14 +5,16 @@
 //! - Percentile calculations (P50/P95/P99)
 //! - Datadog DogStatsD integration
 //! - Poison-safe mutex handling
-//! - Label cardinality enforcement
+//! - Label cardinality enforcement (top-N roll-up and sampling via
+//!   [`metrics::MetricAggregator`])
 //!
 //! ## Design Principles
 //!
-//! 1. **Poison Recovery**: All mutex locks use explicit poison recovery
-//!    pattern: ```rust let guard = match mutex.lock() { Ok(guard) => guard,
-//!    Err(poison_err) => { tracing::warn!("Mutex poisoned, recovering");
-//!    poison_err.into_inner() } }; ```
+//! 1. **Poison Recovery**: Mutex locks recover from poisoning instead of
+//!    panicking. New code should use
+//!    [`PoisonSafeMutex`](pulsearc_common::observability::PoisonSafeMutex),
+//!    whose `lock()` logs a warning and returns the recovered guard; older
+//!    sites still match on `lock()` and call `into_inner()` by hand.
 //!
 //! 2. **Future-Proof Returns**: All record methods return `MetricsResult<()>`
 //!    for future extensibility (cardinality limits, quotas, validation), but
@@ -37,6 +39,8 @@
 //! }
 //! ```
 
+pub mod collector;
+pub mod correlation;
 pub mod exporters;
 pub mod metrics;
 
@@ -52,7 +56,8 @@ use std::io;
 /// - **Poison recovery:** Transparent (logs warning, continues with recovered
 ///   data)
 /// - **Ring buffer overflow:** Automatic eviction (FIFO, no error)
-/// - **Label cardinality:** LRU eviction (logs warning, no error)
+/// - **Label cardinality:** Smallest label folded into "other" (logs warning,
+///   no error)
 ///
 /// ## Future Extensions
 /// The `MetricsResult<()>` return type allows future additions without API
@@ -71,7 +76,8 @@ pub enum MetricsError {
 
     /// Label cardinality limit exceeded
     ///
-    /// Currently only used as warning (LRU evicts oldest), but could become
+    /// Currently only used as warning (the smallest label is folded into
+    /// "other"), but could become
     /// a hard error in future versions.
     #[error("Label cardinality exceeded for metric '{metric}': {count} > {limit}")]
     CardinalityExceeded {
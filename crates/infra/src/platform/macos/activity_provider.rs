@@ -18,6 +18,8 @@
 //! - App name and bundle ID are always available (via NSWorkspace)
 //! - Window titles require Accessibility permission
 //! - No panics or errors on permission denial
+//! - [`ActivityProvider::permission_status`] reports the permission so the
+//!   tracking pipeline can flag app-only captures
 
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -25,9 +27,9 @@ use std::time::{Duration, Instant};
 use async_trait::async_trait;
 use pulsearc_core::tracking::ports::ActivityProvider;
 use pulsearc_domain::types::{
-    ActivityCategory, ActivityMetadata, ConfidenceEvidence, WindowContext,
+    ActivityCategory, ActivityMetadata, CaptureMode, ConfidenceEvidence, WindowContext,
 };
-use pulsearc_domain::{ActivityContext, Result as DomainResult};
+use pulsearc_domain::{ActivityContext, PermissionStatus, Result as DomainResult};
 use url::Url;
 
 use super::ax_helpers;
@@ -341,6 +343,7 @@ impl ActivityProvider for MacOsActivityProvider {
                 location: None,
                 temporal_context: None,
                 classification: None,
+                capture_mode: CaptureMode::Full,
             });
         }
 
@@ -403,6 +406,7 @@ impl ActivityProvider for MacOsActivityProvider {
             location: None,                            // Integration (future)
             temporal_context: None,                    // Integration (future)
             classification: None,                      // Classification (Phase 4)
+            capture_mode: CaptureMode::Full,
         })
     }
 
@@ -436,6 +440,19 @@ impl ActivityProvider for MacOsActivityProvider {
         Ok(())
     }
 
+    /// Report whether Accessibility permission is granted.
+    ///
+    /// Uses the cached `AXIsProcessTrustedWithOptions` result (without
+    /// prompting), so it is cheap to call on every capture and picks up a
+    /// changed setting once the cache expires.
+    fn permission_status(&self) -> PermissionStatus {
+        match ax_helpers::check_ax_permission(false) {
+            Ok(true) => PermissionStatus::Granted,
+            Ok(false) => PermissionStatus::Denied,
+            Err(_) => PermissionStatus::Unsupported,
+        }
+    }
+
     /// Resume activity tracking.
     ///
     /// Re-enables activity tracking after being paused.
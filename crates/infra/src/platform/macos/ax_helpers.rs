@@ -73,6 +73,48 @@ const K_AX_ERROR_SUCCESS: i32 = 0;
 #[cfg(target_os = "macos")]
 const AX_PERMISSION_CACHE_TTL: Duration = Duration::from_secs(300);
 
+/// Deep link to System Settings > Privacy & Security > Accessibility
+pub const ACCESSIBILITY_SETTINGS_URL: &str =
+    "x-apple.systempreferences:com.apple.preference.security?Privacy_Accessibility";
+
+/// The `AXIsProcessTrustedWithOptions` call, behind a trait so the permission
+/// cache can be exercised without the system API.
+pub trait AxTrustCheck: Send + Sync {
+    /// Whether this process is trusted for Accessibility.
+    ///
+    /// With `prompt` set, macOS shows its permission dialog if the process is
+    /// not yet trusted.
+    fn is_process_trusted(&self, prompt: bool) -> bool;
+}
+
+/// [`AxTrustCheck`] backed by the system Accessibility API.
+#[cfg(target_os = "macos")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemAxTrust;
+
+#[cfg(target_os = "macos")]
+impl AxTrustCheck for SystemAxTrust {
+    fn is_process_trusted(&self, prompt: bool) -> bool {
+        // SAFETY: AXIsProcessTrustedWithOptions is a C function that:
+        // - Accepts a CFDictionary pointer (we create valid CFDictionary)
+        // - Returns a boolean (C bool) indicating trust status
+        // - Does not retain references to the dictionary after return
+        // - Is thread-safe according to Apple documentation
+        unsafe {
+            // Create options dictionary for prompt control
+            let prompt_key = CFString::from_static_string("AXTrustedCheckOptionPrompt");
+            let prompt_value = CFBoolean::from(prompt);
+
+            let options = CFDictionary::from_CFType_pairs(&[(
+                prompt_key.as_CFType(),
+                prompt_value.as_CFType(),
+            )]);
+
+            AXIsProcessTrustedWithOptions(options.as_concrete_TypeRef().cast())
+        }
+    }
+}
+
 #[cfg(target_os = "macos")]
 #[derive(Clone, Copy)]
 struct CachedPermission {
@@ -80,14 +122,71 @@ struct CachedPermission {
     checked_at: Instant,
 }
 
+/// Accessibility permission state cached for a TTL.
+///
+/// Avoids a system call on every capture while still picking up changes the
+/// user makes in System Settings.
+#[cfg(target_os = "macos")]
+pub struct AxPermissionCache {
+    entry: RwLock<Option<CachedPermission>>,
+    ttl: Duration,
+}
+
+#[cfg(target_os = "macos")]
+impl AxPermissionCache {
+    /// Create an empty cache whose entries expire after `ttl`.
+    pub fn new(ttl: Duration) -> Self {
+        Self { entry: RwLock::new(None), ttl }
+    }
+
+    /// Return the cached state, querying `trust` when the entry is missing or
+    /// stale.
+    ///
+    /// A `prompt` check always goes to the system, since it may show a dialog.
+    pub fn check(&self, trust: &dyn AxTrustCheck, prompt: bool) -> bool {
+        if !prompt {
+            if let Some(entry) = *self.entry.read() {
+                if entry.checked_at.elapsed() < self.ttl {
+                    return entry.value;
+                }
+            }
+        }
+
+        let is_trusted = trust.is_process_trusted(prompt);
+        *self.entry.write() =
+            Some(CachedPermission { value: is_trusted, checked_at: Instant::now() });
+        is_trusted
+    }
+
+    /// Drop the cached state so the next check queries the system.
+    pub fn invalidate(&self) {
+        *self.entry.write() = None;
+    }
+
+    /// Invalidate, then query `trust` again.
+    pub fn recheck(&self, trust: &dyn AxTrustCheck, prompt: bool) -> bool {
+        self.invalidate();
+        self.check(trust, prompt)
+    }
+}
+
 // Cache for AX permission state (avoid repeated system calls, but allow
 // refresh)
 #[cfg(target_os = "macos")]
-static AX_PERMISSION_CACHE: OnceLock<RwLock<Option<CachedPermission>>> = OnceLock::new();
+static AX_PERMISSION_CACHE: OnceLock<AxPermissionCache> = OnceLock::new();
+
+#[cfg(target_os = "macos")]
+fn permission_cache() -> &'static AxPermissionCache {
+    AX_PERMISSION_CACHE.get_or_init(|| AxPermissionCache::new(AX_PERMISSION_CACHE_TTL))
+}
 
 #[cfg(target_os = "macos")]
-fn permission_cache() -> &'static RwLock<Option<CachedPermission>> {
-    AX_PERMISSION_CACHE.get_or_init(|| RwLock::new(None))
+fn log_permission_status(is_trusted: bool) {
+    if is_trusted {
+        tracing::info!("Accessibility permission granted");
+    } else {
+        tracing::warn!("Accessibility permission denied - running in app-only mode");
+    }
 }
 
 /// Check if Accessibility permission is granted.
@@ -120,53 +219,68 @@ fn permission_cache() -> &'static RwLock<Option<CachedPermission>> {
 /// ```
 #[cfg(target_os = "macos")]
 pub fn check_ax_permission(prompt: bool) -> DomainResult<bool> {
-    // Check cache first unless an explicit prompt is requested
-    if !prompt {
-        let cached = permission_cache().read();
-        if let Some(entry) = *cached {
-            if entry.checked_at.elapsed() < AX_PERMISSION_CACHE_TTL {
-                return Ok(entry.value);
-            }
-        }
-    }
-
-    // SAFETY: AXIsProcessTrustedWithOptions is a C function that:
-    // - Accepts a CFDictionary pointer (we create valid CFDictionary)
-    // - Returns a boolean (C bool) indicating trust status
-    // - Does not retain references to the dictionary after return
-    // - Is thread-safe according to Apple documentation
-    let is_trusted = unsafe {
-        // Create options dictionary for prompt control
-        let prompt_key = CFString::from_static_string("AXTrustedCheckOptionPrompt");
-        let prompt_value = CFBoolean::from(prompt);
-
-        let options =
-            CFDictionary::from_CFType_pairs(&[(prompt_key.as_CFType(), prompt_value.as_CFType())]);
+    let is_trusted = permission_cache().check(&SystemAxTrust, prompt);
+    log_permission_status(is_trusted);
+    Ok(is_trusted)
+}
 
-        AXIsProcessTrustedWithOptions(options.as_concrete_TypeRef().cast())
-    };
+#[cfg(not(target_os = "macos"))]
+pub fn check_ax_permission(_prompt: bool) -> DomainResult<bool> {
+    Err(PulseArcError::Platform("Accessibility API is only available on macOS".to_string()))
+}
 
-    // Cache the result with a TTL so we can refresh after the user changes settings
-    {
-        let mut cache = permission_cache().write();
-        *cache = Some(CachedPermission { value: is_trusted, checked_at: Instant::now() });
-    }
+/// Re-check Accessibility permission, bypassing the cache.
+///
+/// Call after the user may have changed the setting (e.g. on returning from
+/// System Settings) so a grant is picked up without restarting the app.
+///
+/// # Platform Support
+///
+/// * macOS: Full support with AX API
+/// * Other: Returns `Err(PulseArcError::Platform)`
+#[cfg(target_os = "macos")]
+pub fn refresh_ax_permission() -> DomainResult<bool> {
+    let is_trusted = permission_cache().recheck(&SystemAxTrust, false);
+    log_permission_status(is_trusted);
+    Ok(is_trusted)
+}
 
-    // Log permission status
-    if is_trusted {
-        tracing::info!("Accessibility permission granted");
-    } else {
-        tracing::warn!("Accessibility permission denied - running in app-only mode");
-    }
+#[cfg(not(target_os = "macos"))]
+pub fn refresh_ax_permission() -> DomainResult<bool> {
+    Err(PulseArcError::Platform("Accessibility API is only available on macOS".to_string()))
+}
 
+/// Ask the user for Accessibility permission.
+///
+/// Calls `AXIsProcessTrustedWithOptions` with `AXTrustedCheckOptionPrompt`
+/// set, which shows the system dialog pointing at System Settings if the
+/// process is not yet trusted. macOS only shows that dialog once per launch;
+/// when it stays hidden, send the user to [`accessibility_settings_url`]
+/// instead.
+///
+/// # Returns
+///
+/// * `Ok(true)` - Permission is already granted
+/// * `Ok(false)` - Not granted yet; the grant is picked up by the next
+///   [`refresh_ax_permission`]
+/// * `Err(_)` - Only on non-macOS platforms
+#[cfg(target_os = "macos")]
+pub fn request_accessibility_permission() -> DomainResult<bool> {
+    let is_trusted = permission_cache().recheck(&SystemAxTrust, true);
+    log_permission_status(is_trusted);
     Ok(is_trusted)
 }
 
 #[cfg(not(target_os = "macos"))]
-pub fn check_ax_permission(_prompt: bool) -> DomainResult<bool> {
+pub fn request_accessibility_permission() -> DomainResult<bool> {
     Err(PulseArcError::Platform("Accessibility API is only available on macOS".to_string()))
 }
 
+/// URL opening the Accessibility pane of System Settings.
+pub fn accessibility_settings_url() -> &'static str {
+    ACCESSIBILITY_SETTINGS_URL
+}
+
 /// Get focused window title from active app using Accessibility API.
 ///
 /// Queries the focused window of the given process using AX APIs:
@@ -582,4 +696,69 @@ mod tests {
         let second = check_ax_permission(false);
         assert!(first.unwrap() == second.unwrap());
     }
+
+    #[test]
+    fn test_accessibility_settings_url_targets_privacy_pane() {
+        assert!(accessibility_settings_url().starts_with("x-apple.systempreferences:"));
+        assert!(accessibility_settings_url().ends_with("Privacy_Accessibility"));
+    }
+
+    #[cfg(target_os = "macos")]
+    mod cache {
+        use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+        use super::*;
+
+        /// Trust check whose answer the test flips
+        #[derive(Default)]
+        struct FakeTrust {
+            trusted: AtomicBool,
+            calls: AtomicUsize,
+            prompts: AtomicUsize,
+        }
+
+        impl AxTrustCheck for FakeTrust {
+            fn is_process_trusted(&self, prompt: bool) -> bool {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                if prompt {
+                    self.prompts.fetch_add(1, Ordering::SeqCst);
+                }
+                self.trusted.load(Ordering::SeqCst)
+            }
+        }
+
+        #[test]
+        fn explicit_recheck_clears_stale_denial() {
+            let trust = FakeTrust::default();
+            let cache = AxPermissionCache::new(Duration::from_secs(300));
+
+            assert!(!cache.check(&trust, false));
+
+            // User grants permission in System Settings
+            trust.trusted.store(true, Ordering::SeqCst);
+            assert!(!cache.check(&trust, false), "cached denial served within TTL");
+            assert_eq!(trust.calls.load(Ordering::SeqCst), 1);
+
+            assert!(cache.recheck(&trust, false));
+            assert_eq!(trust.calls.load(Ordering::SeqCst), 2);
+
+            // The fresh grant is cached in turn
+            assert!(cache.check(&trust, false));
+            assert_eq!(trust.calls.load(Ordering::SeqCst), 2);
+        }
+
+        #[test]
+        fn prompt_bypasses_cache() {
+            let trust = FakeTrust::default();
+            let cache = AxPermissionCache::new(Duration::from_secs(300));
+
+            assert!(!cache.check(&trust, false));
+            trust.trusted.store(true, Ordering::SeqCst);
+
+            assert!(cache.check(&trust, true));
+            assert_eq!(trust.prompts.load(Ordering::SeqCst), 1);
+            assert!(cache.check(&trust, false));
+            assert_eq!(trust.calls.load(Ordering::SeqCst), 2);
+        }
+    }
 }
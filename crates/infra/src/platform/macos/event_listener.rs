@@ -35,6 +35,9 @@
 
 use std::ptr::NonNull;
 
+use pulsearc_core::tracking::AppActivation;
+use tokio::sync::mpsc;
+
 #[cfg(target_os = "macos")]
 use block2::RcBlock;
 #[cfg(target_os = "macos")]
@@ -81,6 +84,24 @@ pub trait OsEventListener: Send + Sync {
     /// * `Err(String)` - Cleanup failed (logged but typically non-fatal)
     fn stop(&mut self) -> Result<(), String>;
 
+    /// Start listening and deliver each app activation on a channel
+    ///
+    /// Feed the receiver to `pulsearc_core::tracking::ActivationCapture` to
+    /// capture activity on focus changes. The channel closes when the
+    /// listener is stopped or dropped.
+    ///
+    /// # Returns
+    /// * `Ok(receiver)` - Listener started; activations arrive on `receiver`
+    /// * `Err(String)` - Failed to start listener
+    fn subscribe(&mut self) -> Result<mpsc::UnboundedReceiver<AppActivation>, String> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        self.start(Box::new(move || {
+            // Receiver gone means nobody is capturing any more; nothing to do
+            let _ = sender.send(AppActivation::now());
+        }))?;
+        Ok(receiver)
+    }
+
     /// Check if OS events are supported on this platform
     ///
     /// This is a static method that can be called without instantiating the
@@ -433,6 +454,53 @@ impl OsEventListener for FallbackEventListener {
 mod tests {
     use super::*;
 
+    /// Listener whose notifications the test fires by hand
+    #[derive(Default)]
+    struct MockListener {
+        callback: Option<Box<dyn Fn() + Send + Sync>>,
+    }
+
+    impl MockListener {
+        fn fire(&self) {
+            (self.callback.as_ref().expect("listener started"))();
+        }
+    }
+
+    impl OsEventListener for MockListener {
+        fn start(&mut self, callback: Box<dyn Fn() + Send + Sync>) -> Result<(), String> {
+            self.callback = Some(callback);
+            Ok(())
+        }
+
+        fn stop(&mut self) -> Result<(), String> {
+            self.callback = None;
+            Ok(())
+        }
+
+        fn is_supported() -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_subscribe_forwards_activations() {
+        let mut listener = MockListener::default();
+        let mut activations = listener.subscribe().unwrap();
+        assert!(activations.try_recv().is_err(), "no activation before a notification");
+
+        listener.fire();
+        listener.fire();
+        assert!(activations.try_recv().is_ok());
+        assert!(activations.try_recv().is_ok());
+
+        listener.stop().unwrap();
+        assert_eq!(
+            activations.try_recv(),
+            Err(mpsc::error::TryRecvError::Disconnected),
+            "stopping drops the sender"
+        );
+    }
+
     #[test]
     fn test_listener_creation() {
         let _listener = MacOsEventListener::new();
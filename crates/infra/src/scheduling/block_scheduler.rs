@@ -5,6 +5,9 @@
 //! `CLAUDE.md`: join handles are tracked, cancellation is explicit, and every
 //! asynchronous operation is wrapped in a timeout.
 //!
+//! [`BlockScheduler::trigger_now`] runs the job on demand, sharing any run
+//! already in progress.
+//!
 //! # Example
 //!
 //! ```no_run
@@ -46,18 +49,22 @@
 //! ```
 
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 use async_trait::async_trait;
+use pulsearc_common::testing::{Clock, SystemClock};
+use pulsearc_domain::PulseArcError;
 use tokio::task::JoinHandle;
-use tokio_cron_scheduler::{Job, JobScheduler};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, instrument, warn};
 
 use crate::errors::InfraError;
 use crate::observability::metrics::PerformanceMetrics;
 use crate::observability::MetricsResult;
+use crate::scheduling::clock::{spawn_cron_loop, CronLoopConfig, CronTrigger};
 use crate::scheduling::error::{SchedulerError, SchedulerResult};
+use crate::scheduling::on_demand::{RunCoalescer, RunFailure, RunOutcome};
+use crate::scheduling::run_span::run_in_span;
 
 /// Trait representing a block generation job.
 #[async_trait]
@@ -73,9 +80,7 @@ pub struct BlockSchedulerConfig {
     pub cron_expression: String,
     /// Timeout applied to a single job execution.
     pub job_timeout: Duration,
-    /// Timeout for starting the underlying scheduler.
-    pub start_timeout: Duration,
-    /// Timeout for stopping the scheduler.
+    /// Timeout for the cron loop to exit after cancellation.
     pub stop_timeout: Duration,
     /// Timeout for awaiting the monitor task join handle.
     pub join_timeout: Duration,
@@ -86,7 +91,6 @@ impl Default for BlockSchedulerConfig {
         Self {
             cron_expression: "0 */15 * * * *".into(), // every 15 minutes
             job_timeout: Duration::from_secs(300),
-            start_timeout: Duration::from_secs(5),
             stop_timeout: Duration::from_secs(5),
             join_timeout: Duration::from_secs(5),
         }
@@ -95,12 +99,14 @@ impl Default for BlockSchedulerConfig {
 
 /// Block scheduler with explicit lifecycle management.
 pub struct BlockScheduler {
-    scheduler: Option<JobScheduler>,
+    scheduler: Option<JoinHandle<()>>,
     config: BlockSchedulerConfig,
     monitor_handle: Option<JoinHandle<()>>,
     cancellation: CancellationToken,
     metrics: Arc<PerformanceMetrics>,
     job: Arc<dyn BlockJob>,
+    clock: Arc<dyn Clock>,
+    runs: RunCoalescer,
 }
 
 impl BlockScheduler {
@@ -119,6 +125,19 @@ impl BlockScheduler {
         config: BlockSchedulerConfig,
         job: Arc<dyn BlockJob>,
         metrics: Arc<PerformanceMetrics>,
+    ) -> SchedulerResult<Self> {
+        Self::with_clock(config, job, metrics, Arc::new(SystemClock))
+    }
+
+    /// Create a scheduler whose schedule is driven by `clock`.
+    ///
+    /// Production code uses [`SystemClock`] via [`Self::with_config`]; tests
+    /// pass a `MockClock` and advance it to trigger runs without sleeping.
+    pub fn with_clock(
+        config: BlockSchedulerConfig,
+        job: Arc<dyn BlockJob>,
+        metrics: Arc<PerformanceMetrics>,
+        clock: Arc<dyn Clock>,
     ) -> SchedulerResult<Self> {
         let scheduler = Self {
             scheduler: None,
@@ -127,6 +146,8 @@ impl BlockScheduler {
             cancellation: CancellationToken::new(),
             metrics,
             job,
+            clock,
+            runs: RunCoalescer::default(),
         };
         Ok(scheduler)
     }
@@ -140,14 +161,7 @@ impl BlockScheduler {
 
         self.cancellation = CancellationToken::new();
 
-        let scheduler_instance = self.build_scheduler().await?;
-        let start_timeout = self.config.start_timeout;
-
-        let start_result = tokio::time::timeout(start_timeout, scheduler_instance.start())
-            .await
-            .map_err(|source| SchedulerError::Timeout { duration: start_timeout, source })?;
-
-        start_result.map_err(|source| SchedulerError::StartFailed { source })?;
+        let scheduler_instance = self.build_scheduler()?;
 
         self.scheduler = Some(scheduler_instance);
 
@@ -172,18 +186,15 @@ impl BlockScheduler {
 
         self.cancellation.cancel();
 
-        let mut scheduler = match self.scheduler.take() {
+        let scheduler = match self.scheduler.take() {
             Some(scheduler) => scheduler,
             None => return Err(SchedulerError::NotRunning),
         };
 
         let stop_timeout = self.config.stop_timeout;
-        let stop_result =
-            tokio::time::timeout(stop_timeout, async move { scheduler.shutdown().await })
-                .await
-                .map_err(|source| SchedulerError::Timeout { duration: stop_timeout, source })?;
-
-        stop_result.map_err(|source| SchedulerError::StopFailed { source })?;
+        tokio::time::timeout(stop_timeout, scheduler)
+            .await
+            .map_err(|source| SchedulerError::Timeout { duration: stop_timeout, source })??;
 
         if let Some(handle) = self.monitor_handle.take() {
             let join_timeout = self.config.join_timeout;
@@ -197,62 +208,62 @@ impl BlockScheduler {
         Ok(())
     }
 
+    /// Generate blocks now, outside the schedule.
+    ///
+    /// If a run is already in progress (scheduled or triggered), waits for
+    /// that run instead of starting another. Returns once the run completes,
+    /// fails or exceeds `job_timeout`. Works whether or not the scheduler is
+    /// started.
+    #[instrument(skip(self))]
+    pub async fn trigger_now(&self) -> SchedulerResult<()> {
+        let job = self.job.clone();
+        let metrics = self.metrics.clone();
+        let cancel = self.cancellation.clone();
+        let job_timeout = self.config.job_timeout;
+
+        let run = self.runs.run(move || execute_job(job, job_timeout, metrics, cancel));
+        run_in_span("block", self.clock.system_time(), &self.metrics, run)
+            .await
+            .map_err(SchedulerError::from)
+    }
+
     /// Returns true when a scheduler instance is active.
     pub fn is_running(&self) -> bool {
         self.scheduler.is_some()
     }
 
-    async fn build_scheduler(&self) -> SchedulerResult<JobScheduler> {
-        let scheduler = JobScheduler::new()
-            .await
-            .map_err(|source| SchedulerError::CreationFailed { source })?;
-        let cron_expr = self.config.cron_expression.clone();
+    fn build_scheduler(&self) -> SchedulerResult<JoinHandle<()>> {
+        let trigger = CronTrigger::parse(&self.config.cron_expression)?;
         let metrics = self.metrics.clone();
         let job = self.job.clone();
         let job_timeout = self.config.job_timeout;
 
-        let job_definition = Job::new_async(cron_expr.as_str(), move |_id, _lock| {
+        let runs = self.runs.clone();
+        let cancel = self.cancellation.clone();
+
+        let run = move || {
             let metrics = metrics.clone();
             let job = job.clone();
+            let runs = runs.clone();
+            let cancel = cancel.clone();
 
-            Box::pin(async move {
-                log_metric(metrics.record_call(), "scheduler.block.job.invoked");
-                let started = Instant::now();
-
-                match tokio::time::timeout(job_timeout, job.run()).await {
-                    Ok(Ok(())) => {
-                        log_metric(
-                            metrics.record_fetch_time(started.elapsed()),
-                            "scheduler.block.job.duration",
-                        );
-                        debug!("Block generation finished successfully");
-                    }
-                    Ok(Err(err)) => {
-                        log_metric(metrics.record_fetch_error(), "scheduler.block.job.error");
-                        log_metric(
-                            metrics.record_fetch_time(started.elapsed()),
-                            "scheduler.block.job.duration",
-                        );
-                        error!(error = ?err, "Block generation failed");
-                    }
-                    Err(elapsed) => {
-                        log_metric(metrics.record_fetch_timeout(), "scheduler.block.job.timeout");
-                        warn!(timeout_secs = job_timeout.as_secs(), "Block generation timed out");
-                        debug!(elapsed = ?elapsed, "Timeout details");
-                    }
-                }
-            })
-        })
-        .map_err(|source| SchedulerError::JobRegistrationFailed { source })?;
-
-        let job_id = job_definition.guid();
-        scheduler
-            .add(job_definition)
-            .await
-            .map_err(|source| SchedulerError::JobRegistrationFailed { source })?;
+            async move {
+                // Scheduled runs report failures through logs and metrics
+                let _ = runs.run(move || execute_job(job, job_timeout, metrics, cancel)).await;
+            }
+        };
 
-        debug!(cron = %self.config.cron_expression, job_id = %job_id, "Registered block generation job");
-        Ok(scheduler)
+        let config = CronLoopConfig {
+            scheduler: "block",
+            trigger,
+            clock: Arc::clone(&self.clock),
+            cancel: self.cancellation.clone(),
+            metrics: self.metrics.clone(),
+        };
+        let handle = spawn_cron_loop(config, run);
+
+        debug!(cron = %self.config.cron_expression, "Registered block generation job");
+        Ok(handle)
     }
 
     async fn monitor_task(cancel: CancellationToken, metrics: Arc<PerformanceMetrics>) {
@@ -266,6 +277,41 @@ impl BlockScheduler {
     }
 }
 
+/// Run `job` once, bounded by `job_timeout` and interrupted by `cancel`.
+async fn execute_job(
+    job: Arc<dyn BlockJob>,
+    job_timeout: Duration,
+    metrics: Arc<PerformanceMetrics>,
+    cancel: CancellationToken,
+) -> RunOutcome {
+    log_metric(metrics.record_call(), "scheduler.block.job.invoked");
+    let result = tokio::select! {
+        _ = cancel.cancelled() => {
+            debug!("Block generation cancelled");
+            return Err(RunFailure::Cancelled);
+        }
+        result = tokio::time::timeout(job_timeout, job.run()) => result,
+    };
+
+    match result {
+        Ok(Ok(())) => {
+            debug!("Block generation finished successfully");
+            Ok(())
+        }
+        Ok(Err(err)) => {
+            log_metric(metrics.record_fetch_error(), "scheduler.block.job.error");
+            error!(error = ?err, "Block generation failed");
+            Err(RunFailure::Failed(PulseArcError::from(err).to_string()))
+        }
+        Err(elapsed) => {
+            log_metric(metrics.record_fetch_timeout(), "scheduler.block.job.timeout");
+            warn!(timeout_secs = job_timeout.as_secs(), "Block generation timed out");
+            debug!(elapsed = ?elapsed, "Timeout details");
+            Err(RunFailure::TimedOut(job_timeout))
+        }
+    }
+}
+
 fn log_metric(result: MetricsResult<()>, metric: &'static str) {
     if let Err(err) = result {
         warn!(metric = metric, error = ?err, "Failed to record scheduler metric");
@@ -285,6 +331,8 @@ impl Drop for BlockScheduler {
 mod tests {
     use std::sync::atomic::{AtomicUsize, Ordering};
 
+    use pulsearc_common::testing::MockClock;
+
     use super::*;
     use crate::scheduling::error::SchedulerError;
 
@@ -310,11 +358,25 @@ mod tests {
         }
     }
 
+    /// Job that takes `duration` to finish
+    struct SlowJob {
+        runs: AtomicUsize,
+        duration: Duration,
+    }
+
+    #[async_trait]
+    impl BlockJob for SlowJob {
+        async fn run(&self) -> Result<(), InfraError> {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(self.duration).await;
+            Ok(())
+        }
+    }
+
     fn fast_config() -> BlockSchedulerConfig {
         BlockSchedulerConfig {
             cron_expression: "*/1 * * * * *".into(), // every second
             job_timeout: Duration::from_secs(2),
-            start_timeout: Duration::from_secs(2),
             stop_timeout: Duration::from_secs(2),
             join_timeout: Duration::from_secs(2),
         }
@@ -362,4 +424,58 @@ mod tests {
         scheduler.start().await.expect("start again");
         scheduler.stop().await.expect("stop again");
     }
+
+    #[tokio::test(start_paused = true)]
+    async fn mock_clock_crossing_cron_boundary_runs_job_once() {
+        let metrics = Arc::new(PerformanceMetrics::new());
+        let job = Arc::new(CountingJob::new());
+        let clock = Arc::new(MockClock::new());
+        let config =
+            BlockSchedulerConfig { cron_expression: "0 0 * * * *".into(), ..fast_config() }; // hourly
+        let mut scheduler = BlockScheduler::with_clock(config, job.clone(), metrics, clock.clone())
+            .expect("scheduler created");
+
+        scheduler.start().await.expect("start succeeds");
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert_eq!(job.run_count(), 0, "job must not run before the boundary");
+
+        clock.advance(Duration::from_secs(3600));
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert_eq!(job.run_count(), 1);
+
+        scheduler.stop().await.expect("stop succeeds");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn concurrent_triggers_run_job_once() {
+        let metrics = Arc::new(PerformanceMetrics::new());
+        let job = Arc::new(SlowJob { runs: AtomicUsize::new(0), duration: Duration::from_secs(1) });
+        let scheduler = BlockScheduler::with_config(fast_config(), job.clone(), metrics)
+            .expect("scheduler created");
+
+        let (first, second) = tokio::join!(scheduler.trigger_now(), scheduler.trigger_now());
+        first.expect("first trigger succeeds");
+        second.expect("second trigger succeeds");
+        assert_eq!(job.runs.load(Ordering::SeqCst), 1, "triggers must coalesce");
+
+        scheduler.trigger_now().await.expect("later trigger succeeds");
+        assert_eq!(job.runs.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn trigger_now_respects_job_timeout() {
+        let metrics = Arc::new(PerformanceMetrics::new());
+        let job =
+            Arc::new(SlowJob { runs: AtomicUsize::new(0), duration: Duration::from_secs(60) });
+        let scheduler =
+            BlockScheduler::with_config(fast_config(), job, metrics).expect("scheduler created");
+
+        let started = tokio::time::Instant::now();
+        let err = scheduler.trigger_now().await.expect_err("job exceeds timeout");
+
+        assert!(
+            matches!(err, SchedulerError::JobTimedOut { duration } if duration == Duration::from_secs(2))
+        );
+        assert!(started.elapsed() < Duration::from_secs(60));
+    }
 }
@@ -5,6 +5,11 @@
 //! `CLAUDE.md`: join handles are tracked, cancellation is explicit, and every
 //! asynchronous operation is wrapped in a timeout.
 //!
+//! Accounts are synced one after another with a pause between them, and each
+//! account's syncs are paced by a [`TokenBucket`] sized from its provider's
+//! per-user quota (see [`AccountRateLimit`]). An account that has used its
+//! budget is skipped until a later run instead of being synced anyway.
+//!
 //! Feature-gated behind `calendar` feature flag.
 //!
 //! # Example
@@ -37,21 +42,77 @@
 //! # }
 //! ```
 
+use std::collections::HashMap;
 use std::fmt;
-use std::sync::Arc;
-use std::time::{Duration, Instant};
-
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant, SystemTime};
+
+use async_trait::async_trait;
+use pulsearc_common::resilience::{self, TokenBucket};
+use pulsearc_common::testing::{Clock, SystemClock};
+use pulsearc_core::calendar_ports::SyncStatus;
+use pulsearc_domain::PulseArcError;
 use sha2::{Digest, Sha256};
 use tokio::task::JoinHandle;
-use tokio_cron_scheduler::{Job, JobScheduler};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, instrument, warn};
 
 use crate::integrations::calendar::sync::CalendarSyncWorker;
 use crate::observability::metrics::PerformanceMetrics;
 use crate::observability::MetricsResult;
+use crate::scheduling::clock::{sleep_until, spawn_cron_loop, CronLoopConfig, CronTrigger};
 use crate::scheduling::error::{SchedulerError, SchedulerResult};
 
+/// Syncs one account's calendar.
+///
+/// Implemented by [`CalendarSyncWorker`]; tests substitute a fake.
+#[async_trait]
+pub trait CalendarAccountSync: Send + Sync {
+    /// Sync events for `user_email`.
+    async fn perform_sync(&self, user_email: &str) -> Result<SyncStatus, PulseArcError>;
+}
+
+#[async_trait]
+impl CalendarAccountSync for CalendarSyncWorker {
+    async fn perform_sync(&self, user_email: &str) -> Result<SyncStatus, PulseArcError> {
+        CalendarSyncWorker::perform_sync(self, user_email).await
+    }
+}
+
+/// How often one account may be synced.
+///
+/// Each account gets a token bucket holding `burst` syncs that refills by one
+/// every `refill_interval`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountRateLimit {
+    /// Syncs an account may run back-to-back.
+    pub burst: u64,
+    /// Time for an account to earn one more sync.
+    pub refill_interval: Duration,
+}
+
+impl AccountRateLimit {
+    /// Limit for `provider` (`"google"` or `"microsoft"`).
+    ///
+    /// Google Calendar allows 600 queries per minute per user and Microsoft
+    /// Graph 10,000 requests per 10 minutes per mailbox. A sync issues one
+    /// request per page of events, so these limits leave headroom for long
+    /// paginated syncs. Unknown providers get the stricter Graph limit.
+    pub fn for_provider(provider: &str) -> Self {
+        if provider.eq_ignore_ascii_case("google") {
+            Self { burst: 5, refill_interval: Duration::from_secs(10) }
+        } else {
+            Self { burst: 4, refill_interval: Duration::from_secs(15) }
+        }
+    }
+}
+
+impl Default for AccountRateLimit {
+    fn default() -> Self {
+        Self::for_provider("google")
+    }
+}
+
 /// Configuration for the calendar scheduler.
 #[derive(Debug, Clone)]
 pub struct CalendarSchedulerConfig {
@@ -61,12 +122,14 @@ pub struct CalendarSchedulerConfig {
     pub user_emails: Vec<String>,
     /// Timeout applied to a single sync execution.
     pub job_timeout: Duration,
-    /// Timeout for starting the underlying scheduler.
-    pub start_timeout: Duration,
-    /// Timeout for stopping the scheduler.
+    /// Timeout for the cron loop to exit after cancellation.
     pub stop_timeout: Duration,
     /// Timeout for awaiting the monitor task join handle.
     pub join_timeout: Duration,
+    /// Per-account sync budget, from the provider's per-user quota.
+    pub account_rate_limit: AccountRateLimit,
+    /// Pause between two account syncs within a run.
+    pub account_stagger: Duration,
 }
 
 impl Default for CalendarSchedulerConfig {
@@ -75,21 +138,24 @@ impl Default for CalendarSchedulerConfig {
             cron_expression: "0 */15 * * * *".into(), // every 15 minutes
             user_emails: Vec::new(),
             job_timeout: Duration::from_secs(300),
-            start_timeout: Duration::from_secs(5),
             stop_timeout: Duration::from_secs(5),
             join_timeout: Duration::from_secs(5),
+            account_rate_limit: AccountRateLimit::default(),
+            account_stagger: Duration::from_secs(2),
         }
     }
 }
 
 /// Calendar synchronization scheduler with explicit lifecycle management.
 pub struct CalendarScheduler {
-    scheduler: Option<JobScheduler>,
+    scheduler: Option<JoinHandle<()>>,
     config: CalendarSchedulerConfig,
     monitor_handle: Option<JoinHandle<()>>,
     cancellation: CancellationToken,
     metrics: Arc<PerformanceMetrics>,
-    sync_worker: Arc<CalendarSyncWorker>,
+    sync_worker: Arc<dyn CalendarAccountSync>,
+    clock: Arc<dyn Clock>,
+    pacer: Arc<AccountPacer>,
 }
 
 impl CalendarScheduler {
@@ -97,7 +163,7 @@ impl CalendarScheduler {
     pub fn new(
         cron_expression: String,
         user_emails: Vec<String>,
-        sync_worker: Arc<CalendarSyncWorker>,
+        sync_worker: Arc<dyn CalendarAccountSync>,
         metrics: Arc<PerformanceMetrics>,
     ) -> SchedulerResult<Self> {
         let config = CalendarSchedulerConfig { cron_expression, user_emails, ..Default::default() };
@@ -107,9 +173,23 @@ impl CalendarScheduler {
     /// Create a scheduler with a custom configuration.
     pub fn with_config(
         config: CalendarSchedulerConfig,
-        sync_worker: Arc<CalendarSyncWorker>,
+        sync_worker: Arc<dyn CalendarAccountSync>,
+        metrics: Arc<PerformanceMetrics>,
+    ) -> SchedulerResult<Self> {
+        Self::with_clock(config, sync_worker, metrics, Arc::new(SystemClock))
+    }
+
+    /// Create a scheduler whose schedule is driven by `clock`.
+    ///
+    /// Production code uses [`SystemClock`] via [`Self::with_config`]; tests
+    /// pass a `MockClock` and advance it to trigger runs without sleeping.
+    pub fn with_clock(
+        config: CalendarSchedulerConfig,
+        sync_worker: Arc<dyn CalendarAccountSync>,
         metrics: Arc<PerformanceMetrics>,
+        clock: Arc<dyn Clock>,
     ) -> SchedulerResult<Self> {
+        let pacer = Arc::new(AccountPacer::new(&config, Arc::clone(&clock)));
         let scheduler = Self {
             scheduler: None,
             config,
@@ -117,6 +197,8 @@ impl CalendarScheduler {
             cancellation: CancellationToken::new(),
             metrics,
             sync_worker,
+            clock,
+            pacer,
         };
         Ok(scheduler)
     }
@@ -130,14 +212,7 @@ impl CalendarScheduler {
 
         self.cancellation = CancellationToken::new();
 
-        let scheduler_instance = self.build_scheduler().await?;
-        let start_timeout = self.config.start_timeout;
-
-        let start_result = tokio::time::timeout(start_timeout, scheduler_instance.start())
-            .await
-            .map_err(|source| SchedulerError::Timeout { duration: start_timeout, source })?;
-
-        start_result.map_err(|source| SchedulerError::StartFailed { source })?;
+        let scheduler_instance = self.build_scheduler()?;
 
         self.scheduler = Some(scheduler_instance);
 
@@ -162,18 +237,15 @@ impl CalendarScheduler {
 
         self.cancellation.cancel();
 
-        let mut scheduler = match self.scheduler.take() {
+        let scheduler = match self.scheduler.take() {
             Some(scheduler) => scheduler,
             None => return Err(SchedulerError::NotRunning),
         };
 
         let stop_timeout = self.config.stop_timeout;
-        let stop_result =
-            tokio::time::timeout(stop_timeout, async move { scheduler.shutdown().await })
-                .await
-                .map_err(|source| SchedulerError::Timeout { duration: stop_timeout, source })?;
-
-        stop_result.map_err(|source| SchedulerError::StopFailed { source })?;
+        tokio::time::timeout(stop_timeout, scheduler)
+            .await
+            .map_err(|source| SchedulerError::Timeout { duration: stop_timeout, source })??;
 
         if let Some(handle) = self.monitor_handle.take() {
             let join_timeout = self.config.join_timeout;
@@ -192,44 +264,33 @@ impl CalendarScheduler {
         self.scheduler.is_some()
     }
 
-    async fn build_scheduler(&self) -> SchedulerResult<JobScheduler> {
-        let scheduler = JobScheduler::new()
-            .await
-            .map_err(|source| SchedulerError::CreationFailed { source })?;
-        let cron_expr = self.config.cron_expression.clone();
+    fn build_scheduler(&self) -> SchedulerResult<JoinHandle<()>> {
+        let trigger = CronTrigger::parse(&self.config.cron_expression)?;
         let metrics = self.metrics.clone();
         let sync_worker = self.sync_worker.clone();
         let job_timeout = self.config.job_timeout;
         let user_emails = self.config.user_emails.clone();
+        let pacer = self.pacer.clone();
 
-        let job_definition = Job::new_async(cron_expr.as_str(), move |_id, _lock| {
+        let run = move || {
             let metrics = metrics.clone();
             let sync_worker = sync_worker.clone();
             let user_emails = user_emails.clone();
+            let pacer = pacer.clone();
 
-            Box::pin(async move {
+            async move {
                 log_metric(metrics.record_call(), "scheduler.calendar.job.invoked");
-                let started = Instant::now();
-
                 match tokio::time::timeout(
                     job_timeout,
-                    Self::perform_calendar_sync(sync_worker, user_emails),
+                    Self::perform_calendar_sync(sync_worker, user_emails, pacer),
                 )
                 .await
                 {
                     Ok(Ok(())) => {
-                        log_metric(
-                            metrics.record_fetch_time(started.elapsed()),
-                            "scheduler.calendar.job.duration",
-                        );
                         debug!("Calendar sync finished successfully");
                     }
                     Ok(Err(err)) => {
                         log_metric(metrics.record_fetch_error(), "scheduler.calendar.job.error");
-                        log_metric(
-                            metrics.record_fetch_time(started.elapsed()),
-                            "scheduler.calendar.job.duration",
-                        );
                         error!(error = ?err, "Calendar sync failed");
                     }
                     Err(elapsed) => {
@@ -241,23 +302,26 @@ impl CalendarScheduler {
                         debug!(elapsed = ?elapsed, "Timeout details");
                     }
                 }
-            })
-        })
-        .map_err(|source| SchedulerError::JobRegistrationFailed { source })?;
+            }
+        };
 
-        let job_id = job_definition.guid();
-        scheduler
-            .add(job_definition)
-            .await
-            .map_err(|source| SchedulerError::JobRegistrationFailed { source })?;
+        let config = CronLoopConfig {
+            scheduler: "calendar",
+            trigger,
+            clock: Arc::clone(&self.clock),
+            cancel: self.cancellation.clone(),
+            metrics: self.metrics.clone(),
+        };
+        let handle = spawn_cron_loop(config, run);
 
-        debug!(cron = %self.config.cron_expression, job_id = %job_id, "Registered calendar sync job");
-        Ok(scheduler)
+        debug!(cron = %self.config.cron_expression, "Registered calendar sync job");
+        Ok(handle)
     }
 
     async fn perform_calendar_sync(
-        sync_worker: Arc<CalendarSyncWorker>,
+        sync_worker: Arc<dyn CalendarAccountSync>,
         user_emails: Vec<String>,
+        pacer: Arc<AccountPacer>,
     ) -> Result<(), CalendarJobError> {
         if user_emails.is_empty() {
             debug!("No user emails configured for calendar sync");
@@ -269,9 +333,17 @@ impl CalendarScheduler {
         let mut total_synced = 0;
         let mut errors = 0;
         let mut failures = Vec::new();
+        let mut rate_limited = 0;
 
         for email in &user_emails {
             let user_tag = redact_email(email);
+            if !pacer.try_acquire(email) {
+                rate_limited += 1;
+                debug!(user = %user_tag, "Calendar sync budget used up; deferring to a later run");
+                continue;
+            }
+            pacer.wait_turn().await;
+
             match sync_worker.perform_sync(email).await {
                 Ok(status) => {
                     if status.success {
@@ -297,7 +369,7 @@ impl CalendarScheduler {
 
         info!(
             total_users = user_emails.len(),
-            total_synced, errors, "Calendar sync batch completed"
+            total_synced, errors, rate_limited, "Calendar sync batch completed"
         );
 
         if errors > 0 {
@@ -318,6 +390,82 @@ impl CalendarScheduler {
     }
 }
 
+/// Paces account syncs: a token bucket per account plus a pause between
+/// consecutive syncs (also across runs).
+struct AccountPacer {
+    buckets: HashMap<String, TokenBucket<BucketClock>>,
+    stagger: Duration,
+    clock: Arc<dyn Clock>,
+    last_sync: Mutex<Option<SystemTime>>,
+}
+
+impl AccountPacer {
+    fn new(config: &CalendarSchedulerConfig, clock: Arc<dyn Clock>) -> Self {
+        let limit = config.account_rate_limit;
+        let buckets = config
+            .user_emails
+            .iter()
+            .filter_map(|email| {
+                // Clamped so the bucket config is always valid
+                let bucket = TokenBucket::with_clock(
+                    limit.burst.max(1),
+                    1,
+                    limit.refill_interval.max(Duration::from_millis(1)),
+                    BucketClock(Arc::clone(&clock)),
+                );
+                match bucket {
+                    Ok(bucket) => Some((email.clone(), bucket)),
+                    Err(err) => {
+                        warn!(
+                            user = %redact_email(email),
+                            error = %err,
+                            "Invalid calendar rate limit; account is not paced"
+                        );
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        Self { buckets, stagger: config.account_stagger, clock, last_sync: Mutex::new(None) }
+    }
+
+    /// Take one sync from `email`'s budget; false if it is used up.
+    fn try_acquire(&self, email: &str) -> bool {
+        match self.buckets.get(email) {
+            Some(bucket) => bucket.try_acquire(1),
+            None => true,
+        }
+    }
+
+    /// Wait until `stagger` has passed since the previous sync started, then
+    /// claim the current time as the latest sync.
+    async fn wait_turn(&self) {
+        let last_sync = *self.last_sync();
+        if let Some(last_sync) = last_sync {
+            sleep_until(self.clock.as_ref(), last_sync + self.stagger).await;
+        }
+        *self.last_sync() = Some(self.clock.system_time());
+    }
+
+    fn last_sync(&self) -> MutexGuard<'_, Option<SystemTime>> {
+        self.last_sync.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Scheduler clock adapted to the resilience `Clock` used by [`TokenBucket`].
+struct BucketClock(Arc<dyn Clock>);
+
+impl resilience::Clock for BucketClock {
+    fn now(&self) -> Instant {
+        self.0.now()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        self.0.system_time()
+    }
+}
+
 fn log_metric(result: MetricsResult<()>, metric: &'static str) {
     if let Err(err) = result {
         warn!(metric = metric, error = ?err, "Failed to record scheduler metric");
@@ -420,8 +568,103 @@ impl Drop for CalendarScheduler {
 
 #[cfg(test)]
 mod tests {
+    use pulsearc_common::testing::MockClock;
+
     use super::*;
 
+    /// Records which account was synced when
+    struct RecordingSync {
+        clock: Arc<MockClock>,
+        syncs: Mutex<Vec<(String, Instant)>>,
+    }
+
+    impl RecordingSync {
+        fn new(clock: Arc<MockClock>) -> Self {
+            Self { clock, syncs: Mutex::new(Vec::new()) }
+        }
+
+        fn syncs(&self) -> Vec<(String, Instant)> {
+            self.syncs.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl CalendarAccountSync for RecordingSync {
+        async fn perform_sync(&self, user_email: &str) -> Result<SyncStatus, PulseArcError> {
+            self.syncs.lock().unwrap().push((user_email.to_string(), self.clock.now()));
+            Ok(SyncStatus { last_sync: None, events_synced: 0, success: true })
+        }
+    }
+
+    /// Advance `clock` in step with (paused) tokio time
+    fn drive_clock(clock: Arc<MockClock>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                clock.advance(Duration::from_millis(100));
+            }
+        })
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn accounts_are_staggered_and_paced_per_account() {
+        let limit = AccountRateLimit { burst: 2, refill_interval: Duration::from_secs(5) };
+        let stagger = Duration::from_millis(500);
+        let config = CalendarSchedulerConfig {
+            cron_expression: "*/1 * * * * *".into(), // every second
+            user_emails: vec!["a@example.com".into(), "b@example.com".into()],
+            job_timeout: Duration::from_secs(2),
+            stop_timeout: Duration::from_secs(2),
+            join_timeout: Duration::from_secs(2),
+            account_rate_limit: limit,
+            account_stagger: stagger,
+        };
+        let clock = Arc::new(MockClock::new());
+        let sync = Arc::new(RecordingSync::new(clock.clone()));
+        let metrics = Arc::new(PerformanceMetrics::new());
+        let mut scheduler =
+            CalendarScheduler::with_clock(config, sync.clone(), metrics, clock.clone())
+                .expect("scheduler created");
+
+        let driver = drive_clock(clock);
+        scheduler.start().await.expect("start succeeds");
+        tokio::time::sleep(Duration::from_secs(30)).await;
+        scheduler.stop().await.expect("stop succeeds");
+        driver.abort();
+
+        let syncs = sync.syncs();
+
+        // Consecutive syncs are at least `stagger` apart, even across runs
+        for pair in syncs.windows(2) {
+            assert!(pair[1].1 - pair[0].1 >= stagger, "account syncs must be staggered");
+        }
+
+        // Each account stays within burst + one sync per refill interval,
+        // far below the 30 syncs the every-second schedule would allow
+        let window = Duration::from_secs(10);
+        let per_window = limit.burst + 2; // 10s / 5s refill
+        for email in ["a@example.com", "b@example.com"] {
+            let times: Vec<Instant> =
+                syncs.iter().filter(|(user, _)| user == email).map(|(_, at)| *at).collect();
+            assert!(times.len() >= 3, "{email} keeps syncing: {}", times.len());
+            assert!(times.len() as u64 <= limit.burst + 30 / 5, "{email}: {}", times.len());
+            for (i, start) in times.iter().enumerate() {
+                let in_window = times[i..].iter().filter(|at| **at - *start < window).count();
+                assert!(in_window as u64 <= per_window, "{email} exceeded its rate");
+            }
+        }
+    }
+
+    #[test]
+    fn provider_limits_default_to_stricter_quota() {
+        let google = AccountRateLimit::for_provider("Google");
+        let graph = AccountRateLimit::for_provider("microsoft");
+
+        assert_eq!(AccountRateLimit::default(), google);
+        assert_eq!(AccountRateLimit::for_provider("unknown"), graph);
+        assert!(graph.refill_interval > google.refill_interval);
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn lifecycle_runs_successfully() {
         let _metrics = Arc::new(PerformanceMetrics::new());
@@ -5,6 +5,10 @@
 //! `CLAUDE.md`: join handles are tracked, cancellation is explicit, and every
 //! asynchronous operation is wrapped in a timeout.
 //!
+//! At most `max_in_flight` runs execute at once. A trigger that fires while
+//! the scheduler is at capacity is handled per [`OverlapPolicy`]: skipped, or
+//! queued (up to a bound) until a run finishes.
+//!
 //! # Example
 //!
 //! ```no_run
@@ -46,19 +50,21 @@
 //! # }
 //! ```
 
-use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
 
 use async_trait::async_trait;
-use tokio::sync::RwLock;
-use tokio::task::JoinHandle;
-use tokio_cron_scheduler::{Job, JobScheduler};
+use pulsearc_common::testing::{Clock, SystemClock};
+use tokio::sync::{OwnedSemaphorePermit, RwLock, Semaphore};
+use tokio::task::{JoinHandle, JoinSet};
 use tokio_util::sync::CancellationToken;
-use tracing::{debug, error, info, instrument, warn};
+use tracing::{debug, error, info, instrument, warn, Instrument};
 
 use crate::errors::InfraError;
 use crate::observability::metrics::PerformanceMetrics;
 use crate::observability::MetricsResult;
+use crate::scheduling::clock::{spawn_cron_loop, CronLoopConfig, CronTrigger};
 use crate::scheduling::error::{SchedulerError, SchedulerResult};
 
 /// Trait representing a classification job.
@@ -75,12 +81,16 @@ pub struct ClassificationSchedulerConfig {
     pub cron_expression: String,
     /// Timeout applied to a single job execution.
     pub job_timeout: Duration,
-    /// Timeout for starting the underlying scheduler.
-    pub start_timeout: Duration,
-    /// Timeout for stopping the scheduler.
+    /// Timeout for the cron loop to exit after cancellation.
     pub stop_timeout: Duration,
     /// Timeout for awaiting the monitor task join handle.
     pub join_timeout: Duration,
+    /// Maximum number of runs executing at once (values below 1 are treated
+    /// as 1).
+    pub max_in_flight: usize,
+    /// What to do with triggers that fire while `max_in_flight` runs are
+    /// executing.
+    pub overlap_policy: OverlapPolicy,
 }
 
 impl Default for ClassificationSchedulerConfig {
@@ -88,21 +98,107 @@ impl Default for ClassificationSchedulerConfig {
         Self {
             cron_expression: "0 */10 * * * *".into(), // every 10 minutes
             job_timeout: Duration::from_secs(600),    // 10 minutes
-            start_timeout: Duration::from_secs(5),
             stop_timeout: Duration::from_secs(5),
             join_timeout: Duration::from_secs(5),
+            max_in_flight: 1,
+            overlap_policy: OverlapPolicy::default(),
         }
     }
 }
 
+/// Handling of triggers that fire while the scheduler is at capacity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverlapPolicy {
+    /// Drop the trigger and record a skipped run.
+    #[default]
+    Skip,
+    /// Start the run once a slot frees up. At most `max_queued` triggers wait
+    /// at a time; triggers beyond that are skipped.
+    Queue {
+        /// Maximum number of waiting triggers
+        max_queued: usize,
+    },
+}
+
+/// Admission control for runs: in-flight slots, queued triggers and the
+/// spawned run tasks.
+struct RunGate {
+    slots: Arc<Semaphore>,
+    queued: AtomicUsize,
+    policy: OverlapPolicy,
+    runs: Mutex<JoinSet<()>>,
+}
+
+/// How a trigger was admitted
+enum Admission {
+    /// A slot was free
+    Started(OwnedSemaphorePermit),
+    /// Waiting for a slot
+    Queued,
+    /// At capacity and not queued
+    Skipped,
+}
+
+impl RunGate {
+    fn new(max_in_flight: usize, policy: OverlapPolicy) -> Self {
+        Self {
+            slots: Arc::new(Semaphore::new(max_in_flight.max(1))),
+            queued: AtomicUsize::new(0),
+            policy,
+            runs: Mutex::new(JoinSet::new()),
+        }
+    }
+
+    fn admit(&self) -> Admission {
+        if let Ok(permit) = Arc::clone(&self.slots).try_acquire_owned() {
+            return Admission::Started(permit);
+        }
+
+        match self.policy {
+            OverlapPolicy::Skip => Admission::Skipped,
+            OverlapPolicy::Queue { max_queued } => {
+                let reserved = self.queued.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+                    (n < max_queued).then_some(n + 1)
+                });
+                if reserved.is_ok() {
+                    Admission::Queued
+                } else {
+                    Admission::Skipped
+                }
+            }
+        }
+    }
+
+    fn runs(&self) -> MutexGuard<'_, JoinSet<()>> {
+        self.runs.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Track a spawned run, reaping runs that already finished.
+    fn spawn(&self, run: impl std::future::Future<Output = ()> + Send + 'static) {
+        let mut runs = self.runs();
+        while runs.try_join_next().is_some() {}
+        runs.spawn(run);
+    }
+
+    /// Take every tracked run, leaving the gate empty.
+    fn take_runs(&self) -> JoinSet<()> {
+        std::mem::take(&mut *self.runs())
+    }
+}
+
+/// Cron loop task handle, shared with `start`/`stop`
+type LoopHandle = Arc<RwLock<Option<JoinHandle<()>>>>;
+
 /// Classification scheduler with explicit lifecycle management.
 pub struct ClassificationScheduler {
-    scheduler: Arc<RwLock<Option<JobScheduler>>>,
+    scheduler: LoopHandle,
     config: ClassificationSchedulerConfig,
     monitor_handle: Option<JoinHandle<()>>,
     cancellation: CancellationToken,
     metrics: Arc<PerformanceMetrics>,
     job: Arc<dyn ClassificationJob>,
+    clock: Arc<dyn Clock>,
+    gate: Arc<RunGate>,
 }
 
 impl ClassificationScheduler {
@@ -122,13 +218,29 @@ impl ClassificationScheduler {
         job: Arc<dyn ClassificationJob>,
         metrics: Arc<PerformanceMetrics>,
     ) -> SchedulerResult<Self> {
+        Self::with_clock(config, job, metrics, Arc::new(SystemClock)).await
+    }
+
+    /// Create a scheduler whose schedule is driven by `clock`.
+    ///
+    /// Production code uses [`SystemClock`] via [`Self::with_config`]; tests
+    /// pass a `MockClock` and advance it to trigger runs without sleeping.
+    pub async fn with_clock(
+        config: ClassificationSchedulerConfig,
+        job: Arc<dyn ClassificationJob>,
+        metrics: Arc<PerformanceMetrics>,
+        clock: Arc<dyn Clock>,
+    ) -> SchedulerResult<Self> {
+        let gate = Arc::new(RunGate::new(config.max_in_flight, config.overlap_policy));
         let scheduler = Self {
             scheduler: Arc::new(RwLock::new(None)),
+            gate,
             config,
             monitor_handle: None,
             cancellation: CancellationToken::new(),
             metrics,
             job,
+            clock,
         };
         Ok(scheduler)
     }
@@ -142,14 +254,7 @@ impl ClassificationScheduler {
 
         self.cancellation = CancellationToken::new();
 
-        let scheduler_instance = self.build_scheduler().await?;
-        let start_timeout = self.config.start_timeout;
-
-        let start_result = tokio::time::timeout(start_timeout, scheduler_instance.start())
-            .await
-            .map_err(|source| SchedulerError::Timeout { duration: start_timeout, source })?;
-
-        start_result.map_err(|source| SchedulerError::StartFailed { source })?;
+        let scheduler_instance = self.build_scheduler()?;
 
         {
             let mut guard = self.scheduler.write().await;
@@ -182,18 +287,21 @@ impl ClassificationScheduler {
             guard.take()
         };
 
-        let mut scheduler = match scheduler {
+        let scheduler = match scheduler {
             Some(scheduler) => scheduler,
             None => return Err(SchedulerError::NotRunning),
         };
 
         let stop_timeout = self.config.stop_timeout;
-        let stop_result =
-            tokio::time::timeout(stop_timeout, async move { scheduler.shutdown().await })
-                .await
-                .map_err(|source| SchedulerError::Timeout { duration: stop_timeout, source })?;
+        tokio::time::timeout(stop_timeout, scheduler)
+            .await
+            .map_err(|source| SchedulerError::Timeout { duration: stop_timeout, source })??;
 
-        stop_result.map_err(|source| SchedulerError::StopFailed { source })?;
+        // Runs observe the cancellation; wait for them to unwind
+        let mut runs = self.gate.take_runs();
+        tokio::time::timeout(stop_timeout, async { while runs.join_next().await.is_some() {} })
+            .await
+            .map_err(|source| SchedulerError::Timeout { duration: stop_timeout, source })?;
 
         if let Some(handle) = self.monitor_handle.take() {
             let join_timeout = self.config.join_timeout;
@@ -211,63 +319,85 @@ impl ClassificationScheduler {
         self.monitor_handle.as_ref().is_some_and(|handle| !handle.is_finished())
     }
 
-    async fn build_scheduler(&self) -> SchedulerResult<JobScheduler> {
-        let scheduler = JobScheduler::new()
-            .await
-            .map_err(|source| SchedulerError::CreationFailed { source })?;
-        let cron_expr = self.config.cron_expression.clone();
+    fn build_scheduler(&self) -> SchedulerResult<JoinHandle<()>> {
+        let trigger = CronTrigger::parse(&self.config.cron_expression)?;
         let metrics = self.metrics.clone();
         let job = self.job.clone();
         let job_timeout = self.config.job_timeout;
+        let gate = Arc::clone(&self.gate);
+        let cancel = self.cancellation.clone();
 
-        let job_definition = Job::new_async(cron_expr.as_str(), move |_id, _lock| {
+        let run = move || {
+            let gate = Arc::clone(&gate);
             let metrics = metrics.clone();
             let job = job.clone();
+            let cancel = cancel.clone();
 
-            Box::pin(async move {
-                log_metric(metrics.record_call(), "scheduler.classification.job.invoked");
-                let started = Instant::now();
-
-                match tokio::time::timeout(job_timeout, job.run()).await {
-                    Ok(Ok(())) => {
+            async move {
+                let permit = match gate.admit() {
+                    Admission::Started(permit) => Some(permit),
+                    Admission::Queued => {
                         log_metric(
-                            metrics.record_fetch_time(started.elapsed()),
-                            "scheduler.classification.job.duration",
+                            metrics.record_scheduler_run_queued(),
+                            "scheduler.classification.run.queued",
                         );
-                        debug!("Classification job finished successfully");
+                        info!("Classification run still in progress; queueing trigger");
+                        None
                     }
-                    Ok(Err(err)) => {
+                    Admission::Skipped => {
                         log_metric(
-                            metrics.record_fetch_error(),
-                            "scheduler.classification.job.error",
+                            metrics.record_scheduler_run_skipped(),
+                            "scheduler.classification.run.skipped",
                         );
-                        log_metric(
-                            metrics.record_fetch_time(started.elapsed()),
-                            "scheduler.classification.job.duration",
-                        );
-                        error!(error = ?err, "Classification job failed");
+                        warn!("Classification run still in progress; skipping trigger");
+                        return;
                     }
-                    Err(elapsed) => {
-                        log_metric(
-                            metrics.record_fetch_timeout(),
-                            "scheduler.classification.job.timeout",
-                        );
-                        warn!(timeout_secs = job_timeout.as_secs(), "Classification job timed out");
-                        debug!(elapsed = ?elapsed, "Timeout details");
+                };
+
+                // The run outlives this trigger so the cron loop keeps firing;
+                // it stays inside the trigger's span
+                let slots = Arc::clone(&gate.slots);
+                let queued_gate = Arc::clone(&gate);
+                let execution = async move {
+                    let _permit = match permit {
+                        Some(permit) => permit,
+                        None => {
+                            // Cancellation wins over a slot freed by a
+                            // cancelled run, so stopping never starts a run
+                            let acquired = tokio::select! {
+                                biased;
+                                _ = cancel.cancelled() => None,
+                                permit = slots.acquire_owned() => permit.ok(),
+                            };
+                            queued_gate.queued.fetch_sub(1, Ordering::SeqCst);
+                            match acquired {
+                                Some(permit) => permit,
+                                None => return,
+                            }
+                        }
+                    };
+
+                    tokio::select! {
+                        biased;
+                        _ = cancel.cancelled() => debug!("Classification run cancelled"),
+                        _ = execute_job(job.as_ref(), job_timeout, &metrics) => {}
                     }
-                }
-            })
-        })
-        .map_err(|source| SchedulerError::JobRegistrationFailed { source })?;
+                };
+                gate.spawn(execution.in_current_span());
+            }
+        };
 
-        let job_id = job_definition.guid();
-        scheduler
-            .add(job_definition)
-            .await
-            .map_err(|source| SchedulerError::JobRegistrationFailed { source })?;
+        let config = CronLoopConfig {
+            scheduler: "classification",
+            trigger,
+            clock: Arc::clone(&self.clock),
+            cancel: self.cancellation.clone(),
+            metrics: self.metrics.clone(),
+        };
+        let handle = spawn_cron_loop(config, run);
 
-        debug!(cron = %self.config.cron_expression, job_id = %job_id, "Registered classification job");
-        Ok(scheduler)
+        debug!(cron = %self.config.cron_expression, "Registered classification job");
+        Ok(handle)
     }
 
     async fn monitor_task(cancel: CancellationToken, metrics: Arc<PerformanceMetrics>) {
@@ -281,6 +411,29 @@ impl ClassificationScheduler {
     }
 }
 
+/// Run `job` once under `job_timeout`, recording failures and timeouts.
+async fn execute_job(
+    job: &dyn ClassificationJob,
+    job_timeout: Duration,
+    metrics: &PerformanceMetrics,
+) {
+    log_metric(metrics.record_call(), "scheduler.classification.job.invoked");
+    match tokio::time::timeout(job_timeout, job.run()).await {
+        Ok(Ok(())) => {
+            debug!("Classification job finished successfully");
+        }
+        Ok(Err(err)) => {
+            log_metric(metrics.record_fetch_error(), "scheduler.classification.job.error");
+            error!(error = ?err, "Classification job failed");
+        }
+        Err(elapsed) => {
+            log_metric(metrics.record_fetch_timeout(), "scheduler.classification.job.timeout");
+            warn!(timeout_secs = job_timeout.as_secs(), "Classification job timed out");
+            debug!(elapsed = ?elapsed, "Timeout details");
+        }
+    }
+}
+
 fn log_metric(result: MetricsResult<()>, metric: &'static str) {
     if let Err(err) = result {
         warn!(metric = metric, error = ?err, "Failed to record scheduler metric");
@@ -300,9 +453,11 @@ impl Drop for ClassificationScheduler {
 mod tests {
     use std::sync::atomic::{AtomicUsize, Ordering};
 
+    use pulsearc_common::testing::MockClock;
     use pulsearc_domain::PulseArcError;
 
     use super::*;
+    use crate::scheduling::clock::until_parked;
 
     struct CountingClassificationJob {
         runs: AtomicUsize,
@@ -330,9 +485,10 @@ mod tests {
         ClassificationSchedulerConfig {
             cron_expression: "*/1 * * * * *".into(), // every second
             job_timeout: Duration::from_secs(2),
-            start_timeout: Duration::from_secs(2),
             stop_timeout: Duration::from_secs(2),
             join_timeout: Duration::from_secs(2),
+            max_in_flight: 1,
+            overlap_policy: OverlapPolicy::Skip,
         }
     }
 
@@ -377,7 +533,7 @@ mod tests {
         scheduler.stop().await.expect("stop succeeds");
 
         assert!(metrics.fetch.get_error_count() >= 1, "error metric recorded");
-        assert!(metrics.fetch.get_fetch_count() >= metrics.fetch.get_error_count());
+        assert!(metrics.scheduler.get_runs_completed() >= metrics.fetch.get_error_count());
         assert!(!scheduler.is_running());
     }
 
@@ -410,4 +566,157 @@ mod tests {
         scheduler.start().await.expect("start again");
         scheduler.stop().await.expect("stop again");
     }
+
+    #[tokio::test(start_paused = true)]
+    async fn mock_clock_crossing_cron_boundary_runs_job_once() {
+        let metrics = Arc::new(PerformanceMetrics::new());
+        let job = Arc::new(CountingClassificationJob::new());
+        let clock = Arc::new(MockClock::new());
+        let config = ClassificationSchedulerConfig {
+            cron_expression: "0 0 * * * *".into(), // hourly
+            ..fast_config()
+        };
+        let mut scheduler =
+            ClassificationScheduler::with_clock(config, job.clone(), metrics, clock.clone())
+                .await
+                .expect("scheduler created");
+
+        scheduler.start().await.expect("start succeeds");
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert_eq!(job.run_count(), 0, "job must not run before the boundary");
+
+        clock.advance(Duration::from_secs(3600));
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert_eq!(job.run_count(), 1);
+
+        scheduler.stop().await.expect("stop succeeds");
+    }
+
+    /// Job that blocks until released, counting how many runs started
+    struct SlowClassificationJob {
+        started: AtomicUsize,
+        release: Semaphore,
+    }
+
+    impl SlowClassificationJob {
+        fn new() -> Self {
+            Self { started: AtomicUsize::new(0), release: Semaphore::new(0) }
+        }
+
+        fn started(&self) -> usize {
+            self.started.load(Ordering::SeqCst)
+        }
+
+        fn release_one(&self) {
+            self.release.add_permits(1);
+        }
+    }
+
+    #[async_trait]
+    impl ClassificationJob for SlowClassificationJob {
+        async fn run(&self) -> Result<(), InfraError> {
+            self.started.fetch_add(1, Ordering::SeqCst);
+            if let Ok(permit) = self.release.acquire().await {
+                permit.forget();
+            }
+            Ok(())
+        }
+    }
+
+    /// Hourly scheduler on a mock clock running `job` with `overlap_policy`
+    async fn overlapping_scheduler(
+        job: Arc<SlowClassificationJob>,
+        metrics: Arc<PerformanceMetrics>,
+        overlap_policy: OverlapPolicy,
+    ) -> (ClassificationScheduler, Arc<MockClock>) {
+        let clock = Arc::new(MockClock::new());
+        let config = ClassificationSchedulerConfig {
+            cron_expression: "0 0 * * * *".into(), // hourly
+            job_timeout: Duration::from_secs(86_400),
+            overlap_policy,
+            ..fast_config()
+        };
+        let mut scheduler =
+            ClassificationScheduler::with_clock(config, job, metrics, clock.clone())
+                .await
+                .expect("scheduler created");
+        scheduler.start().await.expect("start succeeds");
+        (scheduler, clock)
+    }
+
+    /// Cross the next hourly boundary and let the scheduler react
+    async fn fire_next_trigger(clock: &MockClock) {
+        until_parked(clock).await;
+        clock.advance(Duration::from_secs(3600));
+        tokio::time::sleep(Duration::from_secs(5)).await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn trigger_during_slow_run_is_skipped_in_skip_mode() {
+        let metrics = Arc::new(PerformanceMetrics::new());
+        let job = Arc::new(SlowClassificationJob::new());
+        let (mut scheduler, clock) =
+            overlapping_scheduler(job.clone(), metrics.clone(), OverlapPolicy::Skip).await;
+
+        fire_next_trigger(&clock).await;
+        assert_eq!(job.started(), 1);
+
+        fire_next_trigger(&clock).await;
+        assert_eq!(job.started(), 1, "second trigger must not start a run");
+        assert_eq!(metrics.scheduler.get_runs_skipped(), 1);
+        assert_eq!(metrics.scheduler.get_runs_queued(), 0);
+
+        job.release_one();
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        assert_eq!(job.started(), 1, "skipped trigger is not replayed");
+
+        fire_next_trigger(&clock).await;
+        assert_eq!(job.started(), 2, "next trigger runs once the slot is free");
+
+        job.release_one();
+        scheduler.stop().await.expect("stop succeeds");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn trigger_during_slow_run_is_queued_in_queue_mode() {
+        let metrics = Arc::new(PerformanceMetrics::new());
+        let job = Arc::new(SlowClassificationJob::new());
+        let (mut scheduler, clock) = overlapping_scheduler(
+            job.clone(),
+            metrics.clone(),
+            OverlapPolicy::Queue { max_queued: 1 },
+        )
+        .await;
+
+        fire_next_trigger(&clock).await;
+        fire_next_trigger(&clock).await;
+        assert_eq!(job.started(), 1, "queued trigger waits for the running job");
+        assert_eq!(metrics.scheduler.get_runs_queued(), 1);
+
+        fire_next_trigger(&clock).await;
+        assert_eq!(metrics.scheduler.get_runs_skipped(), 1, "queue bound reached");
+
+        job.release_one();
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        assert_eq!(job.started(), 2, "queued trigger runs when the slot frees up");
+
+        job.release_one();
+        scheduler.stop().await.expect("stop succeeds");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stop_cancels_slow_run() {
+        let metrics = Arc::new(PerformanceMetrics::new());
+        let job = Arc::new(SlowClassificationJob::new());
+        let (mut scheduler, clock) =
+            overlapping_scheduler(job.clone(), metrics, OverlapPolicy::Queue { max_queued: 1 })
+                .await;
+
+        fire_next_trigger(&clock).await;
+        fire_next_trigger(&clock).await;
+
+        scheduler.stop().await.expect("stop does not wait for blocked runs");
+        assert_eq!(job.started(), 1);
+        assert!(!scheduler.is_running());
+    }
 }
@@ -0,0 +1,212 @@
+//! Clock-driven scheduling primitives
+//!
+//! Schedulers compute their next fire time and wait for it through an
+//! injectable [`Clock`], so tests can drive them with a `MockClock` instead of
+//! sleeping in real time. Production schedulers use
+//! [`SystemClock`](pulsearc_common::testing::SystemClock).
+//!
+//! Each run executes inside a `scheduler.run` tracing span (see
+//! [`run_span`](super::run_span)).
+//!
+//! Waits are sliced: the clock is re-read at least every `MAX_WAIT_SLICE`, so
+//! an advanced `MockClock` (or a wall-clock jump after the machine wakes from
+//! sleep) is observed promptly.
+
+use std::future::Future;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use chrono::{DateTime, Utc};
+use cron::Schedule;
+use pulsearc_common::testing::Clock;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, warn};
+
+use crate::observability::metrics::PerformanceMetrics;
+use crate::scheduling::error::{SchedulerError, SchedulerResult};
+use crate::scheduling::run_span::run_in_span;
+
+/// Longest single sleep before the clock is re-read
+const MAX_WAIT_SLICE: Duration = Duration::from_secs(1);
+
+/// Parsed cron schedule (six fields, seconds first)
+#[derive(Debug, Clone)]
+pub(crate) struct CronTrigger {
+    expression: String,
+    schedule: Schedule,
+}
+
+impl CronTrigger {
+    /// Parse a cron expression such as `"0 */15 * * * *"`.
+    pub(crate) fn parse(expression: &str) -> SchedulerResult<Self> {
+        let schedule = Schedule::from_str(expression).map_err(|source| {
+            SchedulerError::InvalidSchedule { expression: expression.to_string(), source }
+        })?;
+        Ok(Self { expression: expression.to_string(), schedule })
+    }
+
+    /// First fire time strictly after `after`.
+    pub(crate) fn next_after(&self, after: SystemTime) -> Option<SystemTime> {
+        let after = DateTime::<Utc>::from(after);
+        self.schedule.after(&after).next().map(SystemTime::from)
+    }
+}
+
+/// Sleep until `clock` reaches `deadline`.
+pub(crate) async fn sleep_until(clock: &dyn Clock, deadline: SystemTime) {
+    let _wait = WaitGuard::new(clock);
+    while let Ok(remaining) = deadline.duration_since(clock.system_time()) {
+        if remaining.is_zero() {
+            break;
+        }
+        tokio::time::sleep(remaining.min(MAX_WAIT_SLICE)).await;
+    }
+}
+
+/// Reports a wait to the clock for as long as it is alive, so a wait dropped
+/// mid-sleep (e.g. on cancellation) is still reported as finished.
+struct WaitGuard<'a>(&'a dyn Clock);
+
+impl<'a> WaitGuard<'a> {
+    fn new(clock: &'a dyn Clock) -> Self {
+        clock.wait_started();
+        Self(clock)
+    }
+}
+
+impl Drop for WaitGuard<'_> {
+    fn drop(&mut self) {
+        self.0.wait_finished();
+    }
+}
+
+/// Sleep for `duration` as measured by `clock`.
+pub(crate) async fn sleep(clock: &dyn Clock, duration: Duration) {
+    sleep_until(clock, clock.system_time() + duration).await;
+}
+
+/// Schedule, clock and bookkeeping for a [`spawn_cron_loop`] task
+pub(crate) struct CronLoopConfig {
+    /// Scheduler name recorded on each run span
+    pub(crate) scheduler: &'static str,
+    pub(crate) trigger: CronTrigger,
+    pub(crate) clock: Arc<dyn Clock>,
+    pub(crate) cancel: CancellationToken,
+    /// Receives the duration of every run
+    pub(crate) metrics: Arc<PerformanceMetrics>,
+}
+
+/// Spawn a task that calls `run` at each fire time of `trigger` until
+/// `cancel` fires.
+///
+/// The next fire time is computed from the clock after each run, so fire
+/// times that pass while a run is in progress (or that the clock jumps over)
+/// are skipped rather than replayed. Cancellation also interrupts a run in
+/// progress.
+///
+/// Each run is wrapped in a span named after `scheduler` and its duration is
+/// recorded into `metrics`.
+pub(crate) fn spawn_cron_loop<F, Fut>(config: CronLoopConfig, run: F) -> JoinHandle<()>
+where
+    F: Fn() -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let CronLoopConfig { scheduler, trigger, clock, cancel, metrics } = config;
+    tokio::spawn(async move {
+        loop {
+            let Some(fire_at) = trigger.next_after(clock.system_time()) else {
+                warn!(cron = %trigger.expression, "Cron schedule has no upcoming fire time");
+                break;
+            };
+
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                _ = sleep_until(clock.as_ref(), fire_at) => {}
+            }
+
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                _ = run_in_span(scheduler, fire_at, &metrics, run()) => {}
+            }
+        }
+
+        debug!(cron = %trigger.expression, "Cron loop exited");
+    })
+}
+
+/// Yield until a wait is parked on `clock`.
+///
+/// Call before advancing a `MockClock` so the loop under test has computed
+/// its next fire time from the clock's current reading.
+#[cfg(test)]
+pub(crate) async fn until_parked(clock: &pulsearc_common::testing::MockClock) {
+    for _ in 0..10_000 {
+        if clock.waiters() > 0 {
+            return;
+        }
+        tokio::task::yield_now().await;
+    }
+    panic!("nothing is waiting on the clock");
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use pulsearc_common::testing::MockClock;
+
+    use super::*;
+
+    #[test]
+    fn next_after_returns_following_boundary() {
+        let trigger = CronTrigger::parse("0 */15 * * * *").expect("valid cron");
+        let start = SystemTime::from(
+            DateTime::parse_from_rfc3339("2025-01-01T10:07:30Z").expect("valid timestamp"),
+        );
+
+        let next = trigger.next_after(start).expect("has next fire time");
+        assert_eq!(DateTime::<Utc>::from(next).to_rfc3339(), "2025-01-01T10:15:00+00:00");
+    }
+
+    #[test]
+    fn invalid_expression_is_rejected() {
+        let err = CronTrigger::parse("not a cron").expect_err("invalid cron");
+        assert!(matches!(err, SchedulerError::InvalidSchedule { .. }));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cron_loop_fires_once_per_boundary_crossed() {
+        let clock = Arc::new(MockClock::new());
+        let runs = Arc::new(AtomicUsize::new(0));
+        let cancel = CancellationToken::new();
+        let trigger = CronTrigger::parse("0 0 * * * *").expect("valid cron"); // hourly
+
+        let counter = Arc::clone(&runs);
+        let metrics = Arc::new(PerformanceMetrics::new());
+        let config = CronLoopConfig {
+            scheduler: "test",
+            trigger,
+            clock: clock.clone(),
+            cancel: cancel.clone(),
+            metrics,
+        };
+        let handle = spawn_cron_loop(config, move || {
+            let counter = Arc::clone(&counter);
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        until_parked(&clock).await;
+        assert_eq!(runs.load(Ordering::SeqCst), 0, "no boundary crossed yet");
+
+        clock.advance(Duration::from_secs(3600));
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+        cancel.cancel();
+        handle.await.expect("cron loop exits cleanly");
+    }
+}
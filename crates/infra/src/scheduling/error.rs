@@ -6,7 +6,6 @@ use pulsearc_domain::PulseArcError;
 use thiserror::Error;
 use tokio::task::JoinError;
 use tokio::time::error::Elapsed;
-use tokio_cron_scheduler::JobSchedulerError;
 
 use crate::errors::InfraError;
 
@@ -21,32 +20,12 @@ pub enum SchedulerError {
     #[error("Scheduler not running")]
     NotRunning,
 
-    /// Failed to create scheduler
-    #[error("Failed to create scheduler")]
-    CreationFailed {
+    /// Cron expression could not be parsed
+    #[error("Invalid cron expression '{expression}'")]
+    InvalidSchedule {
+        expression: String,
         #[source]
-        source: JobSchedulerError,
-    },
-
-    /// Failed to start scheduler
-    #[error("Failed to start scheduler")]
-    StartFailed {
-        #[source]
-        source: JobSchedulerError,
-    },
-
-    /// Failed to stop scheduler
-    #[error("Failed to stop scheduler")]
-    StopFailed {
-        #[source]
-        source: JobSchedulerError,
-    },
-
-    /// Failed to register job
-    #[error("Failed to register job")]
-    JobRegistrationFailed {
-        #[source]
-        source: JobSchedulerError,
+        source: cron::error::Error,
     },
 
     /// Operation timed out
@@ -57,6 +36,18 @@ pub enum SchedulerError {
         source: Elapsed,
     },
 
+    /// Job run returned an error
+    #[error("Job failed: {message}")]
+    JobFailed { message: String },
+
+    /// Job run did not finish within its timeout
+    #[error("Job timed out after {duration:?}")]
+    JobTimedOut { duration: Duration },
+
+    /// Scheduler was stopped while the job was running
+    #[error("Job cancelled")]
+    Cancelled,
+
     /// Task join failed
     #[error("Task join failed")]
     JoinFailed {
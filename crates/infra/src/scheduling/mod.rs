@@ -4,6 +4,7 @@
 //! - Block generation scheduling (inference blocks)
 //! - Classification scheduling (periodic classification jobs)
 //! - Sync scheduling (API outbox processing - always compiled)
+//! - Vacuum scheduling (incremental vacuum above a fragmentation threshold)
 //! - SAP scheduler (batch forwarding - feature-gated)
 //! - Calendar scheduler (calendar sync - feature-gated)
 //!
@@ -13,11 +14,27 @@
 //! - Cancellation token support
 //! - Timeout wrapping on all async operations
 //! - Structured tracing with PerformanceMetrics integration
+//!
+//! Each job run executes inside a `scheduler.run` tracing span carrying the
+//! scheduler name, run id and trigger time; its duration is recorded into
+//! `PerformanceMetrics`.
+//!
+//! Block and vacuum schedulers also expose `trigger_now()` to run their job
+//! out-of-band. A trigger that arrives while a run is in progress waits for
+//! that run rather than starting a second one.
+//!
+//! Next-fire computation and waits go through an injectable
+//! `pulsearc_common::testing::Clock`. Each scheduler has a `with_clock`
+//! constructor so tests can drive it with a `MockClock` instead of sleeping.
 
 pub mod block_scheduler;
 pub mod classification_scheduler;
+mod clock;
 pub mod error;
+mod on_demand;
+mod run_span;
 pub mod sync_scheduler;
+pub mod vacuum_scheduler;
 
 #[cfg(feature = "sap")]
 pub mod sap_scheduler;
@@ -27,11 +44,14 @@ pub mod calendar_scheduler;
 
 pub use block_scheduler::{BlockJob, BlockScheduler, BlockSchedulerConfig};
 #[cfg(feature = "calendar")]
-pub use calendar_scheduler::{CalendarScheduler, CalendarSchedulerConfig};
+pub use calendar_scheduler::{
+    AccountRateLimit, CalendarAccountSync, CalendarScheduler, CalendarSchedulerConfig,
+};
 pub use classification_scheduler::{
-    ClassificationJob, ClassificationScheduler, ClassificationSchedulerConfig,
+    ClassificationJob, ClassificationScheduler, ClassificationSchedulerConfig, OverlapPolicy,
 };
 pub use error::{SchedulerError, SchedulerResult};
 #[cfg(feature = "sap")]
 pub use sap_scheduler::{SapScheduler, SapSchedulerConfig};
 pub use sync_scheduler::{SyncScheduler, SyncSchedulerConfig};
+pub use vacuum_scheduler::{VacuumScheduler, VacuumSchedulerConfig};
@@ -0,0 +1,168 @@
+//! Coalescing of scheduled and on-demand job runs
+//!
+//! A scheduler's `trigger_now()` runs its job out-of-band. When a run is
+//! already in progress - fired by the cron loop or by another trigger - the
+//! caller waits for that run instead of starting a second one, so rapid
+//! triggers never double-run the job.
+//!
+//! The run itself executes on its own task: a caller that stops waiting
+//! (e.g. a dropped Tauri command future) does not abort the run for the other
+//! waiters.
+
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tracing::Instrument;
+
+use crate::scheduling::error::SchedulerError;
+
+/// Result of one job run, shared with every caller waiting on it
+pub(crate) type RunOutcome = Result<(), RunFailure>;
+
+/// Why a job run did not complete successfully
+///
+/// Cloneable (unlike [`SchedulerError`]) so one outcome can be handed to
+/// every coalesced caller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum RunFailure {
+    /// The job returned an error
+    Failed(String),
+    /// The job did not finish within its timeout
+    TimedOut(Duration),
+    /// The scheduler was stopped while the job was running
+    Cancelled,
+    /// The run task ended without reporting an outcome (panic)
+    Aborted,
+}
+
+impl From<RunFailure> for SchedulerError {
+    fn from(failure: RunFailure) -> Self {
+        match failure {
+            RunFailure::Failed(message) => SchedulerError::JobFailed { message },
+            RunFailure::TimedOut(duration) => SchedulerError::JobTimedOut { duration },
+            RunFailure::Cancelled => SchedulerError::Cancelled,
+            RunFailure::Aborted => {
+                SchedulerError::JobFailed { message: "job run aborted".to_string() }
+            }
+        }
+    }
+}
+
+/// Outcome receiver of the run in progress, if any
+type InFlightRun = Option<watch::Receiver<Option<RunOutcome>>>;
+type InFlight = Arc<Mutex<InFlightRun>>;
+
+/// Ensures at most one job run is in progress at a time
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RunCoalescer {
+    in_flight: InFlight,
+}
+
+impl RunCoalescer {
+    /// Wait for the in-progress run, or start one with `start` if none is.
+    ///
+    /// `start` is only called when a new run begins; its future runs on a
+    /// spawned task in the caller's span.
+    pub(crate) async fn run<F, Fut>(&self, start: F) -> RunOutcome
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = RunOutcome> + Send + 'static,
+    {
+        let mut outcome = self.join_or_start(start);
+        let result = match outcome.wait_for(Option::is_some).await {
+            Ok(outcome) => outcome.clone().unwrap_or(Err(RunFailure::Aborted)),
+            Err(_) => Err(RunFailure::Aborted),
+        };
+        result
+    }
+
+    fn join_or_start<F, Fut>(&self, start: F) -> watch::Receiver<Option<RunOutcome>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = RunOutcome> + Send + 'static,
+    {
+        let mut slot = lock(&self.in_flight);
+        if let Some(outcome) = slot.as_ref() {
+            return outcome.clone();
+        }
+
+        let (tx, rx) = watch::channel(None);
+        *slot = Some(rx.clone());
+
+        let release = ReleaseOnDrop(Arc::clone(&self.in_flight));
+        let run = start();
+        tokio::spawn(
+            async move {
+                let outcome = run.await;
+                // Free the slot before publishing so a trigger arriving after
+                // completion starts a fresh run
+                drop(release);
+                tx.send_replace(Some(outcome));
+            }
+            .in_current_span(),
+        );
+
+        rx
+    }
+}
+
+/// Clears the in-flight slot when the run task ends, including on panic
+struct ReleaseOnDrop(InFlight);
+
+impl Drop for ReleaseOnDrop {
+    fn drop(&mut self) {
+        *lock(&self.0) = None;
+    }
+}
+
+fn lock(in_flight: &InFlight) -> MutexGuard<'_, InFlightRun> {
+    in_flight.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn concurrent_callers_share_one_run() {
+        let coalescer = RunCoalescer::default();
+        let runs = Arc::new(AtomicUsize::new(0));
+
+        let start = || {
+            let runs = Arc::clone(&runs);
+            move || async move {
+                runs.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                Err(RunFailure::Failed("boom".into()))
+            }
+        };
+
+        let (first, second) = tokio::join!(coalescer.run(start()), coalescer.run(start()));
+        assert_eq!(first, Err(RunFailure::Failed("boom".into())));
+        assert_eq!(second, first);
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+        // Once the run has finished, the next trigger starts a new one
+        let _ = coalescer.run(start()).await;
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+    }
+
+    async fn panicking_job() -> RunOutcome {
+        panic!("job panicked")
+    }
+
+    #[tokio::test]
+    async fn panicking_run_releases_slot() {
+        let coalescer = RunCoalescer::default();
+
+        let outcome = coalescer.run(panicking_job).await;
+        assert_eq!(outcome, Err(RunFailure::Aborted));
+
+        let outcome = coalescer.run(|| async { Ok(()) }).await;
+        assert_eq!(outcome, Ok(()));
+    }
+}
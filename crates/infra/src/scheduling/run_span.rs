@@ -0,0 +1,176 @@
+//! Tracing spans around scheduler job runs
+//!
+//! Every job run executes inside a `scheduler.run` span carrying the
+//! scheduler name, a fresh run id and the trigger time. DB, HTTP and LLM calls
+//! made by the job open their spans inside it, so a slow run can be followed
+//! down to the operations it triggered. When the run completes, its duration
+//! is written to the span's `duration_ms` field and recorded as a scheduler
+//! run in [`PerformanceMetrics`].
+
+use std::future::Future;
+use std::time::{Instant, SystemTime};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use tracing::{info_span, warn, Instrument, Span};
+use uuid::Uuid;
+
+use crate::observability::metrics::PerformanceMetrics;
+
+/// Span for one run of `scheduler` fired at `trigger_time`
+pub(crate) fn run_span(scheduler: &'static str, trigger_time: SystemTime) -> Span {
+    let trigger_time =
+        DateTime::<Utc>::from(trigger_time).to_rfc3339_opts(SecondsFormat::Millis, true);
+    info_span!(
+        "scheduler.run",
+        scheduler,
+        run_id = %Uuid::now_v7(),
+        trigger_time = %trigger_time,
+        duration_ms = tracing::field::Empty,
+    )
+}
+
+/// Drive `run` inside a [`run_span`] and record how long it took
+pub(crate) async fn run_in_span<Fut>(
+    scheduler: &'static str,
+    trigger_time: SystemTime,
+    metrics: &PerformanceMetrics,
+    run: Fut,
+) -> Fut::Output
+where
+    Fut: Future,
+{
+    let span = run_span(scheduler, trigger_time);
+    let started = Instant::now();
+    let output = run.instrument(span.clone()).await;
+    let elapsed = started.elapsed();
+
+    span.record("duration_ms", u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX));
+    if let Err(err) = metrics.record_scheduler_run(elapsed) {
+        warn!(scheduler, error = ?err, "Failed to record scheduler run duration");
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use pulsearc_common::testing::MockClock;
+    use tokio_util::sync::CancellationToken;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::Subscriber;
+    use tracing_subscriber::layer::{Context, SubscriberExt};
+    use tracing_subscriber::registry::LookupSpan;
+    use tracing_subscriber::Layer;
+
+    use super::*;
+    use crate::scheduling::clock::{spawn_cron_loop, until_parked, CronLoopConfig, CronTrigger};
+
+    /// Fields and parent of a span seen by [`CaptureLayer`]
+    #[derive(Debug, Clone, Default)]
+    struct CapturedSpan {
+        name: String,
+        parent: Option<String>,
+        fields: HashMap<String, String>,
+    }
+
+    /// Captured spans keyed by span id
+    type SpanMap = HashMap<u64, CapturedSpan>;
+
+    /// Layer recording every span and its fields
+    #[derive(Clone, Default)]
+    struct CaptureLayer {
+        spans: Arc<Mutex<SpanMap>>,
+    }
+
+    impl CaptureLayer {
+        fn named(&self, name: &str) -> Vec<CapturedSpan> {
+            self.spans.lock().unwrap().values().filter(|span| span.name == name).cloned().collect()
+        }
+    }
+
+    struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0.insert(field.name().to_string(), format!("{value:?}"));
+        }
+    }
+
+    impl<S> Layer<S> for CaptureLayer
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+            let parent = ctx.span(id).and_then(|span| span.parent()).map(|p| p.name().to_string());
+            let mut captured = CapturedSpan {
+                name: attrs.metadata().name().to_string(),
+                parent,
+                ..Default::default()
+            };
+            attrs.record(&mut FieldVisitor(&mut captured.fields));
+            self.spans.lock().unwrap().insert(id.into_u64(), captured);
+        }
+
+        fn on_record(&self, id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+            if let Some(captured) = self.spans.lock().unwrap().get_mut(&id.into_u64()) {
+                values.record(&mut FieldVisitor(&mut captured.fields));
+            }
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn job_run_produces_span_with_fields_and_duration() {
+        let layer = CaptureLayer::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(layer.clone()));
+
+        let metrics = Arc::new(PerformanceMetrics::new());
+        let clock = Arc::new(MockClock::new());
+        let cancel = CancellationToken::new();
+        let trigger = CronTrigger::parse("0 0 * * * *").unwrap(); // hourly
+
+        let config = CronLoopConfig {
+            scheduler: "classification",
+            trigger,
+            clock: clock.clone(),
+            cancel: cancel.clone(),
+            metrics: metrics.clone(),
+        };
+        let handle = spawn_cron_loop(config, || async {
+            let _query = info_span!("db.query").entered();
+        });
+
+        until_parked(&clock).await;
+        clock.advance(Duration::from_secs(3600));
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        cancel.cancel();
+        handle.await.unwrap();
+
+        let runs = layer.named("scheduler.run");
+        assert_eq!(runs.len(), 1);
+        let run = &runs[0];
+        assert_eq!(run.fields.get("scheduler").map(String::as_str), Some("classification"));
+        assert!(run.fields.get("run_id").is_some_and(|id| Uuid::parse_str(id).is_ok()));
+        let trigger_time = run.fields.get("trigger_time").unwrap();
+        assert!(DateTime::parse_from_rfc3339(trigger_time).is_ok());
+        assert!(trigger_time.contains(":00:00.000"), "fires on the hour: {trigger_time}");
+        assert!(run.fields.contains_key("duration_ms"));
+
+        let children = layer.named("db.query");
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].parent.as_deref(), Some("scheduler.run"));
+
+        assert_eq!(metrics.scheduler.get_runs_completed(), 1);
+        assert_eq!(metrics.fetch.get_fetch_count(), 0, "runs are not counted as fetches");
+    }
+}
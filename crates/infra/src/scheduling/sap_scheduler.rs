@@ -5,6 +5,11 @@
 //! `CLAUDE.md`: join handles are tracked, cancellation is explicit, and every
 //! asynchronous operation is wrapped in a timeout.
 //!
+//! On start, entries whose posting window passed while the scheduler was down
+//! are forwarded straight away (see [`SapSchedulerConfig::catch_up_on_start`])
+//! rather than left waiting for the next window. Entries for periods that are
+//! still closed stay deferred.
+//!
 //! Feature-gated behind `sap` feature flag.
 //!
 //! # Example
@@ -38,21 +43,24 @@
 //! # }
 //! ```
 
+use std::future::Future;
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use pulsearc_common::testing::{Clock, SystemClock};
 use pulsearc_core::OutboxQueue as OutboxQueuePort;
-use pulsearc_domain::PulseArcError;
+use pulsearc_domain::{PulseArcError, TimeEntryOutbox};
 use thiserror::Error;
 use tokio::task::JoinHandle;
-use tokio_cron_scheduler::{Job, JobScheduler};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, instrument, warn};
 
-use crate::integrations::sap::BatchForwarder;
+use crate::integrations::sap::{BatchForwarder, BatchSubmissionResult};
 use crate::observability::metrics::PerformanceMetrics;
 use crate::observability::MetricsResult;
+use crate::scheduling::clock::{spawn_cron_loop, CronLoopConfig, CronTrigger};
 use crate::scheduling::error::{SchedulerError, SchedulerResult};
+use crate::scheduling::run_span::run_in_span;
 
 /// Configuration for the SAP scheduler.
 #[derive(Debug, Clone)]
@@ -63,12 +71,13 @@ pub struct SapSchedulerConfig {
     pub batch_size: usize,
     /// Timeout applied to a single batch processing execution.
     pub job_timeout: Duration,
-    /// Timeout for starting the underlying scheduler.
-    pub start_timeout: Duration,
-    /// Timeout for stopping the scheduler.
+    /// Timeout for the cron loop to exit after cancellation.
     pub stop_timeout: Duration,
     /// Timeout for awaiting the monitor task join handle.
     pub join_timeout: Duration,
+    /// Forward entries whose posting window passed while the scheduler was
+    /// down as soon as it starts, instead of waiting for the next window.
+    pub catch_up_on_start: bool,
 }
 
 impl Default for SapSchedulerConfig {
@@ -77,9 +86,9 @@ impl Default for SapSchedulerConfig {
             cron_expression: "0 */30 * * * *".into(), // every 30 minutes
             batch_size: 50,
             job_timeout: Duration::from_secs(300),
-            start_timeout: Duration::from_secs(5),
             stop_timeout: Duration::from_secs(5),
             join_timeout: Duration::from_secs(5),
+            catch_up_on_start: true,
         }
     }
 }
@@ -109,13 +118,14 @@ impl SapBatchError {
 
 /// SAP time entry scheduler with explicit lifecycle management.
 pub struct SapScheduler {
-    scheduler: Option<JobScheduler>,
+    scheduler: Option<JoinHandle<()>>,
     config: SapSchedulerConfig,
     monitor_handle: Option<JoinHandle<()>>,
     cancellation: CancellationToken,
     metrics: Arc<PerformanceMetrics>,
     batch_forwarder: Arc<BatchForwarder>,
     outbox_repo: Arc<dyn OutboxQueuePort>,
+    clock: Arc<dyn Clock>,
 }
 
 impl SapScheduler {
@@ -140,6 +150,23 @@ impl SapScheduler {
         outbox_repo: Arc<Q>,
         metrics: Arc<PerformanceMetrics>,
     ) -> SchedulerResult<Self>
+    where
+        Q: OutboxQueuePort + 'static,
+    {
+        Self::with_clock(config, batch_forwarder, outbox_repo, metrics, Arc::new(SystemClock))
+    }
+
+    /// Create a scheduler whose schedule is driven by `clock`.
+    ///
+    /// Production code uses [`SystemClock`] via [`Self::with_config`]; tests
+    /// pass a `MockClock` and advance it to trigger runs without sleeping.
+    pub fn with_clock<Q>(
+        config: SapSchedulerConfig,
+        batch_forwarder: Arc<BatchForwarder>,
+        outbox_repo: Arc<Q>,
+        metrics: Arc<PerformanceMetrics>,
+        clock: Arc<dyn Clock>,
+    ) -> SchedulerResult<Self>
     where
         Q: OutboxQueuePort + 'static,
     {
@@ -152,6 +179,7 @@ impl SapScheduler {
             metrics,
             batch_forwarder,
             outbox_repo,
+            clock,
         };
         Ok(scheduler)
     }
@@ -165,14 +193,7 @@ impl SapScheduler {
 
         self.cancellation = CancellationToken::new();
 
-        let scheduler_instance = self.build_scheduler().await?;
-        let start_timeout = self.config.start_timeout;
-
-        let start_result = tokio::time::timeout(start_timeout, scheduler_instance.start())
-            .await
-            .map_err(|source| SchedulerError::Timeout { duration: start_timeout, source })?;
-
-        start_result.map_err(|source| SchedulerError::StartFailed { source })?;
+        let scheduler_instance = self.build_scheduler()?;
 
         self.scheduler = Some(scheduler_instance);
 
@@ -197,18 +218,15 @@ impl SapScheduler {
 
         self.cancellation.cancel();
 
-        let mut scheduler = match self.scheduler.take() {
+        let scheduler = match self.scheduler.take() {
             Some(scheduler) => scheduler,
             None => return Err(SchedulerError::NotRunning),
         };
 
         let stop_timeout = self.config.stop_timeout;
-        let stop_result =
-            tokio::time::timeout(stop_timeout, async move { scheduler.shutdown().await })
-                .await
-                .map_err(|source| SchedulerError::Timeout { duration: stop_timeout, source })?;
-
-        stop_result.map_err(|source| SchedulerError::StopFailed { source })?;
+        tokio::time::timeout(stop_timeout, scheduler)
+            .await
+            .map_err(|source| SchedulerError::Timeout { duration: stop_timeout, source })??;
 
         if let Some(handle) = self.monitor_handle.take() {
             let join_timeout = self.config.join_timeout;
@@ -227,26 +245,21 @@ impl SapScheduler {
         self.scheduler.is_some()
     }
 
-    async fn build_scheduler(&self) -> SchedulerResult<JobScheduler> {
-        let scheduler = JobScheduler::new()
-            .await
-            .map_err(|source| SchedulerError::CreationFailed { source })?;
-        let cron_expr = self.config.cron_expression.clone();
+    fn build_scheduler(&self) -> SchedulerResult<JoinHandle<()>> {
+        let trigger = CronTrigger::parse(&self.config.cron_expression)?;
         let metrics = self.metrics.clone();
         let batch_forwarder = self.batch_forwarder.clone();
         let outbox_repo = self.outbox_repo.clone();
         let job_timeout = self.config.job_timeout;
         let batch_size = self.config.batch_size;
 
-        let job_definition = Job::new_async(cron_expr.as_str(), move |_id, _lock| {
+        let run = move || {
             let metrics = metrics.clone();
             let batch_forwarder = batch_forwarder.clone();
             let outbox_repo = outbox_repo.clone();
 
-            Box::pin(async move {
+            async move {
                 log_metric(metrics.record_call(), "scheduler.sap.job.invoked");
-                let started = Instant::now();
-
                 match tokio::time::timeout(
                     job_timeout,
                     Self::process_sap_batch(batch_forwarder, outbox_repo, batch_size),
@@ -254,10 +267,6 @@ impl SapScheduler {
                 .await
                 {
                     Ok(Ok(())) => {
-                        log_metric(
-                            metrics.record_fetch_time(started.elapsed()),
-                            "scheduler.sap.job.duration",
-                        );
                         debug!(
                             scheduler = "sap",
                             event = "job_complete",
@@ -266,10 +275,6 @@ impl SapScheduler {
                     }
                     Ok(Err(err)) => {
                         log_metric(metrics.record_fetch_error(), "scheduler.sap.job.error");
-                        log_metric(
-                            metrics.record_fetch_time(started.elapsed()),
-                            "scheduler.sap.job.duration",
-                        );
                         error!(
                             scheduler = "sap",
                             error = ?err,
@@ -293,18 +298,51 @@ impl SapScheduler {
                         );
                     }
                 }
-            })
-        })
-        .map_err(|source| SchedulerError::JobRegistrationFailed { source })?;
+            }
+        };
 
-        let job_id = job_definition.guid();
-        scheduler
-            .add(job_definition)
-            .await
-            .map_err(|source| SchedulerError::JobRegistrationFailed { source })?;
+        let catch_up = self.config.catch_up_on_start.then(|| {
+            Self::run_catch_up(
+                Self::catch_up_missed_windows(
+                    self.batch_forwarder.clone(),
+                    self.outbox_repo.clone(),
+                    batch_size,
+                    trigger.clone(),
+                    self.clock.system_time(),
+                ),
+                job_timeout,
+                self.metrics.clone(),
+            )
+        });
+        let clock = Arc::clone(&self.clock);
+        let cancel = self.cancellation.clone();
+        let loop_metrics = self.metrics.clone();
 
-        debug!(cron = %self.config.cron_expression, job_id = %job_id, "Registered SAP batch processing job");
-        Ok(scheduler)
+        // Catch-up finishes before the cron loop starts so the two never
+        // forward the same entries concurrently
+        let handle = tokio::spawn(async move {
+            if let Some(catch_up) = catch_up {
+                tokio::select! {
+                    _ = cancel.cancelled() => return,
+                    _ = run_in_span("sap", clock.system_time(), &loop_metrics, catch_up) => {}
+                }
+            }
+
+            let config = CronLoopConfig {
+                scheduler: "sap",
+                trigger,
+                clock,
+                cancel,
+                metrics: loop_metrics,
+            };
+            let cron_loop = spawn_cron_loop(config, run);
+            if let Err(err) = cron_loop.await {
+                error!(scheduler = "sap", error = ?err, "SAP cron loop task failed");
+            }
+        });
+
+        debug!(cron = %self.config.cron_expression, "Registered SAP batch processing job");
+        Ok(handle)
     }
 
     async fn process_sap_batch(
@@ -334,9 +372,100 @@ impl SapScheduler {
             "Processing SAP batch"
         );
 
+        Self::submit_entries(&batch_forwarder, outbox_repo.as_ref(), &entries).await?;
+        Ok(())
+    }
+
+    /// Forward pending entries whose posting window passed before `now`.
+    ///
+    /// Entries created since the last window are left for the next scheduled
+    /// run. Returns the number of entries submitted; entries for periods that
+    /// are not open are deferred by the forwarder and stay pending.
+    async fn catch_up_missed_windows(
+        batch_forwarder: Arc<BatchForwarder>,
+        outbox_repo: Arc<dyn OutboxQueuePort>,
+        batch_size: usize,
+        trigger: CronTrigger,
+        now: SystemTime,
+    ) -> Result<usize, SapBatchError> {
+        let entries = outbox_repo
+            .dequeue_batch(batch_size)
+            .await
+            .map_err(|source| SapBatchError::Dequeue { source })?;
+
+        let missed: Vec<TimeEntryOutbox> = entries
+            .into_iter()
+            .filter(|entry| posting_window_passed(&trigger, entry.created_at, now))
+            .collect();
+
+        if missed.is_empty() {
+            debug!(
+                scheduler = "sap",
+                event = "catch_up_idle",
+                "No entries missed a posting window"
+            );
+            return Ok(0);
+        }
+
+        info!(
+            scheduler = "sap",
+            event = "catch_up_started",
+            count = missed.len(),
+            "Forwarding entries from missed posting windows"
+        );
+
+        let result = Self::submit_entries(&batch_forwarder, outbox_repo.as_ref(), &missed).await?;
+        Ok(result.successful)
+    }
+
+    /// Bound a catch-up with `job_timeout` and record how many entries it
+    /// forwarded.
+    async fn run_catch_up(
+        catch_up: impl Future<Output = Result<usize, SapBatchError>>,
+        job_timeout: Duration,
+        metrics: Arc<PerformanceMetrics>,
+    ) {
+        log_metric(metrics.record_call(), "scheduler.sap.catch_up.invoked");
+        match tokio::time::timeout(job_timeout, catch_up).await {
+            Ok(Ok(forwarded)) => {
+                log_metric(metrics.record_scheduler_catch_up(forwarded), "scheduler.sap.catch_up");
+                info!(
+                    scheduler = "sap",
+                    event = "catch_up_complete",
+                    forwarded,
+                    "SAP catch-up finished"
+                );
+            }
+            Ok(Err(err)) => {
+                log_metric(metrics.record_fetch_error(), "scheduler.sap.catch_up.error");
+                error!(
+                    scheduler = "sap",
+                    error = ?err,
+                    error_kind = err.kind(),
+                    "SAP catch-up failed"
+                );
+            }
+            Err(_) => {
+                log_metric(metrics.record_fetch_timeout(), "scheduler.sap.catch_up.timeout");
+                warn!(
+                    scheduler = "sap",
+                    event = "catch_up_timeout",
+                    timeout_secs = job_timeout.as_secs(),
+                    "SAP catch-up timed out"
+                );
+            }
+        }
+    }
+
+    /// Submit `entries` and mark each one sent or failed from the outcome.
+    async fn submit_entries(
+        batch_forwarder: &BatchForwarder,
+        outbox_repo: &dyn OutboxQueuePort,
+        entries: &[TimeEntryOutbox],
+    ) -> Result<BatchSubmissionResult, SapBatchError> {
         // Submit batch via BatchForwarder
         let result = batch_forwarder
-            .submit_batch(&entries)
+            .submit_batch(entries)
             .await
             .map_err(|source| SapBatchError::Submit { source })?;
 
@@ -345,12 +474,13 @@ impl SapScheduler {
             event = "job_finished",
             successful = result.successful,
             failed = result.failed,
+            deferred = result.deferred,
             "SAP batch submission completed"
         );
 
         // Mark entries as sent or failed based on results
-        for entry_result in result.entry_results {
-            match entry_result.status {
+        for entry_result in &result.entry_results {
+            match &entry_result.status {
                 crate::integrations::sap::EntrySubmissionStatus::Submitted { .. } => {
                     if let Err(e) = outbox_repo.mark_sent(&entry_result.outbox_id).await {
                         warn!(
@@ -375,10 +505,38 @@ impl SapScheduler {
                         );
                     }
                 }
+                crate::integrations::sap::EntrySubmissionStatus::DeadLettered { error } => {
+                    // The DLQ already marked the outbox entry failed; marking it
+                    // again would schedule a retry
+                    warn!(
+                        scheduler = "sap",
+                        id = %entry_result.outbox_id,
+                        sap_error = %error,
+                        "Entry moved to dead letter queue"
+                    );
+                }
+                crate::integrations::sap::EntrySubmissionStatus::Deferred { date, reason } => {
+                    // Left pending so the next run re-checks the period
+                    debug!(
+                        scheduler = "sap",
+                        id = %entry_result.outbox_id,
+                        date = %date,
+                        reason = ?reason,
+                        "Entry deferred until its posting period is open"
+                    );
+                }
+                crate::integrations::sap::EntrySubmissionStatus::Validated => {
+                    // Only produced by validate-only forwarders; nothing was sent
+                    debug!(
+                        scheduler = "sap",
+                        id = %entry_result.outbox_id,
+                        "Entry validated without submission"
+                    );
+                }
             }
         }
 
-        Ok(())
+        Ok(result)
     }
 
     async fn monitor_task(cancel: CancellationToken, metrics: Arc<PerformanceMetrics>) {
@@ -396,6 +554,13 @@ impl SapScheduler {
     }
 }
 
+/// Whether the first posting window after `created_at` (Unix seconds) is at
+/// or before `now`.
+fn posting_window_passed(trigger: &CronTrigger, created_at: i64, now: SystemTime) -> bool {
+    let created = UNIX_EPOCH + Duration::from_secs(u64::try_from(created_at).unwrap_or(0));
+    trigger.next_after(created).is_some_and(|window| window <= now)
+}
+
 fn log_metric(result: MetricsResult<()>, metric: &'static str) {
     if let Err(err) = result {
         warn!(
@@ -425,6 +590,7 @@ mod tests {
     use std::sync::atomic::{AtomicUsize, Ordering};
 
     use async_trait::async_trait;
+    use pulsearc_common::testing::MockClock;
     use pulsearc_core::sap_ports::{
         SapClient as SapClientTrait, SapEntryId, TimeEntry as SapTimeEntry,
     };
@@ -437,11 +603,17 @@ mod tests {
     #[derive(Clone)]
     struct MockSapClient {
         call_count: Arc<AtomicUsize>,
+        closed_dates: Vec<String>,
     }
 
     impl MockSapClient {
         fn new() -> Self {
-            Self { call_count: Arc::new(AtomicUsize::new(0)) }
+            Self { call_count: Arc::new(AtomicUsize::new(0)), closed_dates: Vec::new() }
+        }
+
+        fn with_closed_period(mut self, date: &str) -> Self {
+            self.closed_dates.push(date.to_string());
+            self
         }
 
         fn calls(&self) -> usize {
@@ -495,6 +667,10 @@ mod tests {
         async fn validate_wbs(&self, _wbs_code: &str) -> DomainResult<bool> {
             Ok(true)
         }
+
+        async fn is_period_open(&self, date: &str) -> DomainResult<bool> {
+            Ok(!self.closed_dates.iter().any(|closed| closed == date))
+        }
     }
 
     // Type alias to avoid complexity warning
@@ -538,9 +714,18 @@ mod tests {
         }
 
         async fn dequeue_batch(&self, limit: usize) -> DomainResult<Vec<TimeEntryOutbox>> {
-            let mut entries = self.entries.lock().await;
-            let drain_count = limit.min(entries.len());
-            let batch: Vec<_> = entries.drain(..drain_count).collect();
+            // Like the SQLite outbox, entries stay pending until marked
+            let sent = self.sent.lock().await.clone();
+            let failed = self.failed.lock().await.clone();
+            let entries = self.entries.lock().await;
+            let batch = entries
+                .iter()
+                .filter(|entry| {
+                    !sent.contains(&entry.id) && !failed.iter().any(|(id, _)| id == &entry.id)
+                })
+                .take(limit)
+                .cloned()
+                .collect();
             Ok(batch)
         }
 
@@ -560,12 +745,37 @@ mod tests {
             cron_expression: "*/1 * * * * *".into(), // every second
             batch_size: 10,
             job_timeout: Duration::from_secs(2),
-            start_timeout: Duration::from_secs(2),
             stop_timeout: Duration::from_secs(2),
             join_timeout: Duration::from_secs(2),
+            catch_up_on_start: true,
         }
     }
 
+    /// Outbox entry for `date`, created `age` before `clock`'s current time
+    fn entry_created_before(
+        id: &str,
+        date: &str,
+        clock: &MockClock,
+        age: Duration,
+    ) -> TimeEntryOutbox {
+        let created = clock.system_time() - age;
+        let created_at = created.duration_since(UNIX_EPOCH).expect("after epoch").as_secs();
+        let mut entry = sample_outbox_entry(id);
+        entry.created_at = i64::try_from(created_at).expect("fits i64");
+        entry.payload_json = serde_json::json!({
+            "duration": 3600,
+            "note": "Work session",
+            "wbs_code": "WBS-123",
+            "date": date
+        })
+        .to_string();
+        entry
+    }
+
+    fn hourly_config() -> SapSchedulerConfig {
+        SapSchedulerConfig { cron_expression: "0 0 * * * *".into(), ..fast_config() }
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn lifecycle_runs_successfully() {
         let metrics = Arc::new(PerformanceMetrics::new());
@@ -681,4 +891,80 @@ mod tests {
         assert!(matches!(err, SapBatchError::Dequeue { .. }));
         assert_eq!(err.kind(), "dequeue_failed");
     }
+
+    #[tokio::test(start_paused = true)]
+    async fn start_forwards_entries_from_missed_window() {
+        let metrics = Arc::new(PerformanceMetrics::new());
+        let mock_client = Arc::new(MockSapClient::new());
+        let client: Arc<dyn SapClientTrait> = mock_client.clone();
+        let batch_forwarder = Arc::new(BatchForwarder::new(client));
+        let outbox_repo = Arc::new(MockOutboxRepo::new());
+        let clock = Arc::new(MockClock::new());
+
+        outbox_repo
+            .add_entries(vec![
+                entry_created_before("missed-1", "2025-01-01", &clock, Duration::from_secs(7200)),
+                entry_created_before("missed-2", "2025-01-01", &clock, Duration::from_secs(7200)),
+                entry_created_before("fresh", "2025-01-01", &clock, Duration::ZERO),
+            ])
+            .await;
+
+        let mut scheduler = SapScheduler::with_clock(
+            hourly_config(),
+            batch_forwarder,
+            outbox_repo.clone(),
+            metrics.clone(),
+            clock,
+        )
+        .expect("scheduler created");
+
+        scheduler.start().await.expect("start succeeds");
+        tokio::time::sleep(Duration::from_secs(5)).await;
+
+        // Forwarded without waiting for the next hourly window; the entry
+        // whose window has not come yet is left for the schedule
+        assert_eq!(outbox_repo.sent_entries().await, vec!["missed-1", "missed-2"]);
+        assert_eq!(mock_client.calls(), 2);
+        assert_eq!(metrics.scheduler.get_catch_up_entries(), 2);
+
+        scheduler.stop().await.expect("stop succeeds");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn catch_up_defers_entries_for_closed_periods() {
+        let metrics = Arc::new(PerformanceMetrics::new());
+        let mock_client = Arc::new(MockSapClient::new().with_closed_period("2025-01-01"));
+        let client: Arc<dyn SapClientTrait> = mock_client.clone();
+        let batch_forwarder = Arc::new(BatchForwarder::new(client));
+        let outbox_repo = Arc::new(MockOutboxRepo::new());
+        let clock = Arc::new(MockClock::new());
+
+        outbox_repo
+            .add_entries(vec![
+                entry_created_before("closed", "2025-01-01", &clock, Duration::from_secs(7200)),
+                entry_created_before("open", "2025-01-02", &clock, Duration::from_secs(7200)),
+            ])
+            .await;
+
+        let mut scheduler = SapScheduler::with_clock(
+            hourly_config(),
+            batch_forwarder,
+            outbox_repo.clone(),
+            metrics.clone(),
+            clock,
+        )
+        .expect("scheduler created");
+
+        scheduler.start().await.expect("start succeeds");
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        scheduler.stop().await.expect("stop succeeds");
+
+        assert_eq!(outbox_repo.sent_entries().await, vec!["open"]);
+        assert!(outbox_repo.failed_entries().await.is_empty());
+        assert_eq!(metrics.scheduler.get_catch_up_entries(), 1);
+
+        let pending = outbox_repo.dequeue_batch(10).await.expect("dequeue succeeds");
+        let pending: Vec<_> = pending.iter().map(|entry| entry.id.as_str()).collect();
+        assert_eq!(pending, vec!["closed"], "closed-period entry stays pending");
+    }
 }
@@ -55,13 +55,14 @@
 //! ```
 
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 // TODO: Remove these placeholder traits when repositories module is implemented
 use async_trait::async_trait;
+use pulsearc_common::testing::{Clock, SystemClock};
 use pulsearc_domain::types::{ActivitySegment, ActivitySnapshot};
 use pulsearc_domain::PulseArcError;
-use tokio::sync::Mutex;
+use tokio::sync::{watch, Mutex};
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, instrument, warn};
@@ -69,7 +70,9 @@ use tracing::{debug, error, info, instrument, warn};
 use crate::api::forwarder::ApiForwarder;
 use crate::observability::metrics::PerformanceMetrics;
 use crate::observability::MetricsResult;
+use crate::scheduling::clock;
 use crate::scheduling::error::{SchedulerError, SchedulerResult};
+use crate::scheduling::run_span::run_in_span;
 
 // =============================================================================
 // PLACEHOLDER TRAITS - TODO: REPLACE WITH ACTUAL REPOSITORIES
@@ -157,6 +160,8 @@ struct SyncLoopContext {
     segment_repo: Arc<dyn ActivitySegmentRepository>,
     snapshot_repo: Arc<dyn ActivitySnapshotRepository>,
     metrics: Arc<PerformanceMetrics>,
+    clock: Arc<dyn Clock>,
+    sync_blocked: Option<watch::Receiver<bool>>,
 }
 
 /// Sync scheduler for periodic outbox processing
@@ -168,6 +173,8 @@ pub struct SyncScheduler {
     cancellation_token: CancellationToken,
     task_handle: TaskHandle,
     metrics: Arc<PerformanceMetrics>,
+    clock: Arc<dyn Clock>,
+    sync_blocked: Option<watch::Receiver<bool>>,
 }
 
 impl SyncScheduler {
@@ -195,9 +202,29 @@ impl SyncScheduler {
             cancellation_token: CancellationToken::new(),
             task_handle: Arc::new(Mutex::new(None)),
             metrics,
+            clock: Arc::new(SystemClock),
+            sync_blocked: None,
         }
     }
 
+    /// Measure the sync interval with `clock` instead of [`SystemClock`]
+    ///
+    /// Tests pass a `MockClock` and advance it to trigger a sync without
+    /// sleeping. Call before [`Self::start`].
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Skip sync ticks while `sync_blocked` holds `true`
+    ///
+    /// Fed from MDM enforcement (`PolicyEnforcer::subscribe_sync_blocked`).
+    /// Call before [`Self::start`].
+    pub fn with_sync_blocked(mut self, sync_blocked: watch::Receiver<bool>) -> Self {
+        self.sync_blocked = Some(sync_blocked);
+        self
+    }
+
     /// Start the scheduler
     ///
     /// Spawns a background task that runs sync periodically.
@@ -221,6 +248,8 @@ impl SyncScheduler {
             segment_repo: Arc::clone(&self.segment_repo),
             snapshot_repo: Arc::clone(&self.snapshot_repo),
             metrics: Arc::clone(&self.metrics),
+            clock: Arc::clone(&self.clock),
+            sync_blocked: self.sync_blocked.clone(),
         };
         let config = self.config.clone();
         let cancel = self.cancellation_token.clone();
@@ -287,43 +316,58 @@ impl SyncScheduler {
         config: SyncSchedulerConfig,
         cancel: CancellationToken,
     ) {
-        let SyncLoopContext { forwarder, segment_repo, snapshot_repo, metrics } = context;
+        let SyncLoopContext {
+            forwarder,
+            segment_repo,
+            snapshot_repo,
+            metrics,
+            clock,
+            sync_blocked,
+        } = context;
         loop {
             tokio::select! {
                 _ = cancel.cancelled() => {
                     debug!("Sync loop cancelled");
                     break;
                 }
-                _ = tokio::time::sleep(config.interval) => {
+                _ = clock::sleep(clock.as_ref(), config.interval) => {
                     log_metric(metrics.record_call(), "scheduler.sync.tick");
-                    let started = Instant::now();
-
-                    // Process segments
-                    if let Err(e) = Self::process_segments(
-                        &forwarder,
-                        &segment_repo,
-                        &config,
-                        &metrics,
-                    ).await {
-                        error!(error = %e, "Failed to process segment batch");
-                        log_metric(metrics.record_fetch_error(), "scheduler.sync.segments.error");
-                    }
 
-                    // Process snapshots
-                    if let Err(e) = Self::process_snapshots(
-                        &forwarder,
-                        &snapshot_repo,
-                        &config,
-                        &metrics,
-                    ).await {
-                        error!(error = %e, "Failed to process snapshot batch");
-                        log_metric(metrics.record_fetch_error(), "scheduler.sync.snapshots.error");
+                    if sync_blocked.as_ref().is_some_and(|blocked| *blocked.borrow()) {
+                        info!("Sync blocked by MDM policy; skipping tick");
+                        continue;
                     }
 
-                    log_metric(
-                        metrics.record_fetch_time(started.elapsed()),
-                        "scheduler.sync.duration",
-                    );
+                    let tick = async {
+                        // Process segments
+                        if let Err(e) = Self::process_segments(
+                            &forwarder,
+                            &segment_repo,
+                            &config,
+                            &metrics,
+                        ).await {
+                            error!(error = %e, "Failed to process segment batch");
+                            log_metric(
+                                metrics.record_fetch_error(),
+                                "scheduler.sync.segments.error",
+                            );
+                        }
+
+                        // Process snapshots
+                        if let Err(e) = Self::process_snapshots(
+                            &forwarder,
+                            &snapshot_repo,
+                            &config,
+                            &metrics,
+                        ).await {
+                            error!(error = %e, "Failed to process snapshot batch");
+                            log_metric(
+                                metrics.record_fetch_error(),
+                                "scheduler.sync.snapshots.error",
+                            );
+                        }
+                    };
+                    run_in_span("sync", clock.system_time(), &metrics, tick).await;
                 }
             }
         }
@@ -505,6 +549,7 @@ mod tests {
     use std::sync::atomic::{AtomicUsize, Ordering};
 
     use async_trait::async_trait;
+    use pulsearc_common::testing::MockClock;
     use pulsearc_domain::{ActivitySegment, ActivitySnapshot};
 
     use super::*;
@@ -634,4 +679,72 @@ mod tests {
 
         scheduler.stop().await.unwrap();
     }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_mock_clock_interval_triggers_single_sync() {
+        let config = ApiClientConfig::default();
+        let client = Arc::new(ApiClient::new(config, Arc::new(MockAuthProvider)).unwrap());
+        let commands = Arc::new(ApiCommands::new(client));
+        let forwarder = Arc::new(ApiForwarder::new(commands, ForwarderConfig::default()));
+        let metrics = Arc::new(PerformanceMetrics::new());
+        let clock = Arc::new(MockClock::new());
+
+        let segments = MockSegmentRepo::new();
+        let segment_calls = Arc::clone(&segments.call_count);
+        let segment_repo: Arc<dyn ActivitySegmentRepository> = Arc::new(segments);
+        let snapshot_repo: Arc<dyn ActivitySnapshotRepository> = Arc::new(MockSnapshotRepo::new());
+
+        let scheduler_config = SyncSchedulerConfig::default();
+        let interval = scheduler_config.interval;
+        let mut scheduler =
+            SyncScheduler::new(forwarder, segment_repo, snapshot_repo, scheduler_config, metrics)
+                .with_clock(clock.clone());
+
+        scheduler.start().await.unwrap();
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert_eq!(segment_calls.load(Ordering::SeqCst), 0);
+
+        // Advancing the mock clock by one interval triggers exactly one sync
+        clock.advance(interval);
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert_eq!(segment_calls.load(Ordering::SeqCst), 1);
+
+        scheduler.stop().await.unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_blocked_sync_skips_ticks() {
+        let config = ApiClientConfig::default();
+        let client = Arc::new(ApiClient::new(config, Arc::new(MockAuthProvider)).unwrap());
+        let commands = Arc::new(ApiCommands::new(client));
+        let forwarder = Arc::new(ApiForwarder::new(commands, ForwarderConfig::default()));
+        let metrics = Arc::new(PerformanceMetrics::new());
+        let clock = Arc::new(MockClock::new());
+        let (blocked, sync_blocked) = watch::channel(true);
+
+        let segments = MockSegmentRepo::new();
+        let segment_calls = Arc::clone(&segments.call_count);
+        let segment_repo: Arc<dyn ActivitySegmentRepository> = Arc::new(segments);
+        let snapshot_repo: Arc<dyn ActivitySnapshotRepository> = Arc::new(MockSnapshotRepo::new());
+
+        let scheduler_config = SyncSchedulerConfig::default();
+        let interval = scheduler_config.interval;
+        let mut scheduler =
+            SyncScheduler::new(forwarder, segment_repo, snapshot_repo, scheduler_config, metrics)
+                .with_clock(clock.clone())
+                .with_sync_blocked(sync_blocked);
+
+        scheduler.start().await.unwrap();
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        clock.advance(interval);
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert_eq!(segment_calls.load(Ordering::SeqCst), 0, "blocked tick must not sync");
+
+        blocked.send_replace(false);
+        clock.advance(interval);
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert_eq!(segment_calls.load(Ordering::SeqCst), 1);
+
+        scheduler.stop().await.unwrap();
+    }
 }
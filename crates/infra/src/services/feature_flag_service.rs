@@ -4,19 +4,55 @@
 //! caching for performance. The service exposes both a simple `is_enabled`
 //! helper (for existing call-sites) and an `evaluate` method that reports when
 //! the default fallback value was used, enabling precise observability.
+//!
+//! For tests and CI matrices, `PULSEARC_FF_<FLAG_NAME>=on|off` forces a
+//! flag's value regardless of the stored state (e.g.
+//! `PULSEARC_FF_NEW_BLOCKS_CMD=off` for `new_blocks_cmd`). Environment
+//! overrides take precedence over the cache and the database, are read on every
+//! evaluation, and are never persisted. An overridden flag takes the forced
+//! value as-is; flag dependencies are only enforced for stored values.
 
 use std::collections::HashMap;
-use std::sync::Arc;
+#[cfg(test)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex as StdMutex, PoisonError};
 
 use async_trait::async_trait;
 use pulsearc_core::feature_flags_ports::{FeatureFlag, FeatureFlagEvaluation, FeatureFlagsPort};
 use pulsearc_domain::Result as DomainResult;
 use tokio::sync::Mutex;
+use tracing::{info, warn};
 
 use crate::database::{DbManager, SqlCipherFeatureFlagsRepository};
 
 type FlagCache = Arc<Mutex<HashMap<String, FeatureFlagEvaluation>>>;
 
+/// Prefix for environment variables that override stored flag values.
+const ENV_OVERRIDE_PREFIX: &str = "PULSEARC_FF_";
+
+/// Environment variable that overrides `flag_name`
+/// (`new_blocks_cmd` -> `PULSEARC_FF_NEW_BLOCKS_CMD`).
+fn env_override_var(flag_name: &str) -> String {
+    let suffix: String = flag_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+        .collect();
+    format!("{ENV_OVERRIDE_PREFIX}{suffix}")
+}
+
+/// Parse an override value; `None` for anything other than on/off (or the
+/// equivalent true/false, 1/0).
+fn parse_override(value: &str) -> Option<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "on" | "true" | "1" => Some(true),
+        "off" | "false" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// Last override logged per flag
+type LoggedOverrides = StdMutex<HashMap<String, Option<bool>>>;
+
 /// Feature flag service with in-memory caching.
 ///
 /// The repository is kept internal; consumers interact via the service to gain
@@ -24,6 +60,12 @@ type FlagCache = Arc<Mutex<HashMap<String, FeatureFlagEvaluation>>>;
 pub struct FeatureFlagService {
     repository: Arc<SqlCipherFeatureFlagsRepository>,
     cache: FlagCache,
+    /// Last override logged per flag, so each change is logged once rather
+    /// than on every evaluation.
+    logged_overrides: LoggedOverrides,
+    /// Number of cache read passes, so tests can check batching.
+    #[cfg(test)]
+    cache_reads: AtomicUsize,
 }
 
 impl FeatureFlagService {
@@ -32,21 +74,64 @@ impl FeatureFlagService {
         Self {
             repository: Arc::new(SqlCipherFeatureFlagsRepository::new(db)),
             cache: Arc::new(Mutex::new(HashMap::new())),
+            logged_overrides: StdMutex::new(HashMap::new()),
+            #[cfg(test)]
+            cache_reads: AtomicUsize::new(0),
+        }
+    }
+
+    /// Value forced by `PULSEARC_FF_<FLAG_NAME>`, if set to a valid value.
+    fn env_override(&self, flag_name: &str) -> Option<bool> {
+        let var = env_override_var(flag_name);
+        let raw = std::env::var(&var).ok();
+        let value = raw.as_deref().and_then(parse_override);
+
+        let mut logged = self.logged_overrides.lock().unwrap_or_else(PoisonError::into_inner);
+        if logged.get(flag_name) != Some(&value) {
+            match (value, raw.as_deref()) {
+                (Some(enabled), _) => {
+                    warn!(flag = flag_name, env_var = %var, enabled, "Feature flag overridden by environment");
+                }
+                (None, Some(raw)) => {
+                    warn!(flag = flag_name, env_var = %var, value = raw, "Ignoring invalid feature flag override (expected on/off)");
+                }
+                (None, None) if logged.contains_key(flag_name) => {
+                    info!(flag = flag_name, env_var = %var, "Feature flag environment override removed");
+                }
+                (None, None) => {}
+            }
+            logged.insert(flag_name.to_owned(), value);
         }
+
+        value
     }
 
     /// Evaluate a feature flag, returning both the computed value and whether
     /// the default fallback was used.
+    ///
+    /// A `PULSEARC_FF_<FLAG_NAME>` environment override wins over the cached
+    /// and stored value.
     pub async fn evaluate(
         &self,
         flag_name: &str,
         default: bool,
     ) -> DomainResult<FeatureFlagEvaluation> {
+        // Environment override: highest precedence, never cached or persisted.
+        if let Some(enabled) = self.env_override(flag_name) {
+            return Ok(FeatureFlagEvaluation {
+                enabled,
+                fallback_used: false,
+                unmet_dependency: None,
+            });
+        }
+
         // Fast path: cache hit.
         {
             let cache = self.cache.lock().await;
+            #[cfg(test)]
+            self.cache_reads.fetch_add(1, Ordering::SeqCst);
             if let Some(entry) = cache.get(flag_name) {
-                return Ok(*entry);
+                return Ok(entry.clone());
             }
         }
 
@@ -56,31 +141,97 @@ impl FeatureFlagService {
         // Populate cache with the evaluation result.
         {
             let mut cache = self.cache.lock().await;
-            cache.insert(flag_name.to_owned(), evaluation);
+            cache.insert(flag_name.to_owned(), evaluation.clone());
         }
 
         Ok(evaluation)
     }
 
+    /// Evaluate several feature flags in one pass.
+    ///
+    /// Environment overrides are applied first, the cache is consulted once
+    /// for the remaining flags, and all misses are fetched from the repository
+    /// together. Flags missing from storage evaluate as disabled with
+    /// `fallback_used` set.
+    pub async fn evaluate_feature_flags(
+        &self,
+        flag_names: &[String],
+    ) -> DomainResult<HashMap<String, FeatureFlagEvaluation>> {
+        let mut evaluations = HashMap::with_capacity(flag_names.len());
+        for flag_name in flag_names {
+            if let Some(enabled) = self.env_override(flag_name) {
+                evaluations.insert(
+                    flag_name.clone(),
+                    FeatureFlagEvaluation { enabled, fallback_used: false, unmet_dependency: None },
+                );
+            }
+        }
+
+        let mut misses = Vec::new();
+        {
+            let cache = self.cache.lock().await;
+            #[cfg(test)]
+            self.cache_reads.fetch_add(1, Ordering::SeqCst);
+            for flag_name in flag_names {
+                if evaluations.contains_key(flag_name) || misses.contains(flag_name) {
+                    continue;
+                }
+                match cache.get(flag_name) {
+                    Some(entry) => {
+                        evaluations.insert(flag_name.clone(), entry.clone());
+                    }
+                    None => misses.push(flag_name.clone()),
+                }
+            }
+        }
+
+        if !misses.is_empty() {
+            let fetched = self.repository.evaluate_many(misses, false).await?;
+
+            let mut cache = self.cache.lock().await;
+            for (flag_name, evaluation) in fetched {
+                cache.insert(flag_name.clone(), evaluation.clone());
+                evaluations.insert(flag_name, evaluation);
+            }
+        }
+
+        Ok(evaluations)
+    }
+
     /// Check if a feature flag is enabled, using read-through caching.
     pub async fn is_enabled(&self, flag_name: &str, default: bool) -> DomainResult<bool> {
         self.evaluate(flag_name, default).await.map(|evaluation| evaluation.enabled)
     }
 
     /// Set a feature flag's enabled status (write-through invalidation).
+    ///
+    /// Writes the stored value even while an environment override is active;
+    /// the override keeps winning until the variable is removed.
     pub async fn set_enabled(&self, flag_name: &str, enabled: bool) -> DomainResult<()> {
         self.repository.set_enabled(flag_name, enabled).await?;
 
-        // Invalidate cache entry immediately to avoid stale reads.
-        {
-            let mut cache = self.cache.lock().await;
-            cache.remove(flag_name);
-        }
+        // Flags that depend on this one may be cached too, so drop everything
+        // to avoid stale reads.
+        self.clear_cache().await;
+
+        Ok(())
+    }
+
+    /// Replace the flags that `flag_name` requires (write-through
+    /// invalidation).
+    ///
+    /// Returns `PulseArcError::InvalidInput` if the declaration would create a
+    /// dependency cycle.
+    pub async fn set_dependencies(&self, flag_name: &str, requires: &[String]) -> DomainResult<()> {
+        self.repository.set_dependencies(flag_name, requires.to_vec()).await?;
+        self.clear_cache().await;
 
         Ok(())
     }
 
     /// List all feature flags (always hits the repository for freshness).
+    ///
+    /// Reports stored values; environment overrides are not applied.
     pub async fn list_all(&self) -> DomainResult<Vec<FeatureFlag>> {
         self.repository.list_all().await
     }
@@ -102,13 +253,29 @@ impl FeatureFlagsPort for FeatureFlagService {
         <FeatureFlagService>::evaluate(self, flag_name, default).await
     }
 
+    async fn evaluate_feature_flags(
+        &self,
+        flag_names: &[String],
+    ) -> DomainResult<HashMap<String, FeatureFlagEvaluation>> {
+        <FeatureFlagService>::evaluate_feature_flags(self, flag_names).await
+    }
+
     async fn set_enabled(&self, flag_name: &str, enabled: bool) -> DomainResult<()> {
         <FeatureFlagService>::set_enabled(self, flag_name, enabled).await
     }
 
+    async fn set_dependencies(&self, flag_name: &str, requires: &[String]) -> DomainResult<()> {
+        <FeatureFlagService>::set_dependencies(self, flag_name, requires).await
+    }
+
     async fn list_all(&self) -> DomainResult<Vec<FeatureFlag>> {
         <FeatureFlagService>::list_all(self).await
     }
+
+    async fn refresh(&self) -> DomainResult<()> {
+        self.clear_cache().await;
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -124,6 +291,54 @@ mod tests {
 
     const TEST_KEY: &str = "test_key_64_chars_long_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
 
+    #[test]
+    fn env_override_var_uppercases_flag_name() {
+        assert_eq!(env_override_var("new_blocks_cmd"), "PULSEARC_FF_NEW_BLOCKS_CMD");
+        assert_eq!(env_override_var("sap.sync-v2"), "PULSEARC_FF_SAP_SYNC_V2");
+        assert_eq!(parse_override(" ON "), Some(true));
+        assert_eq!(parse_override("off"), Some(false));
+        assert_eq!(parse_override("maybe"), None);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn env_override_wins_over_stored_value() {
+        let (service, _mgr, _dir) = setup().await;
+        // Each env test uses its own flag so parallel tests don't interfere.
+        service.set_enabled("env_override_wins", true).await.expect("set flag");
+        let var = env_override_var("env_override_wins");
+
+        std::env::set_var(&var, "off");
+        let evaluation = service.evaluate("env_override_wins", true).await.expect("evaluation");
+        assert!(!evaluation.enabled, "env override should win over stored value");
+        assert!(!evaluation.fallback_used);
+
+        // The override is not persisted
+        let stored = service.list_all().await.expect("list_all");
+        let flag = stored.iter().find(|f| f.flag_name == "env_override_wins").expect("stored flag");
+        assert!(flag.enabled);
+
+        std::env::remove_var(&var);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn removing_env_override_restores_stored_value() {
+        let (service, _mgr, _dir) = setup().await;
+        service.set_enabled("env_override_removed", false).await.expect("set flag");
+        let var = env_override_var("env_override_removed");
+
+        // Populate the cache with the stored value before overriding
+        assert!(!service.is_enabled("env_override_removed", true).await.expect("evaluation"));
+
+        std::env::set_var(&var, "on");
+        assert!(service.is_enabled("env_override_removed", false).await.expect("evaluation"));
+
+        std::env::remove_var(&var);
+        assert!(
+            !service.is_enabled("env_override_removed", true).await.expect("evaluation"),
+            "stored value should apply once the override is removed"
+        );
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn cache_hit_after_miss() {
         let (service, _mgr, _dir) = setup().await;
@@ -195,6 +410,77 @@ mod tests {
         assert_eq!(service.cache.lock().await.len(), 1);
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn enabling_dependency_refreshes_cached_dependent() {
+        let (service, _mgr, _dir) = setup().await;
+        service.set_enabled("sap", false).await.expect("set flag");
+        service.set_enabled("sap_batch_retry", true).await.expect("set flag");
+        service
+            .set_dependencies("sap_batch_retry", &["sap".to_string()])
+            .await
+            .expect("dependencies declared");
+
+        // Populate the cache with the dependent flag while its dependency is off
+        let evaluation = service.evaluate("sap_batch_retry", false).await.expect("evaluation");
+        assert!(!evaluation.enabled);
+        assert_eq!(evaluation.unmet_dependency.as_deref(), Some("sap"));
+
+        service.set_enabled("sap", true).await.expect("set flag");
+
+        let evaluation = service.evaluate("sap_batch_retry", false).await.expect("evaluation");
+        assert!(evaluation.enabled, "cached dependent should see the enabled dependency");
+        assert_eq!(evaluation.unmet_dependency, None);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn batch_evaluation_matches_individual_evaluation() {
+        let (service, _mgr, _dir) = setup().await;
+        service.set_enabled("sap", false).await.expect("set flag");
+        service.set_enabled("sap_batch_retry", true).await.expect("set flag");
+        service
+            .set_dependencies("sap_batch_retry", &["sap".to_string()])
+            .await
+            .expect("dependencies declared");
+        service.set_enabled("use_new_infra", false).await.expect("set flag");
+
+        let names: Vec<String> =
+            ["new_blocks_cmd", "use_new_infra", "sap_batch_retry", "missing_batch_flag"]
+                .iter()
+                .map(|name| name.to_string())
+                .collect();
+
+        let reads_before = service.cache_reads.load(Ordering::SeqCst);
+        let batch = service.evaluate_feature_flags(&names).await.expect("batch evaluation");
+        assert_eq!(
+            service.cache_reads.load(Ordering::SeqCst) - reads_before,
+            1,
+            "the cache should be consulted once for the whole batch"
+        );
+        assert_eq!(batch.len(), names.len());
+
+        service.clear_cache().await;
+        for name in &names {
+            let individual = service.evaluate(name, false).await.expect("evaluation");
+            assert_eq!(batch.get(name), Some(&individual), "mismatch for {name}");
+        }
+        assert_eq!(batch["sap_batch_retry"].unmet_dependency.as_deref(), Some("sap"));
+        assert!(batch["missing_batch_flag"].fallback_used);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn batch_evaluation_serves_cached_flags() {
+        let (service, _mgr, _dir) = setup().await;
+        let names = vec!["new_blocks_cmd".to_string(), "use_new_infra".to_string()];
+
+        service.evaluate_feature_flags(&names).await.expect("batch evaluation");
+        assert_eq!(service.cache.lock().await.len(), 2, "misses populate the cache");
+
+        // Change storage behind the cache's back; a second batch is a pure cache hit
+        service.repository.set_enabled("new_blocks_cmd", false).await.expect("set flag");
+        let batch = service.evaluate_feature_flags(&names).await.expect("batch evaluation");
+        assert!(batch["new_blocks_cmd"].enabled);
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn fallback_flag_detected() {
         let (service, _mgr, _dir) = setup().await;
@@ -7,6 +7,8 @@
 //!
 //! - Token usage tracking with cost calculation
 //! - Monthly cost caps and alerts
+//! - Budget reservations for costed operations, synced with persisted monthly
+//!   cost ([`CostTracker::reserve`], [`BudgetGuard`])
 //! - Variance tracking (estimated vs actual)
 //! - Historical cost queries
 //! - Integration with Phase 3F observability
@@ -17,12 +19,14 @@
 //! - **Database**: SqlCipherConnection via DbManager
 //! - **Thread-safety**: Arc<Mutex<>> for metrics
 
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 
 use chrono::Utc;
 use pulsearc_common::error::{CommonError, CommonResult};
 use pulsearc_common::observability::MetricsTracker;
+pub use pulsearc_domain::CostRateConfig;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 use tracing::{debug, info, warn};
 
 use crate::database::DbManager;
@@ -32,30 +36,6 @@ type TokenCounts = (Option<i64>, Option<i64>);
 
 const THIRTY_DAYS_SECS: i64 = 30 * 86400;
 
-/// Configuration for cost tracking
-#[derive(Debug, Clone)]
-pub struct CostRateConfig {
-    /// Maximum monthly cost in USD (default: $5.00)
-    pub max_monthly_cost_usd: f64,
-    /// Alert threshold in USD (default: $4.00)
-    pub alert_threshold_usd: f64,
-    /// GPT-4o-mini input cost per 1M tokens (default: $0.15)
-    pub gpt4o_mini_input_cost_per_1m_tokens: f64,
-    /// GPT-4o-mini output cost per 1M tokens (default: $0.60)
-    pub gpt4o_mini_output_cost_per_1m_tokens: f64,
-}
-
-impl Default for CostRateConfig {
-    fn default() -> Self {
-        Self {
-            max_monthly_cost_usd: 5.0,
-            alert_threshold_usd: 4.0,
-            gpt4o_mini_input_cost_per_1m_tokens: 0.15,
-            gpt4o_mini_output_cost_per_1m_tokens: 0.60,
-        }
-    }
-}
-
 /// Token usage record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenUsage {
@@ -110,10 +90,216 @@ pub enum ClassificationMode {
     RulesOnly, // Cost cap exceeded, use rule-based only
 }
 
+/// Budget guard errors
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum BudgetError {
+    #[error("Budget exceeded: requested ${requested_usd:.4}, ${headroom_usd:.4} available")]
+    Exceeded { requested_usd: f64, headroom_usd: f64 },
+
+    #[error("Invalid cost amount: {0}")]
+    InvalidAmount(f64),
+
+    #[error("Budget unavailable: {0}")]
+    Unavailable(String),
+}
+
+/// Reservation and settlement totals of a [`BudgetGuard`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BudgetSnapshot {
+    /// Total budget in USD
+    pub limit_usd: f64,
+    /// Estimated cost of reservations not yet settled
+    pub reserved_usd: f64,
+    /// Cost spent so far: the spent total last reset with
+    /// [`BudgetGuard::reset_spent`] plus reservations settled since
+    pub settled_usd: f64,
+    /// Budget still available for new reservations (never negative)
+    pub headroom_usd: f64,
+    /// Reservations made so far
+    pub reservations: u64,
+    /// Reservations settled so far
+    pub settlements: u64,
+}
+
+/// Estimate vs actual cost of a settled reservation
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Settlement {
+    pub estimated_usd: f64,
+    pub actual_usd: f64,
+    /// `actual_usd - estimated_usd`; negative when headroom was returned
+    pub variance_usd: f64,
+}
+
+#[derive(Debug, Default)]
+struct BudgetLedger {
+    limit_usd: f64,
+    reserved_usd: f64,
+    settled_usd: f64,
+    reservations: u64,
+    settlements: u64,
+}
+
+impl BudgetLedger {
+    fn headroom(&self) -> f64 {
+        (self.limit_usd - self.reserved_usd - self.settled_usd).max(0.0)
+    }
+}
+
+/// Reserves budget for costed operations before they run
+///
+/// An operation calls [`BudgetGuard::try_spend`] with its estimated cost and,
+/// once it knows the real cost, settles the returned [`SpendToken`]. The
+/// estimate is held back from other callers in the meantime, so concurrent
+/// operations cannot jointly overrun the budget. Settling below the estimate
+/// returns the difference as headroom; settling above it is recorded as
+/// spent (the money is already gone). Dropping a token without settling
+/// releases the reservation, e.g. when the operation failed before incurring
+/// any cost.
+///
+/// Clones share the same ledger.
+#[derive(Debug, Clone)]
+pub struct BudgetGuard {
+    ledger: Arc<Mutex<BudgetLedger>>,
+}
+
+impl BudgetGuard {
+    /// Create a guard with `limit_usd` to spend
+    pub fn new(limit_usd: f64) -> Self {
+        Self { ledger: Arc::new(Mutex::new(BudgetLedger { limit_usd, ..BudgetLedger::default() })) }
+    }
+
+    /// Reserve `estimated_usd` of budget for an operation
+    ///
+    /// # Errors
+    ///
+    /// Returns `BudgetError::Exceeded` if the reservation does not fit in the
+    /// remaining headroom, or `BudgetError::InvalidAmount` for a negative or
+    /// non-finite estimate.
+    pub fn try_spend(&self, estimated_usd: f64) -> Result<SpendToken, BudgetError> {
+        check_amount(estimated_usd)?;
+
+        let mut ledger = lock_ledger(&self.ledger);
+        let headroom_usd = ledger.headroom();
+        if estimated_usd > headroom_usd {
+            debug!(
+                requested = estimated_usd,
+                headroom = headroom_usd,
+                "Budget reservation refused"
+            );
+            return Err(BudgetError::Exceeded { requested_usd: estimated_usd, headroom_usd });
+        }
+
+        ledger.reserved_usd += estimated_usd;
+        ledger.reservations += 1;
+        Ok(SpendToken { ledger: Arc::clone(&self.ledger), estimated_usd, open: true })
+    }
+
+    /// Budget still available for new reservations
+    pub fn headroom(&self) -> f64 {
+        lock_ledger(&self.ledger).headroom()
+    }
+
+    /// Replace the spent total with `spent_usd`
+    ///
+    /// Used to resync with persisted spend, which also covers cost recorded
+    /// outside the guard and spend that has aged out of the budget window.
+    /// Open reservations stay held.
+    pub fn reset_spent(&self, spent_usd: f64) {
+        lock_ledger(&self.ledger).settled_usd = spent_usd.max(0.0);
+    }
+
+    /// Current reservation and settlement totals
+    pub fn snapshot(&self) -> BudgetSnapshot {
+        let ledger = lock_ledger(&self.ledger);
+        BudgetSnapshot {
+            limit_usd: ledger.limit_usd,
+            reserved_usd: ledger.reserved_usd,
+            settled_usd: ledger.settled_usd,
+            headroom_usd: ledger.headroom(),
+            reservations: ledger.reservations,
+            settlements: ledger.settlements,
+        }
+    }
+}
+
+/// Budget reserved by [`BudgetGuard::try_spend`]
+///
+/// Settle it with the actual cost once the operation completes; dropping it
+/// unsettled releases the reservation.
+#[derive(Debug)]
+#[must_use = "settle the token with the actual cost, or drop it to release the reservation"]
+pub struct SpendToken {
+    ledger: Arc<Mutex<BudgetLedger>>,
+    estimated_usd: f64,
+    open: bool,
+}
+
+impl SpendToken {
+    /// Estimated cost reserved by this token
+    pub fn estimated_usd(&self) -> f64 {
+        self.estimated_usd
+    }
+
+    /// Replace the reservation with the actual cost of the operation
+    ///
+    /// # Errors
+    ///
+    /// Returns `BudgetError::InvalidAmount` for a negative or non-finite
+    /// cost; the reservation stays open until the token is dropped.
+    pub fn settle(mut self, actual_usd: f64) -> Result<Settlement, BudgetError> {
+        check_amount(actual_usd)?;
+
+        let mut ledger = lock_ledger(&self.ledger);
+        ledger.reserved_usd = (ledger.reserved_usd - self.estimated_usd).max(0.0);
+        ledger.settled_usd += actual_usd;
+        ledger.settlements += 1;
+        drop(ledger);
+        self.open = false;
+
+        let settlement = Settlement {
+            estimated_usd: self.estimated_usd,
+            actual_usd,
+            variance_usd: actual_usd - self.estimated_usd,
+        };
+        if settlement.variance_usd > 0.0 {
+            warn!(
+                estimated = settlement.estimated_usd,
+                actual = settlement.actual_usd,
+                "Operation cost more than its budget reservation"
+            );
+        }
+        Ok(settlement)
+    }
+}
+
+impl Drop for SpendToken {
+    fn drop(&mut self) {
+        if self.open {
+            let mut ledger = lock_ledger(&self.ledger);
+            ledger.reserved_usd = (ledger.reserved_usd - self.estimated_usd).max(0.0);
+        }
+    }
+}
+
+fn check_amount(amount_usd: f64) -> Result<(), BudgetError> {
+    if amount_usd.is_finite() && amount_usd >= 0.0 {
+        Ok(())
+    } else {
+        Err(BudgetError::InvalidAmount(amount_usd))
+    }
+}
+
+fn lock_ledger(ledger: &Mutex<BudgetLedger>) -> MutexGuard<'_, BudgetLedger> {
+    // Ledger updates are single arithmetic steps, so a poisoned lock still
+    // holds consistent totals
+    ledger.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
 /// Cost tracker with persistent storage and observability
 pub struct CostTracker {
     db: Arc<DbManager>,
     config: CostRateConfig,
+    budget: BudgetGuard,
     metrics: Arc<Mutex<CostMetrics>>,
     _metrics_tracker: Arc<MetricsTracker>,
 }
@@ -124,22 +310,19 @@ impl CostTracker {
     /// # Arguments
     ///
     /// * `db` - Database manager for persistent storage
-    /// * `config` - Cost rate configuration
+    /// * `config` - Cost rate configuration (usually `Config::costs`)
     ///
     /// # Returns
     ///
-    /// Configured cost tracker
+    /// Configured cost tracker, or a config error if the rates are invalid
     pub fn new(db: Arc<DbManager>, config: CostRateConfig) -> CommonResult<Self> {
-        if config.max_monthly_cost_usd <= 0.0 {
-            return Err(pulsearc_common::error::CommonError::config(
-                "max_monthly_cost_usd must be positive",
-            ));
-        }
+        config.validate().map_err(CommonError::config)?;
 
         let metrics_tracker = Arc::new(MetricsTracker::default());
 
         Ok(Self {
             db,
+            budget: BudgetGuard::new(config.max_monthly_cost_usd),
             config,
             metrics: Arc::new(Mutex::new(CostMetrics::default())),
             _metrics_tracker: metrics_tracker,
@@ -151,7 +334,7 @@ impl CostTracker {
         Self::new(db, CostRateConfig::default())
     }
 
-    /// Calculate cost for given token usage
+    /// Calculate cost for given token usage at the default model's rates
     ///
     /// # Arguments
     ///
@@ -162,17 +345,90 @@ impl CostTracker {
     ///
     /// Estimated cost in USD
     pub fn calculate_cost(&self, input_tokens: u32, output_tokens: u32) -> f64 {
-        let input_cost = (f64::from(input_tokens)
-            * self.config.gpt4o_mini_input_cost_per_1m_tokens)
-            / 1_000_000.0;
-        let output_cost = (f64::from(output_tokens)
-            * self.config.gpt4o_mini_output_cost_per_1m_tokens)
-            / 1_000_000.0;
-        input_cost + output_cost
+        self.config.default_pricing().cost_usd(input_tokens, output_tokens)
+    }
+
+    /// Calculate cost for given token usage at `model`'s rates
+    ///
+    /// Models without configured rates are priced at the default model's
+    /// rates.
+    pub fn calculate_model_cost(&self, model: &str, input_tokens: u32, output_tokens: u32) -> f64 {
+        let pricing = self.config.model_pricing(model).unwrap_or_else(|| {
+            warn!(model = model, "No cost rates configured for model, using default model rates");
+            self.config.default_pricing()
+        });
+        pricing.cost_usd(input_tokens, output_tokens)
+    }
+
+    /// Cost rates in use
+    pub fn rates(&self) -> &CostRateConfig {
+        &self.config
+    }
+
+    /// Budget guard for costed operations, limited to `max_monthly_cost_usd`
+    ///
+    /// Reserve through [`Self::reserve`] so headroom reflects the persisted
+    /// monthly cost; reserving on the guard directly skips that resync.
+    pub fn budget(&self) -> &BudgetGuard {
+        &self.budget
+    }
+
+    /// Reserve `estimated_usd` of `user_id`'s monthly budget for an operation
+    ///
+    /// The guard's spent total is first reset to the persisted cost of the
+    /// 30-day window ([`Self::get_monthly_cost`]), so usage recorded without a
+    /// reservation counts and spend older than the window frees headroom.
+    /// Settle the token with [`Self::settle_usage`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `BudgetError::Unavailable` if the persisted cost cannot be
+    /// read, otherwise the errors of [`BudgetGuard::try_spend`].
+    pub async fn reserve(
+        &self,
+        user_id: &str,
+        estimated_usd: f64,
+    ) -> Result<SpendToken, BudgetError> {
+        let spent_usd = self
+            .get_monthly_cost(user_id)
+            .await
+            .map_err(|err| BudgetError::Unavailable(err.to_string()))?;
+        let budget = self.budget();
+        budget.reset_spent(spent_usd);
+        budget.try_spend(estimated_usd)
+    }
+
+    /// Record the actual usage of a reserved operation and settle its token
+    ///
+    /// The usage is persisted with [`Self::record_usage`] and the token is
+    /// settled with `usage.estimated_cost_usd`. The token is settled even if
+    /// persisting fails, since the cost has already been incurred.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error for a negative or non-finite cost (the
+    /// reservation is released), or the error of [`Self::record_usage`].
+    pub async fn settle_usage(
+        &self,
+        token: SpendToken,
+        usage: &TokenUsage,
+    ) -> CommonResult<Settlement> {
+        check_amount(usage.estimated_cost_usd).map_err(invalid_cost)?;
+        let recorded = self.record_usage(usage).await;
+        let settlement = token.settle(usage.estimated_cost_usd).map_err(invalid_cost)?;
+        recorded?;
+        Ok(settlement)
+    }
+
+    /// Reservation vs settlement totals of [`Self::budget`]
+    pub fn budget_snapshot(&self) -> BudgetSnapshot {
+        self.budget.snapshot()
     }
 
     /// Record API call (for non-token-based APIs like SAP, Calendar)
     ///
+    /// Adds the service's configured call cost to the running total.
+    ///
     /// # Arguments
     ///
     /// * `service` - Service name (e.g., "sap", "calendar", "neon")
@@ -183,6 +439,7 @@ impl CostTracker {
             .map_err(|_| CommonError::lock_resource("CostMetrics", "mutex poisoned"))?;
 
         metrics.total_api_calls += 1;
+        metrics.total_cost_usd += self.config.call_cost(service);
 
         match service {
             "openai" => metrics.openai_calls += 1,
@@ -578,6 +835,10 @@ impl CostTracker {
     }
 }
 
+fn invalid_cost(error: BudgetError) -> CommonError {
+    CommonError::validation("estimated_cost_usd", error.to_string())
+}
+
 fn join_error(task: &'static str, error: tokio::task::JoinError) -> CommonError {
     CommonError::Internal {
         message: format!("Task join failed: {error}"),
@@ -614,6 +875,182 @@ mod tests {
         assert_eq!(metrics.openai_calls, 1);
     }
 
+    #[test]
+    fn test_configured_model_rate_changes_cost() {
+        let db = Arc::new(DbManager::new(":memory:", 1, Some("test-key")).unwrap());
+        let mut config = CostRateConfig::default();
+        config.models.insert(
+            "gpt-4o-mini".to_string(),
+            pulsearc_domain::ModelPricing { input_per_1m: 0.30, output_per_1m: 1.20 },
+        );
+        let default_tracker = CostTracker::with_defaults(db.clone()).unwrap();
+        let tracker = CostTracker::new(db, config).unwrap();
+
+        let default_cost =
+            default_tracker.calculate_model_cost("gpt-4o-mini", 1_000_000, 1_000_000);
+        let cost = tracker.calculate_model_cost("gpt-4o-mini", 1_000_000, 1_000_000);
+
+        assert!((default_cost - 0.75).abs() < 0.001);
+        assert!((cost - 1.50).abs() < 0.001);
+        assert!((tracker.calculate_cost(1_000_000, 1_000_000) - 1.50).abs() < 0.001);
+        // Unknown models fall back to the default model's rates
+        assert!(
+            (tracker.calculate_model_cost("unknown", 1_000_000, 1_000_000) - 1.50).abs() < 0.001
+        );
+    }
+
+    #[test]
+    fn test_record_call_adds_configured_call_cost() {
+        let db = Arc::new(DbManager::new(":memory:", 1, Some("test-key")).unwrap());
+        let mut config = CostRateConfig::default();
+        config.call_costs.insert("sap".to_string(), 0.01);
+        let tracker = CostTracker::new(db, config).unwrap();
+
+        tracker.record_call("sap").unwrap();
+        tracker.record_call("calendar").unwrap();
+
+        let metrics = tracker.get_metrics().unwrap();
+        assert!((metrics.total_cost_usd - 0.01).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_budget_reservations_stop_at_limit() {
+        let budget = BudgetGuard::new(1.0);
+
+        let _first = budget.try_spend(0.75).unwrap();
+        let _second = budget.try_spend(0.25).unwrap();
+        let err = budget.try_spend(0.01).unwrap_err();
+
+        assert!(matches!(err, BudgetError::Exceeded { .. }));
+        assert_eq!(budget.headroom(), 0.0);
+    }
+
+    #[test]
+    fn test_settling_below_estimate_returns_headroom() {
+        let budget = BudgetGuard::new(1.0);
+        let token = budget.try_spend(1.0).unwrap();
+        assert!(budget.try_spend(0.25).is_err());
+
+        let settlement = token.settle(0.7).unwrap();
+
+        assert!((settlement.variance_usd + 0.3).abs() < 1e-9);
+        assert!((budget.headroom() - 0.3).abs() < 1e-9);
+        assert!(budget.try_spend(0.25).is_ok());
+    }
+
+    #[test]
+    fn test_dropped_token_releases_reservation() {
+        let budget = BudgetGuard::new(1.0);
+
+        drop(budget.try_spend(0.8).unwrap());
+
+        assert!((budget.headroom() - 1.0).abs() < 1e-9);
+        assert!(matches!(budget.try_spend(-1.0), Err(BudgetError::InvalidAmount(_))));
+    }
+
+    #[test]
+    fn test_tracker_budget_tracks_reservations_and_settlements() {
+        let db = Arc::new(DbManager::new(":memory:", 1, Some("test-key")).unwrap());
+        let tracker = CostTracker::with_defaults(db).unwrap();
+
+        let settled = tracker.budget().try_spend(2.0).unwrap();
+        let _open = tracker.budget().try_spend(1.0).unwrap();
+        settled.settle(2.5).unwrap();
+
+        let snapshot = tracker.budget_snapshot();
+        assert_eq!(snapshot.limit_usd, 5.0);
+        assert_eq!((snapshot.reservations, snapshot.settlements), (2, 1));
+        assert!((snapshot.reserved_usd - 1.0).abs() < 1e-9);
+        assert!((snapshot.settled_usd - 2.5).abs() < 1e-9);
+        assert!((snapshot.headroom_usd - 1.5).abs() < 1e-9);
+    }
+
+    /// Tracker over a migrated database, kept alive by the returned dir
+    fn migrated_tracker() -> (tempfile::TempDir, CostTracker) {
+        let dir = tempfile::TempDir::new().unwrap();
+        let db =
+            Arc::new(DbManager::new(dir.path().join("costs.db"), 1, Some("test-key")).unwrap());
+        db.run_migrations().unwrap();
+        (dir, CostTracker::with_defaults(db).unwrap())
+    }
+
+    fn usage(cost_usd: f64, timestamp: i64) -> TokenUsage {
+        TokenUsage {
+            batch_id: "batch-1".to_string(),
+            user_id: "user-1".to_string(),
+            input_tokens: 1_000,
+            output_tokens: 500,
+            estimated_cost_usd: cost_usd,
+            timestamp,
+            is_actual: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reserve_seeds_headroom_from_persisted_cost() {
+        let (_dir, tracker) = migrated_tracker();
+        tracker.record_usage(&usage(4.0, Utc::now().timestamp())).await.unwrap();
+
+        let err = tracker.reserve("user-1", 1.5).await.unwrap_err();
+        assert!(matches!(err, BudgetError::Exceeded { .. }));
+        let _token = tracker.reserve("user-1", 0.5).await.unwrap();
+
+        let snapshot = tracker.budget_snapshot();
+        assert!((snapshot.settled_usd - 4.0).abs() < 1e-9);
+        assert!((snapshot.headroom_usd - 0.5).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_settle_usage_records_actual_cost_once() {
+        let (_dir, tracker) = migrated_tracker();
+
+        let token = tracker.reserve("user-1", 1.0).await.unwrap();
+        let settlement =
+            tracker.settle_usage(token, &usage(0.4, Utc::now().timestamp())).await.unwrap();
+
+        assert!((settlement.variance_usd + 0.6).abs() < 1e-9);
+        assert!((tracker.get_monthly_cost("user-1").await.unwrap() - 0.4).abs() < 1e-9);
+        assert_eq!(tracker.get_metrics().unwrap().openai_calls, 1);
+
+        // Resyncing with the persisted cost does not count the settlement twice
+        let _token = tracker.reserve("user-1", 0.1).await.unwrap();
+        let snapshot = tracker.budget_snapshot();
+        assert!((snapshot.settled_usd - 0.4).abs() < 1e-9);
+        assert!((snapshot.headroom_usd - 4.5).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_settle_usage_rejects_invalid_cost_and_releases_reservation() {
+        let (_dir, tracker) = migrated_tracker();
+
+        let token = tracker.reserve("user-1", 1.0).await.unwrap();
+        let result = tracker.settle_usage(token, &usage(-1.0, Utc::now().timestamp())).await;
+
+        assert!(matches!(result, Err(CommonError::Validation { .. })));
+        assert_eq!(tracker.get_monthly_cost("user-1").await.unwrap(), 0.0);
+        assert!((tracker.budget().headroom() - 5.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_spend_leaving_the_window_frees_headroom() {
+        let (_dir, tracker) = migrated_tracker();
+        let now = Utc::now().timestamp();
+        tracker.record_usage(&usage(4.5, now)).await.unwrap();
+        assert!(tracker.reserve("user-1", 1.0).await.is_err());
+
+        // The spend ages out of the 30-day window
+        let conn = tracker.db.get_connection().unwrap();
+        conn.execute(
+            "UPDATE token_usage SET timestamp = ?1",
+            rusqlite::params![now - THIRTY_DAYS_SECS - 1],
+        )
+        .unwrap();
+        drop(conn);
+
+        let _token = tracker.reserve("user-1", 1.0).await.unwrap();
+        assert!((tracker.budget().headroom() - 4.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_cost_config_validation() {
         let db = Arc::new(DbManager::new(":memory:", 1, Some("test-key")).unwrap());
@@ -99,6 +99,7 @@ impl From<PulseArcError> for SyncError {
     fn from(err: PulseArcError) -> Self {
         match err {
             PulseArcError::Database(message) => Self::Database(message),
+            PulseArcError::DatabaseOpen(err) => Self::Database(err.to_string()),
             PulseArcError::Config(message) => Self::Config(message),
             PulseArcError::Platform(message) => Self::Server(message),
             PulseArcError::Network(message) => Self::Network(message),
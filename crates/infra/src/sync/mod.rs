@@ -2,9 +2,10 @@
 //!
 //! This module provides background synchronization services:
 //! - NeonClient: Postgres database sync to remote
-//! - CostTracker: API usage tracking and cost monitoring
+//! - CostTracker: API usage tracking, cost monitoring and budget reservations
 //! - CleanupService: Periodic cleanup of stale data
 //! - OutboxWorker: Batch processing and forwarding of outbox entries
+//! - VectorClock: Conflict detection for rows edited on several devices
 //!
 //! All modules follow CLAUDE.md runtime rules with explicit lifecycle
 //! management, join handle tracking, and cancellation support.
@@ -14,9 +15,14 @@ pub mod cost_tracker;
 mod errors;
 pub mod neon_client;
 pub mod outbox_worker;
+pub mod vector_clock;
 
 pub use cleanup::{CleanupConfig, CleanupService, CleanupStats};
-pub use cost_tracker::{CostMetrics, CostRateConfig, CostTracker, DailyCost};
+pub use cost_tracker::{
+    BudgetError, BudgetGuard, BudgetSnapshot, CostMetrics, CostRateConfig, CostTracker, DailyCost,
+    Settlement, SpendToken,
+};
 pub use errors::SyncError;
-pub use neon_client::{NeonClient, NeonClientConfig};
-pub use outbox_worker::{OutboxWorker, OutboxWorkerConfig, TimeEntryForwarder};
+pub use neon_client::{NeonClient, NeonClientConfig, UpsertOutcome};
+pub use outbox_worker::{OutboxOrdering, OutboxWorker, OutboxWorkerConfig, TimeEntryForwarder};
+pub use vector_clock::{ClockOrdering, ConflictWinner, EditConflict, VectorClock, VersionedRecord};
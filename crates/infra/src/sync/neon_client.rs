@@ -27,7 +27,14 @@ use serde::{Deserialize, Serialize};
 use tracing::{debug, info, instrument, warn};
 
 use super::errors::SyncError;
+use super::vector_clock::{
+    resolve_concurrent, ClockOrdering, ConflictWinner, EditConflict, VersionedRecord,
+};
 use crate::http::HttpClient;
+use crate::observability::metrics::PerformanceMetrics;
+
+/// Upsert rounds before giving up on a row that keeps changing underneath us
+const MAX_UPSERT_ATTEMPTS: usize = 3;
 
 /// Configuration for Neon client
 #[derive(Debug, Clone)]
@@ -102,6 +109,31 @@ struct CreateTimeEntryResponse {
     created: bool,
 }
 
+/// Body of `PUT /time-entries/{id}`
+#[derive(Debug, Clone, Serialize)]
+struct UpsertTimeEntryRequest<'a> {
+    record: &'a VersionedRecord<PrismaTimeEntryDto>,
+}
+
+/// Body of a `409 Conflict` upsert response: the version the server kept
+#[derive(Debug, Clone, Deserialize)]
+struct UpsertConflictResponse {
+    current: VersionedRecord<PrismaTimeEntryDto>,
+}
+
+/// Result of a conflict-aware upsert
+#[derive(Debug, Clone, PartialEq)]
+pub enum UpsertOutcome {
+    /// The server stored this version
+    Applied,
+    /// The server already holds a causally later version; ours was dropped
+    Superseded { remote: Box<VersionedRecord<PrismaTimeEntryDto>> },
+    /// Another device edited the row concurrently. The edits were resolved
+    /// last-write-wins (`conflict.resolved` is what the server now holds) and
+    /// the losing edit should be reconciled.
+    Conflict(Box<EditConflict<PrismaTimeEntryDto>>),
+}
+
 impl NeonClient {
     /// Create a new Neon client with default configuration
     ///
@@ -133,6 +165,14 @@ impl NeonClient {
         Ok(Self { http_client: Arc::new(http_client), config, keychain: Arc::new(keychain) })
     }
 
+    /// Record per-operation retry metrics (attempts histogram, exhausted
+    /// retries) in `metrics`
+    pub fn with_metrics(mut self, metrics: Arc<PerformanceMetrics>) -> Self {
+        let http_client = (*self.http_client).clone().with_retry_metrics(metrics);
+        self.http_client = Arc::new(http_client);
+        self
+    }
+
     /// Get API token from keychain
     ///
     /// # Errors
@@ -200,7 +240,7 @@ impl NeonClient {
             .header("Content-Type", "application/json")
             .json(&request_body);
 
-        let response = self.send_request(request_builder).await?;
+        let response = self.send_request("neon.sync_segment", request_builder).await?;
 
         let result: CreateSegmentResponse = response
             .json()
@@ -248,7 +288,7 @@ impl NeonClient {
             .header("Content-Type", "application/json")
             .json(&request_body);
 
-        let response = self.send_request(request_builder).await?;
+        let response = self.send_request("neon.sync_segments_batch", request_builder).await?;
 
         let result: BatchSegmentsResponse = response
             .json()
@@ -291,7 +331,7 @@ impl NeonClient {
             .header("Content-Type", "application/json")
             .json(&request_body);
 
-        let response = self.send_request(request_builder).await?;
+        let response = self.send_request("neon.submit_time_entry", request_builder).await?;
         let status = response.status();
 
         if status == StatusCode::NO_CONTENT || status == StatusCode::RESET_CONTENT {
@@ -309,6 +349,100 @@ impl NeonClient {
         Ok(result.id)
     }
 
+    /// Upsert a versioned time entry without overwriting concurrent edits.
+    ///
+    /// The server applies the record only if its clock is not behind the
+    /// stored one, otherwise it answers `409 Conflict` with the stored
+    /// version:
+    /// - Stored version causally later: ours is stale,
+    ///   [`UpsertOutcome::Superseded`]
+    /// - Concurrent edits: resolved last-write-wins. If ours wins it is
+    ///   re-sent with the merged clock; either way the conflict is returned as
+    ///   [`UpsertOutcome::Conflict`]
+    ///
+    /// # Errors
+    ///
+    /// Returns error on transport/status failures, or
+    /// [`SyncError::Server`] if the row keeps changing for
+    /// `MAX_UPSERT_ATTEMPTS` rounds.
+    #[instrument(skip(self, record), fields(device_id = %record.device_id))]
+    pub async fn upsert_time_entry(
+        &self,
+        entry_id: &str,
+        record: &VersionedRecord<PrismaTimeEntryDto>,
+    ) -> Result<UpsertOutcome, SyncError> {
+        let mut candidate = record.clone();
+        let mut conflict = None;
+
+        for attempt in 1..=MAX_UPSERT_ATTEMPTS {
+            let Some(current) = self.put_time_entry_version(entry_id, &candidate).await? else {
+                info!(attempt, conflict = conflict.is_some(), "Time entry upserted to Neon");
+                return Ok(match conflict {
+                    Some(conflict) => UpsertOutcome::Conflict(Box::new(conflict)),
+                    None => UpsertOutcome::Applied,
+                });
+            };
+
+            match candidate.clock.compare(&current.clock) {
+                ClockOrdering::Before | ClockOrdering::Equal => {
+                    debug!("Neon holds a causally later time entry version");
+                    return Ok(UpsertOutcome::Superseded { remote: Box::new(current) });
+                }
+                ClockOrdering::Concurrent => {
+                    let resolved = resolve_concurrent(&candidate, &current);
+                    warn!(
+                        remote_device = %current.device_id,
+                        winner = ?resolved.winner,
+                        "Concurrent time entry edit detected"
+                    );
+                    if resolved.winner == ConflictWinner::Remote {
+                        return Ok(UpsertOutcome::Conflict(Box::new(resolved)));
+                    }
+                    candidate = resolved.resolved.clone();
+                    conflict = Some(resolved);
+                }
+                // The row changed between the server's check and its reply;
+                // our version still dominates, so send it again
+                ClockOrdering::After => {}
+            }
+        }
+
+        Err(SyncError::Server(format!(
+            "Time entry {} still conflicting after {} attempts",
+            entry_id, MAX_UPSERT_ATTEMPTS
+        )))
+    }
+
+    /// `PUT` one version; `Some(current)` if the server rejected it with 409
+    async fn put_time_entry_version(
+        &self,
+        entry_id: &str,
+        record: &VersionedRecord<PrismaTimeEntryDto>,
+    ) -> Result<Option<VersionedRecord<PrismaTimeEntryDto>>, SyncError> {
+        let token = self.get_api_token()?;
+        let url = format!("{}/time-entries/{}", self.config.base_url, entry_id);
+
+        debug!(url = %url, "Upserting versioned time entry to Neon");
+
+        let request_builder = self
+            .http_client
+            .request(Method::PUT, &url)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Content-Type", "application/json")
+            .json(&UpsertTimeEntryRequest { record });
+
+        let response = self.send_unchecked("neon.upsert_time_entry", request_builder).await?;
+        if response.status() == StatusCode::CONFLICT {
+            let body: UpsertConflictResponse = response.json().await.map_err(|e| {
+                SyncError::Client(format!("Failed to parse conflict response: {}", e))
+            })?;
+            return Ok(Some(body.current));
+        }
+
+        classify_status(response.status())?;
+        Ok(None)
+    }
+
     /// Health check for Neon API
     ///
     /// # Returns
@@ -340,7 +474,23 @@ impl NeonClient {
         }
     }
 
-    async fn send_request(&self, builder: RequestBuilder) -> Result<Response, SyncError> {
+    async fn send_request(
+        &self,
+        operation: &str,
+        builder: RequestBuilder,
+    ) -> Result<Response, SyncError> {
+        let response = self.send_unchecked(operation, builder).await?;
+        classify_status(response.status())?;
+        Ok(response)
+    }
+
+    /// Validate and send a request, leaving status handling to the caller
+    /// (except 503, which is always a rate limit)
+    async fn send_unchecked(
+        &self,
+        operation: &str,
+        builder: RequestBuilder,
+    ) -> Result<Response, SyncError> {
         let prepared_builder = builder
             .try_clone()
             .ok_or_else(|| SyncError::Client("Unable to clone request for validation".into()))?;
@@ -356,14 +506,14 @@ impl NeonClient {
             return Err(SyncError::Client(format!("HTTP method {} is not allowed", method)));
         }
 
-        let response = self.http_client.send(builder).await.map_err(SyncError::from)?;
+        let response =
+            self.http_client.send_operation(operation, builder).await.map_err(SyncError::from)?;
         let status = response.status();
 
         if status == StatusCode::SERVICE_UNAVAILABLE {
             return Err(SyncError::RateLimit("Neon service unavailable".into()));
         }
 
-        classify_status(status)?;
         Ok(response)
     }
 }
@@ -392,6 +542,7 @@ mod tests {
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use super::*;
+    use crate::sync::vector_clock::VectorClock;
 
     #[tokio::test]
     async fn test_health_check_success() {
@@ -559,4 +710,159 @@ mod tests {
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), SyncError::Server(_)));
     }
+
+    type StoredRow = Arc<std::sync::Mutex<Option<VersionedRecord<PrismaTimeEntryDto>>>>;
+
+    /// In-memory Neon time-entry endpoint enforcing the versioned upsert
+    /// contract: apply unless the stored clock is ahead or concurrent
+    #[derive(Clone, Default)]
+    struct VersionedStore {
+        row: StoredRow,
+    }
+
+    impl VersionedStore {
+        fn stored(&self) -> VersionedRecord<PrismaTimeEntryDto> {
+            self.row.lock().unwrap().clone().expect("row stored")
+        }
+    }
+
+    impl wiremock::Respond for VersionedStore {
+        fn respond(&self, request: &wiremock::Request) -> ResponseTemplate {
+            let body: serde_json::Value = request.body_json().expect("json body");
+            let incoming: VersionedRecord<PrismaTimeEntryDto> =
+                serde_json::from_value(body["record"].clone()).expect("versioned record");
+
+            let mut row = self.row.lock().unwrap();
+            match row.as_ref() {
+                Some(current)
+                    if matches!(
+                        incoming.clock.compare(&current.clock),
+                        ClockOrdering::Before | ClockOrdering::Concurrent
+                    ) =>
+                {
+                    ResponseTemplate::new(409)
+                        .set_body_json(serde_json::json!({ "current": current }))
+                }
+                _ => {
+                    *row = Some(incoming);
+                    ResponseTemplate::new(200).set_body_json(serde_json::json!({ "applied": true }))
+                }
+            }
+        }
+    }
+
+    async fn versioned_client(server: &MockServer, store: &VersionedStore) -> NeonClient {
+        Mock::given(method("PUT"))
+            .and(path("/time-entries/entry-1"))
+            .respond_with(store.clone())
+            .mount(server)
+            .await;
+
+        let config = NeonClientConfig {
+            base_url: server.uri(),
+            keychain_service_name: "PulseArc.neon.test.upsert".to_string(),
+            ..Default::default()
+        };
+        std::env::set_var("PULSARC_NEON_API_TOKEN", "test-token");
+        NeonClient::with_config(config).unwrap()
+    }
+
+    fn entry_with_notes(notes: &str) -> PrismaTimeEntryDto {
+        PrismaTimeEntryDto { notes: Some(notes.to_string()), ..sample_time_entry() }
+    }
+
+    /// Both devices start from the version created on the laptop
+    async fn seed_base(client: &NeonClient) -> VersionedRecord<PrismaTimeEntryDto> {
+        let base = VersionedRecord::edit(
+            entry_with_notes("original"),
+            &VectorClock::new(),
+            "laptop",
+            1_000,
+        );
+        assert_eq!(
+            client.upsert_time_entry("entry-1", &base).await.unwrap(),
+            UpsertOutcome::Applied
+        );
+        base
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_edits_keep_latest_write_and_report_conflict() {
+        let server = MockServer::start().await;
+        let store = VersionedStore::default();
+        let client = versioned_client(&server, &store).await;
+        let base = seed_base(&client).await;
+
+        let laptop =
+            VersionedRecord::edit(entry_with_notes("laptop edit"), &base.clock, "laptop", 2_000);
+        let desktop =
+            VersionedRecord::edit(entry_with_notes("desktop edit"), &base.clock, "desktop", 3_000);
+
+        assert_eq!(
+            client.upsert_time_entry("entry-1", &laptop).await.unwrap(),
+            UpsertOutcome::Applied
+        );
+        let outcome = client.upsert_time_entry("entry-1", &desktop).await.unwrap();
+
+        let UpsertOutcome::Conflict(conflict) = outcome else {
+            panic!("expected a conflict, got {outcome:?}");
+        };
+        assert_eq!(conflict.winner, ConflictWinner::Local);
+        assert_eq!(conflict.local, desktop);
+        assert_eq!(conflict.remote, laptop);
+
+        // The later desktop edit wins and dominates both edits
+        let stored = store.stored();
+        assert_eq!(stored.payload.notes.as_deref(), Some("desktop edit"));
+        assert_eq!(stored.device_id, "desktop");
+        assert_eq!(stored.clock.compare(&laptop.clock), ClockOrdering::After);
+        assert_eq!(stored.clock.compare(&desktop.clock), ClockOrdering::After);
+        assert_eq!(stored, conflict.resolved);
+    }
+
+    #[tokio::test]
+    async fn test_stale_concurrent_edit_loses_and_reports_conflict() {
+        let server = MockServer::start().await;
+        let store = VersionedStore::default();
+        let client = versioned_client(&server, &store).await;
+        let base = seed_base(&client).await;
+
+        let laptop =
+            VersionedRecord::edit(entry_with_notes("laptop edit"), &base.clock, "laptop", 3_000);
+        let desktop =
+            VersionedRecord::edit(entry_with_notes("desktop edit"), &base.clock, "desktop", 2_000);
+
+        client.upsert_time_entry("entry-1", &laptop).await.unwrap();
+        let outcome = client.upsert_time_entry("entry-1", &desktop).await.unwrap();
+
+        let UpsertOutcome::Conflict(conflict) = outcome else {
+            panic!("expected a conflict, got {outcome:?}");
+        };
+        assert_eq!(conflict.winner, ConflictWinner::Remote);
+        assert_eq!(conflict.resolved.payload.notes.as_deref(), Some("laptop edit"));
+        assert_eq!(store.stored(), laptop, "server keeps the later laptop edit");
+    }
+
+    #[tokio::test]
+    async fn test_causally_ordered_edits_apply_without_conflict() {
+        let server = MockServer::start().await;
+        let store = VersionedStore::default();
+        let client = versioned_client(&server, &store).await;
+        let base = seed_base(&client).await;
+
+        // The desktop saw the base version, so its edit supersedes it even
+        // though its wall clock is behind
+        let desktop =
+            VersionedRecord::edit(entry_with_notes("desktop edit"), &base.clock, "desktop", 500);
+        assert_eq!(
+            client.upsert_time_entry("entry-1", &desktop).await.unwrap(),
+            UpsertOutcome::Applied
+        );
+        assert_eq!(store.stored(), desktop);
+
+        // Replaying the old base version is stale, not a conflict
+        let outcome = client.upsert_time_entry("entry-1", &base).await.unwrap();
+        assert_eq!(outcome, UpsertOutcome::Superseded { remote: Box::new(desktop.clone()) });
+        assert_eq!(store.stored(), desktop);
+    }
 }
@@ -7,6 +7,31 @@
 //! asynchronous operation is wrapped in a timeout. SAP-bound entries are
 //! handled by the dedicated `SapScheduler`.
 //!
+//! With [`OutboxWorker::with_concurrency_limiter`], every forward holds a
+//! permit from a shared [`AdaptiveConcurrencyLimiter`] and reports its
+//! latency, so the worker and other senders back off together when the Neon
+//! API slows down.
+//!
+//! With [`OutboxWorker::with_sync_blocked`], nothing is forwarded while the
+//! signal holds `true` (MDM policy blocking sync): background flushes are
+//! skipped and `sync_now` fails.
+//!
+//! Flushes are requested through [`SyncQueue`] priority lanes: each poll tick
+//! queues a `Priority::Background` flush, while [`OutboxWorker::sync_now`]
+//! queues a `Priority::High` one. The user's request is serviced first, and a
+//! background batch already in progress yields to it between entries, so an
+//! explicit "sync now" does not wait behind a large backlog.
+//!
+//! By default a batch is forwarded one entry at a time in dequeue order. With
+//! [`OutboxOrdering::PerEntity`], entries are grouped by the time entry they
+//! affect (see [`TimeEntryOutbox::entity_id`]): each entity's entries are still
+//! delivered in enqueue order, while different entities are forwarded
+//! concurrently. When an entry fails, the entity's later entries are held back
+//! so an update never reaches the backend ahead of the create it depends on.
+//! The hold outlives the batch: [`OutboxQueue::dequeue_batch`] does not return
+//! an entry while an earlier entry for the same entity is still pending (for
+//! example waiting out its retry window) or dead-lettered.
+//!
 //! # Example
 //!
 //! ```no_run
@@ -39,19 +64,27 @@
 //! # }
 //! ```
 
-use std::sync::Arc;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
+use pulsearc_common::lifecycle::{ShutdownHook, ShutdownReason};
+use pulsearc_common::resilience::{AdaptiveConcurrencyLimiter, AdaptiveConcurrencyMetrics};
+use pulsearc_common::sync::{Priority, QueueError, SyncItem, SyncQueue};
 use pulsearc_core::OutboxQueue;
-use pulsearc_domain::types::{PrismaTimeEntryDto, TimeEntryOutbox};
-use tokio::task::JoinHandle;
+use pulsearc_domain::types::{OutboxFlushReport, PrismaTimeEntryDto, TimeEntryOutbox};
+use serde_json::json;
+use tokio::sync::{oneshot, watch, Notify};
+use tokio::task::{JoinHandle, JoinSet};
+use tokio::time::MissedTickBehavior;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, instrument, warn};
 
+use crate::database::outbox_compaction::{entity_key, OutboxEntityKey};
 use crate::observability::metrics::PerformanceMetrics;
 use crate::observability::MetricsResult;
-use crate::sync::errors::SyncError;
+use crate::sync::errors::{SyncError, SyncErrorCategory};
 use crate::sync::neon_client::NeonClient;
 
 /// Configuration for the outbox worker.
@@ -67,6 +100,8 @@ pub struct OutboxWorkerConfig {
     pub max_retries: usize,
     /// Join timeout when stopping
     pub join_timeout: Duration,
+    /// Delivery ordering within a batch
+    pub ordering: OutboxOrdering,
 }
 
 impl Default for OutboxWorkerConfig {
@@ -77,10 +112,28 @@ impl Default for OutboxWorkerConfig {
             processing_timeout: Duration::from_secs(300),
             max_retries: 3,
             join_timeout: Duration::from_secs(5),
+            ordering: OutboxOrdering::default(),
         }
     }
 }
 
+/// How the worker orders deliveries within a batch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutboxOrdering {
+    /// Forward entries one at a time in dequeue order.
+    #[default]
+    Sequential,
+    /// Deliver entries for the same entity in enqueue order, forwarding up to
+    /// `max_in_flight` different entities concurrently.
+    ///
+    /// Entries without an entity id are treated as independent entities.
+    PerEntity {
+        /// Maximum number of entries forwarded at once (values below 1 are
+        /// treated as 1)
+        max_in_flight: usize,
+    },
+}
+
 /// Interface for submitting time entries to a remote destination.
 #[async_trait]
 pub trait TimeEntryForwarder: Send + Sync {
@@ -103,6 +156,140 @@ impl TimeEntryForwarder for NeonClient {
     }
 }
 
+/// Forwarder decorator that holds an adaptive concurrency permit for the
+/// duration of each submission.
+struct LimitedForwarder {
+    inner: Arc<dyn TimeEntryForwarder>,
+    limiter: AdaptiveConcurrencyLimiter,
+}
+
+#[async_trait]
+impl TimeEntryForwarder for LimitedForwarder {
+    async fn forward_time_entry(
+        &self,
+        dto: &PrismaTimeEntryDto,
+        idempotency_key: &str,
+    ) -> Result<String, SyncError> {
+        let permit = self.limiter.acquire().await;
+        let result = self.inner.forward_time_entry(dto, idempotency_key).await;
+        match &result {
+            Ok(_) => permit.record_success(),
+            Err(err) if signals_overload(err) => permit.record_failure(),
+            Err(_) => drop(permit),
+        }
+        result
+    }
+}
+
+/// Whether a failed forward suggests the backend is overloaded (as opposed to
+/// a problem with the payload or credentials).
+fn signals_overload(err: &SyncError) -> bool {
+    matches!(
+        err.category(),
+        SyncErrorCategory::RateLimit | SyncErrorCategory::Server | SyncErrorCategory::Network
+    )
+}
+
+/// Outcome delivered to a waiting `sync_now` caller
+type FlushResult = Result<OutboxFlushReport, String>;
+
+/// Senders for flush requests awaiting a result, keyed by queue item id
+type FlushWaiters = Mutex<HashMap<String, oneshot::Sender<FlushResult>>>;
+
+/// Queue item id shared by all background flush requests, so deduplication
+/// keeps at most one waiting at a time.
+const BACKGROUND_FLUSH_ID: &str = "outbox-background-flush";
+
+/// Flush requests waiting for the worker loop, ordered by `SyncQueue`
+/// priority.
+struct FlushLanes {
+    queue: SyncQueue,
+    waiters: FlushWaiters,
+    wake: Notify,
+}
+
+impl FlushLanes {
+    fn new() -> Self {
+        Self { queue: SyncQueue::new(), waiters: Mutex::new(HashMap::new()), wake: Notify::new() }
+    }
+
+    /// Queue a background flush unless one is already waiting or running.
+    async fn request_background(&self) {
+        let request = SyncItem::with_id(
+            BACKGROUND_FLUSH_ID.to_string(),
+            json!({ "source": "poll" }),
+            Priority::Background,
+        );
+        match self.queue.push(request).await {
+            Ok(()) | Err(QueueError::DuplicateItem(_)) => {}
+            Err(err) => warn!(error = %err, "Failed to queue background outbox flush"),
+        }
+    }
+
+    /// Queue a user-initiated flush and wake the worker loop.
+    ///
+    /// The returned receiver resolves once the request has been serviced.
+    async fn request_priority(&self) -> Result<oneshot::Receiver<FlushResult>, String> {
+        let request = SyncItem::new(json!({ "source": "sync_now" }), Priority::High);
+        let request_id = request.id.clone();
+        let (sender, receiver) = oneshot::channel();
+
+        self.waiters
+            .lock()
+            .map_err(|_| "Sync request registry lock poisoned".to_string())?
+            .insert(request_id.clone(), sender);
+
+        if let Err(err) = self.queue.push(request).await {
+            self.take_waiter(&request_id);
+            return Err(format!("Failed to queue sync request: {err}"));
+        }
+
+        self.wake.notify_one();
+        Ok(receiver)
+    }
+
+    /// Next request in priority order, if any.
+    async fn next_request(&self) -> Option<SyncItem> {
+        match self.queue.pop().await {
+            Ok(request) => request,
+            Err(err) => {
+                warn!(error = %err, "Failed to pop outbox flush request");
+                None
+            }
+        }
+    }
+
+    /// Whether a request above background priority is waiting.
+    fn priority_waiting(&self) -> bool {
+        self.queue.peek().is_some_and(|request| request.priority < Priority::Background)
+    }
+
+    /// Retire a serviced request and hand its outcome to the caller, if any.
+    async fn complete(&self, request_id: &str, result: FlushResult) {
+        if let Err(err) = self.queue.mark_completed(request_id).await {
+            warn!(request_id, error = %err, "Failed to retire outbox flush request");
+        }
+        if let Some(waiter) = self.take_waiter(request_id) {
+            // The caller may have given up waiting; nothing to do then
+            let _ = waiter.send(result);
+        }
+    }
+
+    /// Drop queued requests; waiting callers observe the worker stopping.
+    async fn abandon(&self) {
+        if let Err(err) = self.queue.clear().await {
+            warn!(error = %err, "Failed to clear outbox flush requests");
+        }
+        if let Ok(mut waiters) = self.waiters.lock() {
+            waiters.clear();
+        }
+    }
+
+    fn take_waiter(&self, request_id: &str) -> Option<oneshot::Sender<FlushResult>> {
+        self.waiters.lock().ok().and_then(|mut waiters| waiters.remove(request_id))
+    }
+}
+
 /// Outbox worker with explicit lifecycle management.
 pub struct OutboxWorker {
     outbox_repo: Arc<dyn OutboxQueue>,
@@ -111,6 +298,9 @@ pub struct OutboxWorker {
     cancellation: CancellationToken,
     task_handle: Option<JoinHandle<()>>,
     metrics: Arc<PerformanceMetrics>,
+    concurrency_limiter: Option<AdaptiveConcurrencyLimiter>,
+    lanes: Arc<FlushLanes>,
+    sync_blocked: Option<watch::Receiver<bool>>,
 }
 
 impl OutboxWorker {
@@ -128,9 +318,40 @@ impl OutboxWorker {
             cancellation: CancellationToken::new(),
             task_handle: None,
             metrics,
+            concurrency_limiter: None,
+            lanes: Arc::new(FlushLanes::new()),
+            sync_blocked: None,
         }
     }
 
+    /// Gate every forward on a permit from `limiter`, feeding it the observed
+    /// latency.
+    ///
+    /// Share the limiter with other senders to the same backend (e.g. the
+    /// `ApiForwarder`) so their combined in-flight requests adapt together.
+    pub fn with_concurrency_limiter(mut self, limiter: AdaptiveConcurrencyLimiter) -> Self {
+        self.forwarder =
+            Arc::new(LimitedForwarder { inner: self.forwarder.clone(), limiter: limiter.clone() });
+        self.concurrency_limiter = Some(limiter);
+        self
+    }
+
+    /// Forward nothing while `sync_blocked` holds `true`.
+    ///
+    /// Fed from MDM enforcement (`PolicyEnforcer::subscribe_sync_blocked`).
+    /// Background flushes are skipped and `sync_now` fails until the block is
+    /// lifted. Call before [`Self::start`].
+    pub fn with_sync_blocked(mut self, sync_blocked: watch::Receiver<bool>) -> Self {
+        self.sync_blocked = Some(sync_blocked);
+        self
+    }
+
+    /// Current adaptive concurrency limit and in-flight count, if a limiter is
+    /// configured.
+    pub fn concurrency_metrics(&self) -> Option<AdaptiveConcurrencyMetrics> {
+        self.concurrency_limiter.as_ref().map(AdaptiveConcurrencyLimiter::metrics)
+    }
+
     /// Start the worker, spawning the background processing task.
     #[instrument(skip(self))]
     pub async fn start(&mut self) -> Result<(), String> {
@@ -146,20 +367,26 @@ impl OutboxWorker {
         let outbox_repo = Arc::clone(&self.outbox_repo);
         let forwarder = Arc::clone(&self.forwarder);
         let poll_interval = self.config.poll_interval;
-        let batch_size = self.config.batch_size;
-        let processing_timeout = self.config.processing_timeout;
+        let batch = BatchSettings {
+            batch_size: self.config.batch_size,
+            processing_timeout: self.config.processing_timeout,
+            ordering: self.config.ordering,
+        };
         let cancel = self.cancellation.clone();
         let metrics = Arc::clone(&self.metrics);
+        let lanes = Arc::clone(&self.lanes);
+        let sync_blocked = self.sync_blocked.clone();
 
         let handle = tokio::spawn(async move {
             Self::process_loop(
+                lanes,
                 outbox_repo,
                 forwarder,
                 poll_interval,
-                batch_size,
-                processing_timeout,
+                batch,
                 cancel,
                 metrics,
+                sync_blocked,
             )
             .await;
         });
@@ -199,6 +426,8 @@ impl OutboxWorker {
             }
         }
 
+        self.lanes.abandon().await;
+
         info!("Outbox worker stopped");
         self.cancellation = CancellationToken::new();
         log_metric(self.metrics.record_call(), "outbox_worker.stop");
@@ -211,160 +440,439 @@ impl OutboxWorker {
         self.task_handle.is_some()
     }
 
+    /// Flush pending entries now, ahead of background batching.
+    ///
+    /// Queues a `Priority::High` request that the worker services before any
+    /// waiting background flush; a background batch in progress yields after
+    /// its current entry. Resolves with the outcome of one batch once it
+    /// completes. The wait is bounded by twice `processing_timeout` to cover
+    /// the entry being forwarded when the request arrives.
+    #[instrument(skip(self))]
+    pub async fn sync_now(&self) -> Result<OutboxFlushReport, String> {
+        if !self.is_running() {
+            return Err("Worker not running".to_string());
+        }
+
+        log_metric(self.metrics.record_call(), "outbox_worker.sync_now");
+        let receiver = self.lanes.request_priority().await?;
+
+        match tokio::time::timeout(self.config.processing_timeout.saturating_mul(2), receiver).await
+        {
+            Ok(Ok(result)) => result,
+            Ok(Err(_)) => Err("Worker stopped before servicing sync request".to_string()),
+            Err(_) => Err("Sync request timed out".to_string()),
+        }
+    }
+
     /// Background processing loop.
+    ///
+    /// Poll ticks queue a background flush; `sync_now` wakes the loop
+    /// directly. Either way, waiting requests are then drained in priority
+    /// order.
     #[allow(clippy::too_many_arguments)]
     async fn process_loop(
+        lanes: Arc<FlushLanes>,
         outbox_repo: Arc<dyn OutboxQueue>,
         forwarder: Arc<dyn TimeEntryForwarder>,
         poll_interval: Duration,
-        batch_size: usize,
-        processing_timeout: Duration,
+        batch: BatchSettings,
         cancel: CancellationToken,
         metrics: Arc<PerformanceMetrics>,
+        sync_blocked: Option<watch::Receiver<bool>>,
     ) {
+        let mut ticker = tokio::time::interval(poll_interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        // The first tick completes immediately; background flushes start one
+        // interval after startup
+        ticker.tick().await;
+
         loop {
             tokio::select! {
                 _ = cancel.cancelled() => {
                     debug!("Outbox worker process loop cancelled");
                     break;
                 }
-                _ = tokio::time::sleep(poll_interval) => {
+                () = lanes.wake.notified() => {}
+                _ = ticker.tick() => {
                     log_metric(metrics.record_call(), "outbox_worker.tick");
-                    let started = Instant::now();
-
-                    match tokio::time::timeout(
-                        processing_timeout,
-                        Self::process_batch(&outbox_repo, &forwarder, batch_size, &metrics),
-                    )
-                    .await
-                    {
-                        Ok(Ok(())) => {
-                            log_metric(
-                                metrics.record_fetch_time(started.elapsed()),
-                                "outbox_worker.batch.duration",
-                            );
-                        }
-                        Ok(Err(e)) => {
-                            error!(error = %e, "Batch processing failed");
-                            log_metric(metrics.record_fetch_error(), "outbox_worker.batch.error");
-                            log_metric(
-                                metrics.record_fetch_time(started.elapsed()),
-                                "outbox_worker.batch.duration",
-                            );
-                        }
-                        Err(_) => {
-                            warn!(timeout_secs = processing_timeout.as_secs(), "Batch processing timed out");
-                            log_metric(metrics.record_fetch_timeout(), "outbox_worker.batch.timeout");
-                        }
-                    }
+                    lanes.request_background().await;
                 }
             }
+
+            if sync_blocked.as_ref().is_some_and(|blocked| *blocked.borrow()) {
+                Self::reject_lanes(&lanes).await;
+                continue;
+            }
+
+            Self::drain_lanes(&lanes, &outbox_repo, &forwarder, batch, &metrics).await;
+        }
+    }
+
+    /// Fail waiting flush requests while MDM policy blocks sync.
+    async fn reject_lanes(lanes: &FlushLanes) {
+        let mut rejected = 0_usize;
+        while let Some(request) = lanes.next_request().await {
+            lanes.complete(&request.id, Err("Sync blocked by MDM policy".to_string())).await;
+            rejected += 1;
+        }
+        if rejected > 0 {
+            info!(rejected, "Sync blocked by MDM policy; outbox flush skipped");
+        }
+    }
+
+    /// Service waiting flush requests, highest priority first.
+    async fn drain_lanes(
+        lanes: &FlushLanes,
+        outbox_repo: &Arc<dyn OutboxQueue>,
+        forwarder: &Arc<dyn TimeEntryForwarder>,
+        batch: BatchSettings,
+        metrics: &Arc<PerformanceMetrics>,
+    ) {
+        let processing_timeout = batch.processing_timeout;
+        while let Some(request) = lanes.next_request().await {
+            let user_initiated = request.priority < Priority::Background;
+            let preempt = || !user_initiated && lanes.priority_waiting();
+            let started = Instant::now();
+
+            let result = match tokio::time::timeout(
+                processing_timeout,
+                Self::process_batch(outbox_repo, forwarder, batch, metrics, &preempt),
+            )
+            .await
+            {
+                Ok(Ok(report)) => {
+                    log_metric(
+                        metrics.record_fetch_time(started.elapsed()),
+                        "outbox_worker.batch.duration",
+                    );
+                    Ok(report)
+                }
+                Ok(Err(e)) => {
+                    error!(error = %e, user_initiated, "Batch processing failed");
+                    log_metric(metrics.record_fetch_error(), "outbox_worker.batch.error");
+                    log_metric(
+                        metrics.record_fetch_time(started.elapsed()),
+                        "outbox_worker.batch.duration",
+                    );
+                    Err(e)
+                }
+                Err(_) => {
+                    warn!(
+                        timeout_secs = processing_timeout.as_secs(),
+                        user_initiated, "Batch processing timed out"
+                    );
+                    log_metric(metrics.record_fetch_timeout(), "outbox_worker.batch.timeout");
+                    Err(format!(
+                        "Batch processing timed out after {}s",
+                        processing_timeout.as_secs()
+                    ))
+                }
+            };
+
+            lanes.complete(&request.id, result).await;
         }
     }
 
     /// Process a single batch of outbox entries.
+    ///
+    /// Before starting each entry after the first, `preempt` is consulted;
+    /// when it returns true the batch stops early and the remaining entries
+    /// stay pending for a later batch.
     async fn process_batch(
         outbox_repo: &Arc<dyn OutboxQueue>,
         forwarder: &Arc<dyn TimeEntryForwarder>,
-        batch_size: usize,
+        batch: BatchSettings,
         metrics: &Arc<PerformanceMetrics>,
-    ) -> Result<(), String> {
+        preempt: &(dyn Fn() -> bool + Sync),
+    ) -> Result<OutboxFlushReport, String> {
         // Dequeue pending entries (status = 'pending' and past retry window)
         let entries = outbox_repo
-            .dequeue_batch(batch_size)
+            .dequeue_batch(batch.batch_size)
             .await
             .map_err(|e| format!("Failed to dequeue batch: {e}"))?;
 
         if entries.is_empty() {
             debug!("No pending entries to process");
-            return Ok(());
+            return Ok(OutboxFlushReport::default());
         }
 
         info!(count = entries.len(), "Processing outbox batch");
 
-        let mut fatal_errors: Vec<String> = Vec::new();
-        let mut forwarded = 0_u32;
-        let mut failures = 0_u32;
-        let mut skipped = 0_u32;
+        let tally = match batch.ordering {
+            OutboxOrdering::Sequential => {
+                Self::forward_sequential(outbox_repo, forwarder, entries, preempt).await
+            }
+            OutboxOrdering::PerEntity { max_in_flight } => {
+                Self::forward_per_entity(outbox_repo, forwarder, entries, max_in_flight, preempt)
+                    .await
+            }
+        };
 
-        for entry in entries {
-            if entry.target.eq_ignore_ascii_case("sap") {
-                debug!(entry_id = %entry.id, "Skipping SAP-target outbox entry");
-                skipped = skipped.saturating_add(1);
-                continue;
+        log_metric(metrics.record_call(), "outbox_worker.batch.processed");
+        debug!(
+            forwarded = tally.forwarded,
+            failures = tally.failures,
+            skipped = tally.skipped,
+            "Outbox batch completed"
+        );
+
+        if tally.failures > 0 {
+            log_metric(metrics.record_fetch_error(), "outbox_worker.batch.failure_count");
+        }
+
+        tally.into_report()
+    }
+
+    /// Forward entries one at a time in dequeue order.
+    async fn forward_sequential(
+        outbox_repo: &Arc<dyn OutboxQueue>,
+        forwarder: &Arc<dyn TimeEntryForwarder>,
+        entries: Vec<TimeEntryOutbox>,
+        preempt: &(dyn Fn() -> bool + Sync),
+    ) -> BatchTally {
+        let mut tally = BatchTally::default();
+        let total = entries.len();
+
+        for (processed, entry) in entries.into_iter().enumerate() {
+            if processed > 0 && preempt() {
+                info!(
+                    remaining = total - processed,
+                    "Outbox batch yielding to user-initiated sync"
+                );
+                break;
             }
 
-            let dto = match parse_time_entry(&entry) {
-                Ok(dto) => dto,
-                Err(err) => {
-                    warn!(
-                        entry_id = %entry.id,
-                        error = %err,
-                        "Failed to parse outbox payload"
+            tally.record(forward_entry(outbox_repo.as_ref(), forwarder.as_ref(), &entry).await);
+        }
+
+        tally
+    }
+
+    /// Forward entries in per-entity FIFO lanes, running up to
+    /// `max_in_flight` lanes concurrently.
+    ///
+    /// A lane only starts its next entry once the previous one has been
+    /// forwarded and marked. When an entry fails, the rest of its lane stays
+    /// pending so later edits are not delivered ahead of it.
+    async fn forward_per_entity(
+        outbox_repo: &Arc<dyn OutboxQueue>,
+        forwarder: &Arc<dyn TimeEntryForwarder>,
+        entries: Vec<TimeEntryOutbox>,
+        max_in_flight: usize,
+        preempt: &(dyn Fn() -> bool + Sync),
+    ) -> BatchTally {
+        let mut lanes = entity_lanes(entries);
+        let mut busy = vec![false; lanes.len()];
+        let max_in_flight = max_in_flight.max(1);
+        let mut in_flight = JoinSet::new();
+        let mut tally = BatchTally::default();
+        let mut started = 0_usize;
+        let mut yielded = false;
+
+        loop {
+            while !yielded && in_flight.len() < max_in_flight {
+                let Some(lane) = (0..lanes.len()).find(|&i| !busy[i] && !lanes[i].is_empty())
+                else {
+                    break;
+                };
+                if started > 0 && preempt() {
+                    info!(
+                        remaining = lanes.iter().map(VecDeque::len).sum::<usize>(),
+                        "Outbox batch yielding to user-initiated sync"
                     );
-                    if let Err(mark_err) =
-                        outbox_repo.mark_failed(&entry.id, &truncate_reason(&err.to_string())).await
-                    {
-                        let msg = mark_err.to_string();
-                        warn!(entry_id = %entry.id, error = %msg, "mark_failed failed");
-                        fatal_errors.push(format!("mark_failed error for {}: {}", entry.id, msg));
-                    }
-                    failures = failures.saturating_add(1);
-                    continue;
+                    yielded = true;
+                    break;
                 }
-            };
+                let Some(entry) = lanes[lane].pop_front() else {
+                    break;
+                };
+
+                busy[lane] = true;
+                started += 1;
+                let outbox_repo = Arc::clone(outbox_repo);
+                let forwarder = Arc::clone(forwarder);
+                in_flight.spawn(async move {
+                    let outcome =
+                        forward_entry(outbox_repo.as_ref(), forwarder.as_ref(), &entry).await;
+                    (lane, entry.id, outcome)
+                });
+            }
 
-            match forwarder.forward_time_entry(&dto, &entry.idempotency_key).await {
-                Ok(remote_id) => {
-                    debug!(
-                        entry_id = %entry.id,
-                        remote_id = %remote_id,
-                        "Forwarded outbox entry"
-                    );
-                    if let Err(err) = outbox_repo.mark_sent(&entry.id).await {
-                        let msg = err.to_string();
-                        warn!(entry_id = %entry.id, error = %msg, "mark_sent failed");
-                        fatal_errors.push(format!("mark_sent error for {}: {}", entry.id, msg));
-                    } else {
-                        forwarded = forwarded.saturating_add(1);
+            let Some(joined) = in_flight.join_next().await else {
+                break;
+            };
+            match joined {
+                Ok((lane, entry_id, outcome)) => {
+                    busy[lane] = false;
+                    if matches!(outcome, EntryOutcome::Failed { .. }) && !lanes[lane].is_empty() {
+                        debug!(
+                            entry_id = %entry_id,
+                            held = lanes[lane].len(),
+                            "Holding later entries for entity until failed entry is retried"
+                        );
+                        lanes[lane].clear();
                     }
+                    tally.record(outcome);
                 }
                 Err(err) => {
-                    warn!(
-                        entry_id = %entry.id,
-                        error = ?err,
-                        "Forwarding outbox entry failed"
-                    );
-                    if let Err(mark_err) =
-                        outbox_repo.mark_failed(&entry.id, &truncate_reason(&err.to_string())).await
-                    {
-                        let msg = mark_err.to_string();
-                        warn!(entry_id = %entry.id, error = %msg, "mark_failed failed");
-                        fatal_errors.push(format!("mark_failed error for {}: {}", entry.id, msg));
-                    }
-                    failures = failures.saturating_add(1);
+                    // The lane stays busy, so its remaining entries stay pending
+                    warn!(error = %err, "Outbox forward task failed");
+                    tally.fatal_errors.push(format!("forward task failed: {err}"));
                 }
             }
         }
 
-        log_metric(metrics.record_call(), "outbox_worker.batch.processed");
-        debug!(
-            forwarded = forwarded,
-            failures = failures,
-            skipped = skipped,
-            "Outbox batch completed"
-        );
+        tally
+    }
+}
 
-        if failures > 0 {
-            log_metric(metrics.record_fetch_error(), "outbox_worker.batch.failure_count");
+/// Result of forwarding a single outbox entry.
+enum EntryOutcome {
+    /// Forwarded and marked sent
+    Forwarded,
+    /// Parsing or forwarding failed; carries the error from marking it failed,
+    /// if that failed too
+    Failed { mark_error: Option<String> },
+    /// Left for another sender (SAP-bound)
+    Skipped,
+    /// Forwarded, but marking it sent failed
+    Unconfirmed { mark_error: String },
+}
+
+/// Per-batch settings copied out of [`OutboxWorkerConfig`] for the worker
+/// loop.
+#[derive(Debug, Clone, Copy)]
+struct BatchSettings {
+    batch_size: usize,
+    processing_timeout: Duration,
+    ordering: OutboxOrdering,
+}
+
+/// Running totals for a batch.
+#[derive(Default)]
+struct BatchTally {
+    forwarded: u32,
+    failures: u32,
+    skipped: u32,
+    fatal_errors: Vec<String>,
+}
+
+impl BatchTally {
+    fn record(&mut self, outcome: EntryOutcome) {
+        match outcome {
+            EntryOutcome::Forwarded => self.forwarded = self.forwarded.saturating_add(1),
+            EntryOutcome::Failed { mark_error } => {
+                self.failures = self.failures.saturating_add(1);
+                self.fatal_errors.extend(mark_error);
+            }
+            EntryOutcome::Skipped => self.skipped = self.skipped.saturating_add(1),
+            EntryOutcome::Unconfirmed { mark_error } => self.fatal_errors.push(mark_error),
         }
+    }
 
-        if !fatal_errors.is_empty() {
-            return Err(fatal_errors.join("; "));
+    fn into_report(self) -> Result<OutboxFlushReport, String> {
+        if !self.fatal_errors.is_empty() {
+            return Err(self.fatal_errors.join("; "));
         }
 
-        Ok(())
+        Ok(OutboxFlushReport {
+            forwarded: self.forwarded,
+            failed: self.failures,
+            skipped: self.skipped,
+        })
+    }
+}
+
+/// Forward one entry and record the outcome in the outbox.
+async fn forward_entry(
+    outbox_repo: &dyn OutboxQueue,
+    forwarder: &dyn TimeEntryForwarder,
+    entry: &TimeEntryOutbox,
+) -> EntryOutcome {
+    if entry.target.eq_ignore_ascii_case("sap") {
+        debug!(entry_id = %entry.id, "Skipping SAP-target outbox entry");
+        return EntryOutcome::Skipped;
+    }
+
+    let dto = match parse_time_entry(entry) {
+        Ok(dto) => dto,
+        Err(err) => {
+            warn!(
+                entry_id = %entry.id,
+                error = %err,
+                "Failed to parse outbox payload"
+            );
+            let mark_error = mark_entry_failed(outbox_repo, entry, &err.to_string()).await;
+            return EntryOutcome::Failed { mark_error };
+        }
+    };
+
+    match forwarder.forward_time_entry(&dto, &entry.idempotency_key).await {
+        Ok(remote_id) => {
+            debug!(
+                entry_id = %entry.id,
+                remote_id = %remote_id,
+                "Forwarded outbox entry"
+            );
+            match outbox_repo.mark_sent(&entry.id).await {
+                Ok(()) => EntryOutcome::Forwarded,
+                Err(err) => {
+                    let msg = err.to_string();
+                    warn!(entry_id = %entry.id, error = %msg, "mark_sent failed");
+                    EntryOutcome::Unconfirmed {
+                        mark_error: format!("mark_sent error for {}: {}", entry.id, msg),
+                    }
+                }
+            }
+        }
+        Err(err) => {
+            warn!(
+                entry_id = %entry.id,
+                error = ?err,
+                "Forwarding outbox entry failed"
+            );
+            let mark_error = mark_entry_failed(outbox_repo, entry, &err.to_string()).await;
+            EntryOutcome::Failed { mark_error }
+        }
+    }
+}
+
+/// Mark `entry` failed, returning a description of the error if marking it
+/// failed too.
+async fn mark_entry_failed(
+    outbox_repo: &dyn OutboxQueue,
+    entry: &TimeEntryOutbox,
+    reason: &str,
+) -> Option<String> {
+    let mark_err = outbox_repo.mark_failed(&entry.id, &truncate_reason(reason)).await.err()?;
+    let msg = mark_err.to_string();
+    warn!(entry_id = %entry.id, error = %msg, "mark_failed failed");
+    Some(format!("mark_failed error for {}: {}", entry.id, msg))
+}
+
+/// Group a batch into per-entity lanes.
+///
+/// Each lane keeps its entries in dequeue order; lanes are ordered by their
+/// first entry. Entries without an entity id get a lane of their own.
+fn entity_lanes(entries: Vec<TimeEntryOutbox>) -> Vec<VecDeque<TimeEntryOutbox>> {
+    let mut lane_by_entity: HashMap<OutboxEntityKey, usize> = HashMap::new();
+    let mut lanes: Vec<VecDeque<TimeEntryOutbox>> = Vec::new();
+
+    for entry in entries {
+        let Some(key) = entity_key(&entry) else {
+            lanes.push(VecDeque::from([entry]));
+            continue;
+        };
+        match lane_by_entity.get(&key) {
+            Some(&lane) => lanes[lane].push_back(entry),
+            None => {
+                lane_by_entity.insert(key, lanes.len());
+                lanes.push(VecDeque::from([entry]));
+            }
+        }
     }
+
+    lanes
 }
 
 fn parse_time_entry(entry: &TimeEntryOutbox) -> Result<PrismaTimeEntryDto, serde_json::Error> {
@@ -388,6 +896,38 @@ fn log_metric(result: MetricsResult<()>, metric: &'static str) {
     }
 }
 
+/// Flushes pending entries once when the app quits or updates; forced and
+/// crash-recovery shutdowns leave them queued for the next launch.
+#[async_trait]
+impl ShutdownHook for OutboxWorker {
+    fn name(&self) -> &str {
+        "outbox_worker"
+    }
+
+    async fn on_shutdown(
+        &self,
+        reason: ShutdownReason,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if !reason.allows_drain() || !self.is_running() {
+            info!(reason = %reason, "Skipping outbox drain on shutdown");
+            return Ok(());
+        }
+        if self.sync_blocked.as_ref().is_some_and(|blocked| *blocked.borrow()) {
+            info!(reason = %reason, "Sync blocked by MDM policy; skipping outbox drain");
+            return Ok(());
+        }
+
+        let report = self.sync_now().await?;
+        info!(
+            reason = %reason,
+            forwarded = report.forwarded,
+            failed = report.failed,
+            "Drained outbox on shutdown"
+        );
+        Ok(())
+    }
+}
+
 impl Drop for OutboxWorker {
     fn drop(&mut self) {
         if self.is_running() {
@@ -399,6 +939,8 @@ impl Drop for OutboxWorker {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+
     use pulsearc_core::OutboxQueue;
     use pulsearc_domain::{OutboxStatus, PulseArcError, Result as DomainResult};
     use tokio::sync::Mutex as TokioMutex;
@@ -410,6 +952,15 @@ mod tests {
     type FailedStore = Arc<TokioMutex<Vec<(String, String)>>>;
     type ResponseQueue = TokioMutex<Vec<Result<String, SyncError>>>;
     type CallStore = Arc<TokioMutex<Vec<PrismaTimeEntryDto>>>;
+    type Deliveries = TokioMutex<Vec<(String, String)>>;
+
+    fn batch(batch_size: usize, ordering: OutboxOrdering) -> BatchSettings {
+        BatchSettings {
+            batch_size,
+            processing_timeout: OutboxWorkerConfig::default().processing_timeout,
+            ordering,
+        }
+    }
 
     fn sample_time_entry_dto() -> PrismaTimeEntryDto {
         PrismaTimeEntryDto {
@@ -512,9 +1063,31 @@ mod tests {
         }
 
         async fn dequeue_batch(&self, limit: usize) -> DomainResult<Vec<TimeEntryOutbox>> {
-            let mut entries = self.entries.lock().await;
-            let batch_len = limit.min(entries.len());
-            let batch: Vec<_> = entries.drain(..batch_len).collect();
+            // Like the SQLCipher outbox, dequeuing only reads: entries stay
+            // pending until marked sent or failed, and an entity's later
+            // entries are held behind a failed one
+            let entries = self.entries.lock().await;
+            let sent = self.sent.lock().await;
+            let failed = self.failed.lock().await;
+            let mut held = HashSet::new();
+            let mut batch = Vec::new();
+            for entry in entries.iter() {
+                if sent.contains(&entry.id) {
+                    continue;
+                }
+                let entity = entry.entity_id();
+                if failed.iter().any(|(id, _)| id == &entry.id) {
+                    held.extend(entity);
+                    continue;
+                }
+                if entity.is_some_and(|entity| held.contains(&entity)) {
+                    continue;
+                }
+                batch.push(entry.clone());
+                if batch.len() == limit {
+                    break;
+                }
+            }
             Ok(batch)
         }
 
@@ -538,6 +1111,7 @@ mod tests {
     struct MockForwarder {
         responses: ResponseQueue,
         calls: CallStore,
+        delay: Duration,
     }
 
     impl MockForwarder {
@@ -545,9 +1119,15 @@ mod tests {
             Self {
                 responses: TokioMutex::new(responses),
                 calls: Arc::new(TokioMutex::new(Vec::new())),
+                delay: Duration::ZERO,
             }
         }
 
+        fn with_delay(mut self, delay: Duration) -> Self {
+            self.delay = delay;
+            self
+        }
+
         async fn call_count(&self) -> usize {
             self.calls.lock().await.len()
         }
@@ -560,6 +1140,9 @@ mod tests {
             dto: &PrismaTimeEntryDto,
             _idempotency_key: &str,
         ) -> Result<String, SyncError> {
+            if !self.delay.is_zero() {
+                tokio::time::sleep(self.delay).await;
+            }
             self.calls.lock().await.push(dto.clone());
             let mut responses = self.responses.lock().await;
             if responses.is_empty() {
@@ -578,7 +1161,14 @@ mod tests {
         let forwarder_trait: Arc<dyn TimeEntryForwarder> = forwarder.clone();
         let metrics = Arc::new(PerformanceMetrics::new());
 
-        let result = OutboxWorker::process_batch(&repo_trait, &forwarder_trait, 10, &metrics).await;
+        let result = OutboxWorker::process_batch(
+            &repo_trait,
+            &forwarder_trait,
+            batch(10, OutboxOrdering::Sequential),
+            &metrics,
+            &|| false,
+        )
+        .await;
         assert!(result.is_ok());
 
         let sent = repo.sent_entries().await;
@@ -597,7 +1187,14 @@ mod tests {
         let forwarder_trait: Arc<dyn TimeEntryForwarder> = forwarder.clone();
         let metrics = Arc::new(PerformanceMetrics::new());
 
-        let result = OutboxWorker::process_batch(&repo_trait, &forwarder_trait, 5, &metrics).await;
+        let result = OutboxWorker::process_batch(
+            &repo_trait,
+            &forwarder_trait,
+            batch(5, OutboxOrdering::Sequential),
+            &metrics,
+            &|| false,
+        )
+        .await;
         assert!(result.is_ok());
 
         let failed = repo.failed_entries().await;
@@ -617,7 +1214,14 @@ mod tests {
         let forwarder_trait: Arc<dyn TimeEntryForwarder> = forwarder.clone();
         let metrics = Arc::new(PerformanceMetrics::new());
 
-        let result = OutboxWorker::process_batch(&repo_trait, &forwarder_trait, 5, &metrics).await;
+        let result = OutboxWorker::process_batch(
+            &repo_trait,
+            &forwarder_trait,
+            batch(5, OutboxOrdering::Sequential),
+            &metrics,
+            &|| false,
+        )
+        .await;
         assert!(result.is_ok());
 
         assert!(repo.sent_entries().await.is_empty());
@@ -635,7 +1239,14 @@ mod tests {
         let forwarder_trait: Arc<dyn TimeEntryForwarder> = forwarder.clone();
         let metrics = Arc::new(PerformanceMetrics::new());
 
-        let result = OutboxWorker::process_batch(&repo_trait, &forwarder_trait, 5, &metrics).await;
+        let result = OutboxWorker::process_batch(
+            &repo_trait,
+            &forwarder_trait,
+            batch(5, OutboxOrdering::Sequential),
+            &metrics,
+            &|| false,
+        )
+        .await;
         assert!(result.is_err());
         assert!(repo.sent_entries().await.is_empty());
     }
@@ -652,8 +1263,381 @@ mod tests {
         let forwarder_trait: Arc<dyn TimeEntryForwarder> = forwarder.clone();
         let metrics = Arc::new(PerformanceMetrics::new());
 
-        let result = OutboxWorker::process_batch(&repo_trait, &forwarder_trait, 5, &metrics).await;
+        let result = OutboxWorker::process_batch(
+            &repo_trait,
+            &forwarder_trait,
+            batch(5, OutboxOrdering::Sequential),
+            &metrics,
+            &|| false,
+        )
+        .await;
         assert!(result.is_err());
         assert!(repo.failed_entries().await.is_empty());
     }
+
+    #[tokio::test]
+    async fn limited_forwarder_feeds_outcomes_to_concurrency_limiter() {
+        let limiter = AdaptiveConcurrencyLimiter::new(
+            pulsearc_common::resilience::AdaptiveConcurrencyConfig::builder()
+                .initial_limit(2)
+                .max_limit(4)
+                .build()
+                .expect("valid limiter config"),
+        )
+        .expect("valid limiter");
+        let repo: Arc<dyn OutboxQueue> = Arc::new(MockOutboxRepo::new(vec![]));
+        let forwarder = Arc::new(MockForwarder::new(vec![
+            Ok("remote-1".to_string()),
+            Err(SyncError::Server("503".to_string())),
+            Err(SyncError::Client("400".to_string())),
+        ]));
+        let worker = OutboxWorker::new(
+            repo,
+            forwarder.clone(),
+            OutboxWorkerConfig::default(),
+            Arc::new(PerformanceMetrics::new()),
+        )
+        .with_concurrency_limiter(limiter.clone());
+        let dto = sample_time_entry_dto();
+
+        assert!(worker.forwarder.forward_time_entry(&dto, "key-1").await.is_ok());
+        assert_eq!(limiter.limit(), 3, "A successful round trip should raise the limit");
+
+        assert!(worker.forwarder.forward_time_entry(&dto, "key-2").await.is_err());
+        assert_eq!(limiter.limit(), 2, "A server error should lower the limit");
+
+        assert!(worker.forwarder.forward_time_entry(&dto, "key-3").await.is_err());
+        assert_eq!(limiter.limit(), 2, "Client errors should not affect the limit");
+
+        let metrics = worker.concurrency_metrics().expect("limiter configured");
+        assert_eq!(metrics.in_flight, 0);
+        assert_eq!(forwarder.call_count().await, 3);
+    }
+
+    fn sample_outbox_entries(count: usize) -> Vec<TimeEntryOutbox> {
+        (1..=count).map(|i| sample_outbox_entry(&format!("entry-{i}"))).collect()
+    }
+
+    #[tokio::test]
+    async fn background_batch_yields_to_waiting_sync_request() {
+        let repo = Arc::new(MockOutboxRepo::new(sample_outbox_entries(4)));
+        let repo_trait: Arc<dyn OutboxQueue> = repo.clone();
+        let forwarder_trait: Arc<dyn TimeEntryForwarder> = Arc::new(MockForwarder::new(vec![]));
+        let metrics = Arc::new(PerformanceMetrics::new());
+
+        let lanes = FlushLanes::new();
+        lanes.request_background().await;
+        let _receiver = lanes.request_priority().await.expect("sync request queued");
+        assert!(lanes.priority_waiting());
+
+        let request = lanes.next_request().await.expect("request waiting");
+        assert_eq!(request.priority, Priority::High, "user request is serviced first");
+        lanes.complete(&request.id, Ok(OutboxFlushReport::default())).await;
+        assert!(!lanes.priority_waiting());
+
+        // A background batch started while a user request waits stops after
+        // its first entry
+        let _receiver = lanes.request_priority().await.expect("sync request queued");
+        let preempt = || lanes.priority_waiting();
+        let report = OutboxWorker::process_batch(
+            &repo_trait,
+            &forwarder_trait,
+            batch(10, OutboxOrdering::Sequential),
+            &metrics,
+            &preempt,
+        )
+        .await
+        .expect("batch processed");
+        assert_eq!(report.forwarded, 1);
+
+        // The rest stay pending and flush with the next batch
+        let report = OutboxWorker::process_batch(
+            &repo_trait,
+            &forwarder_trait,
+            batch(10, OutboxOrdering::Sequential),
+            &metrics,
+            &|| false,
+        )
+        .await
+        .expect("batch processed");
+        assert_eq!(report.forwarded, 3);
+        assert_eq!(repo.sent_entries().await.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn sync_now_is_serviced_ahead_of_background_backlog() {
+        let total = 30;
+        let repo = Arc::new(MockOutboxRepo::new(sample_outbox_entries(total)));
+        let forwarder = Arc::new(MockForwarder::new(vec![]).with_delay(Duration::from_millis(10)));
+        let mut worker = OutboxWorker::new(
+            repo.clone(),
+            forwarder,
+            OutboxWorkerConfig {
+                batch_size: 5,
+                poll_interval: Duration::from_millis(5),
+                processing_timeout: Duration::from_secs(5),
+                ..Default::default()
+            },
+            Arc::new(PerformanceMetrics::new()),
+        );
+
+        worker.start().await.expect("worker starts");
+        // Let background batching get underway
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        let report = worker.sync_now().await.expect("sync now succeeds");
+        assert_eq!(report.forwarded, 5);
+        assert!(
+            repo.sent_entries().await.len() < total,
+            "sync_now should not wait for the background backlog"
+        );
+
+        // Background batching still drains the backlog
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while repo.sent_entries().await.len() < total {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("background backlog flushes");
+
+        worker.stop().await.expect("worker stops");
+    }
+
+    fn entity_entry(id: &str, entity_id: &str, notes: &str) -> TimeEntryOutbox {
+        let mut dto = sample_time_entry_dto();
+        dto.id = Some(entity_id.to_string());
+        dto.notes = Some(notes.to_string());
+        let mut entry = sample_outbox_entry(id);
+        entry.payload_json = serde_json::to_string(&dto).unwrap();
+        entry
+    }
+
+    /// Forwarder recording `(entity id, notes)` in delivery order, delaying
+    /// or failing selected notes.
+    #[derive(Default)]
+    struct RecordingForwarder {
+        delays: HashMap<String, Duration>,
+        failing: Vec<String>,
+        delivered: Deliveries,
+    }
+
+    impl RecordingForwarder {
+        fn with_delay(mut self, notes: &str, delay: Duration) -> Self {
+            self.delays.insert(notes.to_string(), delay);
+            self
+        }
+
+        fn with_failure(mut self, notes: &str) -> Self {
+            self.failing.push(notes.to_string());
+            self
+        }
+
+        async fn delivered(&self) -> Vec<(String, String)> {
+            self.delivered.lock().await.clone()
+        }
+    }
+
+    #[async_trait]
+    impl TimeEntryForwarder for RecordingForwarder {
+        async fn forward_time_entry(
+            &self,
+            dto: &PrismaTimeEntryDto,
+            _idempotency_key: &str,
+        ) -> Result<String, SyncError> {
+            let notes = dto.notes.clone().unwrap_or_default();
+            if let Some(delay) = self.delays.get(&notes) {
+                tokio::time::sleep(*delay).await;
+            }
+            if self.failing.contains(&notes) {
+                return Err(SyncError::Server("503".to_string()));
+            }
+            self.delivered.lock().await.push((dto.id.clone().unwrap_or_default(), notes));
+            Ok("remote-id".to_string())
+        }
+    }
+
+    const PER_ENTITY: OutboxOrdering = OutboxOrdering::PerEntity { max_in_flight: 4 };
+
+    #[tokio::test(start_paused = true)]
+    async fn per_entity_ordering_delivers_create_before_update() {
+        let repo = Arc::new(MockOutboxRepo::new(vec![
+            entity_entry("entry-1", "entity-a", "create"),
+            entity_entry("entry-2", "entity-a", "update"),
+        ]));
+        let repo_trait: Arc<dyn OutboxQueue> = repo.clone();
+        // A slow create must not be overtaken by the update queued behind it
+        let forwarder =
+            Arc::new(RecordingForwarder::default().with_delay("create", Duration::from_millis(50)));
+        let forwarder_trait: Arc<dyn TimeEntryForwarder> = forwarder.clone();
+        let metrics = Arc::new(PerformanceMetrics::new());
+
+        let report = OutboxWorker::process_batch(
+            &repo_trait,
+            &forwarder_trait,
+            batch(10, PER_ENTITY),
+            &metrics,
+            &|| false,
+        )
+        .await
+        .expect("batch processed");
+
+        assert_eq!(report.forwarded, 2);
+        assert_eq!(
+            forwarder.delivered().await,
+            vec![
+                ("entity-a".to_string(), "create".to_string()),
+                ("entity-a".to_string(), "update".to_string()),
+            ]
+        );
+        assert_eq!(repo.sent_entries().await, vec!["entry-1".to_string(), "entry-2".to_string()]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn per_entity_ordering_parallelizes_unrelated_entities() {
+        let repo = Arc::new(MockOutboxRepo::new(vec![
+            entity_entry("entry-1", "entity-a", "create-a"),
+            entity_entry("entry-2", "entity-b", "create-b"),
+            entity_entry("entry-3", "entity-a", "update-a"),
+        ]));
+        let repo_trait: Arc<dyn OutboxQueue> = repo.clone();
+        let forwarder = Arc::new(
+            RecordingForwarder::default().with_delay("create-a", Duration::from_millis(50)),
+        );
+        let forwarder_trait: Arc<dyn TimeEntryForwarder> = forwarder.clone();
+        let metrics = Arc::new(PerformanceMetrics::new());
+
+        let report = OutboxWorker::process_batch(
+            &repo_trait,
+            &forwarder_trait,
+            batch(10, PER_ENTITY),
+            &metrics,
+            &|| false,
+        )
+        .await
+        .expect("batch processed");
+
+        assert_eq!(report.forwarded, 3);
+        // entity-b does not wait behind entity-a's slow create
+        assert_eq!(
+            forwarder.delivered().await,
+            vec![
+                ("entity-b".to_string(), "create-b".to_string()),
+                ("entity-a".to_string(), "create-a".to_string()),
+                ("entity-a".to_string(), "update-a".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn per_entity_ordering_holds_entity_after_failure() {
+        let repo = Arc::new(MockOutboxRepo::new(vec![
+            entity_entry("entry-1", "entity-a", "create-a"),
+            entity_entry("entry-2", "entity-a", "update-a"),
+            entity_entry("entry-3", "entity-b", "create-b"),
+        ]));
+        let repo_trait: Arc<dyn OutboxQueue> = repo.clone();
+        let forwarder = Arc::new(RecordingForwarder::default().with_failure("create-a"));
+        let forwarder_trait: Arc<dyn TimeEntryForwarder> = forwarder.clone();
+        let metrics = Arc::new(PerformanceMetrics::new());
+
+        let report = OutboxWorker::process_batch(
+            &repo_trait,
+            &forwarder_trait,
+            batch(10, PER_ENTITY),
+            &metrics,
+            &|| false,
+        )
+        .await
+        .expect("batch processed");
+
+        assert_eq!(report, OutboxFlushReport { forwarded: 1, failed: 1, skipped: 0 });
+        assert_eq!(
+            forwarder.delivered().await,
+            vec![("entity-b".to_string(), "create-b".to_string())]
+        );
+        let failed = repo.failed_entries().await;
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].0, "entry-1");
+        // The update stays pending until the create goes through
+        assert_eq!(repo.sent_entries().await, vec!["entry-3".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn per_entity_ordering_holds_entity_across_batches_after_failure() {
+        let repo = Arc::new(MockOutboxRepo::new(vec![
+            entity_entry("entry-1", "entity-a", "create-a"),
+            entity_entry("entry-2", "entity-a", "update-a"),
+        ]));
+        let repo_trait: Arc<dyn OutboxQueue> = repo.clone();
+        let forwarder = Arc::new(RecordingForwarder::default().with_failure("create-a"));
+        let forwarder_trait: Arc<dyn TimeEntryForwarder> = forwarder.clone();
+        let metrics = Arc::new(PerformanceMetrics::new());
+
+        let first = OutboxWorker::process_batch(
+            &repo_trait,
+            &forwarder_trait,
+            batch(10, PER_ENTITY),
+            &metrics,
+            &|| false,
+        )
+        .await
+        .expect("first batch processed");
+        assert_eq!(first, OutboxFlushReport { forwarded: 0, failed: 1, skipped: 0 });
+
+        // The next tick must not pick up the update on its own
+        let second = OutboxWorker::process_batch(
+            &repo_trait,
+            &forwarder_trait,
+            batch(10, PER_ENTITY),
+            &metrics,
+            &|| false,
+        )
+        .await
+        .expect("second batch processed");
+        assert_eq!(second, OutboxFlushReport::default());
+        assert!(forwarder.delivered().await.is_empty());
+        assert!(repo.sent_entries().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn blocked_sync_rejects_flushes_until_lifted() {
+        let repo = Arc::new(MockOutboxRepo::new(sample_outbox_entries(2)));
+        let (blocked, sync_blocked) = watch::channel(true);
+        let mut worker = OutboxWorker::new(
+            repo.clone(),
+            Arc::new(MockForwarder::new(vec![])),
+            OutboxWorkerConfig {
+                poll_interval: Duration::from_millis(5),
+                processing_timeout: Duration::from_secs(5),
+                ..Default::default()
+            },
+            Arc::new(PerformanceMetrics::new()),
+        )
+        .with_sync_blocked(sync_blocked);
+
+        worker.start().await.expect("worker starts");
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        assert_eq!(worker.sync_now().await, Err("Sync blocked by MDM policy".to_string()));
+        assert!(repo.sent_entries().await.is_empty(), "nothing is forwarded while blocked");
+
+        blocked.send_replace(false);
+        let report = worker.sync_now().await.expect("sync now succeeds");
+        assert_eq!(report.forwarded, 2);
+
+        worker.stop().await.expect("worker stops");
+    }
+
+    #[tokio::test]
+    async fn sync_now_requires_running_worker() {
+        let worker = OutboxWorker::new(
+            Arc::new(MockOutboxRepo::new(vec![])),
+            Arc::new(MockForwarder::new(vec![])),
+            OutboxWorkerConfig::default(),
+            Arc::new(PerformanceMetrics::new()),
+        );
+
+        assert_eq!(worker.sync_now().await, Err("Worker not running".to_string()));
+    }
 }
@@ -0,0 +1,191 @@
+//! Vector clocks and last-write-wins resolution for multi-device sync
+//!
+//! Every synced row carries a [`VectorClock`] with one counter per device that
+//! edited it. Comparing two clocks tells whether one edit causally follows
+//! the other (safe to overwrite) or whether they were made concurrently on
+//! different devices (a conflict):
+//! - Causally ordered edits: the later one wins, nothing to reconcile
+//! - Concurrent edits: resolved last-write-wins on `updated_at` (device id
+//!   breaks ties) and reported so the loser can be reconciled
+//!
+//! The resolved record carries the merged clock, so it dominates both edits
+//! and later syncs from either device see it as the latest version.
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Causal relationship between two vector clocks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockOrdering {
+    /// Both clocks are identical
+    Equal,
+    /// `self` happened before `other`
+    Before,
+    /// `self` happened after `other`
+    After,
+    /// Neither clock has seen the other's latest edit
+    Concurrent,
+}
+
+/// Per-device edit counters
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct VectorClock(BTreeMap<String, u64>);
+
+impl VectorClock {
+    /// Empty clock (no edits)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counter for `device_id` (0 if the device never edited the row)
+    pub fn get(&self, device_id: &str) -> u64 {
+        self.0.get(device_id).copied().unwrap_or(0)
+    }
+
+    /// Record an edit made on `device_id`.
+    pub fn increment(&mut self, device_id: &str) {
+        let counter = self.0.entry(device_id.to_string()).or_insert(0);
+        *counter = counter.saturating_add(1);
+    }
+
+    /// Clock that has seen every edit seen by `self` or `other`.
+    pub fn merged(&self, other: &Self) -> Self {
+        let mut merged = self.0.clone();
+        for (device, &counter) in &other.0 {
+            let entry = merged.entry(device.clone()).or_insert(0);
+            *entry = (*entry).max(counter);
+        }
+        Self(merged)
+    }
+
+    /// Causal order of `self` relative to `other`.
+    pub fn compare(&self, other: &Self) -> ClockOrdering {
+        let mut ahead = false;
+        let mut behind = false;
+        for device in self.0.keys().chain(other.0.keys()) {
+            match self.get(device).cmp(&other.get(device)) {
+                Ordering::Greater => ahead = true,
+                Ordering::Less => behind = true,
+                Ordering::Equal => {}
+            }
+        }
+        match (ahead, behind) {
+            (false, false) => ClockOrdering::Equal,
+            (true, false) => ClockOrdering::After,
+            (false, true) => ClockOrdering::Before,
+            (true, true) => ClockOrdering::Concurrent,
+        }
+    }
+}
+
+/// A row payload with the version metadata used for conflict detection
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VersionedRecord<T> {
+    /// Row contents
+    pub payload: T,
+    /// Edits this version has seen
+    pub clock: VectorClock,
+    /// Wall-clock time of the edit (Unix epoch milliseconds)
+    pub updated_at: i64,
+    /// Device that made the edit
+    pub device_id: String,
+}
+
+impl<T> VersionedRecord<T> {
+    /// Record an edit of `payload` on `device_id` based on the version with
+    /// clock `base`.
+    pub fn edit(payload: T, base: &VectorClock, device_id: &str, updated_at: i64) -> Self {
+        let mut clock = base.clone();
+        clock.increment(device_id);
+        Self { payload, clock, updated_at, device_id: device_id.to_string() }
+    }
+
+    /// Last-write-wins order: later `updated_at` first, device id breaking
+    /// ties so every device picks the same winner.
+    fn lww_cmp(&self, other: &Self) -> Ordering {
+        self.updated_at.cmp(&other.updated_at).then_with(|| self.device_id.cmp(&other.device_id))
+    }
+}
+
+/// Which side of a concurrent edit was kept
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictWinner {
+    /// This device's edit
+    Local,
+    /// The edit already stored on the server
+    Remote,
+}
+
+/// Concurrent edits to the same row, resolved last-write-wins
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditConflict<T> {
+    /// This device's edit
+    pub local: VersionedRecord<T>,
+    /// The concurrent edit found on the server
+    pub remote: VersionedRecord<T>,
+    /// Side whose payload was kept
+    pub winner: ConflictWinner,
+    /// Winning payload with the merged clock
+    pub resolved: VersionedRecord<T>,
+}
+
+/// Resolve concurrent edits last-write-wins.
+///
+/// The winner's payload is kept with the merged clock so it dominates both
+/// edits.
+pub fn resolve_concurrent<T: Clone>(
+    local: &VersionedRecord<T>,
+    remote: &VersionedRecord<T>,
+) -> EditConflict<T> {
+    let (winner, kept) = if local.lww_cmp(remote) == Ordering::Greater {
+        (ConflictWinner::Local, local)
+    } else {
+        (ConflictWinner::Remote, remote)
+    };
+    let resolved = VersionedRecord { clock: local.clock.merged(&remote.clock), ..kept.clone() };
+    EditConflict { local: local.clone(), remote: remote.clone(), winner, resolved }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clock(entries: &[(&str, u64)]) -> VectorClock {
+        VectorClock(entries.iter().map(|(device, n)| (device.to_string(), *n)).collect())
+    }
+
+    #[test]
+    fn compares_causal_order() {
+        let base = clock(&[("laptop", 1)]);
+        let later = clock(&[("laptop", 2)]);
+        let other = clock(&[("laptop", 1), ("desktop", 1)]);
+
+        assert_eq!(base.compare(&base.clone()), ClockOrdering::Equal);
+        assert_eq!(base.compare(&later), ClockOrdering::Before);
+        assert_eq!(later.compare(&base), ClockOrdering::After);
+        assert_eq!(later.compare(&other), ClockOrdering::Concurrent);
+        assert_eq!(later.merged(&other), clock(&[("laptop", 2), ("desktop", 1)]));
+    }
+
+    #[test]
+    fn concurrent_edits_resolve_to_latest_write_with_merged_clock() {
+        let base = clock(&[("laptop", 1)]);
+        let laptop = VersionedRecord::edit("laptop edit", &base, "laptop", 1_000);
+        let desktop = VersionedRecord::edit("desktop edit", &base, "desktop", 2_000);
+
+        let conflict = resolve_concurrent(&laptop, &desktop);
+
+        assert_eq!(conflict.winner, ConflictWinner::Remote);
+        assert_eq!(conflict.resolved.payload, "desktop edit");
+        assert_eq!(conflict.resolved.clock.compare(&laptop.clock), ClockOrdering::After);
+        assert_eq!(conflict.resolved.clock.compare(&desktop.clock), ClockOrdering::After);
+
+        // Same timestamp: the device id decides, identically on both sides
+        let tied = VersionedRecord { updated_at: 1_000, ..desktop };
+        assert_eq!(resolve_concurrent(&laptop, &tied).winner, ConflictWinner::Local);
+        assert_eq!(resolve_concurrent(&tied, &laptop).winner, ConflictWinner::Remote);
+    }
+}
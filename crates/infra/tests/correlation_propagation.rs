@@ -0,0 +1,133 @@
+//! Correlation ids across the DB and HTTP layers
+//!
+//! A command opens one correlation scope (see `with_correlation_id` in the API
+//! crate). Repository work runs on blocking threads, so its logs only carry
+//! the command's id if the closure was wrapped with
+//! `correlation::propagate`; HTTP logs run on the command's own task.
+
+#![allow(dead_code)]
+
+#[path = "support.rs"]
+mod support;
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use chrono::Utc;
+use pulsearc_domain::OutboxStatus;
+use pulsearc_infra::database::SqliteOutboxRepository;
+use pulsearc_infra::observability::correlation;
+use pulsearc_infra::HttpClient;
+use reqwest::Method;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{info_span, Event, Instrument, Subscriber};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+use wiremock::matchers::method;
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// `correlation_id` recorded on a span
+struct SpanCorrelationId(String);
+
+/// Finds the `correlation_id` field among recorded values
+#[derive(Default)]
+struct CorrelationVisitor(Option<String>);
+
+impl Visit for CorrelationVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "correlation_id" {
+            self.0 = Some(value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "correlation_id" {
+            self.0 = Some(format!("{value:?}"));
+        }
+    }
+}
+
+/// Target and correlation id (if any) of a captured event
+type CapturedEvent = (String, Option<String>);
+
+/// Layer recording each PulseArc event
+#[derive(Clone, Default)]
+struct CaptureLayer {
+    events: Arc<Mutex<Vec<CapturedEvent>>>,
+}
+
+impl<S> Layer<S> for CaptureLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = CorrelationVisitor::default();
+        attrs.record(&mut visitor);
+        if let (Some(value), Some(span)) = (visitor.0, ctx.span(id)) {
+            span.extensions_mut().insert(SpanCorrelationId(value));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let target = event.metadata().target();
+        if !target.starts_with("pulsearc") {
+            return;
+        }
+        let mut visitor = CorrelationVisitor::default();
+        event.record(&mut visitor);
+        let from_span = || {
+            ctx.event_scope(event)?.find_map(|span| {
+                span.extensions().get::<SpanCorrelationId>().map(|id| id.0.clone())
+            })
+        };
+        let correlation_id = visitor.0.or_else(from_span);
+        self.events.lock().unwrap().push((target.to_string(), correlation_id));
+    }
+}
+
+#[tokio::test]
+async fn db_and_http_logs_of_one_command_share_correlation_id() {
+    let db = support::setup_outbox_db();
+    let repo = SqliteOutboxRepository::new(db.manager.clone());
+    let server = MockServer::start().await;
+    Mock::given(method("GET")).respond_with(ResponseTemplate::new(200)).mount(&server).await;
+    let client = HttpClient::builder().max_attempts(1).build().unwrap();
+
+    // Global, not thread-local: repository logs come from blocking threads
+    let layer = CaptureLayer::default();
+    tracing::subscriber::set_global_default(tracing_subscriber::registry().with(layer.clone()))
+        .expect("only subscriber in this test binary");
+
+    let correlation_id = correlation::new_correlation_id();
+    let span = info_span!("command", correlation_id = %correlation_id);
+    correlation::scope(
+        correlation_id.clone(),
+        async {
+            let entry = support::make_outbox_entry(
+                "entry-1",
+                OutboxStatus::Pending,
+                Utc::now().timestamp(),
+            );
+            repo.insert_entry(&entry).await.expect("entry inserted");
+            client.send(client.request(Method::GET, server.uri())).await.expect("response");
+        }
+        .instrument(span),
+    )
+    .await;
+
+    let events = layer.events.lock().unwrap().clone();
+    assert!(
+        events.iter().any(|(target, _)| target.contains("sqlcipher")),
+        "DB logs captured: {events:?}"
+    );
+    assert!(
+        events.iter().any(|(target, _)| target.contains("http")),
+        "HTTP logs captured: {events:?}"
+    );
+    assert!(
+        events.iter().all(|(_, id)| id.as_deref() == Some(correlation_id.as_str())),
+        "every log carries the command's correlation id: {events:?}"
+    );
+}
@@ -407,6 +407,7 @@ fn make_snapshot(ts: DateTime<Utc>, primary_app: &str, is_idle: bool) -> Activit
         processed_at: None,
         is_idle,
         idle_duration_secs: if is_idle { Some(120) } else { None },
+        content_hash: None,
     }
 }
 
@@ -132,7 +132,8 @@ pub fn setup_snapshot_db() -> TestDatabase {
             created_at INTEGER NOT NULL,
             processed_at INTEGER,
             is_idle INTEGER NOT NULL DEFAULT 0,
-            idle_duration_secs INTEGER
+            idle_duration_secs INTEGER,
+            content_hash TEXT
         );
         CREATE INDEX idx_activity_snapshots_timestamp ON activity_snapshots(timestamp);
     ",
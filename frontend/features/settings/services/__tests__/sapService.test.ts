@@ -178,6 +178,9 @@ describe('SapService', () => {
       pending_count: 3,
       sent_count: 10,
       failed_count: 1,
+      dismissed_count: 0,
+      oldest_pending_age_secs: 120,
+      pending_age_buckets: { under_1m: 1, under_10m: 2, under_1h: 0, older: 0 },
     };
 
     it('should get outbox status', async () => {
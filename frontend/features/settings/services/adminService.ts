@@ -17,8 +17,8 @@ export const adminService = {
   },
 
   /**
-   * Clear all outbox entries (suggestions)
-   * WARNING: This is irreversible
+   * Clear all pending and failed outbox entries (suggestions)
+   * Can be undone with `restore_last_cleared` for a short window
    */
   clearOutbox: async (): Promise<void> => {
     await invoke('clear_suggestions');
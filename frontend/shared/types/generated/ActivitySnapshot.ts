@@ -20,4 +20,10 @@ export type ActivitySnapshot = {
   processed_at?: number;
   is_idle: boolean;
   idle_duration_secs: number | null;
+  /**
+   * Stable hash of the salient context fields (see
+   * [`ActivitySnapshot::compute_content_hash`]); `None` for rows captured
+   * before hashing was introduced.
+   */
+  content_hash?: string;
 };
@@ -0,0 +1,40 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ProjectDaySummary } from "./ProjectDaySummary";
+
+/**
+ * End-of-day totals for accepted blocks
+ */
+export type DailySummary = {
+  /**
+   * Day being summarized (YYYY-MM-DD)
+   */
+  date: string;
+  /**
+   * Total tracked time across accepted blocks (seconds)
+   */
+  total_secs: number;
+  /**
+   * Billable time (seconds)
+   */
+  billable_secs: number;
+  /**
+   * Non-billable time (seconds)
+   */
+  non_billable_secs: number;
+  /**
+   * Time outside the user's working hours (seconds)
+   */
+  after_hours_secs: number;
+  /**
+   * Time on the user's non-working days (seconds)
+   */
+  weekend_secs: number;
+  /**
+   * Number of accepted blocks
+   */
+  block_count: number;
+  /**
+   * Per-project breakdown, largest total first
+   */
+  projects: Array<ProjectDaySummary>;
+};
@@ -20,4 +20,8 @@ export type DatabaseSize = {
    * Number of unused pages (PRAGMA freelist_count)
    */
   freelist_count: number;
+  /**
+   * Size of the write-ahead log file in bytes (0 when there is none)
+   */
+  wal_size_bytes: number;
 };
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Row that violates a foreign key (PRAGMA foreign_key_check).
+ */
+export type ForeignKeyViolation = {
+  /**
+   * Table holding the offending row
+   */
+  table: string;
+  /**
+   * Rowid of the offending row (None for WITHOUT ROWID tables)
+   */
+  rowid: number | null;
+  /**
+   * Table the foreign key refers to
+   */
+  parent: string;
+  /**
+   * Index of the foreign key constraint on `table`
+   */
+  constraint_index: number;
+};
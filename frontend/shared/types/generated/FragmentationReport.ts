@@ -0,0 +1,40 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VacuumRecommendation } from "./VacuumRecommendation";
+
+/**
+ * Free-page and fragmentation report from PRAGMA introspection.
+ */
+export type FragmentationReport = {
+  /**
+   * Number of pages in the database (PRAGMA page_count)
+   */
+  page_count: number;
+  /**
+   * Size of each page in bytes (PRAGMA page_size)
+   */
+  page_size: number;
+  /**
+   * Number of unused pages (PRAGMA freelist_count)
+   */
+  freelist_count: number;
+  /**
+   * Size of the free list in bytes
+   */
+  freelist_bytes: number;
+  /**
+   * Free pages divided by total pages (0.0 - 1.0)
+   */
+  fragmentation_ratio: number;
+  /**
+   * Whether the database uses `auto_vacuum = INCREMENTAL`
+   */
+  incremental_vacuum_enabled: boolean;
+  /**
+   * Suggested maintenance action
+   */
+  recommendation: VacuumRecommendation;
+  /**
+   * Human-readable recommendation, e.g. "incremental vacuum 128 pages"
+   */
+  summary: string;
+};
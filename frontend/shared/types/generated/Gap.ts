@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Untracked interval within a workday
+ */
+export type Gap = {
+  /**
+   * Start of the gap (Unix epoch seconds)
+   */
+  start_ts: number;
+  /**
+   * End of the gap, exclusive (Unix epoch seconds)
+   */
+  end_ts: number;
+  /**
+   * Length of the gap in seconds
+   */
+  duration_secs: number;
+  /**
+   * Title of the calendar event that most overlaps the gap, if any
+   */
+  likely_meeting: string | null;
+  /**
+   * ID of that calendar event
+   */
+  calendar_event_id: string | null;
+};
@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ForeignKeyViolation } from "./ForeignKeyViolation";
+
+/**
+ * Findings of PRAGMA integrity_check and PRAGMA foreign_key_check.
+ */
+export type IntegrityReport = {
+  /**
+   * No integrity problems or foreign key violations were found
+   */
+  ok: boolean;
+  /**
+   * Problems reported by PRAGMA integrity_check (empty when it says "ok")
+   */
+  integrity_errors: Array<string>;
+  /**
+   * Rows reported by PRAGMA foreign_key_check
+   */
+  foreign_key_violations: Array<ForeignKeyViolation>;
+  /**
+   * Human-readable result, e.g. "2 integrity errors"
+   */
+  summary: string;
+};
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Histogram of outbox entry ages.
+ *
+ * Bucket boundaries are exclusive upper bounds: an entry exactly 60 seconds
+ * old lands in `under_10m`, not `under_1m`.
+ */
+export type OutboxAgeBuckets = {
+  under_1m: number;
+  under_10m: number;
+  under_1h: number;
+  older: number;
+};
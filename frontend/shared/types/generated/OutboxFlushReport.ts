@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcome of a user-initiated outbox flush (`sync_now`).
+ */
+export type OutboxFlushReport = {
+  /**
+   * Entries forwarded and marked sent
+   */
+  forwarded: number;
+  /**
+   * Entries that failed to parse or forward (scheduled for retry)
+   */
+  failed: number;
+  /**
+   * SAP-bound entries left for the SAP scheduler
+   */
+  skipped: number;
+};
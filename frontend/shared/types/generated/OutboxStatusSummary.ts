@@ -1,10 +1,25 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OutboxAgeBuckets } from './OutboxAgeBuckets';
 
 /**
  * Aggregate counters for the SAP time-entry outbox.
+ *
+ * Besides the per-status counts, the summary carries the age of the oldest
+ * pending entry and an age histogram of pending entries so the UI can flag a
+ * stuck sync (entries piling up in the older buckets).
  */
 export type OutboxStatusSummary = {
   pending_count: number;
   sent_count: number;
   failed_count: number;
+  dismissed_count: number;
+  /**
+   * Age in seconds of the oldest pending entry (`None` when nothing is
+   * pending)
+   */
+  oldest_pending_age_secs: number | null;
+  /**
+   * Histogram of pending entry ages
+   */
+  pending_age_buckets: OutboxAgeBuckets;
 };
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Two time entries whose `[start, end)` intervals intersect
+ */
+export type OverlapConflict = {
+  /**
+   * Entry that starts first
+   */
+  first_entry_id: string;
+  /**
+   * Entry that starts later (or at the same time)
+   */
+  second_entry_id: string;
+  /**
+   * Start of the overlapping span
+   */
+  overlap_start: string;
+  /**
+   * End of the overlapping span (exclusive)
+   */
+  overlap_end: string;
+  /**
+   * Length of the overlapping span in seconds
+   */
+  overlap_secs: number;
+};
@@ -0,0 +1,31 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One project's share of a [`DailySummary`]
+ */
+export type ProjectDaySummary = {
+  /**
+   * Project ID (`None` for blocks without an inferred project)
+   */
+  project_id: string | null;
+  /**
+   * Deal/project name, when known (e.g., "Project Astro")
+   */
+  project_name: string | null;
+  /**
+   * Total time on this project (seconds)
+   */
+  total_secs: number;
+  /**
+   * Billable time on this project (seconds)
+   */
+  billable_secs: number;
+  /**
+   * Non-billable time on this project (seconds)
+   */
+  non_billable_secs: number;
+  /**
+   * Number of accepted blocks for this project
+   */
+  block_count: number;
+};
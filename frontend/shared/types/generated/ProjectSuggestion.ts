@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * ProjectSuggestion - Autocomplete match for a typed project code
+ */
+export type ProjectSuggestion = {
+  id: string;
+  name: string;
+  wbs_code: string | null;
+  /**
+   * Times the user picked this project (ranks suggestions)
+   */
+  usage_count: number;
+};
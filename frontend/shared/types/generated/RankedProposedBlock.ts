@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ProposedBlock } from "./ProposedBlock";
+
+/**
+ * A proposed block paired with its ranking score
+ *
+ * Serialized flat, so consumers expecting a `ProposedBlock` keep working and
+ * simply see an extra `score` field.
+ */
+export type RankedProposedBlock = {
+  /**
+   * Normalized ranking score (0.0 to 1.0, higher ranks first)
+   */
+  score: number;
+} & ProposedBlock;
@@ -0,0 +1,29 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A suppression learned from repeated dismissals of the same signature
+ */
+export type SuggestionSuppression = {
+  /**
+   * App/context signature (e.g., "spotify|-")
+   */
+  signature: string;
+  /**
+   * Dismissals of this signature still inside the decay window
+   */
+  dismissal_count: number;
+  /**
+   * Most recent dismissal (Unix epoch seconds)
+   */
+  last_dismissed_at: number;
+  /**
+   * Whether matching suggestions are currently suppressed
+   */
+  active: boolean;
+  /**
+   * When the suppression lapses as dismissals decay (Unix epoch seconds)
+   *
+   * `None` while the signature is below the suppression threshold.
+   */
+  expires_at: number | null;
+};
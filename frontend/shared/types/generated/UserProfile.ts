@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WorkdayConfig } from "./WorkdayConfig";
 
 /**
  * User profile stored in local database
@@ -32,4 +33,8 @@ export type UserProfile = {
   last_synced_at: number;
   created_at: number;
   updated_at: number;
+  /**
+   * Working hours, evaluated in `timezone`
+   */
+  workday: WorkdayConfig;
 };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Maintenance action suggested by a [`FragmentationReport`].
+ */
+export type VacuumRecommendation = "none" | "incremental_vacuum" | "vacuum";
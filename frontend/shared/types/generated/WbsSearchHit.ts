@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WbsElement } from "./WbsElement";
+
+/**
+ * A WBS element matched by fuzzy search, with its similarity score.
+ */
+export type WbsSearchHit = {
+  element: WbsElement;
+  /**
+   * Similarity in `0.0..=1.0`; 1.0 is an exact code match
+   */
+  score: number;
+};
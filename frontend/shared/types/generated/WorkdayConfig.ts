@@ -0,0 +1,25 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A user's working hours
+ *
+ * Times are local to the profile's `timezone`. A workday whose end is
+ * earlier than its start runs past midnight.
+ */
+export type WorkdayConfig = {
+  /**
+   * Start of the workday, minutes after local midnight (default 480 =
+   * 08:00)
+   */
+  start_minutes: number;
+  /**
+   * End of the workday, minutes after local midnight (default 1080 =
+   * 18:00)
+   */
+  end_minutes: number;
+  /**
+   * Working days as ISO weekday numbers, 1 = Monday .. 7 = Sunday
+   * (default Monday-Friday)
+   */
+  working_days: Array<number>;
+};
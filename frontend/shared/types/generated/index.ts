@@ -34,28 +34,40 @@ export type { ClassifiedTimeEntry } from './ClassifiedTimeEntry';
 export type { ClassifierUsageStats } from './ClassifierUsageStats';
 export type { ConfidenceEvidence } from './ConfidenceEvidence';
 export type { ContextPart } from './ContextPart';
+export type { DailySummary } from './DailySummary';
 export type { DatabaseSize } from './DatabaseSize';
 export type { DatabaseStats } from './DatabaseStats';
 export type { DlqBatch } from './DlqBatch';
 export type { DrainStats } from './DrainStats';
 export type { EvidenceSignals } from './EvidenceSignals';
+export type { ForeignKeyViolation } from './ForeignKeyViolation';
+export type { FragmentationReport } from './FragmentationReport';
+export type { Gap } from './Gap';
 export type { HealthStatus } from './HealthStatus';
 export type { IdMapping } from './IdMapping';
 export type { IdleConfig } from './IdleConfig';
 export type { IdlePeriod } from './IdlePeriod';
 export type { IdleSummary } from './IdleSummary';
+export type { IntegrityReport } from './IntegrityReport';
 export type { OpenAIBatchResponse } from './OpenAIBatchResponse';
+export type { OutboxAgeBuckets } from './OutboxAgeBuckets';
+export type { OutboxFlushReport } from './OutboxFlushReport';
 export type { OutboxStats } from './OutboxStats';
 export type { OutboxStatus } from './OutboxStatus';
 export type { OutboxStatusSummary } from './OutboxStatusSummary';
+export type { OverlapConflict } from './OverlapConflict';
 export type { ParsedFields } from './ParsedFields';
 export type { PauseReason } from './PauseReason';
 export type { PrismaTimeEntryDto } from './PrismaTimeEntryDto';
 export type { Project } from './Project';
+export type { ProjectDaySummary } from './ProjectDaySummary';
+export type { ProjectSuggestion } from './ProjectSuggestion';
 export type { ProjectWithWbs } from './ProjectWithWbs';
 export type { ProposedBlock } from './ProposedBlock';
+export type { RankedProposedBlock } from './RankedProposedBlock';
 export type { SapSyncSettings } from './SapSyncSettings';
 export type { SuggestionFeedbackParams } from './SuggestionFeedbackParams';
+export type { SuggestionSuppression } from './SuggestionSuppression';
 export type { SyncStats } from './SyncStats';
 export type { TableStats } from './TableStats';
 export type { TimeEntry } from './TimeEntry';
@@ -68,7 +80,10 @@ export type { TrainingReport } from './TrainingReport';
 export type { TrainingStats } from './TrainingStats';
 export type { UserCostSummary } from './UserCostSummary';
 export type { UserProfile } from './UserProfile';
+export type { VacuumRecommendation } from './VacuumRecommendation';
 export type { WbsElement } from './WbsElement';
+export type { WbsSearchHit } from './WbsSearchHit';
 export type { WindowContext } from './WindowContext';
 export type { WorkLocation } from './WorkLocation';
 export type { WorkType } from './WorkType';
+export type { WorkdayConfig } from './WorkdayConfig';